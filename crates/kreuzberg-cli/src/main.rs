@@ -8,8 +8,15 @@
 //! The CLI is built using `clap` for argument parsing and provides five main commands:
 //! - `extract`: Extract text/data from a single document
 //! - `batch`: Process multiple documents in parallel
+//! - `diff`: Compare two documents (sections, tables, metadata)
 //! - `detect`: Identify MIME type of a file
+//! - `chunk`: Split a document or text into chunks
+//! - `reduce`: Reduce the token count of a document or text
+//! - `keywords`: Extract keywords from a document or text
+//! - `detect-language`: Detect the languages of a document or text
+//! - `ocr`: Manage Tesseract language packs (list, verify, download)
 //! - `cache`: Manage cache (clear, stats)
+//! - `plugins`: Inspect registered plugins
 //! - `serve`: Start API server (requires `api` feature)
 //! - `version`: Show version information
 //!
@@ -18,6 +25,7 @@
 //! The CLI supports configuration files in TOML, YAML, or JSON formats:
 //! - Explicit: `--config path/to/config.toml`
 //! - Auto-discovery: Searches for `kreuzberg.{toml,yaml,json}` in current and parent directories
+//! - Profiles: `--profile name` applies the file's `[profile.name]` section over its top-level settings
 //! - Command-line flags override config file settings
 //!
 //! # Exit Codes
@@ -37,21 +45,60 @@
 //! # Batch processing
 //! kreuzberg batch *.pdf --format json
 //!
+//! # Extract a directory tree, writing results and a manifest
+//! kreuzberg batch corpus/ --recursive --include '*.pdf' --output-dir out --manifest out/manifest.json
+//!
+//! # Nightly refresh: only extract files that changed since the last manifest
+//! kreuzberg batch corpus/ --recursive --output-dir out --manifest out/manifest.json --incremental
+//!
+//! # Long OCR batch that can be resumed after a crash by re-running the same command
+//! kreuzberg batch scans/*.pdf --resume batch.state --output-format jsonl --output scans.jsonl
+//!
+//! # Write a directory tree as one Parquet dataset
+//! kreuzberg batch corpus/ --recursive --output-format parquet --output corpus.parquet
+//!
 //! # Detect MIME type
 //! kreuzberg detect unknown-file.bin
+//!
+//! # Chunk a document for embedding, one JSON object per chunk
+//! kreuzberg chunk report.pdf --max-chars 800 --overlap 100 --format jsonl
+//!
+//! # Shrink text from a pipeline before sending it to an LLM
+//! cat notes.txt | kreuzberg reduce - --level aggressive --max-tokens 2000
+//!
+//! # Top keywords and languages of a document
+//! kreuzberg keywords report.pdf --max-keywords 5
+//! kreuzberg detect-language report.pdf --multiple --format json
+//!
+//! # Check OCR languages before a run and install the missing ones
+//! kreuzberg ocr languages --verify eng+deu || kreuzberg ocr languages --download deu
 //! ```
 
 #![deny(unsafe_code)]
 
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
+#[cfg(any(feature = "api", feature = "mcp"))]
+use kreuzberg::ConfigHandle;
+use kreuzberg::chunking::{ChunkerType, chunk_text};
+use kreuzberg::diff::{ChangeKind, DocumentDiff};
+use kreuzberg::io::{ResultFileFormat, write_result};
+use kreuzberg::keywords::{KeywordAlgorithm, KeywordConfig, extract_keywords};
+use kreuzberg::language_detection::detect_languages;
+use kreuzberg::ocr::create_searchable_pdf;
+use kreuzberg::ocr::languages::{self as ocr_languages, TessdataModel};
+use kreuzberg::text::token_reduction::{ReductionLevel, TokenReductionConfig, get_reduction_statistics, reduce_tokens};
 use kreuzberg::{
-    ChunkingConfig, ExtractionConfig, LanguageDetectionConfig, OcrConfig, batch_extract_file_sync, detect_mime_type,
-    extract_file_sync,
+    ChunkingConfig, DatasetFormat, DatasetWriter, DirectoryManifest, DirectoryOptions, ExtractionConfig,
+    ExtractionResult, FileChange, KreuzbergError, LanguageDetectionConfig, OcrConfig,
+    batch_extract_file_resumable_sync, batch_extract_file_sync, detect_mime_type, extract_directory, extract_file_sync,
 };
 use serde_json::json;
+use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
-use tracing_subscriber::EnvFilter;
+
+/// Dataset output of `batch --output-format`.
+type Dataset = DatasetWriter<Box<dyn Write + Send>>;
 
 /// Kreuzberg document intelligence CLI
 #[derive(Parser)]
@@ -73,6 +120,10 @@ enum Commands {
         #[arg(short, long)]
         config: Option<PathBuf>,
 
+        /// Named profile of the config file (`[profile.<name>]`) to apply over its top-level settings
+        #[arg(long)]
+        profile: Option<String>,
+
         /// MIME type hint (auto-detected if not provided)
         #[arg(short, long)]
         mime_type: Option<String>,
@@ -112,17 +163,37 @@ enum Commands {
         /// Enable language detection (overrides config file)
         #[arg(long)]
         detect_language: Option<bool>,
+
+        /// Enable keyword extraction (overrides config file)
+        #[arg(long)]
+        keywords: Option<bool>,
+
+        /// Keyword extraction algorithm: yake or rake (overrides config file)
+        #[arg(long)]
+        keyword_algorithm: Option<String>,
+
+        /// Maximum number of keywords to extract (overrides config file)
+        #[arg(long)]
+        max_keywords: Option<usize>,
+
+        /// Also write a searchable PDF (page images with an invisible OCR text layer) to this path
+        #[arg(long, value_name = "PATH")]
+        searchable_pdf: Option<PathBuf>,
     },
 
     /// Batch extract from multiple documents
     Batch {
-        /// Paths to documents
+        /// Paths to documents (a single directory with --recursive)
         paths: Vec<PathBuf>,
 
         /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
         #[arg(short, long)]
         config: Option<PathBuf>,
 
+        /// Named profile of the config file (`[profile.<name>]`) to apply over its top-level settings
+        #[arg(long)]
+        profile: Option<String>,
+
         /// Output format (text or json)
         #[arg(short, long, default_value = "json")]
         format: OutputFormat,
@@ -142,6 +213,68 @@ enum Commands {
         /// Enable quality processing (overrides config file)
         #[arg(long)]
         quality: Option<bool>,
+
+        /// Extract every file below the given directory
+        #[arg(short, long)]
+        recursive: bool,
+
+        /// Only extract files matching this glob (repeatable, requires --recursive)
+        #[arg(long, requires = "recursive")]
+        include: Vec<String>,
+
+        /// Skip files and directories matching this glob (repeatable, requires --recursive)
+        #[arg(long, requires = "recursive")]
+        exclude: Vec<String>,
+
+        /// Write each result to its own file below this directory, in --output-dir-format
+        #[arg(long)]
+        output_dir: Option<PathBuf>,
+
+        /// Format of the files in --output-dir: md, txt, json or html (md and html save images alongside)
+        #[arg(long, value_name = "FORMAT", default_value = "json", requires = "output_dir")]
+        output_dir_format: ResultFileFormat,
+
+        /// Write a manifest of files, content hashes and output paths (requires --recursive)
+        #[arg(long, requires = "recursive")]
+        manifest: Option<PathBuf>,
+
+        /// Resume an interrupted run. Without --recursive, progress is checkpointed to STATE_FILE;
+        /// with --recursive, results are cached by content and no state file is taken
+        #[arg(long, value_name = "STATE_FILE", num_args = 0..=1)]
+        resume: Option<Option<PathBuf>>,
+
+        /// Only extract files that are new or changed since the run that wrote --manifest
+        #[arg(long, requires_all = ["recursive", "manifest"])]
+        incremental: bool,
+
+        /// Write all results as one dataset (jsonl or parquet) instead of --format output
+        #[arg(long)]
+        output_format: Option<DatasetFormat>,
+
+        /// Dataset file for --output-format (defaults to stdout)
+        #[arg(short, long, requires = "output_format")]
+        output: Option<PathBuf>,
+    },
+
+    /// Compare two documents: changed sections, tables and metadata
+    Diff {
+        /// Original document
+        before: PathBuf,
+
+        /// Revised document
+        after: PathBuf,
+
+        /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Named profile of the config file (`[profile.<name>]`) to apply over its top-level settings
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Output format (text or json)
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
     },
 
     /// Detect MIME type of a file
@@ -154,6 +287,142 @@ enum Commands {
         format: OutputFormat,
     },
 
+    /// Split a document or text into chunks, e.g. for embedding
+    Chunk {
+        /// Document to extract, or `-` to read plain text from stdin
+        input: PathBuf,
+
+        /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Named profile of the config file (`[profile.<name>]`) to apply over its top-level settings
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Read the input file as plain text instead of extracting it
+        #[arg(long)]
+        raw: bool,
+
+        /// Maximum chunk size in characters
+        #[arg(long, default_value_t = 1000)]
+        max_chars: usize,
+
+        /// Characters shared by consecutive chunks
+        #[arg(long, default_value_t = 200)]
+        overlap: usize,
+
+        /// Split along Markdown structure (headings, lists, code blocks) instead of sentences
+        #[arg(long)]
+        markdown: bool,
+
+        /// Output format (text, json or jsonl)
+        #[arg(short, long, default_value = "text")]
+        format: ChunkFormat,
+    },
+
+    /// Reduce the token count of a document or text, e.g. before sending it to an LLM
+    Reduce {
+        /// Document to extract, or `-` to read plain text from stdin
+        input: PathBuf,
+
+        /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Named profile of the config file (`[profile.<name>]`) to apply over its top-level settings
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Read the input file as plain text instead of extracting it
+        #[arg(long)]
+        raw: bool,
+
+        /// Reduction level: off, light, moderate, aggressive or maximum
+        #[arg(long, default_value = "moderate")]
+        level: String,
+
+        /// Language of the text as ISO 639-3 code (e.g. eng, deu), for stopword removal
+        #[arg(long)]
+        language: Option<String>,
+
+        /// Keep at most this many tokens, dropping the least important sentences
+        #[arg(long)]
+        max_tokens: Option<usize>,
+
+        /// Keep at most this fraction (0.0-1.0) of the tokens, dropping the least important sentences
+        #[arg(long)]
+        target_ratio: Option<f32>,
+
+        /// Output format (text or json, which adds reduction statistics)
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
+    },
+
+    /// Extract keywords from a document or text
+    Keywords {
+        /// Document to extract, or `-` to read plain text from stdin
+        input: PathBuf,
+
+        /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Named profile of the config file (`[profile.<name>]`) to apply over its top-level settings
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Read the input file as plain text instead of extracting it
+        #[arg(long)]
+        raw: bool,
+
+        /// Keyword extraction algorithm: yake or rake (overrides config file)
+        #[arg(long)]
+        algorithm: Option<String>,
+
+        /// Maximum number of keywords to extract (overrides config file)
+        #[arg(long)]
+        max_keywords: Option<usize>,
+
+        /// Language of the text for stopword filtering (e.g. en, de); defaults to English
+        #[arg(long)]
+        language: Option<String>,
+
+        /// Output format (text or json)
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
+    },
+
+    /// Detect the languages of a document or text
+    DetectLanguage {
+        /// Document to extract, or `-` to read plain text from stdin
+        input: PathBuf,
+
+        /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+
+        /// Named profile of the config file (`[profile.<name>]`) to apply over its top-level settings
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Read the input file as plain text instead of extracting it
+        #[arg(long)]
+        raw: bool,
+
+        /// Report every language of a mixed-language text instead of only the main one
+        #[arg(long)]
+        multiple: bool,
+
+        /// Minimum detection confidence (0.0-1.0, overrides config file)
+        #[arg(long)]
+        min_confidence: Option<f64>,
+
+        /// Output format (text or json)
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
+    },
+
     /// Show version information
     Version {
         /// Output format (text or json)
@@ -161,12 +430,31 @@ enum Commands {
         format: OutputFormat,
     },
 
+    /// Check external dependencies (Tesseract, PDFium, LibreOffice, pffexport); exits with status 1 if any is missing
+    Doctor {
+        /// Output format (text or json)
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
+    },
+
+    /// OCR setup operations
+    Ocr {
+        #[command(subcommand)]
+        command: OcrCommands,
+    },
+
     /// Cache management operations
     Cache {
         #[command(subcommand)]
         command: CacheCommands,
     },
 
+    /// Plugin introspection operations
+    Plugins {
+        #[command(subcommand)]
+        command: PluginCommands,
+    },
+
     /// Start the API server
     #[cfg(feature = "api")]
     Serve {
@@ -181,6 +469,23 @@ enum Commands {
         /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
         #[arg(short, long)]
         config: Option<PathBuf>,
+
+        /// Named profile of the config file (`[profile.<name>]`) to apply over its top-level settings
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Watch the config file and reload it on change without restarting the server
+        #[arg(long)]
+        watch: bool,
+
+        /// Maximum number of extractions running at once across all requests (default: number of CPU cores)
+        #[arg(long)]
+        max_concurrent_jobs: Option<usize>,
+
+        /// Also serve the gRPC interface on this port, sharing the configuration with the REST API
+        #[cfg(feature = "grpc")]
+        #[arg(long)]
+        grpc_port: Option<u16>,
     },
 
     /// Start the MCP (Model Context Protocol) server
@@ -189,6 +494,44 @@ enum Commands {
         /// Path to config file (TOML, YAML, or JSON). If not specified, searches for kreuzberg.toml/yaml/json in current and parent directories.
         #[arg(short, long)]
         config: Option<PathBuf>,
+
+        /// Named profile of the config file (`[profile.<name>]`) to apply over its top-level settings
+        #[arg(long)]
+        profile: Option<String>,
+
+        /// Watch the config file and reload it on change without restarting the server
+        #[arg(long)]
+        watch: bool,
+
+        /// Maximum number of extractions running at once across all requests (default: number of CPU cores)
+        #[arg(long)]
+        max_concurrent_jobs: Option<usize>,
+    },
+}
+
+#[derive(Subcommand)]
+enum OcrCommands {
+    /// List installed Tesseract language packs, or verify or download languages
+    Languages {
+        /// Check that these languages (e.g. "eng+deu") are installed, failing with the missing ones
+        #[arg(long, value_name = "LANGUAGES", conflicts_with = "download")]
+        verify: Option<String>,
+
+        /// Download these languages (e.g. "deu+fra") from the tesseract-ocr tessdata repositories
+        #[arg(long, value_name = "LANGUAGES")]
+        download: Option<String>,
+
+        /// Model family to download: fast, best or legacy
+        #[arg(long, default_value = "fast")]
+        model: TessdataModel,
+
+        /// Tessdata directory (default: TESSDATA_PREFIX or the system tessdata directory)
+        #[arg(long, value_name = "DIR")]
+        tessdata_dir: Option<PathBuf>,
+
+        /// Output format (text or json)
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
     },
 }
 
@@ -217,6 +560,16 @@ enum CacheCommands {
     },
 }
 
+#[derive(Subcommand)]
+enum PluginCommands {
+    /// List all registered plugins with their MIME types, priorities and stages
+    List {
+        /// Output format (text or json)
+        #[arg(short, long, default_value = "text")]
+        format: OutputFormat,
+    },
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum OutputFormat {
     Text,
@@ -235,6 +588,36 @@ impl std::str::FromStr for OutputFormat {
     }
 }
 
+/// Output format of `chunk`: one block per chunk, a JSON array, or one JSON object per line.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ChunkFormat {
+    Text,
+    Json,
+    Jsonl,
+}
+
+impl std::str::FromStr for ChunkFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(ChunkFormat::Text),
+            "json" => Ok(ChunkFormat::Json),
+            "jsonl" => Ok(ChunkFormat::Jsonl),
+            _ => Err(format!("Invalid format: {}. Use 'text', 'json' or 'jsonl'", s)),
+        }
+    }
+}
+
+impl From<ChunkFormat> for OutputFormat {
+    fn from(format: ChunkFormat) -> Self {
+        match format {
+            ChunkFormat::Text => OutputFormat::Text,
+            ChunkFormat::Json | ChunkFormat::Jsonl => OutputFormat::Json,
+        }
+    }
+}
+
 /// Validates that a file exists and is accessible.
 ///
 /// Checks that the path exists in the filesystem and points to a regular file
@@ -324,11 +707,217 @@ fn validate_batch_paths(paths: &[PathBuf]) -> Result<()> {
     Ok(())
 }
 
+fn validate_directory_path(paths: &[PathBuf]) -> Result<()> {
+    let [path] = paths else {
+        anyhow::bail!("--recursive takes exactly one directory, got {} paths.", paths.len());
+    };
+    if !path.is_dir() {
+        anyhow::bail!(
+            "Path is not a directory: '{}'. --recursive expects a directory to walk.",
+            path.display()
+        );
+    }
+    Ok(())
+}
+
+fn batch_result_json(result: &ExtractionResult) -> serde_json::Value {
+    json!({
+        "content": result.content,
+        "mime_type": result.mime_type,
+        "metadata": result.metadata,
+        "tables": result.tables.iter().map(|t| json!({
+            "cells": t.cells,
+            "markdown": t.markdown,
+            "page_number": t.page_number,
+        })).collect::<Vec<_>>(),
+        "keywords": result.keywords,
+    })
+}
+
+/// Reads the text to process: stdin for `-`, the file itself with `--raw`, and otherwise the
+/// content extracted from the document.
+fn read_input_text(input: &Path, raw: bool, config: &ExtractionConfig, format: OutputFormat) -> Result<String> {
+    if input == Path::new("-") {
+        let mut text = String::new();
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .context("Failed to read text from stdin")?;
+        return Ok(text);
+    }
+
+    validate_file_exists(input)?;
+    if raw {
+        return std::fs::read_to_string(input)
+            .with_context(|| format!("Failed to read '{}' as UTF-8 text", input.display()));
+    }
+
+    let result = extract_file_sync(input, None, config)
+        .map_err(|e| report_json_error(e, format))
+        .with_context(|| format!("Failed to extract '{}'", input.display()))?;
+    Ok(result.content)
+}
+
+/// Parses `reduce --level`, rejecting unknown levels instead of falling back to moderate.
+fn parse_reduction_level(level: &str) -> Result<ReductionLevel> {
+    match level.to_lowercase().as_str() {
+        "off" | "light" | "moderate" | "aggressive" | "maximum" => Ok(ReductionLevel::from(level)),
+        _ => anyhow::bail!(
+            "Invalid reduction level: {}. Use 'off', 'light', 'moderate', 'aggressive' or 'maximum'.",
+            level
+        ),
+    }
+}
+
+/// Opens the `--output` file, or stdout, for `batch --output-format`.
+fn open_dataset(format: DatasetFormat, output: Option<&Path>) -> Result<Dataset> {
+    let writer: Box<dyn Write + Send> = match output {
+        Some(path) => {
+            let file = std::fs::File::create(path)
+                .with_context(|| format!("Failed to create dataset file '{}'", path.display()))?;
+            Box::new(std::io::BufWriter::new(file))
+        }
+        None => Box::new(std::io::BufWriter::new(std::io::stdout())),
+    };
+    DatasetWriter::new(writer, format).context("Failed to start dataset output")
+}
+
+/// Prints how many files an incremental run added, modified, skipped and found deleted (stderr).
+fn report_changes(manifest_path: &Path) -> Result<()> {
+    let data = std::fs::read(manifest_path)
+        .with_context(|| format!("Failed to read manifest '{}'", manifest_path.display()))?;
+    let manifest: DirectoryManifest = serde_json::from_slice(&data).context("Failed to parse manifest")?;
+    let count = |change: FileChange| {
+        manifest
+            .entries
+            .iter()
+            .filter(|entry| entry.change == Some(change))
+            .count()
+    };
+    eprintln!(
+        "Incremental run: {} added, {} modified, {} unchanged, {} deleted",
+        count(FileChange::Added),
+        count(FileChange::Modified),
+        count(FileChange::Unchanged),
+        count(FileChange::Deleted)
+    );
+    Ok(())
+}
+
+/// Prints a document diff as `+`/`-`/`~` lines grouped by sections, tables and metadata.
+fn print_diff(diff: &DocumentDiff) {
+    if diff.is_empty() {
+        println!("No differences");
+        return;
+    }
+    let marker = |kind: ChangeKind| match kind {
+        ChangeKind::Added => '+',
+        ChangeKind::Removed => '-',
+        ChangeKind::Modified => '~',
+    };
+
+    for section in &diff.sections {
+        let heading = section.heading.as_deref().unwrap_or("(no heading)");
+        println!("{} Section under \"{}\"", marker(section.kind), heading);
+        if let Some(before) = &section.before {
+            println!("  - {}", before.replace('\n', "\n    "));
+        }
+        if let Some(after) = &section.after {
+            println!("  + {}", after.replace('\n', "\n    "));
+        }
+    }
+    for table in &diff.tables {
+        println!("{} Table on page {}", marker(table.kind), table.page_number);
+    }
+    let show = |value: &Option<serde_json::Value>| match value {
+        Some(value) => value.to_string(),
+        None => "(none)".to_string(),
+    };
+    for field in &diff.metadata {
+        println!(
+            "~ Metadata {}: {} -> {}",
+            field.path,
+            show(&field.before),
+            show(&field.after)
+        );
+    }
+}
+
+/// Runs `batch --recursive`: prints each file as it completes (text) or all files at the end (JSON).
+///
+/// With a dataset, successful results become dataset rows and failures are reported on stderr.
+fn extract_directory_recursive(
+    root: &Path,
+    options: DirectoryOptions,
+    format: OutputFormat,
+    mut dataset: Option<Dataset>,
+) -> Result<()> {
+    let incremental_manifest = options.manifest_path.clone().filter(|_| options.incremental);
+    let mut stream = extract_directory(root, options)
+        .map_err(|e| report_json_error(e, format))
+        .with_context(|| format!("Failed to extract directory '{}'", root.display()))?;
+
+    let mut output = Vec::new();
+    while let Some(item) = stream.blocking_recv() {
+        let item = item
+            .map_err(|e| report_json_error(e, format))
+            .with_context(|| format!("Failed to extract directory '{}'", root.display()))?;
+
+        if let Some(dataset) = dataset.as_mut() {
+            match &item.result {
+                Ok(result) => dataset
+                    .write(Some(item.relative_path.as_str()), result)
+                    .context("Failed to write dataset row")?,
+                Err(e) => eprintln!("Skipping '{}': {}", item.relative_path, e),
+            }
+            continue;
+        }
+
+        match format {
+            OutputFormat::Text => {
+                println!("=== {} ===", item.relative_path);
+                match &item.result {
+                    Ok(result) => {
+                        println!("MIME Type: {}", result.mime_type);
+                        println!("Content:\n{}", result.content);
+                    }
+                    Err(e) => println!("Error: {}", e),
+                }
+                println!();
+            }
+            OutputFormat::Json => {
+                let mut entry = match &item.result {
+                    Ok(result) => batch_result_json(result),
+                    Err(e) => json!({ "error": e.to_info() }),
+                };
+                entry["path"] = json!(item.relative_path);
+                output.push(entry);
+            }
+        }
+    }
+
+    if let Some(manifest_path) = incremental_manifest {
+        report_changes(&manifest_path)?;
+    }
+
+    if let Some(dataset) = dataset {
+        dataset.finish().context("Failed to finish dataset output")?;
+        return Ok(());
+    }
+
+    if format == OutputFormat::Json {
+        output.sort_by_key(|entry| entry["path"].as_str().map(str::to_string));
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&output).context("Failed to serialize batch extraction results to JSON")?
+        );
+    }
+    Ok(())
+}
+
 fn main() -> Result<()> {
-    let _ = tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
-        .with_writer(std::io::stderr)
-        .try_init();
+    if let Err(e) = kreuzberg::init_logging(&kreuzberg::LogConfig::from_env()) {
+        eprintln!("Warning: logging disabled: {}", e);
+    }
 
     let cli = Cli::parse();
 
@@ -336,6 +925,7 @@ fn main() -> Result<()> {
         Commands::Extract {
             path,
             config: config_path,
+            profile,
             mime_type,
             format,
             ocr,
@@ -346,11 +936,15 @@ fn main() -> Result<()> {
             chunk_overlap,
             quality,
             detect_language,
+            keywords,
+            keyword_algorithm,
+            max_keywords,
+            searchable_pdf,
         } => {
             validate_file_exists(&path)?;
             validate_chunk_params(chunk_size, chunk_overlap)?;
 
-            let mut config = load_config(config_path)?;
+            let mut config = load_config(config_path, profile.as_deref())?;
 
             if let Some(ocr_flag) = ocr {
                 if ocr_flag {
@@ -399,20 +993,32 @@ fn main() -> Result<()> {
                         enabled: true,
                         min_confidence: 0.8,
                         detect_multiple: false,
+                        max_sample_kb: None,
                     });
                 } else {
                     config.language_detection = None;
                 }
             }
+            apply_keyword_flags(&mut config, keywords, keyword_algorithm.as_deref(), max_keywords)?;
 
             let path_str = path.to_string_lossy().to_string();
 
-            let result = extract_file_sync(&path_str, mime_type.as_deref(), &config).with_context(|| {
-                format!(
-                    "Failed to extract file '{}'. Ensure the file is readable and the format is supported.",
-                    path.display()
-                )
-            })?;
+            let result = extract_file_sync(&path_str, mime_type.as_deref(), &config)
+                .map_err(|e| report_json_error(e, format))
+                .with_context(|| {
+                    format!(
+                        "Failed to extract file '{}'. Ensure the file is readable and the format is supported.",
+                        path.display()
+                    )
+                })?;
+
+            if let Some(output_path) = searchable_pdf {
+                let pdf = create_searchable_pdf(&path, &config)
+                    .map_err(|e| report_json_error(e, format))
+                    .with_context(|| format!("Failed to create searchable PDF for '{}'", path.display()))?;
+                std::fs::write(&output_path, pdf)
+                    .with_context(|| format!("Failed to write searchable PDF to '{}'", output_path.display()))?;
+            }
 
             match format {
                 OutputFormat::Text => {
@@ -428,6 +1034,7 @@ fn main() -> Result<()> {
                             "markdown": t.markdown,
                             "page_number": t.page_number,
                         })).collect::<Vec<_>>(),
+                        "keywords": result.keywords,
                     });
                     println!(
                         "{}",
@@ -441,15 +1048,30 @@ fn main() -> Result<()> {
         Commands::Batch {
             paths,
             config: config_path,
+            profile,
             format,
             ocr,
             force_ocr,
             no_cache,
             quality,
+            recursive,
+            include,
+            exclude,
+            output_dir,
+            output_dir_format,
+            manifest,
+            resume,
+            incremental,
+            output_format,
+            output,
         } => {
-            validate_batch_paths(&paths)?;
+            if recursive {
+                validate_directory_path(&paths)?;
+            } else {
+                validate_batch_paths(&paths)?;
+            }
 
-            let mut config = load_config(config_path)?;
+            let mut config = load_config(config_path, profile.as_deref())?;
 
             if let Some(ocr_flag) = ocr {
                 if ocr_flag {
@@ -472,11 +1094,61 @@ fn main() -> Result<()> {
                 config.enable_quality_processing = quality_flag;
             }
 
+            if recursive {
+                if let Some(Some(state_file)) = &resume {
+                    anyhow::bail!(
+                        "--resume takes no state file with --recursive (got '{}'); results are resumed from the cache.",
+                        state_file.display()
+                    );
+                }
+                let options = DirectoryOptions {
+                    config,
+                    include,
+                    exclude,
+                    resume: resume.is_some(),
+                    incremental,
+                    output_dir,
+                    output_format: output_dir_format,
+                    manifest_path: manifest,
+                    ..Default::default()
+                };
+                let dataset = output_format
+                    .map(|dataset_format| open_dataset(dataset_format, output.as_deref()))
+                    .transpose()?;
+                return extract_directory_recursive(&paths[0], options, format, dataset);
+            }
+
             let path_strs: Vec<String> = paths.iter().map(|p| p.to_string_lossy().to_string()).collect();
 
-            let results = batch_extract_file_sync(path_strs, &config)
+            let results = match &resume {
+                Some(Some(state_file)) => batch_extract_file_resumable_sync(path_strs, &config, state_file),
+                Some(None) => anyhow::bail!("--resume needs a state file unless --recursive is given."),
+                None => batch_extract_file_sync(path_strs, &config),
+            };
+            let results = results
+                .map_err(|e| report_json_error(e, format))
                 .with_context(|| format!("Failed to batch extract {} documents. Check that all files are readable and formats are supported.", paths.len()))?;
 
+            if let Some(output_dir) = &output_dir {
+                for (path, result) in paths.iter().zip(&results) {
+                    let file_name = path.file_name().unwrap_or(path.as_os_str()).to_string_lossy();
+                    let output = output_dir.join(format!("{}.{}", file_name, output_dir_format.extension()));
+                    write_result(result, output_dir_format, &output)
+                        .with_context(|| format!("Failed to write '{}'", output.display()))?;
+                }
+            }
+
+            if let Some(dataset_format) = output_format {
+                let mut dataset = open_dataset(dataset_format, output.as_deref())?;
+                for (path, result) in paths.iter().zip(&results) {
+                    dataset
+                        .write(Some(path.to_string_lossy().as_ref()), result)
+                        .context("Failed to write dataset row")?;
+                }
+                dataset.finish().context("Failed to finish dataset output")?;
+                return Ok(());
+            }
+
             match format {
                 OutputFormat::Text => {
                     for (i, result) in results.iter().enumerate() {
@@ -487,21 +1159,7 @@ fn main() -> Result<()> {
                     }
                 }
                 OutputFormat::Json => {
-                    let output: Vec<_> = results
-                        .iter()
-                        .map(|result| {
-                            json!({
-                                "content": result.content,
-                                "mime_type": result.mime_type,
-                                "metadata": result.metadata,
-                                "tables": result.tables.iter().map(|t| json!({
-                                    "cells": t.cells,
-                                    "markdown": t.markdown,
-                                    "page_number": t.page_number,
-                                })).collect::<Vec<_>>(),
-                            })
-                        })
-                        .collect();
+                    let output: Vec<_> = results.iter().map(batch_result_json).collect();
                     println!(
                         "{}",
                         serde_json::to_string_pretty(&output)
@@ -511,16 +1169,47 @@ fn main() -> Result<()> {
             }
         }
 
+        Commands::Diff {
+            before,
+            after,
+            config: config_path,
+            profile,
+            format,
+        } => {
+            validate_file_exists(&before)?;
+            validate_file_exists(&after)?;
+            let config = load_config(config_path, profile.as_deref())?;
+
+            let extract = |path: &Path| {
+                extract_file_sync(path, None, &config)
+                    .map_err(|e| report_json_error(e, format))
+                    .with_context(|| format!("Failed to extract '{}'", path.display()))
+            };
+            let diff = kreuzberg::diff::compare(&extract(&before)?, &extract(&after)?);
+
+            match format {
+                OutputFormat::Text => print_diff(&diff),
+                OutputFormat::Json => {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&diff).context("Failed to serialize document diff to JSON")?
+                    );
+                }
+            }
+        }
+
         Commands::Detect { path, format } => {
             validate_file_exists(&path)?;
 
             let path_str = path.to_string_lossy().to_string();
-            let mime_type = detect_mime_type(&path_str, true).with_context(|| {
-                format!(
-                    "Failed to detect MIME type for file '{}'. Ensure the file is readable.",
-                    path.display()
-                )
-            })?;
+            let mime_type = detect_mime_type(&path_str, true)
+                .map_err(|e| report_json_error(e, format))
+                .with_context(|| {
+                    format!(
+                        "Failed to detect MIME type for file '{}'. Ensure the file is readable.",
+                        path.display()
+                    )
+                })?;
 
             match format {
                 OutputFormat::Text => {
@@ -540,6 +1229,221 @@ fn main() -> Result<()> {
             }
         }
 
+        Commands::Chunk {
+            input,
+            config: config_path,
+            profile,
+            raw,
+            max_chars,
+            overlap,
+            markdown,
+            format,
+        } => {
+            validate_chunk_params(Some(max_chars), Some(overlap))?;
+            let config = load_config(config_path, profile.as_deref())?;
+            let text = read_input_text(&input, raw, &config, format.into())?;
+
+            let chunker_type = if markdown {
+                ChunkerType::Markdown
+            } else {
+                ChunkerType::Text
+            };
+            let chunk_config = kreuzberg::chunking::ChunkingConfig {
+                max_characters: max_chars,
+                overlap,
+                trim: true,
+                chunker_type,
+            };
+            let chunks = chunk_text(&text, &chunk_config, None)
+                .map_err(|e| report_json_error(e, format.into()))
+                .context("Failed to chunk text")?
+                .chunks;
+
+            match format {
+                ChunkFormat::Text => {
+                    for chunk in &chunks {
+                        println!("=== Chunk {} ===", chunk.metadata.chunk_index + 1);
+                        println!("{}", chunk.content);
+                        println!();
+                    }
+                }
+                ChunkFormat::Json => {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&chunks).context("Failed to serialize chunks to JSON")?
+                    );
+                }
+                ChunkFormat::Jsonl => {
+                    let mut stdout = std::io::stdout().lock();
+                    for chunk in &chunks {
+                        serde_json::to_writer(&mut stdout, chunk).context("Failed to serialize chunk to JSON")?;
+                        writeln!(stdout)?;
+                    }
+                }
+            }
+        }
+
+        Commands::Reduce {
+            input,
+            config: config_path,
+            profile,
+            raw,
+            level,
+            language,
+            max_tokens,
+            target_ratio,
+            format,
+        } => {
+            if let Some(ratio) = target_ratio
+                && !(0.0..=1.0).contains(&ratio)
+            {
+                anyhow::bail!(
+                    "Invalid target ratio: {}. The ratio must be between 0.0 and 1.0.",
+                    ratio
+                );
+            }
+            let reduction_config = TokenReductionConfig {
+                level: parse_reduction_level(&level)?,
+                language_hint: language.clone(),
+                max_tokens,
+                target_ratio,
+                ..Default::default()
+            };
+            let config = load_config(config_path, profile.as_deref())?;
+            let text = read_input_text(&input, raw, &config, format)?;
+
+            let reduced = reduce_tokens(&text, &reduction_config, language.as_deref())
+                .map_err(|e| report_json_error(e, format))
+                .context("Failed to reduce tokens")?;
+
+            match format {
+                OutputFormat::Text => {
+                    println!("{}", reduced);
+                }
+                OutputFormat::Json => {
+                    let (
+                        char_reduction,
+                        token_reduction,
+                        original_chars,
+                        reduced_chars,
+                        original_tokens,
+                        reduced_tokens,
+                    ) = get_reduction_statistics(&text, &reduced);
+                    let output = json!({
+                        "content": reduced,
+                        "statistics": {
+                            "char_reduction": char_reduction,
+                            "token_reduction": token_reduction,
+                            "original_chars": original_chars,
+                            "reduced_chars": reduced_chars,
+                            "original_tokens": original_tokens,
+                            "reduced_tokens": reduced_tokens,
+                        },
+                    });
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&output).context("Failed to serialize reduced text to JSON")?
+                    );
+                }
+            }
+        }
+
+        Commands::Keywords {
+            input,
+            config: config_path,
+            profile,
+            raw,
+            algorithm,
+            max_keywords,
+            language,
+            format,
+        } => {
+            let mut config = load_config(config_path, profile.as_deref())?;
+            apply_keyword_flags(&mut config, Some(true), algorithm.as_deref(), max_keywords)?;
+            // Taken out of the config so that the extraction below does not extract keywords too.
+            let mut keyword_config = config.keywords.take().unwrap_or_default();
+            if language.is_some() {
+                keyword_config.language = language;
+            }
+            let text = read_input_text(&input, raw, &config, format)?;
+
+            let keywords = extract_keywords(&text, &keyword_config)
+                .map_err(|e| report_json_error(e, format))
+                .context("Failed to extract keywords")?;
+
+            match format {
+                OutputFormat::Text => {
+                    for keyword in &keywords {
+                        println!("{:.3}\t{}", keyword.score, keyword.text);
+                    }
+                }
+                OutputFormat::Json => {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&keywords).context("Failed to serialize keywords to JSON")?
+                    );
+                }
+            }
+        }
+
+        Commands::DetectLanguage {
+            input,
+            config: config_path,
+            profile,
+            raw,
+            multiple,
+            min_confidence,
+            format,
+        } => {
+            if let Some(confidence) = min_confidence
+                && !(0.0..=1.0).contains(&confidence)
+            {
+                anyhow::bail!(
+                    "Invalid minimum confidence: {}. It must be between 0.0 and 1.0.",
+                    confidence
+                );
+            }
+            let mut config = load_config(config_path, profile.as_deref())?;
+            // Taken out of the config so that the extraction below does not detect languages too.
+            let mut detection_config = match config.language_detection.take() {
+                Some(detection_config) => detection_config,
+                None => LanguageDetectionConfig {
+                    enabled: true,
+                    min_confidence: 0.8,
+                    detect_multiple: false,
+                    max_sample_kb: None,
+                },
+            };
+            detection_config.enabled = true;
+            detection_config.detect_multiple |= multiple;
+            if let Some(confidence) = min_confidence {
+                detection_config.min_confidence = confidence;
+            }
+            let text = read_input_text(&input, raw, &config, format)?;
+
+            let languages = detect_languages(&text, &detection_config)
+                .map_err(|e| report_json_error(e, format))
+                .context("Failed to detect languages")?
+                .unwrap_or_default();
+
+            match format {
+                OutputFormat::Text => {
+                    if languages.is_empty() {
+                        println!("No language detected");
+                    }
+                    for language in &languages {
+                        println!("{}\t{:.2}\t{}", language.language, language.confidence, language.script);
+                    }
+                }
+                OutputFormat::Json => {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&languages).context("Failed to serialize languages to JSON")?
+                    );
+                }
+            }
+        }
+
         Commands::Version { format } => {
             let version = env!("CARGO_PKG_VERSION");
             let name = env!("CARGO_PKG_NAME");
@@ -549,9 +1453,16 @@ fn main() -> Result<()> {
                     println!("{} {}", name, version);
                 }
                 OutputFormat::Json => {
+                    // Device settings of a discovered config file; version output never fails on it
+                    let device = ExtractionConfig::discover()
+                        .ok()
+                        .flatten()
+                        .and_then(|config| config.device)
+                        .unwrap_or_default();
                     let output = json!({
                         "name": name,
                         "version": version,
+                        "device": kreuzberg::device::resolve_device(&device),
                     });
                     println!(
                         "{}",
@@ -562,30 +1473,152 @@ fn main() -> Result<()> {
             }
         }
 
+        Commands::Doctor { format } => {
+            let report = kreuzberg::doctor();
+
+            match format {
+                OutputFormat::Text => {
+                    println!("kreuzberg {}", report.version);
+                    println!("Features: {}", report.features.join(", "));
+                    println!("OCR backends: {}", report.ocr_backends.join(", "));
+                    for dependency in &report.dependencies {
+                        let status = if dependency.available { "ok" } else { "MISSING" };
+                        let details = dependency
+                            .version
+                            .iter()
+                            .chain(&dependency.path)
+                            .chain(&dependency.error)
+                            .map(String::as_str)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        println!(
+                            "{:<12} {:<8} {} (needed for {})",
+                            dependency.name, status, details, dependency.required_for
+                        );
+                        if !dependency.languages.is_empty() {
+                            println!("{:<12} {:<8} languages: {}", "", "", dependency.languages.join(", "));
+                        }
+                    }
+                }
+                OutputFormat::Json => {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&report).context("Failed to serialize doctor report to JSON")?
+                    );
+                }
+            }
+
+            if !report.is_healthy() {
+                std::process::exit(1);
+            }
+        }
+
         #[cfg(feature = "api")]
         Commands::Serve {
             host,
             port,
             config: config_path,
+            profile,
+            watch,
+            max_concurrent_jobs,
+            #[cfg(feature = "grpc")]
+            grpc_port,
         } => {
-            let config = load_config(config_path)?;
+            let config = load_config_handle(config_path, profile)?;
+            configure_concurrency(max_concurrent_jobs)?;
 
             println!("Starting Kreuzberg API server on http://{}:{}...", host, port);
             let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(kreuzberg::api::serve_with_config(&host, port, config))
-                .with_context(|| format!("Failed to start API server on {}:{}. Ensure the port is not already in use and you have permission to bind to this address.", host, port))?;
+            rt.block_on(async {
+                let _watcher = if watch { watch_config(&config) } else { None };
+                let limits = kreuzberg::api::ApiSizeLimits::default();
+
+                #[cfg(feature = "grpc")]
+                if let Some(grpc_port) = grpc_port {
+                    println!("Starting Kreuzberg gRPC server on {}:{}...", host, grpc_port);
+                    let grpc = kreuzberg::api::serve_grpc_with_config_handle(&host, grpc_port, config.clone(), limits);
+                    let rest = kreuzberg::api::serve_with_config_handle(&host, port, config, limits);
+                    return tokio::try_join!(rest, grpc).map(|_| ());
+                }
+
+                kreuzberg::api::serve_with_config_handle(&host, port, config, limits).await
+            })
+            .with_context(|| format!("Failed to start API server on {}:{}. Ensure the port is not already in use and you have permission to bind to this address.", host, port))?;
         }
 
         #[cfg(feature = "mcp")]
-        Commands::Mcp { config: config_path } => {
-            let config = load_config(config_path)?;
+        Commands::Mcp {
+            config: config_path,
+            profile,
+            watch,
+            max_concurrent_jobs,
+        } => {
+            let config = load_config_handle(config_path, profile)?;
+            configure_concurrency(max_concurrent_jobs)?;
 
             tracing::debug!("Starting Kreuzberg MCP server...");
             let rt = tokio::runtime::Runtime::new()?;
-            rt.block_on(kreuzberg::mcp::start_mcp_server_with_config(config))
-                .map_err(|e| anyhow::anyhow!("Failed to start MCP server: {}", e))?;
+            rt.block_on(async {
+                let _watcher = if watch { watch_config(&config) } else { None };
+                kreuzberg::mcp::start_mcp_server_with_config_handle(config).await
+            })
+            .map_err(|e| anyhow::anyhow!("Failed to start MCP server: {}", e))?;
         }
 
+        Commands::Ocr { command } => match command {
+            OcrCommands::Languages {
+                verify,
+                download,
+                model,
+                tessdata_dir,
+                format,
+            } => {
+                let tessdata_dir = tessdata_dir.as_deref();
+                let output = if let Some(languages) = download {
+                    let rt = tokio::runtime::Runtime::new()?;
+                    let installed = rt
+                        .block_on(ocr_languages::download(&languages, model, tessdata_dir))
+                        .map_err(|e| report_json_error(e, format))
+                        .with_context(|| format!("Failed to download language packs '{}'", languages))?;
+                    if format == OutputFormat::Text {
+                        for path in &installed {
+                            println!("Installed {}", path.display());
+                        }
+                    }
+                    json!({ "installed": installed })
+                } else if let Some(languages) = verify {
+                    ocr_languages::verify(&languages, tessdata_dir)
+                        .map_err(|e| report_json_error(e, format))
+                        .context("Language verification failed")?;
+                    if format == OutputFormat::Text {
+                        println!("All language packs installed: {}", languages);
+                    }
+                    json!({ "languages": languages, "installed": true })
+                } else {
+                    let installed = ocr_languages::list_installed(tessdata_dir)
+                        .map_err(|e| report_json_error(e, format))
+                        .context("Failed to list installed language packs")?;
+                    let dir = tessdata_dir.map(Path::to_path_buf).or_else(ocr_languages::tessdata_dir);
+                    if format == OutputFormat::Text {
+                        if let Some(dir) = &dir {
+                            println!("Tessdata directory: {}", dir.display());
+                        }
+                        for language in &installed {
+                            println!("{}", language);
+                        }
+                    }
+                    json!({ "tessdata_dir": dir, "languages": installed })
+                };
+
+                if format == OutputFormat::Json {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&output).context("Failed to serialize language packs to JSON")?
+                    );
+                }
+            }
+        },
+
         Commands::Cache { command } => {
             use kreuzberg::cache;
 
@@ -666,11 +1699,148 @@ fn main() -> Result<()> {
                 }
             }
         }
+
+        Commands::Plugins { command } => match command {
+            PluginCommands::List { format } => {
+                let plugins = kreuzberg::plugins::describe().context("Failed to describe registered plugins")?;
+
+                match format {
+                    OutputFormat::Text => {
+                        println!(
+                            "{:<20} {:<32} {:<10} {:>8}  {:<8} DETAILS",
+                            "KIND", "NAME", "VERSION", "PRIORITY", "STAGE"
+                        );
+                        for plugin in &plugins {
+                            let priority = plugin.priority.map_or_else(|| "-".to_string(), |p| p.to_string());
+                            let stage = plugin.stage.map_or("-", |s| s.as_str());
+                            let details = if plugin.mime_types.is_empty() {
+                                plugin.languages.join(", ")
+                            } else {
+                                plugin.mime_types.join(", ")
+                            };
+                            println!(
+                                "{:<20} {:<32} {:<10} {:>8}  {:<8} {}",
+                                plugin.kind.as_str(),
+                                plugin.name,
+                                plugin.version,
+                                priority,
+                                stage,
+                                details
+                            );
+                        }
+                    }
+                    OutputFormat::Json => {
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&plugins)
+                                .context("Failed to serialize plugin list to JSON")?
+                        );
+                    }
+                }
+            }
+        },
     }
 
     Ok(())
 }
 
+/// In JSON mode, prints a failed operation as `{"error": ErrorInfo}` on stdout and exits with
+/// status 1 so scripts can branch on the error code. In text mode the error is returned as-is.
+fn report_json_error(error: KreuzbergError, format: OutputFormat) -> KreuzbergError {
+    if format == OutputFormat::Json {
+        let output = json!({ "error": error.to_info() });
+        println!("{:#}", output);
+        std::process::exit(1);
+    }
+    error
+}
+
+/// Applies the `--keywords`, `--keyword-algorithm` and `--max-keywords` overrides.
+///
+/// `--keywords false` disables extraction; the other two flags enable it on their own.
+fn apply_keyword_flags(
+    config: &mut ExtractionConfig,
+    enabled: Option<bool>,
+    algorithm: Option<&str>,
+    max_keywords: Option<usize>,
+) -> Result<()> {
+    if enabled == Some(false) {
+        config.keywords = None;
+        return Ok(());
+    }
+    if enabled.is_none() && algorithm.is_none() && max_keywords.is_none() {
+        return Ok(());
+    }
+
+    let keyword_config = config.keywords.get_or_insert_with(KeywordConfig::default);
+    if let Some(algorithm) = algorithm {
+        keyword_config.algorithm = algorithm
+            .parse::<KeywordAlgorithm>()
+            .context("Invalid --keyword-algorithm value")?;
+    }
+    if let Some(max_keywords) = max_keywords {
+        if max_keywords == 0 {
+            anyhow::bail!("Invalid --max-keywords value: must be greater than 0");
+        }
+        keyword_config.max_keywords = max_keywords;
+    }
+    Ok(())
+}
+
+/// Loads extraction configuration into a reloadable handle.
+///
+/// Uses the same resolution as [`load_config`], and remembers the config file (explicit
+/// or discovered) so the servers can reload it at runtime.
+#[cfg(any(feature = "api", feature = "mcp"))]
+fn load_config_handle(config_path: Option<PathBuf>, profile: Option<String>) -> Result<ConfigHandle> {
+    let source = match &config_path {
+        Some(path) => Some(path.clone()),
+        None => ExtractionConfig::discover_path().context("Failed to auto-discover configuration file")?,
+    };
+    let config = load_config(config_path, profile.as_deref())?;
+
+    Ok(match source {
+        Some(path) => {
+            let handle = ConfigHandle::with_source(config, path).with_env_overrides();
+            match profile {
+                Some(profile) => handle.with_profile(profile),
+                None => handle,
+            }
+        }
+        None => ConfigHandle::new(config),
+    })
+}
+
+/// Applies the `--max-concurrent-jobs` limit to the process-wide concurrency governor.
+#[cfg(any(feature = "api", feature = "mcp"))]
+fn configure_concurrency(max_concurrent_jobs: Option<usize>) -> Result<()> {
+    if let Some(limit) = max_concurrent_jobs {
+        kreuzberg::configure_max_concurrent_jobs(limit).context("Invalid --max-concurrent-jobs value")?;
+    }
+    Ok(())
+}
+
+/// Starts a background task that reloads the config file whenever it changes.
+///
+/// Must be called from within a Tokio runtime.
+#[cfg(any(feature = "api", feature = "mcp"))]
+fn watch_config(config: &ConfigHandle) -> Option<tokio::task::JoinHandle<()>> {
+    match config.source() {
+        Some(path) => {
+            tracing::info!("Watching {} for configuration changes", path.display());
+            Some(config.watch(CONFIG_WATCH_INTERVAL))
+        }
+        None => {
+            tracing::warn!("--watch has no effect: no config file found");
+            None
+        }
+    }
+}
+
+/// Polling interval used by `--watch`.
+#[cfg(any(feature = "api", feature = "mcp"))]
+const CONFIG_WATCH_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+
 /// Loads extraction configuration from a file or discovers it automatically.
 ///
 /// This function implements the CLI's configuration hierarchy:
@@ -678,6 +1848,10 @@ fn main() -> Result<()> {
 /// 2. Auto-discovered config (searches `kreuzberg.{toml,yaml,json}` in current and parent directories)
 /// 3. Default configuration (if no config file found)
 ///
+/// With `--profile`, the named `[profile.<name>]` section of the file is merged over its
+/// top-level settings; a profile requires a config file. `KREUZBERG_*` environment variables
+/// (e.g. `KREUZBERG_OCR__LANGUAGE=deu`) override fields of whichever configuration was loaded.
+///
 /// # Configuration File Formats
 ///
 /// Supports three formats, determined by file extension:
@@ -691,11 +1865,14 @@ fn main() -> Result<()> {
 /// - Explicit config file has unsupported extension (must be .toml, .yaml, or .json)
 /// - Config file cannot be read or parsed
 /// - Config file contains invalid extraction settings
-fn load_config(config_path: Option<PathBuf>) -> Result<ExtractionConfig> {
+/// - The requested profile is not defined in the config file
+fn load_config(config_path: Option<PathBuf>, profile: Option<&str>) -> Result<ExtractionConfig> {
     if let Some(path) = config_path {
         let path_str = path.to_string_lossy();
         let path_lower = path_str.to_lowercase();
-        let config = if path_lower.ends_with(".toml") {
+        let config = if let Some(profile) = profile {
+            ExtractionConfig::from_file_with_profile(&path, profile)
+        } else if path_lower.ends_with(".toml") {
             ExtractionConfig::from_toml_file(&path)
         } else if path_lower.ends_with(".yaml") {
             ExtractionConfig::from_yaml_file(&path)
@@ -704,7 +1881,14 @@ fn load_config(config_path: Option<PathBuf>) -> Result<ExtractionConfig> {
         } else {
             anyhow::bail!("Config file must have .toml, .yaml, or .json extension (case-insensitive)");
         };
-        config.with_context(|| format!("Failed to load configuration from '{}'. Ensure the file exists, is readable, and contains valid configuration.", path.display()))
+        let mut config = config.with_context(|| format!("Failed to load configuration from '{}'. Ensure the file exists, is readable, and contains valid configuration.", path.display()))?;
+        config
+            .apply_env_overrides()
+            .context("Invalid KREUZBERG_* environment variable override")?;
+        Ok(config)
+    } else if let Some(profile) = profile {
+        ExtractionConfig::from_profile(profile)
+            .with_context(|| format!("Failed to load profile '{}' from the discovered configuration file. Use --config to specify an explicit path.", profile))
     } else {
         match ExtractionConfig::discover() {
             Ok(Some(config)) => Ok(config),
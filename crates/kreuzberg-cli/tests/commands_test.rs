@@ -3,8 +3,9 @@
 //! These tests verify that the CLI commands work correctly end-to-end,
 //! including input validation, file processing, and output formatting.
 
+use std::io::Write;
 use std::path::PathBuf;
-use std::process::Command;
+use std::process::{Command, Stdio};
 use tempfile::tempdir;
 
 /// Get the path to the kreuzberg binary.
@@ -171,6 +172,38 @@ fn test_extract_directory_not_file() {
     );
 }
 
+#[test]
+fn test_extract_json_error_reports_code() {
+    build_binary();
+
+    let tmp_dir = tempdir().expect("Failed to create temp dir");
+    let file_path = tmp_dir.path().join("data.bin");
+    std::fs::write(&file_path, b"opaque bytes").expect("Failed to write test file");
+    let file_path = file_path.to_string_lossy().to_string();
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "extract",
+            file_path.as_str(),
+            "--mime-type",
+            "application/x-unknown",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("Failed to execute extract command");
+
+    assert!(
+        !output.status.success(),
+        "Extract should fail for an unsupported format"
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("Should be valid JSON");
+    assert_eq!(json["error"]["code"], "unsupported_format");
+    assert_eq!(json["error"]["retryable"], false);
+}
+
 #[test]
 fn test_extract_invalid_chunk_size_zero() {
     build_binary();
@@ -221,6 +254,34 @@ fn test_extract_invalid_chunk_size_too_large() {
     );
 }
 
+#[test]
+fn test_extract_invalid_keyword_algorithm() {
+    build_binary();
+
+    let test_file = get_test_file("text/simple.txt");
+    if !PathBuf::from(&test_file).exists() {
+        tracing::debug!("Skipping test: {} not found", test_file);
+        return;
+    }
+
+    let output = Command::new(get_binary_path())
+        .args(["extract", test_file.as_str(), "--keyword-algorithm", "tfidf"])
+        .output()
+        .expect("Failed to execute extract command");
+
+    assert!(
+        !output.status.success(),
+        "Extract should fail for unknown keyword algorithm"
+    );
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Invalid --keyword-algorithm"),
+        "Error should mention the keyword algorithm, got: {}",
+        stderr
+    );
+}
+
 #[test]
 fn test_extract_invalid_overlap_equals_chunk_size() {
     build_binary();
@@ -395,6 +456,369 @@ fn test_batch_with_missing_file() {
     );
 }
 
+#[test]
+fn test_batch_recursive_with_manifest() {
+    build_binary();
+
+    let dir = tempdir().unwrap();
+    let corpus = dir.path().join("corpus");
+    std::fs::create_dir_all(corpus.join("nested")).unwrap();
+    std::fs::write(corpus.join("a.txt"), "first").unwrap();
+    std::fs::write(corpus.join("nested/b.txt"), "second").unwrap();
+    std::fs::write(corpus.join("nested/skip.csv"), "x,y").unwrap();
+    let manifest = dir.path().join("manifest.json");
+
+    let output = Command::new(get_binary_path())
+        .args([
+            "batch",
+            "--recursive",
+            "--include",
+            "*.txt",
+            "--format",
+            "json",
+            "--manifest",
+        ])
+        .arg(&manifest)
+        .arg(&corpus)
+        .output()
+        .expect("Failed to execute batch command");
+
+    assert!(
+        output.status.success(),
+        "Batch command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    let paths: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| r["path"].as_str().unwrap())
+        .collect();
+    assert_eq!(paths, vec!["a.txt", "nested/b.txt"]);
+
+    let manifest: serde_json::Value = serde_json::from_slice(&std::fs::read(&manifest).unwrap()).unwrap();
+    assert_eq!(manifest["entries"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_diff_reports_changed_sections() {
+    build_binary();
+
+    let dir = tempdir().unwrap();
+    let before = dir.path().join("v1.txt");
+    let after = dir.path().join("v2.txt");
+    std::fs::write(&before, "# Payment\n\nPayment is due within 30 days.\n").unwrap();
+    std::fs::write(&after, "# Payment\n\nPayment is due within 45 days.\n").unwrap();
+
+    let output = Command::new(get_binary_path())
+        .args(["diff", "--format", "json"])
+        .arg(&before)
+        .arg(&after)
+        .output()
+        .expect("Failed to execute diff command");
+
+    assert!(
+        output.status.success(),
+        "Diff command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let diff: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    let sections = diff["sections"].as_array().unwrap();
+    assert_eq!(sections.len(), 1);
+    assert_eq!(sections[0]["kind"], "modified");
+    assert_eq!(sections[0]["heading"], "Payment");
+}
+
+#[test]
+fn test_batch_jsonl_dataset_output() {
+    build_binary();
+
+    let dir = tempdir().unwrap();
+    let first = dir.path().join("a.txt");
+    let second = dir.path().join("b.txt");
+    std::fs::write(&first, "first").unwrap();
+    std::fs::write(&second, "second").unwrap();
+    let dataset = dir.path().join("corpus.jsonl");
+
+    let output = Command::new(get_binary_path())
+        .args(["batch", "--output-format", "jsonl", "--output"])
+        .arg(&dataset)
+        .arg(&first)
+        .arg(&second)
+        .output()
+        .expect("Failed to execute batch command");
+
+    assert!(
+        output.status.success(),
+        "Batch command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let rows: Vec<serde_json::Value> = std::fs::read_to_string(&dataset)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Each line should be a JSON object"))
+        .collect();
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[1]["content"].as_str().unwrap().trim(), "second");
+    assert!(rows[0]["source"].as_str().unwrap().ends_with("a.txt"));
+    assert!(rows[0]["tables"].is_array());
+}
+
+#[test]
+fn test_chunk_stdin_as_jsonl() {
+    build_binary();
+
+    let text = "Kreuzberg splits long text into chunks. ".repeat(20);
+    let mut child = Command::new(get_binary_path())
+        .args([
+            "chunk",
+            "-",
+            "--max-chars",
+            "200",
+            "--overlap",
+            "20",
+            "--format",
+            "jsonl",
+        ])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute chunk command");
+    child.stdin.take().unwrap().write_all(text.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(
+        output.status.success(),
+        "Chunk command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let chunks: Vec<serde_json::Value> = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("Each line should be a JSON object"))
+        .collect();
+    assert!(chunks.len() > 1);
+    for (i, chunk) in chunks.iter().enumerate() {
+        assert!(chunk["content"].as_str().unwrap().chars().count() <= 200);
+        assert_eq!(chunk["metadata"]["chunk_index"], i);
+    }
+}
+
+#[test]
+fn test_chunk_rejects_overlap_larger_than_chunks() {
+    build_binary();
+
+    let dir = tempdir().unwrap();
+    let input = dir.path().join("notes.txt");
+    std::fs::write(&input, "some text").unwrap();
+
+    let output = Command::new(get_binary_path())
+        .arg("chunk")
+        .arg(&input)
+        .args(["--max-chars", "100", "--overlap", "100"])
+        .output()
+        .expect("Failed to execute chunk command");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid chunk overlap"));
+}
+
+#[test]
+fn test_reduce_raw_file_with_statistics() {
+    build_binary();
+
+    let dir = tempdir().unwrap();
+    let input = dir.path().join("notes.txt");
+    std::fs::write(
+        &input,
+        "This is a very simple example of the text that has a lot of the stopwords in it.",
+    )
+    .unwrap();
+
+    let output = Command::new(get_binary_path())
+        .arg("reduce")
+        .arg(&input)
+        .args([
+            "--raw",
+            "--level",
+            "aggressive",
+            "--language",
+            "eng",
+            "--format",
+            "json",
+        ])
+        .output()
+        .expect("Failed to execute reduce command");
+
+    assert!(
+        output.status.success(),
+        "Reduce command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let statistics = &json["statistics"];
+    assert!(statistics["reduced_tokens"].as_u64().unwrap() < statistics["original_tokens"].as_u64().unwrap());
+    assert!(!json["content"].as_str().unwrap().is_empty());
+}
+
+#[test]
+fn test_reduce_rejects_unknown_level() {
+    build_binary();
+
+    let output = Command::new(get_binary_path())
+        .args(["reduce", "-", "--level", "extreme"])
+        .stdin(Stdio::null())
+        .output()
+        .expect("Failed to execute reduce command");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid reduction level"));
+}
+
+#[test]
+fn test_keywords_stdin_as_json() {
+    build_binary();
+
+    let text = "Rust is a systems programming language. Rust programming focuses on memory safety, \
+                and memory safety without garbage collection makes Rust programming popular.";
+    let mut child = Command::new(get_binary_path())
+        .args(["keywords", "-", "--max-keywords", "3", "--format", "json"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("Failed to execute keywords command");
+    child.stdin.take().unwrap().write_all(text.as_bytes()).unwrap();
+    let output = child.wait_with_output().unwrap();
+
+    assert!(
+        output.status.success(),
+        "Keywords command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let keywords: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(!keywords.is_empty() && keywords.len() <= 3);
+    assert!(keywords.iter().all(|keyword| keyword["text"].is_string()));
+}
+
+#[test]
+fn test_detect_language_of_raw_file() {
+    build_binary();
+
+    let dir = tempdir().unwrap();
+    let input = dir.path().join("notes.txt");
+    std::fs::write(
+        &input,
+        "Der schnelle braune Fuchs springt über den faulen Hund und läuft dann in den dunklen Wald.",
+    )
+    .unwrap();
+
+    let output = Command::new(get_binary_path())
+        .arg("detect-language")
+        .arg(&input)
+        .args(["--raw", "--min-confidence", "0.5"])
+        .output()
+        .expect("Failed to execute detect-language command");
+
+    assert!(
+        output.status.success(),
+        "Detect-language command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).starts_with("deu\t"));
+}
+
+#[test]
+fn test_batch_resume_checkpoints_progress() {
+    build_binary();
+
+    let dir = tempdir().unwrap();
+    let first = dir.path().join("a.txt");
+    let second = dir.path().join("b.txt");
+    std::fs::write(&first, "first").unwrap();
+    std::fs::write(&second, "second").unwrap();
+    let state = dir.path().join("batch.state");
+
+    let run = || {
+        Command::new(get_binary_path())
+            .args(["batch", "--format", "json", "--resume"])
+            .arg(&state)
+            .arg(&first)
+            .arg(&second)
+            .output()
+            .expect("Failed to execute batch command")
+    };
+
+    let output = run();
+    assert!(
+        output.status.success(),
+        "Batch command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let completed = std::fs::read_to_string(&state).unwrap().lines().count() - 1;
+    assert_eq!(completed, 2);
+
+    // A resumed run reuses the checkpointed result instead of extracting the file again.
+    std::fs::write(&first, "rewritten").unwrap();
+    let output = run();
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(json[0]["content"].as_str().unwrap().trim(), "first");
+}
+
+#[test]
+fn test_ocr_languages_list_and_verify() {
+    build_binary();
+
+    let tessdata = tempdir().unwrap();
+    std::fs::write(tessdata.path().join("eng.traineddata"), b"").unwrap();
+    std::fs::write(tessdata.path().join("deu.traineddata"), b"").unwrap();
+    let run = |args: &[&str]| {
+        Command::new(get_binary_path())
+            .args(["ocr", "languages", "--format", "json", "--tessdata-dir"])
+            .arg(tessdata.path())
+            .args(args)
+            .output()
+            .expect("Failed to execute ocr languages command")
+    };
+
+    let output = run(&[]);
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(json["languages"], serde_json::json!(["deu", "eng"]));
+
+    assert!(run(&["--verify", "eng+deu"]).status.success());
+
+    let output = run(&["--verify", "eng+fra"]);
+    assert!(!output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(json["error"]["code"], "missing_dependency");
+}
+
+#[test]
+fn test_version_json_reports_device() {
+    build_binary();
+
+    let dir = tempdir().unwrap();
+    let output = Command::new(get_binary_path())
+        .args(["version", "--format", "json"])
+        .current_dir(dir.path())
+        .output()
+        .expect("Failed to execute version command");
+
+    assert!(output.status.success());
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("Output should be valid JSON");
+    assert_eq!(json["device"]["requested"], "auto");
+    assert!(json["device"]["active"].is_string());
+}
+
 #[test]
 fn test_extract_help() {
     build_binary();
@@ -441,3 +865,28 @@ fn test_batch_help() {
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(stdout.contains("Batch extract from multiple documents"));
 }
+
+#[test]
+fn test_extract_searchable_pdf_rejects_text_input() {
+    build_binary();
+
+    let dir = tempdir().unwrap();
+    let input = dir.path().join("notes.txt");
+    let output_pdf = dir.path().join("notes.pdf");
+    std::fs::write(&input, "not a scan").unwrap();
+
+    let output = Command::new(get_binary_path())
+        .arg("extract")
+        .arg(&input)
+        .arg("--searchable-pdf")
+        .arg(&output_pdf)
+        .output()
+        .expect("Failed to execute extract command");
+
+    assert!(
+        !output.status.success(),
+        "Text input should not produce a searchable PDF"
+    );
+    assert!(String::from_utf8_lossy(&output.stderr).contains("searchable PDF"));
+    assert!(!output_pdf.exists());
+}
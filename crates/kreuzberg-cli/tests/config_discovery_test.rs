@@ -615,3 +615,56 @@ max_pages = -1
 
     assert!(!output.status.success());
 }
+
+#[test]
+fn test_profile_flag_applies_discovered_profile() {
+    build_binary();
+
+    let dir = tempdir().unwrap();
+    let config_path = dir.path().join("kreuzberg.toml");
+
+    fs::write(
+        &config_path,
+        r#"
+use_cache = false
+
+[profile.fast]
+enable_quality_processing = false
+
+[profile.broken.language_detection]
+min_confidence = 2.0
+"#,
+    )
+    .unwrap();
+
+    let test_file = get_test_file("text/simple.txt");
+    if !PathBuf::from(&test_file).exists() {
+        tracing::debug!("Skipping test: {} not found", test_file);
+        return;
+    }
+
+    let run = |profile: &str| {
+        Command::new(get_binary_path())
+            .current_dir(dir.path())
+            .args(["extract", "--profile", profile, test_file.as_str()])
+            .output()
+            .expect("Failed to execute kreuzberg")
+    };
+
+    let output = run("fast");
+    assert!(
+        output.status.success(),
+        "Command failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = run("broken");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("profile.broken.language_detection.min_confidence"));
+
+    let output = run("missing");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Profile 'missing' not found"));
+}
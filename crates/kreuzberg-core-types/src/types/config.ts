@@ -5,14 +5,31 @@
  * including OCR, chunking, image processing, and post-processing options.
  */
 
+import type { EntityType } from "./results.js";
+
 // ============================================================================
 // OCR Configuration
 // ============================================================================
 
+export interface ImagePreprocessingConfig {
+	targetDpi?: number;
+	autoRotate?: boolean;
+	deskew?: boolean;
+	/** Convert to grayscale, implied by every other enhancement step */
+	grayscale?: boolean;
+	denoise?: boolean;
+	contrastEnhance?: boolean;
+	binarizationMethod?: "otsu" | "sauvola" | "adaptive" | "none";
+	invertColors?: boolean;
+	/** Whiten the dark borders scanners leave around the page */
+	removeBorders?: boolean;
+}
+
 export interface TesseractConfig {
 	psm?: number;
 	enableTableDetection?: boolean;
 	tesseditCharWhitelist?: string;
+	preprocessing?: ImagePreprocessingConfig;
 }
 
 export interface OcrConfig {
@@ -43,11 +60,31 @@ export interface LanguageDetectionConfig {
 	enabled?: boolean;
 	minConfidence?: number;
 	detectMultiple?: boolean;
+	/** Only analyze the first N KiB of text */
+	maxSampleKb?: number;
+}
+
+/** How custom stopwords combine with the embedded list: add to it or use instead of it. */
+export type StopwordsMode = "merge" | "replace";
+
+/**
+ * Custom stopwords for keyword extraction and token reduction.
+ */
+export interface StopwordsConfig {
+	/** Custom stopwords (matched case-insensitively) */
+	custom?: string[];
+	/** Merge with (default) or replace the embedded stopword list */
+	mode?: StopwordsMode;
 }
 
 export interface TokenReductionConfig {
 	mode?: string;
 	preserveImportantWords?: boolean;
+	stopwords?: StopwordsConfig;
+	/** Keep at most this fraction (0.0-1.0) of the original tokens */
+	targetRatio?: number;
+	/** Keep at most this many tokens (CJK text counts one token per character bigram) */
+	maxTokens?: number;
 }
 
 // ============================================================================
@@ -58,6 +95,8 @@ export interface PdfConfig {
 	extractImages?: boolean;
 	passwords?: string[];
 	extractMetadata?: boolean;
+	validatePdfa?: boolean;
+	layoutStats?: boolean;
 }
 
 export interface ImageExtractionConfig {
@@ -67,16 +106,75 @@ export interface ImageExtractionConfig {
 	autoAdjustDpi?: boolean;
 	minDpi?: number;
 	maxDpi?: number;
+	/** Name of a registered image describer that fills each image's description */
+	describer?: string;
+	/** Use image descriptions as alt text of Markdown image references in the content */
+	inlineDescriptions?: boolean;
 }
 
 // ============================================================================
 // Post-Processing Configuration
 // ============================================================================
 
+export interface HeaderFooterConfig {
+	/** Number of non-empty lines at the top and bottom of each page to inspect (default: 2) */
+	linesToCheck?: number;
+	/** Minimum number of pages before anything is removed (default: 3) */
+	minPages?: number;
+	/** Fraction of pages a line must appear on to count as a header or footer (default: 0.5) */
+	minRepeatRatio?: number;
+	/** Also remove bare page numbers at page edges (default: true) */
+	removePageNumbers?: boolean;
+}
+
+export interface LineRepairConfig {
+	/** Join words hyphenated across line breaks (default: true) */
+	dehyphenate?: boolean;
+	/** Merge hard-wrapped lines within paragraphs (default: true) */
+	mergeLines?: boolean;
+	/** ISO 639 language code of the text (default: detected language or script) */
+	language?: string;
+}
+
+export type NormalizationForm = "none" | "nfc" | "nfkc";
+
+export interface TextNormalizationConfig {
+	/** Unicode normalization form (default: "nfc") */
+	form?: NormalizationForm;
+	/** Remove zero-width spaces, word joiners, BOMs and soft hyphens (default: true) */
+	removeZeroWidth?: boolean;
+	/** Remove bidirectional control characters (default: true) */
+	removeBidiControls?: boolean;
+	/** Remove control characters other than tab, newline, carriage return and form feed (default: true) */
+	removeControlChars?: boolean;
+	/** Replace typographic quotes with ASCII quotes (default: true) */
+	canonicalizeQuotes?: boolean;
+	/** Replace Unicode hyphens, dashes and minus with ASCII "-" (default: true) */
+	canonicalizeDashes?: boolean;
+}
+
+export interface TextMetricsConfig {
+	/** Words with more characters than this count as long words for LIX (default: 6) */
+	longWordLength?: number;
+}
+
 export interface PostProcessorConfig {
 	enabled?: boolean;
 	enabledProcessors?: string[];
 	disabledProcessors?: string[];
+	/** Remove running headers, footers and page numbers (`true` uses the defaults) */
+	headerFooter?: HeaderFooterConfig | boolean;
+	/** Join hyphenated words and hard-wrapped lines (`true` uses the defaults) */
+	lineRepair?: LineRepairConfig | boolean;
+	/** Unicode normalization and invisible-character cleanup (`true` uses the defaults) */
+	normalization?: TextNormalizationConfig | boolean;
+	/** Readability and vocabulary metrics in `metadata.text_metrics` (`true` uses the defaults) */
+	metrics?: TextMetricsConfig | boolean;
+	/**
+	 * Named custom taggers whose matches go to `metadata.tags`: a regular expression (Rust `regex`
+	 * syntax) or an array of dictionary terms matched as whole words, ignoring case.
+	 */
+	taggers?: Record<string, string | string[]>;
 }
 
 // ============================================================================
@@ -145,10 +243,55 @@ export interface KeywordConfig {
 	minScore?: number;
 	ngramRange?: [number, number];
 	language?: string;
+	stopwords?: StopwordsConfig;
 	yakeParams?: YakeParams;
 	rakeParams?: RakeParams;
 }
 
+// ============================================================================
+// Entity Extraction Configuration
+// ============================================================================
+
+/**
+ * Named-entity extraction configuration.
+ *
+ * Persons, organizations, dates and monetary amounts are recognized from surface
+ * patterns and returned in `ExtractionResult.entities`.
+ */
+export interface EntityConfig {
+	/** Entity types to extract (default: all) */
+	entityTypes?: EntityType[];
+	/** Minimum confidence threshold (0.0-1.0, default: 0.5) */
+	minConfidence?: number;
+}
+
+// ============================================================================
+// Quality Gate Configuration
+// ============================================================================
+
+export type QualityGateAction = "flag" | "reject";
+
+/**
+ * Garble detection gate for OCR output.
+ *
+ * Scores text by its dictionary-hit rate and OCR confusion patterns; results below
+ * either threshold get `metadata.low_quality = true`, or fail when `action` is `"reject"`.
+ */
+export interface QualityGateConfig {
+	/** Minimum share of words found in the language's stopword list (0.0-1.0, default: 0.15) */
+	minDictionaryHitRate?: number;
+	/** Minimum share of tokens free of OCR confusions such as "he11o" (0.0-1.0, default: 0.8) */
+	minConfusionScore?: number;
+	/** Results with fewer words are not scored (default: 20) */
+	minWords?: number;
+	/** Only gate results produced by OCR (default: true) */
+	ocrOnly?: boolean;
+	/** What to do with results below the thresholds (default: "flag") */
+	action?: QualityGateAction;
+	/** ISO 639 language code for the dictionary check (default: detected language, then English) */
+	language?: string;
+}
+
 // ============================================================================
 // Main Extraction Configuration
 // ============================================================================
@@ -166,5 +309,10 @@ export interface ExtractionConfig {
 	postprocessor?: PostProcessorConfig;
 	htmlOptions?: HtmlConversionOptions;
 	keywords?: KeywordConfig;
+	entities?: EntityConfig;
+	qualityGate?: QualityGateConfig;
+	collectTimings?: boolean;
+	strictMime?: boolean;
+	strict?: boolean;
 	maxConcurrentExtractions?: number;
 }
@@ -126,6 +126,56 @@ export interface PanicContext {
 	timestamp_secs: number;
 }
 
+/**
+ * Stable error codes reported by the native library.
+ *
+ * Codes are never renamed, so they are safe to branch on; new codes may be added.
+ */
+export type KreuzbergErrorCode =
+	| "io"
+	| "parsing"
+	| "ocr"
+	| "validation"
+	| "cache"
+	| "image_processing"
+	| "serialization"
+	| "missing_dependency"
+	| "plugin"
+	| "lock_poisoned"
+	| "unsupported_format"
+	| "limit_exceeded"
+	| "encrypted_document"
+	| "other";
+
+/**
+ * Machine-readable description of an error raised by the native library.
+ *
+ * The same shape is used by the API server, the MCP server, the CLI's JSON output
+ * and every language binding.
+ */
+export interface ErrorInfo {
+	/**
+	 * Stable error code
+	 */
+	code: KreuzbergErrorCode;
+	/**
+	 * Human-readable error message
+	 */
+	message: string;
+	/**
+	 * Pipeline stage the error belongs to (`mime_detection`, `extraction`, `ocr` or `cache`), if known
+	 */
+	stage: string | null;
+	/**
+	 * Name of the failing plugin, for plugin errors
+	 */
+	plugin: string | null;
+	/**
+	 * Whether retrying the same operation may succeed
+	 */
+	retryable: boolean;
+}
+
 // ============================================================================
 // Base Error Class
 // ============================================================================
@@ -161,6 +211,26 @@ export class KreuzbergError extends Error {
 	 */
 	public readonly panicContext: PanicContext | null;
 
+	/**
+	 * Stable error code for errors raised by the native library, `null` otherwise.
+	 */
+	public readonly code: KreuzbergErrorCode | null = null;
+
+	/**
+	 * Pipeline stage the error belongs to, if known.
+	 */
+	public readonly stage: string | null = null;
+
+	/**
+	 * Name of the failing plugin, for plugin errors.
+	 */
+	public readonly plugin: string | null = null;
+
+	/**
+	 * Whether retrying the same operation may succeed.
+	 */
+	public readonly retryable: boolean = false;
+
 	constructor(message: string, panicContext?: PanicContext | null) {
 		super(message);
 		this.name = "KreuzbergError";
@@ -172,6 +242,10 @@ export class KreuzbergError extends Error {
 		return {
 			name: this.name,
 			message: this.message,
+			code: this.code,
+			stage: this.stage,
+			plugin: this.plugin,
+			retryable: this.retryable,
 			panicContext: this.panicContext,
 			stack: this.stack,
 		};
@@ -384,6 +458,10 @@ export class PluginError extends KreuzbergError {
 			name: this.name,
 			message: this.message,
 			pluginName: this.pluginName,
+			code: this.code,
+			stage: this.stage,
+			plugin: this.plugin,
+			retryable: this.retryable,
 			panicContext: this.panicContext,
 			stack: this.stack,
 		};
@@ -14,18 +14,29 @@
 // Re-export configuration types
 export type {
 	ChunkingConfig,
+	EntityConfig,
 	ExtractionConfig,
+	HeaderFooterConfig,
 	HtmlConversionOptions,
 	HtmlPreprocessingOptions,
 	ImageExtractionConfig,
+	ImagePreprocessingConfig,
 	KeywordAlgorithm,
 	KeywordConfig,
 	LanguageDetectionConfig,
+	LineRepairConfig,
+	NormalizationForm,
 	OcrConfig,
 	PdfConfig,
 	PostProcessorConfig,
+	QualityGateAction,
+	QualityGateConfig,
 	RakeParams,
+	StopwordsConfig,
+	StopwordsMode,
 	TesseractConfig,
+	TextMetricsConfig,
+	TextNormalizationConfig,
 	TokenReductionConfig,
 	YakeParams,
 } from "./config.js";
@@ -34,25 +45,47 @@ export type {
 export type {
 	Chunk,
 	ChunkMetadata,
+	DetectedLanguage,
+	Entity,
+	EntityType,
 	ExtractionResult,
+	ExtractionWarning,
 	ExtractedImage,
+	Keyword,
 	Table,
+	WarningStage,
 } from "./results.js";
 
 // Re-export metadata types
 export type {
 	ArchiveMetadata,
+	CalendarEvent,
+	DocumentProvenance,
 	EmailMetadata,
 	ErrorMetadata,
 	ExcelMetadata,
+	ExtractionStats,
+	ExtractionTimings,
+	HtmlImage,
+	HtmlLink,
 	HtmlMetadata,
 	ImageMetadata,
 	ImagePreprocessingMetadata,
 	Metadata,
 	OcrMetadata,
+	PdfAReport,
+	PdfAViolation,
+	PdfFontInfo,
+	PdfLayoutStats,
 	PdfMetadata,
 	PptxMetadata,
+	ReviewReason,
+	ReviewStatus,
+	Revision,
+	StageDurations,
+	TagMatch,
 	TextMetadata,
+	TextMetrics,
 	XmlMetadata,
 } from "./metadata.js";
 
@@ -69,8 +102,10 @@ export type { ProcessingStage } from "./protocols.js";
 export {
 	CacheError,
 	ErrorCode,
+	type ErrorInfo,
 	ImageProcessingError,
 	KreuzbergError,
+	type KreuzbergErrorCode,
 	MissingDependencyError,
 	OcrError,
 	type PanicContext,
@@ -14,6 +14,27 @@ export interface ExcelMetadata {
 	sheetNames?: string[];
 }
 
+/**
+ * A meeting from a calendar invite (`text/calendar` part, `.ics` attachment or TNEF meeting request).
+ */
+export interface CalendarEvent {
+	/** iTIP method of the invite (e.g. "REQUEST", "CANCEL") */
+	method?: string;
+	uid?: string;
+	summary?: string;
+	/** Start as ISO 8601, in `timezone` when set */
+	start?: string;
+	end?: string;
+	timezone?: string;
+	location?: string;
+	organizer?: string;
+	attendees?: string[];
+	description?: string;
+	status?: string;
+	/** Recurrence rule (RRULE) */
+	recurrence?: string;
+}
+
 export interface EmailMetadata {
 	fromEmail?: string | null;
 	fromName?: string | null;
@@ -21,7 +42,17 @@ export interface EmailMetadata {
 	ccEmails?: string[];
 	bccEmails?: string[];
 	messageId?: string | null;
+	replyToEmails?: string[];
+	inReplyTo?: string | null;
+	references?: string[];
+	threadId?: string | null;
+	hasDkimSignature?: boolean;
+	rawHeaders?: [string, string][];
+	calendarEvents?: CalendarEvent[];
 	attachments?: string[];
+	quotedText?: string;
+	signature?: string;
+	trackingPixels?: string[];
 }
 
 export interface ArchiveMetadata {
@@ -42,6 +73,8 @@ export interface ImageMetadata {
 export interface XmlMetadata {
 	elementCount?: number;
 	uniqueElements?: string[];
+	/** Name of the configured XML document type whose XPath mapping was applied */
+	documentType?: string | null;
 }
 
 export interface TextMetadata {
@@ -51,6 +84,37 @@ export interface TextMetadata {
 	headers?: string[] | null;
 	links?: [string, string][] | null;
 	codeBlocks?: [string, string][] | null;
+	/** Encoding the file was decoded from, e.g. `UTF-8`, `windows-1252` or `IBM037` */
+	encoding?: string | null;
+}
+
+/**
+ * A hyperlink of an HTML document.
+ */
+export interface HtmlLink {
+	/** Anchor text with markup removed */
+	text: string;
+	/** `href` attribute as written in the document */
+	href: string;
+	title?: string | null;
+	/** `rel` attribute (e.g. "nofollow") */
+	rel?: string | null;
+	/** Byte offset of the `<a>` tag in the HTML source */
+	position: number;
+}
+
+/**
+ * An image of an HTML document.
+ */
+export interface HtmlImage {
+	/** `src` attribute; base64 data URIs are shortened to `data:<media type>` */
+	src: string;
+	alt?: string | null;
+	title?: string | null;
+	/** Byte offset of the `<img>` tag in the HTML source */
+	position: number;
+	/** Index of the decoded image in `images` (data URI images, when image extraction is enabled) */
+	imageIndex?: number | null;
 }
 
 export interface HtmlMetadata {
@@ -75,6 +139,53 @@ export interface HtmlMetadata {
 	linkAuthor?: string | null;
 	linkLicense?: string | null;
 	linkAlternate?: string | null;
+	links?: HtmlLink[];
+	images?: HtmlImage[];
+}
+
+/**
+ * A PDF/A requirement the file does not meet.
+ */
+export interface PdfAViolation {
+	/** Stable identifier of the check (e.g. "fonts.embedded") */
+	rule: string;
+	message: string;
+}
+
+/**
+ * PDF/A conformance report (`metadata.pdfa`), set when `pdfOptions.validatePdfa` is enabled.
+ */
+export interface PdfAReport {
+	/** Declared PDF/A part (`pdfaid:part`) */
+	part?: number | null;
+	/** Declared conformance level (e.g. "B") */
+	conformance?: string | null;
+	/** Whether the file claims PDF/A and no violation was found */
+	conformant: boolean;
+	violations: PdfAViolation[];
+}
+
+/**
+ * A font defined in a PDF.
+ */
+export interface PdfFontInfo {
+	/** Base font name without the subset prefix */
+	name: string;
+	embedded: boolean;
+}
+
+/**
+ * Font and layout statistics (`metadata.layout`), set when `pdfOptions.layoutStats` is enabled.
+ */
+export interface PdfLayoutStats {
+	fonts: PdfFontInfo[];
+	/** Share of the text and image area that is text (0.0 images only, 1.0 text only) */
+	text_coverage?: number | null;
+	/** Average share of the page area covered by images */
+	image_coverage: number;
+	/** Average number of non-whitespace characters per page in the text layer */
+	avg_glyphs_per_page: number;
+	pages_without_text: number;
 }
 
 export interface PdfMetadata {
@@ -87,6 +198,8 @@ export interface PdfMetadata {
 	creationDate?: string | null;
 	modificationDate?: string | null;
 	pageCount?: number;
+	pdfa?: PdfAReport | null;
+	layout?: PdfLayoutStats | null;
 }
 
 export interface PptxMetadata {
@@ -134,6 +247,132 @@ export interface ErrorMetadata {
 	message?: string;
 }
 
+/**
+ * Wall-clock duration of each extraction stage, in milliseconds.
+ */
+export interface StageDurations {
+	extraction_ms: number;
+	post_processing_ms: number;
+}
+
+/**
+ * Summary statistics computed for every extraction result (`metadata.stats`).
+ */
+export interface ExtractionStats {
+	word_count: number;
+	character_count: number;
+	page_count?: number;
+	table_count: number;
+	image_count: number;
+	/** Mean OCR confidence (0.0-1.0), present when OCR ran */
+	ocr_confidence?: number;
+	/** Stage durations; absent where no monotonic clock is available */
+	durations?: StageDurations;
+}
+
+/**
+ * Per-stage durations in milliseconds (`metadata.timings`), recorded when `collectTimings` is set.
+ * Stages that did not run are absent.
+ */
+export interface ExtractionTimings {
+	mime_detection_ms?: number;
+	/** Document extractor, including OCR */
+	extraction_ms?: number;
+	/** OCR of pages and images, as part of `extraction_ms` */
+	ocr_ms?: number;
+	/** Image descriptions and post-processors */
+	post_processing_ms?: number;
+	chunking_ms?: number;
+	language_detection_ms?: number;
+	total_ms?: number;
+}
+
+/**
+ * Producing application, capture device and scan heuristic (`metadata.provenance`).
+ */
+export interface DocumentProvenance {
+	/** PDF `Producer`, Office `Application` or EXIF `Software` */
+	producer?: string;
+	/** Authoring application (PDF `Creator`) */
+	creator_tool?: string;
+	/** Scanner or camera (EXIF `Make`/`Model`, or a device named by the producer) */
+	scanner_model?: string;
+	likely_scanned: boolean;
+	/** Evidence for `likely_scanned`, e.g. "raster_image", "scanner_producer", "sparse_text_layer" */
+	scan_signals?: string[];
+}
+
+/**
+ * Readability scores, sentence/word statistics and vocabulary richness (`metadata.text_metrics`).
+ */
+export interface TextMetrics {
+	sentence_count: number;
+	word_count: number;
+	syllable_count: number;
+	long_word_count: number;
+	unique_word_count: number;
+	avg_sentence_length: number;
+	avg_word_length: number;
+	avg_syllables_per_word: number;
+	/** Flesch reading ease (higher is easier) */
+	flesch_reading_ease: number;
+	/** Flesch-Kincaid grade level */
+	flesch_kincaid_grade: number;
+	/** LIX readability index */
+	lix: number;
+	/** Distinct words divided by total words */
+	type_token_ratio: number;
+	/** Fraction of distinct words that occur exactly once */
+	hapax_ratio: number;
+}
+
+/**
+ * A match of a custom tagger (`metadata.tags`), with byte offsets into the content.
+ */
+export interface TagMatch {
+	text: string;
+	byte_start: number;
+	byte_end: number;
+}
+
+/**
+ * A signal that routes a result to human review, discriminated by `kind`.
+ */
+export type ReviewReason =
+	| { kind: "low_ocr_confidence"; confidence: number; threshold: number }
+	| { kind: "low_quality_score"; score: number; threshold: number }
+	| { kind: "low_quality" }
+	| { kind: "limits_exceeded"; limits: string[] }
+	| { kind: "archive_truncated" }
+	| { kind: "validator_failed"; validator: string; message: string };
+
+/**
+ * Human-review verdict (`metadata.review`), set when the `review` config is present.
+ */
+export interface ReviewStatus {
+	/** Whether at least one review signal fired */
+	required: boolean;
+	reasons: ReviewReason[];
+}
+
+/**
+ * A tracked change or comment in a DOCX or PPTX document (`metadata.revisions`).
+ */
+export interface Revision {
+	kind: "insertion" | "deletion" | "comment";
+	author?: string | null;
+	/** Timestamp as stored in the document (ISO 8601) */
+	date?: string | null;
+	/** Inserted or deleted text, or the comment text */
+	text: string;
+	/** Text a comment refers to (DOCX) */
+	anchor?: string | null;
+	/** Slide a comment is attached to (PPTX) */
+	page_number?: number | null;
+	/** `pending` for tracked changes and open comments, `resolved` for comments marked done */
+	status: "pending" | "resolved";
+}
+
 // ============================================================================
 // Unified Metadata Interface
 // ============================================================================
@@ -152,17 +391,29 @@ export interface Metadata {
 	date?: string | null;
 	subject?: string | null;
 
-	format_type?: "pdf" | "excel" | "email" | "pptx" | "archive" | "image" | "xml" | "text" | "html" | "ocr";
+	format_type?: "pdf" | "excel" | "email" | "pptx" | "office" | "archive" | "image" | "xml" | "text" | "html" | "ocr";
 
-	// Common PDF/Document metadata
+	// Common (Dublin Core) document metadata, normalized across formats
 	title?: string | null;
+	authors?: string[] | null;
+	/** Keyword list; HTML metadata reports the raw `<meta name="keywords">` string */
+	keywords?: string[] | string | null;
+	created_at?: string | null;
+	modified_at?: string | null;
+	created_by?: string | null;
+	modified_by?: string | null;
+	publisher?: string | null;
+	identifier?: string | null;
+
+	// Common PDF/Document metadata
 	author?: string | null;
-	keywords?: string | null;
 	creator?: string | null;
 	producer?: string | null;
 	creation_date?: string | null;
 	modification_date?: string | null;
 	page_count?: number;
+	pdfa?: PdfAReport | null;
+	layout?: PdfLayoutStats | null;
 
 	// Excel-specific metadata
 	sheet_count?: number;
@@ -175,13 +426,37 @@ export interface Metadata {
 	cc_emails?: string[];
 	bcc_emails?: string[];
 	message_id?: string | null;
+	reply_to_emails?: string[];
+	in_reply_to?: string | null;
+	references?: string[];
+	thread_id?: string | null;
+	has_dkim_signature?: boolean;
+	raw_headers?: [string, string][];
+	calendar_events?: CalendarEvent[];
 	attachments?: string[];
+	quoted_text?: string;
+	signature?: string;
+	tracking_pixels?: string[];
 
 	// PowerPoint-specific metadata
 	description?: string | null;
 	summary?: string | null;
 	fonts?: string[];
 
+	// Office-specific metadata (DOCX, ODT, RTF)
+	application?: string | null;
+	template?: string | null;
+	organization?: string | null;
+	initial_creator?: string | null;
+	revision?: string | null;
+	category?: string | null;
+	content_status?: string | null;
+	total_editing_time_minutes?: number | null;
+	editing_duration?: string | null;
+	editing_cycles?: string | null;
+	paragraph_count?: number | null;
+	image_count?: number | null;
+
 	// Archive-specific metadata
 	format?: string;
 	file_count?: number;
@@ -205,6 +480,7 @@ export interface Metadata {
 	headers?: string[] | null;
 	links?: [string, string][] | null;
 	code_blocks?: [string, string][] | null;
+	encoding?: string | null;
 
 	// HTML-specific metadata
 	canonical?: string | null;
@@ -241,6 +517,23 @@ export interface Metadata {
 	// Error information
 	error?: ErrorMetadata | null;
 
+	stats?: ExtractionStats | null;
+
+	timings?: ExtractionTimings | null;
+
+	provenance?: DocumentProvenance | null;
+
+	text_metrics?: TextMetrics | null;
+
+	tags?: Record<string, TagMatch[]> | null;
+
+	review?: ReviewStatus | null;
+
+	// Near-duplicate of an earlier batch result (with the dedup config)
+	duplicate_of?: string | null;
+
+	revisions?: Revision[] | null;
+
 	// biome-ignore lint/suspicious/noExplicitAny: Postprocessors can add arbitrary metadata fields
 	[key: string]: any;
 }
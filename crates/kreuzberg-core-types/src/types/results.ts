@@ -5,6 +5,7 @@
  * including extracted content, metadata, tables, chunks, and images.
  */
 
+import type { KeywordAlgorithm } from "./config.js";
 import type { Metadata } from "./metadata.js";
 
 // ============================================================================
@@ -39,6 +40,48 @@ export interface Chunk {
 // Image Results
 // ============================================================================
 
+export type EntityType = "person" | "organization" | "date" | "money";
+
+export interface Entity {
+	entityType: EntityType;
+	text: string;
+	/** Byte offset where the entity starts in `content` */
+	byteStart: number;
+	/** Byte offset where the entity ends in `content` */
+	byteEnd: number;
+	confidence: number;
+}
+
+export interface DetectedLanguage {
+	/** ISO 639-3 language code */
+	language: string;
+	/** Detection confidence (0.0-1.0) */
+	confidence: number;
+	/** Writing system, e.g. "Latin" or "Cyrillic" */
+	script: string;
+}
+
+export interface Keyword {
+	text: string;
+	/** Relevance score (algorithm-specific range) */
+	score: number;
+	algorithm: KeywordAlgorithm;
+	/** Character offsets where the keyword appears in `content` */
+	positions?: number[] | null;
+}
+
+export type WarningStage = "recovery" | "extraction" | "ocr" | "post_processing" | "limits";
+
+/** Non-fatal issue encountered while processing a document, e.g. a skipped attachment. */
+export interface ExtractionWarning {
+	/** Stable identifier of the kind of issue, e.g. `attachment_failed` */
+	code: string;
+	message: string;
+	stage: WarningStage;
+	/** Page the issue applies to (1-indexed) */
+	page?: number | null;
+}
+
 export interface ExtractedImage {
 	data: Uint8Array;
 	format: string;
@@ -46,6 +89,7 @@ export interface ExtractedImage {
 	pageNumber?: number | null;
 	width?: number | null;
 	height?: number | null;
+	dpi?: number | null;
 	colorspace?: string | null;
 	bitsPerComponent?: number | null;
 	isMask: boolean;
@@ -62,7 +106,10 @@ export interface ExtractionResult {
 	mimeType: string;
 	metadata: Metadata;
 	tables: Table[];
-	detectedLanguages: string[] | null;
+	detectedLanguages: DetectedLanguage[] | null;
 	chunks: Chunk[] | null;
 	images: ExtractedImage[] | null;
+	entities?: Entity[] | null;
+	keywords?: Keyword[] | null;
+	warnings?: ExtractionWarning[];
 }
@@ -18,6 +18,16 @@
 typedef struct ExtractionConfig ExtractionConfig;
 
 
+/**
+ * Version of the C ABI: the function signatures and the layouts of `CExtractionResult`,
+ * `CBatchResult` and `CBytesWithMime`.
+ *
+ * Incremented on every incompatible change. Adding functions does not change it, so bindings
+ * can check `kreuzberg_abi_version() == KREUZBERG_FFI_ABI_VERSION` against the header they were
+ * generated from when loading the library.
+ */
+#define KREUZBERG_FFI_ABI_VERSION 1
+
 /**
  * C-compatible extraction result structure
  *
@@ -564,6 +574,32 @@ int32_t kreuzberg_last_error_code(void);
  */
 char *kreuzberg_last_panic_context(void);
 
+/**
+ * Get a structured description of the last error.
+ *
+ * Returns a JSON object with a stable string `code` (e.g. `"parsing"`,
+ * `"missing_dependency"`), `message`, `stage`, `plugin` and `retryable`, or NULL if
+ * there is no error or it did not come from the Kreuzberg core.
+ *
+ * # Safety
+ *
+ * The returned string must be freed with kreuzberg_free_string().
+ *
+ * # Example (C)
+ *
+ * ```c
+ * CExtractionResult* result = kreuzberg_extract_file_sync(path);
+ * if (result == NULL) {
+ *     char* info = kreuzberg_last_error_info();
+ *     if (info != NULL) {
+ *         printf("Error info: %s\n", info);
+ *         kreuzberg_free_string(info);
+ *     }
+ * }
+ * ```
+ */
+char *kreuzberg_last_error_info(void);
+
 /**
  * Get the library version string.
  *
@@ -581,6 +617,20 @@ char *kreuzberg_last_panic_context(void);
  */
 const char *kreuzberg_version(void);
 
+/**
+ * Get the C ABI version of the loaded library (see `KREUZBERG_FFI_ABI_VERSION`).
+ *
+ * # Example (C)
+ *
+ * ```c
+ * if (kreuzberg_abi_version() != KREUZBERG_FFI_ABI_VERSION) {
+ *     fprintf(stderr, "libkreuzberg_ffi does not match kreuzberg.h\n");
+ *     return 1;
+ * }
+ * ```
+ */
+uint32_t kreuzberg_abi_version(void);
+
 /**
  * Register a custom OCR backend via FFI callback.
  *
@@ -1084,4 +1134,127 @@ ExtractionConfig *kreuzberg_config_from_file(const char *path);
  */
 char *kreuzberg_config_discover(void);
 
+/**
+ * Create an ExtractionConfig with the default settings.
+ *
+ * # Safety
+ *
+ * - The returned config must be freed with `kreuzberg_free_config`
+ *
+ * # Example (C)
+ *
+ * ```c
+ * ExtractionConfig* config = kreuzberg_config_new();
+ * CExtractionResult* result = kreuzberg_extract_file_with_config_sync("document.pdf", config);
+ * kreuzberg_free_config(config);
+ * ```
+ */
+ExtractionConfig *kreuzberg_config_new(void);
+
+/**
+ * Create an ExtractionConfig from JSON.
+ *
+ * Accepts the same JSON as the `config_json` parameters of the extraction functions. Parsing
+ * the config once and passing the handle avoids parsing it again for every document.
+ *
+ * # Safety
+ *
+ * - `config_json` must be a valid null-terminated C string
+ * - The returned config must be freed with `kreuzberg_free_config`
+ * - Returns NULL on error (check `kreuzberg_last_error`)
+ *
+ * # Example (C)
+ *
+ * ```c
+ * ExtractionConfig* config = kreuzberg_config_from_json("{\"force_ocr\": true}");
+ * if (config == NULL) {
+ *     printf("Invalid config: %s\n", kreuzberg_last_error());
+ *     return 1;
+ * }
+ * ```
+ */
+ExtractionConfig *kreuzberg_config_from_json(const char *config_json);
+
+/**
+ * Serialize an ExtractionConfig to JSON, including every default value.
+ *
+ * # Safety
+ *
+ * - `config` must be a config returned by one of the `kreuzberg_config_*` constructors
+ * - The returned string must be freed with `kreuzberg_free_string`
+ * - Returns NULL on error (check `kreuzberg_last_error`)
+ */
+char *kreuzberg_config_to_json(const ExtractionConfig *config);
+
+/**
+ * Free an ExtractionConfig returned by one of the `kreuzberg_config_*` constructors.
+ *
+ * # Safety
+ *
+ * - `config` must be a config returned by `kreuzberg_config_new`, `kreuzberg_config_from_json`
+ *   or `kreuzberg_config_from_file`
+ * - `config` can be NULL (no-op)
+ * - `config` must not be used after this call
+ */
+void kreuzberg_free_config(ExtractionConfig *config);
+
+/**
+ * Extract text and metadata from a file with a config handle (synchronous).
+ *
+ * # Safety
+ *
+ * - `file_path` must be a valid null-terminated C string
+ * - `config` must be a config returned by one of the `kreuzberg_config_*` constructors, or NULL
+ *   for the default config; it is not consumed and can be reused
+ * - The returned pointer must be freed with `kreuzberg_free_result`
+ * - Returns NULL on error (check `kreuzberg_last_error` for details)
+ *
+ * # Example (C)
+ *
+ * ```c
+ * ExtractionConfig* config = kreuzberg_config_from_file("kreuzberg.toml");
+ * CExtractionResult* result = kreuzberg_extract_file_with_config_sync("document.pdf", config);
+ * if (result != NULL && result->success) {
+ *     printf("Content: %s\n", result->content);
+ *     kreuzberg_free_result(result);
+ * }
+ * kreuzberg_free_config(config);
+ * ```
+ */
+struct CExtractionResult *kreuzberg_extract_file_with_config_sync(const char *file_path,
+                                                                  const ExtractionConfig *config);
+
+/**
+ * Extract text and metadata from a byte array with a config handle (synchronous).
+ *
+ * # Safety
+ *
+ * - `data` must be a valid pointer to a byte array of length `data_len`
+ * - `mime_type` must be a valid null-terminated C string
+ * - `config` must be a config returned by one of the `kreuzberg_config_*` constructors, or NULL
+ *   for the default config; it is not consumed and can be reused
+ * - The returned pointer must be freed with `kreuzberg_free_result`
+ * - Returns NULL on error (check `kreuzberg_last_error` for details)
+ */
+struct CExtractionResult *kreuzberg_extract_bytes_with_config_sync(const uint8_t *data,
+                                                                   uintptr_t data_len,
+                                                                   const char *mime_type,
+                                                                   const ExtractionConfig *config);
+
+/**
+ * Batch extract text and metadata from multiple files with a config handle (synchronous).
+ *
+ * # Safety
+ *
+ * - `file_paths` must be a valid pointer to an array of null-terminated C strings
+ * - `count` must be the number of file paths in the array
+ * - `config` must be a config returned by one of the `kreuzberg_config_*` constructors, or NULL
+ *   for the default config; it is not consumed and can be reused
+ * - The returned pointer must be freed with `kreuzberg_free_batch_result`
+ * - Returns NULL on error (check `kreuzberg_last_error` for details)
+ */
+struct CBatchResult *kreuzberg_batch_extract_files_with_config_sync(const char *const *file_paths,
+                                                                    uintptr_t count,
+                                                                    const ExtractionConfig *config);
+
 #endif  /* KREUZBERG_FFI_H */
@@ -6,10 +6,11 @@
 mod panic_shield;
 
 pub use panic_shield::{
-    ErrorCode, StructuredError, clear_structured_error, get_last_error_code, get_last_error_message,
-    get_last_panic_context, set_structured_error,
+    ErrorCode, StructuredError, clear_structured_error, get_last_error_code, get_last_error_info,
+    get_last_error_message, get_last_panic_context, set_structured_error,
 };
 
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
@@ -40,6 +41,16 @@ fn set_last_error(err: String) {
     set_structured_error(structured_err);
 }
 
+/// Set the last error from a Kreuzberg error, with its error code and `ErrorInfo`
+fn set_kreuzberg_error(err: KreuzbergError) {
+    let structured_err = StructuredError::from_kreuzberg_error(&err);
+    if let Ok(c_str) = CString::new(structured_err.message.clone()) {
+        LAST_ERROR_C_STRING.with(|last| *last.borrow_mut() = Some(c_str));
+    }
+
+    set_structured_error(structured_err);
+}
+
 /// Clear the last error message
 fn clear_last_error() {
     LAST_ERROR_C_STRING.with(|last| *last.borrow_mut() = None);
@@ -492,6 +503,11 @@ fn to_c_extraction_result(result: ExtractionResult) -> std::result::Result<*mut
         chunks,
         images,
         pages,
+        entities: _,
+        keywords: _,
+        children: _,
+        fields: _,
+        warnings: _,
     } = result;
 
     let content_guard =
@@ -663,7 +679,7 @@ pub unsafe extern "C" fn kreuzberg_extract_file_sync(file_path: *const c_char) -
                 }
             },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -704,7 +720,7 @@ pub unsafe extern "C" fn kreuzberg_detect_mime_type(file_path: *const c_char, ch
                 }
             },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -745,7 +761,7 @@ pub unsafe extern "C" fn kreuzberg_validate_mime_type(mime_type: *const c_char)
                 }
             },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -924,7 +940,7 @@ pub unsafe extern "C" fn kreuzberg_extract_file_sync_with_config(
                 }
             },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -995,7 +1011,7 @@ pub unsafe extern "C" fn kreuzberg_extract_bytes_sync(
                 }
             },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -1084,7 +1100,7 @@ pub unsafe extern "C" fn kreuzberg_extract_bytes_sync_with_config(
                 }
             },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -1121,6 +1137,31 @@ pub struct CBatchResult {
     _padding2: [u8; 7],
 }
 
+/// Convert batch results into a `CBatchResult`, freeing the converted results if one fails.
+fn to_c_batch_result(results: Vec<ExtractionResult>) -> FfiResult<*mut CBatchResult> {
+    let count = results.len();
+    let mut c_results = Vec::with_capacity(count);
+    for result in results {
+        match to_c_extraction_result(result) {
+            Ok(ptr) => c_results.push(ptr),
+            Err(e) => {
+                for c_res in c_results {
+                    unsafe { kreuzberg_free_result(c_res) };
+                }
+                return Err(e);
+            }
+        }
+    }
+
+    let results_ptr = Box::into_raw(c_results.into_boxed_slice()) as *mut *mut CExtractionResult;
+    Ok(Box::into_raw(Box::new(CBatchResult {
+        results: results_ptr,
+        count,
+        success: true,
+        _padding2: [0u8; 7],
+    })))
+}
+
 /// Batch extract text and metadata from multiple files (synchronous).
 ///
 /// # Safety
@@ -1184,33 +1225,15 @@ pub unsafe extern "C" fn kreuzberg_batch_extract_files_sync(
         }
 
         match kreuzberg::batch_extract_file_sync(paths, &config) {
-            Ok(results) => {
-                let mut c_results = Vec::with_capacity(results.len());
-                for result in results {
-                    match to_c_extraction_result(result) {
-                        Ok(ptr) => c_results.push(ptr),
-                        Err(e) => {
-                            for c_res in c_results {
-                                unsafe { kreuzberg_free_result(c_res) };
-                            }
-                            set_last_error(e);
-                            return ptr::null_mut();
-                        }
-                    }
+            Ok(results) => match to_c_batch_result(results) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
                 }
-
-                let results_array = c_results.into_boxed_slice();
-                let results_ptr = Box::into_raw(results_array) as *mut *mut CExtractionResult;
-
-                Box::into_raw(Box::new(CBatchResult {
-                    results: results_ptr,
-                    count,
-                    success: true,
-                    _padding2: [0u8; 7],
-                }))
-            }
+            },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -1288,33 +1311,15 @@ pub unsafe extern "C" fn kreuzberg_batch_extract_bytes_sync(
         }
 
         match kreuzberg::batch_extract_bytes_sync(contents, &config) {
-            Ok(results) => {
-                let mut c_results = Vec::with_capacity(results.len());
-                for result in results {
-                    match to_c_extraction_result(result) {
-                        Ok(ptr) => c_results.push(ptr),
-                        Err(e) => {
-                            for c_res in c_results {
-                                unsafe { kreuzberg_free_result(c_res) };
-                            }
-                            set_last_error(e);
-                            return ptr::null_mut();
-                        }
-                    }
+            Ok(results) => match to_c_batch_result(results) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
                 }
-
-                let results_array = c_results.into_boxed_slice();
-                let results_ptr = Box::into_raw(results_array) as *mut *mut CExtractionResult;
-
-                Box::into_raw(Box::new(CBatchResult {
-                    results: results_ptr,
-                    count,
-                    success: true,
-                    _padding2: [0u8; 7],
-                }))
-            }
+            },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -1361,7 +1366,7 @@ pub unsafe extern "C" fn kreuzberg_load_extraction_config_from_file(file_path: *
                 }
             },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -1624,6 +1629,38 @@ pub unsafe extern "C" fn kreuzberg_last_panic_context() -> *mut c_char {
     })
 }
 
+/// Get a structured description of the last error.
+///
+/// Returns a JSON object with a stable string `code` (e.g. `"parsing"`,
+/// `"missing_dependency"`), `message`, `stage`, `plugin` and `retryable`, or NULL if
+/// there is no error or it did not come from the Kreuzberg core.
+///
+/// # Safety
+///
+/// The returned string must be freed with kreuzberg_free_string().
+///
+/// # Example (C)
+///
+/// ```c
+/// CExtractionResult* result = kreuzberg_extract_file_sync(path);
+/// if (result == NULL) {
+///     char* info = kreuzberg_last_error_info();
+///     if (info != NULL) {
+///         printf("Error info: %s\n", info);
+///         kreuzberg_free_string(info);
+///     }
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_last_error_info() -> *mut c_char {
+    ffi_panic_guard!("kreuzberg_last_error_info", {
+        match get_last_error_info().map(|info| serde_json::to_string(&info)) {
+            Some(Ok(json)) => string_to_c_string(json).unwrap_or(ptr::null_mut()),
+            _ => ptr::null_mut(),
+        }
+    })
+}
+
 /// Get the library version string.
 ///
 /// # Safety
@@ -1642,6 +1679,29 @@ pub unsafe extern "C" fn kreuzberg_version() -> *const c_char {
     concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr() as *const c_char
 }
 
+/// Version of the C ABI: the function signatures and the layouts of `CExtractionResult`,
+/// `CBatchResult` and `CBytesWithMime`.
+///
+/// Incremented on every incompatible change. Adding functions does not change it, so bindings
+/// can check `kreuzberg_abi_version() == KREUZBERG_FFI_ABI_VERSION` against the header they were
+/// generated from when loading the library.
+pub const KREUZBERG_FFI_ABI_VERSION: u32 = 1;
+
+/// Get the C ABI version of the loaded library (see `KREUZBERG_FFI_ABI_VERSION`).
+///
+/// # Example (C)
+///
+/// ```c
+/// if (kreuzberg_abi_version() != KREUZBERG_FFI_ABI_VERSION) {
+///     fprintf(stderr, "libkreuzberg_ffi does not match kreuzberg.h\n");
+///     return 1;
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub extern "C" fn kreuzberg_abi_version() -> u32 {
+    KREUZBERG_FFI_ABI_VERSION
+}
+
 /// Type alias for the OCR backend callback function.
 ///
 /// # Parameters
@@ -1790,6 +1850,11 @@ impl OcrBackend for FfiOcrBackend {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -3122,7 +3187,7 @@ pub unsafe extern "C" fn kreuzberg_unregister_ocr_backend(name: *const c_char) -
         match kreuzberg::plugins::unregister_ocr_backend(name_str) {
             Ok(()) => true,
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 false
             }
         }
@@ -3168,7 +3233,7 @@ pub unsafe extern "C" fn kreuzberg_list_ocr_backends() -> *mut c_char {
                 }
             },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -3199,7 +3264,7 @@ pub unsafe extern "C" fn kreuzberg_clear_ocr_backends() -> bool {
         match kreuzberg::plugins::clear_ocr_backends() {
             Ok(()) => true,
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 false
             }
         }
@@ -3285,7 +3350,7 @@ pub unsafe extern "C" fn kreuzberg_detect_mime_type_from_bytes(bytes: *const u8,
                 }
             },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -3340,7 +3405,7 @@ pub unsafe extern "C" fn kreuzberg_detect_mime_type_from_path(file_path: *const
             },
             Err(e) => {
                 // ~keep: IO errors from file operations should bubble up as they indicate
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -3400,7 +3465,7 @@ pub unsafe extern "C" fn kreuzberg_get_extensions_for_mime(mime_type: *const c_c
                 }
             },
             Err(e) => {
-                set_last_error(e.to_string());
+                set_kreuzberg_error(e);
                 ptr::null_mut()
             }
         }
@@ -3542,6 +3607,312 @@ pub unsafe extern "C" fn kreuzberg_config_discover() -> *mut c_char {
     })
 }
 
+/// Create an ExtractionConfig with the default settings.
+///
+/// # Safety
+///
+/// - The returned config must be freed with `kreuzberg_free_config`
+///
+/// # Example (C)
+///
+/// ```c
+/// ExtractionConfig* config = kreuzberg_config_new();
+/// CExtractionResult* result = kreuzberg_extract_file_with_config_sync("document.pdf", config);
+/// kreuzberg_free_config(config);
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_config_new() -> *mut ExtractionConfig {
+    ffi_panic_guard!("kreuzberg_config_new", {
+        clear_last_error();
+        Box::into_raw(Box::new(ExtractionConfig::default()))
+    })
+}
+
+/// Create an ExtractionConfig from JSON.
+///
+/// Accepts the same JSON as the `config_json` parameters of the extraction functions. Parsing
+/// the config once and passing the handle avoids parsing it again for every document.
+///
+/// # Safety
+///
+/// - `config_json` must be a valid null-terminated C string
+/// - The returned config must be freed with `kreuzberg_free_config`
+/// - Returns NULL on error (check `kreuzberg_last_error`)
+///
+/// # Example (C)
+///
+/// ```c
+/// ExtractionConfig* config = kreuzberg_config_from_json("{\"force_ocr\": true}");
+/// if (config == NULL) {
+///     printf("Invalid config: %s\n", kreuzberg_last_error());
+///     return 1;
+/// }
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_config_from_json(config_json: *const c_char) -> *mut ExtractionConfig {
+    ffi_panic_guard!("kreuzberg_config_from_json", {
+        clear_last_error();
+
+        if config_json.is_null() {
+            set_last_error("config_json cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        let config_str = match unsafe { CStr::from_ptr(config_json) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in config JSON: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        match parse_extraction_config_from_json(config_str) {
+            Ok(config) => Box::into_raw(Box::new(config)),
+            Err(e) => {
+                set_last_error(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Serialize an ExtractionConfig to JSON, including every default value.
+///
+/// # Safety
+///
+/// - `config` must be a config returned by one of the `kreuzberg_config_*` constructors
+/// - The returned string must be freed with `kreuzberg_free_string`
+/// - Returns NULL on error (check `kreuzberg_last_error`)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_config_to_json(config: *const ExtractionConfig) -> *mut c_char {
+    ffi_panic_guard!("kreuzberg_config_to_json", {
+        clear_last_error();
+
+        let Some(config) = (unsafe { config.as_ref() }) else {
+            set_last_error("config cannot be NULL".to_string());
+            return ptr::null_mut();
+        };
+
+        let json = match serde_json::to_string(config) {
+            Ok(json) => json,
+            Err(e) => {
+                set_last_error(format!("Failed to serialize config: {}", e));
+                return ptr::null_mut();
+            }
+        };
+        match string_to_c_string(json) {
+            Ok(ptr) => ptr,
+            Err(e) => {
+                set_last_error(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Free an ExtractionConfig returned by one of the `kreuzberg_config_*` constructors.
+///
+/// # Safety
+///
+/// - `config` must be a config returned by `kreuzberg_config_new`, `kreuzberg_config_from_json`
+///   or `kreuzberg_config_from_file`
+/// - `config` can be NULL (no-op)
+/// - `config` must not be used after this call
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_free_config(config: *mut ExtractionConfig) {
+    if !config.is_null() {
+        unsafe { drop(Box::from_raw(config)) };
+    }
+}
+
+/// The config behind a handle, or the default config for NULL.
+///
+/// # Safety
+///
+/// `config` must be NULL or a live config returned by one of the `kreuzberg_config_*` constructors.
+unsafe fn config_from_handle<'a>(config: *const ExtractionConfig) -> Cow<'a, ExtractionConfig> {
+    match unsafe { config.as_ref() } {
+        Some(config) => Cow::Borrowed(config),
+        None => Cow::Owned(ExtractionConfig::default()),
+    }
+}
+
+/// Extract text and metadata from a file with a config handle (synchronous).
+///
+/// # Safety
+///
+/// - `file_path` must be a valid null-terminated C string
+/// - `config` must be a config returned by one of the `kreuzberg_config_*` constructors, or NULL
+///   for the default config; it is not consumed and can be reused
+/// - The returned pointer must be freed with `kreuzberg_free_result`
+/// - Returns NULL on error (check `kreuzberg_last_error` for details)
+///
+/// # Example (C)
+///
+/// ```c
+/// ExtractionConfig* config = kreuzberg_config_from_file("kreuzberg.toml");
+/// CExtractionResult* result = kreuzberg_extract_file_with_config_sync("document.pdf", config);
+/// if (result != NULL && result->success) {
+///     printf("Content: %s\n", result->content);
+///     kreuzberg_free_result(result);
+/// }
+/// kreuzberg_free_config(config);
+/// ```
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_extract_file_with_config_sync(
+    file_path: *const c_char,
+    config: *const ExtractionConfig,
+) -> *mut CExtractionResult {
+    ffi_panic_guard!("kreuzberg_extract_file_with_config_sync", {
+        clear_last_error();
+
+        if file_path.is_null() {
+            set_last_error("file_path cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        let path_str = match unsafe { CStr::from_ptr(file_path) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in file path: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let config = unsafe { config_from_handle(config) };
+        match kreuzberg::extract_file_sync(Path::new(path_str), None, &config) {
+            Ok(result) => match to_c_extraction_result(result) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                set_kreuzberg_error(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Extract text and metadata from a byte array with a config handle (synchronous).
+///
+/// # Safety
+///
+/// - `data` must be a valid pointer to a byte array of length `data_len`
+/// - `mime_type` must be a valid null-terminated C string
+/// - `config` must be a config returned by one of the `kreuzberg_config_*` constructors, or NULL
+///   for the default config; it is not consumed and can be reused
+/// - The returned pointer must be freed with `kreuzberg_free_result`
+/// - Returns NULL on error (check `kreuzberg_last_error` for details)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_extract_bytes_with_config_sync(
+    data: *const u8,
+    data_len: usize,
+    mime_type: *const c_char,
+    config: *const ExtractionConfig,
+) -> *mut CExtractionResult {
+    ffi_panic_guard!("kreuzberg_extract_bytes_with_config_sync", {
+        clear_last_error();
+
+        if data.is_null() {
+            set_last_error("data cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        if mime_type.is_null() {
+            set_last_error("mime_type cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        let bytes = unsafe { std::slice::from_raw_parts(data, data_len) };
+
+        let mime_str = match unsafe { CStr::from_ptr(mime_type) }.to_str() {
+            Ok(s) => s,
+            Err(e) => {
+                set_last_error(format!("Invalid UTF-8 in MIME type: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let config = unsafe { config_from_handle(config) };
+        match kreuzberg::extract_bytes_sync(bytes, mime_str, &config) {
+            Ok(result) => match to_c_extraction_result(result) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                set_kreuzberg_error(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Batch extract text and metadata from multiple files with a config handle (synchronous).
+///
+/// # Safety
+///
+/// - `file_paths` must be a valid pointer to an array of null-terminated C strings
+/// - `count` must be the number of file paths in the array
+/// - `config` must be a config returned by one of the `kreuzberg_config_*` constructors, or NULL
+///   for the default config; it is not consumed and can be reused
+/// - The returned pointer must be freed with `kreuzberg_free_batch_result`
+/// - Returns NULL on error (check `kreuzberg_last_error` for details)
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn kreuzberg_batch_extract_files_with_config_sync(
+    file_paths: *const *const c_char,
+    count: usize,
+    config: *const ExtractionConfig,
+) -> *mut CBatchResult {
+    ffi_panic_guard!("kreuzberg_batch_extract_files_with_config_sync", {
+        clear_last_error();
+
+        if file_paths.is_null() {
+            set_last_error("file_paths cannot be NULL".to_string());
+            return ptr::null_mut();
+        }
+
+        let mut paths = Vec::with_capacity(count);
+        for i in 0..count {
+            let path_ptr = unsafe { *file_paths.add(i) };
+            if path_ptr.is_null() {
+                set_last_error(format!("File path at index {} is NULL", i));
+                return ptr::null_mut();
+            }
+
+            let path_str = match unsafe { CStr::from_ptr(path_ptr) }.to_str() {
+                Ok(s) => s,
+                Err(e) => {
+                    set_last_error(format!("Invalid UTF-8 in file path at index {}: {}", i, e));
+                    return ptr::null_mut();
+                }
+            };
+
+            paths.push(Path::new(path_str));
+        }
+
+        let config = unsafe { config_from_handle(config) };
+        match kreuzberg::batch_extract_file_sync(paths, &config) {
+            Ok(results) => match to_c_batch_result(results) {
+                Ok(ptr) => ptr,
+                Err(e) => {
+                    set_last_error(e);
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                set_kreuzberg_error(e);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
 #[allow(non_upper_case_globals)]
 const _: () = {
     const fn assert_c_extraction_result_size() {
@@ -3621,4 +3992,46 @@ mod tests {
             assert!(!error.is_null());
         }
     }
+
+    #[test]
+    fn test_config_handle_roundtrip() {
+        unsafe {
+            let json = CString::new(r#"{"force_ocr": true}"#).unwrap();
+            let config = kreuzberg_config_from_json(json.as_ptr());
+            assert!(!config.is_null());
+            assert!((*config).force_ocr);
+
+            let serialized = kreuzberg_config_to_json(config);
+            assert!(!serialized.is_null());
+            let serialized_str = CStr::from_ptr(serialized).to_str().unwrap();
+            let value: serde_json::Value = serde_json::from_str(serialized_str).unwrap();
+            assert_eq!(value["force_ocr"], true);
+            kreuzberg_free_string(serialized);
+            kreuzberg_free_config(config);
+
+            let invalid = CString::new("{not json").unwrap();
+            assert!(kreuzberg_config_from_json(invalid.as_ptr()).is_null());
+            assert!(!kreuzberg_last_error().is_null());
+        }
+    }
+
+    #[test]
+    fn test_extract_bytes_with_config_handle() {
+        unsafe {
+            let config = kreuzberg_config_new();
+            let data = b"Hello from a config handle";
+            let mime_type = CString::new("text/plain").unwrap();
+            let result =
+                kreuzberg_extract_bytes_with_config_sync(data.as_ptr(), data.len(), mime_type.as_ptr(), config);
+            assert!(!result.is_null());
+            let content = CStr::from_ptr((*result).content).to_str().unwrap();
+            assert!(content.contains("config handle"));
+            kreuzberg_free_result(result);
+
+            let path = CString::new("/nonexistent/file.pdf").unwrap();
+            assert!(kreuzberg_extract_file_with_config_sync(path.as_ptr(), config).is_null());
+            kreuzberg_free_config(config);
+            assert_eq!(kreuzberg_abi_version(), KREUZBERG_FFI_ABI_VERSION);
+        }
+    }
 }
@@ -1,4 +1,5 @@
 use kreuzberg::panic_context::PanicContext;
+use kreuzberg::{ErrorInfo, KreuzbergError};
 use std::cell::RefCell;
 
 /// Structured error that includes both the error message and optional panic context.
@@ -10,6 +11,8 @@ pub struct StructuredError {
     pub panic_context: Option<PanicContext>,
     /// Error code for programmatic error handling
     pub code: ErrorCode,
+    /// Structured description if this error originated from a `KreuzbergError`
+    pub info: Option<ErrorInfo>,
 }
 
 /// Error codes for different types of errors.
@@ -41,6 +44,7 @@ impl StructuredError {
             message: context.format(),
             panic_context: Some(context),
             code: ErrorCode::Panic,
+            info: None,
         }
     }
 
@@ -50,6 +54,29 @@ impl StructuredError {
             message,
             panic_context: None,
             code,
+            info: None,
+        }
+    }
+
+    /// Creates a new StructuredError from a Kreuzberg error, keeping its `ErrorInfo`.
+    pub fn from_kreuzberg_error(error: &KreuzbergError) -> Self {
+        let code = match error {
+            KreuzbergError::Io(_) => ErrorCode::IoError,
+            KreuzbergError::Parsing { .. } | KreuzbergError::EncryptedDocument(_) => ErrorCode::ParsingError,
+            KreuzbergError::Ocr { .. } => ErrorCode::OcrError,
+            KreuzbergError::MissingDependency(_) => ErrorCode::MissingDependency,
+            KreuzbergError::Validation { .. }
+            | KreuzbergError::UnsupportedFormat(_)
+            | KreuzbergError::LimitExceeded { .. } => ErrorCode::InvalidArgument,
+            _ => ErrorCode::GenericError,
+        };
+        let info = error.to_info();
+
+        Self {
+            message: info.message.clone(),
+            panic_context: None,
+            code,
+            info: Some(info),
         }
     }
 
@@ -87,6 +114,11 @@ pub fn get_last_panic_context() -> Option<PanicContext> {
     LAST_STRUCTURED_ERROR.with(|last| last.borrow().as_ref().and_then(|e| e.panic_context.clone()))
 }
 
+/// Gets the `ErrorInfo` of the last error if it originated from a `KreuzbergError`.
+pub fn get_last_error_info() -> Option<ErrorInfo> {
+    LAST_STRUCTURED_ERROR.with(|last| last.borrow().as_ref().and_then(|e| e.info.clone()))
+}
+
 /// Clears the last structured error.
 pub fn clear_structured_error() {
     LAST_STRUCTURED_ERROR.with(|last| *last.borrow_mut() = None);
@@ -183,6 +215,17 @@ mod tests {
         assert_eq!(err.message, "test error");
     }
 
+    #[test]
+    fn test_structured_error_from_kreuzberg_error() {
+        let err = StructuredError::from_kreuzberg_error(&KreuzbergError::parsing("bad header"));
+
+        assert_eq!(err.code, ErrorCode::ParsingError);
+        assert_eq!(err.message, "Parsing error: bad header");
+        let info = err.info.unwrap();
+        assert_eq!(info.code, kreuzberg::ErrorCode::Parsing);
+        assert_eq!(info.stage.as_deref(), Some("extraction"));
+    }
+
     #[test]
     fn test_error_storage() {
         clear_structured_error();
@@ -396,6 +396,8 @@ export interface ExtractedImage {
 	width?: number;
 	/** Image height in pixels */
 	height?: number;
+	/** Resolution recorded in the image data, in dots per inch */
+	dpi?: number;
 	/** Colorspace (e.g., "DeviceRGB", "DeviceGray") */
 	colorspace?: string;
 	/** Bits per color component */
@@ -4,6 +4,7 @@ use html_to_markdown_rs::options::{
     CodeBlockStyle, ConversionOptions, HeadingStyle, HighlightStyle, ListIndentType, NewlineStyle,
     PreprocessingOptions as HtmlPreprocessingOptions, PreprocessingPreset, WhitespaceMode,
 };
+use kreuzberg::entities::EntityConfig as RustEntityConfig;
 use kreuzberg::keywords::{
     KeywordAlgorithm as RustKeywordAlgorithm, KeywordConfig as RustKeywordConfig, RakeParams as RustRakeParams,
     YakeParams as RustYakeParams,
@@ -12,10 +13,13 @@ use kreuzberg::plugins::registry::{get_post_processor_registry, get_validator_re
 use kreuzberg::{
     Chunk as RustChunk, ChunkMetadata as RustChunkMetadata, ChunkingConfig as RustChunkingConfig,
     EmbeddingConfig as RustEmbeddingConfig, EmbeddingModelType as RustEmbeddingModelType, ExtractionConfig,
-    ExtractionResult as RustExtractionResult, ImageExtractionConfig as RustImageExtractionConfig,
-    LanguageDetectionConfig as RustLanguageDetectionConfig, OcrConfig as RustOcrConfig, PdfConfig as RustPdfConfig,
-    PostProcessorConfig as RustPostProcessorConfig, TesseractConfig as RustTesseractConfig,
-    TokenReductionConfig as RustTokenReductionConfig,
+    ExtractionResult as RustExtractionResult, HeaderFooterConfig as RustHeaderFooterConfig,
+    ImageExtractionConfig as RustImageExtractionConfig, ImagePreprocessingConfig as RustImagePreprocessingConfig,
+    LanguageDetectionConfig as RustLanguageDetectionConfig, LineRepairConfig as RustLineRepairConfig,
+    OcrConfig as RustOcrConfig, PdfConfig as RustPdfConfig, PostProcessorConfig as RustPostProcessorConfig,
+    QualityGateConfig as RustQualityGateConfig, StopwordsConfig as RustStopwordsConfig,
+    TesseractConfig as RustTesseractConfig, TextMetricsConfig as RustTextMetricsConfig,
+    TextNormalizationConfig as RustTextNormalizationConfig, TokenReductionConfig as RustTokenReductionConfig,
 };
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
@@ -84,8 +88,14 @@ fn get_panic_context() -> Option<serde_json::Value> {
 /// - `Plugin` → GenericFailure (plugin-specific errors)
 /// - `LockPoisoned` → GenericFailure (lock poisoning, should not happen)
 /// - `UnsupportedFormat` → InvalidArg (unsupported MIME types)
+/// - `LimitExceeded` → InvalidArg (configured resource limit exceeded)
+/// - `EncryptedDocument` → InvalidArg (no configured password opens the document)
 /// - `Other` → GenericFailure (catch-all)
 ///
+/// The error reason is the JSON-encoded `kreuzberg::ErrorInfo` (code, message, stage, plugin,
+/// retryable flag); the TypeScript wrapper turns it back into the matching `KreuzbergError`
+/// subclass.
+///
 /// # Usage
 ///
 /// ```rust,ignore
@@ -97,48 +107,19 @@ fn get_panic_context() -> Option<serde_json::Value> {
 fn convert_error(err: kreuzberg::KreuzbergError) -> napi::Error {
     use kreuzberg::KreuzbergError;
 
-    match err {
-        KreuzbergError::Io(e) => Error::new(Status::GenericFailure, format!("IO error: {}", e)),
-
-        KreuzbergError::Parsing { message, .. } => {
-            Error::new(Status::InvalidArg, format!("Parsing error: {}", message))
-        }
-
-        KreuzbergError::Ocr { message, .. } => Error::new(Status::GenericFailure, format!("OCR error: {}", message)),
-
-        KreuzbergError::Validation { message, .. } => {
-            Error::new(Status::InvalidArg, format!("Validation error: {}", message))
-        }
-
-        KreuzbergError::Cache { message, .. } => {
-            Error::new(Status::GenericFailure, format!("Cache error: {}", message))
-        }
-
-        KreuzbergError::ImageProcessing { message, .. } => {
-            Error::new(Status::GenericFailure, format!("Image processing error: {}", message))
-        }
-
-        KreuzbergError::Serialization { message, .. } => {
-            Error::new(Status::InvalidArg, format!("Serialization error: {}", message))
-        }
-
-        KreuzbergError::MissingDependency(dep) => {
-            Error::new(Status::GenericFailure, format!("Missing dependency: {}", dep))
-        }
-
-        KreuzbergError::Plugin { message, plugin_name } => Error::new(
-            Status::GenericFailure,
-            format!("Plugin error in '{}': {}", plugin_name, message),
-        ),
-
-        KreuzbergError::LockPoisoned(msg) => Error::new(Status::GenericFailure, format!("Lock poisoned: {}", msg)),
-
-        KreuzbergError::UnsupportedFormat(format) => {
-            Error::new(Status::InvalidArg, format!("Unsupported format: {}", format))
-        }
+    let status = match &err {
+        KreuzbergError::Parsing { .. }
+        | KreuzbergError::Validation { .. }
+        | KreuzbergError::Serialization { .. }
+        | KreuzbergError::UnsupportedFormat(_)
+        | KreuzbergError::LimitExceeded { .. }
+        | KreuzbergError::EncryptedDocument(_) => Status::InvalidArg,
+        _ => Status::GenericFailure,
+    };
 
-        KreuzbergError::Other(msg) => Error::new(Status::GenericFailure, msg),
-    }
+    let info = err.to_info();
+    let reason = serde_json::to_string(&info).unwrap_or(info.message);
+    Error::new(status, reason)
 }
 
 /// Validates that a JavaScript object has all required properties before plugin registration.
@@ -208,6 +189,7 @@ pub struct JsTesseractConfig {
     pub psm: Option<i32>,
     pub enable_table_detection: Option<bool>,
     pub tessedit_char_whitelist: Option<String>,
+    pub preprocessing: Option<JsImagePreprocessingConfig>,
 }
 
 impl From<JsTesseractConfig> for RustTesseractConfig {
@@ -222,10 +204,57 @@ impl From<JsTesseractConfig> for RustTesseractConfig {
         if let Some(whitelist) = val.tessedit_char_whitelist {
             config.tessedit_char_whitelist = whitelist;
         }
+        config.preprocessing = val.preprocessing.map(Into::into);
         config
     }
 }
 
+#[napi(object)]
+pub struct JsImagePreprocessingConfig {
+    pub target_dpi: Option<i32>,
+    pub auto_rotate: Option<bool>,
+    pub deskew: Option<bool>,
+    pub grayscale: Option<bool>,
+    pub denoise: Option<bool>,
+    pub contrast_enhance: Option<bool>,
+    pub binarization_method: Option<String>,
+    pub invert_colors: Option<bool>,
+    pub remove_borders: Option<bool>,
+}
+
+impl From<JsImagePreprocessingConfig> for RustImagePreprocessingConfig {
+    fn from(val: JsImagePreprocessingConfig) -> Self {
+        let defaults = RustImagePreprocessingConfig::default();
+        RustImagePreprocessingConfig {
+            target_dpi: val.target_dpi.unwrap_or(defaults.target_dpi),
+            auto_rotate: val.auto_rotate.unwrap_or(defaults.auto_rotate),
+            deskew: val.deskew.unwrap_or(defaults.deskew),
+            grayscale: val.grayscale.unwrap_or(defaults.grayscale),
+            denoise: val.denoise.unwrap_or(defaults.denoise),
+            contrast_enhance: val.contrast_enhance.unwrap_or(defaults.contrast_enhance),
+            binarization_method: val.binarization_method.unwrap_or(defaults.binarization_method),
+            invert_colors: val.invert_colors.unwrap_or(defaults.invert_colors),
+            remove_borders: val.remove_borders.unwrap_or(defaults.remove_borders),
+        }
+    }
+}
+
+impl From<RustImagePreprocessingConfig> for JsImagePreprocessingConfig {
+    fn from(val: RustImagePreprocessingConfig) -> Self {
+        JsImagePreprocessingConfig {
+            target_dpi: Some(val.target_dpi),
+            auto_rotate: Some(val.auto_rotate),
+            deskew: Some(val.deskew),
+            grayscale: Some(val.grayscale),
+            denoise: Some(val.denoise),
+            contrast_enhance: Some(val.contrast_enhance),
+            binarization_method: Some(val.binarization_method),
+            invert_colors: Some(val.invert_colors),
+            remove_borders: Some(val.remove_borders),
+        }
+    }
+}
+
 /// Embedding model type configuration for Node.js bindings.
 ///
 /// This struct represents different embedding model sources:
@@ -316,6 +345,7 @@ pub struct JsLanguageDetectionConfig {
     pub enabled: Option<bool>,
     pub min_confidence: Option<f64>,
     pub detect_multiple: Option<bool>,
+    pub max_sample_kb: Option<u32>,
 }
 
 impl From<JsLanguageDetectionConfig> for RustLanguageDetectionConfig {
@@ -324,6 +354,122 @@ impl From<JsLanguageDetectionConfig> for RustLanguageDetectionConfig {
             enabled: val.enabled.unwrap_or(true),
             min_confidence: val.min_confidence.unwrap_or(0.8),
             detect_multiple: val.detect_multiple.unwrap_or(false),
+            max_sample_kb: val.max_sample_kb.map(|kb| kb as usize),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct JsEntityConfig {
+    pub entity_types: Option<Vec<String>>,
+    pub min_confidence: Option<f64>,
+}
+
+impl TryFrom<JsEntityConfig> for RustEntityConfig {
+    type Error = Error;
+
+    fn try_from(val: JsEntityConfig) -> Result<Self> {
+        let mut config = RustEntityConfig::default();
+        if let Some(types) = val.entity_types {
+            config.entity_types = types.iter().map(|t| parse_entity_type(t)).collect::<Result<Vec<_>>>()?;
+        }
+        if let Some(min_confidence) = val.min_confidence {
+            config.min_confidence = min_confidence;
+        }
+        Ok(config)
+    }
+}
+
+fn parse_entity_type(value: &str) -> Result<kreuzberg::EntityType> {
+    value
+        .parse()
+        .map_err(|e: kreuzberg::KreuzbergError| Error::new(Status::InvalidArg, e.to_string()))
+}
+
+impl From<RustEntityConfig> for JsEntityConfig {
+    fn from(config: RustEntityConfig) -> Self {
+        Self {
+            entity_types: Some(config.entity_types.iter().map(|t| t.as_str().to_string()).collect()),
+            min_confidence: Some(config.min_confidence),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct JsQualityGateConfig {
+    pub min_dictionary_hit_rate: Option<f64>,
+    pub min_confusion_score: Option<f64>,
+    pub min_words: Option<u32>,
+    pub ocr_only: Option<bool>,
+    pub action: Option<String>,
+    pub language: Option<String>,
+}
+
+impl TryFrom<JsQualityGateConfig> for RustQualityGateConfig {
+    type Error = Error;
+
+    fn try_from(val: JsQualityGateConfig) -> Result<Self> {
+        let defaults = RustQualityGateConfig::default();
+        let action = match val.action {
+            Some(action) => action
+                .parse()
+                .map_err(|e: kreuzberg::KreuzbergError| Error::new(Status::InvalidArg, e.to_string()))?,
+            None => kreuzberg::QualityGateAction::default(),
+        };
+
+        Ok(RustQualityGateConfig {
+            min_dictionary_hit_rate: val.min_dictionary_hit_rate.unwrap_or(defaults.min_dictionary_hit_rate),
+            min_confusion_score: val.min_confusion_score.unwrap_or(defaults.min_confusion_score),
+            min_words: val.min_words.map_or(defaults.min_words, |v| v as usize),
+            ocr_only: val.ocr_only.unwrap_or(defaults.ocr_only),
+            action,
+            language: val.language,
+        })
+    }
+}
+
+impl From<RustQualityGateConfig> for JsQualityGateConfig {
+    fn from(config: RustQualityGateConfig) -> Self {
+        Self {
+            min_dictionary_hit_rate: Some(config.min_dictionary_hit_rate),
+            min_confusion_score: Some(config.min_confusion_score),
+            min_words: Some(config.min_words as u32),
+            ocr_only: Some(config.ocr_only),
+            action: Some(config.action.as_str().to_string()),
+            language: config.language,
+        }
+    }
+}
+
+#[napi(object)]
+pub struct JsStopwordsConfig {
+    pub custom: Option<Vec<String>>,
+    pub mode: Option<String>,
+}
+
+impl TryFrom<JsStopwordsConfig> for RustStopwordsConfig {
+    type Error = Error;
+
+    fn try_from(val: JsStopwordsConfig) -> Result<Self> {
+        let mode = match val.mode {
+            Some(mode) => mode
+                .parse()
+                .map_err(|e: kreuzberg::KreuzbergError| Error::new(Status::InvalidArg, e.to_string()))?,
+            None => kreuzberg::StopwordsMode::default(),
+        };
+
+        Ok(RustStopwordsConfig {
+            custom: val.custom.unwrap_or_default(),
+            mode,
+        })
+    }
+}
+
+impl From<RustStopwordsConfig> for JsStopwordsConfig {
+    fn from(config: RustStopwordsConfig) -> Self {
+        Self {
+            custom: Some(config.custom),
+            mode: Some(config.mode.as_str().to_string()),
         }
     }
 }
@@ -332,14 +478,22 @@ impl From<JsLanguageDetectionConfig> for RustLanguageDetectionConfig {
 pub struct JsTokenReductionConfig {
     pub mode: Option<String>,
     pub preserve_important_words: Option<bool>,
+    pub stopwords: Option<JsStopwordsConfig>,
+    pub target_ratio: Option<f64>,
+    pub max_tokens: Option<u32>,
 }
 
-impl From<JsTokenReductionConfig> for RustTokenReductionConfig {
-    fn from(val: JsTokenReductionConfig) -> Self {
-        RustTokenReductionConfig {
+impl TryFrom<JsTokenReductionConfig> for RustTokenReductionConfig {
+    type Error = Error;
+
+    fn try_from(val: JsTokenReductionConfig) -> Result<Self> {
+        Ok(RustTokenReductionConfig {
             mode: val.mode.unwrap_or_else(|| "off".to_string()),
             preserve_important_words: val.preserve_important_words.unwrap_or(true),
-        }
+            stopwords: val.stopwords.map(RustStopwordsConfig::try_from).transpose()?,
+            target_ratio: val.target_ratio.map(|ratio| ratio as f32),
+            max_tokens: val.max_tokens.map(|tokens| tokens as usize),
+        })
     }
 }
 
@@ -348,6 +502,8 @@ pub struct JsPdfConfig {
     pub extract_images: Option<bool>,
     pub passwords: Option<Vec<String>>,
     pub extract_metadata: Option<bool>,
+    pub validate_pdfa: Option<bool>,
+    pub layout_stats: Option<bool>,
 }
 
 impl From<JsPdfConfig> for RustPdfConfig {
@@ -356,6 +512,8 @@ impl From<JsPdfConfig> for RustPdfConfig {
             extract_images: val.extract_images.unwrap_or(false),
             passwords: val.passwords,
             extract_metadata: val.extract_metadata.unwrap_or(true),
+            validate_pdfa: val.validate_pdfa.unwrap_or(false),
+            layout_stats: val.layout_stats.unwrap_or(false),
         }
     }
 }
@@ -368,6 +526,8 @@ pub struct JsImageExtractionConfig {
     pub auto_adjust_dpi: Option<bool>,
     pub min_dpi: Option<i32>,
     pub max_dpi: Option<i32>,
+    pub describer: Option<String>,
+    pub inline_descriptions: Option<bool>,
 }
 
 impl From<JsImageExtractionConfig> for RustImageExtractionConfig {
@@ -379,6 +539,138 @@ impl From<JsImageExtractionConfig> for RustImageExtractionConfig {
             auto_adjust_dpi: val.auto_adjust_dpi.unwrap_or(true),
             min_dpi: val.min_dpi.unwrap_or(72),
             max_dpi: val.max_dpi.unwrap_or(600),
+            describer: val.describer,
+            inline_descriptions: val.inline_descriptions.unwrap_or(false),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct JsHeaderFooterConfig {
+    pub lines_to_check: Option<u32>,
+    pub min_pages: Option<u32>,
+    pub min_repeat_ratio: Option<f64>,
+    pub remove_page_numbers: Option<bool>,
+}
+
+impl From<JsHeaderFooterConfig> for RustHeaderFooterConfig {
+    fn from(val: JsHeaderFooterConfig) -> Self {
+        let defaults = RustHeaderFooterConfig::default();
+        RustHeaderFooterConfig {
+            lines_to_check: val
+                .lines_to_check
+                .map(|v| v as usize)
+                .unwrap_or(defaults.lines_to_check),
+            min_pages: val.min_pages.map(|v| v as usize).unwrap_or(defaults.min_pages),
+            min_repeat_ratio: val.min_repeat_ratio.unwrap_or(defaults.min_repeat_ratio),
+            remove_page_numbers: val.remove_page_numbers.unwrap_or(defaults.remove_page_numbers),
+        }
+    }
+}
+
+impl From<&RustHeaderFooterConfig> for JsHeaderFooterConfig {
+    fn from(val: &RustHeaderFooterConfig) -> Self {
+        JsHeaderFooterConfig {
+            lines_to_check: Some(val.lines_to_check as u32),
+            min_pages: Some(val.min_pages as u32),
+            min_repeat_ratio: Some(val.min_repeat_ratio),
+            remove_page_numbers: Some(val.remove_page_numbers),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct JsLineRepairConfig {
+    pub dehyphenate: Option<bool>,
+    pub merge_lines: Option<bool>,
+    pub language: Option<String>,
+}
+
+impl From<JsLineRepairConfig> for RustLineRepairConfig {
+    fn from(val: JsLineRepairConfig) -> Self {
+        RustLineRepairConfig {
+            dehyphenate: val.dehyphenate.unwrap_or(true),
+            merge_lines: val.merge_lines.unwrap_or(true),
+            language: val.language,
+        }
+    }
+}
+
+impl From<&RustLineRepairConfig> for JsLineRepairConfig {
+    fn from(val: &RustLineRepairConfig) -> Self {
+        JsLineRepairConfig {
+            dehyphenate: Some(val.dehyphenate),
+            merge_lines: Some(val.merge_lines),
+            language: val.language.clone(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct JsTextNormalizationConfig {
+    pub form: Option<String>,
+    pub remove_zero_width: Option<bool>,
+    pub remove_bidi_controls: Option<bool>,
+    pub remove_control_chars: Option<bool>,
+    pub canonicalize_quotes: Option<bool>,
+    pub canonicalize_dashes: Option<bool>,
+}
+
+impl TryFrom<JsTextNormalizationConfig> for RustTextNormalizationConfig {
+    type Error = Error;
+
+    fn try_from(val: JsTextNormalizationConfig) -> Result<Self> {
+        let form = match val.form {
+            Some(form) => form
+                .parse()
+                .map_err(|e: kreuzberg::KreuzbergError| Error::new(Status::InvalidArg, e.to_string()))?,
+            None => kreuzberg::NormalizationForm::default(),
+        };
+
+        Ok(RustTextNormalizationConfig {
+            form,
+            remove_zero_width: val.remove_zero_width.unwrap_or(true),
+            remove_bidi_controls: val.remove_bidi_controls.unwrap_or(true),
+            remove_control_chars: val.remove_control_chars.unwrap_or(true),
+            canonicalize_quotes: val.canonicalize_quotes.unwrap_or(true),
+            canonicalize_dashes: val.canonicalize_dashes.unwrap_or(true),
+        })
+    }
+}
+
+impl From<&RustTextNormalizationConfig> for JsTextNormalizationConfig {
+    fn from(val: &RustTextNormalizationConfig) -> Self {
+        JsTextNormalizationConfig {
+            form: Some(val.form.as_str().to_string()),
+            remove_zero_width: Some(val.remove_zero_width),
+            remove_bidi_controls: Some(val.remove_bidi_controls),
+            remove_control_chars: Some(val.remove_control_chars),
+            canonicalize_quotes: Some(val.canonicalize_quotes),
+            canonicalize_dashes: Some(val.canonicalize_dashes),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct JsTextMetricsConfig {
+    pub long_word_length: Option<u32>,
+}
+
+impl From<JsTextMetricsConfig> for RustTextMetricsConfig {
+    fn from(val: JsTextMetricsConfig) -> Self {
+        let defaults = RustTextMetricsConfig::default();
+        RustTextMetricsConfig {
+            long_word_length: val
+                .long_word_length
+                .map_or(defaults.long_word_length, |length| length as usize),
+        }
+    }
+}
+
+impl From<&RustTextMetricsConfig> for JsTextMetricsConfig {
+    fn from(val: &RustTextMetricsConfig) -> Self {
+        JsTextMetricsConfig {
+            long_word_length: Some(val.long_word_length as u32),
         }
     }
 }
@@ -388,15 +680,34 @@ pub struct JsPostProcessorConfig {
     pub enabled: Option<bool>,
     pub enabled_processors: Option<Vec<String>>,
     pub disabled_processors: Option<Vec<String>>,
+    pub header_footer: Option<JsHeaderFooterConfig>,
+    pub line_repair: Option<JsLineRepairConfig>,
+    pub normalization: Option<JsTextNormalizationConfig>,
+    pub metrics: Option<JsTextMetricsConfig>,
+    pub taggers: Option<serde_json::Value>,
 }
 
-impl From<JsPostProcessorConfig> for RustPostProcessorConfig {
-    fn from(val: JsPostProcessorConfig) -> Self {
-        RustPostProcessorConfig {
+impl TryFrom<JsPostProcessorConfig> for RustPostProcessorConfig {
+    type Error = Error;
+
+    fn try_from(val: JsPostProcessorConfig) -> Result<Self> {
+        Ok(RustPostProcessorConfig {
             enabled: val.enabled.unwrap_or(true),
             enabled_processors: val.enabled_processors,
             disabled_processors: val.disabled_processors,
-        }
+            header_footer: val.header_footer.map(Into::into),
+            line_repair: val.line_repair.map(Into::into),
+            normalization: val
+                .normalization
+                .map(RustTextNormalizationConfig::try_from)
+                .transpose()?,
+            metrics: val.metrics.map(Into::into),
+            taggers: val
+                .taggers
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(|e| Error::new(Status::InvalidArg, format!("Invalid taggers: {}", e)))?,
+        })
     }
 }
 
@@ -467,6 +778,7 @@ pub struct JsKeywordConfig {
     #[napi(ts_type = "[number, number] | undefined")]
     pub ngram_range: Option<Vec<u32>>,
     pub language: Option<String>,
+    pub stopwords: Option<JsStopwordsConfig>,
     pub yake_params: Option<JsYakeParams>,
     pub rake_params: Option<JsRakeParams>,
 }
@@ -668,6 +980,9 @@ impl TryFrom<JsKeywordConfig> for RustKeywordConfig {
         if let Some(language) = config.language {
             keywords.language = Some(language);
         }
+        if let Some(stopwords) = config.stopwords {
+            keywords.stopwords = Some(RustStopwordsConfig::try_from(stopwords)?);
+        }
         if let Some(algorithm) = config.algorithm {
             keywords.algorithm = parse_keyword_algorithm(&algorithm)?;
         }
@@ -701,6 +1016,7 @@ impl From<RustKeywordConfig> for JsKeywordConfig {
             min_score: Some(config.min_score as f64),
             ngram_range: Some(vec![config.ngram_range.0 as u32, config.ngram_range.1 as u32]),
             language: config.language,
+            stopwords: config.stopwords.map(JsStopwordsConfig::from),
             yake_params: config.yake_params.map(|params| JsYakeParams {
                 window_size: Some(params.window_size as u32),
             }),
@@ -859,6 +1175,20 @@ fn parse_keyword_algorithm(value: &str) -> Result<RustKeywordAlgorithm> {
     }
 }
 
+fn parse_warning_stage(value: &str) -> Result<kreuzberg::WarningStage> {
+    match value {
+        "recovery" => Ok(kreuzberg::WarningStage::Recovery),
+        "extraction" => Ok(kreuzberg::WarningStage::Extraction),
+        "ocr" => Ok(kreuzberg::WarningStage::Ocr),
+        "post_processing" => Ok(kreuzberg::WarningStage::PostProcessing),
+        "limits" => Ok(kreuzberg::WarningStage::Limits),
+        other => Err(Error::new(
+            Status::InvalidArg,
+            format!("Invalid warnings[].stage '{}'", other),
+        )),
+    }
+}
+
 fn keyword_algorithm_to_string(algo: RustKeywordAlgorithm) -> &'static str {
     match algo {
         RustKeywordAlgorithm::Yake => "yake",
@@ -887,8 +1217,13 @@ pub struct JsExtractionConfig {
     pub postprocessor: Option<JsPostProcessorConfig>,
     pub keywords: Option<JsKeywordConfig>,
     pub html_options: Option<JsHtmlOptions>,
+    pub collect_timings: Option<bool>,
+    pub strict_mime: Option<bool>,
+    pub strict: Option<bool>,
     pub max_concurrent_extractions: Option<u32>,
     pub pages: Option<JsPageConfig>,
+    pub entities: Option<JsEntityConfig>,
+    pub quality_gate: Option<JsQualityGateConfig>,
 }
 
 impl TryFrom<JsPageConfig> for kreuzberg::core::config::PageConfig {
@@ -937,13 +1272,22 @@ impl TryFrom<JsExtractionConfig> for ExtractionConfig {
             chunking: val.chunking.map(Into::into),
             images: val.images.map(Into::into),
             pdf_options: val.pdf_options.map(Into::into),
-            token_reduction: val.token_reduction.map(Into::into),
+            token_reduction: val
+                .token_reduction
+                .map(RustTokenReductionConfig::try_from)
+                .transpose()?,
             language_detection: val.language_detection.map(Into::into),
             keywords,
-            postprocessor: val.postprocessor.map(Into::into),
+            postprocessor: val.postprocessor.map(RustPostProcessorConfig::try_from).transpose()?,
             html_options,
+            collect_timings: val.collect_timings.unwrap_or(false),
+            strict_mime: val.strict_mime.unwrap_or(false),
+            strict: val.strict.unwrap_or(false),
             max_concurrent_extractions: val.max_concurrent_extractions.map(|v| v as usize),
             pages: val.pages.map(|p| p.try_into()).transpose()?,
+            entities: val.entities.map(RustEntityConfig::try_from).transpose()?,
+            quality_gate: val.quality_gate.map(RustQualityGateConfig::try_from).transpose()?,
+            ..Default::default()
         })
     }
 }
@@ -966,6 +1310,7 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                     } else {
                         Some(tc.tessedit_char_whitelist)
                     },
+                    preprocessing: tc.preprocessing.map(Into::into),
                 }),
             }),
             force_ocr: Some(val.force_ocr),
@@ -1004,30 +1349,51 @@ impl TryFrom<ExtractionConfig> for JsExtractionConfig {
                 auto_adjust_dpi: Some(img.auto_adjust_dpi),
                 min_dpi: Some(img.min_dpi),
                 max_dpi: Some(img.max_dpi),
+                describer: img.describer,
+                inline_descriptions: Some(img.inline_descriptions),
             }),
             pdf_options: val.pdf_options.map(|pdf| JsPdfConfig {
                 extract_images: Some(pdf.extract_images),
                 passwords: pdf.passwords,
                 extract_metadata: Some(pdf.extract_metadata),
+                validate_pdfa: Some(pdf.validate_pdfa),
+                layout_stats: Some(pdf.layout_stats),
             }),
             token_reduction: val.token_reduction.map(|tr| JsTokenReductionConfig {
                 mode: Some(tr.mode),
                 preserve_important_words: Some(tr.preserve_important_words),
+                stopwords: tr.stopwords.map(JsStopwordsConfig::from),
+                target_ratio: tr.target_ratio.map(f64::from),
+                max_tokens: tr.max_tokens.map(|tokens| tokens as u32),
             }),
             language_detection: val.language_detection.map(|ld| JsLanguageDetectionConfig {
                 enabled: Some(ld.enabled),
                 min_confidence: Some(ld.min_confidence),
                 detect_multiple: Some(ld.detect_multiple),
+                max_sample_kb: ld.max_sample_kb.map(|kb| kb as u32),
             }),
             postprocessor: val.postprocessor.map(|pp| JsPostProcessorConfig {
                 enabled: Some(pp.enabled),
                 enabled_processors: pp.enabled_processors,
                 disabled_processors: pp.disabled_processors,
+                header_footer: pp.header_footer.as_ref().map(JsHeaderFooterConfig::from),
+                line_repair: pp.line_repair.as_ref().map(JsLineRepairConfig::from),
+                normalization: pp.normalization.as_ref().map(JsTextNormalizationConfig::from),
+                metrics: pp.metrics.as_ref().map(JsTextMetricsConfig::from),
+                taggers: pp
+                    .taggers
+                    .as_ref()
+                    .and_then(|taggers| serde_json::to_value(taggers).ok()),
             }),
             keywords: val.keywords.map(JsKeywordConfig::from),
             html_options: val.html_options.as_ref().map(JsHtmlOptions::from),
+            collect_timings: Some(val.collect_timings),
+            strict_mime: Some(val.strict_mime),
+            strict: Some(val.strict),
             max_concurrent_extractions: val.max_concurrent_extractions.map(|v| v as u32),
             pages: val.pages.map(JsPageConfig::from),
+            entities: val.entities.map(JsEntityConfig::from),
+            quality_gate: val.quality_gate.map(JsQualityGateConfig::from),
         })
     }
 }
@@ -1144,6 +1510,7 @@ pub struct JsExtractedImage {
     pub page_number: Option<u32>,
     pub width: Option<u32>,
     pub height: Option<u32>,
+    pub dpi: Option<u32>,
     pub colorspace: Option<String>,
     pub bits_per_component: Option<u32>,
     pub is_mask: bool,
@@ -1164,6 +1531,45 @@ pub struct JsChunkMetadata {
     pub last_page: Option<u32>,
 }
 
+#[napi(object)]
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct JsDetectedLanguage {
+    pub language: String,
+    pub confidence: f64,
+    pub script: String,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct JsEntity {
+    #[napi(ts_type = "'person' | 'organization' | 'date' | 'money'")]
+    pub entity_type: String,
+    pub text: String,
+    pub byte_start: u32,
+    pub byte_end: u32,
+    pub confidence: f64,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct JsKeyword {
+    pub text: String,
+    pub score: f64,
+    #[napi(ts_type = "'yake' | 'rake'")]
+    pub algorithm: String,
+    pub positions: Option<Vec<u32>>,
+}
+
+#[napi(object)]
+#[derive(serde::Serialize, serde::Deserialize, Clone)]
+pub struct JsExtractionWarning {
+    pub code: String,
+    pub message: String,
+    #[napi(ts_type = "'recovery' | 'extraction' | 'ocr' | 'post_processing' | 'limits'")]
+    pub stage: String,
+    pub page: Option<u32>,
+}
+
 #[napi(object)]
 #[derive(serde::Serialize, serde::Deserialize, Clone)]
 pub struct JsChunk {
@@ -1197,10 +1603,14 @@ pub struct JsExtractionResult {
     #[napi(ts_type = "Metadata")]
     pub metadata: serde_json::Value,
     pub tables: Vec<JsTable>,
-    pub detected_languages: Option<Vec<String>>,
+    pub detected_languages: Option<Vec<JsDetectedLanguage>>,
     pub chunks: Option<Vec<JsChunk>>,
     #[serde(skip)]
     pub images: Option<Vec<JsExtractedImage>>,
+    pub entities: Option<Vec<JsEntity>>,
+    pub keywords: Option<Vec<JsKeyword>>,
+    #[serde(default)]
+    pub warnings: Option<Vec<JsExtractionWarning>>,
 }
 
 impl TryFrom<RustExtractionResult> for JsExtractionResult {
@@ -1233,6 +1643,7 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                     page_number: img.page_number.map(|p| p as u32),
                     width: img.width,
                     height: img.height,
+                    dpi: img.dpi,
                     colorspace: img.colorspace,
                     bits_per_component: img.bits_per_component,
                     is_mask: img.is_mask,
@@ -1245,6 +1656,22 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
             None
         };
 
+        let warnings = val
+            .warnings
+            .into_iter()
+            .map(|warning| {
+                Ok(JsExtractionWarning {
+                    code: warning.code,
+                    message: warning.message,
+                    stage: warning.stage.as_str().to_string(),
+                    page: warning
+                        .page
+                        .map(|page| usize_to_u32(page, "warnings[].page"))
+                        .transpose()?,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
         Ok(JsExtractionResult {
             content: val.content,
             mime_type: val.mime_type,
@@ -1258,7 +1685,16 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                     page_number: t.page_number as u32,
                 })
                 .collect(),
-            detected_languages: val.detected_languages,
+            detected_languages: val.detected_languages.map(|languages| {
+                languages
+                    .into_iter()
+                    .map(|lang| JsDetectedLanguage {
+                        language: lang.language,
+                        confidence: lang.confidence,
+                        script: lang.script,
+                    })
+                    .collect()
+            }),
             chunks: if let Some(chunks) = val.chunks {
                 let mut js_chunks = Vec::with_capacity(chunks.len());
                 for chunk in chunks {
@@ -1290,6 +1726,45 @@ impl TryFrom<RustExtractionResult> for JsExtractionResult {
                 None
             },
             images,
+            entities: if let Some(entities) = val.entities {
+                let mut js_entities = Vec::with_capacity(entities.len());
+                for entity in entities {
+                    js_entities.push(JsEntity {
+                        entity_type: entity.entity_type.as_str().to_string(),
+                        text: entity.text,
+                        byte_start: usize_to_u32(entity.byte_start, "entities[].byte_start")?,
+                        byte_end: usize_to_u32(entity.byte_end, "entities[].byte_end")?,
+                        confidence: entity.confidence,
+                    });
+                }
+                Some(js_entities)
+            } else {
+                None
+            },
+            keywords: if let Some(keywords) = val.keywords {
+                let mut js_keywords = Vec::with_capacity(keywords.len());
+                for keyword in keywords {
+                    let positions = keyword
+                        .positions
+                        .map(|positions| {
+                            positions
+                                .into_iter()
+                                .map(|pos| usize_to_u32(pos, "keywords[].positions"))
+                                .collect::<Result<Vec<_>>>()
+                        })
+                        .transpose()?;
+                    js_keywords.push(JsKeyword {
+                        text: keyword.text,
+                        score: keyword.score as f64,
+                        algorithm: keyword_algorithm_to_string(keyword.algorithm).to_string(),
+                        positions,
+                    });
+                }
+                Some(js_keywords)
+            } else {
+                None
+            },
+            warnings: Some(warnings),
         })
     }
 }
@@ -1314,6 +1789,12 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
             let subject = metadata_map
                 .remove("subject")
                 .and_then(|v| serde_json::from_value(v).ok());
+            let publisher = metadata_map
+                .remove("publisher")
+                .and_then(|v| serde_json::from_value(v).ok());
+            let identifier = metadata_map
+                .remove("identifier")
+                .and_then(|v| serde_json::from_value(v).ok());
             let image_preprocessing = metadata_map
                 .remove("image_preprocessing")
                 .and_then(|v| serde_json::from_value(v).ok());
@@ -1321,6 +1802,19 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
             let error = metadata_map
                 .remove("error")
                 .and_then(|v| serde_json::from_value(v).ok());
+            let stats = metadata_map
+                .remove("stats")
+                .and_then(|v| serde_json::from_value(v).ok());
+            let timings = metadata_map
+                .remove("timings")
+                .and_then(|v| serde_json::from_value(v).ok());
+            let provenance = metadata_map
+                .remove("provenance")
+                .and_then(|v| serde_json::from_value(v).ok());
+            let text_metrics = metadata_map
+                .remove("text_metrics")
+                .and_then(|v| serde_json::from_value(v).ok());
+            let tags = metadata_map.remove("tags").and_then(|v| serde_json::from_value(v).ok());
 
             let known_format_fields: std::collections::HashSet<&str> = [
                 "format_type",
@@ -1340,7 +1834,17 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                 "cc_emails",
                 "bcc_emails",
                 "message_id",
+                "reply_to_emails",
+                "in_reply_to",
+                "references",
+                "thread_id",
+                "has_dkim_signature",
+                "raw_headers",
+                "calendar_events",
                 "attachments",
+                "quoted_text",
+                "signature",
+                "tracking_pixels",
                 "description",
                 "summary",
                 "fonts",
@@ -1353,12 +1857,14 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                 "height",
                 "element_count",
                 "unique_elements",
+                "document_type",
                 "line_count",
                 "word_count",
                 "character_count",
                 "headers",
                 "links",
                 "code_blocks",
+                "encoding",
                 "canonical",
                 "base_href",
                 "og_title",
@@ -1376,11 +1882,24 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                 "link_author",
                 "link_license",
                 "link_alternate",
+                "images",
                 "psm",
                 "output_format",
                 "table_count",
                 "table_rows",
                 "table_cols",
+                "application",
+                "template",
+                "organization",
+                "initial_creator",
+                "revision",
+                "category",
+                "content_status",
+                "total_editing_time_minutes",
+                "editing_duration",
+                "editing_cycles",
+                "paragraph_count",
+                "image_count",
             ]
             .iter()
             .copied()
@@ -1401,17 +1920,26 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
 
             let additional = metadata_map;
 
-            kreuzberg::Metadata {
+            let mut metadata = kreuzberg::Metadata {
                 language,
                 date,
                 subject,
+                publisher,
+                identifier,
                 format,
                 image_preprocessing,
                 json_schema,
                 error,
+                stats,
+                timings,
+                provenance,
+                text_metrics,
+                tags,
                 additional,
                 ..Default::default()
-            }
+            };
+            metadata.promote_common_fields();
+            metadata
         };
 
         let images = if let Some(imgs) = val.images {
@@ -1439,6 +1967,7 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                     page_number: img.page_number.map(|p| p as usize),
                     width: img.width,
                     height: img.height,
+                    dpi: img.dpi,
                     colorspace: img.colorspace,
                     bits_per_component: img.bits_per_component,
                     is_mask: img.is_mask,
@@ -1508,10 +2037,69 @@ impl TryFrom<JsExtractionResult> for RustExtractionResult {
                     page_number: t.page_number as usize,
                 })
                 .collect(),
-            detected_languages: val.detected_languages,
+            detected_languages: val.detected_languages.map(|languages| {
+                languages
+                    .into_iter()
+                    .map(|lang| kreuzberg::DetectedLanguage {
+                        language: lang.language,
+                        confidence: lang.confidence,
+                        script: lang.script,
+                    })
+                    .collect()
+            }),
             chunks,
             images,
             pages: None,
+            entities: val
+                .entities
+                .map(|entities| {
+                    entities
+                        .into_iter()
+                        .map(|entity| {
+                            Ok(kreuzberg::Entity {
+                                entity_type: parse_entity_type(&entity.entity_type)?,
+                                text: entity.text,
+                                byte_start: entity.byte_start as usize,
+                                byte_end: entity.byte_end as usize,
+                                confidence: entity.confidence,
+                            })
+                        })
+                        .collect::<Result<Vec<_>>>()
+                })
+                .transpose()?,
+            keywords: val
+                .keywords
+                .map(|keywords| {
+                    keywords
+                        .into_iter()
+                        .map(|keyword| {
+                            Ok(kreuzberg::Keyword {
+                                text: keyword.text,
+                                score: keyword.score as f32,
+                                algorithm: parse_keyword_algorithm(&keyword.algorithm)?,
+                                positions: keyword
+                                    .positions
+                                    .map(|positions| positions.into_iter().map(|pos| pos as usize).collect()),
+                            })
+                        })
+                        .collect::<Result<Vec<_>>>()
+                })
+                .transpose()?,
+            children: None,
+            fields: None,
+            warnings: val
+                .warnings
+                .unwrap_or_default()
+                .into_iter()
+                .map(|warning| {
+                    Ok(kreuzberg::ExtractionWarning {
+                        code: warning.code,
+                        message: warning.message,
+                        stage: parse_warning_stage(&warning.stage)?,
+                        page: warning.page.map(|page| page as usize),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?,
         })
     }
 }
@@ -1707,7 +2295,7 @@ pub async fn extract_bytes(
     {
         if std::env::var("KREUZBERG_DEBUG_GUTEN").as_deref() == Ok("1") && mime_type.starts_with("image/") {
             let header: Vec<u8> = owned_data.iter().take(8).copied().collect();
-            eprintln!("[Rust Binding] Debug input header: {:?}", header);
+            tracing::debug!("Input image header: {:?}", header);
         }
     }
 
@@ -1717,6 +2305,79 @@ pub async fn extract_bytes(
         .and_then(JsExtractionResult::try_from)
 }
 
+/// Reusable extraction session.
+///
+/// Registers the built-in extractors and post-processors, checks the configured OCR backend
+/// and resolves extractors once, so repeated extractions in high-throughput services skip
+/// that work.
+///
+/// # Example
+///
+/// ```typescript
+/// import { ExtractionSession } from '@kreuzberg/node';
+///
+/// const session = new ExtractionSession({ ocr: { backend: 'tesseract', language: 'eng' } });
+/// const result = await session.extractFile('scanned.pdf');
+/// ```
+#[napi(js_name = "ExtractionSession")]
+pub struct JsExtractionSession {
+    inner: kreuzberg::ExtractionSession,
+}
+
+#[napi]
+impl JsExtractionSession {
+    /// Create a session for the given configuration.
+    ///
+    /// Throws if the built-in plugins fail to initialize or the OCR backend is not registered.
+    #[napi(constructor)]
+    pub fn new(config: Option<JsExtractionConfig>) -> Result<Self> {
+        let rust_config = resolve_config(config)?;
+        let inner = kreuzberg::ExtractionSession::new(rust_config).map_err(convert_error)?;
+        Ok(Self { inner })
+    }
+
+    /// Extract content from a file (synchronous).
+    #[napi]
+    pub fn extract_file_sync(&self, file_path: String, mime_type: Option<String>) -> Result<JsExtractionResult> {
+        self.inner
+            .extract_file_sync(&file_path, mime_type.as_deref())
+            .map_err(convert_error)
+            .and_then(JsExtractionResult::try_from)
+    }
+
+    /// Extract content from a file (asynchronous).
+    #[napi]
+    pub async fn extract_file(&self, file_path: String, mime_type: Option<String>) -> Result<JsExtractionResult> {
+        let session = self.inner.clone();
+        session
+            .extract_file(&file_path, mime_type.as_deref())
+            .await
+            .map_err(convert_error)
+            .and_then(JsExtractionResult::try_from)
+    }
+
+    /// Extract content from bytes (synchronous).
+    #[napi]
+    pub fn extract_bytes_sync(&self, data: Buffer, mime_type: String) -> Result<JsExtractionResult> {
+        self.inner
+            .extract_bytes_sync(&data, &mime_type)
+            .map_err(convert_error)
+            .and_then(JsExtractionResult::try_from)
+    }
+
+    /// Extract content from bytes (asynchronous).
+    #[napi]
+    pub async fn extract_bytes(&self, data: Buffer, mime_type: String) -> Result<JsExtractionResult> {
+        let session = self.inner.clone();
+        let owned_data = data.to_vec();
+        session
+            .extract_bytes(&owned_data, &mime_type)
+            .await
+            .map_err(convert_error)
+            .and_then(JsExtractionResult::try_from)
+    }
+}
+
 /// Batch extract from multiple files (synchronous).
 ///
 /// Synchronously processes multiple files in parallel using Rayon. Significantly
@@ -1894,6 +2555,130 @@ pub async fn batch_extract_bytes(
         .and_then(|results| results.into_iter().map(JsExtractionResult::try_from).collect())
 }
 
+use kreuzberg::{DirectoryItem, DirectoryOptions, DirectoryStream};
+
+/// Options of `extractDirectory`.
+#[napi(object)]
+pub struct JsDirectoryOptions {
+    /// Globs a file must match to be extracted (all files if empty)
+    pub include: Option<Vec<String>>,
+    /// Globs of files and directories to skip
+    pub exclude: Option<Vec<String>>,
+    /// Descend into subdirectories (default: true)
+    pub recursive: Option<bool>,
+    /// Files extracted at once (default: `maxConcurrentExtractions` of the config, or CPUs * 2)
+    pub concurrency: Option<u32>,
+}
+
+/// One processed file of a directory extraction.
+#[napi(object)]
+pub struct JsDirectoryItem {
+    pub path: String,
+    /// `/`-separated path relative to the extracted directory
+    pub relative_path: String,
+    pub content_hash: Option<String>,
+    /// The result was reused from an earlier run
+    pub cached: bool,
+    /// Extraction result (undefined when the file failed)
+    pub result: Option<JsExtractionResult>,
+    /// Why the file failed
+    #[napi(ts_type = "ErrorInfo | undefined")]
+    pub error: Option<serde_json::Value>,
+}
+
+impl TryFrom<DirectoryItem> for JsDirectoryItem {
+    type Error = napi::Error;
+
+    fn try_from(item: DirectoryItem) -> Result<Self> {
+        let (result, error) = match item.result {
+            Ok(result) => (Some(JsExtractionResult::try_from(result)?), None),
+            Err(err) => (None, Some(serde_json::to_value(err.to_info()).unwrap_or_default())),
+        };
+        Ok(Self {
+            path: item.path.to_string_lossy().into_owned(),
+            relative_path: item.relative_path,
+            content_hash: item.content_hash,
+            cached: item.cached,
+            result,
+            error,
+        })
+    }
+}
+
+/// Results of a directory extraction, in completion order.
+///
+/// Returned by `extractDirectory`. Extraction runs in the background; `next()` resolves
+/// with each file as it completes.
+#[napi(js_name = "DirectoryExtraction")]
+pub struct JsDirectoryExtraction {
+    stream: Arc<tokio::sync::Mutex<DirectoryStream>>,
+}
+
+#[napi]
+impl JsDirectoryExtraction {
+    /// Next processed file, or `null` once every file is processed.
+    ///
+    /// Rejects when the whole run fails (e.g. the directory cannot be read); per-file
+    /// failures are reported in the item's `error`.
+    #[napi]
+    pub async fn next(&self) -> Result<Option<JsDirectoryItem>> {
+        let stream = Arc::clone(&self.stream);
+        let item = stream.lock().await.recv().await;
+        match item {
+            Some(item) => item
+                .map_err(convert_error)
+                .and_then(JsDirectoryItem::try_from)
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Extract every matching file below a directory.
+///
+/// Walks the directory, extracts matching files concurrently and yields each file through
+/// the returned `DirectoryExtraction` as soon as it is done.
+///
+/// # Parameters
+///
+/// * `path` - Directory to extract
+/// * `options` - Include/exclude globs, recursion and concurrency
+/// * `config` - Optional extraction configuration (applied to all files)
+///
+/// # Errors
+///
+/// Throws if `path` is not a directory.
+///
+/// # Example
+///
+/// ```typescript
+/// import { extractDirectory } from '@kreuzberg/node';
+///
+/// const results = await extractDirectory('corpus', { glob: ['*.pdf', '*.docx'], concurrency: 8 });
+/// ```
+#[napi]
+pub fn extract_directory(
+    path: String,
+    options: Option<JsDirectoryOptions>,
+    config: Option<JsExtractionConfig>,
+) -> Result<JsDirectoryExtraction> {
+    let mut directory_options = DirectoryOptions {
+        config: resolve_config(config)?,
+        ..Default::default()
+    };
+    if let Some(options) = options {
+        directory_options.include = options.include.unwrap_or_default();
+        directory_options.exclude = options.exclude.unwrap_or_default();
+        directory_options.recursive = options.recursive.unwrap_or(true);
+        directory_options.max_concurrency = options.concurrency.map(|value| value.max(1) as usize);
+    }
+
+    let stream = kreuzberg::extract_directory(&path, directory_options).map_err(convert_error)?;
+    Ok(JsDirectoryExtraction {
+        stream: Arc::new(tokio::sync::Mutex::new(stream)),
+    })
+}
+
 use async_trait::async_trait;
 use base64::Engine;
 use kreuzberg::plugins::{Plugin, PostProcessor as RustPostProcessor, ProcessingStage};
@@ -1951,9 +2736,9 @@ impl RustPostProcessor for JsPostProcessor {
         result: &mut kreuzberg::ExtractionResult,
         _config: &kreuzberg::ExtractionConfig,
     ) -> std::result::Result<(), kreuzberg::KreuzbergError> {
-        eprintln!("\n[POST-PROCESSOR] === Starting JS Post-Processor '{}' ===", self.name);
-        eprintln!(
-            "[POST-PROCESSOR] Original Rust metadata.additional keys: {:?}",
+        tracing::debug!(
+            "Running JavaScript PostProcessor '{}' with metadata keys {:?}",
+            self.name,
             result.metadata.additional.keys().collect::<Vec<_>>()
         );
 
@@ -1967,11 +2752,6 @@ impl RustPostProcessor for JsPostProcessor {
             plugin_name: self.name.clone(),
         })?;
 
-        eprintln!(
-            "[POST-PROCESSOR] JSON being sent to JS (first 500 chars): {}",
-            &json_input.chars().take(500).collect::<String>()
-        );
-
         let json_output = self
             .process_fn
             .call_async(json_input)
@@ -1986,11 +2766,6 @@ impl RustPostProcessor for JsPostProcessor {
                 plugin_name: self.name.clone(),
             })?;
 
-        eprintln!(
-            "[POST-PROCESSOR] JSON received from JS (first 500 chars): {}",
-            &json_output.chars().take(500).collect::<String>()
-        );
-
         let updated: JsExtractionResult =
             serde_json::from_str(&json_output).map_err(|e| kreuzberg::KreuzbergError::Plugin {
                 message: format!(
@@ -2006,11 +2781,11 @@ impl RustPostProcessor for JsPostProcessor {
                 plugin_name: self.name.clone(),
             })?;
 
-        eprintln!(
-            "[POST-PROCESSOR] Final Rust metadata.additional keys after conversion: {:?}",
+        tracing::debug!(
+            "JavaScript PostProcessor '{}' returned metadata keys {:?}",
+            self.name,
             rust_result.metadata.additional.keys().collect::<Vec<_>>()
         );
-        eprintln!("[POST-PROCESSOR] === Completed JS Post-Processor '{}' ===\n", self.name);
 
         *result = rust_result;
         Ok(())
@@ -2458,7 +3233,7 @@ impl RustOcrBackend for JsOcrBackend {
         {
             if std::env::var("KREUZBERG_DEBUG_GUTEN").as_deref() == Ok("1") {
                 let header: Vec<u8> = image_bytes.iter().take(8).copied().collect();
-                eprintln!("[Rust OCR] Debug input header: {:?}", header);
+                tracing::debug!("OCR input image header: {:?}", header);
             }
         }
         let encoded = base64::engine::general_purpose::STANDARD.encode(image_bytes);
@@ -2539,6 +3314,11 @@ impl RustOcrBackend for JsOcrBackend {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -2865,6 +3645,40 @@ pub fn detect_mime_type_from_path(path: String, check_exists: Option<bool>) -> R
     kreuzberg::core::mime::detect_mime_type(&path, check_exists.unwrap_or(true)).map_err(convert_error)
 }
 
+/// Thread pool sizing for CPU-bound extraction work.
+#[napi(object)]
+pub struct JsThreadPoolConfig {
+    pub rayon_threads: Option<u32>,
+    pub worker_threads: Option<u32>,
+    pub max_blocking_threads: Option<u32>,
+}
+
+/// Configure the thread pools used for CPU-bound extraction work.
+///
+/// Extraction and OCR run on Kreuzberg's own pools instead of libuv's thread pool, so heavy
+/// batch loads don't starve the Node.js process. Must be called before the first extraction.
+///
+/// # Errors
+///
+/// Throws an error if a value is zero or the pools are already initialized.
+///
+/// # Example
+///
+/// ```typescript
+/// import { configureThreadPools } from '@kreuzberg/node';
+///
+/// configureThreadPools({ rayonThreads: 4, maxBlockingThreads: 8 });
+/// ```
+#[napi]
+pub fn configure_thread_pools(config: JsThreadPoolConfig) -> Result<()> {
+    kreuzberg::configure_thread_pools(kreuzberg::ThreadPoolConfig {
+        rayon_threads: config.rayon_threads.map(|v| v as usize),
+        worker_threads: config.worker_threads.map(|v| v as usize),
+        max_blocking_threads: config.max_blocking_threads.map(|v| v as usize),
+    })
+    .map_err(convert_error)
+}
+
 /// Validate that a MIME type is supported by Kreuzberg.
 ///
 /// Checks if a MIME type is in the list of supported formats. Note that any
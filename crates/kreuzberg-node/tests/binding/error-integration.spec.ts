@@ -1,5 +1,13 @@
 import { describe, expect, it } from "vitest";
-import { CacheError, extractFile, ImageProcessingError, KreuzbergError, PluginError } from "../../dist/index.js";
+import {
+	CacheError,
+	extractBytesSync,
+	extractFile,
+	ImageProcessingError,
+	KreuzbergError,
+	PluginError,
+	ValidationError,
+} from "../../dist/index.js";
 
 /**
  * Integration tests for error handling in real-world scenarios.
@@ -163,4 +171,19 @@ describe("Error Integration", () => {
 			expect(report?.message).toContain("my-custom-plugin");
 		});
 	});
+
+	describe("Structured native errors", () => {
+		it("should expose the error code of native errors", () => {
+			try {
+				extractBytesSync(Buffer.from("content"), "invalid/type");
+				expect.fail("Should have thrown an error");
+			} catch (error) {
+				expect(error).toBeInstanceOf(ValidationError);
+				const kreuzbergError = error as ValidationError;
+				expect(kreuzbergError.code).toBe("unsupported_format");
+				expect(kreuzbergError.retryable).toBe(false);
+				expect(kreuzbergError.message).toContain("Unsupported format");
+			}
+		});
+	});
 });
@@ -0,0 +1,61 @@
+import { mkdirSync, mkdtempSync, rmSync, writeFileSync } from "node:fs";
+import { tmpdir } from "node:os";
+import { join } from "node:path";
+import { afterAll, beforeAll, describe, expect, it } from "vitest";
+import { type DirectoryProgress, extractDirectory, extractDirectoryIter } from "../../dist/index.js";
+
+let corpus: string;
+
+beforeAll(() => {
+	corpus = mkdtempSync(join(tmpdir(), "kreuzberg-directory-"));
+	mkdirSync(join(corpus, "nested"));
+	writeFileSync(join(corpus, "a.txt"), "first document");
+	writeFileSync(join(corpus, "b.md"), "# second document");
+	writeFileSync(join(corpus, "nested", "c.txt"), "third document");
+});
+
+afterAll(() => {
+	rmSync(corpus, { recursive: true, force: true });
+});
+
+describe("extractDirectory", () => {
+	it("should extract every file recursively", async () => {
+		const items = await extractDirectory(corpus);
+
+		const paths = items.map((item) => item.relativePath).sort();
+		expect(paths).toEqual(["a.txt", "b.md", "nested/c.txt"]);
+		for (const item of items) {
+			expect(item.error).toBeNull();
+			expect(item.result?.content).toContain("document");
+		}
+	});
+
+	it("should apply globs and recursion", async () => {
+		const items = await extractDirectory(corpus, { glob: "*.txt", recursive: false });
+
+		expect(items.map((item) => item.relativePath)).toEqual(["a.txt"]);
+	});
+
+	it("should report progress", async () => {
+		const progress: DirectoryProgress[] = [];
+		await extractDirectory(corpus, { concurrency: 1, onProgress: (update) => progress.push(update) });
+
+		expect(progress.map((update) => update.processed)).toEqual([1, 2, 3]);
+		expect(progress.at(-1)?.failed).toBe(0);
+	});
+
+	it("should reject paths that are not directories", async () => {
+		await expect(extractDirectory(join(corpus, "a.txt"))).rejects.toThrow();
+	});
+});
+
+describe("extractDirectoryIter", () => {
+	it("should yield items as they complete", async () => {
+		const paths: string[] = [];
+		for await (const item of extractDirectoryIter(corpus, { exclude: "nested" })) {
+			paths.push(item.relativePath);
+		}
+
+		expect(paths.sort()).toEqual(["a.txt", "b.md"]);
+	});
+});
@@ -120,6 +120,56 @@ export interface PanicContext {
 	timestamp_secs: number;
 }
 
+/**
+ * Stable error codes reported by the native library.
+ *
+ * Codes are never renamed, so they are safe to branch on; new codes may be added.
+ */
+export type KreuzbergErrorCode =
+	| "io"
+	| "parsing"
+	| "ocr"
+	| "validation"
+	| "cache"
+	| "image_processing"
+	| "serialization"
+	| "missing_dependency"
+	| "plugin"
+	| "lock_poisoned"
+	| "unsupported_format"
+	| "limit_exceeded"
+	| "encrypted_document"
+	| "other";
+
+/**
+ * Machine-readable description of an error raised by the native library.
+ *
+ * The same shape is used by the API server, the MCP server, the CLI's JSON output
+ * and every language binding.
+ */
+export interface ErrorInfo {
+	/**
+	 * Stable error code
+	 */
+	code: KreuzbergErrorCode;
+	/**
+	 * Human-readable error message
+	 */
+	message: string;
+	/**
+	 * Pipeline stage the error belongs to (`mime_detection`, `extraction`, `ocr` or `cache`), if known
+	 */
+	stage: string | null;
+	/**
+	 * Name of the failing plugin, for plugin errors
+	 */
+	plugin: string | null;
+	/**
+	 * Whether retrying the same operation may succeed
+	 */
+	retryable: boolean;
+}
+
 /**
  * Base error class for all Kreuzberg errors.
  *
@@ -151,6 +201,26 @@ export class KreuzbergError extends Error {
 	 */
 	public readonly panicContext: PanicContext | null;
 
+	/**
+	 * Stable error code for errors raised by the native library, `null` otherwise.
+	 */
+	public readonly code: KreuzbergErrorCode | null = null;
+
+	/**
+	 * Pipeline stage the error belongs to, if known.
+	 */
+	public readonly stage: string | null = null;
+
+	/**
+	 * Name of the failing plugin, for plugin errors.
+	 */
+	public readonly plugin: string | null = null;
+
+	/**
+	 * Whether retrying the same operation may succeed.
+	 */
+	public readonly retryable: boolean = false;
+
 	constructor(message: string, panicContext?: PanicContext | null) {
 		super(message);
 		this.name = "KreuzbergError";
@@ -162,6 +232,10 @@ export class KreuzbergError extends Error {
 		return {
 			name: this.name,
 			message: this.message,
+			code: this.code,
+			stage: this.stage,
+			plugin: this.plugin,
+			retryable: this.retryable,
 			panicContext: this.panicContext,
 			stack: this.stack,
 		};
@@ -370,6 +444,10 @@ export class PluginError extends KreuzbergError {
 			name: this.name,
 			message: this.message,
 			pluginName: this.pluginName,
+			code: this.code,
+			stage: this.stage,
+			plugin: this.plugin,
+			retryable: this.retryable,
 			panicContext: this.panicContext,
 			stack: this.stack,
 		};
@@ -406,3 +484,73 @@ export class MissingDependencyError extends KreuzbergError {
 		Object.setPrototypeOf(this, MissingDependencyError.prototype);
 	}
 }
+
+function parseErrorInfo(error: unknown): ErrorInfo | null {
+	if (!(error instanceof Error)) {
+		return null;
+	}
+
+	try {
+		const parsed = JSON.parse(error.message);
+		if (parsed && typeof parsed.code === "string" && typeof parsed.message === "string") {
+			return parsed as ErrorInfo;
+		}
+	} catch {
+		// Not a structured error from the native library
+	}
+	return null;
+}
+
+/**
+ * Convert an error thrown by the native binding into the matching `KreuzbergError` subclass.
+ *
+ * The native binding reports errors as JSON-encoded {@link ErrorInfo}; any other error is
+ * returned unchanged.
+ *
+ * @internal
+ */
+export function fromNativeError(error: unknown): unknown {
+	const info = parseErrorInfo(error);
+	if (!info) {
+		return error;
+	}
+
+	let converted: KreuzbergError;
+	switch (info.code) {
+		case "validation":
+		case "unsupported_format":
+		case "limit_exceeded":
+			converted = new ValidationError(info.message);
+			break;
+		case "parsing":
+		case "serialization":
+		case "encrypted_document":
+			converted = new ParsingError(info.message);
+			break;
+		case "ocr":
+			converted = new OcrError(info.message);
+			break;
+		case "cache":
+			converted = new CacheError(info.message);
+			break;
+		case "image_processing":
+			converted = new ImageProcessingError(info.message);
+			break;
+		case "plugin":
+			converted = new PluginError(info.message, info.plugin ?? "unknown");
+			break;
+		case "missing_dependency":
+			converted = new MissingDependencyError(info.message);
+			break;
+		default:
+			converted = new KreuzbergError(info.message);
+	}
+
+	converted.message = info.message;
+	return Object.assign(converted, {
+		code: info.code,
+		stage: info.stage,
+		plugin: info.plugin,
+		retryable: info.retryable,
+	});
+}
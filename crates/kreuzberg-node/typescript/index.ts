@@ -46,26 +46,37 @@
  */
 
 import { createRequire } from "node:module";
-import type { PanicContext } from "./errors.js";
+import { fromNativeError, type PanicContext } from "./errors.js";
 import type {
 	Chunk,
 	ChunkingConfig,
+	DetectedLanguage,
+	DirectoryExtractionOptions,
+	DirectoryItem,
+	EntityConfig,
 	ExtractedImage,
 	ExtractionConfig as ExtractionConfigType,
 	ExtractionResult,
+	HeaderFooterConfig,
 	HtmlConversionOptions,
 	HtmlPreprocessingOptions,
 	ImageExtractionConfig,
 	KeywordConfig,
 	LanguageDetectionConfig,
+	LineRepairConfig,
 	OcrBackendProtocol,
 	OcrConfig,
 	PageConfig,
 	PdfConfig,
 	PostProcessorConfig,
 	PostProcessorProtocol,
+	QualityGateConfig,
+	StopwordsConfig,
 	Table,
 	TesseractConfig,
+	TextMetricsConfig,
+	TextNormalizationConfig,
+	ThreadPoolConfig,
 	TokenReductionConfig,
 	ValidatorProtocol,
 } from "./types.js";
@@ -73,8 +84,10 @@ import type {
 export {
 	CacheError,
 	ErrorCode,
+	type ErrorInfo,
 	ImageProcessingError,
 	KreuzbergError,
+	type KreuzbergErrorCode,
 	MissingDependencyError,
 	OcrError,
 	type PanicContext,
@@ -172,7 +185,58 @@ function loadNativeBinding(): any {
 		throw new Error("Unable to resolve native binding loader (require not available).");
 	}
 
-	return localRequire("../index.js");
+	return withStructuredErrors(localRequire("../index.js"));
+}
+
+/**
+ * Wrap a native object (the binding itself or an instance of one of its classes) so that
+ * errors its functions throw or reject with become `KreuzbergError` subclasses carrying the
+ * structured error info. Functions are always invoked with the native object as `this`.
+ */
+// biome-ignore lint/suspicious/noExplicitAny: NAPI binding type is dynamically loaded
+function withStructuredErrors(native: any): any {
+	const wrappers = new Map<PropertyKey, unknown>();
+
+	return new Proxy(native, {
+		get(target, property) {
+			const value = Reflect.get(target, property);
+			if (typeof value !== "function") {
+				return value;
+			}
+
+			let wrapper = wrappers.get(property);
+			if (wrapper === undefined) {
+				wrapper = wrapNativeFunction(value, target);
+				wrappers.set(property, wrapper);
+			}
+			return wrapper;
+		},
+	});
+}
+
+// biome-ignore lint/complexity/noBannedTypes: wraps arbitrary native functions and classes
+function wrapNativeFunction(fn: Function, owner: unknown): Function {
+	const rethrow = (error: unknown): never => {
+		throw fromNativeError(error);
+	};
+
+	return new Proxy(fn, {
+		apply(target, _thisArg, args) {
+			try {
+				const result = Reflect.apply(target, owner, args);
+				return result instanceof Promise ? result.catch(rethrow) : result;
+			} catch (error) {
+				return rethrow(error);
+			}
+		},
+		construct(target, args) {
+			try {
+				return withStructuredErrors(Reflect.construct(target, args));
+			} catch (error) {
+				return rethrow(error);
+			}
+		},
+	});
 }
 
 // biome-ignore lint/suspicious/noExplicitAny: NAPI binding type is dynamically loaded
@@ -260,6 +324,7 @@ function convertImage(rawImage: any): ExtractedImage {
 			pageNumber: null,
 			width: null,
 			height: null,
+			dpi: null,
 			colorspace: null,
 			bitsPerComponent: null,
 			isMask: false,
@@ -275,6 +340,7 @@ function convertImage(rawImage: any): ExtractedImage {
 		pageNumber: rawImage.pageNumber ?? null,
 		width: rawImage.width ?? null,
 		height: rawImage.height ?? null,
+		dpi: rawImage.dpi ?? null,
 		colorspace: rawImage.colorspace ?? null,
 		bitsPerComponent: rawImage.bitsPerComponent ?? null,
 		isMask: rawImage.isMask ?? false,
@@ -297,6 +363,9 @@ function convertResult(rawResult: any): ExtractionResult {
 		images: Array.isArray(rawResult.images)
 			? (rawResult.images as unknown[]).map((image) => convertImage(image))
 			: null,
+		entities: Array.isArray(rawResult.entities) ? rawResult.entities : null,
+		keywords: Array.isArray(rawResult.keywords) ? rawResult.keywords : null,
+		warnings: Array.isArray(rawResult.warnings) ? rawResult.warnings : [],
 	};
 }
 
@@ -317,6 +386,7 @@ function normalizeTesseractConfig(config?: TesseractConfig) {
 	setIfDefined(normalized, "psm", config.psm);
 	setIfDefined(normalized, "enableTableDetection", config.enableTableDetection);
 	setIfDefined(normalized, "tesseditCharWhitelist", config.tesseditCharWhitelist);
+	setIfDefined(normalized, "preprocessing", config.preprocessing);
 	return normalized;
 }
 
@@ -364,6 +434,8 @@ function normalizeImageExtractionConfig(images?: ImageExtractionConfig): NativeE
 	setIfDefined(normalized, "autoAdjustDpi", images.autoAdjustDpi);
 	setIfDefined(normalized, "minDpi", images.minDpi);
 	setIfDefined(normalized, "maxDpi", images.maxDpi);
+	setIfDefined(normalized, "describer", images.describer);
+	setIfDefined(normalized, "inlineDescriptions", images.inlineDescriptions);
 	return normalized;
 }
 
@@ -387,6 +459,20 @@ function normalizeTokenReductionConfig(tokenReduction?: TokenReductionConfig): N
 	const normalized: NativeExtractionConfig = {};
 	setIfDefined(normalized, "mode", tokenReduction.mode);
 	setIfDefined(normalized, "preserveImportantWords", tokenReduction.preserveImportantWords);
+	setIfDefined(normalized, "stopwords", normalizeStopwordsConfig(tokenReduction.stopwords));
+	setIfDefined(normalized, "targetRatio", tokenReduction.targetRatio);
+	setIfDefined(normalized, "maxTokens", tokenReduction.maxTokens);
+	return normalized;
+}
+
+function normalizeStopwordsConfig(stopwords?: StopwordsConfig): NativeExtractionConfig | undefined {
+	if (!stopwords) {
+		return undefined;
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "custom", stopwords.custom);
+	setIfDefined(normalized, "mode", stopwords.mode);
 	return normalized;
 }
 
@@ -401,6 +487,7 @@ function normalizeLanguageDetectionConfig(
 	setIfDefined(normalized, "enabled", languageDetection.enabled);
 	setIfDefined(normalized, "minConfidence", languageDetection.minConfidence);
 	setIfDefined(normalized, "detectMultiple", languageDetection.detectMultiple);
+	setIfDefined(normalized, "maxSampleKb", languageDetection.maxSampleKb);
 	return normalized;
 }
 
@@ -413,6 +500,75 @@ function normalizePostProcessorConfig(postprocessor?: PostProcessorConfig): Nati
 	setIfDefined(normalized, "enabled", postprocessor.enabled);
 	setIfDefined(normalized, "enabledProcessors", postprocessor.enabledProcessors);
 	setIfDefined(normalized, "disabledProcessors", postprocessor.disabledProcessors);
+	setIfDefined(normalized, "headerFooter", normalizeHeaderFooterConfig(postprocessor.headerFooter));
+	setIfDefined(normalized, "lineRepair", normalizeLineRepairConfig(postprocessor.lineRepair));
+	setIfDefined(normalized, "normalization", normalizeTextNormalizationConfig(postprocessor.normalization));
+	setIfDefined(normalized, "metrics", normalizeTextMetricsConfig(postprocessor.metrics));
+	setIfDefined(normalized, "taggers", postprocessor.taggers);
+	return normalized;
+}
+
+function normalizeHeaderFooterConfig(headerFooter?: HeaderFooterConfig | boolean): NativeExtractionConfig | undefined {
+	if (headerFooter === undefined || headerFooter === false) {
+		return undefined;
+	}
+	if (headerFooter === true) {
+		return {};
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "linesToCheck", headerFooter.linesToCheck);
+	setIfDefined(normalized, "minPages", headerFooter.minPages);
+	setIfDefined(normalized, "minRepeatRatio", headerFooter.minRepeatRatio);
+	setIfDefined(normalized, "removePageNumbers", headerFooter.removePageNumbers);
+	return normalized;
+}
+
+function normalizeLineRepairConfig(lineRepair?: LineRepairConfig | boolean): NativeExtractionConfig | undefined {
+	if (lineRepair === undefined || lineRepair === false) {
+		return undefined;
+	}
+	if (lineRepair === true) {
+		return {};
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "dehyphenate", lineRepair.dehyphenate);
+	setIfDefined(normalized, "mergeLines", lineRepair.mergeLines);
+	setIfDefined(normalized, "language", lineRepair.language);
+	return normalized;
+}
+
+function normalizeTextNormalizationConfig(
+	normalization?: TextNormalizationConfig | boolean,
+): NativeExtractionConfig | undefined {
+	if (normalization === undefined || normalization === false) {
+		return undefined;
+	}
+	if (normalization === true) {
+		return {};
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "form", normalization.form);
+	setIfDefined(normalized, "removeZeroWidth", normalization.removeZeroWidth);
+	setIfDefined(normalized, "removeBidiControls", normalization.removeBidiControls);
+	setIfDefined(normalized, "removeControlChars", normalization.removeControlChars);
+	setIfDefined(normalized, "canonicalizeQuotes", normalization.canonicalizeQuotes);
+	setIfDefined(normalized, "canonicalizeDashes", normalization.canonicalizeDashes);
+	return normalized;
+}
+
+function normalizeTextMetricsConfig(metrics?: TextMetricsConfig | boolean): NativeExtractionConfig | undefined {
+	if (metrics === undefined || metrics === false) {
+		return undefined;
+	}
+	if (metrics === true) {
+		return {};
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "longWordLength", metrics.longWordLength);
 	return normalized;
 }
 
@@ -483,11 +639,38 @@ function normalizeKeywordConfig(config?: KeywordConfig): NativeExtractionConfig
 	setIfDefined(normalized, "minScore", config.minScore);
 	setIfDefined(normalized, "ngramRange", config.ngramRange);
 	setIfDefined(normalized, "language", config.language);
+	setIfDefined(normalized, "stopwords", normalizeStopwordsConfig(config.stopwords));
 	setIfDefined(normalized, "yakeParams", config.yakeParams);
 	setIfDefined(normalized, "rakeParams", config.rakeParams);
 	return normalized;
 }
 
+function normalizeEntityConfig(entities?: EntityConfig): NativeExtractionConfig | undefined {
+	if (!entities) {
+		return undefined;
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "entityTypes", entities.entityTypes);
+	setIfDefined(normalized, "minConfidence", entities.minConfidence);
+	return normalized;
+}
+
+function normalizeQualityGateConfig(qualityGate?: QualityGateConfig): NativeExtractionConfig | undefined {
+	if (!qualityGate) {
+		return undefined;
+	}
+
+	const normalized: NativeExtractionConfig = {};
+	setIfDefined(normalized, "minDictionaryHitRate", qualityGate.minDictionaryHitRate);
+	setIfDefined(normalized, "minConfusionScore", qualityGate.minConfusionScore);
+	setIfDefined(normalized, "minWords", qualityGate.minWords);
+	setIfDefined(normalized, "ocrOnly", qualityGate.ocrOnly);
+	setIfDefined(normalized, "action", qualityGate.action);
+	setIfDefined(normalized, "language", qualityGate.language);
+	return normalized;
+}
+
 function normalizePageConfig(pages?: PageConfig): NativeExtractionConfig | undefined {
 	if (!pages) {
 		return undefined;
@@ -509,6 +692,9 @@ function normalizeExtractionConfig(config: ExtractionConfigType | null): NativeE
 	setIfDefined(normalized, "useCache", config.useCache);
 	setIfDefined(normalized, "enableQualityProcessing", config.enableQualityProcessing);
 	setIfDefined(normalized, "forceOcr", config.forceOcr);
+	setIfDefined(normalized, "collectTimings", config.collectTimings);
+	setIfDefined(normalized, "strictMime", config.strictMime);
+	setIfDefined(normalized, "strict", config.strict);
 	setIfDefined(normalized, "maxConcurrentExtractions", config.maxConcurrentExtractions);
 
 	const ocr = normalizeOcrConfig(config.ocr);
@@ -538,6 +724,12 @@ function normalizeExtractionConfig(config: ExtractionConfigType | null): NativeE
 	const pages = normalizePageConfig(config.pages);
 	setIfDefined(normalized, "pages", pages);
 
+	const entities = normalizeEntityConfig(config.entities);
+	setIfDefined(normalized, "entities", entities);
+
+	const qualityGate = normalizeQualityGateConfig(config.qualityGate);
+	setIfDefined(normalized, "qualityGate", qualityGate);
+
 	const htmlOptions = normalizeHtmlOptions(config.htmlOptions);
 	setIfDefined(normalized, "htmlOptions", htmlOptions);
 
@@ -695,6 +887,55 @@ export async function extractBytes(
 	return convertResult(rawResult);
 }
 
+/**
+ * Reusable extraction session.
+ *
+ * Initializes the extractor registry, post-processors and OCR backend once and reuses them
+ * for every call, avoiding per-document initialization in high-throughput services.
+ *
+ * @example
+ * ```typescript
+ * import { ExtractionSession } from '@kreuzberg/node';
+ *
+ * const session = new ExtractionSession({ ocr: { backend: 'tesseract', language: 'eng' } });
+ * for (const path of ['a.pdf', 'b.docx']) {
+ *   const result = await session.extractFile(path);
+ *   console.log(result.content);
+ * }
+ * ```
+ */
+export class ExtractionSession {
+	// biome-ignore lint/suspicious/noExplicitAny: native class handle
+	private readonly inner: any;
+
+	/**
+	 * @param config - Extraction configuration used for every call (uses defaults if null)
+	 * @throws {Error} If plugin initialization fails or the OCR backend is not registered
+	 */
+	constructor(config: ExtractionConfigType | null = null) {
+		const binding = getBinding();
+		this.inner = new binding.ExtractionSession(normalizeExtractionConfig(config));
+	}
+
+	extractFileSync(filePath: string, mimeType: string | null = null): ExtractionResult {
+		return convertResult(this.inner.extractFileSync(filePath, mimeType));
+	}
+
+	async extractFile(filePath: string, mimeType: string | null = null): Promise<ExtractionResult> {
+		return convertResult(await this.inner.extractFile(filePath, mimeType));
+	}
+
+	extractBytesSync(data: Uint8Array, mimeType: string): ExtractionResult {
+		const validated = assertUint8Array(data, "data");
+		return convertResult(this.inner.extractBytesSync(Buffer.from(validated), mimeType));
+	}
+
+	async extractBytes(data: Uint8Array, mimeType: string): Promise<ExtractionResult> {
+		const validated = assertUint8Array(data, "data");
+		return convertResult(await this.inner.extractBytes(Buffer.from(validated), mimeType));
+	}
+}
+
 /**
  * Extract content from multiple files in parallel (synchronous).
  *
@@ -865,6 +1106,110 @@ export async function batchExtractBytes(
 	return rawResults.map(convertResult);
 }
 
+function toGlobList(value: string | string[] | undefined): string[] | undefined {
+	if (value === undefined) {
+		return undefined;
+	}
+	return Array.isArray(value) ? value : [value];
+}
+
+/**
+ * Extract every matching file below a directory, yielding each file as it completes.
+ *
+ * Files are extracted concurrently in Rust, so results arrive in completion order rather
+ * than path order. A failed file does not stop the run; its `error` is set instead.
+ * Breaking out of the loop early leaves the files not yet extracted unprocessed.
+ *
+ * @param dirPath - Directory to extract
+ * @param options - Globs, recursion, concurrency, extraction config and progress callback
+ * @returns Async iterator over the processed files
+ * @throws {ValidationError} If `dirPath` is not a directory
+ *
+ * @example
+ * ```typescript
+ * import { extractDirectoryIter } from '@kreuzberg/node';
+ *
+ * for await (const item of extractDirectoryIter('corpus', { glob: '*.pdf' })) {
+ *   if (item.result) {
+ *     await index(item.relativePath, item.result.content);
+ *   } else {
+ *     console.error(item.relativePath, item.error?.message);
+ *   }
+ * }
+ * ```
+ */
+export async function* extractDirectoryIter(
+	dirPath: string,
+	options: DirectoryExtractionOptions = {},
+): AsyncGenerator<DirectoryItem, void, undefined> {
+	const nativeOptions = {
+		include: toGlobList(options.glob),
+		exclude: toGlobList(options.exclude),
+		recursive: options.recursive,
+		concurrency: options.concurrency,
+	};
+	const normalizedConfig = normalizeExtractionConfig(options.config ?? null);
+	const extraction = withStructuredErrors(getBinding().extractDirectory(dirPath, nativeOptions, normalizedConfig));
+
+	let processed = 0;
+	let failed = 0;
+	while (true) {
+		const rawItem = await extraction.next();
+		if (rawItem === null || rawItem === undefined) {
+			return;
+		}
+
+		const item: DirectoryItem = {
+			path: rawItem.path,
+			relativePath: rawItem.relativePath,
+			contentHash: rawItem.contentHash ?? null,
+			cached: rawItem.cached,
+			result: rawItem.result ? convertResult(rawItem.result) : null,
+			error: rawItem.error ?? null,
+		};
+		processed += 1;
+		if (item.error) {
+			failed += 1;
+		}
+		options.onProgress?.({ item, processed, failed });
+		yield item;
+	}
+}
+
+/**
+ * Extract every matching file below a directory.
+ *
+ * Collects the items of {@link extractDirectoryIter}. Use the iterator instead for large
+ * corpora, so results can be processed and released as they arrive.
+ *
+ * @param dirPath - Directory to extract
+ * @param options - Globs, recursion, concurrency, extraction config and progress callback
+ * @returns Promise resolving to one item per processed file, in completion order
+ * @throws {ValidationError} If `dirPath` is not a directory
+ *
+ * @example
+ * ```typescript
+ * import { extractDirectory } from '@kreuzberg/node';
+ *
+ * const items = await extractDirectory('corpus', {
+ *   glob: ['*.pdf', '*.docx'],
+ *   exclude: 'archive',
+ *   concurrency: 8,
+ *   onProgress: ({ processed, failed }) => console.log(`${processed} done, ${failed} failed`),
+ * });
+ * ```
+ */
+export async function extractDirectory(
+	dirPath: string,
+	options: DirectoryExtractionOptions = {},
+): Promise<DirectoryItem[]> {
+	const items: DirectoryItem[] = [];
+	for await (const item of extractDirectoryIter(dirPath, options)) {
+		items.push(item);
+	}
+	return items;
+}
+
 /**
  * Register a custom postprocessor.
  *
@@ -919,7 +1264,7 @@ export function registerPostProcessor(processor: PostProcessorProtocol): void {
 				mime_type: string;
 				metadata: string | Record<string, unknown>;
 				tables?: unknown[];
-				detected_languages?: string[];
+				detected_languages?: DetectedLanguage[];
 				chunks?: unknown[];
 				images?: unknown[];
 			};
@@ -1754,4 +2099,26 @@ export function getLastPanicContext(): PanicContext | null {
 	return binding.getLastPanicContext();
 }
 
+/**
+ * Configure the thread pools used for CPU-bound extraction work.
+ *
+ * Extraction and OCR run on Kreuzberg's own pools instead of libuv's thread pool, so heavy
+ * batch loads don't starve the Node.js process. Must be called before the first extraction.
+ *
+ * @param config - Pool sizes; omitted values use the defaults
+ *
+ * @throws {Error} If a value is zero or the pools are already initialized
+ *
+ * @example
+ * ```typescript
+ * import { configureThreadPools } from '@kreuzberg/node';
+ *
+ * configureThreadPools({ rayonThreads: 4, maxBlockingThreads: 8 });
+ * ```
+ */
+export function configureThreadPools(config: ThreadPoolConfig): void {
+	const binding = getBinding();
+	binding.configureThreadPools(config);
+}
+
 export const __version__ = "4.0.0-rc.7";
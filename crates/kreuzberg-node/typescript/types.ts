@@ -5,13 +5,30 @@
  * providing type safety for TypeScript users.
  */
 
+import type { ErrorInfo } from "./errors.js";
+
 // ============================================================================
 // ============================================================================
 
+export interface ImagePreprocessingConfig {
+	targetDpi?: number;
+	autoRotate?: boolean;
+	deskew?: boolean;
+	/** Convert to grayscale, implied by every other enhancement step */
+	grayscale?: boolean;
+	denoise?: boolean;
+	contrastEnhance?: boolean;
+	binarizationMethod?: "otsu" | "sauvola" | "adaptive" | "none";
+	invertColors?: boolean;
+	/** Whiten the dark borders scanners leave around the page */
+	removeBorders?: boolean;
+}
+
 export interface TesseractConfig {
 	psm?: number;
 	enableTableDetection?: boolean;
 	tesseditCharWhitelist?: string;
+	preprocessing?: ImagePreprocessingConfig;
 }
 
 export interface OcrConfig {
@@ -34,17 +51,45 @@ export interface LanguageDetectionConfig {
 	enabled?: boolean;
 	minConfidence?: number;
 	detectMultiple?: boolean;
+	/** Only analyze the first N KiB of text */
+	maxSampleKb?: number;
+}
+
+/** How custom stopwords combine with the embedded list: add to it or use instead of it. */
+export type StopwordsMode = "merge" | "replace";
+
+/**
+ * Custom stopwords for keyword extraction and token reduction.
+ */
+export interface StopwordsConfig {
+	/** Custom stopwords (matched case-insensitively) */
+	custom?: string[];
+	/** Merge with (default) or replace the embedded stopword list */
+	mode?: StopwordsMode;
 }
 
 export interface TokenReductionConfig {
 	mode?: string;
 	preserveImportantWords?: boolean;
+	stopwords?: StopwordsConfig;
+	/** Keep at most this fraction (0.0-1.0) of the original tokens */
+	targetRatio?: number;
+	/** Keep at most this many tokens (CJK text counts one token per character bigram) */
+	maxTokens?: number;
+}
+
+export interface ThreadPoolConfig {
+	rayonThreads?: number;
+	workerThreads?: number;
+	maxBlockingThreads?: number;
 }
 
 export interface PdfConfig {
 	extractImages?: boolean;
 	passwords?: string[];
 	extractMetadata?: boolean;
+	validatePdfa?: boolean;
+	layoutStats?: boolean;
 }
 
 export interface ImageExtractionConfig {
@@ -54,12 +99,71 @@ export interface ImageExtractionConfig {
 	autoAdjustDpi?: boolean;
 	minDpi?: number;
 	maxDpi?: number;
+	/** Name of a registered image describer that fills each image's description */
+	describer?: string;
+	/** Use image descriptions as alt text of Markdown image references in the content */
+	inlineDescriptions?: boolean;
+}
+
+export interface HeaderFooterConfig {
+	/** Number of non-empty lines at the top and bottom of each page to inspect (default: 2) */
+	linesToCheck?: number;
+	/** Minimum number of pages before anything is removed (default: 3) */
+	minPages?: number;
+	/** Fraction of pages a line must appear on to count as a header or footer (default: 0.5) */
+	minRepeatRatio?: number;
+	/** Also remove bare page numbers at page edges (default: true) */
+	removePageNumbers?: boolean;
+}
+
+export interface LineRepairConfig {
+	/** Join words hyphenated across line breaks (default: true) */
+	dehyphenate?: boolean;
+	/** Merge hard-wrapped lines within paragraphs (default: true) */
+	mergeLines?: boolean;
+	/** ISO 639 language code of the text (default: detected language or script) */
+	language?: string;
+}
+
+export type NormalizationForm = "none" | "nfc" | "nfkc";
+
+export interface TextNormalizationConfig {
+	/** Unicode normalization form (default: "nfc") */
+	form?: NormalizationForm;
+	/** Remove zero-width spaces, word joiners, BOMs and soft hyphens (default: true) */
+	removeZeroWidth?: boolean;
+	/** Remove bidirectional control characters (default: true) */
+	removeBidiControls?: boolean;
+	/** Remove control characters other than tab, newline, carriage return and form feed (default: true) */
+	removeControlChars?: boolean;
+	/** Replace typographic quotes with ASCII quotes (default: true) */
+	canonicalizeQuotes?: boolean;
+	/** Replace Unicode hyphens, dashes and minus with ASCII "-" (default: true) */
+	canonicalizeDashes?: boolean;
+}
+
+export interface TextMetricsConfig {
+	/** Words with more characters than this count as long words for LIX (default: 6) */
+	longWordLength?: number;
 }
 
 export interface PostProcessorConfig {
 	enabled?: boolean;
 	enabledProcessors?: string[];
 	disabledProcessors?: string[];
+	/** Remove running headers, footers and page numbers (`true` uses the defaults) */
+	headerFooter?: HeaderFooterConfig | boolean;
+	/** Join hyphenated words and hard-wrapped lines (`true` uses the defaults) */
+	lineRepair?: LineRepairConfig | boolean;
+	/** Unicode normalization and invisible-character cleanup (`true` uses the defaults) */
+	normalization?: TextNormalizationConfig | boolean;
+	/** Readability and vocabulary metrics in `metadata.text_metrics` (`true` uses the defaults) */
+	metrics?: TextMetricsConfig | boolean;
+	/**
+	 * Named custom taggers whose matches go to `metadata.tags`: a regular expression (Rust `regex`
+	 * syntax) or an array of dictionary terms matched as whole words, ignoring case.
+	 */
+	taggers?: Record<string, string | string[]>;
 }
 
 export interface HtmlPreprocessingOptions {
@@ -120,6 +224,7 @@ export interface KeywordConfig {
 	minScore?: number;
 	ngramRange?: [number, number];
 	language?: string;
+	stopwords?: StopwordsConfig;
 	yakeParams?: YakeParams;
 	rakeParams?: RakeParams;
 }
@@ -140,6 +245,44 @@ export interface PageConfig {
 	markerFormat?: string;
 }
 
+export type EntityType = "person" | "organization" | "date" | "money";
+
+/**
+ * Named-entity extraction configuration.
+ *
+ * Persons, organizations, dates and monetary amounts are recognized from surface
+ * patterns and returned in `ExtractionResult.entities`.
+ */
+export interface EntityConfig {
+	/** Entity types to extract (default: all) */
+	entityTypes?: EntityType[];
+	/** Minimum confidence threshold (0.0-1.0, default: 0.5) */
+	minConfidence?: number;
+}
+
+export type QualityGateAction = "flag" | "reject";
+
+/**
+ * Garble detection gate for OCR output.
+ *
+ * Scores text by its dictionary-hit rate and OCR confusion patterns; results below
+ * either threshold get `metadata.low_quality = true`, or fail when `action` is `"reject"`.
+ */
+export interface QualityGateConfig {
+	/** Minimum share of words found in the language's stopword list (0.0-1.0, default: 0.15) */
+	minDictionaryHitRate?: number;
+	/** Minimum share of tokens free of OCR confusions such as "he11o" (0.0-1.0, default: 0.8) */
+	minConfusionScore?: number;
+	/** Results with fewer words are not scored (default: 20) */
+	minWords?: number;
+	/** Only gate results produced by OCR (default: true) */
+	ocrOnly?: boolean;
+	/** What to do with results below the thresholds (default: "flag") */
+	action?: QualityGateAction;
+	/** ISO 639 language code for the dictionary check (default: detected language, then English) */
+	language?: string;
+}
+
 export interface ExtractionConfig {
 	useCache?: boolean;
 	enableQualityProcessing?: boolean;
@@ -154,6 +297,11 @@ export interface ExtractionConfig {
 	htmlOptions?: HtmlConversionOptions;
 	keywords?: KeywordConfig;
 	pages?: PageConfig;
+	entities?: EntityConfig;
+	qualityGate?: QualityGateConfig;
+	collectTimings?: boolean;
+	strictMime?: boolean;
+	strict?: boolean;
 	maxConcurrentExtractions?: number;
 }
 
@@ -168,6 +316,27 @@ export interface ExcelMetadata {
 	sheetNames?: string[];
 }
 
+/**
+ * A meeting from a calendar invite (`text/calendar` part, `.ics` attachment or TNEF meeting request).
+ */
+export interface CalendarEvent {
+	/** iTIP method of the invite (e.g. "REQUEST", "CANCEL") */
+	method?: string;
+	uid?: string;
+	summary?: string;
+	/** Start as ISO 8601, in `timezone` when set */
+	start?: string;
+	end?: string;
+	timezone?: string;
+	location?: string;
+	organizer?: string;
+	attendees?: string[];
+	description?: string;
+	status?: string;
+	/** Recurrence rule (RRULE) */
+	recurrence?: string;
+}
+
 export interface EmailMetadata {
 	fromEmail?: string | null;
 	fromName?: string | null;
@@ -175,7 +344,17 @@ export interface EmailMetadata {
 	ccEmails?: string[];
 	bccEmails?: string[];
 	messageId?: string | null;
+	replyToEmails?: string[];
+	inReplyTo?: string | null;
+	references?: string[];
+	threadId?: string | null;
+	hasDkimSignature?: boolean;
+	rawHeaders?: [string, string][];
+	calendarEvents?: CalendarEvent[];
 	attachments?: string[];
+	quotedText?: string;
+	signature?: string;
+	trackingPixels?: string[];
 }
 
 export interface ArchiveMetadata {
@@ -196,6 +375,8 @@ export interface ImageMetadata {
 export interface XmlMetadata {
 	elementCount?: number;
 	uniqueElements?: string[];
+	/** Name of the configured XML document type whose XPath mapping was applied */
+	documentType?: string | null;
 }
 
 export interface TextMetadata {
@@ -205,6 +386,37 @@ export interface TextMetadata {
 	headers?: string[] | null;
 	links?: [string, string][] | null;
 	codeBlocks?: [string, string][] | null;
+	/** Encoding the file was decoded from, e.g. `UTF-8`, `windows-1252` or `IBM037` */
+	encoding?: string | null;
+}
+
+/**
+ * A hyperlink of an HTML document.
+ */
+export interface HtmlLink {
+	/** Anchor text with markup removed */
+	text: string;
+	/** `href` attribute as written in the document */
+	href: string;
+	title?: string | null;
+	/** `rel` attribute (e.g. "nofollow") */
+	rel?: string | null;
+	/** Byte offset of the `<a>` tag in the HTML source */
+	position: number;
+}
+
+/**
+ * An image of an HTML document.
+ */
+export interface HtmlImage {
+	/** `src` attribute; base64 data URIs are shortened to `data:<media type>` */
+	src: string;
+	alt?: string | null;
+	title?: string | null;
+	/** Byte offset of the `<img>` tag in the HTML source */
+	position: number;
+	/** Index of the decoded image in `images` (data URI images, when image extraction is enabled) */
+	imageIndex?: number | null;
 }
 
 export interface HtmlMetadata {
@@ -229,6 +441,53 @@ export interface HtmlMetadata {
 	linkAuthor?: string | null;
 	linkLicense?: string | null;
 	linkAlternate?: string | null;
+	links?: HtmlLink[];
+	images?: HtmlImage[];
+}
+
+/**
+ * A PDF/A requirement the file does not meet.
+ */
+export interface PdfAViolation {
+	/** Stable identifier of the check (e.g. "fonts.embedded") */
+	rule: string;
+	message: string;
+}
+
+/**
+ * PDF/A conformance report (`metadata.pdfa`), set when `pdfOptions.validatePdfa` is enabled.
+ */
+export interface PdfAReport {
+	/** Declared PDF/A part (`pdfaid:part`) */
+	part?: number | null;
+	/** Declared conformance level (e.g. "B") */
+	conformance?: string | null;
+	/** Whether the file claims PDF/A and no violation was found */
+	conformant: boolean;
+	violations: PdfAViolation[];
+}
+
+/**
+ * A font defined in a PDF.
+ */
+export interface PdfFontInfo {
+	/** Base font name without the subset prefix */
+	name: string;
+	embedded: boolean;
+}
+
+/**
+ * Font and layout statistics (`metadata.layout`), set when `pdfOptions.layoutStats` is enabled.
+ */
+export interface PdfLayoutStats {
+	fonts: PdfFontInfo[];
+	/** Share of the text and image area that is text (0.0 images only, 1.0 text only) */
+	text_coverage?: number | null;
+	/** Average share of the page area covered by images */
+	image_coverage: number;
+	/** Average number of non-whitespace characters per page in the text layer */
+	avg_glyphs_per_page: number;
+	pages_without_text: number;
 }
 
 export interface PdfMetadata {
@@ -241,6 +500,8 @@ export interface PdfMetadata {
 	creationDate?: string | null;
 	modificationDate?: string | null;
 	pageCount?: number;
+	pdfa?: PdfAReport | null;
+	layout?: PdfLayoutStats | null;
 }
 
 export interface PptxMetadata {
@@ -280,6 +541,132 @@ export interface ErrorMetadata {
 	message?: string;
 }
 
+/**
+ * Wall-clock duration of each extraction stage, in milliseconds.
+ */
+export interface StageDurations {
+	extraction_ms: number;
+	post_processing_ms: number;
+}
+
+/**
+ * Summary statistics computed for every extraction result (`metadata.stats`).
+ */
+export interface ExtractionStats {
+	word_count: number;
+	character_count: number;
+	page_count?: number;
+	table_count: number;
+	image_count: number;
+	/** Mean OCR confidence (0.0-1.0), present when OCR ran */
+	ocr_confidence?: number;
+	/** Stage durations; absent where no monotonic clock is available */
+	durations?: StageDurations;
+}
+
+/**
+ * Per-stage durations in milliseconds (`metadata.timings`), recorded when `collectTimings` is set.
+ * Stages that did not run are absent.
+ */
+export interface ExtractionTimings {
+	mime_detection_ms?: number;
+	/** Document extractor, including OCR */
+	extraction_ms?: number;
+	/** OCR of pages and images, as part of `extraction_ms` */
+	ocr_ms?: number;
+	/** Image descriptions and post-processors */
+	post_processing_ms?: number;
+	chunking_ms?: number;
+	language_detection_ms?: number;
+	total_ms?: number;
+}
+
+/**
+ * Producing application, capture device and scan heuristic (`metadata.provenance`).
+ */
+export interface DocumentProvenance {
+	/** PDF `Producer`, Office `Application` or EXIF `Software` */
+	producer?: string;
+	/** Authoring application (PDF `Creator`) */
+	creator_tool?: string;
+	/** Scanner or camera (EXIF `Make`/`Model`, or a device named by the producer) */
+	scanner_model?: string;
+	likely_scanned: boolean;
+	/** Evidence for `likely_scanned`, e.g. "raster_image", "scanner_producer", "sparse_text_layer" */
+	scan_signals?: string[];
+}
+
+/**
+ * Readability scores, sentence/word statistics and vocabulary richness (`metadata.text_metrics`).
+ */
+export interface TextMetrics {
+	sentence_count: number;
+	word_count: number;
+	syllable_count: number;
+	long_word_count: number;
+	unique_word_count: number;
+	avg_sentence_length: number;
+	avg_word_length: number;
+	avg_syllables_per_word: number;
+	/** Flesch reading ease (higher is easier) */
+	flesch_reading_ease: number;
+	/** Flesch-Kincaid grade level */
+	flesch_kincaid_grade: number;
+	/** LIX readability index */
+	lix: number;
+	/** Distinct words divided by total words */
+	type_token_ratio: number;
+	/** Fraction of distinct words that occur exactly once */
+	hapax_ratio: number;
+}
+
+/**
+ * A match of a custom tagger (`metadata.tags`), with byte offsets into the content.
+ */
+export interface TagMatch {
+	text: string;
+	byte_start: number;
+	byte_end: number;
+}
+
+/**
+ * A signal that routes a result to human review, discriminated by `kind`.
+ */
+export type ReviewReason =
+	| { kind: "low_ocr_confidence"; confidence: number; threshold: number }
+	| { kind: "low_quality_score"; score: number; threshold: number }
+	| { kind: "low_quality" }
+	| { kind: "limits_exceeded"; limits: string[] }
+	| { kind: "archive_truncated" }
+	| { kind: "validator_failed"; validator: string; message: string };
+
+/**
+ * Human-review verdict (`metadata.review`), set when the `review` config is present.
+ */
+export interface ReviewStatus {
+	/** Whether at least one review signal fired */
+	required: boolean;
+	reasons: ReviewReason[];
+}
+
+/**
+ * A tracked change or comment in a DOCX or PPTX document (`metadata.revisions`).
+ */
+export interface Revision {
+	kind: "insertion" | "deletion" | "comment";
+	author?: string | null;
+	/** Timestamp as stored in the document (ISO 8601) */
+	date?: string | null;
+	/** Inserted or deleted text, or the comment text */
+	text: string;
+	/** Text a comment refers to (DOCX) */
+	anchor?: string | null;
+	/** Slide a comment is attached to (PPTX) */
+	page_number?: number | null;
+	/** `pending` for tracked changes and open comments, `resolved` for comments marked done */
+	status: "pending" | "resolved";
+}
+
 /**
  * Page boundary information for chunk metadata.
  *
@@ -373,6 +760,46 @@ export interface Chunk {
 	metadata: ChunkMetadata;
 }
 
+export interface Entity {
+	entityType: EntityType;
+	text: string;
+	/** Byte offset where the entity starts in `content` */
+	byteStart: number;
+	/** Byte offset where the entity ends in `content` */
+	byteEnd: number;
+	confidence: number;
+}
+
+export interface DetectedLanguage {
+	/** ISO 639-3 language code */
+	language: string;
+	/** Detection confidence (0.0-1.0) */
+	confidence: number;
+	/** Writing system, e.g. "Latin" or "Cyrillic" */
+	script: string;
+}
+
+export interface Keyword {
+	text: string;
+	/** Relevance score (algorithm-specific range) */
+	score: number;
+	algorithm: KeywordAlgorithm;
+	/** Character offsets where the keyword appears in `content` */
+	positions?: number[] | null;
+}
+
+export type WarningStage = "recovery" | "extraction" | "ocr" | "post_processing" | "limits";
+
+/** Non-fatal issue encountered while processing a document, e.g. a skipped attachment. */
+export interface ExtractionWarning {
+	/** Stable identifier of the kind of issue, e.g. `attachment_failed` */
+	code: string;
+	message: string;
+	stage: WarningStage;
+	/** Page the issue applies to (1-indexed) */
+	page?: number | null;
+}
+
 export interface ExtractedImage {
 	data: Uint8Array;
 	format: string;
@@ -380,6 +807,7 @@ export interface ExtractedImage {
 	pageNumber?: number | null;
 	width?: number | null;
 	height?: number | null;
+	dpi?: number | null;
 	colorspace?: string | null;
 	bitsPerComponent?: number | null;
 	isMask: boolean;
@@ -418,16 +846,27 @@ export interface Metadata {
 	date?: string | null;
 	subject?: string | null;
 
-	format_type?: "pdf" | "excel" | "email" | "pptx" | "archive" | "image" | "xml" | "text" | "html" | "ocr";
+	format_type?: "pdf" | "excel" | "email" | "pptx" | "office" | "archive" | "image" | "xml" | "text" | "html" | "ocr";
 
 	title?: string | null;
+	authors?: string[] | null;
+	/** Keyword list; HTML metadata reports the raw `<meta name="keywords">` string */
+	keywords?: string[] | string | null;
+	created_at?: string | null;
+	modified_at?: string | null;
+	created_by?: string | null;
+	modified_by?: string | null;
+	publisher?: string | null;
+	identifier?: string | null;
+
 	author?: string | null;
-	keywords?: string | null;
 	creator?: string | null;
 	producer?: string | null;
 	creation_date?: string | null;
 	modification_date?: string | null;
 	page_count?: number;
+	pdfa?: PdfAReport | null;
+	layout?: PdfLayoutStats | null;
 
 	sheet_count?: number;
 	sheet_names?: string[];
@@ -438,12 +877,35 @@ export interface Metadata {
 	cc_emails?: string[];
 	bcc_emails?: string[];
 	message_id?: string | null;
+	reply_to_emails?: string[];
+	in_reply_to?: string | null;
+	references?: string[];
+	thread_id?: string | null;
+	has_dkim_signature?: boolean;
+	raw_headers?: [string, string][];
+	calendar_events?: CalendarEvent[];
 	attachments?: string[];
+	quoted_text?: string;
+	signature?: string;
+	tracking_pixels?: string[];
 
 	description?: string | null;
 	summary?: string | null;
 	fonts?: string[];
 
+	application?: string | null;
+	template?: string | null;
+	organization?: string | null;
+	initial_creator?: string | null;
+	revision?: string | null;
+	category?: string | null;
+	content_status?: string | null;
+	total_editing_time_minutes?: number | null;
+	editing_duration?: string | null;
+	editing_cycles?: string | null;
+	paragraph_count?: number | null;
+	image_count?: number | null;
+
 	format?: string;
 	file_count?: number;
 	file_list?: string[];
@@ -463,6 +925,7 @@ export interface Metadata {
 	headers?: string[] | null;
 	links?: [string, string][] | null;
 	code_blocks?: [string, string][] | null;
+	encoding?: string | null;
 
 	canonical?: string | null;
 	base_href?: string | null;
@@ -496,6 +959,23 @@ export interface Metadata {
 
 	error?: ErrorMetadata | null;
 
+	stats?: ExtractionStats | null;
+
+	timings?: ExtractionTimings | null;
+
+	provenance?: DocumentProvenance | null;
+
+	text_metrics?: TextMetrics | null;
+
+	tags?: Record<string, TagMatch[]> | null;
+
+	review?: ReviewStatus | null;
+
+	// Near-duplicate of an earlier batch result (with the dedup config)
+	duplicate_of?: string | null;
+
+	revisions?: Revision[] | null;
+
 	// biome-ignore lint/suspicious/noExplicitAny: Postprocessors can add arbitrary metadata fields
 	[key: string]: any;
 }
@@ -505,10 +985,64 @@ export interface ExtractionResult {
 	mimeType: string;
 	metadata: Metadata;
 	tables: Table[];
-	detectedLanguages: string[] | null;
+	detectedLanguages: DetectedLanguage[] | null;
 	chunks: Chunk[] | null;
 	images: ExtractedImage[] | null;
 	pages?: PageContent[] | null;
+	entities?: Entity[] | null;
+	keywords?: Keyword[] | null;
+	warnings?: ExtractionWarning[];
+}
+
+/**
+ * Options of `extractDirectory()` and `extractDirectoryIter()`.
+ */
+export interface DirectoryExtractionOptions {
+	/**
+	 * Globs a file must match to be extracted (all files if omitted). Patterns are matched
+	 * against the `/`-separated path relative to the directory and support `*`, `?` and `**`;
+	 * a pattern without `/` matches the file name at any depth.
+	 */
+	glob?: string | string[];
+	/** Globs of files and directories to skip, with the same syntax as `glob` */
+	exclude?: string | string[];
+	/** Descend into subdirectories (default: true) */
+	recursive?: boolean;
+	/** Files extracted at once (default: `maxConcurrentExtractions` of the config, or CPUs * 2) */
+	concurrency?: number;
+	/** Extraction configuration applied to every file */
+	config?: ExtractionConfig | null;
+	/** Called after each file is processed */
+	onProgress?: (progress: DirectoryProgress) => void;
+}
+
+/**
+ * One processed file of a directory extraction.
+ */
+export interface DirectoryItem {
+	path: string;
+	/** `/`-separated path relative to the extracted directory */
+	relativePath: string;
+	/** Hex hash of the file content (null when the file could not be read) */
+	contentHash: string | null;
+	/** The result was reused from an earlier run */
+	cached: boolean;
+	/** Extraction result (null when the file failed) */
+	result: ExtractionResult | null;
+	/** Why the file failed (null on success) */
+	error: ErrorInfo | null;
+}
+
+/**
+ * Progress of a directory extraction, passed to `onProgress`.
+ */
+export interface DirectoryProgress {
+	/** The file that was just processed */
+	item: DirectoryItem;
+	/** Files processed so far, including failed ones */
+	processed: number;
+	/** Files that failed so far */
+	failed: number;
 }
 
 export type ProcessingStage = "early" | "middle" | "late";
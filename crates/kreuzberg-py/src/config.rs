@@ -7,7 +7,7 @@ use html_to_markdown_rs::options::{
     CodeBlockStyle, ConversionOptions, HeadingStyle, HighlightStyle, ListIndentType, NewlineStyle, PreprocessingPreset,
     WhitespaceMode,
 };
-use pyo3::exceptions::PyValueError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
@@ -57,7 +57,12 @@ impl ExtractionConfig {
         postprocessor=None,
         html_options=None,
         max_concurrent_extractions=None,
-        pages=None
+        pages=None,
+        entities=None,
+        quality_gate=None,
+        collect_timings=None,
+        strict_mime=None,
+        strict=None
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -75,6 +80,11 @@ impl ExtractionConfig {
         html_options: Option<Bound<'_, PyDict>>,
         max_concurrent_extractions: Option<usize>,
         pages: Option<PageConfig>,
+        entities: Option<EntityConfig>,
+        quality_gate: Option<QualityGateConfig>,
+        collect_timings: Option<bool>,
+        strict_mime: Option<bool>,
+        strict: Option<bool>,
     ) -> PyResult<Self> {
         let (html_options_inner, html_options_dict) = parse_html_options_dict(html_options)?;
         Ok(Self {
@@ -93,6 +103,12 @@ impl ExtractionConfig {
                 html_options: html_options_inner,
                 max_concurrent_extractions,
                 pages: pages.map(Into::into),
+                entities: entities.map(Into::into),
+                quality_gate: quality_gate.map(|config| config.inner),
+                collect_timings: collect_timings.unwrap_or(false),
+                strict_mime: strict_mime.unwrap_or(false),
+                strict: strict.unwrap_or(false),
+                ..Default::default()
             },
             html_options_dict,
         })
@@ -113,7 +129,12 @@ impl ExtractionConfig {
         postprocessor=None,
         html_options=None,
         max_concurrent_extractions=None,
-        pages=None
+        pages=None,
+        entities=None,
+        quality_gate=None,
+        collect_timings=None,
+        strict_mime=None,
+        strict=None
     ))]
     #[allow(clippy::too_many_arguments)]
     fn new(
@@ -130,6 +151,11 @@ impl ExtractionConfig {
         html_options: Option<Bound<'_, PyDict>>,
         max_concurrent_extractions: Option<usize>,
         pages: Option<PageConfig>,
+        entities: Option<EntityConfig>,
+        quality_gate: Option<QualityGateConfig>,
+        collect_timings: Option<bool>,
+        strict_mime: Option<bool>,
+        strict: Option<bool>,
     ) -> PyResult<Self> {
         let (html_options_inner, html_options_dict) = parse_html_options_dict(html_options)?;
         Ok(Self {
@@ -148,6 +174,12 @@ impl ExtractionConfig {
                 html_options: html_options_inner,
                 max_concurrent_extractions,
                 pages: pages.map(Into::into),
+                entities: entities.map(Into::into),
+                quality_gate: quality_gate.map(|config| config.inner),
+                collect_timings: collect_timings.unwrap_or(false),
+                strict_mime: strict_mime.unwrap_or(false),
+                strict: strict.unwrap_or(false),
+                ..Default::default()
             },
             html_options_dict,
         })
@@ -275,6 +307,36 @@ impl ExtractionConfig {
         self.inner.max_concurrent_extractions = value;
     }
 
+    #[getter]
+    fn collect_timings(&self) -> bool {
+        self.inner.collect_timings
+    }
+
+    #[setter]
+    fn set_collect_timings(&mut self, value: bool) {
+        self.inner.collect_timings = value;
+    }
+
+    #[getter]
+    fn strict_mime(&self) -> bool {
+        self.inner.strict_mime
+    }
+
+    #[setter]
+    fn set_strict_mime(&mut self, value: bool) {
+        self.inner.strict_mime = value;
+    }
+
+    #[getter]
+    fn strict(&self) -> bool {
+        self.inner.strict
+    }
+
+    #[setter]
+    fn set_strict(&mut self, value: bool) {
+        self.inner.strict = value;
+    }
+
     #[getter]
     fn html_options<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>> {
         self.html_options_dict.as_ref().map(|dict| dict.bind(py).clone())
@@ -298,6 +360,26 @@ impl ExtractionConfig {
         self.inner.pages = value.map(Into::into);
     }
 
+    #[getter]
+    fn entities(&self) -> Option<EntityConfig> {
+        self.inner.entities.clone().map(Into::into)
+    }
+
+    #[setter]
+    fn set_entities(&mut self, value: Option<EntityConfig>) {
+        self.inner.entities = value.map(Into::into);
+    }
+
+    #[getter]
+    fn quality_gate(&self) -> Option<QualityGateConfig> {
+        self.inner.quality_gate.clone().map(|inner| QualityGateConfig { inner })
+    }
+
+    #[setter]
+    fn set_quality_gate(&mut self, value: Option<QualityGateConfig>) {
+        self.inner.quality_gate = value.map(|config| config.inner);
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ExtractionConfig(use_cache={}, enable_quality_processing={}, ocr={}, force_ocr={})",
@@ -1022,8 +1104,11 @@ impl ImageExtractionConfig {
         max_image_dimension=None,
         auto_adjust_dpi=None,
         min_dpi=None,
-        max_dpi=None
+        max_dpi=None,
+        describer=None,
+        inline_descriptions=None
     ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         extract_images: Option<bool>,
         target_dpi: Option<i32>,
@@ -1031,6 +1116,8 @@ impl ImageExtractionConfig {
         auto_adjust_dpi: Option<bool>,
         min_dpi: Option<i32>,
         max_dpi: Option<i32>,
+        describer: Option<String>,
+        inline_descriptions: Option<bool>,
     ) -> Self {
         Self {
             inner: kreuzberg::ImageExtractionConfig {
@@ -1040,6 +1127,8 @@ impl ImageExtractionConfig {
                 auto_adjust_dpi: auto_adjust_dpi.unwrap_or(true),
                 min_dpi: min_dpi.unwrap_or(72),
                 max_dpi: max_dpi.unwrap_or(600),
+                describer,
+                inline_descriptions: inline_descriptions.unwrap_or(false),
             },
         }
     }
@@ -1104,6 +1193,26 @@ impl ImageExtractionConfig {
         self.inner.max_dpi = value;
     }
 
+    #[getter]
+    fn describer(&self) -> Option<String> {
+        self.inner.describer.clone()
+    }
+
+    #[setter]
+    fn set_describer(&mut self, value: Option<String>) {
+        self.inner.describer = value;
+    }
+
+    #[getter]
+    fn inline_descriptions(&self) -> bool {
+        self.inner.inline_descriptions
+    }
+
+    #[setter]
+    fn set_inline_descriptions(&mut self, value: bool) {
+        self.inner.inline_descriptions = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ImageExtractionConfig(extract_images={}, target_dpi={}, max_image_dimension={})",
@@ -1138,13 +1247,27 @@ pub struct PdfConfig {
 #[pymethods]
 impl PdfConfig {
     #[new]
-    #[pyo3(signature = (extract_images=None, passwords=None, extract_metadata=None))]
-    fn new(extract_images: Option<bool>, passwords: Option<Vec<String>>, extract_metadata: Option<bool>) -> Self {
+    #[pyo3(signature = (
+        extract_images=None,
+        passwords=None,
+        extract_metadata=None,
+        validate_pdfa=None,
+        layout_stats=None
+    ))]
+    fn new(
+        extract_images: Option<bool>,
+        passwords: Option<Vec<String>>,
+        extract_metadata: Option<bool>,
+        validate_pdfa: Option<bool>,
+        layout_stats: Option<bool>,
+    ) -> Self {
         Self {
             inner: kreuzberg::PdfConfig {
                 extract_images: extract_images.unwrap_or(false),
                 passwords,
                 extract_metadata: extract_metadata.unwrap_or(true),
+                validate_pdfa: validate_pdfa.unwrap_or(false),
+                layout_stats: layout_stats.unwrap_or(false),
             },
         }
     }
@@ -1179,11 +1302,33 @@ impl PdfConfig {
         self.inner.extract_metadata = value;
     }
 
+    #[getter]
+    fn validate_pdfa(&self) -> bool {
+        self.inner.validate_pdfa
+    }
+
+    #[setter]
+    fn set_validate_pdfa(&mut self, value: bool) {
+        self.inner.validate_pdfa = value;
+    }
+
+    #[getter]
+    fn layout_stats(&self) -> bool {
+        self.inner.layout_stats
+    }
+
+    #[setter]
+    fn set_layout_stats(&mut self, value: bool) {
+        self.inner.layout_stats = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
-            "PdfConfig(extract_images={}, extract_metadata={}, passwords={})",
+            "PdfConfig(extract_images={}, extract_metadata={}, validate_pdfa={}, layout_stats={}, passwords={})",
             self.inner.extract_images,
             self.inner.extract_metadata,
+            self.inner.validate_pdfa,
+            self.inner.layout_stats,
             if self.inner.passwords.is_some() {
                 "Some([...])"
             } else {
@@ -1210,6 +1355,7 @@ impl From<kreuzberg::PdfConfig> for PdfConfig {
 /// Example:
 ///     >>> from kreuzberg import TokenReductionConfig
 ///     >>> config = TokenReductionConfig(mode="aggressive", preserve_important_words=True)
+///     >>> budget = TokenReductionConfig(max_tokens=4000)
 #[pyclass(name = "TokenReductionConfig", module = "kreuzberg")]
 #[derive(Clone)]
 pub struct TokenReductionConfig {
@@ -1219,12 +1365,21 @@ pub struct TokenReductionConfig {
 #[pymethods]
 impl TokenReductionConfig {
     #[new]
-    #[pyo3(signature = (mode=None, preserve_important_words=None))]
-    fn new(mode: Option<String>, preserve_important_words: Option<bool>) -> Self {
+    #[pyo3(signature = (mode=None, preserve_important_words=None, stopwords=None, target_ratio=None, max_tokens=None))]
+    fn new(
+        mode: Option<String>,
+        preserve_important_words: Option<bool>,
+        stopwords: Option<StopwordsConfig>,
+        target_ratio: Option<f32>,
+        max_tokens: Option<usize>,
+    ) -> Self {
         Self {
             inner: kreuzberg::TokenReductionConfig {
                 mode: mode.unwrap_or_else(|| "off".to_string()),
                 preserve_important_words: preserve_important_words.unwrap_or(true),
+                stopwords: stopwords.map(Into::into),
+                target_ratio,
+                max_tokens,
             },
         }
     }
@@ -1249,10 +1404,47 @@ impl TokenReductionConfig {
         self.inner.preserve_important_words = value;
     }
 
+    #[getter]
+    fn stopwords(&self) -> Option<StopwordsConfig> {
+        self.inner.stopwords.clone().map(Into::into)
+    }
+
+    #[setter]
+    fn set_stopwords(&mut self, value: Option<StopwordsConfig>) {
+        self.inner.stopwords = value.map(Into::into);
+    }
+
+    #[getter]
+    fn target_ratio(&self) -> Option<f32> {
+        self.inner.target_ratio
+    }
+
+    #[setter]
+    fn set_target_ratio(&mut self, value: Option<f32>) {
+        self.inner.target_ratio = value;
+    }
+
+    #[getter]
+    fn max_tokens(&self) -> Option<usize> {
+        self.inner.max_tokens
+    }
+
+    #[setter]
+    fn set_max_tokens(&mut self, value: Option<usize>) {
+        self.inner.max_tokens = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
-            "TokenReductionConfig(mode='{}', preserve_important_words={})",
-            self.inner.mode, self.inner.preserve_important_words
+            "TokenReductionConfig(mode='{}', preserve_important_words={}, stopwords={}, target_ratio={:?}, max_tokens={:?})",
+            self.inner.mode,
+            self.inner.preserve_important_words,
+            self.inner
+                .stopwords
+                .clone()
+                .map_or_else(|| "None".to_string(), |s| StopwordsConfig::from(s).__repr__()),
+            self.inner.target_ratio,
+            self.inner.max_tokens
         )
     }
 }
@@ -1269,6 +1461,85 @@ impl From<kreuzberg::TokenReductionConfig> for TokenReductionConfig {
     }
 }
 
+/// Custom stopwords for keyword extraction and token reduction.
+///
+/// ``mode="merge"`` (default) adds ``custom`` to the embedded stopword list of the
+/// language; ``mode="replace"`` uses only ``custom``.
+///
+/// Example:
+///     >>> from kreuzberg import KeywordConfig, StopwordsConfig
+///     >>> config = KeywordConfig(stopwords=StopwordsConfig(custom=["invoice", "total"]))
+#[pyclass(name = "StopwordsConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct StopwordsConfig {
+    inner: kreuzberg::StopwordsConfig,
+}
+
+#[pymethods]
+impl StopwordsConfig {
+    #[new]
+    #[pyo3(signature = (custom=None, mode=None))]
+    fn new(custom: Option<Vec<String>>, mode: Option<String>) -> PyResult<Self> {
+        Ok(Self {
+            inner: kreuzberg::StopwordsConfig {
+                custom: custom.unwrap_or_default(),
+                mode: mode
+                    .as_deref()
+                    .map(parse_stopwords_mode)
+                    .transpose()?
+                    .unwrap_or_default(),
+            },
+        })
+    }
+
+    #[getter]
+    fn custom(&self) -> Vec<String> {
+        self.inner.custom.clone()
+    }
+
+    #[setter]
+    fn set_custom(&mut self, value: Vec<String>) {
+        self.inner.custom = value;
+    }
+
+    #[getter]
+    fn mode(&self) -> &'static str {
+        self.inner.mode.as_str()
+    }
+
+    #[setter]
+    fn set_mode(&mut self, value: String) -> PyResult<()> {
+        self.inner.mode = parse_stopwords_mode(&value)?;
+        Ok(())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "StopwordsConfig(custom={:?}, mode='{}')",
+            self.inner.custom,
+            self.inner.mode.as_str()
+        )
+    }
+}
+
+fn parse_stopwords_mode(value: &str) -> PyResult<kreuzberg::StopwordsMode> {
+    value
+        .parse::<kreuzberg::StopwordsMode>()
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+impl From<StopwordsConfig> for kreuzberg::StopwordsConfig {
+    fn from(config: StopwordsConfig) -> Self {
+        config.inner
+    }
+}
+
+impl From<kreuzberg::StopwordsConfig> for StopwordsConfig {
+    fn from(config: kreuzberg::StopwordsConfig) -> Self {
+        Self { inner: config }
+    }
+}
+
 /// Language detection configuration.
 ///
 /// Example:
@@ -1283,13 +1554,19 @@ pub struct LanguageDetectionConfig {
 #[pymethods]
 impl LanguageDetectionConfig {
     #[new]
-    #[pyo3(signature = (enabled=None, min_confidence=None, detect_multiple=None))]
-    fn new(enabled: Option<bool>, min_confidence: Option<f64>, detect_multiple: Option<bool>) -> Self {
+    #[pyo3(signature = (enabled=None, min_confidence=None, detect_multiple=None, max_sample_kb=None))]
+    fn new(
+        enabled: Option<bool>,
+        min_confidence: Option<f64>,
+        detect_multiple: Option<bool>,
+        max_sample_kb: Option<usize>,
+    ) -> Self {
         Self {
             inner: kreuzberg::LanguageDetectionConfig {
                 enabled: enabled.unwrap_or(true),
                 min_confidence: min_confidence.unwrap_or(0.8),
                 detect_multiple: detect_multiple.unwrap_or(false),
+                max_sample_kb,
             },
         }
     }
@@ -1324,10 +1601,20 @@ impl LanguageDetectionConfig {
         self.inner.detect_multiple = value;
     }
 
+    #[getter]
+    fn max_sample_kb(&self) -> Option<usize> {
+        self.inner.max_sample_kb
+    }
+
+    #[setter]
+    fn set_max_sample_kb(&mut self, value: Option<usize>) {
+        self.inner.max_sample_kb = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
-            "LanguageDetectionConfig(enabled={}, min_confidence={}, detect_multiple={})",
-            self.inner.enabled, self.inner.min_confidence, self.inner.detect_multiple
+            "LanguageDetectionConfig(enabled={}, min_confidence={}, detect_multiple={}, max_sample_kb={:?})",
+            self.inner.enabled, self.inner.min_confidence, self.inner.detect_multiple, self.inner.max_sample_kb
         )
     }
 }
@@ -1344,11 +1631,553 @@ impl From<kreuzberg::LanguageDetectionConfig> for LanguageDetectionConfig {
     }
 }
 
+/// Named-entity extraction configuration.
+///
+/// Persons, organizations, dates and monetary amounts are recognized from surface
+/// patterns and returned in `ExtractionResult.entities`.
+///
+/// Example:
+///     >>> from kreuzberg import EntityConfig, ExtractionConfig
+///     >>> config = ExtractionConfig(entities=EntityConfig(entity_types=["person", "org"]))
+#[pyclass(name = "EntityConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct EntityConfig {
+    inner: kreuzberg::entities::EntityConfig,
+}
+
+#[pymethods]
+impl EntityConfig {
+    #[new]
+    #[pyo3(signature = (entity_types=None, min_confidence=None))]
+    fn new(entity_types: Option<Vec<String>>, min_confidence: Option<f64>) -> PyResult<Self> {
+        let mut inner = kreuzberg::entities::EntityConfig::default();
+        if let Some(types) = entity_types {
+            inner.entity_types = parse_entity_types(&types)?;
+        }
+        if let Some(confidence) = min_confidence {
+            inner.min_confidence = confidence;
+        }
+        Ok(Self { inner })
+    }
+
+    #[getter]
+    fn entity_types(&self) -> Vec<String> {
+        self.inner.entity_types.iter().map(|t| t.as_str().to_string()).collect()
+    }
+
+    #[setter]
+    fn set_entity_types(&mut self, value: Vec<String>) -> PyResult<()> {
+        self.inner.entity_types = parse_entity_types(&value)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn min_confidence(&self) -> f64 {
+        self.inner.min_confidence
+    }
+
+    #[setter]
+    fn set_min_confidence(&mut self, value: f64) {
+        self.inner.min_confidence = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "EntityConfig(entity_types={:?}, min_confidence={})",
+            self.entity_types(),
+            self.inner.min_confidence
+        )
+    }
+}
+
+fn parse_entity_types(types: &[String]) -> PyResult<Vec<kreuzberg::EntityType>> {
+    types
+        .iter()
+        .map(|t| {
+            t.parse::<kreuzberg::EntityType>()
+                .map_err(|e| PyValueError::new_err(e.to_string()))
+        })
+        .collect()
+}
+
+impl From<EntityConfig> for kreuzberg::entities::EntityConfig {
+    fn from(config: EntityConfig) -> Self {
+        config.inner
+    }
+}
+
+impl From<kreuzberg::entities::EntityConfig> for EntityConfig {
+    fn from(config: kreuzberg::entities::EntityConfig) -> Self {
+        Self { inner: config }
+    }
+}
+
+/// Running header/footer removal configuration.
+///
+/// Lines repeated at the top or bottom of many pages (ignoring digits) and bare page
+/// numbers are removed from the extracted content.
+///
+/// Example:
+///     >>> from kreuzberg import HeaderFooterConfig, PostProcessorConfig
+///     >>> config = PostProcessorConfig(header_footer=HeaderFooterConfig(min_repeat_ratio=0.6))
+#[pyclass(name = "HeaderFooterConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct HeaderFooterConfig {
+    inner: kreuzberg::HeaderFooterConfig,
+}
+
+#[pymethods]
+impl HeaderFooterConfig {
+    #[new]
+    #[pyo3(signature = (lines_to_check=None, min_pages=None, min_repeat_ratio=None, remove_page_numbers=None))]
+    fn new(
+        lines_to_check: Option<usize>,
+        min_pages: Option<usize>,
+        min_repeat_ratio: Option<f64>,
+        remove_page_numbers: Option<bool>,
+    ) -> Self {
+        let defaults = kreuzberg::HeaderFooterConfig::default();
+        Self {
+            inner: kreuzberg::HeaderFooterConfig {
+                lines_to_check: lines_to_check.unwrap_or(defaults.lines_to_check),
+                min_pages: min_pages.unwrap_or(defaults.min_pages),
+                min_repeat_ratio: min_repeat_ratio.unwrap_or(defaults.min_repeat_ratio),
+                remove_page_numbers: remove_page_numbers.unwrap_or(defaults.remove_page_numbers),
+            },
+        }
+    }
+
+    #[getter]
+    fn lines_to_check(&self) -> usize {
+        self.inner.lines_to_check
+    }
+
+    #[setter]
+    fn set_lines_to_check(&mut self, value: usize) {
+        self.inner.lines_to_check = value;
+    }
+
+    #[getter]
+    fn min_pages(&self) -> usize {
+        self.inner.min_pages
+    }
+
+    #[setter]
+    fn set_min_pages(&mut self, value: usize) {
+        self.inner.min_pages = value;
+    }
+
+    #[getter]
+    fn min_repeat_ratio(&self) -> f64 {
+        self.inner.min_repeat_ratio
+    }
+
+    #[setter]
+    fn set_min_repeat_ratio(&mut self, value: f64) {
+        self.inner.min_repeat_ratio = value;
+    }
+
+    #[getter]
+    fn remove_page_numbers(&self) -> bool {
+        self.inner.remove_page_numbers
+    }
+
+    #[setter]
+    fn set_remove_page_numbers(&mut self, value: bool) {
+        self.inner.remove_page_numbers = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "HeaderFooterConfig(lines_to_check={}, min_pages={}, min_repeat_ratio={}, remove_page_numbers={})",
+            self.inner.lines_to_check,
+            self.inner.min_pages,
+            self.inner.min_repeat_ratio,
+            self.inner.remove_page_numbers
+        )
+    }
+}
+
+/// Hyphenation and line-break repair configuration.
+///
+/// Joins words hyphenated across line breaks and merges hard-wrapped lines within
+/// paragraphs.
+///
+/// Example:
+///     >>> from kreuzberg import LineRepairConfig, PostProcessorConfig
+///     >>> config = PostProcessorConfig(line_repair=LineRepairConfig(language="de"))
+#[pyclass(name = "LineRepairConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct LineRepairConfig {
+    inner: kreuzberg::LineRepairConfig,
+}
+
+#[pymethods]
+impl LineRepairConfig {
+    #[new]
+    #[pyo3(signature = (dehyphenate=None, merge_lines=None, language=None))]
+    fn new(dehyphenate: Option<bool>, merge_lines: Option<bool>, language: Option<String>) -> Self {
+        Self {
+            inner: kreuzberg::LineRepairConfig {
+                dehyphenate: dehyphenate.unwrap_or(true),
+                merge_lines: merge_lines.unwrap_or(true),
+                language,
+            },
+        }
+    }
+
+    #[getter]
+    fn dehyphenate(&self) -> bool {
+        self.inner.dehyphenate
+    }
+
+    #[setter]
+    fn set_dehyphenate(&mut self, value: bool) {
+        self.inner.dehyphenate = value;
+    }
+
+    #[getter]
+    fn merge_lines(&self) -> bool {
+        self.inner.merge_lines
+    }
+
+    #[setter]
+    fn set_merge_lines(&mut self, value: bool) {
+        self.inner.merge_lines = value;
+    }
+
+    #[getter]
+    fn language(&self) -> Option<String> {
+        self.inner.language.clone()
+    }
+
+    #[setter]
+    fn set_language(&mut self, value: Option<String>) {
+        self.inner.language = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "LineRepairConfig(dehyphenate={}, merge_lines={}, language={:?})",
+            self.inner.dehyphenate, self.inner.merge_lines, self.inner.language
+        )
+    }
+}
+
+/// Unicode normalization and control-character policy.
+///
+/// Applies NFC/NFKC normalization, removes zero-width, bidi and control characters, and
+/// replaces typographic quotes and dashes with their ASCII forms.
+///
+/// Example:
+///     >>> from kreuzberg import PostProcessorConfig, TextNormalizationConfig
+///     >>> config = PostProcessorConfig(normalization=TextNormalizationConfig(form="nfkc"))
+#[pyclass(name = "TextNormalizationConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct TextNormalizationConfig {
+    inner: kreuzberg::TextNormalizationConfig,
+}
+
+#[pymethods]
+impl TextNormalizationConfig {
+    #[new]
+    #[pyo3(signature = (
+        form=None,
+        remove_zero_width=None,
+        remove_bidi_controls=None,
+        remove_control_chars=None,
+        canonicalize_quotes=None,
+        canonicalize_dashes=None
+    ))]
+    fn new(
+        form: Option<String>,
+        remove_zero_width: Option<bool>,
+        remove_bidi_controls: Option<bool>,
+        remove_control_chars: Option<bool>,
+        canonicalize_quotes: Option<bool>,
+        canonicalize_dashes: Option<bool>,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            inner: kreuzberg::TextNormalizationConfig {
+                form: form
+                    .as_deref()
+                    .map(parse_normalization_form)
+                    .transpose()?
+                    .unwrap_or_default(),
+                remove_zero_width: remove_zero_width.unwrap_or(true),
+                remove_bidi_controls: remove_bidi_controls.unwrap_or(true),
+                remove_control_chars: remove_control_chars.unwrap_or(true),
+                canonicalize_quotes: canonicalize_quotes.unwrap_or(true),
+                canonicalize_dashes: canonicalize_dashes.unwrap_or(true),
+            },
+        })
+    }
+
+    #[getter]
+    fn form(&self) -> &'static str {
+        self.inner.form.as_str()
+    }
+
+    #[setter]
+    fn set_form(&mut self, value: String) -> PyResult<()> {
+        self.inner.form = parse_normalization_form(&value)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn remove_zero_width(&self) -> bool {
+        self.inner.remove_zero_width
+    }
+
+    #[setter]
+    fn set_remove_zero_width(&mut self, value: bool) {
+        self.inner.remove_zero_width = value;
+    }
+
+    #[getter]
+    fn remove_bidi_controls(&self) -> bool {
+        self.inner.remove_bidi_controls
+    }
+
+    #[setter]
+    fn set_remove_bidi_controls(&mut self, value: bool) {
+        self.inner.remove_bidi_controls = value;
+    }
+
+    #[getter]
+    fn remove_control_chars(&self) -> bool {
+        self.inner.remove_control_chars
+    }
+
+    #[setter]
+    fn set_remove_control_chars(&mut self, value: bool) {
+        self.inner.remove_control_chars = value;
+    }
+
+    #[getter]
+    fn canonicalize_quotes(&self) -> bool {
+        self.inner.canonicalize_quotes
+    }
+
+    #[setter]
+    fn set_canonicalize_quotes(&mut self, value: bool) {
+        self.inner.canonicalize_quotes = value;
+    }
+
+    #[getter]
+    fn canonicalize_dashes(&self) -> bool {
+        self.inner.canonicalize_dashes
+    }
+
+    #[setter]
+    fn set_canonicalize_dashes(&mut self, value: bool) {
+        self.inner.canonicalize_dashes = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "TextNormalizationConfig(form='{}', remove_zero_width={}, remove_bidi_controls={}, \
+             remove_control_chars={}, canonicalize_quotes={}, canonicalize_dashes={})",
+            self.inner.form.as_str(),
+            self.inner.remove_zero_width,
+            self.inner.remove_bidi_controls,
+            self.inner.remove_control_chars,
+            self.inner.canonicalize_quotes,
+            self.inner.canonicalize_dashes
+        )
+    }
+}
+
+fn parse_normalization_form(value: &str) -> PyResult<kreuzberg::NormalizationForm> {
+    value
+        .parse::<kreuzberg::NormalizationForm>()
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Garble detection gate for OCR output.
+///
+/// Scores OCR text by its dictionary-hit rate (share of common words of the document
+/// language) and confusion score (share of tokens without OCR confusions such as "he11o").
+/// Results below either threshold get ``metadata["low_quality"] = True``, or fail with a
+/// ``ValidationError`` when ``action="reject"``.
+///
+/// Example:
+///     >>> from kreuzberg import ExtractionConfig, QualityGateConfig
+///     >>> config = ExtractionConfig(quality_gate=QualityGateConfig(action="reject"))
+#[pyclass(name = "QualityGateConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct QualityGateConfig {
+    inner: kreuzberg::QualityGateConfig,
+}
+
+#[pymethods]
+impl QualityGateConfig {
+    #[new]
+    #[pyo3(signature = (
+        min_dictionary_hit_rate=None,
+        min_confusion_score=None,
+        min_words=None,
+        ocr_only=None,
+        action=None,
+        language=None
+    ))]
+    fn new(
+        min_dictionary_hit_rate: Option<f64>,
+        min_confusion_score: Option<f64>,
+        min_words: Option<usize>,
+        ocr_only: Option<bool>,
+        action: Option<String>,
+        language: Option<String>,
+    ) -> PyResult<Self> {
+        let defaults = kreuzberg::QualityGateConfig::default();
+        Ok(Self {
+            inner: kreuzberg::QualityGateConfig {
+                min_dictionary_hit_rate: min_dictionary_hit_rate.unwrap_or(defaults.min_dictionary_hit_rate),
+                min_confusion_score: min_confusion_score.unwrap_or(defaults.min_confusion_score),
+                min_words: min_words.unwrap_or(defaults.min_words),
+                ocr_only: ocr_only.unwrap_or(defaults.ocr_only),
+                action: action
+                    .as_deref()
+                    .map(parse_quality_gate_action)
+                    .transpose()?
+                    .unwrap_or_default(),
+                language,
+            },
+        })
+    }
+
+    #[getter]
+    fn min_dictionary_hit_rate(&self) -> f64 {
+        self.inner.min_dictionary_hit_rate
+    }
+
+    #[setter]
+    fn set_min_dictionary_hit_rate(&mut self, value: f64) {
+        self.inner.min_dictionary_hit_rate = value;
+    }
+
+    #[getter]
+    fn min_confusion_score(&self) -> f64 {
+        self.inner.min_confusion_score
+    }
+
+    #[setter]
+    fn set_min_confusion_score(&mut self, value: f64) {
+        self.inner.min_confusion_score = value;
+    }
+
+    #[getter]
+    fn min_words(&self) -> usize {
+        self.inner.min_words
+    }
+
+    #[setter]
+    fn set_min_words(&mut self, value: usize) {
+        self.inner.min_words = value;
+    }
+
+    #[getter]
+    fn ocr_only(&self) -> bool {
+        self.inner.ocr_only
+    }
+
+    #[setter]
+    fn set_ocr_only(&mut self, value: bool) {
+        self.inner.ocr_only = value;
+    }
+
+    #[getter]
+    fn action(&self) -> &'static str {
+        self.inner.action.as_str()
+    }
+
+    #[setter]
+    fn set_action(&mut self, value: String) -> PyResult<()> {
+        self.inner.action = parse_quality_gate_action(&value)?;
+        Ok(())
+    }
+
+    #[getter]
+    fn language(&self) -> Option<String> {
+        self.inner.language.clone()
+    }
+
+    #[setter]
+    fn set_language(&mut self, value: Option<String>) {
+        self.inner.language = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "QualityGateConfig(min_dictionary_hit_rate={}, min_confusion_score={}, min_words={}, \
+             ocr_only={}, action='{}', language={:?})",
+            self.inner.min_dictionary_hit_rate,
+            self.inner.min_confusion_score,
+            self.inner.min_words,
+            self.inner.ocr_only,
+            self.inner.action.as_str(),
+            self.inner.language
+        )
+    }
+}
+
+fn parse_quality_gate_action(value: &str) -> PyResult<kreuzberg::QualityGateAction> {
+    value
+        .parse::<kreuzberg::QualityGateAction>()
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/// Readability and complexity metrics.
+///
+/// Computes Flesch reading ease, Flesch-Kincaid grade, LIX, sentence/word statistics and
+/// vocabulary richness, stored in ``metadata["text_metrics"]``.
+///
+/// Example:
+///     >>> from kreuzberg import PostProcessorConfig, TextMetricsConfig
+///     >>> config = PostProcessorConfig(metrics=TextMetricsConfig(long_word_length=6))
+#[pyclass(name = "TextMetricsConfig", module = "kreuzberg")]
+#[derive(Clone)]
+pub struct TextMetricsConfig {
+    inner: kreuzberg::TextMetricsConfig,
+}
+
+#[pymethods]
+impl TextMetricsConfig {
+    #[new]
+    #[pyo3(signature = (long_word_length=None))]
+    fn new(long_word_length: Option<usize>) -> Self {
+        let defaults = kreuzberg::TextMetricsConfig::default();
+        Self {
+            inner: kreuzberg::TextMetricsConfig {
+                long_word_length: long_word_length.unwrap_or(defaults.long_word_length),
+            },
+        }
+    }
+
+    #[getter]
+    fn long_word_length(&self) -> usize {
+        self.inner.long_word_length
+    }
+
+    #[setter]
+    fn set_long_word_length(&mut self, value: usize) {
+        self.inner.long_word_length = value;
+    }
+
+    fn __repr__(&self) -> String {
+        format!("TextMetricsConfig(long_word_length={})", self.inner.long_word_length)
+    }
+}
+
 /// Post-processor configuration.
 ///
+/// ``taggers`` maps tagger names to a regular expression or a list of dictionary terms;
+/// matches are stored in ``metadata["tags"]``.
+///
 /// Example:
 ///     >>> from kreuzberg import PostProcessorConfig
 ///     >>> config = PostProcessorConfig(enabled=True, enabled_processors=["entity_extraction"])
+///     >>> config = PostProcessorConfig(taggers={"contract_number": r"\bCN-\d{6}\b", "product": ["Widget Pro"]})
 #[pyclass(name = "PostProcessorConfig", module = "kreuzberg")]
 #[derive(Clone)]
 pub struct PostProcessorConfig {
@@ -1358,19 +2187,39 @@ pub struct PostProcessorConfig {
 #[pymethods]
 impl PostProcessorConfig {
     #[new]
-    #[pyo3(signature = (enabled=None, enabled_processors=None, disabled_processors=None))]
+    #[pyo3(signature = (
+        enabled=None,
+        enabled_processors=None,
+        disabled_processors=None,
+        header_footer=None,
+        line_repair=None,
+        normalization=None,
+        metrics=None,
+        taggers=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         enabled: Option<bool>,
         enabled_processors: Option<Vec<String>>,
         disabled_processors: Option<Vec<String>>,
-    ) -> Self {
-        Self {
+        header_footer: Option<HeaderFooterConfig>,
+        line_repair: Option<LineRepairConfig>,
+        normalization: Option<TextNormalizationConfig>,
+        metrics: Option<TextMetricsConfig>,
+        taggers: Option<Bound<'_, PyDict>>,
+    ) -> PyResult<Self> {
+        Ok(Self {
             inner: kreuzberg::PostProcessorConfig {
                 enabled: enabled.unwrap_or(true),
                 enabled_processors,
                 disabled_processors,
+                header_footer: header_footer.map(|config| config.inner),
+                line_repair: line_repair.map(|config| config.inner),
+                normalization: normalization.map(|config| config.inner),
+                metrics: metrics.map(|config| config.inner),
+                taggers: taggers.as_ref().map(parse_taggers).transpose()?,
             },
-        }
+        })
     }
 
     #[getter]
@@ -1403,6 +2252,73 @@ impl PostProcessorConfig {
         self.inner.disabled_processors = value;
     }
 
+    #[getter]
+    fn header_footer(&self) -> Option<HeaderFooterConfig> {
+        self.inner
+            .header_footer
+            .clone()
+            .map(|inner| HeaderFooterConfig { inner })
+    }
+
+    #[setter]
+    fn set_header_footer(&mut self, value: Option<HeaderFooterConfig>) {
+        self.inner.header_footer = value.map(|config| config.inner);
+    }
+
+    #[getter]
+    fn line_repair(&self) -> Option<LineRepairConfig> {
+        self.inner.line_repair.clone().map(|inner| LineRepairConfig { inner })
+    }
+
+    #[setter]
+    fn set_line_repair(&mut self, value: Option<LineRepairConfig>) {
+        self.inner.line_repair = value.map(|config| config.inner);
+    }
+
+    #[getter]
+    fn normalization(&self) -> Option<TextNormalizationConfig> {
+        self.inner
+            .normalization
+            .clone()
+            .map(|inner| TextNormalizationConfig { inner })
+    }
+
+    #[setter]
+    fn set_normalization(&mut self, value: Option<TextNormalizationConfig>) {
+        self.inner.normalization = value.map(|config| config.inner);
+    }
+
+    #[getter]
+    fn metrics(&self) -> Option<TextMetricsConfig> {
+        self.inner.metrics.clone().map(|inner| TextMetricsConfig { inner })
+    }
+
+    #[setter]
+    fn set_metrics(&mut self, value: Option<TextMetricsConfig>) {
+        self.inner.metrics = value.map(|config| config.inner);
+    }
+
+    #[getter]
+    fn taggers<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let Some(taggers) = &self.inner.taggers else {
+            return Ok(None);
+        };
+        let dict = PyDict::new(py);
+        for (name, tagger) in taggers {
+            match tagger {
+                kreuzberg::TaggerConfig::Pattern(pattern) => dict.set_item(name, pattern)?,
+                kreuzberg::TaggerConfig::Dictionary(terms) => dict.set_item(name, terms)?,
+            }
+        }
+        Ok(Some(dict))
+    }
+
+    #[setter]
+    fn set_taggers(&mut self, value: Option<Bound<'_, PyDict>>) -> PyResult<()> {
+        self.inner.taggers = value.as_ref().map(parse_taggers).transpose()?;
+        Ok(())
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "PostProcessorConfig(enabled={}, enabled_processors={:?}, disabled_processors={:?})",
@@ -1411,6 +2327,25 @@ impl PostProcessorConfig {
     }
 }
 
+/// Convert ``{name: pattern | [terms]}`` into tagger definitions.
+fn parse_taggers(dict: &Bound<'_, PyDict>) -> PyResult<std::collections::HashMap<String, kreuzberg::TaggerConfig>> {
+    dict.iter()
+        .map(|(name, tagger)| {
+            let name: String = name.extract()?;
+            let tagger = if let Ok(pattern) = tagger.extract::<String>() {
+                kreuzberg::TaggerConfig::Pattern(pattern)
+            } else if let Ok(terms) = tagger.extract::<Vec<String>>() {
+                kreuzberg::TaggerConfig::Dictionary(terms)
+            } else {
+                return Err(PyTypeError::new_err(format!(
+                    "Tagger '{name}' must be a regex string or a list of terms"
+                )));
+            };
+            Ok((name, tagger))
+        })
+        .collect()
+}
+
 impl From<PostProcessorConfig> for kreuzberg::PostProcessorConfig {
     fn from(config: PostProcessorConfig) -> Self {
         config.inner
@@ -1447,29 +2382,36 @@ impl ImagePreprocessingConfig {
         target_dpi=None,
         auto_rotate=None,
         deskew=None,
+        grayscale=None,
         denoise=None,
         contrast_enhance=None,
         binarization_method=None,
-        invert_colors=None
+        invert_colors=None,
+        remove_borders=None
     ))]
+    #[allow(clippy::too_many_arguments)]
     fn new(
         target_dpi: Option<i32>,
         auto_rotate: Option<bool>,
         deskew: Option<bool>,
+        grayscale: Option<bool>,
         denoise: Option<bool>,
         contrast_enhance: Option<bool>,
         binarization_method: Option<String>,
         invert_colors: Option<bool>,
+        remove_borders: Option<bool>,
     ) -> Self {
         Self {
             inner: kreuzberg::types::ImagePreprocessingConfig {
                 target_dpi: target_dpi.unwrap_or(300),
                 auto_rotate: auto_rotate.unwrap_or(true),
                 deskew: deskew.unwrap_or(true),
+                grayscale: grayscale.unwrap_or(true),
                 denoise: denoise.unwrap_or(false),
                 contrast_enhance: contrast_enhance.unwrap_or(false),
                 binarization_method: binarization_method.unwrap_or_else(|| "otsu".to_string()),
                 invert_colors: invert_colors.unwrap_or(false),
+                remove_borders: remove_borders.unwrap_or(false),
             },
         }
     }
@@ -1504,6 +2446,16 @@ impl ImagePreprocessingConfig {
         self.inner.deskew = value;
     }
 
+    #[getter]
+    fn grayscale(&self) -> bool {
+        self.inner.grayscale
+    }
+
+    #[setter]
+    fn set_grayscale(&mut self, value: bool) {
+        self.inner.grayscale = value;
+    }
+
     #[getter]
     fn denoise(&self) -> bool {
         self.inner.denoise
@@ -1544,6 +2496,16 @@ impl ImagePreprocessingConfig {
         self.inner.invert_colors = value;
     }
 
+    #[getter]
+    fn remove_borders(&self) -> bool {
+        self.inner.remove_borders
+    }
+
+    #[setter]
+    fn set_remove_borders(&mut self, value: bool) {
+        self.inner.remove_borders = value;
+    }
+
     fn __repr__(&self) -> String {
         format!(
             "ImagePreprocessingConfig(target_dpi={}, auto_rotate={}, deskew={}, denoise={})",
@@ -1901,10 +2863,12 @@ impl From<kreuzberg::types::TesseractConfig> for TesseractConfig {
 pub enum KeywordAlgorithm {
     /// YAKE (Yet Another Keyword Extractor) - statistical approach
     #[cfg(feature = "keywords-yake")]
+    #[pyo3(name = "YAKE")]
     Yake,
 
     /// RAKE (Rapid Automatic Keyword Extraction) - co-occurrence based
     #[cfg(feature = "keywords-rake")]
+    #[pyo3(name = "RAKE")]
     Rake,
 }
 
@@ -2082,6 +3046,7 @@ impl KeywordConfig {
         min_score=None,
         ngram_range=None,
         language=None,
+        stopwords=None,
         yake_params=None,
         rake_params=None
     ))]
@@ -2091,6 +3056,7 @@ impl KeywordConfig {
         min_score: Option<f32>,
         ngram_range: Option<(usize, usize)>,
         language: Option<String>,
+        stopwords: Option<StopwordsConfig>,
         yake_params: Option<YakeParams>,
         rake_params: Option<RakeParams>,
     ) -> Self {
@@ -2100,7 +3066,8 @@ impl KeywordConfig {
                 max_keywords: max_keywords.unwrap_or(10),
                 min_score: min_score.unwrap_or(0.0),
                 ngram_range: ngram_range.unwrap_or((1, 3)),
-                language: language.or_else(|| Some("en".to_string())),
+                language,
+                stopwords: stopwords.map(Into::into),
                 yake_params: yake_params.map(Into::into),
                 rake_params: rake_params.map(Into::into),
             },
@@ -2115,6 +3082,7 @@ impl KeywordConfig {
         min_score=None,
         ngram_range=None,
         language=None,
+        stopwords=None,
         yake_params=None
     ))]
     fn new(
@@ -2123,6 +3091,7 @@ impl KeywordConfig {
         min_score: Option<f32>,
         ngram_range: Option<(usize, usize)>,
         language: Option<String>,
+        stopwords: Option<StopwordsConfig>,
         yake_params: Option<YakeParams>,
     ) -> Self {
         Self {
@@ -2131,7 +3100,8 @@ impl KeywordConfig {
                 max_keywords: max_keywords.unwrap_or(10),
                 min_score: min_score.unwrap_or(0.0),
                 ngram_range: ngram_range.unwrap_or((1, 3)),
-                language: language.or_else(|| Some("en".to_string())),
+                language,
+                stopwords: stopwords.map(Into::into),
                 yake_params: yake_params.map(Into::into),
             },
         }
@@ -2145,6 +3115,7 @@ impl KeywordConfig {
         min_score=None,
         ngram_range=None,
         language=None,
+        stopwords=None,
         rake_params=None
     ))]
     fn new(
@@ -2153,6 +3124,7 @@ impl KeywordConfig {
         min_score: Option<f32>,
         ngram_range: Option<(usize, usize)>,
         language: Option<String>,
+        stopwords: Option<StopwordsConfig>,
         rake_params: Option<RakeParams>,
     ) -> Self {
         #[allow(unused_variables)]
@@ -2164,7 +3136,8 @@ impl KeywordConfig {
                 max_keywords: max_keywords.unwrap_or(10),
                 min_score: min_score.unwrap_or(0.0),
                 ngram_range: ngram_range.unwrap_or((1, 3)),
-                language: language.or_else(|| Some("en".to_string())),
+                language,
+                stopwords: stopwords.map(Into::into),
                 rake_params: rake_params.map(Into::into),
             },
         }
@@ -2177,7 +3150,8 @@ impl KeywordConfig {
         max_keywords=None,
         min_score=None,
         ngram_range=None,
-        language=None
+        language=None,
+        stopwords=None
     ))]
     fn new(
         algorithm: Option<KeywordAlgorithm>,
@@ -2185,6 +3159,7 @@ impl KeywordConfig {
         min_score: Option<f32>,
         ngram_range: Option<(usize, usize)>,
         language: Option<String>,
+        stopwords: Option<StopwordsConfig>,
     ) -> Self {
         Self {
             inner: kreuzberg::keywords::KeywordConfig {
@@ -2192,7 +3167,8 @@ impl KeywordConfig {
                 max_keywords: max_keywords.unwrap_or(10),
                 min_score: min_score.unwrap_or(0.0),
                 ngram_range: ngram_range.unwrap_or((1, 3)),
-                language: language.or_else(|| Some("en".to_string())),
+                language,
+                stopwords: stopwords.map(Into::into),
             },
         }
     }
@@ -2247,6 +3223,16 @@ impl KeywordConfig {
         self.inner.language = value;
     }
 
+    #[getter]
+    fn stopwords(&self) -> Option<StopwordsConfig> {
+        self.inner.stopwords.clone().map(Into::into)
+    }
+
+    #[setter]
+    fn set_stopwords(&mut self, value: Option<StopwordsConfig>) {
+        self.inner.stopwords = value.map(Into::into);
+    }
+
     #[cfg(feature = "keywords-yake")]
     #[getter]
     fn yake_params(&self) -> Option<YakeParams> {
@@ -2286,6 +3272,13 @@ impl From<KeywordConfig> for kreuzberg::keywords::KeywordConfig {
     }
 }
 
+#[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+impl From<kreuzberg::keywords::KeywordConfig> for KeywordConfig {
+    fn from(config: kreuzberg::keywords::KeywordConfig) -> Self {
+        Self { inner: config }
+    }
+}
+
 /// Page extraction and tracking configuration.
 ///
 /// Controls how pages are extracted, tracked, and represented in the extraction results.
@@ -421,6 +421,92 @@ pub fn batch_extract_bytes<'py>(
     })
 }
 
+/// Reusable extraction session.
+///
+/// Registers the built-in extractors and post-processors, checks the configured OCR
+/// backend and resolves extractors once, so repeated extractions skip that work.
+///
+/// Args:
+///     config: Extraction configuration used for every call
+///
+/// Raises:
+///     ValueError: Invalid configuration
+///     RuntimeError: Plugin initialization failures
+///
+/// Example:
+///     >>> from kreuzberg import ExtractionSession, ExtractionConfig
+///     >>> session = ExtractionSession(ExtractionConfig())
+///     >>> for path in ["doc1.pdf", "doc2.docx"]:
+///     ...     print(session.extract_file_sync(path).content)
+#[pyclass(name = "ExtractionSession", module = "kreuzberg")]
+pub struct ExtractionSession {
+    inner: kreuzberg::ExtractionSession,
+}
+
+#[pymethods]
+impl ExtractionSession {
+    #[new]
+    #[pyo3(signature = (config=ExtractionConfig::default()))]
+    fn new(py: Python, config: ExtractionConfig) -> PyResult<Self> {
+        let rust_config: kreuzberg::ExtractionConfig = config.into();
+        let inner = Python::detach(py, || kreuzberg::ExtractionSession::new(rust_config)).map_err(to_py_err)?;
+        Ok(Self { inner })
+    }
+
+    /// Extract content from a file (synchronous).
+    #[pyo3(signature = (path, mime_type=None))]
+    fn extract_file_sync(
+        &self,
+        py: Python,
+        path: &Bound<'_, PyAny>,
+        mime_type: Option<String>,
+    ) -> PyResult<ExtractionResult> {
+        let path_str = extract_path_string(path)?;
+
+        // Release GIL during sync extraction - OSError/RuntimeError must bubble up ~keep
+        let result =
+            Python::detach(py, || self.inner.extract_file_sync(&path_str, mime_type.as_deref())).map_err(to_py_err)?;
+
+        ExtractionResult::from_rust(result, py)
+    }
+
+    /// Extract content from bytes (synchronous).
+    fn extract_bytes_sync(&self, py: Python, data: Vec<u8>, mime_type: String) -> PyResult<ExtractionResult> {
+        // Release GIL during sync extraction - OSError/RuntimeError must bubble up ~keep
+        let result = Python::detach(py, || self.inner.extract_bytes_sync(&data, &mime_type)).map_err(to_py_err)?;
+
+        ExtractionResult::from_rust(result, py)
+    }
+
+    /// Extract content from a file (asynchronous).
+    #[pyo3(signature = (path, mime_type=None))]
+    fn extract_file<'py>(
+        &self,
+        py: Python<'py>,
+        path: &Bound<'py, PyAny>,
+        mime_type: Option<String>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let path_str = extract_path_string(path)?;
+        let session = self.inner.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let result = session
+                .extract_file(&path_str, mime_type.as_deref())
+                .await
+                .map_err(to_py_err)?;
+            Python::attach(|py| ExtractionResult::from_rust(result, py))
+        })
+    }
+
+    /// Extract content from bytes (asynchronous).
+    fn extract_bytes<'py>(&self, py: Python<'py>, data: Vec<u8>, mime_type: String) -> PyResult<Bound<'py, PyAny>> {
+        let session = self.inner.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let result = session.extract_bytes(&data, &mime_type).await.map_err(to_py_err)?;
+            Python::attach(|py| ExtractionResult::from_rust(result, py))
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -519,4 +605,15 @@ mod tests {
             assert_eq!(list.bind(py).len(), 2);
         });
     }
+
+    #[test]
+    fn test_extraction_session_extract_bytes_sync() {
+        with_py(|py| {
+            let session = ExtractionSession::new(py, ExtractionConfig::default()).expect("session should build");
+            let result = session
+                .extract_bytes_sync(py, b"hello session".to_vec(), "text/plain".to_string())
+                .expect("text/plain extraction should succeed");
+            assert!(result.content.contains("hello session"));
+        });
+    }
 }
@@ -69,8 +69,8 @@ fn exception_from_module(name: &str, message: String) -> PyErr {
         }
 
         match name {
-            "ValidationError" => PyErr::from_type(py.get_type::<ValidationError>(), (message,)),
-            "ParsingError" => PyErr::from_type(py.get_type::<ParsingError>(), (message,)),
+            "ValidationError" | "LimitExceededError" => PyErr::from_type(py.get_type::<ValidationError>(), (message,)),
+            "ParsingError" | "EncryptedDocumentError" => PyErr::from_type(py.get_type::<ParsingError>(), (message,)),
             "OCRError" => PyErr::from_type(py.get_type::<OCRError>(), (message,)),
             "MissingDependencyError" => PyErr::from_type(py.get_type::<MissingDependencyError>(), (message,)),
             "CacheError" => PyErr::from_type(py.get_type::<CacheError>(), (message,)),
@@ -86,7 +86,9 @@ fn exception_from_module(name: &str, message: String) -> PyErr {
 /// Maps error variants to appropriate Python exception types:
 /// - `Validation` → `ValidationError` (custom exception)
 /// - `UnsupportedFormat` → `ValidationError` (custom exception)
+/// - `LimitExceeded` → `LimitExceededError` (subclass of `ValidationError`)
 /// - `Parsing` → `ParsingError` (custom exception)
+/// - `EncryptedDocument` → `EncryptedDocumentError` (subclass of `ParsingError`)
 /// - `Io` → `OSError` (system error - must bubble up!)
 /// - `Ocr` → `OCRError` (custom exception)
 /// - `Plugin` → `PluginError` (custom exception)
@@ -97,7 +99,8 @@ fn exception_from_module(name: &str, message: String) -> PyErr {
 /// - `MissingDependency` → `MissingDependencyError` (custom exception)
 /// - `Other` → `RuntimeError` (runtime error - must bubble up!)
 ///
-/// All errors preserve their source chain for better debugging.
+/// All errors preserve their source chain for better debugging, and every exception carries
+/// the error's `code`, `stage`, `plugin` and `retryable` attributes (see `kreuzberg::ErrorInfo`).
 ///
 /// # Important: System Error Handling
 ///
@@ -106,14 +109,19 @@ fn exception_from_module(name: &str, message: String) -> PyErr {
 pub fn to_py_err(error: kreuzberg::KreuzbergError) -> PyErr {
     use kreuzberg::KreuzbergError;
 
-    match error {
+    let info = error.to_info();
+    let py_err = match error {
         KreuzbergError::Validation { message, source } => {
             exception_from_module("ValidationError", format_error_with_source(message, source))
         }
         KreuzbergError::UnsupportedFormat(msg) => exception_from_module("ValidationError", msg),
+        error @ KreuzbergError::LimitExceeded { .. } => exception_from_module("LimitExceededError", error.to_string()),
         KreuzbergError::Parsing { message, source } => {
             exception_from_module("ParsingError", format_error_with_source(message, source))
         }
+        error @ KreuzbergError::EncryptedDocument(_) => {
+            exception_from_module("EncryptedDocumentError", error.to_string())
+        }
         // OSError must bubble up - system errors need user reports ~keep
         KreuzbergError::Io(e) => PyOSError::new_err(e.to_string()),
         KreuzbergError::Ocr { message, source } => {
@@ -136,7 +144,21 @@ pub fn to_py_err(error: kreuzberg::KreuzbergError) -> PyErr {
         KreuzbergError::MissingDependency(msg) => exception_from_module("MissingDependencyError", msg),
         // RuntimeError must bubble up - unexpected errors need user reports ~keep
         KreuzbergError::Other(msg) => PyRuntimeError::new_err(msg),
-    }
+    };
+
+    attach_error_info(&py_err, &info);
+    py_err
+}
+
+/// Expose an `ErrorInfo` as attributes of the raised exception.
+fn attach_error_info(py_err: &PyErr, info: &kreuzberg::ErrorInfo) {
+    Python::attach(|py| {
+        let value = py_err.value(py);
+        let _ = value.setattr("code", info.code.as_str());
+        let _ = value.setattr("stage", info.stage.as_deref());
+        let _ = value.setattr("plugin", info.plugin.as_deref());
+        let _ = value.setattr("retryable", info.retryable);
+    });
 }
 
 #[cfg(test)]
@@ -372,4 +394,23 @@ mod tests {
             assert!(err_msg.contains("RuntimeError"));
         });
     }
+
+    #[test]
+    fn test_exception_carries_error_info() {
+        with_gil(|py| {
+            let error = KreuzbergError::Plugin {
+                message: "boom".to_string(),
+                plugin_name: "my-ocr".to_string(),
+            };
+            let py_err = to_py_err(error);
+            let value = py_err.value(py);
+
+            let code: String = value.getattr("code").unwrap().extract().unwrap();
+            let plugin: Option<String> = value.getattr("plugin").unwrap().extract().unwrap();
+            let retryable: bool = value.getattr("retryable").unwrap().extract().unwrap();
+            assert_eq!(code, "plugin");
+            assert_eq!(plugin.as_deref(), Some("my-ocr"));
+            assert!(!retryable);
+        });
+    }
 }
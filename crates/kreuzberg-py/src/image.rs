@@ -0,0 +1,318 @@
+//! Image preprocessing and standalone OCR
+//!
+//! Exposes DPI normalization, image conversion and compression, and the Tesseract OCR
+//! processor of the Rust core, for users of the deprecated image and OCR bindings.
+
+use crate::config::{ImageExtractionConfig, TesseractConfig};
+use crate::error::to_py_err;
+use crate::plugins::json_value_to_py;
+use kreuzberg::KreuzbergError;
+use kreuzberg::image::{ConvertOptions, ImageOutputFormat};
+use kreuzberg::ocr::OcrError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyList};
+use std::path::PathBuf;
+
+fn ocr_err(error: OcrError) -> PyErr {
+    to_py_err(KreuzbergError::ocr_with_source(error.to_string(), error))
+}
+
+fn to_py_object<'py>(py: Python<'py>, value: serde_json::Result<serde_json::Value>) -> PyResult<Bound<'py, PyAny>> {
+    let value = value.map_err(|e| to_py_err(e.into()))?;
+    json_value_to_py(py, &value)
+}
+
+/// Rescale raw RGB pixels to the target DPI of an image extraction configuration.
+///
+/// Args:
+///     rgb_data: Pixels as bytes, row-major, 3 bytes per pixel (height * width * 3)
+///     width: Image width in pixels
+///     height: Image height in pixels
+///     config: ImageExtractionConfig with target_dpi, max_image_dimension, auto_adjust_dpi,
+///         min_dpi and max_dpi (defaults if None)
+///     current_dpi: DPI of the input image (72 if None)
+///
+/// Returns:
+///     tuple[bytes, tuple[int, int], dict]: Rescaled pixels, their (width, height) and the
+///     preprocessing metadata (original and final DPI, scale factor, resample method, ...)
+///
+/// Raises:
+///     ValidationError: Dimensions do not match the pixel data or exceed 65536
+///
+/// Example:
+///     >>> from kreuzberg import ImageExtractionConfig, normalize_image_dpi
+///     >>> config = ImageExtractionConfig(target_dpi=300)
+///     >>> pixels, (width, height), metadata = normalize_image_dpi(rgb, 1275, 1650, config, current_dpi=150)
+///     >>> print(metadata["scale_factor"])
+///     2.0
+#[pyfunction]
+#[pyo3(signature = (rgb_data, width, height, config=None, current_dpi=None))]
+pub fn normalize_image_dpi<'py>(
+    py: Python<'py>,
+    rgb_data: Vec<u8>,
+    width: usize,
+    height: usize,
+    config: Option<ImageExtractionConfig>,
+    current_dpi: Option<f64>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let rust_config = match config {
+        Some(config) => {
+            let config = kreuzberg::ImageExtractionConfig::from(config);
+            kreuzberg::types::ExtractionConfig {
+                target_dpi: config.target_dpi,
+                max_image_dimension: config.max_image_dimension,
+                auto_adjust_dpi: config.auto_adjust_dpi,
+                min_dpi: config.min_dpi,
+                max_dpi: config.max_dpi,
+            }
+        }
+        None => kreuzberg::types::ExtractionConfig::default(),
+    };
+
+    let result = Python::detach(py, || {
+        kreuzberg::image::normalize_image_dpi(&rgb_data, width, height, &rust_config, current_dpi)
+    })
+    .map_err(to_py_err)?;
+
+    let metadata = to_py_object(py, serde_json::to_value(&result.metadata))?;
+    let pixels = PyBytes::new(py, &result.rgb_data);
+    Ok((pixels, result.dimensions, metadata).into_pyobject(py)?.into_any())
+}
+
+/// Calculate the DPI to render a page at.
+///
+/// The DPI is lowered from target_dpi when the rendered page would exceed max_dimension
+/// pixels, and clamped to [min_dpi, max_dpi].
+///
+/// Args:
+///     page_width: Page width in points (1/72 inch)
+///     page_height: Page height in points
+///     target_dpi: Preferred DPI
+///     max_dimension: Maximum width or height of the rendered page in pixels
+///     min_dpi: Lowest DPI to return
+///     max_dpi: Highest DPI to return
+///
+/// Returns:
+///     int: DPI to render at
+///
+/// Example:
+///     >>> from kreuzberg import calculate_optimal_dpi
+///     >>> calculate_optimal_dpi(612.0, 792.0, 300, 4096, 72, 600)
+///     300
+#[pyfunction]
+#[pyo3(signature = (page_width, page_height, target_dpi=300, max_dimension=4096, min_dpi=72, max_dpi=600))]
+pub fn calculate_optimal_dpi(
+    page_width: f64,
+    page_height: f64,
+    target_dpi: i32,
+    max_dimension: i32,
+    min_dpi: i32,
+    max_dpi: i32,
+) -> i32 {
+    kreuzberg::image::calculate_optimal_dpi(page_width, page_height, target_dpi, max_dimension, min_dpi, max_dpi)
+}
+
+/// Convert an image to another format, optionally downscaling it.
+///
+/// Args:
+///     image_bytes: Encoded image (PNG, JPEG, WebP, TIFF, BMP or GIF)
+///     format: Output format: "png", "jpeg", "webp", "tiff", "bmp" or "gif"
+///     quality: JPEG quality from 1 to 100 (ignored by the other formats)
+///     max_dimension: Downscale so that neither side exceeds this many pixels
+///
+/// Returns:
+///     bytes: The encoded image
+///
+/// Raises:
+///     ValidationError: Unknown format
+///     KreuzbergError: The image cannot be decoded or encoded
+///
+/// Example:
+///     >>> from kreuzberg import convert_image
+///     >>> webp = convert_image(png_bytes, "webp", max_dimension=1600)
+#[pyfunction]
+#[pyo3(signature = (image_bytes, format="png", quality=85, max_dimension=None))]
+pub fn convert_image<'py>(
+    py: Python<'py>,
+    image_bytes: Vec<u8>,
+    format: &str,
+    quality: u8,
+    max_dimension: Option<u32>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let options = ConvertOptions {
+        format: format.parse().map_err(to_py_err)?,
+        quality,
+        max_dimension,
+    };
+    encode(py, image_bytes, options)
+}
+
+/// Compress an image by re-encoding it as JPEG, optionally downscaling it.
+///
+/// Args:
+///     image_bytes: Encoded image (PNG, JPEG, WebP, TIFF, BMP or GIF)
+///     quality: JPEG quality from 1 (smallest) to 100 (best)
+///     max_dimension: Downscale so that neither side exceeds this many pixels
+///
+/// Returns:
+///     bytes: The JPEG image
+///
+/// Raises:
+///     KreuzbergError: The image cannot be decoded
+///
+/// Example:
+///     >>> from kreuzberg import compress_image
+///     >>> jpeg = compress_image(png_bytes, quality=60, max_dimension=2000)
+#[pyfunction]
+#[pyo3(signature = (image_bytes, quality=75, max_dimension=None))]
+pub fn compress_image<'py>(
+    py: Python<'py>,
+    image_bytes: Vec<u8>,
+    quality: u8,
+    max_dimension: Option<u32>,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let options = ConvertOptions {
+        format: ImageOutputFormat::Jpeg,
+        quality,
+        max_dimension,
+    };
+    encode(py, image_bytes, options)
+}
+
+fn encode<'py>(py: Python<'py>, image_bytes: Vec<u8>, options: ConvertOptions) -> PyResult<Bound<'py, PyBytes>> {
+    let encoded = Python::detach(py, || kreuzberg::image::convert_image(&image_bytes, &options)).map_err(to_py_err)?;
+    Ok(PyBytes::new(py, &encoded))
+}
+
+/// Tesseract OCR processor with a result cache.
+///
+/// Runs OCR on images directly, without going through document extraction. Results are
+/// cached on disk by image and configuration hash.
+///
+/// Args:
+///     cache_dir: Cache directory (defaults to .kreuzberg/ocr in the working directory)
+///
+/// Example:
+///     >>> from kreuzberg import OCRProcessor, TesseractConfig
+///     >>> processor = OCRProcessor()
+///     >>> result = processor.process_file("scan.png", TesseractConfig(language="deu"))
+///     >>> print(result["content"])
+///     >>> for item in processor.process_files_batch(["a.png", "b.png"]):
+///     ...     print(item["file_path"], item["success"])
+#[pyclass(name = "OCRProcessor", module = "kreuzberg")]
+pub struct OcrProcessor {
+    inner: kreuzberg::ocr::OcrProcessor,
+}
+
+#[pymethods]
+impl OcrProcessor {
+    #[new]
+    #[pyo3(signature = (cache_dir=None))]
+    fn new(cache_dir: Option<PathBuf>) -> PyResult<Self> {
+        let inner = kreuzberg::ocr::OcrProcessor::new(cache_dir).map_err(ocr_err)?;
+        Ok(Self { inner })
+    }
+
+    /// Run OCR on an encoded image.
+    ///
+    /// Returns:
+    ///     dict: content, mime_type, metadata and tables
+    #[pyo3(signature = (image_bytes, config=None))]
+    fn process_image<'py>(
+        &self,
+        py: Python<'py>,
+        image_bytes: Vec<u8>,
+        config: Option<TesseractConfig>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let config = tesseract_config(config);
+        let result = Python::detach(py, || self.inner.process_image(&image_bytes, &config)).map_err(ocr_err)?;
+        to_py_object(py, serde_json::to_value(&result))
+    }
+
+    /// Run OCR on an image file.
+    ///
+    /// Returns:
+    ///     dict: content, mime_type, metadata and tables
+    #[pyo3(signature = (path, config=None))]
+    fn process_file<'py>(
+        &self,
+        py: Python<'py>,
+        path: PathBuf,
+        config: Option<TesseractConfig>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let config = tesseract_config(config);
+        let path = path.to_string_lossy().into_owned();
+        let result = Python::detach(py, || self.inner.process_file(&path, &config)).map_err(ocr_err)?;
+        to_py_object(py, serde_json::to_value(&result))
+    }
+
+    /// Run OCR on several image files in parallel.
+    ///
+    /// Failures do not stop the batch; each item reports its own error.
+    ///
+    /// Returns:
+    ///     list[dict]: One item per path with file_path, success, result and error
+    #[pyo3(signature = (paths, config=None))]
+    fn process_files_batch<'py>(
+        &self,
+        py: Python<'py>,
+        paths: Vec<PathBuf>,
+        config: Option<TesseractConfig>,
+    ) -> PyResult<Bound<'py, PyList>> {
+        let config = tesseract_config(config);
+        let paths = paths.iter().map(|path| path.to_string_lossy().into_owned()).collect();
+        let results = Python::detach(py, || self.inner.process_files_batch(paths, &config));
+
+        let list = PyList::empty(py);
+        for item in &results {
+            list.append(to_py_object(py, serde_json::to_value(item))?)?;
+        }
+        Ok(list)
+    }
+
+    /// Remove all cached OCR results.
+    fn clear_cache(&self) -> PyResult<()> {
+        self.inner.clear_cache().map_err(ocr_err)
+    }
+
+    /// Number of cached results and cache size.
+    ///
+    /// Returns:
+    ///     dict: total_files and total_size_mb
+    fn get_cache_stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let stats = self.inner.get_cache_stats().map_err(ocr_err)?;
+        let dict = PyDict::new(py);
+        dict.set_item("total_files", stats.total_files)?;
+        dict.set_item("total_size_mb", stats.total_size_mb)?;
+        Ok(dict)
+    }
+
+    fn __repr__(&self) -> String {
+        "OCRProcessor()".to_string()
+    }
+}
+
+fn tesseract_config(config: Option<TesseractConfig>) -> kreuzberg::ocr::TesseractConfig {
+    let config: kreuzberg::types::TesseractConfig = config.map(Into::into).unwrap_or_default();
+    kreuzberg::ocr::TesseractConfig::from(&config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calculate_optimal_dpi_clamps() {
+        assert_eq!(calculate_optimal_dpi(612.0, 792.0, 300, 4096, 72, 600), 300);
+        assert_eq!(calculate_optimal_dpi(612.0, 792.0, 1200, 100_000, 72, 600), 600);
+    }
+
+    #[test]
+    fn test_convert_image_rejects_unknown_format() {
+        Python::initialize();
+        Python::attach(|py| {
+            let err = convert_image(py, Vec::new(), "svg", 85, None).unwrap_err();
+            assert!(err.to_string().contains("Invalid image format"));
+        });
+    }
+}
@@ -20,6 +20,7 @@ mod config;
 mod core;
 mod error;
 mod ffi;
+mod image;
 mod plugins;
 mod types;
 
@@ -67,8 +68,15 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<config::EmbeddingModelType>()?;
     m.add_class::<config::LanguageDetectionConfig>()?;
     m.add_class::<config::TokenReductionConfig>()?;
+    m.add_class::<config::StopwordsConfig>()?;
     m.add_class::<config::ImageExtractionConfig>()?;
     m.add_class::<config::PostProcessorConfig>()?;
+    m.add_class::<config::HeaderFooterConfig>()?;
+    m.add_class::<config::LineRepairConfig>()?;
+    m.add_class::<config::TextNormalizationConfig>()?;
+    m.add_class::<config::TextMetricsConfig>()?;
+    m.add_class::<config::EntityConfig>()?;
+    m.add_class::<config::QualityGateConfig>()?;
     m.add_class::<config::TesseractConfig>()?;
     m.add_class::<config::ImagePreprocessingConfig>()?;
 
@@ -84,6 +92,8 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     m.add_class::<types::ExtractionResult>()?;
     m.add_class::<types::ExtractedTable>()?;
+    m.add_class::<core::ExtractionSession>()?;
+    m.add_class::<image::OcrProcessor>()?;
 
     m.add_function(wrap_pyfunction!(core::extract_file_sync, m)?)?;
     m.add_function(wrap_pyfunction!(core::extract_bytes_sync, m)?)?;
@@ -94,6 +104,11 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(core::batch_extract_files, m)?)?;
     m.add_function(wrap_pyfunction!(core::batch_extract_bytes, m)?)?;
 
+    m.add_function(wrap_pyfunction!(image::normalize_image_dpi, m)?)?;
+    m.add_function(wrap_pyfunction!(image::calculate_optimal_dpi, m)?)?;
+    m.add_function(wrap_pyfunction!(image::convert_image, m)?)?;
+    m.add_function(wrap_pyfunction!(image::compress_image, m)?)?;
+
     m.add_function(wrap_pyfunction!(plugins::register_ocr_backend, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::unregister_ocr_backend, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::list_ocr_backends, m)?)?;
@@ -106,6 +121,10 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(plugins::unregister_validator, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::clear_validators, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::list_validators, m)?)?;
+    m.add_function(wrap_pyfunction!(plugins::register_image_describer, m)?)?;
+    m.add_function(wrap_pyfunction!(plugins::unregister_image_describer, m)?)?;
+    m.add_function(wrap_pyfunction!(plugins::list_image_describers, m)?)?;
+    m.add_function(wrap_pyfunction!(plugins::clear_image_describers, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::list_document_extractors, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::unregister_document_extractor, m)?)?;
     m.add_function(wrap_pyfunction!(plugins::clear_document_extractors, m)?)?;
@@ -122,6 +141,7 @@ fn _internal_bindings(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(get_extensions_for_mime, m)?)?;
     m.add_function(wrap_pyfunction!(get_last_error_code, m)?)?;
     m.add_function(wrap_pyfunction!(get_last_panic_context, m)?)?;
+    m.add_function(wrap_pyfunction!(configure_thread_pools, m)?)?;
 
     Ok(())
 }
@@ -339,6 +359,37 @@ fn get_last_error_code() -> i32 {
     ffi::get_last_error_code()
 }
 
+/// Configure the thread pools used for CPU-bound extraction work.
+///
+/// Extraction and OCR run on Kreuzberg's own pools instead of the interpreter's threads.
+/// Must be called before the first extraction; pools cannot be resized afterwards.
+///
+/// Args:
+///     rayon_threads (int | None): Threads in the parallel text/OCR pool (default: CPU cores)
+///     worker_threads (int | None): Worker threads of the internal async runtime (default: CPU cores)
+///     max_blocking_threads (int | None): Cap on blocking threads of the internal runtime (default: 512)
+///
+/// Raises:
+///     ValidationError: If a value is zero or the pools are already initialized
+///
+/// Example:
+///     >>> from kreuzberg import configure_thread_pools
+///     >>> configure_thread_pools(rayon_threads=4, max_blocking_threads=8)
+#[pyfunction]
+#[pyo3(signature = (rayon_threads=None, worker_threads=None, max_blocking_threads=None))]
+fn configure_thread_pools(
+    rayon_threads: Option<usize>,
+    worker_threads: Option<usize>,
+    max_blocking_threads: Option<usize>,
+) -> PyResult<()> {
+    kreuzberg::configure_thread_pools(kreuzberg::ThreadPoolConfig {
+        rayon_threads,
+        worker_threads,
+        max_blocking_threads,
+    })
+    .map_err(error::to_py_err)
+}
+
 /// Get panic context information from the last error.
 ///
 /// Returns JSON string with panic context if the last error was a panic,
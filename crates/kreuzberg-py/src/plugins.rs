@@ -9,6 +9,7 @@
 //! - **Python OCR backends** (EasyOCR, PaddleOCR, custom backends) to be used by Rust extraction
 //! - **Python PostProcessors** (entity extraction, keyword extraction, metadata enrichment) to enrich results
 //! - **Python Validators** (content validation, quality checks) to validate extraction results
+//! - **Python ImageDescribers** (local vision models, captioning APIs) to describe extracted images
 //!
 //! # GIL (Global Interpreter Lock) Management
 //!
@@ -123,8 +124,13 @@ use std::sync::Arc;
 
 use async_trait::async_trait;
 use kreuzberg::core::config::{ExtractionConfig, OcrConfig};
-use kreuzberg::plugins::registry::{get_ocr_backend_registry, get_post_processor_registry, get_validator_registry};
-use kreuzberg::plugins::{OcrBackend, OcrBackendType, Plugin, PostProcessor, ProcessingStage, Validator};
+use kreuzberg::plugins::registry::{
+    get_image_describer_registry, get_ocr_backend_registry, get_post_processor_registry, get_validator_registry,
+};
+use kreuzberg::plugins::{
+    ImageDescriber, ImageDescriptionContext, OcrBackend, OcrBackendType, Plugin, PostProcessor, ProcessingStage,
+    Validator,
+};
 use kreuzberg::types::{ExtractionResult, Table};
 use kreuzberg::{KreuzbergError, Result};
 
@@ -674,6 +680,11 @@ fn dict_to_extraction_result(_py: Python<'_>, dict: &Bound<'_, PyAny>) -> Result
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     })
 }
 
@@ -1882,3 +1893,204 @@ pub fn unregister_document_extractor(name: &str) -> PyResult<()> {
 pub fn clear_document_extractors() -> PyResult<()> {
     kreuzberg::plugins::clear_extractors().map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
 }
+
+/// Wrapper that makes a Python ImageDescriber usable from Rust.
+///
+/// The Python object's `describe_image(image: bytes, context: dict) -> str` is called with
+/// the image bytes and the [`ImageDescriptionContext`] as a dict.
+pub struct PythonImageDescriber {
+    /// Python object implementing the ImageDescriber protocol
+    python_obj: Py<PyAny>,
+    /// Cached describer name (to avoid repeated GIL acquisition)
+    name: String,
+}
+
+impl PythonImageDescriber {
+    /// Create a new Python ImageDescriber wrapper.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Python object doesn't have required methods
+    /// - The describer name is empty
+    pub fn new(py: Python<'_>, python_obj: Py<PyAny>) -> PyResult<Self> {
+        let obj = python_obj.bind(py);
+
+        validate_plugin_object(obj, "ImageDescriber", &["name", "describe_image"])?;
+
+        let name: String = obj.call_method0("name")?.extract()?;
+        if name.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ImageDescriber name cannot be empty",
+            ));
+        }
+
+        Ok(Self { python_obj, name })
+    }
+}
+
+impl Plugin for PythonImageDescriber {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn version(&self) -> String {
+        Python::attach(|py| {
+            self.python_obj
+                .bind(py)
+                .getattr("version")
+                .and_then(|v| v.call0())
+                .and_then(|v| v.extract::<String>())
+                .unwrap_or_else(|_| "1.0.0".to_string())
+        })
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Python::attach(|py| {
+            let obj = self.python_obj.bind(py);
+            if obj.hasattr("initialize")? {
+                obj.call_method0("initialize")?;
+            }
+            Ok(())
+        })
+        .map_err(|e: PyErr| KreuzbergError::Plugin {
+            message: format!("Failed to initialize Python ImageDescriber '{}': {}", self.name, e),
+            plugin_name: self.name.clone(),
+        })
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Python::attach(|py| {
+            let obj = self.python_obj.bind(py);
+            if obj.hasattr("shutdown")? {
+                obj.call_method0("shutdown")?;
+            }
+            Ok(())
+        })
+        .map_err(|e: PyErr| KreuzbergError::Plugin {
+            message: format!("Failed to shutdown Python ImageDescriber '{}': {}", self.name, e),
+            plugin_name: self.name.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl ImageDescriber for PythonImageDescriber {
+    async fn describe_image(&self, image: &[u8], context: &ImageDescriptionContext) -> Result<String> {
+        let describer_name = self.name.clone();
+        let context_json = serde_json::to_value(context)?;
+
+        tokio::task::block_in_place(|| {
+            Python::attach(|py| {
+                let obj = self.python_obj.bind(py);
+                let py_bytes = PyBytes::new(py, image);
+
+                let description = json_value_to_py(py, &context_json)
+                    .and_then(|py_context| obj.call_method1("describe_image", (py_bytes, py_context)))
+                    .and_then(|description| description.extract::<String>());
+
+                description.map_err(|e| KreuzbergError::Plugin {
+                    message: format!(
+                        "Python ImageDescriber '{}' failed during describe_image: {}",
+                        describer_name, e
+                    ),
+                    plugin_name: describer_name.clone(),
+                })
+            })
+        })
+    }
+}
+
+/// Register a Python ImageDescriber with the Rust core.
+///
+/// A registered describer is used when `ImageExtractionConfig.describer` names it, filling
+/// the `description` of every extracted image.
+///
+/// # Arguments
+///
+/// * `describer` - Python object implementing the ImageDescriber protocol
+///
+/// # Required Methods on Python Describer
+///
+/// - `name() -> str`: Return describer name
+/// - `describe_image(image: bytes, context: dict) -> str`: Describe one image
+///
+/// # Optional Methods
+///
+/// - `version() -> str`: Return describer version
+/// - `initialize()`: Called when describer is registered
+/// - `shutdown()`: Called when describer is unregistered
+///
+/// # Example
+///
+/// ```python
+/// from kreuzberg import ExtractionConfig, ImageExtractionConfig, register_image_describer
+///
+/// class Captioner:
+///     def name(self) -> str:
+///         return "captioner"
+///
+///     def describe_image(self, image: bytes, context: dict) -> str:
+///         return f"{context['format']} image"
+///
+/// register_image_describer(Captioner())
+/// config = ExtractionConfig(images=ImageExtractionConfig(describer="captioner"))
+/// ```
+#[pyfunction]
+pub fn register_image_describer(py: Python<'_>, describer: Py<PyAny>) -> PyResult<()> {
+    let rust_describer = PythonImageDescriber::new(py, describer)?;
+    let describer_name = rust_describer.name().to_string();
+
+    let arc_describer: Arc<dyn ImageDescriber> = Arc::new(rust_describer);
+
+    py.detach(|| {
+        let registry = get_image_describer_registry();
+        let mut registry = registry.write().map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!(
+                "Failed to acquire write lock on ImageDescriber registry: {}",
+                e
+            ))
+        })?;
+
+        registry.register(arc_describer).map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!(
+                "Failed to register ImageDescriber '{}': {}",
+                describer_name, e
+            ))
+        })
+    })?;
+
+    Ok(())
+}
+
+/// Unregister an ImageDescriber by name.
+///
+/// # Arguments
+///
+/// * `name` - Describer name to unregister
+#[pyfunction]
+pub fn unregister_image_describer(py: Python<'_>, name: &str) -> PyResult<()> {
+    py.detach(|| {
+        kreuzberg::plugins::unregister_image_describer(name).map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to unregister ImageDescriber '{}': {}", name, e))
+        })
+    })
+}
+
+/// List all registered image describer names.
+#[pyfunction]
+pub fn list_image_describers() -> PyResult<Vec<String>> {
+    kreuzberg::plugins::list_image_describers().map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))
+}
+
+/// Clear all registered ImageDescribers.
+///
+/// Useful for test cleanup or resetting state.
+#[pyfunction]
+pub fn clear_image_describers(py: Python<'_>) -> PyResult<()> {
+    py.detach(|| {
+        kreuzberg::plugins::clear_image_describers().map_err(|e| {
+            pyo3::exceptions::PyRuntimeError::new_err(format!("Failed to clear ImageDescriber registry: {}", e))
+        })
+    })
+}
@@ -18,7 +18,10 @@ use crate::plugins::json_value_to_py;
 ///     mime_type (str): MIME type of the extracted document
 ///     metadata (dict): Document metadata as key-value pairs
 ///     tables (list[ExtractedTable]): Extracted tables
-///     detected_languages (list[dict] | None): Detected languages with confidence scores
+///     detected_languages (list[dict] | None): Detected languages with ISO 639-3 code, confidence and script
+///     entities (list[dict] | None): Named entities with type, text, byte offsets and confidence
+///     keywords (list[dict] | None): Extracted keywords with text, score and algorithm
+///     warnings (list[dict]): Non-fatal issues with code, message, stage and page
 ///
 /// Example:
 ///     >>> from kreuzberg import extract_file_sync, ExtractionConfig
@@ -47,6 +50,12 @@ pub struct ExtractionResult {
     chunks: Option<Py<PyList>>,
 
     pages: Option<Py<PyList>>,
+
+    entities: Option<Py<PyList>>,
+
+    keywords: Option<Py<PyList>>,
+
+    warnings: Py<PyList>,
 }
 
 #[pymethods]
@@ -82,6 +91,21 @@ impl ExtractionResult {
         self.pages.as_ref().map(|pages| pages.bind(py).clone())
     }
 
+    #[getter]
+    fn entities<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyList>> {
+        self.entities.as_ref().map(|entities| entities.bind(py).clone())
+    }
+
+    #[getter]
+    fn keywords<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyList>> {
+        self.keywords.as_ref().map(|keywords| keywords.bind(py).clone())
+    }
+
+    #[getter]
+    fn warnings<'py>(&self, py: Python<'py>) -> Bound<'py, PyList> {
+        self.warnings.bind(py).clone()
+    }
+
     fn __repr__(&self) -> String {
         Python::attach(|py| {
             format!(
@@ -119,7 +143,14 @@ impl ExtractionResult {
         }
 
         let detected_languages = if let Some(langs) = result.detected_languages {
-            let lang_list = PyList::new(py, langs)?;
+            let lang_list = PyList::empty(py);
+            for lang in langs {
+                let lang_dict = PyDict::new(py);
+                lang_dict.set_item("language", &lang.language)?;
+                lang_dict.set_item("confidence", lang.confidence)?;
+                lang_dict.set_item("script", &lang.script)?;
+                lang_list.append(lang_dict)?;
+            }
             Some(lang_list.unbind())
         } else {
             None
@@ -142,6 +173,9 @@ impl ExtractionResult {
                 if let Some(height) = img.height {
                     img_dict.set_item("height", height)?;
                 }
+                if let Some(dpi) = img.dpi {
+                    img_dict.set_item("dpi", dpi)?;
+                }
                 if let Some(colorspace) = &img.colorspace {
                     img_dict.set_item("colorspace", colorspace)?;
                 }
@@ -228,6 +262,9 @@ impl ExtractionResult {
                     if let Some(height) = img.height {
                         img_dict.set_item("height", height)?;
                     }
+                    if let Some(dpi) = img.dpi {
+                        img_dict.set_item("dpi", dpi)?;
+                    }
                     if let Some(colorspace) = &img.colorspace {
                         img_dict.set_item("colorspace", colorspace)?;
                     }
@@ -249,6 +286,47 @@ impl ExtractionResult {
             None
         };
 
+        let entities = if let Some(ents) = result.entities {
+            let entity_list = PyList::empty(py);
+            for entity in ents {
+                let entity_dict = PyDict::new(py);
+                entity_dict.set_item("entity_type", entity.entity_type.as_str())?;
+                entity_dict.set_item("text", &entity.text)?;
+                entity_dict.set_item("byte_start", entity.byte_start)?;
+                entity_dict.set_item("byte_end", entity.byte_end)?;
+                entity_dict.set_item("confidence", entity.confidence)?;
+                entity_list.append(entity_dict)?;
+            }
+            Some(entity_list.unbind())
+        } else {
+            None
+        };
+
+        let keywords = if let Some(kws) = result.keywords {
+            let keyword_list = PyList::empty(py);
+            for keyword in kws {
+                let keyword_dict = PyDict::new(py);
+                keyword_dict.set_item("text", &keyword.text)?;
+                keyword_dict.set_item("score", keyword.score)?;
+                keyword_dict.set_item("algorithm", keyword.algorithm.as_str())?;
+                keyword_dict.set_item("positions", keyword.positions)?;
+                keyword_list.append(keyword_dict)?;
+            }
+            Some(keyword_list.unbind())
+        } else {
+            None
+        };
+
+        let warnings = PyList::empty(py);
+        for warning in result.warnings {
+            let warning_dict = PyDict::new(py);
+            warning_dict.set_item("code", &warning.code)?;
+            warning_dict.set_item("message", &warning.message)?;
+            warning_dict.set_item("stage", warning.stage.as_str())?;
+            warning_dict.set_item("page", warning.page)?;
+            warnings.append(warning_dict)?;
+        }
+
         Ok(Self {
             content: result.content,
             mime_type: result.mime_type,
@@ -258,6 +336,9 @@ impl ExtractionResult {
             images,
             chunks,
             pages,
+            entities,
+            keywords,
+            warnings: warnings.unbind(),
         })
     }
 }
@@ -288,10 +369,19 @@ mod tests {
                 mime_type: "text/plain".to_string(),
                 metadata: kreuzberg::Metadata::default(),
                 tables: Vec::new(),
-                detected_languages: Some(vec!["en".to_string()]),
+                detected_languages: Some(vec![kreuzberg::DetectedLanguage {
+                    language: "eng".to_string(),
+                    confidence: 0.9,
+                    script: "Latin".to_string(),
+                }]),
                 chunks: None,
                 images: None,
                 pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             };
 
             let py_result = ExtractionResult::from_rust(rust_result, py).expect("conversion should succeed");
@@ -300,6 +390,7 @@ mod tests {
             assert!(py_result.metadata(py).is_empty());
             assert_eq!(py_result.tables(py).len(), 0);
             assert!(py_result.detected_languages.is_some());
+            assert!(py_result.warnings(py).is_empty());
             assert_eq!(py_result.__str__(), "ExtractionResult: 5 characters");
             let repr = py_result.__repr__();
             assert!(repr.contains("mime_type='text/plain'"));
@@ -317,6 +408,12 @@ mod tests {
                 detected_languages: None,
                 chunks: None,
                 images: None,
+                pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             };
             rust_result
                 .metadata
@@ -22,10 +22,16 @@ use wasm_bindgen::prelude::*;
 /// - `Plugin` → Plugin-specific error
 /// - `LockPoisoned` → Lock poisoning (internal error)
 /// - `UnsupportedFormat` → Unsupported MIME type
+/// - `LimitExceeded` → Resource limit exceeded
+/// - `EncryptedDocument` → No configured password opens the document
 /// - `Other` → Generic error
+///
+/// The JavaScript error also carries the `code`, `stage`, `plugin` and `retryable`
+/// properties of the error's `kreuzberg::ErrorInfo`.
 pub fn convert_error(err: KreuzbergError) -> JsValue {
     use kreuzberg::KreuzbergError;
 
+    let info = err.to_info();
     let (error_type, message) = match err {
         KreuzbergError::Io(e) => ("IOError", format!("IO error: {}", e)),
 
@@ -57,6 +63,10 @@ pub fn convert_error(err: KreuzbergError) -> JsValue {
             ("UnsupportedFormatError", format!("Unsupported format: {}", format))
         }
 
+        err @ KreuzbergError::LimitExceeded { .. } => ("LimitExceededError", err.to_string()),
+
+        err @ KreuzbergError::EncryptedDocument(_) => ("EncryptedDocumentError", err.to_string()),
+
         KreuzbergError::Other(msg) => ("Error", msg),
     };
 
@@ -72,12 +82,32 @@ pub fn convert_error(err: KreuzbergError) -> JsValue {
         });
 
     match error_constructor {
-        Some(ctor) => js_sys::Reflect::construct(&ctor, &js_sys::Array::of1(&JsValue::from_str(&message)))
-            .unwrap_or_else(|_| JsValue::from_str(&format!("{}: {}", error_type, message))),
+        Some(ctor) => match js_sys::Reflect::construct(&ctor, &js_sys::Array::of1(&JsValue::from_str(&message))) {
+            Ok(error) => {
+                set_error_info(&error, &info);
+                error
+            }
+            Err(_) => JsValue::from_str(&format!("{}: {}", error_type, message)),
+        },
         None => JsValue::from_str(&format!("{}: {}", error_type, message)),
     }
 }
 
+/// Set the `ErrorInfo` fields as properties of a JavaScript error object.
+fn set_error_info(error: &JsValue, info: &kreuzberg::ErrorInfo) {
+    let optional = |value: &Option<String>| value.as_deref().map_or(JsValue::NULL, JsValue::from_str);
+    let properties = [
+        ("code", JsValue::from_str(info.code.as_str())),
+        ("stage", optional(&info.stage)),
+        ("plugin", optional(&info.plugin)),
+        ("retryable", JsValue::from_bool(info.retryable)),
+    ];
+
+    for (key, value) in properties {
+        let _ = js_sys::Reflect::set(error, &JsValue::from_str(key), &value);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,6 +124,15 @@ mod tests {
         assert!(!result.is_undefined());
     }
 
+    #[wasm_bindgen_test]
+    fn test_convert_error_sets_error_code() {
+        let err = KreuzbergError::UnsupportedFormat("application/x-custom".to_string());
+        let result = convert_error(err);
+
+        let code = js_sys::Reflect::get(&result, &JsValue::from_str("code")).unwrap();
+        assert_eq!(code.as_string().as_deref(), Some("unsupported_format"));
+    }
+
     #[wasm_bindgen_test]
     fn test_convert_error_parsing_error_returns_jsvalue() {
         let err = KreuzbergError::Parsing {
@@ -101,6 +101,7 @@ pub mod errors;
 pub mod extraction;
 pub mod mime;
 pub mod plugins;
+pub mod session;
 pub mod types;
 
 // Re-export common types and functions
@@ -109,6 +110,7 @@ pub use errors::*;
 pub use extraction::*;
 pub use mime::*;
 pub use plugins::*;
+pub use session::*;
 pub use types::*;
 
 /// Version of the kreuzberg-wasm binding
@@ -880,6 +880,11 @@ impl OcrBackend for JsOcrBackendWrapper {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -916,6 +921,11 @@ impl OcrBackend for JsOcrBackendWrapper {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -929,6 +939,11 @@ impl OcrBackend for JsOcrBackendWrapper {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
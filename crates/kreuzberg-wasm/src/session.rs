@@ -0,0 +1,71 @@
+//! Reusable extraction sessions for WASM
+//!
+//! Exposes [`kreuzberg::ExtractionSession`] as the `ExtractionSession` JavaScript class. A
+//! session registers the built-in extractors and resolves them once, so repeated extractions
+//! with the same configuration skip that work.
+//!
+//! Only asynchronous byte extraction is offered: the session's synchronous methods block on
+//! the Tokio runtime, which WASM builds don't have, and there is no file system to read from.
+
+use crate::errors::convert_error;
+use crate::types::{parse_config, result_to_js_value};
+use js_sys::Uint8Array;
+use wasm_bindgen::prelude::*;
+
+/// Pre-warmed extraction context reused across calls.
+///
+/// # Example
+///
+/// ```javascript
+/// import { ExtractionSession } from '@kreuzberg/wasm';
+///
+/// const session = new ExtractionSession({ useCache: false });
+/// for (const data of documents) {
+///   const result = await session.extractBytes(data, 'application/pdf');
+///   console.log(result.content);
+/// }
+/// ```
+#[wasm_bindgen(js_name = ExtractionSession)]
+pub struct WasmExtractionSession {
+    inner: kreuzberg::ExtractionSession,
+}
+
+#[wasm_bindgen(js_class = ExtractionSession)]
+impl WasmExtractionSession {
+    /// Create a session.
+    ///
+    /// # JavaScript Parameters
+    ///
+    /// * `config?: object` - Extraction configuration used for every call
+    ///
+    /// # Throws
+    ///
+    /// Throws an error if the configuration is invalid or plugin initialization fails.
+    #[wasm_bindgen(constructor)]
+    pub fn new(config: Option<JsValue>) -> Result<WasmExtractionSession, JsValue> {
+        let extraction_config = parse_config(config)?;
+        let inner = kreuzberg::ExtractionSession::new(extraction_config).map_err(convert_error)?;
+        Ok(Self { inner })
+    }
+
+    /// Extract content from a byte array with the session's configuration (asynchronous).
+    ///
+    /// # JavaScript Parameters
+    ///
+    /// * `data: Uint8Array` - The document bytes to extract
+    /// * `mimeType: string` - MIME type of the data
+    ///
+    /// # Returns
+    ///
+    /// `Promise<object>` - Promise resolving to ExtractionResult
+    #[wasm_bindgen(js_name = extractBytes)]
+    pub fn extract_bytes(&self, data: Uint8Array, mime_type: String) -> js_sys::Promise {
+        let bytes = data.to_vec();
+        let session = self.inner.clone();
+
+        wasm_bindgen_futures::future_to_promise(async move {
+            let result = session.extract_bytes(&bytes, &mime_type).await.map_err(convert_error)?;
+            result_to_js_value(&result)
+        })
+    }
+}
@@ -184,6 +184,18 @@ fn test_extract_bytes_async_with_config() {
     assert!(!promise.is_null(), "Async extraction with config should return Promise");
 }
 
+/// Test a session extracts text asynchronously with its configuration
+#[wasm_bindgen_test]
+async fn test_extraction_session_extracts_bytes() {
+    let session = WasmExtractionSession::new(None).expect("Session should be created with defaults");
+
+    let text = unsafe { Uint8Array::view(TEXT_DATA) };
+    let promise = session.extract_bytes(text, "text/plain".to_string());
+    let result = wasm_bindgen_futures::JsFuture::from(promise).await;
+
+    assert!(result.is_ok(), "Session extraction of plain text should succeed");
+}
+
 /// Test batch extraction sync with matching lengths
 #[wasm_bindgen_test]
 fn test_batch_extract_sync_matching_lengths_succeeds() {
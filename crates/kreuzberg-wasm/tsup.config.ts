@@ -5,6 +5,7 @@ export default defineConfig({
 		"typescript/index.ts",
 		"typescript/runtime.ts",
 		"typescript/adapters/wasm-adapter.ts",
+		"typescript/cache/result-cache.ts",
 		"typescript/ocr/registry.ts",
 		"typescript/ocr/tesseract-wasm-backend.ts",
 	],
@@ -188,7 +188,7 @@ describe("WASM Adapter", () => {
 				mimeType: "text/plain",
 				metadata: { pageCount: 1 },
 				tables: [],
-				detectedLanguages: ["en"],
+				detectedLanguages: [{ language: "eng", confidence: 0.99, script: "Latin" }],
 			};
 
 			const result = jsToExtractionResult(jsValue);
@@ -340,12 +340,16 @@ describe("WASM Adapter", () => {
 				content: "Bonjour",
 				mimeType: "text/plain",
 				metadata: {},
-				detectedLanguages: ["fr", "en"],
+				detectedLanguages: [
+					{ language: "fra", confidence: 0.91, script: "Latin" },
+					{ language: "eng", confidence: 0.42, script: "Latin" },
+				],
 			};
 
 			const result = jsToExtractionResult(jsValue);
 
-			expect(result.detectedLanguages).toEqual(["fr", "en"]);
+			expect(result.detectedLanguages?.map((lang) => lang.language)).toEqual(["fra", "eng"]);
+			expect(result.detectedLanguages?.[0].confidence).toBe(0.91);
 		});
 
 		it("should throw if chunk has invalid metadata", () => {
@@ -392,15 +396,15 @@ describe("WASM Adapter", () => {
 			expect(() => jsToExtractionResult(jsValue)).toThrow("data must be Uint8Array");
 		});
 
-		it("should validate detectedLanguages are strings", () => {
+		it("should validate detectedLanguages are objects", () => {
 			const jsValue = {
 				content: "test",
 				mimeType: "text/plain",
 				metadata: {},
-				detectedLanguages: ["en", 123],
+				detectedLanguages: ["en"],
 			};
 
-			expect(() => jsToExtractionResult(jsValue)).toThrow("detectedLanguages must contain only strings");
+			expect(() => jsToExtractionResult(jsValue)).toThrow("detectedLanguages must contain only objects");
 		});
 	});
 
@@ -428,6 +432,17 @@ describe("WASM Adapter", () => {
 			expect(wrapped.message).toContain("extracting from bytes");
 		});
 
+		it("should keep structured error info", () => {
+			const original = Object.assign(new Error("Unsupported format: x"), {
+				code: "unsupported_format",
+				retryable: false,
+			});
+			const wrapped = wrapWasmError(original, "context") as Error & { code?: string; retryable?: boolean };
+
+			expect(wrapped.code).toBe("unsupported_format");
+			expect(wrapped.retryable).toBe(false);
+		});
+
 		it("should preserve original error as cause", () => {
 			const original = new Error("Original message");
 			const wrapped = wrapWasmError(original, "context");
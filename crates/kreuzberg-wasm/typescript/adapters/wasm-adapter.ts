@@ -26,7 +26,17 @@
  * ```
  */
 
-import type { Chunk, ExtractedImage, ExtractionConfig, ExtractionResult, Table } from "../types.js";
+import type {
+	Chunk,
+	DetectedLanguage,
+	ExtractedImage,
+	ExtractionConfig,
+	ExtractionResult,
+	ExtractionWarning,
+	Keyword,
+	Table,
+	WarningStage,
+} from "../types.js";
 
 /**
  * Maximum file size for processing (512 MB)
@@ -291,6 +301,9 @@ export function jsToExtractionResult(jsValue: unknown): ExtractionResult {
 				if (!isNumberOrNull(img.height)) {
 					throw new Error("Invalid image: height must be a number or null");
 				}
+				if (img.dpi !== undefined && !isNumberOrNull(img.dpi)) {
+					throw new Error("Invalid image: dpi must be a number or null");
+				}
 				if (!isNumberOrNull(img.bitsPerComponent)) {
 					throw new Error("Invalid image: bitsPerComponent must be a number or null");
 				}
@@ -315,6 +328,7 @@ export function jsToExtractionResult(jsValue: unknown): ExtractionResult {
 					pageNumber: img.pageNumber,
 					width: img.width,
 					height: img.height,
+					dpi: img.dpi,
 					colorspace: img.colorspace,
 					bitsPerComponent: img.bitsPerComponent,
 					isMask: img.isMask,
@@ -324,14 +338,60 @@ export function jsToExtractionResult(jsValue: unknown): ExtractionResult {
 			})
 		: null;
 
-	// Validate detectedLanguages array
-	let detectedLanguages: string[] | null = null;
-	if (Array.isArray(result.detectedLanguages)) {
-		if (!result.detectedLanguages.every((lang) => typeof lang === "string")) {
-			throw new Error("Invalid result: detectedLanguages must contain only strings");
-		}
-		detectedLanguages = result.detectedLanguages;
-	}
+	// Parse detected languages
+	const detectedLanguages: DetectedLanguage[] | null = Array.isArray(result.detectedLanguages)
+		? result.detectedLanguages.map((lang) => {
+				if (!lang || typeof lang !== "object") {
+					throw new Error("Invalid result: detectedLanguages must contain only objects");
+				}
+				const l = lang as Record<string, unknown>;
+				if (typeof l.language !== "string" || typeof l.confidence !== "number" || typeof l.script !== "string") {
+					throw new Error("Invalid detected language: language and script must be strings and confidence a number");
+				}
+				return { language: l.language, confidence: l.confidence, script: l.script };
+			})
+		: null;
+
+	// Parse keywords
+	const keywords: Keyword[] | null = Array.isArray(result.keywords)
+		? result.keywords.map((keyword) => {
+				if (!keyword || typeof keyword !== "object") {
+					throw new Error("Invalid keyword structure");
+				}
+				const k = keyword as Record<string, unknown>;
+				if (typeof k.text !== "string" || typeof k.score !== "number") {
+					throw new Error("Invalid keyword: text must be a string and score a number");
+				}
+				if (k.algorithm !== "yake" && k.algorithm !== "rake") {
+					throw new Error("Invalid keyword: algorithm must be 'yake' or 'rake'");
+				}
+				return {
+					text: k.text,
+					score: k.score,
+					algorithm: k.algorithm,
+					positions: Array.isArray(k.positions) ? (k.positions as number[]) : null,
+				};
+			})
+		: null;
+
+	// Parse warnings
+	const warnings: ExtractionWarning[] = Array.isArray(result.warnings)
+		? result.warnings.map((warning) => {
+				if (!warning || typeof warning !== "object") {
+					throw new Error("Invalid warning structure");
+				}
+				const w = warning as Record<string, unknown>;
+				if (typeof w.code !== "string" || typeof w.message !== "string" || typeof w.stage !== "string") {
+					throw new Error("Invalid warning: code, message and stage must be strings");
+				}
+				return {
+					code: w.code,
+					message: w.message,
+					stage: w.stage as WarningStage,
+					page: typeof w.page === "number" ? w.page : null,
+				};
+			})
+		: [];
 
 	return {
 		content: result.content,
@@ -341,14 +401,31 @@ export function jsToExtractionResult(jsValue: unknown): ExtractionResult {
 		detectedLanguages,
 		chunks,
 		images,
+		keywords,
+		warnings,
 	};
 }
 
+const ERROR_INFO_KEYS = ["code", "stage", "plugin", "retryable"] as const;
+
+/**
+ * Copy the structured error info (`code`, `stage`, `plugin`, `retryable`) set by the WASM module
+ */
+function copyErrorInfo(source: Error, target: Error): Error {
+	for (const key of ERROR_INFO_KEYS) {
+		if (key in source) {
+			Reflect.set(target, key, Reflect.get(source, key));
+		}
+	}
+	return target;
+}
+
 /**
  * Wrap and format WASM errors with context
  *
  * Converts WASM error messages to JavaScript Error objects with proper context
- * and stack trace information when available.
+ * and stack trace information when available. The structured error info of
+ * errors raised by the WASM module (`code`, `stage`, `plugin`, `retryable`) is kept.
  *
  * @param error - The error from WASM
  * @param context - Additional context about what operation failed
@@ -367,9 +444,10 @@ export function jsToExtractionResult(jsValue: unknown): ExtractionResult {
  */
 export function wrapWasmError(error: unknown, context: string): Error {
 	if (error instanceof Error) {
-		return new Error(`Error ${context}: ${error.message}`, {
+		const wrapped = new Error(`Error ${context}: ${error.message}`, {
 			cause: error,
 		});
+		return copyErrorInfo(error, wrapped);
 	}
 
 	const message = String(error);
@@ -0,0 +1,83 @@
+// @vitest-environment node
+import type { ExtractionResult } from "../types.js";
+import { afterEach, describe, expect, it } from "vitest";
+import {
+	computeCacheKey,
+	createDefaultCacheBackend,
+	deserializeResult,
+	getCacheBackend,
+	MemoryCacheBackend,
+	readCachedResult,
+	serializeResult,
+	setCacheBackend,
+	writeCachedResult,
+} from "./result-cache.js";
+
+const result: ExtractionResult = {
+	content: "cached content",
+	mimeType: "image/png",
+	metadata: {},
+	tables: [],
+	images: [{ data: new Uint8Array([0, 1, 2, 254, 255]), format: "png", imageIndex: 0 }],
+};
+
+describe("Result Cache", () => {
+	afterEach(() => {
+		setCacheBackend(null);
+	});
+
+	it("should keep image bytes when serializing results", () => {
+		const restored = deserializeResult(serializeResult(result));
+
+		expect(restored.content).toBe("cached content");
+		expect(restored.images?.[0]?.data).toBeInstanceOf(Uint8Array);
+		expect(Array.from(restored.images?.[0]?.data ?? [])).toEqual([0, 1, 2, 254, 255]);
+	});
+
+	it("should derive keys from the bytes, MIME type, config and version", async () => {
+		const data = new Uint8Array([1, 2, 3]);
+		const key = await computeCacheKey(data, "text/plain", {}, "4.0.0");
+
+		expect(key).toMatch(/^[0-9a-f]{64}-[0-9a-f]{16}$/);
+		expect(await computeCacheKey(data, "text/plain", {}, "4.0.0")).toBe(key);
+		expect(await computeCacheKey(new Uint8Array([1, 2, 4]), "text/plain", {}, "4.0.0")).not.toBe(key);
+		expect(await computeCacheKey(data, "text/plain", { chunking: { maxChars: 10 } }, "4.0.0")).not.toBe(key);
+		expect(await computeCacheKey(data, "text/plain", {}, "4.0.1")).not.toBe(key);
+	});
+
+	it("should store and clear results in the active backend", async () => {
+		setCacheBackend(new MemoryCacheBackend());
+		const cache = getCacheBackend();
+		expect(cache?.name()).toBe("memory");
+		if (!cache) {
+			return;
+		}
+
+		expect(await readCachedResult(cache, "key")).toBeNull();
+		await writeCachedResult(cache, "key", result);
+		expect((await readCachedResult(cache, "key"))?.content).toBe("cached content");
+
+		await cache.clear();
+		expect(await readCachedResult(cache, "key")).toBeNull();
+	});
+
+	it("should treat failing backends as cache misses", async () => {
+		const failing = {
+			name: () => "failing",
+			get: async () => {
+				throw new Error("quota exceeded");
+			},
+			set: async () => {
+				throw new Error("quota exceeded");
+			},
+			clear: async () => undefined,
+		};
+
+		await expect(writeCachedResult(failing, "key", result)).resolves.toBeUndefined();
+		await expect(readCachedResult(failing, "key")).resolves.toBeNull();
+	});
+
+	it("should have no default cache outside of browsers", () => {
+		expect(createDefaultCacheBackend()).toBeNull();
+	});
+});
@@ -0,0 +1,358 @@
+/**
+ * Extraction Result Cache
+ *
+ * Caches extraction results so that extracting the same document again with the same
+ * configuration skips the WASM extraction, like the disk cache of the native bindings.
+ *
+ * In browsers, results are stored in the Origin Private File System (OPFS) where available
+ * and in IndexedDB otherwise. Other runtimes have no cache unless one is set with
+ * {@link setCacheBackend}. Entries are keyed by the SHA-256 of the document bytes, the MIME
+ * type, the configuration and the Kreuzberg version.
+ *
+ * @example
+ * ```typescript
+ * import { clearCache, extractBytes, setCacheBackend, MemoryCacheBackend } from '@kreuzberg/wasm';
+ *
+ * // Second call is served from the cache
+ * await extractBytes(bytes, 'application/pdf');
+ * await extractBytes(bytes, 'application/pdf');
+ *
+ * // Bypass the cache for one call
+ * await extractBytes(bytes, 'application/pdf', { useCache: false });
+ *
+ * // Keep results in memory instead, e.g. in Node.js
+ * setCacheBackend(new MemoryCacheBackend());
+ *
+ * await clearCache();
+ * ```
+ */
+
+import { isBrowser } from "../runtime.js";
+import type { CacheBackend, ExtractionResult } from "../types.js";
+
+const DEFAULT_CACHE_NAME = "kreuzberg-cache";
+const STORE_NAME = "results";
+
+/** Tag of Uint8Array values (image data) in serialized results */
+const BYTES_TAG = "__kreuzberg_bytes__";
+
+function bytesToBase64(bytes: Uint8Array): string {
+	let binary = "";
+	for (let offset = 0; offset < bytes.length; offset += 0x8000) {
+		binary += String.fromCharCode(...bytes.subarray(offset, offset + 0x8000));
+	}
+	return btoa(binary);
+}
+
+function base64ToBytes(base64: string): Uint8Array {
+	const binary = atob(base64);
+	const bytes = new Uint8Array(binary.length);
+	for (let i = 0; i < binary.length; i++) {
+		bytes[i] = binary.charCodeAt(i);
+	}
+	return bytes;
+}
+
+/**
+ * Serialize an extraction result to JSON, keeping image data as bytes
+ *
+ * @param result - Extraction result
+ * @returns JSON text, read back with {@link deserializeResult}
+ */
+export function serializeResult(result: ExtractionResult): string {
+	return JSON.stringify(result, (_key, value: unknown) =>
+		value instanceof Uint8Array ? { [BYTES_TAG]: bytesToBase64(value) } : value,
+	);
+}
+
+/**
+ * Deserialize an extraction result written by {@link serializeResult}
+ *
+ * @param text - JSON text
+ * @returns The extraction result
+ */
+export function deserializeResult(text: string): ExtractionResult {
+	return JSON.parse(text, (_key, value: unknown) => {
+		if (value !== null && typeof value === "object" && BYTES_TAG in value) {
+			return base64ToBytes((value as Record<string, string>)[BYTES_TAG] ?? "");
+		}
+		return value;
+	}) as ExtractionResult;
+}
+
+/**
+ * Cache backed by the Origin Private File System, one JSON file per result
+ */
+export class OpfsCacheBackend implements CacheBackend {
+	private readonly directoryName: string;
+
+	/**
+	 * @param directoryName - OPFS directory holding the cached results
+	 */
+	constructor(directoryName: string = DEFAULT_CACHE_NAME) {
+		this.directoryName = directoryName;
+	}
+
+	/**
+	 * Check whether the Origin Private File System is available
+	 */
+	static isSupported(): boolean {
+		return typeof navigator !== "undefined" && typeof navigator.storage?.getDirectory === "function";
+	}
+
+	name(): string {
+		return "opfs";
+	}
+
+	async get(key: string): Promise<ExtractionResult | null> {
+		try {
+			const directory = await this.directory();
+			const handle = await directory.getFileHandle(`${key}.json`);
+			const file = await handle.getFile();
+			return deserializeResult(await file.text());
+		} catch (error) {
+			if (error instanceof DOMException && error.name === "NotFoundError") {
+				return null;
+			}
+			throw error;
+		}
+	}
+
+	async set(key: string, result: ExtractionResult): Promise<void> {
+		const directory = await this.directory();
+		const handle = await directory.getFileHandle(`${key}.json`, { create: true });
+		const writable = await handle.createWritable();
+		try {
+			await writable.write(serializeResult(result));
+		} finally {
+			await writable.close();
+		}
+	}
+
+	async clear(): Promise<void> {
+		const root = await navigator.storage.getDirectory();
+		try {
+			await root.removeEntry(this.directoryName, { recursive: true });
+		} catch (error) {
+			if (!(error instanceof DOMException && error.name === "NotFoundError")) {
+				throw error;
+			}
+		}
+	}
+
+	private async directory(): Promise<FileSystemDirectoryHandle> {
+		const root = await navigator.storage.getDirectory();
+		return root.getDirectoryHandle(this.directoryName, { create: true });
+	}
+}
+
+/**
+ * Cache backed by an IndexedDB object store
+ */
+export class IndexedDbCacheBackend implements CacheBackend {
+	private readonly databaseName: string;
+
+	/**
+	 * @param databaseName - IndexedDB database holding the cached results
+	 */
+	constructor(databaseName: string = DEFAULT_CACHE_NAME) {
+		this.databaseName = databaseName;
+	}
+
+	/**
+	 * Check whether IndexedDB is available
+	 */
+	static isSupported(): boolean {
+		return typeof indexedDB !== "undefined";
+	}
+
+	name(): string {
+		return "indexeddb";
+	}
+
+	async get(key: string): Promise<ExtractionResult | null> {
+		const result = await this.request<unknown>("readonly", (store) => store.get(key));
+		return typeof result === "string" ? deserializeResult(result) : null;
+	}
+
+	async set(key: string, result: ExtractionResult): Promise<void> {
+		await this.request("readwrite", (store) => store.put(serializeResult(result), key));
+	}
+
+	async clear(): Promise<void> {
+		await this.request("readwrite", (store) => store.clear());
+	}
+
+	private open(): Promise<IDBDatabase> {
+		return new Promise((resolve, reject) => {
+			const request = indexedDB.open(this.databaseName, 1);
+			request.onupgradeneeded = () => {
+				request.result.createObjectStore(STORE_NAME);
+			};
+			request.onsuccess = () => resolve(request.result);
+			request.onerror = () => reject(request.error);
+		});
+	}
+
+	private async request<T>(mode: IDBTransactionMode, run: (store: IDBObjectStore) => IDBRequest<T>): Promise<T> {
+		const database = await this.open();
+		try {
+			return await new Promise<T>((resolve, reject) => {
+				const transaction = database.transaction(STORE_NAME, mode);
+				const request = run(transaction.objectStore(STORE_NAME));
+				transaction.oncomplete = () => resolve(request.result);
+				transaction.onerror = () => reject(transaction.error ?? request.error);
+				transaction.onabort = () => reject(transaction.error);
+			});
+		} finally {
+			database.close();
+		}
+	}
+}
+
+/**
+ * Cache holding results in memory for the lifetime of the page or process
+ */
+export class MemoryCacheBackend implements CacheBackend {
+	private readonly entries = new Map<string, string>();
+
+	name(): string {
+		return "memory";
+	}
+
+	async get(key: string): Promise<ExtractionResult | null> {
+		const entry = this.entries.get(key);
+		return entry === undefined ? null : deserializeResult(entry);
+	}
+
+	async set(key: string, result: ExtractionResult): Promise<void> {
+		this.entries.set(key, serializeResult(result));
+	}
+
+	async clear(): Promise<void> {
+		this.entries.clear();
+	}
+}
+
+/** Active cache backend; undefined until the default has been chosen */
+let activeBackend: CacheBackend | null | undefined;
+
+/**
+ * Create the default cache backend of the current runtime
+ *
+ * @returns An OPFS cache in browsers that support it, an IndexedDB cache in other browsers,
+ * and null outside of browsers
+ */
+export function createDefaultCacheBackend(): CacheBackend | null {
+	if (!isBrowser()) {
+		return null;
+	}
+	if (OpfsCacheBackend.isSupported()) {
+		return new OpfsCacheBackend();
+	}
+	if (IndexedDbCacheBackend.isSupported()) {
+		return new IndexedDbCacheBackend();
+	}
+	return null;
+}
+
+/**
+ * Set the cache used by extractBytes, extractFile and extractFromFile
+ *
+ * @param backend - Cache backend, or null to disable caching
+ *
+ * @example
+ * ```typescript
+ * import { IndexedDbCacheBackend, setCacheBackend } from '@kreuzberg/wasm';
+ *
+ * setCacheBackend(new IndexedDbCacheBackend('my-app-extractions'));
+ * ```
+ */
+export function setCacheBackend(backend: CacheBackend | null): void {
+	activeBackend = backend;
+}
+
+/**
+ * Get the active cache backend
+ *
+ * @returns The backend set with {@link setCacheBackend}, otherwise the default backend of the
+ * runtime (null outside of browsers)
+ */
+export function getCacheBackend(): CacheBackend | null {
+	if (activeBackend === undefined) {
+		activeBackend = createDefaultCacheBackend();
+	}
+	return activeBackend;
+}
+
+/**
+ * Remove all results from the active cache
+ */
+export async function clearCache(): Promise<void> {
+	await getCacheBackend()?.clear();
+}
+
+function toHex(buffer: ArrayBuffer): string {
+	return Array.from(new Uint8Array(buffer), (byte) => byte.toString(16).padStart(2, "0")).join("");
+}
+
+/**
+ * Compute the cache key of an extraction
+ *
+ * @param data - Document bytes
+ * @param mimeType - MIME type of the document
+ * @param config - Normalized extraction configuration
+ * @param version - Kreuzberg version, so that upgrades do not return stale results
+ * @returns Hex key, or null when the Web Crypto API is unavailable
+ */
+export async function computeCacheKey(
+	data: Uint8Array,
+	mimeType: string,
+	config: Record<string, unknown>,
+	version: string,
+): Promise<string | null> {
+	const subtle = globalThis.crypto?.subtle;
+	if (!subtle) {
+		return null;
+	}
+
+	try {
+		const options = new TextEncoder().encode(JSON.stringify([mimeType, config, version]));
+		const dataHash = await subtle.digest("SHA-256", data as Uint8Array<ArrayBuffer>);
+		const optionsHash = await subtle.digest("SHA-256", options);
+		return `${toHex(dataHash)}-${toHex(optionsHash).slice(0, 16)}`;
+	} catch {
+		return null;
+	}
+}
+
+/**
+ * Look up a cached result, treating cache failures as misses
+ *
+ * @param backend - Cache backend
+ * @param key - Cache key
+ * @returns The cached result, or null
+ */
+export async function readCachedResult(backend: CacheBackend, key: string): Promise<ExtractionResult | null> {
+	try {
+		return await backend.get(key);
+	} catch (error) {
+		console.warn(`Failed to read from ${backend.name()} cache:`, error);
+		return null;
+	}
+}
+
+/**
+ * Store a result in the cache, ignoring cache failures such as an exceeded storage quota
+ *
+ * @param backend - Cache backend
+ * @param key - Cache key
+ * @param result - Extraction result
+ */
+export async function writeCachedResult(backend: CacheBackend, key: string, result: ExtractionResult): Promise<void> {
+	try {
+		await backend.set(key, result);
+	} catch (error) {
+		console.warn(`Failed to write to ${backend.name()} cache:`, error);
+	}
+}
@@ -97,6 +97,7 @@
 import type { ExtractionConfig as ExtractionConfigType, ExtractionResult } from "./types.js";
 
 import { configToJS, fileToUint8Array, jsToExtractionResult, wrapWasmError } from "./adapters/wasm-adapter.js";
+import { computeCacheKey, getCacheBackend, readCachedResult, writeCachedResult } from "./cache/result-cache.js";
 import { registerOcrBackend } from "./ocr/registry.js";
 import { TesseractWasmBackend } from "./ocr/tesseract-wasm-backend.js";
 import { detectRuntime, hasWasm, isBrowser } from "./runtime.js";
@@ -121,6 +122,17 @@ export {
 	wrapWasmError,
 } from "./adapters/wasm-adapter.js";
 
+// Re-export result cache
+export {
+	clearCache,
+	createDefaultCacheBackend,
+	getCacheBackend,
+	IndexedDbCacheBackend,
+	MemoryCacheBackend,
+	OpfsCacheBackend,
+	setCacheBackend,
+} from "./cache/result-cache.js";
+
 // Re-export OCR registry
 export {
 	clearOcrBackends,
@@ -175,6 +187,12 @@ type WasmModule = {
 	) => unknown;
 	extractFile: (file: File, mimeType: string | null, config: Record<string, unknown> | null) => Promise<unknown>;
 	batchExtractFiles: (files: File[], config: Record<string, unknown> | null) => Promise<unknown>;
+	ExtractionSession: new (
+		config: Record<string, unknown> | null,
+	) => {
+		extractBytes: (data: Uint8Array, mimeType: string) => Promise<unknown>;
+		free: () => void;
+	};
 
 	// MIME and file type utilities
 	detectMimeFromBytes: (data: Uint8Array) => string;
@@ -380,6 +398,9 @@ export function getInitializationError(): Error | null {
  *
  * Extracts text, metadata, tables, images, and other content from document bytes.
  * Automatically detects document type from MIME type and applies appropriate extraction logic.
+ * Repeated extractions of the same bytes with the same configuration are served from the
+ * result cache (OPFS or IndexedDB in browsers, see {@link setCacheBackend}) unless
+ * `config.useCache` is false.
  *
  * @param data - The document bytes to extract from
  * @param mimeType - MIME type of the document (e.g., 'application/pdf', 'image/jpeg')
@@ -442,6 +463,16 @@ export async function extractBytes(
 		// Normalize config for WASM
 		const normalizedConfig = configToJS(config ?? null);
 
+		// Serve repeated extractions from the cache
+		const cache = config?.useCache === false ? null : getCacheBackend();
+		const cacheKey = cache ? await computeCacheKey(data, mimeType, normalizedConfig, wasm.version()) : null;
+		if (cache && cacheKey) {
+			const cached = await readCachedResult(cache, cacheKey);
+			if (cached) {
+				return cached;
+			}
+		}
+
 		// Call WASM function
 		const result = await wasm.extractBytes(data, mimeType, normalizedConfig);
 
@@ -451,7 +482,11 @@ export async function extractBytes(
 		}
 
 		// Convert and return result
-		return jsToExtractionResult(result);
+		const extractionResult = jsToExtractionResult(result);
+		if (cache && cacheKey) {
+			await writeCachedResult(cache, cacheKey, extractionResult);
+		}
+		return extractionResult;
 	} catch (error) {
 		throw wrapWasmError(error, "extracting from bytes");
 	}
@@ -678,6 +713,69 @@ export function extractBytesSync(
 	}
 }
 
+/**
+ * Pre-warmed extraction context reused across calls
+ *
+ * Registers the built-in extractors and resolves them once, so repeated extractions with
+ * the same configuration skip that work. Unlike the Node.js binding, the WASM session only
+ * extracts bytes asynchronously: WASM builds have no file system and no blocking runtime.
+ * Results are not read from or written to the result cache.
+ *
+ * @example
+ * ```typescript
+ * const session = new ExtractionSession({ chunking: { maxChars: 1000 } });
+ * for (const data of documents) {
+ *   const result = await session.extractBytes(data, 'application/pdf');
+ *   console.log(result.chunks?.length);
+ * }
+ * session.free();
+ * ```
+ */
+export class ExtractionSession {
+	private readonly inner: InstanceType<WasmModule["ExtractionSession"]>;
+
+	/**
+	 * @param config - Extraction configuration used for every call (uses defaults if null)
+	 * @throws {Error} If WASM module is not initialized or plugin initialization fails
+	 */
+	constructor(config: ExtractionConfigType | null = null) {
+		if (!initialized || !wasm) {
+			throw new Error("WASM module not initialized. Call initWasm() first.");
+		}
+
+		try {
+			this.inner = new wasm.ExtractionSession(configToJS(config));
+		} catch (error) {
+			throw wrapWasmError(error, "creating extraction session");
+		}
+	}
+
+	async extractBytes(data: Uint8Array, mimeType: string): Promise<ExtractionResult> {
+		if (!data || data.length === 0) {
+			throw new Error("Document data cannot be empty");
+		}
+
+		if (!mimeType) {
+			throw new Error("MIME type is required");
+		}
+
+		try {
+			const result = await this.inner.extractBytes(data, mimeType);
+			if (!result) {
+				throw new Error("Invalid extraction result: no result from WASM module");
+			}
+			return jsToExtractionResult(result);
+		} catch (error) {
+			throw wrapWasmError(error, "extracting from bytes with a session");
+		}
+	}
+
+	/** Release the session's WASM memory. The session cannot be used afterwards. */
+	free(): void {
+		this.inner.free();
+	}
+}
+
 /**
  * Batch extract content from multiple byte arrays asynchronously
  *
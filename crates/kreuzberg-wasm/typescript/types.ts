@@ -9,6 +9,8 @@
  * Configuration for document extraction
  */
 export interface ExtractionConfig {
+	/** Serve repeated extractions from the result cache (default: true) */
+	useCache?: boolean;
 	/** OCR configuration */
 	ocr?: OcrConfig;
 	/** Chunking configuration */
@@ -19,6 +21,8 @@ export interface ExtractionConfig {
 	pages?: PageExtractionConfig;
 	/** Language detection configuration */
 	languageDetection?: LanguageDetectionConfig;
+	/** Keyword extraction configuration */
+	keywords?: KeywordConfig;
 }
 
 /**
@@ -65,6 +69,28 @@ export interface PageExtractionConfig {
 export interface LanguageDetectionConfig {
 	/** Whether to detect languages */
 	enabled?: boolean;
+	/** Minimum confidence threshold (0.0-1.0) */
+	minConfidence?: number;
+	/** Detect multiple languages in the document */
+	detectMultiple?: boolean;
+	/** Only analyze the first N KiB of text */
+	maxSampleKb?: number;
+}
+
+/**
+ * Keyword extraction configuration
+ */
+export interface KeywordConfig {
+	/** Algorithm to use: "yake" (default) or "rake" */
+	algorithm?: "yake" | "rake";
+	/** Maximum number of keywords to return */
+	maxKeywords?: number;
+	/** Minimum score threshold */
+	minScore?: number;
+	/** N-gram range as [min, max] */
+	ngramRange?: [number, number];
+	/** Language code for stopword filtering */
+	language?: string;
 }
 
 /**
@@ -79,14 +105,63 @@ export interface ExtractionResult {
 	metadata: Metadata;
 	/** Extracted tables */
 	tables: Table[];
-	/** Detected languages (ISO 639 codes) */
-	detectedLanguages?: string[] | null;
+	/** Detected languages with confidence and script, most prominent first */
+	detectedLanguages?: DetectedLanguage[] | null;
 	/** Text chunks when chunking is enabled */
 	chunks?: Chunk[] | null;
 	/** Extracted images */
 	images?: ExtractedImage[] | null;
 	/** Per-page content */
 	pages?: PageContent[] | null;
+	/** Extracted keywords when keyword extraction is enabled */
+	keywords?: Keyword[] | null;
+	/** Non-fatal issues encountered during processing, in the order they occurred */
+	warnings?: ExtractionWarning[];
+}
+
+/**
+ * Language detected in the content
+ */
+export interface DetectedLanguage {
+	/** ISO 639-3 language code */
+	language: string;
+	/** Detection confidence (0.0-1.0) */
+	confidence: number;
+	/** Writing system, e.g. "Latin" or "Cyrillic" */
+	script: string;
+}
+
+/**
+ * Keyword extracted from the content
+ */
+export interface Keyword {
+	/** Keyword text */
+	text: string;
+	/** Relevance score (algorithm-specific range) */
+	score: number;
+	/** Algorithm that extracted the keyword */
+	algorithm: "yake" | "rake";
+	/** Character offsets where the keyword appears */
+	positions?: number[] | null;
+}
+
+/**
+ * Processing stage that reported a warning
+ */
+export type WarningStage = "recovery" | "extraction" | "ocr" | "post_processing" | "limits";
+
+/**
+ * Non-fatal issue encountered while processing a document
+ */
+export interface ExtractionWarning {
+	/** Stable identifier of the kind of issue, e.g. `attachment_failed` */
+	code: string;
+	/** Human-readable description */
+	message: string;
+	/** Processing stage that reported the issue */
+	stage: WarningStage;
+	/** Page the issue applies to (1-indexed) */
+	page?: number | null;
 }
 
 /**
@@ -185,6 +260,8 @@ export interface ExtractedImage {
 	width?: number | null;
 	/** Image height in pixels */
 	height?: number | null;
+	/** Resolution recorded in the image data, in dots per inch */
+	dpi?: number | null;
 	/** Color space of the image */
 	colorspace?: string | null;
 	/** Bits per color component */
@@ -237,3 +314,19 @@ export interface OcrBackendProtocol {
 		| string
 	>;
 }
+
+/**
+ * Storage for cached extraction results
+ *
+ * See OpfsCacheBackend, IndexedDbCacheBackend and MemoryCacheBackend.
+ */
+export interface CacheBackend {
+	/** Get the backend name */
+	name(): string;
+	/** Get a cached result, or null when there is none */
+	get(key: string): Promise<ExtractionResult | null>;
+	/** Store a result */
+	set(key: string, result: ExtractionResult): Promise<void>;
+	/** Remove all cached results */
+	clear(): Promise<void>;
+}
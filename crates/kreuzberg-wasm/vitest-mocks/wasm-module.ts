@@ -3,7 +3,7 @@ export const extractBytes = async () => ({
 	mimeType: "application/pdf",
 	metadata: { pageCount: 1 },
 	tables: [],
-	detectedLanguages: ["en"],
+	detectedLanguages: [{ language: "eng", confidence: 0.99, script: "Latin" }],
 });
 
 export const extractBytesSync = () => ({
@@ -11,7 +11,7 @@ export const extractBytesSync = () => ({
 	mimeType: "application/pdf",
 	metadata: { pageCount: 1 },
 	tables: [],
-	detectedLanguages: ["en"],
+	detectedLanguages: [{ language: "eng", confidence: 0.99, script: "Latin" }],
 });
 
 export const batchExtractBytes = async () => [
@@ -38,6 +38,19 @@ export const batchExtractBytesSync = () => [
 	},
 ];
 
+export class ExtractionSession {
+	async extractBytes() {
+		return {
+			content: "test content",
+			mimeType: "application/pdf",
+			metadata: { pageCount: 1 },
+			tables: [],
+		};
+	}
+
+	free() {}
+}
+
 export const detectMimeFromBytes = () => "application/pdf";
 
 export const normalizeMimeType = (mime: string) => mime;
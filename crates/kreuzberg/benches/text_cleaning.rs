@@ -0,0 +1,36 @@
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use kreuzberg::text::{calculate_text_confidence, clean_extracted_text, fix_mojibake, normalize_spaces};
+use std::hint::black_box;
+
+fn ascii_corpus() -> String {
+    let paragraph = "The quick  brown fox\tjumps over the lazy dog. Page 3 of 12\n\
+                     Lorem ipsum dolor sit amet,   consectetur adipiscing elit.\r\n\n\n";
+    paragraph.repeat(4096)
+}
+
+fn unicode_corpus() -> String {
+    let paragraph = "Grüße aus Köln — naïve café façade.\u{00A0}Übersicht\u{2003}über Straßen\n\
+                     Привет мир. 日本語のテキスト。\u{FFFD}\n\n\n";
+    paragraph.repeat(4096)
+}
+
+fn bench_text_cleaning(c: &mut Criterion) {
+    for (name, corpus) in [("ascii", ascii_corpus()), ("unicode", unicode_corpus())] {
+        let mut group = c.benchmark_group(format!("text_cleaning_{}", name));
+        group.throughput(Throughput::Bytes(corpus.len() as u64));
+
+        group.bench_function("clean_extracted_text", |b| {
+            b.iter(|| clean_extracted_text(black_box(&corpus)))
+        });
+        group.bench_function("normalize_spaces", |b| b.iter(|| normalize_spaces(black_box(&corpus))));
+        group.bench_function("fix_mojibake", |b| b.iter(|| fix_mojibake(black_box(&corpus))));
+        group.bench_function("calculate_text_confidence", |b| {
+            b.iter(|| calculate_text_confidence(black_box(&corpus)))
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_text_cleaning);
+criterion_main!(benches);
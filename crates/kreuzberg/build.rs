@@ -29,6 +29,9 @@ fn main() {
 
     println!("cargo::rustc-check-cfg=cfg(coverage)");
 
+    #[cfg(feature = "grpc")]
+    compile_protos();
+
     // Skip pdfium linking if the pdf feature is not enabled
     if !cfg!(feature = "pdf") {
         tracing::debug!("PDF feature not enabled, skipping pdfium linking");
@@ -66,6 +69,24 @@ fn main() {
     println!("cargo:rerun-if-changed=build.rs");
 }
 
+// ============================================================================
+// GRPC CODE GENERATION
+// ============================================================================
+
+/// Generate the gRPC service and messages from `proto/`
+///
+/// The protos are parsed with protox, so building does not need `protoc` installed.
+#[cfg(feature = "grpc")]
+fn compile_protos() {
+    let proto = "proto/kreuzberg/v1/extraction.proto";
+    println!("cargo:rerun-if-changed={}", proto);
+
+    let descriptors = protox::compile([proto], ["proto"]).expect("Failed to parse gRPC protos");
+    tonic_build::configure()
+        .compile_fds(descriptors)
+        .expect("Failed to generate gRPC code");
+}
+
 // ============================================================================
 // FEATURE & STRATEGY VALIDATION
 // ============================================================================
@@ -0,0 +1,16 @@
+#![no_main]
+
+use kreuzberg::{ExtractionConfig, extract_bytes_sync};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let config = ExtractionConfig {
+        use_cache: false,
+        ..Default::default()
+    };
+    let _ = extract_bytes_sync(
+        data,
+        "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        &config,
+    );
+});
@@ -34,16 +34,24 @@ impl ApiError {
             KreuzbergError::Plugin { .. } => "PluginError",
             KreuzbergError::LockPoisoned(_) => "LockPoisonedError",
             KreuzbergError::UnsupportedFormat(_) => "UnsupportedFormatError",
+            KreuzbergError::EncryptedDocument(_) => "EncryptedDocumentError",
+            KreuzbergError::LimitExceeded { .. } => "LimitExceededError",
             KreuzbergError::Other(_) => "Error",
         };
 
+        let info = error.to_info();
+
         Self {
             status,
             body: ErrorResponse {
                 error_type: error_type.to_string(),
-                message: error.to_string(),
+                message: info.message,
                 traceback: None,
                 status_code: status.as_u16(),
+                code: info.code,
+                stage: info.stage,
+                plugin: info.plugin,
+                retryable: info.retryable,
             },
         }
     }
@@ -53,6 +61,11 @@ impl ApiError {
         Self::new(StatusCode::BAD_REQUEST, error)
     }
 
+    /// Create an unauthorized error (401).
+    pub fn unauthorized(error: KreuzbergError) -> Self {
+        Self::new(StatusCode::UNAUTHORIZED, error)
+    }
+
     /// Create an unprocessable entity error (422).
     pub fn unprocessable(error: KreuzbergError) -> Self {
         Self::new(StatusCode::UNPROCESSABLE_ENTITY, error)
@@ -62,6 +75,11 @@ impl ApiError {
     pub fn internal(error: KreuzbergError) -> Self {
         Self::new(StatusCode::INTERNAL_SERVER_ERROR, error)
     }
+
+    /// Create a service unavailable error (503).
+    pub fn unavailable(error: KreuzbergError) -> Self {
+        Self::new(StatusCode::SERVICE_UNAVAILABLE, error)
+    }
 }
 
 impl IntoResponse for ApiError {
@@ -74,7 +92,10 @@ impl From<KreuzbergError> for ApiError {
     fn from(error: KreuzbergError) -> Self {
         match &error {
             KreuzbergError::Validation { .. } => Self::validation(error),
-            KreuzbergError::Parsing { .. } | KreuzbergError::Ocr { .. } => Self::unprocessable(error),
+            KreuzbergError::Parsing { .. } | KreuzbergError::Ocr { .. } | KreuzbergError::EncryptedDocument(_) => {
+                Self::unprocessable(error)
+            }
+            KreuzbergError::LimitExceeded { .. } => Self::new(StatusCode::PAYLOAD_TOO_LARGE, error),
             _ => Self::internal(error),
         }
     }
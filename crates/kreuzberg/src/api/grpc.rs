@@ -0,0 +1,403 @@
+//! gRPC interface of the API server.
+//!
+//! Serves the `kreuzberg.v1.Extraction` service defined in `proto/kreuzberg/v1/extraction.proto`
+//! with tonic. It mirrors `POST /extract` of the REST API and shares its configuration
+//! handling, archive expansion and extraction, so both interfaces return the same results:
+//!
+//! - `Extract` - extract a single file
+//! - `BatchExtract` - extract several files, returning all results at once
+//! - `BatchExtractStream` - extract several files, streaming each result as soon as it is ready
+//!
+//! Results carry the extracted text and MIME type plus the complete result as JSON, in the
+//! format of the REST API. Errors are mapped to gRPC status codes: validation errors to
+//! `INVALID_ARGUMENT`, parsing and OCR errors to `FAILED_PRECONDITION`, exceeded limits to
+//! `RESOURCE_EXHAUSTED` and everything else to `INTERNAL`.
+//!
+//! # Example
+//!
+//! Serving REST and gRPC side by side with one reloadable configuration:
+//!
+//! ```no_run
+//! use kreuzberg::ConfigHandle;
+//! use kreuzberg::api::{ApiSizeLimits, serve_grpc_with_config_handle, serve_with_config_handle};
+//!
+//! #[tokio::main]
+//! async fn main() -> kreuzberg::Result<()> {
+//!     let config = ConfigHandle::discover()?;
+//!     let limits = ApiSizeLimits::default();
+//!     tokio::try_join!(
+//!         serve_with_config_handle("127.0.0.1", 8000, config.clone(), limits),
+//!         serve_grpc_with_config_handle("127.0.0.1", 50051, config, limits),
+//!     )?;
+//!     Ok(())
+//! }
+//! ```
+
+use std::net::{IpAddr, SocketAddr};
+use std::pin::Pin;
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+use tokio_stream::{Stream, wrappers::ReceiverStream};
+use tonic::{Code, Request, Response, Status};
+
+use crate::core::shutdown;
+use crate::{ConfigHandle, ExtractionResult, JobPriority, KreuzbergError, Result, extract_bytes, with_priority};
+
+use super::{
+    error::ApiError,
+    handlers::{
+        UploadedFile, extract_uploads, prepare_uploads, record_origin, reject_while_draining, request_config,
+        upload_priority,
+    },
+    types::{ApiSizeLimits, ApiState},
+};
+
+/// Messages and service code generated from `proto/kreuzberg/v1/extraction.proto`.
+#[allow(clippy::all, missing_docs)]
+pub mod proto {
+    tonic::include_proto!("kreuzberg.v1");
+}
+
+use proto::extraction_server::{Extraction, ExtractionServer};
+
+/// Implementation of the `kreuzberg.v1.Extraction` gRPC service.
+///
+/// Use [`grpc_service`] to create the tonic server for it.
+#[derive(Debug, Clone)]
+pub struct ExtractionService {
+    state: ApiState,
+}
+
+impl ExtractionService {
+    /// Create the service with a reloadable default configuration.
+    pub fn new(config: ConfigHandle) -> Self {
+        Self {
+            state: ApiState { default_config: config },
+        }
+    }
+}
+
+type ProgressStream = Pin<Box<dyn Stream<Item = std::result::Result<proto::BatchExtractProgress, Status>> + Send>>;
+
+#[tonic::async_trait]
+impl Extraction for ExtractionService {
+    async fn extract(
+        &self,
+        request: Request<proto::ExtractRequest>,
+    ) -> std::result::Result<Response<proto::ExtractResponse>, Status> {
+        let request = request.into_inner();
+        let file = request
+            .file
+            .ok_or_else(|| Status::invalid_argument("No file provided for extraction"))?;
+
+        let profile = request.profile.as_deref();
+        let config = request_config(&self.state, profile, request.config_json.as_deref())?;
+        let priority = request_priority(request.priority.as_deref())?;
+        let results = extract_uploads(vec![uploaded_file(file)], &config, false, priority).await?;
+        let result = match results.into_iter().next() {
+            Some(result) => Some(to_proto_result(result)?),
+            None => None,
+        };
+
+        Ok(Response::new(proto::ExtractResponse { result }))
+    }
+
+    async fn batch_extract(
+        &self,
+        request: Request<proto::BatchExtractRequest>,
+    ) -> std::result::Result<Response<proto::BatchExtractResponse>, Status> {
+        let request = request.into_inner();
+        let profile = request.profile.as_deref();
+        let config = request_config(&self.state, profile, request.config_json.as_deref())?;
+        let priority = request_priority(request.priority.as_deref())?;
+        let files = request.files.into_iter().map(uploaded_file).collect();
+
+        let results = extract_uploads(files, &config, request.expand_archives, priority)
+            .await?
+            .into_iter()
+            .map(to_proto_result)
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Response::new(proto::BatchExtractResponse { results }))
+    }
+
+    type BatchExtractStreamStream = ProgressStream;
+
+    async fn batch_extract_stream(
+        &self,
+        request: Request<proto::BatchExtractRequest>,
+    ) -> std::result::Result<Response<ProgressStream>, Status> {
+        reject_while_draining()?;
+        let request = request.into_inner();
+        let profile = request.profile.as_deref();
+        let config = request_config(&self.state, profile, request.config_json.as_deref())?;
+        let files = request.files.into_iter().map(uploaded_file).collect();
+        let (files, origins) = prepare_uploads(files, &config, request.expand_archives).await?;
+        let priority = request_priority(request.priority.as_deref())?;
+        let priority = priority.unwrap_or_else(|| upload_priority(&files));
+
+        let config = Arc::new(config);
+        let total = files.len() as u32;
+        let (tx, rx) = mpsc::channel(16);
+
+        tokio::spawn(async move {
+            // Extractions share the process-wide concurrency limit; dropping the set when
+            // the client goes away cancels the remaining ones.
+            let mut tasks = JoinSet::new();
+            for (index, ((data, mime_type, _file_name), origin)) in files.into_iter().zip(origins).enumerate() {
+                let config = Arc::clone(&config);
+                tasks.spawn(async move {
+                    let mut outcome = with_priority(priority, extract_bytes(&data, &mime_type, &config)).await;
+                    if let Ok(result) = &mut outcome {
+                        record_origin(result, origin);
+                    }
+                    (index as u32, outcome)
+                });
+            }
+
+            let mut completed = 0;
+            while let Some(joined) = tasks.join_next().await {
+                let message = match joined {
+                    Ok((index, outcome)) => {
+                        completed += 1;
+                        progress(index, completed, total, outcome)
+                    }
+                    Err(e) => Err(Status::internal(format!("Extraction task failed: {}", e))),
+                };
+                if tx.send(message).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Response::new(Box::pin(ReceiverStream::new(rx))))
+    }
+}
+
+/// Create the tonic server of the gRPC service.
+///
+/// Messages up to `limits.max_request_body_bytes` are accepted, like request bodies of the
+/// REST API. Add it to a [`tonic::transport::Server`] to serve it next to other services.
+pub fn grpc_service(config: ConfigHandle, limits: ApiSizeLimits) -> ExtractionServer<ExtractionService> {
+    ExtractionServer::new(ExtractionService::new(config)).max_decoding_message_size(limits.max_request_body_bytes)
+}
+
+/// Start the gRPC server with a reloadable configuration.
+///
+/// Runs until the server fails or shuts down gracefully on SIGTERM or Ctrl+C (see
+/// [`crate::core::shutdown`]). Run it next to
+/// [`serve_with_config_handle`](super::serve_with_config_handle) with a clone of the same
+/// handle to serve REST and gRPC side by side.
+pub async fn serve_grpc_with_config_handle(
+    host: impl AsRef<str>,
+    port: u16,
+    config: ConfigHandle,
+    limits: ApiSizeLimits,
+) -> Result<()> {
+    let ip: IpAddr = host
+        .as_ref()
+        .parse()
+        .map_err(|e| KreuzbergError::validation(format!("Invalid host address: {}", e)))?;
+
+    let addr = SocketAddr::new(ip, port);
+
+    tracing::info!("Starting Kreuzberg gRPC server on {}:{}", ip, port);
+
+    let drain_timeout = shutdown::drain_timeout();
+    let server = tonic::transport::Server::builder()
+        .add_service(grpc_service(config, limits))
+        .serve_with_shutdown(addr, shutdown::shutdown_signal());
+
+    let remaining = tokio::select! {
+        result = server => {
+            result.map_err(|e| KreuzbergError::Other(e.to_string()))?;
+            crate::core::governor::current_load()
+        }
+        remaining = shutdown::drain_deadline(drain_timeout) => remaining,
+    };
+    shutdown::finish(&remaining).await;
+
+    Ok(())
+}
+
+fn uploaded_file(file: proto::File) -> UploadedFile {
+    let mime_type = if file.mime_type.is_empty() {
+        "application/octet-stream".to_string()
+    } else {
+        file.mime_type
+    };
+    (file.data, mime_type, file.filename)
+}
+
+fn request_priority(priority: Option<&str>) -> std::result::Result<Option<JobPriority>, ApiError> {
+    priority.map(str::parse).transpose().map_err(ApiError::validation)
+}
+
+fn to_proto_result(result: ExtractionResult) -> std::result::Result<proto::ExtractionResult, Status> {
+    let json = serde_json::to_string(&result)
+        .map_err(|e| Status::internal(format!("Failed to serialize extraction result: {}", e)))?;
+
+    Ok(proto::ExtractionResult {
+        content: result.content,
+        mime_type: result.mime_type,
+        json,
+    })
+}
+
+fn progress(
+    index: u32,
+    completed: u32,
+    total: u32,
+    outcome: Result<ExtractionResult>,
+) -> std::result::Result<proto::BatchExtractProgress, Status> {
+    let outcome = match outcome {
+        Ok(result) => proto::batch_extract_progress::Outcome::Result(to_proto_result(result)?),
+        Err(error) => {
+            let body = ApiError::from(error).body;
+            proto::batch_extract_progress::Outcome::Error(proto::Error {
+                error_type: body.error_type,
+                message: body.message,
+                code: body.code.as_str().to_string(),
+                retryable: body.retryable,
+            })
+        }
+    };
+
+    Ok(proto::BatchExtractProgress {
+        index,
+        completed,
+        total,
+        outcome: Some(outcome),
+    })
+}
+
+impl From<ApiError> for Status {
+    fn from(error: ApiError) -> Self {
+        let code = match error.status.as_u16() {
+            400 => Code::InvalidArgument,
+            413 => Code::ResourceExhausted,
+            422 => Code::FailedPrecondition,
+            503 => Code::Unavailable,
+            _ => Code::Internal,
+        };
+        Status::new(code, error.body.message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ExtractionConfig;
+    use tokio_stream::StreamExt;
+
+    fn service() -> ExtractionService {
+        ExtractionService::new(ConfigHandle::new(ExtractionConfig::default()))
+    }
+
+    fn text_file(text: &str) -> proto::File {
+        proto::File {
+            data: text.as_bytes().to_vec(),
+            mime_type: "text/plain".to_string(),
+            filename: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_extract() {
+        let request = proto::ExtractRequest {
+            file: Some(text_file("Hello gRPC")),
+            config_json: None,
+            profile: None,
+            priority: None,
+        };
+
+        let response = service().extract(Request::new(request)).await.unwrap().into_inner();
+
+        let result = response.result.unwrap();
+        assert!(result.content.contains("Hello gRPC"));
+        assert_eq!(result.mime_type, "text/plain");
+        let json: serde_json::Value = serde_json::from_str(&result.json).unwrap();
+        assert_eq!(json["mime_type"], "text/plain");
+    }
+
+    #[tokio::test]
+    async fn test_batch_extract_rejects_invalid_config() {
+        let request = proto::BatchExtractRequest {
+            files: vec![text_file("a")],
+            config_json: Some("{not json".to_string()),
+            profile: None,
+            expand_archives: false,
+            priority: None,
+        };
+
+        let status = service().batch_extract(Request::new(request)).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert!(status.message().contains("Invalid extraction configuration"));
+    }
+
+    #[tokio::test]
+    async fn test_batch_extract_stream_reports_every_file() {
+        let request = proto::BatchExtractRequest {
+            files: vec![text_file("first"), text_file("second"), text_file("third")],
+            config_json: None,
+            profile: None,
+            expand_archives: false,
+            priority: None,
+        };
+
+        let stream = service()
+            .batch_extract_stream(Request::new(request))
+            .await
+            .unwrap()
+            .into_inner();
+        let updates: Vec<_> = stream.map(|update| update.unwrap()).collect().await;
+
+        assert_eq!(updates.len(), 3);
+        let mut completed: Vec<_> = updates.iter().map(|update| update.completed).collect();
+        completed.sort_unstable();
+        assert_eq!(completed, vec![1, 2, 3]);
+        let mut indices: Vec<_> = updates.iter().map(|update| update.index).collect();
+        indices.sort_unstable();
+        assert_eq!(indices, vec![0, 1, 2]);
+        for update in &updates {
+            assert_eq!(update.total, 3);
+            assert!(matches!(
+                update.outcome,
+                Some(proto::batch_extract_progress::Outcome::Result(_))
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_extract_requires_files() {
+        let request = proto::BatchExtractRequest {
+            files: Vec::new(),
+            config_json: None,
+            profile: None,
+            expand_archives: false,
+            priority: None,
+        };
+
+        let status = service().batch_extract(Request::new(request)).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_extract_rejects_unknown_priority() {
+        let request = proto::ExtractRequest {
+            file: Some(text_file("a")),
+            config_json: None,
+            profile: None,
+            priority: Some("urgent".to_string()),
+        };
+
+        let status = service().extract(Request::new(request)).await.unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert!(status.message().contains("Invalid job priority"));
+    }
+}
@@ -2,14 +2,23 @@
 
 use axum::{
     Json,
-    extract::{Multipart, State},
+    extract::{Multipart, Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
 };
 
-use crate::{batch_extract_bytes, cache, extract_bytes};
+use crate::{
+    ConfigUpdate, ExtractionConfig, ExtractionResult, JobPriority, batch_extract_bytes, cache, extract_bytes,
+    with_priority,
+};
 
 use super::{
     error::ApiError,
-    types::{ApiState, CacheClearResponse, CacheStatsResponse, ExtractResponse, HealthResponse, InfoResponse},
+    selection::ResultSelection,
+    types::{
+        ApiState, CacheClearResponse, CacheStatsResponse, ConfigReloadResponse, ConfigUpdateResponse, ExtractQuery,
+        ExtractResponse, HealthResponse, InfoResponse, PluginsResponse,
+    },
 };
 
 /// Extract endpoint handler.
@@ -22,6 +31,12 @@ use super::{
 ///
 /// Returns a list of extraction results, one per file.
 ///
+/// With `?expand_archives=true`, uploaded ZIP archives are expanded and every member is
+/// extracted as a document of its own, under the archive limits of the configuration
+/// (`archives` and `limits.max_decompressed_bytes`). Member results record their origin in
+/// `metadata.additional["archive"]` (`filename`, `path`, and `truncated` when the limits
+/// stopped the expansion early).
+///
 /// # Size Limits
 ///
 /// Request body size limits are enforced at the router layer via `RequestBodyLimitLayer`.
@@ -33,12 +48,23 @@ use super::{
 ///
 /// The server's default config (loaded from kreuzberg.toml/yaml/json via discovery)
 /// is used as the base, and any per-request config overrides those defaults.
+/// `?profile=<name>` selects a named profile of the config file as the base instead.
+///
+/// `?fields=content,metadata` returns only the listed top-level fields of each result, and
+/// `?content_offset=`/`?content_limit=` a window of `content` counted in characters, described
+/// by `content_page` (see [`ContentPage`](super::ContentPage)).
+///
+/// `?priority=high|normal|low` sets the queue priority of the extractions under the
+/// process-wide concurrency limit; without it, small uploads run at high and large ones at
+/// low priority (see [`upload_priority`]).
 pub async fn extract_handler(
     State(state): State<ApiState>,
+    Query(query): Query<ExtractQuery>,
     mut multipart: Multipart,
-) -> Result<Json<ExtractResponse>, ApiError> {
+) -> Result<Response, ApiError> {
+    let selection = ResultSelection::from_query(&query)?;
     let mut files = Vec::new();
-    let mut config = (*state.default_config).clone();
+    let mut config_json = None;
 
     while let Some(field) = multipart
         .next_field()
@@ -66,61 +92,347 @@ pub async fn extract_handler(
                     .await
                     .map_err(|e| ApiError::validation(crate::error::KreuzbergError::validation(e.to_string())))?;
 
-                config = serde_json::from_str(&config_str).map_err(|e| {
-                    ApiError::validation(crate::error::KreuzbergError::validation(format!(
-                        "Invalid extraction configuration: {}",
-                        e
-                    )))
-                })?;
+                config_json = Some(config_str);
             }
             _ => {}
         }
     }
 
+    let config = request_config(&state, query.profile.as_deref(), config_json.as_deref())?;
+    let results = extract_uploads(files, &config, query.expand_archives, query.priority).await?;
+
+    if selection.selects_everything() {
+        return Ok(Json(results).into_response());
+    }
+    let results = results
+        .into_iter()
+        .map(|result| selection.apply(result))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Json(results).into_response())
+}
+
+/// Resolve the configuration of an extraction request.
+///
+/// A per-request JSON configuration replaces the server configuration; otherwise the named
+/// `profile` of the config file or the server's default configuration is used. Shared by
+/// the REST and gRPC extract endpoints.
+pub(crate) fn request_config(
+    state: &ApiState,
+    profile: Option<&str>,
+    config_json: Option<&str>,
+) -> Result<ExtractionConfig, ApiError> {
+    let config = match profile {
+        Some(profile) => state.default_config.load_profile(profile)?,
+        None => (*state.default_config.current()).clone(),
+    };
+
+    match config_json {
+        Some(config_json) => {
+            let mut request_config: ExtractionConfig = serde_json::from_str(config_json).map_err(|e| {
+                ApiError::validation(crate::error::KreuzbergError::validation(format!(
+                    "Invalid extraction configuration: {}",
+                    e
+                )))
+            })?;
+            // Extract with the server's plugins
+            request_config.context = config.context;
+            Ok(request_config)
+        }
+        None => Ok(config),
+    }
+}
+
+/// Reject new extractions once the server is draining for shutdown (HTTP 503).
+pub(crate) fn reject_while_draining() -> Result<(), ApiError> {
+    if crate::core::shutdown::is_draining() {
+        return Err(ApiError::unavailable(crate::KreuzbergError::Other(
+            "Server is shutting down".to_string(),
+        )));
+    }
+    Ok(())
+}
+
+/// Extract uploaded files, one result per file (per archive member with `expand_archives`).
+///
+/// A single uploaded file is extracted directly; several files, and the members of expanded
+/// archives, in parallel with [`batch_extract_bytes`] so one failing member doesn't fail the
+/// request. The extractions queue at `priority`, or at the priority of the
+/// upload size (see [`upload_priority`]) when none was requested. Shared by the REST and gRPC
+/// extract endpoints.
+pub(crate) async fn extract_uploads(
+    files: Vec<UploadedFile>,
+    config: &ExtractionConfig,
+    expand_archives: bool,
+    priority: Option<JobPriority>,
+) -> Result<ExtractResponse, ApiError> {
+    reject_while_draining()?;
+    let (files, origins) = prepare_uploads(files, config, expand_archives).await?;
+    let priority = priority.unwrap_or_else(|| upload_priority(&files));
+    let single_upload = files.len() == 1 && origins[0].is_none();
+
+    let extraction = async {
+        if single_upload {
+            let (data, mime_type, _file_name) = files
+                .into_iter()
+                .next()
+                .expect("files.len() == 1 guarantees one element exists");
+            let result = extract_bytes(&data, mime_type.as_str(), config).await;
+            result.map(|result| vec![result])
+        } else {
+            let files_data: Vec<(Vec<u8>, String)> =
+                files.into_iter().map(|(data, mime, _name)| (data, mime)).collect();
+
+            let file_refs: Vec<(&[u8], &str)> = files_data
+                .iter()
+                .map(|(data, mime)| (data.as_slice(), mime.as_str()))
+                .collect();
+
+            batch_extract_bytes(file_refs, config).await
+        }
+    };
+    let mut results = with_priority(priority, extraction).await?;
+
+    for (result, origin) in results.iter_mut().zip(origins) {
+        record_origin(result, origin);
+    }
+
+    Ok(results)
+}
+
+/// Uploads up to this total size are treated as interactive requests.
+const HIGH_PRIORITY_MAX_BYTES: usize = 1024 * 1024;
+
+/// Uploads of at least this total size are treated as bulk work.
+const LOW_PRIORITY_MIN_BYTES: usize = 20 * 1024 * 1024;
+
+/// Uploads of at least this many documents are treated as bulk work.
+const LOW_PRIORITY_MIN_FILES: usize = 50;
+
+/// Priority of a request that didn't ask for one, derived from the size of its upload.
+///
+/// Small uploads run at high priority so they don't wait behind large batches, which run at
+/// low priority; everything in between is normal.
+pub(crate) fn upload_priority(files: &[UploadedFile]) -> JobPriority {
+    let total_bytes: usize = files.iter().map(|(data, _, _)| data.len()).sum();
+    if total_bytes >= LOW_PRIORITY_MIN_BYTES || files.len() >= LOW_PRIORITY_MIN_FILES {
+        JobPriority::Low
+    } else if total_bytes <= HIGH_PRIORITY_MAX_BYTES {
+        JobPriority::High
+    } else {
+        JobPriority::Normal
+    }
+}
+
+/// Check that files were uploaded and expand ZIP archives when `expand_archives` is set.
+///
+/// Returns the files to extract with the archive origin of each (see [`expand_uploaded_archives`]).
+pub(crate) async fn prepare_uploads(
+    files: Vec<UploadedFile>,
+    config: &ExtractionConfig,
+    expand: bool,
+) -> Result<(Vec<UploadedFile>, Vec<Option<serde_json::Value>>), ApiError> {
     if files.is_empty() {
         return Err(ApiError::validation(crate::error::KreuzbergError::validation(
             "No files provided for extraction",
         )));
     }
 
-    if files.len() == 1 {
-        let (data, mime_type, _file_name) = files
-            .into_iter()
-            .next()
-            .expect("files.len() == 1 guarantees one element exists");
-        let result = extract_bytes(&data, mime_type.as_str(), &config).await?;
-        return Ok(Json(vec![result]));
+    if expand {
+        expand_uploaded_archives(files, config).await
+    } else {
+        let origins = vec![None; files.len()];
+        Ok((files, origins))
+    }
+}
+
+/// Record the archive an extracted file came from in `metadata.additional["archive"]`.
+pub(crate) fn record_origin(result: &mut ExtractionResult, origin: Option<serde_json::Value>) {
+    if let Some(origin) = origin {
+        result.metadata.additional.insert("archive".to_string(), origin);
     }
+}
+
+/// An uploaded file: bytes, MIME type and file name.
+pub(crate) type UploadedFile = (Vec<u8>, String, Option<String>);
+
+/// Expand uploaded ZIP archives with [`expand_archives`] on the blocking thread pool.
+#[cfg(feature = "archives")]
+async fn expand_uploaded_archives(
+    files: Vec<UploadedFile>,
+    config: &ExtractionConfig,
+) -> Result<(Vec<UploadedFile>, Vec<Option<serde_json::Value>>), ApiError> {
+    let limits = crate::extractors::archive::archive_limits(config);
+    tokio::task::spawn_blocking(move || expand_archives(files, &limits))
+        .await
+        .map_err(|e| {
+            ApiError::internal(crate::KreuzbergError::Other(format!(
+                "Archive expansion panicked: {}",
+                e
+            )))
+        })?
+}
+
+/// Replace uploaded ZIP archives by their members, under the archive `limits`.
+///
+/// Returns the files to extract and, for archive members, the `metadata.additional["archive"]`
+/// entry recording the archive and member path. Members are typed by their file extension,
+/// falling back to content detection.
+#[cfg(feature = "archives")]
+fn expand_archives(
+    files: Vec<UploadedFile>,
+    limits: &crate::core::config::ArchiveConfig,
+) -> Result<(Vec<UploadedFile>, Vec<Option<serde_json::Value>>), ApiError> {
+    let mut expanded = Vec::new();
+    let mut origins = Vec::new();
 
-    let files_data: Vec<(Vec<u8>, String)> = files.into_iter().map(|(data, mime, _name)| (data, mime)).collect();
+    for (data, mime_type, file_name) in files {
+        let is_zip = matches!(mime_type.as_str(), "application/zip" | "application/x-zip-compressed")
+            || file_name
+                .as_deref()
+                .is_some_and(|name| name.to_lowercase().ends_with(".zip"));
+        if !is_zip {
+            expanded.push((data, mime_type, file_name));
+            origins.push(None);
+            continue;
+        }
+
+        let archive = crate::extraction::archive::extract_zip_files(&data, limits)?;
+        if archive.truncated {
+            tracing::warn!(
+                "Archive limits reached while expanding '{}', remaining members are not extracted",
+                file_name.as_deref().unwrap_or("upload")
+            );
+        }
+
+        for member in archive.files {
+            let member_mime_type = crate::core::mime::detect_mime_type(&member.path, false)
+                .or_else(|_| crate::core::mime::detect_mime_type_from_bytes(&member.data))
+                .unwrap_or_else(|_| "application/octet-stream".to_string());
+            origins.push(Some(serde_json::json!({
+                "filename": file_name,
+                "path": member.path,
+                "truncated": archive.truncated,
+            })));
+            expanded.push((member.data, member_mime_type, Some(member.path)));
+        }
+    }
 
-    let file_refs: Vec<(&[u8], &str)> = files_data
-        .iter()
-        .map(|(data, mime)| (data.as_slice(), mime.as_str()))
-        .collect();
+    Ok((expanded, origins))
+}
 
-    let results = batch_extract_bytes(file_refs, &config).await?;
-    Ok(Json(results))
+#[cfg(not(feature = "archives"))]
+async fn expand_uploaded_archives(
+    _files: Vec<UploadedFile>,
+    _config: &ExtractionConfig,
+) -> Result<(Vec<UploadedFile>, Vec<Option<serde_json::Value>>), ApiError> {
+    Err(ApiError::validation(crate::error::KreuzbergError::validation(
+        "expand_archives requires the archives feature",
+    )))
 }
 
 /// Health check endpoint handler.
 ///
 /// GET /health
-pub async fn health_handler() -> Json<HealthResponse> {
-    Json(HealthResponse {
-        status: "healthy".to_string(),
+///
+/// Includes the current load of the process-wide concurrency governor. While the server drains
+/// for shutdown, the status is `draining` and the response has status 503, so load balancers
+/// stop routing requests to it.
+pub async fn health_handler() -> (StatusCode, Json<HealthResponse>) {
+    let (code, status) = if crate::core::shutdown::is_draining() {
+        (StatusCode::SERVICE_UNAVAILABLE, "draining")
+    } else {
+        (StatusCode::OK, "healthy")
+    };
+    let health = HealthResponse {
+        status: status.to_string(),
         version: env!("CARGO_PKG_VERSION").to_string(),
-    })
+        load: crate::core::governor::current_load(),
+    };
+    (code, Json(health))
 }
 
 /// Server info endpoint handler.
 ///
 /// GET /info
-pub async fn info_handler() -> Json<InfoResponse> {
-    Json(InfoResponse {
+///
+/// Includes the compute device resolved from the default configuration's `device` settings
+/// and the [`doctor`](crate::doctor::doctor) report of external dependencies.
+///
+/// # Errors
+///
+/// Returns `ApiError::Internal` if the dependency probe task panics.
+pub async fn info_handler(State(state): State<ApiState>) -> Result<Json<InfoResponse>, ApiError> {
+    let device = state.default_config.current().device.clone().unwrap_or_default();
+    let doctor = tokio::task::spawn_blocking(crate::doctor::doctor)
+        .await
+        .map_err(|e| ApiError::internal(crate::KreuzbergError::Other(format!("Dependency probe failed: {}", e))))?;
+    Ok(Json(InfoResponse {
         version: env!("CARGO_PKG_VERSION").to_string(),
         rust_backend: true,
-    })
+        load: crate::core::governor::current_load(),
+        device: crate::device::resolve_device(&device),
+        doctor,
+    }))
+}
+
+/// Plugin listing endpoint handler.
+///
+/// GET /plugins
+///
+/// Returns name, version, MIME types, priority and stage of every registered plugin.
+///
+/// # Errors
+///
+/// Returns `ApiError::Internal` if a plugin registry lock is poisoned.
+pub async fn plugins_handler(State(state): State<ApiState>) -> Result<Json<PluginsResponse>, ApiError> {
+    let registries = state.default_config.registries();
+    let plugins = crate::plugins::describe_registries(&registries).map_err(ApiError::internal)?;
+    Ok(Json(plugins))
+}
+
+/// Config reload endpoint handler.
+///
+/// POST /config/reload
+///
+/// Re-reads the server's config file, reloads the plugins of its plugin directories and
+/// atomically swaps both in. In-flight requests keep the configuration and plugins they
+/// started with.
+///
+/// # Errors
+///
+/// Returns `ApiError` if the config file cannot be read or is invalid or the plugins fail to
+/// load; the previous configuration and plugins stay active in that case.
+pub async fn config_reload_handler(State(state): State<ApiState>) -> Result<Json<ConfigReloadResponse>, ApiError> {
+    let reloaded = state.default_config.reload_async().await?;
+
+    Ok(Json(ConfigReloadResponse {
+        reloaded,
+        source: state
+            .default_config
+            .source()
+            .map(|path| path.to_string_lossy().to_string()),
+    }))
+}
+
+/// Config update endpoint handler.
+///
+/// PATCH /config
+///
+/// Accepts a JSON body with any of `ocr_language`, `use_cache` and
+/// `max_concurrent_extractions` and applies them to the server's configuration without a
+/// restart. Changes are validated before they take effect, logged, and kept until the
+/// config file is reloaded.
+///
+/// # Errors
+///
+/// Returns `ApiError` (400) if the update produces an invalid configuration; the current
+/// configuration stays active in that case.
+pub async fn config_update_handler(
+    State(state): State<ApiState>,
+    Json(update): Json<ConfigUpdate>,
+) -> Result<Json<ConfigUpdateResponse>, ApiError> {
+    let updated = state.default_config.update(&update)?;
+    Ok(Json(ConfigUpdateResponse { updated }))
 }
 
 /// Cache stats endpoint handler.
@@ -8,6 +8,16 @@
 //! - `POST /extract` - Extract text from uploaded files (multipart form data)
 //! - `GET /health` - Health check endpoint
 //! - `GET /info` - Server information
+//! - `GET /plugins` - Registered plugins with MIME types, priorities and stages
+//! - `PATCH /config` - Change selected config fields (OCR language, caching, concurrency) at runtime
+//! - `POST /config/reload` - Reload the server's config file and plugins without restarting
+//!
+//! `PATCH /config`, `POST /config/reload` and `DELETE /cache/clear` are admin endpoints. When
+//! `KREUZBERG_ADMIN_TOKEN` is set they require `Authorization: Bearer <token>`.
+//!
+//! With the `grpc` feature, `serve_grpc_with_config_handle` serves the same extraction as
+//! the `kreuzberg.v1.Extraction` gRPC service (`Extract`, `BatchExtract` and the streaming
+//! `BatchExtractStream`).
 //!
 //! # Examples
 //!
@@ -62,18 +72,27 @@
 //!
 //! # Server info
 //! curl http://localhost:8000/info
+//!
+//! # Registered plugins
+//! curl http://localhost:8000/plugins
 //! ```
 
 mod error;
+#[cfg(feature = "grpc")]
+mod grpc;
 mod handlers;
+mod selection;
 mod server;
 mod types;
 
 pub use error::ApiError;
+#[cfg(feature = "grpc")]
+pub use grpc::{ExtractionService, grpc_service, proto, serve_grpc_with_config_handle};
 pub use server::{
-    create_router, create_router_with_limits, serve, serve_default, serve_with_config, serve_with_config_and_limits,
+    create_router, create_router_with_admin_token, create_router_with_config_handle, create_router_with_limits, serve,
+    serve_default, serve_with_config, serve_with_config_and_limits, serve_with_config_handle,
 };
 pub use types::{
-    ApiSizeLimits, ApiState, CacheClearResponse, CacheStatsResponse, ErrorResponse, ExtractResponse, HealthResponse,
-    InfoResponse,
+    ApiSizeLimits, ApiState, CacheClearResponse, CacheStatsResponse, ConfigReloadResponse, ConfigUpdateResponse,
+    ContentPage, ErrorResponse, ExtractQuery, ExtractResponse, HealthResponse, InfoResponse, PluginsResponse,
 };
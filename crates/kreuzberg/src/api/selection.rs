@@ -0,0 +1,223 @@
+//! Field selection and content pagination of extract responses.
+//!
+//! `?fields=content,metadata` limits every result to the listed top-level fields, and
+//! `?content_offset=` / `?content_limit=` return a window of `content`, counted in characters,
+//! together with a [`ContentPage`] describing it. Clients on constrained networks can so fetch
+//! only what they need, or page through the text of very large documents.
+
+use serde_json::Value;
+
+use crate::{ExtractionResult, KreuzbergError};
+
+use super::{
+    error::ApiError,
+    types::{ContentPage, ExtractQuery},
+};
+
+/// Top-level fields of a serialized [`ExtractionResult`].
+const RESULT_FIELDS: &[&str] = &[
+    "content",
+    "mime_type",
+    "metadata",
+    "tables",
+    "detected_languages",
+    "chunks",
+    "images",
+    "pages",
+    "entities",
+    "keywords",
+    "children",
+    "fields",
+    "warnings",
+];
+
+/// The parts of each result an extract request asked for.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ResultSelection {
+    fields: Option<Vec<String>>,
+    content_offset: Option<usize>,
+    content_limit: Option<usize>,
+}
+
+impl ResultSelection {
+    /// Read the selection from the query parameters of an extract request.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error (HTTP 400) for unknown or empty field names.
+    pub(crate) fn from_query(query: &ExtractQuery) -> Result<Self, ApiError> {
+        let fields = match &query.fields {
+            Some(fields) => Some(parse_fields(fields)?),
+            None => None,
+        };
+
+        Ok(Self {
+            fields,
+            content_offset: query.content_offset,
+            content_limit: query.content_limit,
+        })
+    }
+
+    /// Whether complete results are returned.
+    pub(crate) fn selects_everything(&self) -> bool {
+        *self == Self::default()
+    }
+
+    fn is_paginated(&self) -> bool {
+        self.content_offset.is_some() || self.content_limit.is_some()
+    }
+
+    fn includes(&self, field: &str) -> bool {
+        self.fields
+            .as_ref()
+            .is_none_or(|fields| fields.iter().any(|selected| selected == field))
+    }
+
+    /// Serialize the selected parts of `result`.
+    ///
+    /// `content` is cut to the requested window before serialization, so the rest of a large
+    /// document is never copied.
+    pub(crate) fn apply(&self, mut result: ExtractionResult) -> Result<Value, ApiError> {
+        let page = self.is_paginated().then(|| {
+            let offset = self.content_offset.unwrap_or(0);
+            let (window, page) = content_window(&result.content, offset, self.content_limit);
+            result.content = result.content[window].to_string();
+            page
+        });
+
+        let serialized = serde_json::to_value(&result).map_err(|e| {
+            ApiError::internal(KreuzbergError::Other(format!(
+                "Failed to serialize extraction result: {}",
+                e
+            )))
+        })?;
+        let Value::Object(mut object) = serialized else {
+            return Ok(serialized);
+        };
+
+        object.retain(|key, _| self.includes(key));
+        if let Some(page) = page
+            && self.includes("content")
+        {
+            let page = serde_json::to_value(page).unwrap_or_default();
+            object.insert("content_page".to_string(), page);
+        }
+        Ok(Value::Object(object))
+    }
+}
+
+fn parse_fields(fields: &str) -> Result<Vec<String>, ApiError> {
+    let fields: Vec<String> = fields
+        .split(',')
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+        .map(String::from)
+        .collect();
+
+    if fields.is_empty() {
+        return Err(ApiError::validation(KreuzbergError::validation(
+            "fields must name at least one result field",
+        )));
+    }
+    if let Some(unknown) = fields.iter().find(|field| !RESULT_FIELDS.contains(&field.as_str())) {
+        return Err(ApiError::validation(KreuzbergError::validation(format!(
+            "Unknown result field '{}': expected one of {}",
+            unknown,
+            RESULT_FIELDS.join(", ")
+        ))));
+    }
+    Ok(fields)
+}
+
+/// Byte range of the window of `content` starting at character `offset`, at most `limit`
+/// characters long, and its description.
+fn content_window(content: &str, offset: usize, limit: Option<usize>) -> (std::ops::Range<usize>, ContentPage) {
+    let total_length = content.chars().count();
+    let length = total_length.saturating_sub(offset);
+    let length = limit.map_or(length, |limit| limit.min(length));
+
+    let start = byte_offset(content, offset);
+    let end = start + byte_offset(&content[start..], length);
+    let page = ContentPage {
+        offset: offset.min(total_length),
+        length,
+        total_length,
+    };
+    (start..end, page)
+}
+
+/// Byte offset of character `chars` of `text`, or its length when it is shorter.
+fn byte_offset(text: &str, chars: usize) -> usize {
+    text.char_indices().nth(chars).map_or(text.len(), |(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Metadata;
+
+    fn result(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn selection(fields: Option<&str>, content_offset: Option<usize>, content_limit: Option<usize>) -> ResultSelection {
+        let query = ExtractQuery {
+            fields: fields.map(String::from),
+            content_offset,
+            content_limit,
+            ..Default::default()
+        };
+        ResultSelection::from_query(&query).unwrap()
+    }
+
+    #[test]
+    fn test_field_selection() {
+        let selected = selection(Some("content, metadata"), None, None)
+            .apply(result("Hello"))
+            .unwrap();
+
+        let mut keys: Vec<_> = selected.as_object().unwrap().keys().cloned().collect();
+        keys.sort();
+        assert_eq!(keys, vec!["content", "metadata"]);
+        assert!(selection(None, None, None).selects_everything());
+
+        let query = ExtractQuery {
+            fields: Some("content,text".to_string()),
+            ..Default::default()
+        };
+        assert!(ResultSelection::from_query(&query).is_err());
+    }
+
+    #[test]
+    fn test_content_pagination_counts_characters() {
+        let greeting = result("Grüße aus Köln");
+        let paged = selection(None, Some(2), Some(3)).apply(greeting).unwrap();
+
+        assert_eq!(paged["content"], "üße");
+        assert_eq!(paged["mime_type"], "text/plain");
+        assert_eq!(
+            paged["content_page"],
+            serde_json::json!({"offset": 2, "length": 3, "total_length": 14})
+        );
+
+        let short = result("short");
+        let past_end = selection(Some("content"), Some(100), None).apply(short).unwrap();
+        assert_eq!(past_end["content"], "");
+        assert_eq!(past_end["content_page"]["offset"], 5);
+        assert_eq!(past_end["content_page"]["length"], 0);
+    }
+}
@@ -1,13 +1,16 @@
 //! API server setup and configuration.
 
-use std::{
-    net::{IpAddr, SocketAddr},
-    sync::Arc,
-};
+use std::future::IntoFuture;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
 
 use axum::{
     Router,
-    routing::{delete, get, post},
+    extract::{Request, State},
+    http::header,
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{delete, get, patch, post},
 };
 use tower_http::{
     cors::{AllowOrigin, Any, CorsLayer},
@@ -15,10 +18,15 @@ use tower_http::{
     trace::TraceLayer,
 };
 
-use crate::{ExtractionConfig, Result};
+use crate::core::shutdown;
+use crate::{ConfigHandle, ExtractionConfig, Result};
 
 use super::{
-    handlers::{cache_clear_handler, cache_stats_handler, extract_handler, health_handler, info_handler},
+    error::ApiError,
+    handlers::{
+        cache_clear_handler, cache_stats_handler, config_reload_handler, config_update_handler, extract_handler,
+        health_handler, info_handler, plugins_handler,
+    },
     types::{ApiSizeLimits, ApiState},
 };
 
@@ -71,6 +79,48 @@ fn parse_size_limits_from_env() -> ApiSizeLimits {
     }
 }
 
+/// Read the admin token from `KREUZBERG_ADMIN_TOKEN`.
+///
+/// Without a token the admin endpoints accept every request, which is logged as a warning.
+fn parse_admin_token_from_env() -> Option<String> {
+    match std::env::var("KREUZBERG_ADMIN_TOKEN") {
+        Ok(token) if !token.trim().is_empty() => {
+            tracing::info!("Admin endpoints require the token from KREUZBERG_ADMIN_TOKEN");
+            Some(token.trim().to_string())
+        }
+        _ => {
+            tracing::warn!(
+                "KREUZBERG_ADMIN_TOKEN not set - admin endpoints (PATCH /config, POST /config/reload, \
+                 DELETE /cache/clear) accept unauthenticated requests. Set it for production."
+            );
+            None
+        }
+    }
+}
+
+/// Reject requests to admin endpoints without `Authorization: Bearer <admin token>` (HTTP 401).
+async fn require_admin_token(State(admin_token): State<Option<Arc<str>>>, request: Request, next: Next) -> Response {
+    if let Some(admin_token) = admin_token.as_deref() {
+        let token = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+
+        if !token.is_some_and(|token| constant_time_eq(token.as_bytes(), admin_token.as_bytes())) {
+            return ApiError::unauthorized(crate::KreuzbergError::validation("Missing or invalid admin token"))
+                .into_response();
+        }
+    }
+
+    next.run(request).await
+}
+
+/// Compare two byte strings in time independent of where they differ.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
 /// Create the API router with all routes configured.
 ///
 /// This is public to allow users to embed the router in their own applications.
@@ -131,9 +181,58 @@ pub fn create_router(config: ExtractionConfig) -> Router {
 /// # }
 /// ```
 pub fn create_router_with_limits(config: ExtractionConfig, limits: ApiSizeLimits) -> Router {
-    let state = ApiState {
-        default_config: Arc::new(config),
-    };
+    create_router_with_config_handle(ConfigHandle::new(config), limits)
+}
+
+/// Create the API router with a reloadable configuration.
+///
+/// Changes made through the handle (e.g. [`ConfigHandle::reload`] or a running
+/// [`ConfigHandle::watch`] task) apply to all subsequent requests.
+///
+/// The admin endpoints (`PATCH /config`, `POST /config/reload` and `DELETE /cache/clear`)
+/// require the token from `KREUZBERG_ADMIN_TOKEN` if it is set; see
+/// [`create_router_with_admin_token`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use kreuzberg::{ConfigHandle, api::{create_router_with_config_handle, ApiSizeLimits}};
+///
+/// # fn example() -> kreuzberg::Result<()> {
+/// let handle = ConfigHandle::from_file("kreuzberg.toml")?;
+/// let router = create_router_with_config_handle(handle, ApiSizeLimits::default());
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_router_with_config_handle(config: ConfigHandle, limits: ApiSizeLimits) -> Router {
+    create_router_with_admin_token(config, limits, parse_admin_token_from_env())
+}
+
+/// Create the API router with a reloadable configuration and an explicit admin token.
+///
+/// With `admin_token` set, the admin endpoints (`PATCH /config`, `POST /config/reload` and
+/// `DELETE /cache/clear`) reject requests that don't send `Authorization: Bearer <admin_token>`
+/// with HTTP 401. Without it, they are open to every client that can reach the server.
+///
+/// # Examples
+///
+/// ```no_run
+/// use kreuzberg::{ConfigHandle, api::{create_router_with_admin_token, ApiSizeLimits}};
+///
+/// # fn example() -> kreuzberg::Result<()> {
+/// let handle = ConfigHandle::from_file("kreuzberg.toml")?;
+/// let token = std::env::var("ADMIN_TOKEN").ok();
+/// let router = create_router_with_admin_token(handle, ApiSizeLimits::default(), token);
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_router_with_admin_token(
+    config: ConfigHandle,
+    limits: ApiSizeLimits,
+    admin_token: Option<String>,
+) -> Router {
+    let state = ApiState { default_config: config };
+    let admin_token: Option<Arc<str>> = admin_token.map(Into::into);
 
     // SECURITY WARNING: The default allows all origins for development convenience,
     let cors_layer = if let Ok(origins_str) = std::env::var("KREUZBERG_CORS_ORIGINS") {
@@ -165,12 +264,19 @@ pub fn create_router_with_limits(config: ExtractionConfig, limits: ApiSizeLimits
         CorsLayer::new().allow_origin(Any).allow_methods(Any).allow_headers(Any)
     };
 
+    let admin_routes = Router::new()
+        .route("/config", patch(config_update_handler))
+        .route("/config/reload", post(config_reload_handler))
+        .route("/cache/clear", delete(cache_clear_handler))
+        .route_layer(middleware::from_fn_with_state(admin_token, require_admin_token));
+
     Router::new()
         .route("/extract", post(extract_handler))
         .route("/health", get(health_handler))
         .route("/info", get(info_handler))
+        .route("/plugins", get(plugins_handler))
         .route("/cache/stats", get(cache_stats_handler))
-        .route("/cache/clear", delete(cache_clear_handler))
+        .merge(admin_routes)
         .layer(RequestBodyLimitLayer::new(limits.max_request_body_bytes))
         .layer(cors_layer)
         .layer(TraceLayer::new_for_http())
@@ -226,23 +332,27 @@ pub fn create_router_with_limits(config: ExtractionConfig, limits: ApiSizeLimits
 /// # Upload size limit (default: 100 MB)
 /// export KREUZBERG_MAX_UPLOAD_SIZE_MB=200
 ///
+/// # Concurrent extraction limit shared by all requests (default: number of CPU cores)
+/// export KREUZBERG_MAX_CONCURRENT_JOBS=4
+///
+/// # Seconds in-flight extractions get to finish after SIGTERM (default: 30)
+/// export KREUZBERG_DRAIN_TIMEOUT_SECS=60
+///
+/// # Bearer token required by the admin endpoints (default: none, admin endpoints are open)
+/// export KREUZBERG_ADMIN_TOKEN="change-me"
+///
 /// python -m kreuzberg.api
 /// ```
 pub async fn serve(host: impl AsRef<str>, port: u16) -> Result<()> {
-    let config = match ExtractionConfig::discover()? {
-        Some(config) => {
-            tracing::info!("Loaded extraction config from discovered file");
-            config
-        }
-        None => {
-            tracing::info!("No config file found, using default configuration");
-            ExtractionConfig::default()
-        }
-    };
+    let config = ConfigHandle::discover()?;
+    match config.source() {
+        Some(path) => tracing::info!("Loaded extraction config from {}", path.display()),
+        None => tracing::info!("No config file found, using default configuration"),
+    }
 
     let limits = parse_size_limits_from_env();
 
-    serve_with_config_and_limits(host, port, config, limits).await
+    serve_with_config_handle(host, port, config, limits).await
 }
 
 /// Start the API server with explicit config.
@@ -303,6 +413,37 @@ pub async fn serve_with_config_and_limits(
     port: u16,
     config: ExtractionConfig,
     limits: ApiSizeLimits,
+) -> Result<()> {
+    serve_with_config_handle(host, port, ConfigHandle::new(config), limits).await
+}
+
+/// Start the API server with a reloadable configuration.
+///
+/// The configuration can be reloaded without restarting the server, either via
+/// `POST /config/reload` or by running [`ConfigHandle::watch`] on the handle.
+///
+/// On SIGTERM or Ctrl+C the server stops accepting connections, waits up to the drain
+/// timeout for in-flight extractions to finish and returns (see [`crate::core::shutdown`]).
+///
+/// # Examples
+///
+/// ```no_run
+/// use kreuzberg::{ConfigHandle, api::{serve_with_config_handle, ApiSizeLimits}};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> kreuzberg::Result<()> {
+///     let handle = ConfigHandle::from_file("kreuzberg.toml")?;
+///     let _watcher = handle.watch(Duration::from_secs(2));
+///     serve_with_config_handle("127.0.0.1", 8000, handle, ApiSizeLimits::default()).await?;
+///     Ok(())
+/// }
+/// ```
+pub async fn serve_with_config_handle(
+    host: impl AsRef<str>,
+    port: u16,
+    config: ConfigHandle,
+    limits: ApiSizeLimits,
 ) -> Result<()> {
     let ip: IpAddr = host
         .as_ref()
@@ -310,7 +451,10 @@ pub async fn serve_with_config_and_limits(
         .map_err(|e| crate::error::KreuzbergError::validation(format!("Invalid host address: {}", e)))?;
 
     let addr = SocketAddr::new(ip, port);
-    let app = create_router_with_limits(config, limits);
+    let app = create_router_with_config_handle(config, limits);
+
+    let scratch_dir = crate::core::scratch::init_scratch_space()?;
+    tracing::info!("Scratch files are written to {}", scratch_dir.display());
 
     tracing::info!("Starting Kreuzberg API server on http://{}:{}", ip, port);
 
@@ -318,9 +462,19 @@ pub async fn serve_with_config_and_limits(
         .await
         .map_err(crate::error::KreuzbergError::Io)?;
 
-    axum::serve(listener, app)
-        .await
-        .map_err(|e| crate::error::KreuzbergError::Other(e.to_string()))?;
+    let drain_timeout = shutdown::drain_timeout();
+    let server = axum::serve(listener, app)
+        .with_graceful_shutdown(shutdown::shutdown_signal())
+        .into_future();
+
+    let remaining = tokio::select! {
+        result = server => {
+            result.map_err(|e| crate::error::KreuzbergError::Other(e.to_string()))?;
+            crate::core::governor::current_load()
+        }
+        remaining = shutdown::drain_deadline(drain_timeout) => remaining,
+    };
+    shutdown::finish(&remaining).await;
 
     Ok(())
 }
@@ -344,6 +498,14 @@ mod tests {
         let _router = create_router(config);
     }
 
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret2"));
+        assert!(!constant_time_eq(b"", b"secret"));
+    }
+
     #[test]
     fn test_router_has_routes() {
         let config = ExtractionConfig::default();
@@ -1,9 +1,7 @@
 //! API request and response types.
 
+use crate::{ConfigChange, ConfigHandle, types::ExtractionResult};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-
-use crate::{ExtractionConfig, types::ExtractionResult};
 
 /// API server size limit configuration.
 ///
@@ -103,6 +101,8 @@ pub struct HealthResponse {
     pub status: String,
     /// API version
     pub version: String,
+    /// Current extraction load
+    pub load: crate::GovernorLoad,
 }
 
 /// Server information response.
@@ -112,11 +112,58 @@ pub struct InfoResponse {
     pub version: String,
     /// Whether using Rust backend
     pub rust_backend: bool,
+    /// Current extraction load
+    pub load: crate::GovernorLoad,
+    /// Compute device of model-backed features under the server's default configuration
+    pub device: crate::device::DeviceInfo,
+    /// Enabled features, OCR backends and the status of external dependencies
+    pub doctor: crate::doctor::DoctorReport,
+}
+
+/// Query parameters of the extract endpoint.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExtractQuery {
+    /// Profile of the server's config file to use instead of its top-level settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// Expand uploaded ZIP archives and return one result per member instead of per archive
+    #[serde(default)]
+    pub expand_archives: bool,
+    /// Queue priority of the extractions; derived from the upload size when absent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<crate::JobPriority>,
+    /// Comma-separated top-level result fields to return, e.g. `content,metadata`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<String>,
+    /// Character offset of the returned window of `content`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_offset: Option<usize>,
+    /// Maximum number of characters of `content` to return
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_limit: Option<usize>,
 }
 
 /// Extraction response (list of results).
 pub type ExtractResponse = Vec<ExtractionResult>;
 
+/// Window of `content` returned by a paginated extract request.
+///
+/// Added to each result as `content_page` when `content_offset` or `content_limit` is set.
+/// Offsets and lengths count characters; the content is complete once
+/// `offset + length == total_length`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContentPage {
+    /// Character offset of the returned content
+    pub offset: usize,
+    /// Number of characters returned
+    pub length: usize,
+    /// Number of characters of the complete content
+    pub total_length: usize,
+}
+
+/// Plugin listing response (one entry per registered plugin).
+pub type PluginsResponse = Vec<crate::plugins::PluginInfo>;
+
 /// Error response.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorResponse {
@@ -129,16 +176,25 @@ pub struct ErrorResponse {
     pub traceback: Option<String>,
     /// HTTP status code
     pub status_code: u16,
+    /// Stable error code (see [`crate::ErrorCode`])
+    pub code: crate::ErrorCode,
+    /// Pipeline stage the error belongs to, if known
+    pub stage: Option<String>,
+    /// Name of the failing plugin, for plugin errors
+    pub plugin: Option<String>,
+    /// Whether retrying the request may succeed
+    pub retryable: bool,
 }
 
 /// API server state.
 ///
 /// Holds the default extraction configuration loaded from config file
 /// (via discovery or explicit path). Per-request configs override these defaults.
+/// The configuration can be swapped at runtime through the [`ConfigHandle`].
 #[derive(Debug, Clone)]
 pub struct ApiState {
     /// Default extraction configuration
-    pub default_config: Arc<ExtractionConfig>,
+    pub default_config: ConfigHandle,
 }
 
 /// Cache statistics response.
@@ -168,3 +224,20 @@ pub struct CacheClearResponse {
     /// Space freed in MB
     pub freed_mb: f64,
 }
+
+/// Config reload response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigReloadResponse {
+    /// Whether a new configuration was loaded
+    pub reloaded: bool,
+    /// Config file the configuration was loaded from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+}
+
+/// Config update response.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigUpdateResponse {
+    /// Fields whose value changed, with their previous and new values
+    pub updated: Vec<ConfigChange>,
+}
@@ -26,6 +26,7 @@
 use crate::error::{KreuzbergError, Result};
 use ahash::AHasher;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashSet;
 use std::fs;
 
@@ -85,6 +86,7 @@ impl GenericCache {
             // OSError/RuntimeError must bubble up - system errors need user reports ~keep
             std::env::current_dir()?.join(".kreuzberg").join(&cache_type)
         };
+        let cache_dir_path = long_path(&cache_dir_path);
 
         fs::create_dir_all(&cache_dir_path)
             .map_err(|e| KreuzbergError::cache(format!("Failed to create cache directory: {}", e)))?;
@@ -501,6 +503,55 @@ pub fn generate_cache_key(parts: &[(&str, &str)]) -> String {
     format!("{:0width$x}", hash, width = CACHE_KEY_HASH_WIDTH)
 }
 
+/// Path usable beyond the 260-character `MAX_PATH` limit of the Windows API.
+///
+/// On Windows, paths are made absolute and rewritten to their verbatim form (`\\?\C:\...`,
+/// `\\?\UNC\server\share\...`), which the Windows API passes through without a length limit, so
+/// caches nested in deep project trees keep working. Everywhere else the path is returned
+/// unchanged.
+pub fn long_path(path: &Path) -> PathBuf {
+    #[cfg(windows)]
+    {
+        let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        match absolute.to_str().and_then(verbatim_path) {
+            Some(verbatim) => PathBuf::from(verbatim),
+            None => absolute,
+        }
+    }
+
+    #[cfg(not(windows))]
+    {
+        path.to_path_buf()
+    }
+}
+
+/// Verbatim form of an absolute Windows path, or `None` for relative and verbatim paths.
+#[cfg(any(windows, test))]
+fn verbatim_path(path: &str) -> Option<String> {
+    if path.starts_with(r"\\?\") || path.starts_with(r"\\.\") {
+        return None;
+    }
+    if let Some(share) = path.strip_prefix(r"\\") {
+        return Some(format!(r"\\?\UNC\{}", share));
+    }
+
+    let bytes = path.as_bytes();
+    let is_drive_path = bytes.len() >= 3 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' && bytes[2] == b'\\';
+    is_drive_path.then(|| format!(r"\\?\{}", path))
+}
+
+#[cfg(windows)]
+#[allow(unsafe_code)]
+#[link(name = "kernel32")]
+unsafe extern "system" {
+    fn GetDiskFreeSpaceExW(
+        directory_name: *const u16,
+        free_bytes_available_to_caller: *mut u64,
+        total_number_of_bytes: *mut u64,
+        total_number_of_free_bytes: *mut u64,
+    ) -> i32;
+}
+
 #[allow(unsafe_code)]
 pub fn get_available_disk_space(path: &str) -> Result<f64> {
     #[cfg(unix)]
@@ -536,7 +587,35 @@ pub fn get_available_disk_space(path: &str) -> Result<f64> {
         }
     }
 
-    #[cfg(not(unix))]
+    #[cfg(windows)]
+    {
+        use std::os::windows::ffi::OsStrExt;
+
+        // GetDiskFreeSpaceExW needs an existing directory
+        let path = long_path(Path::new(path));
+        let check_path = path.ancestors().find(|dir| dir.is_dir()).unwrap_or(path.as_path());
+        let wide_path: Vec<u16> = check_path.as_os_str().encode_wide().chain([0]).collect();
+
+        let mut available_bytes = 0u64;
+        let result = unsafe {
+            GetDiskFreeSpaceExW(
+                wide_path.as_ptr(),
+                &mut available_bytes,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            )
+        };
+
+        if result != 0 {
+            Ok(available_bytes as f64 / (1024.0 * 1024.0))
+        } else {
+            let error = std::io::Error::last_os_error();
+            tracing::debug!("Failed to get disk stats for {}: {}", check_path.display(), error);
+            Ok(10000.0)
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
     {
         let _ = path;
         Ok(10000.0)
@@ -544,7 +623,7 @@ pub fn get_available_disk_space(path: &str) -> Result<f64> {
 }
 
 fn scan_cache_directory(cache_dir: &str) -> Result<CacheScanResult> {
-    let dir_path = Path::new(cache_dir);
+    let dir_path = long_path(Path::new(cache_dir));
 
     if !dir_path.exists() {
         return Ok(CacheScanResult {
@@ -565,7 +644,7 @@ fn scan_cache_directory(cache_dir: &str) -> Result<CacheScanResult> {
         .as_secs() as f64;
 
     let read_dir =
-        fs::read_dir(dir_path).map_err(|e| KreuzbergError::cache(format!("Failed to read cache directory: {}", e)))?;
+        fs::read_dir(&dir_path).map_err(|e| KreuzbergError::cache(format!("Failed to read cache directory: {}", e)))?;
 
     let mut total_size = 0u64;
     let mut oldest_age = 0.0f64;
@@ -755,6 +834,27 @@ pub fn fast_hash(data: &[u8]) -> u64 {
     hasher.finish()
 }
 
+/// Hash of `data` that is the same in every process and on every platform.
+///
+/// [`fast_hash`] is seeded randomly per process, so hashes written to disk and compared by a
+/// later run (checkpoints, manifests, cache keys) must use this one.
+pub fn stable_hash(data: &[u8]) -> u64 {
+    digest_prefix(&Sha256::digest(data))
+}
+
+/// [`stable_hash`] of everything `reader` yields, without holding it in memory.
+pub fn stable_hash_reader(mut reader: impl std::io::Read) -> std::io::Result<u64> {
+    let mut hasher = Sha256::new();
+    std::io::copy(&mut reader, &mut hasher)?;
+    Ok(digest_prefix(&hasher.finalize()))
+}
+
+fn digest_prefix(digest: &[u8]) -> u64 {
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(prefix)
+}
+
 pub fn validate_cache_key(key: &str) -> bool {
     key.len() == 32 && key.chars().all(|c| c.is_ascii_hexdigit())
 }
@@ -782,7 +882,7 @@ pub fn is_cache_valid(cache_path: &str, max_age_days: f64) -> bool {
 }
 
 pub fn clear_cache_directory(cache_dir: &str) -> Result<(usize, f64)> {
-    let dir_path = Path::new(cache_dir);
+    let dir_path = long_path(Path::new(cache_dir));
 
     if !dir_path.exists() {
         return Ok((0, 0.0));
@@ -792,7 +892,7 @@ pub fn clear_cache_directory(cache_dir: &str) -> Result<(usize, f64)> {
     let mut removed_size = 0.0;
 
     let read_dir =
-        fs::read_dir(dir_path).map_err(|e| KreuzbergError::cache(format!("Failed to read cache directory: {}", e)))?;
+        fs::read_dir(&dir_path).map_err(|e| KreuzbergError::cache(format!("Failed to read cache directory: {}", e)))?;
 
     for entry in read_dir {
         let entry = match entry {
@@ -828,6 +928,43 @@ pub fn clear_cache_directory(cache_dir: &str) -> Result<(usize, f64)> {
     Ok((removed_count, removed_size))
 }
 
+/// Write the files of a cache directory, including its subdirectories, through to disk.
+///
+/// Returns the number of synced files; a missing directory has nothing to sync.
+pub fn sync_cache_directory(cache_dir: &str) -> Result<usize> {
+    let dir_path = long_path(Path::new(cache_dir));
+    if !dir_path.exists() {
+        return Ok(0);
+    }
+    sync_directory(&dir_path)
+}
+
+fn sync_directory(dir: &Path) -> Result<usize> {
+    let read_dir =
+        fs::read_dir(dir).map_err(|e| KreuzbergError::cache(format!("Failed to read cache directory: {}", e)))?;
+
+    let mut synced = 0;
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        match entry.file_type() {
+            Ok(file_type) if file_type.is_dir() => synced += sync_directory(&path)?,
+            Ok(file_type) if file_type.is_file() => match sync_file(&path) {
+                Ok(()) => synced += 1,
+                // Entries removed by a concurrent cleanup have nothing left to sync
+                Err(e) => tracing::debug!("Failed to sync {:?}: {}", path, e),
+            },
+            _ => {}
+        }
+    }
+
+    Ok(synced)
+}
+
+/// Flushing needs write access on Windows.
+fn sync_file(path: &Path) -> std::io::Result<()> {
+    fs::OpenOptions::new().write(true).open(path)?.sync_all()
+}
+
 pub fn batch_cleanup_caches(
     cache_dirs: &[&str],
     max_age_days: f64,
@@ -879,6 +1016,16 @@ mod tests {
         assert_ne!(fast_hash(data1), fast_hash(data3));
     }
 
+    #[test]
+    fn test_stable_hash_is_fixed() {
+        assert_eq!(stable_hash(b"kreuzberg"), 0x6006_aa72_3873_3175);
+        assert_ne!(stable_hash(b"kreuzberg"), stable_hash(b"Kreuzberg"));
+        assert_eq!(
+            stable_hash_reader(&b"kreuzberg"[..]).unwrap(),
+            stable_hash(b"kreuzberg")
+        );
+    }
+
     #[test]
     fn test_filter_old_cache_entries() {
         let cache_times = vec![100.0, 200.0, 300.0, 400.0];
@@ -945,6 +1092,21 @@ mod tests {
         assert!(!file1.exists());
     }
 
+    #[test]
+    fn test_sync_cache_directory() {
+        let temp_dir = tempdir().unwrap();
+        let cache_dir = temp_dir.path().to_str().unwrap();
+        fs::write(temp_dir.path().join("a.msgpack"), b"a").unwrap();
+        fs::create_dir(temp_dir.path().join("ocr")).unwrap();
+        fs::write(temp_dir.path().join("ocr").join("b.msgpack"), b"b").unwrap();
+
+        assert_eq!(sync_cache_directory(cache_dir).unwrap(), 2);
+        assert_eq!(
+            sync_cache_directory(temp_dir.path().join("missing").to_str().unwrap()).unwrap(),
+            0
+        );
+    }
+
     #[test]
     fn test_is_cache_valid() {
         let temp_dir = tempdir().unwrap();
@@ -1164,4 +1326,39 @@ mod tests {
         assert_eq!(cache.cache_type(), "test");
         assert!(cache.cache_dir().to_string_lossy().contains("test"));
     }
+
+    #[test]
+    fn test_verbatim_path() {
+        assert_eq!(
+            verbatim_path(r"C:\Users\dev\.kreuzberg"),
+            Some(r"\\?\C:\Users\dev\.kreuzberg".to_string())
+        );
+        assert_eq!(
+            verbatim_path(r"\\server\share\cache"),
+            Some(r"\\?\UNC\server\share\cache".to_string())
+        );
+        assert_eq!(verbatim_path(r"\\?\C:\cache"), None);
+        assert_eq!(verbatim_path(r"cache\ocr"), None);
+        assert_eq!(verbatim_path("/var/cache"), None);
+    }
+
+    #[test]
+    fn test_generic_cache_deep_directory() {
+        let temp_dir = tempdir().unwrap();
+        let mut deep_dir = temp_dir.path().to_path_buf();
+        for level in 0..10 {
+            deep_dir.push(format!("nested-project-directory-{:02}", level));
+        }
+        assert!(deep_dir.as_os_str().len() > 260);
+
+        let deep_dir = deep_dir.to_str().unwrap().to_string();
+        let cache = GenericCache::new("test".to_string(), Some(deep_dir.clone()), 30.0, 500.0, 1000.0).unwrap();
+        cache.set("test_key", b"test data".to_vec(), None).unwrap();
+
+        assert_eq!(cache.get("test_key", None).unwrap(), Some(b"test data".to_vec()));
+        let stats = get_cache_metadata(cache.cache_dir().to_str().unwrap()).unwrap();
+        assert_eq!(stats.total_files, 1);
+        assert!(stats.available_space_mb > 0.0);
+        assert!(get_available_disk_space(&deep_dir).unwrap() > 0.0);
+    }
 }
@@ -111,6 +111,11 @@ mod tests {
 	            chunks: None,
 	            images: None,
 	            pages: None,
+	            entities: None,
+	            keywords: None,
+	            children: None,
+	            fields: None,
+	            warnings: Vec::new(),
 	        };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -134,6 +139,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -169,6 +179,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config_with_chunking = ExtractionConfig {
@@ -199,6 +214,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let long_result = ExtractionResult {
@@ -210,6 +230,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
@@ -0,0 +1,356 @@
+//! Checkpoint/resume for long batch jobs.
+//!
+//! [`batch_extract_file_resumable`] extracts like [`batch_extract_file`](crate::batch_extract_file)
+//! but records its progress in a state file, so a run that was interrupted (a crash, a killed
+//! container, a reboot) picks up where it left off instead of repeating hours of OCR.
+//!
+//! The state file is JSON Lines: a header with the configuration hash and the directory of
+//! partial results, followed by one line per completed file. Each successful result is written
+//! to that directory (`<state file>.results/` by default) before its line is appended, so a
+//! crash at any point loses at most the files still in flight. Failed files are not recorded
+//! and are retried on resume. A checkpoint written with a different configuration is
+//! discarded and the batch starts over.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::{ExtractionConfig, batch_extract_file_resumable};
+//!
+//! # async fn example() -> kreuzberg::Result<()> {
+//! let paths = vec!["scan-001.pdf", "scan-002.pdf"];
+//! // Re-running after an interruption only extracts the files not completed before.
+//! let results = batch_extract_file_resumable(paths, &ExtractionConfig::default(), "batch.state").await?;
+//! println!("{} files extracted", results.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::cache::stable_hash;
+use crate::core::batch_mode::with_batch_mode;
+use crate::core::config::ExtractionConfig;
+use crate::core::dedup::mark_duplicates;
+use crate::core::extractor::{GLOBAL_RUNTIME, batch_error_result, extract_file};
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// Version of the state file format.
+const STATE_VERSION: u32 = 1;
+
+/// First line of a state file.
+#[derive(Debug, Serialize, Deserialize)]
+struct StateHeader {
+    version: u32,
+    config_hash: String,
+    results_dir: PathBuf,
+}
+
+/// A completed file: its path and the name of its result in the results directory.
+#[derive(Debug, Serialize, Deserialize)]
+struct CompletedFile {
+    path: String,
+    result: String,
+}
+
+/// Progress of a resumable batch, backed by an append-only state file.
+struct Checkpoint {
+    state: tokio::fs::File,
+    results_dir: PathBuf,
+    completed: HashMap<String, String>,
+}
+
+impl Checkpoint {
+    /// Continue the checkpoint at `state_path`, or start a new one when there is none or it
+    /// was written with a different configuration.
+    async fn open(state_path: &Path, config_hash: String) -> Result<Self> {
+        if let Some((header, completed)) = read_state(state_path).await?
+            && header.config_hash == config_hash
+        {
+            tracing::info!("Resuming batch: {} files already completed", completed.len());
+            tokio::fs::create_dir_all(&header.results_dir).await?;
+            let state = tokio::fs::OpenOptions::new().append(true).open(state_path).await?;
+            return Ok(Self {
+                state,
+                results_dir: header.results_dir,
+                completed,
+            });
+        }
+
+        let header = StateHeader {
+            version: STATE_VERSION,
+            config_hash,
+            results_dir: std::path::absolute(default_results_dir(state_path))?,
+        };
+        tokio::fs::create_dir_all(&header.results_dir).await?;
+        let mut state = tokio::fs::File::create(state_path).await?;
+        state.write_all(&json_line(&header)?).await?;
+        state.flush().await?;
+        Ok(Self {
+            state,
+            results_dir: header.results_dir,
+            completed: HashMap::new(),
+        })
+    }
+
+    /// The stored result of `key`, if it was completed and its result is still readable.
+    async fn restore(&self, key: &str) -> Option<ExtractionResult> {
+        let name = self.completed.get(key)?;
+        let data = tokio::fs::read(self.results_dir.join(name)).await.ok()?;
+        serde_json::from_slice(&data).ok()
+    }
+
+    /// Store the result of `key` and append it to the state file.
+    async fn record(&mut self, key: String, result: &ExtractionResult) -> Result<()> {
+        let name = result_name(&key);
+        tokio::fs::write(self.results_dir.join(&name), serde_json::to_vec(result)?).await?;
+
+        let entry = CompletedFile {
+            path: key,
+            result: name,
+        };
+        self.state.write_all(&json_line(&entry)?).await?;
+        self.state.flush().await?;
+        self.completed.insert(entry.path, entry.result);
+        Ok(())
+    }
+}
+
+/// The header and completed files of the state file at `state_path` (None when it does not
+/// exist or has no valid header). A line cut off by a crash is ignored.
+async fn read_state(state_path: &Path) -> Result<Option<(StateHeader, HashMap<String, String>)>> {
+    let data = match tokio::fs::read_to_string(state_path).await {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut lines = data.lines();
+    let header = lines
+        .next()
+        .and_then(|line| serde_json::from_str::<StateHeader>(line).ok());
+    let Some(header) = header.filter(|header| header.version == STATE_VERSION) else {
+        tracing::warn!("Ignoring unreadable batch state file '{}'", state_path.display());
+        return Ok(None);
+    };
+    let completed = lines
+        .filter_map(|line| serde_json::from_str::<CompletedFile>(line).ok())
+        .map(|entry| (entry.path, entry.result))
+        .collect();
+    Ok(Some((header, completed)))
+}
+
+fn default_results_dir(state_path: &Path) -> PathBuf {
+    let mut dir = state_path.as_os_str().to_owned();
+    dir.push(".results");
+    PathBuf::from(dir)
+}
+
+/// File name of the result of `key` in the results directory, the same in every run.
+fn result_name(key: &str) -> String {
+    format!("{:016x}.json", stable_hash(key.as_bytes()))
+}
+
+fn json_line<T: Serialize>(value: &T) -> Result<Vec<u8>> {
+    let mut line = serde_json::to_vec(value)?;
+    line.push(b'\n');
+    Ok(line)
+}
+
+/// Key identifying a file across runs, independent of the working directory.
+fn file_key(path: &Path) -> String {
+    std::fs::canonicalize(path)
+        .unwrap_or_else(|_| path.to_path_buf())
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Extract multiple files concurrently, checkpointing progress to `state_path`.
+///
+/// Behaves like [`batch_extract_file`](crate::batch_extract_file): results are returned in
+/// input order and failed files yield a placeholder result with `metadata.error` set. Files
+/// completed by an earlier run with the same state file and configuration are loaded from the
+/// results directory instead of being extracted again. Files are identified by their
+/// canonical path, so a file changed in place between runs keeps its earlier result.
+///
+/// # Errors
+///
+/// Fails when the state file cannot be created, and on system (I/O) errors of individual
+/// files, like `batch_extract_file`. Progress made up to that point is kept. A result that
+/// cannot be checkpointed is logged and still returned; it is extracted again on resume.
+pub async fn batch_extract_file_resumable(
+    paths: Vec<impl AsRef<Path>>,
+    config: &ExtractionConfig,
+    state_path: impl AsRef<Path>,
+) -> Result<Vec<ExtractionResult>> {
+    let config_json = serde_json::to_value(config)?.to_string();
+    let config_hash = format!("{:016x}", stable_hash(config_json.as_bytes()));
+    let mut checkpoint = Checkpoint::open(state_path.as_ref(), config_hash).await?;
+
+    let mut results = Vec::with_capacity(paths.len());
+    let mut pending = Vec::new();
+    for (index, path) in paths.iter().enumerate() {
+        let path = path.as_ref().to_path_buf();
+        let key = file_key(&path);
+        let restored = checkpoint.restore(&key).await;
+        if restored.is_none() {
+            pending.push((index, key, path));
+        }
+        results.push(restored);
+    }
+
+    let config = Arc::new(config.clone());
+    let max_concurrent = config.max_concurrent_extractions.unwrap_or_else(|| num_cpus::get() * 2);
+    let semaphore = Arc::new(Semaphore::new(max_concurrent.max(1)));
+    let mut tasks = JoinSet::new();
+    for (index, key, path) in pending {
+        let config = Arc::clone(&config);
+        let semaphore = Arc::clone(&semaphore);
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire().await;
+            let result = with_batch_mode(extract_file(&path, None, &config)).await;
+            (index, key, result)
+        });
+    }
+
+    while let Some(joined) = tasks.join_next().await {
+        let (index, key, result) = joined.map_err(|e| KreuzbergError::Other(format!("Task panicked: {}", e)))?;
+        results[index] = Some(match result {
+            Ok(result) => {
+                // Without its state line the file is only extracted again on resume, so the
+                // batch and the tasks still running carry on.
+                if let Err(e) = checkpoint.record(key.clone(), &result).await {
+                    tracing::warn!("Failed to checkpoint '{}': {}", key, e);
+                }
+                result
+            }
+            Err(e @ KreuzbergError::Io(_)) => return Err(e),
+            Err(e) => batch_error_result(&e),
+        });
+    }
+
+    let mut results: Vec<ExtractionResult> = results.into_iter().flatten().collect();
+    if let Some(dedup) = &config.dedup {
+        let ids: Vec<String> = paths
+            .iter()
+            .map(|path| path.as_ref().to_string_lossy().into_owned())
+            .collect();
+        mark_duplicates(&mut results, &ids, dedup);
+    }
+    Ok(results)
+}
+
+/// Synchronous wrapper for [`batch_extract_file_resumable`].
+pub fn batch_extract_file_resumable_sync(
+    paths: Vec<impl AsRef<Path>>,
+    config: &ExtractionConfig,
+    state_path: impl AsRef<Path>,
+) -> Result<Vec<ExtractionResult>> {
+    GLOBAL_RUNTIME.block_on(batch_extract_file_resumable(paths, config, state_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_files(dir: &TempDir, names: &[&str]) -> Vec<PathBuf> {
+        names
+            .iter()
+            .map(|name| {
+                let path = dir.path().join(name);
+                std::fs::write(&path, format!("Contents of {}", name)).unwrap();
+                path
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_resumable_batch_records_progress() {
+        let dir = TempDir::new().unwrap();
+        let paths = write_files(&dir, &["a.txt", "b.txt"]);
+        let state_path = dir.path().join("batch.state");
+        let config = ExtractionConfig::default();
+
+        let results = batch_extract_file_resumable(paths.clone(), &config, &state_path)
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].content.contains("Contents of a.txt"));
+
+        let (header, completed) = read_state(&state_path).await.unwrap().unwrap();
+        assert_eq!(completed.len(), 2);
+        assert!(header.results_dir.ends_with("batch.state.results"));
+        assert_eq!(std::fs::read_dir(&header.results_dir).unwrap().count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resumable_batch_skips_completed_files() {
+        let dir = TempDir::new().unwrap();
+        let paths = write_files(&dir, &["a.txt", "b.txt"]);
+        let state_path = dir.path().join("batch.state");
+        let config = ExtractionConfig::default();
+
+        batch_extract_file_resumable(vec![&paths[0]], &config, &state_path)
+            .await
+            .unwrap();
+        // Changing the file shows whether the second run extracts it again.
+        std::fs::write(&paths[0], "Rewritten").unwrap();
+
+        let results = batch_extract_file_resumable(paths.clone(), &config, &state_path)
+            .await
+            .unwrap();
+        assert!(results[0].content.contains("Contents of a.txt"));
+        assert!(results[1].content.contains("Contents of b.txt"));
+        let (_, completed) = read_state(&state_path).await.unwrap().unwrap();
+        assert_eq!(completed.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_resumable_batch_restarts_on_config_change() {
+        let dir = TempDir::new().unwrap();
+        let paths = write_files(&dir, &["a.txt"]);
+        let state_path = dir.path().join("batch.state");
+
+        batch_extract_file_resumable(paths.clone(), &ExtractionConfig::default(), &state_path)
+            .await
+            .unwrap();
+        std::fs::write(&paths[0], "Rewritten").unwrap();
+
+        let config = ExtractionConfig {
+            use_cache: false,
+            ..Default::default()
+        };
+        let results = batch_extract_file_resumable(paths, &config, state_path).await.unwrap();
+        assert!(results[0].content.contains("Rewritten"));
+    }
+
+    #[test]
+    fn test_result_name_is_stable_across_runs() {
+        assert_eq!(result_name("/data/a.pdf"), "0a062a8f9d64148c.json");
+    }
+
+    #[tokio::test]
+    async fn test_read_state_ignores_truncated_line() {
+        let dir = TempDir::new().unwrap();
+        let state_path = dir.path().join("batch.state");
+        let header = StateHeader {
+            version: STATE_VERSION,
+            config_hash: "abc".to_string(),
+            results_dir: dir.path().to_path_buf(),
+        };
+        let mut data = json_line(&header).unwrap();
+        data.extend(br#"{"path":"/data/a.pdf","result":"1.json"}"#);
+        data.extend(b"\n{\"path\":\"/data/b.p");
+        std::fs::write(&state_path, data).unwrap();
+
+        let (_, completed) = read_state(&state_path).await.unwrap().unwrap();
+        assert_eq!(completed.len(), 1);
+        assert_eq!(completed["/data/a.pdf"], "1.json");
+    }
+}
@@ -3,9 +3,13 @@
 //! This module provides utilities for loading extraction configuration from various
 //! sources (TOML, YAML, JSON) and discovering configuration files in the project hierarchy.
 
-use crate::{KreuzbergError, Result};
+use super::config_validation;
+use super::context::ExtractionContext;
+use crate::{ErrorCode, KreuzbergError, Result};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// Page extraction and tracking configuration.
 ///
@@ -14,7 +18,7 @@ use std::path::Path;
 ///
 /// Page range tracking in chunk metadata (first_page/last_page) is automatically enabled
 /// when page boundaries are available and chunking is configured.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct PageConfig {
     /// Extract pages as separate array (ExtractionResult.pages)
@@ -57,7 +61,7 @@ impl Default for PageConfig {
 /// // Load from TOML file
 /// // let config = ExtractionConfig::from_toml_file("kreuzberg.toml")?;
 /// ```
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ExtractionConfig {
     /// Enable caching of extraction results
     #[serde(default = "default_true")]
@@ -88,6 +92,53 @@ pub struct ExtractionConfig {
     #[serde(default)]
     pub pdf_options: Option<PdfConfig>,
 
+    /// Archive extraction limits (None = use defaults)
+    #[cfg(feature = "archives")]
+    #[serde(default)]
+    pub archives: Option<ArchiveConfig>,
+
+    /// Email attachment handling (None = attachments are only listed)
+    #[cfg(feature = "email")]
+    #[serde(default)]
+    pub email: Option<EmailConfig>,
+
+    /// XPath field mappings per XML document type (None = plain text of all elements)
+    #[cfg(feature = "xml")]
+    #[serde(default)]
+    pub xml: Option<XmlConfig>,
+
+    /// Passwords for encrypted Word, Excel and PowerPoint documents (None = no passwords)
+    #[cfg(any(feature = "office", feature = "excel"))]
+    #[serde(default)]
+    pub office: Option<OfficeConfig>,
+
+    /// PowerPoint speaker notes and slide selection (None = all slides with their notes)
+    #[cfg(feature = "office")]
+    #[serde(default)]
+    pub pptx: Option<PptxConfig>,
+
+    /// Inline rendering of DOCX and PPTX tracked changes and comments (None = metadata only)
+    #[cfg(feature = "office")]
+    #[serde(default)]
+    pub revisions: Option<RevisionsConfig>,
+
+    /// Key-path filters, size guards and table mode for JSON and YAML (None = flatten everything)
+    #[serde(default)]
+    pub structured: Option<StructuredConfig>,
+
+    /// Encoding of plain text and Markdown inputs, e.g. `windows-1252`, `shift_jis` or `cp500`
+    /// (None = detect; detecting legacy encodings requires the `quality` feature)
+    #[serde(default)]
+    pub text_encoding: Option<String>,
+
+    /// Resource limits for hostile or oversized inputs (None = no limits)
+    #[serde(default)]
+    pub limits: Option<LimitsConfig>,
+
+    /// Retry policy for OCR and subprocess-based extraction (None = no retries)
+    #[serde(default)]
+    pub retry: Option<RetryConfig>,
+
     /// Token reduction configuration (None = no token reduction)
     #[serde(default)]
     pub token_reduction: Option<TokenReductionConfig>,
@@ -105,6 +156,28 @@ pub struct ExtractionConfig {
     #[serde(default)]
     pub keywords: Option<crate::keywords::KeywordConfig>,
 
+    /// Named-entity extraction configuration (None = no entity extraction)
+    #[cfg(feature = "entities")]
+    #[serde(default)]
+    pub entities: Option<crate::entities::EntityConfig>,
+
+    /// Template-based field extraction (None = no field extraction)
+    #[cfg(feature = "templates")]
+    #[serde(default)]
+    pub templates: Option<crate::templates::TemplateConfig>,
+
+    /// Garble detection gate for OCR output (None = no gating)
+    #[serde(default)]
+    pub quality_gate: Option<QualityGateConfig>,
+
+    /// Human-review flagging in `metadata.review` (None = no flagging)
+    #[serde(default)]
+    pub review: Option<ReviewConfig>,
+
+    /// How validator rejections and findings are applied (None = reject)
+    #[serde(default)]
+    pub validation: Option<ValidationConfig>,
+
     /// Post-processor configuration (None = use defaults)
     #[serde(default)]
     pub postprocessor: Option<PostProcessorConfig>,
@@ -117,16 +190,61 @@ pub struct ExtractionConfig {
     #[serde(skip)]
     pub html_options: Option<html_to_markdown_rs::ConversionOptions>,
 
+    /// Record per-stage durations in `metadata.timings`
+    #[serde(default)]
+    pub collect_timings: bool,
+
+    /// Refuse inputs whose content contradicts their declared MIME type or that also contain
+    /// another format (polyglots); otherwise mismatches are only reported in metadata
+    #[serde(default)]
+    pub strict_mime: bool,
+
+    /// Custom extension and magic-byte MIME mappings for formats the built-in detection
+    /// doesn't know (None = built-in and registered mappings only)
+    #[serde(default)]
+    pub mime_mappings: Option<MimeMappingsConfig>,
+
+    /// Fail on malformed documents instead of repairing them.
+    ///
+    /// By default, documents that fail to parse because of a damaged structure (truncated
+    /// ZIP central directory, broken XML entity references, corrupt PDF cross-reference
+    /// table) are repaired and extracted on a best-effort basis, with each repair reported in
+    /// `ExtractionResult::warnings`.
+    #[serde(default)]
+    pub strict: bool,
+
+    /// Near-duplicate detection across batch and directory results, recorded in
+    /// `metadata.duplicate_of` (None = disabled; single-document extraction ignores it)
+    #[serde(default)]
+    pub dedup: Option<DedupConfig>,
+
     /// Maximum concurrent extractions in batch operations (None = num_cpus * 2).
     ///
     /// Limits parallelism to prevent resource exhaustion when processing
     /// large batches. Defaults to twice the number of CPU cores.
     #[serde(default)]
     pub max_concurrent_extractions: Option<usize>,
+
+    /// Compute device for model-backed features such as embeddings (None = auto-select)
+    #[serde(default)]
+    pub device: Option<DeviceConfig>,
+
+    /// Per-plugin configuration keyed by plugin name.
+    ///
+    /// Loaded from `[extractors.<name>]` sections in config files. Entries are passed
+    /// opaquely to plugins, which read their `options` table via [`ExtractionConfig::plugin_options`].
+    /// An optional `priority` key overrides the registered priority of a document extractor.
+    #[serde(default, alias = "extractors", skip_serializing_if = "HashMap::is_empty")]
+    pub plugin_config: HashMap<String, serde_json::Value>,
+
+    /// Runtime state handed to nested extractions, set by the extraction functions
+    #[doc(hidden)]
+    #[serde(skip)]
+    pub context: ExtractionContext,
 }
 
 /// Post-processor configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PostProcessorConfig {
     /// Enable post-processors
     #[serde(default = "default_true")]
@@ -139,10 +257,523 @@ pub struct PostProcessorConfig {
     /// Blacklist of processor names to skip (None = none disabled)
     #[serde(default)]
     pub disabled_processors: Option<Vec<String>>,
+
+    /// Running header/footer and page-number removal (None = disabled)
+    #[serde(default)]
+    pub header_footer: Option<HeaderFooterConfig>,
+
+    /// Hyphenation and hard line-break repair (None = disabled)
+    #[serde(default)]
+    pub line_repair: Option<LineRepairConfig>,
+
+    /// Unicode normalization and invisible-character cleanup (None = disabled)
+    #[serde(default)]
+    pub normalization: Option<TextNormalizationConfig>,
+
+    /// Readability and vocabulary metrics in `metadata.text_metrics` (None = disabled)
+    #[serde(default)]
+    pub metrics: Option<TextMetricsConfig>,
+
+    /// Named regex or dictionary taggers whose matches go to `metadata.tags` (None = disabled)
+    #[serde(default)]
+    pub taggers: Option<HashMap<String, TaggerConfig>>,
+}
+
+/// Running header/footer removal configuration.
+///
+/// Lines near the top or bottom of a page that repeat across pages (ignoring digits, so
+/// "Page 3 of 12" and "Page 4 of 12" count as the same line) are removed from `content`.
+/// Requires page boundaries (`metadata.pages`) or per-page content (`pages.extract_pages`).
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct HeaderFooterConfig {
+    /// Number of non-empty lines at the top and bottom of each page to inspect
+    #[serde(default = "default_header_footer_lines")]
+    pub lines_to_check: usize,
+
+    /// Minimum number of pages a document needs before anything is removed
+    #[serde(default = "default_header_footer_min_pages")]
+    pub min_pages: usize,
+
+    /// Fraction of pages (0.0-1.0) a line must appear on to count as a header or footer
+    #[serde(default = "default_header_footer_repeat_ratio")]
+    pub min_repeat_ratio: f64,
+
+    /// Also remove bare page numbers ("12", "- 12 -", "Page 12", "12 / 40") at page edges
+    #[serde(default = "default_true")]
+    pub remove_page_numbers: bool,
+}
+
+impl Default for HeaderFooterConfig {
+    fn default() -> Self {
+        Self {
+            lines_to_check: default_header_footer_lines(),
+            min_pages: default_header_footer_min_pages(),
+            min_repeat_ratio: default_header_footer_repeat_ratio(),
+            remove_page_numbers: true,
+        }
+    }
+}
+
+/// Hyphenation and line-break repair configuration.
+///
+/// Joins words split with a hyphen at the end of a line ("extrac-\ntion" becomes "extraction")
+/// and merges hard-wrapped lines within a paragraph into a single line. Blank lines, list items,
+/// headings and table rows are kept as they are.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct LineRepairConfig {
+    /// Join words hyphenated across line breaks
+    #[serde(default = "default_true")]
+    pub dehyphenate: bool,
+
+    /// Merge hard-wrapped lines within paragraphs
+    #[serde(default = "default_true")]
+    pub merge_lines: bool,
+
+    /// ISO 639 language code of the text (None = use detected languages, falling back to the script)
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+impl Default for LineRepairConfig {
+    fn default() -> Self {
+        Self {
+            dehyphenate: true,
+            merge_lines: true,
+            language: None,
+        }
+    }
+}
+
+/// Unicode normalization form applied by [`TextNormalizationConfig`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum NormalizationForm {
+    /// Leave the text in whatever form the extractor produced
+    None,
+    /// Canonical composition: "e" + combining acute becomes "é"
+    #[default]
+    Nfc,
+    /// Compatibility composition: additionally folds ligatures, full-width forms and
+    /// superscripts ("ﬁ" becomes "fi", "Ａ" becomes "A")
+    Nfkc,
+}
+
+impl NormalizationForm {
+    /// Lowercase name used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NormalizationForm::None => "none",
+            NormalizationForm::Nfc => "nfc",
+            NormalizationForm::Nfkc => "nfkc",
+        }
+    }
+}
+
+impl std::str::FromStr for NormalizationForm {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "none" => Ok(NormalizationForm::None),
+            "nfc" => Ok(NormalizationForm::Nfc),
+            "nfkc" => Ok(NormalizationForm::Nfkc),
+            other => Err(KreuzbergError::validation(format!(
+                "Unknown normalization form '{}', expected one of: none, nfc, nfkc",
+                other
+            ))),
+        }
+    }
+}
+
+/// Unicode normalization and control-character policy.
+///
+/// The same text extracted from different formats often differs only in invisible ways:
+/// composed vs. decomposed accents, zero-width spaces, bidi marks, or curly vs. straight
+/// quotes. Normalizing these makes content from different sources compare equal, which
+/// exact-match deduplication and hashing rely on.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TextNormalizationConfig {
+    /// Unicode normalization form
+    #[serde(default)]
+    pub form: NormalizationForm,
+
+    /// Remove zero-width characters (zero-width space, word joiner, BOM, soft hyphen).
+    /// Zero-width joiners are kept because they change rendering in Indic and Arabic
+    /// scripts and in emoji sequences.
+    #[serde(default = "default_true")]
+    pub remove_zero_width: bool,
+
+    /// Remove bidirectional control characters (LRM, RLM, embeddings, overrides, isolates)
+    #[serde(default = "default_true")]
+    pub remove_bidi_controls: bool,
+
+    /// Remove C0/C1 control characters other than tab, line feed, carriage return and form feed
+    #[serde(default = "default_true")]
+    pub remove_control_chars: bool,
+
+    /// Replace typographic quotes (‘ ’ “ ” „ « » ...) with ASCII `'` and `"`
+    #[serde(default = "default_true")]
+    pub canonicalize_quotes: bool,
+
+    /// Replace Unicode hyphens, dashes and the minus sign with ASCII `-`
+    #[serde(default = "default_true")]
+    pub canonicalize_dashes: bool,
+}
+
+impl Default for TextNormalizationConfig {
+    fn default() -> Self {
+        Self {
+            form: NormalizationForm::default(),
+            remove_zero_width: true,
+            remove_bidi_controls: true,
+            remove_control_chars: true,
+            canonicalize_quotes: true,
+            canonicalize_dashes: true,
+        }
+    }
+}
+
+/// Readability and complexity metrics configuration.
+///
+/// Computes Flesch reading ease, Flesch-Kincaid grade, LIX, sentence/word statistics and
+/// vocabulary richness for the extracted content and stores them in `metadata.text_metrics`.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TextMetricsConfig {
+    /// Words with more characters than this count as long words for LIX
+    #[serde(default = "default_long_word_length")]
+    pub long_word_length: usize,
+}
+
+impl Default for TextMetricsConfig {
+    fn default() -> Self {
+        Self {
+            long_word_length: default_long_word_length(),
+        }
+    }
+}
+
+/// A custom tagger: a regular expression or a dictionary of terms.
+///
+/// Taggers are keyed by name in [`PostProcessorConfig::taggers`]; every match is reported
+/// under that name in `metadata.tags` with byte offsets into the content.
+///
+/// ```toml
+/// [postprocessor.taggers]
+/// contract_number = '\bCN-\d{6}\b'
+/// product = ["Widget Pro", "Gadget"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum TaggerConfig {
+    /// Regular expression (Rust `regex` syntax); each match is a tag. Use `(?i)` for
+    /// case-insensitive matching.
+    Pattern(String),
+    /// Terms matched as whole words, case-insensitively.
+    Dictionary(Vec<String>),
+}
+
+/// What the quality gate does with a result that falls below its thresholds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum QualityGateAction {
+    /// Set `metadata.additional["low_quality"]` and return the result
+    #[default]
+    Flag,
+    /// Fail the extraction with a validation error
+    Reject,
+}
+
+impl QualityGateAction {
+    /// Lowercase name used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QualityGateAction::Flag => "flag",
+            QualityGateAction::Reject => "reject",
+        }
+    }
+}
+
+impl std::str::FromStr for QualityGateAction {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "flag" => Ok(QualityGateAction::Flag),
+            "reject" => Ok(QualityGateAction::Reject),
+            other => Err(KreuzbergError::validation(format!(
+                "Unknown quality gate action '{}', expected one of: flag, reject",
+                other
+            ))),
+        }
+    }
+}
+
+/// Garble detection thresholds for OCR output.
+///
+/// A failed scan usually still produces text, just not words. The gate scores the content
+/// by how many tokens are common words of the document language (dictionary-hit rate) and
+/// how many tokens show typical OCR confusion patterns, and marks or rejects results that
+/// fall below either threshold.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct QualityGateConfig {
+    /// Minimum share of words (0.0-1.0) found in the stopword list of the document language.
+    /// Running text usually scores well above 0.3; garbled OCR scores close to zero.
+    #[serde(default = "default_min_dictionary_hit_rate")]
+    pub min_dictionary_hit_rate: f64,
+
+    /// Minimum share of tokens (0.0-1.0) free of OCR confusion patterns such as digits inside
+    /// words ("he11o"), stray symbols inside words ("w~rd") or symbol-only tokens
+    #[serde(default = "default_min_confusion_score")]
+    pub min_confusion_score: f64,
+
+    /// Results with fewer words are not scored
+    #[serde(default = "default_min_words")]
+    pub min_words: usize,
+
+    /// Only gate results produced by OCR
+    #[serde(default = "default_true")]
+    pub ocr_only: bool,
+
+    /// What to do with results below the thresholds
+    #[serde(default)]
+    pub action: QualityGateAction,
+
+    /// ISO 639 language code used for the dictionary check (None = use detected languages,
+    /// falling back to English)
+    #[serde(default)]
+    pub language: Option<String>,
+}
+
+impl Default for QualityGateConfig {
+    fn default() -> Self {
+        Self {
+            min_dictionary_hit_rate: default_min_dictionary_hit_rate(),
+            min_confusion_score: default_min_confusion_score(),
+            min_words: default_min_words(),
+            ocr_only: true,
+            action: QualityGateAction::default(),
+            language: None,
+        }
+    }
+}
+
+/// Human-review flagging.
+///
+/// Combines the mean OCR confidence, the quality score, the quality gate verdict, validator
+/// rejections and limit truncations into `metadata.review`, a single `required` flag plus the
+/// reasons behind it, so review queues can route on one field. Thresholds set to `None` are
+/// not checked.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ReviewConfig {
+    /// Flag OCR results whose mean confidence (0.0-1.0) is below this
+    #[serde(default = "default_review_min_ocr_confidence")]
+    pub min_ocr_confidence: Option<f64>,
+
+    /// Flag results whose quality score (0.0-1.0) is below this; requires
+    /// `enable_quality_processing`
+    #[serde(default = "default_review_min_quality_score")]
+    pub min_quality_score: Option<f64>,
+
+    /// Flag results the quality gate marked as low quality
+    #[serde(default = "default_true")]
+    pub flag_low_quality: bool,
+
+    /// Flag results truncated by `limits` or by the archive limits
+    #[serde(default = "default_true")]
+    pub flag_truncation: bool,
+
+    /// Record validator rejections as review reasons instead of failing the extraction
+    #[serde(default = "default_true")]
+    pub flag_validator_failures: bool,
+}
+
+impl Default for ReviewConfig {
+    fn default() -> Self {
+        Self {
+            min_ocr_confidence: default_review_min_ocr_confidence(),
+            min_quality_score: default_review_min_quality_score(),
+            flag_low_quality: true,
+            flag_truncation: true,
+            flag_validator_failures: true,
+        }
+    }
+}
+
+/// What validator rejections and error findings do to a result.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationMode {
+    /// Fail the extraction with a validation error
+    #[default]
+    Reject,
+    /// Record the rejection as an error finding in `metadata.findings` and return the result
+    Annotate,
+}
+
+impl ValidationMode {
+    /// Lowercase name used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ValidationMode::Reject => "reject",
+            ValidationMode::Annotate => "annotate",
+        }
+    }
+}
+
+impl std::str::FromStr for ValidationMode {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "reject" => Ok(ValidationMode::Reject),
+            "annotate" => Ok(ValidationMode::Annotate),
+            other => Err(KreuzbergError::validation(format!(
+                "Unknown validation mode '{}', expected one of: reject, annotate",
+                other
+            ))),
+        }
+    }
+}
+
+/// Validator settings.
+///
+/// Validators report findings (severity, code, message and location) that are collected in
+/// `metadata.findings`. Warnings and informational findings never fail an extraction; `mode`
+/// decides whether a rejection or an error finding does, so soft policies such as
+/// "content suspiciously short" can annotate results instead of failing pipelines.
+///
+/// ```toml
+/// [validation]
+/// mode = "annotate"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ValidationConfig {
+    /// What validator rejections and error findings do (default: reject)
+    #[serde(default)]
+    pub mode: ValidationMode,
+}
+
+/// Near-duplicate detection for batch and directory extraction.
+///
+/// The content of every result is fingerprinted with a 64-bit simhash over overlapping word
+/// triples. A result whose fingerprint is within `max_distance` bits of an earlier result gets
+/// `metadata.duplicate_of` set to that result's path (its index for byte batches), so scanned
+/// copies and re-exports of the same document can be skipped when indexing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DedupConfig {
+    /// Maximum number of differing fingerprint bits (0-64) for two results to be duplicates
+    #[serde(default = "default_dedup_max_distance")]
+    pub max_distance: u32,
+
+    /// Results with fewer words are never grouped, as the fingerprints of short texts collide
+    #[serde(default = "default_dedup_min_words")]
+    pub min_words: usize,
+}
+
+impl Default for DedupConfig {
+    fn default() -> Self {
+        Self {
+            max_distance: default_dedup_max_distance(),
+            min_words: default_dedup_min_words(),
+        }
+    }
+}
+
+/// Custom MIME type mappings for formats the built-in detection doesn't know.
+///
+/// Proprietary exports such as `.dat` or `.rpt` files are otherwise rejected as
+/// `application/octet-stream`. Mapped MIME types are accepted as supported, so they can name a
+/// built-in format such as `text/plain` or one handled by a plugin extractor. These mappings
+/// apply to the input documents of extractions using this configuration and take precedence
+/// over the ones registered process-wide with
+/// [`register_mime_mappings`](crate::core::mime::register_mime_mappings).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct MimeMappingsConfig {
+    /// MIME type per file extension (case-insensitive, with or without the leading dot)
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub extensions: HashMap<String, String>,
+
+    /// Magic-byte signatures, checked in order before the file extension is looked up in the
+    /// built-in tables
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signatures: Vec<MimeSignature>,
+}
+
+/// Magic bytes identifying a custom format.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct MimeSignature {
+    /// Magic bytes as hexadecimal digits, e.g. `"52 50 54 31"`; spaces are ignored
+    pub magic: String,
+
+    /// Byte offset of the magic bytes in the content
+    #[serde(default)]
+    pub offset: usize,
+
+    /// MIME type of content carrying the magic bytes
+    pub mime_type: String,
+}
+
+/// Compute device for model-backed features.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Device {
+    /// The first available accelerator (CUDA, then Metal), otherwise the CPU
+    #[default]
+    Auto,
+    /// Always the CPU
+    Cpu,
+    /// An NVIDIA GPU through CUDA (`gpu-cuda` feature)
+    Cuda,
+    /// The Apple GPU/Neural Engine through CoreML (`gpu-metal` feature, macOS)
+    Metal,
+}
+
+impl Device {
+    /// Lowercase name used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Device::Auto => "auto",
+            Device::Cpu => "cpu",
+            Device::Cuda => "cuda",
+            Device::Metal => "metal",
+        }
+    }
+}
+
+impl std::str::FromStr for Device {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "auto" => Ok(Device::Auto),
+            "cpu" => Ok(Device::Cpu),
+            "cuda" => Ok(Device::Cuda),
+            "metal" => Ok(Device::Metal),
+            other => Err(KreuzbergError::validation(format!(
+                "Unknown device '{}', expected one of: auto, cpu, cuda, metal",
+                other
+            ))),
+        }
+    }
+}
+
+/// Where model-backed features (embeddings) run their ONNX models.
+///
+/// A requested accelerator that is not compiled in or not present on the machine falls back to
+/// the CPU with a warning instead of failing the extraction; see [`crate::device`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct DeviceConfig {
+    /// Device to run models on
+    #[serde(default)]
+    pub device: Device,
+
+    /// Upper bound for GPU memory used by a model session, in MiB (CUDA only, None = no limit)
+    #[serde(default)]
+    pub max_vram_mb: Option<u64>,
 }
 
 /// OCR configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct OcrConfig {
     /// OCR backend: tesseract, easyocr, paddleocr
     #[serde(default = "default_tesseract_backend")]
@@ -158,7 +789,7 @@ pub struct OcrConfig {
 }
 
 /// Chunking configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ChunkingConfig {
     /// Maximum characters per chunk
     #[serde(default = "default_chunk_size")]
@@ -181,7 +812,7 @@ pub struct ChunkingConfig {
 ///
 /// Configures embedding generation using ONNX models via fastembed-rs.
 /// Requires the `embeddings` feature to be enabled.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct EmbeddingConfig {
     /// The embedding model to use
     pub model: EmbeddingModelType,
@@ -221,7 +852,7 @@ impl Default for EmbeddingConfig {
 }
 
 /// Embedding model types supported by Kreuzberg.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum EmbeddingModelType {
     /// Use a preset model configuration (recommended)
@@ -236,7 +867,7 @@ pub enum EmbeddingModelType {
 }
 
 /// Image extraction configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ImageExtractionConfig {
     /// Extract images from documents
     #[serde(default = "default_true")]
@@ -261,11 +892,19 @@ pub struct ImageExtractionConfig {
     /// Maximum DPI threshold
     #[serde(default = "default_max_dpi")]
     pub max_dpi: i32,
+
+    /// Name of a registered image describer that fills `ExtractedImage::description`
+    #[serde(default)]
+    pub describer: Option<String>,
+
+    /// Use image descriptions as alt text of the Markdown image references in `content`
+    #[serde(default)]
+    pub inline_descriptions: bool,
 }
 
 /// PDF-specific configuration.
 #[cfg(feature = "pdf")]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PdfConfig {
     /// Extract images from PDF
     #[serde(default)]
@@ -278,10 +917,400 @@ pub struct PdfConfig {
     /// Extract PDF metadata
     #[serde(default = "default_true")]
     pub extract_metadata: bool,
+
+    /// Check PDF/A conformance and report the result in the PDF metadata (`pdfa`)
+    #[serde(default)]
+    pub validate_pdfa: bool,
+
+    /// Collect font and layout statistics and report them in the PDF metadata (`layout`)
+    #[serde(default)]
+    pub layout_stats: bool,
+}
+
+/// Archive extraction limits and options.
+///
+/// Members of ZIP, TAR and 7z archives are decompressed one at a time and never buffered
+/// beyond these limits, protecting long-running services from decompression bombs.
+/// Archives nested inside archives are expanded up to `max_depth` levels.
+#[cfg(feature = "archives")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ArchiveConfig {
+    /// Maximum decompressed size of a single member in bytes; larger members are skipped
+    #[serde(default = "default_max_archive_entry_size")]
+    pub max_entry_size: u64,
+
+    /// Maximum total decompressed size in bytes; extraction stops once it is reached
+    #[serde(default = "default_max_archive_total_size")]
+    pub max_total_size: u64,
+
+    /// How many levels of nested archives are expanded (0 = only the top-level archive)
+    #[serde(default = "default_max_archive_depth")]
+    pub max_depth: usize,
+
+    /// Maximum number of members processed, including members of nested archives
+    #[serde(default = "default_max_archive_entries")]
+    pub max_entries: usize,
+
+    /// List of passwords to try for encrypted ZIP members and 7z archives
+    #[serde(default)]
+    pub passwords: Option<Vec<String>>,
+
+    /// Only extract the members matching this filter (None = every member)
+    #[serde(default)]
+    pub members: Option<ArchiveMemberFilter>,
+}
+
+#[cfg(feature = "archives")]
+impl Default for ArchiveConfig {
+    fn default() -> Self {
+        Self {
+            max_entry_size: default_max_archive_entry_size(),
+            max_total_size: default_max_archive_total_size(),
+            max_depth: default_max_archive_depth(),
+            max_entries: default_max_archive_entries(),
+            passwords: None,
+            members: None,
+        }
+    }
+}
+
+/// Selection of the archive members that are extracted.
+///
+/// A member is selected when it matches one of the `include` globs (or `include` is empty),
+/// none of the `exclude` globs, one of the `mime_types` (or `mime_types` is empty) and the size
+/// bounds. Globs are matched against the member path and support `*`, `?` and `**`; a pattern
+/// without `/` matches the file name at any depth. MIME types are detected from the file
+/// extension, and a type ending in `/*` matches every subtype. Members that are not selected
+/// are neither decompressed nor counted against the archive limits; nested archives are
+/// expanded either way so that their members can be selected.
+///
+/// ```toml
+/// [archives.members]
+/// include = ["reports/**"]
+/// mime_types = ["application/pdf"]
+/// max_size = 52428800
+/// ```
+#[cfg(feature = "archives")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ArchiveMemberFilter {
+    /// Globs a member path must match (empty = every member)
+    #[serde(default)]
+    pub include: Vec<String>,
+
+    /// Globs of member paths to skip
+    #[serde(default)]
+    pub exclude: Vec<String>,
+
+    /// MIME types a member must have, e.g. `application/pdf` or `image/*` (empty = any type)
+    #[serde(default)]
+    pub mime_types: Vec<String>,
+
+    /// Minimum decompressed size in bytes
+    #[serde(default)]
+    pub min_size: Option<u64>,
+
+    /// Maximum decompressed size in bytes
+    #[serde(default)]
+    pub max_size: Option<u64>,
+}
+
+#[cfg(feature = "archives")]
+impl ArchiveMemberFilter {
+    /// Whether the member at `path` with `size` decompressed bytes is selected.
+    pub fn matches(&self, path: &str, size: u64) -> bool {
+        use crate::utils::glob::glob_match;
+
+        let included = self.include.is_empty() || self.include.iter().any(|pattern| glob_match(pattern, path));
+        let excluded = self.exclude.iter().any(|pattern| glob_match(pattern, path));
+        let within_size = self.min_size.is_none_or(|min| size >= min) && self.max_size.is_none_or(|max| size <= max);
+        included && !excluded && within_size && self.matches_mime_type(path)
+    }
+
+    fn matches_mime_type(&self, path: &str) -> bool {
+        if self.mime_types.is_empty() {
+            return true;
+        }
+        let Ok(mime_type) = crate::core::mime::detect_mime_type(path, false) else {
+            return false;
+        };
+
+        self.mime_types.iter().any(|wanted| match wanted.strip_suffix("/*") {
+            Some(top_level) => mime_type.split('/').next() == Some(top_level),
+            None => wanted.eq_ignore_ascii_case(&mime_type),
+        })
+    }
+}
+
+/// Resource limits enforced for every document, whatever its format.
+///
+/// Unset limits are not enforced. A result with more pages, characters or images than allowed
+/// fails with `KreuzbergError::LimitExceeded`, or with `truncate` set, is cut down to the limit
+/// and lists the exceeded limits in `metadata.additional["limits_exceeded"]`.
+/// `max_decompressed_bytes` and `max_recursion_depth` tighten the archive and email attachment
+/// limits.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct LimitsConfig {
+    /// Maximum number of pages, slides or sheets
+    #[serde(default)]
+    pub max_pages: Option<usize>,
+
+    /// Maximum number of characters in the extracted content
+    #[serde(default)]
+    pub max_output_chars: Option<usize>,
+
+    /// Maximum number of extracted images
+    #[serde(default)]
+    pub max_images: Option<usize>,
+
+    /// Maximum total bytes decompressed from an archive
+    #[serde(default)]
+    pub max_decompressed_bytes: Option<u64>,
+
+    /// Maximum nesting depth of archives in archives and attachments in emails
+    #[serde(default)]
+    pub max_recursion_depth: Option<usize>,
+
+    /// Truncate results that exceed a limit instead of failing
+    #[serde(default)]
+    pub truncate: bool,
+}
+
+/// Retry policy for transient extraction failures.
+///
+/// Applies to OCR and to extractors that shell out to external tools (LibreOffice for legacy
+/// Office formats, `pffexport` for Outlook stores). A failed attempt is retried when its
+/// [`ErrorCode`] is listed in `retry_on` or the error is a transient I/O error
+/// (see [`KreuzbergError::is_retryable`]). The delay before attempt `n + 1` is
+/// `initial_backoff_ms * backoff_multiplier^(n - 1)`, capped at `max_backoff_ms`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RetryConfig {
+    /// Total number of attempts, including the first one
+    #[serde(default = "default_retry_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Delay before the first retry in milliseconds
+    #[serde(default = "default_retry_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+
+    /// Factor applied to the delay after each retry
+    #[serde(default = "default_retry_backoff_multiplier")]
+    pub backoff_multiplier: f64,
+
+    /// Upper bound for the delay between attempts in milliseconds
+    #[serde(default = "default_retry_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+
+    /// Error codes that are retried in addition to transient I/O errors
+    #[serde(default = "default_retry_on")]
+    pub retry_on: Vec<ErrorCode>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: default_retry_max_attempts(),
+            initial_backoff_ms: default_retry_initial_backoff_ms(),
+            backoff_multiplier: default_retry_backoff_multiplier(),
+            max_backoff_ms: default_retry_max_backoff_ms(),
+            retry_on: default_retry_on(),
+        }
+    }
+}
+
+/// Email extraction options.
+///
+/// With `extract_attachments` set, each attachment is run through the extractor registry and
+/// post-processing pipeline, and its result is returned in `ExtractionResult.children`.
+///
+/// The `strip_*` options remove quoted replies, signatures and tracking pixels from message
+/// bodies, which keeps the conversation history of every reply out of a mail archive index.
+/// What was removed is kept in the email metadata (`quoted_text`, `signature` and
+/// `tracking_pixels`).
+#[cfg(feature = "email")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EmailConfig {
+    /// Extract attachments as child documents
+    #[serde(default)]
+    pub extract_attachments: bool,
+
+    /// How many levels of attachments are extracted (1 = only attachments of the top-level message)
+    #[serde(default = "default_max_attachment_depth")]
+    pub max_attachment_depth: usize,
+
+    /// Maximum attachment size in bytes; larger attachments are listed but not extracted
+    #[serde(default = "default_max_attachment_size")]
+    pub max_attachment_size: usize,
+
+    /// Remove quoted replies ("On ... wrote:" blocks, `>` lines, Outlook reply headers) from the body
+    #[serde(default)]
+    pub strip_quoted_replies: bool,
+
+    /// Remove the sender's signature (after a `-- ` line, or a "Sent from my ..." line) from the body
+    #[serde(default)]
+    pub strip_signatures: bool,
+
+    /// Remove tracking pixels (1x1 or hidden remote images) from HTML bodies
+    #[serde(default)]
+    pub strip_tracking_pixels: bool,
+
+    /// Maximum number of messages read from a PST/OST mailbox; later messages are not extracted
+    #[serde(default = "default_max_mailbox_messages")]
+    pub max_mailbox_messages: usize,
+
+    /// Maximum total size in bytes of the exported messages read from a PST/OST mailbox
+    #[serde(default = "default_max_mailbox_size")]
+    pub max_mailbox_size: u64,
+}
+
+#[cfg(feature = "email")]
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            extract_attachments: false,
+            max_attachment_depth: default_max_attachment_depth(),
+            max_attachment_size: default_max_attachment_size(),
+            strip_quoted_replies: false,
+            strip_signatures: false,
+            strip_tracking_pixels: false,
+            max_mailbox_messages: default_max_mailbox_messages(),
+            max_mailbox_size: default_max_mailbox_size(),
+        }
+    }
+}
+
+/// XML extraction options.
+///
+/// Each document type maps XPath expressions to named fields (`ExtractionResult.fields`) and
+/// content sections. The first type whose `root` matches the document's root element is used;
+/// documents matching none are extracted as the plain text of all elements.
+#[cfg(feature = "xml")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct XmlConfig {
+    /// Document types, checked in order
+    #[serde(default)]
+    pub document_types: Vec<XmlDocumentType>,
+}
+
+/// XPath mapping for one kind of XML document, such as UBL invoices or JATS articles.
+///
+/// Expressions use the subset of XPath described in [`crate::extraction::xpath`].
+#[cfg(feature = "xml")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct XmlDocumentType {
+    /// Name recorded in `metadata.document_type` of matching documents
+    pub name: String,
+
+    /// Root element identifying the type, e.g. `ubl:Invoice` (None = any document)
+    #[serde(default)]
+    pub root: Option<String>,
+
+    /// Namespace prefixes usable in `root` and the expressions, mapped to their URIs
+    #[serde(default)]
+    pub namespaces: std::collections::BTreeMap<String, String>,
+
+    /// Fields keyed by name: a single match gives a string, several give a list, none gives null
+    #[serde(default)]
+    pub fields: std::collections::BTreeMap<String, String>,
+
+    /// Content sections in output order; without sections the content is the plain text of all
+    /// elements
+    #[serde(default)]
+    pub sections: Vec<XmlSection>,
+}
+
+/// A content section of an [`XmlDocumentType`].
+#[cfg(feature = "xml")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct XmlSection {
+    /// Section heading
+    pub title: String,
+
+    /// XPath expression; each selected node becomes one paragraph
+    pub path: String,
+}
+
+/// Options for Office Open XML documents (DOCX, XLSX and PPTX).
+///
+/// Password-protected documents using ECMA-376 agile encryption (Office 2010 and later) are
+/// decrypted with the first matching password. Documents that are encrypted without a user
+/// password open without configuration.
+#[cfg(any(feature = "office", feature = "excel"))]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct OfficeConfig {
+    /// List of passwords to try when opening encrypted DOCX, XLSX and PPTX files
+    #[serde(default)]
+    pub passwords: Option<Vec<String>>,
+}
+
+/// PowerPoint extraction options.
+#[cfg(feature = "office")]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PptxConfig {
+    /// Append each slide's speaker notes under a `### Notes:` heading
+    #[serde(default = "default_true")]
+    pub include_notes: bool,
+
+    /// Extract only the speaker notes, one paragraph per slide, without the slide content
+    #[serde(default)]
+    pub notes_only: bool,
+
+    /// Slides to extract as 1-based numbers and ranges, e.g. `"1-3,7"` or `"5-"` (None = all slides)
+    #[serde(default)]
+    pub slide_range: Option<String>,
+}
+
+#[cfg(feature = "office")]
+impl Default for PptxConfig {
+    fn default() -> Self {
+        Self {
+            include_notes: true,
+            notes_only: false,
+            slide_range: None,
+        }
+    }
+}
+
+/// Tracked changes and comments of DOCX and PPTX documents.
+///
+/// Revisions are always listed in `metadata.revisions`; these options add them to the content.
+#[cfg(feature = "office")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct RevisionsConfig {
+    /// Render revisions inline in CriticMarkup: `{++inserted++}`, `{--deleted--}`, `{>>author: comment<<}`
+    #[serde(default)]
+    pub render_inline: bool,
+}
+
+/// JSON and YAML extraction options.
+///
+/// Key paths use the notation of the flattened output (`user.name`, `items[0].title`); in
+/// `include_paths` and `exclude_paths`, `*` matches any single key or index and `[*]` any index.
+/// A path also selects everything below it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct StructuredConfig {
+    /// Only extract values at or below these key paths (empty = everything)
+    #[serde(default)]
+    pub include_paths: Vec<String>,
+
+    /// Skip values at or below these key paths
+    #[serde(default)]
+    pub exclude_paths: Vec<String>,
+
+    /// Maximum nesting depth of objects and arrays; deeper values are skipped (None = unlimited)
+    #[serde(default)]
+    pub max_depth: Option<usize>,
+
+    /// Maximum number of items extracted per array (None = unlimited)
+    #[serde(default)]
+    pub max_array_items: Option<usize>,
+
+    /// Extract arrays of objects sharing the same scalar fields as tables instead of key/value lines
+    #[serde(default)]
+    pub tabular: bool,
 }
 
 /// Token reduction configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct TokenReductionConfig {
     /// Reduction mode: "off", "light", "moderate", "aggressive", "maximum"
     #[serde(default = "default_reduction_mode")]
@@ -290,10 +1319,73 @@ pub struct TokenReductionConfig {
     /// Preserve important words (capitalized, technical terms)
     #[serde(default = "default_true")]
     pub preserve_important_words: bool,
+
+    /// Custom stopwords merged with or replacing the embedded list
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopwords: Option<StopwordsConfig>,
+
+    /// Keep at most this fraction (0.0-1.0) of the original tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_ratio: Option<f32>,
+
+    /// Keep at most this many tokens (CJK text counts one token per character bigram)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<usize>,
+}
+
+/// How custom stopwords combine with the embedded list for a language.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum StopwordsMode {
+    /// Add the custom words to the embedded list
+    #[default]
+    Merge,
+    /// Use only the custom words
+    Replace,
+}
+
+impl StopwordsMode {
+    /// Lowercase name used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StopwordsMode::Merge => "merge",
+            StopwordsMode::Replace => "replace",
+        }
+    }
+}
+
+impl std::str::FromStr for StopwordsMode {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "merge" => Ok(StopwordsMode::Merge),
+            "replace" => Ok(StopwordsMode::Replace),
+            other => Err(KreuzbergError::validation(format!(
+                "Unknown stopwords mode '{}', expected one of: merge, replace",
+                other
+            ))),
+        }
+    }
+}
+
+/// Custom stopwords for keyword extraction and token reduction.
+///
+/// Stopword lists for 64 languages are embedded in the binary; this adds domain
+/// words to them (`Merge`) or swaps them out entirely (`Replace`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct StopwordsConfig {
+    /// Custom stopwords (matched case-insensitively)
+    #[serde(default)]
+    pub custom: Vec<String>,
+
+    /// Whether `custom` is merged with or replaces the embedded list
+    #[serde(default)]
+    pub mode: StopwordsMode,
 }
 
 /// Language detection configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct LanguageDetectionConfig {
     /// Enable language detection
     #[serde(default = "default_true")]
@@ -305,7 +1397,11 @@ pub struct LanguageDetectionConfig {
 
     /// Detect multiple languages in the document
     #[serde(default)]
-    pub detect_multiple: bool,
+    pub detect_multiple: bool,
+
+    /// Only analyze the first N KiB of text (speeds up detection on large documents)
+    #[serde(default)]
+    pub max_sample_kb: Option<usize>,
 }
 
 fn default_true() -> bool {
@@ -344,6 +1440,97 @@ fn default_min_dpi() -> i32 {
 fn default_max_dpi() -> i32 {
     600
 }
+#[cfg(feature = "archives")]
+fn default_max_archive_entry_size() -> u64 {
+    64 * 1024 * 1024
+}
+#[cfg(feature = "archives")]
+fn default_max_archive_total_size() -> u64 {
+    512 * 1024 * 1024
+}
+#[cfg(feature = "archives")]
+fn default_max_archive_depth() -> usize {
+    3
+}
+#[cfg(feature = "archives")]
+fn default_max_archive_entries() -> usize {
+    10_000
+}
+#[cfg(feature = "email")]
+fn default_max_attachment_depth() -> usize {
+    3
+}
+#[cfg(feature = "email")]
+fn default_max_attachment_size() -> usize {
+    64 * 1024 * 1024
+}
+#[cfg(feature = "email")]
+fn default_max_mailbox_messages() -> usize {
+    100_000
+}
+#[cfg(feature = "email")]
+fn default_max_mailbox_size() -> u64 {
+    1024 * 1024 * 1024
+}
+fn default_retry_max_attempts() -> u32 {
+    3
+}
+fn default_retry_initial_backoff_ms() -> u64 {
+    500
+}
+fn default_retry_backoff_multiplier() -> f64 {
+    2.0
+}
+fn default_retry_max_backoff_ms() -> u64 {
+    10_000
+}
+fn default_retry_on() -> Vec<ErrorCode> {
+    vec![ErrorCode::Ocr, ErrorCode::Io]
+}
+fn default_long_word_length() -> usize {
+    6
+}
+
+fn default_header_footer_lines() -> usize {
+    2
+}
+
+fn default_header_footer_min_pages() -> usize {
+    3
+}
+
+fn default_header_footer_repeat_ratio() -> f64 {
+    0.5
+}
+
+fn default_min_dictionary_hit_rate() -> f64 {
+    0.15
+}
+
+fn default_min_confusion_score() -> f64 {
+    0.8
+}
+
+fn default_min_words() -> usize {
+    20
+}
+
+fn default_review_min_ocr_confidence() -> Option<f64> {
+    Some(0.6)
+}
+
+fn default_review_min_quality_score() -> Option<f64> {
+    Some(0.5)
+}
+
+fn default_dedup_max_distance() -> u32 {
+    3
+}
+
+fn default_dedup_min_words() -> usize {
+    20
+}
+
 fn default_reduction_mode() -> String {
     "off".to_string()
 }
@@ -362,15 +1549,46 @@ impl Default for ExtractionConfig {
             images: None,
             #[cfg(feature = "pdf")]
             pdf_options: None,
+            #[cfg(feature = "archives")]
+            archives: None,
+            #[cfg(feature = "email")]
+            email: None,
+            #[cfg(feature = "xml")]
+            xml: None,
+            #[cfg(any(feature = "office", feature = "excel"))]
+            office: None,
+            #[cfg(feature = "office")]
+            pptx: None,
+            #[cfg(feature = "office")]
+            revisions: None,
+            structured: None,
+            text_encoding: None,
+            limits: None,
+            retry: None,
             token_reduction: None,
             language_detection: None,
             pages: None,
             #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
             keywords: None,
+            #[cfg(feature = "entities")]
+            entities: None,
+            #[cfg(feature = "templates")]
+            templates: None,
+            quality_gate: None,
+            review: None,
+            validation: None,
             postprocessor: None,
             #[cfg(feature = "html")]
             html_options: None,
+            collect_timings: false,
+            strict_mime: false,
+            mime_mappings: None,
+            strict: false,
+            dedup: None,
             max_concurrent_extractions: None,
+            device: None,
+            plugin_config: HashMap::new(),
+            context: ExtractionContext::default(),
         }
     }
 }
@@ -381,47 +1599,232 @@ impl Default for PostProcessorConfig {
             enabled: true,
             enabled_processors: None,
             disabled_processors: None,
+            header_footer: None,
+            line_repair: None,
+            normalization: None,
+            metrics: None,
+            taggers: None,
         }
     }
 }
 
 impl ExtractionConfig {
+    /// Get the `options` table configured for a plugin.
+    ///
+    /// Returns `None` if the plugin has no `[extractors.<name>.options]` section.
+    pub fn plugin_options(&self, plugin_name: &str) -> Option<&serde_json::Value> {
+        self.plugin_config
+            .get(plugin_name)
+            .and_then(|entry| entry.get("options"))
+    }
+
+    /// Get the priority override configured for a plugin.
+    ///
+    /// Returns `None` if no override is set or the value is not a valid `i32`.
+    pub fn plugin_priority(&self, plugin_name: &str) -> Option<i32> {
+        self.plugin_config
+            .get(plugin_name)
+            .and_then(|entry| entry.get("priority"))
+            .and_then(|priority| priority.as_i64())
+            .and_then(|priority| i32::try_from(priority).ok())
+    }
+
     /// Load configuration from a TOML file.
     ///
+    /// The file is checked against [`json_schema`](Self::json_schema) and
+    /// [`validate`](Self::validate): unknown keys and out-of-range values are rejected.
+    ///
     /// # Arguments
     ///
     /// * `path` - Path to the TOML file
     ///
     /// # Errors
     ///
-    /// Returns `KreuzbergError::Validation` if file doesn't exist or is invalid TOML.
+    /// Returns `KreuzbergError::Validation` if file doesn't exist, is invalid TOML, or contains
+    /// unknown keys or out-of-range values. The message names the line and column of each problem.
     pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self> {
-        let content = std::fs::read_to_string(path.as_ref()).map_err(|e| {
-            KreuzbergError::validation(format!("Failed to read config file {}: {}", path.as_ref().display(), e))
-        })?;
-
-        toml::from_str(&content)
-            .map_err(|e| KreuzbergError::validation(format!("Invalid TOML in {}: {}", path.as_ref().display(), e)))
+        Self::load(path.as_ref(), ConfigFormat::Toml, None)
     }
 
     /// Load configuration from a YAML file.
+    ///
+    /// Checked like [`from_toml_file`](Self::from_toml_file).
     pub fn from_yaml_file(path: impl AsRef<Path>) -> Result<Self> {
-        let content = std::fs::read_to_string(path.as_ref()).map_err(|e| {
-            KreuzbergError::validation(format!("Failed to read config file {}: {}", path.as_ref().display(), e))
-        })?;
-
-        serde_yaml_ng::from_str(&content)
-            .map_err(|e| KreuzbergError::validation(format!("Invalid YAML in {}: {}", path.as_ref().display(), e)))
+        Self::load(path.as_ref(), ConfigFormat::Yaml, None)
     }
 
     /// Load configuration from a JSON file.
+    ///
+    /// Checked like [`from_toml_file`](Self::from_toml_file).
     pub fn from_json_file(path: impl AsRef<Path>) -> Result<Self> {
-        let content = std::fs::read_to_string(path.as_ref()).map_err(|e| {
-            KreuzbergError::validation(format!("Failed to read config file {}: {}", path.as_ref().display(), e))
+        Self::load(path.as_ref(), ConfigFormat::Json, None)
+    }
+
+    /// Load configuration from a file with a named profile applied.
+    ///
+    /// Profiles are partial configurations stored under `[profile.<name>]` next to the
+    /// top-level settings of the same file. The selected profile is merged over the top-level
+    /// settings: tables are merged key by key, any other value replaces the top-level one.
+    /// Without a profile, the `[profile.*]` tables are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` if the file can't be loaded (see
+    /// [`from_file`](Self::from_file)), doesn't define `profile`, or the merged
+    /// configuration is invalid.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use kreuzberg::ExtractionConfig;
+    ///
+    /// // kreuzberg.toml:
+    /// //
+    /// //   use_cache = true
+    /// //
+    /// //   [profile.fast]
+    /// //   use_cache = false
+    /// //   ocr.backend = "tesseract"
+    /// let config = ExtractionConfig::from_file_with_profile("kreuzberg.toml", "fast")?;
+    /// assert!(!config.use_cache);
+    /// # Ok::<(), kreuzberg::KreuzbergError>(())
+    /// ```
+    pub fn from_file_with_profile(path: impl AsRef<Path>, profile: &str) -> Result<Self> {
+        let path = path.as_ref();
+        Self::load(path, ConfigFormat::from_path(path)?, Some(profile))
+    }
+
+    /// Load a named profile from the discovered configuration file.
+    ///
+    /// Like [`discover`](Self::discover), but applies `name` with
+    /// [`from_file_with_profile`](Self::from_file_with_profile) before layering the
+    /// `KREUZBERG_*` environment overrides.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` if no config file is found, the file doesn't
+    /// define the profile, or the resulting configuration is invalid.
+    pub fn from_profile(name: &str) -> Result<Self> {
+        let path = Self::discover_path()?.ok_or_else(|| {
+            KreuzbergError::validation(format!(
+                "Cannot load profile '{}': no kreuzberg.toml found in the current or parent directories",
+                name
+            ))
         })?;
 
-        serde_json::from_str(&content)
-            .map_err(|e| KreuzbergError::validation(format!("Invalid JSON in {}: {}", path.as_ref().display(), e)))
+        let mut config = Self::from_file_with_profile(path, name)?;
+        config.apply_env_overrides()?;
+        Ok(config)
+    }
+
+    fn load(path: &Path, format: ConfigFormat, profile: Option<&str>) -> Result<Self> {
+        let content = read_config_file(path)?;
+        let mut document: serde_json::Value = format.parse(path, &content)?;
+        config_validation::report(&config_validation::unknown_keys(&document), Some((path, &content)))?;
+
+        let Some(name) = profile else {
+            let config: Self = format.parse(path, &content)?;
+            config.validate_source(path, &content)?;
+            return Ok(config);
+        };
+
+        let mut profiles = document
+            .as_object_mut()
+            .and_then(|root| root.remove(config_validation::PROFILES_KEY))
+            .unwrap_or_default();
+        let Some(overlay) = profiles.as_object_mut().and_then(|profiles| profiles.remove(name)) else {
+            let mut available: Vec<&str> = profiles
+                .as_object()
+                .map(|profiles| profiles.keys().map(String::as_str).collect())
+                .unwrap_or_default();
+            available.sort_unstable();
+            let available = match available.as_slice() {
+                [] => "it defines no profiles".to_string(),
+                names => format!("available profiles: {}", names.join(", ")),
+            };
+            return Err(KreuzbergError::validation(format!(
+                "Profile '{}' not found in {} ({})",
+                name,
+                path.display(),
+                available
+            )));
+        };
+
+        merge_config_values(&mut document, overlay.clone());
+        let config: Self = serde_json::from_value(document)
+            .map_err(|e| invalid_config(format.name(), path, format!("profile '{}': {}", name, e)))?;
+        let issues = config_validation::in_profile(config_validation::range_issues(&config), name, &overlay);
+        config_validation::report(&issues, Some((path, &content)))?;
+        Ok(config)
+    }
+
+    /// JSON Schema describing configuration files.
+    ///
+    /// Field documentation becomes the schema descriptions, so editors that understand JSON
+    /// Schema (for TOML via Taplo / Even Better TOML, for YAML via the YAML language server) can
+    /// offer completion and flag typos in `kreuzberg.toml`. The schema reflects the features
+    /// this build was compiled with; `html_options` is not part of it because it can only be
+    /// set programmatically. Named profiles (`[profile.<name>]`) are described as tables of
+    /// the same schema.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kreuzberg::ExtractionConfig;
+    ///
+    /// let schema = ExtractionConfig::json_schema();
+    /// assert!(schema["properties"]["ocr"].is_object());
+    /// ```
+    pub fn json_schema() -> serde_json::Value {
+        let mut schema = serde_json::to_value(schemars::schema_for!(ExtractionConfig))
+            .expect("the configuration schema serializes to JSON");
+        if let Some(properties) = schema.get_mut("properties").and_then(serde_json::Value::as_object_mut) {
+            properties.insert(
+                config_validation::PROFILES_KEY.to_string(),
+                serde_json::json!({
+                    "description": "Named profiles: partial configurations merged over the top-level settings when selected.",
+                    "type": "object",
+                    "additionalProperties": { "$ref": "#" },
+                }),
+            );
+        }
+        schema
+    }
+
+    /// Check that every value is within its documented range.
+    ///
+    /// Ratios and confidences must lie in 0.0-1.0, sizes and limits must be positive,
+    /// `chunking.max_overlap` must be smaller than `chunking.max_chars`, and string options
+    /// with a fixed set of values (such as `token_reduction.mode`) must use one of them.
+    /// Configuration files are validated when they are loaded; call this for configurations
+    /// built in code.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` listing every offending field.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kreuzberg::{ExtractionConfig, LanguageDetectionConfig};
+    ///
+    /// let config = ExtractionConfig {
+    ///     language_detection: Some(LanguageDetectionConfig {
+    ///         enabled: true,
+    ///         min_confidence: 80.0,
+    ///         detect_multiple: false,
+    ///         max_sample_kb: None,
+    ///     }),
+    ///     ..Default::default()
+    /// };
+    /// assert!(config.validate().is_err());
+    /// ```
+    pub fn validate(&self) -> Result<()> {
+        config_validation::report(&config_validation::range_issues(self), None)
+    }
+
+    fn validate_source(&self, path: &Path, content: &str) -> Result<()> {
+        config_validation::report(&config_validation::range_issues(self), Some((path, content)))
     }
 
     /// Load configuration from a file, auto-detecting format by extension.
@@ -455,39 +1858,119 @@ impl ExtractionConfig {
     /// ```
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
         let path = path.as_ref();
-        let extension = path.extension().and_then(|ext| ext.to_str()).ok_or_else(|| {
+        Self::load(path, ConfigFormat::from_path(path)?, None)
+    }
+
+    /// Discover configuration file in parent directories.
+    ///
+    /// Searches for `kreuzberg.toml` in current directory and parent directories, then
+    /// layers `KREUZBERG_*` environment variables over it (see
+    /// [`apply_env_overrides`](Self::apply_env_overrides)).
+    ///
+    /// # Returns
+    ///
+    /// - `Some(config)` if a config file was found or any environment override is set
+    /// - `None` otherwise
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` if the file or an environment override is invalid.
+    pub fn discover() -> Result<Option<Self>> {
+        let (mut config, found) = match Self::discover_path()? {
+            Some(path) => (Self::from_toml_file(path)?, true),
+            None => (Self::default(), false),
+        };
+
+        let applied = config.apply_env_overrides()?;
+        Ok((found || applied > 0).then_some(config))
+    }
+
+    /// Override fields from `KREUZBERG_*` environment variables.
+    ///
+    /// The variable name after the prefix is the field path, with `__` separating nested
+    /// sections and matched case-insensitively: `KREUZBERG_USE_CACHE=false` sets `use_cache`,
+    /// `KREUZBERG_OCR__LANGUAGE=deu` sets `ocr.language`. Missing sections are created with
+    /// their defaults. Values are parsed as JSON (booleans, numbers, arrays, objects) and
+    /// otherwise taken as strings; fields that currently hold a string always take the raw
+    /// value. Variables that don't name a top-level field, such as `KREUZBERG_CACHE_DIR`,
+    /// are ignored.
+    ///
+    /// # Returns
+    ///
+    /// The number of overrides applied.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` if an override produces an invalid configuration.
+    pub fn apply_env_overrides(&mut self) -> Result<usize> {
+        let vars =
+            std::env::vars_os().filter_map(|(key, value)| Some((key.into_string().ok()?, value.into_string().ok()?)));
+        self.apply_overrides(vars)
+    }
+
+    fn apply_overrides(&mut self, vars: impl IntoIterator<Item = (String, String)>) -> Result<usize> {
+        let mut overrides: Vec<(Vec<String>, String, String)> = vars
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let path = key.strip_prefix(ENV_PREFIX)?;
+                let segments: Vec<String> = path.split("__").map(str::to_lowercase).collect();
+                (!segments.iter().any(String::is_empty)).then_some((segments, key, value))
+            })
+            .collect();
+        if overrides.is_empty() {
+            return Ok(0);
+        }
+        // Parents before children, so `KREUZBERG_OCR` can be refined by `KREUZBERG_OCR__LANGUAGE`.
+        overrides.sort();
+
+        let mut json = serde_json::to_value(&*self)
+            .map_err(|e| KreuzbergError::validation(format!("Failed to serialize configuration: {}", e)))?;
+        let serde_json::Value::Object(root) = &mut json else {
+            return Ok(0);
+        };
+
+        let mut applied = Vec::new();
+        for (segments, key, raw) in overrides {
+            if !root.contains_key(&segments[0]) {
+                continue;
+            }
+            set_env_override(root, &segments, &raw);
+            applied.push(key);
+        }
+        if applied.is_empty() {
+            return Ok(0);
+        }
+
+        #[cfg_attr(not(feature = "html"), allow(unused_mut))]
+        let mut updated: Self = serde_json::from_value(json).map_err(|e| {
             KreuzbergError::validation(format!(
-                "Cannot determine file format: no extension found in {}",
-                path.display()
+                "Invalid configuration from environment variables {}: {}",
+                applied.join(", "),
+                e
             ))
         })?;
-
-        match extension.to_lowercase().as_str() {
-            "toml" => Self::from_toml_file(path),
-            "yaml" | "yml" => Self::from_yaml_file(path),
-            "json" => Self::from_json_file(path),
-            _ => Err(KreuzbergError::validation(format!(
-                "Unsupported config file format: .{}. Supported formats: .toml, .yaml, .json",
-                extension
-            ))),
+        #[cfg(feature = "html")]
+        {
+            updated.html_options = self.html_options.take();
         }
+        *self = updated;
+
+        Ok(applied.len())
     }
 
-    /// Discover configuration file in parent directories.
-    ///
-    /// Searches for `kreuzberg.toml` in current directory and parent directories.
+    /// Locate the configuration file that [`discover`](Self::discover) would load.
     ///
     /// # Returns
     ///
-    /// - `Some(config)` if found
+    /// - `Some(path)` to the nearest `kreuzberg.toml`
     /// - `None` if no config file found
-    pub fn discover() -> Result<Option<Self>> {
+    pub fn discover_path() -> Result<Option<PathBuf>> {
         let mut current = std::env::current_dir().map_err(KreuzbergError::Io)?;
 
         loop {
             let kreuzberg_toml = current.join("kreuzberg.toml");
             if kreuzberg_toml.exists() {
-                return Ok(Some(Self::from_toml_file(kreuzberg_toml)?));
+                return Ok(Some(kreuzberg_toml));
             }
 
             if let Some(parent) = current.parent() {
@@ -501,6 +1984,110 @@ impl ExtractionConfig {
     }
 }
 
+/// Prefix of environment variables that override configuration fields.
+const ENV_PREFIX: &str = "KREUZBERG_";
+
+/// Formats of configuration files.
+#[derive(Debug, Clone, Copy)]
+enum ConfigFormat {
+    Toml,
+    Yaml,
+    Json,
+}
+
+impl ConfigFormat {
+    /// Detect the format from the file extension.
+    fn from_path(path: &Path) -> Result<Self> {
+        let extension = path.extension().and_then(|ext| ext.to_str()).ok_or_else(|| {
+            KreuzbergError::validation(format!(
+                "Cannot determine file format: no extension found in {}",
+                path.display()
+            ))
+        })?;
+
+        match extension.to_lowercase().as_str() {
+            "toml" => Ok(Self::Toml),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            "json" => Ok(Self::Json),
+            _ => Err(KreuzbergError::validation(format!(
+                "Unsupported config file format: .{}. Supported formats: .toml, .yaml, .json",
+                extension
+            ))),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Toml => "TOML",
+            Self::Yaml => "YAML",
+            Self::Json => "JSON",
+        }
+    }
+
+    fn parse<T: serde::de::DeserializeOwned>(self, path: &Path, content: &str) -> Result<T> {
+        match self {
+            Self::Toml => toml::from_str(content).map_err(|e| invalid_config(self.name(), path, e)),
+            Self::Yaml => serde_yaml_ng::from_str(content).map_err(|e| invalid_config(self.name(), path, e)),
+            Self::Json => serde_json::from_str(content).map_err(|e| invalid_config(self.name(), path, e)),
+        }
+    }
+}
+
+fn read_config_file(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path)
+        .map_err(|e| KreuzbergError::validation(format!("Failed to read config file {}: {}", path.display(), e)))
+}
+
+fn invalid_config(format: &str, path: &Path, error: impl std::fmt::Display) -> KreuzbergError {
+    KreuzbergError::validation(format!("Invalid {} in {}: {}", format, path.display(), error))
+}
+
+/// Merge a profile `overlay` into `base`: tables are merged key by key, other values replace.
+fn merge_config_values(base: &mut serde_json::Value, overlay: serde_json::Value) {
+    match (base, overlay) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_config_values(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+/// Set the field at `path` below `object` to the parsed `raw` value, creating sections as needed.
+fn set_env_override(object: &mut serde_json::Map<String, serde_json::Value>, path: &[String], raw: &str) {
+    let (field, parents) = path.split_last().expect("override paths are never empty");
+
+    let mut current = object;
+    for segment in parents {
+        let entry = current
+            .entry(segment.clone())
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        if !entry.is_object() {
+            *entry = serde_json::Value::Object(serde_json::Map::new());
+        }
+        current = entry.as_object_mut().expect("entry was just made an object");
+    }
+
+    let value = parse_env_value(raw, current.get(field));
+    current.insert(field.clone(), value);
+}
+
+fn parse_env_value(raw: &str, current: Option<&serde_json::Value>) -> serde_json::Value {
+    if matches!(current, Some(serde_json::Value::String(_))) {
+        return serde_json::Value::String(raw.to_string());
+    }
+    if raw.eq_ignore_ascii_case("true") || raw.eq_ignore_ascii_case("false") {
+        return serde_json::Value::Bool(raw.eq_ignore_ascii_case("true"));
+    }
+    serde_json::from_str(raw).unwrap_or_else(|_| serde_json::Value::String(raw.to_string()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -735,6 +2322,8 @@ use_cache = true
 [token_reduction]
 mode = "aggressive"
 preserve_important_words = true
+target_ratio = 0.25
+max_tokens = 4000
         "#,
         )
         .unwrap();
@@ -744,6 +2333,8 @@ preserve_important_words = true
         let token = config.token_reduction.unwrap();
         assert_eq!(token.mode, "aggressive");
         assert!(token.preserve_important_words);
+        assert_eq!(token.target_ratio, Some(0.25));
+        assert_eq!(token.max_tokens, Some(4000));
     }
 
     #[test]
@@ -760,6 +2351,7 @@ use_cache = true
 enabled = true
 min_confidence = 0.9
 detect_multiple = true
+max_sample_kb = 64
         "#,
         )
         .unwrap();
@@ -770,6 +2362,7 @@ detect_multiple = true
         assert!(lang.enabled);
         assert_eq!(lang.min_confidence, 0.9);
         assert!(lang.detect_multiple);
+        assert_eq!(lang.max_sample_kb, Some(64));
     }
 
     #[test]
@@ -862,6 +2455,8 @@ enabled = true
         let token = config.token_reduction.unwrap();
         assert_eq!(token.mode, "off");
         assert!(token.preserve_important_words);
+        assert!(token.target_ratio.is_none());
+        assert!(token.max_tokens.is_none());
     }
 
     #[test]
@@ -882,6 +2477,7 @@ enabled = true
         assert!(lang.enabled);
         assert_eq!(lang.min_confidence, 0.8);
         assert!(!lang.detect_multiple);
+        assert!(lang.max_sample_kb.is_none());
     }
 
     #[test]
@@ -1056,6 +2652,113 @@ language = "eng"
         assert!(ocr.tesseract_config.is_none());
     }
 
+    #[test]
+    fn test_config_with_extractor_overrides() {
+        let dir = tempdir().unwrap();
+        let config_path = dir.path().join("kreuzberg.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+[extractors.pdf-extractor]
+priority = 80
+
+[extractors.pdf-extractor.options]
+strategy = "fast"
+max_pages = 10
+        "#,
+        )
+        .unwrap();
+
+        let config = ExtractionConfig::from_toml_file(&config_path).unwrap();
+        assert_eq!(config.plugin_priority("pdf-extractor"), Some(80));
+        assert_eq!(config.plugin_priority("docx-extractor"), None);
+
+        let options = config.plugin_options("pdf-extractor").unwrap();
+        assert_eq!(options["strategy"], "fast");
+        assert_eq!(options["max_pages"], 10);
+    }
+
+    #[test]
+    fn test_profile_merges_over_top_level_settings() {
+        let dir = tempdir().unwrap();
+        let config_path = dir.path().join("kreuzberg.toml");
+
+        fs::write(
+            &config_path,
+            r#"
+use_cache = true
+
+[ocr]
+backend = "tesseract"
+language = "eng"
+
+[chunking]
+max_chars = 500
+max_overlap = 50
+
+[profile.fast]
+use_cache = false
+
+[profile.fast.chunking]
+max_chars = 200
+
+[profile.archival.ocr]
+language = "deu"
+        "#,
+        )
+        .unwrap();
+
+        let base = ExtractionConfig::from_toml_file(&config_path).unwrap();
+        assert!(base.use_cache);
+        assert_eq!(base.chunking.as_ref().unwrap().max_chars, 500);
+
+        let fast = ExtractionConfig::from_file_with_profile(&config_path, "fast").unwrap();
+        assert!(!fast.use_cache);
+        assert_eq!(fast.ocr.as_ref().unwrap().language, "eng");
+        let chunking = fast.chunking.unwrap();
+        assert_eq!(chunking.max_chars, 200);
+        assert_eq!(chunking.max_overlap, 50);
+
+        let archival = ExtractionConfig::from_file_with_profile(&config_path, "archival").unwrap();
+        assert!(archival.use_cache);
+        assert_eq!(archival.ocr.unwrap().language, "deu");
+
+        let err = ExtractionConfig::from_file_with_profile(&config_path, "slow").unwrap_err();
+        assert!(err.to_string().contains("available profiles: archival, fast"));
+    }
+
+    #[test]
+    fn test_profile_values_are_validated_in_place() {
+        let dir = tempdir().unwrap();
+        let config_path = dir.path().join("kreuzberg.yaml");
+
+        fs::write(
+            &config_path,
+            "use_cache: true\nprofile:\n  strict:\n    language_detection:\n      min_confidence: 2.0\n",
+        )
+        .unwrap();
+
+        assert!(ExtractionConfig::from_file(&config_path).is_ok());
+        let err = ExtractionConfig::from_file_with_profile(&config_path, "strict").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("profile.strict.language_detection.min_confidence (line 5, column 7)"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_plugin_config_without_priority() {
+        let mut config = ExtractionConfig::default();
+        config
+            .plugin_config
+            .insert("custom".to_string(), serde_json::json!({ "options": { "flag": true } }));
+
+        assert_eq!(config.plugin_priority("custom"), None);
+        assert_eq!(config.plugin_options("custom").unwrap()["flag"], true);
+    }
+
     #[test]
     fn test_tesseract_config_defaults() {
         let tess = crate::types::TesseractConfig::default();
@@ -1077,4 +2780,72 @@ language = "eng"
         assert!(tess.textord_space_size_is_variable);
         assert!(!tess.thresholding_method);
     }
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn test_env_overrides_set_scalar_and_nested_fields() {
+        let mut config = ExtractionConfig::default();
+        let applied = config
+            .apply_overrides(env(&[
+                ("KREUZBERG_USE_CACHE", "false"),
+                ("KREUZBERG_OCR__LANGUAGE", "deu"),
+                ("KREUZBERG_CACHE_DIR", "/tmp/cache"),
+                ("PATH", "/usr/bin"),
+            ]))
+            .unwrap();
+
+        assert_eq!(applied, 2);
+        assert!(!config.use_cache);
+        let ocr = config.ocr.expect("ocr section should be created");
+        assert_eq!(ocr.language, "deu");
+        assert_eq!(ocr.backend, "tesseract");
+    }
+
+    #[test]
+    fn test_env_overrides_layer_over_file_config() {
+        let mut config: ExtractionConfig = toml::from_str(
+            r#"
+            max_concurrent_extractions = 2
+
+            [chunking]
+            max_chars = 2000
+            max_overlap = 100
+            "#,
+        )
+        .unwrap();
+
+        config
+            .apply_overrides(env(&[
+                ("KREUZBERG_MAX_CONCURRENT_EXTRACTIONS", "8"),
+                ("KREUZBERG_CHUNKING__MAX_CHARS", "500"),
+                ("KREUZBERG_POSTPROCESSOR__DISABLED_PROCESSORS", r#"["quality"]"#),
+                ("KREUZBERG_FORCE_OCR", "TRUE"),
+            ]))
+            .unwrap();
+
+        assert_eq!(config.max_concurrent_extractions, Some(8));
+        let chunking = config.chunking.unwrap();
+        assert_eq!(chunking.max_chars, 500);
+        assert_eq!(chunking.max_overlap, 100);
+        assert_eq!(
+            config.postprocessor.unwrap().disabled_processors,
+            Some(vec!["quality".to_string()])
+        );
+        assert!(config.force_ocr);
+    }
+
+    #[test]
+    fn test_env_overrides_reject_invalid_values() {
+        let mut config = ExtractionConfig::default();
+        let err = config
+            .apply_overrides(env(&[("KREUZBERG_FORCE_OCR", "sometimes")]))
+            .unwrap_err();
+
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+        assert!(err.to_string().contains("KREUZBERG_FORCE_OCR"));
+        assert!(config.use_cache);
+    }
 }
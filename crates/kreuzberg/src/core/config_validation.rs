@@ -0,0 +1,869 @@
+//! Schema and range validation of configuration files.
+//!
+//! Deserialization ignores keys it doesn't know, so a typo such as `languge = "deu"` under
+//! `[ocr]` silently falls back to the default. The file loaders of [`ExtractionConfig`] check
+//! the parsed document against [`ExtractionConfig::json_schema`] and the loaded values against
+//! their documented ranges, and report every problem with the line and column of its key.
+//! Named profiles under `[profile.<name>]` are checked against the same schema. Sections of
+//! features missing from this build, such as `[pdf_options]` without `pdf`, are skipped with a
+//! warning so one file can serve builds with different features.
+
+use super::config::ExtractionConfig;
+use crate::{KreuzbergError, Result};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Keys accepted in place of a field name. Serde aliases don't show up in the schema.
+const KEY_ALIASES: &[(&str, &str)] = &[("extractors", "plugin_config")];
+
+/// Top-level table holding named profiles, each a partial configuration.
+pub(crate) const PROFILES_KEY: &str = "profile";
+
+/// Token reduction modes understood by the token reduction processor.
+const REDUCTION_MODES: &[&str] = &["off", "light", "moderate", "aggressive", "maximum"];
+
+/// Output formats understood by the Tesseract backend.
+const TESSERACT_OUTPUT_FORMATS: &[&str] = &["text", "markdown", "hocr", "tsv"];
+
+/// Binarization methods understood by the OCR image preprocessing.
+const BINARIZATION_METHODS: &[&str] = &["otsu", "sauvola", "adaptive", "none"];
+
+/// A problem with a configuration value, identified by the path of its key.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ConfigIssue {
+    pub(crate) path: Vec<String>,
+    pub(crate) message: String,
+}
+
+impl ConfigIssue {
+    fn new(path: &[&str], message: impl Into<String>) -> Self {
+        Self {
+            path: path.iter().map(|segment| segment.to_string()).collect(),
+            message: message.into(),
+        }
+    }
+
+    fn describe(&self, source: Option<&str>) -> String {
+        let path = self.path.join(".");
+        match source.and_then(|source| locate(source, &self.path)) {
+            Some((line, column)) => format!("{} (line {}, column {}): {}", path, line, column, self.message),
+            None => format!("{}: {}", path, self.message),
+        }
+    }
+}
+
+/// Turn `issues` into a single validation error, locating each key in `file`'s source if given.
+pub(crate) fn report(issues: &[ConfigIssue], file: Option<(&Path, &str)>) -> Result<()> {
+    if issues.is_empty() {
+        return Ok(());
+    }
+
+    let source = file.map(|(_, source)| source);
+    let described: Vec<String> = issues.iter().map(|issue| issue.describe(source)).collect();
+    let context = file.map_or_else(String::new, |(path, _)| format!(" in {}", path.display()));
+    Err(KreuzbergError::validation(format!(
+        "Invalid configuration{}: {}",
+        context,
+        described.join("; ")
+    )))
+}
+
+/// Keys of a parsed configuration `document` that the configuration schema doesn't declare.
+pub(crate) fn unknown_keys(document: &Value) -> Vec<ConfigIssue> {
+    let schema = ExtractionConfig::json_schema();
+    let empty = Map::new();
+    let defs = schema.get("$defs").and_then(Value::as_object).unwrap_or(&empty);
+
+    let mut base = document.clone();
+    let profiles = base.as_object_mut().and_then(|root| root.remove(PROFILES_KEY));
+    skip_disabled_sections(&mut base);
+
+    let mut issues = Vec::new();
+    collect_unknown_keys(&base, &schema, defs, &mut Vec::new(), &mut issues);
+
+    match profiles {
+        Some(Value::Object(profiles)) => {
+            for (name, mut profile) in profiles {
+                skip_disabled_sections(&mut profile);
+                let mut path = vec![PROFILES_KEY.to_string(), name.clone()];
+                if !profile.is_object() {
+                    issues.push(ConfigIssue {
+                        path,
+                        message: "a profile must be a table of configuration fields".to_string(),
+                    });
+                } else if profile.get(PROFILES_KEY).is_some() {
+                    path.push(PROFILES_KEY.to_string());
+                    issues.push(ConfigIssue {
+                        path,
+                        message: "profiles cannot be nested".to_string(),
+                    });
+                } else {
+                    collect_unknown_keys(&profile, &schema, defs, &mut path, &mut issues);
+                }
+            }
+        }
+        Some(_) => issues.push(ConfigIssue::new(&[PROFILES_KEY], "must be a table of named profiles")),
+        None => {}
+    }
+
+    issues
+}
+
+/// Remove the top-level sections of `table` that belong to features this build lacks.
+fn skip_disabled_sections(table: &mut Value) {
+    let Some(table) = table.as_object_mut() else {
+        return;
+    };
+    table.retain(|key, _| match disabled_feature(key) {
+        Some(feature) => {
+            tracing::warn!(
+                "Ignoring `{}`: Kreuzberg was built without the `{}` feature",
+                key,
+                feature
+            );
+            false
+        }
+        None => true,
+    });
+}
+
+/// The feature providing top-level section `key`, if this build lacks it.
+fn disabled_feature(key: &str) -> Option<&'static str> {
+    let (enabled, feature) = match key {
+        "pdf_options" => (cfg!(feature = "pdf"), "pdf"),
+        "archives" => (cfg!(feature = "archives"), "archives"),
+        "email" => (cfg!(feature = "email"), "email"),
+        "xml" => (cfg!(feature = "xml"), "xml"),
+        "office" => (cfg!(any(feature = "office", feature = "excel")), "office"),
+        "pptx" | "revisions" => (cfg!(feature = "office"), "office"),
+        "keywords" => (
+            cfg!(any(feature = "keywords-yake", feature = "keywords-rake")),
+            "keywords",
+        ),
+        "entities" => (cfg!(feature = "entities"), "entities"),
+        "templates" => (cfg!(feature = "templates"), "templates"),
+        "html_options" => (cfg!(feature = "html"), "html"),
+        _ => return None,
+    };
+    (!enabled).then_some(feature)
+}
+
+/// Attribute `issues` of the configuration merged from profile `name` to the profile's keys
+/// wherever the profile's `overlay` sets the offending value.
+pub(crate) fn in_profile(issues: Vec<ConfigIssue>, name: &str, overlay: &Value) -> Vec<ConfigIssue> {
+    issues
+        .into_iter()
+        .map(|mut issue| {
+            if issue
+                .path
+                .iter()
+                .try_fold(overlay, |value, key| value.get(key))
+                .is_some()
+            {
+                let mut path = vec![PROFILES_KEY.to_string(), name.to_string()];
+                path.append(&mut issue.path);
+                issue.path = path;
+            }
+            issue
+        })
+        .collect()
+}
+
+fn collect_unknown_keys(
+    value: &Value,
+    schema: &Value,
+    defs: &Map<String, Value>,
+    path: &mut Vec<String>,
+    issues: &mut Vec<ConfigIssue>,
+) {
+    let alternatives = alternatives(schema, defs);
+
+    match value {
+        Value::Object(object) => {
+            let mut properties: HashMap<&str, &Value> = HashMap::new();
+            let mut additional = None;
+            for alternative in &alternatives {
+                if let Some(fields) = alternative.get("properties").and_then(Value::as_object) {
+                    properties.extend(fields.iter().map(|(name, schema)| (name.as_str(), schema)));
+                }
+                if additional.is_none() {
+                    additional = alternative
+                        .get("additionalProperties")
+                        .filter(|schema| schema.as_bool() != Some(false));
+                }
+            }
+            if properties.is_empty() && additional.is_none() {
+                return;
+            }
+
+            for (key, child) in object {
+                let field = KEY_ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == key.as_str())
+                    .map_or(key.as_str(), |&(_, field)| field);
+                let child_schema = properties
+                    .get(key.as_str())
+                    .or_else(|| properties.get(field))
+                    .copied()
+                    .or(additional);
+
+                path.push(key.clone());
+                match child_schema {
+                    Some(child_schema) => collect_unknown_keys(child, child_schema, defs, path, issues),
+                    None => issues.push(ConfigIssue {
+                        path: path.clone(),
+                        message: unknown_key_message(key, properties.keys().copied()),
+                    }),
+                }
+                path.pop();
+            }
+        }
+        Value::Array(items) => {
+            let Some(item_schema) = alternatives.iter().find_map(|alternative| alternative.get("items")) else {
+                return;
+            };
+            for (index, item) in items.iter().enumerate() {
+                path.push(index.to_string());
+                collect_unknown_keys(item, item_schema, defs, path, issues);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The object schemas `schema` stands for: itself plus every `anyOf`/`oneOf`/`allOf` branch,
+/// with `$ref`s resolved against `defs`.
+fn alternatives<'a>(schema: &'a Value, defs: &'a Map<String, Value>) -> Vec<&'a Map<String, Value>> {
+    let Some(object) = schema.as_object() else {
+        return Vec::new();
+    };
+
+    if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+        let name = reference.rsplit('/').next().unwrap_or(reference);
+        return defs
+            .get(name)
+            .map(|target| alternatives(target, defs))
+            .unwrap_or_default();
+    }
+
+    let mut found = vec![object];
+    for keyword in ["anyOf", "oneOf", "allOf"] {
+        if let Some(branches) = object.get(keyword).and_then(Value::as_array) {
+            found.extend(branches.iter().flat_map(|branch| alternatives(branch, defs)));
+        }
+    }
+    found
+}
+
+fn unknown_key_message<'a>(key: &str, known: impl Iterator<Item = &'a str>) -> String {
+    let closest = known
+        .map(|candidate| (edit_distance(key, candidate), candidate))
+        .min()
+        .filter(|(distance, _)| *distance <= (key.chars().count() / 3).max(2));
+
+    match closest {
+        Some((_, suggestion)) => format!("unknown key, did you mean `{}`?", suggestion),
+        None => "unknown key".to_string(),
+    }
+}
+
+/// Levenshtein distance between `a` and `b`, counted in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(diagonal + 1).min(row[j] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Line and column (both 1-based) of the key at `path` in `source`.
+///
+/// Each key is searched after the previous one, which finds TOML sections and dotted keys as
+/// well as nested YAML and JSON objects. Array indices are skipped, so keys inside arrays point
+/// at their first occurrence after the array's key.
+fn locate(source: &str, path: &[String]) -> Option<(usize, usize)> {
+    let mut offset = 0;
+    let mut found = None;
+
+    for key in path.iter().filter(|key| key.parse::<usize>().is_err()) {
+        let position = offset + find_key(&source[offset..], key)?;
+        offset = position + key.len();
+        found = Some(position);
+    }
+
+    let position = found?;
+    let line_start = source[..position].rfind('\n').map_or(0, |newline| newline + 1);
+    let line = source[..position].matches('\n').count() + 1;
+    let column = source[line_start..position].chars().count() + 1;
+    Some((line, column))
+}
+
+/// Byte offset of the first occurrence of `key` that is used as a key: not part of a longer
+/// name, not in a `#` comment, and followed by `=`, `:`, `.` or `]` (after an optional
+/// closing quote).
+fn find_key(haystack: &str, key: &str) -> Option<usize> {
+    let is_name_char = |c: char| c.is_alphanumeric() || c == '_' || c == '-';
+
+    haystack.match_indices(key).map(|(start, _)| start).find(|&start| {
+        let line_start = haystack[..start].rfind('\n').map_or(0, |newline| newline + 1);
+        let after = &haystack[start + key.len()..];
+        let rest = after.trim_start_matches(['"', '\'']).trim_start_matches([' ', '\t']);
+
+        !haystack[line_start..start].contains('#')
+            && !haystack[..start].ends_with(is_name_char)
+            && !after.starts_with(is_name_char)
+            && rest.starts_with(['=', ':', '.', ']'])
+    })
+}
+
+/// Values of `config` outside their documented ranges.
+pub(crate) fn range_issues(config: &ExtractionConfig) -> Vec<ConfigIssue> {
+    let mut issues = Vec::new();
+
+    if config.max_concurrent_extractions == Some(0) {
+        issues.push(ConfigIssue::new(
+            &["max_concurrent_extractions"],
+            "must be greater than 0",
+        ));
+    }
+
+    if let Some(ocr) = &config.ocr
+        && let Some(tesseract) = &ocr.tesseract_config
+    {
+        check_between(&mut issues, &["ocr", "tesseract_config", "psm"], tesseract.psm, 0, 13);
+        check_between(&mut issues, &["ocr", "tesseract_config", "oem"], tesseract.oem, 0, 3);
+        check_between(
+            &mut issues,
+            &["ocr", "tesseract_config", "min_confidence"],
+            tesseract.min_confidence,
+            0.0,
+            100.0,
+        );
+        check_ratio(
+            &mut issues,
+            &["ocr", "tesseract_config", "table_min_confidence"],
+            tesseract.table_min_confidence,
+        );
+        check_ratio(
+            &mut issues,
+            &["ocr", "tesseract_config", "table_row_threshold_ratio"],
+            tesseract.table_row_threshold_ratio,
+        );
+        check_one_of(
+            &mut issues,
+            &["ocr", "tesseract_config", "output_format"],
+            &tesseract.output_format,
+            TESSERACT_OUTPUT_FORMATS,
+        );
+        if let Some(preprocessing) = &tesseract.preprocessing {
+            if preprocessing.target_dpi <= 0 {
+                issues.push(ConfigIssue::new(
+                    &["ocr", "tesseract_config", "preprocessing", "target_dpi"],
+                    "must be greater than 0",
+                ));
+            }
+            check_one_of(
+                &mut issues,
+                &["ocr", "tesseract_config", "preprocessing", "binarization_method"],
+                &preprocessing.binarization_method,
+                BINARIZATION_METHODS,
+            );
+        }
+    }
+
+    if let Some(chunking) = &config.chunking {
+        check_positive(&mut issues, &["chunking", "max_chars"], chunking.max_chars);
+        if chunking.max_chars > 0 && chunking.max_overlap >= chunking.max_chars {
+            issues.push(ConfigIssue::new(
+                &["chunking", "max_overlap"],
+                format!(
+                    "must be less than chunking.max_chars ({}), got {}",
+                    chunking.max_chars, chunking.max_overlap
+                ),
+            ));
+        }
+        if let Some(embedding) = &chunking.embedding {
+            check_positive(
+                &mut issues,
+                &["chunking", "embedding", "batch_size"],
+                embedding.batch_size,
+            );
+        }
+    }
+
+    if let Some(images) = &config.images {
+        for (field, value) in [
+            ("target_dpi", images.target_dpi),
+            ("max_image_dimension", images.max_image_dimension),
+            ("min_dpi", images.min_dpi),
+        ] {
+            if value <= 0 {
+                issues.push(ConfigIssue::new(&["images", field], "must be greater than 0"));
+            }
+        }
+        if images.min_dpi > images.max_dpi {
+            issues.push(ConfigIssue::new(
+                &["images", "max_dpi"],
+                format!(
+                    "must not be less than images.min_dpi ({}), got {}",
+                    images.min_dpi, images.max_dpi
+                ),
+            ));
+        }
+    }
+
+    #[cfg(feature = "archives")]
+    if let Some(archives) = &config.archives {
+        check_positive(&mut issues, &["archives", "max_entry_size"], archives.max_entry_size);
+        check_positive(&mut issues, &["archives", "max_total_size"], archives.max_total_size);
+        check_positive(&mut issues, &["archives", "max_entries"], archives.max_entries);
+        if let Some(members) = &archives.members
+            && let (Some(min), Some(max)) = (members.min_size, members.max_size)
+            && min > max
+        {
+            issues.push(ConfigIssue::new(
+                &["archives", "members", "max_size"],
+                format!("must not be less than archives.members.min_size ({}), got {}", min, max),
+            ));
+        }
+    }
+
+    #[cfg(feature = "email")]
+    if let Some(email) = &config.email {
+        check_positive(
+            &mut issues,
+            &["email", "max_attachment_size"],
+            email.max_attachment_size,
+        );
+    }
+
+    if let Some(retry) = &config.retry {
+        check_positive(&mut issues, &["retry", "max_attempts"], retry.max_attempts);
+        if retry.backoff_multiplier.is_nan() || retry.backoff_multiplier < 1.0 {
+            issues.push(ConfigIssue::new(
+                &["retry", "backoff_multiplier"],
+                format!("must be at least 1.0, got {:?}", retry.backoff_multiplier),
+            ));
+        }
+        if retry.initial_backoff_ms > retry.max_backoff_ms {
+            issues.push(ConfigIssue::new(
+                &["retry", "max_backoff_ms"],
+                format!(
+                    "must not be less than retry.initial_backoff_ms ({}), got {}",
+                    retry.initial_backoff_ms, retry.max_backoff_ms
+                ),
+            ));
+        }
+    }
+
+    if let Some(token_reduction) = &config.token_reduction {
+        check_one_of(
+            &mut issues,
+            &["token_reduction", "mode"],
+            &token_reduction.mode.to_lowercase(),
+            REDUCTION_MODES,
+        );
+        if let Some(target_ratio) = token_reduction.target_ratio {
+            check_ratio(
+                &mut issues,
+                &["token_reduction", "target_ratio"],
+                f64::from(target_ratio),
+            );
+        }
+        if let Some(max_tokens) = token_reduction.max_tokens {
+            check_positive(&mut issues, &["token_reduction", "max_tokens"], max_tokens);
+        }
+    }
+
+    if let Some(language_detection) = &config.language_detection {
+        check_ratio(
+            &mut issues,
+            &["language_detection", "min_confidence"],
+            language_detection.min_confidence,
+        );
+        if let Some(max_sample_kb) = language_detection.max_sample_kb {
+            check_positive(&mut issues, &["language_detection", "max_sample_kb"], max_sample_kb);
+        }
+    }
+
+    #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+    if let Some(keywords) = &config.keywords {
+        check_positive(&mut issues, &["keywords", "max_keywords"], keywords.max_keywords);
+        let (min, max) = keywords.ngram_range;
+        if min == 0 || min > max {
+            issues.push(ConfigIssue::new(
+                &["keywords", "ngram_range"],
+                format!("must be [min, max] with 1 <= min <= max, got [{}, {}]", min, max),
+            ));
+        }
+    }
+
+    #[cfg(feature = "entities")]
+    if let Some(entities) = &config.entities {
+        check_ratio(&mut issues, &["entities", "min_confidence"], entities.min_confidence);
+    }
+
+    #[cfg(feature = "templates")]
+    if let Some(templates) = &config.templates {
+        for (index, template) in templates.templates.iter().enumerate() {
+            if let Err(KreuzbergError::Validation { message, .. }) = crate::templates::check_template(template) {
+                let index = index.to_string();
+                issues.push(ConfigIssue::new(&["templates", "templates", &index], message));
+            }
+        }
+    }
+
+    #[cfg(feature = "xml")]
+    if let Some(xml) = &config.xml {
+        for (index, document_type) in xml.document_types.iter().enumerate() {
+            if let Err(KreuzbergError::Validation { message, .. }) =
+                crate::extraction::xml::check_document_type(document_type)
+            {
+                let index = index.to_string();
+                issues.push(ConfigIssue::new(&["xml", "document_types", &index], message));
+            }
+        }
+    }
+
+    #[cfg(feature = "office")]
+    if let Some(pptx) = &config.pptx
+        && let Some(slide_range) = &pptx.slide_range
+        && let Err(KreuzbergError::Validation { message, .. }) = crate::extraction::pptx::SlideRange::parse(slide_range)
+    {
+        issues.push(ConfigIssue::new(&["pptx", "slide_range"], message));
+    }
+
+    #[cfg(feature = "quality")]
+    if let Some(text_encoding) = &config.text_encoding
+        && crate::utils::ebcdic::EbcdicCodePage::for_label(text_encoding).is_none()
+        && encoding_rs::Encoding::for_label(text_encoding.as_bytes()).is_none()
+    {
+        issues.push(ConfigIssue::new(
+            &["text_encoding"],
+            format!("must be a known encoding label, got \"{}\"", text_encoding),
+        ));
+    }
+
+    if let Some(structured) = &config.structured {
+        if let Some(max_depth) = structured.max_depth {
+            check_positive(&mut issues, &["structured", "max_depth"], max_depth);
+        }
+        if let Some(max_array_items) = structured.max_array_items {
+            check_positive(&mut issues, &["structured", "max_array_items"], max_array_items);
+        }
+    }
+
+    if let Some(quality_gate) = &config.quality_gate {
+        check_ratio(
+            &mut issues,
+            &["quality_gate", "min_dictionary_hit_rate"],
+            quality_gate.min_dictionary_hit_rate,
+        );
+        check_ratio(
+            &mut issues,
+            &["quality_gate", "min_confusion_score"],
+            quality_gate.min_confusion_score,
+        );
+    }
+
+    if let Some(review) = &config.review {
+        if let Some(min_ocr_confidence) = review.min_ocr_confidence {
+            check_ratio(&mut issues, &["review", "min_ocr_confidence"], min_ocr_confidence);
+        }
+        if let Some(min_quality_score) = review.min_quality_score {
+            check_ratio(&mut issues, &["review", "min_quality_score"], min_quality_score);
+        }
+    }
+
+    if let Some(mime_mappings) = &config.mime_mappings
+        && let Err(message) = crate::core::mime::validate_mappings(mime_mappings)
+    {
+        issues.push(ConfigIssue::new(&["mime_mappings"], message));
+    }
+
+    if let Some(dedup) = &config.dedup {
+        check_between(&mut issues, &["dedup", "max_distance"], dedup.max_distance, 0, 64);
+    }
+
+    if let Some(max_vram_mb) = config.device.as_ref().and_then(|device| device.max_vram_mb) {
+        check_positive(&mut issues, &["device", "max_vram_mb"], max_vram_mb);
+    }
+
+    if let Some(header_footer) = config.postprocessor.as_ref().and_then(|p| p.header_footer.as_ref()) {
+        check_positive(
+            &mut issues,
+            &["postprocessor", "header_footer", "lines_to_check"],
+            header_footer.lines_to_check,
+        );
+        check_ratio(
+            &mut issues,
+            &["postprocessor", "header_footer", "min_repeat_ratio"],
+            header_footer.min_repeat_ratio,
+        );
+    }
+
+    issues
+}
+
+fn check_ratio(issues: &mut Vec<ConfigIssue>, path: &[&str], value: f64) {
+    check_between(issues, path, value, 0.0, 1.0);
+}
+
+fn check_between<T: PartialOrd + Copy + std::fmt::Debug>(
+    issues: &mut Vec<ConfigIssue>,
+    path: &[&str],
+    value: T,
+    min: T,
+    max: T,
+) {
+    if !(min..=max).contains(&value) {
+        issues.push(ConfigIssue::new(
+            path,
+            format!("must be between {:?} and {:?}, got {:?}", min, max, value),
+        ));
+    }
+}
+
+fn check_positive<T: Default + PartialOrd>(issues: &mut Vec<ConfigIssue>, path: &[&str], value: T) {
+    if value <= T::default() {
+        issues.push(ConfigIssue::new(path, "must be greater than 0"));
+    }
+}
+
+fn check_one_of(issues: &mut Vec<ConfigIssue>, path: &[&str], value: &str, allowed: &[&str]) {
+    if !allowed.contains(&value) {
+        issues.push(ConfigIssue::new(
+            path,
+            format!("must be one of {}, got \"{}\"", allowed.join(", "), value),
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{ChunkingConfig, LanguageDetectionConfig, OcrConfig, RetryConfig};
+    use crate::types::{ImagePreprocessingConfig, TesseractConfig};
+    use serde_json::json;
+
+    fn paths(issues: &[ConfigIssue]) -> Vec<String> {
+        issues.iter().map(|issue| issue.path.join(".")).collect()
+    }
+
+    #[test]
+    fn test_unknown_keys_at_any_depth() {
+        let document = json!({
+            "use_cache": true,
+            "use_cahce": false,
+            "ocr": {"languge": "deu", "tesseract_config": {"psm": 6, "colour": true}},
+            "chunking": {"max_chars": 500, "embedding": {"model": {"type": "preset", "name": "fast", "size": 1}}},
+        });
+
+        let issues = unknown_keys(&document);
+        let message = |path: &str| {
+            let issue = issues.iter().find(|issue| issue.path.join(".") == path);
+            issue.map(|issue| issue.message.as_str())
+        };
+
+        let mut found = paths(&issues);
+        found.sort();
+        assert_eq!(
+            found,
+            vec![
+                "chunking.embedding.model.size",
+                "ocr.languge",
+                "ocr.tesseract_config.colour",
+                "use_cahce"
+            ]
+        );
+        assert_eq!(message("ocr.languge"), Some("unknown key, did you mean `language`?"));
+        assert_eq!(message("ocr.tesseract_config.colour"), Some("unknown key"));
+    }
+
+    #[test]
+    fn test_maps_and_aliases_accept_any_key() {
+        let document = json!({
+            "extractors": {"my-plugin": {"priority": 80, "options": {"anything": [1, 2]}}},
+            "postprocessor": {"taggers": {"ticket": "[A-Z]+-\\d+", "product": ["Widget"]}},
+        });
+
+        assert!(unknown_keys(&document).is_empty());
+    }
+
+    #[test]
+    fn test_sections_of_missing_features_are_skipped() {
+        let document = json!({
+            "pdf_options": {"extract_imgs": true},
+            "profile": {"scans": {"pdf_options": {"extract_imgs": false}}},
+        });
+
+        let issues = unknown_keys(&document);
+
+        if cfg!(feature = "pdf") {
+            assert_eq!(
+                paths(&issues),
+                vec!["pdf_options.extract_imgs", "profile.scans.pdf_options.extract_imgs"]
+            );
+        } else {
+            assert!(issues.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_profiles_are_checked_like_the_top_level() {
+        let document = json!({
+            "use_cache": true,
+            "profile": {
+                "fast": {"use_cache": false, "ocr": {"languge": "deu"}},
+                "nested": {"profile": {"inner": {}}},
+                "broken": 3,
+            },
+        });
+
+        let mut found = paths(&unknown_keys(&document));
+        found.sort();
+        assert_eq!(
+            found,
+            vec!["profile.broken", "profile.fast.ocr.languge", "profile.nested.profile"]
+        );
+
+        let overlay = json!({"chunking": {"max_chars": 10}});
+        let issues = vec![
+            ConfigIssue::new(&["chunking", "max_chars"], "too small"),
+            ConfigIssue::new(&["chunking", "max_overlap"], "too large"),
+        ];
+        assert_eq!(
+            paths(&in_profile(issues, "fast", &overlay)),
+            vec!["profile.fast.chunking.max_chars", "chunking.max_overlap"]
+        );
+    }
+
+    #[test]
+    fn test_locate_keys_in_toml_yaml_and_json() {
+        let path = |keys: &[&str]| keys.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+        let toml = "use_cache = true\n\n[ocr]\n# languge is misspelled\nlanguge = \"deu\"\n";
+        let yaml = "ocr:\n  backend: tesseract\n  languge: deu\n";
+        let json = "{\n  \"ocr\": {\n    \"languge\": \"deu\"\n  }\n}";
+
+        assert_eq!(locate(toml, &path(&["ocr", "languge"])), Some((5, 1)));
+        assert_eq!(locate(yaml, &path(&["ocr", "languge"])), Some((3, 3)));
+        assert_eq!(locate(json, &path(&["ocr", "languge"])), Some((3, 6)));
+        assert_eq!(locate(toml, &path(&["chunking"])), None);
+    }
+
+    #[test]
+    fn test_range_issues() {
+        let config = ExtractionConfig {
+            chunking: Some(ChunkingConfig {
+                max_chars: 500,
+                max_overlap: 500,
+                embedding: None,
+                preset: None,
+            }),
+            language_detection: Some(LanguageDetectionConfig {
+                enabled: true,
+                min_confidence: 1.5,
+                detect_multiple: false,
+                max_sample_kb: None,
+            }),
+            ocr: Some(OcrConfig {
+                backend: "tesseract".to_string(),
+                language: "eng".to_string(),
+                tesseract_config: None,
+            }),
+            max_concurrent_extractions: Some(0),
+            ..Default::default()
+        };
+
+        let issues = range_issues(&config);
+
+        assert_eq!(
+            paths(&issues),
+            vec![
+                "max_concurrent_extractions",
+                "chunking.max_overlap",
+                "language_detection.min_confidence"
+            ]
+        );
+        assert_eq!(issues[2].message, "must be between 0.0 and 1.0, got 1.5");
+        assert!(range_issues(&ExtractionConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_retry_range_issues() {
+        let config = ExtractionConfig {
+            retry: Some(RetryConfig {
+                max_attempts: 0,
+                backoff_multiplier: 0.5,
+                initial_backoff_ms: 2_000,
+                max_backoff_ms: 1_000,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            paths(&range_issues(&config)),
+            vec!["retry.max_attempts", "retry.backoff_multiplier", "retry.max_backoff_ms"]
+        );
+    }
+
+    #[test]
+    fn test_preprocessing_range_issues() {
+        let config = ExtractionConfig {
+            ocr: Some(OcrConfig {
+                backend: "tesseract".to_string(),
+                language: "eng".to_string(),
+                tesseract_config: Some(TesseractConfig {
+                    preprocessing: Some(ImagePreprocessingConfig {
+                        target_dpi: 0,
+                        binarization_method: "niblack".to_string(),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+            }),
+            ..Default::default()
+        };
+
+        let issues = range_issues(&config);
+
+        assert_eq!(
+            paths(&issues),
+            vec![
+                "ocr.tesseract_config.preprocessing.target_dpi",
+                "ocr.tesseract_config.preprocessing.binarization_method"
+            ]
+        );
+        assert_eq!(
+            issues[1].message,
+            "must be one of otsu, sauvola, adaptive, none, got \"niblack\""
+        );
+    }
+
+    #[test]
+    fn test_report_includes_file_and_location() {
+        let source = "[language_detection]\nmin_confidence = 1.5\n";
+        let issue = ConfigIssue::new(&["language_detection", "min_confidence"], "must be between 0.0 and 1.0");
+
+        let err = report(&[issue], Some((Path::new("kreuzberg.toml"), source))).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "Validation error: Invalid configuration in kreuzberg.toml: language_detection.min_confidence \
+             (line 2, column 1): must be between 0.0 and 1.0"
+        );
+        assert!(report(&[], None).is_ok());
+    }
+}
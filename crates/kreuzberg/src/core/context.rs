@@ -0,0 +1,55 @@
+//! Runtime context an extraction hands to the extractions it starts.
+//!
+//! Extractors that extract nested documents and plugins calling the extraction functions
+//! receive the [`ExtractionConfig`](crate::ExtractionConfig) of their parent. The context
+//! travels in that configuration, so it reaches nested extractions wherever they run.
+
+#[cfg(feature = "tokio-runtime")]
+use crate::core::governor::GovernorPermit;
+use crate::plugins::registry::PluginRegistries;
+use std::fmt;
+#[cfg(feature = "tokio-runtime")]
+use std::sync::Arc;
+
+/// State of the extraction a configuration is used for.
+///
+/// Set by the extraction functions and never serialized; leave it at its default.
+#[derive(Clone, Default)]
+pub struct ExtractionContext {
+    /// Concurrency permit of the running extraction, reused by nested extractions
+    #[cfg(feature = "tokio-runtime")]
+    pub(crate) permit: Option<Arc<GovernorPermit<'static>>>,
+    /// Plugin registries to extract with, the global registries if unset
+    pub(crate) registries: Option<PluginRegistries>,
+}
+
+impl ExtractionContext {
+    /// Whether the extraction runs under a permit of the concurrency governor.
+    pub(crate) fn holds_permit(&self) -> bool {
+        #[cfg(feature = "tokio-runtime")]
+        {
+            self.permit.is_some()
+        }
+        #[cfg(not(feature = "tokio-runtime"))]
+        {
+            false
+        }
+    }
+
+    /// Plugin registries the extraction resolves its plugins from.
+    pub(crate) fn registries(&self) -> PluginRegistries {
+        self.registries.clone().unwrap_or_else(PluginRegistries::global)
+    }
+}
+
+impl fmt::Debug for ExtractionContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtractionContext")
+            .field("holds_permit", &self.holds_permit())
+            .field(
+                "global_registries",
+                &self.registries.as_ref().is_none_or(|r| r.is_global()),
+            )
+            .finish()
+    }
+}
@@ -0,0 +1,357 @@
+//! Dataset files of extraction results.
+//!
+//! [`DatasetWriter`] appends results to a single JSON Lines or Parquet file, so a corpus
+//! can be fed to training or analytics jobs without handling one JSON file per document.
+//! Every row has the same columns:
+//!
+//! | Column | Type | Content |
+//! |--------|------|---------|
+//! | `source` | string, nullable | Path or other identifier of the input |
+//! | `mime_type` | string | Detected MIME type |
+//! | `content` | string | Extracted text |
+//! | `metadata` | JSON | [`Metadata`](crate::types::Metadata) |
+//! | `tables` | JSON array | [`Table`](crate::types::Table)s |
+//! | `chunks` | JSON array | [`Chunk`](crate::types::Chunk)s (empty without chunking) |
+//!
+//! In JSON Lines the JSON columns are nested values. Parquet stores them as JSON strings,
+//! which keeps the file schema identical for every document format.
+//!
+//! Parquet output requires the `dataset-parquet` feature.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::{DatasetFormat, DatasetWriter, ExtractionConfig, extract_file_sync};
+//!
+//! # fn example() -> kreuzberg::Result<()> {
+//! let config = ExtractionConfig::default();
+//! let mut writer = DatasetWriter::create("corpus.jsonl", DatasetFormat::Jsonl)?;
+//! for path in ["a.pdf", "b.docx"] {
+//!     let result = extract_file_sync(path, None, &config)?;
+//!     writer.write(Some(path), &result)?;
+//! }
+//! writer.finish()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+#[cfg(feature = "dataset-parquet")]
+use arrow_array::{RecordBatch, StringArray};
+#[cfg(feature = "dataset-parquet")]
+use arrow_schema::{DataType, Field, Schema, SchemaRef};
+#[cfg(feature = "dataset-parquet")]
+use parquet::arrow::ArrowWriter;
+#[cfg(feature = "dataset-parquet")]
+use std::sync::Arc;
+
+/// Rows buffered before a Parquet row group is written.
+#[cfg(feature = "dataset-parquet")]
+const PARQUET_ROW_GROUP_SIZE: usize = 256;
+
+/// File format of a dataset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DatasetFormat {
+    /// One JSON object per line
+    Jsonl,
+    /// Apache Parquet (requires the `dataset-parquet` feature)
+    Parquet,
+}
+
+impl DatasetFormat {
+    /// Format implied by the file extension (`.jsonl`, `.ndjson` or `.parquet`).
+    pub fn from_path(path: impl AsRef<Path>) -> Option<Self> {
+        let extension = path.as_ref().extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "jsonl" | "ndjson" => Some(Self::Jsonl),
+            "parquet" => Some(Self::Parquet),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for DatasetFormat {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "jsonl" | "ndjson" => Ok(Self::Jsonl),
+            "parquet" => Ok(Self::Parquet),
+            _ => Err(KreuzbergError::validation(format!(
+                "Invalid dataset format '{}'. Use 'jsonl' or 'parquet'",
+                s
+            ))),
+        }
+    }
+}
+
+/// Row layout shared by both formats.
+#[derive(Serialize)]
+struct Row<'a> {
+    source: Option<&'a str>,
+    mime_type: &'a str,
+    content: &'a str,
+    metadata: &'a crate::types::Metadata,
+    tables: &'a [crate::types::Table],
+    chunks: &'a [crate::types::Chunk],
+}
+
+impl<'a> Row<'a> {
+    fn new(source: Option<&'a str>, result: &'a ExtractionResult) -> Self {
+        Self {
+            source,
+            mime_type: &result.mime_type,
+            content: &result.content,
+            metadata: &result.metadata,
+            tables: &result.tables,
+            chunks: result.chunks.as_deref().unwrap_or_default(),
+        }
+    }
+}
+
+enum Sink<W: Write + Send> {
+    Jsonl(W),
+    #[cfg(feature = "dataset-parquet")]
+    Parquet {
+        writer: ArrowWriter<W>,
+        pending: Vec<[Option<String>; 6]>,
+    },
+}
+
+/// Appends extraction results to a JSON Lines or Parquet dataset.
+///
+/// Call [`DatasetWriter::finish`] when done: it flushes buffered rows and, for Parquet,
+/// writes the file footer. A Parquet file that was not finished cannot be read.
+pub struct DatasetWriter<W: Write + Send> {
+    sink: Sink<W>,
+    rows: usize,
+}
+
+impl DatasetWriter<BufWriter<File>> {
+    /// Create (or truncate) the dataset file at `path`.
+    pub fn create(path: impl AsRef<Path>, format: DatasetFormat) -> Result<Self> {
+        let file = File::create(path.as_ref())?;
+        Self::new(BufWriter::new(file), format)
+    }
+}
+
+impl<W: Write + Send> DatasetWriter<W> {
+    /// Write a dataset to `writer`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::MissingDependency` for Parquet without the `dataset-parquet` feature.
+    pub fn new(writer: W, format: DatasetFormat) -> Result<Self> {
+        let sink = match format {
+            DatasetFormat::Jsonl => Sink::Jsonl(writer),
+            #[cfg(feature = "dataset-parquet")]
+            DatasetFormat::Parquet => {
+                let properties = parquet::file::properties::WriterProperties::builder()
+                    .set_compression(parquet::basic::Compression::SNAPPY)
+                    .set_max_row_group_size(PARQUET_ROW_GROUP_SIZE)
+                    .build();
+                Sink::Parquet {
+                    writer: ArrowWriter::try_new(writer, parquet_schema(), Some(properties))
+                        .map_err(|e| KreuzbergError::serialization_with_source("Failed to start Parquet file", e))?,
+                    pending: Vec::new(),
+                }
+            }
+            #[cfg(not(feature = "dataset-parquet"))]
+            DatasetFormat::Parquet => {
+                return Err(KreuzbergError::MissingDependency(
+                    "Parquet datasets require the `dataset-parquet` feature".to_string(),
+                ));
+            }
+        };
+        Ok(Self { sink, rows: 0 })
+    }
+
+    /// Append one result. `source` is stored in the `source` column.
+    pub fn write(&mut self, source: Option<&str>, result: &ExtractionResult) -> Result<()> {
+        let row = Row::new(source, result);
+        match &mut self.sink {
+            Sink::Jsonl(writer) => {
+                serde_json::to_writer(&mut *writer, &row)?;
+                writer.write_all(b"\n")?;
+            }
+            #[cfg(feature = "dataset-parquet")]
+            Sink::Parquet { writer, pending } => {
+                pending.push([
+                    row.source.map(str::to_string),
+                    Some(row.mime_type.to_string()),
+                    Some(row.content.to_string()),
+                    Some(serde_json::to_string(row.metadata)?),
+                    Some(serde_json::to_string(row.tables)?),
+                    Some(serde_json::to_string(row.chunks)?),
+                ]);
+                if pending.len() >= PARQUET_ROW_GROUP_SIZE {
+                    write_row_group(writer, pending)?;
+                }
+            }
+        }
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Number of rows written so far.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Flush remaining rows, finalize the file and return the underlying writer.
+    pub fn finish(self) -> Result<W> {
+        match self.sink {
+            Sink::Jsonl(mut writer) => {
+                writer.flush()?;
+                Ok(writer)
+            }
+            #[cfg(feature = "dataset-parquet")]
+            Sink::Parquet {
+                mut writer,
+                mut pending,
+            } => {
+                write_row_group(&mut writer, &mut pending)?;
+                writer
+                    .into_inner()
+                    .map_err(|e| KreuzbergError::serialization_with_source("Failed to finish Parquet file", e))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "dataset-parquet")]
+fn parquet_schema() -> SchemaRef {
+    Arc::new(Schema::new(vec![
+        Field::new("source", DataType::Utf8, true),
+        Field::new("mime_type", DataType::Utf8, false),
+        Field::new("content", DataType::Utf8, false),
+        Field::new("metadata", DataType::Utf8, false),
+        Field::new("tables", DataType::Utf8, false),
+        Field::new("chunks", DataType::Utf8, false),
+    ]))
+}
+
+#[cfg(feature = "dataset-parquet")]
+fn write_row_group<W: Write + Send>(writer: &mut ArrowWriter<W>, pending: &mut Vec<[Option<String>; 6]>) -> Result<()> {
+    if pending.is_empty() {
+        return Ok(());
+    }
+    let columns = (0..6)
+        .map(|column| {
+            let values: StringArray = pending.iter_mut().map(|row| row[column].take()).collect();
+            Arc::new(values) as arrow_array::ArrayRef
+        })
+        .collect();
+    pending.clear();
+
+    let batch = RecordBatch::try_new(parquet_schema(), columns)
+        .map_err(|e| KreuzbergError::serialization_with_source("Failed to build Parquet row group", e))?;
+    writer
+        .write(&batch)
+        .map_err(|e| KreuzbergError::serialization_with_source("Failed to write Parquet row group", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Chunk, ChunkMetadata};
+
+    fn result(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Default::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_jsonl_rows_have_stable_columns() {
+        let mut chunked = result("second");
+        chunked.chunks = Some(vec![Chunk {
+            content: "second".to_string(),
+            embedding: None,
+            metadata: ChunkMetadata {
+                byte_start: 0,
+                byte_end: 6,
+                token_count: None,
+                chunk_index: 0,
+                total_chunks: 1,
+                first_page: None,
+                last_page: None,
+            },
+        }]);
+
+        let mut writer = DatasetWriter::new(Vec::new(), DatasetFormat::Jsonl).unwrap();
+        writer.write(Some("a.txt"), &result("first")).unwrap();
+        writer.write(None, &chunked).unwrap();
+        assert_eq!(writer.rows(), 2);
+        let output = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+        let rows: Vec<serde_json::Value> = output.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(rows.len(), 2);
+        for row in &rows {
+            let keys: Vec<&str> = row.as_object().unwrap().keys().map(String::as_str).collect();
+            assert_eq!(keys.len(), 6, "{:?}", keys);
+        }
+        assert_eq!(rows[0]["source"], "a.txt");
+        assert_eq!(rows[0]["chunks"], serde_json::json!([]));
+        assert!(rows[1]["source"].is_null());
+        assert_eq!(rows[1]["chunks"][0]["content"], "second");
+    }
+
+    #[test]
+    fn test_format_from_str_and_path() {
+        assert_eq!("JSONL".parse::<DatasetFormat>().unwrap(), DatasetFormat::Jsonl);
+        assert_eq!("parquet".parse::<DatasetFormat>().unwrap(), DatasetFormat::Parquet);
+        assert!("csv".parse::<DatasetFormat>().is_err());
+        assert_eq!(
+            DatasetFormat::from_path("out/corpus.ndjson"),
+            Some(DatasetFormat::Jsonl)
+        );
+        assert_eq!(DatasetFormat::from_path("corpus.parquet"), Some(DatasetFormat::Parquet));
+        assert_eq!(DatasetFormat::from_path("corpus.json"), None);
+    }
+
+    #[cfg(feature = "dataset-parquet")]
+    #[test]
+    fn test_parquet_round_trip() {
+        use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("corpus.parquet");
+        let mut writer = DatasetWriter::create(&path, DatasetFormat::Parquet).unwrap();
+        for i in 0..(PARQUET_ROW_GROUP_SIZE + 3) {
+            writer
+                .write(Some(format!("{}.txt", i).as_str()), &result("text"))
+                .unwrap();
+        }
+        writer.finish().unwrap();
+
+        let reader = ParquetRecordBatchReaderBuilder::try_new(File::open(&path).unwrap())
+            .unwrap()
+            .build()
+            .unwrap();
+        let batches: Vec<RecordBatch> = reader.map(|batch| batch.unwrap()).collect();
+        let rows: usize = batches.iter().map(RecordBatch::num_rows).sum();
+        assert_eq!(rows, PARQUET_ROW_GROUP_SIZE + 3);
+        assert_eq!(batches[0].schema().fields(), parquet_schema().fields());
+    }
+}
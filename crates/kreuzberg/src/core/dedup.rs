@@ -0,0 +1,156 @@
+//! Near-duplicate detection across batch and directory results.
+//!
+//! The content of each result is reduced to a 64-bit simhash over overlapping word triples:
+//! every triple votes on each fingerprint bit through its hash, so documents that share most of
+//! their text end up with fingerprints a few bits apart while unrelated documents differ in about
+//! half of the bits. [`Deduplicator`] keeps the first result of each group as the original and
+//! points later ones at it through `metadata.duplicate_of`. Failed results and texts shorter than
+//! `DedupConfig::min_words` are never grouped.
+
+use crate::cache::fast_hash;
+use crate::core::config::DedupConfig;
+use crate::types::ExtractionResult;
+
+/// Words per shingle.
+const SHINGLE_WORDS: usize = 3;
+
+/// Groups results as they arrive; the first result of a group is its original.
+pub(crate) struct Deduplicator {
+    config: DedupConfig,
+    originals: Vec<(u64, String)>,
+}
+
+impl Deduplicator {
+    pub(crate) fn new(config: DedupConfig) -> Self {
+        Self {
+            config,
+            originals: Vec::new(),
+        }
+    }
+
+    /// Set `metadata.duplicate_of` when `result` nearly duplicates an earlier result, otherwise
+    /// remember it as the original of a new group under `id`.
+    pub(crate) fn check(&mut self, result: &mut ExtractionResult, id: &str) {
+        if result.metadata.error.is_some() {
+            return;
+        }
+        let Some(fingerprint) = simhash(&result.content, self.config.min_words) else {
+            return;
+        };
+
+        let max_distance = self.config.max_distance;
+        let original = self
+            .originals
+            .iter()
+            .find(|(seen, _)| (seen ^ fingerprint).count_ones() <= max_distance);
+        match original {
+            Some((_, original_id)) => result.metadata.duplicate_of = Some(original_id.clone()),
+            None => self.originals.push((fingerprint, id.to_string())),
+        }
+    }
+}
+
+/// Mark the near-duplicates of a batch in input order; `ids` names each result.
+pub(crate) fn mark_duplicates(results: &mut [ExtractionResult], ids: &[String], config: &DedupConfig) {
+    let mut deduplicator = Deduplicator::new(config.clone());
+    for (result, id) in results.iter_mut().zip(ids) {
+        deduplicator.check(result, id);
+    }
+}
+
+/// Simhash fingerprint of `text`, or `None` when it has fewer than `min_words` words.
+///
+/// Words are compared case-insensitively and punctuation is ignored, so OCR output and a
+/// re-exported original of the same document produce nearby fingerprints.
+pub(crate) fn simhash(text: &str, min_words: usize) -> Option<u64> {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    if words.is_empty() || words.len() < min_words {
+        return None;
+    }
+
+    let mut votes = [0i64; 64];
+    for shingle in words.windows(SHINGLE_WORDS.min(words.len())) {
+        let hash = fast_hash(shingle.join(" ").as_bytes());
+        for (bit, vote) in votes.iter_mut().enumerate() {
+            if (hash >> bit) & 1 == 1 {
+                *vote += 1;
+            } else {
+                *vote -= 1;
+            }
+        }
+    }
+
+    let mut fingerprint = 0u64;
+    for (bit, vote) in votes.iter().enumerate() {
+        if *vote > 0 {
+            fingerprint |= 1 << bit;
+        }
+    }
+    Some(fingerprint)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ErrorMetadata, Metadata};
+
+    const CONTRACT: &str = "This service agreement is entered into between the supplier and the customer. \
+        The supplier shall deliver the goods described in the annex within thirty days of the order. \
+        Payment is due within fourteen days of delivery. Either party may terminate this agreement \
+        with three months notice in writing.";
+
+    fn result(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_simhash_ignores_case_and_layout() {
+        let reformatted = CONTRACT.to_uppercase().replace(". ", ".\n\n");
+        let other = "The quick brown fox jumps over the lazy dog again. ".repeat(5);
+        let original = simhash(CONTRACT, 20).unwrap();
+
+        assert_eq!(simhash(&reformatted, 20), Some(original));
+        assert!((original ^ simhash(&other, 20).unwrap()).count_ones() > 10);
+        assert_eq!(simhash("Too short", 20), None);
+    }
+
+    #[test]
+    fn test_mark_duplicates() {
+        let mut failed = result(CONTRACT);
+        failed.metadata.error = Some(ErrorMetadata {
+            error_type: "Parsing".to_string(),
+            message: "broken".to_string(),
+        });
+        let weather = "Rain is expected in the mountains on Monday and snow later in the week. ".repeat(2);
+        let mut results = vec![
+            failed,
+            result(CONTRACT),
+            result(&weather),
+            result(&format!("{}\n", CONTRACT)),
+        ];
+        let ids: Vec<String> = ["a.pdf", "b.pdf", "c.pdf", "d.pdf"].map(String::from).to_vec();
+
+        mark_duplicates(&mut results, &ids, &DedupConfig::default());
+
+        let duplicate_of: Vec<_> = results.iter().map(|r| r.metadata.duplicate_of.as_deref()).collect();
+        assert_eq!(duplicate_of, vec![None, None, None, Some("b.pdf")]);
+    }
+}
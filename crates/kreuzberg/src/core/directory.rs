@@ -0,0 +1,844 @@
+//! Directory and corpus extraction.
+//!
+//! [`extract_directory`] walks a directory tree, extracts every file matching the include and
+//! exclude globs concurrently, and yields results through a [`DirectoryStream`] as they
+//! complete. With `resume` set, results are stored in the cache keyed by content hash and
+//! configuration, so a re-run after an interruption only extracts files that changed or were
+//! not reached. Once every file is processed, a [`DirectoryManifest`] mapping each file to its
+//! content hash and output path can be written.
+//!
+//! With `incremental` set, the manifest of the previous run is compared against the current
+//! content hashes: unchanged files are skipped without being read by an extractor, new and
+//! modified files are extracted, and files that disappeared are marked as deleted in the new
+//! manifest. A nightly refresh of a large corpus then costs as much as the files that changed.
+//!
+//! With `config.dedup` set, every result is compared with the files processed before it, and
+//! near-duplicates get `metadata.duplicate_of` set to the relative path of the first of them,
+//! which the manifest records as well. Incremental runs only compare the files they extract.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::{DirectoryOptions, extract_directory};
+//!
+//! # async fn example() -> kreuzberg::Result<()> {
+//! let options = DirectoryOptions {
+//!     include: vec!["*.pdf".to_string(), "*.docx".to_string()],
+//!     exclude: vec!["archive".to_string()],
+//!     resume: true,
+//!     manifest_path: Some("manifest.json".into()),
+//!     ..Default::default()
+//! };
+//!
+//! let mut stream = extract_directory("corpus", options)?;
+//! while let Some(item) = stream.recv().await {
+//!     let item = item?;
+//!     match &item.result {
+//!         Ok(result) => println!("{}: {} chars", item.relative_path, result.content.len()),
+//!         Err(e) => eprintln!("{}: {}", item.relative_path, e),
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::cache::{GenericCache, stable_hash, stable_hash_reader};
+use crate::core::batch_mode::with_batch_mode;
+use crate::core::config::ExtractionConfig;
+use crate::core::dedup::Deduplicator;
+use crate::core::extractor::{GLOBAL_RUNTIME, extract_file};
+use crate::core::io::{ResultFileFormat, write_result};
+use crate::types::ExtractionResult;
+use crate::utils::glob::{glob_match, validate_globs};
+use crate::{ErrorInfo, KreuzbergError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+
+/// Cache type (subdirectory of the cache directory) holding results of resumable runs.
+const CACHE_TYPE: &str = "directory";
+const CACHE_MAX_AGE_DAYS: f64 = 30.0;
+const CACHE_MAX_SIZE_MB: f64 = 2048.0;
+const CACHE_MIN_FREE_SPACE_MB: f64 = 1000.0;
+
+/// Completed items buffered before the walk waits for the consumer.
+const CHANNEL_CAPACITY: usize = 64;
+
+/// Options for [`extract_directory`].
+#[derive(Debug, Clone)]
+pub struct DirectoryOptions {
+    /// Extraction configuration applied to every file
+    pub config: ExtractionConfig,
+
+    /// Globs a file must match to be extracted (empty = every file)
+    ///
+    /// Patterns are matched against the `/`-separated path relative to the root and support
+    /// `*`, `?`, `[...]` and `**`. A pattern without `/` matches the file name at any depth.
+    pub include: Vec<String>,
+
+    /// Globs of files and directories to skip, with the same syntax as `include`
+    pub exclude: Vec<String>,
+
+    /// Descend into subdirectories (symlinked directories are not followed)
+    pub recursive: bool,
+
+    /// Files extracted at once (None = `config.max_concurrent_extractions`, or `num_cpus * 2`)
+    pub max_concurrency: Option<usize>,
+
+    /// Reuse cached results of earlier runs with the same content and configuration
+    pub resume: bool,
+
+    /// Cache directory for resumable runs (None = `.kreuzberg` in the current directory)
+    pub cache_dir: Option<PathBuf>,
+
+    /// Write each successful result to `<output_dir>/<relative path>.<extension>`
+    pub output_dir: Option<PathBuf>,
+
+    /// Format of the files written to `output_dir` (see [`write_result`])
+    pub output_format: ResultFileFormat,
+
+    /// Write a [`DirectoryManifest`] as JSON to this path once every file is processed
+    pub manifest_path: Option<PathBuf>,
+
+    /// Skip files whose content hash matches the previous manifest at `manifest_path`
+    ///
+    /// Only new and modified files (and files that previously failed or lost their output) are
+    /// extracted and yielded. Unchanged and deleted files are recorded in the new manifest.
+    /// A configuration change re-extracts every file.
+    pub incremental: bool,
+}
+
+impl Default for DirectoryOptions {
+    fn default() -> Self {
+        Self {
+            config: ExtractionConfig::default(),
+            include: Vec::new(),
+            exclude: Vec::new(),
+            recursive: true,
+            max_concurrency: None,
+            resume: false,
+            cache_dir: None,
+            output_dir: None,
+            output_format: ResultFileFormat::default(),
+            manifest_path: None,
+            incremental: false,
+        }
+    }
+}
+
+/// One processed file of a directory extraction.
+#[derive(Debug)]
+pub struct DirectoryItem {
+    /// Path of the file
+    pub path: PathBuf,
+    /// `/`-separated path relative to the extracted directory
+    pub relative_path: String,
+    /// Hex hash of the file content (None when the file could not be read)
+    pub content_hash: Option<String>,
+    /// Where the result was written (with `output_dir`)
+    pub output_path: Option<PathBuf>,
+    /// The result was reused from an earlier run instead of extracted
+    pub cached: bool,
+    /// How the file changed since the previous manifest (incremental runs only)
+    pub change: Option<FileChange>,
+    /// Extraction result, or the error that file failed with
+    pub result: Result<ExtractionResult>,
+}
+
+/// Change of a file since the previous run of an incremental extraction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileChange {
+    /// Not in the previous manifest
+    Added,
+    /// Content hash differs from the previous manifest
+    Modified,
+    /// Content hash matches the previous manifest
+    Unchanged,
+    /// In the previous manifest but no longer on disk
+    Deleted,
+}
+
+/// Record of a directory extraction: file → content hash → output path.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DirectoryManifest {
+    /// The extracted directory
+    pub root: PathBuf,
+    /// Hash of the extraction configuration the results were produced with
+    #[serde(default)]
+    pub config_hash: Option<String>,
+    /// One entry per processed file, sorted by path
+    pub entries: Vec<ManifestEntry>,
+}
+
+/// A file in a [`DirectoryManifest`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// `/`-separated path relative to the extracted directory
+    pub path: String,
+    /// Hex hash of the file content
+    pub content_hash: Option<String>,
+    /// Detected MIME type (None when extraction failed)
+    pub mime_type: Option<String>,
+    /// Where the result was written (with `output_dir`)
+    pub output_path: Option<PathBuf>,
+    /// The result was reused from an earlier run
+    pub cached: bool,
+    /// How the file changed since the previous run (incremental runs only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub change: Option<FileChange>,
+    /// Path of the earlier file this one nearly duplicates (with `config.dedup`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duplicate_of: Option<String>,
+    /// Why extraction failed
+    pub error: Option<ErrorInfo>,
+}
+
+impl From<&DirectoryItem> for ManifestEntry {
+    fn from(item: &DirectoryItem) -> Self {
+        Self {
+            path: item.relative_path.clone(),
+            content_hash: item.content_hash.clone(),
+            mime_type: item.result.as_ref().ok().map(|result| result.mime_type.clone()),
+            output_path: item.output_path.clone(),
+            cached: item.cached,
+            change: item.change,
+            duplicate_of: item
+                .result
+                .as_ref()
+                .ok()
+                .and_then(|result| result.metadata.duplicate_of.clone()),
+            error: item.result.as_ref().err().map(ErrorInfo::from),
+        }
+    }
+}
+
+/// Results of [`extract_directory`] in completion order.
+///
+/// Dropping the stream stops the walk; files not yet extracted are skipped and no manifest
+/// is written.
+pub struct DirectoryStream {
+    receiver: mpsc::Receiver<Result<DirectoryItem>>,
+}
+
+impl DirectoryStream {
+    /// Next processed file, or `None` once every file is processed and the manifest written.
+    ///
+    /// Errors are reserved for failures of the whole run (an unreadable directory, a manifest
+    /// that cannot be written); per-file failures are reported in [`DirectoryItem::result`].
+    pub async fn recv(&mut self) -> Option<Result<DirectoryItem>> {
+        self.receiver.recv().await
+    }
+
+    /// Blocking variant of [`recv`](Self::recv) for synchronous callers.
+    ///
+    /// # Panics
+    ///
+    /// Panics when called from within an async runtime.
+    pub fn blocking_recv(&mut self) -> Option<Result<DirectoryItem>> {
+        self.receiver.blocking_recv()
+    }
+}
+
+/// Extract every matching file below `path`.
+///
+/// Files are walked in sorted order and extracted concurrently; the stream yields each file
+/// as soon as it is done. The walk runs on the current Tokio runtime, or on the global runtime
+/// when called outside of one, so the stream can be consumed from synchronous code with
+/// [`DirectoryStream::blocking_recv`].
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if `path` is not a directory, if a glob is invalid, or
+/// if `incremental` is set without a `manifest_path`.
+pub fn extract_directory(path: impl AsRef<Path>, options: DirectoryOptions) -> Result<DirectoryStream> {
+    let root = path.as_ref().to_path_buf();
+    if !root.is_dir() {
+        return Err(KreuzbergError::validation(format!(
+            "'{}' is not a directory",
+            root.display()
+        )));
+    }
+    if options.incremental && options.manifest_path.is_none() {
+        return Err(KreuzbergError::validation(
+            "Incremental extraction needs a manifest_path to compare against",
+        ));
+    }
+    validate_globs(&options.include)?;
+    validate_globs(&options.exclude)?;
+
+    let (sender, receiver) = mpsc::channel(CHANNEL_CAPACITY);
+    let task = async move {
+        if let Err(error) = run(root, options, &sender).await {
+            // The consumer may already be gone; nothing left to report to ~keep
+            let _ = sender.send(Err(error)).await;
+        }
+    };
+
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => {
+            handle.spawn(task);
+        }
+        Err(_) => {
+            GLOBAL_RUNTIME.spawn(task);
+        }
+    }
+
+    Ok(DirectoryStream { receiver })
+}
+
+/// Settings shared by every file task.
+struct Shared {
+    config: ExtractionConfig,
+    config_hash: String,
+    cache: Option<GenericCache>,
+    output_dir: Option<PathBuf>,
+    output_format: ResultFileFormat,
+    incremental: bool,
+    /// Near-duplicate grouping of `config.dedup`, shared by every file task
+    deduplicator: Option<Mutex<Deduplicator>>,
+}
+
+/// Outcome of a file task.
+enum Processed {
+    /// The file was extracted (or its extraction failed)
+    Extracted(Box<DirectoryItem>),
+    /// Incremental run: the previous manifest entry still applies
+    Unchanged(ManifestEntry),
+}
+
+async fn run(root: PathBuf, options: DirectoryOptions, sender: &mpsc::Sender<Result<DirectoryItem>>) -> Result<()> {
+    let files = {
+        let root = root.clone();
+        let options = options.clone();
+        tokio::task::spawn_blocking(move || collect_files(&root, &options))
+            .await
+            .map_err(|e| KreuzbergError::Other(format!("Directory walk panicked: {}", e)))??
+    };
+
+    let cache = if options.resume {
+        let cache_dir = options.cache_dir.as_ref().map(|dir| dir.to_string_lossy().to_string());
+        Some(GenericCache::new(
+            CACHE_TYPE.to_string(),
+            cache_dir,
+            CACHE_MAX_AGE_DAYS,
+            CACHE_MAX_SIZE_MB,
+            CACHE_MIN_FREE_SPACE_MB,
+        )?)
+    } else {
+        None
+    };
+    let max_concurrency = options
+        .max_concurrency
+        .or(options.config.max_concurrent_extractions)
+        .unwrap_or_else(|| num_cpus::get() * 2)
+        .max(1);
+    let config_hash = config_hash(&options.config)?;
+    let mut previous = match &options.manifest_path {
+        Some(manifest_path) if options.incremental => previous_entries(manifest_path, &config_hash).await?,
+        _ => BTreeMap::new(),
+    };
+    let deduplicator = options.config.dedup.clone().map(Deduplicator::new).map(Mutex::new);
+    let shared = Arc::new(Shared {
+        config_hash,
+        config: options.config,
+        cache,
+        output_dir: options.output_dir,
+        output_format: options.output_format,
+        incremental: options.incremental,
+        deduplicator,
+    });
+
+    let mut entries = BTreeMap::new();
+    let mut tasks = JoinSet::new();
+    for (relative_path, path) in files {
+        if tasks.len() >= max_concurrency
+            && let Some(joined) = tasks.join_next().await
+            && !forward(joined, sender, &mut entries).await?
+        {
+            return Ok(());
+        }
+        let previous_entry = previous.remove(&relative_path);
+        tasks.spawn(process_file(path, relative_path, previous_entry, Arc::clone(&shared)));
+    }
+    while let Some(joined) = tasks.join_next().await {
+        if !forward(joined, sender, &mut entries).await? {
+            return Ok(());
+        }
+    }
+
+    for (relative_path, entry) in previous {
+        let deleted = ManifestEntry {
+            change: Some(FileChange::Deleted),
+            error: None,
+            ..entry
+        };
+        entries.insert(relative_path, deleted);
+    }
+
+    if let Some(manifest_path) = &options.manifest_path {
+        let manifest = DirectoryManifest {
+            root,
+            config_hash: Some(shared.config_hash.clone()),
+            entries: entries.into_values().collect(),
+        };
+        write_json(manifest_path, &manifest).await?;
+    }
+
+    Ok(())
+}
+
+/// Hash of `config`, stable across runs so manifests of earlier runs can be compared.
+fn config_hash(config: &ExtractionConfig) -> Result<String> {
+    let config_json = serde_json::to_value(config)?.to_string();
+    Ok(format!("{:016x}", stable_hash(config_json.as_bytes())))
+}
+
+/// Entries of the previous manifest that can be carried over, keyed by path.
+///
+/// A missing manifest (first run) or one written with a different configuration yields no
+/// entries, so every file is extracted. Files already marked deleted are dropped.
+async fn previous_entries(manifest_path: &Path, config_hash: &str) -> Result<BTreeMap<String, ManifestEntry>> {
+    let data = match tokio::fs::read(manifest_path).await {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(e) => return Err(e.into()),
+    };
+    let manifest: DirectoryManifest = serde_json::from_slice(&data)?;
+    if manifest.config_hash.as_deref() != Some(config_hash) {
+        tracing::info!("Configuration changed since the previous run; re-extracting every file");
+        return Ok(BTreeMap::new());
+    }
+
+    Ok(manifest
+        .entries
+        .into_iter()
+        .filter(|entry| entry.change != Some(FileChange::Deleted))
+        .map(|entry| (entry.path.clone(), entry))
+        .collect())
+}
+
+/// Send a finished file to the consumer; `false` once the stream was dropped.
+async fn forward(
+    joined: std::result::Result<Processed, tokio::task::JoinError>,
+    sender: &mpsc::Sender<Result<DirectoryItem>>,
+    entries: &mut BTreeMap<String, ManifestEntry>,
+) -> Result<bool> {
+    match joined.map_err(|e| KreuzbergError::Other(format!("Task panicked: {}", e)))? {
+        Processed::Extracted(item) => {
+            entries.insert(item.relative_path.clone(), ManifestEntry::from(item.as_ref()));
+            Ok(sender.send(Ok(*item)).await.is_ok())
+        }
+        Processed::Unchanged(entry) => {
+            entries.insert(entry.path.clone(), entry);
+            Ok(!sender.is_closed())
+        }
+    }
+}
+
+async fn process_file(
+    path: PathBuf,
+    relative_path: String,
+    previous: Option<ManifestEntry>,
+    shared: Arc<Shared>,
+) -> Processed {
+    let content_hash = match hash_file(&path).await {
+        Ok(content_hash) => content_hash,
+        Err(e) => {
+            let change = match &previous {
+                Some(_) => FileChange::Modified,
+                None => FileChange::Added,
+            };
+            return Processed::Extracted(Box::new(DirectoryItem {
+                path,
+                relative_path,
+                content_hash: None,
+                output_path: None,
+                cached: false,
+                change: shared.incremental.then_some(change),
+                result: Err(KreuzbergError::Io(e)),
+            }));
+        }
+    };
+    let change = match &previous {
+        None => FileChange::Added,
+        Some(entry) if entry.content_hash.as_deref() == Some(content_hash.as_str()) => FileChange::Unchanged,
+        Some(_) => FileChange::Modified,
+    };
+    if let Some(entry) = previous
+        && change == FileChange::Unchanged
+        && entry.error.is_none()
+        && (shared.output_dir.is_none() || entry.output_path.as_ref().is_some_and(|output| output.is_file()))
+    {
+        return Processed::Unchanged(ManifestEntry {
+            cached: true,
+            change: Some(FileChange::Unchanged),
+            ..entry
+        });
+    }
+    // Both hashes are stable across runs, so a later run finds the result
+    let cache_key = format!("{}{}", content_hash, shared.config_hash);
+
+    let cached = load_cached(Arc::clone(&shared), cache_key.clone()).await;
+    let is_cached = cached.is_some();
+    let mut result = match cached {
+        Some(result) => Ok(result),
+        None => with_batch_mode(extract_file(&path, None, &shared.config)).await,
+    };
+
+    if !is_cached
+        && let Ok(result) = &result
+        && let Err(e) = store_cached(Arc::clone(&shared), cache_key, result).await
+    {
+        tracing::warn!("Failed to cache the result of {}: {}", relative_path, e);
+    }
+
+    if let (Some(deduplicator), Ok(result)) = (&shared.deduplicator, &mut result)
+        && let Ok(mut deduplicator) = deduplicator.lock()
+    {
+        deduplicator.check(result, &relative_path);
+    }
+
+    let mut output_path = None;
+    let result = match (result, &shared.output_dir) {
+        (Ok(result), Some(output_dir)) => {
+            let output = output_dir.join(format!("{}.{}", relative_path, shared.output_format.extension()));
+            let written = write_output(result, shared.output_format, output.clone()).await;
+            output_path = written.is_ok().then_some(output);
+            written
+        }
+        (result, _) => result,
+    };
+
+    Processed::Extracted(Box::new(DirectoryItem {
+        path,
+        relative_path,
+        content_hash: Some(content_hash),
+        output_path,
+        cached: is_cached,
+        change: shared.incremental.then_some(change),
+        result,
+    }))
+}
+
+/// Hex content hash of the file at `path`, read on the blocking pool in chunks.
+async fn hash_file(path: &Path) -> std::io::Result<String> {
+    let path = path.to_path_buf();
+    let hash = tokio::task::spawn_blocking(move || std::fs::File::open(path).and_then(stable_hash_reader))
+        .await
+        .map_err(std::io::Error::other)??;
+    Ok(format!("{:016x}", hash))
+}
+
+/// The cached result of `cache_key`, looked up on the blocking pool (None without `resume`).
+async fn load_cached(shared: Arc<Shared>, cache_key: String) -> Option<ExtractionResult> {
+    shared.cache.as_ref()?;
+    tokio::task::spawn_blocking(move || {
+        let data = shared.cache.as_ref()?.get(&cache_key, None).ok()??;
+        serde_json::from_slice(&data).ok()
+    })
+    .await
+    .ok()?
+}
+
+/// Store `result` under `cache_key` on the blocking pool (nothing without `resume`).
+async fn store_cached(shared: Arc<Shared>, cache_key: String, result: &ExtractionResult) -> Result<()> {
+    if shared.cache.is_none() {
+        return Ok(());
+    }
+    let data = serde_json::to_vec(result)?;
+    tokio::task::spawn_blocking(move || match &shared.cache {
+        Some(cache) => cache.set(&cache_key, data, None),
+        None => Ok(()),
+    })
+    .await
+    .map_err(|e| KreuzbergError::Other(format!("Caching the result panicked: {}", e)))?
+}
+
+/// Write a result with [`write_result`] on the blocking pool and hand it back.
+async fn write_output(result: ExtractionResult, format: ResultFileFormat, output: PathBuf) -> Result<ExtractionResult> {
+    tokio::task::spawn_blocking(move || write_result(&result, format, output).map(|_| result))
+        .await
+        .map_err(|e| KreuzbergError::Other(format!("Writing the result panicked: {}", e)))?
+}
+
+async fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    tokio::fs::write(path, serde_json::to_vec_pretty(value)?).await?;
+    Ok(())
+}
+
+/// Files below `root` that pass the globs, keyed by relative path.
+fn collect_files(root: &Path, options: &DirectoryOptions) -> Result<BTreeMap<String, PathBuf>> {
+    let mut files = BTreeMap::new();
+    let mut pending = vec![root.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let relative_path = to_relative(root, &path);
+            if matches_any(&options.exclude, &relative_path) {
+                continue;
+            }
+
+            if entry.file_type()?.is_dir() {
+                if options.recursive {
+                    pending.push(path);
+                }
+            } else if path.is_file() && (options.include.is_empty() || matches_any(&options.include, &relative_path)) {
+                files.insert(relative_path, path);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+fn to_relative(root: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn matches_any(patterns: &[String], relative_path: &str) -> bool {
+    patterns.iter().any(|pattern| glob_match(pattern, relative_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::DedupConfig;
+    use tempfile::tempdir;
+
+    async fn collect(stream: &mut DirectoryStream) -> Vec<DirectoryItem> {
+        let mut items = Vec::new();
+        while let Some(item) = stream.recv().await {
+            items.push(item.unwrap());
+        }
+        items.sort_by_key(|item| item.relative_path.clone());
+        items
+    }
+
+    #[tokio::test]
+    async fn test_extract_directory_with_globs_and_manifest() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("corpus");
+        std::fs::create_dir_all(root.join("sub")).unwrap();
+        std::fs::create_dir_all(root.join("skip")).unwrap();
+        std::fs::write(root.join("a.txt"), "first").unwrap();
+        std::fs::write(root.join("sub/b.txt"), "second").unwrap();
+        std::fs::write(root.join("skip/c.txt"), "skipped").unwrap();
+        std::fs::write(root.join("data.csv"), "x,y").unwrap();
+
+        let options = DirectoryOptions {
+            include: vec!["*.txt".to_string()],
+            exclude: vec!["skip".to_string()],
+            output_dir: Some(dir.path().join("out")),
+            manifest_path: Some(dir.path().join("manifest.json")),
+            ..Default::default()
+        };
+        let items = collect(&mut extract_directory(&root, options).unwrap()).await;
+
+        let paths: Vec<&str> = items.iter().map(|item| item.relative_path.as_str()).collect();
+        assert_eq!(paths, vec!["a.txt", "sub/b.txt"]);
+        assert_eq!(items[1].result.as_ref().unwrap().content.trim_end(), "second");
+        assert!(dir.path().join("out/sub/b.txt.json").is_file());
+
+        let manifest: DirectoryManifest =
+            serde_json::from_slice(&std::fs::read(dir.path().join("manifest.json")).unwrap()).unwrap();
+        assert_eq!(manifest.entries.len(), 2);
+        assert_eq!(manifest.entries[0].path, "a.txt");
+        assert_eq!(manifest.entries[0].content_hash, items[0].content_hash);
+        assert_eq!(
+            manifest.entries[1].output_path,
+            Some(dir.path().join("out/sub/b.txt.json"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_extract_directory_marks_duplicates() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("corpus");
+        std::fs::create_dir_all(&root).unwrap();
+        let text = "Payment is due within fourteen days of delivery of the goods described in the annex. ".repeat(2);
+        std::fs::write(root.join("a.txt"), &text).unwrap();
+        std::fs::write(root.join("b.txt"), text.to_uppercase()).unwrap();
+        std::fs::write(root.join("c.txt"), "Too short to compare").unwrap();
+
+        let options = DirectoryOptions {
+            config: ExtractionConfig {
+                dedup: Some(DedupConfig::default()),
+                ..Default::default()
+            },
+            max_concurrency: Some(1),
+            manifest_path: Some(dir.path().join("manifest.json")),
+            ..Default::default()
+        };
+        let items = collect(&mut extract_directory(&root, options).unwrap()).await;
+
+        let duplicate_of: Vec<_> = items
+            .iter()
+            .map(|item| item.result.as_ref().unwrap().metadata.duplicate_of.clone())
+            .collect();
+        assert_eq!(duplicate_of, vec![None, Some("a.txt".to_string()), None]);
+        let manifest: DirectoryManifest =
+            serde_json::from_slice(&std::fs::read(dir.path().join("manifest.json")).unwrap()).unwrap();
+        assert_eq!(manifest.entries[1].duplicate_of.as_deref(), Some("a.txt"));
+    }
+
+    #[tokio::test]
+    async fn test_extract_directory_resumes_from_cache() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("corpus");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("a.txt"), "unchanged").unwrap();
+        std::fs::write(root.join("b.txt"), "before").unwrap();
+
+        let options = DirectoryOptions {
+            resume: true,
+            cache_dir: Some(dir.path().join("cache")),
+            ..Default::default()
+        };
+        let first = collect(&mut extract_directory(&root, options.clone()).unwrap()).await;
+        assert!(first.iter().all(|item| !item.cached));
+
+        std::fs::write(root.join("b.txt"), "after").unwrap();
+        let second = collect(&mut extract_directory(&root, options).unwrap()).await;
+
+        assert!(second[0].cached);
+        assert!(!second[1].cached);
+        assert_eq!(second[1].result.as_ref().unwrap().content.trim_end(), "after");
+    }
+
+    #[tokio::test]
+    async fn test_incremental_extracts_only_changes() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("corpus");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("same.txt"), "unchanged").unwrap();
+        std::fs::write(root.join("edit.txt"), "before").unwrap();
+        std::fs::write(root.join("gone.txt"), "deleted later").unwrap();
+        let manifest_path = dir.path().join("manifest.json");
+        let options = DirectoryOptions {
+            incremental: true,
+            manifest_path: Some(manifest_path.clone()),
+            ..Default::default()
+        };
+
+        let first = collect(&mut extract_directory(&root, options.clone()).unwrap()).await;
+        assert_eq!(first.len(), 3);
+        assert!(first.iter().all(|item| item.change == Some(FileChange::Added)));
+
+        std::fs::write(root.join("edit.txt"), "after").unwrap();
+        std::fs::remove_file(root.join("gone.txt")).unwrap();
+        std::fs::write(root.join("new.txt"), "new").unwrap();
+        let second = collect(&mut extract_directory(&root, options.clone()).unwrap()).await;
+
+        let changes: Vec<(&str, Option<FileChange>)> = second
+            .iter()
+            .map(|item| (item.relative_path.as_str(), item.change))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("edit.txt", Some(FileChange::Modified)),
+                ("new.txt", Some(FileChange::Added)),
+            ]
+        );
+
+        let data = std::fs::read(&manifest_path).unwrap();
+        let manifest: DirectoryManifest = serde_json::from_slice(&data).unwrap();
+        let recorded: Vec<(&str, Option<FileChange>)> = manifest
+            .entries
+            .iter()
+            .map(|entry| (entry.path.as_str(), entry.change))
+            .collect();
+        assert_eq!(
+            recorded,
+            vec![
+                ("edit.txt", Some(FileChange::Modified)),
+                ("gone.txt", Some(FileChange::Deleted)),
+                ("new.txt", Some(FileChange::Added)),
+                ("same.txt", Some(FileChange::Unchanged)),
+            ]
+        );
+
+        let third = collect(&mut extract_directory(&root, options).unwrap()).await;
+        assert!(third.is_empty());
+        let data = std::fs::read(&manifest_path).unwrap();
+        let manifest: DirectoryManifest = serde_json::from_slice(&data).unwrap();
+        assert_eq!(manifest.entries.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_incremental_skips_files_of_earlier_process() {
+        let dir = tempdir().unwrap();
+        let root = dir.path().join("corpus");
+        std::fs::create_dir_all(&root).unwrap();
+        std::fs::write(root.join("same.txt"), "unchanged").unwrap();
+        let manifest_path = dir.path().join("manifest.json");
+        let config = ExtractionConfig::default();
+
+        // The manifest a previous process wrote, with the content hash of "unchanged"
+        let previous = DirectoryManifest {
+            root: root.clone(),
+            config_hash: Some(config_hash(&config).unwrap()),
+            entries: vec![ManifestEntry {
+                path: "same.txt".to_string(),
+                content_hash: Some("aaa8d3c8d74ad3e8".to_string()),
+                mime_type: Some("text/plain".to_string()),
+                output_path: None,
+                cached: false,
+                change: Some(FileChange::Added),
+                duplicate_of: None,
+                error: None,
+            }],
+        };
+        std::fs::write(&manifest_path, serde_json::to_vec(&previous).unwrap()).unwrap();
+
+        let options = DirectoryOptions {
+            config,
+            incremental: true,
+            manifest_path: Some(manifest_path.clone()),
+            ..Default::default()
+        };
+        let items = collect(&mut extract_directory(&root, options).unwrap()).await;
+        assert!(items.is_empty());
+
+        let data = std::fs::read(&manifest_path).unwrap();
+        let manifest: DirectoryManifest = serde_json::from_slice(&data).unwrap();
+        assert_eq!(manifest.entries[0].change, Some(FileChange::Unchanged));
+    }
+
+    #[test]
+    fn test_incremental_requires_manifest() {
+        let dir = tempdir().unwrap();
+        let options = DirectoryOptions {
+            incremental: true,
+            ..Default::default()
+        };
+
+        assert!(extract_directory(dir.path(), options).is_err());
+    }
+
+    #[test]
+    fn test_extract_directory_rejects_files() {
+        let dir = tempdir().unwrap();
+        let file = dir.path().join("a.txt");
+        std::fs::write(&file, "text").unwrap();
+
+        assert!(matches!(
+            extract_directory(&file, DirectoryOptions::default()),
+            Err(KreuzbergError::Validation { .. })
+        ));
+    }
+}
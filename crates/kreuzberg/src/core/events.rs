@@ -0,0 +1,263 @@
+//! Page and section events of an extraction result.
+//!
+//! [`ExtractionResult::into_events`] splits a result into a sequence of [`ExtractionEvent`]s:
+//! one per page for results with pages (`PageConfig::extract_pages`), otherwise one per
+//! Markdown heading section. Language bindings hand the events to callers one at a time, so
+//! a huge document never has to be converted into host-language objects all at once.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::{ExtractionConfig, ExtractionEvent, extract_file_sync};
+//! use kreuzberg::core::config::PageConfig;
+//!
+//! # fn example() -> kreuzberg::Result<()> {
+//! let config = ExtractionConfig {
+//!     pages: Some(PageConfig {
+//!         extract_pages: true,
+//!         ..Default::default()
+//!     }),
+//!     ..Default::default()
+//! };
+//! let result = extract_file_sync("big.pdf", None, &config)?;
+//! for event in result.into_events() {
+//!     if let ExtractionEvent::Page(page) = event {
+//!         println!("page {}: {} chars", page.page_number, page.content.len());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::types::{ExtractionResult, Metadata, PageContent, Table};
+
+/// A part of an extraction result, produced by [`ExtractionResult::into_events`].
+#[derive(Debug, Clone)]
+pub enum ExtractionEvent {
+    /// Document-level information, always the first event
+    Document { mime_type: String, metadata: Box<Metadata> },
+    /// One page, slide or sheet of a result with pages
+    Page(PageContent),
+    /// Content from one Markdown heading to the next, for results without pages
+    Section(DocumentSection),
+    /// A table of a result without pages (tables of pages are part of their page)
+    Table(Table),
+}
+
+/// Part of the content of a result without pages, starting at a Markdown heading.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentSection {
+    /// Position of the section in the document (0-indexed)
+    pub index: usize,
+    /// Heading text (None for the text before the first heading)
+    pub heading: Option<String>,
+    /// Heading level from 1 to 6 (0 for the text before the first heading)
+    pub level: u8,
+    /// Section text without the heading line
+    pub content: String,
+}
+
+/// Iterator over the [`ExtractionEvent`]s of a result.
+pub struct ExtractionEvents {
+    document: Option<ExtractionEvent>,
+    pages: std::vec::IntoIter<PageContent>,
+    content: String,
+    section_starts: std::vec::IntoIter<usize>,
+    next_section_start: Option<usize>,
+    section_index: usize,
+    tables: std::vec::IntoIter<Table>,
+}
+
+impl ExtractionResult {
+    /// Split the result into a document event followed by page events, or by section and
+    /// table events when the result has no pages.
+    ///
+    /// Chunks, images outside of pages, entities and keywords are not part of the events.
+    pub fn into_events(self) -> ExtractionEvents {
+        let document = ExtractionEvent::Document {
+            mime_type: self.mime_type,
+            metadata: Box::new(self.metadata),
+        };
+
+        let pages = self.pages.unwrap_or_default();
+        let (content, tables) = if pages.is_empty() {
+            (self.content, self.tables)
+        } else {
+            (String::new(), Vec::new())
+        };
+        let mut section_starts = section_starts(&content).into_iter();
+        let next_section_start = section_starts.next();
+
+        ExtractionEvents {
+            document: Some(document),
+            pages: pages.into_iter(),
+            content,
+            section_starts,
+            next_section_start,
+            section_index: 0,
+            tables: tables.into_iter(),
+        }
+    }
+}
+
+impl ExtractionEvents {
+    fn next_section(&mut self) -> Option<ExtractionEvent> {
+        while let Some(start) = self.next_section_start {
+            self.next_section_start = self.section_starts.next();
+            let end = self.next_section_start.unwrap_or(self.content.len());
+            let section = &self.content[start..end];
+
+            let (first_line, rest) = section.split_once('\n').unwrap_or((section, ""));
+            let (heading, level, body) = match heading_level(first_line) {
+                Some(level) => {
+                    let heading = first_line.trim().trim_start_matches('#').trim();
+                    let heading = heading.trim_end_matches('#').trim_end();
+                    (Some(heading.to_string()), level, rest)
+                }
+                None => (None, 0, section),
+            };
+
+            let body = body.trim();
+            if heading.is_none() && body.is_empty() {
+                continue;
+            }
+
+            let section = DocumentSection {
+                index: self.section_index,
+                heading,
+                level,
+                content: body.to_string(),
+            };
+            self.section_index += 1;
+            return Some(ExtractionEvent::Section(section));
+        }
+        None
+    }
+}
+
+impl Iterator for ExtractionEvents {
+    type Item = ExtractionEvent;
+
+    fn next(&mut self) -> Option<ExtractionEvent> {
+        if let Some(document) = self.document.take() {
+            return Some(document);
+        }
+        if let Some(page) = self.pages.next() {
+            return Some(ExtractionEvent::Page(page));
+        }
+        if let Some(section) = self.next_section() {
+            return Some(section);
+        }
+        self.tables.next().map(ExtractionEvent::Table)
+    }
+}
+
+/// Byte offsets where sections start: 0 and every ATX heading line outside of code fences.
+fn section_starts(content: &str) -> Vec<usize> {
+    let mut starts = vec![0];
+    let mut offset = 0;
+    let mut in_fence = false;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        } else if !in_fence && offset > 0 && heading_level(line).is_some() {
+            starts.push(offset);
+        }
+        offset += line.len();
+    }
+    starts
+}
+
+/// Level of an ATX heading line (`# Title` to `###### Title`).
+fn heading_level(line: &str) -> Option<u8> {
+    let line = line.trim_end();
+    let hashes = line.bytes().take_while(|&b| b == b'#').count();
+    if !(1..=6).contains(&hashes) {
+        return None;
+    }
+    match line.as_bytes().get(hashes) {
+        None | Some(b' ') | Some(b'\t') => Some(hashes as u8),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(content: &str, pages: Option<Vec<PageContent>>) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/markdown".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![Table {
+                cells: vec![vec!["a".to_string()]],
+                markdown: "| a |".to_string(),
+                page_number: 1,
+            }],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_events_of_sections() {
+        let content = "Intro text\n\n# Title\n\nBody\n\n```\n# not a heading\n```\n\n## Sub ##\nMore";
+        let events: Vec<_> = result(content, None).into_events().collect();
+
+        assert!(matches!(events[0], ExtractionEvent::Document { .. }));
+        let mut headings = Vec::new();
+        let mut contents = Vec::new();
+        for event in &events {
+            if let ExtractionEvent::Section(section) = event {
+                headings.push((section.heading.as_deref(), section.level));
+                contents.push(section.content.as_str());
+            }
+        }
+        assert_eq!(headings, vec![(None, 0), (Some("Title"), 1), (Some("Sub"), 2)]);
+        assert_eq!(
+            contents,
+            vec!["Intro text", "Body\n\n```\n# not a heading\n```", "More"]
+        );
+        assert!(matches!(events.last(), Some(ExtractionEvent::Table(_))));
+    }
+
+    #[test]
+    fn test_events_of_pages() {
+        let pages = (1..=3)
+            .map(|page_number| PageContent {
+                page_number,
+                content: format!("page {}", page_number),
+                tables: Vec::new(),
+                images: Vec::new(),
+            })
+            .collect();
+        let events: Vec<_> = result("page 1 page 2 page 3", Some(pages)).into_events().collect();
+
+        assert_eq!(events.len(), 4);
+        let page_numbers: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                ExtractionEvent::Page(page) => Some(page.page_number),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(page_numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_heading_level() {
+        assert_eq!(heading_level("### Three\n"), Some(3));
+        assert_eq!(heading_level("#"), Some(1));
+        assert_eq!(heading_level("#hashtag"), None);
+        assert_eq!(heading_level("####### seven"), None);
+    }
+}
@@ -12,10 +12,18 @@
 //! - [`batch_extract_bytes`] - Extract content from multiple byte arrays concurrently
 
 use crate::core::config::ExtractionConfig;
-use crate::core::mime::{LEGACY_POWERPOINT_MIME_TYPE, LEGACY_WORD_MIME_TYPE};
+use crate::core::dedup::mark_duplicates;
+use crate::core::limits::enforce_limits;
+use crate::core::mime::{LEGACY_POWERPOINT_MIME_TYPE, LEGACY_WORD_MIME_TYPE, MimeInspection};
+use crate::core::provenance::attach_provenance;
+use crate::core::recovery;
+use crate::core::retry::{self, with_retry};
+use crate::core::review::attach_review;
+use crate::core::stats::{StageTimer, attach_stats, discard_unrequested_timings, record_timing, stage_durations};
 #[cfg(feature = "office")]
 use crate::extraction::libreoffice::{convert_doc_to_docx, convert_ppt_to_pptx};
 use crate::plugins::DocumentExtractor;
+use crate::plugins::registry::PluginRegistries;
 use crate::types::ExtractionResult;
 #[cfg(feature = "office")]
 use crate::types::LibreOfficeConversionResult;
@@ -24,6 +32,7 @@ use crate::{KreuzbergError, Result};
 use once_cell::sync::Lazy;
 #[cfg(feature = "office")]
 use serde_json::json;
+use std::borrow::Cow;
 use std::path::Path;
 use std::sync::Arc;
 
@@ -103,10 +112,22 @@ fn sanitize_path(path: &Path) -> String {
 ///
 /// This static is only available when the `tokio-runtime` feature is enabled.
 /// For WASM targets, use the truly synchronous extraction functions instead.
+///
+/// Worker and blocking thread counts come from [`ThreadPoolConfig`](crate::core::runtime::ThreadPoolConfig).
 #[cfg(feature = "tokio-runtime")]
-static GLOBAL_RUNTIME: Lazy<tokio::runtime::Runtime> = Lazy::new(|| {
-    tokio::runtime::Builder::new_multi_thread()
-        .enable_all()
+pub(crate) static GLOBAL_RUNTIME: Lazy<tokio::runtime::Runtime> = Lazy::new(|| {
+    let pools = crate::core::runtime::thread_pool_config();
+    let mut builder = tokio::runtime::Builder::new_multi_thread();
+    builder.enable_all();
+
+    if let Some(threads) = pools.worker_threads {
+        builder.worker_threads(threads);
+    }
+    if let Some(threads) = pools.max_blocking_threads {
+        builder.max_blocking_threads(threads);
+    }
+
+    builder
         .build()
         .expect("Failed to create global Tokio runtime - system may be out of resources")
 });
@@ -116,16 +137,33 @@ static GLOBAL_RUNTIME: Lazy<tokio::runtime::Runtime> = Lazy::new(|| {
 /// This function acquires the registry read lock and retrieves the appropriate
 /// extractor for the given MIME type.
 ///
+/// Priority overrides from `ExtractionConfig::plugin_config` are applied when present.
+/// Registries warmed up by an [`ExtractionSession`](crate::ExtractionSession) answer from
+/// their pre-resolved snapshot without taking the lock.
+///
 /// # Performance
 ///
 /// RwLock read + HashMap lookup is ~100ns, fast enough without caching.
 /// Removed thread-local cache to avoid Tokio work-stealing scheduler issues.
-fn get_extractor(mime_type: &str) -> Result<Arc<dyn DocumentExtractor>> {
-    let registry = crate::plugins::registry::get_document_extractor_registry();
-    let registry_read = registry
+fn get_extractor(
+    mime_type: &str,
+    config: &ExtractionConfig,
+    registries: &PluginRegistries,
+) -> Result<Arc<dyn DocumentExtractor>> {
+    if let Some(extractor) = registries.resolved_extractor(mime_type) {
+        return Ok(extractor);
+    }
+
+    let registry_read = registries
+        .document_extractors
         .read()
         .map_err(|e| KreuzbergError::Other(format!("Document extractor registry lock poisoned: {}", e)))?;
-    registry_read.get(mime_type)
+
+    if config.plugin_config.is_empty() {
+        registry_read.get(mime_type)
+    } else {
+        registry_read.get_with_priority_override(mime_type, |name| config.plugin_priority(name))
+    }
 }
 
 /// Extract content from a file.
@@ -178,9 +216,19 @@ pub async fn extract_file(
     mime_type: Option<&str>,
     config: &ExtractionConfig,
 ) -> Result<ExtractionResult> {
-    use crate::core::{io, mime};
+    extract_file_with_registries(path.as_ref(), mime_type, config, &config.context.registries()).await
+}
 
-    let path = path.as_ref();
+/// Extract content from a file using the given plugin registries.
+///
+/// Shared implementation of [`extract_file`] and [`Extractor::extract_file`](crate::Extractor::extract_file).
+pub(crate) async fn extract_file_with_registries(
+    path: &Path,
+    mime_type: Option<&str>,
+    config: &ExtractionConfig,
+    registries: &PluginRegistries,
+) -> Result<ExtractionResult> {
+    use crate::core::{io, mime};
 
     #[cfg(feature = "otel")]
     {
@@ -188,20 +236,38 @@ pub async fn extract_file(
         span.record("extraction.filename", sanitize_path(path));
     }
 
+    let total_timer = StageTimer::start();
     let result = async {
+        // Held until this extraction and the nested ones it starts are done
+        #[cfg(feature = "tokio-runtime")]
+        let governed = crate::core::governor::govern(config).await?;
+        #[cfg(feature = "tokio-runtime")]
+        let config: &ExtractionConfig = &governed;
+        let scoped = with_registries(config, registries);
+        let config: &ExtractionConfig = &scoped;
+
         io::validate_file_exists(path)?;
 
-        let detected_mime = mime::detect_or_validate(Some(path), mime_type)?;
+        let mime_timer = StageTimer::start();
+        let detected_mime = mime::resolve_file_mime(path, mime_type, config.mime_mappings.as_ref())?;
+        let inspection = mime::inspect_file(path, &detected_mime)?;
+        check_mime(&inspection, config)?;
+        let mime_detection_ms = mime_timer.elapsed_ms();
 
-        match detected_mime.as_str() {
+        let mut result = match detected_mime.as_str() {
             #[cfg(feature = "office")]
             LEGACY_WORD_MIME_TYPE => {
-                let original_bytes = tokio::fs::read(path).await?;
-                let conversion = convert_doc_to_docx(&original_bytes).await?;
-                let mut result =
-                    extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
+                let original_bytes = io::load_file_async(path).await?;
+                let conversion = with_retry(config, "libreoffice", || convert_doc_to_docx(&original_bytes)).await?;
+                let mut result = extract_bytes_with_extractor(
+                    &conversion.converted_bytes,
+                    &conversion.target_mime,
+                    config,
+                    registries,
+                )
+                .await?;
                 apply_libreoffice_metadata(&mut result, LEGACY_WORD_MIME_TYPE, &conversion);
-                return Ok(result);
+                result
             }
             #[cfg(not(feature = "office"))]
             LEGACY_WORD_MIME_TYPE => {
@@ -211,12 +277,17 @@ pub async fn extract_file(
             }
             #[cfg(feature = "office")]
             LEGACY_POWERPOINT_MIME_TYPE => {
-                let original_bytes = tokio::fs::read(path).await?;
-                let conversion = convert_ppt_to_pptx(&original_bytes).await?;
-                let mut result =
-                    extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
+                let original_bytes = io::load_file_async(path).await?;
+                let conversion = with_retry(config, "libreoffice", || convert_ppt_to_pptx(&original_bytes)).await?;
+                let mut result = extract_bytes_with_extractor(
+                    &conversion.converted_bytes,
+                    &conversion.target_mime,
+                    config,
+                    registries,
+                )
+                .await?;
                 apply_libreoffice_metadata(&mut result, LEGACY_POWERPOINT_MIME_TYPE, &conversion);
-                return Ok(result);
+                result
             }
             #[cfg(not(feature = "office"))]
             LEGACY_POWERPOINT_MIME_TYPE => {
@@ -224,10 +295,12 @@ pub async fn extract_file(
                     "Legacy PowerPoint conversion requires the `office` feature or LibreOffice support".to_string(),
                 ));
             }
-            _ => {}
-        }
+            _ => extract_file_with_extractor(path, &detected_mime, config, registries).await?,
+        };
 
-        extract_file_with_extractor(path, &detected_mime, config).await
+        inspection.record(&mut result.metadata);
+        finish_timings(&mut result, config, mime_detection_ms, total_timer);
+        Ok(result)
     }
     .await;
 
@@ -239,6 +312,20 @@ pub async fn extract_file(
     result
 }
 
+/// `config` handing `registries` on to the nested extractions it starts.
+///
+/// Extractors of container formats (emails, archives, embedded images) read the registries
+/// from the configuration they receive, so nested documents use the plugins of the caller.
+fn with_registries<'a>(config: &'a ExtractionConfig, registries: &PluginRegistries) -> Cow<'a, ExtractionConfig> {
+    if config.context.registries().same_as(registries) {
+        return Cow::Borrowed(config);
+    }
+
+    let mut config = config.clone();
+    config.context.registries = Some(registries.clone());
+    Cow::Owned(config)
+}
+
 /// Extract content from a byte array.
 #[cfg_attr(feature = "otel", tracing::instrument(
     skip(config, content),
@@ -248,19 +335,49 @@ pub async fn extract_file(
     )
 ))]
 pub async fn extract_bytes(content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    extract_bytes_with_registries(content, mime_type, config, &config.context.registries()).await
+}
+
+/// Extract content from a byte array using the given plugin registries.
+///
+/// Shared implementation of [`extract_bytes`] and [`Extractor::extract_bytes`](crate::Extractor::extract_bytes).
+pub(crate) async fn extract_bytes_with_registries(
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+    registries: &PluginRegistries,
+) -> Result<ExtractionResult> {
     use crate::core::mime;
 
+    let total_timer = StageTimer::start();
     let result = async {
-        let validated_mime = mime::validate_mime_type(mime_type)?;
-
-        match validated_mime.as_str() {
+        // Held until this extraction and the nested ones it starts are done
+        #[cfg(feature = "tokio-runtime")]
+        let governed = crate::core::governor::govern(config).await?;
+        #[cfg(feature = "tokio-runtime")]
+        let config: &ExtractionConfig = &governed;
+        let scoped = with_registries(config, registries);
+        let config: &ExtractionConfig = &scoped;
+
+        let mime_timer = StageTimer::start();
+        let validated_mime = mime::resolve_bytes_mime(content, mime_type, config.mime_mappings.as_ref())?;
+        let inspection = mime::inspect_content(content, &validated_mime);
+        check_mime(&inspection, config)?;
+        let mime_detection_ms = mime_timer.elapsed_ms();
+
+        let mut result = match validated_mime.as_str() {
             #[cfg(feature = "office")]
             LEGACY_WORD_MIME_TYPE => {
-                let conversion = convert_doc_to_docx(content).await?;
-                let mut result =
-                    extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
+                let conversion = with_retry(config, "libreoffice", || convert_doc_to_docx(content)).await?;
+                let mut result = extract_bytes_with_extractor(
+                    &conversion.converted_bytes,
+                    &conversion.target_mime,
+                    config,
+                    registries,
+                )
+                .await?;
                 apply_libreoffice_metadata(&mut result, LEGACY_WORD_MIME_TYPE, &conversion);
-                return Ok(result);
+                result
             }
             #[cfg(not(feature = "office"))]
             LEGACY_WORD_MIME_TYPE => {
@@ -270,11 +387,16 @@ pub async fn extract_bytes(content: &[u8], mime_type: &str, config: &ExtractionC
             }
             #[cfg(feature = "office")]
             LEGACY_POWERPOINT_MIME_TYPE => {
-                let conversion = convert_ppt_to_pptx(content).await?;
-                let mut result =
-                    extract_bytes_with_extractor(&conversion.converted_bytes, &conversion.target_mime, config).await?;
+                let conversion = with_retry(config, "libreoffice", || convert_ppt_to_pptx(content)).await?;
+                let mut result = extract_bytes_with_extractor(
+                    &conversion.converted_bytes,
+                    &conversion.target_mime,
+                    config,
+                    registries,
+                )
+                .await?;
                 apply_libreoffice_metadata(&mut result, LEGACY_POWERPOINT_MIME_TYPE, &conversion);
-                return Ok(result);
+                result
             }
             #[cfg(not(feature = "office"))]
             LEGACY_POWERPOINT_MIME_TYPE => {
@@ -282,10 +404,12 @@ pub async fn extract_bytes(content: &[u8], mime_type: &str, config: &ExtractionC
                     "Legacy PowerPoint conversion requires the `office` feature or LibreOffice support".to_string(),
                 ));
             }
-            _ => {}
-        }
+            _ => extract_bytes_with_extractor(content, &validated_mime, config, registries).await?,
+        };
 
-        extract_bytes_with_extractor(content, &validated_mime, config).await
+        inspection.record(&mut result.metadata);
+        finish_timings(&mut result, config, mime_detection_ms, total_timer);
+        Ok(result)
     }
     .await;
 
@@ -328,6 +452,7 @@ pub async fn batch_extract_file(
     paths: Vec<impl AsRef<Path>>,
     config: &ExtractionConfig,
 ) -> Result<Vec<ExtractionResult>> {
+    use crate::core::governor::{current_priority, with_priority};
     use std::sync::Arc;
     use tokio::sync::Semaphore;
     use tokio::task::JoinSet;
@@ -341,18 +466,21 @@ pub async fn batch_extract_file(
     let max_concurrent = config.max_concurrent_extractions.unwrap_or_else(|| num_cpus::get() * 2);
     let semaphore = Arc::new(Semaphore::new(max_concurrent));
 
+    // Spawned tasks don't inherit task-locals, so the batch's priority is passed on explicitly
+    let priority = current_priority();
     let mut tasks = JoinSet::new();
+    let mut ids = Vec::with_capacity(paths.len());
 
     for (index, path) in paths.into_iter().enumerate() {
         let path_buf = path.as_ref().to_path_buf();
+        ids.push(path_buf.to_string_lossy().into_owned());
         let config_clone = Arc::clone(&config);
         let semaphore_clone = Arc::clone(&semaphore);
 
         tasks.spawn(async move {
             let _permit = semaphore_clone.acquire().await.unwrap();
-            let result =
-                crate::core::batch_mode::with_batch_mode(async { extract_file(&path_buf, None, &config_clone).await })
-                    .await;
+            let extraction = async { extract_file(&path_buf, None, &config_clone).await };
+            let result = with_priority(priority, crate::core::batch_mode::with_batch_mode(extraction)).await;
             (index, result)
         });
     }
@@ -370,25 +498,7 @@ pub async fn batch_extract_file(
                     return Err(e);
                 }
 
-                use crate::types::{ErrorMetadata, Metadata};
-                let metadata = Metadata {
-                    error: Some(ErrorMetadata {
-                        error_type: format!("{:?}", e),
-                        message: e.to_string(),
-                    }),
-                    ..Default::default()
-                };
-
-                results[index] = Some(ExtractionResult {
-                    content: format!("Error: {}", e),
-                    mime_type: "text/plain".to_string(),
-                    metadata,
-                    tables: vec![],
-                    detected_languages: None,
-                    chunks: None,
-                    images: None,
-                    pages: None,
-                });
+                results[index] = Some(batch_error_result(&e));
             }
             Err(join_err) => {
                 return Err(KreuzbergError::Other(format!("Task panicked: {}", join_err)));
@@ -397,7 +507,40 @@ pub async fn batch_extract_file(
     }
 
     #[allow(clippy::unwrap_used)]
-    Ok(results.into_iter().map(|r| r.unwrap()).collect())
+    let mut results: Vec<ExtractionResult> = results.into_iter().map(|r| r.unwrap()).collect();
+    if let Some(dedup) = &config.dedup {
+        mark_duplicates(&mut results, &ids, dedup);
+    }
+    Ok(results)
+}
+
+/// The placeholder result that batch extraction returns for a file that failed.
+#[cfg(feature = "tokio-runtime")]
+pub(crate) fn batch_error_result(e: &KreuzbergError) -> ExtractionResult {
+    use crate::types::{ErrorMetadata, Metadata};
+    let metadata = Metadata {
+        error: Some(ErrorMetadata {
+            error_type: format!("{:?}", e),
+            message: e.to_string(),
+        }),
+        ..Default::default()
+    };
+
+    ExtractionResult {
+        content: format!("Error: {}", e),
+        mime_type: "text/plain".to_string(),
+        metadata,
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
+    }
 }
 
 /// Extract content from multiple byte arrays concurrently.
@@ -426,6 +569,7 @@ pub async fn batch_extract_bytes(
     contents: Vec<(&[u8], &str)>,
     config: &ExtractionConfig,
 ) -> Result<Vec<ExtractionResult>> {
+    use crate::core::governor::{current_priority, with_priority};
     use std::sync::Arc;
     use tokio::sync::Semaphore;
     use tokio::task::JoinSet;
@@ -445,6 +589,7 @@ pub async fn batch_extract_bytes(
         .map(|(bytes, mime)| (bytes.to_vec(), mime.to_string()))
         .collect();
 
+    let priority = current_priority();
     let mut tasks = JoinSet::new();
 
     for (index, (bytes, mime_type)) in owned_contents.into_iter().enumerate() {
@@ -453,10 +598,8 @@ pub async fn batch_extract_bytes(
 
         tasks.spawn(async move {
             let _permit = semaphore_clone.acquire().await.unwrap();
-            let result = crate::core::batch_mode::with_batch_mode(async {
-                extract_bytes(&bytes, &mime_type, &config_clone).await
-            })
-            .await;
+            let extraction = async { extract_bytes(&bytes, &mime_type, &config_clone).await };
+            let result = with_priority(priority, crate::core::batch_mode::with_batch_mode(extraction)).await;
             (index, result)
         });
     }
@@ -492,6 +635,11 @@ pub async fn batch_extract_bytes(
                     chunks: None,
                     images: None,
                     pages: None,
+                    entities: None,
+                    keywords: None,
+                    children: None,
+                    fields: None,
+                    warnings: Vec::new(),
                 });
             }
             Err(join_err) => {
@@ -501,7 +649,12 @@ pub async fn batch_extract_bytes(
     }
 
     #[allow(clippy::unwrap_used)]
-    Ok(results.into_iter().map(|r| r.unwrap()).collect())
+    let mut results: Vec<ExtractionResult> = results.into_iter().map(|r| r.unwrap()).collect();
+    if let Some(dedup) = &config.dedup {
+        let ids: Vec<String> = (0..results.len()).map(|index| index.to_string()).collect();
+        mark_duplicates(&mut results, &ids, dedup);
+    }
+    Ok(results)
 }
 
 /// Synchronous wrapper for `extract_file`.
@@ -604,9 +757,18 @@ pub fn batch_extract_bytes_sync(
                 chunks: None,
                 images: None,
                 pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             }
         }));
     }
+    if let Some(dedup) = &config.dedup {
+        let ids: Vec<String> = (0..results.len()).map(|index| index.to_string()).collect();
+        mark_duplicates(&mut results, &ids, dedup);
+    }
     Ok(results)
 }
 
@@ -638,22 +800,27 @@ fn extract_bytes_sync_impl(
     use crate::core::mime;
 
     let config = config.unwrap_or_default();
+    let total_timer = StageTimer::start();
 
     // Validate MIME type if provided
+    let mime_timer = StageTimer::start();
     let validated_mime = if let Some(mime) = mime_type {
-        mime::validate_mime_type(&mime)?
+        mime::resolve_bytes_mime(&content, &mime, config.mime_mappings.as_ref())?
     } else {
         return Err(KreuzbergError::Validation {
             message: "MIME type is required for synchronous extraction".to_string(),
             source: None,
         });
     };
+    let inspection = mime::inspect_content(&content, &validated_mime);
+    check_mime(&inspection, &config)?;
+    let mime_detection_ms = mime_timer.elapsed_ms();
 
     // Ensure extractors are initialized
     crate::extractors::ensure_initialized()?;
 
     // Get the appropriate extractor
-    let extractor = get_extractor(&validated_mime)?;
+    let extractor = get_extractor(&validated_mime, &config, &config.context.registries())?;
 
     // Check if extractor supports synchronous extraction
     let sync_extractor = extractor.as_sync_extractor().ok_or_else(|| {
@@ -664,11 +831,38 @@ fn extract_bytes_sync_impl(
     })?;
 
     // Call the sync extract method
-    let mut result = sync_extractor.extract_sync(&content, &validated_mime, &config)?;
+    let timer = StageTimer::start();
+    let mut result = match sync_extractor.extract_sync(&content, &validated_mime, &config) {
+        Ok(result) => result,
+        Err(e) if recovery::should_recover(&e, &config) => {
+            let recovered = recovery::repair(&content, &validated_mime).and_then(|repair| {
+                let result = sync_extractor
+                    .extract_sync(&repair.content, &validated_mime, &config)
+                    .ok()?;
+                Some((result, repair.warning))
+            });
+            let Some((mut result, warning)) = recovered else {
+                return Err(e);
+            };
+            tracing::warn!("Recovered malformed {} document: {}", validated_mime, warning);
+            recovery::record_warning(&mut result, warning);
+            result
+        }
+        Err(e) => return Err(e),
+    };
+    enforce_limits(&mut result, &config)?;
+    discard_unrequested_timings(&mut result, &config);
+    record_timing(&mut result, &config, timer, |t| &mut t.extraction_ms);
+    result.metadata.promote_common_fields();
+    attach_provenance(&mut result);
 
     // Run post-processing pipeline (sync version)
     result = crate::core::pipeline::run_pipeline_sync(result, &config)?;
 
+    attach_stats(&mut result, None);
+    attach_review(&mut result, &config);
+    inspection.record(&mut result.metadata);
+    finish_timings(&mut result, &config, mime_detection_ms, total_timer);
     Ok(result)
 }
 
@@ -676,12 +870,41 @@ async fn extract_file_with_extractor(
     path: &Path,
     mime_type: &str,
     config: &ExtractionConfig,
+    registries: &PluginRegistries,
 ) -> Result<ExtractionResult> {
-    crate::extractors::ensure_initialized()?;
+    if registries.needs_lazy_init() {
+        crate::extractors::ensure_initialized()?;
+    }
 
-    let extractor = get_extractor(mime_type)?;
-    let mut result = extractor.extract_file(path, mime_type, config).await?;
-    result = crate::core::pipeline::run_pipeline(result, config).await?;
+    let extractor = get_extractor(mime_type, config, registries)?;
+
+    let timer = StageTimer::start();
+    let extract = || extractor.extract_file(path, mime_type, config);
+    let outcome = if retry::applies_to(mime_type) {
+        with_retry(config, "extraction", extract).await
+    } else {
+        extract().await
+    };
+    let mut result = match outcome {
+        Ok(result) => result,
+        Err(e) if recovery::should_recover(&e, config) => match crate::core::io::load_file_async(path).await {
+            Ok(content) => extract_repaired(extractor.as_ref(), &content, mime_type, config, e).await?,
+            Err(_) => return Err(e),
+        },
+        Err(e) => return Err(e),
+    };
+    let extraction_ms = timer.elapsed_ms();
+    enforce_limits(&mut result, config)?;
+    discard_unrequested_timings(&mut result, config);
+    record_timing(&mut result, config, timer, |t| &mut t.extraction_ms);
+    result.metadata.promote_common_fields();
+    attach_provenance(&mut result);
+
+    let timer = StageTimer::start();
+    result = crate::core::pipeline::run_pipeline_with_registries(result, config, registries).await?;
+
+    attach_stats(&mut result, stage_durations(extraction_ms, timer.elapsed_ms()));
+    attach_review(&mut result, config);
     Ok(result)
 }
 
@@ -689,15 +912,94 @@ async fn extract_bytes_with_extractor(
     content: &[u8],
     mime_type: &str,
     config: &ExtractionConfig,
+    registries: &PluginRegistries,
 ) -> Result<ExtractionResult> {
-    crate::extractors::ensure_initialized()?;
+    if registries.needs_lazy_init() {
+        crate::extractors::ensure_initialized()?;
+    }
+
+    let extractor = get_extractor(mime_type, config, registries)?;
 
-    let extractor = get_extractor(mime_type)?;
-    let mut result = extractor.extract_bytes(content, mime_type, config).await?;
-    result = crate::core::pipeline::run_pipeline(result, config).await?;
+    let timer = StageTimer::start();
+    let extract = || extractor.extract_bytes(content, mime_type, config);
+    let outcome = if retry::applies_to(mime_type) {
+        with_retry(config, "extraction", extract).await
+    } else {
+        extract().await
+    };
+    let mut result = match outcome {
+        Ok(result) => result,
+        Err(e) => extract_repaired(extractor.as_ref(), content, mime_type, config, e).await?,
+    };
+    let extraction_ms = timer.elapsed_ms();
+    enforce_limits(&mut result, config)?;
+    discard_unrequested_timings(&mut result, config);
+    record_timing(&mut result, config, timer, |t| &mut t.extraction_ms);
+    result.metadata.promote_common_fields();
+    attach_provenance(&mut result);
+
+    let timer = StageTimer::start();
+    result = crate::core::pipeline::run_pipeline_with_registries(result, config, registries).await?;
+
+    attach_stats(&mut result, stage_durations(extraction_ms, timer.elapsed_ms()));
+    attach_review(&mut result, config);
     Ok(result)
 }
 
+/// Extract a repaired copy of a document whose extraction failed, unless in strict mode.
+///
+/// The original error is returned when recovery does not apply, the document cannot be
+/// repaired or the repaired copy fails as well.
+async fn extract_repaired(
+    extractor: &dyn DocumentExtractor,
+    content: &[u8],
+    mime_type: &str,
+    config: &ExtractionConfig,
+    error: KreuzbergError,
+) -> Result<ExtractionResult> {
+    if !recovery::should_recover(&error, config) {
+        return Err(error);
+    }
+    let Some(repair) = recovery::repair(content, mime_type) else {
+        return Err(error);
+    };
+
+    match extractor.extract_bytes(&repair.content, mime_type, config).await {
+        Ok(mut result) => {
+            tracing::warn!("Recovered malformed {} document: {}", mime_type, repair.warning);
+            recovery::record_warning(&mut result, repair.warning);
+            Ok(result)
+        }
+        Err(_) => Err(error),
+    }
+}
+
+/// Refuse content that contradicts its declared MIME type in strict mode, and warn otherwise.
+fn check_mime(inspection: &MimeInspection, config: &ExtractionConfig) -> Result<()> {
+    match inspection.ensure_consistent() {
+        Err(e) if config.strict_mime => Err(e),
+        Err(e) => {
+            tracing::warn!("Extracting despite MIME mismatch: {}", e);
+            Ok(())
+        }
+        Ok(()) => Ok(()),
+    }
+}
+
+/// Record MIME detection and total time once the result is complete.
+fn finish_timings(
+    result: &mut ExtractionResult,
+    config: &ExtractionConfig,
+    mime_detection_ms: Option<f64>,
+    total_timer: StageTimer,
+) {
+    if config.collect_timings {
+        let timings = result.metadata.timings.get_or_insert_with(Default::default);
+        timings.mime_detection_ms = mime_detection_ms;
+        timings.total_ms = total_timer.elapsed_ms();
+    }
+}
+
 #[cfg(feature = "office")]
 fn apply_libreoffice_metadata(
     result: &mut ExtractionResult,
@@ -784,6 +1086,20 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_extract_bytes_reports_mime_mismatch() {
+        let mut config = ExtractionConfig::default();
+        let content = b"%PDF-1.4 declared as plain text";
+
+        let result = extract_bytes(content, "text/plain", &config).await.unwrap();
+        let mismatch = &result.metadata.additional[crate::core::mime::DETECTED_VS_DECLARED_KEY];
+        assert_eq!(mismatch["detected"], "application/pdf");
+
+        config.strict_mime = true;
+        let result = extract_bytes(content, "text/plain", &config).await;
+        assert!(matches!(result, Err(KreuzbergError::Validation { .. })));
+    }
+
     #[tokio::test]
     async fn test_batch_extract_file() {
         let dir = tempdir().unwrap();
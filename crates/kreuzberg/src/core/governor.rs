@@ -0,0 +1,451 @@
+//! Process-wide concurrency governor.
+//!
+//! Every extraction entry point (API, MCP, batch functions and the language bindings)
+//! ends up in [`extract_file`](crate::extract_file) or [`extract_bytes`](crate::extract_bytes),
+//! which acquire a permit from a single process-wide governor before doing any work.
+//! This keeps concurrent OCR/PDF jobs from oversubscribing CPU and memory no matter
+//! how many callers submit work at once; excess jobs wait for a permit (backpressure).
+//!
+//! The limit defaults to the number of CPU cores. It can be set with
+//! [`configure_max_concurrent_jobs`] or the `KREUZBERG_MAX_CONCURRENT_JOBS` environment
+//! variable, both of which only take effect before the first extraction.
+//!
+//! Waiting jobs are queued by [`JobPriority`], set for a task with [`with_priority`]. When a
+//! permit frees up, the queues are served by weighted round robin: out of every seven permits,
+//! four go to high, two to normal and one to low priority jobs while all three are waiting.
+//! Small interactive requests therefore overtake a large batch without starving it. Jobs of
+//! the same priority run in submission order.
+//!
+//! Nested extractions (email attachments, archive members, plugins calling the extraction
+//! functions with the configuration they were given) run under the permit of their parent.
+//! The permit travels in the [`ExtractionContext`](crate::core::context::ExtractionContext)
+//! of the configuration, so it is carried into spawned tasks, the blocking pool and the
+//! synchronous wrappers alike; waiting for a second permit there would deadlock once parents
+//! hold all of them.
+
+use crate::core::config::ExtractionConfig;
+use crate::{KreuzbergError, Result};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use tokio::sync::oneshot;
+
+/// Environment variable used to set the limit when it was not configured programmatically.
+pub const MAX_CONCURRENT_JOBS_ENV: &str = "KREUZBERG_MAX_CONCURRENT_JOBS";
+
+static GOVERNOR: OnceCell<ConcurrencyGovernor> = OnceCell::new();
+
+tokio::task_local! {
+    /// Priority of the extractions started by the current task.
+    static JOB_PRIORITY: JobPriority;
+}
+
+/// Scheduling priority of an extraction job.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JobPriority {
+    /// Interactive requests that should start as soon as possible
+    High,
+    /// Regular jobs (default)
+    #[default]
+    Normal,
+    /// Bulk work that may wait behind everything else
+    Low,
+}
+
+impl JobPriority {
+    const ALL: [JobPriority; 3] = [JobPriority::High, JobPriority::Normal, JobPriority::Low];
+
+    /// Share of the freed permits this priority receives while all queues are waiting.
+    fn weight(self) -> i64 {
+        match self {
+            JobPriority::High => 4,
+            JobPriority::Normal => 2,
+            JobPriority::Low => 1,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl FromStr for JobPriority {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "high" => Ok(JobPriority::High),
+            "normal" => Ok(JobPriority::Normal),
+            "low" => Ok(JobPriority::Low),
+            _ => Err(KreuzbergError::validation(format!(
+                "Invalid job priority '{}': expected high, normal or low",
+                s
+            ))),
+        }
+    }
+}
+
+/// Snapshot of the governor's current load.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GovernorLoad {
+    /// Maximum number of concurrent extraction jobs
+    pub max_concurrent_jobs: usize,
+    /// Jobs currently running
+    pub active_jobs: usize,
+    /// Jobs waiting for a permit
+    pub queued_jobs: usize,
+    /// Jobs waiting for a permit, by priority
+    pub queued_by_priority: QueuedJobs,
+}
+
+/// Number of waiting jobs per priority.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QueuedJobs {
+    /// High priority jobs
+    pub high: usize,
+    /// Normal priority jobs
+    pub normal: usize,
+    /// Low priority jobs
+    pub low: usize,
+}
+
+/// Governor limiting the number of concurrently running extraction jobs.
+#[derive(Debug)]
+pub struct ConcurrencyGovernor {
+    max_concurrent_jobs: usize,
+    state: Mutex<SchedulerState>,
+}
+
+#[derive(Debug)]
+struct SchedulerState {
+    available: usize,
+    /// Waiting jobs per priority, indexed by [`JobPriority::index`]
+    waiting: [VecDeque<oneshot::Sender<()>>; 3],
+    /// Smooth weighted round robin credits per priority
+    credits: [i64; 3],
+}
+
+impl SchedulerState {
+    fn queued(&self, priority: JobPriority) -> usize {
+        self.waiting[priority.index()]
+            .iter()
+            .filter(|waiter| !waiter.is_closed())
+            .count()
+    }
+
+    /// Pick the priority to serve next among those with waiting jobs.
+    ///
+    /// Every waiting priority earns its weight in credits; the one with the most credits is
+    /// served and pays back the total, so each priority gets its share of the permits.
+    fn next_priority(&mut self) -> Option<JobPriority> {
+        let waiting: Vec<JobPriority> = JobPriority::ALL
+            .into_iter()
+            .filter(|priority| !self.waiting[priority.index()].is_empty())
+            .collect();
+        let total: i64 = waiting.iter().map(|priority| priority.weight()).sum();
+        for priority in &waiting {
+            self.credits[priority.index()] += priority.weight();
+        }
+
+        let next = waiting.into_iter().max_by_key(|priority| {
+            // Ties go to the higher priority, which comes first in `ALL`
+            (self.credits[priority.index()], std::cmp::Reverse(priority.index()))
+        })?;
+        self.credits[next.index()] -= total;
+        Some(next)
+    }
+}
+
+/// Permit of a running job; frees its slot for the next waiting job when dropped.
+#[derive(Debug)]
+pub(crate) struct GovernorPermit<'a> {
+    governor: &'a ConcurrencyGovernor,
+}
+
+impl Drop for GovernorPermit<'_> {
+    fn drop(&mut self) {
+        self.governor.release();
+    }
+}
+
+/// Waiting job; hands a permit granted after cancellation on to the next job.
+struct Waiter<'a> {
+    governor: &'a ConcurrencyGovernor,
+    receiver: Option<oneshot::Receiver<()>>,
+}
+
+impl Drop for Waiter<'_> {
+    fn drop(&mut self) {
+        if let Some(mut receiver) = self.receiver.take() {
+            receiver.close();
+            if receiver.try_recv().is_ok() {
+                self.governor.release();
+            }
+        }
+    }
+}
+
+impl ConcurrencyGovernor {
+    fn new(max_concurrent_jobs: usize) -> Self {
+        let max_concurrent_jobs = max_concurrent_jobs.max(1);
+        Self {
+            max_concurrent_jobs,
+            state: Mutex::new(SchedulerState {
+                available: max_concurrent_jobs,
+                waiting: Default::default(),
+                credits: [0; 3],
+            }),
+        }
+    }
+
+    /// Maximum number of concurrent extraction jobs.
+    pub fn max_concurrent_jobs(&self) -> usize {
+        self.max_concurrent_jobs
+    }
+
+    /// Current load of the governor.
+    pub fn load(&self) -> GovernorLoad {
+        let state = self.state();
+        let queued_by_priority = QueuedJobs {
+            high: state.queued(JobPriority::High),
+            normal: state.queued(JobPriority::Normal),
+            low: state.queued(JobPriority::Low),
+        };
+        GovernorLoad {
+            max_concurrent_jobs: self.max_concurrent_jobs,
+            active_jobs: self.max_concurrent_jobs.saturating_sub(state.available),
+            queued_jobs: queued_by_priority.high + queued_by_priority.normal + queued_by_priority.low,
+            queued_by_priority,
+        }
+    }
+
+    /// The scheduler state stays consistent across panics, so a poisoned lock is recovered.
+    fn state(&self) -> MutexGuard<'_, SchedulerState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    async fn acquire(&self, priority: JobPriority) -> Result<GovernorPermit<'_>> {
+        let receiver = {
+            let mut state = self.state();
+            if state.available > 0 && state.waiting.iter().all(VecDeque::is_empty) {
+                state.available -= 1;
+                return Ok(GovernorPermit { governor: self });
+            }
+            let (sender, receiver) = oneshot::channel();
+            state.waiting[priority.index()].push_back(sender);
+            receiver
+        };
+
+        let mut waiter = Waiter {
+            governor: self,
+            receiver: Some(receiver),
+        };
+        let granted = waiter.receiver.as_mut().expect("set until granted").await;
+        waiter.receiver = None;
+
+        granted
+            .map(|()| GovernorPermit { governor: self })
+            .map_err(|e| KreuzbergError::Other(format!("Concurrency governor closed: {}", e)))
+    }
+
+    /// Hand the slot of a finished job to the next waiting job, or return it to the pool.
+    fn release(&self) {
+        let mut state = self.state();
+        while let Some(priority) = state.next_priority() {
+            let Some(waiter) = state.waiting[priority.index()].pop_front() else {
+                continue;
+            };
+            // A waiter whose job was cancelled is skipped
+            if waiter.send(()).is_ok() {
+                return;
+            }
+        }
+        state.available += 1;
+    }
+}
+
+/// Set the process-wide limit on concurrent extraction jobs.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if `max_concurrent_jobs` is zero, or if the
+/// governor was already initialized (by an earlier call or the first extraction).
+pub fn configure_max_concurrent_jobs(max_concurrent_jobs: usize) -> Result<()> {
+    if max_concurrent_jobs == 0 {
+        return Err(KreuzbergError::validation("max_concurrent_jobs must be greater than 0"));
+    }
+
+    GOVERNOR
+        .set(ConcurrencyGovernor::new(max_concurrent_jobs))
+        .map_err(|_| KreuzbergError::validation("Concurrency governor is already initialized"))
+}
+
+/// The process-wide concurrency governor, initialized on first use.
+pub fn global_governor() -> &'static ConcurrencyGovernor {
+    GOVERNOR.get_or_init(|| ConcurrencyGovernor::new(default_max_concurrent_jobs()))
+}
+
+/// Current load of the process-wide governor.
+pub fn current_load() -> GovernorLoad {
+    global_governor().load()
+}
+
+fn default_max_concurrent_jobs() -> usize {
+    std::env::var(MAX_CONCURRENT_JOBS_ENV)
+        .ok()
+        .and_then(|value| value.parse::<usize>().ok())
+        .filter(|&value| value > 0)
+        .unwrap_or_else(num_cpus::get)
+}
+
+/// Run `future` with its extractions queued at `priority`.
+///
+/// The priority only applies to the current task; batch functions pass it on to the tasks
+/// they spawn.
+pub async fn with_priority<F, T>(priority: JobPriority, future: F) -> T
+where
+    F: std::future::Future<Output = T>,
+{
+    JOB_PRIORITY.scope(priority, future).await
+}
+
+/// Priority of the current task, [`JobPriority::Normal`] outside of [`with_priority`].
+pub fn current_priority() -> JobPriority {
+    JOB_PRIORITY.try_with(|priority| *priority).unwrap_or_default()
+}
+
+/// `config` holding a permit from the global governor, for the duration of an extraction.
+///
+/// Waits for a permit unless `config` already carries the permit of a parent extraction, in
+/// which case the nested extraction runs immediately. The permit is released once the
+/// returned configuration and the copies handed to nested extractions are dropped.
+pub(crate) async fn govern(config: &ExtractionConfig) -> Result<Cow<'_, ExtractionConfig>> {
+    if config.context.holds_permit() {
+        return Ok(Cow::Borrowed(config));
+    }
+
+    let permit = global_governor().acquire(current_priority()).await?;
+    let mut config = config.clone();
+    config.context.permit = Some(Arc::new(permit));
+    Ok(Cow::Owned(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_governor_tracks_active_jobs() {
+        let governor = ConcurrencyGovernor::new(2);
+        assert_eq!(governor.load().active_jobs, 0);
+
+        let permit = governor.acquire(JobPriority::Normal).await.unwrap();
+        let load = governor.load();
+        assert_eq!(load.max_concurrent_jobs, 2);
+        assert_eq!(load.active_jobs, 1);
+        assert_eq!(load.queued_jobs, 0);
+        assert_eq!(load.queued_by_priority, QueuedJobs::default());
+
+        drop(permit);
+        assert_eq!(governor.load().active_jobs, 0);
+    }
+
+    #[test]
+    fn test_governor_minimum_limit() {
+        assert_eq!(ConcurrencyGovernor::new(0).max_concurrent_jobs(), 1);
+    }
+
+    #[test]
+    fn test_configure_rejects_zero() {
+        assert!(configure_max_concurrent_jobs(0).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_nested_extraction_reuses_permit() {
+        let config = ExtractionConfig::default();
+        let governed = govern(&config).await.unwrap();
+        assert!(governed.context.holds_permit());
+
+        // The permit is carried into other tasks with the configuration
+        let nested = governed.into_owned();
+        let reused = tokio::spawn(async move { matches!(govern(&nested).await, Ok(Cow::Borrowed(_))) });
+        assert!(reused.await.unwrap());
+    }
+
+    #[test]
+    fn test_weighted_round_robin_shares() {
+        let mut receivers = Vec::new();
+        let mut state = ConcurrencyGovernor::new(1).state.into_inner().unwrap();
+        for queue in state.waiting.iter_mut() {
+            for _ in 0..10 {
+                let (sender, receiver) = oneshot::channel();
+                queue.push_back(sender);
+                receivers.push(receiver);
+            }
+        }
+
+        let mut served = Vec::new();
+        for _ in 0..7 {
+            let priority = state.next_priority().unwrap();
+            state.waiting[priority.index()].pop_front();
+            served.push(priority);
+        }
+
+        let count = |priority| served.iter().filter(|&&p| p == priority).count();
+        assert_eq!(served[0], JobPriority::High);
+        assert_eq!(count(JobPriority::High), 4);
+        assert_eq!(count(JobPriority::Normal), 2);
+        assert_eq!(count(JobPriority::Low), 1);
+    }
+
+    #[tokio::test]
+    async fn test_high_priority_overtakes_queued_jobs() {
+        let governor: &'static ConcurrencyGovernor = Box::leak(Box::new(ConcurrencyGovernor::new(1)));
+        let permit = governor.acquire(JobPriority::Normal).await.unwrap();
+        let (order_tx, mut order_rx) = tokio::sync::mpsc::unbounded_channel();
+
+        for (queued, priority) in [JobPriority::Low, JobPriority::High].into_iter().enumerate() {
+            let order_tx = order_tx.clone();
+            tokio::spawn(async move {
+                let _permit = governor.acquire(priority).await.unwrap();
+                order_tx.send(priority).unwrap();
+            });
+            while governor.load().queued_jobs <= queued {
+                tokio::task::yield_now().await;
+            }
+        }
+        assert_eq!(governor.load().queued_by_priority.low, 1);
+
+        drop(permit);
+        assert_eq!(order_rx.recv().await, Some(JobPriority::High));
+        assert_eq!(order_rx.recv().await, Some(JobPriority::Low));
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_waiter_frees_its_place() {
+        let governor = ConcurrencyGovernor::new(1);
+        let permit = governor.acquire(JobPriority::Normal).await.unwrap();
+
+        let waiting = tokio::time::timeout(Duration::from_millis(10), governor.acquire(JobPriority::High)).await;
+        assert!(waiting.is_err());
+        assert_eq!(governor.load().queued_jobs, 0);
+
+        drop(permit);
+        assert_eq!(governor.load().active_jobs, 0);
+        assert!(governor.acquire(JobPriority::Low).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_priority_scope() {
+        assert_eq!(current_priority(), JobPriority::Normal);
+        let priority = with_priority(JobPriority::Low, async { current_priority() }).await;
+        assert_eq!(priority, JobPriority::Low);
+        assert_eq!("HIGH".parse::<JobPriority>().unwrap(), JobPriority::High);
+        assert!("urgent".parse::<JobPriority>().is_err());
+    }
+}
@@ -1,9 +1,17 @@
 //! File I/O utilities.
 //!
-//! This module provides async and sync file reading utilities with proper error handling.
+//! This module provides async and sync file reading utilities with proper error handling,
+//! and [`write_result`] for saving extraction results as Markdown, text, JSON or HTML files.
 
+use crate::types::ExtractionResult;
 use crate::{KreuzbergError, Result};
-use std::path::Path;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+static IMAGE_LINK_RE: OnceLock<Regex> = OnceLock::new();
 
 /// Read a file asynchronously.
 ///
@@ -40,6 +48,131 @@ pub fn read_file_sync(path: impl AsRef<Path>) -> Result<Vec<u8>> {
     std::fs::read(path.as_ref()).map_err(KreuzbergError::Io)
 }
 
+/// Files at least this large are memory-mapped by [`load_file`] when the `mmap` feature is enabled.
+///
+/// Smaller files are cheaper to read into memory than to map.
+pub const MMAP_THRESHOLD_BYTES: u64 = 4 * 1024 * 1024;
+
+/// File contents, either read into memory or memory-mapped.
+///
+/// Dereferences to `&[u8]`, so it can be passed anywhere a byte slice is expected
+/// (e.g. [`DocumentExtractor::extract_bytes`](crate::plugins::DocumentExtractor::extract_bytes)).
+/// Memory-mapped contents are paged in lazily by the OS, which keeps peak RSS low for
+/// multi-GB inputs.
+pub enum FileContent {
+    /// Contents read into an owned buffer
+    Owned(Vec<u8>),
+    /// Memory-mapped contents (requires the `mmap` feature)
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl FileContent {
+    /// Whether the contents are memory-mapped.
+    pub fn is_mapped(&self) -> bool {
+        match self {
+            FileContent::Owned(_) => false,
+            #[cfg(feature = "mmap")]
+            FileContent::Mapped(_) => true,
+        }
+    }
+
+    /// Convert into an owned buffer, copying if the contents are memory-mapped.
+    pub fn into_vec(self) -> Vec<u8> {
+        match self {
+            FileContent::Owned(bytes) => bytes,
+            #[cfg(feature = "mmap")]
+            FileContent::Mapped(map) => map.to_vec(),
+        }
+    }
+}
+
+impl std::ops::Deref for FileContent {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            FileContent::Owned(bytes) => bytes,
+            #[cfg(feature = "mmap")]
+            FileContent::Mapped(map) => map,
+        }
+    }
+}
+
+impl AsRef<[u8]> for FileContent {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl From<Vec<u8>> for FileContent {
+    fn from(bytes: Vec<u8>) -> Self {
+        FileContent::Owned(bytes)
+    }
+}
+
+/// Load a file for extraction.
+///
+/// With the `mmap` feature, files of at least [`MMAP_THRESHOLD_BYTES`] are memory-mapped
+/// instead of being read into a `Vec`. Otherwise (or if mapping fails) the file is read
+/// into memory.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Io` for I/O errors (these always bubble up).
+pub fn load_file(path: impl AsRef<Path>) -> Result<FileContent> {
+    let path = path.as_ref();
+
+    #[cfg(feature = "mmap")]
+    {
+        let file = std::fs::File::open(path).map_err(KreuzbergError::Io)?;
+        let len = file.metadata().map_err(KreuzbergError::Io)?.len();
+
+        if len >= MMAP_THRESHOLD_BYTES {
+            match map_file(&file) {
+                Ok(map) => return Ok(FileContent::Mapped(map)),
+                Err(e) => tracing::debug!("Failed to memory-map {}, reading instead: {}", path.display(), e),
+            }
+        }
+    }
+
+    read_file_sync(path).map(FileContent::Owned)
+}
+
+/// Memory-map a file read-only.
+///
+/// Documents must not be modified while they are being extracted; truncating a mapped
+/// file during extraction is undefined behavior on some platforms.
+#[cfg(feature = "mmap")]
+#[allow(unsafe_code)]
+fn map_file(file: &std::fs::File) -> std::io::Result<memmap2::Mmap> {
+    // SAFETY: read-only mapping of a file we just opened; callers never write through it ~keep
+    unsafe { memmap2::Mmap::map(file) }
+}
+
+/// Load a file for extraction without blocking the async runtime.
+///
+/// See [`load_file`] for details.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Io` for I/O errors (these always bubble up).
+#[cfg(feature = "tokio-runtime")]
+pub async fn load_file_async(path: impl AsRef<Path>) -> Result<FileContent> {
+    #[cfg(feature = "mmap")]
+    {
+        let path = path.as_ref().to_path_buf();
+        crate::core::runtime::spawn_blocking(move || load_file(path))
+            .await
+            .map_err(|e| KreuzbergError::Other(format!("File loading task failed: {}", e)))?
+    }
+
+    #[cfg(not(feature = "mmap"))]
+    {
+        read_file_async(path).await.map(FileContent::Owned)
+    }
+}
+
 /// Check if a file exists.
 ///
 /// # Arguments
@@ -174,6 +307,194 @@ pub fn find_files_by_extension(
     )
 }
 
+/// File format written by [`write_result`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResultFileFormat {
+    /// The content as Markdown, with the images saved alongside (`.md`)
+    Markdown,
+    /// The content as extracted, without images (`.txt`)
+    Text,
+    /// The whole result as pretty-printed JSON (`.json`)
+    #[default]
+    Json,
+    /// A standalone HTML page rendered from the Markdown content, with the images saved
+    /// alongside (`.html`, requires the `result-html` feature)
+    Html,
+}
+
+impl ResultFileFormat {
+    /// File extension of the format, without the dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Text => "txt",
+            Self::Json => "json",
+            Self::Html => "html",
+        }
+    }
+}
+
+impl FromStr for ResultFileFormat {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "md" | "markdown" => Ok(Self::Markdown),
+            "txt" | "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "html" | "htm" => Ok(Self::Html),
+            _ => Err(KreuzbergError::validation(format!(
+                "Invalid result file format '{}'. Use 'md', 'txt', 'json' or 'html'",
+                s
+            ))),
+        }
+    }
+}
+
+/// Write an extraction result to a file.
+///
+/// Markdown and HTML output saves `result.images` next to the file, in `<file stem>_images/`,
+/// and points the image links of the content at the saved files: the n-th link with a relative
+/// or `data:` target refers to the n-th image. Links to absolute URLs are kept as they are.
+/// Missing parent directories are created.
+///
+/// # Returns
+///
+/// The written files, the document first.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Io` when a file cannot be written, `Serialization` when JSON
+/// encoding fails and `MissingDependency` for HTML without the `result-html` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::io::{ResultFileFormat, write_result};
+/// use kreuzberg::{ExtractionConfig, extract_file_sync};
+///
+/// # fn example() -> kreuzberg::Result<()> {
+/// let result = extract_file_sync("slides.pptx", None, &ExtractionConfig::default())?;
+/// // Writes out/slides.md and the images to out/slides_images/
+/// write_result(&result, ResultFileFormat::Markdown, "out/slides.md")?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn write_result(
+    result: &ExtractionResult,
+    format: ResultFileFormat,
+    path: impl AsRef<Path>,
+) -> Result<Vec<PathBuf>> {
+    let path = path.as_ref();
+    let mut written = vec![path.to_path_buf()];
+    let document = match format {
+        ResultFileFormat::Json => serde_json::to_string_pretty(result)?,
+        ResultFileFormat::Text => result.content.clone(),
+        ResultFileFormat::Markdown => {
+            let image_links = save_images(result, path, &mut written)?;
+            link_images(&result.content, &image_links)
+        }
+        #[cfg(feature = "result-html")]
+        ResultFileFormat::Html => {
+            let image_links = save_images(result, path, &mut written)?;
+            let markdown = link_images(&result.content, &image_links);
+            markdown_to_html(&markdown, &document_title(result, path))
+        }
+        #[cfg(not(feature = "result-html"))]
+        ResultFileFormat::Html => {
+            return Err(KreuzbergError::MissingDependency(
+                "HTML result files require the `result-html` feature".to_string(),
+            ));
+        }
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, document)?;
+    Ok(written)
+}
+
+/// Save the images of a result to `<file stem>_images/` and return their links relative to `path`.
+fn save_images(result: &ExtractionResult, path: &Path, written: &mut Vec<PathBuf>) -> Result<Vec<String>> {
+    let Some(images) = result.images.as_deref().filter(|images| !images.is_empty()) else {
+        return Ok(Vec::new());
+    };
+    let dir_name = format!("{}_images", file_stem(path));
+    let dir = path.with_file_name(&dir_name);
+    std::fs::create_dir_all(&dir)?;
+
+    let mut links = Vec::with_capacity(images.len());
+    for (position, image) in images.iter().enumerate() {
+        let file_name = format!("image_{}.{}", position + 1, image_extension(&image.format));
+        let image_path = dir.join(&file_name);
+        std::fs::write(&image_path, &image.data)?;
+        written.push(image_path);
+        links.push(format!("{}/{}", dir_name.replace(' ', "%20"), file_name));
+    }
+    Ok(links)
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "document".to_string())
+}
+
+/// `jpeg` -> `jpeg`, `image/png` -> `png`; unknown formats are saved as `.bin`.
+fn image_extension(format: &str) -> String {
+    let subtype = format.rsplit('/').next().unwrap_or(format);
+    let extension: String = subtype
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if extension.is_empty() {
+        "bin".to_string()
+    } else {
+        extension
+    }
+}
+
+/// Point the Markdown image links with a relative or `data:` target at the saved images, in order.
+fn link_images(markdown: &str, image_links: &[String]) -> String {
+    if image_links.is_empty() {
+        return markdown.to_string();
+    }
+    let re = IMAGE_LINK_RE.get_or_init(|| Regex::new(r"!\[([^\]]*)\]\(([^)\s]*)([^)]*)\)").unwrap());
+    let mut next_link = image_links.iter();
+    re.replace_all(markdown, |caps: &Captures<'_>| {
+        if caps[2].contains("://") {
+            return caps[0].to_string();
+        }
+        match next_link.next() {
+            Some(link) => format!("![{}]({}{})", &caps[1], link, &caps[3]),
+            None => caps[0].to_string(),
+        }
+    })
+    .into_owned()
+}
+
+#[cfg(feature = "result-html")]
+fn document_title(result: &ExtractionResult, path: &Path) -> String {
+    result.metadata.title.clone().unwrap_or_else(|| file_stem(path))
+}
+
+#[cfg(feature = "result-html")]
+fn markdown_to_html(markdown: &str, title: &str) -> String {
+    use pulldown_cmark::{Options, Parser, html};
+
+    let mut body = String::with_capacity(markdown.len() * 3 / 2);
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_FOOTNOTES;
+    html::push_html(&mut body, Parser::new_ext(markdown, options));
+    let title = title.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;");
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n</head>\n<body>\n{}</body>\n</html>\n",
+        title, body
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -204,6 +525,49 @@ mod tests {
         assert_eq!(content, b"test content");
     }
 
+    #[test]
+    fn test_load_file() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("test.txt");
+        let mut file = File::create(&file_path).unwrap();
+        file.write_all(b"test content").unwrap();
+
+        let content = load_file(&file_path).unwrap();
+        assert_eq!(&*content, b"test content");
+        assert!(!content.is_mapped());
+        assert_eq!(content.into_vec(), b"test content");
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_load_file_maps_large_files() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("large.bin");
+        let data = vec![b'x'; MMAP_THRESHOLD_BYTES as usize];
+        std::fs::write(&file_path, &data).unwrap();
+
+        let content = load_file(&file_path).unwrap();
+        assert!(content.is_mapped());
+        assert_eq!(content.len(), data.len());
+    }
+
+    #[cfg(feature = "tokio-runtime")]
+    #[tokio::test]
+    async fn test_load_file_async() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("test.txt");
+        std::fs::write(&file_path, b"test content").unwrap();
+
+        let content = load_file_async(&file_path).await.unwrap();
+        assert_eq!(content.as_ref(), b"test content");
+    }
+
+    #[test]
+    fn test_load_file_io_error() {
+        let result = load_file("/nonexistent/file.txt");
+        assert!(matches!(result, Err(KreuzbergError::Io(_))));
+    }
+
     #[test]
     fn test_file_exists() {
         let dir = tempdir().unwrap();
@@ -326,4 +690,109 @@ mod tests {
         assert!(result.is_err());
         assert!(matches!(result.unwrap_err(), KreuzbergError::Io(_)));
     }
+
+    fn result_with_images() -> ExtractionResult {
+        let image = |data: &[u8], format: &str, image_index| crate::types::ExtractedImage {
+            data: data.to_vec(),
+            format: format.to_string(),
+            image_index,
+            page_number: Some(1),
+            width: None,
+            height: None,
+            dpi: None,
+            colorspace: None,
+            bits_per_component: None,
+            is_mask: false,
+            description: None,
+            ocr_result: None,
+        };
+        let content = "# Slide\n\n![logo](slide_1.jpg)\n\n![chart](https://example.com/c.png)\n\n![photo](data:,AAAA)";
+        let images = vec![image(b"jpeg bytes", "jpeg", 0), image(b"png bytes", "image/png", 1)];
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "application/vnd.openxmlformats-officedocument.presentationml.presentation".to_string(),
+            metadata: crate::types::Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: Some(images),
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_write_result_markdown_saves_images() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("out").join("slides.md");
+        let written = write_result(&result_with_images(), ResultFileFormat::Markdown, &path).unwrap();
+
+        let images_dir = dir.path().join("out").join("slides_images");
+        assert_eq!(
+            written,
+            vec![
+                path.clone(),
+                images_dir.join("image_1.jpeg"),
+                images_dir.join("image_2.png")
+            ]
+        );
+        assert_eq!(std::fs::read(images_dir.join("image_2.png")).unwrap(), b"png bytes");
+
+        let markdown = std::fs::read_to_string(&path).unwrap();
+        assert!(markdown.contains("![logo](slides_images/image_1.jpeg)"));
+        assert!(markdown.contains("![chart](https://example.com/c.png)"));
+        assert!(markdown.contains("![photo](slides_images/image_2.png)"));
+    }
+
+    #[test]
+    fn test_write_result_text_and_json() {
+        let dir = tempdir().unwrap();
+        let result = result_with_images();
+
+        let text_path = dir.path().join("slides.txt");
+        assert_eq!(
+            write_result(&result, ResultFileFormat::Text, &text_path).unwrap(),
+            vec![text_path.clone()]
+        );
+        assert_eq!(std::fs::read_to_string(&text_path).unwrap(), result.content);
+        assert!(!dir.path().join("slides_images").exists());
+
+        let json_path = dir.path().join("slides.json");
+        write_result(&result, ResultFileFormat::Json, &json_path).unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&std::fs::read(&json_path).unwrap()).unwrap();
+        assert_eq!(json["content"], result.content);
+    }
+
+    #[test]
+    fn test_write_result_html() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("slides.html");
+        let written = write_result(&result_with_images(), ResultFileFormat::Html, &path);
+
+        #[cfg(feature = "result-html")]
+        {
+            assert_eq!(written.unwrap().len(), 3);
+            let html = std::fs::read_to_string(&path).unwrap();
+            assert!(html.contains("<title>slides</title>"));
+            assert!(html.contains("<h1>Slide</h1>"));
+            assert!(html.contains(r#"src="slides_images/image_1.jpeg""#));
+        }
+        #[cfg(not(feature = "result-html"))]
+        {
+            assert!(matches!(written.unwrap_err(), KreuzbergError::MissingDependency(_)));
+            assert!(!path.exists());
+        }
+    }
+
+    #[test]
+    fn test_result_file_format_from_str() {
+        assert_eq!("MD".parse::<ResultFileFormat>().unwrap(), ResultFileFormat::Markdown);
+        assert_eq!("text".parse::<ResultFileFormat>().unwrap(), ResultFileFormat::Text);
+        assert_eq!(ResultFileFormat::Html.extension(), "html");
+        assert!("docx".parse::<ResultFileFormat>().is_err());
+    }
 }
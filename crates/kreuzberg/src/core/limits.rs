@@ -0,0 +1,265 @@
+//! Enforcement of [`LimitsConfig`] resource limits.
+//!
+//! [`enforce_limits`] runs right after the extractor, before post-processing, so oversized
+//! results never reach chunking, embeddings or the bindings. Extractors that can tell early
+//! that a document is too large (e.g. from the PDF page count, before OCR) call
+//! [`check_page_count`] to fail fast. Archive and email extractors apply
+//! `max_decompressed_bytes` and `max_recursion_depth` themselves.
+
+use crate::core::config::{ExtractionConfig, LimitsConfig};
+use crate::types::{ExtractionResult, ExtractionWarning, WarningStage};
+use crate::{KreuzbergError, Result};
+
+/// Metadata key listing the limits a truncated result exceeded.
+pub(crate) const LIMITS_EXCEEDED_KEY: &str = "limits_exceeded";
+
+/// Fail when a document has more than `max_pages` pages and truncation is disabled.
+#[cfg_attr(not(feature = "pdf"), allow(dead_code))]
+pub(crate) fn check_page_count(page_count: usize, config: &ExtractionConfig) -> Result<()> {
+    if let Some(limits) = &config.limits
+        && !limits.truncate
+        && let Some(max) = limits.max_pages
+        && page_count > max
+    {
+        return Err(KreuzbergError::limit_exceeded(
+            "max_pages",
+            page_count as u64,
+            max as u64,
+        ));
+    }
+    Ok(())
+}
+
+/// Report an exceeded limit: an error unless `limits.truncate` is set, in which case the
+/// limit is added to `metadata.additional["limits_exceeded"]` and reported as a
+/// `limit_exceeded` warning, and the caller truncates.
+pub(crate) fn exceed_limit(
+    result: &mut ExtractionResult,
+    limits: &LimitsConfig,
+    limit: &str,
+    actual: u64,
+    max: u64,
+) -> Result<()> {
+    if !limits.truncate {
+        return Err(KreuzbergError::limit_exceeded(limit, actual, max));
+    }
+
+    let message = format!("Truncated result: {} is {}, maximum is {}", limit, actual, max);
+    tracing::debug!("{}", message);
+    let exceeded = result
+        .metadata
+        .additional
+        .entry(LIMITS_EXCEEDED_KEY.to_string())
+        .or_insert_with(|| serde_json::json!([]));
+    if let Some(list) = exceeded.as_array_mut() {
+        list.push(serde_json::json!(limit));
+    }
+    let warning = ExtractionWarning::new("limit_exceeded", message, WarningStage::Limits);
+    result.warnings.push(warning);
+    Ok(())
+}
+
+/// Apply the page, output size and image limits of `config.limits` to an extracted result.
+pub(crate) fn enforce_limits(result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+    let Some(limits) = &config.limits else {
+        return Ok(());
+    };
+
+    let page_count = page_count(result);
+    if let Some(max) = limits.max_pages
+        && page_count > max
+    {
+        exceed_limit(result, limits, "max_pages", page_count as u64, max as u64)?;
+        truncate_pages(result, max);
+    }
+
+    let image_count = result.images.as_ref().map_or(0, Vec::len);
+    if let Some(max) = limits.max_images
+        && image_count > max
+    {
+        exceed_limit(result, limits, "max_images", image_count as u64, max as u64)?;
+        if let Some(images) = result.images.as_mut() {
+            images.truncate(max);
+        }
+    }
+
+    if let Some(max) = limits.max_output_chars
+        && let Some((cut, _)) = result.content.char_indices().nth(max)
+    {
+        let char_count = max + result.content[cut..].chars().count();
+        exceed_limit(result, limits, "max_output_chars", char_count as u64, max as u64)?;
+        result.content.truncate(cut);
+    }
+
+    Ok(())
+}
+
+fn page_count(result: &ExtractionResult) -> usize {
+    match &result.metadata.pages {
+        Some(pages) => pages.total_count,
+        None => result.pages.as_ref().map_or(0, Vec::len),
+    }
+}
+
+/// Keep the first `max` pages, cutting the content at the start of the next page when page
+/// boundaries are known. `metadata.pages.total_count` keeps the document's page count.
+fn truncate_pages(result: &mut ExtractionResult, max: usize) {
+    if let Some(pages) = result.pages.as_mut() {
+        pages.truncate(max);
+    }
+
+    let Some(structure) = result.metadata.pages.as_mut() else {
+        return;
+    };
+
+    if let Some(boundaries) = structure.boundaries.as_mut() {
+        if let Some(next) = boundaries.get(max)
+            && result.content.is_char_boundary(next.byte_start)
+        {
+            result.content.truncate(next.byte_start);
+        }
+        boundaries.truncate(max);
+    }
+
+    if let Some(pages) = structure.pages.as_mut() {
+        pages.truncate(max);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, PageBoundary, PageStructure, PageUnitType};
+
+    fn result_with(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn config_with(limits: LimitsConfig) -> ExtractionConfig {
+        ExtractionConfig {
+            limits: Some(limits),
+            ..Default::default()
+        }
+    }
+
+    fn three_pages() -> ExtractionResult {
+        let mut result = result_with("one\ntwo\nthree");
+        result.metadata.pages = Some(PageStructure {
+            total_count: 3,
+            unit_type: PageUnitType::Page,
+            boundaries: Some(
+                [(0, 4), (4, 8), (8, 13)]
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, (byte_start, byte_end))| PageBoundary {
+                        byte_start,
+                        byte_end,
+                        page_number: index + 1,
+                    })
+                    .collect(),
+            ),
+            pages: None,
+        });
+        result
+    }
+
+    #[test]
+    fn test_enforce_limits_rejects_too_many_pages() {
+        let config = config_with(LimitsConfig {
+            max_pages: Some(2),
+            ..Default::default()
+        });
+
+        let err = enforce_limits(&mut three_pages(), &config).unwrap_err();
+
+        assert!(matches!(
+            err,
+            KreuzbergError::LimitExceeded { ref limit, actual: 3, max: 2 } if limit == "max_pages"
+        ));
+        assert!(check_page_count(3, &config).is_err());
+        assert!(check_page_count(2, &config).is_ok());
+    }
+
+    #[test]
+    fn test_enforce_limits_truncates_pages_at_boundary() {
+        let config = config_with(LimitsConfig {
+            max_pages: Some(2),
+            truncate: true,
+            ..Default::default()
+        });
+        let mut result = three_pages();
+
+        enforce_limits(&mut result, &config).unwrap();
+
+        assert_eq!(result.content, "one\ntwo\n");
+        let structure = result.metadata.pages.unwrap();
+        assert_eq!(structure.total_count, 3);
+        assert_eq!(structure.boundaries.unwrap().len(), 2);
+        assert_eq!(
+            result.metadata.additional[LIMITS_EXCEEDED_KEY],
+            serde_json::json!(["max_pages"])
+        );
+        assert!(check_page_count(3, &config).is_ok());
+    }
+
+    #[test]
+    fn test_enforce_limits_truncates_output_on_char_boundary() {
+        let config = config_with(LimitsConfig {
+            max_output_chars: Some(4),
+            truncate: true,
+            ..Default::default()
+        });
+        let mut result = result_with("Grüße aus Berlin");
+
+        enforce_limits(&mut result, &config).unwrap();
+
+        assert_eq!(result.content, "Grüß");
+        assert_eq!(
+            result.metadata.additional[LIMITS_EXCEEDED_KEY],
+            serde_json::json!(["max_output_chars"])
+        );
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].code, "limit_exceeded");
+        assert_eq!(result.warnings[0].stage, WarningStage::Limits);
+        assert_eq!(
+            result.warnings[0].message,
+            "Truncated result: max_output_chars is 16, maximum is 4"
+        );
+    }
+
+    #[test]
+    fn test_enforce_limits_reports_output_length() {
+        let config = config_with(LimitsConfig {
+            max_output_chars: Some(5),
+            ..Default::default()
+        });
+
+        let err = enforce_limits(&mut result_with("Grüße aus Berlin"), &config).unwrap_err();
+
+        assert_eq!(err.to_string(), "Limit exceeded: max_output_chars is 16, maximum is 5");
+    }
+
+    #[test]
+    fn test_enforce_limits_without_limits_is_noop() {
+        let mut result = three_pages();
+
+        enforce_limits(&mut result, &ExtractionConfig::default()).unwrap();
+
+        assert_eq!(result.content, "one\ntwo\nthree");
+        assert!(result.metadata.additional.is_empty());
+    }
+}
@@ -0,0 +1,240 @@
+//! Logging configuration and redaction of document content.
+//!
+//! Kreuzberg reports diagnostics as [`tracing`] events, so applications that install their own
+//! subscriber receive them without further setup. Applications without one can call
+//! [`init_logging`] (feature `logging`) to write events to stderr or a file, as text or JSON,
+//! with a level per module. The `KREUZBERG_LOG_*` environment variables feed
+//! [`LogConfig::from_env`], which the CLI uses.
+//!
+//! Extracted text is never written to logs unless [`LogConfig::log_content`] is set: log events
+//! that would include document text wrap it in [`redact`].
+
+use crate::{KreuzbergError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::level_filters::LevelFilter;
+
+/// Environment variable setting [`LogConfig::level`] and [`LogConfig::modules`], in `RUST_LOG`
+/// syntax (`info,kreuzberg::ocr=debug`). Falls back to `RUST_LOG`.
+pub const LOG_LEVEL_ENV: &str = "KREUZBERG_LOG_LEVEL";
+/// Environment variable setting [`LogConfig::format`] (`text` or `json`).
+pub const LOG_FORMAT_ENV: &str = "KREUZBERG_LOG_FORMAT";
+/// Environment variable setting [`LogConfig::file`].
+pub const LOG_FILE_ENV: &str = "KREUZBERG_LOG_FILE";
+/// Environment variable setting [`LogConfig::log_content`] (`1` or `true`).
+pub const LOG_CONTENT_ENV: &str = "KREUZBERG_LOG_CONTENT";
+
+static LOG_CONTENT: AtomicBool = AtomicBool::new(false);
+
+/// Format of log lines written by [`init_logging`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    /// Human-readable lines
+    #[default]
+    Text,
+    /// One JSON object per event
+    Json,
+}
+
+/// Logging configuration for [`init_logging`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogConfig {
+    /// Default level: `off`, `error`, `warn`, `info`, `debug` or `trace`
+    #[serde(default = "default_level")]
+    pub level: String,
+
+    /// Levels for individual modules, e.g. `{"kreuzberg::ocr": "debug"}`
+    #[serde(default)]
+    pub modules: BTreeMap<String, String>,
+
+    /// Output format
+    #[serde(default)]
+    pub format: LogFormat,
+
+    /// Append to this file instead of writing to stderr
+    #[serde(default)]
+    pub file: Option<PathBuf>,
+
+    /// Allow document content in log events
+    #[serde(default)]
+    pub log_content: bool,
+}
+
+fn default_level() -> String {
+    "info".to_string()
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            level: default_level(),
+            modules: BTreeMap::new(),
+            format: LogFormat::default(),
+            file: None,
+            log_content: false,
+        }
+    }
+}
+
+impl LogConfig {
+    /// Read a configuration from the `KREUZBERG_LOG_*` environment variables.
+    ///
+    /// Unset or unrecognized values keep their defaults.
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+
+        if let Ok(directives) = std::env::var(LOG_LEVEL_ENV).or_else(|_| std::env::var("RUST_LOG")) {
+            for directive in directives.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+                match directive.split_once('=') {
+                    Some((module, level)) => {
+                        config.modules.insert(module.to_string(), level.to_string());
+                    }
+                    None => config.level = directive.to_string(),
+                }
+            }
+        }
+
+        if let Ok(format) = std::env::var(LOG_FORMAT_ENV) {
+            match format.to_ascii_lowercase().as_str() {
+                "json" => config.format = LogFormat::Json,
+                "text" => config.format = LogFormat::Text,
+                _ => {}
+            }
+        }
+
+        config.file = std::env::var_os(LOG_FILE_ENV).map(PathBuf::from);
+        config.log_content = std::env::var(LOG_CONTENT_ENV).is_ok_and(|value| value == "1" || value == "true");
+
+        config
+    }
+
+    /// The configured levels as a `tracing` filter (`info,kreuzberg::ocr=debug`).
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` if a level is not recognized.
+    pub fn filter_directives(&self) -> Result<String> {
+        let mut directives = vec![parse_level(&self.level)?.to_string()];
+        for (module, level) in &self.modules {
+            directives.push(format!("{}={}", module, parse_level(level)?));
+        }
+        Ok(directives.join(","))
+    }
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter> {
+    level
+        .parse()
+        .map_err(|_| KreuzbergError::validation(format!("Invalid log level '{}'", level)))
+}
+
+/// Install a global `tracing` subscriber configured by `config`.
+///
+/// Also enables or disables content logging (see [`redact`]).
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` for an invalid level, `KreuzbergError::Io` if the log
+/// file cannot be opened, and `KreuzbergError::Other` if a global subscriber is already set.
+#[cfg(feature = "logging")]
+pub fn init_logging(config: &LogConfig) -> Result<()> {
+    use tracing_subscriber::EnvFilter;
+    use tracing_subscriber::fmt::writer::BoxMakeWriter;
+
+    let filter = EnvFilter::try_new(config.filter_directives()?)
+        .map_err(|e| KreuzbergError::validation(format!("Invalid log filter: {}", e)))?;
+
+    let writer = match &config.file {
+        Some(path) => {
+            let file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+            BoxMakeWriter::new(std::sync::Mutex::new(file))
+        }
+        None => BoxMakeWriter::new(std::io::stderr),
+    };
+
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(writer)
+        .with_ansi(config.file.is_none());
+    let installed = match config.format {
+        LogFormat::Text => builder.try_init(),
+        LogFormat::Json => builder.json().try_init(),
+    };
+    installed.map_err(|e| KreuzbergError::Other(format!("Failed to install log subscriber: {}", e)))?;
+
+    set_content_logging(config.log_content);
+    Ok(())
+}
+
+/// Whether log events may include document content.
+pub fn content_logging_enabled() -> bool {
+    LOG_CONTENT.load(Ordering::Relaxed)
+}
+
+/// Allow or forbid document content in log events; forbidden by default.
+pub fn set_content_logging(enabled: bool) {
+    LOG_CONTENT.store(enabled, Ordering::Relaxed);
+}
+
+/// Document text in a log event, replaced by its length unless content logging is enabled.
+#[derive(Debug, Clone, Copy)]
+pub struct Redacted<'a>(&'a str);
+
+impl fmt::Display for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if content_logging_enabled() {
+            f.write_str(self.0)
+        } else {
+            write!(f, "<redacted {} bytes>", self.0.len())
+        }
+    }
+}
+
+/// Wrap document text for a log event; see [`Redacted`].
+pub fn redact(text: &str) -> Redacted<'_> {
+    Redacted(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_directives_include_module_levels() {
+        let mut config = LogConfig::default();
+        config.modules.insert("kreuzberg::ocr".to_string(), "DEBUG".to_string());
+
+        assert_eq!(config.filter_directives().unwrap(), "info,kreuzberg::ocr=debug");
+    }
+
+    #[test]
+    fn test_filter_directives_reject_unknown_level() {
+        let config = LogConfig {
+            level: "loud".to_string(),
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            config.filter_directives(),
+            Err(KreuzbergError::Validation { .. })
+        ));
+    }
+
+    #[test]
+    fn test_log_config_deserializes_with_defaults() {
+        let config: LogConfig = serde_json::from_str(r#"{"format": "json"}"#).unwrap();
+
+        assert_eq!(config.level, "info");
+        assert_eq!(config.format, LogFormat::Json);
+        assert!(!config.log_content);
+    }
+
+    #[test]
+    fn test_redact_hides_content_by_default() {
+        assert_eq!(redact("secret text").to_string(), "<redacted 11 bytes>");
+    }
+}
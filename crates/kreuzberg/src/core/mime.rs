@@ -2,11 +2,22 @@
 //!
 //! This module provides utilities for detecting MIME types from file extensions
 //! and validating them against supported types.
+//!
+//! Formats the built-in tables don't know, such as proprietary `.rpt` exports, can be mapped
+//! with [`register_mime_mappings`] or `ExtractionConfig::mime_mappings`, by file extension or by
+//! magic bytes. Custom mappings take precedence over the built-in ones.
+//!
+//! [`inspect_content`] and [`inspect_file`] verify the magic bytes of an input against its
+//! declared MIME type and look for polyglot files that are valid in a second format as well,
+//! such as a PDF with a ZIP archive appended.
 
+use crate::core::config::{MimeMappingsConfig, MimeSignature};
+use crate::types::Metadata;
 use crate::{KreuzbergError, Result};
 use once_cell::sync::Lazy;
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::sync::{PoisonError, RwLock};
 
 pub const HTML_MIME_TYPE: &str = "text/html";
 pub const MARKDOWN_MIME_TYPE: &str = "text/markdown";
@@ -19,6 +30,8 @@ pub const LEGACY_POWERPOINT_MIME_TYPE: &str = "application/vnd.ms-powerpoint";
 
 pub const EML_MIME_TYPE: &str = "message/rfc822";
 pub const MSG_MIME_TYPE: &str = "application/vnd.ms-outlook";
+pub const PST_MIME_TYPE: &str = "application/vnd.ms-outlook-pst";
+pub const OST_MIME_TYPE: &str = "application/vnd.ms-outlook-ost";
 pub const JSON_MIME_TYPE: &str = "application/json";
 pub const YAML_MIME_TYPE: &str = "application/x-yaml";
 pub const TOML_MIME_TYPE: &str = "application/toml";
@@ -35,6 +48,18 @@ pub const EXCEL_TEMPLATE_MIME_TYPE: &str = "application/vnd.ms-excel.template.ma
 
 pub const OPENDOC_SPREADSHEET_MIME_TYPE: &str = "application/vnd.oasis.opendocument.spreadsheet";
 
+/// Metadata key holding the declared and detected MIME types when content contradicts the declaration.
+pub const DETECTED_VS_DECLARED_KEY: &str = "detected_vs_declared";
+/// Metadata key listing further formats found in a polyglot file.
+pub const POLYGLOT_FORMATS_KEY: &str = "polyglot_formats";
+
+/// Bytes read from the start of a file by [`inspect_file`].
+const SNIFF_HEAD_LEN: usize = 8 * 1024;
+/// Bytes read from the end of a file: a ZIP end-of-central-directory record with the longest comment.
+const SNIFF_TAIL_LEN: usize = 22 + u16::MAX as usize;
+/// PDF readers accept the `%PDF-` header anywhere in the first KiB.
+const PDF_HEADER_WINDOW: usize = 1024;
+
 /// Extension to MIME type mapping (ported from Python EXT_TO_MIME_TYPE).
 static EXT_TO_MIME: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
     let mut m = HashMap::new();
@@ -91,12 +116,16 @@ static EXT_TO_MIME: Lazy<HashMap<&'static str, &'static str>> = Lazy::new(|| {
 
     m.insert("eml", EML_MIME_TYPE);
     m.insert("msg", MSG_MIME_TYPE);
+    m.insert("pst", PST_MIME_TYPE);
+    m.insert("ost", OST_MIME_TYPE);
 
     m.insert("zip", "application/zip");
     m.insert("tar", "application/x-tar");
     m.insert("gz", "application/gzip");
     m.insert("tgz", "application/x-tar");
     m.insert("7z", "application/x-7z-compressed");
+    m.insert("rar", "application/vnd.rar");
+    m.insert("iso", "application/x-iso9660-image");
 
     m.insert("rst", "text/x-rst");
     m.insert("org", "text/x-org");
@@ -185,6 +214,8 @@ static SUPPORTED_MIME_TYPES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     set.insert(HTML_MIME_TYPE);
     set.insert(EML_MIME_TYPE);
     set.insert(MSG_MIME_TYPE);
+    set.insert(PST_MIME_TYPE);
+    set.insert(OST_MIME_TYPE);
     set.insert(JSON_MIME_TYPE);
     set.insert("text/json");
     set.insert(YAML_MIME_TYPE);
@@ -204,10 +235,269 @@ static SUPPORTED_MIME_TYPES: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     set.insert("application/x-gtar");
     set.insert("application/x-ustar");
     set.insert("application/x-7z-compressed");
+    set.insert("application/vnd.rar");
+    set.insert("application/x-rar-compressed");
+    set.insert("application/x-rar");
+    set.insert("application/x-iso9660-image");
+    set.insert("application/x-cd-image");
 
     set
 });
 
+/// Mappings registered with [`register_mime_mappings`], consulted before the built-in ones.
+static REGISTERED_MAPPINGS: Lazy<RwLock<CustomMappings>> = Lazy::new(|| RwLock::new(CustomMappings::default()));
+
+/// MIME type assigned to content of unknown type, which custom signatures may refine.
+const OCTET_STREAM_MIME_TYPE: &str = "application/octet-stream";
+
+/// Custom extension and magic-byte mappings with decoded signatures.
+#[derive(Debug, Default)]
+struct CustomMappings {
+    extensions: HashMap<String, String>,
+    /// Offset, magic bytes and MIME type, checked in order
+    signatures: Vec<(usize, Vec<u8>, String)>,
+}
+
+impl CustomMappings {
+    fn from_config(config: &MimeMappingsConfig) -> Result<Self> {
+        let mut mappings = Self::default();
+        mappings.add(config)?;
+        Ok(mappings)
+    }
+
+    fn add(&mut self, config: &MimeMappingsConfig) -> Result<()> {
+        validate_mappings(config).map_err(KreuzbergError::validation)?;
+
+        for (extension, mime_type) in &config.extensions {
+            let extension = normalize_extension(extension);
+            self.extensions.insert(extension, mime_type.clone());
+        }
+        for signature in &config.signatures {
+            let magic = decode_magic(&signature.magic).unwrap_or_default();
+            let mime_type = signature.mime_type.clone();
+            self.signatures.push((signature.offset, magic, mime_type));
+        }
+        Ok(())
+    }
+
+    fn by_extension(&self, path: &Path) -> Option<&str> {
+        let extension = path.extension()?.to_str()?;
+        self.extensions.get(&normalize_extension(extension)).map(String::as_str)
+    }
+
+    fn by_content(&self, content: &[u8]) -> Option<&str> {
+        self.signatures
+            .iter()
+            .find(|(offset, magic, _)| content.get(*offset..).is_some_and(|rest| rest.starts_with(magic)))
+            .map(|(_, _, mime_type)| mime_type.as_str())
+    }
+
+    fn maps_to(&self, mime_type: &str) -> bool {
+        self.extensions.values().any(|mapped| mapped == mime_type)
+            || self.signatures.iter().any(|(_, _, mapped)| mapped == mime_type)
+    }
+}
+
+fn registered_mappings() -> std::sync::RwLockReadGuard<'static, CustomMappings> {
+    REGISTERED_MAPPINGS.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn normalize_extension(extension: &str) -> String {
+    extension.trim().trim_start_matches('.').to_lowercase()
+}
+
+/// Decode magic bytes written as hexadecimal digits, ignoring spaces.
+pub(crate) fn decode_magic(hex: &str) -> Option<Vec<u8>> {
+    let digits: Vec<u8> = hex.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+    if digits.is_empty() || !digits.len().is_multiple_of(2) || !digits.iter().all(u8::is_ascii_hexdigit) {
+        return None;
+    }
+    digits
+        .chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+/// Check that every mapping names a `type/subtype` MIME type and decodable magic bytes.
+pub(crate) fn validate_mappings(config: &MimeMappingsConfig) -> std::result::Result<(), String> {
+    let is_mime_type = |mime_type: &str| {
+        let (kind, subtype) = mime_type.split_once('/').unwrap_or_default();
+        !kind.trim().is_empty() && !subtype.trim().is_empty()
+    };
+
+    for (extension, mime_type) in &config.extensions {
+        if normalize_extension(extension).is_empty() {
+            return Err("MIME mapping extensions must not be empty".to_string());
+        }
+        if !is_mime_type(mime_type) {
+            return Err(format!("'{}' (for .{}) is not a MIME type", mime_type, extension));
+        }
+    }
+    for signature in &config.signatures {
+        if decode_magic(&signature.magic).is_none() {
+            return Err(format!(
+                "Magic bytes '{}' are not hexadecimal byte values",
+                signature.magic
+            ));
+        }
+        if !is_mime_type(&signature.mime_type) {
+            return Err(format!("'{}' is not a MIME type", signature.mime_type));
+        }
+    }
+    Ok(())
+}
+
+/// Register custom extension and magic-byte mappings for the whole process.
+///
+/// Registered mappings take precedence over the built-in detection in [`detect_mime_type`],
+/// [`detect_mime_type_from_bytes`] and the extraction functions, including for archive members
+/// and email attachments. Their MIME types pass [`validate_mime_type`], so they can name a
+/// format handled by a plugin extractor as well as a built-in one such as `text/plain`.
+/// Later registrations of an extension replace earlier ones; signatures are checked in
+/// registration order.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` for empty extensions, malformed MIME types and magic
+/// bytes that are not hexadecimal.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::core::mime::{detect_mime_type, detect_mime_type_from_bytes, register_mime_mappings};
+/// use kreuzberg::{MimeMappingsConfig, MimeSignature};
+///
+/// let mut mappings = MimeMappingsConfig::default();
+/// mappings.extensions.insert("rpt".to_string(), "text/plain".to_string());
+/// mappings.signatures.push(MimeSignature {
+///     magic: "41 43 4d 45 44 41 54".to_string(),
+///     offset: 0,
+///     mime_type: "text/csv".to_string(),
+/// });
+/// register_mime_mappings(&mappings).unwrap();
+///
+/// assert_eq!(detect_mime_type("ledger.rpt", false).unwrap(), "text/plain");
+/// assert_eq!(detect_mime_type_from_bytes(b"ACMEDAT\x01\x02").unwrap(), "text/csv");
+/// ```
+pub fn register_mime_mappings(mappings: &MimeMappingsConfig) -> Result<()> {
+    REGISTERED_MAPPINGS
+        .write()
+        .map_err(|e| KreuzbergError::LockPoisoned(format!("MIME mappings lock poisoned: {}", e)))?
+        .add(mappings)
+}
+
+/// Map files with `extension` (with or without the leading dot) to `mime_type`.
+///
+/// See [`register_mime_mappings`].
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` for an empty extension or a malformed MIME type.
+pub fn register_extension(extension: &str, mime_type: &str) -> Result<()> {
+    let mut mappings = MimeMappingsConfig::default();
+    mappings.extensions.insert(extension.to_string(), mime_type.to_string());
+    register_mime_mappings(&mappings)
+}
+
+/// Map content carrying `magic` at byte `offset` to `mime_type`.
+///
+/// See [`register_mime_mappings`].
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` for empty magic bytes or a malformed MIME type.
+pub fn register_signature(magic: &[u8], offset: usize, mime_type: &str) -> Result<()> {
+    let magic: String = magic.iter().map(|byte| format!("{:02x}", byte)).collect();
+    let mut mappings = MimeMappingsConfig::default();
+    mappings.signatures.push(MimeSignature {
+        magic,
+        offset,
+        mime_type: mime_type.to_string(),
+    });
+    register_mime_mappings(&mappings)
+}
+
+/// Remove all mappings registered with [`register_mime_mappings`].
+pub fn clear_mime_mappings() {
+    let mut registered = REGISTERED_MAPPINGS.write().unwrap_or_else(PoisonError::into_inner);
+    *registered = CustomMappings::default();
+}
+
+/// MIME type of an input file.
+///
+/// A declared MIME type is validated; otherwise the file is matched against `mappings` (from
+/// `ExtractionConfig::mime_mappings`), the registered mappings and finally the built-in
+/// detection. Custom signatures are checked against the first 8 KiB of the file.
+pub(crate) fn resolve_file_mime(
+    path: &Path,
+    declared: Option<&str>,
+    mappings: Option<&MimeMappingsConfig>,
+) -> Result<String> {
+    let custom = match mappings {
+        Some(mappings) => CustomMappings::from_config(mappings)?,
+        None => CustomMappings::default(),
+    };
+    if let Some(declared) = declared {
+        if custom.maps_to(declared) {
+            return Ok(declared.to_string());
+        }
+        return validate_mime_type(declared);
+    }
+
+    if let Some(mime_type) = custom.by_extension(path) {
+        return Ok(mime_type.to_string());
+    }
+    let registered = registered_mappings();
+    if let Some(mime_type) = registered.by_extension(path) {
+        return Ok(mime_type.to_string());
+    }
+    if !custom.signatures.is_empty() || !registered.signatures.is_empty() {
+        let head = read_head(path)?;
+        if let Some(mime_type) = custom.by_content(&head).or_else(|| registered.by_content(&head)) {
+            return Ok(mime_type.to_string());
+        }
+    }
+    drop(registered);
+
+    detect_or_validate(Some(path), None)
+}
+
+/// MIME type of input bytes declared as `declared`.
+///
+/// Content declared as `application/octet-stream` is matched against the custom signatures of
+/// `mappings` and the registered mappings; MIME types named by either are accepted as declared.
+pub(crate) fn resolve_bytes_mime(
+    content: &[u8],
+    declared: &str,
+    mappings: Option<&MimeMappingsConfig>,
+) -> Result<String> {
+    let custom = match mappings {
+        Some(mappings) => CustomMappings::from_config(mappings)?,
+        None => CustomMappings::default(),
+    };
+    if custom.maps_to(declared) {
+        return Ok(declared.to_string());
+    }
+    if declared == OCTET_STREAM_MIME_TYPE {
+        let registered = registered_mappings();
+        if let Some(mime_type) = custom.by_content(content).or_else(|| registered.by_content(content)) {
+            return Ok(mime_type.to_string());
+        }
+    }
+
+    validate_mime_type(declared)
+}
+
+fn read_head(path: &Path) -> Result<Vec<u8>> {
+    use std::io::Read;
+
+    let mut head = Vec::with_capacity(SNIFF_HEAD_LEN);
+    std::fs::File::open(path)?
+        .take(SNIFF_HEAD_LEN as u64)
+        .read_to_end(&mut head)?;
+    Ok(head)
+}
+
 /// Detect MIME type from a file path.
 ///
 /// Uses file extension to determine MIME type. Falls back to `mime_guess` crate
@@ -236,6 +526,10 @@ pub fn detect_mime_type(path: impl AsRef<Path>, check_exists: bool) -> Result<St
         )));
     }
 
+    if let Some(mime_type) = registered_mappings().by_extension(path) {
+        return Ok(mime_type.to_string());
+    }
+
     let extension = path.extension().and_then(|ext| ext.to_str()).map(|s| s.to_lowercase());
 
     if let Some(ext) = &extension
@@ -276,7 +570,7 @@ pub fn detect_mime_type(path: impl AsRef<Path>, check_exists: bool) -> Result<St
 ///
 /// Returns `KreuzbergError::UnsupportedFormat` if not supported.
 pub fn validate_mime_type(mime_type: &str) -> Result<String> {
-    if SUPPORTED_MIME_TYPES.contains(mime_type) {
+    if SUPPORTED_MIME_TYPES.contains(mime_type) || registered_mappings().maps_to(mime_type) {
         return Ok(mime_type.to_string());
     }
 
@@ -329,6 +623,20 @@ pub fn detect_or_validate(path: Option<&Path>, mime_type: Option<&str>) -> Resul
 ///
 /// Returns `KreuzbergError::UnsupportedFormat` if MIME type cannot be determined.
 pub fn detect_mime_type_from_bytes(content: &[u8]) -> Result<String> {
+    if let Some(mime_type) = registered_mappings().by_content(content) {
+        return Ok(mime_type.to_string());
+    }
+
+    // Outlook stores start with "!BDN"; the client magic tells OST ("SO") from PST ("SM").
+    if content.starts_with(b"!BDN") && content.len() >= 10 {
+        let mime_type = if content[8..10] == *b"SO" {
+            OST_MIME_TYPE
+        } else {
+            PST_MIME_TYPE
+        };
+        return Ok(mime_type.to_string());
+    }
+
     if let Some(kind) = infer::get(content) {
         let mime_type = kind.mime_type();
 
@@ -366,6 +674,183 @@ pub fn detect_mime_type_from_bytes(content: &[u8]) -> Result<String> {
     ))
 }
 
+/// Result of checking content against its declared MIME type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MimeInspection {
+    /// The MIME type given by the caller or implied by the file extension
+    pub declared: String,
+    /// The MIME type identified from magic bytes, if it contradicts `declared`
+    pub detected: Option<String>,
+    /// Other formats also present in the content, e.g. a ZIP archive appended to a PDF
+    pub polyglot: Vec<String>,
+}
+
+impl MimeInspection {
+    /// Whether the content matches its declared type and contains no other format.
+    pub fn is_consistent(&self) -> bool {
+        self.detected.is_none() && self.polyglot.is_empty()
+    }
+
+    /// Fail unless [`is_consistent`](Self::is_consistent) holds.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` describing the mismatch or the polyglot formats.
+    pub fn ensure_consistent(&self) -> Result<()> {
+        if let Some(detected) = &self.detected {
+            return Err(KreuzbergError::validation(format!(
+                "Content is {} but was declared as {}",
+                detected, self.declared
+            )));
+        }
+        if !self.polyglot.is_empty() {
+            return Err(KreuzbergError::validation(format!(
+                "{} content also contains {}",
+                self.declared,
+                self.polyglot.join(", ")
+            )));
+        }
+        Ok(())
+    }
+
+    /// Report mismatches under [`DETECTED_VS_DECLARED_KEY`] and [`POLYGLOT_FORMATS_KEY`].
+    pub fn record(&self, metadata: &mut Metadata) {
+        if let Some(detected) = &self.detected {
+            metadata.additional.insert(
+                DETECTED_VS_DECLARED_KEY.to_string(),
+                serde_json::json!({ "declared": self.declared, "detected": detected }),
+            );
+        }
+        if !self.polyglot.is_empty() {
+            metadata
+                .additional
+                .insert(POLYGLOT_FORMATS_KEY.to_string(), serde_json::json!(self.polyglot));
+        }
+    }
+}
+
+/// Check content against its declared MIME type.
+///
+/// Magic bytes naming another format are reported in [`MimeInspection::detected`]. Formats
+/// sharing a container are not mismatches: OOXML, ODF and EPUB are ZIP archives, legacy Office
+/// files, Outlook messages and encrypted OOXML are OLE compound files, and images may be declared
+/// as any other image type. Text formats have no magic bytes and are never reported.
+///
+/// [`MimeInspection::polyglot`] lists a PDF header hidden in the first KiB of another binary
+/// format and a ZIP archive appended to a file that is not itself ZIP-based.
+pub fn inspect_content(content: &[u8], declared: &str) -> MimeInspection {
+    let tail_start = content.len().saturating_sub(SNIFF_TAIL_LEN);
+    inspect(declared, content, &content[tail_start..])
+}
+
+/// Check a file against its declared MIME type, reading only its first 8 KiB and last 64 KiB.
+///
+/// See [`inspect_content`].
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Io` if the file cannot be read.
+pub fn inspect_file(path: impl AsRef<Path>, declared: &str) -> Result<MimeInspection> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = std::fs::File::open(path.as_ref())?;
+    let len = usize::try_from(file.metadata()?.len()).unwrap_or(usize::MAX);
+
+    let mut head = vec![0; len.min(SNIFF_HEAD_LEN)];
+    file.read_exact(&mut head)?;
+
+    let mut tail = vec![0; len.min(SNIFF_TAIL_LEN)];
+    file.seek(SeekFrom::End(-(tail.len() as i64)))?;
+    file.read_exact(&mut tail)?;
+
+    Ok(inspect(declared, &head, &tail))
+}
+
+fn inspect(declared: &str, head: &[u8], tail: &[u8]) -> MimeInspection {
+    let sniffed = sniff(head);
+    let actual = sniffed.unwrap_or(declared);
+
+    let pdf_window = &head[..head.len().min(PDF_HEADER_WINDOW)];
+
+    let mut polyglot = Vec::new();
+    if sniffed.is_some() && actual != PDF_MIME_TYPE && memchr::memmem::find(pdf_window, b"%PDF-").is_some() {
+        polyglot.push(PDF_MIME_TYPE.to_string());
+    }
+    if signature_family(actual) != Some("zip") && has_zip_trailer(tail) {
+        polyglot.push("application/zip".to_string());
+    }
+
+    MimeInspection {
+        declared: declared.to_string(),
+        detected: sniffed
+            .filter(|detected| !same_signature(declared, detected))
+            .map(str::to_string),
+        polyglot,
+    }
+}
+
+/// Binary format named by the magic bytes at the start of the content.
+fn sniff(head: &[u8]) -> Option<&'static str> {
+    if head.starts_with(b"!BDN") {
+        return Some(PST_MIME_TYPE);
+    }
+
+    infer::get(head)
+        .filter(|kind| kind.matcher_type() != infer::MatcherType::Text)
+        .map(|kind| kind.mime_type())
+}
+
+fn same_signature(declared: &str, detected: &str) -> bool {
+    declared == detected
+        || signature_family(declared).is_some_and(|family| signature_family(detected) == Some(family))
+        || (is_ooxml(declared) && signature_family(detected) == Some("ole"))
+}
+
+/// Password-protected OOXML files are OLE compound files rather than ZIP archives.
+fn is_ooxml(mime_type: &str) -> bool {
+    mime_type.starts_with("application/vnd.openxmlformats-officedocument.")
+        || mime_type.starts_with("application/vnd.ms-excel.")
+}
+
+/// Formats that magic bytes cannot tell apart because they share a container.
+fn signature_family(mime_type: &str) -> Option<&'static str> {
+    match mime_type {
+        SVG_MIME_TYPE => None,
+        "application/zip" | "application/x-zip-compressed" | "application/epub+zip" | "application/java-archive" => {
+            Some("zip")
+        }
+        LEGACY_WORD_MIME_TYPE
+        | LEGACY_POWERPOINT_MIME_TYPE
+        | EXCEL_BINARY_MIME_TYPE
+        | MSG_MIME_TYPE
+        | "application/x-ole-storage"
+        | "application/x-cfb" => Some("ole"),
+        "application/x-tar"
+        | "application/tar"
+        | "application/x-gtar"
+        | "application/x-ustar"
+        | "application/gzip"
+        | "application/x-gzip" => Some("tar"),
+        PST_MIME_TYPE | OST_MIME_TYPE => Some("outlook"),
+        _ if mime_type.starts_with("application/vnd.openxmlformats-officedocument.")
+            || mime_type.starts_with("application/vnd.oasis.opendocument.")
+            || mime_type.starts_with("application/vnd.ms-excel.") =>
+        {
+            Some("zip")
+        }
+        _ if mime_type.starts_with("image/") => Some("image"),
+        _ => None,
+    }
+}
+
+/// Whether `tail` ends with a ZIP end-of-central-directory record.
+fn has_zip_trailer(tail: &[u8]) -> bool {
+    memchr::memmem::rfind_iter(tail, b"PK\x05\x06").any(|pos| match tail.get(pos + 20..pos + 22) {
+        Some(&[low, high]) => pos + 22 + usize::from(u16::from_le_bytes([low, high])) == tail.len(),
+        _ => false,
+    })
+}
+
 /// Get file extensions for a given MIME type.
 ///
 /// Returns all known file extensions that map to the specified MIME type.
@@ -390,7 +875,12 @@ pub fn detect_mime_type_from_bytes(content: &[u8]) -> Result<String> {
 /// assert!(doc_extensions.contains(&"docx".to_string()));
 /// ```
 pub fn get_extensions_for_mime(mime_type: &str) -> Result<Vec<String>> {
-    let mut extensions = Vec::new();
+    let mut extensions: Vec<String> = registered_mappings()
+        .extensions
+        .iter()
+        .filter(|(_, mime)| *mime == mime_type)
+        .map(|(ext, _)| ext.clone())
+        .collect();
 
     for (ext, mime) in EXT_TO_MIME.iter() {
         if *mime == mime_type {
@@ -515,7 +1005,12 @@ mod tests {
     fn test_detect_mime_type_email() {
         let dir = tempdir().unwrap();
 
-        let test_cases = vec![("test.eml", EML_MIME_TYPE), ("test.msg", MSG_MIME_TYPE)];
+        let test_cases = vec![
+            ("test.eml", EML_MIME_TYPE),
+            ("test.msg", MSG_MIME_TYPE),
+            ("test.pst", PST_MIME_TYPE),
+            ("test.ost", OST_MIME_TYPE),
+        ];
 
         for (filename, expected_mime) in test_cases {
             let file_path = dir.path().join(filename);
@@ -525,6 +1020,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_detect_mime_type_from_bytes_outlook_store() {
+        assert_eq!(
+            detect_mime_type_from_bytes(b"!BDN\0\0\0\0SM\x17\0").unwrap(),
+            PST_MIME_TYPE
+        );
+        assert_eq!(
+            detect_mime_type_from_bytes(b"!BDN\0\0\0\0SO\x24\0").unwrap(),
+            OST_MIME_TYPE
+        );
+    }
+
     #[test]
     fn test_validate_mime_type_exact() {
         assert!(validate_mime_type("application/pdf").is_ok());
@@ -602,4 +1109,125 @@ mod tests {
         let mime2 = detect_mime_type(&file_path2, true).unwrap();
         assert_eq!(mime2, EXCEL_MIME_TYPE);
     }
+
+    fn empty_zip() -> Vec<u8> {
+        let mut zip = b"PK\x05\x06".to_vec();
+        zip.resize(22, 0);
+        zip
+    }
+
+    #[test]
+    fn test_inspect_content_reports_mismatched_magic_bytes() {
+        let inspection = inspect_content(&empty_zip(), PDF_MIME_TYPE);
+
+        assert_eq!(inspection.detected.as_deref(), Some("application/zip"));
+        assert!(inspection.polyglot.is_empty());
+        let err = inspection.ensure_consistent().unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+
+        let mut metadata = Metadata::default();
+        inspection.record(&mut metadata);
+        assert_eq!(
+            metadata.additional[DETECTED_VS_DECLARED_KEY],
+            serde_json::json!({ "declared": PDF_MIME_TYPE, "detected": "application/zip" })
+        );
+    }
+
+    #[test]
+    fn test_inspect_content_accepts_shared_signatures() {
+        assert!(inspect_content(&empty_zip(), DOCX_MIME_TYPE).is_consistent());
+        assert!(inspect_content(b"\xFF\xD8\xFF\xE0 jpeg", "image/png").is_consistent());
+        assert!(inspect_content(b"Notes on the %PDF- header", PLAIN_TEXT_MIME_TYPE).is_consistent());
+    }
+
+    #[test]
+    fn test_inspect_content_detects_zip_appended_to_pdf() {
+        let mut content = b"%PDF-1.7\n1 0 obj\n<<>>\nendobj\n%%EOF\n".to_vec();
+        content.extend(empty_zip());
+
+        let inspection = inspect_content(&content, PDF_MIME_TYPE);
+
+        assert_eq!(inspection.detected, None);
+        assert_eq!(inspection.polyglot, vec!["application/zip"]);
+    }
+
+    #[test]
+    fn test_inspect_content_detects_pdf_header_in_image() {
+        let mut content = b"\xFF\xD8\xFF\xE0".to_vec();
+        content.extend(b"%PDF-1.4\n");
+
+        let inspection = inspect_content(&content, "image/jpeg");
+
+        assert_eq!(inspection.polyglot, vec![PDF_MIME_TYPE]);
+    }
+
+    #[test]
+    fn test_inspect_file_reads_head_and_tail() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("large.pdf");
+        let mut content = b"%PDF-1.7\n".to_vec();
+        content.resize(200_000, b' ');
+        content.extend(empty_zip());
+        std::fs::write(&file_path, &content).unwrap();
+
+        let inspection = inspect_file(&file_path, PDF_MIME_TYPE).unwrap();
+
+        assert_eq!(inspection.detected, None);
+        assert_eq!(inspection.polyglot, vec!["application/zip"]);
+    }
+
+    #[test]
+    fn test_register_mime_mappings() {
+        register_extension(".KzbRpt", "application/x-kzb-report").unwrap();
+        register_signature(b"KZBSIG", 2, "text/csv").unwrap();
+
+        assert_eq!(
+            detect_mime_type("ledger.kzbrpt", false).unwrap(),
+            "application/x-kzb-report"
+        );
+        assert_eq!(detect_mime_type_from_bytes(b"\0\0KZBSIG;1;2").unwrap(), "text/csv");
+        assert_eq!(
+            validate_mime_type("application/x-kzb-report").unwrap(),
+            "application/x-kzb-report"
+        );
+        assert_eq!(
+            get_extensions_for_mime("application/x-kzb-report").unwrap(),
+            vec!["kzbrpt"]
+        );
+
+        assert!(register_extension("", "text/plain").is_err());
+        assert!(register_extension("kzbbad", "plain").is_err());
+        assert!(register_signature(b"", 0, "text/plain").is_err());
+    }
+
+    #[test]
+    fn test_resolve_with_configured_mappings() {
+        let mut mappings = MimeMappingsConfig::default();
+        mappings.extensions.insert("kzbdat".to_string(), "text/csv".to_string());
+        mappings.signatures.push(MimeSignature {
+            magic: "4b 5a 42 43".to_string(),
+            offset: 0,
+            mime_type: "text/plain".to_string(),
+        });
+
+        let dir = tempdir().unwrap();
+        let export = dir.path().join("export.kzbdat");
+        let sniffed = dir.path().join("export.kzbunknown");
+        std::fs::write(&export, "a,b\n").unwrap();
+        std::fs::write(&sniffed, "KZBC report").unwrap();
+
+        assert_eq!(resolve_file_mime(&export, None, Some(&mappings)).unwrap(), "text/csv");
+        assert_eq!(
+            resolve_file_mime(&sniffed, None, Some(&mappings)).unwrap(),
+            "text/plain"
+        );
+        assert!(resolve_file_mime(&sniffed, None, None).is_err());
+
+        let resolved = resolve_bytes_mime(b"KZBC report", "application/octet-stream", Some(&mappings));
+        assert_eq!(resolved.unwrap(), "text/plain");
+        assert!(resolve_bytes_mime(b"KZBC report", "application/octet-stream", None).is_err());
+
+        mappings.signatures[0].magic = "4b 5z".to_string();
+        assert!(validate_mappings(&mappings).is_err());
+    }
 }
@@ -8,6 +8,10 @@
 //!
 //! The core module is responsible for:
 //! - **Entry Points**: Main `extract_file()` and `extract_bytes()` functions
+//! - **Directories**: `extract_directory()` for walking and ingesting whole corpora
+//! - **Datasets**: `DatasetWriter` for JSON Lines and Parquet files of results
+//! - **Events**: Page and section events of a result (`ExtractionResult::into_events`)
+//! - **Serialization**: Versioned MessagePack/CBOR encoding of results (`ExtractionResult::to_bytes`)
 //! - **Registry**: Mapping MIME types to extractors with priority-based selection
 //! - **MIME Detection**: Detecting and validating MIME types from files and extensions
 //! - **Pipeline**: Orchestrating post-processing steps (chunking, quality, etc.)
@@ -30,18 +34,77 @@
 
 #[cfg(feature = "tokio-runtime")]
 pub(crate) mod batch_mode;
+#[cfg(feature = "tokio-runtime")]
+pub mod checkpoint;
 pub mod config;
+pub(crate) mod config_validation;
+pub mod context;
+pub mod dataset;
+pub(crate) mod dedup;
+#[cfg(feature = "tokio-runtime")]
+pub mod directory;
+pub mod events;
 pub mod extractor;
+#[cfg(feature = "tokio-runtime")]
+pub mod governor;
 pub mod io;
+pub(crate) mod limits;
+pub mod logging;
 pub mod mime;
 pub mod pipeline;
+pub(crate) mod provenance;
+pub(crate) mod recovery;
+pub mod reload;
+pub(crate) mod retry;
+pub(crate) mod review;
+pub mod runtime;
+pub mod scoped;
+pub mod scratch;
+pub mod serialization;
+pub mod session;
+#[cfg(any(feature = "api", feature = "mcp"))]
+pub mod shutdown;
+pub(crate) mod stats;
 
 pub use config::{
-    ChunkingConfig, ExtractionConfig, ImageExtractionConfig, LanguageDetectionConfig, OcrConfig, TokenReductionConfig,
+    ChunkingConfig, DedupConfig, Device, DeviceConfig, ExtractionConfig, HeaderFooterConfig, ImageExtractionConfig,
+    LanguageDetectionConfig, LimitsConfig, LineRepairConfig, MimeMappingsConfig, MimeSignature, NormalizationForm,
+    OcrConfig, QualityGateAction, QualityGateConfig, RetryConfig, ReviewConfig, StopwordsConfig, StopwordsMode,
+    StructuredConfig, TaggerConfig, TextMetricsConfig, TextNormalizationConfig, TokenReductionConfig, ValidationConfig,
+    ValidationMode,
 };
 
+#[cfg(feature = "tokio-runtime")]
+pub use checkpoint::{batch_extract_file_resumable, batch_extract_file_resumable_sync};
+#[cfg(feature = "email")]
+pub use config::EmailConfig;
+#[cfg(any(feature = "office", feature = "excel"))]
+pub use config::OfficeConfig;
 #[cfg(feature = "pdf")]
 pub use config::PdfConfig;
+#[cfg(feature = "archives")]
+pub use config::{ArchiveConfig, ArchiveMemberFilter};
+#[cfg(feature = "office")]
+pub use config::{PptxConfig, RevisionsConfig};
+#[cfg(feature = "xml")]
+pub use config::{XmlConfig, XmlDocumentType, XmlSection};
+pub use dataset::{DatasetFormat, DatasetWriter};
+#[cfg(feature = "tokio-runtime")]
+pub use directory::{
+    DirectoryItem, DirectoryManifest, DirectoryOptions, DirectoryStream, FileChange, ManifestEntry, extract_directory,
+};
+pub use events::{DocumentSection, ExtractionEvent, ExtractionEvents};
 #[cfg(feature = "tokio-runtime")]
 pub use extractor::{batch_extract_bytes, batch_extract_file};
 pub use extractor::{extract_bytes, extract_file};
+#[cfg(feature = "tokio-runtime")]
+pub use governor::{GovernorLoad, JobPriority, QueuedJobs, configure_max_concurrent_jobs, with_priority};
+#[cfg(feature = "logging")]
+pub use logging::init_logging;
+pub use logging::{LogConfig, LogFormat};
+pub use reload::{ConfigChange, ConfigHandle, ConfigUpdate};
+pub use runtime::{ThreadPoolConfig, configure_thread_pools};
+pub use scoped::{Extractor, ExtractorBuilder};
+pub use scratch::{TempDirConfig, configure_temp_dir};
+pub use serialization::{RESULT_SCHEMA_VERSION, ResultFormat};
+pub use session::ExtractionSession;
@@ -4,17 +4,27 @@
 //! quality processing, chunking, and custom hooks in the correct order.
 
 use crate::core::config::ExtractionConfig;
-use crate::plugins::ProcessingStage;
-use crate::types::ExtractionResult;
+use crate::core::review::{record_findings, record_validator_failure};
+use crate::core::stats::{StageTimer, record_timing};
+use crate::plugins::registry::PluginRegistries;
+use crate::types::{ExtractionResult, ExtractionWarning, WarningStage};
 use crate::{KreuzbergError, Result};
 
 /// Run the post-processing pipeline on an extraction result.
 ///
 /// Executes post-processing in the following order:
-/// 1. Post-Processors - Execute by stage (Early, Middle, Late) to modify/enhance the result
-/// 2. Quality Processing - Text cleaning and quality scoring
-/// 3. Chunking - Text splitting if enabled
-/// 4. Validators - Run validation hooks on the processed result (can fail fast)
+/// 1. Image Descriptions - Describe extracted images if `images.describer` is configured
+/// 2. Post-Processors - Execute in the order of the execution plan (stage, priority and declared
+///    ordering constraints) to modify/enhance the result
+/// 3. Quality Processing - Text cleaning and quality scoring
+/// 4. Chunking - Text splitting if enabled
+/// 5. Quality Gate - Garble scoring of OCR output if `quality_gate` is configured
+/// 6. Validators - Run validation hooks on the processed result and collect their findings in
+///    `metadata.findings` (can fail fast, annotate the result when `validation.mode` is
+///    `annotate`, or flag it for human review when `review.flag_validator_failures` is set)
+///
+/// With `config.collect_timings`, the durations of steps 1-2, chunking and language detection
+/// are recorded in `metadata.timings`.
 ///
 /// # Arguments
 ///
@@ -27,101 +37,162 @@ use crate::{KreuzbergError, Result};
 ///
 /// # Errors
 ///
-/// - Validator errors bubble up immediately
+/// - Validator errors and error findings bubble up immediately, unless annotate mode records them
+///   in `metadata.findings` or `review.flag_validator_failures` records them in `metadata.review`
+/// - A configured image describer that is not registered is an error
 /// - Post-processor errors are caught and recorded in metadata
 /// - System errors (IO, RuntimeError equivalents) always bubble up
+pub async fn run_pipeline(result: ExtractionResult, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    run_pipeline_with_registries(result, config, &PluginRegistries::global()).await
+}
+
+/// Register the built-in post-processors (header/footer removal, keywords, entities, templates,
+/// language detection, chunking, normalization, quality, text metrics, custom taggers, token reduction) into the
+/// global registry.
+///
+/// Registration is idempotent and errors are ignored; a processor that fails to register
+/// is simply skipped by the pipeline.
+pub(crate) fn ensure_builtin_post_processors() {
+    {
+        let registry = crate::plugins::registry::get_post_processor_registry();
+        if let Ok(mut reg) = registry.write() {
+            let _ = reg.register(std::sync::Arc::new(crate::text::HeaderFooterProcessor), 60);
+            let _ = reg.register(std::sync::Arc::new(crate::text::LineRepairProcessor), 35);
+            let _ = reg.register(std::sync::Arc::new(crate::text::TextMetricsProcessor), 10);
+            let _ = reg.register(std::sync::Arc::new(crate::text::TaggerProcessor), 40);
+        }
+    }
+
+    #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+    {
+        let _ = crate::keywords::ensure_initialized();
+    }
+
+    #[cfg(feature = "entities")]
+    {
+        let _ = crate::entities::ensure_initialized();
+    }
+
+    #[cfg(feature = "templates")]
+    {
+        let _ = crate::templates::ensure_initialized();
+    }
+
+    #[cfg(feature = "language-detection")]
+    {
+        let _ = crate::language_detection::ensure_initialized();
+    }
+
+    #[cfg(feature = "chunking")]
+    {
+        let _ = crate::chunking::ensure_initialized();
+    }
+
+    #[cfg(feature = "quality")]
+    {
+        let registry = crate::plugins::registry::get_post_processor_registry();
+        if let Ok(mut reg) = registry.write() {
+            let _ = reg.register(std::sync::Arc::new(crate::text::TextNormalizationProcessor), 70);
+            let _ = reg.register(std::sync::Arc::new(crate::text::QualityProcessor), 30);
+            let _ = reg.register(std::sync::Arc::new(crate::text::TokenReductionProcessor), 50);
+        }
+    }
+}
+
+/// Register the built-in validators (quality gate) into the global registry.
+///
+/// Like [`ensure_builtin_post_processors`], registration is idempotent and errors are ignored.
+pub(crate) fn ensure_builtin_validators() {
+    #[cfg(feature = "quality")]
+    {
+        let registry = crate::plugins::registry::get_validator_registry();
+        if let Ok(mut reg) = registry.write() {
+            let _ = reg.register(std::sync::Arc::new(crate::text::QualityGateValidator));
+        }
+    }
+}
+
+/// Run the post-processing pipeline using the given plugin registries.
+///
+/// Identical to [`run_pipeline`], except that post-processors and validators are taken
+/// from `registries`. Built-in post-processors are only auto-registered when `registries`
+/// are the global registries.
 #[cfg_attr(feature = "otel", tracing::instrument(
-    skip(result, config),
+    skip(result, config, registries),
     fields(
         pipeline.stage = "post_processing",
         content.length = result.content.len(),
     )
 ))]
-pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfig) -> Result<ExtractionResult> {
+pub async fn run_pipeline_with_registries(
+    mut result: ExtractionResult,
+    config: &ExtractionConfig,
+    registries: &PluginRegistries,
+) -> Result<ExtractionResult> {
     let pp_config = config.postprocessor.as_ref();
     let postprocessing_enabled = pp_config.is_none_or(|c| c.enabled);
 
-    if postprocessing_enabled {
-        #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
-        {
-            let _ = crate::keywords::ensure_initialized();
-        }
-
-        #[cfg(feature = "language-detection")]
-        {
-            let _ = crate::language_detection::ensure_initialized();
-        }
+    if postprocessing_enabled && registries.needs_lazy_init() {
+        ensure_builtin_post_processors();
+    }
 
-        #[cfg(feature = "chunking")]
-        {
-            let _ = crate::chunking::ensure_initialized();
-        }
+    if config.quality_gate.is_some() && registries.needs_lazy_init() {
+        ensure_builtin_validators();
+    }
 
-        #[cfg(feature = "quality")]
-        {
-            let registry = crate::plugins::registry::get_post_processor_registry();
-            if let Ok(mut reg) = registry.write() {
-                let _ = reg.register(std::sync::Arc::new(crate::text::QualityProcessor), 30);
-            }
-        }
+    let timer = StageTimer::start();
+    crate::plugins::describe_images(&mut result, config, &registries.image_describers).await?;
 
-        let processor_registry = crate::plugins::registry::get_post_processor_registry();
+    if postprocessing_enabled {
+        let processor_registry = &registries.post_processors;
 
-        for stage in [ProcessingStage::Early, ProcessingStage::Middle, ProcessingStage::Late] {
-            let processors = {
-                let registry = processor_registry.read().map_err(|e| {
-                    crate::KreuzbergError::Other(format!("Post-processor registry lock poisoned: {}", e))
-                })?;
-                registry.get_for_stage(stage)
-            };
+        let processors = {
+            let registry = processor_registry
+                .read()
+                .map_err(|e| crate::KreuzbergError::Other(format!("Post-processor registry lock poisoned: {}", e)))?;
+            registry.execution_plan()?
+        };
 
-            for processor in processors {
-                let processor_name = processor.name();
+        for processor in processors {
+            let processor_name = processor.name();
 
-                let should_run = if let Some(config) = pp_config {
-                    if let Some(ref enabled) = config.enabled_processors {
-                        enabled.iter().any(|name| name == processor_name)
-                    } else if let Some(ref disabled) = config.disabled_processors {
-                        !disabled.iter().any(|name| name == processor_name)
-                    } else {
-                        true
-                    }
+            let should_run = if let Some(config) = pp_config {
+                if let Some(ref enabled) = config.enabled_processors {
+                    enabled.iter().any(|name| name == processor_name)
+                } else if let Some(ref disabled) = config.disabled_processors {
+                    !disabled.iter().any(|name| name == processor_name)
                 } else {
                     true
-                };
-
-                if should_run && processor.should_process(&result, config) {
-                    match processor.process(&mut result, config).await {
-                        Ok(_) => {}
-                        Err(err @ KreuzbergError::Io(_))
-                        | Err(err @ KreuzbergError::LockPoisoned(_))
-                        | Err(err @ KreuzbergError::Plugin { .. }) => {
-                            return Err(err);
-                        }
-                        Err(err) => {
-                            result.metadata.additional.insert(
-                                format!("processing_error_{processor_name}"),
-                                serde_json::Value::String(err.to_string()),
-                            );
-                        }
+                }
+            } else {
+                true
+            };
+
+            if should_run && processor.should_process(&result, config) {
+                match processor.process(&mut result, config).await {
+                    Ok(_) => {}
+                    Err(err @ KreuzbergError::Io(_))
+                    | Err(err @ KreuzbergError::LockPoisoned(_))
+                    | Err(err @ KreuzbergError::Plugin { .. }) => {
+                        return Err(err);
+                    }
+                    Err(err) => {
+                        let context = format!("Post-processor '{}' failed", processor_name);
+                        let key = format!("processing_error_{processor_name}");
+                        record_stage_error(&mut result, key, "post_processor_failed", &context, err);
                     }
                 }
             }
         }
     }
 
+    record_timing(&mut result, config, timer, |t| &mut t.post_processing_ms);
+
     #[cfg(feature = "quality")]
     if config.enable_quality_processing {
         let quality_score = crate::text::quality::calculate_quality_score(
             &result.content,
-            Some(
-                &result
-                    .metadata
-                    .additional
-                    .iter()
-                    .map(|(k, v)| (k.clone(), v.to_string()))
-                    .collect(),
-            ),
+            Some(&crate::text::quality::quality_metadata_fields(&result.metadata)),
         );
         result.metadata.additional.insert(
             "quality_score".to_string(),
@@ -141,6 +212,7 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
 
     #[cfg(feature = "chunking")]
     if let Some(ref chunking_config) = config.chunking {
+        let timer = StageTimer::start();
         let chunk_config = crate::chunking::ChunkingConfig {
             max_characters: chunking_config.max_chars,
             overlap: chunking_config.max_overlap,
@@ -165,7 +237,8 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
                 if let Some(ref embedding_config) = chunking_config.embedding
                     && let Some(ref mut chunks) = result.chunks
                 {
-                    match crate::embeddings::generate_embeddings_for_chunks(chunks, embedding_config) {
+                    let device = config.device.clone().unwrap_or_default();
+                    match crate::embeddings::generate_embeddings_on_device(chunks, embedding_config, &device) {
                         Ok(()) => {
                             result
                                 .metadata
@@ -173,10 +246,8 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
                                 .insert("embeddings_generated".to_string(), serde_json::Value::Bool(true));
                         }
                         Err(e) => {
-                            result
-                                .metadata
-                                .additional
-                                .insert("embedding_error".to_string(), serde_json::Value::String(e.to_string()));
+                            let context = "Embedding generation failed";
+                            record_stage_error(&mut result, "embedding_error", "embedding_failed", context, e);
                         }
                     }
                 }
@@ -190,12 +261,10 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
                 }
             }
             Err(e) => {
-                result
-                    .metadata
-                    .additional
-                    .insert("chunking_error".to_string(), serde_json::Value::String(e.to_string()));
+                record_stage_error(&mut result, "chunking_error", "chunking_failed", "Chunking failed", e);
             }
         }
+        record_timing(&mut result, config, timer, |t| &mut t.chunking_ms);
     }
 
     #[cfg(not(feature = "chunking"))]
@@ -208,17 +277,17 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
 
     #[cfg(feature = "language-detection")]
     if let Some(ref lang_config) = config.language_detection {
+        let timer = StageTimer::start();
         match crate::language_detection::detect_languages(&result.content, lang_config) {
             Ok(detected) => {
                 result.detected_languages = detected;
             }
             Err(e) => {
-                result.metadata.additional.insert(
-                    "language_detection_error".to_string(),
-                    serde_json::Value::String(e.to_string()),
-                );
+                let (key, code) = ("language_detection_error", "language_detection_failed");
+                record_stage_error(&mut result, key, code, "Language detection failed", e);
             }
         }
+        record_timing(&mut result, config, timer, |t| &mut t.language_detection_ms);
     }
 
     #[cfg(not(feature = "language-detection"))]
@@ -229,8 +298,21 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
         );
     }
 
+    #[cfg(feature = "quality")]
+    if let Some(ref gate_config) = config.quality_gate {
+        crate::text::quality_gate::apply_quality_gate(&mut result, gate_config);
+    }
+
+    #[cfg(not(feature = "quality"))]
+    if config.quality_gate.is_some() {
+        result.metadata.additional.insert(
+            "quality_gate_error".to_string(),
+            serde_json::Value::String("Quality processing feature not enabled".to_string()),
+        );
+    }
+
     {
-        let validator_registry = crate::plugins::registry::get_validator_registry();
+        let validator_registry = &registries.validators;
         let validators = {
             let registry = validator_registry
                 .read()
@@ -239,8 +321,12 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
         };
 
         for validator in validators {
-            if validator.should_validate(&result, config) {
-                validator.validate(&result, config).await?;
+            if !validator.should_validate(&result, config) {
+                continue;
+            }
+            match validator.check(&result, config).await {
+                Ok(findings) => record_findings(&mut result, config, validator.name(), findings)?,
+                Err(err) => record_validator_failure(&mut result, config, validator.name(), err)?,
             }
         }
     }
@@ -248,6 +334,25 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
     Ok(result)
 }
 
+/// Record a failed post-processing stage under `metadata.additional[key]` and as a warning,
+/// without failing the extraction.
+fn record_stage_error(
+    result: &mut ExtractionResult,
+    key: impl Into<String>,
+    code: &str,
+    context: &str,
+    error: KreuzbergError,
+) {
+    let message = error.to_string();
+    let description = format!("{}: {}", context, message);
+    let warning = ExtractionWarning::new(code, description, WarningStage::PostProcessing);
+    result.warnings.push(warning);
+    result
+        .metadata
+        .additional
+        .insert(key.into(), serde_json::Value::String(message));
+}
+
 /// Run the post-processing pipeline synchronously (WASM-compatible version).
 ///
 /// This is a synchronous implementation for WASM and non-async contexts.
@@ -270,6 +375,7 @@ pub async fn run_pipeline(mut result: ExtractionResult, config: &ExtractionConfi
 /// - Quality processing (if enabled)
 /// - Chunking (if enabled)
 /// - Language detection (if enabled)
+/// - Quality gate (if configured)
 ///
 /// It does NOT handle:
 /// - Async post-processors
@@ -281,14 +387,7 @@ pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig
     if config.enable_quality_processing {
         let quality_score = crate::text::quality::calculate_quality_score(
             &result.content,
-            Some(
-                &result
-                    .metadata
-                    .additional
-                    .iter()
-                    .map(|(k, v)| (k.clone(), v.to_string()))
-                    .collect(),
-            ),
+            Some(&crate::text::quality::quality_metadata_fields(&result.metadata)),
         );
         result.metadata.additional.insert(
             "quality_score".to_string(),
@@ -309,6 +408,7 @@ pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig
     // Chunking
     #[cfg(feature = "chunking")]
     if let Some(ref chunking_config) = config.chunking {
+        let timer = StageTimer::start();
         let chunk_config = crate::chunking::ChunkingConfig {
             max_characters: chunking_config.max_chars,
             overlap: chunking_config.max_overlap,
@@ -331,7 +431,8 @@ pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig
                 if let Some(ref embedding_config) = chunking_config.embedding
                     && let Some(ref mut chunks) = result.chunks
                 {
-                    match crate::embeddings::generate_embeddings_for_chunks(chunks, embedding_config) {
+                    let device = config.device.clone().unwrap_or_default();
+                    match crate::embeddings::generate_embeddings_on_device(chunks, embedding_config, &device) {
                         Ok(()) => {
                             result
                                 .metadata
@@ -339,10 +440,8 @@ pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig
                                 .insert("embeddings_generated".to_string(), serde_json::Value::Bool(true));
                         }
                         Err(e) => {
-                            result
-                                .metadata
-                                .additional
-                                .insert("embedding_error".to_string(), serde_json::Value::String(e.to_string()));
+                            let context = "Embedding generation failed";
+                            record_stage_error(&mut result, "embedding_error", "embedding_failed", context, e);
                         }
                     }
                 }
@@ -356,12 +455,10 @@ pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig
                 }
             }
             Err(e) => {
-                result
-                    .metadata
-                    .additional
-                    .insert("chunking_error".to_string(), serde_json::Value::String(e.to_string()));
+                record_stage_error(&mut result, "chunking_error", "chunking_failed", "Chunking failed", e);
             }
         }
+        record_timing(&mut result, config, timer, |t| &mut t.chunking_ms);
     }
 
     #[cfg(not(feature = "chunking"))]
@@ -375,17 +472,17 @@ pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig
     // Language detection
     #[cfg(feature = "language-detection")]
     if let Some(ref lang_config) = config.language_detection {
+        let timer = StageTimer::start();
         match crate::language_detection::detect_languages(&result.content, lang_config) {
             Ok(detected) => {
                 result.detected_languages = detected;
             }
             Err(e) => {
-                result.metadata.additional.insert(
-                    "language_detection_error".to_string(),
-                    serde_json::Value::String(e.to_string()),
-                );
+                let (key, code) = ("language_detection_error", "language_detection_failed");
+                record_stage_error(&mut result, key, code, "Language detection failed", e);
             }
         }
+        record_timing(&mut result, config, timer, |t| &mut t.language_detection_ms);
     }
 
     #[cfg(not(feature = "language-detection"))]
@@ -396,6 +493,24 @@ pub fn run_pipeline_sync(mut result: ExtractionResult, config: &ExtractionConfig
         );
     }
 
+    // Quality gate (no validator registry here, so rejection is applied directly)
+    #[cfg(feature = "quality")]
+    if let Some(ref gate_config) = config.quality_gate
+        && crate::text::quality_gate::apply_quality_gate(&mut result, gate_config)
+        && gate_config.action == crate::core::config::QualityGateAction::Reject
+    {
+        let err = crate::text::quality_gate::rejection_error(&result);
+        record_validator_failure(&mut result, config, "quality-gate", err)?;
+    }
+
+    #[cfg(not(feature = "quality"))]
+    if config.quality_gate.is_some() {
+        result.metadata.additional.insert(
+            "quality_gate_error".to_string(),
+            serde_json::Value::String("Quality processing feature not enabled".to_string()),
+        );
+    }
+
     Ok(result)
 }
 
@@ -426,6 +541,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
         result.metadata.additional.insert(
             VALIDATION_MARKER_KEY.to_string(),
@@ -449,6 +569,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
         let config = ExtractionConfig {
             enable_quality_processing: true,
@@ -470,6 +595,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
         let config = ExtractionConfig {
             enable_quality_processing: false,
@@ -480,6 +610,44 @@ mod tests {
         assert!(!processed.metadata.additional.contains_key("quality_score"));
     }
 
+    #[tokio::test]
+    #[cfg(feature = "quality")]
+    async fn test_pipeline_quality_gate_flags_and_rejects_garbled_ocr() {
+        let garbled = || {
+            let mut result = ExtractionResult {
+                content: "Tlie c0mmittee rnet 0n Tuesdav t0 rev|ew tbe budqet f0r tlie c0ming ycar. ".repeat(2),
+                mime_type: "image/png".to_string(),
+                metadata: Metadata::default(),
+                tables: vec![],
+                detected_languages: None,
+                chunks: None,
+                images: None,
+                pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
+            };
+            result
+                .metadata
+                .additional
+                .insert("ocr_confidence".to_string(), serde_json::json!(0.35));
+            result
+        };
+
+        let mut config = ExtractionConfig {
+            quality_gate: Some(crate::core::config::QualityGateConfig::default()),
+            ..Default::default()
+        };
+        let processed = run_pipeline(garbled(), &config).await.unwrap();
+        assert_eq!(processed.metadata.additional["low_quality"], serde_json::json!(true));
+
+        config.quality_gate.as_mut().unwrap().action = crate::core::config::QualityGateAction::Reject;
+        let rejected = run_pipeline(garbled(), &config).await;
+        assert!(matches!(rejected, Err(KreuzbergError::Validation { .. })));
+    }
+
     #[tokio::test]
     #[cfg(feature = "chunking")]
     async fn test_pipeline_with_chunking() {
@@ -492,6 +660,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
         let config = ExtractionConfig {
             chunking: Some(crate::ChunkingConfig {
@@ -520,6 +693,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
         let config = ExtractionConfig {
             chunking: None,
@@ -549,6 +727,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
         let config = ExtractionConfig::default();
 
@@ -582,6 +765,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
         let config = ExtractionConfig::default();
 
@@ -612,6 +800,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
         let config = ExtractionConfig::default();
 
@@ -633,6 +826,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
         let config = ExtractionConfig {
             enable_quality_processing: true,
@@ -670,6 +868,11 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         #[cfg(feature = "keywords-yake")]
@@ -685,19 +888,10 @@ Natural language processing enables computers to understand human language.
 
         let processed = run_pipeline(result, &config).await.unwrap();
 
-        assert!(processed.metadata.additional.contains_key("keywords"));
-
-        let keywords_value = processed.metadata.additional.get("keywords").unwrap();
-        assert!(keywords_value.is_array());
-
-        let keywords = keywords_value.as_array().unwrap();
+        let keywords = processed.keywords.expect("Should have extracted keywords");
         assert!(!keywords.is_empty(), "Should have extracted keywords");
-
-        let first_keyword = &keywords[0];
-        assert!(first_keyword.is_object());
-        assert!(first_keyword.get("text").is_some());
-        assert!(first_keyword.get("score").is_some());
-        assert!(first_keyword.get("algorithm").is_some());
+        assert!(!keywords[0].text.is_empty());
+        assert!(!processed.metadata.additional.contains_key("keywords"));
     }
 
     #[tokio::test]
@@ -712,6 +906,11 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig {
@@ -721,7 +920,7 @@ Natural language processing enables computers to understand human language.
 
         let processed = run_pipeline(result, &config).await.unwrap();
 
-        assert!(!processed.metadata.additional.contains_key("keywords"));
+        assert!(processed.keywords.is_none());
     }
 
     #[tokio::test]
@@ -748,6 +947,11 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         #[cfg(feature = "keywords-yake")]
@@ -765,7 +969,7 @@ Natural language processing enables computers to understand human language.
 
         let processed = run_pipeline(result, &config).await.unwrap();
 
-        assert!(!processed.metadata.additional.contains_key("keywords"));
+        assert!(processed.keywords.is_none());
     }
 
     #[tokio::test]
@@ -878,6 +1082,11 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
         result.metadata.additional.insert(
             VALIDATION_MARKER_KEY.to_string(),
@@ -964,6 +1173,11 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
         result.metadata.additional.insert(
             VALIDATION_MARKER_KEY.to_string(),
@@ -1156,6 +1370,11 @@ Natural language processing enables computers to understand human language.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -0,0 +1,331 @@
+//! Producer fingerprinting and scan detection.
+//!
+//! [`attach_provenance`] runs right after extraction, before the post-processing
+//! pipeline, so processors and validators can branch on `metadata.provenance`. It
+//! collects the producing application and capture device from the format metadata
+//! and decides whether the document is likely a scan. Extractors that know something
+//! the metadata doesn't show (the PDF extractor's native text layer, the image
+//! extractor's OCR output) may pre-populate `metadata.provenance.scan_signals`; those
+//! signals are kept.
+//!
+//! Being a raster image or carrying a camera's EXIF data is recorded as evidence but
+//! does not make a document a likely scan on its own: a photo or screenshot is only
+//! treated as a scanned document when OCR finds a page of text in it or its producer
+//! or device is scanning equipment.
+
+use crate::types::{DocumentProvenance, ExtractionResult, FormatMetadata};
+
+/// The document is a raster image (photo, scan or screenshot).
+pub(crate) const SIGNAL_RASTER_IMAGE: &str = "raster_image";
+/// The producer, creator or capture device names scanning/capture software or a scanner.
+pub(crate) const SIGNAL_SCANNER_PRODUCER: &str = "scanner_producer";
+/// EXIF data names the capture device.
+pub(crate) const SIGNAL_CAPTURE_DEVICE: &str = "capture_device";
+/// The PDF text layer is empty or nearly so.
+#[cfg_attr(not(feature = "pdf"), allow(dead_code))]
+pub(crate) const SIGNAL_SPARSE_TEXT_LAYER: &str = "sparse_text_layer";
+/// OCR recognized text as dense as a document page in a raster image.
+#[cfg_attr(not(feature = "ocr"), allow(dead_code))]
+pub(crate) const SIGNAL_OCR_TEXT: &str = "ocr_text";
+
+/// Signals that describe the medium but are not evidence of a scan by themselves.
+const CONTEXT_SIGNALS: &[&str] = &[SIGNAL_RASTER_IMAGE, SIGNAL_CAPTURE_DEVICE];
+
+/// Below this many non-whitespace characters per page a text layer counts as sparse.
+#[cfg_attr(not(any(feature = "pdf", feature = "ocr")), allow(dead_code))]
+const SPARSE_CHARS_PER_PAGE: usize = 32;
+
+/// Producer/creator fragments of scanner devices and multifunction printers (lowercase).
+const SCANNER_DEVICE_PATTERNS: &[&str] = &[
+    "bizhub",
+    "brother mfc",
+    "canon ir",
+    "canoscan",
+    "canon dr-",
+    "epson scan",
+    "e-studio",
+    "fujitsu fi-",
+    "hp digital sending",
+    "imagerunner",
+    "kodak scanner",
+    "konica minolta",
+    "kyocera",
+    "laserjet mfp",
+    "lexmark",
+    "ricoh",
+    "scanjet",
+    "scansnap",
+    "sharp mx-",
+    "taskalfa",
+    "workcentre",
+    "xerox",
+];
+
+/// Producer/creator fragments of scanning, capture and OCR software (lowercase).
+const SCAN_SOFTWARE_PATTERNS: &[&str] = &[
+    "abbyy",
+    "finereader",
+    "image capture",
+    "kofax",
+    "naps2",
+    "ocrmypdf",
+    "omnipage",
+    "paper capture",
+    "paperport",
+    "readiris",
+    "scanner",
+    "tesseract",
+    "twain",
+    "vuescan",
+];
+
+/// Whether a text layer (a PDF's native text or an image's OCR output) is too thin to be
+/// a page of document text.
+#[cfg_attr(not(any(feature = "pdf", feature = "ocr")), allow(dead_code))]
+pub(crate) fn is_sparse_text_layer(native_text: &str, page_count: Option<usize>) -> bool {
+    let non_whitespace = native_text.chars().filter(|c| !c.is_whitespace()).count();
+    non_whitespace < SPARSE_CHARS_PER_PAGE * page_count.unwrap_or(1).max(1)
+}
+
+/// Fingerprint the producer of `result` and store it in `metadata.provenance`.
+///
+/// Nothing is stored when neither a producer, a device nor any scan signal was found.
+pub(crate) fn attach_provenance(result: &mut ExtractionResult) {
+    let mut provenance = result.metadata.provenance.take().unwrap_or_default();
+    let metadata = &result.metadata;
+
+    match &metadata.format {
+        #[cfg(feature = "pdf")]
+        Some(FormatMetadata::Pdf(pdf)) => {
+            fill(&mut provenance.producer, pdf.producer.as_deref());
+            // The PDF `Creator` entry names the authoring application, not a person.
+            fill(&mut provenance.creator_tool, metadata.created_by.as_deref());
+        }
+        Some(FormatMetadata::Office(office)) => {
+            fill(&mut provenance.producer, office.application.as_deref());
+        }
+        Some(FormatMetadata::Image(image)) => {
+            fill(&mut provenance.producer, image.exif.get("Software").map(String::as_str));
+            if let Some(device) = capture_device(image.exif.get("Make"), image.exif.get("Model")) {
+                fill(&mut provenance.scanner_model, Some(device.as_str()));
+                add_signal(&mut provenance, SIGNAL_CAPTURE_DEVICE);
+            }
+        }
+        _ => {}
+    }
+
+    for key in ["application", "generator"] {
+        if let Some(value) = metadata.additional.get(key).and_then(|v| v.as_str()) {
+            fill(&mut provenance.producer, Some(value));
+        }
+    }
+
+    if result.mime_type.starts_with("image/") || matches!(metadata.format, Some(FormatMetadata::Image(_))) {
+        add_signal(&mut provenance, SIGNAL_RASTER_IMAGE);
+    }
+
+    let tools = [
+        provenance.producer.clone(),
+        provenance.creator_tool.clone(),
+        provenance.scanner_model.clone(),
+    ];
+    for tool in tools.iter().flatten() {
+        let lower = tool.to_lowercase();
+        if SCANNER_DEVICE_PATTERNS.iter().any(|p| lower.contains(p)) {
+            fill(&mut provenance.scanner_model, Some(tool.as_str()));
+            add_signal(&mut provenance, SIGNAL_SCANNER_PRODUCER);
+        } else if SCAN_SOFTWARE_PATTERNS.iter().any(|p| lower.contains(p)) {
+            add_signal(&mut provenance, SIGNAL_SCANNER_PRODUCER);
+        }
+    }
+
+    provenance.likely_scanned = provenance
+        .scan_signals
+        .iter()
+        .any(|signal| !CONTEXT_SIGNALS.contains(&signal.as_str()));
+
+    let known = provenance.producer.is_some()
+        || provenance.creator_tool.is_some()
+        || provenance.scanner_model.is_some()
+        || !provenance.scan_signals.is_empty();
+    result.metadata.provenance = known.then_some(provenance);
+}
+
+/// Set `slot` to the trimmed `value` unless it already holds one or `value` is blank.
+fn fill(slot: &mut Option<String>, value: Option<&str>) {
+    if slot.is_none()
+        && let Some(value) = value.map(str::trim).filter(|v| !v.is_empty())
+    {
+        *slot = Some(value.to_string());
+    }
+}
+
+fn add_signal(provenance: &mut DocumentProvenance, signal: &str) {
+    if !provenance.scan_signals.iter().any(|s| s == signal) {
+        provenance.scan_signals.push(signal.to_string());
+    }
+}
+
+/// Join EXIF `Make` and `Model`, skipping the make when the model already starts with it.
+fn capture_device(make: Option<&String>, model: Option<&String>) -> Option<String> {
+    let make = make.map(|m| m.trim()).filter(|m| !m.is_empty());
+    let model = model.map(|m| m.trim()).filter(|m| !m.is_empty());
+
+    match (make, model) {
+        (Some(make), Some(model)) if model.to_lowercase().starts_with(&make.to_lowercase()) => Some(model.to_string()),
+        (Some(make), Some(model)) => Some(format!("{make} {model}")),
+        (Some(only), None) | (None, Some(only)) => Some(only.to_string()),
+        (None, None) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ImageMetadata, Metadata, OfficeMetadata};
+    use std::collections::HashMap;
+
+    fn result_with(mime_type: &str, metadata: Metadata) -> ExtractionResult {
+        ExtractionResult {
+            content: String::new(),
+            mime_type: mime_type.to_string(),
+            metadata,
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_office_application_is_producer() {
+        let metadata = Metadata {
+            format: Some(FormatMetadata::Office(OfficeMetadata {
+                application: Some("Microsoft Office Word".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+        let mut result = result_with(
+            "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+            metadata,
+        );
+
+        attach_provenance(&mut result);
+
+        let provenance = result.metadata.provenance.unwrap();
+        assert_eq!(provenance.producer.as_deref(), Some("Microsoft Office Word"));
+        assert!(!provenance.likely_scanned);
+        assert!(provenance.scan_signals.is_empty());
+    }
+
+    #[test]
+    fn test_exif_scanner_model() {
+        let exif = HashMap::from([
+            ("Make".to_string(), "Canon".to_string()),
+            ("Model".to_string(), "Canon CanoScan LiDE 300".to_string()),
+            ("Software".to_string(), "IJ Scan Utility".to_string()),
+        ]);
+        let metadata = Metadata {
+            format: Some(FormatMetadata::Image(ImageMetadata {
+                width: 100,
+                height: 100,
+                format: "JPEG".to_string(),
+                exif,
+            })),
+            ..Default::default()
+        };
+        let mut result = result_with("image/jpeg", metadata);
+
+        attach_provenance(&mut result);
+
+        let provenance = result.metadata.provenance.unwrap();
+        assert_eq!(provenance.producer.as_deref(), Some("IJ Scan Utility"));
+        assert_eq!(provenance.scanner_model.as_deref(), Some("Canon CanoScan LiDE 300"));
+        assert!(provenance.likely_scanned);
+        assert_eq!(
+            provenance.scan_signals,
+            vec![SIGNAL_CAPTURE_DEVICE, SIGNAL_RASTER_IMAGE, SIGNAL_SCANNER_PRODUCER]
+        );
+    }
+
+    #[test]
+    fn test_photo_is_scanned_only_with_ocr_text() {
+        let image_metadata = || Metadata {
+            format: Some(FormatMetadata::Image(ImageMetadata {
+                width: 4032,
+                height: 3024,
+                format: "JPEG".to_string(),
+                exif: HashMap::from([
+                    ("Make".to_string(), "Apple".to_string()),
+                    ("Model".to_string(), "iPhone 15".to_string()),
+                ]),
+            })),
+            ..Default::default()
+        };
+
+        let mut photo = result_with("image/jpeg", image_metadata());
+        attach_provenance(&mut photo);
+        let provenance = photo.metadata.provenance.unwrap();
+        assert!(!provenance.likely_scanned);
+        assert_eq!(
+            provenance.scan_signals,
+            vec![SIGNAL_CAPTURE_DEVICE, SIGNAL_RASTER_IMAGE]
+        );
+
+        let mut metadata = image_metadata();
+        metadata.provenance = Some(DocumentProvenance {
+            scan_signals: vec![SIGNAL_OCR_TEXT.to_string()],
+            ..Default::default()
+        });
+        let mut document_photo = result_with("image/jpeg", metadata);
+        attach_provenance(&mut document_photo);
+        assert!(document_photo.metadata.provenance.unwrap().likely_scanned);
+    }
+
+    #[test]
+    fn test_scanner_producer_and_extractor_hints() {
+        let mut metadata = Metadata {
+            provenance: Some(DocumentProvenance {
+                scan_signals: vec![SIGNAL_SPARSE_TEXT_LAYER.to_string()],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        metadata.additional.insert(
+            "application".to_string(),
+            serde_json::json!("KONICA MINOLTA bizhub C360"),
+        );
+        let mut result = result_with("application/pdf", metadata);
+
+        attach_provenance(&mut result);
+
+        let provenance = result.metadata.provenance.unwrap();
+        assert_eq!(provenance.scanner_model.as_deref(), Some("KONICA MINOLTA bizhub C360"));
+        assert!(provenance.likely_scanned);
+        assert_eq!(
+            provenance.scan_signals,
+            vec![SIGNAL_SPARSE_TEXT_LAYER, SIGNAL_SCANNER_PRODUCER]
+        );
+    }
+
+    #[test]
+    fn test_nothing_known_leaves_provenance_empty() {
+        let mut result = result_with("text/plain", Metadata::default());
+        attach_provenance(&mut result);
+        assert!(result.metadata.provenance.is_none());
+    }
+
+    #[test]
+    fn test_is_sparse_text_layer() {
+        assert!(is_sparse_text_layer("  \n ", Some(3)));
+        assert!(is_sparse_text_layer("Page 1", Some(2)));
+        assert!(!is_sparse_text_layer(&"lorem ipsum dolor ".repeat(10), Some(2)));
+    }
+}
@@ -0,0 +1,381 @@
+//! Best-effort repair of malformed documents.
+//!
+//! Unless [`ExtractionConfig::strict`] is set, a document whose extractor fails with a parsing
+//! error is repaired here and extracted once more. Repairs only touch the structure that keeps
+//! a parser from reading the document:
+//!
+//! - ZIP containers (archives, Office Open XML, OpenDocument, EPUB) with a truncated or damaged
+//!   central directory are rebuilt from their local file headers
+//! - XML with broken entity references gets its stray `&` escaped
+//! - PDFs with a corrupt cross-reference table get a new one built from their objects
+//!
+//! Each repair is reported as a `document_repaired` entry in [`ExtractionResult::warnings`]. When a
+//! document cannot be repaired, or the repaired copy fails as well, the original error is returned.
+
+use crate::KreuzbergError;
+use crate::core::config::ExtractionConfig;
+use crate::types::{ExtractionResult, ExtractionWarning, WarningStage};
+
+/// Warning code reported for a document that was repaired before extraction.
+const REPAIRED_WARNING_CODE: &str = "document_repaired";
+
+/// ZIP-based formats besides Office Open XML and OpenDocument.
+const ZIP_MIME_TYPES: &[&str] = &[
+    "application/zip",
+    "application/x-zip-compressed",
+    "application/epub+zip",
+];
+
+/// Highest object number allowed in a PDF.
+const MAX_PDF_OBJECT_NUMBER: u32 = 8_388_607;
+
+/// Longest entity name considered when checking XML entity references.
+const MAX_XML_ENTITY_NAME: usize = 64;
+
+/// A repaired copy of a document.
+#[derive(Debug, Clone)]
+pub(crate) struct Repair {
+    /// Repaired document bytes
+    pub content: Vec<u8>,
+    /// What was repaired
+    pub warning: String,
+}
+
+/// Whether a failed extraction should be retried with a repaired document.
+pub(crate) fn should_recover(error: &KreuzbergError, config: &ExtractionConfig) -> bool {
+    !config.strict && matches!(error, KreuzbergError::Parsing { .. })
+}
+
+/// Repair the structure of a malformed document.
+///
+/// Returns `None` when there is no repair for the format or nothing could be repaired.
+pub(crate) fn repair(content: &[u8], mime_type: &str) -> Option<Repair> {
+    if is_zip_container(mime_type) {
+        repair_zip(content)
+    } else if is_xml(mime_type) {
+        repair_xml(content)
+    } else if mime_type == "application/pdf" {
+        repair_pdf(content)
+    } else {
+        None
+    }
+}
+
+/// Report a repair in the result's warnings, ahead of anything reported during extraction.
+pub(crate) fn record_warning(result: &mut ExtractionResult, warning: String) {
+    let warning = ExtractionWarning::new(REPAIRED_WARNING_CODE, warning, WarningStage::Recovery);
+    result.warnings.insert(0, warning);
+}
+
+fn is_zip_container(mime_type: &str) -> bool {
+    ZIP_MIME_TYPES.contains(&mime_type)
+        || mime_type.starts_with("application/vnd.openxmlformats-officedocument.")
+        || mime_type.starts_with("application/vnd.oasis.opendocument.")
+}
+
+fn is_xml(mime_type: &str) -> bool {
+    matches!(mime_type, "application/xml" | "text/xml") || mime_type.ends_with("+xml")
+}
+
+/// Rebuild a ZIP container from its local file headers, skipping the central directory.
+///
+/// Entries are read in order until the first one that cannot be read and written back
+/// uncompressed. Decompressed data is bounded by the archive security limits.
+#[cfg(any(feature = "office", feature = "archives"))]
+fn repair_zip(content: &[u8]) -> Option<Repair> {
+    use crate::extractors::security::SecurityLimits;
+    use std::io::{Cursor, Read, Write};
+    use zip::write::{SimpleFileOptions, ZipWriter};
+
+    let limits = SecurityLimits::default();
+    let max_size = content
+        .len()
+        .saturating_mul(limits.max_compression_ratio)
+        .min(limits.max_archive_size);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+    let mut reader = Cursor::new(content);
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let mut entries = 0;
+    let mut total_size = 0;
+
+    while entries < limits.max_files_in_archive {
+        let Ok(Some(mut file)) = zip::read::read_zipfile_from_stream(&mut reader) else {
+            break;
+        };
+        let name = file.name().to_string();
+        if file.is_dir() {
+            writer.add_directory(name, options).ok()?;
+            continue;
+        }
+
+        let remaining = max_size - total_size;
+        let limit = remaining as u64 + 1;
+        let mut data = Vec::new();
+        if (&mut file).take(limit).read_to_end(&mut data).is_err() || data.len() > remaining {
+            break;
+        }
+
+        total_size += data.len();
+        writer.start_file(name, options).ok()?;
+        writer.write_all(&data).ok()?;
+        entries += 1;
+    }
+
+    if entries == 0 {
+        return None;
+    }
+
+    let repaired = writer.finish().ok()?.into_inner();
+    Some(Repair {
+        content: repaired,
+        warning: format!("Rebuilt the damaged ZIP directory from {} recovered entries", entries),
+    })
+}
+
+#[cfg(not(any(feature = "office", feature = "archives")))]
+fn repair_zip(_content: &[u8]) -> Option<Repair> {
+    None
+}
+
+/// Escape every `&` that does not start a valid entity or character reference.
+///
+/// Documents without a DTD can only reference the predefined entities, so other named
+/// references are escaped as well.
+fn repair_xml(content: &[u8]) -> Option<Repair> {
+    let has_dtd = content.windows(9).any(|window| window == b"<!DOCTYPE");
+
+    let mut repaired = Vec::with_capacity(content.len());
+    let mut escaped = 0;
+    for (i, &byte) in content.iter().enumerate() {
+        if byte == b'&' && !is_entity_reference(&content[i + 1..], has_dtd) {
+            repaired.extend_from_slice(b"&amp;");
+            escaped += 1;
+        } else {
+            repaired.push(byte);
+        }
+    }
+
+    (escaped > 0).then(|| Repair {
+        content: repaired,
+        warning: format!("Escaped {} broken XML entity references", escaped),
+    })
+}
+
+/// Whether the bytes after a `&` form a reference the XML parser accepts.
+fn is_entity_reference(rest: &[u8], has_dtd: bool) -> bool {
+    let window = &rest[..rest.len().min(MAX_XML_ENTITY_NAME + 1)];
+    let Some(end) = window.iter().position(|&b| b == b';') else {
+        return false;
+    };
+
+    match &rest[..end] {
+        [b'#', b'x', digits @ ..] => !digits.is_empty() && digits.iter().all(u8::is_ascii_hexdigit),
+        [b'#', digits @ ..] => !digits.is_empty() && digits.iter().all(u8::is_ascii_digit),
+        b"amp" | b"lt" | b"gt" | b"quot" | b"apos" => true,
+        name => has_dtd && is_xml_name(name),
+    }
+}
+
+fn is_xml_name(name: &[u8]) -> bool {
+    let is_start = |b: u8| b.is_ascii_alphabetic() || matches!(b, b'_' | b':') || b >= 0x80;
+    let is_part = |b: u8| is_start(b) || b.is_ascii_digit() || matches!(b, b'-' | b'.');
+    match name.split_first() {
+        Some((&first, rest)) => is_start(first) && rest.iter().all(|&b| is_part(b)),
+        None => false,
+    }
+}
+
+/// Append a cross-reference table built from the `N G obj` headers of a PDF.
+///
+/// The last definition of an object wins, as with incremental updates. PDFs without a
+/// catalog cannot be repaired, and encrypted ones are left alone since the new trailer
+/// would lose their encryption dictionary.
+fn repair_pdf(content: &[u8]) -> Option<Repair> {
+    if !content.starts_with(b"%PDF-") || contains(content, b"/Encrypt") {
+        return None;
+    }
+
+    let mut objects = std::collections::BTreeMap::new();
+    let mut line_start = 0;
+    while line_start < content.len() {
+        let line_end = content[line_start..]
+            .iter()
+            .position(|&b| b == b'\n' || b == b'\r')
+            .map_or(content.len(), |end| line_start + end);
+        if let Some((number, generation)) = parse_object_header(&content[line_start..line_end]) {
+            objects.insert(number, (generation, line_start));
+        }
+        line_start = line_end + 1;
+    }
+
+    let root = find_catalog(content, &objects)?;
+    let size = objects.keys().next_back()? + 1;
+
+    let mut table = format!("\nxref\n0 {}\n0000000000 65535 f \n", size);
+    for number in 1..size {
+        match objects.get(&number) {
+            Some((generation, offset)) => table.push_str(&format!("{:010} {:05} n \n", offset, generation)),
+            None => table.push_str("0000000000 65535 f \n"),
+        }
+    }
+    let (root_number, root_generation) = root;
+    table.push_str(&format!(
+        "trailer\n<< /Size {} /Root {} {} R >>\nstartxref\n{}\n%%EOF\n",
+        size,
+        root_number,
+        root_generation,
+        content.len() + 1
+    ));
+
+    let mut repaired = content.to_vec();
+    repaired.extend_from_slice(table.as_bytes());
+    Some(Repair {
+        content: repaired,
+        warning: format!("Rebuilt the PDF cross-reference table from {} objects", objects.len()),
+    })
+}
+
+/// Parse `N G obj` at the start of a line into the object number and generation.
+fn parse_object_header(line: &[u8]) -> Option<(u32, u16)> {
+    let line = &line[..line.len().min(64)];
+    let mut parts = line.split(u8::is_ascii_whitespace).filter(|part| !part.is_empty());
+    let number: u32 = std::str::from_utf8(parts.next()?).ok()?.parse().ok()?;
+    let generation = std::str::from_utf8(parts.next()?).ok()?.parse().ok()?;
+    let keyword = parts.next()?;
+
+    let is_obj = keyword.starts_with(b"obj") && keyword.get(3).is_none_or(|b| !b.is_ascii_alphanumeric());
+    (is_obj && number > 0 && number <= MAX_PDF_OBJECT_NUMBER).then_some((number, generation))
+}
+
+/// Find the object holding `/Type /Catalog`, the root of the document.
+fn find_catalog(content: &[u8], objects: &std::collections::BTreeMap<u32, (u16, usize)>) -> Option<(u32, u16)> {
+    let mut position = 0;
+    while let Some(found) = find(&content[position..], b"/Catalog") {
+        let at = position + found;
+        if content[..at].trim_ascii_end().ends_with(b"/Type") {
+            return objects
+                .iter()
+                .filter(|(_, (_, offset))| *offset < at)
+                .max_by_key(|(_, (_, offset))| *offset)
+                .map(|(number, (generation, _))| (*number, *generation));
+        }
+        position = at + 1;
+    }
+    None
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    find(haystack, needle).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(feature = "office", feature = "archives"))]
+    #[test]
+    fn test_repair_zip_with_truncated_central_directory() {
+        use std::io::{Cursor, Read, Write};
+        use zip::write::{SimpleFileOptions, ZipWriter};
+
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file("first.txt", SimpleFileOptions::default()).unwrap();
+        zip.write_all(b"first entry").unwrap();
+        zip.start_file("second.txt", SimpleFileOptions::default()).unwrap();
+        zip.write_all(b"second entry").unwrap();
+        let archive = zip.finish().unwrap().into_inner();
+
+        let directory = find(&archive, b"PK\x01\x02").unwrap();
+        let truncated = &archive[..directory + 10];
+        assert!(zip::ZipArchive::new(Cursor::new(truncated)).is_err());
+
+        let repair = repair(truncated, "application/zip").unwrap();
+        assert!(repair.warning.contains("2 recovered entries"));
+
+        let mut repaired = zip::ZipArchive::new(Cursor::new(repair.content)).unwrap();
+        let mut text = String::new();
+        repaired
+            .by_name("second.txt")
+            .unwrap()
+            .read_to_string(&mut text)
+            .unwrap();
+        assert_eq!(text, "second entry");
+    }
+
+    #[test]
+    fn test_repair_zip_without_entries() {
+        assert!(repair(b"PK\x03\x04CORRUPTED_DATA", "application/zip").is_none());
+    }
+
+    #[test]
+    fn test_repair_xml_escapes_broken_entities() {
+        let xml = b"<root>AT&T &amp; &#169; &#x00A9; &nbsp; &broken</root>";
+
+        let repair = repair(xml, "application/xml").unwrap();
+
+        assert_eq!(
+            String::from_utf8(repair.content).unwrap(),
+            "<root>AT&amp;T &amp; &#169; &#x00A9; &amp;nbsp; &amp;broken</root>"
+        );
+        assert!(repair.warning.contains("3 broken"));
+    }
+
+    #[test]
+    fn test_repair_xml_keeps_dtd_entities() {
+        let xml = b"<!DOCTYPE root [<!ENTITY copy \"(c)\">]><root>&copy; R&D</root>";
+
+        let repair = repair(xml, "text/xml").unwrap();
+
+        assert!(String::from_utf8(repair.content).unwrap().contains("&copy; R&amp;D"));
+    }
+
+    #[test]
+    fn test_repair_xml_well_formed() {
+        assert!(repair(b"<root>&lt;ok&gt;</root>", "application/xml").is_none());
+    }
+
+    #[test]
+    fn test_repair_pdf_rebuilds_xref() {
+        let pdf = b"%PDF-1.4\n1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n\
+2 0 obj\n<< /Type /Pages /Kids [] /Count 0 >>\nendobj\nxref\ngarbage\n";
+
+        let repair = repair(pdf, "application/pdf").unwrap();
+        let repaired = String::from_utf8(repair.content).unwrap();
+
+        assert!(repaired.contains("0000000009 00000 n \n"));
+        assert!(repaired.contains("trailer\n<< /Size 3 /Root 1 0 R >>"));
+        assert!(repaired.ends_with(&format!("startxref\n{}\n%%EOF\n", pdf.len() + 1)));
+        assert!(repair.warning.contains("2 objects"));
+    }
+
+    #[test]
+    fn test_repair_pdf_without_catalog() {
+        assert!(repair(b"%PDF-1.4\n1 0 obj\n<<", "application/pdf").is_none());
+    }
+
+    #[test]
+    fn test_should_recover() {
+        let error = KreuzbergError::parsing("bad xref");
+        assert!(should_recover(&error, &ExtractionConfig::default()));
+
+        let strict = ExtractionConfig {
+            strict: true,
+            ..Default::default()
+        };
+        assert!(!should_recover(&error, &strict));
+        assert!(!should_recover(
+            &KreuzbergError::validation("bad"),
+            &ExtractionConfig::default()
+        ));
+    }
+
+    #[test]
+    fn test_repair_unknown_format() {
+        assert!(repair(b"plain text", "text/plain").is_none());
+    }
+}
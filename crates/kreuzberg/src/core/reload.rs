@@ -0,0 +1,685 @@
+//! Hot-reloadable extraction configuration.
+//!
+//! Long-running servers (API, MCP) hold their default configuration in a [`ConfigHandle`].
+//! Calling [`ConfigHandle::reload`] (or running [`ConfigHandle::watch`] in the background)
+//! re-reads the config file and atomically swaps the new configuration in. Requests that
+//! are already running keep the snapshot they started with; new requests see the new one.
+//!
+//! Selected fields can also be changed in place with [`ConfigHandle::update`] (exposed as
+//! `PATCH /config` by the API server and the `update_config` MCP tool), for example to switch
+//! the OCR language of a running server without editing its config file.
+//!
+//! Handles created with [`ConfigHandle::with_plugin_loader`] also own the plugin registries
+//! their extractions use. A reload rebuilds them from the plugin directories and swaps them in
+//! together with the configuration, so a request never sees the new configuration with the
+//! previous plugins. [`ConfigHandle::watch`] reloads when a file in a plugin directory changes.
+
+use crate::core::config::{ExtractionConfig, OcrConfig};
+use crate::plugins::registry::PluginRegistries;
+use crate::{KreuzbergError, Result};
+use arc_swap::ArcSwap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+#[cfg(feature = "tokio-runtime")]
+use std::time::SystemTime;
+
+/// Builds plugin registries from the plugin directories of a [`ConfigHandle`].
+pub type PluginLoader = Arc<dyn Fn(&[PathBuf]) -> Result<PluginRegistries> + Send + Sync>;
+
+/// Plugin directories of a handle and the loader that turns them into registries.
+#[derive(Clone)]
+struct PluginSource {
+    dirs: Vec<PathBuf>,
+    loader: PluginLoader,
+}
+
+/// Fields of the configuration that can be changed at runtime with [`ConfigHandle::update`].
+///
+/// Unset fields are left unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigUpdate {
+    /// OCR language(s), e.g. "eng" or "eng+deu". Enables Tesseract OCR if OCR was disabled
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ocr_language: Option<String>,
+    /// Enable or disable caching of extraction results
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_cache: Option<bool>,
+    /// Maximum number of concurrent extractions in batch operations
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_extractions: Option<usize>,
+}
+
+/// A field changed by [`ConfigHandle::update`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigChange {
+    /// Name of the changed field
+    pub field: String,
+    /// Value before the update
+    pub previous: serde_json::Value,
+    /// Value after the update
+    pub current: serde_json::Value,
+}
+
+impl ConfigChange {
+    fn new(field: &str, previous: impl Serialize, current: impl Serialize) -> Self {
+        Self {
+            field: field.to_string(),
+            previous: serde_json::json!(previous),
+            current: serde_json::json!(current),
+        }
+    }
+}
+
+impl ConfigUpdate {
+    /// Apply the set fields to `config`, returning the fields whose value changed.
+    fn apply(&self, config: &mut ExtractionConfig) -> Result<Vec<ConfigChange>> {
+        let mut changes = Vec::new();
+
+        if let Some(language) = &self.ocr_language {
+            if language.trim().is_empty() {
+                return Err(KreuzbergError::validation("ocr_language must not be empty"));
+            }
+            let previous = config.ocr.as_ref().map(|ocr| ocr.language.clone());
+            if previous.as_ref() != Some(language) {
+                match config.ocr.as_mut() {
+                    Some(ocr) => ocr.language = language.clone(),
+                    None => {
+                        config.ocr = Some(OcrConfig {
+                            backend: "tesseract".to_string(),
+                            language: language.clone(),
+                            tesseract_config: None,
+                        });
+                    }
+                }
+                changes.push(ConfigChange::new("ocr_language", previous, language));
+            }
+        }
+
+        if let Some(use_cache) = self.use_cache
+            && use_cache != config.use_cache
+        {
+            changes.push(ConfigChange::new("use_cache", config.use_cache, use_cache));
+            config.use_cache = use_cache;
+        }
+
+        if let Some(max_concurrent_extractions) = self.max_concurrent_extractions
+            && config.max_concurrent_extractions != Some(max_concurrent_extractions)
+        {
+            changes.push(ConfigChange::new(
+                "max_concurrent_extractions",
+                config.max_concurrent_extractions,
+                max_concurrent_extractions,
+            ));
+            config.max_concurrent_extractions = Some(max_concurrent_extractions);
+        }
+
+        Ok(changes)
+    }
+}
+
+/// Shared handle to an atomically swappable [`ExtractionConfig`].
+///
+/// Cloning the handle is cheap; all clones observe the same configuration.
+#[derive(Clone)]
+pub struct ConfigHandle {
+    current: Arc<ArcSwap<ExtractionConfig>>,
+    source: Option<PathBuf>,
+    env_overrides: bool,
+    profile: Option<String>,
+    plugins: Option<PluginSource>,
+}
+
+impl ConfigHandle {
+    /// Create a handle for a fixed configuration (no backing file).
+    ///
+    /// [`reload`](Self::reload) is a no-op for such handles, but [`replace`](Self::replace)
+    /// can still be used to swap the configuration programmatically.
+    pub fn new(config: ExtractionConfig) -> Self {
+        Self {
+            current: Arc::new(ArcSwap::from_pointee(config)),
+            source: None,
+            env_overrides: false,
+            profile: None,
+            plugins: None,
+        }
+    }
+
+    /// Create a handle backed by a config file (TOML, YAML, or JSON).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let config = ExtractionConfig::from_file(path)?;
+        Ok(Self::with_source(config, path))
+    }
+
+    /// Create a handle backed by the config file found via [`ExtractionConfig::discover_path`].
+    ///
+    /// Falls back to the default configuration (without a backing file) if none is found.
+    /// `KREUZBERG_*` environment overrides are layered over the file, on every reload too
+    /// (see [`ExtractionConfig::apply_env_overrides`]).
+    pub fn discover() -> Result<Self> {
+        let handle = match ExtractionConfig::discover_path()? {
+            Some(path) => Self::from_file(path)?,
+            None => Self::new(ExtractionConfig::default()),
+        };
+
+        let mut config = (*handle.current()).clone();
+        if config.apply_env_overrides()? > 0 {
+            handle.replace(config);
+        }
+
+        Ok(handle.with_env_overrides())
+    }
+
+    /// Create a handle for an already loaded configuration and the file it came from.
+    pub fn with_source(config: ExtractionConfig, source: impl Into<PathBuf>) -> Self {
+        Self {
+            current: Arc::new(ArcSwap::from_pointee(config)),
+            source: Some(source.into()),
+            env_overrides: false,
+            profile: None,
+            plugins: None,
+        }
+    }
+
+    /// Re-apply `KREUZBERG_*` environment overrides whenever the file is reloaded.
+    ///
+    /// The overrides are not applied to the current configuration; load it with them applied.
+    pub fn with_env_overrides(mut self) -> Self {
+        self.env_overrides = true;
+        self
+    }
+
+    /// Apply the named profile of the backing file whenever it is reloaded.
+    ///
+    /// Like [`with_env_overrides`](Self::with_env_overrides), this doesn't change the current
+    /// configuration; load it with [`ExtractionConfig::from_file_with_profile`].
+    pub fn with_profile(mut self, name: impl Into<String>) -> Self {
+        self.profile = Some(name.into());
+        self
+    }
+
+    /// Load the plugin registries from `dirs` with `loader`, now and on every reload.
+    ///
+    /// Kreuzberg doesn't load plugin code itself: the loader builds fresh registries (see
+    /// [`PluginRegistries::new`]) from whatever the directories contain, for example with a
+    /// language binding that imports plugin modules. Extractions with the handle's configuration
+    /// use these registries instead of the global ones.
+    ///
+    /// # Errors
+    ///
+    /// Returns the loader's error if the registries cannot be loaded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kreuzberg::{ConfigHandle, ExtractionConfig, PluginRegistries};
+    ///
+    /// let handle = ConfigHandle::new(ExtractionConfig::default())
+    ///     .with_plugin_loader(["plugins"], |_dirs| Ok(PluginRegistries::new()))?;
+    ///
+    /// assert!(!handle.registries().is_global());
+    /// # Ok::<(), kreuzberg::KreuzbergError>(())
+    /// ```
+    pub fn with_plugin_loader<F>(
+        mut self,
+        dirs: impl IntoIterator<Item = impl Into<PathBuf>>,
+        loader: F,
+    ) -> Result<Self>
+    where
+        F: Fn(&[PathBuf]) -> Result<PluginRegistries> + Send + Sync + 'static,
+    {
+        let plugins = PluginSource {
+            dirs: dirs.into_iter().map(Into::into).collect(),
+            loader: Arc::new(loader),
+        };
+
+        let mut config = (*self.current()).clone();
+        config.context.registries = Some((plugins.loader)(&plugins.dirs)?);
+        self.current.store(Arc::new(config));
+        self.plugins = Some(plugins);
+
+        Ok(self)
+    }
+
+    /// Load a named profile of the backing config file.
+    ///
+    /// The file is read on every call, so the profile always matches the file on disk.
+    /// `KREUZBERG_*` environment overrides are layered over it if the handle applies them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the handle has no backing file, the file doesn't define the
+    /// profile, or the resulting configuration is invalid.
+    pub fn load_profile(&self, name: &str) -> Result<ExtractionConfig> {
+        let Some(source) = self.source.as_ref() else {
+            return Err(KreuzbergError::validation(format!(
+                "Cannot load profile '{}': the configuration has no backing file",
+                name
+            )));
+        };
+
+        let mut config = ExtractionConfig::from_file_with_profile(source, name)?;
+        if self.env_overrides {
+            config.apply_env_overrides()?;
+        }
+        config.context.registries = self.current().context.registries.clone();
+        Ok(config)
+    }
+
+    /// Snapshot of the current configuration.
+    pub fn current(&self) -> Arc<ExtractionConfig> {
+        self.current.load_full()
+    }
+
+    /// Plugin registries extractions with the current configuration use.
+    ///
+    /// The global registries unless the handle has a [plugin loader](Self::with_plugin_loader).
+    pub fn registries(&self) -> PluginRegistries {
+        self.current().context.registries()
+    }
+
+    /// Path of the backing config file, if any.
+    pub fn source(&self) -> Option<&Path> {
+        self.source.as_deref()
+    }
+
+    /// Plugin directories the registries are loaded from, if the handle has a plugin loader.
+    pub fn plugin_dirs(&self) -> &[PathBuf] {
+        self.plugins
+            .as_ref()
+            .map(|plugins| plugins.dirs.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Atomically replace the current configuration.
+    ///
+    /// The handle keeps its plugin registries.
+    pub fn replace(&self, mut config: ExtractionConfig) {
+        config.context.registries = self.current().context.registries.clone();
+        self.current.store(Arc::new(config));
+    }
+
+    /// Change selected fields of the current configuration without a restart.
+    ///
+    /// The updated configuration is validated (see [`ExtractionConfig::validate`]) before it is
+    /// swapped in; if it is invalid, the current configuration stays active. Every changed field
+    /// is logged. Updates apply to the in-memory configuration only and are replaced when the
+    /// backing file is reloaded.
+    ///
+    /// # Returns
+    ///
+    /// The fields whose value changed, with their previous and new values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` if the update produces an invalid configuration.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kreuzberg::{ConfigHandle, ConfigUpdate, ExtractionConfig};
+    ///
+    /// let handle = ConfigHandle::new(ExtractionConfig::default());
+    /// let changes = handle.update(&ConfigUpdate {
+    ///     ocr_language: Some("deu".to_string()),
+    ///     ..Default::default()
+    /// })?;
+    ///
+    /// assert_eq!(changes[0].field, "ocr_language");
+    /// assert_eq!(handle.current().ocr.as_ref().unwrap().language, "deu");
+    /// # Ok::<(), kreuzberg::KreuzbergError>(())
+    /// ```
+    pub fn update(&self, update: &ConfigUpdate) -> Result<Vec<ConfigChange>> {
+        loop {
+            let current = self.current.load_full();
+            let mut config = (*current).clone();
+            let changes = update.apply(&mut config)?;
+            if changes.is_empty() {
+                return Ok(changes);
+            }
+            config.validate()?;
+
+            // Only swap if no reload or other update happened in the meantime; retry otherwise.
+            let previous = self.current.compare_and_swap(&current, Arc::new(config));
+            if Arc::ptr_eq(&previous, &current) {
+                for change in &changes {
+                    tracing::info!(
+                        "Updated extraction config: {} changed from {} to {}",
+                        change.field,
+                        change.previous,
+                        change.current
+                    );
+                }
+                return Ok(changes);
+            }
+        }
+    }
+
+    /// Re-read the backing config file, reload the plugin registries and swap both in.
+    ///
+    /// The configuration and the registries are swapped together, in a single step. The
+    /// previous ones stay active if the file is missing or invalid or the plugins fail to load.
+    /// This reads files and runs the plugin loader on the calling thread; async code should
+    /// use [`reload_async`](Self::reload_async).
+    ///
+    /// # Returns
+    ///
+    /// `true` if a new configuration was loaded, `false` if the handle has neither a backing
+    /// file nor a plugin loader.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or the plugin loader fails.
+    pub fn reload(&self) -> Result<bool> {
+        if self.source.is_none() && self.plugins.is_none() {
+            return Ok(false);
+        }
+
+        let mut config = match &self.source {
+            Some(source) => {
+                let mut config = match &self.profile {
+                    Some(profile) => ExtractionConfig::from_file_with_profile(source, profile)?,
+                    None => ExtractionConfig::from_file(source)?,
+                };
+                if self.env_overrides {
+                    config.apply_env_overrides()?;
+                }
+                config
+            }
+            None => (*self.current()).clone(),
+        };
+        config.context.registries = match &self.plugins {
+            Some(plugins) => Some((plugins.loader)(&plugins.dirs)?),
+            None => self.current().context.registries.clone(),
+        };
+        self.current.store(Arc::new(config));
+
+        if let Some(source) = &self.source {
+            tracing::info!("Reloaded extraction config from {}", source.display());
+        }
+        if let Some(plugins) = &self.plugins {
+            tracing::info!("Reloaded plugins from {} plugin directories", plugins.dirs.len());
+        }
+
+        Ok(true)
+    }
+
+    /// [`reload`](Self::reload) on a blocking thread, for use in async code.
+    #[cfg(feature = "tokio-runtime")]
+    pub async fn reload_async(&self) -> Result<bool> {
+        let handle = self.clone();
+        tokio::task::spawn_blocking(move || handle.reload())
+            .await
+            .map_err(|e| KreuzbergError::Other(format!("Config reload panicked: {}", e)))?
+    }
+
+    /// Modification times of the backing file and of the files in the plugin directories.
+    #[cfg(feature = "tokio-runtime")]
+    fn watched_files(&self) -> WatchedFiles {
+        let mut plugins = Vec::new();
+        for dir in self.plugin_dirs() {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if let Some(modified) = modified_time(&path) {
+                    plugins.push((path, modified));
+                }
+            }
+        }
+        plugins.sort();
+
+        WatchedFiles {
+            config: self.source.as_deref().and_then(modified_time),
+            plugins,
+        }
+    }
+
+    /// Poll the backing config file and the plugin directories and reload whenever a
+    /// modification time changes or a file is added to or removed from a plugin directory.
+    ///
+    /// Reload errors are logged and the previous configuration and plugins are kept. The
+    /// returned task runs until aborted. Handles with neither a backing file nor a plugin
+    /// loader return a task that exits immediately.
+    #[cfg(feature = "tokio-runtime")]
+    pub fn watch(&self, interval: std::time::Duration) -> tokio::task::JoinHandle<()> {
+        let handle = self.clone();
+
+        tokio::spawn(async move {
+            if handle.source.is_none() && handle.plugins.is_none() {
+                return;
+            }
+
+            let Ok(mut last_seen) = scan(&handle).await else {
+                return;
+            };
+            let mut ticker = tokio::time::interval(interval);
+            ticker.tick().await;
+
+            loop {
+                ticker.tick().await;
+
+                let Ok(seen) = scan(&handle).await else {
+                    continue;
+                };
+                // A missing config file is usually being rewritten; wait until it is back
+                if (handle.source.is_some() && seen.config.is_none()) || seen == last_seen {
+                    continue;
+                }
+                last_seen = seen;
+
+                if let Err(e) = handle.reload_async().await {
+                    tracing::warn!("Failed to reload extraction config, keeping previous config: {}", e);
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Debug for ConfigHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConfigHandle")
+            .field("current", &self.current)
+            .field("source", &self.source)
+            .field("env_overrides", &self.env_overrides)
+            .field("profile", &self.profile)
+            .field("plugin_dirs", &self.plugin_dirs())
+            .finish()
+    }
+}
+
+impl Default for ConfigHandle {
+    fn default() -> Self {
+        Self::new(ExtractionConfig::default())
+    }
+}
+
+impl From<ExtractionConfig> for ConfigHandle {
+    fn from(config: ExtractionConfig) -> Self {
+        Self::new(config)
+    }
+}
+
+/// Files [`ConfigHandle::watch`] polls for changes.
+#[cfg(feature = "tokio-runtime")]
+#[derive(Debug, PartialEq)]
+struct WatchedFiles {
+    config: Option<SystemTime>,
+    plugins: Vec<(PathBuf, SystemTime)>,
+}
+
+#[cfg(feature = "tokio-runtime")]
+async fn scan(handle: &ConfigHandle) -> std::result::Result<WatchedFiles, tokio::task::JoinError> {
+    let handle = handle.clone();
+    tokio::task::spawn_blocking(move || handle.watched_files()).await
+}
+
+#[cfg(feature = "tokio-runtime")]
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_reload_swaps_config() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("kreuzberg.toml");
+        fs::write(&path, "use_cache = true\n").unwrap();
+
+        let handle = ConfigHandle::from_file(&path).unwrap();
+        let before = handle.current();
+        assert!(before.use_cache);
+
+        fs::write(&path, "use_cache = false\n").unwrap();
+        assert!(handle.reload().unwrap());
+
+        assert!(!handle.current().use_cache);
+        assert!(before.use_cache);
+    }
+
+    #[test]
+    fn test_reload_keeps_previous_config_on_error() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("kreuzberg.toml");
+        fs::write(&path, "force_ocr = true\n").unwrap();
+
+        let handle = ConfigHandle::from_file(&path).unwrap();
+        fs::write(&path, "force_ocr = \"not a bool\"\n").unwrap();
+
+        assert!(handle.reload().is_err());
+        assert!(handle.current().force_ocr);
+    }
+
+    #[test]
+    fn test_reload_applies_profile() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("kreuzberg.toml");
+        fs::write(&path, "use_cache = true\n\n[profile.fast]\nuse_cache = false\n").unwrap();
+
+        let handle = ConfigHandle::from_file(&path).unwrap().with_profile("fast");
+        assert!(handle.current().use_cache);
+        assert!(!handle.load_profile("fast").unwrap().use_cache);
+        assert!(handle.load_profile("slow").is_err());
+
+        assert!(handle.reload().unwrap());
+        assert!(!handle.current().use_cache);
+    }
+
+    #[test]
+    fn test_reload_without_source() {
+        let handle = ConfigHandle::new(ExtractionConfig::default());
+        assert!(handle.source().is_none());
+        assert!(handle.registries().is_global());
+        assert!(!handle.reload().unwrap());
+
+        handle.replace(ExtractionConfig {
+            force_ocr: true,
+            ..Default::default()
+        });
+        assert!(handle.current().force_ocr);
+    }
+
+    #[test]
+    fn test_reload_swaps_plugin_registries() {
+        let loads = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&loads);
+        let handle = ConfigHandle::new(ExtractionConfig::default())
+            .with_plugin_loader(["plugins"], move |dirs| {
+                assert_eq!(dirs, [PathBuf::from("plugins")]);
+                if counter.fetch_add(1, Ordering::SeqCst) == 2 {
+                    return Err(KreuzbergError::Other("broken plugin".to_string()));
+                }
+                Ok(PluginRegistries::new())
+            })
+            .unwrap();
+        assert_eq!(handle.plugin_dirs(), [PathBuf::from("plugins")]);
+
+        let before = handle.registries();
+        assert!(!before.is_global());
+        handle.replace(ExtractionConfig {
+            force_ocr: true,
+            ..Default::default()
+        });
+        assert!(Arc::ptr_eq(&handle.registries().ocr_backends, &before.ocr_backends));
+
+        assert!(handle.reload().unwrap());
+        let after = handle.registries();
+        assert!(!Arc::ptr_eq(&after.ocr_backends, &before.ocr_backends));
+        assert!(handle.current().force_ocr);
+
+        assert!(handle.reload().is_err());
+        assert!(Arc::ptr_eq(&handle.registries().ocr_backends, &after.ocr_backends));
+        assert_eq!(loads.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_update_changes_selected_fields() {
+        let handle = ConfigHandle::new(ExtractionConfig::default());
+        let before = handle.current();
+
+        let changes = handle
+            .update(&ConfigUpdate {
+                ocr_language: Some("deu".to_string()),
+                use_cache: Some(false),
+                max_concurrent_extractions: Some(4),
+            })
+            .unwrap();
+
+        let fields: Vec<&str> = changes.iter().map(|change| change.field.as_str()).collect();
+        assert_eq!(fields, vec!["ocr_language", "use_cache", "max_concurrent_extractions"]);
+        assert_eq!(changes[1].previous, serde_json::json!(true));
+        assert_eq!(changes[1].current, serde_json::json!(false));
+
+        let current = handle.current();
+        assert_eq!(current.ocr.as_ref().unwrap().backend, "tesseract");
+        assert_eq!(current.ocr.as_ref().unwrap().language, "deu");
+        assert!(!current.use_cache);
+        assert_eq!(current.max_concurrent_extractions, Some(4));
+        assert!(before.use_cache);
+
+        let unchanged = handle
+            .update(&ConfigUpdate {
+                use_cache: Some(false),
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(unchanged.is_empty());
+    }
+
+    #[test]
+    fn test_update_rejects_invalid_values() {
+        let handle = ConfigHandle::new(ExtractionConfig::default());
+
+        let invalid = [
+            ConfigUpdate {
+                max_concurrent_extractions: Some(0),
+                ..Default::default()
+            },
+            ConfigUpdate {
+                ocr_language: Some(" ".to_string()),
+                use_cache: Some(false),
+                ..Default::default()
+            },
+        ];
+        for update in &invalid {
+            assert!(handle.update(update).is_err());
+        }
+        assert!(handle.current().use_cache);
+        assert!(serde_json::from_str::<ConfigUpdate>(r#"{"force_ocr": true}"#).is_err());
+    }
+}
@@ -0,0 +1,173 @@
+//! Retries for transient extraction failures.
+//!
+//! [`with_retry`] re-runs an extraction step according to [`RetryConfig`]. Only steps that
+//! depend on external state are wrapped: OCR backend calls, LibreOffice conversions and
+//! extractors that shell out to `pffexport` (see [`applies_to`]). Deterministic parse failures
+//! of in-process extractors are never retried, even when the document also needs OCR.
+
+use crate::core::config::{ExtractionConfig, RetryConfig};
+use crate::core::mime::{OST_MIME_TYPE, PST_MIME_TYPE};
+use crate::{KreuzbergError, Result};
+use std::future::Future;
+use std::time::Duration;
+
+/// MIME types whose extractors run an external program.
+const SUBPROCESS_MIME_TYPES: &[&str] = &[PST_MIME_TYPE, OST_MIME_TYPE];
+
+/// Whether the whole extraction of `mime_type` runs an external program and may hit a transient
+/// failure worth retrying. OCR is retried per backend call instead.
+pub(crate) fn applies_to(mime_type: &str) -> bool {
+    SUBPROCESS_MIME_TYPES.contains(&mime_type)
+}
+
+/// Run `operation` until it succeeds, fails with a non-retryable error, or
+/// `retry.max_attempts` is reached. Without `config.retry` it runs exactly once.
+///
+/// `step` names the operation in the warning logged before each retry.
+pub(crate) async fn with_retry<T, F, Fut>(config: &ExtractionConfig, step: &str, mut operation: F) -> Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let Some(retry) = &config.retry else {
+        return operation().await;
+    };
+
+    let mut attempt = 1;
+    loop {
+        match operation().await {
+            Err(error) if attempt < retry.max_attempts && should_retry(retry, &error) => {
+                let delay = backoff(retry, attempt);
+                tracing::warn!(
+                    step,
+                    attempt,
+                    max_attempts = retry.max_attempts,
+                    delay_ms = delay.as_millis() as u64,
+                    code = error.code().as_str(),
+                    "Retrying after failure: {}",
+                    error
+                );
+                sleep(delay).await;
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+fn should_retry(retry: &RetryConfig, error: &KreuzbergError) -> bool {
+    error.is_retryable() || retry.retry_on.contains(&error.code())
+}
+
+/// Delay after the given (1-based) failed attempt.
+fn backoff(retry: &RetryConfig, attempt: u32) -> Duration {
+    let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+    let delay_ms = retry.initial_backoff_ms as f64 * retry.backoff_multiplier.powi(exponent);
+    Duration::from_millis(delay_ms.min(retry.max_backoff_ms as f64) as u64)
+}
+
+#[cfg(feature = "tokio-runtime")]
+async fn sleep(delay: Duration) {
+    tokio::time::sleep(delay).await;
+}
+
+/// Without a timer (WASM builds), retries run back to back.
+#[cfg(not(feature = "tokio-runtime"))]
+async fn sleep(_delay: Duration) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorCode;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn config(retry: RetryConfig) -> ExtractionConfig {
+        ExtractionConfig {
+            retry: Some(RetryConfig {
+                initial_backoff_ms: 0,
+                ..retry
+            }),
+            ..Default::default()
+        }
+    }
+
+    async fn fail_until(
+        config: &ExtractionConfig,
+        succeed_on: u32,
+        error: fn() -> KreuzbergError,
+    ) -> (Result<u32>, u32) {
+        let counter = AtomicU32::new(0);
+        let attempts = &counter;
+        let result = with_retry(config, "test", || async move {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst) + 1;
+            if attempt >= succeed_on {
+                Ok(attempt)
+            } else {
+                Err(error())
+            }
+        })
+        .await;
+        (result, counter.load(Ordering::SeqCst))
+    }
+
+    #[tokio::test]
+    async fn test_retries_listed_codes_until_success() {
+        let config = config(RetryConfig::default());
+
+        let (result, attempts) = fail_until(&config, 3, || KreuzbergError::ocr("tesseract crashed")).await;
+
+        assert_eq!(result.unwrap(), 3);
+        assert_eq!(attempts, 3);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let config = config(RetryConfig {
+            max_attempts: 2,
+            ..Default::default()
+        });
+
+        let (result, attempts) = fail_until(&config, 5, || KreuzbergError::ocr("tesseract crashed")).await;
+
+        assert_eq!(result.unwrap_err().code(), ErrorCode::Ocr);
+        assert_eq!(attempts, 2);
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_other_errors_or_without_config() {
+        let config = config(RetryConfig::default());
+        let (result, attempts) = fail_until(&config, 3, || KreuzbergError::parsing("bad input")).await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+
+        let transient = || KreuzbergError::Io(std::io::Error::from(std::io::ErrorKind::TimedOut));
+        let (result, attempts) = fail_until(&config, 2, transient).await;
+        assert_eq!(result.unwrap(), 2);
+        assert_eq!(attempts, 2);
+
+        let (result, attempts) = fail_until(&ExtractionConfig::default(), 3, transient).await;
+        assert!(result.is_err());
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let retry = RetryConfig {
+            initial_backoff_ms: 500,
+            backoff_multiplier: 2.0,
+            max_backoff_ms: 1_500,
+            ..Default::default()
+        };
+
+        assert_eq!(backoff(&retry, 1), Duration::from_millis(500));
+        assert_eq!(backoff(&retry, 2), Duration::from_millis(1_000));
+        assert_eq!(backoff(&retry, 3), Duration::from_millis(1_500));
+    }
+
+    #[test]
+    fn test_applies_only_to_subprocess_extractors() {
+        assert!(applies_to(PST_MIME_TYPE));
+        assert!(applies_to(OST_MIME_TYPE));
+        assert!(!applies_to("application/pdf"));
+    }
+}
@@ -0,0 +1,320 @@
+//! Human-review flagging.
+//!
+//! [`attach_review`] runs after [`attach_stats`](crate::core::stats::attach_stats) and
+//! combines the signals other stages leave on a result into `metadata.review`: the mean OCR
+//! confidence, the quality score, the quality gate verdict and limit truncations. Validator
+//! rejections are recorded by the pipeline through [`record_validator_failure`] when
+//! `review.flag_validator_failures` is set, so they end up in the same reasons list instead
+//! of failing the extraction. With `validation.mode = "annotate"` they are also kept as error
+//! findings in `metadata.findings`, next to the findings validators report through
+//! [`record_findings`].
+
+use crate::core::config::{ExtractionConfig, ValidationMode};
+use crate::core::limits::LIMITS_EXCEEDED_KEY;
+use crate::types::{ExtractionResult, FindingSeverity, Metadata, ReviewReason, ReviewStatus, ValidationFinding};
+use crate::{KreuzbergError, Result};
+use serde_json::Value;
+
+/// Metadata key of the score written by quality processing.
+const QUALITY_SCORE_KEY: &str = "quality_score";
+
+/// Metadata key set by archive extractors that skipped entries because of archive limits.
+const ARCHIVE_TRUNCATED_KEY: &str = "truncated";
+
+/// Finding code of validator rejections recorded in annotate mode.
+const VALIDATION_FAILED_CODE: &str = "validation_failed";
+
+/// Record a validator rejection, or return it when neither `config.review` nor
+/// `config.validation` ask for that.
+///
+/// In annotate mode the rejection becomes an error finding; with
+/// `review.flag_validator_failures` it also becomes a review reason. Only validation errors are
+/// recorded; other errors are always returned.
+pub(crate) fn record_validator_failure(
+    result: &mut ExtractionResult,
+    config: &ExtractionConfig,
+    validator: &str,
+    error: KreuzbergError,
+) -> Result<()> {
+    let KreuzbergError::Validation { message, .. } = &error else {
+        return Err(error);
+    };
+    let annotate = validation_mode(config) == ValidationMode::Annotate;
+    let flag = matches!(&config.review, Some(review) if review.flag_validator_failures);
+    if !annotate && !flag {
+        return Err(error);
+    }
+
+    if annotate {
+        tracing::debug!("Validator '{}' rejected the result, recording a finding", validator);
+        let finding = ValidationFinding::new(FindingSeverity::Error, VALIDATION_FAILED_CODE, message.clone());
+        push_findings(result, validator, vec![finding]);
+    }
+    if flag {
+        tracing::debug!("Validator '{}' rejected the result, flagging for review", validator);
+        let status = result.metadata.review.get_or_insert_with(ReviewStatus::default);
+        status.required = true;
+        status.reasons.push(ReviewReason::ValidatorFailed {
+            validator: validator.to_string(),
+            message: message.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// Store the findings a validator reported in `metadata.findings`.
+///
+/// In reject mode the first error finding rejects the result like a validation error returned
+/// by the validator, unless `review.flag_validator_failures` flags it for review instead.
+pub(crate) fn record_findings(
+    result: &mut ExtractionResult,
+    config: &ExtractionConfig,
+    validator: &str,
+    findings: Vec<ValidationFinding>,
+) -> Result<()> {
+    let rejection = findings
+        .iter()
+        .find(|finding| finding.severity == FindingSeverity::Error)
+        .map(|finding| finding.message.clone());
+
+    push_findings(result, validator, findings);
+    match rejection {
+        Some(message) if validation_mode(config) == ValidationMode::Reject => {
+            record_validator_failure(result, config, validator, KreuzbergError::validation(message))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn validation_mode(config: &ExtractionConfig) -> ValidationMode {
+    match &config.validation {
+        Some(validation) => validation.mode,
+        None => ValidationMode::default(),
+    }
+}
+
+fn push_findings(result: &mut ExtractionResult, validator: &str, findings: Vec<ValidationFinding>) {
+    if findings.is_empty() {
+        return;
+    }
+    let recorded = result.metadata.findings.get_or_insert_with(Vec::new);
+    recorded.extend(findings.into_iter().map(|mut finding| {
+        finding.validator = validator.to_string();
+        finding
+    }));
+}
+
+/// Evaluate the review signals of `result` and store the outcome in `metadata.review`.
+///
+/// Does nothing unless `config.review` is set.
+pub(crate) fn attach_review(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    let Some(review) = &config.review else {
+        return;
+    };
+
+    let mut reasons = result.metadata.review.take().unwrap_or_default().reasons;
+    let metadata = &result.metadata;
+
+    if let Some(threshold) = review.min_ocr_confidence
+        && let Some(confidence) = metadata.stats.as_ref().and_then(|stats| stats.ocr_confidence)
+        && confidence < threshold
+    {
+        reasons.push(ReviewReason::LowOcrConfidence { confidence, threshold });
+    }
+
+    if let Some(threshold) = review.min_quality_score
+        && let Some(score) = metadata.additional.get(QUALITY_SCORE_KEY).and_then(Value::as_f64)
+        && score < threshold
+    {
+        reasons.push(ReviewReason::LowQualityScore { score, threshold });
+    }
+
+    #[cfg(feature = "quality")]
+    if review.flag_low_quality && is_set(metadata, crate::text::quality_gate::LOW_QUALITY_KEY) {
+        reasons.push(ReviewReason::LowQuality);
+    }
+
+    if review.flag_truncation {
+        if let Some(limits) = metadata.additional.get(LIMITS_EXCEEDED_KEY).and_then(Value::as_array) {
+            let limits = limits.iter().filter_map(Value::as_str).map(str::to_string).collect();
+            reasons.push(ReviewReason::LimitsExceeded { limits });
+        }
+        if is_set(metadata, ARCHIVE_TRUNCATED_KEY) {
+            reasons.push(ReviewReason::ArchiveTruncated);
+        }
+    }
+
+    result.metadata.review = Some(ReviewStatus {
+        required: !reasons.is_empty(),
+        reasons,
+    });
+}
+
+fn is_set(metadata: &Metadata, key: &str) -> bool {
+    metadata.additional.get(key) == Some(&Value::Bool(true))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::{ReviewConfig, ValidationConfig};
+    use crate::types::{ExtractionStats, FindingLocation};
+
+    fn result_with(additional: &[(&str, Value)]) -> ExtractionResult {
+        let mut metadata = Metadata::default();
+        for (key, value) in additional {
+            metadata.additional.insert(key.to_string(), value.clone());
+        }
+        ExtractionResult {
+            content: "Scanned text".to_string(),
+            mime_type: "application/pdf".to_string(),
+            metadata,
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn review_config() -> ExtractionConfig {
+        ExtractionConfig {
+            review: Some(ReviewConfig::default()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_attach_review_without_config_is_noop() {
+        let mut result = result_with(&[(QUALITY_SCORE_KEY, serde_json::json!(0.1))]);
+        attach_review(&mut result, &ExtractionConfig::default());
+        assert!(result.metadata.review.is_none());
+    }
+
+    #[test]
+    fn test_attach_review_passes_clean_result() {
+        let mut result = result_with(&[(QUALITY_SCORE_KEY, serde_json::json!(0.9))]);
+        attach_review(&mut result, &review_config());
+        assert_eq!(result.metadata.review, Some(ReviewStatus::default()));
+    }
+
+    #[test]
+    fn test_attach_review_collects_reasons() {
+        let mut result = result_with(&[
+            (QUALITY_SCORE_KEY, serde_json::json!(0.2)),
+            (LIMITS_EXCEEDED_KEY, serde_json::json!(["max_pages"])),
+            (ARCHIVE_TRUNCATED_KEY, serde_json::json!(true)),
+        ]);
+        result.metadata.stats = Some(ExtractionStats {
+            ocr_confidence: Some(0.4),
+            ..Default::default()
+        });
+
+        attach_review(&mut result, &review_config());
+
+        let review = result.metadata.review.unwrap();
+        assert!(review.required);
+        assert_eq!(
+            review.reasons,
+            vec![
+                ReviewReason::LowOcrConfidence {
+                    confidence: 0.4,
+                    threshold: 0.6,
+                },
+                ReviewReason::LowQualityScore {
+                    score: 0.2,
+                    threshold: 0.5,
+                },
+                ReviewReason::LimitsExceeded {
+                    limits: vec!["max_pages".to_string()],
+                },
+                ReviewReason::ArchiveTruncated,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_attach_review_respects_disabled_signals() {
+        let mut result = result_with(&[(LIMITS_EXCEEDED_KEY, serde_json::json!(["max_output_chars"]))]);
+        let config = ExtractionConfig {
+            review: Some(ReviewConfig {
+                flag_truncation: false,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        attach_review(&mut result, &config);
+
+        assert!(!result.metadata.review.unwrap().required);
+    }
+
+    #[test]
+    fn test_record_validator_failure() {
+        let mut result = result_with(&[]);
+        let rejection = || KreuzbergError::validation("too short");
+
+        let outcome = record_validator_failure(&mut result, &ExtractionConfig::default(), "length", rejection());
+        assert!(matches!(outcome, Err(KreuzbergError::Validation { .. })));
+
+        let config = review_config();
+        record_validator_failure(&mut result, &config, "length", rejection()).unwrap();
+        let other_error = KreuzbergError::Other("boom".to_string());
+        assert!(record_validator_failure(&mut result, &config, "length", other_error).is_err());
+
+        attach_review(&mut result, &config);
+        let review = result.metadata.review.unwrap();
+        assert!(review.required);
+        assert_eq!(
+            review.reasons,
+            vec![ReviewReason::ValidatorFailed {
+                validator: "length".to_string(),
+                message: "too short".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_record_findings() {
+        let short = ValidationFinding::new(FindingSeverity::Warning, "content_too_short", "Only 12 characters");
+        let blank_page = FindingLocation {
+            page: Some(2),
+            ..Default::default()
+        };
+        let blank = ValidationFinding::new(FindingSeverity::Error, "blank_page", "Page 2 is blank").at(blank_page);
+
+        let mut result = result_with(&[]);
+        let config = ExtractionConfig::default();
+        record_findings(&mut result, &config, "length", vec![short]).unwrap();
+        let outcome = record_findings(&mut result, &config, "pages", vec![blank.clone()]);
+        assert!(matches!(outcome, Err(KreuzbergError::Validation { .. })));
+
+        let mut result = result_with(&[]);
+        let annotate = ExtractionConfig {
+            validation: Some(ValidationConfig {
+                mode: ValidationMode::Annotate,
+            }),
+            ..Default::default()
+        };
+        record_findings(&mut result, &annotate, "pages", vec![blank]).unwrap();
+        let too_short = KreuzbergError::validation("too short");
+        record_validator_failure(&mut result, &annotate, "length", too_short).unwrap();
+
+        let findings = result.metadata.findings.unwrap();
+        let recorded: Vec<_> = findings
+            .iter()
+            .map(|f| (f.validator.as_str(), f.code.as_str()))
+            .collect();
+        assert_eq!(recorded, vec![("pages", "blank_page"), ("length", "validation_failed")]);
+        assert_eq!(
+            findings[0].location.as_ref().and_then(|location| location.page),
+            Some(2)
+        );
+        assert!(result.metadata.review.is_none());
+    }
+}
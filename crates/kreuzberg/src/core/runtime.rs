@@ -0,0 +1,178 @@
+//! Dedicated thread pools for CPU-bound extraction work.
+//!
+//! CPU-heavy steps (PDF rendering, OCR, Office parsing, token reduction) run on pools owned
+//! by Kreuzberg rather than on the caller's runtime. This keeps heavy batch loads from
+//! starving the blocking pool of a host process such as a Node.js or Python application.
+//!
+//! - [`spawn_blocking`] runs closures on the blocking pool of Kreuzberg's global Tokio runtime
+//!   (the one behind the `*_sync` functions), capped at [`ThreadPoolConfig::max_blocking_threads`].
+//! - [`install`] runs parallel iterators on a dedicated rayon pool sized by
+//!   [`ThreadPoolConfig::rayon_threads`].
+//!
+//! Pools are created lazily on first use. Call [`configure_thread_pools`] before the first
+//! extraction to size them, or set the `KREUZBERG_RAYON_THREADS`,
+//! `KREUZBERG_WORKER_THREADS` and `KREUZBERG_MAX_BLOCKING_THREADS` environment variables.
+
+use crate::{KreuzbergError, Result};
+use once_cell::sync::{Lazy, OnceCell};
+use serde::{Deserialize, Serialize};
+
+/// Environment variable setting [`ThreadPoolConfig::rayon_threads`].
+pub const RAYON_THREADS_ENV: &str = "KREUZBERG_RAYON_THREADS";
+/// Environment variable setting [`ThreadPoolConfig::worker_threads`].
+pub const WORKER_THREADS_ENV: &str = "KREUZBERG_WORKER_THREADS";
+/// Environment variable setting [`ThreadPoolConfig::max_blocking_threads`].
+pub const MAX_BLOCKING_THREADS_ENV: &str = "KREUZBERG_MAX_BLOCKING_THREADS";
+
+/// Sizing of Kreuzberg's internal thread pools.
+///
+/// `None` fields use the library defaults (number of CPU cores for rayon and Tokio workers,
+/// Tokio's default of 512 for blocking threads).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThreadPoolConfig {
+    /// Number of threads in the rayon pool used for parallel text and OCR work
+    #[serde(default)]
+    pub rayon_threads: Option<usize>,
+
+    /// Number of worker threads of the global Tokio runtime
+    #[serde(default)]
+    pub worker_threads: Option<usize>,
+
+    /// Maximum number of blocking threads of the global Tokio runtime
+    #[serde(default)]
+    pub max_blocking_threads: Option<usize>,
+}
+
+impl ThreadPoolConfig {
+    /// Read pool sizes from the `KREUZBERG_*_THREADS` environment variables.
+    pub fn from_env() -> Self {
+        Self {
+            rayon_threads: env_threads(RAYON_THREADS_ENV),
+            worker_threads: env_threads(WORKER_THREADS_ENV),
+            max_blocking_threads: env_threads(MAX_BLOCKING_THREADS_ENV),
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        for (name, value) in [
+            ("rayon_threads", self.rayon_threads),
+            ("worker_threads", self.worker_threads),
+            ("max_blocking_threads", self.max_blocking_threads),
+        ] {
+            if value == Some(0) {
+                return Err(KreuzbergError::validation(format!("{} must be greater than 0", name)));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn env_threads(name: &str) -> Option<usize> {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse::<usize>().ok())
+        .filter(|&value| value > 0)
+}
+
+static POOL_CONFIG: OnceCell<ThreadPoolConfig> = OnceCell::new();
+
+/// Configure Kreuzberg's internal thread pools.
+///
+/// Must be called before the first extraction; pools cannot be resized once created.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if a size is zero or the pools were already configured
+/// (explicitly or implicitly by a previous extraction).
+pub fn configure_thread_pools(config: ThreadPoolConfig) -> Result<()> {
+    config.validate()?;
+    POOL_CONFIG
+        .set(config)
+        .map_err(|_| KreuzbergError::validation("Thread pools are already initialized"))
+}
+
+/// The active thread pool configuration.
+pub fn thread_pool_config() -> &'static ThreadPoolConfig {
+    POOL_CONFIG.get_or_init(ThreadPoolConfig::from_env)
+}
+
+static RAYON_POOL: Lazy<Option<rayon::ThreadPool>> = Lazy::new(|| {
+    let mut builder = rayon::ThreadPoolBuilder::new().thread_name(|i| format!("kreuzberg-rayon-{}", i));
+    if let Some(threads) = thread_pool_config().rayon_threads {
+        builder = builder.num_threads(threads);
+    }
+
+    match builder.build() {
+        Ok(pool) => Some(pool),
+        Err(e) => {
+            tracing::warn!("Failed to create rayon pool, falling back to the global pool: {}", e);
+            None
+        }
+    }
+});
+
+/// Run `op` inside Kreuzberg's rayon pool.
+///
+/// Parallel iterators used within `op` are scheduled on the dedicated pool.
+pub fn install<OP, R>(op: OP) -> R
+where
+    OP: FnOnce() -> R + Send,
+    R: Send,
+{
+    match RAYON_POOL.as_ref() {
+        Some(pool) => pool.install(op),
+        None => op(),
+    }
+}
+
+/// Number of threads available to [`install`].
+pub fn current_num_threads() -> usize {
+    match RAYON_POOL.as_ref() {
+        Some(pool) => pool.current_num_threads(),
+        None => rayon::current_num_threads(),
+    }
+}
+
+/// Run a blocking closure on the blocking pool of Kreuzberg's global runtime.
+///
+/// Unlike [`tokio::task::spawn_blocking`], this does not consume threads from the caller's
+/// runtime. The returned handle can be awaited from any runtime.
+#[cfg(feature = "tokio-runtime")]
+pub fn spawn_blocking<F, R>(f: F) -> tokio::task::JoinHandle<R>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    crate::core::extractor::GLOBAL_RUNTIME.spawn_blocking(f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_rejects_zero() {
+        let config = ThreadPoolConfig {
+            rayon_threads: Some(0),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+        assert!(ThreadPoolConfig::default().validate().is_ok());
+    }
+
+    #[test]
+    fn test_install_runs_on_pool() {
+        use rayon::prelude::*;
+
+        let sum: u64 = install(|| (1..=100u64).into_par_iter().sum());
+        assert_eq!(sum, 5050);
+        assert!(current_num_threads() >= 1);
+    }
+
+    #[cfg(feature = "tokio-runtime")]
+    #[tokio::test]
+    async fn test_spawn_blocking_from_caller_runtime() {
+        let value = spawn_blocking(|| 21 * 2).await.unwrap();
+        assert_eq!(value, 42);
+    }
+}
@@ -0,0 +1,336 @@
+//! Extractors with isolated plugin registries.
+//!
+//! The free extraction functions ([`extract_file`](crate::extract_file), [`extract_bytes`](crate::extract_bytes), ...)
+//! resolve plugins from process-wide global registries. An [`Extractor`] owns its own
+//! [`PluginRegistries`] instead, so several independently configured extractors can run
+//! side by side in one process without seeing each other's plugins.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::{ExtractionConfig, ExtractorBuilder};
+//!
+//! # async fn example() -> kreuzberg::Result<()> {
+//! let extractor = ExtractorBuilder::new()
+//!     .with_default_extractors()
+//!     .with_config(ExtractionConfig::default())
+//!     .build()?;
+//!
+//! let result = extractor.extract_file("document.pdf", None).await?;
+//! println!("{}", result.content);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::config::ExtractionConfig;
+use crate::core::extractor::{extract_bytes_with_registries, extract_file_with_registries};
+use crate::plugins::registry::PluginRegistries;
+use crate::plugins::{DocumentExtractor, OcrBackend, PostProcessor, Validator};
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Builder for [`Extractor`].
+///
+/// Plugins added to the builder are registered (and initialized) when [`build`](Self::build)
+/// is called, so registration errors surface there.
+#[derive(Default)]
+pub struct ExtractorBuilder {
+    config: ExtractionConfig,
+    default_extractors: bool,
+    extractors: Vec<Arc<dyn DocumentExtractor>>,
+    ocr_backends: Vec<Arc<dyn OcrBackend>>,
+    post_processors: Vec<(Arc<dyn PostProcessor>, i32)>,
+    validators: Vec<Arc<dyn Validator>>,
+}
+
+impl ExtractorBuilder {
+    /// Create a builder with empty registries and the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the extraction configuration used by the built extractor.
+    pub fn with_config(mut self, config: ExtractionConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Register all built-in document extractors (PDF, Office, HTML, ...).
+    pub fn with_default_extractors(mut self) -> Self {
+        self.default_extractors = true;
+        self
+    }
+
+    /// Register a document extractor.
+    pub fn with_extractor(mut self, extractor: Arc<dyn DocumentExtractor>) -> Self {
+        self.extractors.push(extractor);
+        self
+    }
+
+    /// Register an OCR backend.
+    pub fn with_ocr_backend(mut self, backend: Arc<dyn OcrBackend>) -> Self {
+        self.ocr_backends.push(backend);
+        self
+    }
+
+    /// Register a post-processor with the given priority (higher runs first within a stage).
+    pub fn with_post_processor(mut self, processor: Arc<dyn PostProcessor>, priority: i32) -> Self {
+        self.post_processors.push((processor, priority));
+        self
+    }
+
+    /// Register a validator.
+    pub fn with_validator(mut self, validator: Arc<dyn Validator>) -> Self {
+        self.validators.push(validator);
+        self
+    }
+
+    /// Build the extractor, registering all plugins into a fresh set of registries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a plugin has an invalid name or fails to initialize.
+    pub fn build(self) -> Result<Extractor> {
+        let registries = PluginRegistries::new();
+
+        {
+            let mut ocr_backends = registries
+                .ocr_backends
+                .write()
+                .map_err(|e| KreuzbergError::Other(format!("OCR backend registry lock poisoned: {}", e)))?;
+            for backend in self.ocr_backends {
+                ocr_backends.register(backend)?;
+            }
+        }
+
+        {
+            let mut extractors = registries
+                .document_extractors
+                .write()
+                .map_err(|e| KreuzbergError::Other(format!("Document extractor registry lock poisoned: {}", e)))?;
+            if self.default_extractors {
+                crate::extractors::register_default_extractors_into(&mut extractors, &registries.ocr_backends)?;
+            }
+            for extractor in self.extractors {
+                extractors.register(extractor)?;
+            }
+        }
+
+        {
+            let mut post_processors = registries
+                .post_processors
+                .write()
+                .map_err(|e| KreuzbergError::Other(format!("Post-processor registry lock poisoned: {}", e)))?;
+            for (processor, priority) in self.post_processors {
+                post_processors.register(processor, priority)?;
+            }
+        }
+
+        {
+            let mut validators = registries
+                .validators
+                .write()
+                .map_err(|e| KreuzbergError::Other(format!("Validator registry lock poisoned: {}", e)))?;
+            for validator in self.validators {
+                validators.register(validator)?;
+            }
+        }
+
+        Ok(Extractor {
+            config: Arc::new(self.config),
+            registries,
+        })
+    }
+}
+
+/// Document extractor with its own plugin registries and configuration.
+///
+/// Cloning an `Extractor` is cheap and shares the underlying registries.
+#[derive(Clone)]
+pub struct Extractor {
+    config: Arc<ExtractionConfig>,
+    registries: PluginRegistries,
+}
+
+impl Extractor {
+    /// Create a builder for an isolated extractor.
+    pub fn builder() -> ExtractorBuilder {
+        ExtractorBuilder::new()
+    }
+
+    /// The extraction configuration used by this extractor.
+    pub fn config(&self) -> &ExtractionConfig {
+        &self.config
+    }
+
+    /// The plugin registries owned by this extractor.
+    ///
+    /// Plugins can be added or removed after construction through these handles.
+    pub fn registries(&self) -> &PluginRegistries {
+        &self.registries
+    }
+
+    /// Extract content from a file.
+    ///
+    /// See [`extract_file`](crate::extract_file) for details.
+    pub async fn extract_file(&self, path: impl AsRef<Path>, mime_type: Option<&str>) -> Result<ExtractionResult> {
+        extract_file_with_registries(path.as_ref(), mime_type, &self.config, &self.registries).await
+    }
+
+    /// Extract content from a byte array.
+    ///
+    /// See [`extract_bytes`](crate::extract_bytes) for details.
+    pub async fn extract_bytes(&self, content: &[u8], mime_type: &str) -> Result<ExtractionResult> {
+        extract_bytes_with_registries(content, mime_type, &self.config, &self.registries).await
+    }
+
+    /// Synchronous wrapper for [`extract_file`](Self::extract_file).
+    #[cfg(feature = "tokio-runtime")]
+    pub fn extract_file_sync(&self, path: impl AsRef<Path>, mime_type: Option<&str>) -> Result<ExtractionResult> {
+        crate::core::extractor::GLOBAL_RUNTIME.block_on(self.extract_file(path, mime_type))
+    }
+
+    /// Synchronous wrapper for [`extract_bytes`](Self::extract_bytes).
+    #[cfg(feature = "tokio-runtime")]
+    pub fn extract_bytes_sync(&self, content: &[u8], mime_type: &str) -> Result<ExtractionResult> {
+        crate::core::extractor::GLOBAL_RUNTIME.block_on(self.extract_bytes(content, mime_type))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::Plugin;
+    use crate::types::Metadata;
+    use async_trait::async_trait;
+
+    struct UppercaseExtractor;
+
+    impl Plugin for UppercaseExtractor {
+        fn name(&self) -> &str {
+            "uppercase-extractor"
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl DocumentExtractor for UppercaseExtractor {
+        async fn extract_bytes(
+            &self,
+            content: &[u8],
+            mime_type: &str,
+            _config: &ExtractionConfig,
+        ) -> Result<ExtractionResult> {
+            Ok(ExtractionResult {
+                content: String::from_utf8_lossy(content).to_uppercase(),
+                mime_type: mime_type.to_string(),
+                metadata: Metadata::default(),
+                tables: vec![],
+                detected_languages: None,
+                chunks: None,
+                images: None,
+                pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
+            })
+        }
+
+        fn supported_mime_types(&self) -> &[&str] {
+            &["text/plain"]
+        }
+
+        fn priority(&self) -> i32 {
+            100
+        }
+    }
+
+    #[tokio::test]
+    async fn test_isolated_extractor_uses_own_registry() {
+        let extractor = ExtractorBuilder::new()
+            .with_extractor(Arc::new(UppercaseExtractor))
+            .build()
+            .unwrap();
+
+        let result = extractor.extract_bytes(b"hello", "text/plain").await.unwrap();
+        assert_eq!(result.content, "HELLO");
+
+        let global = crate::plugins::registry::get_document_extractor_registry();
+        assert!(
+            !global
+                .read()
+                .unwrap()
+                .list()
+                .contains(&"uppercase-extractor".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_isolated_extractor_without_extractors() {
+        let extractor = Extractor::builder().build().unwrap();
+
+        let result = extractor.extract_bytes(b"hello", "text/plain").await;
+        assert!(matches!(result, Err(KreuzbergError::UnsupportedFormat(_))));
+    }
+
+    #[cfg(feature = "email")]
+    #[tokio::test]
+    async fn test_isolated_extractor_extracts_attachments_with_own_registry() {
+        let config = ExtractionConfig {
+            email: Some(crate::core::config::EmailConfig {
+                extract_attachments: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let extractor = ExtractorBuilder::new()
+            .with_config(config)
+            .with_default_extractors()
+            .with_extractor(Arc::new(UppercaseExtractor))
+            .build()
+            .unwrap();
+
+        let eml = b"From: sender@example.com\r\n\
+Subject: Notes\r\n\
+Content-Type: multipart/mixed; boundary=\"b\"\r\n\
+\r\n\
+--b\r\n\
+Content-Type: text/plain\r\n\
+\r\n\
+Body.\r\n\
+--b\r\n\
+Content-Type: application/octet-stream; name=\"notes.txt\"\r\n\
+Content-Disposition: attachment; filename=\"notes.txt\"\r\n\
+\r\n\
+Attachment content\r\n\
+--b--\r\n";
+
+        let result = extractor.extract_bytes(eml, "message/rfc822").await.unwrap();
+        let children = result.children.expect("attachment should be extracted");
+        assert!(children[0].content.contains("ATTACHMENT CONTENT"));
+    }
+
+    #[tokio::test]
+    async fn test_isolated_extractor_with_default_extractors() {
+        let extractor = ExtractorBuilder::new().with_default_extractors().build().unwrap();
+
+        let result = extractor.extract_bytes(b"hello", "text/plain").await.unwrap();
+        assert_eq!(result.content, "hello");
+        assert!(extractor.registries().post_processors.read().unwrap().list().is_empty());
+    }
+}
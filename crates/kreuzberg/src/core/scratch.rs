@@ -0,0 +1,430 @@
+//! Scratch files written during extraction.
+//!
+//! Some inputs can only be processed from disk: LibreOffice converts legacy Office files
+//! between directories, and PPTX bytes, RAR archives and Outlook mailboxes are spilled to
+//! files for the libraries and tools that read them. These files live in a per-process
+//! directory below [`TempDirConfig::path`]; [`ScratchFile`] and [`ScratchDir`] remove them
+//! when dropped, and the bytes spilled at once are capped by [`TempDirConfig::max_size_bytes`].
+//! Files a subprocess writes into a [`ScratchDir`] count towards the cap once
+//! [`ScratchDir::measure`] is called after the subprocess exits.
+//!
+//! A crash skips those guards, so each process holds a lock on its directory. The directories
+//! of processes that no longer hold their lock are removed when scratch space is first used,
+//! or at startup with [`init_scratch_space`], which the API server calls. Call
+//! [`configure_temp_dir`] before the first extraction, or set the `KREUZBERG_TEMP_DIR` and
+//! `KREUZBERG_TEMP_MAX_SIZE_BYTES` environment variables.
+
+use crate::{KreuzbergError, Result};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, TryLockError};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Environment variable setting [`TempDirConfig::path`].
+pub const TEMP_DIR_ENV: &str = "KREUZBERG_TEMP_DIR";
+/// Environment variable setting [`TempDirConfig::max_size_bytes`].
+pub const TEMP_MAX_SIZE_BYTES_ENV: &str = "KREUZBERG_TEMP_MAX_SIZE_BYTES";
+
+/// Name prefix of the per-process scratch directories.
+const SCRATCH_DIR_PREFIX: &str = "kreuzberg-scratch-";
+
+/// Location and quota of the scratch files Kreuzberg writes.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TempDirConfig {
+    /// Directory in which the per-process scratch directory is created (default: the system
+    /// temp directory)
+    #[serde(default)]
+    pub path: Option<PathBuf>,
+
+    /// Most bytes spilled to scratch files at once; further spills fail with
+    /// `KreuzbergError::LimitExceeded` until earlier files are removed (default: unlimited)
+    #[serde(default)]
+    pub max_size_bytes: Option<u64>,
+}
+
+impl TempDirConfig {
+    /// Read the configuration from `KREUZBERG_TEMP_DIR` and `KREUZBERG_TEMP_MAX_SIZE_BYTES`.
+    pub fn from_env() -> Self {
+        Self {
+            path: std::env::var_os(TEMP_DIR_ENV)
+                .filter(|value| !value.is_empty())
+                .map(PathBuf::from),
+            max_size_bytes: std::env::var(TEMP_MAX_SIZE_BYTES_ENV)
+                .ok()
+                .and_then(|value| value.parse::<u64>().ok())
+                .filter(|&value| value > 0),
+        }
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.max_size_bytes == Some(0) {
+            return Err(KreuzbergError::validation("max_size_bytes must be greater than 0"));
+        }
+        Ok(())
+    }
+}
+
+static CONFIG: OnceCell<TempDirConfig> = OnceCell::new();
+
+static SCRATCH_SPACE: OnceCell<ScratchSpace> = OnceCell::new();
+
+/// Configure where scratch files are written and how much space they may take.
+///
+/// Must be called before the first extraction that writes scratch files.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if `max_size_bytes` is zero or the scratch space was
+/// already configured (explicitly or implicitly by a previous extraction).
+pub fn configure_temp_dir(config: TempDirConfig) -> Result<()> {
+    config.validate()?;
+    CONFIG
+        .set(config)
+        .map_err(|_| KreuzbergError::validation("Temp directory is already configured"))
+}
+
+/// The active temp directory configuration.
+pub fn temp_dir_config() -> &'static TempDirConfig {
+    CONFIG.get_or_init(TempDirConfig::from_env)
+}
+
+/// Create this process's scratch directory and remove those left behind by crashed processes.
+///
+/// Happens implicitly on first use; long-running servers call it at startup so leftovers are
+/// reclaimed right away. Returns the scratch directory.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Io` if the scratch directory cannot be created or locked.
+pub fn init_scratch_space() -> Result<&'static Path> {
+    Ok(&scratch_space()?.dir)
+}
+
+/// The scratch directory of this process and the bytes spilled into it.
+struct ScratchSpace {
+    dir: PathBuf,
+    /// Held for the lifetime of the process to mark `dir` as in use
+    _lock: File,
+    used_bytes: AtomicU64,
+    max_size_bytes: Option<u64>,
+}
+
+fn scratch_space() -> Result<&'static ScratchSpace> {
+    SCRATCH_SPACE.get_or_try_init(|| {
+        let config = temp_dir_config();
+        let base = config.path.clone().unwrap_or_else(std::env::temp_dir);
+        fs::create_dir_all(&base)?;
+
+        let removed = remove_orphans(&base);
+        if removed > 0 {
+            tracing::info!("Removed {} scratch directories of crashed processes", removed);
+        }
+
+        // The lock exists before the directory, so other processes never mistake it for an orphan.
+        let dir = base.join(format!("{}{}", SCRATCH_DIR_PREFIX, uuid::Uuid::new_v4()));
+        let lock = File::create(lock_path(&dir))?;
+        lock.lock()?;
+        fs::create_dir(&dir)?;
+
+        Ok(ScratchSpace {
+            dir,
+            _lock: lock,
+            used_bytes: AtomicU64::new(0),
+            max_size_bytes: config.max_size_bytes,
+        })
+    })
+}
+
+impl ScratchSpace {
+    fn unique_path(&self, suffix: &str) -> PathBuf {
+        self.dir.join(format!("{}{}", uuid::Uuid::new_v4(), suffix))
+    }
+
+    /// Count `bytes` against the quota.
+    fn reserve(&self, bytes: u64) -> Result<()> {
+        let previous = self.used_bytes.fetch_add(bytes, Ordering::SeqCst);
+        if let Some(max) = self.max_size_bytes
+            && previous + bytes > max
+        {
+            self.used_bytes.fetch_sub(bytes, Ordering::SeqCst);
+            return Err(KreuzbergError::limit_exceeded(
+                "temp_dir.max_size_bytes",
+                previous + bytes,
+                max,
+            ));
+        }
+        Ok(())
+    }
+
+    fn release(&self, bytes: u64) {
+        self.used_bytes.fetch_sub(bytes, Ordering::SeqCst);
+    }
+}
+
+fn lock_path(dir: &Path) -> PathBuf {
+    dir.with_extension("lock")
+}
+
+/// Remove the scratch directories below `base` whose process no longer holds their lock.
+fn remove_orphans(base: &Path) -> usize {
+    let Ok(entries) = fs::read_dir(base) else {
+        return 0;
+    };
+
+    let mut removed = 0;
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        let is_scratch_dir = dir
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with(SCRATCH_DIR_PREFIX));
+        if !is_scratch_dir || !dir.is_dir() || is_locked(&lock_path(&dir)) {
+            continue;
+        }
+
+        match fs::remove_dir_all(&dir) {
+            Ok(()) => removed += 1,
+            Err(e) => tracing::warn!("Failed to remove orphaned scratch directory {}: {}", dir.display(), e),
+        }
+        let _ = fs::remove_file(lock_path(&dir));
+    }
+    removed
+}
+
+fn is_locked(lock_path: &Path) -> bool {
+    match File::open(lock_path) {
+        Ok(file) => matches!(file.try_lock(), Err(TryLockError::WouldBlock)),
+        Err(_) => false,
+    }
+}
+
+/// A file in the scratch directory, removed when dropped.
+#[derive(Debug)]
+pub struct ScratchFile {
+    path: PathBuf,
+    reserved: u64,
+}
+
+impl ScratchFile {
+    /// Write `bytes` to a new scratch file whose name ends with `suffix`, e.g. `.pptx`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::LimitExceeded` if the file would exceed
+    /// [`TempDirConfig::max_size_bytes`] and `KreuzbergError::Io` if it cannot be written.
+    pub fn write(bytes: &[u8], suffix: &str) -> Result<Self> {
+        let space = scratch_space()?;
+        let reserved = bytes.len() as u64;
+        space.reserve(reserved)?;
+        let file = Self {
+            path: space.unique_path(suffix),
+            reserved,
+        };
+        fs::write(&file.path, bytes)?;
+        Ok(file)
+    }
+
+    /// Path of the file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ScratchFile {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_file(&self.path)
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            tracing::warn!("Failed to remove scratch file {}: {}", self.path.display(), e);
+        }
+        if let Some(space) = SCRATCH_SPACE.get() {
+            space.release(self.reserved);
+        }
+    }
+}
+
+/// A directory in the scratch directory, removed with its contents when dropped.
+#[derive(Debug)]
+pub struct ScratchDir {
+    path: PathBuf,
+    reserved: AtomicU64,
+}
+
+impl ScratchDir {
+    /// Create an empty scratch directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Io` if the directory cannot be created.
+    pub fn new() -> Result<Self> {
+        let path = scratch_space()?.unique_path("");
+        fs::create_dir(&path)?;
+        Ok(Self {
+            path,
+            reserved: AtomicU64::new(0),
+        })
+    }
+
+    /// Path of the directory.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Write `bytes` to `name` inside the directory, counting them against
+    /// [`TempDirConfig::max_size_bytes`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::LimitExceeded` if the quota would be exceeded and
+    /// `KreuzbergError::Io` if the file cannot be written.
+    pub fn write_file(&self, name: &str, bytes: &[u8]) -> Result<PathBuf> {
+        let size = bytes.len() as u64;
+        scratch_space()?.reserve(size)?;
+        self.reserved.fetch_add(size, Ordering::SeqCst);
+        let path = self.path.join(name);
+        fs::write(&path, bytes)?;
+        Ok(path)
+    }
+
+    /// Count everything written into the directory, e.g. by a subprocess, against
+    /// [`TempDirConfig::max_size_bytes`].
+    ///
+    /// Bytes already counted by [`write_file`](Self::write_file) or an earlier call are not
+    /// counted twice.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::LimitExceeded` if the contents exceed the quota and
+    /// `KreuzbergError::Io` if the directory cannot be read.
+    pub fn measure(&self) -> Result<()> {
+        let size = dir_size(&self.path)?;
+        let reserved = self.reserved.load(Ordering::SeqCst);
+        if size > reserved {
+            let grown = size - reserved;
+            scratch_space()?.reserve(grown)?;
+            self.reserved.fetch_add(grown, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+}
+
+/// Total size of the files below `path`, without following symlinks.
+fn dir_size(path: &Path) -> std::io::Result<u64> {
+    let mut size = 0;
+    for entry in fs::read_dir(path)? {
+        let entry = entry?;
+        let metadata = fs::symlink_metadata(entry.path())?;
+        size += if metadata.is_dir() {
+            dir_size(&entry.path())?
+        } else {
+            metadata.len()
+        };
+    }
+    Ok(size)
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_dir_all(&self.path)
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            tracing::warn!("Failed to remove scratch directory {}: {}", self.path.display(), e);
+        }
+        if let Some(space) = SCRATCH_SPACE.get() {
+            space.release(*self.reserved.get_mut());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_scratch_files_are_removed_on_drop() {
+        let file = ScratchFile::write(b"slides", ".pptx").unwrap();
+        let path = file.path().to_path_buf();
+        assert_eq!(fs::read(&path).unwrap(), b"slides");
+        assert!(path.starts_with(init_scratch_space().unwrap()));
+        drop(file);
+        assert!(!path.exists());
+
+        let dir = ScratchDir::new().unwrap();
+        let input = dir.write_file("input.doc", b"doc").unwrap();
+        let dir_path = dir.path().to_path_buf();
+        assert!(input.exists());
+        drop(dir);
+        assert!(!dir_path.exists());
+    }
+
+    #[test]
+    fn test_measure_counts_files_written_by_others() {
+        let dir = ScratchDir::new().unwrap();
+        dir.write_file("input.doc", b"doc").unwrap();
+        fs::create_dir(dir.path().join("export")).unwrap();
+        fs::write(dir.path().join("export").join("Message.txt"), b"exported").unwrap();
+
+        dir.measure().unwrap();
+        assert_eq!(dir.reserved.load(Ordering::SeqCst), 11);
+        dir.measure().unwrap();
+        assert_eq!(dir.reserved.load(Ordering::SeqCst), 11);
+    }
+
+    #[test]
+    fn test_quota() {
+        let space = ScratchSpace {
+            dir: PathBuf::new(),
+            _lock: tempfile::tempfile().unwrap(),
+            used_bytes: AtomicU64::new(0),
+            max_size_bytes: Some(100),
+        };
+
+        space.reserve(60).unwrap();
+        let err = space.reserve(60).unwrap_err();
+        assert!(matches!(
+            err,
+            KreuzbergError::LimitExceeded {
+                actual: 120,
+                max: 100,
+                ..
+            }
+        ));
+        space.release(60);
+        space.reserve(100).unwrap();
+    }
+
+    #[test]
+    fn test_remove_orphans_keeps_locked_directories() {
+        let base = tempdir().unwrap();
+        let orphan = base.path().join(format!("{}crashed", SCRATCH_DIR_PREFIX));
+        fs::create_dir(&orphan).unwrap();
+        fs::write(orphan.join("input.pptx"), b"leftover").unwrap();
+        File::create(lock_path(&orphan)).unwrap();
+
+        let live = base.path().join(format!("{}running", SCRATCH_DIR_PREFIX));
+        fs::create_dir(&live).unwrap();
+        let lock = File::create(lock_path(&live)).unwrap();
+        lock.lock().unwrap();
+
+        let unrelated = base.path().join("other-tool");
+        fs::create_dir(&unrelated).unwrap();
+
+        assert_eq!(remove_orphans(base.path()), 1);
+        assert!(!orphan.exists());
+        assert!(!lock_path(&orphan).exists());
+        assert!(live.exists());
+        assert!(unrelated.exists());
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_quota() {
+        let config = TempDirConfig {
+            max_size_bytes: Some(0),
+            ..Default::default()
+        };
+        assert!(config.validate().is_err());
+        assert!(TempDirConfig::default().validate().is_ok());
+    }
+}
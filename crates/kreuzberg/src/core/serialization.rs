@@ -0,0 +1,227 @@
+//! Versioned binary serialization of extraction results.
+//!
+//! [`ExtractionResult::to_bytes`] encodes a result as MessagePack or CBOR inside an envelope
+//! that records the [`RESULT_SCHEMA_VERSION`] it was written with, and
+//! [`ExtractionResult::from_bytes`] reads it back. Results cached on disk or passed between
+//! services this way do not depend on each binding's JSON conversion.
+//!
+//! Fields added in later schema versions are optional, so payloads of older versions decode
+//! with those fields unset. Payloads of a newer schema version than the reader supports are
+//! rejected instead of being decoded partially.
+//!
+//! CBOR requires the `result-cbor` feature.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::{ExtractionConfig, ExtractionResult, ResultFormat, extract_file_sync};
+//!
+//! # fn example() -> kreuzberg::Result<()> {
+//! let result = extract_file_sync("document.pdf", None, &ExtractionConfig::default())?;
+//! let bytes = result.to_bytes(ResultFormat::Msgpack)?;
+//! std::fs::write("document.kzr", &bytes)?;
+//!
+//! let cached = ExtractionResult::from_bytes(&std::fs::read("document.kzr")?, ResultFormat::Msgpack)?;
+//! assert_eq!(cached.content, result.content);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Schema version written by [`ExtractionResult::to_bytes`].
+///
+/// Incremented when the layout of `ExtractionResult` changes in a way older readers cannot
+/// decode; adding optional fields does not change it.
+pub const RESULT_SCHEMA_VERSION: u32 = 1;
+
+/// Binary encoding of a serialized [`ExtractionResult`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResultFormat {
+    /// MessagePack, with structs encoded as maps so optional fields can be omitted
+    #[default]
+    Msgpack,
+    /// CBOR (RFC 8949, requires the `result-cbor` feature)
+    Cbor,
+}
+
+impl FromStr for ResultFormat {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "msgpack" | "messagepack" => Ok(Self::Msgpack),
+            "cbor" => Ok(Self::Cbor),
+            _ => Err(KreuzbergError::validation(format!(
+                "Invalid result format '{}'. Use 'msgpack' or 'cbor'",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct EnvelopeRef<'a> {
+    schema_version: u32,
+    result: &'a ExtractionResult,
+}
+
+#[derive(Deserialize)]
+struct Envelope {
+    result: ExtractionResult,
+}
+
+/// The envelope without the result, read first to check the schema version.
+#[derive(Deserialize)]
+struct EnvelopeHeader {
+    schema_version: u32,
+}
+
+impl ExtractionResult {
+    /// Serialize the result, tagged with [`RESULT_SCHEMA_VERSION`].
+    ///
+    /// # Errors
+    ///
+    /// `Serialization` when encoding fails and `MissingDependency` for CBOR without the
+    /// `result-cbor` feature.
+    pub fn to_bytes(&self, format: ResultFormat) -> Result<Vec<u8>> {
+        let envelope = EnvelopeRef {
+            schema_version: RESULT_SCHEMA_VERSION,
+            result: self,
+        };
+        match format {
+            ResultFormat::Msgpack => Ok(rmp_serde::to_vec_named(&envelope)?),
+            #[cfg(feature = "result-cbor")]
+            ResultFormat::Cbor => {
+                let mut bytes = Vec::new();
+                ciborium::into_writer(&envelope, &mut bytes).map_err(cbor_error)?;
+                Ok(bytes)
+            }
+            #[cfg(not(feature = "result-cbor"))]
+            ResultFormat::Cbor => Err(cbor_unavailable()),
+        }
+    }
+
+    /// Deserialize a result written by [`to_bytes`](Self::to_bytes).
+    ///
+    /// # Errors
+    ///
+    /// `Validation` for payloads of a newer schema version, `Serialization` for payloads that
+    /// cannot be decoded and `MissingDependency` for CBOR without the `result-cbor` feature.
+    pub fn from_bytes(bytes: &[u8], format: ResultFormat) -> Result<Self> {
+        let header: EnvelopeHeader = decode(bytes, format)?;
+        if header.schema_version > RESULT_SCHEMA_VERSION {
+            return Err(KreuzbergError::validation(format!(
+                "Result was written with schema version {}, this version of Kreuzberg reads up to {}",
+                header.schema_version, RESULT_SCHEMA_VERSION
+            )));
+        }
+        let envelope: Envelope = decode(bytes, format)?;
+        Ok(envelope.result)
+    }
+}
+
+fn decode<T: DeserializeOwned>(bytes: &[u8], format: ResultFormat) -> Result<T> {
+    match format {
+        ResultFormat::Msgpack => Ok(rmp_serde::from_slice(bytes)?),
+        #[cfg(feature = "result-cbor")]
+        ResultFormat::Cbor => ciborium::from_reader(bytes).map_err(cbor_error),
+        #[cfg(not(feature = "result-cbor"))]
+        ResultFormat::Cbor => Err(cbor_unavailable()),
+    }
+}
+
+#[cfg(feature = "result-cbor")]
+fn cbor_error(err: impl std::fmt::Display) -> KreuzbergError {
+    KreuzbergError::serialization(format!("CBOR serialization failed: {}", err))
+}
+
+#[cfg(not(feature = "result-cbor"))]
+fn cbor_unavailable() -> KreuzbergError {
+    KreuzbergError::MissingDependency("CBOR serialization requires the `result-cbor` feature".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, Table};
+
+    fn sample_result() -> ExtractionResult {
+        let mut metadata = Metadata {
+            title: Some("Quarterly report".to_string()),
+            ..Default::default()
+        };
+        metadata
+            .additional
+            .insert("custom".to_string(), serde_json::json!({"pages": 3, "draft": false}));
+        ExtractionResult {
+            content: "Revenue grew.".to_string(),
+            mime_type: "application/pdf".to_string(),
+            metadata,
+            tables: vec![Table {
+                cells: vec![vec!["Q1".to_string(), "10".to_string()]],
+                markdown: "| Q1 | 10 |".to_string(),
+                page_number: 1,
+            }],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_msgpack_roundtrip() {
+        let result = sample_result();
+        let bytes = result.to_bytes(ResultFormat::Msgpack).unwrap();
+        let decoded = ExtractionResult::from_bytes(&bytes, ResultFormat::Msgpack).unwrap();
+
+        assert_eq!(decoded.content, result.content);
+        assert_eq!(decoded.metadata.title.as_deref(), Some("Quarterly report"));
+        assert_eq!(decoded.metadata.additional["custom"]["pages"], 3);
+        assert_eq!(decoded.tables[0].cells, result.tables[0].cells);
+    }
+
+    #[cfg(feature = "result-cbor")]
+    #[test]
+    fn test_cbor_roundtrip() {
+        let result = sample_result();
+        let bytes = result.to_bytes(ResultFormat::Cbor).unwrap();
+        let decoded = ExtractionResult::from_bytes(&bytes, ResultFormat::Cbor).unwrap();
+
+        assert_eq!(decoded.content, result.content);
+        assert_eq!(decoded.metadata.additional["custom"]["draft"], false);
+        assert_eq!(decoded.tables[0].markdown, result.tables[0].markdown);
+    }
+
+    #[test]
+    fn test_newer_schema_version_is_rejected() {
+        let result = sample_result();
+        let envelope = EnvelopeRef {
+            schema_version: RESULT_SCHEMA_VERSION + 1,
+            result: &result,
+        };
+        let bytes = rmp_serde::to_vec_named(&envelope).unwrap();
+
+        let err = ExtractionResult::from_bytes(&bytes, ResultFormat::Msgpack).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }), "{}", err);
+    }
+
+    #[test]
+    fn test_invalid_payload() {
+        let err = ExtractionResult::from_bytes(b"not a result", ResultFormat::Msgpack).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Serialization { .. }), "{}", err);
+        assert_eq!("CBOR".parse::<ResultFormat>().unwrap(), ResultFormat::Cbor);
+        assert!("json".parse::<ResultFormat>().is_err());
+    }
+}
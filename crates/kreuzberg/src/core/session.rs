@@ -0,0 +1,173 @@
+//! Reusable extraction sessions.
+//!
+//! Every call to [`extract_file`](crate::extract_file) or [`extract_bytes`](crate::extract_bytes)
+//! checks that the built-in extractors and post-processors are registered and resolves the
+//! extractor for the document's MIME type under the registry lock. An [`ExtractionSession`] does
+//! that work once: it registers the built-in plugins, checks the configured OCR backend, loads
+//! language data, and snapshots the extractor for every registered MIME type. Subsequent calls
+//! skip the lazy initialization and resolve extractors without locking.
+//!
+//! Plugins registered after the session was created are only picked up for MIME types the
+//! snapshot doesn't cover; call [`ExtractionSession::refresh`] to take a new snapshot.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::{ExtractionConfig, ExtractionSession};
+//!
+//! # async fn example() -> kreuzberg::Result<()> {
+//! let session = ExtractionSession::new(ExtractionConfig::default())?;
+//!
+//! for path in ["a.pdf", "b.docx"] {
+//!     let result = session.extract_file(path, None).await?;
+//!     println!("{}", result.content);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::config::ExtractionConfig;
+use crate::core::extractor::{extract_bytes_with_registries, extract_file_with_registries};
+use crate::plugins::DocumentExtractor;
+use crate::plugins::registry::PluginRegistries;
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Pre-warmed extraction context that is reused across calls.
+///
+/// Cloning a session is cheap and shares the snapshot.
+#[derive(Clone)]
+pub struct ExtractionSession {
+    config: Arc<ExtractionConfig>,
+    registries: PluginRegistries,
+}
+
+impl ExtractionSession {
+    /// Create a session using the global plugin registries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the built-in extractors fail to register or the OCR backend named
+    /// in `config.ocr` is not registered.
+    pub fn new(config: ExtractionConfig) -> Result<Self> {
+        let config = Arc::new(config);
+        let registries = warm_up(&config)?;
+        Ok(Self { config, registries })
+    }
+
+    /// The extraction configuration used by this session.
+    pub fn config(&self) -> &ExtractionConfig {
+        &self.config
+    }
+
+    /// Take a new snapshot of the global registries.
+    ///
+    /// Call this after registering or removing plugins to make the session see them.
+    pub fn refresh(&mut self) -> Result<()> {
+        self.registries = warm_up(&self.config)?;
+        Ok(())
+    }
+
+    /// Extract content from a file.
+    ///
+    /// See [`extract_file`](crate::extract_file) for details.
+    pub async fn extract_file(&self, path: impl AsRef<Path>, mime_type: Option<&str>) -> Result<ExtractionResult> {
+        extract_file_with_registries(path.as_ref(), mime_type, &self.config, &self.registries).await
+    }
+
+    /// Extract content from a byte array.
+    ///
+    /// See [`extract_bytes`](crate::extract_bytes) for details.
+    pub async fn extract_bytes(&self, content: &[u8], mime_type: &str) -> Result<ExtractionResult> {
+        extract_bytes_with_registries(content, mime_type, &self.config, &self.registries).await
+    }
+
+    /// Synchronous wrapper for [`extract_file`](Self::extract_file).
+    #[cfg(feature = "tokio-runtime")]
+    pub fn extract_file_sync(&self, path: impl AsRef<Path>, mime_type: Option<&str>) -> Result<ExtractionResult> {
+        crate::core::extractor::GLOBAL_RUNTIME.block_on(self.extract_file(path, mime_type))
+    }
+
+    /// Synchronous wrapper for [`extract_bytes`](Self::extract_bytes).
+    #[cfg(feature = "tokio-runtime")]
+    pub fn extract_bytes_sync(&self, content: &[u8], mime_type: &str) -> Result<ExtractionResult> {
+        crate::core::extractor::GLOBAL_RUNTIME.block_on(self.extract_bytes(content, mime_type))
+    }
+}
+
+/// Run all lazy initialization up front and snapshot the extractor for each MIME type.
+fn warm_up(config: &ExtractionConfig) -> Result<PluginRegistries> {
+    crate::extractors::ensure_initialized()?;
+    crate::core::pipeline::ensure_builtin_post_processors();
+
+    let registries = PluginRegistries::global();
+
+    if let Some(ocr) = &config.ocr {
+        let backends = registries
+            .ocr_backends
+            .read()
+            .map_err(|e| KreuzbergError::Other(format!("OCR backend registry lock poisoned: {}", e)))?;
+        backends.get(&ocr.backend)?;
+    }
+
+    #[cfg(feature = "stopwords")]
+    once_cell::sync::Lazy::force(&crate::stopwords::STOPWORDS);
+
+    let resolved = {
+        let extractors = registries
+            .document_extractors
+            .read()
+            .map_err(|e| KreuzbergError::Other(format!("Document extractor registry lock poisoned: {}", e)))?;
+
+        let mut resolved: HashMap<String, Arc<dyn DocumentExtractor>> = HashMap::new();
+        for mime_type in extractors.mime_types() {
+            if mime_type.ends_with("/*") {
+                continue;
+            }
+
+            let extractor = if config.plugin_config.is_empty() {
+                extractors.get(&mime_type)
+            } else {
+                extractors.get_with_priority_override(&mime_type, |name| config.plugin_priority(name))
+            };
+            if let Ok(extractor) = extractor {
+                resolved.insert(mime_type, extractor);
+            }
+        }
+        resolved
+    };
+
+    Ok(registries.with_resolved_extractors(resolved))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_session_extracts_plain_text() {
+        let session = ExtractionSession::new(ExtractionConfig::default()).unwrap();
+        assert!(session.registries.resolved_extractor("text/plain").is_some());
+        assert!(!session.registries.needs_lazy_init());
+
+        let result = session.extract_bytes(b"hello session", "text/plain").await.unwrap();
+        assert_eq!(result.content, "hello session");
+    }
+
+    #[tokio::test]
+    async fn test_session_rejects_unknown_mime_type() {
+        let session = ExtractionSession::new(ExtractionConfig::default()).unwrap();
+        let result = session.extract_bytes(b"data", "application/x-unknown-format").await;
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_session_refresh() {
+        let mut session = ExtractionSession::new(ExtractionConfig::default()).unwrap();
+        session.refresh().unwrap();
+        assert!(session.registries.resolved_extractor("text/plain").is_some());
+    }
+}
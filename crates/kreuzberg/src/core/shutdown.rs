@@ -0,0 +1,244 @@
+//! Graceful shutdown of the API, gRPC and MCP servers.
+//!
+//! On SIGTERM or Ctrl+C a server stops accepting new work: listeners close, new extract
+//! requests and tool calls are rejected as unavailable, and `/health` reports `draining`.
+//! In-flight extractions, including those still queued at the concurrency governor, get up to
+//! the drain timeout to finish. The servers then sync the extraction cache to disk, run the
+//! hooks registered with [`on_shutdown`] (for example to flush a metrics exporter), shut down
+//! the registered plugins so they can release their resources and flush their state, and
+//! return. Jobs still running when the timeout elapses are abandoned and logged.
+//!
+//! The drain timeout defaults to 30 seconds and can be set with the
+//! `KREUZBERG_DRAIN_TIMEOUT_SECS` environment variable. In Kubernetes, set
+//! `terminationGracePeriodSeconds` a few seconds above it so rollouts don't kill jobs that
+//! are about to finish.
+//!
+//! Embedders running their own servers can start draining with [`begin_draining`] and wait for
+//! running jobs with [`drain`].
+
+use crate::Result;
+use crate::core::governor::{GovernorLoad, current_load};
+use crate::plugins::registry::PluginRegistries;
+use once_cell::sync::Lazy;
+use std::sync::{Mutex, PoisonError, RwLock, RwLockWriteGuard};
+use std::time::Duration;
+use tokio::sync::watch;
+
+/// Environment variable setting the drain timeout in seconds.
+pub const DRAIN_TIMEOUT_ENV: &str = "KREUZBERG_DRAIN_TIMEOUT_SECS";
+
+/// Drain timeout used when `KREUZBERG_DRAIN_TIMEOUT_SECS` is not set.
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How often [`drain`] checks whether the governor is idle.
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+static DRAINING: Lazy<watch::Sender<bool>> = Lazy::new(|| watch::channel(false).0);
+
+/// Work run once the servers drained; see [`on_shutdown`].
+pub type ShutdownHook = Box<dyn FnOnce() -> Result<()> + Send>;
+
+static SHUTDOWN_HOOKS: Lazy<Mutex<Vec<(String, ShutdownHook)>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// Drain timeout from `KREUZBERG_DRAIN_TIMEOUT_SECS`, or [`DEFAULT_DRAIN_TIMEOUT`].
+pub fn drain_timeout() -> Duration {
+    parse_drain_timeout(std::env::var(DRAIN_TIMEOUT_ENV).ok().as_deref())
+}
+
+fn parse_drain_timeout(value: Option<&str>) -> Duration {
+    value
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map_or(DEFAULT_DRAIN_TIMEOUT, Duration::from_secs)
+}
+
+/// Stop accepting new work and start shutting the servers of this process down.
+pub fn begin_draining() {
+    DRAINING.send_replace(true);
+}
+
+/// Whether the process is shutting down and rejects new work.
+pub fn is_draining() -> bool {
+    *DRAINING.borrow()
+}
+
+/// Run `hook` when a server of this process shuts down, after in-flight extractions drained.
+///
+/// Use it to flush state the application owns, such as a metrics exporter or its own caches.
+/// Hooks run once, in registration order, before the plugins shut down; a failing hook is
+/// logged under `name`.
+pub fn on_shutdown(name: impl Into<String>, hook: impl FnOnce() -> Result<()> + Send + 'static) {
+    SHUTDOWN_HOOKS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push((name.into(), Box::new(hook)));
+}
+
+/// Resolve once [`begin_draining`] was called.
+async fn draining() {
+    let mut draining = DRAINING.subscribe();
+    // The sender is static and never dropped, so waiting can't fail
+    let _ = draining.wait_for(|draining| *draining).await;
+}
+
+/// Resolve on SIGTERM, Ctrl+C or a call to [`begin_draining`], after which the process drains.
+pub async fn shutdown_signal() {
+    tokio::select! {
+        () = termination_signal() => {
+            tracing::info!("Received shutdown signal, draining in-flight extractions");
+            begin_draining();
+        }
+        () = draining() => {}
+    }
+}
+
+async fn termination_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = terminate.recv() => {}
+                    _ = tokio::signal::ctrl_c() => {}
+                }
+                return;
+            }
+            Err(e) => tracing::warn!("Failed to listen for SIGTERM: {}", e),
+        }
+    }
+
+    if let Err(e) = tokio::signal::ctrl_c().await {
+        tracing::warn!("Failed to listen for Ctrl+C: {}", e);
+        std::future::pending::<()>().await;
+    }
+}
+
+/// Wait until no extraction is running or queued, for at most `timeout`.
+///
+/// Returns the load of the governor when it went idle or the timeout elapsed.
+pub async fn drain(timeout: Duration) -> GovernorLoad {
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        let load = current_load();
+        let idle = load.active_jobs == 0 && load.queued_jobs == 0;
+        if idle || tokio::time::Instant::now() >= deadline {
+            return load;
+        }
+        tokio::time::sleep(DRAIN_POLL_INTERVAL).await;
+    }
+}
+
+/// Resolve `timeout` after draining began, with the load of the governor at that moment.
+///
+/// Servers race this against their own graceful shutdown, which finishes open requests.
+#[cfg(feature = "api")]
+pub(crate) async fn drain_deadline(timeout: Duration) -> GovernorLoad {
+    draining().await;
+    tokio::time::sleep(timeout).await;
+    current_load()
+}
+
+/// Log jobs abandoned by the drain, then flush the cache, run the shutdown hooks and shut the
+/// plugins down on the blocking thread pool.
+pub(crate) async fn finish(remaining: &GovernorLoad) {
+    if remaining.active_jobs > 0 || remaining.queued_jobs > 0 {
+        tracing::warn!(
+            "Drain timeout elapsed, abandoning {} running and {} queued extraction jobs",
+            remaining.active_jobs,
+            remaining.queued_jobs
+        );
+    } else {
+        tracing::info!("All in-flight extractions finished");
+    }
+
+    let finished = tokio::task::spawn_blocking(|| {
+        flush_cache();
+        run_shutdown_hooks();
+        shut_down_plugins();
+    });
+    if let Err(e) = finished.await {
+        tracing::warn!("Shutdown tasks panicked: {}", e);
+    }
+}
+
+/// Sync the extraction cache (`.kreuzberg` in the working directory) to disk.
+fn flush_cache() {
+    let Ok(current_dir) = std::env::current_dir() else {
+        return;
+    };
+    let cache_dir = current_dir.join(".kreuzberg");
+    match crate::cache::sync_cache_directory(&cache_dir.to_string_lossy()) {
+        Ok(synced) => tracing::debug!("Synced {} cache files", synced),
+        Err(e) => tracing::warn!("Failed to flush the cache: {}", e),
+    }
+}
+
+/// Run and remove the hooks registered with [`on_shutdown`].
+fn run_shutdown_hooks() {
+    let hooks = std::mem::take(&mut *SHUTDOWN_HOOKS.lock().unwrap_or_else(PoisonError::into_inner));
+    for (name, hook) in hooks {
+        if let Err(e) = hook() {
+            tracing::warn!("Shutdown hook '{}' failed: {}", name, e);
+        }
+    }
+}
+
+/// Call `shutdown()` on every plugin of the global registries.
+fn shut_down_plugins() {
+    let registries = PluginRegistries::global();
+    let results = [
+        write(&registries.post_processors).shutdown_all(),
+        write(&registries.validators).shutdown_all(),
+        write(&registries.image_describers).shutdown_all(),
+        write(&registries.ocr_backends).shutdown_all(),
+        write(&registries.document_extractors).shutdown_all(),
+    ];
+    for e in results.into_iter().filter_map(Result::err) {
+        tracing::warn!("Failed to shut down plugin: {}", e);
+    }
+}
+
+/// The registries stay usable after a panicking plugin, so a poisoned lock is recovered.
+fn write<T>(registry: &RwLock<T>) -> RwLockWriteGuard<'_, T> {
+    registry.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_drain_timeout() {
+        assert_eq!(parse_drain_timeout(None), DEFAULT_DRAIN_TIMEOUT);
+        assert_eq!(parse_drain_timeout(Some(" 5 ")), Duration::from_secs(5));
+        assert_eq!(parse_drain_timeout(Some("0")), Duration::ZERO);
+        assert_eq!(parse_drain_timeout(Some("soon")), DEFAULT_DRAIN_TIMEOUT);
+    }
+
+    #[test]
+    fn test_shutdown_hooks_run_once() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        on_shutdown("counter", move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        });
+        on_shutdown("failing", || {
+            Err(crate::KreuzbergError::Other("flush failed".to_string()))
+        });
+
+        run_shutdown_hooks();
+        run_shutdown_hooks();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_drain_returns_at_timeout() {
+        let drained = tokio::time::timeout(Duration::from_secs(5), drain(Duration::ZERO)).await;
+        assert!(drained.is_ok());
+    }
+}
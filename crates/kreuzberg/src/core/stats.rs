@@ -0,0 +1,218 @@
+//! Summary statistics attached to every extraction result.
+//!
+//! [`attach_stats`] runs after the post-processing pipeline and stores an
+//! [`ExtractionStats`] in `metadata.stats`. Extractors that know counts the result
+//! doesn't carry (e.g. PPTX tables rendered into the content) may pre-populate
+//! `metadata.stats`; those counts are kept when they are larger than what the
+//! result itself shows. OCR backends report their mean confidence under
+//! `metadata.additional["ocr_confidence"]`, which is moved into the stats.
+//!
+//! When `ExtractionConfig::collect_timings` is set, each stage also records its duration in
+//! `metadata.timings` through [`record_timing`]. Extractors that run OCR may pre-populate
+//! `metadata.timings.ocr_ms`.
+
+use crate::core::config::ExtractionConfig;
+use crate::types::{ExtractionResult, ExtractionStats, ExtractionTimings, StageDurations};
+
+/// Metadata key under which OCR backends report their mean confidence (0.0-1.0).
+pub(crate) const OCR_CONFIDENCE_KEY: &str = "ocr_confidence";
+
+/// Monotonic timer for stage durations.
+///
+/// `Instant` is unavailable on `wasm32-unknown-unknown`, so timing is skipped there.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StageTimer {
+    #[cfg(not(target_arch = "wasm32"))]
+    started: std::time::Instant,
+}
+
+impl StageTimer {
+    pub(crate) fn start() -> Self {
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            started: std::time::Instant::now(),
+        }
+    }
+
+    /// Milliseconds since the timer was started (`None` when timing is unavailable).
+    pub(crate) fn elapsed_ms(&self) -> Option<f64> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Some(self.started.elapsed().as_secs_f64() * 1000.0)
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            None
+        }
+    }
+}
+
+/// Combine stage timings, if both were measured.
+pub(crate) fn stage_durations(extraction_ms: Option<f64>, post_processing_ms: Option<f64>) -> Option<StageDurations> {
+    Some(StageDurations {
+        extraction_ms: extraction_ms?,
+        post_processing_ms: post_processing_ms?,
+    })
+}
+
+/// Store the time since `timer` started in the `stage` field of `metadata.timings`.
+///
+/// Does nothing unless `config.collect_timings` is set.
+pub(crate) fn record_timing(
+    result: &mut ExtractionResult,
+    config: &ExtractionConfig,
+    timer: StageTimer,
+    stage: impl FnOnce(&mut ExtractionTimings) -> &mut Option<f64>,
+) {
+    if config.collect_timings {
+        *stage(result.metadata.timings.get_or_insert_with(Default::default)) = timer.elapsed_ms();
+    }
+}
+
+/// Drop timing hints left by the extractor when `config.collect_timings` is not set.
+pub(crate) fn discard_unrequested_timings(result: &mut ExtractionResult, config: &ExtractionConfig) {
+    if !config.collect_timings {
+        result.metadata.timings = None;
+    }
+}
+
+/// Compute summary statistics for `result` and store them in `metadata.stats`.
+pub(crate) fn attach_stats(result: &mut ExtractionResult, durations: Option<StageDurations>) {
+    let hints = result.metadata.stats.take().unwrap_or_default();
+
+    let ocr_confidence = result
+        .metadata
+        .additional
+        .remove(OCR_CONFIDENCE_KEY)
+        .and_then(|v| v.as_f64())
+        .or(hints.ocr_confidence);
+
+    let stats = ExtractionStats {
+        word_count: result.content.split_whitespace().count(),
+        character_count: result.content.chars().count(),
+        page_count: result
+            .metadata
+            .pages
+            .as_ref()
+            .map(|p| p.total_count)
+            .or(hints.page_count),
+        table_count: result.tables.len().max(hints.table_count),
+        image_count: result.images.as_ref().map_or(0, Vec::len).max(hints.image_count),
+        ocr_confidence,
+        durations: durations.or(hints.durations),
+    };
+
+    result.metadata.stats = Some(stats);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, Table};
+
+    fn result_with(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_attach_stats_counts() {
+        let mut result = result_with("Grüße aus Berlin\n\nzweite Zeile");
+        result.tables.push(Table {
+            cells: vec![vec!["a".to_string()]],
+            markdown: "| a |".to_string(),
+            page_number: 1,
+        });
+        result
+            .metadata
+            .additional
+            .insert(OCR_CONFIDENCE_KEY.to_string(), serde_json::json!(0.87));
+
+        attach_stats(
+            &mut result,
+            Some(StageDurations {
+                extraction_ms: 12.0,
+                post_processing_ms: 3.0,
+            }),
+        );
+
+        let stats = result.metadata.stats.unwrap();
+        assert_eq!(stats.word_count, 5);
+        assert_eq!(stats.character_count, 30);
+        assert_eq!(stats.page_count, None);
+        assert_eq!(stats.table_count, 1);
+        assert_eq!(stats.image_count, 0);
+        assert_eq!(stats.ocr_confidence, Some(0.87));
+        assert_eq!(stats.durations.unwrap().extraction_ms, 12.0);
+        assert!(!result.metadata.additional.contains_key(OCR_CONFIDENCE_KEY));
+    }
+
+    #[test]
+    fn test_attach_stats_keeps_extractor_hints() {
+        let mut result = result_with("slide text");
+        result.metadata.stats = Some(ExtractionStats {
+            table_count: 2,
+            image_count: 3,
+            ..Default::default()
+        });
+
+        attach_stats(&mut result, None);
+
+        let stats = result.metadata.stats.unwrap();
+        assert_eq!(stats.word_count, 2);
+        assert_eq!(stats.table_count, 2);
+        assert_eq!(stats.image_count, 3);
+        assert!(stats.durations.is_none());
+    }
+
+    #[test]
+    fn test_record_timing_only_when_collected() {
+        let mut result = result_with("text");
+        let mut config = ExtractionConfig::default();
+
+        record_timing(&mut result, &config, StageTimer::start(), |t| &mut t.chunking_ms);
+        assert!(result.metadata.timings.is_none());
+
+        config.collect_timings = true;
+        record_timing(&mut result, &config, StageTimer::start(), |t| &mut t.chunking_ms);
+        let timings = result.metadata.timings.unwrap();
+        assert!(timings.chunking_ms.is_some_and(|ms| ms >= 0.0));
+        assert!(timings.extraction_ms.is_none());
+    }
+
+    #[test]
+    fn test_discard_unrequested_timings() {
+        let mut result = result_with("text");
+        result.metadata.timings = Some(ExtractionTimings {
+            ocr_ms: Some(40.0),
+            ..Default::default()
+        });
+
+        discard_unrequested_timings(&mut result, &ExtractionConfig::default());
+
+        assert!(result.metadata.timings.is_none());
+    }
+
+    #[test]
+    fn test_stats_serialized_under_metadata() {
+        let mut result = result_with("one two");
+        attach_stats(&mut result, None);
+
+        let json = serde_json::to_value(&result.metadata).unwrap();
+        assert_eq!(json["stats"]["word_count"], 2);
+        assert!(json["stats"].get("ocr_confidence").is_none());
+    }
+}
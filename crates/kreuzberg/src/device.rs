@@ -0,0 +1,169 @@
+//! Compute device selection for model-backed features.
+//!
+//! [`resolve_device`] turns a [`DeviceConfig`] into the device models actually run on.
+//! `auto` picks CUDA, then Metal (CoreML), then the CPU. An explicitly requested accelerator
+//! that is not compiled in (`gpu-cuda`, `gpu-metal` features) or not present on the machine
+//! falls back to the CPU with a warning, and the reason is kept in [`DeviceInfo`] so servers
+//! can report it (`/info`, `kreuzberg version --format json`).
+//!
+//! Embeddings are currently the only model-backed feature; OCR (Tesseract) and entity
+//! extraction (rule-based) always run on the CPU.
+
+use crate::core::config::{Device, DeviceConfig};
+use serde::{Deserialize, Serialize};
+
+/// The device a [`DeviceConfig`] resolves to on this machine.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeviceInfo {
+    /// Device asked for in the configuration
+    pub requested: Device,
+    /// Device models run on
+    pub active: Device,
+    /// Devices usable on this machine, in `auto` preference order (always ends with `cpu`)
+    pub available: Vec<Device>,
+    /// GPU memory limit per model session in MiB, if configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_vram_mb: Option<u64>,
+    /// Why the requested device is not used, when it fell back to the CPU
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback_reason: Option<String>,
+}
+
+/// Devices usable on this machine, in `auto` preference order. The CPU is always last.
+pub fn available_devices() -> Vec<Device> {
+    let mut devices = Vec::new();
+    if cuda_available() {
+        devices.push(Device::Cuda);
+    }
+    if metal_available() {
+        devices.push(Device::Metal);
+    }
+    devices.push(Device::Cpu);
+    devices
+}
+
+/// Resolve `config` against the devices available on this machine.
+pub fn resolve_device(config: &DeviceConfig) -> DeviceInfo {
+    let available = available_devices();
+    let (active, fallback_reason) = match config.device {
+        Device::Auto => (available[0], None),
+        requested if available.contains(&requested) => (requested, None),
+        requested => {
+            let reason = format!("{} is {}", requested.as_str(), unavailable_reason(requested));
+            tracing::warn!("Device {}, falling back to CPU", reason);
+            (Device::Cpu, Some(reason))
+        }
+    };
+    DeviceInfo {
+        requested: config.device,
+        active,
+        available,
+        max_vram_mb: config.max_vram_mb,
+        fallback_reason,
+    }
+}
+
+fn unavailable_reason(device: Device) -> &'static str {
+    let compiled_in = match device {
+        Device::Cuda => cfg!(feature = "gpu-cuda"),
+        Device::Metal => cfg!(all(feature = "gpu-metal", target_os = "macos")),
+        Device::Auto | Device::Cpu => true,
+    };
+    if compiled_in {
+        "not available on this machine"
+    } else {
+        "not supported by this build"
+    }
+}
+
+#[cfg(feature = "gpu-cuda")]
+fn cuda_available() -> bool {
+    use ort::execution_providers::{CUDAExecutionProvider, ExecutionProvider};
+    CUDAExecutionProvider::default().is_available().unwrap_or(false)
+}
+
+#[cfg(not(feature = "gpu-cuda"))]
+fn cuda_available() -> bool {
+    false
+}
+
+#[cfg(all(feature = "gpu-metal", target_os = "macos"))]
+fn metal_available() -> bool {
+    use ort::execution_providers::{CoreMLExecutionProvider, ExecutionProvider};
+    CoreMLExecutionProvider::default().is_available().unwrap_or(false)
+}
+
+#[cfg(not(all(feature = "gpu-metal", target_os = "macos")))]
+fn metal_available() -> bool {
+    false
+}
+
+/// ONNX Runtime execution providers for the active device. Empty for the CPU, which ONNX
+/// Runtime always uses when no provider (or a provider that fails to register) is given.
+#[cfg(any(feature = "gpu-cuda", feature = "gpu-metal"))]
+pub(crate) fn execution_providers(device: &DeviceInfo) -> Vec<ort::execution_providers::ExecutionProviderDispatch> {
+    match device.active {
+        #[cfg(feature = "gpu-cuda")]
+        Device::Cuda => {
+            let mut cuda = ort::execution_providers::CUDAExecutionProvider::default();
+            if let Some(max_vram_mb) = device.max_vram_mb {
+                cuda = cuda.with_memory_limit(max_vram_mb as usize * 1024 * 1024);
+            }
+            vec![cuda.build()]
+        }
+        #[cfg(feature = "gpu-metal")]
+        Device::Metal => vec![ort::execution_providers::CoreMLExecutionProvider::default().build()],
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cpu_is_always_available() {
+        assert_eq!(available_devices().last(), Some(&Device::Cpu));
+        let info = resolve_device(&DeviceConfig {
+            device: Device::Cpu,
+            max_vram_mb: None,
+        });
+        assert_eq!(info.active, Device::Cpu);
+        assert!(info.fallback_reason.is_none());
+    }
+
+    #[test]
+    fn test_auto_picks_first_available_device() {
+        let info = resolve_device(&DeviceConfig::default());
+        assert_eq!(info.requested, Device::Auto);
+        assert_eq!(info.active, available_devices()[0]);
+        assert!(info.fallback_reason.is_none());
+    }
+
+    #[test]
+    fn test_unavailable_device_falls_back_to_cpu() {
+        let requested = [Device::Cuda, Device::Metal]
+            .into_iter()
+            .find(|device| !available_devices().contains(device));
+        // Only machines with both accelerators have nothing to fall back from.
+        let Some(requested) = requested else {
+            return;
+        };
+
+        let info = resolve_device(&DeviceConfig {
+            device: requested,
+            max_vram_mb: Some(2048),
+        });
+        assert_eq!(info.active, Device::Cpu);
+        assert!(info.fallback_reason.unwrap().starts_with(requested.as_str()));
+    }
+
+    #[test]
+    fn test_device_config_serde() {
+        let config: DeviceConfig = serde_json::from_str(r#"{"device": "cuda", "max_vram_mb": 4096}"#).unwrap();
+        assert_eq!(config.device, Device::Cuda);
+        assert_eq!(config.max_vram_mb, Some(4096));
+        assert_eq!("METAL".parse::<Device>().unwrap(), Device::Metal);
+        assert!("tpu".parse::<Device>().is_err());
+    }
+}
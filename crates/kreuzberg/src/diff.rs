@@ -0,0 +1,462 @@
+//! Structural comparison of two extraction results.
+//!
+//! [`compare`] aligns two versions of a document and reports what changed in terms a reviewer
+//! cares about: text sections that were added, removed or modified, tables that appeared,
+//! disappeared or changed, and metadata fields with different values. Identical documents
+//! produce an empty [`DocumentDiff`].
+//!
+//! Sections are the paragraphs of the extracted content (blocks separated by blank lines),
+//! each labelled with the nearest preceding Markdown heading. Paragraphs are aligned by their
+//! whitespace-normalized text; within a stretch of unmatched paragraphs, a removed and an added
+//! paragraph with mostly the same words are reported as one modification.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::{ExtractionConfig, extract_file_sync};
+//!
+//! # fn example() -> kreuzberg::Result<()> {
+//! let config = ExtractionConfig::default();
+//! let before = extract_file_sync("contract-v1.pdf", None, &config)?;
+//! let after = extract_file_sync("contract-v2.pdf", None, &config)?;
+//!
+//! let diff = kreuzberg::diff::compare(&before, &after);
+//! for section in &diff.sections {
+//!     println!("{:?} in {:?}", section.kind, section.heading);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::types::{ExtractionResult, Table};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+/// Minimum share of common words for a removed and an added block to count as one modification.
+const MODIFIED_SIMILARITY: f64 = 0.5;
+
+/// Largest alignment table (unmatched blocks before × after); beyond it, blocks are paired in order.
+const MAX_ALIGNMENT_CELLS: usize = 16_000_000;
+
+/// Kind of a change between two documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// Only in the second document
+    Added,
+    /// Only in the first document
+    Removed,
+    /// In both documents with different content
+    Modified,
+}
+
+/// Differences between two extraction results.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DocumentDiff {
+    /// Changed sections, in document order
+    pub sections: Vec<SectionChange>,
+    /// Changed tables, in document order
+    pub tables: Vec<TableChange>,
+    /// Metadata fields with different values, sorted by path
+    pub metadata: Vec<MetadataChange>,
+}
+
+impl DocumentDiff {
+    /// Whether the documents have no structural differences.
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty() && self.tables.is_empty() && self.metadata.is_empty()
+    }
+}
+
+/// A section (paragraph) that differs between the documents.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionChange {
+    /// What happened to the section
+    pub kind: ChangeKind,
+    /// Nearest Markdown heading above the section (from the second document unless removed)
+    pub heading: Option<String>,
+    /// Text in the first document (None when added)
+    pub before: Option<String>,
+    /// Text in the second document (None when removed)
+    pub after: Option<String>,
+}
+
+/// A table that differs between the documents.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TableChange {
+    /// What happened to the table
+    pub kind: ChangeKind,
+    /// Index of the table in the first document's `tables`
+    pub before_index: Option<usize>,
+    /// Index of the table in the second document's `tables`
+    pub after_index: Option<usize>,
+    /// Page of the table (from the second document unless removed)
+    pub page_number: usize,
+    /// Markdown of the table in the first document
+    pub before: Option<String>,
+    /// Markdown of the table in the second document
+    pub after: Option<String>,
+}
+
+/// A metadata field with different values.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetadataChange {
+    /// Dotted path of the field, e.g. `title` or `pdf.page_count`
+    pub path: String,
+    /// Value in the first document (None when absent)
+    pub before: Option<Value>,
+    /// Value in the second document (None when absent)
+    pub after: Option<Value>,
+}
+
+/// Compare two extraction results, typically two revisions of the same document.
+pub fn compare(before: &ExtractionResult, after: &ExtractionResult) -> DocumentDiff {
+    DocumentDiff {
+        sections: compare_sections(&before.content, &after.content),
+        tables: compare_tables(&before.tables, &after.tables),
+        metadata: compare_metadata(before, after),
+    }
+}
+
+struct Section {
+    heading: Option<String>,
+    text: String,
+    normalized: String,
+}
+
+fn compare_sections(before: &str, after: &str) -> Vec<SectionChange> {
+    let before = split_sections(before);
+    let after = split_sections(after);
+    let keys = |sections: &[Section]| sections.iter().map(|s| s.normalized.clone()).collect::<Vec<_>>();
+
+    pair_changes(&keys(&before), &keys(&after))
+        .into_iter()
+        .map(|(kind, old, new)| {
+            let old = old.map(|i| &before[i]);
+            let new = new.map(|i| &after[i]);
+            SectionChange {
+                kind,
+                heading: new.or(old).and_then(|section| section.heading.clone()),
+                before: old.map(|section| section.text.clone()),
+                after: new.map(|section| section.text.clone()),
+            }
+        })
+        .collect()
+}
+
+fn compare_tables(before: &[Table], after: &[Table]) -> Vec<TableChange> {
+    let keys = |tables: &[Table]| tables.iter().map(|t| normalize(&t.markdown)).collect::<Vec<_>>();
+
+    pair_changes(&keys(before), &keys(after))
+        .into_iter()
+        .map(|(kind, old, new)| TableChange {
+            kind,
+            before_index: old,
+            after_index: new,
+            page_number: new.map_or_else(|| old.map_or(0, |i| before[i].page_number), |i| after[i].page_number),
+            before: old.map(|i| before[i].markdown.clone()),
+            after: new.map(|i| after[i].markdown.clone()),
+        })
+        .collect()
+}
+
+fn compare_metadata(before: &ExtractionResult, after: &ExtractionResult) -> Vec<MetadataChange> {
+    let mut old = BTreeMap::new();
+    let mut new = BTreeMap::new();
+    old.insert("mime_type".to_string(), Value::String(before.mime_type.clone()));
+    new.insert("mime_type".to_string(), Value::String(after.mime_type.clone()));
+    flatten("", serde_json::to_value(&before.metadata).unwrap_or_default(), &mut old);
+    flatten("", serde_json::to_value(&after.metadata).unwrap_or_default(), &mut new);
+
+    let paths: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    paths
+        .into_iter()
+        .filter(|path| old.get(*path) != new.get(*path))
+        .map(|path| MetadataChange {
+            path: path.clone(),
+            before: old.get(path).cloned(),
+            after: new.get(path).cloned(),
+        })
+        .collect()
+}
+
+/// Flatten nested objects into dotted paths; arrays and scalars are leaves, nulls are dropped.
+fn flatten(prefix: &str, value: Value, out: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) => {
+            for (key, value) in map {
+                let path = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(&path, value, out);
+            }
+        }
+        Value::Null => {}
+        leaf => {
+            out.insert(prefix.to_string(), leaf);
+        }
+    }
+}
+
+/// Paragraphs of `content`, each with the nearest Markdown heading at or above it.
+fn split_sections(content: &str) -> Vec<Section> {
+    let mut sections = Vec::new();
+    let mut heading: Option<String> = None;
+
+    for block in content.split("\n\n") {
+        let text = block.trim();
+        if text.is_empty() {
+            continue;
+        }
+        if let Some(title) = markdown_heading(text) {
+            heading = Some(title.to_string());
+        }
+        sections.push(Section {
+            heading: heading.clone(),
+            text: text.to_string(),
+            normalized: normalize(text),
+        });
+    }
+
+    sections
+}
+
+fn markdown_heading(block: &str) -> Option<&str> {
+    let line = block.lines().next()?;
+    let title = line.trim_start_matches('#');
+    let level = line.len() - title.len();
+    ((1..=6).contains(&level) && title.starts_with(' ')).then(|| title.trim())
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Changes between two key sequences as `(kind, index in before, index in after)`.
+///
+/// Equal keys are aligned with a longest common subsequence. Within each run of unmatched keys,
+/// a removed and an added key that are similar enough become one modification.
+fn pair_changes(before: &[String], after: &[String]) -> Vec<(ChangeKind, Option<usize>, Option<usize>)> {
+    let mut changes = Vec::new();
+    let mut removed = Vec::new();
+    let mut added = Vec::new();
+
+    for (old, new) in align(before, after) {
+        match (old, new) {
+            (Some(_), Some(_)) => pair_run(before, after, &mut removed, &mut added, &mut changes),
+            (Some(old), None) => removed.push(old),
+            (None, Some(new)) => added.push(new),
+            (None, None) => {}
+        }
+    }
+    pair_run(before, after, &mut removed, &mut added, &mut changes);
+
+    changes
+}
+
+fn pair_run(
+    before: &[String],
+    after: &[String],
+    removed: &mut Vec<usize>,
+    added: &mut Vec<usize>,
+    changes: &mut Vec<(ChangeKind, Option<usize>, Option<usize>)>,
+) {
+    let similar = |old: usize, new: usize| similarity(&before[old], &after[new]) >= MODIFIED_SIMILARITY;
+    let (mut i, mut j) = (0, 0);
+    while i < removed.len() && j < added.len() {
+        if similar(removed[i], added[j]) {
+            changes.push((ChangeKind::Modified, Some(removed[i]), Some(added[j])));
+            i += 1;
+            j += 1;
+        } else if !added[j + 1..].iter().any(|&new| similar(removed[i], new)) {
+            changes.push((ChangeKind::Removed, Some(removed[i]), None));
+            i += 1;
+        } else {
+            changes.push((ChangeKind::Added, None, Some(added[j])));
+            j += 1;
+        }
+    }
+    changes.extend(removed[i..].iter().map(|&old| (ChangeKind::Removed, Some(old), None)));
+    changes.extend(added[j..].iter().map(|&new| (ChangeKind::Added, None, Some(new))));
+    removed.clear();
+    added.clear();
+}
+
+/// Longest-common-subsequence alignment as pairs of indices; unmatched entries have one side `None`.
+fn align(before: &[String], after: &[String]) -> Vec<(Option<usize>, Option<usize>)> {
+    let prefix = before.iter().zip(after).take_while(|(a, b)| a == b).count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old = &before[prefix..before.len() - suffix];
+    let new = &after[prefix..after.len() - suffix];
+    let mut pairs: Vec<_> = (0..prefix).map(|i| (Some(i), Some(i))).collect();
+
+    if old.len().saturating_mul(new.len()) > MAX_ALIGNMENT_CELLS {
+        pairs.extend((0..old.len()).map(|i| (Some(prefix + i), None)));
+        pairs.extend((0..new.len()).map(|j| (None, Some(prefix + j))));
+    } else {
+        align_lcs(old, new, prefix, &mut pairs);
+    }
+
+    let offset = (before.len() - suffix, after.len() - suffix);
+    pairs.extend((0..suffix).map(|k| (Some(offset.0 + k), Some(offset.1 + k))));
+    pairs
+}
+
+/// Append the LCS alignment of `old` and `new`, whose indices start at `offset`.
+fn align_lcs(old: &[String], new: &[String], offset: usize, pairs: &mut Vec<(Option<usize>, Option<usize>)>) {
+    // lengths[i][j] = LCS length of old[i..] and new[j..] ~keep
+    let width = new.len() + 1;
+    let mut lengths = vec![0u32; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lengths[i * width + j] = if old[i] == new[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            pairs.push((Some(offset + i), Some(offset + j)));
+            i += 1;
+            j += 1;
+        } else if j == new.len() || (i < old.len() && lengths[(i + 1) * width + j] >= lengths[i * width + j + 1]) {
+            pairs.push((Some(offset + i), None));
+            i += 1;
+        } else {
+            pairs.push((None, Some(offset + j)));
+            j += 1;
+        }
+    }
+}
+
+/// Share of distinct words the two texts have in common (Jaccard index).
+fn similarity(a: &str, b: &str) -> f64 {
+    let a: HashSet<&str> = a.split_whitespace().collect();
+    let b: HashSet<&str> = b.split_whitespace().collect();
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/markdown".to_string(),
+            metadata: Default::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn table(markdown: &str, page_number: usize) -> Table {
+        Table {
+            cells: vec![],
+            markdown: markdown.to_string(),
+            page_number,
+        }
+    }
+
+    #[test]
+    fn test_identical_documents_have_empty_diff() {
+        let doc = result("# Terms\n\nPayment is due in 30 days.");
+        assert!(compare(&doc, &doc).is_empty());
+    }
+
+    #[test]
+    fn test_section_changes() {
+        let before = result(
+            "# Payment\n\nPayment is due within 30 days of invoicing.\n\n# Termination\n\nEither party may terminate.",
+        );
+        let after =
+            result("# Payment\n\nPayment is due within 45 days of invoicing.\n\n# Liability\n\nLiability is capped.");
+
+        let diff = compare(&before, &after);
+
+        let kinds: Vec<(ChangeKind, Option<&str>)> = diff
+            .sections
+            .iter()
+            .map(|change| (change.kind, change.heading.as_deref()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (ChangeKind::Modified, Some("Payment")),
+                (ChangeKind::Removed, Some("Termination")),
+                (ChangeKind::Removed, Some("Termination")),
+                (ChangeKind::Added, Some("Liability")),
+                (ChangeKind::Added, Some("Liability")),
+            ]
+        );
+        assert_eq!(
+            diff.sections[0].after.as_deref(),
+            Some("Payment is due within 45 days of invoicing.")
+        );
+    }
+
+    #[test]
+    fn test_table_and_metadata_changes() {
+        let mut before = result("text");
+        before.tables = vec![table("| a |", 1), table("| b |", 2)];
+        before.metadata.title = Some("Contract v1".to_string());
+        let mut after = result("text");
+        after.tables = vec![table("| a |", 1), table("| c |", 3)];
+        after.metadata.title = Some("Contract v2".to_string());
+        after.metadata.subject = Some("Services".to_string());
+
+        let diff = compare(&before, &after);
+
+        assert_eq!(diff.tables.len(), 2);
+        assert_eq!(diff.tables[0].kind, ChangeKind::Removed);
+        assert_eq!(diff.tables[0].before_index, Some(1));
+        assert_eq!(diff.tables[1].kind, ChangeKind::Added);
+        assert_eq!(diff.tables[1].page_number, 3);
+
+        let paths: Vec<&str> = diff.metadata.iter().map(|change| change.path.as_str()).collect();
+        assert_eq!(paths, vec!["subject", "title"]);
+        assert_eq!(diff.metadata[0].before, None);
+        assert_eq!(diff.metadata[1].after, Some(Value::from("Contract v2")));
+    }
+
+    #[test]
+    fn test_align_uses_longest_common_subsequence() {
+        let keys = |items: &[&str]| items.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        let pairs = align(&keys(&["a", "b", "c", "d"]), &keys(&["a", "c", "x", "d"]));
+
+        assert_eq!(
+            pairs,
+            vec![
+                (Some(0), Some(0)),
+                (Some(1), None),
+                (Some(2), Some(1)),
+                (None, Some(2)),
+                (Some(3), Some(3)),
+            ]
+        );
+    }
+}
@@ -0,0 +1,287 @@
+//! Availability of the external dependencies extraction relies on.
+//!
+//! [`doctor`] probes everything this build calls out to at extraction time: Tesseract and its
+//! language packs (`ocr`), the PDFium library (`pdf`), LibreOffice for legacy Office formats
+//! (`office`) and `pffexport` for Outlook mailboxes (`pst`). Deployments can run it at startup
+//! (`kreuzberg doctor`, `/info`) and fail fast instead of erroring on the first document that
+//! needs a missing dependency.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! let report = kreuzberg::doctor();
+//! for dependency in report.missing() {
+//!     eprintln!("{}: {}", dependency.name, dependency.error.as_deref().unwrap_or("unavailable"));
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Cargo features reported by [`doctor`]; convenience bundles such as `full` are left out.
+const FEATURES: &[(&str, bool)] = &[
+    ("tokio-runtime", cfg!(feature = "tokio-runtime")),
+    ("mmap", cfg!(feature = "mmap")),
+    ("pdf", cfg!(feature = "pdf")),
+    ("excel", cfg!(feature = "excel")),
+    ("office", cfg!(feature = "office")),
+    ("email", cfg!(feature = "email")),
+    ("pst", cfg!(feature = "pst")),
+    ("html", cfg!(feature = "html")),
+    ("xml", cfg!(feature = "xml")),
+    ("archives", cfg!(feature = "archives")),
+    ("iso", cfg!(feature = "iso")),
+    ("rar", cfg!(feature = "rar")),
+    ("ocr", cfg!(feature = "ocr")),
+    ("ocr-download", cfg!(feature = "ocr-download")),
+    ("language-detection", cfg!(feature = "language-detection")),
+    ("chunking", cfg!(feature = "chunking")),
+    ("embeddings", cfg!(feature = "embeddings")),
+    ("gpu-cuda", cfg!(feature = "gpu-cuda")),
+    ("gpu-metal", cfg!(feature = "gpu-metal")),
+    ("stopwords", cfg!(feature = "stopwords")),
+    ("quality", cfg!(feature = "quality")),
+    ("keywords-yake", cfg!(feature = "keywords-yake")),
+    ("keywords-rake", cfg!(feature = "keywords-rake")),
+    ("entities", cfg!(feature = "entities")),
+    ("templates", cfg!(feature = "templates")),
+    ("api", cfg!(feature = "api")),
+    ("mcp", cfg!(feature = "mcp")),
+    ("grpc", cfg!(feature = "grpc")),
+    ("dataset-parquet", cfg!(feature = "dataset-parquet")),
+    ("result-cbor", cfg!(feature = "result-cbor")),
+    ("result-html", cfg!(feature = "result-html")),
+    ("sinks-qdrant", cfg!(feature = "sinks-qdrant")),
+    ("sinks-pgvector", cfg!(feature = "sinks-pgvector")),
+    ("sinks-lancedb", cfg!(feature = "sinks-lancedb")),
+    ("otel", cfg!(feature = "otel")),
+    ("logging", cfg!(feature = "logging")),
+];
+
+/// Result of [`doctor`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DoctorReport {
+    /// Kreuzberg version
+    pub version: String,
+    /// Cargo features this build was compiled with
+    pub features: Vec<String>,
+    /// Names of the registered OCR backends, sorted
+    pub ocr_backends: Vec<String>,
+    /// External dependencies of the compiled-in features
+    pub dependencies: Vec<DependencyStatus>,
+}
+
+/// Status of one external dependency.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyStatus {
+    /// Dependency name, e.g. `tesseract` or `libreoffice`
+    pub name: String,
+    /// What extraction needs it for
+    pub required_for: String,
+    /// Whether it was found and works
+    pub available: bool,
+    /// Version the dependency reports
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Executable or data directory that was found
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Installed language packs (Tesseract only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<String>,
+    /// Why the dependency is unavailable, with installation hints
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl DoctorReport {
+    /// Whether every dependency of the compiled-in features is available.
+    pub fn is_healthy(&self) -> bool {
+        self.dependencies.iter().all(|dependency| dependency.available)
+    }
+
+    /// The dependencies that are unavailable.
+    pub fn missing(&self) -> impl Iterator<Item = &DependencyStatus> {
+        self.dependencies.iter().filter(|dependency| !dependency.available)
+    }
+}
+
+impl DependencyStatus {
+    #[cfg_attr(
+        not(any(feature = "ocr", feature = "pdf", feature = "office", feature = "pst")),
+        allow(dead_code)
+    )]
+    fn new(name: &str, required_for: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            required_for: required_for.to_string(),
+            available: false,
+            version: None,
+            path: None,
+            languages: Vec::new(),
+            error: None,
+        }
+    }
+}
+
+/// Probe the external dependencies of this build.
+///
+/// Never fails: problems are reported in [`DependencyStatus::error`]. Runs `--version` style
+/// commands for executables, so call it at startup rather than per document.
+pub fn doctor() -> DoctorReport {
+    let dependencies = vec![
+        #[cfg(feature = "ocr")]
+        tesseract(),
+        #[cfg(feature = "pdf")]
+        pdfium(),
+        #[cfg(feature = "office")]
+        libreoffice(),
+        #[cfg(feature = "pst")]
+        pffexport(),
+    ];
+
+    DoctorReport {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        features: FEATURES
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(name, _)| name.to_string())
+            .collect(),
+        ocr_backends: ocr_backends(),
+        dependencies,
+    }
+}
+
+fn ocr_backends() -> Vec<String> {
+    let registry = crate::plugins::registry::get_ocr_backend_registry();
+    let mut backends = registry.read().map(|registry| registry.list()).unwrap_or_default();
+    backends.sort();
+    backends
+}
+
+#[cfg(feature = "ocr")]
+fn tesseract() -> DependencyStatus {
+    use crate::ocr::languages;
+
+    let mut status = DependencyStatus::new("tesseract", "OCR");
+    status.version = Some(kreuzberg_tesseract::TesseractAPI::version());
+    status.path = languages::tessdata_dir().map(|dir| dir.display().to_string());
+    match languages::list_installed(None) {
+        Ok(installed) if installed.is_empty() => {
+            status.error =
+                Some("No Tesseract language packs installed. Run `kreuzberg ocr languages --download eng`".to_string());
+        }
+        Ok(installed) => {
+            status.available = true;
+            status.languages = installed;
+        }
+        Err(e) => status.error = Some(e.to_string()),
+    }
+    status
+}
+
+#[cfg(feature = "pdf")]
+fn pdfium() -> DependencyStatus {
+    use pdfium_render::prelude::Pdfium;
+
+    let mut status = DependencyStatus::new("pdfium", "PDF extraction");
+    let bindings = Pdfium::bind_to_library(Pdfium::pdfium_platform_library_name_at_path("./"))
+        .or_else(|_| Pdfium::bind_to_system_library());
+    match bindings {
+        Ok(_) => status.available = true,
+        Err(e) => status.error = Some(format!("Failed to initialize Pdfium: {}", e)),
+    }
+    status
+}
+
+#[cfg(feature = "office")]
+fn libreoffice() -> DependencyStatus {
+    let mut status = DependencyStatus::new("libreoffice", "legacy Office formats (.doc, .ppt)");
+    match crate::extraction::libreoffice::locate_soffice_binary() {
+        Ok(path) => {
+            status.path = Some(path.display().to_string());
+            record_version(&mut status, command_version(&path, "--version"));
+        }
+        Err(e) => status.error = Some(e.to_string()),
+    }
+    status
+}
+
+#[cfg(feature = "pst")]
+fn pffexport() -> DependencyStatus {
+    use crate::extraction::pst::{pffexport_binary, pffexport_install_message};
+
+    let mut status = DependencyStatus::new("pffexport", "Outlook mailboxes (.pst, .ost)");
+    let path = pffexport_binary();
+    match command_version(&path, "-V") {
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => status.error = Some(pffexport_install_message()),
+        result => {
+            status.path = Some(path.display().to_string());
+            record_version(&mut status, result);
+        }
+    }
+    status
+}
+
+#[cfg(any(feature = "office", feature = "pst"))]
+fn record_version(status: &mut DependencyStatus, version: std::io::Result<String>) {
+    match version {
+        Ok(version) => {
+            status.available = true;
+            status.version = Some(version);
+        }
+        Err(e) => status.error = Some(format!("{} could not be executed: {}", status.name, e)),
+    }
+}
+
+/// First line of what `program` prints for `flag`.
+#[cfg(any(feature = "office", feature = "pst"))]
+fn command_version(program: &std::path::Path, flag: &str) -> std::io::Result<String> {
+    let output = std::process::Command::new(program).arg(flag).output()?;
+    if !output.status.success() {
+        return Err(std::io::Error::other(format!(
+            "`{}` exited with {}",
+            flag, output.status
+        )));
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let version = stdout.lines().map(str::trim).find(|line| !line.is_empty());
+    Ok(version.unwrap_or_default().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_doctor_reports_compiled_in_features() {
+        let report = doctor();
+
+        assert_eq!(report.version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(report.features.contains(&"pdf".to_string()), cfg!(feature = "pdf"));
+        assert_eq!(report.features.contains(&"ocr".to_string()), cfg!(feature = "ocr"));
+        for dependency in &report.dependencies {
+            assert_eq!(dependency.available, dependency.error.is_none(), "{}", dependency.name);
+        }
+    }
+
+    #[test]
+    fn test_missing_dependencies() {
+        let mut missing = DependencyStatus::new("libreoffice", "legacy Office formats");
+        missing.error = Some("not installed".to_string());
+        let mut found = DependencyStatus::new("pdfium", "PDF extraction");
+        found.available = true;
+        let report = DoctorReport {
+            version: "1.0.0".to_string(),
+            features: Vec::new(),
+            ocr_backends: Vec::new(),
+            dependencies: vec![found, missing],
+        };
+
+        assert!(!report.is_healthy());
+        let names: Vec<&str> = report.missing().map(|dependency| dependency.name.as_str()).collect();
+        assert_eq!(names, vec!["libreoffice"]);
+        let json = serde_json::to_value(&report).unwrap();
+        assert!(json["dependencies"][0].get("error").is_none());
+        assert!(json["dependencies"][0].get("languages").is_none());
+    }
+}
@@ -9,7 +9,8 @@
 //! - Preset configurations for common RAG scenarios
 //! - Full customization of model location and parameters
 //! - Batch processing for efficient embedding generation
-//! - Optional GPU acceleration via ONNX Runtime execution providers
+//! - Optional GPU acceleration via ONNX Runtime execution providers (`gpu-cuda`, `gpu-metal`
+//!   features), selected with [`DeviceConfig`](crate::DeviceConfig) and falling back to the CPU
 //!
 //! # Example
 //!
@@ -149,11 +150,23 @@ lazy_static! {
 ///
 /// This function ensures models are initialized only once and reused across
 /// the application, avoiding redundant downloads and initialization overhead.
+/// The model runs on the automatically selected device (see [`crate::device`]).
 #[cfg(feature = "embeddings")]
 #[allow(private_interfaces)]
 pub fn get_or_init_model(
     model: EmbeddingModel,
     cache_dir: Option<std::path::PathBuf>,
+) -> crate::Result<CachedEmbedding> {
+    let device = crate::device::resolve_device(&crate::DeviceConfig::default());
+    get_or_init_model_on_device(model, cache_dir, &device)
+}
+
+/// [`get_or_init_model`] on a resolved device. Models are cached per device.
+#[cfg(feature = "embeddings")]
+fn get_or_init_model_on_device(
+    model: EmbeddingModel,
+    cache_dir: Option<std::path::PathBuf>,
+    device: &crate::device::DeviceInfo,
 ) -> crate::Result<CachedEmbedding> {
     let cache_directory = cache_dir.unwrap_or_else(|| {
         let mut path = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
@@ -162,7 +175,7 @@ pub fn get_or_init_model(
         path
     });
 
-    let model_key = format!("{:?}_{}", model, cache_directory.display());
+    let model_key = format!("{:?}_{}_{}", model, device.active.as_str(), cache_directory.display());
 
     {
         match MODEL_CACHE.read() {
@@ -190,8 +203,13 @@ pub fn get_or_init_model(
             return Ok(Arc::clone(cached_model));
         }
 
+        tracing::debug!("Initializing embedding model {:?} on {}", model, device.active.as_str());
         let mut init_options = InitOptions::new(model);
         init_options = init_options.with_cache_dir(cache_directory);
+        #[cfg(any(feature = "gpu-cuda", feature = "gpu-metal"))]
+        {
+            init_options = init_options.with_execution_providers(crate::device::execution_providers(device));
+        }
 
         let embedding_model = TextEmbedding::try_new(init_options).map_err(|e| crate::KreuzbergError::Plugin {
             message: format!("Failed to initialize embedding model: {}", e),
@@ -311,6 +329,18 @@ pub fn list_presets() -> Vec<&'static str> {
 pub fn generate_embeddings_for_chunks(
     chunks: &mut [crate::types::Chunk],
     config: &crate::core::config::EmbeddingConfig,
+) -> crate::Result<()> {
+    generate_embeddings_on_device(chunks, config, &crate::DeviceConfig::default())
+}
+
+/// [`generate_embeddings_for_chunks`] on the device selected by `device`.
+///
+/// An unavailable device falls back to the CPU (see [`crate::device::resolve_device`]).
+#[cfg(feature = "embeddings")]
+pub fn generate_embeddings_on_device(
+    chunks: &mut [crate::types::Chunk],
+    config: &crate::core::config::EmbeddingConfig,
+    device: &crate::DeviceConfig,
 ) -> crate::Result<()> {
     if chunks.is_empty() {
         return Ok(());
@@ -345,7 +375,8 @@ pub fn generate_embeddings_for_chunks(
         }
     };
 
-    let model = get_or_init_model(fastembed_model, config.cache_dir.clone())?;
+    let device = crate::device::resolve_device(device);
+    let model = get_or_init_model_on_device(fastembed_model, config.cache_dir.clone(), &device)?;
 
     let texts: Vec<String> = chunks.iter().map(|chunk| chunk.content.clone()).collect();
 
@@ -0,0 +1,50 @@
+//! Configuration for named-entity extraction.
+
+use crate::types::EntityType;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Named-entity extraction configuration.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EntityConfig {
+    /// Entity types to extract (default: all types).
+    #[serde(default = "default_entity_types")]
+    pub entity_types: Vec<EntityType>,
+
+    /// Minimum confidence threshold (0.0-1.0, default: 0.5).
+    ///
+    /// Entities recognized with a lower confidence are dropped.
+    #[serde(default = "default_min_confidence")]
+    pub min_confidence: f64,
+}
+
+impl Default for EntityConfig {
+    fn default() -> Self {
+        Self {
+            entity_types: default_entity_types(),
+            min_confidence: default_min_confidence(),
+        }
+    }
+}
+
+impl EntityConfig {
+    /// Only extract the given entity types.
+    pub fn with_entity_types(mut self, entity_types: Vec<EntityType>) -> Self {
+        self.entity_types = entity_types;
+        self
+    }
+
+    /// Set the minimum confidence threshold.
+    pub fn with_min_confidence(mut self, min_confidence: f64) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+}
+
+fn default_entity_types() -> Vec<EntityType> {
+    EntityType::ALL.to_vec()
+}
+
+fn default_min_confidence() -> f64 {
+    0.5
+}
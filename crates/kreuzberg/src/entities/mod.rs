@@ -0,0 +1,109 @@
+//! Named-entity extraction module.
+//!
+//! Finds persons, organizations, dates, and monetary amounts in extracted text and
+//! returns them with byte offsets into the content.
+//!
+//! Recognition is rule-based: entities are matched from surface patterns (honorifics,
+//! legal-form suffixes, date formats, currency symbols), each with a fixed confidence.
+//! This keeps the feature dependency-free and deterministic; it favours precision over
+//! recall and works best on English text. Model-based NER is out of scope; a custom
+//! [`PostProcessor`](crate::plugins::PostProcessor) can fill `entities` from a model instead.
+//!
+//! # Feature Flags
+//!
+//! - `entities`: Enable named-entity extraction (included in `full` feature)
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use kreuzberg::entities::{extract_entities, EntityConfig};
+//! # use kreuzberg::EntityType;
+//! let text = "Dr. Jane Smith joined Acme Corp. on March 3, 2024.";
+//! let entities = extract_entities(text, &EntityConfig::default());
+//!
+//! assert!(entities.iter().any(|e| e.entity_type == EntityType::Person && e.text == "Jane Smith"));
+//! ```
+
+use crate::Result;
+use crate::plugins::registry::get_post_processor_registry;
+use crate::types::Entity;
+use once_cell::sync::Lazy;
+use std::sync::Arc;
+
+pub mod config;
+pub mod processor;
+mod rules;
+
+pub use config::EntityConfig;
+pub use processor::EntityExtractor;
+
+/// Extract named entities from text.
+///
+/// # Returns
+///
+/// Non-overlapping entities sorted by position. `byte_start` and `byte_end`
+/// are byte offsets into `text`.
+pub fn extract_entities(text: &str, config: &EntityConfig) -> Vec<Entity> {
+    rules::recognize(text, &config.entity_types, config.min_confidence)
+}
+
+/// Lazy-initialized flag that ensures the entity processor is registered exactly once.
+static PROCESSOR_INITIALIZED: Lazy<Result<()>> = Lazy::new(register_entity_processor);
+
+/// Ensure the entity processor is registered.
+///
+/// This function is called automatically when needed.
+/// It's safe to call multiple times - registration only happens once.
+pub fn ensure_initialized() -> Result<()> {
+    PROCESSOR_INITIALIZED
+        .as_ref()
+        .map(|_| ())
+        .map_err(|e| crate::KreuzbergError::Plugin {
+            message: format!("Failed to register entity processor: {}", e),
+            plugin_name: "entity-extraction".to_string(),
+        })
+}
+
+/// Register the entity extraction processor with the global registry.
+///
+/// **Note:** This is called automatically on first use.
+/// Explicit calling is optional.
+pub fn register_entity_processor() -> Result<()> {
+    let registry = get_post_processor_registry();
+    let mut registry = registry
+        .write()
+        .map_err(|e| crate::KreuzbergError::Other(format!("Post-processor registry lock poisoned: {}", e)))?;
+
+    registry.register(Arc::new(EntityExtractor), 45)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::EntityType;
+
+    #[test]
+    fn test_extract_entities_offsets() {
+        let text = "Invoice dated 2024-01-15 for USD 1,200.50 issued by Globex Ltd.";
+        let entities = extract_entities(text, &EntityConfig::default());
+
+        assert_eq!(entities.len(), 3);
+        for entity in &entities {
+            assert_eq!(&text[entity.byte_start..entity.byte_end], entity.text);
+        }
+        assert!(entities.windows(2).all(|w| w[0].byte_end <= w[1].byte_start));
+    }
+
+    #[test]
+    fn test_extract_entities_type_filter() {
+        let text = "Invoice dated 2024-01-15 for USD 1,200.50 issued by Globex Ltd.";
+        let config = EntityConfig::default().with_entity_types(vec![EntityType::Money]);
+        let entities = extract_entities(text, &config);
+
+        assert_eq!(entities.len(), 1);
+        assert_eq!(entities[0].entity_type, EntityType::Money);
+        assert_eq!(entities[0].text, "USD 1,200.50");
+    }
+}
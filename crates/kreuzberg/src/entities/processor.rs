@@ -0,0 +1,148 @@
+//! Named-entity extraction post-processor.
+//!
+//! This module provides a PostProcessor plugin that recognizes named entities
+//! in extraction results and stores them in `ExtractionResult::entities`.
+
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::{ExtractionConfig, ExtractionResult, Result};
+use async_trait::async_trait;
+
+/// Post-processor that extracts named entities from document content.
+///
+/// This processor:
+/// - Runs in the Middle processing stage
+/// - Only processes when `config.entities` is configured
+/// - Stores extracted entities in `result.entities`
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::Plugin;
+/// use kreuzberg::entities::EntityExtractor;
+///
+/// let processor = EntityExtractor;
+/// assert_eq!(processor.name(), "entity-extraction");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct EntityExtractor;
+
+impl Plugin for EntityExtractor {
+    fn name(&self) -> &str {
+        "entity-extraction"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for EntityExtractor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let entity_config = match &config.entities {
+            Some(cfg) => cfg,
+            None => return Ok(()),
+        };
+
+        result.entities = Some(super::extract_entities(&result.content, entity_config));
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Middle
+    }
+
+    fn should_process(&self, _result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        config.entities.is_some()
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        (result.content.len() as u64) / 10_000 + 5
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::EntityConfig;
+    use crate::types::{EntityType, Metadata};
+
+    fn result_with(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_entity_processor_populates_entities() {
+        let processor = EntityExtractor;
+        let config = ExtractionConfig {
+            entities: Some(EntityConfig::default()),
+            ..Default::default()
+        };
+
+        let mut result = result_with("Prof. Alan Turing lectured at the University of Manchester in June 1951.");
+        processor.process(&mut result, &config).await.unwrap();
+
+        let entities = result.entities.expect("entities should be set");
+        assert!(
+            entities
+                .iter()
+                .any(|e| e.entity_type == EntityType::Person && e.text == "Alan Turing")
+        );
+        assert!(
+            entities
+                .iter()
+                .any(|e| e.entity_type == EntityType::Organization && e.text == "University of Manchester")
+        );
+        assert!(
+            entities
+                .iter()
+                .any(|e| e.entity_type == EntityType::Date && e.text == "June 1951")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_entity_processor_no_config() {
+        let processor = EntityExtractor;
+        let config = ExtractionConfig::default();
+
+        let mut result = result_with("Dr. Jane Smith paid $500.");
+        processor.process(&mut result, &config).await.unwrap();
+
+        assert!(result.entities.is_none());
+        assert!(!processor.should_process(&result, &config));
+    }
+
+    #[test]
+    fn test_entity_processor_plugin_interface() {
+        let processor = EntityExtractor;
+        assert_eq!(processor.name(), "entity-extraction");
+        assert!(!processor.version().is_empty());
+        assert!(processor.initialize().is_ok());
+        assert!(processor.shutdown().is_ok());
+        assert_eq!(processor.processing_stage(), ProcessingStage::Middle);
+    }
+}
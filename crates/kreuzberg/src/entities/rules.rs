@@ -0,0 +1,300 @@
+//! Rule-based entity recognizer.
+//!
+//! Recognizes entities from surface patterns: honorifics and initials for persons, legal-form
+//! suffixes and institution keywords for organizations, numeric and month-name formats for
+//! dates, and currency symbols or codes for monetary amounts. Each pattern carries a fixed
+//! confidence; overlapping matches are resolved in favour of the more confident, longer one.
+
+use crate::types::{Entity, EntityType};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+const MONTHS: &str = "January|February|March|April|May|June|July|August|September|October|November|December|\
+                      Jan|Feb|Mar|Apr|Jun|Jul|Aug|Sept|Sep|Oct|Nov|Dec";
+
+const AMOUNT: &str = r"\d+(?:[,.\u{00A0}\u{202F}]\d{3})*(?:[.,]\d{1,2})?";
+
+const CURRENCY_CODES: &str = "USD|EUR|GBP|JPY|CHF|CAD|AUD|CNY|INR";
+
+/// Capitalized words that start sentences rather than names.
+const LEADING_STOPWORDS: &[&str] = &[
+    "The", "A", "An", "In", "At", "By", "For", "From", "With", "And", "Of", "On", "To", "As", "Both",
+];
+
+struct Rule {
+    entity_type: EntityType,
+    regex: Regex,
+    confidence: f64,
+    /// Capture group holding the entity (0 = whole match)
+    group: usize,
+}
+
+impl Rule {
+    fn new(entity_type: EntityType, pattern: &str, confidence: f64, group: usize) -> Self {
+        Self {
+            entity_type,
+            regex: Regex::new(pattern).expect("entity rule pattern is valid"),
+            confidence,
+            group,
+        }
+    }
+}
+
+static RULES: Lazy<Vec<Rule>> = Lazy::new(|| {
+    vec![
+        // Persons
+        Rule::new(
+            EntityType::Person,
+            r"\b(?:Mr|Mrs|Ms|Miss|Mx|Dr|Prof|Sir|Dame|Lord|Lady|Rev|Herr|Frau|Mme|Mlle)\.?\s+((?:[A-Z]\.\s*)*[A-Z][\p{Ll}'-]+(?:\s+(?:[A-Z]\.|[A-Z][\p{Ll}'-]+)){0,2})",
+            0.9,
+            1,
+        ),
+        Rule::new(
+            EntityType::Person,
+            r"\b[A-Z][\p{Ll}]+\s+(?:[A-Z]\.\s+)+[A-Z][\p{Ll}'-]+\b",
+            0.75,
+            0,
+        ),
+        Rule::new(
+            EntityType::Person,
+            r"\b(?:by|said|says|according to|signed by|written by)\s+([A-Z][\p{Ll}]+\s+[A-Z][\p{Ll}'-]+)\b",
+            0.6,
+            1,
+        ),
+        // Organizations
+        Rule::new(
+            EntityType::Organization,
+            r"\b(?:[A-Z][\p{L}\p{N}&'-]*\s+){0,4}[A-Z][\p{L}\p{N}&'-]*,?\s+(?:Inc|Incorporated|Corp|Corporation|Ltd|Limited|LLC|LLP|PLC|plc|GmbH|AG|SE|S\.A|SA|N\.V|NV|B\.V|BV|Co|Company|Group|Holdings)\b\.?",
+            0.85,
+            0,
+        ),
+        Rule::new(
+            EntityType::Organization,
+            r"\b(?:University|Institute|College|Bank|Ministry|Department|Bureau|Agency|Council)\s+of\s+(?:the\s+)?[A-Z][\p{L}]+(?:\s+[A-Z][\p{L}]+){0,3}",
+            0.8,
+            0,
+        ),
+        Rule::new(
+            EntityType::Organization,
+            r"\b(?:[A-Z][\p{L}&'-]+\s+){1,3}(?:University|Institute|Foundation|Association|Society|Bank|Agency|Commission|Committee)\b",
+            0.75,
+            0,
+        ),
+        // Dates
+        Rule::new(
+            EntityType::Date,
+            r"\b\d{4}-(?:0[1-9]|1[0-2])-(?:0[1-9]|[12]\d|3[01])\b",
+            0.95,
+            0,
+        ),
+        Rule::new(
+            EntityType::Date,
+            &format!(r"\b(?:{MONTHS})\.?\s+\d{{1,2}}(?:st|nd|rd|th)?,?\s+\d{{4}}\b"),
+            0.9,
+            0,
+        ),
+        Rule::new(
+            EntityType::Date,
+            &format!(r"\b\d{{1,2}}(?:st|nd|rd|th)?\.?\s+(?:of\s+)?(?:{MONTHS})\.?,?\s+\d{{4}}\b"),
+            0.9,
+            0,
+        ),
+        Rule::new(EntityType::Date, &format!(r"\b(?:{MONTHS})\s+\d{{4}}\b"), 0.75, 0),
+        Rule::new(
+            EntityType::Date,
+            r"\b(?:0?[1-9]|[12]\d|3[01])[./](?:0?[1-9]|[12]\d|3[01])[./](?:\d{4}|\d{2})\b",
+            0.7,
+            0,
+        ),
+        // Money
+        Rule::new(
+            EntityType::Money,
+            &format!(r"(?:US\$|A\$|C\$|[$€£¥₹])\s?{AMOUNT}(?:\s?(?:million|billion|trillion|thousand|mn|bn|[MBK])\b)?"),
+            0.9,
+            0,
+        ),
+        Rule::new(
+            EntityType::Money,
+            &format!(r"\b(?:{CURRENCY_CODES})\s?{AMOUNT}(?:\s?(?:million|billion|trillion|thousand)\b)?"),
+            0.9,
+            0,
+        ),
+        Rule::new(
+            EntityType::Money,
+            &format!(
+                r"\b{AMOUNT}(?:\s?(?:million|billion|trillion|thousand))?\s?(?:{CURRENCY_CODES}|dollars?|euros?|pounds?|yen)\b"
+            ),
+            0.85,
+            0,
+        ),
+        Rule::new(EntityType::Money, &format!(r"\b{AMOUNT}\s?[€£]"), 0.85, 0),
+    ]
+});
+
+/// Recognize entities of the given types in `text`.
+///
+/// Returned entities don't overlap and are sorted by position.
+pub(crate) fn recognize(text: &str, entity_types: &[EntityType], min_confidence: f64) -> Vec<Entity> {
+    let mut candidates: Vec<Entity> = Vec::new();
+
+    for rule in RULES.iter() {
+        if rule.confidence < min_confidence || !entity_types.contains(&rule.entity_type) {
+            continue;
+        }
+
+        for captures in rule.regex.captures_iter(text) {
+            let Some(m) = captures.get(rule.group) else {
+                continue;
+            };
+
+            let (start, end) = match rule.entity_type {
+                EntityType::Person | EntityType::Organization => trim_leading_stopwords(text, m.start(), m.end()),
+                _ => (m.start(), m.end()),
+            };
+            if start >= end {
+                continue;
+            }
+
+            candidates.push(Entity {
+                entity_type: rule.entity_type,
+                text: text[start..end].to_string(),
+                byte_start: start,
+                byte_end: end,
+                confidence: rule.confidence,
+            });
+        }
+    }
+
+    resolve_overlaps(candidates)
+}
+
+/// Keep non-overlapping entities, sorted by position.
+///
+/// Candidates are swept in order of position; a candidate overlapping the last kept entity
+/// replaces it if it is more confident (or equally confident and longer), and is dropped
+/// otherwise.
+fn resolve_overlaps(mut candidates: Vec<Entity>) -> Vec<Entity> {
+    candidates.sort_by_key(|e| (e.byte_start, e.byte_end));
+
+    let mut accepted: Vec<Entity> = Vec::with_capacity(candidates.len());
+    for candidate in candidates {
+        match accepted.last_mut() {
+            Some(last) if candidate.byte_start < last.byte_end => {
+                if outranks(&candidate, last) {
+                    *last = candidate;
+                }
+            }
+            _ => accepted.push(candidate),
+        }
+    }
+
+    accepted
+}
+
+/// Whether `a` wins an overlap against `b`: higher confidence, then longer span.
+fn outranks(a: &Entity, b: &Entity) -> bool {
+    a.confidence
+        .total_cmp(&b.confidence)
+        .then((a.byte_end - a.byte_start).cmp(&(b.byte_end - b.byte_start)))
+        .is_gt()
+}
+
+/// Drop sentence-initial function words ("The", "In", ...) from the start of a name.
+fn trim_leading_stopwords(text: &str, mut start: usize, end: usize) -> (usize, usize) {
+    loop {
+        let span = &text[start..end];
+        let word_end = span.find(char::is_whitespace).unwrap_or(span.len());
+        if word_end == span.len() || !LEADING_STOPWORDS.contains(&&span[..word_end]) {
+            return (start, end);
+        }
+        let rest = &span[word_end..];
+        start += word_end + (rest.len() - rest.trim_start().len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(entities: &[Entity], entity_type: EntityType) -> Vec<&str> {
+        entities
+            .iter()
+            .filter(|e| e.entity_type == entity_type)
+            .map(|e| e.text.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_recognize_all_types() {
+        let text = "On March 3, 2024 Dr. Jane Smith of Acme Corp. signed a contract worth $1,250,000 \
+                    with the University of Oxford, effective 2024-04-01.";
+        let entities = recognize(text, &EntityType::ALL, 0.5);
+
+        assert_eq!(texts(&entities, EntityType::Person), vec!["Jane Smith"]);
+        assert_eq!(
+            texts(&entities, EntityType::Organization),
+            vec!["Acme Corp.", "University of Oxford"]
+        );
+        assert_eq!(texts(&entities, EntityType::Date), vec!["March 3, 2024", "2024-04-01"]);
+        assert_eq!(texts(&entities, EntityType::Money), vec!["$1,250,000"]);
+
+        for entity in &entities {
+            assert_eq!(&text[entity.byte_start..entity.byte_end], entity.text);
+        }
+    }
+
+    #[test]
+    fn test_recognize_money_formats() {
+        let entities = recognize(
+            "Revenue rose to EUR 3.5 million, up from 2,000 dollars and 15 €.",
+            &[EntityType::Money],
+            0.5,
+        );
+        assert_eq!(
+            texts(&entities, EntityType::Money),
+            vec!["EUR 3.5 million", "2,000 dollars", "15 €"]
+        );
+    }
+
+    #[test]
+    fn test_recognize_filters_types_and_confidence() {
+        let text = "The report was written by John Miller on 12/05/2023.";
+        let entities = recognize(text, &[EntityType::Date], 0.5);
+        assert_eq!(texts(&entities, EntityType::Date), vec!["12/05/2023"]);
+        assert!(texts(&entities, EntityType::Person).is_empty());
+
+        let entities = recognize(text, &EntityType::ALL, 0.65);
+        assert!(texts(&entities, EntityType::Person).is_empty());
+        assert_eq!(texts(&entities, EntityType::Date), vec!["12/05/2023"]);
+    }
+
+    #[test]
+    fn test_resolve_overlaps() {
+        let entity = |byte_start, byte_end, confidence| Entity {
+            entity_type: EntityType::Organization,
+            text: String::new(),
+            byte_start,
+            byte_end,
+            confidence,
+        };
+        let spans = |entities: Vec<Entity>| -> Vec<(usize, usize)> {
+            entities.iter().map(|e| (e.byte_start, e.byte_end)).collect()
+        };
+
+        let resolved = resolve_overlaps(vec![
+            entity(20, 30, 0.6),
+            entity(0, 10, 0.6),
+            entity(5, 8, 0.9),
+            entity(22, 35, 0.6),
+            entity(40, 45, 0.5),
+        ]);
+        assert_eq!(spans(resolved), vec![(5, 8), (22, 35), (40, 45)]);
+    }
+
+    #[test]
+    fn test_trim_leading_stopwords() {
+        let text = "The Acme Group";
+        let (start, end) = trim_leading_stopwords(text, 0, text.len());
+        assert_eq!(&text[start..end], "Acme Group");
+    }
+}
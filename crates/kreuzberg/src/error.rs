@@ -39,6 +39,14 @@
 //!     Ok(content)
 //! }
 //! ```
+//!
+//! # Error Codes
+//!
+//! Every error maps to a stable [`ErrorCode`] and can be described as an [`ErrorInfo`]
+//! (code, message, stage, plugin, retryable flag). The API server, MCP server, CLI JSON
+//! output and all language bindings report errors in this shape.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 /// Result type alias using `KreuzbergError`.
@@ -64,6 +72,8 @@ pub type Result<T> = std::result::Result<T, KreuzbergError>;
 /// - `Plugin` - Plugin-specific errors
 /// - `LockPoisoned` - Mutex/RwLock poisoning (should not happen in normal operation)
 /// - `UnsupportedFormat` - Unsupported MIME type or file format
+/// - `EncryptedDocument` - Password-protected document that none of the configured passwords opens
+/// - `LimitExceeded` - A document exceeds a configured resource limit
 /// - `Other` - Catch-all for uncommon errors
 #[derive(Debug, Error)]
 pub enum KreuzbergError {
@@ -124,6 +134,12 @@ pub enum KreuzbergError {
     #[error("Unsupported format: {0}")]
     UnsupportedFormat(String),
 
+    #[error("Encrypted document: {0}")]
+    EncryptedDocument(String),
+
+    #[error("Limit exceeded: {limit} is {actual}, maximum is {max}")]
+    LimitExceeded { limit: String, actual: u64, max: u64 },
+
     #[error("{0}")]
     Other(String),
 }
@@ -207,6 +223,182 @@ impl KreuzbergError {
     error_constructor!(cache, Cache);
     error_constructor!(image_processing, ImageProcessing);
     error_constructor!(serialization, Serialization);
+
+    /// Create a LimitExceeded error
+    pub fn limit_exceeded<S: Into<String>>(limit: S, actual: u64, max: u64) -> Self {
+        Self::LimitExceeded {
+            limit: limit.into(),
+            actual,
+            max,
+        }
+    }
+
+    /// Stable code identifying the kind of error.
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::Io(_) => ErrorCode::Io,
+            Self::Parsing { .. } => ErrorCode::Parsing,
+            Self::Ocr { .. } => ErrorCode::Ocr,
+            Self::Validation { .. } => ErrorCode::Validation,
+            Self::Cache { .. } => ErrorCode::Cache,
+            Self::ImageProcessing { .. } => ErrorCode::ImageProcessing,
+            Self::Serialization { .. } => ErrorCode::Serialization,
+            Self::MissingDependency(_) => ErrorCode::MissingDependency,
+            Self::Plugin { .. } => ErrorCode::Plugin,
+            Self::LockPoisoned(_) => ErrorCode::LockPoisoned,
+            Self::UnsupportedFormat(_) => ErrorCode::UnsupportedFormat,
+            Self::EncryptedDocument(_) => ErrorCode::EncryptedDocument,
+            Self::LimitExceeded { .. } => ErrorCode::LimitExceeded,
+            Self::Other(_) => ErrorCode::Other,
+        }
+    }
+
+    /// Pipeline stage implied by the error kind: `mime_detection`, `extraction`, `ocr` or `cache`.
+    pub fn stage(&self) -> Option<&'static str> {
+        match self {
+            Self::UnsupportedFormat(_) => Some("mime_detection"),
+            Self::Parsing { .. } | Self::EncryptedDocument(_) | Self::LimitExceeded { .. } => Some("extraction"),
+            Self::Ocr { .. } => Some("ocr"),
+            Self::Cache { .. } => Some("cache"),
+            _ => None,
+        }
+    }
+
+    /// Name of the failing plugin, for `Plugin` errors.
+    pub fn plugin_name(&self) -> Option<&str> {
+        match self {
+            Self::Plugin { plugin_name, .. } => Some(plugin_name.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Whether retrying the same operation may succeed.
+    ///
+    /// True when the error or any error in its source chain is an I/O error of a transient
+    /// kind (interrupted, timed out, would block, or a reset, aborted or broken connection).
+    pub fn is_retryable(&self) -> bool {
+        let mut current: Option<&(dyn std::error::Error + 'static)> = Some(self);
+        while let Some(error) = current {
+            if let Some(io_error) = error.downcast_ref::<std::io::Error>()
+                && is_transient_io_error(io_error.kind())
+            {
+                return true;
+            }
+            current = error.source();
+        }
+        false
+    }
+
+    /// Serializable description of this error.
+    pub fn to_info(&self) -> ErrorInfo {
+        ErrorInfo {
+            code: self.code(),
+            message: self.to_string(),
+            stage: self.stage().map(str::to_string),
+            plugin: self.plugin_name().map(str::to_string),
+            retryable: self.is_retryable(),
+        }
+    }
+}
+
+fn is_transient_io_error(kind: std::io::ErrorKind) -> bool {
+    use std::io::ErrorKind;
+
+    matches!(
+        kind,
+        ErrorKind::Interrupted
+            | ErrorKind::TimedOut
+            | ErrorKind::WouldBlock
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::BrokenPipe
+    )
+}
+
+/// Stable, machine-readable error code.
+///
+/// Codes serialize as snake_case strings (`"parsing"`, `"missing_dependency"`, ...). They are
+/// part of the public contract and are never renamed; new codes may be added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    /// File system and I/O errors
+    Io,
+    /// Document parsing errors
+    Parsing,
+    /// OCR processing errors
+    Ocr,
+    /// Invalid input, configuration or parameters
+    Validation,
+    /// Cache operation errors
+    Cache,
+    /// Image manipulation errors
+    ImageProcessing,
+    /// JSON/MessagePack serialization errors
+    Serialization,
+    /// Missing optional dependency
+    MissingDependency,
+    /// Plugin failures
+    Plugin,
+    /// Poisoned lock
+    LockPoisoned,
+    /// Unsupported MIME type or file format
+    UnsupportedFormat,
+    /// Password-protected document that none of the configured passwords opens
+    EncryptedDocument,
+    /// Configured resource limit exceeded
+    LimitExceeded,
+    /// Anything else
+    Other,
+}
+
+impl ErrorCode {
+    /// The code as serialized, e.g. `"missing_dependency"`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Io => "io",
+            Self::Parsing => "parsing",
+            Self::Ocr => "ocr",
+            Self::Validation => "validation",
+            Self::Cache => "cache",
+            Self::ImageProcessing => "image_processing",
+            Self::Serialization => "serialization",
+            Self::MissingDependency => "missing_dependency",
+            Self::Plugin => "plugin",
+            Self::LockPoisoned => "lock_poisoned",
+            Self::UnsupportedFormat => "unsupported_format",
+            Self::EncryptedDocument => "encrypted_document",
+            Self::LimitExceeded => "limit_exceeded",
+            Self::Other => "other",
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Serializable description of a [`KreuzbergError`], shared by every error surface.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorInfo {
+    /// Stable error code
+    pub code: ErrorCode,
+    /// Human-readable message (the error's `Display` output)
+    pub message: String,
+    /// Pipeline stage the error belongs to, if the error kind implies one
+    pub stage: Option<String>,
+    /// Name of the failing plugin, for plugin errors
+    pub plugin: Option<String>,
+    /// Whether retrying the same operation may succeed
+    pub retryable: bool,
+}
+
+impl From<&KreuzbergError> for ErrorInfo {
+    fn from(error: &KreuzbergError) -> Self {
+        error.to_info()
+    }
 }
 
 #[cfg(test)]
@@ -326,6 +518,20 @@ mod tests {
         assert_eq!(err.to_string(), "Unsupported format: application/unknown");
     }
 
+    #[test]
+    fn test_encrypted_document_error() {
+        let err = KreuzbergError::EncryptedDocument("no password opens the document".to_string());
+        assert_eq!(err.to_string(), "Encrypted document: no password opens the document");
+        assert_eq!(err.code(), ErrorCode::EncryptedDocument);
+        assert_eq!(err.stage(), Some("extraction"));
+    }
+
+    #[test]
+    fn test_limit_exceeded_error() {
+        let err = KreuzbergError::limit_exceeded("max_pages", 1200, 500);
+        assert_eq!(err.to_string(), "Limit exceeded: max_pages is 1200, maximum is 500");
+    }
+
     #[test]
     fn test_other_error() {
         let err = KreuzbergError::Other("unexpected error".to_string());
@@ -428,4 +634,50 @@ mod tests {
         let parse_err = KreuzbergError::parsing("corrupted format");
         assert!(matches!(parse_err, KreuzbergError::Parsing { .. }));
     }
+
+    #[test]
+    fn test_error_info_serializes_stable_shape() {
+        let err = KreuzbergError::Plugin {
+            message: "boom".to_string(),
+            plugin_name: "my-ocr".to_string(),
+        };
+
+        let value = serde_json::to_value(err.to_info()).unwrap();
+
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "code": "plugin",
+                "message": "Plugin error in 'my-ocr': boom",
+                "stage": null,
+                "plugin": "my-ocr",
+                "retryable": false,
+            })
+        );
+    }
+
+    #[test]
+    fn test_error_code_as_str_matches_serde() {
+        for code in [ErrorCode::MissingDependency, ErrorCode::LimitExceeded, ErrorCode::Io] {
+            assert_eq!(serde_json::to_value(code).unwrap(), code.as_str());
+        }
+        assert_eq!(
+            KreuzbergError::limit_exceeded("max_pages", 3, 2).code(),
+            ErrorCode::LimitExceeded
+        );
+        assert_eq!(KreuzbergError::ocr("bad").stage(), Some("ocr"));
+    }
+
+    #[test]
+    fn test_is_retryable_walks_source_chain() {
+        let timeout = std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out");
+        assert!(KreuzbergError::from(timeout).is_retryable());
+
+        let reset = std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset");
+        assert!(KreuzbergError::ocr_with_source("backend failed", reset).is_retryable());
+
+        let missing = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+        assert!(!KreuzbergError::from(missing).is_retryable());
+        assert!(!KreuzbergError::validation("bad").is_retryable());
+    }
 }
@@ -0,0 +1,143 @@
+//! ISO 9660 disc image support.
+//!
+//! Images are read with `cdfs`, including Joliet and Rock Ridge file names. UDF-only images
+//! without an ISO 9660 bridge are not supported; hybrid UDF/ISO 9660 images (the common case)
+//! are read through their ISO 9660 file system.
+
+use super::{ArchiveEntry, ArchiveMetadata, ArchiveTextContent, is_wanted_entry};
+use crate::core::config::ArchiveConfig;
+use crate::error::{KreuzbergError, Result};
+use cdfs::{DirectoryEntry, ISO9660, ISODirectory, ISOFile};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+type Image<'a> = Cursor<&'a [u8]>;
+
+/// Directory nesting beyond which an image is treated as malformed, guarding against loops.
+const MAX_DIRECTORY_DEPTH: usize = 64;
+
+fn open_image(bytes: &[u8]) -> Result<ISO9660<Image<'_>>> {
+    ISO9660::new(Cursor::new(bytes)).map_err(|e| KreuzbergError::parsing(format!("Failed to read ISO image: {}", e)))
+}
+
+/// Strip the ISO 9660 version suffix (`README.TXT;1`) from a file identifier.
+fn file_name(identifier: &str) -> &str {
+    match identifier.rsplit_once(';') {
+        Some((name, version)) if !version.is_empty() && version.bytes().all(|b| b.is_ascii_digit()) => name,
+        _ => identifier,
+    }
+}
+
+/// Visit every directory and file of an image depth-first; directories are passed without a file.
+///
+/// Returns `Ok(false)` once `visit` asks to stop.
+fn visit_directory(
+    directory: &ISODirectory<Image<'_>>,
+    prefix: &str,
+    level: usize,
+    visit: &mut dyn FnMut(&str, Option<&ISOFile<Image<'_>>>) -> bool,
+) -> Result<bool> {
+    if level > MAX_DIRECTORY_DEPTH {
+        return Err(KreuzbergError::parsing(format!(
+            "ISO directory nesting exceeds {} levels",
+            MAX_DIRECTORY_DEPTH
+        )));
+    }
+
+    for entry in directory.contents() {
+        let entry = entry.map_err(|e| KreuzbergError::parsing(format!("Failed to read ISO directory entry: {}", e)))?;
+
+        if let DirectoryEntry::Directory(subdirectory) = &entry {
+            if subdirectory.identifier == "." || subdirectory.identifier == ".." {
+                continue;
+            }
+
+            let path = format!("{}{}/", prefix, subdirectory.identifier);
+            if !visit(&path, None) || !visit_directory(subdirectory, &path, level + 1, visit)? {
+                return Ok(false);
+            }
+        } else if let DirectoryEntry::File(file) = &entry {
+            let path = format!("{}{}", prefix, file_name(&file.identifier));
+            if !visit(&path, Some(file)) {
+                return Ok(false);
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+/// Extract metadata from an ISO 9660 image.
+pub fn extract_iso_metadata(bytes: &[u8]) -> Result<ArchiveMetadata> {
+    let image = open_image(bytes)?;
+
+    let mut file_list = Vec::new();
+    let mut total_size = 0u64;
+
+    visit_directory(image.root(), "", 0, &mut |path, file| {
+        let size = file.map_or(0, |file| u64::from(file.size()));
+        total_size += size;
+        file_list.push(ArchiveEntry {
+            path: path.to_string(),
+            size,
+            is_dir: file.is_none(),
+        });
+        true
+    })?;
+
+    let file_count = file_list.len();
+
+    Ok(ArchiveMetadata {
+        format: "ISO".to_string(),
+        file_list,
+        file_count,
+        total_size,
+    })
+}
+
+/// Extract text content from files within an ISO 9660 image.
+///
+/// Uses the default [`ArchiveConfig`] size limits; see [`extract_iso_text_content_with_limits`].
+pub fn extract_iso_text_content(bytes: &[u8]) -> Result<HashMap<String, String>> {
+    extract_iso_text_content_with_limits(bytes, &ArchiveConfig::default()).map(|text| text.contents)
+}
+
+/// Extract text content from files within an ISO 9660 image under a decompressed-size budget.
+///
+/// See [`super::extract_zip_text_content_with_limits`] for how limits and nested archives are
+/// handled.
+pub fn extract_iso_text_content_with_limits(bytes: &[u8], limits: &ArchiveConfig) -> Result<ArchiveTextContent> {
+    let mut text = ArchiveTextContent::default();
+    walk_iso(bytes, limits, &mut text, "", 0)?;
+    Ok(text)
+}
+
+pub(super) fn walk_iso(
+    bytes: &[u8],
+    limits: &ArchiveConfig,
+    text: &mut ArchiveTextContent,
+    prefix: &str,
+    depth: usize,
+) -> Result<()> {
+    let image = open_image(bytes)?;
+
+    visit_directory(image.root(), "", 0, &mut |name, file| {
+        let Some(file) = file else {
+            return true;
+        };
+
+        let size = u64::from(file.size());
+        if !is_wanted_entry(name, size, depth, limits) {
+            return true;
+        }
+
+        if !text.admit_entry(limits) {
+            return false;
+        }
+
+        text.process_entry(format!("{}{}", prefix, name), depth, size, file.read(), limits);
+        !text.truncated
+    })?;
+
+    Ok(())
+}
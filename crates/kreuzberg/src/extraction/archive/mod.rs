@@ -0,0 +1,1997 @@
+//! Archive extraction functionality.
+//!
+//! This module provides functions for listing archives and extracting their contents.
+//! RAR archives and ISO 9660 images are supported behind the `rar` and `iso` features.
+//! [`ArchiveConfig::members`] restricts extraction to the members matching path globs, MIME
+//! types and size bounds, and [`list_archive`] shows what an archive contains without
+//! decompressing anything.
+
+#[cfg(feature = "iso")]
+mod iso;
+#[cfg(feature = "rar")]
+mod rar;
+
+#[cfg(feature = "iso")]
+pub use iso::{extract_iso_metadata, extract_iso_text_content, extract_iso_text_content_with_limits};
+#[cfg(feature = "rar")]
+pub use rar::{
+    extract_rar_metadata, extract_rar_metadata_with_passwords, extract_rar_text_content,
+    extract_rar_text_content_with_limits,
+};
+
+use crate::core::config::ArchiveConfig;
+use crate::error::{KreuzbergError, Result};
+use serde::Serialize;
+use sevenz_rust::SevenZReader;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Cursor, Read, Seek};
+use std::path::Path;
+use tar::Archive as TarArchive;
+use zip::ZipArchive;
+
+/// Archive metadata extracted from an archive file.
+#[derive(Debug, Clone)]
+pub struct ArchiveMetadata {
+    /// Archive format (e.g., "ZIP", "TAR")
+    pub format: String,
+    /// List of files in the archive
+    pub file_list: Vec<ArchiveEntry>,
+    /// Total number of files
+    pub file_count: usize,
+    /// Total uncompressed size in bytes
+    pub total_size: u64,
+}
+
+impl ArchiveMetadata {
+    fn from_entries(format: &str, file_list: Vec<ArchiveEntry>) -> Self {
+        let file_count = file_list.len();
+        let total_size = file_list
+            .iter()
+            .filter(|entry| !entry.is_dir)
+            .map(|entry| entry.size)
+            .sum();
+
+        Self {
+            format: format.to_string(),
+            file_list,
+            file_count,
+            total_size,
+        }
+    }
+}
+
+/// Information about a single file in an archive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ArchiveEntry {
+    /// File path within the archive
+    pub path: String,
+    /// File size in bytes
+    pub size: u64,
+    /// Whether this is a directory
+    pub is_dir: bool,
+}
+
+/// Bytes read to detect the format of archives whose extension is unknown.
+const FORMAT_SNIFF_BYTES: u64 = 8192;
+
+/// List the members of an archive file without decompressing them.
+///
+/// ZIP archives are listed from their central directory and 7z archives from their header, and
+/// TAR archives are scanned by seeking from one member header to the next, so listing an
+/// archive with tens of thousands of members stays cheap. RAR archives and ISO images are
+/// supported behind the `rar` and `iso` features. The format is detected from the file
+/// extension, falling back to the content. Set [`ArchiveConfig::members`] to extract only some
+/// of the listed members.
+///
+/// # Errors
+///
+/// Returns `UnsupportedFormat` for files that are not archives of a supported format, and a
+/// parsing error for archives that cannot be read, including 7z and RAR archives with
+/// encrypted headers.
+pub fn list_archive(path: impl AsRef<Path>) -> Result<Vec<ArchiveEntry>> {
+    let path = path.as_ref();
+    let mut file = File::open(path)?;
+
+    let mime_type = match crate::core::mime::detect_mime_type(path, false) {
+        Ok(mime_type) => mime_type,
+        Err(_) => {
+            let mut head = Vec::new();
+            (&file).take(FORMAT_SNIFF_BYTES).read_to_end(&mut head)?;
+            file.rewind()?;
+            crate::core::mime::detect_mime_type_from_bytes(&head)?
+        }
+    };
+
+    match mime_type.as_str() {
+        "application/zip" | "application/x-zip-compressed" => zip_entries(file),
+        "application/x-tar" | "application/tar" | "application/x-gtar" | "application/x-ustar" => tar_entries(file),
+        "application/x-7z-compressed" => {
+            let len = file.metadata()?.len();
+            sevenz_entries(file, len, "")
+        }
+        #[cfg(feature = "rar")]
+        "application/vnd.rar" | "application/x-rar-compressed" | "application/x-rar" => {
+            rar::read_rar_metadata(path, "").map(|metadata| metadata.file_list)
+        }
+        #[cfg(feature = "iso")]
+        "application/x-iso9660-image" | "application/x-cd-image" => {
+            let bytes = std::fs::read(path)?;
+            extract_iso_metadata(&bytes).map(|metadata| metadata.file_list)
+        }
+        other => Err(KreuzbergError::UnsupportedFormat(format!(
+            "Cannot list '{}': not a supported archive ({})",
+            path.display(),
+            other
+        ))),
+    }
+}
+
+/// Extract metadata from a ZIP archive.
+pub fn extract_zip_metadata(bytes: &[u8]) -> Result<ArchiveMetadata> {
+    let file_list = zip_entries(Cursor::new(bytes))?;
+    Ok(ArchiveMetadata::from_entries("ZIP", file_list))
+}
+
+/// Members of a ZIP archive, read from its central directory.
+fn zip_entries<R: Read + Seek>(reader: R) -> Result<Vec<ArchiveEntry>> {
+    let mut archive =
+        ZipArchive::new(reader).map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP archive: {}", e)))?;
+
+    let mut file_list = Vec::with_capacity(archive.len());
+    for i in 0..archive.len() {
+        let file = archive
+            .by_index_raw(i)
+            .map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP entry: {}", e)))?;
+
+        file_list.push(ArchiveEntry {
+            path: file.name().to_string(),
+            size: file.size(),
+            is_dir: file.is_dir(),
+        });
+    }
+
+    Ok(file_list)
+}
+
+/// Extract metadata from a TAR archive.
+pub fn extract_tar_metadata(bytes: &[u8]) -> Result<ArchiveMetadata> {
+    let file_list = tar_entries(Cursor::new(bytes))?;
+    Ok(ArchiveMetadata::from_entries("TAR", file_list))
+}
+
+/// Members of a TAR archive, seeking past the data of each member.
+fn tar_entries<R: Read + Seek>(reader: R) -> Result<Vec<ArchiveEntry>> {
+    let mut archive = TarArchive::new(reader);
+
+    let entries = archive
+        .entries_with_seek()
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR archive: {}", e)))?;
+
+    let mut file_list = Vec::new();
+    for entry_result in entries {
+        let entry = entry_result.map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR entry: {}", e)))?;
+
+        let path = entry
+            .path()
+            .map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR entry path: {}", e)))?
+            .to_string_lossy()
+            .to_string();
+
+        file_list.push(ArchiveEntry {
+            path,
+            size: entry.size(),
+            is_dir: entry.header().entry_type().is_dir(),
+        });
+    }
+
+    Ok(file_list)
+}
+
+/// File extensions treated as text when extracting archive member contents.
+const TEXT_EXTENSIONS: [&str; 9] = [
+    ".txt", ".md", ".json", ".xml", ".html", ".csv", ".log", ".yaml", ".toml",
+];
+
+/// Archive formats that are expanded when nested inside another archive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ArchiveKind {
+    Zip,
+    Tar,
+    SevenZ,
+    #[cfg(feature = "rar")]
+    Rar,
+    #[cfg(feature = "iso")]
+    Iso,
+}
+
+impl ArchiveKind {
+    fn from_path(path: &str) -> Option<Self> {
+        let (_, extension) = path.rsplit_once('.')?;
+        match extension.to_lowercase().as_str() {
+            "zip" => Some(Self::Zip),
+            "tar" => Some(Self::Tar),
+            "7z" => Some(Self::SevenZ),
+            #[cfg(feature = "rar")]
+            "rar" => Some(Self::Rar),
+            #[cfg(feature = "iso")]
+            "iso" => Some(Self::Iso),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of processing a single archive member.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchiveEntryStatus {
+    /// Text content was extracted
+    Extracted,
+    /// Nested archive whose members were processed
+    Expanded,
+    /// Larger than `max_entry_size`
+    Skipped,
+    /// Encrypted, and none of the configured passwords matched
+    Encrypted,
+    /// Could not be read, decoded or parsed
+    Failed,
+}
+
+/// Result for a single text or nested-archive member.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ArchiveEntryResult {
+    /// Member path; members of nested archives are prefixed with the path of their archive
+    /// (e.g. `docs/inner.zip/readme.txt`)
+    pub path: String,
+    /// Nesting depth, 0 for members of the top-level archive
+    pub depth: usize,
+    /// Decompressed size in bytes as declared by the archive
+    pub size: u64,
+    /// What happened to the member
+    pub status: ArchiveEntryStatus,
+}
+
+/// Text contents extracted from archive members under a decompressed-size budget.
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveTextContent {
+    /// Decoded text keyed by member path (nested members use prefixed paths)
+    pub contents: HashMap<String, String>,
+    /// Members skipped because they exceed `max_entry_size`
+    pub skipped_entries: Vec<String>,
+    /// Whether extraction stopped early because `max_total_size` or `max_entries` was reached
+    pub truncated: bool,
+    /// Total number of decompressed bytes read
+    pub decompressed_bytes: u64,
+    /// Number of text and nested-archive members processed, including nested members
+    pub entry_count: usize,
+    /// Per-member results for text members and nested archives, in archive order
+    pub entries: Vec<ArchiveEntryResult>,
+    /// Members that could not be read; used to detect wrong RAR passwords
+    read_failures: usize,
+}
+
+impl ArchiveTextContent {
+    fn record(&mut self, path: &str, depth: usize, size: u64, status: ArchiveEntryStatus) {
+        self.entries.push(ArchiveEntryResult {
+            path: path.to_string(),
+            depth,
+            size,
+            status,
+        });
+    }
+
+    /// Count a member against `max_entries`.
+    ///
+    /// Returns `false` and marks the content as truncated once the limit is reached.
+    fn admit_entry(&mut self, limits: &ArchiveConfig) -> bool {
+        if self.entry_count >= limits.max_entries {
+            tracing::warn!(
+                "Archive entry limit of {} reached, skipping remaining entries",
+                limits.max_entries
+            );
+            self.truncated = true;
+            return false;
+        }
+
+        self.entry_count += 1;
+        true
+    }
+
+    /// Process a text or nested-archive member of an archive at `depth`.
+    ///
+    /// Sets `truncated` once the total budget is exhausted and extraction should stop.
+    fn process_entry<R: Read>(&mut self, path: String, depth: usize, size: u64, reader: R, limits: &ArchiveConfig) {
+        if size > limits.max_entry_size {
+            self.skip_entry(path, depth, size);
+            return;
+        }
+
+        let Some(content) = self.read_member(&path, depth, size, reader, limits) else {
+            return;
+        };
+
+        if let Some(kind) = ArchiveKind::from_path(&path) {
+            self.expand_nested(kind, &content, &path, depth, limits);
+            return;
+        }
+
+        match String::from_utf8(content) {
+            Ok(text) => {
+                self.record(&path, depth, size, ArchiveEntryStatus::Extracted);
+                self.contents.insert(path, text);
+            }
+            Err(_) => self.record(&path, depth, size, ArchiveEntryStatus::Failed),
+        }
+    }
+
+    /// Read a single member, streaming at most the remaining budget into memory.
+    fn read_member<R: Read>(
+        &mut self,
+        path: &str,
+        depth: usize,
+        size: u64,
+        reader: R,
+        limits: &ArchiveConfig,
+    ) -> Option<Vec<u8>> {
+        let remaining = limits.max_total_size.saturating_sub(self.decompressed_bytes);
+        let limit = limits.max_entry_size.min(remaining);
+
+        let mut content = Vec::new();
+        if reader.take(limit.saturating_add(1)).read_to_end(&mut content).is_err() {
+            self.read_failures += 1;
+            self.record(path, depth, size, ArchiveEntryStatus::Failed);
+            return None;
+        }
+
+        let read = content.len() as u64;
+        self.decompressed_bytes += read.min(limit);
+
+        if read > limit {
+            if limit < limits.max_entry_size {
+                tracing::warn!(
+                    "Archive decompressed-size budget of {} bytes exhausted at '{}', skipping remaining entries",
+                    limits.max_total_size,
+                    path
+                );
+                self.truncated = true;
+                return None;
+            }
+
+            tracing::debug!(
+                "Skipping archive entry '{}' larger than {} bytes",
+                path,
+                limits.max_entry_size
+            );
+            self.skipped_entries.push(path.to_string());
+            self.record(path, depth, size, ArchiveEntryStatus::Skipped);
+            return None;
+        }
+
+        Some(content)
+    }
+
+    /// Walk the members of a nested archive, prefixing their paths with the archive's path.
+    fn expand_nested(&mut self, kind: ArchiveKind, bytes: &[u8], path: &str, depth: usize, limits: &ArchiveConfig) {
+        let index = self.entries.len();
+        self.record(path, depth, bytes.len() as u64, ArchiveEntryStatus::Expanded);
+
+        let prefix = format!("{}/", path);
+        let walked = match kind {
+            ArchiveKind::Zip => walk_zip(bytes, limits, self, &prefix, depth + 1),
+            ArchiveKind::Tar => walk_tar(bytes, limits, self, &prefix, depth + 1),
+            ArchiveKind::SevenZ => walk_7z(bytes, limits, self, &prefix, depth + 1),
+            #[cfg(feature = "rar")]
+            ArchiveKind::Rar => rar::walk_rar(bytes, limits, self, &prefix, depth + 1),
+            #[cfg(feature = "iso")]
+            ArchiveKind::Iso => iso::walk_iso(bytes, limits, self, &prefix, depth + 1),
+        };
+
+        if let Err(e) = walked {
+            tracing::warn!("Failed to expand nested archive '{}': {}", path, e);
+            self.entries[index].status = ArchiveEntryStatus::Failed;
+        }
+    }
+
+    /// Record a member whose declared size already exceeds `max_entry_size`.
+    fn skip_entry(&mut self, path: String, depth: usize, size: u64) {
+        tracing::debug!("Skipping oversized archive entry '{}'", path);
+        self.record(&path, depth, size, ArchiveEntryStatus::Skipped);
+        self.skipped_entries.push(path);
+    }
+}
+
+fn is_text_entry(path: &str) -> bool {
+    let path = path.to_lowercase();
+    TEXT_EXTENSIONS.iter().any(|ext| path.ends_with(ext))
+}
+
+/// Whether a member of an archive at `depth` is extracted: text files selected by
+/// `members`, and nested archives while `max_depth` has not been reached.
+fn is_wanted_entry(path: &str, size: u64, depth: usize, limits: &ArchiveConfig) -> bool {
+    if ArchiveKind::from_path(path).is_some() {
+        return depth < limits.max_depth;
+    }
+    is_text_entry(path) && is_selected(path, size, limits)
+}
+
+/// Whether a member passes the `members` filter of `limits`.
+fn is_selected(path: &str, size: u64, limits: &ArchiveConfig) -> bool {
+    limits.members.as_ref().is_none_or(|filter| filter.matches(path, size))
+}
+
+fn archive_passwords(limits: &ArchiveConfig) -> &[String] {
+    limits.passwords.as_deref().unwrap_or_default()
+}
+
+/// No password first, then each configured password.
+fn password_candidates(passwords: &[String]) -> impl Iterator<Item = &str> {
+    std::iter::once("").chain(passwords.iter().map(String::as_str))
+}
+
+/// Return the result of the first password candidate that opens the archive.
+fn open_with_passwords<T>(passwords: &[String], mut open: impl FnMut(&str) -> Result<T>) -> Result<T> {
+    let mut last_error = None;
+
+    for password in password_candidates(passwords) {
+        match open(password) {
+            Ok(value) => return Ok(value),
+            Err(err) => last_error = Some(err),
+        }
+    }
+
+    Err(last_error.unwrap_or_else(|| KreuzbergError::parsing("Failed to read archive")))
+}
+
+/// Find the first configured password that decrypts ZIP member `index`.
+///
+/// ZipCrypto's password check byte accepts one wrong password in 256, so each candidate is
+/// confirmed by reading the member to its end, where its CRC (or AES authentication code) is
+/// verified. Members larger than `max_size` are skipped by the callers and only checked against
+/// the password verifier.
+fn find_zip_password<'p, R: Read + Seek>(
+    archive: &mut ZipArchive<R>,
+    index: usize,
+    passwords: &'p [String],
+    max_size: u64,
+) -> Option<&'p str> {
+    passwords.iter().map(String::as_str).find(|password| {
+        let Ok(file) = archive.by_index_decrypt(index, password.as_bytes()) else {
+            return false;
+        };
+        if file.size() > max_size {
+            return true;
+        }
+        let mut file = file.take(max_size.saturating_add(1));
+        std::io::copy(&mut file, &mut std::io::sink()).is_ok()
+    })
+}
+
+/// Extract text content from files within a ZIP archive.
+///
+/// Only extracts files with common text extensions: .txt, .md, .json, .xml, .html, .csv, .log
+///
+/// Uses the default [`ArchiveConfig`] size limits; see [`extract_zip_text_content_with_limits`].
+pub fn extract_zip_text_content(bytes: &[u8]) -> Result<HashMap<String, String>> {
+    extract_zip_text_content_with_limits(bytes, &ArchiveConfig::default()).map(|text| text.contents)
+}
+
+/// Extract text content from files within a ZIP archive under a decompressed-size budget.
+///
+/// Members are streamed one at a time and never buffered beyond `max_entry_size`, so
+/// decompression bombs cannot exhaust memory. Oversized members are skipped and reported
+/// in [`ArchiveTextContent::skipped_entries`].
+///
+/// ZIP, TAR and 7z archives nested up to `max_depth` levels deep are expanded, with their
+/// members keyed by `<archive path>/<member path>`. Encrypted members are decrypted with the
+/// first matching password from `passwords`.
+pub fn extract_zip_text_content_with_limits(bytes: &[u8], limits: &ArchiveConfig) -> Result<ArchiveTextContent> {
+    let mut text = ArchiveTextContent::default();
+    walk_zip(bytes, limits, &mut text, "", 0)?;
+    Ok(text)
+}
+
+fn walk_zip(
+    bytes: &[u8],
+    limits: &ArchiveConfig,
+    text: &mut ArchiveTextContent,
+    prefix: &str,
+    depth: usize,
+) -> Result<()> {
+    let cursor = Cursor::new(bytes);
+    let mut archive =
+        ZipArchive::new(cursor).map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP archive: {}", e)))?;
+
+    for i in 0..archive.len() {
+        if text.truncated {
+            break;
+        }
+
+        let (name, size, is_dir, encrypted) = {
+            let file = archive
+                .by_index_raw(i)
+                .map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP entry: {}", e)))?;
+            (file.name().to_string(), file.size(), file.is_dir(), file.encrypted())
+        };
+
+        if is_dir || !is_wanted_entry(&name, size, depth, limits) {
+            continue;
+        }
+
+        if !text.admit_entry(limits) {
+            break;
+        }
+
+        let path = format!("{}{}", prefix, name);
+
+        let password = if encrypted {
+            let max_size = limits.max_entry_size;
+            let Some(password) = find_zip_password(&mut archive, i, archive_passwords(limits), max_size) else {
+                tracing::debug!("No configured password decrypts archive entry '{}'", path);
+                text.record(&path, depth, size, ArchiveEntryStatus::Encrypted);
+                continue;
+            };
+            Some(password)
+        } else {
+            None
+        };
+
+        let file = match password {
+            Some(password) => archive.by_index_decrypt(i, password.as_bytes()),
+            None => archive.by_index(i),
+        }
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP entry: {}", e)))?;
+
+        text.process_entry(path, depth, size, file, limits);
+    }
+
+    Ok(())
+}
+
+/// A decompressed member of an archive.
+#[derive(Debug, Clone)]
+pub struct ArchiveFile {
+    /// Member path within the archive
+    pub path: String,
+    /// Decompressed bytes
+    pub data: Vec<u8>,
+}
+
+/// Members of an archive decompressed under the limits of an [`ArchiveConfig`].
+#[derive(Debug, Clone, Default)]
+pub struct ArchiveFiles {
+    /// Decompressed members, in archive order
+    pub files: Vec<ArchiveFile>,
+    /// Per-member outcome, including skipped and encrypted members
+    pub entries: Vec<ArchiveEntryResult>,
+    /// Whether decompression stopped early because `max_total_size` or `max_entries` was reached
+    pub truncated: bool,
+}
+
+/// Files decompressed from an archive so far, with the budget they count against.
+#[derive(Debug, Clone, Default)]
+struct FileCollector {
+    budget: ArchiveTextContent,
+    files: Vec<ArchiveFile>,
+}
+
+impl FileCollector {
+    /// Whether a member is read: selected by `members`, within `max_entries` and no larger
+    /// than `max_entry_size`.
+    fn admit(&mut self, name: &str, size: u64, limits: &ArchiveConfig) -> bool {
+        if !is_selected(name, size, limits) || !self.budget.admit_entry(limits) {
+            return false;
+        }
+        if size > limits.max_entry_size {
+            self.budget.skip_entry(name.to_string(), 0, size);
+            return false;
+        }
+        true
+    }
+
+    fn read<R: Read>(&mut self, name: String, size: u64, reader: R, limits: &ArchiveConfig) {
+        if let Some(data) = self.budget.read_member(&name, 0, size, reader, limits) {
+            self.budget.record(&name, 0, size, ArchiveEntryStatus::Extracted);
+            self.files.push(ArchiveFile { path: name, data });
+        }
+    }
+
+    fn finish(self) -> ArchiveFiles {
+        ArchiveFiles {
+            files: self.files,
+            entries: self.budget.entries,
+            truncated: self.budget.truncated,
+        }
+    }
+}
+
+/// Decompress every file of a ZIP archive, whatever its type, so that each member can be
+/// extracted as a document of its own.
+///
+/// `max_entry_size`, `max_total_size`, `max_entries` and `passwords` apply as for
+/// [`extract_zip_text_content_with_limits`], and only the members selected by `members` are
+/// decompressed. Nested archives are returned as files, not expanded.
+pub fn extract_zip_files(bytes: &[u8], limits: &ArchiveConfig) -> Result<ArchiveFiles> {
+    let cursor = Cursor::new(bytes);
+    let mut archive =
+        ZipArchive::new(cursor).map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP archive: {}", e)))?;
+
+    let mut collector = FileCollector::default();
+
+    for i in 0..archive.len() {
+        if collector.budget.truncated {
+            break;
+        }
+
+        let (name, size, is_dir, encrypted) = {
+            let file = archive
+                .by_index_raw(i)
+                .map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP entry: {}", e)))?;
+            (file.name().to_string(), file.size(), file.is_dir(), file.encrypted())
+        };
+
+        if is_dir || !collector.admit(&name, size, limits) {
+            continue;
+        }
+
+        let password = if encrypted {
+            let max_size = limits.max_entry_size;
+            let Some(password) = find_zip_password(&mut archive, i, archive_passwords(limits), max_size) else {
+                tracing::debug!("No configured password decrypts archive entry '{}'", name);
+                collector.budget.record(&name, 0, size, ArchiveEntryStatus::Encrypted);
+                continue;
+            };
+            Some(password)
+        } else {
+            None
+        };
+
+        let file = match password {
+            Some(password) => archive.by_index_decrypt(i, password.as_bytes()),
+            None => archive.by_index(i),
+        }
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read ZIP entry: {}", e)))?;
+
+        collector.read(name, size, file, limits);
+    }
+
+    Ok(collector.finish())
+}
+
+/// Decompress every file of a TAR archive, whatever its type.
+///
+/// See [`extract_zip_files`] for how limits and the `members` filter apply.
+pub fn extract_tar_files(bytes: &[u8], limits: &ArchiveConfig) -> Result<ArchiveFiles> {
+    let cursor = Cursor::new(bytes);
+    let mut archive = TarArchive::new(cursor);
+
+    let entries = archive
+        .entries()
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR archive: {}", e)))?;
+
+    let mut collector = FileCollector::default();
+
+    for entry_result in entries {
+        if collector.budget.truncated {
+            break;
+        }
+
+        let entry = entry_result.map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR entry: {}", e)))?;
+
+        let name = entry
+            .path()
+            .map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR entry path: {}", e)))?
+            .to_string_lossy()
+            .to_string();
+
+        let size = entry.size();
+        if entry.header().entry_type().is_dir() || !collector.admit(&name, size, limits) {
+            continue;
+        }
+
+        collector.read(name, size, entry, limits);
+    }
+
+    Ok(collector.finish())
+}
+
+/// Extract text content from files within a TAR archive.
+///
+/// Only extracts files with common text extensions: .txt, .md, .json, .xml, .html, .csv, .log
+///
+/// Uses the default [`ArchiveConfig`] size limits; see [`extract_tar_text_content_with_limits`].
+pub fn extract_tar_text_content(bytes: &[u8]) -> Result<HashMap<String, String>> {
+    extract_tar_text_content_with_limits(bytes, &ArchiveConfig::default()).map(|text| text.contents)
+}
+
+/// Extract text content from files within a TAR archive under a decompressed-size budget.
+///
+/// See [`extract_zip_text_content_with_limits`] for how limits and nested archives are handled.
+pub fn extract_tar_text_content_with_limits(bytes: &[u8], limits: &ArchiveConfig) -> Result<ArchiveTextContent> {
+    let mut text = ArchiveTextContent::default();
+    walk_tar(bytes, limits, &mut text, "", 0)?;
+    Ok(text)
+}
+
+fn walk_tar(
+    bytes: &[u8],
+    limits: &ArchiveConfig,
+    text: &mut ArchiveTextContent,
+    prefix: &str,
+    depth: usize,
+) -> Result<()> {
+    let cursor = Cursor::new(bytes);
+    let mut archive = TarArchive::new(cursor);
+
+    let entries = archive
+        .entries()
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR archive: {}", e)))?;
+
+    for entry_result in entries {
+        if text.truncated {
+            break;
+        }
+
+        let entry = entry_result.map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR entry: {}", e)))?;
+
+        let name = entry
+            .path()
+            .map_err(|e| KreuzbergError::parsing(format!("Failed to read TAR entry path: {}", e)))?
+            .to_string_lossy()
+            .to_string();
+
+        let size = entry.size();
+        if entry.header().entry_type().is_dir() || !is_wanted_entry(&name, size, depth, limits) {
+            continue;
+        }
+
+        if !text.admit_entry(limits) {
+            break;
+        }
+
+        text.process_entry(format!("{}{}", prefix, name), depth, size, entry, limits);
+    }
+
+    Ok(())
+}
+
+/// Extract metadata from a 7z archive.
+pub fn extract_7z_metadata(bytes: &[u8]) -> Result<ArchiveMetadata> {
+    extract_7z_metadata_with_passwords(bytes, &[])
+}
+
+/// Extract metadata from a 7z archive, trying each password for archives with encrypted headers.
+pub fn extract_7z_metadata_with_passwords(bytes: &[u8], passwords: &[String]) -> Result<ArchiveMetadata> {
+    open_with_passwords(passwords, |password| read_7z_metadata(bytes, password))
+}
+
+fn read_7z_metadata(bytes: &[u8], password: &str) -> Result<ArchiveMetadata> {
+    let file_list = sevenz_entries(Cursor::new(bytes), bytes.len() as u64, password)?;
+    Ok(ArchiveMetadata::from_entries("7Z", file_list))
+}
+
+/// Members of a 7z archive, read from its header.
+fn sevenz_entries<R: Read + Seek>(reader: R, len: u64, password: &str) -> Result<Vec<ArchiveEntry>> {
+    let archive = SevenZReader::new(reader, len, password.into())
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read 7z archive: {}", e)))?;
+
+    let file_list = archive
+        .archive()
+        .files
+        .iter()
+        .map(|entry| ArchiveEntry {
+            path: entry.name().to_string(),
+            size: entry.size(),
+            is_dir: entry.is_directory(),
+        })
+        .collect();
+    Ok(file_list)
+}
+
+/// Extract text content from files within a 7z archive.
+///
+/// Only extracts files with common text extensions: .txt, .md, .json, .xml, .html, .csv, .log
+///
+/// Uses the default [`ArchiveConfig`] size limits; see [`extract_7z_text_content_with_limits`].
+pub fn extract_7z_text_content(bytes: &[u8]) -> Result<HashMap<String, String>> {
+    extract_7z_text_content_with_limits(bytes, &ArchiveConfig::default()).map(|text| text.contents)
+}
+
+/// Extract text content from files within a 7z archive under a decompressed-size budget.
+///
+/// See [`extract_zip_text_content_with_limits`] for how limits and nested archives are handled.
+/// Encrypted archives are opened with the first password that decompresses their first file.
+pub fn extract_7z_text_content_with_limits(bytes: &[u8], limits: &ArchiveConfig) -> Result<ArchiveTextContent> {
+    let mut text = ArchiveTextContent::default();
+    walk_7z(bytes, limits, &mut text, "", 0)?;
+    Ok(text)
+}
+
+/// Decompress every file of a 7z archive, whatever its type.
+///
+/// See [`extract_zip_files`] for how limits and the `members` filter apply. Encrypted archives
+/// are opened with the first password that decompresses their first file.
+pub fn extract_7z_files(bytes: &[u8], limits: &ArchiveConfig) -> Result<ArchiveFiles> {
+    let mut collector = FileCollector::default();
+    let password = find_7z_password(bytes, archive_passwords(limits), limits.max_entry_size);
+    collect_7z_files(bytes, password, limits, &mut collector)?;
+    Ok(collector.finish())
+}
+
+fn collect_7z_files(bytes: &[u8], password: &str, limits: &ArchiveConfig, collector: &mut FileCollector) -> Result<()> {
+    let cursor = Cursor::new(bytes);
+    let mut archive = SevenZReader::new(cursor, bytes.len() as u64, password.into())
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read 7z archive: {}", e)))?;
+
+    archive
+        .for_each_entries(|entry, reader| {
+            let name = entry.name();
+            if !entry.is_directory() && collector.admit(name, entry.size(), limits) {
+                collector.read(name.to_string(), entry.size(), reader, limits);
+            }
+            Ok(!collector.budget.truncated)
+        })
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read 7z entries: {}", e)))?;
+
+    Ok(())
+}
+
+fn walk_7z(
+    bytes: &[u8],
+    limits: &ArchiveConfig,
+    text: &mut ArchiveTextContent,
+    prefix: &str,
+    depth: usize,
+) -> Result<()> {
+    let password = find_7z_password(bytes, archive_passwords(limits), limits.max_entry_size);
+    walk_7z_with_password(bytes, password, limits, text, prefix, depth)
+}
+
+/// Find the password candidate (no password first) that opens a 7z archive.
+///
+/// A wrong password either fails to open an archive with encrypted headers or fails to
+/// decompress its first file, whose CRC is verified, so only that member is decompressed per
+/// candidate, at most `max_size` bytes of it. Falls back to no password when none matches.
+fn find_7z_password<'p>(bytes: &[u8], passwords: &'p [String], max_size: u64) -> &'p str {
+    if passwords.is_empty() {
+        return "";
+    }
+
+    password_candidates(passwords)
+        .find(|password| opens_7z(bytes, password, max_size))
+        .unwrap_or("")
+}
+
+/// Whether `password` opens a 7z archive and decompresses its first non-empty file.
+fn opens_7z(bytes: &[u8], password: &str, max_size: u64) -> bool {
+    let Ok(mut archive) = SevenZReader::new(Cursor::new(bytes), bytes.len() as u64, password.into()) else {
+        return false;
+    };
+
+    let mut readable = true;
+    let walked = archive.for_each_entries(|entry, reader| {
+        if entry.is_directory() || entry.size() == 0 {
+            return Ok(true);
+        }
+        let mut reader = reader.take(max_size.saturating_add(1));
+        readable = std::io::copy(&mut reader, &mut std::io::sink()).is_ok();
+        Ok(false)
+    });
+    walked.is_ok() && readable
+}
+
+/// State of a walk over the members of an archive.
+trait ArchiveWalk: Clone {
+    /// Members that could not be read
+    fn read_failures(&self) -> usize;
+}
+
+impl ArchiveWalk for ArchiveTextContent {
+    fn read_failures(&self) -> usize {
+        self.read_failures
+    }
+}
+
+/// Walk an archive that is encrypted as a whole, trying no password and then each configured one.
+///
+/// A wrong password either fails to open the archive (encrypted headers) or surfaces as read
+/// failures of its members, so each candidate walks a copy of the content and the first clean
+/// walk wins.
+fn walk_with_passwords<T: ArchiveWalk>(
+    limits: &ArchiveConfig,
+    text: &mut T,
+    mut walk: impl FnMut(&str, &mut T) -> Result<()>,
+) -> Result<()> {
+    let passwords = archive_passwords(limits);
+    if passwords.is_empty() {
+        return walk("", text);
+    }
+
+    let mut fallback = None;
+    let mut last_error = None;
+
+    for password in password_candidates(passwords) {
+        let mut attempt = text.clone();
+        match walk(password, &mut attempt) {
+            Ok(()) if attempt.read_failures() == text.read_failures() => {
+                *text = attempt;
+                return Ok(());
+            }
+            Ok(()) => {
+                fallback.get_or_insert(attempt);
+            }
+            Err(err) => last_error = Some(err),
+        }
+    }
+
+    match fallback {
+        Some(attempt) => {
+            *text = attempt;
+            Ok(())
+        }
+        None => Err(last_error.unwrap_or_else(|| KreuzbergError::parsing("Failed to read archive"))),
+    }
+}
+
+fn walk_7z_with_password(
+    bytes: &[u8],
+    password: &str,
+    limits: &ArchiveConfig,
+    text: &mut ArchiveTextContent,
+    prefix: &str,
+    depth: usize,
+) -> Result<()> {
+    let cursor = Cursor::new(bytes);
+    let mut archive = SevenZReader::new(cursor, bytes.len() as u64, password.into())
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read 7z archive: {}", e)))?;
+
+    archive
+        .for_each_entries(|entry, reader| {
+            let name = entry.name();
+
+            if entry.is_directory() || !is_wanted_entry(name, entry.size(), depth, limits) {
+                return Ok(true);
+            }
+
+            if !text.admit_entry(limits) {
+                return Ok(false);
+            }
+
+            text.process_entry(format!("{}{}", prefix, name), depth, entry.size(), reader, limits);
+            Ok(!text.truncated)
+        })
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read 7z entries: {}", e)))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::ArchiveMemberFilter;
+    use std::io::Write;
+    use tar::Builder as TarBuilder;
+    use zip::write::{FileOptions, ZipWriter};
+
+    #[test]
+    fn test_extract_zip_files_honors_limits() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            zip.add_directory("docs/", options).unwrap();
+            zip.start_file("docs/report.pdf", options).unwrap();
+            zip.write_all(b"%PDF-1.4").unwrap();
+            zip.start_file("big.txt", options).unwrap();
+            zip.write_all(&[b'a'; 64]).unwrap();
+            zip.start_file("notes.txt", options).unwrap();
+            zip.write_all(b"notes").unwrap();
+            zip.start_file("last.txt", options).unwrap();
+            zip.write_all(b"last").unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let limits = ArchiveConfig {
+            max_entry_size: 32,
+            max_entries: 3,
+            ..Default::default()
+        };
+        let files = extract_zip_files(&cursor.into_inner(), &limits).unwrap();
+
+        let paths: Vec<&str> = files.files.iter().map(|file| file.path.as_str()).collect();
+        assert_eq!(paths, vec!["docs/report.pdf", "notes.txt"]);
+        assert_eq!(files.files[0].data, b"%PDF-1.4");
+        assert_eq!(files.entries[1].status, ArchiveEntryStatus::Skipped);
+        assert!(files.truncated);
+    }
+
+    #[test]
+    fn test_extract_zip_metadata() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            zip.start_file("test.txt", options).unwrap();
+            zip.write_all(b"Hello, World!").unwrap();
+
+            zip.start_file("dir/file.md", options).unwrap();
+            zip.write_all(b"# Header").unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_zip_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.format, "ZIP");
+        assert_eq!(metadata.file_count, 2);
+        assert_eq!(metadata.file_list.len(), 2);
+        assert!(metadata.total_size > 0);
+    }
+
+    #[test]
+    fn test_extract_tar_metadata() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut tar = TarBuilder::new(&mut cursor);
+
+            let data1 = b"Hello, World!";
+            let mut header1 = tar::Header::new_gnu();
+            header1.set_path("test.txt").unwrap();
+            header1.set_size(data1.len() as u64);
+            header1.set_cksum();
+            tar.append(&header1, &data1[..]).unwrap();
+
+            let data2 = b"# Header";
+            let mut header2 = tar::Header::new_gnu();
+            header2.set_path("dir/file.md").unwrap();
+            header2.set_size(data2.len() as u64);
+            header2.set_cksum();
+            tar.append(&header2, &data2[..]).unwrap();
+
+            tar.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_tar_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.format, "TAR");
+        assert_eq!(metadata.file_count, 2);
+        assert_eq!(metadata.file_list.len(), 2);
+        assert!(metadata.total_size > 0);
+    }
+
+    #[test]
+    fn test_extract_zip_text_content() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            zip.start_file("test.txt", options).unwrap();
+            zip.write_all(b"Hello, World!").unwrap();
+
+            zip.start_file("readme.md", options).unwrap();
+            zip.write_all(b"# README").unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let contents = extract_zip_text_content(&bytes).unwrap();
+
+        assert_eq!(contents.len(), 2);
+        assert_eq!(contents.get("test.txt").unwrap(), "Hello, World!");
+        assert_eq!(contents.get("readme.md").unwrap(), "# README");
+    }
+
+    #[test]
+    fn test_extract_tar_text_content() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut tar = TarBuilder::new(&mut cursor);
+
+            let data1 = b"Hello, World!";
+            let mut header1 = tar::Header::new_gnu();
+            header1.set_path("test.txt").unwrap();
+            header1.set_size(data1.len() as u64);
+            header1.set_cksum();
+            tar.append(&header1, &data1[..]).unwrap();
+
+            let data2 = b"# README";
+            let mut header2 = tar::Header::new_gnu();
+            header2.set_path("readme.md").unwrap();
+            header2.set_size(data2.len() as u64);
+            header2.set_cksum();
+            tar.append(&header2, &data2[..]).unwrap();
+
+            tar.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let contents = extract_tar_text_content(&bytes).unwrap();
+
+        assert_eq!(contents.len(), 2);
+        assert_eq!(contents.get("test.txt").unwrap(), "Hello, World!");
+        assert_eq!(contents.get("readme.md").unwrap(), "# README");
+    }
+
+    #[test]
+    fn test_extract_zip_metadata_invalid() {
+        let invalid_bytes = vec![0, 1, 2, 3, 4, 5];
+        let result = extract_zip_metadata(&invalid_bytes);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_extract_tar_metadata_invalid() {
+        let invalid_bytes = vec![0, 1, 2, 3, 4, 5];
+        let result = extract_tar_metadata(&invalid_bytes);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_extract_zip_metadata_with_directories() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            zip.add_directory("dir1/", options).unwrap();
+            zip.add_directory("dir1/subdir/", options).unwrap();
+
+            zip.start_file("dir1/file1.txt", options).unwrap();
+            zip.write_all(b"content1").unwrap();
+
+            zip.start_file("dir1/subdir/file2.txt", options).unwrap();
+            zip.write_all(b"content2").unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_zip_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.format, "ZIP");
+        assert_eq!(metadata.file_count, 4);
+        assert_eq!(metadata.total_size, 16);
+
+        let dir_count = metadata.file_list.iter().filter(|e| e.is_dir).count();
+        assert_eq!(dir_count, 2);
+    }
+
+    #[test]
+    fn test_extract_tar_metadata_with_directories() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut tar = TarBuilder::new(&mut cursor);
+
+            let mut header_dir = tar::Header::new_gnu();
+            header_dir.set_path("dir1/").unwrap();
+            header_dir.set_size(0);
+            header_dir.set_entry_type(tar::EntryType::Directory);
+            header_dir.set_cksum();
+            tar.append(&header_dir, &[][..]).unwrap();
+
+            let data = b"content1";
+            let mut header1 = tar::Header::new_gnu();
+            header1.set_path("dir1/file1.txt").unwrap();
+            header1.set_size(data.len() as u64);
+            header1.set_cksum();
+            tar.append(&header1, &data[..]).unwrap();
+
+            tar.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_tar_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.format, "TAR");
+        assert_eq!(metadata.file_count, 2);
+
+        let dir_count = metadata.file_list.iter().filter(|e| e.is_dir).count();
+        assert_eq!(dir_count, 1);
+    }
+
+    #[test]
+    fn test_extract_tar_gz_metadata() {
+        let mut tar_data = Vec::new();
+        {
+            let mut tar = TarBuilder::new(&mut tar_data);
+
+            let data = b"Hello from gzip!";
+            let mut header = tar::Header::new_gnu();
+            header.set_path("test.txt").unwrap();
+            header.set_size(data.len() as u64);
+            header.set_cksum();
+            tar.append(&header, &data[..]).unwrap();
+
+            tar.finish().unwrap();
+        }
+
+        let metadata = extract_tar_metadata(&tar_data).unwrap();
+        assert_eq!(metadata.format, "TAR");
+        assert_eq!(metadata.file_count, 1);
+        assert_eq!(metadata.file_list[0].path, "test.txt");
+    }
+
+    #[test]
+    fn test_extract_7z_metadata_with_files() {
+        use sevenz_rust::SevenZWriter;
+
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut sz = SevenZWriter::new(&mut cursor).unwrap();
+
+            sz.push_archive_entry(
+                sevenz_rust::SevenZArchiveEntry::from_path("test.txt", "test.txt".to_string()),
+                Some(Cursor::new(b"Hello 7z!".to_vec())),
+            )
+            .unwrap();
+
+            sz.push_archive_entry(
+                sevenz_rust::SevenZArchiveEntry::from_path("data.json", "data.json".to_string()),
+                Some(Cursor::new(b"{\"key\":\"value\"}".to_vec())),
+            )
+            .unwrap();
+
+            sz.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_7z_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.format, "7Z");
+        assert_eq!(metadata.file_count, 2);
+        assert!(metadata.total_size > 0);
+    }
+
+    #[test]
+    fn test_extract_zip_within_zip() {
+        let mut inner_cursor = Cursor::new(Vec::new());
+        {
+            let mut inner_zip = ZipWriter::new(&mut inner_cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            inner_zip.start_file("inner.txt", options).unwrap();
+            inner_zip.write_all(b"Nested content").unwrap();
+
+            inner_zip.finish().unwrap();
+        }
+        let inner_bytes = inner_cursor.into_inner();
+
+        let mut outer_cursor = Cursor::new(Vec::new());
+        {
+            let mut outer_zip = ZipWriter::new(&mut outer_cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            outer_zip.start_file("archive.zip", options).unwrap();
+            outer_zip.write_all(&inner_bytes).unwrap();
+
+            outer_zip.start_file("readme.txt", options).unwrap();
+            outer_zip.write_all(b"Outer content").unwrap();
+
+            outer_zip.finish().unwrap();
+        }
+
+        let outer_bytes = outer_cursor.into_inner();
+        let metadata = extract_zip_metadata(&outer_bytes).unwrap();
+
+        assert_eq!(metadata.file_count, 2);
+
+        let archive_entry = metadata.file_list.iter().find(|e| e.path == "archive.zip");
+        assert!(archive_entry.is_some());
+        assert!(archive_entry.unwrap().size > 0);
+    }
+
+    #[test]
+    fn test_extract_tar_within_tar() {
+        let mut inner_cursor = Cursor::new(Vec::new());
+        {
+            let mut inner_tar = TarBuilder::new(&mut inner_cursor);
+
+            let data = b"Nested content";
+            let mut header = tar::Header::new_gnu();
+            header.set_path("inner.txt").unwrap();
+            header.set_size(data.len() as u64);
+            header.set_cksum();
+            inner_tar.append(&header, &data[..]).unwrap();
+
+            inner_tar.finish().unwrap();
+        }
+        let inner_bytes = inner_cursor.into_inner();
+
+        let mut outer_cursor = Cursor::new(Vec::new());
+        {
+            let mut outer_tar = TarBuilder::new(&mut outer_cursor);
+
+            let mut header1 = tar::Header::new_gnu();
+            header1.set_path("archive.tar").unwrap();
+            header1.set_size(inner_bytes.len() as u64);
+            header1.set_cksum();
+            outer_tar.append(&header1, &inner_bytes[..]).unwrap();
+
+            let data = b"Outer content";
+            let mut header2 = tar::Header::new_gnu();
+            header2.set_path("readme.txt").unwrap();
+            header2.set_size(data.len() as u64);
+            header2.set_cksum();
+            outer_tar.append(&header2, &data[..]).unwrap();
+
+            outer_tar.finish().unwrap();
+        }
+
+        let outer_bytes = outer_cursor.into_inner();
+        let metadata = extract_tar_metadata(&outer_bytes).unwrap();
+
+        assert_eq!(metadata.file_count, 2);
+
+        let archive_entry = metadata.file_list.iter().find(|e| e.path == "archive.tar");
+        assert!(archive_entry.is_some());
+    }
+
+    #[test]
+    fn test_extract_zip_corrupted_data() {
+        let mut valid_cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut valid_cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            zip.start_file("test.txt", options).unwrap();
+            zip.write_all(b"content").unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let mut corrupted = valid_cursor.into_inner();
+        corrupted.truncate(corrupted.len() / 2);
+
+        let result = extract_zip_metadata(&corrupted);
+        assert!(result.is_err());
+
+        if let Err(e) = result {
+            assert!(matches!(e, KreuzbergError::Parsing { .. }));
+        }
+    }
+
+    #[test]
+    fn test_extract_tar_corrupted_data() {
+        let mut valid_cursor = Cursor::new(Vec::new());
+        {
+            let mut tar = TarBuilder::new(&mut valid_cursor);
+
+            let data = b"content";
+            let mut header = tar::Header::new_gnu();
+            header.set_path("test.txt").unwrap();
+            header.set_size(data.len() as u64);
+            header.set_cksum();
+            tar.append(&header, &data[..]).unwrap();
+
+            tar.finish().unwrap();
+        }
+
+        let mut corrupted = valid_cursor.into_inner();
+        corrupted[100] = 0xFF;
+
+        let result = extract_tar_metadata(&corrupted);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_extract_zip_empty_archive() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let zip = ZipWriter::new(&mut cursor);
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_zip_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.format, "ZIP");
+        assert_eq!(metadata.file_count, 0);
+        assert_eq!(metadata.total_size, 0);
+        assert_eq!(metadata.file_list.len(), 0);
+    }
+
+    #[test]
+    fn test_extract_tar_empty_archive() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut tar = TarBuilder::new(&mut cursor);
+            tar.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_tar_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.format, "TAR");
+        assert_eq!(metadata.file_count, 0);
+        assert_eq!(metadata.total_size, 0);
+        assert_eq!(metadata.file_list.len(), 0);
+    }
+
+    #[test]
+    fn test_extract_zip_multiple_text_files() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            zip.start_file("file1.txt", options).unwrap();
+            zip.write_all(b"Content 1").unwrap();
+
+            zip.start_file("file2.md", options).unwrap();
+            zip.write_all(b"# Markdown").unwrap();
+
+            zip.start_file("data.json", options).unwrap();
+            zip.write_all(b"{\"key\":\"value\"}").unwrap();
+
+            zip.start_file("binary.bin", options).unwrap();
+            zip.write_all(&[0xFF, 0xFE, 0xFD]).unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let contents = extract_zip_text_content(&bytes).unwrap();
+
+        assert_eq!(contents.len(), 3);
+        assert_eq!(contents.get("file1.txt").unwrap(), "Content 1");
+        assert_eq!(contents.get("file2.md").unwrap(), "# Markdown");
+        assert_eq!(contents.get("data.json").unwrap(), "{\"key\":\"value\"}");
+        assert!(!contents.contains_key("binary.bin"));
+    }
+
+    #[test]
+    fn test_extract_tar_multiple_text_files() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut tar = TarBuilder::new(&mut cursor);
+
+            let files = vec![
+                ("file1.txt", b"Content 1" as &[u8]),
+                ("file2.md", b"# Markdown"),
+                ("data.xml", b"<root>data</root>"),
+                ("config.yaml", b"key: value"),
+            ];
+
+            for (path, data) in files {
+                let mut header = tar::Header::new_gnu();
+                header.set_path(path).unwrap();
+                header.set_size(data.len() as u64);
+                header.set_cksum();
+                tar.append(&header, data).unwrap();
+            }
+
+            tar.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let contents = extract_tar_text_content(&bytes).unwrap();
+
+        assert_eq!(contents.len(), 4);
+        assert_eq!(contents.get("file1.txt").unwrap(), "Content 1");
+        assert_eq!(contents.get("file2.md").unwrap(), "# Markdown");
+        assert_eq!(contents.get("data.xml").unwrap(), "<root>data</root>");
+        assert_eq!(contents.get("config.yaml").unwrap(), "key: value");
+    }
+
+    #[test]
+    fn test_extract_zip_preserves_directory_structure() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            zip.add_directory("root/", options).unwrap();
+            zip.add_directory("root/sub1/", options).unwrap();
+            zip.add_directory("root/sub2/", options).unwrap();
+
+            zip.start_file("root/file1.txt", options).unwrap();
+            zip.write_all(b"Root file").unwrap();
+
+            zip.start_file("root/sub1/file2.txt", options).unwrap();
+            zip.write_all(b"Sub1 file").unwrap();
+
+            zip.start_file("root/sub2/file3.txt", options).unwrap();
+            zip.write_all(b"Sub2 file").unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_zip_metadata(&bytes).unwrap();
+
+        let paths: Vec<&str> = metadata.file_list.iter().map(|e| e.path.as_str()).collect();
+        assert!(paths.contains(&"root/"));
+        assert!(paths.contains(&"root/sub1/"));
+        assert!(paths.contains(&"root/sub2/"));
+        assert!(paths.contains(&"root/file1.txt"));
+        assert!(paths.contains(&"root/sub1/file2.txt"));
+        assert!(paths.contains(&"root/sub2/file3.txt"));
+    }
+
+    #[test]
+    fn test_extract_zip_with_large_file() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            let large_content = "x".repeat(10_000);
+
+            zip.start_file("large.txt", options).unwrap();
+            zip.write_all(large_content.as_bytes()).unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_zip_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.file_count, 1);
+        assert_eq!(metadata.total_size, 10_000);
+
+        let contents = extract_zip_text_content(&bytes).unwrap();
+        assert_eq!(contents.get("large.txt").unwrap().len(), 10_000);
+    }
+
+    #[test]
+    fn test_extract_zip_with_many_files() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            for i in 0..100 {
+                let filename = format!("file_{}.txt", i);
+                let content = format!("Content {}", i);
+
+                zip.start_file(&filename, options).unwrap();
+                zip.write_all(content.as_bytes()).unwrap();
+            }
+
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_zip_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.file_count, 100);
+        assert_eq!(metadata.file_list.len(), 100);
+
+        let contents = extract_zip_text_content(&bytes).unwrap();
+        assert_eq!(contents.len(), 100);
+    }
+
+    #[test]
+    fn test_extract_zip_with_long_paths() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            let long_path = format!("{}/file.txt", "a".repeat(200));
+
+            zip.start_file(&long_path, options).unwrap();
+            zip.write_all(b"Deep file").unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_zip_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.file_count, 1);
+        assert!(metadata.file_list[0].path.len() > 200);
+
+        let contents = extract_zip_text_content(&bytes).unwrap();
+        assert_eq!(contents.len(), 1);
+    }
+
+    #[test]
+    fn test_extract_7z_text_content() {
+        use sevenz_rust::SevenZWriter;
+
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut sz = SevenZWriter::new(&mut cursor).unwrap();
+
+            sz.push_archive_entry(
+                sevenz_rust::SevenZArchiveEntry::from_path("test.txt", "test.txt".to_string()),
+                Some(Cursor::new(b"Hello 7z text!".to_vec())),
+            )
+            .unwrap();
+
+            sz.push_archive_entry(
+                sevenz_rust::SevenZArchiveEntry::from_path("readme.md", "readme.md".to_string()),
+                Some(Cursor::new(b"# 7z README".to_vec())),
+            )
+            .unwrap();
+
+            sz.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let contents = extract_7z_text_content(&bytes).unwrap();
+
+        assert_eq!(contents.len(), 2);
+        assert_eq!(contents.get("test.txt").unwrap(), "Hello 7z text!");
+        assert_eq!(contents.get("readme.md").unwrap(), "# 7z README");
+    }
+
+    #[test]
+    fn test_extract_7z_files_with_passwords_for_unencrypted_archive() {
+        use sevenz_rust::SevenZWriter;
+
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut sz = SevenZWriter::new(&mut cursor).unwrap();
+            sz.push_archive_entry(
+                sevenz_rust::SevenZArchiveEntry::from_path("test.txt", "test.txt".to_string()),
+                Some(Cursor::new(b"Hello 7z text!".to_vec())),
+            )
+            .unwrap();
+            sz.finish().unwrap();
+        }
+        let bytes = cursor.into_inner();
+
+        let passwords = vec!["wrong".to_string()];
+        assert_eq!(find_7z_password(&bytes, &passwords, u64::MAX), "");
+
+        let limits = ArchiveConfig {
+            passwords: Some(passwords),
+            ..Default::default()
+        };
+        let files = extract_7z_files(&bytes, &limits).unwrap();
+        assert_eq!(files.files[0].data, b"Hello 7z text!");
+    }
+
+    #[test]
+    fn test_extract_7z_empty_archive() {
+        use sevenz_rust::SevenZWriter;
+
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let sz = SevenZWriter::new(&mut cursor).unwrap();
+            sz.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_7z_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.format, "7Z");
+        assert_eq!(metadata.file_count, 0);
+        assert_eq!(metadata.total_size, 0);
+    }
+
+    #[test]
+    fn test_extract_tar_with_large_file() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut tar = TarBuilder::new(&mut cursor);
+
+            let large_content = "y".repeat(50_000);
+
+            let mut header = tar::Header::new_gnu();
+            header.set_path("large.txt").unwrap();
+            header.set_size(large_content.len() as u64);
+            header.set_cksum();
+            tar.append(&header, large_content.as_bytes()).unwrap();
+
+            tar.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let metadata = extract_tar_metadata(&bytes).unwrap();
+
+        assert_eq!(metadata.file_count, 1);
+        assert_eq!(metadata.total_size, 50_000);
+
+        let contents = extract_tar_text_content(&bytes).unwrap();
+        assert_eq!(contents.get("large.txt").unwrap().len(), 50_000);
+    }
+
+    #[test]
+    fn test_extract_zip_text_content_filters_non_text_extensions() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            zip.start_file("document.txt", options).unwrap();
+            zip.write_all(b"Text file").unwrap();
+
+            zip.start_file("image.png", options).unwrap();
+            zip.write_all(&[0x89, 0x50, 0x4E, 0x47]).unwrap();
+
+            zip.start_file("binary.exe", options).unwrap();
+            zip.write_all(&[0x4D, 0x5A]).unwrap();
+
+            zip.start_file("config.toml", options).unwrap();
+            zip.write_all(b"[section]").unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let contents = extract_zip_text_content(&bytes).unwrap();
+
+        assert_eq!(contents.len(), 2);
+        assert!(contents.contains_key("document.txt"));
+        assert!(contents.contains_key("config.toml"));
+        assert!(!contents.contains_key("image.png"));
+        assert!(!contents.contains_key("binary.exe"));
+    }
+
+    #[test]
+    fn test_extract_zip_text_content_skips_oversized_entries() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            zip.start_file("small.txt", options).unwrap();
+            zip.write_all(b"small").unwrap();
+
+            zip.start_file("large.txt", options).unwrap();
+            zip.write_all(&vec![b'a'; 1024]).unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let limits = ArchiveConfig {
+            max_entry_size: 100,
+            ..Default::default()
+        };
+        let text = extract_zip_text_content_with_limits(&bytes, &limits).unwrap();
+
+        assert_eq!(text.contents.get("small.txt").map(String::as_str), Some("small"));
+        assert!(!text.contents.contains_key("large.txt"));
+        assert_eq!(text.skipped_entries, vec!["large.txt".to_string()]);
+        assert!(!text.truncated);
+    }
+
+    #[test]
+    fn test_extract_tar_text_content_stops_at_total_budget() {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut tar = TarBuilder::new(&mut cursor);
+
+            for name in ["a.txt", "b.txt", "c.txt"] {
+                let data = [b'x'; 40];
+                let mut header = tar::Header::new_gnu();
+                header.set_path(name).unwrap();
+                header.set_size(data.len() as u64);
+                header.set_cksum();
+                tar.append(&header, &data[..]).unwrap();
+            }
+
+            tar.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let limits = ArchiveConfig {
+            max_entry_size: 100,
+            max_total_size: 100,
+            ..Default::default()
+        };
+        let text = extract_tar_text_content_with_limits(&bytes, &limits).unwrap();
+
+        assert_eq!(text.contents.len(), 2);
+        assert!(text.truncated);
+        assert!(text.skipped_entries.is_empty());
+        assert!(text.decompressed_bytes <= limits.max_total_size);
+    }
+
+    fn zip_bytes(files: &[(&str, &[u8])], password: Option<&[u8]>) -> Vec<u8> {
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let mut options = FileOptions::<'_, ()>::default();
+            if let Some(password) = password {
+                options = options.with_deprecated_encryption(password);
+            }
+
+            for (name, data) in files {
+                zip.start_file(*name, options).unwrap();
+                zip.write_all(data).unwrap();
+            }
+
+            zip.finish().unwrap();
+        }
+        cursor.into_inner()
+    }
+
+    #[test]
+    fn test_extract_zip_text_content_expands_nested_archives() {
+        let innermost = zip_bytes(&[("deep.txt", b"deep")], None);
+        let inner = zip_bytes(&[("readme.txt", b"inner"), ("innermost.zip", &innermost)], None);
+        let bytes = zip_bytes(&[("top.txt", b"top"), ("nested/inner.zip", &inner)], None);
+
+        let limits = ArchiveConfig {
+            max_depth: 1,
+            ..Default::default()
+        };
+        let text = extract_zip_text_content_with_limits(&bytes, &limits).unwrap();
+
+        assert_eq!(text.contents.get("top.txt").map(String::as_str), Some("top"));
+        assert_eq!(
+            text.contents.get("nested/inner.zip/readme.txt").map(String::as_str),
+            Some("inner")
+        );
+        assert!(!text.contents.keys().any(|path| path.ends_with("deep.txt")));
+
+        let nested = text
+            .entries
+            .iter()
+            .find(|entry| entry.path == "nested/inner.zip")
+            .unwrap();
+        assert_eq!(nested.status, ArchiveEntryStatus::Expanded);
+        assert_eq!(nested.depth, 0);
+        let member = text
+            .entries
+            .iter()
+            .find(|entry| entry.path == "nested/inner.zip/readme.txt")
+            .unwrap();
+        assert_eq!(member.depth, 1);
+
+        let flat = ArchiveConfig {
+            max_depth: 0,
+            ..Default::default()
+        };
+        let text = extract_zip_text_content_with_limits(&bytes, &flat).unwrap();
+        assert_eq!(text.contents.len(), 1);
+    }
+
+    fn tar_bytes(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut tar = TarBuilder::new(Vec::new());
+        for (name, data) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_path(name).unwrap();
+            header.set_size(data.len() as u64);
+            header.set_cksum();
+            tar.append(&header, *data).unwrap();
+        }
+        tar.into_inner().unwrap()
+    }
+
+    fn select(members: ArchiveMemberFilter) -> ArchiveConfig {
+        ArchiveConfig {
+            members: Some(members),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_list_archive() {
+        let dir = tempfile::tempdir().unwrap();
+        let zip_path = dir.path().join("bundle.zip");
+        let zip = zip_bytes(&[("a.txt", b"alpha"), ("docs/b.pdf", b"%PDF")], None);
+        std::fs::write(&zip_path, zip).unwrap();
+
+        let entries = list_archive(&zip_path).unwrap();
+        let paths: Vec<&str> = entries.iter().map(|entry| entry.path.as_str()).collect();
+        assert_eq!(paths, vec!["a.txt", "docs/b.pdf"]);
+        assert_eq!(entries[0].size, 5);
+
+        // Without an extension, the format is detected from the content
+        let tar_path = dir.path().join("bundle");
+        std::fs::write(&tar_path, tar_bytes(&[("c.txt", b"gamma")])).unwrap();
+        assert_eq!(list_archive(&tar_path).unwrap()[0].path, "c.txt");
+
+        let text_path = dir.path().join("notes.txt");
+        std::fs::write(&text_path, "not an archive").unwrap();
+        assert!(matches!(
+            list_archive(&text_path),
+            Err(KreuzbergError::UnsupportedFormat(_))
+        ));
+    }
+
+    #[test]
+    fn test_extract_files_selects_members() {
+        let members: [(&str, &[u8]); 4] = [
+            ("report.pdf", b"%PDF-1.7 report"),
+            ("notes.txt", b"notes"),
+            ("drafts/old.pdf", b"%PDF-1.7 old"),
+            ("scan.pdf", b"%PDF-1.7 a scan that is too large"),
+        ];
+        let limits = select(ArchiveMemberFilter {
+            exclude: vec!["drafts/**".to_string()],
+            mime_types: vec!["application/pdf".to_string()],
+            max_size: Some(16),
+            ..Default::default()
+        });
+
+        let from_zip = extract_zip_files(&zip_bytes(&members, None), &limits).unwrap();
+        let from_tar = extract_tar_files(&tar_bytes(&members), &limits).unwrap();
+
+        for files in [from_zip, from_tar] {
+            let paths: Vec<&str> = files.files.iter().map(|file| file.path.as_str()).collect();
+            assert_eq!(paths, vec!["report.pdf"]);
+            assert_eq!(files.entries.len(), 1);
+        }
+    }
+
+    #[test]
+    fn test_extract_text_content_selects_members() {
+        let bytes = zip_bytes(&[("docs/a.md", b"a"), ("logs/b.log", b"b"), ("docs/c.txt", b"c")], None);
+        let limits = select(ArchiveMemberFilter {
+            include: vec!["docs/**".to_string()],
+            mime_types: vec!["text/*".to_string()],
+            ..Default::default()
+        });
+
+        let text = extract_zip_text_content_with_limits(&bytes, &limits).unwrap();
+
+        let mut paths: Vec<&str> = text.contents.keys().map(String::as_str).collect();
+        paths.sort();
+        assert_eq!(paths, vec!["docs/a.md", "docs/c.txt"]);
+        assert_eq!(text.entry_count, 2);
+    }
+
+    #[test]
+    fn test_extract_zip_text_content_stops_at_entry_limit() {
+        let bytes = zip_bytes(&[("a.txt", b"a"), ("b.txt", b"b"), ("c.txt", b"c")], None);
+
+        let limits = ArchiveConfig {
+            max_entries: 2,
+            ..Default::default()
+        };
+        let text = extract_zip_text_content_with_limits(&bytes, &limits).unwrap();
+
+        assert_eq!(text.contents.len(), 2);
+        assert_eq!(text.entry_count, 2);
+        assert!(text.truncated);
+    }
+
+    #[test]
+    fn test_extract_zip_text_content_with_passwords() {
+        let bytes = zip_bytes(&[("secret.txt", b"classified")], Some(b"hunter2"));
+
+        let text = extract_zip_text_content_with_limits(&bytes, &ArchiveConfig::default()).unwrap();
+        assert!(text.contents.is_empty());
+        assert_eq!(text.entries[0].status, ArchiveEntryStatus::Encrypted);
+
+        let limits = ArchiveConfig {
+            passwords: Some(vec!["hunter2".to_string()]),
+            ..Default::default()
+        };
+        let text = extract_zip_text_content_with_limits(&bytes, &limits).unwrap();
+        assert_eq!(text.contents.get("secret.txt").map(String::as_str), Some("classified"));
+        assert_eq!(text.entries[0].status, ArchiveEntryStatus::Extracted);
+    }
+
+    #[test]
+    fn test_zip_password_is_confirmed_by_crc() {
+        let bytes = zip_bytes(&[("secret.txt", b"classified")], Some(b"hunter2"));
+
+        // ZipCrypto's check byte lets about one in 256 wrong passwords through
+        let mut passwords: Vec<String> = (0..1000).map(|i| format!("wrong-{}", i)).collect();
+        passwords.push("hunter2".to_string());
+        let limits = ArchiveConfig {
+            passwords: Some(passwords),
+            ..Default::default()
+        };
+
+        let files = extract_zip_files(&bytes, &limits).unwrap();
+        assert_eq!(files.files.len(), 1);
+        assert_eq!(files.files[0].data, b"classified");
+    }
+
+    #[test]
+    fn test_extract_7z_corrupted_data() {
+        let invalid_7z_data = vec![0x37, 0x7A, 0xBC, 0xAF, 0x27, 0x1C, 0x00];
+
+        let result = extract_7z_metadata(&invalid_7z_data);
+        assert!(result.is_err());
+
+        if let Err(e) = result {
+            assert!(matches!(e, KreuzbergError::Parsing { .. }));
+        }
+    }
+}
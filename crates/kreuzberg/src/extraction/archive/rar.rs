@@ -0,0 +1,153 @@
+//! RAR archive support.
+//!
+//! RAR archives are read with the `unrar` library, which only opens archives from disk, so the
+//! archive bytes are written to a scratch file first.
+
+use super::{
+    ArchiveEntry, ArchiveEntryStatus, ArchiveMetadata, ArchiveTextContent, is_wanted_entry, open_with_passwords,
+    walk_with_passwords,
+};
+use crate::core::config::ArchiveConfig;
+use crate::core::scratch::ScratchFile;
+use crate::error::{KreuzbergError, Result};
+use std::collections::HashMap;
+use std::path::Path;
+use unrar::Archive;
+
+fn open_archive<'a>(path: &'a Path, password: &'a str) -> Archive<'a> {
+    if password.is_empty() {
+        Archive::new(path)
+    } else {
+        Archive::with_password(path, password)
+    }
+}
+
+/// Extract metadata from a RAR archive.
+pub fn extract_rar_metadata(bytes: &[u8]) -> Result<ArchiveMetadata> {
+    extract_rar_metadata_with_passwords(bytes, &[])
+}
+
+/// Extract metadata from a RAR archive, trying each password for archives with encrypted headers.
+pub fn extract_rar_metadata_with_passwords(bytes: &[u8], passwords: &[String]) -> Result<ArchiveMetadata> {
+    let file = ScratchFile::write(bytes, ".rar")?;
+    open_with_passwords(passwords, |password| read_rar_metadata(file.path(), password))
+}
+
+pub(super) fn read_rar_metadata(path: &Path, password: &str) -> Result<ArchiveMetadata> {
+    let archive = open_archive(path, password)
+        .open_for_listing()
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read RAR archive: {}", e)))?;
+
+    let mut file_list = Vec::new();
+    let mut total_size = 0u64;
+
+    for header in archive {
+        let header = header.map_err(|e| KreuzbergError::parsing(format!("Failed to read RAR entry: {}", e)))?;
+
+        let path = header.filename.to_string_lossy().to_string();
+        let size = header.unpacked_size;
+        let is_dir = header.is_directory();
+
+        if !is_dir {
+            total_size += size;
+        }
+
+        file_list.push(ArchiveEntry { path, size, is_dir });
+    }
+
+    let file_count = file_list.len();
+
+    Ok(ArchiveMetadata {
+        format: "RAR".to_string(),
+        file_list,
+        file_count,
+        total_size,
+    })
+}
+
+/// Extract text content from files within a RAR archive.
+///
+/// Uses the default [`ArchiveConfig`] size limits; see [`extract_rar_text_content_with_limits`].
+pub fn extract_rar_text_content(bytes: &[u8]) -> Result<HashMap<String, String>> {
+    extract_rar_text_content_with_limits(bytes, &ArchiveConfig::default()).map(|text| text.contents)
+}
+
+/// Extract text content from files within a RAR archive under a decompressed-size budget.
+///
+/// See [`super::extract_zip_text_content_with_limits`] for how limits and nested archives are
+/// handled. Encrypted archives are opened with the first password that reads all members cleanly.
+pub fn extract_rar_text_content_with_limits(bytes: &[u8], limits: &ArchiveConfig) -> Result<ArchiveTextContent> {
+    let mut text = ArchiveTextContent::default();
+    walk_rar(bytes, limits, &mut text, "", 0)?;
+    Ok(text)
+}
+
+pub(super) fn walk_rar(
+    bytes: &[u8],
+    limits: &ArchiveConfig,
+    text: &mut ArchiveTextContent,
+    prefix: &str,
+    depth: usize,
+) -> Result<()> {
+    let file = ScratchFile::write(bytes, ".rar")?;
+    walk_with_passwords(limits, text, |password, text| {
+        walk_rar_with_password(file.path(), password, limits, text, prefix, depth)
+    })
+}
+
+fn walk_rar_with_password(
+    path: &Path,
+    password: &str,
+    limits: &ArchiveConfig,
+    text: &mut ArchiveTextContent,
+    prefix: &str,
+    depth: usize,
+) -> Result<()> {
+    let mut archive = open_archive(path, password)
+        .open_for_processing()
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read RAR archive: {}", e)))?;
+
+    while let Some(header) = archive
+        .read_header()
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read RAR entry: {}", e)))?
+    {
+        if text.truncated {
+            break;
+        }
+
+        let name = header.entry().filename.to_string_lossy().to_string();
+        let size = header.entry().unpacked_size;
+        let wanted = !header.entry().is_directory() && is_wanted_entry(&name, size, depth, limits);
+
+        if wanted && !text.admit_entry(limits) {
+            break;
+        }
+
+        let member_path = format!("{}{}", prefix, name);
+        if !wanted || size > limits.max_entry_size {
+            if wanted {
+                text.skip_entry(member_path, depth, size);
+            }
+            archive = header
+                .skip()
+                .map_err(|e| KreuzbergError::parsing(format!("Failed to skip RAR entry: {}", e)))?;
+            continue;
+        }
+
+        match header.read() {
+            Ok((data, rest)) => {
+                text.process_entry(member_path, depth, size, data.as_slice(), limits);
+                archive = rest;
+            }
+            Err(e) => {
+                // unrar consumes the archive on failure (e.g. a wrong password), so stop here.
+                tracing::debug!("Failed to read RAR entry '{}': {}", member_path, e);
+                text.read_failures += 1;
+                text.record(&member_path, depth, size, ArchiveEntryStatus::Failed);
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
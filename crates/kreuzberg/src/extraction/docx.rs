@@ -5,10 +5,35 @@
 //!
 //! Page break detection is best-effort, detecting only explicit page breaks (`<w:br w:type="page"/>`)
 //! in the document XML. This does not account for automatic pagination based on content reflowing.
+//!
+//! Tracked changes and comments are read separately by [`extract_revisions`], since docx-lite
+//! does not report them.
 
 use crate::error::{KreuzbergError, Result};
-use crate::types::PageBoundary;
-use std::io::Cursor;
+use crate::extraction::pptx::detect_image_format;
+use crate::types::{PageBoundary, Revision, RevisionKind, RevisionStatus};
+use roxmltree::{Document, Node};
+use std::collections::{HashMap, HashSet};
+use std::io::{Cursor, Read};
+
+const RELATIONSHIPS_NS: &str = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
+const WORDML_NS: &str = "http://schemas.openxmlformats.org/wordprocessingml/2006/main";
+const WORDML_2010_NS: &str = "http://schemas.microsoft.com/office/word/2010/wordml";
+const WORDML_2012_NS: &str = "http://schemas.microsoft.com/office/word/2012/wordml";
+
+/// Markers of tracked changes in `document.xml`, used to skip parsing documents without them.
+const TRACKED_CHANGE_MARKERS: [&[u8]; 4] = [b":ins ", b":del ", b":moveTo ", b":moveFrom "];
+
+/// An image drawn in the document body.
+#[derive(Debug, Clone)]
+pub struct BodyImage {
+    /// Raw image bytes from the package's media part
+    pub data: Vec<u8>,
+    /// Image format detected from the bytes (e.g. "png", "jpeg")
+    pub format: String,
+    /// Text of the paragraph holding the image, or of the closest non-empty paragraph before it
+    pub anchor: Option<String>,
+}
 
 /// Extract text from DOCX bytes using docx-lite.
 ///
@@ -87,6 +112,349 @@ pub fn detect_page_breaks_from_docx(bytes: &[u8]) -> Result<Option<Vec<PageBound
     }
 }
 
+/// Page boundaries for `text` from the explicit page breaks in `bytes`.
+///
+/// Used when the extracted text changed after extraction, e.g. by inserting the OCR text of
+/// embedded images, so boundaries stay on character boundaries of the final text.
+pub(crate) fn page_boundaries_for_text(bytes: &[u8], text: &str) -> Option<Vec<PageBoundary>> {
+    let page_breaks = detect_page_breaks(bytes).ok()?;
+    if page_breaks.is_empty() {
+        return None;
+    }
+
+    map_page_breaks_to_boundaries(text, page_breaks).ok()
+}
+
+/// Extract the images drawn in the document body (`<a:blip r:embed>`), in reading order.
+///
+/// Each image carries the text of its paragraph so callers can place content derived from the
+/// image next to it in the extracted text. Linked (external) images are not included.
+pub fn extract_body_images(bytes: &[u8]) -> Result<Vec<BodyImage>> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to open DOCX as ZIP: {}", e)))?;
+
+    let (Some(document_xml), Some(rels_xml)) = (
+        read_part(&mut archive, "word/document.xml")?,
+        read_part(&mut archive, "word/_rels/document.xml.rels")?,
+    ) else {
+        return Ok(Vec::new());
+    };
+
+    let targets = parse_image_relationships(&String::from_utf8_lossy(&rels_xml))?;
+    let document_xml = String::from_utf8_lossy(&document_xml);
+    let document = Document::parse(&document_xml)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to parse document.xml: {}", e)))?;
+
+    let mut images = Vec::new();
+    let mut anchor: Option<String> = None;
+
+    let paragraphs = document
+        .descendants()
+        .filter(|node| node.has_tag_name("p") && !node.ancestors().skip(1).any(|a| a.has_tag_name("p")));
+
+    for paragraph in paragraphs {
+        let text: String = paragraph
+            .descendants()
+            .filter(|node| node.has_tag_name("t"))
+            .filter_map(|node| node.text())
+            .collect();
+        if !text.trim().is_empty() {
+            anchor = Some(text.trim().to_string());
+        }
+
+        for blip in paragraph.descendants().filter(|node| node.has_tag_name("blip")) {
+            let Some(path) = blip
+                .attribute((RELATIONSHIPS_NS, "embed"))
+                .and_then(|id| targets.get(id))
+            else {
+                continue;
+            };
+
+            if let Some(data) = read_part(&mut archive, path)? {
+                images.push(BodyImage {
+                    format: detect_image_format(&data),
+                    data,
+                    anchor: anchor.clone(),
+                });
+            }
+        }
+    }
+
+    Ok(images)
+}
+
+/// Tracked changes and comments of a DOCX document.
+#[derive(Debug, Clone, Default)]
+pub struct DocxRevisions {
+    /// Revisions in document order; comments not referenced from the body come last
+    pub revisions: Vec<Revision>,
+    /// Body text with the revisions marked in CriticMarkup, when requested and revisions exist
+    pub inline_text: Option<String>,
+}
+
+/// Extract the tracked changes (insertions, deletions and moves) and comments of a DOCX document.
+///
+/// With `render_inline`, the body is also rendered as plain paragraphs with the revisions marked
+/// in CriticMarkup: `{++inserted++}`, `{--deleted--}` and `{>>author: comment<<}` after the
+/// commented text. This text has no headings, list markers or text box content, so it only
+/// replaces the regular text when the document has revisions. Documents without comments or
+/// tracked changes are not parsed.
+pub fn extract_revisions(bytes: &[u8], render_inline: bool) -> Result<DocxRevisions> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to open DOCX as ZIP: {}", e)))?;
+
+    let Some(document_xml) = read_part(&mut archive, "word/document.xml")? else {
+        return Ok(DocxRevisions::default());
+    };
+    let comments_xml = read_part(&mut archive, "word/comments.xml")?;
+    let has_tracked_changes = TRACKED_CHANGE_MARKERS
+        .iter()
+        .any(|marker| memchr::memmem::find(&document_xml, marker).is_some());
+    if comments_xml.is_none() && !has_tracked_changes {
+        return Ok(DocxRevisions::default());
+    }
+
+    let mut walker = RevisionWalker::default();
+    if let Some(comments_xml) = comments_xml {
+        let resolved = match read_part(&mut archive, "word/commentsExtended.xml")? {
+            Some(extended_xml) => parse_resolved_comments(&String::from_utf8_lossy(&extended_xml))?,
+            None => HashSet::new(),
+        };
+        for (id, comment) in parse_comments(&String::from_utf8_lossy(&comments_xml), &resolved)? {
+            walker.comment_index.insert(id, walker.comments.len());
+            walker.comments.push(Some(comment));
+        }
+    }
+
+    let document_xml = String::from_utf8_lossy(&document_xml);
+    let document = Document::parse(&document_xml)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to parse document.xml: {}", e)))?;
+    walker.walk(document.root_element());
+
+    let inline_text = if render_inline && !walker.revisions.is_empty() {
+        Some(walker.text.trim_end().to_string())
+    } else {
+        None
+    };
+    let mut revisions = walker.revisions;
+    revisions.extend(walker.comments.into_iter().flatten());
+
+    Ok(DocxRevisions { revisions, inline_text })
+}
+
+/// Walks `document.xml` in reading order, collecting revisions and the CriticMarkup text.
+#[derive(Default)]
+struct RevisionWalker {
+    /// Comments of `comments.xml`, taken once referenced from the body
+    comments: Vec<Option<Revision>>,
+    comment_index: HashMap<String, usize>,
+    /// Text of comment ranges that are still open, by comment id
+    open_anchors: Vec<(String, String)>,
+    revisions: Vec<Revision>,
+    text: String,
+}
+
+impl RevisionWalker {
+    fn walk(&mut self, node: Node) {
+        // Drawing content (text boxes, alternate content) lives outside the WordprocessingML namespace.
+        if node.tag_name().namespace() != Some(WORDML_NS) {
+            return;
+        }
+        match node.tag_name().name() {
+            // Properties record formatting changes, which have no text
+            name if name.ends_with("Pr") => {}
+            "ins" | "moveTo" => self.tracked_change(node, RevisionKind::Insertion),
+            "del" | "moveFrom" => self.tracked_change(node, RevisionKind::Deletion),
+            "t" => self.push_text(node.text().unwrap_or_default()),
+            "tab" => self.push_text("\t"),
+            "br" | "cr" => self.push_text("\n"),
+            "commentRangeStart" => {
+                if let Some(id) = node.attribute((WORDML_NS, "id")) {
+                    self.open_anchors.push((id.to_string(), String::new()));
+                }
+            }
+            "commentReference" => self.comment(node),
+            name => {
+                for child in node.children().filter(|child| child.is_element()) {
+                    self.walk(child);
+                }
+                if name == "p" {
+                    self.text.push('\n');
+                }
+            }
+        }
+    }
+
+    fn push_text(&mut self, text: &str) {
+        self.text.push_str(text);
+        for (_, anchor) in &mut self.open_anchors {
+            anchor.push_str(text);
+        }
+    }
+
+    fn tracked_change(&mut self, node: Node, kind: RevisionKind) {
+        let text: String = node
+            .descendants()
+            .filter(|n| n.has_tag_name((WORDML_NS, "t")) || n.has_tag_name((WORDML_NS, "delText")))
+            .filter_map(|n| n.text())
+            .collect();
+        if text.is_empty() {
+            return;
+        }
+
+        if kind == RevisionKind::Insertion {
+            self.text.push_str("{++");
+            self.push_text(&text);
+            self.text.push_str("++}");
+        } else {
+            self.text.push_str("{--");
+            self.text.push_str(&text);
+            self.text.push_str("--}");
+        }
+        self.revisions.push(Revision {
+            kind,
+            author: wordml_attribute(node, "author"),
+            date: wordml_attribute(node, "date"),
+            text,
+            anchor: None,
+            page_number: None,
+            status: RevisionStatus::Pending,
+        });
+    }
+
+    fn comment(&mut self, node: Node) {
+        let Some(id) = node.attribute((WORDML_NS, "id")) else {
+            return;
+        };
+        let anchor_index = self.open_anchors.iter().position(|(open_id, _)| open_id == id);
+        let anchor = anchor_index.map(|index| self.open_anchors.remove(index).1);
+        let Some(&index) = self.comment_index.get(id) else {
+            return;
+        };
+        let Some(mut comment) = self.comments[index].take() else {
+            return;
+        };
+
+        self.text.push_str("{>>");
+        if let Some(author) = &comment.author {
+            self.text.push_str(author);
+            self.text.push_str(": ");
+        }
+        self.text.push_str(&comment.text.replace('\n', " "));
+        self.text.push_str("<<}");
+
+        comment.anchor = anchor
+            .map(|anchor| anchor.trim().to_string())
+            .filter(|anchor| !anchor.is_empty());
+        self.revisions.push(comment);
+    }
+}
+
+fn wordml_attribute(node: Node, name: &str) -> Option<String> {
+    node.attribute((WORDML_NS, name)).map(str::to_string)
+}
+
+/// Comments of `comments.xml` with their ids, in file order.
+fn parse_comments(comments_xml: &str, resolved: &HashSet<String>) -> Result<Vec<(String, Revision)>> {
+    let doc = Document::parse(comments_xml)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to parse comments.xml: {}", e)))?;
+
+    let mut comments = Vec::new();
+    for node in doc.descendants().filter(|n| n.has_tag_name((WORDML_NS, "comment"))) {
+        let Some(id) = node.attribute((WORDML_NS, "id")) else {
+            continue;
+        };
+        let paragraphs: Vec<Node> = node.children().filter(|n| n.has_tag_name((WORDML_NS, "p"))).collect();
+        let text = paragraphs
+            .iter()
+            .map(|paragraph| {
+                paragraph
+                    .descendants()
+                    .filter(|n| n.has_tag_name((WORDML_NS, "t")))
+                    .filter_map(|n| n.text())
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        // commentsExtended.xml marks a comment as done through the id of its last paragraph.
+        let is_resolved = paragraphs
+            .iter()
+            .filter_map(|paragraph| paragraph.attribute((WORDML_2010_NS, "paraId")))
+            .any(|para_id| resolved.contains(para_id));
+
+        comments.push((
+            id.to_string(),
+            Revision {
+                kind: RevisionKind::Comment,
+                author: wordml_attribute(node, "author"),
+                date: wordml_attribute(node, "date"),
+                text: text.trim().to_string(),
+                anchor: None,
+                page_number: None,
+                status: if is_resolved {
+                    RevisionStatus::Resolved
+                } else {
+                    RevisionStatus::Pending
+                },
+            },
+        ));
+    }
+
+    Ok(comments)
+}
+
+/// Paragraph ids of the comments `commentsExtended.xml` marks as done.
+fn parse_resolved_comments(extended_xml: &str) -> Result<HashSet<String>> {
+    let doc = Document::parse(extended_xml)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to parse commentsExtended.xml: {}", e)))?;
+
+    Ok(doc
+        .descendants()
+        .filter(|n| n.has_tag_name((WORDML_2012_NS, "commentEx")))
+        .filter(|n| matches!(n.attribute((WORDML_2012_NS, "done")), Some("1" | "true")))
+        .filter_map(|n| n.attribute((WORDML_2012_NS, "paraId")))
+        .map(str::to_string)
+        .collect())
+}
+
+/// Read a package part, returning `None` when it does not exist.
+fn read_part<R: Read + std::io::Seek>(archive: &mut zip::ZipArchive<R>, name: &str) -> Result<Option<Vec<u8>>> {
+    let mut file = match archive.by_name(name) {
+        Ok(file) => file,
+        Err(_) => return Ok(None),
+    };
+
+    let mut data = Vec::new();
+    file.read_to_end(&mut data)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read {}: {}", name, e)))?;
+    Ok(Some(data))
+}
+
+/// Map image relationship ids of `document.xml` to their part names in the package.
+fn parse_image_relationships(rels_xml: &str) -> Result<HashMap<String, String>> {
+    let doc = Document::parse(rels_xml)
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to parse document rels: {}", e)))?;
+
+    let mut targets = HashMap::new();
+
+    for node in doc.descendants() {
+        if node.has_tag_name("Relationship")
+            && let Some(rel_type) = node.attribute("Type")
+            && rel_type.ends_with("/image")
+            && node.attribute("TargetMode") != Some("External")
+            && let (Some(id), Some(target)) = (node.attribute("Id"), node.attribute("Target"))
+        {
+            let path = match target.strip_prefix('/') {
+                Some(absolute) => absolute.to_string(),
+                None => format!("word/{}", target),
+            };
+            targets.insert(id.to_string(), path);
+        }
+    }
+
+    Ok(targets)
+}
+
 /// Detect explicit page break positions in document.xml.
 ///
 /// Returns a vector of byte offsets within the document.xml content where page breaks occur.
@@ -375,6 +743,75 @@ mod tests {
         assert_eq!(reconstructed, text);
     }
 
+    fn create_docx(parts: &[(&str, &str)]) -> Vec<u8> {
+        use std::io::Write;
+        use zip::write::{SimpleFileOptions, ZipWriter};
+
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, content) in parts {
+            zip.start_file(*name, SimpleFileOptions::default()).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_extract_revisions() {
+        let document = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>
+<w:p>
+  <w:r><w:t xml:space="preserve">The fee is </w:t></w:r>
+  <w:del w:id="1" w:author="Ann" w:date="2024-05-01T09:00:00Z"><w:r><w:delText>100</w:delText></w:r></w:del>
+  <w:ins w:id="2" w:author="Ann" w:date="2024-05-01T09:00:00Z"><w:r><w:t>120</w:t></w:r></w:ins>
+  <w:r><w:t xml:space="preserve"> EUR.</w:t></w:r>
+</w:p>
+<w:p>
+  <w:commentRangeStart w:id="0"/><w:r><w:t>Payment within 30 days</w:t></w:r><w:commentRangeEnd w:id="0"/>
+  <w:r><w:commentReference w:id="0"/></w:r>
+</w:p>
+</w:body></w:document>"#;
+        let comments = r#"<w:comments xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main" xmlns:w14="http://schemas.microsoft.com/office/word/2010/wordml">
+<w:comment w:id="0" w:author="Ben" w:date="2024-05-02T10:00:00Z"><w:p w14:paraId="1A2B3C4D"><w:r><w:t>Too short?</w:t></w:r></w:p></w:comment>
+</w:comments>"#;
+        let extended = r#"<w15:commentsEx xmlns:w15="http://schemas.microsoft.com/office/word/2012/wordml">
+<w15:commentEx w15:paraId="1A2B3C4D" w15:done="1"/>
+</w15:commentsEx>"#;
+        let bytes = create_docx(&[
+            ("word/document.xml", document),
+            ("word/comments.xml", comments),
+            ("word/commentsExtended.xml", extended),
+        ]);
+
+        let result = extract_revisions(&bytes, true).unwrap();
+        let kinds: Vec<_> = result.revisions.iter().map(|r| (r.kind, r.text.as_str())).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                (RevisionKind::Deletion, "100"),
+                (RevisionKind::Insertion, "120"),
+                (RevisionKind::Comment, "Too short?"),
+            ]
+        );
+        assert_eq!(result.revisions[0].author.as_deref(), Some("Ann"));
+        assert_eq!(result.revisions[0].status, RevisionStatus::Pending);
+        assert_eq!(result.revisions[2].anchor.as_deref(), Some("Payment within 30 days"));
+        assert_eq!(result.revisions[2].status, RevisionStatus::Resolved);
+        assert_eq!(
+            result.inline_text.as_deref(),
+            Some("The fee is {--100--}{++120++} EUR.\nPayment within 30 days{>>Ben: Too short?<<}")
+        );
+
+        assert!(extract_revisions(&bytes, false).unwrap().inline_text.is_none());
+    }
+
+    #[test]
+    fn test_extract_revisions_without_revisions() {
+        let document = r#"<w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body><w:p><w:r><w:t>Plain</w:t></w:r></w:p></w:body></w:document>"#;
+        let bytes = create_docx(&[("word/document.xml", document)]);
+        let result = extract_revisions(&bytes, true).unwrap();
+        assert!(result.revisions.is_empty());
+        assert!(result.inline_text.is_none());
+    }
+
     #[test]
     fn test_detect_page_breaks_no_feature() {
         let result = detect_page_breaks(b"invalid");
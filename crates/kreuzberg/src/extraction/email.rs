@@ -8,7 +8,11 @@
 //! - **EML support**: RFC822 format parsing
 //! - **HTML to text**: Strips HTML tags from HTML email bodies
 //! - **Metadata extraction**: Sender, recipients, subject, message ID
+//! - **Threading**: In-Reply-To/References chains and a conversation `thread_id`
+//! - **Raw headers**: All RFC 5322 header fields, including DKIM signature presence
 //! - **Attachment list**: Names of all attachments (content not extracted)
+//! - **TNEF**: Attachments, body and meeting details of `winmail.dat` attachments
+//! - **Calendar invites**: Meetings from `text/calendar` parts and `.ics` attachments
 //!
 //! # Example
 //!
@@ -25,7 +29,9 @@
 //! # }
 //! ```
 use crate::error::{KreuzbergError, Result};
-use crate::types::{EmailAttachment, EmailExtractionResult};
+use crate::extraction::icalendar::{is_calendar, parse_icalendar};
+use crate::extraction::tnef::{self, TnefMessage};
+use crate::types::{CalendarEvent, EmailAttachment, EmailExtractionResult};
 use mail_parser::MimeHeaders;
 use regex::Regex;
 use std::collections::HashMap;
@@ -97,17 +103,22 @@ pub fn parse_eml_content(data: &[u8]) -> Result<EmailExtractionResult> {
 
     let message_id = message.message_id().map(|id| id.to_string());
 
-    let plain_text = message.body_text(0).map(|s| s.to_string());
+    let from_name = message
+        .from()
+        .and_then(|from| from.first())
+        .and_then(|addr| addr.name())
+        .map(|s| s.to_string());
 
-    let html_content = message.body_html(0).map(|s| s.to_string());
+    let reply_to_emails = reply_to_addresses(&message);
 
-    let cleaned_text = if let Some(plain) = &plain_text {
-        plain.clone()
-    } else if let Some(html) = &html_content {
-        clean_html_content(html)
-    } else {
-        String::new()
-    };
+    let raw_headers = parse_raw_headers(data);
+    let in_reply_to =
+        header_value(&raw_headers, "In-Reply-To").and_then(|value| parse_message_ids(value).into_iter().next());
+    let references = header_value(&raw_headers, "References")
+        .map(parse_message_ids)
+        .unwrap_or_default();
+    let thread_id = compute_thread_id(message_id.as_deref(), in_reply_to.as_deref(), &references);
+    let has_dkim_signature = header_value(&raw_headers, "DKIM-Signature").is_some();
 
     let mut attachments = Vec::new();
     for attachment in message.attachments() {
@@ -133,9 +144,20 @@ pub fn parse_eml_content(data: &[u8]) -> Result<EmailExtractionResult> {
             size: Some(size),
             is_image,
             data: Some(data.to_vec()),
+            content_id: attachment.content_id().map(|id| id.to_string()),
         });
     }
 
+    let mut unpacked = unpack_tnef_attachments(attachments);
+    let attachments = std::mem::take(&mut unpacked.attachments);
+    let mut calendar_events = calendar_parts(&message);
+    calendar_events.append(&mut unpacked.calendar_events);
+
+    let mut plain_text = message.body_text(0).map(|s| s.to_string());
+    let mut html_content = message.body_html(0).map(|s| s.to_string());
+    unpacked.fill_body(&mut plain_text, &mut html_content);
+    let cleaned_text = cleaned_body(&plain_text, &html_content);
+
     let metadata = build_metadata(
         &subject,
         &from_email,
@@ -155,10 +177,21 @@ pub fn parse_eml_content(data: &[u8]) -> Result<EmailExtractionResult> {
         bcc_emails,
         date,
         message_id,
+        from_name,
+        reply_to_emails,
+        in_reply_to,
+        references,
+        thread_id,
+        has_dkim_signature,
+        raw_headers,
         plain_text,
         html_content,
         cleaned_text,
         attachments,
+        calendar_events,
+        quoted_text: None,
+        signature: None,
+        tracking_pixels: Vec::new(),
         metadata,
     })
 }
@@ -203,15 +236,6 @@ pub fn parse_msg_content(data: &[u8]) -> Result<EmailExtractionResult> {
         None
     };
 
-    let plain_text = if !outlook.body.is_empty() {
-        Some(outlook.body.clone())
-    } else {
-        None
-    };
-
-    let html_content = None;
-    let cleaned_text = plain_text.clone().unwrap_or_default();
-
     let attachments: Vec<EmailAttachment> = outlook
         .attachments
         .iter()
@@ -246,16 +270,45 @@ pub fn parse_msg_content(data: &[u8]) -> Result<EmailExtractionResult> {
                 size,
                 is_image,
                 data,
+                content_id: None,
             }
         })
         .collect();
 
+    let mut calendar_events = calendar_events_from_attachments(&attachments);
+    let mut unpacked = unpack_tnef_attachments(attachments);
+    let attachments = std::mem::take(&mut unpacked.attachments);
+    calendar_events.append(&mut unpacked.calendar_events);
+
+    let mut plain_text = if !outlook.body.is_empty() {
+        Some(outlook.body.clone())
+    } else {
+        None
+    };
+    let mut html_content = None;
+    unpacked.fill_body(&mut plain_text, &mut html_content);
+    let cleaned_text = cleaned_body(&plain_text, &html_content);
+
     let from_name = if !outlook.sender.name.is_empty() {
         Some(outlook.sender.name.clone())
     } else {
         None
     };
 
+    let transport_headers = msg_transport_headers(data).unwrap_or_default();
+    let raw_headers = parse_raw_headers(transport_headers.as_bytes());
+    let reply_to_emails = mail_parser::MessageParser::default()
+        .parse_headers(transport_headers.as_bytes())
+        .map(|headers| reply_to_addresses(&headers))
+        .unwrap_or_default();
+    let in_reply_to =
+        header_value(&raw_headers, "In-Reply-To").and_then(|value| parse_message_ids(value).into_iter().next());
+    let references = header_value(&raw_headers, "References")
+        .map(parse_message_ids)
+        .unwrap_or_default();
+    let thread_id = compute_thread_id(message_id.as_deref(), in_reply_to.as_deref(), &references);
+    let has_dkim_signature = header_value(&raw_headers, "DKIM-Signature").is_some();
+
     let mut metadata = HashMap::new();
     if let Some(ref subj) = subject {
         metadata.insert("subject".to_string(), subj.to_string());
@@ -298,10 +351,21 @@ pub fn parse_msg_content(data: &[u8]) -> Result<EmailExtractionResult> {
         bcc_emails,
         date,
         message_id,
+        from_name,
+        reply_to_emails,
+        in_reply_to,
+        references,
+        thread_id,
+        has_dkim_signature,
+        raw_headers,
         plain_text,
         html_content,
         cleaned_text,
         attachments,
+        calendar_events,
+        quoted_text: None,
+        signature: None,
+        tracking_pixels: Vec::new(),
         metadata,
     })
 }
@@ -352,6 +416,11 @@ pub fn build_email_text_output(result: &EmailExtractionResult) -> String {
 
     text_parts.push(result.cleaned_text.clone());
 
+    let include_description = result.cleaned_text.trim().is_empty();
+    for event in &result.calendar_events {
+        text_parts.push(format_calendar_event(event, include_description));
+    }
+
     if !result.attachments.is_empty() {
         let attachment_names: Vec<String> = result
             .attachments
@@ -367,7 +436,137 @@ pub fn build_email_text_output(result: &EmailExtractionResult) -> String {
     text_parts.join("\n")
 }
 
-fn clean_html_content(html: &str) -> String {
+/// Render a calendar invite as text; the description is included only when the message has no
+/// body of its own, as it usually repeats the body.
+fn format_calendar_event(event: &CalendarEvent, include_description: bool) -> String {
+    let summary = event.summary.as_deref().unwrap_or("(untitled)");
+    let mut lines = vec![format!("Meeting: {}", summary)];
+
+    match (&event.start, &event.end) {
+        (Some(start), Some(end)) => lines.push(format!("When: {} - {}", start, end)),
+        (Some(start), None) => lines.push(format!("When: {}", start)),
+        _ => {}
+    }
+    if let Some(ref location) = event.location {
+        lines.push(format!("Location: {}", location));
+    }
+    if let Some(ref organizer) = event.organizer {
+        lines.push(format!("Organizer: {}", organizer));
+    }
+    if !event.attendees.is_empty() {
+        lines.push(format!("Attendees: {}", event.attendees.join(", ")));
+    }
+    if include_description && let Some(ref description) = event.description {
+        lines.push(description.clone());
+    }
+
+    lines.join("\n")
+}
+
+/// Body text of a message: the plain text part, or the text of the HTML part.
+fn cleaned_body(plain_text: &Option<String>, html_content: &Option<String>) -> String {
+    if let Some(plain) = plain_text {
+        plain.clone()
+    } else if let Some(html) = html_content {
+        clean_html_content(html)
+    } else {
+        String::new()
+    }
+}
+
+/// Calendar invites sent as MIME parts, either as a body alternative or as an attachment.
+fn calendar_parts(message: &mail_parser::Message<'_>) -> Vec<CalendarEvent> {
+    message
+        .parts
+        .iter()
+        .filter(|part| {
+            let mime_type = part
+                .content_type()
+                .map(|ct| format!("{}/{}", ct.ctype(), ct.subtype().unwrap_or_default()));
+            is_calendar(mime_type.as_deref(), part.attachment_name())
+        })
+        .flat_map(|part| parse_icalendar(part.contents()))
+        .collect()
+}
+
+/// Calendar invites among the attachments of a message (`.ics` files or `text/calendar` parts).
+pub(crate) fn calendar_events_from_attachments(attachments: &[EmailAttachment]) -> Vec<CalendarEvent> {
+    attachments
+        .iter()
+        .filter(|attachment| is_calendar(attachment.mime_type.as_deref(), attachment.filename.as_deref()))
+        .filter_map(|attachment| attachment.data.as_deref())
+        .flat_map(parse_icalendar)
+        .collect()
+}
+
+/// Attachments of a message with TNEF (`winmail.dat`) attachments replaced by their content.
+#[derive(Debug, Default)]
+pub(crate) struct UnpackedAttachments {
+    pub(crate) attachments: Vec<EmailAttachment>,
+    /// Meeting requests and `.ics` files found inside TNEF attachments
+    pub(crate) calendar_events: Vec<CalendarEvent>,
+    body: Option<String>,
+    html_body: Option<String>,
+}
+
+impl UnpackedAttachments {
+    fn add_tnef(&mut self, message: TnefMessage) {
+        let inner_events = calendar_events_from_attachments(&message.attachments);
+        self.calendar_events.extend(message.meeting);
+        self.calendar_events.extend(inner_events);
+        if self.body.is_none() && self.html_body.is_none() {
+            self.body = match message.body {
+                Some(body) => Some(body),
+                None => message.rtf_body.as_deref().and_then(rtf_to_text),
+            };
+            self.html_body = message.html_body;
+        }
+        self.attachments.extend(message.attachments);
+    }
+
+    /// Use the TNEF body when the MIME structure of the message carries none, which is the case
+    /// for messages Outlook sends in rich text format.
+    pub(crate) fn fill_body(&mut self, plain_text: &mut Option<String>, html_content: &mut Option<String>) {
+        let is_blank = |text: &Option<String>| text.as_deref().is_none_or(|text| text.trim().is_empty());
+        if is_blank(plain_text) && is_blank(html_content) && (self.body.is_some() || self.html_body.is_some()) {
+            *plain_text = self.body.take();
+            *html_content = self.html_body.take();
+        }
+    }
+}
+
+/// Replace TNEF attachments by the attachments they wrap, collecting their body and meeting
+/// details. TNEF attachments that cannot be decoded are kept as they are.
+pub(crate) fn unpack_tnef_attachments(attachments: Vec<EmailAttachment>) -> UnpackedAttachments {
+    let mut unpacked = UnpackedAttachments::default();
+    for attachment in attachments {
+        let data = attachment.data.as_deref().unwrap_or_default();
+        if tnef::is_tnef(data) {
+            match tnef::parse_tnef(data) {
+                Ok(message) => {
+                    unpacked.add_tnef(message);
+                    continue;
+                }
+                Err(e) => tracing::debug!("Keeping TNEF attachment that cannot be decoded: {}", e),
+            }
+        }
+        unpacked.attachments.push(attachment);
+    }
+    unpacked
+}
+
+#[cfg(feature = "office")]
+fn rtf_to_text(rtf: &str) -> Option<String> {
+    let (text, _) = crate::extractors::rtf::extract_text_from_rtf(rtf);
+    Some(text).filter(|text| !text.trim().is_empty())
+}
+
+#[cfg(not(feature = "office"))]
+fn rtf_to_text(_rtf: &str) -> Option<String> {
+    None
+}
+
+pub(crate) fn clean_html_content(html: &str) -> String {
     if html.is_empty() {
         return String::new();
     }
@@ -382,7 +581,7 @@ fn clean_html_content(html: &str) -> String {
     cleaned.trim().to_string()
 }
 
-fn is_image_mime_type(mime_type: &str) -> bool {
+pub(crate) fn is_image_mime_type(mime_type: &str) -> bool {
     mime_type.starts_with("image/")
 }
 
@@ -399,8 +598,141 @@ fn parse_content_type(content_type: &str) -> String {
         .to_lowercase()
 }
 
+/// Parse the header section of a raw RFC 5322 message into (name, value) pairs.
+///
+/// Folded header lines are unfolded; values are otherwise kept as they appear on the
+/// wire (encoded words are not decoded). Lines that are not header fields, such as an
+/// mbox `From ` separator, are skipped.
+pub(crate) fn parse_raw_headers(data: &[u8]) -> Vec<(String, String)> {
+    let mut headers: Vec<(String, String)> = Vec::new();
+
+    for line in data.split(|&b| b == b'\n') {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.is_empty() {
+            break;
+        }
+        let line = String::from_utf8_lossy(line);
+
+        if line.starts_with([' ', '\t']) {
+            let continuation = line.trim();
+            if let Some((_, value)) = headers.last_mut()
+                && !continuation.is_empty()
+            {
+                if !value.is_empty() {
+                    value.push(' ');
+                }
+                value.push_str(continuation);
+            }
+            continue;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            let name = name.trim_end();
+            if !name.is_empty() && !name.contains(char::is_whitespace) {
+                headers.push((name.to_string(), value.trim().to_string()));
+            }
+        }
+    }
+
+    headers
+}
+
+/// Read the RFC 5322 header block Outlook stores in `PR_TRANSPORT_MESSAGE_HEADERS` (0x007D).
+///
+/// The property is a Unicode (`001F`) or 8-bit (`001E`) string stream at the root of the
+/// MSG compound file. Messages that never went over SMTP, such as drafts, don't have it.
+fn msg_transport_headers(data: &[u8]) -> Option<String> {
+    use std::io::Read;
+
+    let mut file = cfb::CompoundFile::open(std::io::Cursor::new(data)).ok()?;
+    let mut read = |name: &str| {
+        let mut bytes = Vec::new();
+        file.open_stream(name).ok()?.read_to_end(&mut bytes).ok()?;
+        Some(bytes)
+    };
+
+    let text = if let Some(bytes) = read("/__substg1.0_007D001F") {
+        let units: Vec<u16> = bytes
+            .chunks_exact(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]))
+            .collect();
+        String::from_utf16_lossy(&units)
+    } else {
+        String::from_utf8_lossy(&read("/__substg1.0_007D001E")?).into_owned()
+    };
+    Some(text.trim_end_matches('\0').to_string())
+}
+
+/// Addresses in the `Reply-To` header of a parsed message or header block.
+fn reply_to_addresses(message: &mail_parser::Message) -> Vec<String> {
+    message
+        .reply_to()
+        .map(|reply_to| {
+            reply_to
+                .iter()
+                .filter_map(|addr| addr.address().map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_else(Vec::new)
+}
+
+/// Value of the first header field with the given name (case-insensitive).
+pub(crate) fn header_value<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+}
+
+/// Extract the message identifiers from a Message-ID, In-Reply-To or References value.
+///
+/// Angle brackets are stripped, matching the form `mail-parser` returns for `Message-ID`.
+pub(crate) fn parse_message_ids(value: &str) -> Vec<String> {
+    let mut ids = Vec::new();
+    let mut rest = value;
+    while let Some(start) = rest.find('<') {
+        let Some(len) = rest[start + 1..].find('>') else {
+            break;
+        };
+        let id = rest[start + 1..start + 1 + len].trim();
+        if !id.is_empty() {
+            ids.push(id.to_string());
+        }
+        rest = &rest[start + len + 2..];
+    }
+
+    if ids.is_empty() {
+        ids.extend(
+            value
+                .split_whitespace()
+                .map(|id| id.trim_matches(['<', '>']))
+                .filter(|id| !id.is_empty())
+                .map(String::from),
+        );
+    }
+
+    ids
+}
+
+/// Determine the conversation root of a message (RFC 5322 section 3.6.4).
+///
+/// The first `References` entry is the thread root; replies without `References` fall back
+/// to `In-Reply-To`, and messages that start a conversation use their own Message-ID.
+pub(crate) fn compute_thread_id(
+    message_id: Option<&str>,
+    in_reply_to: Option<&str>,
+    references: &[String],
+) -> Option<String> {
+    references
+        .first()
+        .map(String::as_str)
+        .or(in_reply_to)
+        .or(message_id)
+        .and_then(|id| parse_message_ids(id).into_iter().next())
+}
+
 #[allow(clippy::too_many_arguments)]
-fn build_metadata(
+pub(crate) fn build_metadata(
     subject: &Option<String>,
     from_email: &Option<String>,
     to_emails: &[String],
@@ -548,10 +880,21 @@ mod tests {
             bcc_emails: vec![],
             date: None,
             message_id: None,
+            from_name: None,
+            reply_to_emails: vec![],
+            in_reply_to: None,
+            references: vec![],
+            thread_id: None,
+            has_dkim_signature: false,
+            raw_headers: vec![],
             plain_text: None,
             html_content: None,
             cleaned_text: "Hello World".to_string(),
             attachments: vec![],
+            calendar_events: vec![],
+            quoted_text: None,
+            signature: None,
+            tracking_pixels: vec![],
             metadata: HashMap::new(),
         };
 
@@ -572,6 +915,13 @@ mod tests {
             bcc_emails: vec![],
             date: None,
             message_id: None,
+            from_name: None,
+            reply_to_emails: vec![],
+            in_reply_to: None,
+            references: vec![],
+            thread_id: None,
+            has_dkim_signature: false,
+            raw_headers: vec![],
             plain_text: None,
             html_content: None,
             cleaned_text: "Hello World".to_string(),
@@ -582,7 +932,12 @@ mod tests {
                 size: Some(1024),
                 is_image: false,
                 data: None,
+                content_id: None,
             }],
+            calendar_events: vec![],
+            quoted_text: None,
+            signature: None,
+            tracking_pixels: vec![],
             metadata: HashMap::new(),
         };
 
@@ -631,6 +986,7 @@ mod tests {
                 size: Some(1024),
                 is_image: false,
                 data: None,
+                content_id: None,
             },
             EmailAttachment {
                 name: Some("image.png".to_string()),
@@ -639,6 +995,7 @@ mod tests {
                 size: Some(2048),
                 is_image: true,
                 data: None,
+                content_id: None,
             },
         ];
 
@@ -742,10 +1099,21 @@ mod tests {
             bcc_emails: vec!["bcc@example.com".to_string()],
             date: Some("2024-01-01T12:00:00Z".to_string()),
             message_id: Some("<msg123@example.com>".to_string()),
+            from_name: None,
+            reply_to_emails: vec![],
+            in_reply_to: None,
+            references: vec![],
+            thread_id: None,
+            has_dkim_signature: false,
+            raw_headers: vec![],
             plain_text: Some("Plain text body".to_string()),
             html_content: Some("<html><body>HTML body</body></html>".to_string()),
             cleaned_text: "Cleaned body text".to_string(),
             attachments: vec![],
+            calendar_events: vec![],
+            quoted_text: None,
+            signature: None,
+            tracking_pixels: vec![],
             metadata: HashMap::new(),
         };
 
@@ -769,6 +1137,13 @@ mod tests {
             bcc_emails: vec![],
             date: None,
             message_id: None,
+            from_name: None,
+            reply_to_emails: vec![],
+            in_reply_to: None,
+            references: vec![],
+            thread_id: None,
+            has_dkim_signature: false,
+            raw_headers: vec![],
             plain_text: None,
             html_content: None,
             cleaned_text: "Body".to_string(),
@@ -779,7 +1154,12 @@ mod tests {
                 size: Some(100),
                 is_image: false,
                 data: None,
+                content_id: None,
             }],
+            calendar_events: vec![],
+            quoted_text: None,
+            signature: None,
+            tracking_pixels: vec![],
             metadata: HashMap::new(),
         };
 
@@ -839,6 +1219,155 @@ mod tests {
         assert_eq!(result.cleaned_text, "Minimal body");
     }
 
+    #[test]
+    fn test_eml_thread_headers() {
+        let eml_content = b"From: Alice <alice@example.com>\r\n\
+To: bob@example.com\r\n\
+Reply-To: list@example.com\r\n\
+Message-ID: <c@example.com>\r\n\
+In-Reply-To: <b@example.com>\r\n\
+References: <a@example.com>\r\n <b@example.com>\r\n\
+DKIM-Signature: v=1; a=rsa-sha256; d=example.com;\r\n\tb=abc\r\n\
+Subject: Re: Plans\r\n\
+\r\n\
+Sounds good.";
+
+        let result = parse_eml_content(eml_content).unwrap();
+        assert_eq!(result.from_name.as_deref(), Some("Alice"));
+        assert_eq!(result.reply_to_emails, vec!["list@example.com".to_string()]);
+        assert_eq!(result.in_reply_to.as_deref(), Some("b@example.com"));
+        assert_eq!(
+            result.references,
+            vec!["a@example.com".to_string(), "b@example.com".to_string()]
+        );
+        assert_eq!(result.thread_id.as_deref(), Some("a@example.com"));
+        assert!(result.has_dkim_signature);
+        assert_eq!(result.raw_headers.len(), 8);
+        assert_eq!(
+            header_value(&result.raw_headers, "dkim-signature"),
+            Some("v=1; a=rsa-sha256; d=example.com; b=abc")
+        );
+    }
+
+    #[test]
+    fn test_eml_thread_id_without_references() {
+        let reply = b"From: a@example.com\r\nMessage-ID: <2@example.com>\r\nIn-Reply-To: <1@example.com>\r\n\r\nBody";
+        let result = parse_eml_content(reply).unwrap();
+        assert_eq!(result.thread_id.as_deref(), Some("1@example.com"));
+        assert!(result.references.is_empty());
+        assert!(!result.has_dkim_signature);
+
+        let root = b"From: a@example.com\r\nMessage-ID: <1@example.com>\r\n\r\nBody";
+        let result = parse_eml_content(root).unwrap();
+        assert_eq!(result.thread_id.as_deref(), Some("1@example.com"));
+        assert!(result.in_reply_to.is_none());
+    }
+
+    #[test]
+    fn test_eml_calendar_invite() {
+        let eml_content = b"From: jane@example.com\r\n\
+To: bob@example.com\r\n\
+Subject: Invitation: Planning\r\n\
+Content-Type: multipart/alternative; boundary=\"b\"\r\n\
+\r\n\
+--b\r\n\
+Content-Type: text/plain\r\n\
+\r\n\
+You have been invited.\r\n\
+--b\r\n\
+Content-Type: text/calendar; method=REQUEST\r\n\
+\r\n\
+BEGIN:VCALENDAR\r\n\
+METHOD:REQUEST\r\n\
+BEGIN:VEVENT\r\n\
+SUMMARY:Planning\r\n\
+DTSTART:20250303T090000Z\r\n\
+DTEND:20250303T100000Z\r\n\
+ORGANIZER;CN=Jane:mailto:jane@example.com\r\n\
+END:VEVENT\r\n\
+END:VCALENDAR\r\n\
+--b--\r\n";
+
+        let result = parse_eml_content(eml_content).unwrap();
+        assert_eq!(result.calendar_events.len(), 1);
+        let event = &result.calendar_events[0];
+        assert_eq!(event.method.as_deref(), Some("REQUEST"));
+        assert_eq!(event.organizer.as_deref(), Some("Jane <jane@example.com>"));
+
+        let output = build_email_text_output(&result);
+        assert!(output.contains("Meeting: Planning"));
+        assert!(output.contains("When: 2025-03-03T09:00:00Z - 2025-03-03T10:00:00Z"));
+    }
+
+    #[test]
+    fn test_unpack_tnef_keeps_undecodable_attachment() {
+        let attachment = EmailAttachment {
+            name: Some("winmail.dat".to_string()),
+            filename: Some("winmail.dat".to_string()),
+            mime_type: Some("application/ms-tnef".to_string()),
+            size: Some(5),
+            is_image: false,
+            data: Some(vec![0x78, 0x9f, 0x3e, 0x22, 0x01]),
+            content_id: None,
+        };
+
+        let unpacked = unpack_tnef_attachments(vec![attachment]);
+        assert_eq!(unpacked.attachments.len(), 1);
+        assert!(unpacked.calendar_events.is_empty());
+    }
+
+    #[test]
+    fn test_msg_transport_headers() {
+        use std::io::Write;
+
+        let headers = "Message-ID: <c@example.com>\r\nReply-To: list@example.com\r\n\
+References: <a@example.com> <b@example.com>\r\nDKIM-Signature: v=1; d=example.com\r\n\r\n";
+        let mut file = cfb::CompoundFile::create(std::io::Cursor::new(Vec::new())).unwrap();
+        let utf16: Vec<u8> = headers.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        file.create_stream("/__substg1.0_007D001F")
+            .unwrap()
+            .write_all(&utf16)
+            .unwrap();
+        file.flush().unwrap();
+        let data = file.into_inner().into_inner();
+
+        let text = msg_transport_headers(&data).unwrap();
+        assert_eq!(text, headers);
+        let raw_headers = parse_raw_headers(text.as_bytes());
+        assert_eq!(raw_headers.len(), 4);
+        assert_eq!(header_value(&raw_headers, "reply-to"), Some("list@example.com"));
+
+        let parsed = mail_parser::MessageParser::default()
+            .parse_headers(text.as_bytes())
+            .unwrap();
+        assert_eq!(reply_to_addresses(&parsed), vec!["list@example.com".to_string()]);
+        assert!(msg_transport_headers(b"not a compound file").is_none());
+    }
+
+    #[test]
+    fn test_parse_raw_headers_skips_mbox_separator() {
+        let data = b"From sender@example.com Mon Jan  1 12:00:00 2024\nSubject: Hi\n  there\nX-Empty:\n\nSubject: body";
+        let headers = parse_raw_headers(data);
+        assert_eq!(
+            headers,
+            vec![
+                ("Subject".to_string(), "Hi there".to_string()),
+                ("X-Empty".to_string(), String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_message_ids() {
+        assert_eq!(
+            parse_message_ids("<a@x> <b@x>\t<c@x>"),
+            vec!["a@x".to_string(), "b@x".to_string(), "c@x".to_string()]
+        );
+        assert_eq!(parse_message_ids("bare@x"), vec!["bare@x".to_string()]);
+        assert!(parse_message_ids("  ").is_empty());
+        assert_eq!(compute_thread_id(None, None, &[]), None);
+    }
+
     #[test]
     fn test_regex_initialization() {
         let _ = html_tag_regex();
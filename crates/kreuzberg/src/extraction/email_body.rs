@@ -0,0 +1,394 @@
+//! Removal of quoted replies, signatures and tracking pixels from email bodies.
+//!
+//! Every reply in a mail archive usually carries the whole conversation below it, so indexing
+//! the messages as they are stores the same quoted text over and over. [`sanitize_body`] strips
+//! the parts selected in [`EmailConfig`] and keeps what it removed in `quoted_text`,
+//! `signature` and `tracking_pixels`.
+//!
+//! Plain text bodies are cleaned line by line. Quoted (`>`) lines and the attribution line
+//! before them ("On ... wrote:") are quoted, as is everything from an Outlook reply header
+//! (`-----Original Message-----`, or a `From:` line followed by `Sent:` or `Date:`) on. The
+//! signature starts after the first `-- ` delimiter line, or is a trailing "Sent from my ..."
+//! line. HTML bodies are cut at the first quote container of common mail clients (Gmail, Apple
+//! Mail, Outlook, Thunderbird, Yahoo) and at their signature containers.
+
+use crate::core::config::EmailConfig;
+use crate::extraction::email::clean_html_content;
+use crate::types::EmailExtractionResult;
+use regex::{Captures, Regex};
+use std::borrow::Cow;
+use std::sync::OnceLock;
+
+static HTML_QUOTE_RE: OnceLock<Regex> = OnceLock::new();
+static HTML_SIGNATURE_RE: OnceLock<Regex> = OnceLock::new();
+static IMG_TAG_RE: OnceLock<Regex> = OnceLock::new();
+static IMG_SRC_RE: OnceLock<Regex> = OnceLock::new();
+static IMG_SIZE_RE: OnceLock<Regex> = OnceLock::new();
+static HIDDEN_STYLE_RE: OnceLock<Regex> = OnceLock::new();
+static ORIGINAL_MESSAGE_RE: OnceLock<Regex> = OnceLock::new();
+static MOBILE_SIGNATURE_RE: OnceLock<Regex> = OnceLock::new();
+
+fn html_quote_regex() -> &'static Regex {
+    HTML_QUOTE_RE.get_or_init(|| {
+        Regex::new(
+            r#"(?ix)
+            <blockquote[^>]*\btype\s*=\s*["']?cite
+            | <div[^>]*\bclass\s*=\s*["'][^"']*\b(?:gmail_quote|yahoo_quoted|moz-cite-prefix)\b
+            | <div[^>]*\bid\s*=\s*["']?(?:divRplyFwdMsg|appendonsend)\b"#,
+        )
+        .unwrap()
+    })
+}
+
+fn html_signature_regex() -> &'static Regex {
+    HTML_SIGNATURE_RE.get_or_init(|| {
+        Regex::new(
+            r#"(?ix)
+            <(?:div|pre)[^>]*\bclass\s*=\s*["'][^"']*\b(?:gmail_signature|moz-signature)\b
+            | <div[^>]*\bid\s*=\s*["']?signature\b"#,
+        )
+        .unwrap()
+    })
+}
+
+fn img_tag_regex() -> &'static Regex {
+    IMG_TAG_RE.get_or_init(|| Regex::new(r"(?i)<img\b[^>]*>").unwrap())
+}
+
+fn img_src_regex() -> &'static Regex {
+    IMG_SRC_RE.get_or_init(|| Regex::new(r#"(?i)\ssrc\s*=\s*["'](https?://[^"']+)["']"#).unwrap())
+}
+
+fn img_size_regex() -> &'static Regex {
+    IMG_SIZE_RE.get_or_init(|| Regex::new(r#"(?i)(?:^|[\s;"'])(width|height)\s*[=:]\s*["']?\s*(\d+)"#).unwrap())
+}
+
+fn hidden_style_regex() -> &'static Regex {
+    HIDDEN_STYLE_RE.get_or_init(|| Regex::new(r"(?i)display\s*:\s*none|visibility\s*:\s*hidden").unwrap())
+}
+
+fn original_message_regex() -> &'static Regex {
+    ORIGINAL_MESSAGE_RE.get_or_init(|| Regex::new(r"(?i)^-{2,}\s*original message\s*-{2,}$").unwrap())
+}
+
+fn mobile_signature_regex() -> &'static Regex {
+    MOBILE_SIGNATURE_RE
+        .get_or_init(|| Regex::new(r"(?i)^(?:sent from (?:my|mail for|outlook for)|get outlook for)\s").unwrap())
+}
+
+/// Quoted text and signature removed from a body.
+#[derive(Debug, Default, PartialEq)]
+struct Removed {
+    quoted_text: Option<String>,
+    signature: Option<String>,
+}
+
+impl Removed {
+    /// Whether `self` holds a part that `other` is missing.
+    fn reveals_more_than(&self, other: &Removed) -> bool {
+        (self.quoted_text.is_some() && other.quoted_text.is_none())
+            || (self.signature.is_some() && other.signature.is_none())
+    }
+}
+
+/// Strip the parts of the message body selected in `config`.
+///
+/// The text body (`cleaned_text`) and the HTML body are both cleaned, so that inline images
+/// located through the HTML body don't bring the removed parts back. The text is taken from
+/// the HTML body when that reveals a quote or signature the text body doesn't, as the text of
+/// HTML-only messages is converted from the HTML and loses its markup. The message is only
+/// copied when one of the `strip_*` options is set.
+pub fn sanitize_body<'a>(
+    email_result: &'a EmailExtractionResult,
+    config: &EmailConfig,
+) -> Cow<'a, EmailExtractionResult> {
+    if !(config.strip_quoted_replies || config.strip_signatures || config.strip_tracking_pixels) {
+        return Cow::Borrowed(email_result);
+    }
+
+    let mut email_result = email_result.clone();
+    let mut html_removed = Removed::default();
+    if let Some(html) = &mut email_result.html_content {
+        if config.strip_tracking_pixels {
+            email_result.tracking_pixels = strip_tracking_pixels(html);
+        }
+        html_removed = strip_html(html, config);
+    }
+
+    let mut text = email_result.cleaned_text.clone();
+    let text_removed = strip_text(&mut text, config);
+    let removed = match &email_result.html_content {
+        Some(html) if email_result.plain_text.is_none() || html_removed.reveals_more_than(&text_removed) => {
+            email_result.cleaned_text = clean_html_content(html);
+            html_removed
+        }
+        _ => {
+            email_result.cleaned_text = text;
+            text_removed
+        }
+    };
+    email_result.quoted_text = removed.quoted_text;
+    email_result.signature = removed.signature;
+
+    Cow::Owned(email_result)
+}
+
+/// Remove the quoted replies and the signature from a plain text body.
+fn strip_text(text: &mut String, config: &EmailConfig) -> Removed {
+    Removed {
+        quoted_text: if config.strip_quoted_replies {
+            split_off_quotes(text)
+        } else {
+            None
+        },
+        signature: if config.strip_signatures {
+            split_off_signature(text)
+        } else {
+            None
+        },
+    }
+}
+
+/// Remove the quoted replies and the signature from an HTML body.
+fn strip_html(html: &mut String, config: &EmailConfig) -> Removed {
+    Removed {
+        quoted_text: if config.strip_quoted_replies {
+            cut_html(html, html_quote_regex())
+        } else {
+            None
+        },
+        signature: if config.strip_signatures {
+            cut_html(html, html_signature_regex())
+        } else {
+            None
+        },
+    }
+}
+
+/// Cut `html` at the first match of `regex`, returning the text of the part cut off.
+fn cut_html(html: &mut String, regex: &Regex) -> Option<String> {
+    let start = regex.find(html)?.start();
+    let removed = clean_html_content(&html[start..]);
+    html.truncate(start);
+    non_empty(&removed)
+}
+
+/// Remove the quoted lines from `text`, returning them.
+fn split_off_quotes(text: &mut String) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut quoted: Vec<bool> = lines.iter().map(|line| line.trim_start().starts_with('>')).collect();
+    if let Some(header) = reply_header(&lines) {
+        quoted[header..].fill(true);
+    }
+    for start in 1..lines.len() {
+        if quoted[start] && !quoted[start - 1] {
+            mark_attribution(&lines, &mut quoted, start);
+        }
+    }
+
+    let select = |wanted: bool| {
+        let selected: Vec<&str> = lines
+            .iter()
+            .zip(&quoted)
+            .filter(|(_, quoted)| **quoted == wanted)
+            .map(|(line, _)| *line)
+            .collect();
+        selected.join("\n")
+    };
+    let quoted_text = non_empty(&select(true));
+    *text = select(false).trim().to_string();
+    quoted_text
+}
+
+/// First line of an Outlook reply header, including the rule of underscores above it.
+fn reply_header(lines: &[&str]) -> Option<usize> {
+    let header = (0..lines.len()).find(|&index| is_reply_header(lines, index))?;
+    match header.checked_sub(1) {
+        Some(rule) if is_rule(lines[rule]) => Some(rule),
+        _ => Some(header),
+    }
+}
+
+fn is_rule(line: &str) -> bool {
+    let line = line.trim();
+    !line.is_empty() && line.chars().all(|c| c == '_')
+}
+
+fn is_reply_header(lines: &[&str], index: usize) -> bool {
+    let line = lines[index].trim();
+    if original_message_regex().is_match(line) {
+        return true;
+    }
+    has_prefix(line, "From:")
+        && lines[index + 1..].iter().take(3).any(|next| {
+            let next = next.trim_start();
+            has_prefix(next, "Sent:") || has_prefix(next, "Date:")
+        })
+}
+
+/// Mark the attribution line ("On ... wrote:") of the quote starting at line `start`.
+fn mark_attribution(lines: &[&str], quoted: &mut [bool], start: usize) {
+    let Some(line) = lines[..start].iter().rposition(|line| !line.trim().is_empty()) else {
+        return;
+    };
+    if quoted[line] || !lines[line].trim_end().ends_with(':') {
+        return;
+    }
+    quoted[line] = true;
+
+    // Long attributions are wrapped, leaving "wrote:" alone on the second line
+    if line > 0 && !has_prefix(lines[line].trim_start(), "On ") && has_prefix(lines[line - 1].trim_start(), "On ") {
+        quoted[line - 1] = true;
+    }
+}
+
+/// Remove the signature from `text`, returning it.
+fn split_off_signature(text: &mut String) -> Option<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let (end, start) = match lines.iter().position(|line| line.trim_end() == "--") {
+        Some(delimiter) => (delimiter, delimiter + 1),
+        None => {
+            let last = lines.iter().rposition(|line| !line.trim().is_empty())?;
+            if !mobile_signature_regex().is_match(lines[last].trim()) {
+                return None;
+            }
+            (last, last)
+        }
+    };
+
+    let signature = non_empty(&lines[start..].join("\n"));
+    *text = lines[..end].join("\n").trim_end().to_string();
+    signature
+}
+
+/// Remove tracking pixels, returning their URLs.
+///
+/// A tracking pixel is a remote image that is hidden or at most 1x1 pixels large. Inline
+/// (`cid:`) images are kept.
+fn strip_tracking_pixels(html: &mut String) -> Vec<String> {
+    let mut pixels = Vec::new();
+    let stripped = img_tag_regex().replace_all(html, |caps: &Captures| {
+        let tag = &caps[0];
+        match img_src_regex().captures(tag) {
+            Some(src) if is_tracking_pixel(tag) => {
+                pixels.push(src[1].to_string());
+                String::new()
+            }
+            _ => tag.to_string(),
+        }
+    });
+    if let Cow::Owned(stripped) = stripped {
+        *html = stripped;
+    }
+    pixels
+}
+
+fn is_tracking_pixel(tag: &str) -> bool {
+    if hidden_style_regex().is_match(tag) {
+        return true;
+    }
+
+    let (mut width, mut height) = (None, None);
+    for caps in img_size_regex().captures_iter(tag) {
+        let size = caps[2].parse::<u32>().ok();
+        if caps[1].eq_ignore_ascii_case("width") {
+            width = size;
+        } else {
+            height = size;
+        }
+    }
+    width.is_some_and(|width| width <= 1) && height.is_some_and(|height| height <= 1)
+}
+
+/// Case-insensitive `starts_with` for ASCII prefixes.
+fn has_prefix(line: &str, prefix: &str) -> bool {
+    let Some(head) = line.get(..prefix.len()) else {
+        return false;
+    };
+    head.eq_ignore_ascii_case(prefix)
+}
+
+fn non_empty(text: &str) -> Option<String> {
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> EmailConfig {
+        EmailConfig {
+            strip_quoted_replies: true,
+            strip_signatures: true,
+            strip_tracking_pixels: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_strip_text_quotes_and_signature() {
+        let mut text = "Sounds good, see you Monday.\n\
+            -- \n\
+            Jane Doe\n\
+            Acme Corp\n\
+            \n\
+            On Fri, Jan 9, 2026 at 10:00 AM John Smith <john@example.com>\n\
+            wrote:\n\
+            > Can we meet on Monday?\n\
+            >\n\
+            > John"
+            .to_string();
+
+        let removed = strip_text(&mut text, &config());
+
+        assert_eq!(text, "Sounds good, see you Monday.");
+        assert_eq!(removed.signature.as_deref(), Some("Jane Doe\nAcme Corp"));
+        let quoted_text = removed.quoted_text.unwrap();
+        assert!(quoted_text.starts_with("On Fri, Jan 9, 2026"));
+        assert!(quoted_text.ends_with("> John"));
+    }
+
+    #[test]
+    fn test_strip_text_outlook_reply_header() {
+        let mut text = "Approved.\n\nSent from my iPhone\n\
+            ________________________________\n\
+            From: John Smith\n\
+            Sent: Friday, January 9, 2026 10:00\n\
+            Subject: Budget\n\
+            \n\
+            Please approve the budget."
+            .to_string();
+
+        let removed = strip_text(&mut text, &config());
+
+        assert_eq!(text, "Approved.");
+        assert_eq!(removed.signature.as_deref(), Some("Sent from my iPhone"));
+        assert!(removed.quoted_text.unwrap().ends_with("Please approve the budget."));
+
+        let mut unquoted = "Plain message\nwith two lines".to_string();
+        assert_eq!(strip_text(&mut unquoted, &config()), Removed::default());
+        assert_eq!(unquoted, "Plain message\nwith two lines");
+    }
+
+    #[test]
+    fn test_strip_html_quotes_and_tracking_pixels() {
+        let mut html = r#"<div>Thanks!</div><img src="https://t.example.com/open.gif" width="1" height="1">
+            <img src="https://example.com/logo.png" width="120" height="40"><img src="cid:chart">
+            <div class="gmail_signature">Jane Doe</div>
+            <div class="gmail_quote">On Fri, John wrote:<blockquote>Report attached</blockquote></div>"#
+            .to_string();
+
+        let pixels = strip_tracking_pixels(&mut html);
+        let removed = strip_html(&mut html, &config());
+
+        assert_eq!(pixels, vec!["https://t.example.com/open.gif"]);
+        assert_eq!(clean_html_content(&html), "Thanks!");
+        assert!(html.contains("logo.png") && html.contains("cid:chart"));
+        assert_eq!(removed.signature.as_deref(), Some("Jane Doe"));
+        assert_eq!(
+            removed.quoted_text.as_deref(),
+            Some("On Fri, John wrote:Report attached")
+        );
+    }
+}
@@ -0,0 +1,305 @@
+//! OCR for images embedded in running text.
+//!
+//! Extractors for formats that place images inline (HTML `<img>` data URIs, email CID parts,
+//! DOCX media) put [`IMAGE_MARKER`] into their text where each image appears and hand the
+//! images to [`ocr_embedded_images`], which runs them through the configured OCR backend and
+//! replaces the markers with the recognized text. This is only done when
+//! [`embedded_image_ocr_enabled`] holds, so extraction without OCR never sees markers.
+
+use crate::Result;
+use crate::core::config::ExtractionConfig;
+use crate::plugins::run_ocr;
+use crate::types::{ExtractedImage, ExtractionResult};
+use crate::utils::resolution::image_dpi;
+
+/// Placeholder for an embedded image in extracted text (U+FFFC OBJECT REPLACEMENT CHARACTER).
+pub const IMAGE_MARKER: char = '\u{FFFC}';
+
+/// Image formats OCR backends can read; vector and metafile images are listed but not OCRed.
+const RASTER_FORMATS: &[&str] = &["png", "jpeg", "jpg", "gif", "bmp", "tiff", "webp"];
+
+/// Whether embedded images are OCRed: image extraction is enabled and OCR is configured.
+pub fn embedded_image_ocr_enabled(config: &ExtractionConfig) -> bool {
+    config.ocr.is_some() && config.images.as_ref().is_some_and(|images| images.extract_images)
+}
+
+/// Build an [`ExtractedImage`] for an embedded image without page or pixel information.
+pub fn embedded_image(data: Vec<u8>, format: String, image_index: usize) -> ExtractedImage {
+    let dpi = image_dpi(&data);
+    ExtractedImage {
+        data,
+        format,
+        image_index,
+        page_number: None,
+        width: None,
+        height: None,
+        dpi,
+        colorspace: None,
+        bits_per_component: None,
+        is_mask: false,
+        description: None,
+        ocr_result: None,
+    }
+}
+
+/// Image format name for an `image/*` MIME type (`image/svg+xml` becomes `svg`).
+pub fn image_format_from_mime(mime_type: &str) -> String {
+    let subtype = mime_type.strip_prefix("image/").unwrap_or(mime_type);
+    subtype.split('+').next().unwrap_or(subtype).to_ascii_lowercase()
+}
+
+/// OCR `images` and splice the recognized text into `text`.
+///
+/// The n-th [`IMAGE_MARKER`] in `text` belongs to `images[n]`; text recognized in images
+/// without a marker is appended at the end. Each OCR result is kept in
+/// [`ExtractedImage::ocr_result`]. Images the backend fails on are logged and skipped so one
+/// unreadable image does not fail the document.
+///
+/// Images are OCRed like image files (see [`run_ocr`]), with the OCR backends of the
+/// extraction's plugin registries, up to `max_concurrent_extractions` at a time.
+pub async fn ocr_embedded_images(text: &str, images: &mut [ExtractedImage], config: &ExtractionConfig) -> String {
+    let image_texts = if config.ocr.is_some() {
+        ocr_images(images, config).await
+    } else {
+        Vec::new()
+    };
+
+    splice_image_text(text, image_texts)
+}
+
+/// OCR the raster images among `images` concurrently, returning the text of each image.
+#[cfg(feature = "tokio-runtime")]
+async fn ocr_images(images: &mut [ExtractedImage], config: &ExtractionConfig) -> Vec<Option<String>> {
+    use tokio::sync::Semaphore;
+    use tokio::task::JoinSet;
+
+    let max_concurrent = config.max_concurrent_extractions.unwrap_or_else(num_cpus::get);
+    let semaphore = std::sync::Arc::new(Semaphore::new(max_concurrent.max(1)));
+    let config = std::sync::Arc::new(config.clone());
+    let mut tasks = JoinSet::new();
+
+    for (index, image) in images.iter_mut().enumerate() {
+        if !RASTER_FORMATS.contains(&image.format.as_str()) {
+            continue;
+        }
+
+        // Moved into the task and put back afterwards
+        let data = std::mem::take(&mut image.data);
+        let semaphore = std::sync::Arc::clone(&semaphore);
+        let config = std::sync::Arc::clone(&config);
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let registries = config.context.registries();
+            let result = run_ocr(&data, &registries.ocr_backends, &config).await;
+            (index, data, result)
+        });
+    }
+
+    let mut image_texts = vec![None; images.len()];
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok((index, data, result)) => {
+                let image = &mut images[index];
+                image.data = data;
+                image_texts[index] = record_ocr_result(image, result);
+            }
+            Err(e) => tracing::warn!("OCR task for an embedded image panicked: {}", e),
+        }
+    }
+
+    image_texts
+}
+
+/// OCR the raster images among `images` one after the other (no async runtime to spawn on).
+#[cfg(not(feature = "tokio-runtime"))]
+async fn ocr_images(images: &mut [ExtractedImage], config: &ExtractionConfig) -> Vec<Option<String>> {
+    let registries = config.context.registries();
+    let mut image_texts = Vec::with_capacity(images.len());
+
+    for image in images.iter_mut() {
+        if !RASTER_FORMATS.contains(&image.format.as_str()) {
+            image_texts.push(None);
+            continue;
+        }
+        let result = run_ocr(&image.data, &registries.ocr_backends, config).await;
+        image_texts.push(record_ocr_result(image, result));
+    }
+
+    image_texts
+}
+
+/// Keep the OCR result in `image` and return its text, logging failures.
+fn record_ocr_result(image: &mut ExtractedImage, result: Result<ExtractionResult>) -> Option<String> {
+    match result {
+        Ok(result) => {
+            let image_text = result.content.trim().to_string();
+            image.ocr_result = Some(Box::new(result));
+            (!image_text.is_empty()).then_some(image_text)
+        }
+        Err(e) => {
+            tracing::warn!("OCR failed for embedded image {}: {}", image.image_index, e);
+            None
+        }
+    }
+}
+
+/// Replace each marker with the matching image text on its own line and drop leftover markers.
+fn splice_image_text(text: &str, image_texts: Vec<Option<String>>) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut image_texts = image_texts.into_iter();
+
+    for (index, segment) in text.split(IMAGE_MARKER).enumerate() {
+        if index > 0
+            && let Some(Some(image_text)) = image_texts.next()
+        {
+            push_block(&mut output, &image_text);
+        }
+        output.push_str(segment);
+    }
+
+    for image_text in image_texts.flatten() {
+        push_block(&mut output, &image_text);
+    }
+
+    output
+}
+
+fn push_block(output: &mut String, block: &str) {
+    if !output.is_empty() && !output.ends_with('\n') {
+        output.push('\n');
+    }
+    output.push_str(block);
+    output.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::OcrConfig;
+    use crate::plugins::registry::PluginRegistries;
+    use crate::plugins::{OcrBackend, OcrBackendType, Plugin};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    /// OCR backend that "recognizes" the image bytes as text.
+    struct EchoOcr;
+
+    impl Plugin for EchoOcr {
+        fn name(&self) -> &str {
+            "echo-ocr"
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl OcrBackend for EchoOcr {
+        async fn process_image(&self, image_bytes: &[u8], _config: &OcrConfig) -> Result<ExtractionResult> {
+            Ok(ExtractionResult {
+                content: String::from_utf8_lossy(image_bytes).to_string(),
+                mime_type: "text/plain".to_string(),
+                metadata: Default::default(),
+                tables: vec![],
+                detected_languages: None,
+                chunks: None,
+                images: None,
+                pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
+            })
+        }
+
+        fn supports_language(&self, _lang: &str) -> bool {
+            true
+        }
+
+        fn backend_type(&self) -> OcrBackendType {
+            OcrBackendType::Custom
+        }
+    }
+
+    #[test]
+    fn test_embedded_image_ocr_enabled_requires_images_and_ocr() {
+        let mut config = ExtractionConfig::default();
+        assert!(!embedded_image_ocr_enabled(&config));
+
+        config.ocr = Some(OcrConfig {
+            backend: "tesseract".to_string(),
+            language: "eng".to_string(),
+            tesseract_config: None,
+        });
+        assert!(!embedded_image_ocr_enabled(&config));
+
+        config.images = Some(serde_json::from_str("{}").unwrap());
+        assert!(embedded_image_ocr_enabled(&config));
+    }
+
+    #[tokio::test]
+    async fn test_ocr_embedded_images_uses_registries_of_the_extraction() {
+        let registries = PluginRegistries::new();
+        registries
+            .ocr_backends
+            .write()
+            .unwrap()
+            .register(Arc::new(EchoOcr))
+            .unwrap();
+        let mut config = ExtractionConfig {
+            ocr: Some(OcrConfig {
+                backend: "echo-ocr".to_string(),
+                language: "eng".to_string(),
+                tesseract_config: None,
+            }),
+            ..Default::default()
+        };
+        config.context.registries = Some(registries);
+
+        let mut images = vec![
+            embedded_image(b"first".to_vec(), "png".to_string(), 0),
+            embedded_image(b"<svg/>".to_vec(), "svg".to_string(), 1),
+            embedded_image(b"second".to_vec(), "png".to_string(), 2),
+        ];
+        let text = format!("A{}B{}C{}", IMAGE_MARKER, IMAGE_MARKER, IMAGE_MARKER);
+
+        let spliced = ocr_embedded_images(&text, &mut images, &config).await;
+
+        assert_eq!(spliced, "A\nfirst\nBC\nsecond\n");
+        assert_eq!(images[2].data, b"second");
+        assert_eq!(images[2].ocr_result.as_ref().unwrap().content, "second");
+        assert!(images[1].ocr_result.is_none());
+    }
+
+    #[test]
+    fn test_image_format_from_mime() {
+        assert_eq!(image_format_from_mime("image/png"), "png");
+        assert_eq!(image_format_from_mime("image/svg+xml"), "svg");
+    }
+
+    #[test]
+    fn test_splice_image_text_replaces_markers_in_order() {
+        let text = format!("Intro {}middle{} end", IMAGE_MARKER, IMAGE_MARKER);
+        let image_texts = vec![Some("Chart A".to_string()), None, Some("Chart C".to_string())];
+
+        let spliced = splice_image_text(&text, image_texts);
+
+        assert_eq!(spliced, "Intro \nChart A\nmiddle end\nChart C\n");
+    }
+
+    #[test]
+    fn test_splice_image_text_drops_unmatched_markers() {
+        let text = format!("Before{}After", IMAGE_MARKER);
+        assert_eq!(splice_image_text(&text, Vec::new()), "BeforeAfter");
+    }
+}
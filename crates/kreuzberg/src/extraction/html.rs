@@ -25,13 +25,14 @@
 //! # }
 //! ```
 use crate::error::{KreuzbergError, Result};
-use crate::types::HtmlMetadata;
+use crate::types::{HtmlImage, HtmlLink, HtmlMetadata};
 use html_to_markdown_rs::{
     ConversionOptions, HtmlExtraction, InlineImage, InlineImageConfig as LibInlineImageConfig, InlineImageFormat,
     convert as convert_html, convert_with_inline_images,
 };
+use regex::{Captures, Regex};
 use serde::{Deserialize, Serialize};
-use std::{any::Any, collections::HashMap, thread};
+use std::{any::Any, collections::HashMap, sync::OnceLock, thread};
 
 pub use html_to_markdown_rs::{
     CodeBlockStyle, HeadingStyle, HighlightStyle, ListIndentType, NewlineStyle, PreprocessingOptions,
@@ -353,6 +354,115 @@ pub fn parse_html_metadata(markdown: &str) -> Result<(Option<HtmlMetadata>, Stri
     }
 }
 
+static ANCHOR_RE: OnceLock<Regex> = OnceLock::new();
+static IMG_RE: OnceLock<Regex> = OnceLock::new();
+static ATTRIBUTE_RE: OnceLock<Regex> = OnceLock::new();
+static TAG_RE: OnceLock<Regex> = OnceLock::new();
+static ENTITY_RE: OnceLock<Regex> = OnceLock::new();
+
+/// Extract the hyperlinks and images of an HTML document, in document order.
+///
+/// Links are `<a>` tags with an `href`; images are `<img>` tags with a `src`. Attribute values
+/// are returned as written, so relative URLs are not resolved against `<base href>`. Data URI
+/// sources are kept in full; the HTML extractor shortens them once their bytes are decoded.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::extraction::html::extract_link_map;
+///
+/// let html = r#"<p>See <a href="/docs" rel="nofollow">the <b>docs</b></a> <img src="logo.png" alt="Logo"></p>"#;
+/// let (links, images) = extract_link_map(html);
+///
+/// assert_eq!(links[0].text, "the docs");
+/// assert_eq!(links[0].href, "/docs");
+/// assert_eq!(images[0].alt.as_deref(), Some("Logo"));
+/// ```
+pub fn extract_link_map(html: &str) -> (Vec<HtmlLink>, Vec<HtmlImage>) {
+    let anchor_re = ANCHOR_RE.get_or_init(|| Regex::new(r"(?is)<a\b([^>]*)>(.*?)</a\s*>").unwrap());
+    let img_re = IMG_RE.get_or_init(|| Regex::new(r"(?is)<img\b([^>]*)>").unwrap());
+
+    let links = anchor_re
+        .captures_iter(html)
+        .filter_map(|caps| {
+            let mut attributes = parse_attributes(&caps[1]);
+            Some(HtmlLink {
+                text: html_text(&caps[2]),
+                href: attributes.remove("href")?,
+                title: attributes.remove("title"),
+                rel: attributes.remove("rel"),
+                position: caps.get(0).map_or(0, |m| m.start()),
+            })
+        })
+        .collect();
+
+    let images = img_re
+        .captures_iter(html)
+        .filter_map(|caps| {
+            let mut attributes = parse_attributes(&caps[1]);
+            Some(HtmlImage {
+                src: attributes.remove("src")?,
+                alt: attributes.remove("alt"),
+                title: attributes.remove("title"),
+                position: caps.get(0).map_or(0, |m| m.start()),
+                image_index: None,
+            })
+        })
+        .collect();
+
+    (links, images)
+}
+
+/// Attributes of a tag (names lowercased, values entity-decoded). The first occurrence wins.
+fn parse_attributes(tag: &str) -> HashMap<String, String> {
+    let attribute_re = ATTRIBUTE_RE.get_or_init(|| {
+        Regex::new(r#"(?is)(?:^|\s)([a-z][a-z0-9_:.-]*)\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s"'>]+))"#).unwrap()
+    });
+    let mut attributes = HashMap::new();
+    for caps in attribute_re.captures_iter(tag) {
+        let value = caps.get(2).or_else(|| caps.get(3)).or_else(|| caps.get(4));
+        let value = decode_entities(value.map_or("", |m| m.as_str()).trim());
+        attributes.entry(caps[1].to_ascii_lowercase()).or_insert(value);
+    }
+    attributes
+}
+
+/// Text of an HTML fragment: tags removed, entities decoded, whitespace collapsed.
+pub(crate) fn html_text(fragment: &str) -> String {
+    let tag_re = TAG_RE.get_or_init(|| Regex::new(r"(?s)<[^>]*>").unwrap());
+    let text = decode_entities(&tag_re.replace_all(fragment, " "));
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Decode numeric character references and the common named entities.
+fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+    let entity_re = ENTITY_RE.get_or_init(|| Regex::new(r"(?i)&(#x[0-9a-f]+|#[0-9]+|[a-z]+);").unwrap());
+    entity_re
+        .replace_all(text, |caps: &Captures| {
+            let entity = &caps[1];
+            let decoded = match entity.to_ascii_lowercase().as_str() {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                lower => {
+                    let code = match lower.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                        None => lower.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+                    };
+                    code.and_then(char::from_u32)
+                }
+            };
+            decoded.map_or_else(|| caps[0].to_string(), String::from)
+        })
+        .into_owned()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -566,4 +676,37 @@ mod tests {
         let markdown = convert_html_to_markdown(html, None).expect("conversion failed");
         assert!(markdown.contains("Taylor Alison Swift"), "{markdown}");
     }
+
+    #[test]
+    fn test_extract_link_map() {
+        let html = concat!(
+            r#"<p>Read <A HREF='/guide' title="The &quot;guide&quot;">the   <em>guide</em></A>.</p>"#,
+            r#"<a name="top">no href</a><a href=https://example.com rel=nofollow>Tom &amp; Jerry</a>"#,
+            r#"<img src="a.png" alt="Chart &#8211; Q1"><img alt="missing src"><IMG SRC="b.gif"/>"#,
+        );
+        let (links, images) = extract_link_map(html);
+
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].text, "the guide");
+        assert_eq!(links[0].href, "/guide");
+        assert_eq!(links[0].title.as_deref(), Some("The \"guide\""));
+        assert_eq!(links[0].position, html.find("<A").unwrap());
+        assert_eq!(links[1].text, "Tom & Jerry");
+        assert_eq!(links[1].href, "https://example.com");
+        assert_eq!(links[1].rel.as_deref(), Some("nofollow"));
+
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0].src, "a.png");
+        assert_eq!(images[0].alt.as_deref(), Some("Chart \u{2013} Q1"));
+        assert_eq!(images[1].src, "b.gif");
+        assert!(images[1].alt.is_none());
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &#x41;&#66; &unknown; &"),
+            "a <b> AB &unknown; &"
+        );
+    }
 }
@@ -0,0 +1,279 @@
+//! Calendar invites (iCalendar, RFC 5545).
+//!
+//! Meeting invitations arrive as `text/calendar` message parts or `.ics` attachments. Only the
+//! properties describing the meeting are read from each `VEVENT`; time zone definitions, alarms
+//! and other components are skipped.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::extraction::icalendar::parse_icalendar;
+//!
+//! let ics = b"BEGIN:VCALENDAR\r\nMETHOD:REQUEST\r\nBEGIN:VEVENT\r\nSUMMARY:Planning\r\n\
+//!             DTSTART:20250303T090000Z\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+//! let events = parse_icalendar(ics);
+//!
+//! assert_eq!(events[0].summary.as_deref(), Some("Planning"));
+//! assert_eq!(events[0].start.as_deref(), Some("2025-03-03T09:00:00Z"));
+//! ```
+
+use crate::types::CalendarEvent;
+
+/// MIME types calendar invites are sent as.
+const CALENDAR_MIME_TYPES: &[&str] = &["text/calendar", "application/ics"];
+
+/// Whether a part or attachment is a calendar invite, judged by its MIME type or file name.
+pub fn is_calendar(mime_type: Option<&str>, filename: Option<&str>) -> bool {
+    mime_type.is_some_and(is_calendar_mime_type) || filename.is_some_and(is_calendar_file_name)
+}
+
+fn is_calendar_mime_type(mime_type: &str) -> bool {
+    let mime_type = mime_type.to_ascii_lowercase();
+    CALENDAR_MIME_TYPES.contains(&mime_type.as_str())
+}
+
+fn is_calendar_file_name(name: &str) -> bool {
+    let name = name.to_ascii_lowercase();
+    name.ends_with(".ics") || name.ends_with(".vcs")
+}
+
+/// Parse the events of an iCalendar object.
+///
+/// Malformed lines are skipped, so this never fails; data without a `VEVENT` yields no events.
+pub fn parse_icalendar(data: &[u8]) -> Vec<CalendarEvent> {
+    let text = String::from_utf8_lossy(data);
+    let mut events = Vec::new();
+    let mut method = None;
+    let mut current: Option<CalendarEvent> = None;
+    // Components nested in the current event, such as VALARM
+    let mut nested = 0usize;
+
+    for line in unfold_lines(&text) {
+        let Some((name, params, value)) = split_content_line(&line) else {
+            continue;
+        };
+
+        match (name.to_ascii_uppercase().as_str(), current.as_mut()) {
+            ("BEGIN", None) if value.eq_ignore_ascii_case("VEVENT") => {
+                current = Some(CalendarEvent {
+                    method: method.clone(),
+                    ..Default::default()
+                });
+            }
+            ("BEGIN", Some(_)) => nested += 1,
+            ("END", Some(_)) if nested > 0 => nested -= 1,
+            ("END", Some(_)) if value.eq_ignore_ascii_case("VEVENT") => events.extend(current.take()),
+            ("METHOD", None) => method = non_empty(value.to_ascii_uppercase()),
+            (_, Some(_)) if nested > 0 => {}
+            (property, Some(event)) => apply_property(event, property, &params, value),
+            _ => {}
+        }
+    }
+
+    events
+}
+
+fn apply_property(event: &mut CalendarEvent, property: &str, params: &[(String, String)], value: &str) {
+    match property {
+        "UID" => event.uid = non_empty(value.to_string()),
+        "SUMMARY" => event.summary = non_empty(unescape_text(value)),
+        "DESCRIPTION" => event.description = non_empty(unescape_text(value)),
+        "LOCATION" => event.location = non_empty(unescape_text(value)),
+        "STATUS" => event.status = non_empty(value.to_ascii_uppercase()),
+        "RRULE" => event.recurrence = non_empty(value.to_string()),
+        "DTSTART" => {
+            event.start = non_empty(format_date_time(value));
+            event.timezone = param(params, "TZID").map(String::from);
+        }
+        "DTEND" => event.end = non_empty(format_date_time(value)),
+        "ORGANIZER" => event.organizer = calendar_user(params, value),
+        "ATTENDEE" => event.attendees.extend(calendar_user(params, value)),
+        _ => {}
+    }
+}
+
+/// Format an ORGANIZER or ATTENDEE from its common name (CN) and `mailto:` address.
+fn calendar_user(params: &[(String, String)], value: &str) -> Option<String> {
+    let value = value.trim();
+    let address = match value.get(..7) {
+        Some(scheme) if scheme.eq_ignore_ascii_case("mailto:") => &value[7..],
+        _ => value,
+    };
+    format_participant(param(params, "CN"), Some(address))
+}
+
+/// Format a meeting participant as `Name <address>`, or whichever of the two is known.
+pub(crate) fn format_participant(name: Option<&str>, address: Option<&str>) -> Option<String> {
+    let name = name.map(str::trim).filter(|name| !name.is_empty());
+    let address = address.map(str::trim).filter(|address| !address.is_empty());
+    match (name, address) {
+        (Some(name), Some(address)) if !name.eq_ignore_ascii_case(address) => Some(format!("{} <{}>", name, address)),
+        (_, Some(address)) => Some(address.to_string()),
+        (Some(name), None) => Some(name.to_string()),
+        (None, None) => None,
+    }
+}
+
+/// Join folded lines: a line starting with a space or tab continues the previous one.
+fn unfold_lines(text: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    for line in text.lines() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(continuation), Some(previous)) => previous.push_str(continuation),
+            _ => lines.push(line.to_string()),
+        }
+    }
+    lines
+}
+
+/// Split `NAME;PARAM=value;...:value` into its name, parameters and value.
+fn split_content_line(line: &str) -> Option<(&str, Vec<(String, String)>, &str)> {
+    let mut in_quotes = false;
+    let mut colon = None;
+    for (index, c) in line.char_indices() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            ':' if !in_quotes => {
+                colon = Some(index);
+                break;
+            }
+            _ => {}
+        }
+    }
+    let colon = colon?;
+
+    let (head, value) = (&line[..colon], &line[colon + 1..]);
+    let mut segments = head.split(';');
+    let name = segments.next()?.trim();
+    if name.is_empty() {
+        return None;
+    }
+
+    let params = segments
+        .filter_map(|segment| segment.split_once('='))
+        .map(|(key, value)| {
+            let value = value.trim().trim_matches('"').to_string();
+            (key.trim().to_ascii_uppercase(), value)
+        })
+        .collect();
+    Some((name, params, value))
+}
+
+fn param<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(key, _)| key == name)
+        .map(|(_, value)| value.as_str())
+}
+
+fn unescape_text(value: &str) -> String {
+    let mut text = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n' | 'N') => text.push('\n'),
+            Some(escaped) => text.push(escaped),
+            None => text.push('\\'),
+        }
+    }
+    text.trim().to_string()
+}
+
+/// Format an iCalendar DATE or DATE-TIME (`20250303`, `20250303T090000Z`) as ISO 8601.
+///
+/// Values in other shapes are returned unchanged.
+fn format_date_time(value: &str) -> String {
+    let value = value.trim();
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let (date, time) = value.split_once(['T', 't']).unwrap_or((value, ""));
+    let (time, utc) = match time.strip_suffix(['Z', 'z']) {
+        Some(time) => (time, "Z"),
+        None => (time, ""),
+    };
+
+    if date.len() != 8 || !is_digits(date) {
+        return value.to_string();
+    }
+
+    let date = format!("{}-{}-{}", &date[..4], &date[4..6], &date[6..]);
+    match time.len() {
+        0 => date,
+        6 if is_digits(time) => format!("{}T{}:{}:{}{}", date, &time[..2], &time[2..4], &time[4..], utc),
+        _ => value.to_string(),
+    }
+}
+
+fn non_empty(value: String) -> Option<String> {
+    (!value.is_empty()).then_some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INVITE: &str = "BEGIN:VCALENDAR\r\n\
+        VERSION:2.0\r\n\
+        METHOD:REQUEST\r\n\
+        BEGIN:VTIMEZONE\r\n\
+        TZID:Europe/Berlin\r\n\
+        END:VTIMEZONE\r\n\
+        BEGIN:VEVENT\r\n\
+        UID:040000008200E00074C5B7101A82E008\r\n\
+        SUMMARY:Quarterly review\\, Q3\r\n\
+        DTSTART;TZID=Europe/Berlin:20250915T140000\r\n\
+        DTEND;TZID=Europe/Berlin:20250915T150000\r\n\
+        LOCATION:Room 4\r\n\
+        ORGANIZER;CN=\"Doe, Jane\":mailto:jane@example.com\r\n\
+        ATTENDEE;ROLE=REQ-PARTICIPANT;CN=Bob:MAILTO:bob@example.com\r\n\
+        ATTENDEE:mailto:carol@example.com\r\n\
+        DESCRIPTION:Agenda:\\n1. Numbers\\n2. Hiring plan for the next quar\r\n \
+         ter\r\n\
+        RRULE:FREQ=QUARTERLY\r\n\
+        BEGIN:VALARM\r\n\
+        DESCRIPTION:Reminder\r\n\
+        END:VALARM\r\n\
+        END:VEVENT\r\n\
+        END:VCALENDAR\r\n";
+
+    #[test]
+    fn test_parse_invite() {
+        let events = parse_icalendar(INVITE.as_bytes());
+
+        assert_eq!(
+            events,
+            vec![CalendarEvent {
+                method: Some("REQUEST".to_string()),
+                uid: Some("040000008200E00074C5B7101A82E008".to_string()),
+                summary: Some("Quarterly review, Q3".to_string()),
+                start: Some("2025-09-15T14:00:00".to_string()),
+                end: Some("2025-09-15T15:00:00".to_string()),
+                timezone: Some("Europe/Berlin".to_string()),
+                location: Some("Room 4".to_string()),
+                organizer: Some("Doe, Jane <jane@example.com>".to_string()),
+                attendees: vec!["Bob <bob@example.com>".to_string(), "carol@example.com".to_string()],
+                description: Some("Agenda:\n1. Numbers\n2. Hiring plan for the next quarter".to_string()),
+                status: None,
+                recurrence: Some("FREQ=QUARTERLY".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_format_date_time() {
+        assert_eq!(format_date_time("20250303T090000Z"), "2025-03-03T09:00:00Z");
+        assert_eq!(format_date_time("20250303"), "2025-03-03");
+        assert_eq!(format_date_time("tomorrow"), "tomorrow");
+    }
+
+    #[test]
+    fn test_is_calendar() {
+        assert!(is_calendar(Some("text/calendar"), None));
+        assert!(is_calendar(Some("application/octet-stream"), Some("invite.ICS")));
+        assert!(!is_calendar(Some("text/plain"), Some("notes.txt")));
+        assert!(parse_icalendar(b"not a calendar").is_empty());
+    }
+}
@@ -43,6 +43,7 @@
 //! # }
 //! ```
 
+use crate::core::scratch::ScratchDir;
 use crate::error::{KreuzbergError, Result};
 use crate::types::LibreOfficeConversionResult;
 use std::collections::HashSet;
@@ -53,27 +54,6 @@ use tokio::fs;
 use tokio::process::Command;
 use tokio::time::{Duration, timeout};
 
-/// RAII guard for automatic temporary directory cleanup
-struct TempDir {
-    path: PathBuf,
-}
-
-impl TempDir {
-    async fn new(path: PathBuf) -> Result<Self> {
-        fs::create_dir_all(&path).await?;
-        Ok(Self { path })
-    }
-}
-
-impl Drop for TempDir {
-    fn drop(&mut self) {
-        let path = self.path.clone();
-        tokio::spawn(async move {
-            let _ = fs::remove_dir_all(&path).await;
-        });
-    }
-}
-
 /// Default timeout for LibreOffice conversion (300 seconds)
 pub const DEFAULT_CONVERSION_TIMEOUT: u64 = 300;
 
@@ -157,7 +137,7 @@ fn soffice_candidates() -> Vec<PathBuf> {
     candidates
 }
 
-fn locate_soffice_binary() -> Result<PathBuf> {
+pub(crate) fn locate_soffice_binary() -> Result<PathBuf> {
     for candidate in soffice_candidates() {
         if candidate.exists()
             && let Ok(metadata) = std_fs::metadata(&candidate)
@@ -201,9 +181,8 @@ pub async fn convert_office_doc(
 ) -> Result<Vec<u8>> {
     let soffice_path = check_libreoffice_available().await?;
 
-    let profile_dir = std::env::temp_dir().join(format!("kreuzberg_lo_profile_{}", uuid::Uuid::new_v4()));
-    let _profile_guard = TempDir::new(profile_dir.clone()).await?;
-    let user_install_arg = format!("-env:UserInstallation={}", path_to_file_uri(&profile_dir));
+    let profile_dir = ScratchDir::new()?;
+    let user_install_arg = format!("-env:UserInstallation={}", path_to_file_uri(profile_dir.path()));
 
     fs::create_dir_all(output_dir).await?;
 
@@ -237,19 +216,23 @@ pub async fn convert_office_doc(
     let output = match timeout(Duration::from_secs(timeout_seconds), child.wait_with_output()).await {
         Ok(Ok(output)) => output,
         Ok(Err(e)) => {
-            return Err(KreuzbergError::parsing(format!(
-                "Failed to wait for LibreOffice: {}",
-                e
-            )));
+            return Err(KreuzbergError::parsing_with_source(
+                format!("Failed to wait for LibreOffice: {}", e),
+                e,
+            ));
         }
         Err(_) => {
             // Timeout occurred - wait_with_output was cancelled, child is dropped and killed automatically ~keep
-            return Err(KreuzbergError::parsing(format!(
-                "LibreOffice conversion timed out after {} seconds (PID: {:?})",
-                timeout_seconds, child_id
-            )));
+            return Err(KreuzbergError::parsing_with_source(
+                format!(
+                    "LibreOffice conversion timed out after {} seconds (PID: {:?})",
+                    timeout_seconds, child_id
+                ),
+                std::io::Error::from(std::io::ErrorKind::TimedOut),
+            ));
         }
     };
+    profile_dir.measure()?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -310,19 +293,15 @@ pub async fn convert_office_doc(
 
 /// Convert .doc to .docx using LibreOffice
 pub async fn convert_doc_to_docx(doc_bytes: &[u8]) -> Result<LibreOfficeConversionResult> {
-    let temp_dir = std::env::temp_dir();
-    let unique_id = uuid::Uuid::new_v4();
-    let input_dir_path = temp_dir.join(format!("kreuzberg_doc_{}", unique_id));
-    let output_dir_path = temp_dir.join(format!("kreuzberg_doc_{}_out", unique_id));
-
     // RAII guards ensure cleanup on all paths including panic ~keep
-    let _input_guard = TempDir::new(input_dir_path.clone()).await?;
-    let _output_guard = TempDir::new(output_dir_path.clone()).await?;
+    let input_dir = ScratchDir::new()?;
+    let output_dir = ScratchDir::new()?;
 
-    let input_path = input_dir_path.join("input.doc");
-    fs::write(&input_path, doc_bytes).await?;
+    let input_path = input_dir.write_file("input.doc", doc_bytes)?;
 
-    let converted_bytes = convert_office_doc(&input_path, &output_dir_path, "docx", DEFAULT_CONVERSION_TIMEOUT).await?;
+    let converted_bytes =
+        convert_office_doc(&input_path, output_dir.path(), "docx", DEFAULT_CONVERSION_TIMEOUT).await?;
+    output_dir.measure()?;
 
     Ok(LibreOfficeConversionResult {
         converted_bytes,
@@ -334,19 +313,15 @@ pub async fn convert_doc_to_docx(doc_bytes: &[u8]) -> Result<LibreOfficeConversi
 
 /// Convert .ppt to .pptx using LibreOffice
 pub async fn convert_ppt_to_pptx(ppt_bytes: &[u8]) -> Result<LibreOfficeConversionResult> {
-    let temp_dir = std::env::temp_dir();
-    let unique_id = uuid::Uuid::new_v4();
-    let input_dir_path = temp_dir.join(format!("kreuzberg_ppt_{}", unique_id));
-    let output_dir_path = temp_dir.join(format!("kreuzberg_ppt_{}_out", unique_id));
-
     // RAII guards ensure cleanup on all paths including panic ~keep
-    let _input_guard = TempDir::new(input_dir_path.clone()).await?;
-    let _output_guard = TempDir::new(output_dir_path.clone()).await?;
+    let input_dir = ScratchDir::new()?;
+    let output_dir = ScratchDir::new()?;
 
-    let input_path = input_dir_path.join("input.ppt");
-    fs::write(&input_path, ppt_bytes).await?;
+    let input_path = input_dir.write_file("input.ppt", ppt_bytes)?;
 
-    let converted_bytes = convert_office_doc(&input_path, &output_dir_path, "pptx", DEFAULT_CONVERSION_TIMEOUT).await?;
+    let converted_bytes =
+        convert_office_doc(&input_path, output_dir.path(), "pptx", DEFAULT_CONVERSION_TIMEOUT).await?;
+    output_dir.measure()?;
 
     Ok(LibreOfficeConversionResult {
         converted_bytes,
@@ -545,18 +520,4 @@ mod tests {
         let _ = fs::remove_file(&input_path).await;
         let _ = fs::remove_dir_all(&output_dir).await;
     }
-
-    #[tokio::test]
-    async fn test_tempdir_raii_cleanup_on_error() {
-        let temp_path = std::env::temp_dir().join(format!("test_raii_{}", uuid::Uuid::new_v4()));
-
-        {
-            let _guard = TempDir::new(temp_path.clone()).await.unwrap();
-            assert!(temp_path.exists());
-        }
-
-        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-
-        assert!(!temp_path.exists() || fs::read_dir(&temp_path).await.is_err());
-    }
 }
@@ -10,6 +10,18 @@ pub mod archive;
 #[cfg(feature = "email")]
 pub mod email;
 
+#[cfg(feature = "email")]
+pub mod email_body;
+
+#[cfg(feature = "email")]
+pub mod icalendar;
+
+#[cfg(feature = "email")]
+pub mod tnef;
+
+#[cfg(feature = "pst")]
+pub mod pst;
+
 #[cfg(feature = "excel")]
 pub mod excel;
 
@@ -22,6 +34,9 @@ pub mod docx;
 #[cfg(feature = "office")]
 pub mod libreoffice;
 
+#[cfg(any(feature = "office", feature = "excel"))]
+pub mod office_encryption;
+
 #[cfg(feature = "office")]
 pub mod office_metadata;
 
@@ -34,9 +49,15 @@ pub mod table;
 #[cfg(feature = "xml")]
 pub mod xml;
 
-#[cfg(any(feature = "office", feature = "html"))]
+#[cfg(feature = "xml")]
+pub mod xpath;
+
 pub mod markdown;
 
+#[cfg(any(feature = "office", feature = "html", feature = "email"))]
+pub mod embedded_images;
+
+pub use markdown::cells_to_markdown;
 pub use structured::{JsonExtractionConfig, StructuredDataResult, parse_json, parse_toml, parse_yaml};
 pub use text::parse_text;
 
@@ -45,13 +66,34 @@ pub use image::{ImageMetadata, extract_image_metadata};
 
 #[cfg(feature = "archives")]
 pub use archive::{
-    ArchiveEntry, ArchiveMetadata, extract_7z_metadata, extract_7z_text_content, extract_tar_metadata,
-    extract_tar_text_content, extract_zip_metadata, extract_zip_text_content,
+    ArchiveEntry, ArchiveEntryResult, ArchiveEntryStatus, ArchiveFile, ArchiveFiles, ArchiveMetadata,
+    ArchiveTextContent, extract_7z_files, extract_7z_metadata, extract_7z_metadata_with_passwords,
+    extract_7z_text_content, extract_7z_text_content_with_limits, extract_tar_files, extract_tar_metadata,
+    extract_tar_text_content, extract_tar_text_content_with_limits, extract_zip_files, extract_zip_metadata,
+    extract_zip_text_content, extract_zip_text_content_with_limits, list_archive,
+};
+
+#[cfg(feature = "iso")]
+pub use archive::{extract_iso_metadata, extract_iso_text_content, extract_iso_text_content_with_limits};
+
+#[cfg(feature = "rar")]
+pub use archive::{
+    extract_rar_metadata, extract_rar_metadata_with_passwords, extract_rar_text_content,
+    extract_rar_text_content_with_limits,
 };
 
 #[cfg(feature = "email")]
 pub use email::{build_email_text_output, extract_email_content, parse_eml_content, parse_msg_content};
 
+#[cfg(feature = "email")]
+pub use email_body::sanitize_body;
+
+#[cfg(feature = "pst")]
+pub use pst::{
+    MailboxExport, MailboxMessage, extract_pst_messages, extract_pst_messages_from_bytes,
+    extract_pst_messages_from_bytes_with_limits, extract_pst_messages_with_limits,
+};
+
 #[cfg(feature = "excel")]
 pub use excel::{excel_to_markdown, read_excel_bytes, read_excel_file};
 
@@ -69,13 +111,13 @@ pub use office_metadata::{
 };
 
 #[cfg(feature = "office")]
-pub use pptx::{extract_pptx_from_bytes, extract_pptx_from_path};
+pub use pptx::{
+    SlideRange, extract_pptx_from_bytes, extract_pptx_from_bytes_with_options, extract_pptx_from_path,
+    extract_pptx_from_path_with_options,
+};
 
 #[cfg(feature = "excel")]
 pub use table::table_from_arrow_to_markdown;
 
 #[cfg(feature = "xml")]
-pub use xml::parse_xml;
-
-#[cfg(any(feature = "office", feature = "html"))]
-pub use markdown::cells_to_markdown;
+pub use xml::{XmlMappedDocument, map_xml, parse_xml};
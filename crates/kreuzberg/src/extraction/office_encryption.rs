@@ -0,0 +1,508 @@
+//! Decryption of password-protected Word, Excel and PowerPoint documents.
+//!
+//! An encrypted OOXML document is not a ZIP package but an OLE compound file holding the
+//! encryption parameters in an `EncryptionInfo` stream and the encrypted package in an
+//! `EncryptedPackage` stream (MS-OFFCRYPTO). Only ECMA-376 agile encryption, the scheme of
+//! Office 2010 and later, is supported.
+//!
+//! Passwords come from [`OfficeConfig::passwords`](crate::core::config::OfficeConfig). The
+//! password Office uses for documents that are encrypted without a user password is always
+//! tried first, so such documents open without configuration.
+
+use crate::core::config::ExtractionConfig;
+use crate::{KreuzbergError, Result};
+use base64::prelude::*;
+use cbc::cipher::block_padding::NoPadding;
+use cbc::cipher::{BlockCipher, BlockDecryptMut, KeyInit, KeyIvInit};
+use sha2::Digest;
+use std::borrow::Cow;
+use std::io::{Cursor, Read, Seek};
+use std::path::Path;
+
+const CFB_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+/// Password Office uses for documents that are encrypted without a user password
+const DEFAULT_PASSWORD: &str = "VelvetSweatshop";
+
+const ENCRYPTION_NAMESPACE: &str = "http://schemas.microsoft.com/office/2006/encryption";
+const PASSWORD_NAMESPACE: &str = "http://schemas.microsoft.com/office/2006/keyEncryptor/password";
+
+/// The encrypted package is split into segments that are encrypted separately.
+const SEGMENT_LENGTH: usize = 4096;
+
+const AES_BLOCK_SIZE: usize = 16;
+/// Upper bound of `spinCount` (MS-OFFCRYPTO 2.3.4.11); larger values would stall the password hash.
+const MAX_SPIN_COUNT: u32 = 10_000_000;
+
+/// Block keys deriving the keys of the password verifier and the package key.
+const VERIFIER_INPUT_BLOCK_KEY: [u8; 8] = [0xFE, 0xA7, 0xD2, 0x76, 0x3B, 0x4B, 0x9E, 0x79];
+const VERIFIER_VALUE_BLOCK_KEY: [u8; 8] = [0xD7, 0xAA, 0x0F, 0x6D, 0x30, 0x61, 0x34, 0x4E];
+const KEY_VALUE_BLOCK_KEY: [u8; 8] = [0x14, 0x6E, 0x0B, 0xE7, 0xAB, 0xAC, 0xD0, 0xD6];
+
+/// Whether `content` is an encrypted OOXML document.
+pub fn is_encrypted(content: &[u8]) -> bool {
+    content.starts_with(&CFB_SIGNATURE) && has_encrypted_package(Cursor::new(content))
+}
+
+/// Whether the file at `path` is an encrypted OOXML document.
+pub fn is_encrypted_file(path: &Path) -> Result<bool> {
+    let mut file = std::fs::File::open(path)?;
+    let mut signature = [0u8; 8];
+    if file.read_exact(&mut signature).is_err() || signature != CFB_SIGNATURE {
+        return Ok(false);
+    }
+    file.rewind()?;
+    Ok(has_encrypted_package(file))
+}
+
+/// Decrypt `content` with the passwords of `config.office` if it is an encrypted OOXML
+/// document; other content is returned as is.
+pub fn decrypt_if_encrypted<'a>(content: &'a [u8], config: &ExtractionConfig) -> Result<Cow<'a, [u8]>> {
+    if !is_encrypted(content) {
+        return Ok(Cow::Borrowed(content));
+    }
+    let passwords = config
+        .office
+        .as_ref()
+        .and_then(|office| office.passwords.as_deref())
+        .unwrap_or_default();
+    decrypt(content, passwords).map(Cow::Owned)
+}
+
+/// Decrypt an encrypted OOXML document into its ZIP package.
+///
+/// Fails with `KreuzbergError::EncryptedDocument` when none of `passwords` opens the document,
+/// and with `KreuzbergError::UnsupportedFormat` for encryption schemes other than agile
+/// encryption.
+pub fn decrypt(content: &[u8], passwords: &[String]) -> Result<Vec<u8>> {
+    let mut file = cfb::CompoundFile::open(Cursor::new(content))
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to open encrypted document: {}", e)))?;
+    let info = AgileEncryptionInfo::parse(&read_stream(&mut file, "/EncryptionInfo")?)?;
+    let package = read_stream(&mut file, "/EncryptedPackage")?;
+
+    let configured = passwords.iter().map(String::as_str);
+    for password in std::iter::once(DEFAULT_PASSWORD).chain(configured) {
+        if let Some(key) = info.package_key(password)? {
+            return info.decrypt_package(&key, &package);
+        }
+    }
+
+    let message = if passwords.is_empty() {
+        "the document is password-protected and no passwords are configured in office.passwords".to_string()
+    } else {
+        format!(
+            "none of the {} configured passwords opens the document",
+            passwords.len()
+        )
+    };
+    Err(KreuzbergError::EncryptedDocument(message))
+}
+
+fn has_encrypted_package<F: Read + Seek>(inner: F) -> bool {
+    let Ok(file) = cfb::CompoundFile::open(inner) else {
+        return false;
+    };
+    file.is_stream("/EncryptionInfo") && file.is_stream("/EncryptedPackage")
+}
+
+fn read_stream<F: Read + Seek>(file: &mut cfb::CompoundFile<F>, name: &str) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+    file.open_stream(name)
+        .and_then(|mut stream| stream.read_to_end(&mut data))
+        .map_err(|e| KreuzbergError::parsing(format!("Failed to read {} stream: {}", name, e)))?;
+    Ok(data)
+}
+
+/// Parameters of ECMA-376 agile encryption with a password key encryptor.
+struct AgileEncryptionInfo {
+    /// Encryption of the package (`keyData`)
+    package: CipherParams,
+    /// Encryption of the package key with the password (`p:encryptedKey`)
+    password: CipherParams,
+    spin_count: u32,
+    encrypted_verifier_hash_input: Vec<u8>,
+    encrypted_verifier_hash_value: Vec<u8>,
+    encrypted_key_value: Vec<u8>,
+}
+
+struct CipherParams {
+    salt: Vec<u8>,
+    key_length: usize,
+    hash: HashAlgorithm,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum HashAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl AgileEncryptionInfo {
+    fn parse(info: &[u8]) -> Result<Self> {
+        let Some(&[major_low, major_high, minor_low, minor_high, ..]) = info.get(..8) else {
+            return Err(KreuzbergError::parsing("Truncated EncryptionInfo stream"));
+        };
+        let version = (
+            u16::from_le_bytes([major_low, major_high]),
+            u16::from_le_bytes([minor_low, minor_high]),
+        );
+        if version != (4, 4) {
+            return Err(KreuzbergError::UnsupportedFormat(format!(
+                "Office encryption version {}.{}; only agile encryption (4.4) is supported",
+                version.0, version.1
+            )));
+        }
+
+        let xml = std::str::from_utf8(&info[8..])
+            .map_err(|e| KreuzbergError::parsing(format!("EncryptionInfo is not UTF-8: {}", e)))?;
+        let doc = roxmltree::Document::parse(xml.trim_start_matches('\u{feff}').trim_end_matches('\0'))
+            .map_err(|e| KreuzbergError::parsing(format!("Invalid EncryptionInfo XML: {}", e)))?;
+
+        let key_data = doc
+            .descendants()
+            .find(|node| node.has_tag_name((ENCRYPTION_NAMESPACE, "keyData")))
+            .ok_or_else(|| KreuzbergError::parsing("EncryptionInfo has no keyData"))?;
+        let Some(encrypted_key) = doc
+            .descendants()
+            .find(|node| node.has_tag_name((PASSWORD_NAMESPACE, "encryptedKey")))
+        else {
+            return Err(KreuzbergError::EncryptedDocument(
+                "the document is encrypted for certificates, not with a password".to_string(),
+            ));
+        };
+
+        let spin_count = number(encrypted_key, "spinCount")?;
+        if spin_count > MAX_SPIN_COUNT {
+            return Err(KreuzbergError::parsing(format!(
+                "spinCount {} in EncryptionInfo exceeds the maximum of {}",
+                spin_count, MAX_SPIN_COUNT
+            )));
+        }
+
+        Ok(Self {
+            package: CipherParams::parse(key_data)?,
+            password: CipherParams::parse(encrypted_key)?,
+            spin_count,
+            encrypted_verifier_hash_input: base64_attribute(encrypted_key, "encryptedVerifierHashInput")?,
+            encrypted_verifier_hash_value: base64_attribute(encrypted_key, "encryptedVerifierHashValue")?,
+            encrypted_key_value: base64_attribute(encrypted_key, "encryptedKeyValue")?,
+        })
+    }
+
+    /// The key of the package if `password` is correct.
+    fn package_key(&self, password: &str) -> Result<Option<Vec<u8>>> {
+        let password: Vec<u8> = password.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let params = &self.password;
+        let password_hash = params.hash.password_hash(&params.salt, &password, self.spin_count);
+        let decrypt = |block_key: &[u8], data: &[u8]| {
+            let key = fit(params.hash.digest(&[&password_hash, block_key]), params.key_length);
+            aes_cbc_decrypt(&key, &fit(params.salt.clone(), AES_BLOCK_SIZE), data.to_vec())
+        };
+
+        let mut verifier_input = decrypt(&VERIFIER_INPUT_BLOCK_KEY, &self.encrypted_verifier_hash_input)?;
+        verifier_input.truncate(params.salt.len());
+        let verifier_hash = decrypt(&VERIFIER_VALUE_BLOCK_KEY, &self.encrypted_verifier_hash_value)?;
+        let expected_hash = params.hash.digest(&[&verifier_input]);
+        if verifier_hash.get(..expected_hash.len()) != Some(expected_hash.as_slice()) {
+            return Ok(None);
+        }
+
+        let key = decrypt(&KEY_VALUE_BLOCK_KEY, &self.encrypted_key_value)?;
+        Ok(Some(fit(key, self.package.key_length)))
+    }
+
+    fn decrypt_package(&self, key: &[u8], package: &[u8]) -> Result<Vec<u8>> {
+        let Some((size, segments)) = package.split_first_chunk::<8>() else {
+            return Err(KreuzbergError::parsing("Truncated EncryptedPackage stream"));
+        };
+        let size = usize::try_from(u64::from_le_bytes(*size)).unwrap_or(usize::MAX);
+
+        let mut output = Vec::with_capacity(segments.len());
+        for (index, segment) in segments.chunks(SEGMENT_LENGTH).enumerate() {
+            let index = (index as u32).to_le_bytes();
+            let iv = fit(self.package.hash.digest(&[&self.package.salt, &index]), AES_BLOCK_SIZE);
+            let mut segment = segment.to_vec();
+            // Only a damaged package has a last segment that is not a whole number of blocks.
+            segment.resize(segment.len().next_multiple_of(AES_BLOCK_SIZE), 0);
+            output.extend(aes_cbc_decrypt(key, &iv, segment)?);
+        }
+
+        if output.len() < size {
+            return Err(KreuzbergError::parsing("Truncated EncryptedPackage stream"));
+        }
+        output.truncate(size);
+        Ok(output)
+    }
+}
+
+impl CipherParams {
+    fn parse(node: roxmltree::Node<'_, '_>) -> Result<Self> {
+        let cipher = attribute(node, "cipherAlgorithm")?;
+        let chaining = attribute(node, "cipherChaining")?;
+        if cipher != "AES" || chaining != "ChainingModeCBC" {
+            return Err(KreuzbergError::UnsupportedFormat(format!(
+                "Office encryption with {} in {}",
+                cipher, chaining
+            )));
+        }
+        if number::<usize>(node, "blockSize")? != AES_BLOCK_SIZE {
+            return Err(KreuzbergError::parsing("Invalid AES block size in EncryptionInfo"));
+        }
+
+        Ok(Self {
+            salt: base64_attribute(node, "saltValue")?,
+            key_length: number::<usize>(node, "keyBits")? / 8,
+            hash: HashAlgorithm::parse(attribute(node, "hashAlgorithm")?)?,
+        })
+    }
+}
+
+impl HashAlgorithm {
+    fn parse(name: &str) -> Result<Self> {
+        match name {
+            "SHA1" | "SHA-1" => Ok(Self::Sha1),
+            "SHA256" | "SHA-256" => Ok(Self::Sha256),
+            "SHA384" | "SHA-384" => Ok(Self::Sha384),
+            "SHA512" | "SHA-512" => Ok(Self::Sha512),
+            other => Err(KreuzbergError::UnsupportedFormat(format!(
+                "Office encryption with the {} hash algorithm",
+                other
+            ))),
+        }
+    }
+
+    fn digest(self, parts: &[&[u8]]) -> Vec<u8> {
+        match self {
+            Self::Sha1 => digest::<sha1::Sha1>(parts),
+            Self::Sha256 => digest::<sha2::Sha256>(parts),
+            Self::Sha384 => digest::<sha2::Sha384>(parts),
+            Self::Sha512 => digest::<sha2::Sha512>(parts),
+        }
+    }
+
+    /// The password hash iterated `spin_count` times (MS-OFFCRYPTO 2.3.4.11).
+    fn password_hash(self, salt: &[u8], password: &[u8], spin_count: u32) -> Vec<u8> {
+        match self {
+            Self::Sha1 => password_hash::<sha1::Sha1>(salt, password, spin_count),
+            Self::Sha256 => password_hash::<sha2::Sha256>(salt, password, spin_count),
+            Self::Sha384 => password_hash::<sha2::Sha384>(salt, password, spin_count),
+            Self::Sha512 => password_hash::<sha2::Sha512>(salt, password, spin_count),
+        }
+    }
+}
+
+fn digest<D: Digest>(parts: &[&[u8]]) -> Vec<u8> {
+    let mut hasher = D::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().to_vec()
+}
+
+fn password_hash<D: Digest>(salt: &[u8], password: &[u8], spin_count: u32) -> Vec<u8> {
+    let mut hash = D::new().chain_update(salt).chain_update(password).finalize();
+    for iteration in 0..spin_count {
+        let mut hasher = D::new();
+        hasher.update(iteration.to_le_bytes());
+        hasher.update(&hash);
+        hash = hasher.finalize();
+    }
+    hash.to_vec()
+}
+
+/// Truncate `bytes` to `length`, or pad them with `0x36` as MS-OFFCRYPTO specifies.
+fn fit(mut bytes: Vec<u8>, length: usize) -> Vec<u8> {
+    bytes.resize(length, 0x36);
+    bytes
+}
+
+/// Decrypt AES-CBC `data`, which must be a whole number of blocks.
+fn aes_cbc_decrypt(key: &[u8], iv: &[u8], mut data: Vec<u8>) -> Result<Vec<u8>> {
+    match key.len() {
+        16 => cbc_decrypt::<aes::Aes128>(key, iv, &mut data)?,
+        24 => cbc_decrypt::<aes::Aes192>(key, iv, &mut data)?,
+        32 => cbc_decrypt::<aes::Aes256>(key, iv, &mut data)?,
+        other => {
+            return Err(KreuzbergError::UnsupportedFormat(format!(
+                "Office encryption with {}-bit AES keys",
+                other * 8
+            )));
+        }
+    }
+    Ok(data)
+}
+
+fn cbc_decrypt<C>(key: &[u8], iv: &[u8], data: &mut [u8]) -> Result<()>
+where
+    C: BlockCipher + BlockDecryptMut + KeyInit,
+{
+    cbc::Decryptor::<C>::new_from_slices(key, iv)
+        .map_err(|_| KreuzbergError::parsing("Invalid AES key or IV length in EncryptionInfo"))?
+        .decrypt_padded_mut::<NoPadding>(data)
+        .map_err(|_| KreuzbergError::parsing("Encrypted data is not a whole number of AES blocks"))?;
+    Ok(())
+}
+
+fn attribute<'a>(node: roxmltree::Node<'a, '_>, name: &str) -> Result<&'a str> {
+    node.attribute(name)
+        .ok_or_else(|| KreuzbergError::parsing(format!("EncryptionInfo is missing {}", name)))
+}
+
+fn number<T: std::str::FromStr>(node: roxmltree::Node<'_, '_>, name: &str) -> Result<T> {
+    attribute(node, name)?
+        .parse()
+        .map_err(|_| KreuzbergError::parsing(format!("Invalid {} in EncryptionInfo", name)))
+}
+
+fn base64_attribute(node: roxmltree::Node<'_, '_>, name: &str) -> Result<Vec<u8>> {
+    BASE64_STANDARD
+        .decode(attribute(node, name)?)
+        .map_err(|e| KreuzbergError::parsing(format!("Invalid {} in EncryptionInfo: {}", name, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cbc::cipher::BlockEncryptMut;
+    use std::io::Write;
+
+    const KEY_SALT: [u8; 16] = [1; 16];
+    const PASSWORD_SALT: [u8; 16] = [2; 16];
+    const PACKAGE_KEY: [u8; 32] = [3; 32];
+    const SPIN_COUNT: u32 = 10;
+
+    fn aes_cbc_encrypt(key: &[u8], iv: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut buffer = data.to_vec();
+        let length = buffer.len();
+        cbc::Encryptor::<aes::Aes256>::new_from_slices(key, iv)
+            .unwrap()
+            .encrypt_padded_mut::<NoPadding>(&mut buffer, length)
+            .unwrap();
+        buffer
+    }
+
+    /// Encrypt `package` with agile encryption (AES-256, SHA-512) the way Office does.
+    fn encrypt(package: &[u8], password: &str) -> Vec<u8> {
+        let hash = HashAlgorithm::Sha512;
+        let password: Vec<u8> = password.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let password_hash = hash.password_hash(&PASSWORD_SALT, &password, SPIN_COUNT);
+        let encrypt_with = |block_key: &[u8], data: &[u8]| {
+            let key = fit(hash.digest(&[&password_hash, block_key]), 32);
+            BASE64_STANDARD.encode(aes_cbc_encrypt(&key, &PASSWORD_SALT, data))
+        };
+        let verifier_input = [4u8; 16];
+        let verifier_hash = hash.digest(&[&verifier_input]);
+
+        let mut encrypted_package = (package.len() as u64).to_le_bytes().to_vec();
+        for (index, segment) in package.chunks(SEGMENT_LENGTH).enumerate() {
+            let iv = fit(hash.digest(&[&KEY_SALT, &(index as u32).to_le_bytes()]), 16);
+            let mut segment = segment.to_vec();
+            segment.resize(segment.len().next_multiple_of(16), 0);
+            encrypted_package.extend(aes_cbc_encrypt(&PACKAGE_KEY, &iv, &segment));
+        }
+
+        let xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<encryption xmlns="{ENCRYPTION_NAMESPACE}" xmlns:p="{PASSWORD_NAMESPACE}">
+  <keyData saltSize="16" blockSize="16" keyBits="256" hashSize="64" cipherAlgorithm="AES"
+    cipherChaining="ChainingModeCBC" hashAlgorithm="SHA512" saltValue="{}"/>
+  <keyEncryptors>
+    <keyEncryptor uri="{PASSWORD_NAMESPACE}">
+      <p:encryptedKey spinCount="{SPIN_COUNT}" saltSize="16" blockSize="16" keyBits="256" hashSize="64"
+        cipherAlgorithm="AES" cipherChaining="ChainingModeCBC" hashAlgorithm="SHA512" saltValue="{}"
+        encryptedVerifierHashInput="{}" encryptedVerifierHashValue="{}" encryptedKeyValue="{}"/>
+    </keyEncryptor>
+  </keyEncryptors>
+</encryption>"#,
+            BASE64_STANDARD.encode(KEY_SALT),
+            BASE64_STANDARD.encode(PASSWORD_SALT),
+            encrypt_with(&VERIFIER_INPUT_BLOCK_KEY, &verifier_input),
+            encrypt_with(&VERIFIER_VALUE_BLOCK_KEY, &verifier_hash),
+            encrypt_with(&KEY_VALUE_BLOCK_KEY, &PACKAGE_KEY),
+        );
+        let mut info = vec![4, 0, 4, 0, 0x40, 0, 0, 0];
+        info.extend_from_slice(xml.as_bytes());
+
+        let mut file = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        file.create_stream("/EncryptionInfo").unwrap().write_all(&info).unwrap();
+        file.create_stream("/EncryptedPackage")
+            .unwrap()
+            .write_all(&encrypted_package)
+            .unwrap();
+        file.flush().unwrap();
+        file.into_inner().into_inner()
+    }
+
+    fn package() -> Vec<u8> {
+        (0..10_000u32).map(|i| (i % 251) as u8).collect()
+    }
+
+    fn passwords(passwords: &[&str]) -> Vec<String> {
+        passwords.iter().map(|password| password.to_string()).collect()
+    }
+
+    #[test]
+    fn test_decrypt_with_configured_password() {
+        let encrypted = encrypt(&package(), "s3cret");
+
+        assert!(is_encrypted(&encrypted));
+        let decrypted = decrypt(&encrypted, &passwords(&["wrong", "s3cret"])).unwrap();
+        assert_eq!(decrypted, package());
+    }
+
+    #[test]
+    fn test_decrypt_without_user_password() {
+        let encrypted = encrypt(&package(), DEFAULT_PASSWORD);
+
+        assert_eq!(decrypt(&encrypted, &[]).unwrap(), package());
+    }
+
+    #[test]
+    fn test_wrong_passwords_fail_with_encrypted_document() {
+        let encrypted = encrypt(&package(), "s3cret");
+
+        let err = decrypt(&encrypted, &passwords(&["wrong"])).unwrap_err();
+        assert!(matches!(err, KreuzbergError::EncryptedDocument(_)));
+        let err = decrypt(&encrypted, &[]).unwrap_err();
+        assert!(err.to_string().contains("office.passwords"));
+    }
+
+    #[test]
+    fn test_spin_count_above_maximum_is_rejected() {
+        let encrypted = encrypt(&package(), "s3cret");
+        let mut file = cfb::CompoundFile::open(Cursor::new(encrypted)).unwrap();
+        let info = read_stream(&mut file, "/EncryptionInfo").unwrap();
+        let info = String::from_utf8(info[8..].to_vec()).unwrap().replace(
+            &format!("spinCount=\"{SPIN_COUNT}\""),
+            &format!("spinCount=\"{}\"", MAX_SPIN_COUNT + 1),
+        );
+        let info = [&[4u8, 0, 4, 0, 0x40, 0, 0, 0][..], info.as_bytes()].concat();
+
+        let err = AgileEncryptionInfo::parse(&info).err().unwrap();
+        assert!(matches!(err, KreuzbergError::Parsing { .. }));
+        assert!(err.to_string().contains("spinCount"));
+    }
+
+    #[test]
+    fn test_unencrypted_content_is_borrowed() {
+        let zip = b"PK\x05\x06\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0";
+
+        assert!(!is_encrypted(zip));
+        let content = decrypt_if_encrypted(zip, &ExtractionConfig::default()).unwrap();
+        assert!(matches!(content, Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn test_is_encrypted_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let encrypted = dir.path().join("protected.docx");
+        std::fs::write(&encrypted, encrypt(&package(), "s3cret")).unwrap();
+        let plain = dir.path().join("plain.docx");
+        std::fs::write(&plain, package()).unwrap();
+
+        assert!(is_encrypted_file(&encrypted).unwrap());
+        assert!(!is_encrypted_file(&plain).unwrap());
+    }
+}
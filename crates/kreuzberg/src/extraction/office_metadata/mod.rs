@@ -47,6 +47,18 @@ pub use odt_properties::{OdtProperties, extract_odt_properties};
 
 use roxmltree::Node;
 
+/// Split a `keywords` property into individual keywords.
+///
+/// Office applications separate keywords with commas or semicolons.
+pub(crate) fn split_keywords(keywords: &str) -> Vec<String> {
+    keywords
+        .split([',', ';'])
+        .map(str::trim)
+        .filter(|k| !k.is_empty())
+        .map(String::from)
+        .collect()
+}
+
 /// Parse text content from an XML element by tag name
 ///
 /// Returns the text content if the element exists and has non-empty text.
@@ -15,7 +15,12 @@
 //! - **Text formatting**: Preserves bold, italic, underline formatting as Markdown
 //! - **Image extraction**: Optionally extracts embedded images with metadata
 //! - **Office metadata**: Extracts core properties, custom properties (when `office` feature enabled)
-//! - **Structure preservation**: Maintains heading hierarchy and list structure
+//! - **Structure preservation**: Maintains heading hierarchy and list structure; slide titles
+//!   (title placeholders) become `#` headings
+//! - **Speaker notes and slide selection**: [`PptxConfig`] leaves out notes, extracts only the
+//!   notes, or limits extraction to a slide range
+//! - **Comments**: Legacy and modern slide comments are returned as revisions and, with
+//!   [`RevisionsConfig::render_inline`], rendered after the slide content
 //!
 //! # Supported Formats
 //!
@@ -37,11 +42,14 @@
 //! # Ok(())
 //! # }
 //! ```
+use crate::core::config::{PageConfig, PptxConfig, RevisionsConfig};
 use crate::error::{KreuzbergError, Result};
-use crate::types::{ExtractedImage, PptxExtractionResult, PptxMetadata};
-use std::collections::HashMap;
+use crate::text::segmented::SegmentedBuffer;
+use crate::types::{ExtractedImage, PptxExtractionResult, PptxMetadata, Revision, RevisionKind, RevisionStatus};
+use std::collections::{BTreeSet, HashMap};
 use std::fs::File;
 use std::io::Read;
+use std::ops::RangeInclusive;
 use std::path::Path;
 use zip::ZipArchive;
 
@@ -101,6 +109,16 @@ impl Run {
 #[derive(Debug, Clone)]
 struct TextElement {
     runs: Vec<Run>,
+    /// Whether the shape is the slide's title placeholder
+    is_title: bool,
+}
+
+impl TextElement {
+    /// The text as a single line, without formatting.
+    fn title_text(&self) -> String {
+        let text: String = self.runs.iter().map(|run| run.extract()).collect();
+        text.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -180,7 +198,7 @@ impl Default for ParserConfig {
 }
 
 struct ContentBuilder {
-    content: String,
+    content: SegmentedBuffer,
     boundaries: Vec<crate::types::PageBoundary>,
     page_contents: Vec<crate::types::PageContent>,
     config: Option<crate::core::config::PageConfig>,
@@ -189,7 +207,7 @@ struct ContentBuilder {
 impl ContentBuilder {
     fn new() -> Self {
         Self {
-            content: String::with_capacity(8192),
+            content: SegmentedBuffer::new(),
             boundaries: Vec::new(),
             page_contents: Vec::new(),
             config: None,
@@ -198,7 +216,7 @@ impl ContentBuilder {
 
     fn with_page_config(capacity: usize, config: Option<crate::core::config::PageConfig>) -> Self {
         Self {
-            content: String::with_capacity(capacity),
+            content: SegmentedBuffer::with_capacity(capacity),
             boundaries: if config.is_some() {
                 Vec::new()
             } else {
@@ -246,7 +264,6 @@ impl ContentBuilder {
     }
 
     fn add_slide_header(&mut self, slide_number: u32) {
-        self.content.reserve(50);
         self.content.push_str("\n\n<!-- Slide number: ");
         self.content.push_str(&slide_number.to_string());
         self.content.push_str(" -->\n");
@@ -312,6 +329,23 @@ impl ContentBuilder {
         self.content.push_str(")\n");
     }
 
+    fn add_comment(&mut self, comment: &Revision) {
+        self.content.push_str("\n{>>");
+        if let Some(author) = &comment.author {
+            self.content.push_str(author);
+            self.content.push_str(": ");
+        }
+        self.content.push_str(&comment.text.replace('\n', " "));
+        self.content.push_str("<<}");
+    }
+
+    fn add_notes_paragraph(&mut self, notes: &str) {
+        if !notes.is_empty() {
+            self.content.push_str(notes);
+            self.content.push_str("\n\n");
+        }
+    }
+
     fn add_notes(&mut self, notes: &str) {
         if !notes.trim().is_empty() {
             self.content.push_str("\n\n### Notes:\n");
@@ -327,7 +361,7 @@ impl ContentBuilder {
         Option<Vec<crate::types::PageBoundary>>,
         Option<Vec<crate::types::PageContent>>,
     ) {
-        let content = self.content.trim().to_string();
+        let content = self.content.into_trimmed_string();
         let boundaries = if self.config.is_some() && !self.boundaries.is_empty() {
             Some(self.boundaries)
         } else {
@@ -376,6 +410,11 @@ impl PptxContainer {
         Ok(Self { archive, slide_paths })
     }
 
+    /// Whether the package has a comments part (legacy or modern).
+    fn has_comments(&self) -> bool {
+        self.archive.file_names().any(|name| name.starts_with("ppt/comments/"))
+    }
+
     fn slide_paths(&self) -> &[String] {
         &self.slide_paths
     }
@@ -471,17 +510,12 @@ impl Slide {
 
         for &idx in &element_indices {
             match &self.elements[idx] {
+                SlideElement::Text(text, _) if text.is_title => {
+                    builder.add_title(&text.title_text());
+                }
                 SlideElement::Text(text, _) => {
                     let text_content: String = text.runs.iter().map(|run| run.render_as_md()).collect();
-
-                    let normalized = text_content.replace('\n', " ");
-                    let is_title = normalized.len() < 100 && !normalized.trim().is_empty();
-
-                    if is_title {
-                        builder.add_title(normalized.trim());
-                    } else {
-                        builder.add_text(&text_content);
-                    }
+                    builder.add_text(&text_content);
                 }
                 SlideElement::Table(table, _) => {
                     let table_rows: Vec<Vec<String>> = table
@@ -512,6 +546,17 @@ impl Slide {
         builder.build().0
     }
 
+    /// Text of the slide's title placeholder, if it has a non-empty one.
+    fn title(&self) -> Option<String> {
+        self.elements
+            .iter()
+            .find_map(|element| match element {
+                SlideElement::Text(text, _) if text.is_title => Some(text.title_text()),
+                _ => None,
+            })
+            .filter(|title| !title.is_empty())
+    }
+
     fn image_count(&self) -> usize {
         self.elements
             .iter()
@@ -567,6 +612,33 @@ impl SlideIterator {
         Ok(Some(slide))
     }
 
+    /// Like [`Self::next_slide`], but skips slides outside `range` without parsing them.
+    fn next_slide_in(&mut self, range: Option<&SlideRange>) -> Result<Option<Slide>> {
+        while self.current_index < self.total_slides
+            && range.is_some_and(|range| !range.contains((self.current_index + 1) as u32))
+        {
+            self.current_index += 1;
+        }
+        self.next_slide()
+    }
+
+    /// Comments attached to `slide` through its relationships, with author names from `authors`.
+    fn get_slide_comments(&mut self, slide: &Slide, authors: &HashMap<String, String>) -> Vec<Revision> {
+        let slide_path = self.container.slide_paths()[slide.slide_number as usize - 1].clone();
+        let rels_path = self.container.get_slide_rels_path(&slide_path);
+        let Ok(rels_data) = self.container.read_file(&rels_path) else {
+            return Vec::new();
+        };
+
+        let mut comments = Vec::new();
+        for target in parse_comment_targets(&rels_data) {
+            if let Ok(comments_xml) = self.container.read_file(&get_full_image_path(&slide_path, &target)) {
+                comments.extend(parse_slide_comments(&comments_xml, slide.slide_number, authors));
+            }
+        }
+        comments
+    }
+
     fn get_slide_images(&mut self, slide: &Slide) -> Result<HashMap<String, Vec<u8>>> {
         let mut image_data = HashMap::new();
 
@@ -675,7 +747,11 @@ fn parse_sp(sp_node: &Node) -> Result<ParsedContent> {
                 }))
     });
 
-    if is_list {
+    if is_title_placeholder(sp_node) {
+        let mut title = parse_text(&tx_body_node)?;
+        title.is_title = true;
+        Ok(ParsedContent::Text(title))
+    } else if is_list {
         Ok(ParsedContent::List(parse_list(&tx_body_node)?))
     } else {
         Ok(ParsedContent::Text(parse_text(&tx_body_node)?))
@@ -693,7 +769,16 @@ fn parse_text(tx_body_node: &Node) -> Result<TextElement> {
         runs.append(&mut paragraph_runs);
     }
 
-    Ok(TextElement { runs })
+    Ok(TextElement { runs, is_title: false })
+}
+
+/// Whether a shape is a title placeholder (`<p:ph type="title"/>` or `type="ctrTitle"`).
+fn is_title_placeholder(sp_node: &Node) -> bool {
+    sp_node
+        .descendants()
+        .find(|n| n.is_element() && n.tag_name().name() == "ph" && n.tag_name().namespace() == Some(P_NAMESPACE))
+        .and_then(|ph| ph.attribute("type"))
+        .is_some_and(|ph_type| ph_type == "title" || ph_type == "ctrTitle")
 }
 
 fn parse_graphic_frame(node: &Node) -> Result<Option<TableElement>> {
@@ -922,6 +1007,93 @@ fn parse_slide_rels(rels_data: &[u8]) -> Result<Vec<ImageReference>> {
     Ok(images)
 }
 
+/// Targets of a slide's comment relationships (legacy and modern comments).
+fn parse_comment_targets(rels_data: &[u8]) -> Vec<String> {
+    let xml_str = String::from_utf8_lossy(rels_data);
+    let Ok(doc) = Document::parse(&xml_str) else {
+        return Vec::new();
+    };
+
+    doc.descendants()
+        .filter(|node| node.has_tag_name("Relationship"))
+        .filter(|node| node.attribute("Type").is_some_and(|t| t.ends_with("/comments")))
+        .filter_map(|node| node.attribute("Target"))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Comment author names by id, from `commentAuthors.xml` (legacy comments) and `authors.xml`
+/// (modern comments).
+fn extract_comment_authors(container: &mut PptxContainer) -> HashMap<String, String> {
+    let mut authors = HashMap::new();
+    for path in ["ppt/commentAuthors.xml", "ppt/authors.xml"] {
+        let Ok(xml_data) = container.read_file(path) else {
+            continue;
+        };
+        let xml_str = String::from_utf8_lossy(&xml_data);
+        let Ok(doc) = Document::parse(&xml_str) else {
+            continue;
+        };
+        for node in doc.descendants() {
+            if (node.has_tag_name("cmAuthor") || node.has_tag_name("author"))
+                && let (Some(id), Some(name)) = (node.attribute("id"), node.attribute("name"))
+            {
+                authors.insert(id.to_string(), name.to_string());
+            }
+        }
+    }
+    authors
+}
+
+/// Comments of a legacy (`<p:cmLst>`) or modern (`<p188:cmLst>`) comments part.
+///
+/// Replies to modern comments are not included.
+fn parse_slide_comments(xml_data: &[u8], slide_number: u32, authors: &HashMap<String, String>) -> Vec<Revision> {
+    let xml_str = String::from_utf8_lossy(xml_data);
+    let Ok(doc) = Document::parse(&xml_str) else {
+        return Vec::new();
+    };
+
+    doc.root_element()
+        .children()
+        .filter(|node| node.has_tag_name("cm"))
+        .map(|cm| Revision {
+            kind: RevisionKind::Comment,
+            author: cm.attribute("authorId").and_then(|id| authors.get(id)).cloned(),
+            date: cm.attribute("dt").or(cm.attribute("created")).map(str::to_string),
+            text: comment_text(&cm),
+            anchor: None,
+            page_number: Some(slide_number as usize),
+            status: match cm.attribute("status") {
+                Some("resolved" | "closed") => RevisionStatus::Resolved,
+                _ => RevisionStatus::Pending,
+            },
+        })
+        .filter(|comment| !comment.text.is_empty())
+        .collect()
+}
+
+/// Text of a legacy comment (`<p:text>`) or a modern one (`<p188:txBody>`).
+fn comment_text(cm: &Node) -> String {
+    if let Some(text) = cm.children().find(|node| node.has_tag_name("text")) {
+        return text.text().unwrap_or_default().trim().to_string();
+    }
+
+    let paragraphs: Vec<String> = cm
+        .children()
+        .filter(|node| node.has_tag_name("txBody"))
+        .flat_map(|body| body.children().filter(|node| node.has_tag_name("p")))
+        .map(|paragraph| {
+            paragraph
+                .descendants()
+                .filter(|node| node.has_tag_name("t"))
+                .filter_map(|node| node.text())
+                .collect()
+        })
+        .collect();
+    paragraphs.join("\n").trim().to_string()
+}
+
 fn parse_presentation_rels(rels_data: &[u8]) -> Result<Vec<String>> {
     let xml_str = std::str::from_utf8(rels_data)
         .map_err(|e| KreuzbergError::parsing(format!("Invalid UTF-8 in presentation rels: {}", e)))?;
@@ -1032,7 +1204,9 @@ fn extract_metadata(archive: &mut ZipArchive<File>) -> PptxMetadata {
             }
         }
 
-        PptxMetadata { fonts: Vec::new() }
+        PptxMetadata {
+            fonts: extract_fonts(archive),
+        }
     }
 
     #[cfg(not(feature = "office"))]
@@ -1041,12 +1215,60 @@ fn extract_metadata(archive: &mut ZipArchive<File>) -> PptxMetadata {
     }
 }
 
-fn extract_all_notes(container: &mut PptxContainer) -> Result<HashMap<u32, String>> {
+/// Typefaces named by the theme, the slide masters, the slides and the embedded font list
+/// (`p:embeddedFontLst`), sorted.
+///
+/// Theme references such as `+mn-lt` are skipped, as are the per-script fallbacks of the theme
+/// font scheme (`a:font`); neither names a font the text is actually set in.
+#[cfg(feature = "office")]
+fn extract_fonts(archive: &mut ZipArchive<File>) -> Vec<String> {
+    const FONT_ELEMENTS: &[&str] = &["latin", "ea", "cs", "sym"];
+
+    let parts: Vec<String> = archive
+        .file_names()
+        .filter(|name| {
+            name.ends_with(".xml")
+                && (name.starts_with("ppt/theme/")
+                    || name.starts_with("ppt/slideMasters/")
+                    || name.starts_with("ppt/slides/")
+                    || *name == "ppt/presentation.xml")
+        })
+        .map(str::to_string)
+        .collect();
+
+    let mut fonts = BTreeSet::new();
+    for part in parts {
+        let Ok(xml_data) = PptxContainer::read_file_from_archive(archive, &part) else {
+            continue;
+        };
+        let xml_str = String::from_utf8_lossy(&xml_data);
+        let Ok(doc) = Document::parse(&xml_str) else {
+            continue;
+        };
+        for node in doc.descendants() {
+            let name = node.tag_name().name();
+            let embedded = name == "font" && node.parent_element().is_some_and(|p| p.has_tag_name("embeddedFont"));
+            if (FONT_ELEMENTS.contains(&name) || embedded)
+                && let Some(typeface) = node.attribute("typeface")
+                && !typeface.is_empty()
+                && !typeface.starts_with('+')
+            {
+                fonts.insert(typeface.to_string());
+            }
+        }
+    }
+    fonts.into_iter().collect()
+}
+
+fn extract_all_notes(container: &mut PptxContainer, range: Option<&SlideRange>) -> Result<HashMap<u32, String>> {
     let mut notes = HashMap::new();
 
     let slide_paths: Vec<String> = container.slide_paths().to_vec();
 
     for (i, slide_path) in slide_paths.iter().enumerate() {
+        if range.is_some_and(|range| !range.contains((i + 1) as u32)) {
+            continue;
+        }
         let notes_path = slide_path.replace("slides/slide", "notesSlides/notesSlide");
         if let Ok(notes_xml) = container.read_file(&notes_path)
             && let Ok(note_text) = extract_notes_text(&notes_xml)
@@ -1106,7 +1328,7 @@ fn get_full_image_path(slide_path: &str, image_target: &str) -> String {
     }
 }
 
-fn detect_image_format(data: &[u8]) -> String {
+pub(crate) fn detect_image_format(data: &[u8]) -> String {
     if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
         "jpeg".to_string()
     } else if data.starts_with(&[0x89, 0x50, 0x4E, 0x47]) {
@@ -1124,21 +1346,88 @@ fn detect_image_format(data: &[u8]) -> String {
     }
 }
 
+/// Slide numbers selected by [`PptxConfig::slide_range`], e.g. `"1-3,7"` or `"5-"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlideRange(Vec<RangeInclusive<u32>>);
+
+impl SlideRange {
+    /// Parse comma-separated 1-based slide numbers and ranges; `"5-"` runs to the last slide.
+    pub fn parse(spec: &str) -> Result<Self> {
+        let mut ranges = Vec::new();
+        for part in spec.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            let (start, end) = part.split_once('-').unwrap_or((part, part));
+            let start: u32 = start.trim().parse().map_err(|_| invalid_slide_range(spec))?;
+            let end: u32 = match end.trim() {
+                "" => u32::MAX,
+                end => end.parse().map_err(|_| invalid_slide_range(spec))?,
+            };
+            if start == 0 || end < start {
+                return Err(invalid_slide_range(spec));
+            }
+            ranges.push(start..=end);
+        }
+        if ranges.is_empty() {
+            return Err(invalid_slide_range(spec));
+        }
+        Ok(Self(ranges))
+    }
+
+    /// Whether the 1-based `slide_number` is selected.
+    pub fn contains(&self, slide_number: u32) -> bool {
+        self.0.iter().any(|range| range.contains(&slide_number))
+    }
+}
+
+fn invalid_slide_range(spec: &str) -> KreuzbergError {
+    KreuzbergError::validation(format!(
+        "Invalid slide range \"{}\": expected 1-based slide numbers and ranges such as \"1-3,7\"",
+        spec
+    ))
+}
+
 pub fn extract_pptx_from_path(
     path: &str,
     extract_images: bool,
-    page_config: Option<&crate::core::config::PageConfig>,
+    page_config: Option<&PageConfig>,
+) -> Result<PptxExtractionResult> {
+    extract_pptx_from_path_with_options(path, extract_images, page_config, &PptxConfig::default(), None)
+}
+
+/// Extract a PPTX file with [`PptxConfig`] options for speaker notes and slide selection.
+///
+/// Slide comments are always returned in `revisions`; `revisions_config` can also render them
+/// after the slide content. `slide_count` is always the number of slides in the presentation,
+/// also when `slide_range` selects fewer.
+pub fn extract_pptx_from_path_with_options(
+    path: &str,
+    extract_images: bool,
+    page_config: Option<&PageConfig>,
+    options: &PptxConfig,
+    revisions_config: Option<&RevisionsConfig>,
 ) -> Result<PptxExtractionResult> {
     let config = ParserConfig {
         extract_images,
         ..Default::default()
     };
+    let slide_range = options.slide_range.as_deref().map(SlideRange::parse).transpose()?;
 
     let mut container = PptxContainer::open(path)?;
 
     let metadata = extract_metadata(&mut container.archive);
 
-    let notes = extract_all_notes(&mut container)?;
+    let notes = if options.include_notes || options.notes_only {
+        extract_all_notes(&mut container, slide_range.as_ref())?
+    } else {
+        HashMap::new()
+    };
+
+    let has_comments = container.has_comments();
+    let comment_authors = if has_comments {
+        extract_comment_authors(&mut container)
+    } else {
+        HashMap::new()
+    };
+    let render_comments = revisions_config.is_some_and(|cfg| cfg.render_inline);
 
     let mut iterator = SlideIterator::new(container);
     let slide_count = iterator.slide_count();
@@ -1149,23 +1438,49 @@ pub fn extract_pptx_from_path(
     let mut total_image_count = 0;
     let mut total_table_count = 0;
     let mut extracted_images = Vec::new();
+    let mut slide_titles = HashMap::new();
+    let mut revisions = Vec::new();
 
-    while let Some(slide) = iterator.next_slide()? {
+    while let Some(slide) = iterator.next_slide_in(slide_range.as_ref())? {
         let byte_start = if page_config.is_some() {
             content_builder.start_slide(slide.slide_number)
         } else {
             0
         };
 
-        let slide_content = slide.to_markdown(&config);
-        content_builder.add_text(&slide_content);
+        let slide_comments = if has_comments {
+            iterator.get_slide_comments(&slide, &comment_authors)
+        } else {
+            Vec::new()
+        };
 
-        if let Some(slide_notes) = notes.get(&slide.slide_number) {
-            content_builder.add_notes(slide_notes);
-        }
+        let slide_content = if options.notes_only {
+            let slide_notes = notes.get(&slide.slide_number).map_or("", |text| text.trim());
+            content_builder.add_notes_paragraph(slide_notes);
+            slide_notes.to_string()
+        } else {
+            let slide_content = slide.to_markdown(&config);
+            content_builder.add_text(&slide_content);
+            if render_comments {
+                for comment in &slide_comments {
+                    content_builder.add_comment(comment);
+                }
+            }
+            if options.include_notes
+                && let Some(slide_notes) = notes.get(&slide.slide_number)
+            {
+                content_builder.add_notes(slide_notes);
+            }
+            slide_content
+        };
+
+        revisions.extend(slide_comments);
 
         if page_config.is_some() {
-            content_builder.end_slide(slide.slide_number, byte_start, slide_content.clone());
+            content_builder.end_slide(slide.slide_number, byte_start, slide_content);
+            if let Some(title) = slide.title() {
+                slide_titles.insert(slide.slide_number as usize, title);
+            }
         }
 
         if config.extract_images
@@ -1174,6 +1489,7 @@ pub fn extract_pptx_from_path(
             for (_, data) in image_data {
                 let format = detect_image_format(&data);
                 let image_index = extracted_images.len();
+                let dpi = crate::utils::resolution::image_dpi(&data);
 
                 extracted_images.push(ExtractedImage {
                     data,
@@ -1182,6 +1498,7 @@ pub fn extract_pptx_from_path(
                     page_number: Some(slide.slide_number as usize),
                     width: None,
                     height: None,
+                    dpi,
                     colorspace: None,
                     bits_per_component: None,
                     is_mask: false,
@@ -1205,7 +1522,7 @@ pub fn extract_pptx_from_path(
             pcs.iter()
                 .map(|pc| crate::types::PageInfo {
                     number: pc.page_number,
-                    title: None,
+                    title: slide_titles.get(&pc.page_number).cloned(),
                     dimensions: None,
                     image_count: None,
                     table_count: None,
@@ -1224,35 +1541,38 @@ pub fn extract_pptx_from_path(
         images: extracted_images,
         page_structure,
         page_contents,
+        revisions,
     })
 }
 
 pub fn extract_pptx_from_bytes(
     data: &[u8],
     extract_images: bool,
-    page_config: Option<&crate::core::config::PageConfig>,
+    page_config: Option<&PageConfig>,
 ) -> Result<PptxExtractionResult> {
-    use std::sync::atomic::{AtomicU64, Ordering};
-    static COUNTER: AtomicU64 = AtomicU64::new(0);
-    let unique_id = COUNTER.fetch_add(1, Ordering::SeqCst);
-    let temp_path = std::env::temp_dir().join(format!("temp_pptx_{}_{}.pptx", std::process::id(), unique_id));
+    extract_pptx_from_bytes_with_options(data, extract_images, page_config, &PptxConfig::default(), None)
+}
 
+/// Extract PPTX bytes with [`PptxConfig`] options; see [`extract_pptx_from_path_with_options`].
+pub fn extract_pptx_from_bytes_with_options(
+    data: &[u8],
+    extract_images: bool,
+    page_config: Option<&PageConfig>,
+    options: &PptxConfig,
+    revisions_config: Option<&RevisionsConfig>,
+) -> Result<PptxExtractionResult> {
     // IO errors must bubble up - temp file write issues need user reports ~keep
-    std::fs::write(&temp_path, data)?;
+    let temp_file = crate::core::scratch::ScratchFile::write(data, ".pptx")?;
 
-    let result = extract_pptx_from_path(
-        temp_path.to_str().ok_or_else(|| {
+    extract_pptx_from_path_with_options(
+        temp_file.path().to_str().ok_or_else(|| {
             crate::KreuzbergError::validation("Invalid temp path - contains invalid UTF-8".to_string())
         })?,
         extract_images,
         page_config,
-    );
-
-    if let Err(e) = std::fs::remove_file(&temp_path) {
-        tracing::warn!("Failed to remove temp PPTX file: {}", e);
-    }
-
-    result
+        options,
+        revisions_config,
+    )
 }
 
 #[cfg(test)]
@@ -1381,6 +1701,39 @@ mod tests {
         assert!(result.metadata.fonts.is_empty() || !result.metadata.fonts.is_empty());
     }
 
+    #[test]
+    fn test_extract_pptx_fonts() {
+        use std::io::Write;
+        use zip::write::{SimpleFileOptions, ZipWriter};
+
+        let pptx_bytes = create_test_pptx_bytes(vec!["Content"]);
+        let mut zip = ZipWriter::new_append(std::io::Cursor::new(pptx_bytes)).unwrap();
+        let options = SimpleFileOptions::default();
+        zip.start_file("ppt/theme/theme1.xml", options).unwrap();
+        zip.write_all(
+            br#"<a:theme xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"><a:themeElements><a:fontScheme name="Office">
+<a:majorFont><a:latin typeface="Calibri Light"/><a:ea typeface=""/><a:font script="Jpan" typeface="Yu Gothic Light"/></a:majorFont>
+<a:minorFont><a:latin typeface="Calibri"/><a:ea typeface=""/></a:minorFont>
+</a:fontScheme></a:themeElements></a:theme>"#,
+        )
+        .unwrap();
+        zip.start_file("ppt/slideMasters/slideMaster1.xml", options).unwrap();
+        zip.write_all(
+            br#"<p:sldMaster xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
+<p:txStyles><p:titleStyle><a:lvl1pPr><a:defRPr><a:latin typeface="+mj-lt"/></a:defRPr></a:lvl1pPr></p:titleStyle>
+<p:bodyStyle><a:lvl1pPr><a:defRPr><a:latin typeface="Georgia"/><a:sym typeface="Wingdings"/></a:defRPr></a:lvl1pPr></p:bodyStyle></p:txStyles>
+</p:sldMaster>"#,
+        )
+        .unwrap();
+        let deck = zip.finish().unwrap().into_inner();
+
+        let result = extract_pptx_from_bytes(&deck, false, None).unwrap();
+        assert_eq!(
+            result.metadata.fonts,
+            vec!["Calibri", "Calibri Light", "Georgia", "Wingdings"]
+        );
+    }
+
     #[test]
     fn test_extract_pptx_empty_slides() {
         let pptx_bytes = create_test_pptx_bytes(vec!["", "", ""]);
@@ -3097,4 +3450,188 @@ mod tests {
 
         let _ = &result.metadata.fonts;
     }
+
+    /// A presentation whose slides have a title placeholder, a body text box and speaker notes.
+    fn create_titled_pptx_bytes(slides: &[(&str, &str, &str)]) -> Vec<u8> {
+        use std::io::Write;
+        use zip::write::{SimpleFileOptions, ZipWriter};
+
+        const NAMESPACES: &str = r#"xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main"
+       xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main""#;
+        let shape = |placeholder: &str, text: &str| {
+            format!(
+                "<p:sp><p:nvSpPr><p:nvPr>{}</p:nvPr></p:nvSpPr>\
+                 <p:txBody><a:p><a:r><a:t>{}</a:t></a:r></a:p></p:txBody></p:sp>",
+                placeholder, text
+            )
+        };
+
+        let mut buffer = Vec::new();
+        {
+            let mut zip = ZipWriter::new(std::io::Cursor::new(&mut buffer));
+            let options = SimpleFileOptions::default();
+
+            let mut rels_xml =
+                String::from(r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#);
+            for (i, (title, body, notes)) in slides.iter().enumerate() {
+                rels_xml.push_str(&format!(
+                    r#"<Relationship Id="rId{}" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/slide" Target="slides/slide{}.xml"/>"#,
+                    i + 1,
+                    i + 1
+                ));
+                let slide_xml = format!(
+                    "<p:sld {}><p:cSld><p:spTree>{}{}</p:spTree></p:cSld></p:sld>",
+                    NAMESPACES,
+                    shape(r#"<p:ph type="title"/>"#, title),
+                    shape(r#"<p:ph type="body" idx="1"/>"#, body)
+                );
+                zip.start_file(format!("ppt/slides/slide{}.xml", i + 1), options)
+                    .unwrap();
+                zip.write_all(slide_xml.as_bytes()).unwrap();
+                let notes_xml = format!(
+                    "<p:notes {}><p:cSld><p:spTree>{}</p:spTree></p:cSld></p:notes>",
+                    NAMESPACES,
+                    shape(r#"<p:ph type="body" idx="1"/>"#, notes)
+                );
+                zip.start_file(format!("ppt/notesSlides/notesSlide{}.xml", i + 1), options)
+                    .unwrap();
+                zip.write_all(notes_xml.as_bytes()).unwrap();
+            }
+            rels_xml.push_str("</Relationships>");
+            zip.start_file("ppt/_rels/presentation.xml.rels", options).unwrap();
+            zip.write_all(rels_xml.as_bytes()).unwrap();
+            zip.start_file("ppt/presentation.xml", options).unwrap();
+            zip.write_all(b"<presentation/>").unwrap();
+
+            let _ = zip.finish().unwrap();
+        }
+        buffer
+    }
+
+    fn titled_deck() -> Vec<u8> {
+        create_titled_pptx_bytes(&[
+            ("Agenda", "Short body", "Welcome everyone"),
+            ("Results", "Revenue grew", "Mention the new region"),
+            ("Outlook", "Next year", "Keep it brief"),
+        ])
+    }
+
+    fn extract_titled_deck(options: &PptxConfig) -> PptxExtractionResult {
+        extract_pptx_from_bytes_with_options(&titled_deck(), false, None, options, None).unwrap()
+    }
+
+    #[test]
+    fn test_title_placeholders_become_headings() {
+        let page_config = PageConfig {
+            extract_pages: true,
+            ..Default::default()
+        };
+        let deck = titled_deck();
+        let result = extract_pptx_from_bytes(&deck, false, Some(&page_config)).unwrap();
+
+        assert!(result.content.starts_with("# Agenda\nShort body"));
+        assert!(result.content.contains("# Results\nRevenue grew"));
+        assert!(!result.content.contains("# Short body"));
+        assert!(result.content.contains("### Notes:\nMention the new region"));
+
+        let pages = result.page_structure.unwrap().pages.unwrap();
+        let titles: Vec<_> = pages.iter().map(|page| page.title.as_deref()).collect();
+        assert_eq!(titles, vec![Some("Agenda"), Some("Results"), Some("Outlook")]);
+    }
+
+    #[test]
+    fn test_pptx_config_notes() {
+        let without_notes = PptxConfig {
+            include_notes: false,
+            ..Default::default()
+        };
+        let result = extract_titled_deck(&without_notes);
+        assert!(result.content.contains("Revenue grew"));
+        assert!(!result.content.contains("Notes:"));
+        assert!(!result.content.contains("Mention the new region"));
+
+        let notes_only = PptxConfig {
+            notes_only: true,
+            ..Default::default()
+        };
+        let expected = "Welcome everyone\n\nMention the new region\n\nKeep it brief";
+        assert_eq!(extract_titled_deck(&notes_only).content, expected);
+    }
+
+    #[test]
+    fn test_pptx_config_slide_range() {
+        let options = PptxConfig {
+            slide_range: Some("2-".to_string()),
+            ..Default::default()
+        };
+        let result = extract_titled_deck(&options);
+
+        assert_eq!(result.slide_count, 3);
+        assert!(!result.content.contains("Agenda"));
+        assert!(!result.content.contains("Welcome everyone"));
+        assert!(result.content.contains("# Results"));
+        assert!(result.content.contains("Keep it brief"));
+    }
+
+    #[test]
+    fn test_slide_comments() {
+        use std::io::Write;
+        use zip::write::{SimpleFileOptions, ZipWriter};
+
+        let mut zip = ZipWriter::new_append(std::io::Cursor::new(titled_deck())).unwrap();
+        let options = SimpleFileOptions::default();
+        zip.start_file("ppt/slides/_rels/slide2.xml.rels", options).unwrap();
+        zip.write_all(
+            br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/comments" Target="../comments/comment1.xml"/>
+</Relationships>"#,
+        )
+        .unwrap();
+        zip.start_file("ppt/commentAuthors.xml", options).unwrap();
+        zip.write_all(
+            br#"<p:cmAuthorLst xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
+<p:cmAuthor id="0" name="Dana" initials="D" lastIdx="1" clrIdx="0"/>
+</p:cmAuthorLst>"#,
+        )
+        .unwrap();
+        zip.start_file("ppt/comments/comment1.xml", options).unwrap();
+        zip.write_all(
+            br#"<p:cmLst xmlns:p="http://schemas.openxmlformats.org/presentationml/2006/main">
+<p:cm authorId="0" dt="2024-03-01T10:00:00.000" idx="1"><p:pos x="10" y="10"/><p:text>Check these numbers</p:text></p:cm>
+</p:cmLst>"#,
+        )
+        .unwrap();
+        let deck = zip.finish().unwrap().into_inner();
+
+        let result = extract_pptx_from_bytes(&deck, false, None).unwrap();
+        assert_eq!(result.revisions.len(), 1);
+        let comment = &result.revisions[0];
+        assert_eq!(comment.kind, RevisionKind::Comment);
+        assert_eq!(comment.author.as_deref(), Some("Dana"));
+        assert_eq!(comment.date.as_deref(), Some("2024-03-01T10:00:00.000"));
+        assert_eq!(comment.text, "Check these numbers");
+        assert_eq!(comment.page_number, Some(2));
+        assert!(!result.content.contains("Check these numbers"));
+
+        let defaults = PptxConfig::default();
+        let render = RevisionsConfig { render_inline: true };
+        let result = extract_pptx_from_bytes_with_options(&deck, false, None, &defaults, Some(&render));
+        let content = result.unwrap().content;
+        assert!(content.contains("Revenue grew\n{>>Dana: Check these numbers<<}"));
+    }
+
+    #[test]
+    fn test_slide_range_parse() {
+        let range = SlideRange::parse("1-3, 7,10-").unwrap();
+        for slide in [1, 3, 7, 500] {
+            assert!(range.contains(slide), "{}", slide);
+        }
+        for slide in [4, 9] {
+            assert!(!range.contains(slide), "{}", slide);
+        }
+
+        for invalid in ["", "0", "3-1", "a-b", "1,,x"] {
+            assert!(SlideRange::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
 }
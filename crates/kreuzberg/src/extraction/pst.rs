@@ -0,0 +1,550 @@
+//! Outlook PST/OST mailbox extraction.
+//!
+//! Mailboxes are exported with `pffexport` from libpff, which writes every folder as a
+//! directory and every item as a directory holding `OutlookHeaders.txt`, `Recipients.txt`,
+//! the plain-text body in `Message.txt`, the transport headers in `InternetHeaders.txt` and
+//! an `Attachments` directory. Each exported item becomes an [`EmailExtractionResult`], so
+//! mailbox messages are rendered and expanded exactly like `.eml` and `.msg` files.
+//!
+//! # System Requirement
+//!
+//! `pffexport` must be installed and in PATH, or `KREUZBERG_PFFEXPORT_PATH` must point to it:
+//! - **macOS**: `brew install libpff`
+//! - **Linux**: `apt install pff-tools`
+
+use super::email::{
+    build_metadata, calendar_events_from_attachments, compute_thread_id, header_value, is_image_mime_type,
+    parse_message_ids, parse_raw_headers, unpack_tnef_attachments,
+};
+use crate::core::config::EmailConfig;
+use crate::core::scratch::{ScratchDir, ScratchFile};
+use crate::error::{KreuzbergError, Result};
+use crate::types::{EmailAttachment, EmailExtractionResult};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use tokio::process::Command;
+use tokio::time::{Duration, timeout};
+
+/// Environment variable pointing to a `pffexport` executable outside PATH.
+pub const PFFEXPORT_PATH_ENV: &str = "KREUZBERG_PFFEXPORT_PATH";
+
+/// Default timeout for exporting a mailbox (600 seconds)
+pub const DEFAULT_EXPORT_TIMEOUT: u64 = 600;
+
+const OUTLOOK_HEADERS_FILE: &str = "OutlookHeaders.txt";
+const RECIPIENTS_FILE: &str = "Recipients.txt";
+const INTERNET_HEADERS_FILE: &str = "InternetHeaders.txt";
+const BODY_FILE: &str = "Message.txt";
+const ATTACHMENTS_DIR: &str = "Attachments";
+
+/// A message exported from a PST/OST mailbox.
+#[derive(Debug, Clone)]
+pub struct MailboxMessage {
+    /// Folder containing the message, such as `Top of Personal Folders/Inbox`
+    pub folder_path: String,
+    /// The message in the same form as parsed `.eml` and `.msg` files
+    pub email: EmailExtractionResult,
+}
+
+/// Messages read from a mailbox under the limits of an [`EmailConfig`].
+#[derive(Debug, Clone, Default)]
+pub struct MailboxExport {
+    /// Messages in folder order
+    pub messages: Vec<MailboxMessage>,
+    /// Whether reading stopped early because `max_mailbox_messages` or `max_mailbox_size` was reached
+    pub truncated: bool,
+}
+
+pub(crate) fn pffexport_install_message() -> String {
+    format!(
+        "pffexport (libpff) is required for PST/OST mailbox support. \
+Install: macOS: 'brew install libpff', Linux: 'apt install pff-tools'. \
+If pffexport is installed in a custom location, set the {} environment variable to the executable.",
+        PFFEXPORT_PATH_ENV
+    )
+}
+
+pub(crate) fn pffexport_binary() -> PathBuf {
+    std::env::var_os(PFFEXPORT_PATH_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("pffexport"))
+}
+
+/// Extract all messages from a PST/OST mailbox in memory.
+///
+/// The bytes are written to a scratch file first; prefer [`extract_pst_messages`] for
+/// mailboxes already on disk. Uses the default [`EmailConfig`] mailbox limits.
+pub async fn extract_pst_messages_from_bytes(bytes: &[u8]) -> Result<Vec<MailboxMessage>> {
+    extract_pst_messages_from_bytes_with_limits(bytes, &EmailConfig::default())
+        .await
+        .map(|export| export.messages)
+}
+
+/// Extract the messages of a PST/OST mailbox in memory under the mailbox limits of `limits`.
+///
+/// See [`extract_pst_messages_with_limits`].
+pub async fn extract_pst_messages_from_bytes_with_limits(bytes: &[u8], limits: &EmailConfig) -> Result<MailboxExport> {
+    let file = ScratchFile::write(bytes, ".pst")?;
+    extract_pst_messages_with_limits(file.path(), limits).await
+}
+
+/// Extract all messages from a PST/OST mailbox, in folder order.
+///
+/// Uses the default [`EmailConfig`] mailbox limits; see [`extract_pst_messages_with_limits`].
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::MissingDependency` if `pffexport` is not installed and
+/// `KreuzbergError::Parsing` if the mailbox cannot be read.
+pub async fn extract_pst_messages(path: &Path) -> Result<Vec<MailboxMessage>> {
+    extract_pst_messages_with_limits(path, &EmailConfig::default())
+        .await
+        .map(|export| export.messages)
+}
+
+/// Extract the messages of a PST/OST mailbox, in folder order, under the mailbox limits of `limits`.
+///
+/// Messages are read from the export one at a time on the blocking thread pool. Reading stops
+/// before the message that would exceed `max_mailbox_messages` or `max_mailbox_size` (the
+/// exported size of its headers, body and attachments), and the export is marked truncated.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::MissingDependency` if `pffexport` is not installed and
+/// `KreuzbergError::Parsing` if the mailbox cannot be read.
+pub async fn extract_pst_messages_with_limits(path: &Path, limits: &EmailConfig) -> Result<MailboxExport> {
+    let export_dir = ScratchDir::new()?;
+    let target = export_dir.path().join("mailbox");
+
+    run_pffexport(path, &target, DEFAULT_EXPORT_TIMEOUT).await?;
+    export_dir.measure()?;
+
+    // pffexport appends `.export` to the target for the items of the folder hierarchy.
+    let root = export_dir.path().join("mailbox.export");
+    let mut budget = ExportBudget {
+        max_messages: limits.max_mailbox_messages,
+        remaining_bytes: limits.max_mailbox_size,
+        truncated: false,
+    };
+    let export = tokio::task::spawn_blocking(move || {
+        let mut messages = Vec::new();
+        if root.is_dir() {
+            visit_export_dir(&root, &root, &mut messages, &mut budget)?;
+        }
+        Ok::<_, KreuzbergError>(MailboxExport {
+            messages,
+            truncated: budget.truncated,
+        })
+    })
+    .await
+    .map_err(|e| KreuzbergError::Other(format!("Mailbox export reader panicked: {}", e)))??;
+
+    if export.truncated {
+        tracing::warn!(
+            "Mailbox limits reached after {} messages, remaining messages are not extracted",
+            export.messages.len()
+        );
+    }
+
+    Ok(export)
+}
+
+async fn run_pffexport(input: &Path, target: &Path, timeout_seconds: u64) -> Result<()> {
+    let child = Command::new(pffexport_binary())
+        .arg("-q")
+        .args(["-m", "items", "-f", "text", "-t"])
+        .arg(target)
+        .arg(input)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => KreuzbergError::MissingDependency(pffexport_install_message()),
+            _ => KreuzbergError::Io(e),
+        })?;
+
+    let output = match timeout(Duration::from_secs(timeout_seconds), child.wait_with_output()).await {
+        Ok(output) => output?,
+        Err(_) => {
+            return Err(KreuzbergError::parsing(format!(
+                "Mailbox export timed out after {} seconds",
+                timeout_seconds
+            )));
+        }
+    };
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(KreuzbergError::parsing(format!(
+            "Failed to read PST/OST mailbox ({}): {}",
+            output.status,
+            stderr.trim()
+        )));
+    }
+
+    Ok(())
+}
+
+/// Messages and bytes still allowed to be read from an export.
+struct ExportBudget {
+    max_messages: usize,
+    remaining_bytes: u64,
+    truncated: bool,
+}
+
+impl ExportBudget {
+    /// Count the exported item `dir` against the budget.
+    ///
+    /// Returns `false` and marks the export as truncated once a limit would be exceeded.
+    fn admit(&mut self, dir: &Path, message_count: usize) -> Result<bool> {
+        let size = exported_size(dir)?;
+        if message_count >= self.max_messages || size > self.remaining_bytes {
+            self.truncated = true;
+            return Ok(false);
+        }
+
+        self.remaining_bytes -= size;
+        Ok(true)
+    }
+}
+
+/// Total size of the files below `dir`.
+fn exported_size(dir: &Path) -> Result<u64> {
+    let mut size = 0;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            size += exported_size(&entry.path())?;
+        } else if file_type.is_file() {
+            size += entry.metadata()?.len();
+        }
+    }
+    Ok(size)
+}
+
+/// Walk an export directory; directories with an `OutlookHeaders.txt` are items, all others folders.
+fn visit_export_dir(
+    root: &Path,
+    dir: &Path,
+    messages: &mut Vec<MailboxMessage>,
+    budget: &mut ExportBudget,
+) -> Result<()> {
+    let mut entries = fs::read_dir(dir)?.collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        if budget.truncated {
+            break;
+        }
+
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+
+        if path.join(OUTLOOK_HEADERS_FILE).is_file() {
+            if !budget.admit(&path, messages.len())? {
+                break;
+            }
+            messages.push(MailboxMessage {
+                folder_path: folder_path(root, dir),
+                email: read_exported_message(&path)?,
+            });
+        } else {
+            visit_export_dir(root, &path, messages, budget)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn folder_path(root: &Path, dir: &Path) -> String {
+    dir.strip_prefix(root)
+        .unwrap_or(dir)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn read_text_file(path: &Path) -> Result<Option<String>> {
+    match fs::read(path) {
+        Ok(bytes) => Ok(Some(String::from_utf8_lossy(&bytes).into_owned())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Parse the `Name:<tabs>value` lines pffexport writes; blank lines separate records.
+fn parse_properties(text: &str) -> Vec<Vec<(String, String)>> {
+    let mut records = Vec::new();
+    let mut current = Vec::new();
+
+    for line in text.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                records.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            current.push((name.trim().to_string(), value.trim().to_string()));
+        }
+    }
+
+    if !current.is_empty() {
+        records.push(current);
+    }
+
+    records
+}
+
+fn property<'a>(properties: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    header_value(properties, name).filter(|value| !value.is_empty())
+}
+
+fn first_message_id(value: &str) -> Option<String> {
+    parse_message_ids(value).into_iter().next()
+}
+
+fn read_exported_message(dir: &Path) -> Result<EmailExtractionResult> {
+    let outlook_headers = read_text_file(&dir.join(OUTLOOK_HEADERS_FILE))?.unwrap_or_default();
+    let headers: Vec<_> = parse_properties(&outlook_headers).into_iter().flatten().collect();
+
+    let raw_headers = read_text_file(&dir.join(INTERNET_HEADERS_FILE))?
+        .map(|text| parse_raw_headers(text.as_bytes()))
+        .unwrap_or_default();
+
+    let subject = property(&headers, "Subject").map(String::from);
+    let from_email = property(&headers, "Sender email address").map(String::from);
+    let from_name = property(&headers, "Sender name").map(String::from);
+    let date = property(&headers, "Client submit time")
+        .or_else(|| property(&headers, "Delivery time"))
+        .map(String::from);
+
+    let mut to_emails = Vec::new();
+    let mut cc_emails = Vec::new();
+    let mut bcc_emails = Vec::new();
+    let recipients = read_text_file(&dir.join(RECIPIENTS_FILE))?.unwrap_or_default();
+    for recipient in parse_properties(&recipients) {
+        let address = property(&recipient, "Email address").or_else(|| property(&recipient, "Display name"));
+        let Some(address) = address else {
+            continue;
+        };
+
+        let recipient_type = property(&recipient, "Recipient type").unwrap_or_default();
+        match recipient_type.to_ascii_lowercase().as_str() {
+            "cc" => cc_emails.push(address.to_string()),
+            "bcc" => bcc_emails.push(address.to_string()),
+            _ => to_emails.push(address.to_string()),
+        }
+    }
+
+    let message_id = header_value(&raw_headers, "Message-ID").and_then(first_message_id);
+    let in_reply_to = header_value(&raw_headers, "In-Reply-To").and_then(first_message_id);
+    let references = header_value(&raw_headers, "References")
+        .map(parse_message_ids)
+        .unwrap_or_default();
+    let reply_to_emails = header_value(&raw_headers, "Reply-To")
+        .map(|value| value.split(',').map(|address| address.trim().to_string()).collect())
+        .unwrap_or_default();
+    let thread_id = compute_thread_id(message_id.as_deref(), in_reply_to.as_deref(), &references);
+    let has_dkim_signature = header_value(&raw_headers, "DKIM-Signature").is_some();
+
+    let attachments = read_exported_attachments(&dir.join(ATTACHMENTS_DIR))?;
+    let mut calendar_events = calendar_events_from_attachments(&attachments);
+    let mut unpacked = unpack_tnef_attachments(attachments);
+    let attachments = std::mem::take(&mut unpacked.attachments);
+    calendar_events.append(&mut unpacked.calendar_events);
+
+    let mut plain_text = read_text_file(&dir.join(BODY_FILE))?.filter(|body| !body.trim().is_empty());
+    let mut html_content = None;
+    unpacked.fill_body(&mut plain_text, &mut html_content);
+    let cleaned_text = plain_text.as_deref().unwrap_or_default().trim().to_string();
+
+    let metadata = build_metadata(
+        &subject,
+        &from_email,
+        &to_emails,
+        &cc_emails,
+        &bcc_emails,
+        &date,
+        &message_id,
+        &attachments,
+    );
+
+    Ok(EmailExtractionResult {
+        subject,
+        from_email,
+        to_emails,
+        cc_emails,
+        bcc_emails,
+        date,
+        message_id,
+        from_name,
+        reply_to_emails,
+        in_reply_to,
+        references,
+        thread_id,
+        has_dkim_signature,
+        raw_headers,
+        plain_text,
+        html_content,
+        cleaned_text,
+        attachments,
+        calendar_events,
+        quoted_text: None,
+        signature: None,
+        tracking_pixels: Vec::new(),
+        metadata,
+    })
+}
+
+/// Read exported attachments; pffexport prefixes each file name with its index (`1_report.pdf`).
+fn read_exported_attachments(dir: &Path) -> Result<Vec<EmailAttachment>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = fs::read_dir(dir)?.collect::<std::io::Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+
+    let mut attachments = Vec::new();
+    for entry in entries {
+        let path = entry.path();
+        if !path.is_file() {
+            continue;
+        }
+
+        let exported_name = entry.file_name().to_string_lossy().into_owned();
+        let filename = match exported_name.split_once('_') {
+            Some((index, name)) if !name.is_empty() && index.bytes().all(|b| b.is_ascii_digit()) => name.to_string(),
+            _ => exported_name,
+        };
+
+        let data = fs::read(&path)?;
+        let mime_type = crate::core::mime::detect_mime_type(&filename, false).ok();
+        let is_image = mime_type.as_deref().is_some_and(is_image_mime_type);
+
+        attachments.push(EmailAttachment {
+            name: Some(filename.clone()),
+            filename: Some(filename),
+            mime_type,
+            size: Some(data.len()),
+            is_image,
+            data: Some(data),
+            content_id: None,
+        });
+    }
+
+    Ok(attachments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(path: &Path, contents: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn budget(limits: &EmailConfig) -> ExportBudget {
+        ExportBudget {
+            max_messages: limits.max_mailbox_messages,
+            remaining_bytes: limits.max_mailbox_size,
+            truncated: false,
+        }
+    }
+
+    #[test]
+    fn test_parse_properties_splits_records() {
+        let text = "Display name:\t\tAlice\nRecipient type:\t\tTo\n\nDisplay name:\t\tBob\n";
+        let records = parse_properties(text);
+        assert_eq!(records.len(), 2);
+        assert_eq!(property(&records[0], "Display name"), Some("Alice"));
+        assert_eq!(property(&records[1], "display name"), Some("Bob"));
+    }
+
+    #[test]
+    fn test_read_export_walks_folders() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let message = root.join("Top of Personal Folders/Inbox/Message00001");
+
+        write(
+            &message.join(OUTLOOK_HEADERS_FILE),
+            "Client submit time:\t\tJan 01, 2024 12:00:00.000000000 UTC\n\
+Subject:\t\t\tQuarterly report\n\
+Sender name:\t\t\tAlice\n\
+Sender email address:\t\talice@example.com\n",
+        );
+        write(
+            &message.join(RECIPIENTS_FILE),
+            "Display name:\t\tBob\nEmail address:\t\tbob@example.com\nRecipient type:\t\tTo\n\n\
+Display name:\t\tCarol\nEmail address:\t\tcarol@example.com\nRecipient type:\t\tCC\n",
+        );
+        write(
+            &message.join(INTERNET_HEADERS_FILE),
+            "Message-ID: <report@example.com>\r\nIn-Reply-To: <request@example.com>\r\n",
+        );
+        write(&message.join(BODY_FILE), "Numbers attached.\n");
+        write(&message.join(ATTACHMENTS_DIR).join("1_report.txt"), "Revenue up.");
+        fs::create_dir_all(root.join("Top of Personal Folders/Deleted Items")).unwrap();
+
+        let mut messages = Vec::new();
+        visit_export_dir(root, root, &mut messages, &mut budget(&EmailConfig::default())).unwrap();
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].folder_path, "Top of Personal Folders/Inbox");
+
+        let email = &messages[0].email;
+        assert_eq!(email.subject.as_deref(), Some("Quarterly report"));
+        assert_eq!(email.from_email.as_deref(), Some("alice@example.com"));
+        assert_eq!(email.from_name.as_deref(), Some("Alice"));
+        assert_eq!(email.to_emails, vec!["bob@example.com"]);
+        assert_eq!(email.cc_emails, vec!["carol@example.com"]);
+        assert_eq!(email.message_id.as_deref(), Some("report@example.com"));
+        assert_eq!(email.thread_id.as_deref(), Some("request@example.com"));
+        assert_eq!(email.cleaned_text, "Numbers attached.");
+        assert_eq!(email.attachments.len(), 1);
+        assert_eq!(email.attachments[0].filename.as_deref(), Some("report.txt"));
+        assert_eq!(email.attachments[0].mime_type.as_deref(), Some("text/plain"));
+    }
+
+    #[test]
+    fn test_read_export_stops_at_mailbox_limits() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        for (index, body) in ["First", "Second", "Third"].iter().enumerate() {
+            let message = root.join(format!("Inbox/Message{:05}", index + 1));
+            write(&message.join(OUTLOOK_HEADERS_FILE), "Subject:\t\tStatus\n");
+            write(&message.join(BODY_FILE), body);
+        }
+
+        let mut messages = Vec::new();
+        let limits = EmailConfig {
+            max_mailbox_messages: 2,
+            ..Default::default()
+        };
+        let mut count_budget = budget(&limits);
+        visit_export_dir(root, root, &mut messages, &mut count_budget).unwrap();
+        assert_eq!(messages.len(), 2);
+        assert!(count_budget.truncated);
+
+        let mut messages = Vec::new();
+        let limits = EmailConfig {
+            max_mailbox_size: 50,
+            ..Default::default()
+        };
+        let mut size_budget = budget(&limits);
+        visit_export_dir(root, root, &mut messages, &mut size_budget).unwrap();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[1].email.cleaned_text, "Second");
+        assert!(size_budget.truncated);
+    }
+}
@@ -15,6 +15,8 @@
 //! - **Schema extraction**: Optional JSON schema generation
 //! - **Depth limiting**: Prevents stack overflow on deeply nested data
 //! - **Flattening**: Converts nested structures to flat text representation
+//! - **Key-path filters and size guards** (JSON, YAML): see [`StructuredConfig`]
+//! - **Tabular mode** (JSON, YAML): arrays of uniform objects become [`Table`]s
 //!
 //! # Example
 //!
@@ -30,7 +32,10 @@
 //! # Ok(())
 //! # }
 //! ```
+use crate::core::config::StructuredConfig;
 use crate::error::{KreuzbergError, Result};
+use crate::extraction::markdown::cells_to_markdown;
+use crate::types::Table;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -40,6 +45,12 @@ pub struct StructuredDataResult {
     pub format: String,
     pub metadata: HashMap<String, String>,
     pub text_fields: Vec<String>,
+    /// Arrays of objects extracted as tables ([`StructuredConfig::tabular`])
+    #[serde(default)]
+    pub tables: Vec<Table>,
+    /// Whether `max_depth` or `max_array_items` cut off part of the data
+    #[serde(default)]
+    pub truncated: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,29 +88,29 @@ const TEXT_FIELD_KEYWORDS: &[&str] = &[
 ];
 
 pub fn parse_json(data: &[u8], config: Option<JsonExtractionConfig>) -> Result<StructuredDataResult> {
+    parse_json_with_options(data, config, &StructuredConfig::default())
+}
+
+/// Parse JSON, applying the key-path filters, size guards and tabular mode of `options`.
+pub fn parse_json_with_options(
+    data: &[u8],
+    config: Option<JsonExtractionConfig>,
+    options: &StructuredConfig,
+) -> Result<StructuredDataResult> {
     let config = config.unwrap_or_default();
 
     let value: serde_json::Value =
         serde_json::from_slice(data).map_err(|e| KreuzbergError::parsing(format!("Failed to parse JSON: {}", e)))?;
 
-    let mut metadata = HashMap::new();
-    let mut text_fields = Vec::new();
+    let mut flattener = Flattener::new(&config, options);
 
     if config.extract_schema
         && let Ok(schema_json) = serde_json::to_string(&extract_json_schema(&value, "", 0, &config))
     {
-        metadata.insert("json_schema".to_string(), schema_json);
+        flattener.metadata.insert("json_schema".to_string(), schema_json);
     }
 
-    let text_parts = extract_from_json_value(&value, "", &config, &mut metadata, &mut text_fields);
-    let content = text_parts.join("\n");
-
-    Ok(StructuredDataResult {
-        content,
-        format: "json".to_string(),
-        metadata,
-        text_fields,
-    })
+    Ok(flattener.finish(&value, "json"))
 }
 
 fn extract_json_schema(
@@ -150,73 +161,252 @@ fn extract_json_schema(
     }
 }
 
-fn extract_from_json_value(
-    value: &serde_json::Value,
-    prefix: &str,
-    config: &JsonExtractionConfig,
-    metadata: &mut HashMap<String, String>,
-    text_fields: &mut Vec<String>,
-) -> Vec<String> {
-    match value {
-        serde_json::Value::Object(obj) => {
-            let mut text_parts = Vec::new();
-            for (key, val) in obj {
-                let full_key = if prefix.is_empty() {
-                    key.clone()
+/// A segment of a key path: an object key or an array index, or a wildcard in patterns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+    /// `*`: any key or index
+    Any,
+    /// `[*]`: any index
+    AnyIndex,
+}
+
+impl PathSegment {
+    fn matches(&self, segment: &PathSegment) -> bool {
+        match (self, segment) {
+            (PathSegment::Any, _) | (PathSegment::AnyIndex, PathSegment::Index(_)) => true,
+            (pattern, segment) => pattern == segment,
+        }
+    }
+}
+
+/// Split a key path such as `items[*].title` into segments.
+fn parse_key_path(path: &str) -> Vec<PathSegment> {
+    let mut segments = Vec::new();
+    for part in path.split('.').filter(|part| !part.is_empty()) {
+        let (key, indices) = part.split_once('[').unwrap_or((part, ""));
+        match key {
+            "" => {}
+            "*" => segments.push(PathSegment::Any),
+            key => segments.push(PathSegment::Key(key.to_string())),
+        }
+        for index in indices.split('[').map(|index| index.trim_end_matches(']')) {
+            let segment = match index {
+                "" => continue,
+                "*" => PathSegment::AnyIndex,
+                index => match index.parse() {
+                    Ok(index) => PathSegment::Index(index),
+                    Err(_) => PathSegment::Key(index.to_string()),
+                },
+            };
+            segments.push(segment);
+        }
+    }
+    segments
+}
+
+/// Whether `path` is at or below the path selected by `pattern`.
+fn selects(pattern: &[PathSegment], path: &[PathSegment]) -> bool {
+    pattern.len() <= path.len() && pattern.iter().zip(path).all(|(wanted, seg)| wanted.matches(seg))
+}
+
+/// Whether `pattern` selects a path below `path`.
+fn leads_to(path: &[PathSegment], pattern: &[PathSegment]) -> bool {
+    path.len() < pattern.len() && pattern.iter().zip(path).all(|(wanted, seg)| wanted.matches(seg))
+}
+
+/// Flattens a JSON value into `key: value` lines, collecting text fields and tables.
+struct Flattener<'a> {
+    config: &'a JsonExtractionConfig,
+    options: &'a StructuredConfig,
+    include: Vec<Vec<PathSegment>>,
+    exclude: Vec<Vec<PathSegment>>,
+    metadata: HashMap<String, String>,
+    text_fields: Vec<String>,
+    tables: Vec<Table>,
+    truncated: bool,
+}
+
+impl<'a> Flattener<'a> {
+    fn new(config: &'a JsonExtractionConfig, options: &'a StructuredConfig) -> Self {
+        Self {
+            config,
+            options,
+            include: options.include_paths.iter().map(|path| parse_key_path(path)).collect(),
+            exclude: options.exclude_paths.iter().map(|path| parse_key_path(path)).collect(),
+            metadata: HashMap::new(),
+            text_fields: Vec::new(),
+            tables: Vec::new(),
+            truncated: false,
+        }
+    }
+
+    fn finish(mut self, value: &serde_json::Value, format: &str) -> StructuredDataResult {
+        let included = self.include.is_empty();
+        let text_parts = self.flatten(value, "", &mut Vec::new(), 0, included);
+
+        StructuredDataResult {
+            content: text_parts.join("\n"),
+            format: format.to_string(),
+            metadata: self.metadata,
+            text_fields: self.text_fields,
+            tables: self.tables,
+            truncated: self.truncated,
+        }
+    }
+
+    /// Flatten `value` found at `prefix`; `included` is set when an include path selects the
+    /// parent of `path`.
+    fn flatten(
+        &mut self,
+        value: &serde_json::Value,
+        prefix: &str,
+        path: &mut Vec<PathSegment>,
+        depth: usize,
+        included: bool,
+    ) -> Vec<String> {
+        if self.exclude.iter().any(|pattern| selects(pattern, path)) {
+            return Vec::new();
+        }
+        let included = included || self.include.iter().any(|pattern| selects(pattern, path));
+        if !included && !self.include.iter().any(|pattern| leads_to(path, pattern)) {
+            return Vec::new();
+        }
+
+        match value {
+            serde_json::Value::Object(obj) => {
+                if !obj.is_empty() && self.depth_exceeded(depth) {
+                    return Vec::new();
+                }
+                let mut text_parts = Vec::new();
+                for (key, val) in obj {
+                    let full_key = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    path.push(PathSegment::Key(key.clone()));
+                    text_parts.extend(self.flatten(val, &full_key, path, depth + 1, included));
+                    path.pop();
+                }
+                text_parts
+            }
+            serde_json::Value::Array(arr) => {
+                if !arr.is_empty() && self.depth_exceeded(depth) {
+                    return Vec::new();
+                }
+                let limit = arr.len().min(self.options.max_array_items.unwrap_or(usize::MAX));
+                self.truncated |= limit < arr.len();
+                let items = &arr[..limit];
+
+                if self.options.tabular
+                    && included
+                    && !self.exclude.iter().any(|pattern| leads_to(path, pattern))
+                    && let Some(cells) = table_cells(items)
+                {
+                    let markdown = cells_to_markdown(&cells);
+                    let text = if prefix.is_empty() {
+                        markdown.trim_end().to_string()
+                    } else {
+                        format!("{}:\n{}", prefix, markdown.trim_end())
+                    };
+                    self.tables.push(Table {
+                        cells,
+                        markdown,
+                        page_number: 1,
+                    });
+                    return vec![text];
+                }
+
+                let mut text_parts = Vec::new();
+                for (i, item) in items.iter().enumerate() {
+                    let item_key = if prefix.is_empty() {
+                        format!("item_{}", i)
+                    } else {
+                        format!("{}[{}]", prefix, i)
+                    };
+                    path.push(PathSegment::Index(i));
+                    text_parts.extend(self.flatten(item, &item_key, path, depth + 1, included));
+                    path.pop();
+                }
+                text_parts
+            }
+            _ if !included => Vec::new(),
+            serde_json::Value::String(s) => {
+                if !s.trim().is_empty() {
+                    let formatted = if self.config.include_type_info {
+                        format!("{} (string): {}", prefix, s)
+                    } else {
+                        format!("{}: {}", prefix, s)
+                    };
+
+                    if is_text_field(prefix, &self.config.custom_text_field_patterns) {
+                        self.metadata.insert(prefix.to_string(), s.clone());
+                        self.text_fields.push(prefix.to_string());
+                    }
+
+                    vec![formatted]
                 } else {
-                    format!("{}.{}", prefix, key)
-                };
-                text_parts.extend(extract_from_json_value(val, &full_key, config, metadata, text_fields));
+                    Vec::new()
+                }
             }
-            text_parts
-        }
-        serde_json::Value::Array(arr) => {
-            let mut text_parts = Vec::new();
-            for (i, item) in arr.iter().enumerate() {
-                let item_key = if prefix.is_empty() {
-                    format!("item_{}", i)
+            serde_json::Value::Number(n) => {
+                let formatted = if self.config.include_type_info {
+                    format!("{} (number): {}", prefix, n)
                 } else {
-                    format!("{}[{}]", prefix, i)
+                    format!("{}: {}", prefix, n)
                 };
-                text_parts.extend(extract_from_json_value(item, &item_key, config, metadata, text_fields));
+                vec![formatted]
             }
-            text_parts
-        }
-        serde_json::Value::String(s) => {
-            if !s.trim().is_empty() {
-                let formatted = if config.include_type_info {
-                    format!("{} (string): {}", prefix, s)
+            serde_json::Value::Bool(b) => {
+                let formatted = if self.config.include_type_info {
+                    format!("{} (bool): {}", prefix, b)
                 } else {
-                    format!("{}: {}", prefix, s)
+                    format!("{}: {}", prefix, b)
                 };
-
-                if is_text_field(prefix, &config.custom_text_field_patterns) {
-                    metadata.insert(prefix.to_string(), s.clone());
-                    text_fields.push(prefix.to_string());
-                }
-
                 vec![formatted]
-            } else {
-                Vec::new()
             }
+            serde_json::Value::Null => Vec::new(),
         }
-        serde_json::Value::Number(n) => {
-            let formatted = if config.include_type_info {
-                format!("{} (number): {}", prefix, n)
-            } else {
-                format!("{}: {}", prefix, n)
-            };
-            vec![formatted]
-        }
-        serde_json::Value::Bool(b) => {
-            let formatted = if config.include_type_info {
-                format!("{} (bool): {}", prefix, b)
-            } else {
-                format!("{}: {}", prefix, b)
-            };
-            vec![formatted]
+    }
+
+    fn depth_exceeded(&mut self, depth: usize) -> bool {
+        let exceeded = self.options.max_depth.is_some_and(|max_depth| depth >= max_depth);
+        self.truncated |= exceeded;
+        exceeded
+    }
+}
+
+/// Header and rows of `items` if they are objects with the same keys and only scalar values.
+fn table_cells(items: &[serde_json::Value]) -> Option<Vec<Vec<String>>> {
+    let header: Vec<String> = items.first()?.as_object()?.keys().cloned().collect();
+    if header.is_empty() {
+        return None;
+    }
+
+    let mut cells = vec![header.clone()];
+    for item in items {
+        let obj = item.as_object()?;
+        if obj.len() != header.len() {
+            return None;
         }
-        serde_json::Value::Null => Vec::new(),
+        let row = header
+            .iter()
+            .map(|key| obj.get(key).and_then(scalar_text))
+            .collect::<Option<Vec<_>>>()?;
+        cells.push(row);
+    }
+    Some(cells)
+}
+
+fn scalar_text(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => Some(String::new()),
+        serde_json::Value::Bool(b) => Some(b.to_string()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => None,
     }
 }
 
@@ -239,75 +429,19 @@ fn is_text_field(key: &str, custom_patterns: &[String]) -> bool {
 }
 
 pub fn parse_yaml(data: &[u8]) -> Result<StructuredDataResult> {
+    parse_yaml_with_options(data, &StructuredConfig::default())
+}
+
+/// Parse YAML, applying the key-path filters, size guards and tabular mode of `options`.
+pub fn parse_yaml_with_options(data: &[u8], options: &StructuredConfig) -> Result<StructuredDataResult> {
     let yaml_str =
         std::str::from_utf8(data).map_err(|e| KreuzbergError::parsing(format!("Invalid UTF-8 in YAML: {}", e)))?;
 
     let value: serde_json::Value = serde_yaml_ng::from_str(yaml_str)
         .map_err(|e| KreuzbergError::parsing(format!("Failed to parse YAML: {}", e)))?;
 
-    let mut metadata = HashMap::new();
-    let mut text_fields = Vec::new();
-
-    let text_parts = extract_from_value(&value, "", &mut metadata, &mut text_fields);
-    let content = text_parts.join("\n");
-
-    Ok(StructuredDataResult {
-        content,
-        format: "yaml".to_string(),
-        metadata,
-        text_fields,
-    })
-}
-
-fn extract_from_value(
-    value: &serde_json::Value,
-    prefix: &str,
-    metadata: &mut HashMap<String, String>,
-    text_fields: &mut Vec<String>,
-) -> Vec<String> {
-    match value {
-        serde_json::Value::Null => Vec::new(),
-        serde_json::Value::Bool(b) => vec![format!("{}: {}", prefix, b)],
-        serde_json::Value::Number(n) => vec![format!("{}: {}", prefix, n)],
-        serde_json::Value::String(s) => {
-            if !s.trim().is_empty() {
-                let formatted = format!("{}: {}", prefix, s);
-
-                if is_text_field(prefix, &[]) {
-                    metadata.insert(prefix.to_string(), s.to_string());
-                    text_fields.push(prefix.to_string());
-                }
-
-                vec![formatted]
-            } else {
-                Vec::new()
-            }
-        }
-        serde_json::Value::Array(arr) => {
-            let mut text_parts = Vec::new();
-            for (i, item) in arr.iter().enumerate() {
-                let item_key = if prefix.is_empty() {
-                    format!("item_{}", i)
-                } else {
-                    format!("{}[{}]", prefix, i)
-                };
-                text_parts.extend(extract_from_value(item, &item_key, metadata, text_fields));
-            }
-            text_parts
-        }
-        serde_json::Value::Object(obj) => {
-            let mut text_parts = Vec::new();
-            for (key, val) in obj {
-                let full_key = if prefix.is_empty() {
-                    key.to_string()
-                } else {
-                    format!("{}.{}", prefix, key)
-                };
-                text_parts.extend(extract_from_value(val, &full_key, metadata, text_fields));
-            }
-            text_parts
-        }
-    }
+    let config = JsonExtractionConfig::default();
+    Ok(Flattener::new(&config, options).finish(&value, "yaml"))
 }
 
 pub fn parse_toml(data: &[u8]) -> Result<StructuredDataResult> {
@@ -328,6 +462,8 @@ pub fn parse_toml(data: &[u8]) -> Result<StructuredDataResult> {
         format: "toml".to_string(),
         metadata,
         text_fields,
+        tables: Vec::new(),
+        truncated: false,
     })
 }
 
@@ -424,6 +560,79 @@ mod tests {
         assert!(result.metadata.contains_key("json_schema"));
     }
 
+    #[test]
+    fn test_parse_json_include_exclude_paths() {
+        let json = br#"{"user": {"name": "Alice", "password": "x"}, "items": [{"id": 1, "title": "A"}], "log": "y"}"#;
+        let options = StructuredConfig {
+            include_paths: vec!["user".to_string(), "items[*].title".to_string()],
+            exclude_paths: vec!["user.password".to_string()],
+            ..Default::default()
+        };
+        let result = parse_json_with_options(json, None, &options).unwrap();
+        assert_eq!(result.content, "items[0].title: A\nuser.name: Alice");
+        assert_eq!(result.text_fields, vec!["items[0].title", "user.name"]);
+    }
+
+    #[test]
+    fn test_parse_json_size_guards() {
+        let json = br#"{"a": {"b": {"c": "deep"}, "d": "kept"}, "list": [1, 2, 3, 4]}"#;
+        let options = StructuredConfig {
+            max_depth: Some(2),
+            max_array_items: Some(2),
+            ..Default::default()
+        };
+        let result = parse_json_with_options(json, None, &options).unwrap();
+        assert_eq!(result.content, "a.d: kept\nlist[0]: 1\nlist[1]: 2");
+        assert!(result.truncated);
+
+        let result = parse_json(json, None).unwrap();
+        assert!(result.content.contains("a.b.c: deep"));
+        assert!(!result.truncated);
+    }
+
+    #[test]
+    fn test_parse_json_tabular() {
+        let json = br#"{"rows": [{"id": 1, "name": "A|B"}, {"id": 2, "name": null}], "mixed": [{"id": 1}, {"x": 2}]}"#;
+        let options = StructuredConfig {
+            tabular: true,
+            ..Default::default()
+        };
+        let result = parse_json_with_options(json, None, &options).unwrap();
+        assert_eq!(result.tables.len(), 1);
+        assert_eq!(result.tables[0].cells[0], vec!["id", "name"]);
+        assert_eq!(result.tables[0].cells[2], vec!["2", ""]);
+        assert!(result.content.contains("rows:\n| id | name |"));
+        assert!(result.content.contains("A\\|B"));
+        assert!(result.content.contains("mixed[1].x: 2"));
+    }
+
+    #[test]
+    fn test_parse_yaml_with_options() {
+        let yaml = "- name: Alice\n  age: 30\n- name: Bob\n  age: 25\n";
+        let options = StructuredConfig {
+            tabular: true,
+            max_array_items: Some(1),
+            ..Default::default()
+        };
+        let result = parse_yaml_with_options(yaml.as_bytes(), &options).unwrap();
+        assert_eq!(result.tables[0].cells, vec![vec!["age", "name"], vec!["30", "Alice"]]);
+        assert!(result.truncated);
+    }
+
+    #[test]
+    fn test_parse_key_path() {
+        assert_eq!(
+            parse_key_path("items[*].tags[0]"),
+            vec![
+                PathSegment::Key("items".to_string()),
+                PathSegment::AnyIndex,
+                PathSegment::Key("tags".to_string()),
+                PathSegment::Index(0),
+            ]
+        );
+        assert_eq!(parse_key_path("[*].*"), vec![PathSegment::AnyIndex, PathSegment::Any]);
+    }
+
     #[test]
     fn test_parse_yaml_simple() {
         let yaml = "name: John\nage: 30";
@@ -0,0 +1,796 @@
+//! TNEF (`winmail.dat`) decoding.
+//!
+//! Outlook wraps the rich content of messages sent to non-Outlook recipients in an
+//! `application/ms-tnef` attachment, usually named `winmail.dat` (MS-OXTNEF). It carries the
+//! actual attachments, the message body (plain text, HTML or compressed RTF) and, for meeting
+//! requests, the meeting details. Everything else in the stream is skipped.
+
+use crate::error::{KreuzbergError, Result};
+use crate::extraction::email::is_image_mime_type;
+use crate::extraction::icalendar::format_participant;
+use crate::types::{CalendarEvent, EmailAttachment};
+
+const TNEF_SIGNATURE: u32 = 0x223E_9F78;
+
+const LEVEL_MESSAGE: u8 = 0x01;
+const LEVEL_ATTACHMENT: u8 = 0x02;
+
+// Attribute IDs (low word of the attribute tag)
+const ATT_DATE_START: u16 = 0x0006;
+const ATT_DATE_END: u16 = 0x0007;
+const ATT_SUBJECT: u16 = 0x8004;
+const ATT_MESSAGE_CLASS: u16 = 0x8008;
+const ATT_BODY: u16 = 0x800C;
+const ATT_ATTACH_DATA: u16 = 0x800F;
+const ATT_ATTACH_TITLE: u16 = 0x8010;
+const ATT_ATTACH_REND_DATA: u16 = 0x9002;
+const ATT_MSG_PROPS: u16 = 0x9003;
+const ATT_RECIP_TABLE: u16 = 0x9004;
+const ATT_ATTACHMENT: u16 = 0x9005;
+
+// MAPI property IDs
+const PR_MESSAGE_CLASS: u16 = 0x001A;
+const PR_SUBJECT: u16 = 0x0037;
+const PR_SENT_REPRESENTING_NAME: u16 = 0x0042;
+const PR_START_DATE: u16 = 0x0060;
+const PR_END_DATE: u16 = 0x0061;
+const PR_SENT_REPRESENTING_EMAIL_ADDRESS: u16 = 0x0065;
+const PR_SENDER_EMAIL_ADDRESS: u16 = 0x0C1F;
+const PR_BODY: u16 = 0x1000;
+const PR_RTF_COMPRESSED: u16 = 0x1009;
+const PR_BODY_HTML: u16 = 0x1013;
+const PR_DISPLAY_NAME: u16 = 0x3001;
+const PR_EMAIL_ADDRESS: u16 = 0x3003;
+const PR_ATTACH_DATA: u16 = 0x3701;
+const PR_ATTACH_FILENAME: u16 = 0x3704;
+const PR_ATTACH_LONG_FILENAME: u16 = 0x3707;
+const PR_ATTACH_MIME_TAG: u16 = 0x370E;
+const PR_ATTACH_CONTENT_ID: u16 = 0x3712;
+const PR_SMTP_ADDRESS: u16 = 0x39FE;
+const PR_SENDER_SMTP_ADDRESS: u16 = 0x5D01;
+const PR_SENT_REPRESENTING_SMTP_ADDRESS: u16 = 0x5D02;
+
+/// Properties that can hold the organizer of a meeting, SMTP addresses first.
+const ORGANIZER_ADDRESS_PROPERTIES: [u16; 4] = [
+    PR_SENT_REPRESENTING_SMTP_ADDRESS,
+    PR_SENDER_SMTP_ADDRESS,
+    PR_SENT_REPRESENTING_EMAIL_ADDRESS,
+    PR_SENDER_EMAIL_ADDRESS,
+];
+
+/// PSETID_Appointment, `{00062002-0000-0000-C000-000000000046}` in its on-disk byte order.
+const PSETID_APPOINTMENT: [u8; 16] = [
+    0x02, 0x20, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46,
+];
+const PID_LID_LOCATION: u32 = 0x8208;
+const PID_LID_APPOINTMENT_START_WHOLE: u32 = 0x820D;
+const PID_LID_APPOINTMENT_END_WHOLE: u32 = 0x820E;
+
+// MAPI property types
+const PT_SHORT: u16 = 0x0002;
+const PT_LONG: u16 = 0x0003;
+const PT_FLOAT: u16 = 0x0004;
+const PT_DOUBLE: u16 = 0x0005;
+const PT_CURRENCY: u16 = 0x0006;
+const PT_APPTIME: u16 = 0x0007;
+const PT_ERROR: u16 = 0x000A;
+const PT_BOOLEAN: u16 = 0x000B;
+const PT_OBJECT: u16 = 0x000D;
+const PT_LONGLONG: u16 = 0x0014;
+const PT_STRING8: u16 = 0x001E;
+const PT_UNICODE: u16 = 0x001F;
+const PT_SYSTIME: u16 = 0x0040;
+const PT_CLSID: u16 = 0x0048;
+const PT_BINARY: u16 = 0x0102;
+const MV_FLAG: u16 = 0x1000;
+
+/// Seconds between 1601-01-01 (FILETIME epoch) and 1970-01-01.
+const FILETIME_UNIX_OFFSET_SECS: u64 = 11_644_473_600;
+
+/// Dictionary preset of compressed RTF (MS-OXRTFCP section 3.1.3.1).
+const LZFU_PRELOAD: &[u8] = b"{\\rtf1\\ansi\\mac\\deff0\\deftab720{\\fonttbl;}{\\f0\\fnil \\froman \\fswiss \\fmodern \
+    \\fscript \\fdecor MS Sans SerifSymbolArialTimes New RomanCourier{\\colortbl\\red0\\green0\\blue0\r\n\\par \
+    \\pard\\plain\\f0\\fs20\\b\\i\\u\\tab\\tx";
+const LZFU_COMPRESSED: u32 = 0x7546_5A4C;
+const LZFU_UNCOMPRESSED: u32 = 0x414C_454D;
+
+/// Content of a TNEF stream.
+#[derive(Debug, Clone, Default)]
+pub struct TnefMessage {
+    /// MAPI message class, e.g. `IPM.Note` or `IPM.Schedule.Meeting.Request`
+    pub message_class: Option<String>,
+    /// Message subject
+    pub subject: Option<String>,
+    /// Plain text body
+    pub body: Option<String>,
+    /// HTML body
+    pub html_body: Option<String>,
+    /// RTF body, decompressed
+    pub rtf_body: Option<String>,
+    /// The wrapped attachments
+    pub attachments: Vec<EmailAttachment>,
+    /// Meeting details, for meeting requests, cancellations and responses
+    pub meeting: Option<CalendarEvent>,
+}
+
+/// Whether `data` starts with the TNEF signature.
+pub fn is_tnef(data: &[u8]) -> bool {
+    data.starts_with(&TNEF_SIGNATURE.to_le_bytes())
+}
+
+/// Decode a TNEF stream.
+///
+/// Unknown attributes and properties are skipped, and a malformed property list only loses
+/// the properties it holds.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Parsing` if `data` is not a TNEF stream or is truncated.
+pub fn parse_tnef(data: &[u8]) -> Result<TnefMessage> {
+    if !is_tnef(data) {
+        return Err(KreuzbergError::parsing("Not a TNEF stream: signature missing"));
+    }
+
+    let mut reader = Reader::new(&data[4..]);
+    reader.u16()?;
+
+    let mut message = TnefMessage::default();
+    let mut message_properties = Vec::new();
+    let mut recipients = Vec::new();
+    let mut dates = (None, None);
+    let mut attachments: Vec<PendingAttachment> = Vec::new();
+
+    while !reader.is_empty() {
+        let level = reader.u8()?;
+        let tag = reader.u32()?;
+        let length = reader.u32()? as usize;
+        let value = reader.bytes(length)?;
+        reader.u16()?;
+
+        match (level, tag as u16) {
+            (LEVEL_MESSAGE, ATT_SUBJECT) => message.subject = decode_string8(value),
+            (LEVEL_MESSAGE, ATT_MESSAGE_CLASS) => message.message_class = decode_string8(value),
+            (LEVEL_MESSAGE, ATT_BODY) => message.body = decode_string8(value),
+            (LEVEL_MESSAGE, ATT_DATE_START) => dates.0 = decode_date(value),
+            (LEVEL_MESSAGE, ATT_DATE_END) => dates.1 = decode_date(value),
+            (LEVEL_MESSAGE, ATT_MSG_PROPS) => message_properties = parse_properties(value),
+            (LEVEL_MESSAGE, ATT_RECIP_TABLE) => recipients = lenient(parse_recipient_table(value)),
+            (LEVEL_ATTACHMENT, ATT_ATTACH_REND_DATA) => attachments.push(PendingAttachment::default()),
+            (LEVEL_ATTACHMENT, id) => {
+                if attachments.is_empty() {
+                    attachments.push(PendingAttachment::default());
+                }
+                let Some(attachment) = attachments.last_mut() else {
+                    continue;
+                };
+                match id {
+                    ATT_ATTACH_TITLE => attachment.title = decode_string8(value),
+                    ATT_ATTACH_DATA => attachment.data = Some(value.to_vec()),
+                    ATT_ATTACHMENT => attachment.properties = parse_properties(value),
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let properties = message_properties;
+    message.message_class = text_property(&properties, PR_MESSAGE_CLASS).or(message.message_class);
+    message.subject = text_property(&properties, PR_SUBJECT).or(message.subject);
+    message.body = text_property(&properties, PR_BODY).or(message.body);
+    message.html_body = text_property(&properties, PR_BODY_HTML);
+    message.rtf_body = binary_property(&properties, PR_RTF_COMPRESSED)
+        .and_then(decompress_rtf)
+        .map(|rtf| String::from_utf8_lossy(&rtf).into_owned());
+    message.meeting = meeting(&message, &properties, &recipients, dates);
+    message.attachments = attachments.into_iter().filter_map(PendingAttachment::finish).collect();
+
+    Ok(message)
+}
+
+/// Attachment attributes collected until the stream ends.
+#[derive(Default)]
+struct PendingAttachment {
+    title: Option<String>,
+    data: Option<Vec<u8>>,
+    properties: Vec<Property>,
+}
+
+impl PendingAttachment {
+    fn finish(self) -> Option<EmailAttachment> {
+        let properties = &self.properties;
+        let data = self
+            .data
+            .or_else(|| binary_property(properties, PR_ATTACH_DATA).map(<[u8]>::to_vec))?;
+        let filename = text_property(properties, PR_ATTACH_LONG_FILENAME)
+            .or_else(|| text_property(properties, PR_ATTACH_FILENAME))
+            .or(self.title)
+            .or_else(|| text_property(properties, PR_DISPLAY_NAME));
+        let mime_type = text_property(properties, PR_ATTACH_MIME_TAG)
+            .map(|mime_type| mime_type.to_ascii_lowercase())
+            .or_else(|| {
+                let name = filename.as_deref()?;
+                crate::core::mime::detect_mime_type(name, false).ok()
+            });
+        let content_id = text_property(properties, PR_ATTACH_CONTENT_ID);
+
+        Some(EmailAttachment {
+            name: filename.clone(),
+            filename,
+            is_image: mime_type.as_deref().is_some_and(is_image_mime_type),
+            mime_type,
+            size: Some(data.len()),
+            data: Some(data),
+            content_id,
+        })
+    }
+}
+
+/// Meeting details of a meeting request, cancellation or response.
+fn meeting(
+    message: &TnefMessage,
+    properties: &[Property],
+    recipients: &[Vec<Property>],
+    (date_start, date_end): (Option<String>, Option<String>),
+) -> Option<CalendarEvent> {
+    let method = meeting_method(message.message_class.as_deref()?)?;
+
+    let start = time_property(properties, PID_LID_APPOINTMENT_START_WHOLE, PR_START_DATE);
+    let end = time_property(properties, PID_LID_APPOINTMENT_END_WHOLE, PR_END_DATE);
+    let location = appointment_property(properties, PID_LID_LOCATION).and_then(PropertyValue::text);
+    let organizer_name = text_property(properties, PR_SENT_REPRESENTING_NAME);
+    let organizer_address = first_text_property(properties, &ORGANIZER_ADDRESS_PROPERTIES);
+    let attendees = recipients
+        .iter()
+        .filter_map(|recipient| {
+            let name = text_property(recipient, PR_DISPLAY_NAME);
+            let address = first_text_property(recipient, &[PR_SMTP_ADDRESS, PR_EMAIL_ADDRESS]);
+            format_participant(name.as_deref(), address.as_deref())
+        })
+        .collect();
+
+    Some(CalendarEvent {
+        method: Some(method.to_string()),
+        summary: message.subject.clone(),
+        start: start.or(date_start),
+        end: end.or(date_end),
+        location,
+        organizer: format_participant(organizer_name.as_deref(), organizer_address.as_deref()),
+        attendees,
+        description: message.body.clone().filter(|body| !body.trim().is_empty()),
+        ..Default::default()
+    })
+}
+
+/// The iCalendar METHOD equivalent of a meeting message class.
+fn meeting_method(message_class: &str) -> Option<&'static str> {
+    let class = message_class.to_ascii_lowercase();
+    if class.starts_with("ipm.schedule.meeting.request") || class == "ipm.microsoft schedule.mtgreq" {
+        Some("REQUEST")
+    } else if class.starts_with("ipm.schedule.meeting.canceled") || class == "ipm.microsoft schedule.mtgcncl" {
+        Some("CANCEL")
+    } else if class.starts_with("ipm.schedule.meeting.resp") || class.starts_with("ipm.microsoft schedule.mtgresp") {
+        Some("REPLY")
+    } else {
+        None
+    }
+}
+
+/// Parse a property list attribute, dropping it when it is malformed.
+fn parse_properties(data: &[u8]) -> Vec<Property> {
+    lenient(parse_property_list(&mut Reader::new(data)))
+}
+
+fn lenient<T: Default>(result: Result<T>) -> T {
+    result.unwrap_or_else(|e| {
+        tracing::debug!("Skipping malformed TNEF property list: {}", e);
+        T::default()
+    })
+}
+
+struct Property {
+    id: u16,
+    /// GUID and numeric ID of a named property
+    named: Option<([u8; 16], u32)>,
+    value: PropertyValue,
+}
+
+enum PropertyValue {
+    Text(String),
+    Binary(Vec<u8>),
+    Time(u64),
+    Other,
+}
+
+impl PropertyValue {
+    fn text(&self) -> Option<String> {
+        match self {
+            PropertyValue::Text(text) => Some(text.clone()).filter(|text| !text.is_empty()),
+            PropertyValue::Binary(bytes) => decode_string8(bytes),
+            _ => None,
+        }
+    }
+}
+
+fn find_property(properties: &[Property], id: u16) -> Option<&PropertyValue> {
+    properties
+        .iter()
+        .find(|property| property.named.is_none() && property.id == id)
+        .map(|property| &property.value)
+}
+
+fn appointment_property(properties: &[Property], lid: u32) -> Option<&PropertyValue> {
+    properties
+        .iter()
+        .find(|property| property.named == Some((PSETID_APPOINTMENT, lid)))
+        .map(|property| &property.value)
+}
+
+fn text_property(properties: &[Property], id: u16) -> Option<String> {
+    find_property(properties, id).and_then(PropertyValue::text)
+}
+
+fn first_text_property(properties: &[Property], ids: &[u16]) -> Option<String> {
+    ids.iter().find_map(|&id| text_property(properties, id))
+}
+
+fn binary_property(properties: &[Property], id: u16) -> Option<&[u8]> {
+    match find_property(properties, id)? {
+        PropertyValue::Binary(bytes) => Some(bytes),
+        _ => None,
+    }
+}
+
+/// A time from the named appointment property `lid`, or else the property `id`.
+fn time_property(properties: &[Property], lid: u32, id: u16) -> Option<String> {
+    let value = appointment_property(properties, lid).or_else(|| find_property(properties, id))?;
+    match value {
+        PropertyValue::Time(filetime) => format_filetime(*filetime),
+        _ => None,
+    }
+}
+
+/// Parse a MAPI property list (MS-OXTNEF section 2.1.3.5).
+fn parse_property_list(reader: &mut Reader<'_>) -> Result<Vec<Property>> {
+    let count = reader.u32()?;
+    let mut properties = Vec::new();
+
+    for _ in 0..count {
+        let property_type = reader.u16()?;
+        let id = reader.u16()?;
+
+        let mut named = None;
+        if id >= 0x8000 {
+            let mut guid = [0u8; 16];
+            guid.copy_from_slice(reader.bytes(16)?);
+            match reader.u32()? {
+                0 => named = Some((guid, reader.u32()?)),
+                _ => {
+                    let length = reader.u32()? as usize;
+                    reader.bytes(length)?;
+                    reader.align(length)?;
+                }
+            }
+        }
+
+        let base_type = property_type & !MV_FLAG;
+        let is_variable = matches!(base_type, PT_STRING8 | PT_UNICODE | PT_BINARY | PT_OBJECT);
+        let values = if property_type & MV_FLAG != 0 || is_variable {
+            reader.u32()?
+        } else {
+            1
+        };
+
+        let mut value = PropertyValue::Other;
+        for index in 0..values {
+            let parsed = read_property_value(reader, base_type)?;
+            if index == 0 {
+                value = parsed;
+            }
+        }
+
+        properties.push(Property { id, named, value });
+    }
+
+    Ok(properties)
+}
+
+fn read_property_value(reader: &mut Reader<'_>, property_type: u16) -> Result<PropertyValue> {
+    let value = match property_type {
+        PT_SHORT | PT_LONG | PT_FLOAT | PT_ERROR | PT_BOOLEAN => {
+            reader.bytes(4)?;
+            PropertyValue::Other
+        }
+        PT_SYSTIME => PropertyValue::Time(reader.u64()?),
+        PT_DOUBLE | PT_CURRENCY | PT_APPTIME | PT_LONGLONG => {
+            reader.bytes(8)?;
+            PropertyValue::Other
+        }
+        PT_CLSID => {
+            reader.bytes(16)?;
+            PropertyValue::Other
+        }
+        PT_STRING8 | PT_UNICODE | PT_BINARY | PT_OBJECT => {
+            let length = reader.u32()? as usize;
+            let bytes = reader.bytes(length)?;
+            reader.align(length)?;
+            match property_type {
+                PT_STRING8 => PropertyValue::Text(decode_string8(bytes).unwrap_or_default()),
+                PT_UNICODE => PropertyValue::Text(decode_unicode(bytes)),
+                // Object data starts with the interface identifier of the object
+                PT_OBJECT => PropertyValue::Binary(bytes.get(16..).unwrap_or_default().to_vec()),
+                _ => PropertyValue::Binary(bytes.to_vec()),
+            }
+        }
+        other => {
+            return Err(KreuzbergError::parsing(format!(
+                "Unsupported TNEF property type 0x{:04X}",
+                other
+            )));
+        }
+    };
+    Ok(value)
+}
+
+/// Parse the recipient table (attRecipTable) into one property list per recipient.
+fn parse_recipient_table(data: &[u8]) -> Result<Vec<Vec<Property>>> {
+    let mut reader = Reader::new(data);
+    let rows = reader.u32()?;
+    (0..rows).map(|_| parse_property_list(&mut reader)).collect()
+}
+
+/// Decode a null-terminated 8-bit string; the OEM code page is assumed to be ASCII compatible.
+fn decode_string8(bytes: &[u8]) -> Option<String> {
+    let end = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+    let bytes = &bytes[..end];
+    let text = match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes.iter().map(|&b| b as char).collect(),
+    };
+    Some(text.trim().to_string()).filter(|text| !text.is_empty())
+}
+
+fn decode_unicode(bytes: &[u8]) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .take_while(|&unit| unit != 0)
+        .collect();
+    String::from_utf16_lossy(&units).trim().to_string()
+}
+
+/// Decode a TNEF date (year, month, day, hour, minute, second and weekday as u16 values).
+fn decode_date(bytes: &[u8]) -> Option<String> {
+    if bytes.len() < 12 {
+        return None;
+    }
+    let field = |index: usize| u16::from_le_bytes([bytes[index * 2], bytes[index * 2 + 1]]);
+    let (year, month, day) = (field(0), field(1), field(2));
+    if year == 0 || month == 0 || day == 0 {
+        return None;
+    }
+    Some(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        field(3),
+        field(4),
+        field(5)
+    ))
+}
+
+/// Format a FILETIME (100 ns intervals since 1601-01-01 UTC) as an ISO 8601 UTC time.
+fn format_filetime(filetime: u64) -> Option<String> {
+    let secs = (filetime / 10_000_000).checked_sub(FILETIME_UNIX_OFFSET_SECS)?;
+    let (year, month, day) = civil_from_days((secs / 86_400) as i64);
+    let (hour, minute, second) = (secs % 86_400 / 3600, secs % 3600 / 60, secs % 60);
+    Some(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year, month, day, hour, minute, second
+    ))
+}
+
+/// Convert days since 1970-01-01 to a (year, month, day) date in the proleptic Gregorian calendar.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month as u32, day)
+}
+
+/// Decompress `PR_RTF_COMPRESSED` (MS-OXRTFCP).
+fn decompress_rtf(data: &[u8]) -> Option<Vec<u8>> {
+    let mut reader = Reader::new(data);
+    let _compressed_size = reader.u32().ok()?;
+    let raw_size = reader.u32().ok()? as usize;
+    let compression = reader.u32().ok()?;
+    let _crc = reader.u32().ok()?;
+    let input = reader.rest();
+
+    if compression == LZFU_UNCOMPRESSED {
+        return Some(input[..raw_size.min(input.len())].to_vec());
+    }
+    if compression != LZFU_COMPRESSED {
+        return None;
+    }
+
+    let mut dictionary = [0u8; 4096];
+    dictionary[..LZFU_PRELOAD.len()].copy_from_slice(LZFU_PRELOAD);
+    let mut write_position = LZFU_PRELOAD.len();
+    let mut output = Vec::with_capacity(raw_size);
+    let mut input = input.iter().copied();
+
+    'blocks: while let Some(control) = input.next() {
+        for bit in 0..8 {
+            if control & (1 << bit) == 0 {
+                let Some(byte) = input.next() else {
+                    break 'blocks;
+                };
+                output.push(byte);
+                dictionary[write_position] = byte;
+                write_position = (write_position + 1) % dictionary.len();
+                continue;
+            }
+
+            let (Some(high), Some(low)) = (input.next(), input.next()) else {
+                break 'blocks;
+            };
+            let reference = u16::from_be_bytes([high, low]);
+            let mut offset = usize::from(reference >> 4);
+            if offset == write_position {
+                break 'blocks;
+            }
+            for _ in 0..usize::from(reference & 0xF) + 2 {
+                let byte = dictionary[offset];
+                output.push(byte);
+                dictionary[write_position] = byte;
+                write_position = (write_position + 1) % dictionary.len();
+                offset = (offset + 1) % dictionary.len();
+            }
+        }
+    }
+
+    output.truncate(raw_size);
+    Some(output)
+}
+
+/// Little-endian reader over a TNEF stream.
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.position >= self.data.len()
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.data[self.position.min(self.data.len())..]
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8]> {
+        let end = self
+            .position
+            .checked_add(length)
+            .filter(|&end| end <= self.data.len())
+            .ok_or_else(|| KreuzbergError::parsing("Truncated TNEF stream"))?;
+        let bytes = &self.data[self.position..end];
+        self.position = end;
+        Ok(bytes)
+    }
+
+    /// Skip the padding after a value of `length` bytes, which is aligned to 4 bytes.
+    fn align(&mut self, length: usize) -> Result<()> {
+        self.bytes((4 - length % 4) % 4).map(|_| ())
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        let bytes = self.bytes(2)?;
+        Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        let bytes = self.bytes(4)?;
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from(self.u32()?) | (u64::from(self.u32()?) << 32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds TNEF streams attribute by attribute.
+    struct TnefBuilder(Vec<u8>);
+
+    impl TnefBuilder {
+        fn new() -> Self {
+            let mut data = TNEF_SIGNATURE.to_le_bytes().to_vec();
+            data.extend_from_slice(&0x0001u16.to_le_bytes());
+            Self(data)
+        }
+
+        fn attribute(mut self, level: u8, id: u16, attribute_type: u16, value: &[u8]) -> Self {
+            self.0.push(level);
+            self.0.extend_from_slice(&id.to_le_bytes());
+            self.0.extend_from_slice(&attribute_type.to_le_bytes());
+            self.0.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            self.0.extend_from_slice(value);
+            let checksum = value.iter().fold(0u16, |sum, &b| sum.wrapping_add(u16::from(b)));
+            self.0.extend_from_slice(&checksum.to_le_bytes());
+            self
+        }
+    }
+
+    fn string8(text: &str) -> Vec<u8> {
+        let mut bytes = text.as_bytes().to_vec();
+        bytes.push(0);
+        bytes
+    }
+
+    fn property_list(properties: &[Vec<u8>]) -> Vec<u8> {
+        let mut data = (properties.len() as u32).to_le_bytes().to_vec();
+        for property in properties {
+            data.extend_from_slice(property);
+        }
+        data
+    }
+
+    fn string_property(id: u16, text: &str) -> Vec<u8> {
+        variable_property(PT_STRING8, id, &string8(text))
+    }
+
+    fn variable_property(property_type: u16, id: u16, value: &[u8]) -> Vec<u8> {
+        let mut data = property_type.to_le_bytes().to_vec();
+        data.extend_from_slice(&id.to_le_bytes());
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        data.extend_from_slice(value);
+        data.resize(data.len() + (4 - value.len() % 4) % 4, 0);
+        data
+    }
+
+    fn systime_property(id: u16, filetime: u64) -> Vec<u8> {
+        let mut data = PT_SYSTIME.to_le_bytes().to_vec();
+        data.extend_from_slice(&id.to_le_bytes());
+        data.extend_from_slice(&filetime.to_le_bytes());
+        data
+    }
+
+    /// Turn a property built with ID 0x8000 into the named appointment property `lid`.
+    fn named_appointment_property(lid: u32, property: Vec<u8>) -> Vec<u8> {
+        let mut data = property[..4].to_vec();
+        data.extend_from_slice(&PSETID_APPOINTMENT);
+        data.extend_from_slice(&0u32.to_le_bytes());
+        data.extend_from_slice(&lid.to_le_bytes());
+        data.extend_from_slice(&property[4..]);
+        data
+    }
+
+    fn filetime(unix_secs: u64) -> u64 {
+        (unix_secs + FILETIME_UNIX_OFFSET_SECS) * 10_000_000
+    }
+
+    #[test]
+    fn test_parse_tnef_attachments() {
+        let attachment_properties = property_list(&[
+            string_property(PR_ATTACH_LONG_FILENAME, "quarterly report.pdf"),
+            string_property(PR_ATTACH_MIME_TAG, "application/pdf"),
+        ]);
+        let tnef = TnefBuilder::new()
+            .attribute(LEVEL_MESSAGE, ATT_SUBJECT, 0x0001, &string8("Report"))
+            .attribute(LEVEL_MESSAGE, ATT_BODY, 0x0002, &string8("See attached."))
+            .attribute(LEVEL_ATTACHMENT, ATT_ATTACH_REND_DATA, 0x0006, &[0; 14])
+            .attribute(LEVEL_ATTACHMENT, ATT_ATTACH_TITLE, 0x0001, &string8("QUARTE~1.PDF"))
+            .attribute(LEVEL_ATTACHMENT, ATT_ATTACH_DATA, 0x0006, b"%PDF-1.7")
+            .attribute(LEVEL_ATTACHMENT, ATT_ATTACHMENT, 0x0006, &attachment_properties)
+            .attribute(LEVEL_ATTACHMENT, ATT_ATTACH_REND_DATA, 0x0006, &[0; 14])
+            .attribute(LEVEL_ATTACHMENT, ATT_ATTACH_TITLE, 0x0001, &string8("logo.png"))
+            .attribute(LEVEL_ATTACHMENT, ATT_ATTACH_DATA, 0x0006, b"\x89PNG")
+            .0;
+
+        assert!(is_tnef(&tnef));
+        let message = parse_tnef(&tnef).unwrap();
+
+        assert_eq!(message.subject.as_deref(), Some("Report"));
+        assert_eq!(message.body.as_deref(), Some("See attached."));
+        assert!(message.meeting.is_none());
+        assert_eq!(message.attachments.len(), 2);
+        assert_eq!(message.attachments[0].filename.as_deref(), Some("quarterly report.pdf"));
+        assert_eq!(message.attachments[0].mime_type.as_deref(), Some("application/pdf"));
+        assert_eq!(message.attachments[0].data.as_deref(), Some(&b"%PDF-1.7"[..]));
+        assert_eq!(message.attachments[1].filename.as_deref(), Some("logo.png"));
+        assert!(message.attachments[1].is_image);
+    }
+
+    #[test]
+    fn test_parse_tnef_meeting_request() {
+        let (start, end) = (filetime(1_757_944_800), filetime(1_757_948_400));
+        let message_properties = property_list(&[
+            string_property(PR_MESSAGE_CLASS, "IPM.Schedule.Meeting.Request"),
+            string_property(PR_SENT_REPRESENTING_NAME, "Jane Doe"),
+            string_property(PR_SENT_REPRESENTING_SMTP_ADDRESS, "jane@example.com"),
+            named_appointment_property(PID_LID_APPOINTMENT_START_WHOLE, systime_property(0x8000, start)),
+            named_appointment_property(PID_LID_APPOINTMENT_END_WHOLE, systime_property(0x8000, end)),
+            named_appointment_property(PID_LID_LOCATION, string_property(0x8000, "Room 4")),
+        ]);
+
+        let mut recipients = 1u32.to_le_bytes().to_vec();
+        recipients.extend_from_slice(&property_list(&[
+            variable_property(PT_UNICODE, PR_DISPLAY_NAME, &utf16("Bob")),
+            string_property(PR_SMTP_ADDRESS, "bob@example.com"),
+        ]));
+
+        let tnef = TnefBuilder::new()
+            .attribute(LEVEL_MESSAGE, ATT_SUBJECT, 0x0001, &string8("Quarterly review"))
+            .attribute(LEVEL_MESSAGE, ATT_RECIP_TABLE, 0x0006, &recipients)
+            .attribute(LEVEL_MESSAGE, ATT_MSG_PROPS, 0x0006, &message_properties)
+            .0;
+
+        let meeting = parse_tnef(&tnef).unwrap().meeting.unwrap();
+
+        assert_eq!(meeting.method.as_deref(), Some("REQUEST"));
+        assert_eq!(meeting.summary.as_deref(), Some("Quarterly review"));
+        assert_eq!(meeting.start.as_deref(), Some("2025-09-15T14:00:00Z"));
+        assert_eq!(meeting.end.as_deref(), Some("2025-09-15T15:00:00Z"));
+        assert_eq!(meeting.location.as_deref(), Some("Room 4"));
+        assert_eq!(meeting.organizer.as_deref(), Some("Jane Doe <jane@example.com>"));
+        assert_eq!(meeting.attendees, vec!["Bob <bob@example.com>"]);
+    }
+
+    fn utf16(text: &str) -> Vec<u8> {
+        text.encode_utf16().chain([0]).flat_map(u16::to_le_bytes).collect()
+    }
+
+    #[test]
+    fn test_decompress_rtf() {
+        // Example from MS-OXRTFCP section 4.1
+        let compressed = [
+            0x2d, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x00, 0x00, 0x4c, 0x5a, 0x46, 0x75, 0xf1, 0xc5, 0xc7, 0xa7, 0x03, 0x00,
+            0x0a, 0x00, 0x72, 0x63, 0x70, 0x67, 0x31, 0x32, 0x35, 0x42, 0x32, 0x0a, 0xf3, 0x20, 0x68, 0x65, 0x6c, 0x09,
+            0x00, 0x20, 0x62, 0x77, 0x05, 0xb0, 0x6c, 0x64, 0x7d, 0x0a, 0x80, 0x0f, 0xa0,
+        ];
+
+        let rtf = decompress_rtf(&compressed).unwrap();
+
+        assert_eq!(rtf, b"{\\rtf1\\ansi\\ansicpg1252\\pard hello world}\r\n");
+    }
+
+    #[test]
+    fn test_format_filetime() {
+        assert_eq!(format_filetime(filetime(0)).as_deref(), Some("1970-01-01T00:00:00Z"));
+        let leap_day = filetime(951_782_400);
+        assert_eq!(format_filetime(leap_day).as_deref(), Some("2000-02-29T00:00:00Z"));
+        assert_eq!(format_filetime(0), None);
+    }
+
+    #[test]
+    fn test_parse_tnef_rejects_other_data() {
+        assert!(!is_tnef(b"PK\x03\x04"));
+        assert!(parse_tnef(b"PK\x03\x04").is_err());
+        assert!(parse_tnef(&TnefBuilder::new().0[..5]).is_err());
+    }
+}
@@ -9,6 +9,8 @@
 //! - **Element tracking**: Counts total elements and unique element names
 //! - **Text extraction**: Extracts text content while filtering XML structure
 //! - **Whitespace handling**: Optional whitespace preservation
+//! - **XPath mapping**: [`map_xml`] turns configured XPath expressions into named fields and
+//!   content sections (see [`XmlDocumentType`])
 //!
 //! # Example
 //!
@@ -24,12 +26,15 @@
 //! # Ok(())
 //! # }
 //! ```
+use super::xpath::XPath;
+use crate::core::config::XmlDocumentType;
 use crate::error::{KreuzbergError, Result};
 use crate::types::XmlExtractionResult;
 use quick_xml::Reader;
 use quick_xml::events::Event;
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 pub fn parse_xml(xml_bytes: &[u8], preserve_whitespace: bool) -> Result<XmlExtractionResult> {
     let mut reader = Reader::from_reader(xml_bytes);
@@ -91,6 +96,105 @@ pub fn parse_xml(xml_bytes: &[u8], preserve_whitespace: bool) -> Result<XmlExtra
     })
 }
 
+/// Fields and sections of an XML document, selected by an [`XmlDocumentType`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct XmlMappedDocument {
+    /// Name of the matching document type
+    pub document_type: String,
+    /// Field values: a string for one match, a list for several, null for none
+    pub fields: BTreeMap<String, serde_json::Value>,
+    /// Section titles with their paragraphs, in configured order; sections without matches
+    /// are left out
+    pub sections: Vec<(String, Vec<String>)>,
+}
+
+impl XmlMappedDocument {
+    /// The sections as Markdown: a level-2 heading per section followed by its paragraphs.
+    pub fn to_markdown(&self) -> String {
+        self.sections
+            .iter()
+            .map(|(title, paragraphs)| format!("## {}\n\n{}", title, paragraphs.join("\n\n")))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+/// Check that the root, field and section expressions of `document_type` compile.
+///
+/// # Errors
+///
+/// Returns a `Validation` error naming the first invalid expression.
+pub fn check_document_type(document_type: &XmlDocumentType) -> Result<()> {
+    let namespaces = &document_type.namespaces;
+    let expressions = document_type
+        .root
+        .iter()
+        .chain(document_type.fields.values())
+        .chain(document_type.sections.iter().map(|section| &section.path));
+    for expression in expressions {
+        XPath::parse(expression, namespaces)?;
+    }
+    Ok(())
+}
+
+/// Map an XML document with the first of `document_types` whose `root` matches its root element.
+///
+/// Returns `None` when no type matches. Unlike [`parse_xml`], this parses the whole document
+/// into memory.
+///
+/// # Errors
+///
+/// Returns a `Parsing` error for documents that are not well-formed UTF-8 XML and a
+/// `Validation` error for invalid expressions.
+pub fn map_xml(xml_bytes: &[u8], document_types: &[XmlDocumentType]) -> Result<Option<XmlMappedDocument>> {
+    if document_types.is_empty() {
+        return Ok(None);
+    }
+    let text = std::str::from_utf8(xml_bytes)
+        .map_err(|e| KreuzbergError::parsing(format!("XML document is not valid UTF-8: {}", e)))?;
+    let options = roxmltree::ParsingOptions {
+        allow_dtd: true,
+        ..Default::default()
+    };
+    let doc = roxmltree::Document::parse_with_options(text, options)
+        .map_err(|e| KreuzbergError::parsing(format!("XML parsing error: {}", e)))?;
+
+    for document_type in document_types {
+        let namespaces = &document_type.namespaces;
+        if let Some(root) = &document_type.root
+            && !XPath::parse(root, namespaces)?.matches_element(doc.root_element())
+        {
+            continue;
+        }
+
+        let mut fields = BTreeMap::new();
+        for (name, expression) in &document_type.fields {
+            let mut values = XPath::parse(expression, namespaces)?.values(doc.root());
+            let value = match values.len() {
+                0 => serde_json::Value::Null,
+                1 => serde_json::Value::String(values.remove(0)),
+                _ => serde_json::Value::from(values),
+            };
+            fields.insert(name.clone(), value);
+        }
+
+        let mut sections = Vec::new();
+        for section in &document_type.sections {
+            let paragraphs = XPath::parse(&section.path, namespaces)?.values(doc.root());
+            if !paragraphs.is_empty() {
+                sections.push((section.title.clone(), paragraphs));
+            }
+        }
+
+        return Ok(Some(XmlMappedDocument {
+            document_type: document_type.name.clone(),
+            fields,
+            sections,
+        }));
+    }
+    Ok(None)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -330,4 +434,85 @@ mod tests {
         let result = parse_xml(xml, false);
         let _ = result;
     }
+
+    fn jats_article() -> XmlDocumentType {
+        serde_json::from_value(serde_json::json!({
+            "name": "jats",
+            "root": "article",
+            "fields": {
+                "title": "/article/front/article-meta/title-group/article-title",
+                "authors": "//contrib[@contrib-type='author']/name/surname",
+                "doi": "//article-id[@pub-id-type='doi']",
+                "volume": "//volume"
+            },
+            "sections": [
+                {"title": "Abstract", "path": "//abstract/p"},
+                {"title": "Body", "path": "/article/body//p"},
+                {"title": "Acknowledgements", "path": "//ack/p"}
+            ]
+        }))
+        .unwrap()
+    }
+
+    const ARTICLE: &[u8] = br#"<!DOCTYPE article PUBLIC "-//NLM//DTD JATS//EN" "JATS-journalpublishing1.dtd">
+<article xmlns:xlink="http://www.w3.org/1999/xlink">
+  <front><article-meta>
+    <article-id pub-id-type="doi">10.1000/xyz123</article-id>
+    <title-group><article-title>On <italic>Streaming</italic> Parsers</article-title></title-group>
+    <contrib-group>
+      <contrib contrib-type="author"><name><surname>Lovelace</surname></name></contrib>
+      <contrib contrib-type="author"><name><surname>Hopper</surname></name></contrib>
+      <contrib contrib-type="editor"><name><surname>Turing</surname></name></contrib>
+    </contrib-group>
+    <abstract><p>We study parsers.</p></abstract>
+  </article-meta></front>
+  <body><sec><title>Intro</title><p>First paragraph.</p><p>Second paragraph.</p></sec></body>
+</article>"#;
+
+    #[test]
+    fn test_map_xml_fields_and_sections() {
+        let mapped = map_xml(ARTICLE, &[jats_article()]).unwrap().unwrap();
+
+        assert_eq!(mapped.document_type, "jats");
+        assert_eq!(mapped.fields["title"], "On Streaming Parsers");
+        assert_eq!(mapped.fields["authors"], serde_json::json!(["Lovelace", "Hopper"]));
+        assert_eq!(mapped.fields["doi"], "10.1000/xyz123");
+        assert!(mapped.fields["volume"].is_null());
+        assert_eq!(
+            mapped.to_markdown(),
+            "## Abstract\n\nWe study parsers.\n\n## Body\n\nFirst paragraph.\n\nSecond paragraph."
+        );
+    }
+
+    #[test]
+    fn test_map_xml_matches_namespaced_root() {
+        let invoice: XmlDocumentType = serde_json::from_value(serde_json::json!({
+            "name": "ubl-invoice",
+            "root": "ubl:Invoice",
+            "namespaces": {
+                "ubl": "urn:oasis:names:specification:ubl:schema:xsd:Invoice-2",
+                "cbc": "urn:oasis:names:specification:ubl:schema:xsd:CommonBasicComponents-2"
+            },
+            "fields": {"number": "/ubl:Invoice/cbc:ID"}
+        }))
+        .unwrap();
+        let xml = br#"<Invoice xmlns="urn:oasis:names:specification:ubl:schema:xsd:Invoice-2"
+            xmlns:cbc="urn:oasis:names:specification:ubl:schema:xsd:CommonBasicComponents-2">
+            <cbc:ID>42</cbc:ID>
+        </Invoice>"#;
+
+        let mapped = map_xml(xml, &[jats_article(), invoice.clone()]).unwrap().unwrap();
+        assert_eq!(mapped.document_type, "ubl-invoice");
+        assert_eq!(mapped.fields["number"], "42");
+
+        assert!(map_xml(ARTICLE, &[invoice]).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_check_document_type_rejects_invalid_expressions() {
+        let mut document_type = jats_article();
+        assert!(check_document_type(&document_type).is_ok());
+        document_type.fields.insert("bad".to_string(), "//x:title".to_string());
+        assert!(check_document_type(&document_type).is_err());
+    }
 }
@@ -0,0 +1,516 @@
+//! A namespace-aware subset of XPath 1.0 for selecting values from XML documents.
+//!
+//! Supported are location paths made of these parts:
+//!
+//! - **Steps**: `name`, `prefix:name`, `*`, `prefix:*`, `@attr`, `@*`, `text()`, `node()`, `.`, `..`
+//! - **Separators**: `/` (child) and `//` (descendant-or-self), absolute or relative
+//! - **Predicates**: `[2]`, `[last()]`, `[@attr]`, `[path]`, `[@attr='value']`, `[path!='value']`
+//! - **Unions**: `path | path`
+//!
+//! Prefixes are resolved through the namespace map given to [`XPath::parse`]. Names without a
+//! prefix match by local name in any namespace, so documents with a default namespace (UBL,
+//! JATS) do not need one declared.
+//!
+//! # Example
+//!
+//! ```rust
+//! use kreuzberg::extraction::xpath::XPath;
+//! use std::collections::BTreeMap;
+//!
+//! # fn example() -> kreuzberg::Result<()> {
+//! let xml = r#"<Invoice xmlns:cbc="urn:cbc"><cbc:ID>INV-7</cbc:ID><Line id="1"/><Line id="2"/></Invoice>"#;
+//! let doc = roxmltree::Document::parse(xml).unwrap();
+//! let namespaces = BTreeMap::from([("cbc".to_string(), "urn:cbc".to_string())]);
+//!
+//! assert_eq!(XPath::parse("/Invoice/cbc:ID", &namespaces)?.values(doc.root()), vec!["INV-7"]);
+//! assert_eq!(XPath::parse("//Line[last()]/@id", &namespaces)?.values(doc.root()), vec!["2"]);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{KreuzbergError, Result};
+use roxmltree::{Node, NodeId};
+use std::collections::BTreeMap;
+
+/// Namespace bound to the `xml` prefix in every document.
+const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+
+/// A compiled XPath expression.
+#[derive(Debug, Clone)]
+pub struct XPath {
+    paths: Vec<LocationPath>,
+}
+
+#[derive(Debug, Clone)]
+struct LocationPath {
+    absolute: bool,
+    steps: Vec<Step>,
+}
+
+#[derive(Debug, Clone)]
+struct Step {
+    axis: Axis,
+    test: NodeTest,
+    predicates: Vec<Predicate>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Child,
+    Attribute,
+    DescendantOrSelf,
+    Parent,
+    SelfNode,
+}
+
+#[derive(Debug, Clone)]
+enum NodeTest {
+    /// Element or attribute name; `None` matches any namespace or any local name
+    Name {
+        namespace: Option<String>,
+        local: Option<String>,
+    },
+    Text,
+    AnyNode,
+}
+
+#[derive(Debug, Clone)]
+enum Predicate {
+    Position(usize),
+    Last,
+    Exists(LocationPath),
+    Compare {
+        path: LocationPath,
+        value: String,
+        negate: bool,
+    },
+}
+
+/// A selected node or attribute.
+#[derive(Debug, Clone, Copy)]
+enum Item<'a, 'input> {
+    Node(Node<'a, 'input>),
+    Attribute {
+        owner: Node<'a, 'input>,
+        index: usize,
+        value: &'a str,
+    },
+}
+
+impl Item<'_, '_> {
+    /// Document-order key, also used to drop duplicates.
+    fn key(&self) -> (NodeId, usize) {
+        match self {
+            Item::Node(node) => (node.id(), 0),
+            Item::Attribute { owner, index, .. } => (owner.id(), index + 1),
+        }
+    }
+
+    /// XPath string value: the concatenated text of an element, the text of a text node or
+    /// the value of an attribute.
+    fn string_value(&self) -> String {
+        match self {
+            Item::Node(node) if node.is_text() => node.text().unwrap_or_default().to_string(),
+            Item::Node(node) => node
+                .descendants()
+                .filter(|descendant| descendant.is_text())
+                .filter_map(|descendant| descendant.text())
+                .collect(),
+            Item::Attribute { value, .. } => value.to_string(),
+        }
+    }
+}
+
+impl XPath {
+    /// Compile `expression`, resolving its prefixes through `namespaces` (prefix to URI).
+    ///
+    /// # Errors
+    ///
+    /// Returns a `Validation` error for syntax outside the supported subset and for undeclared
+    /// prefixes.
+    pub fn parse(expression: &str, namespaces: &BTreeMap<String, String>) -> Result<Self> {
+        let invalid = |reason: &str| KreuzbergError::validation(format!("Invalid XPath '{}': {}", expression, reason));
+        let paths = split_top_level(expression, "|")
+            .into_iter()
+            .map(|path| parse_path(path.trim(), namespaces).map_err(|reason| invalid(&reason)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { paths })
+    }
+
+    /// String values of the nodes selected from `context`, in document order, with whitespace
+    /// collapsed. Empty values are dropped.
+    pub fn values(&self, context: Node) -> Vec<String> {
+        let mut items = Vec::new();
+        for path in &self.paths {
+            items.extend(evaluate(path, Item::Node(context)));
+        }
+        sort_unique(&mut items);
+        items
+            .iter()
+            .map(|item| collapse_whitespace(&item.string_value()))
+            .filter(|value| !value.is_empty())
+            .collect()
+    }
+
+    /// Whether `node` is the element named by a single-step expression such as `ubl:Invoice`.
+    pub(crate) fn matches_element(&self, node: Node) -> bool {
+        self.paths.iter().any(|path| match path.steps.as_slice() {
+            [step] if step.axis == Axis::Child && step.predicates.is_empty() => {
+                node.is_element() && name_matches(&step.test, node.tag_name().namespace(), node.tag_name().name())
+            }
+            _ => false,
+        })
+    }
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn sort_unique(items: &mut Vec<Item>) {
+    items.sort_by_key(Item::key);
+    items.dedup_by_key(|item| item.key());
+}
+
+fn evaluate<'a, 'input>(path: &LocationPath, context: Item<'a, 'input>) -> Vec<Item<'a, 'input>> {
+    let mut current = match (path.absolute, context) {
+        (true, Item::Node(node)) => vec![Item::Node(node.document().root())],
+        (true, Item::Attribute { owner, .. }) => vec![Item::Node(owner.document().root())],
+        (false, item) => vec![item],
+    };
+    for step in &path.steps {
+        let mut next = Vec::new();
+        for item in current {
+            let candidates = select(step, item);
+            let size = candidates.len();
+            next.extend(
+                candidates
+                    .into_iter()
+                    .enumerate()
+                    .filter(|(index, candidate)| {
+                        step.predicates
+                            .iter()
+                            .all(|predicate| predicate_holds(predicate, *candidate, index + 1, size))
+                    })
+                    .map(|(_, candidate)| candidate),
+            );
+        }
+        sort_unique(&mut next);
+        current = next;
+    }
+    current
+}
+
+fn select<'a, 'input>(step: &Step, item: Item<'a, 'input>) -> Vec<Item<'a, 'input>> {
+    let node = match (step.axis, item) {
+        (Axis::SelfNode, item) => return vec![item],
+        (Axis::Parent, Item::Attribute { owner, .. }) => return vec![Item::Node(owner)],
+        (_, Item::Attribute { .. }) => return Vec::new(),
+        (_, Item::Node(node)) => node,
+    };
+    match step.axis {
+        Axis::Child => node
+            .children()
+            .filter(|child| node_matches(&step.test, *child))
+            .map(Item::Node)
+            .collect(),
+        Axis::DescendantOrSelf => node.descendants().map(Item::Node).collect(),
+        Axis::Parent => node.parent().map(Item::Node).into_iter().collect(),
+        Axis::Attribute => node
+            .attributes()
+            .enumerate()
+            .filter(|(_, attribute)| name_matches(&step.test, attribute.namespace(), attribute.name()))
+            .map(|(index, attribute)| Item::Attribute {
+                owner: node,
+                index,
+                value: attribute.value(),
+            })
+            .collect(),
+        Axis::SelfNode => unreachable!("self steps return their context above"),
+    }
+}
+
+fn node_matches(test: &NodeTest, node: Node) -> bool {
+    match test {
+        NodeTest::AnyNode => node.is_element() || node.is_text(),
+        NodeTest::Text => node.is_text(),
+        NodeTest::Name { .. } => {
+            node.is_element() && name_matches(test, node.tag_name().namespace(), node.tag_name().name())
+        }
+    }
+}
+
+fn name_matches(test: &NodeTest, namespace: Option<&str>, local: &str) -> bool {
+    match test {
+        NodeTest::Name {
+            namespace: expected_namespace,
+            local: expected_local,
+        } => {
+            expected_namespace
+                .as_deref()
+                .is_none_or(|expected| namespace == Some(expected))
+                && expected_local.as_deref().is_none_or(|expected| local == expected)
+        }
+        NodeTest::Text | NodeTest::AnyNode => false,
+    }
+}
+
+fn predicate_holds(predicate: &Predicate, item: Item, position: usize, size: usize) -> bool {
+    match predicate {
+        Predicate::Position(expected) => position == *expected,
+        Predicate::Last => position == size,
+        Predicate::Exists(path) => !evaluate(path, item).is_empty(),
+        Predicate::Compare { path, value, negate } => evaluate(path, item)
+            .iter()
+            .any(|selected| (collapse_whitespace(&selected.string_value()) == *value) != *negate),
+    }
+}
+
+/// Split `text` at `separator` outside of brackets and quotes.
+fn split_top_level<'t>(text: &'t str, separator: &str) -> Vec<&'t str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+    for (index, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => depth = depth.saturating_sub(1),
+            (None, _) if depth == 0 && index >= start && text[index..].starts_with(separator) => {
+                parts.push(&text[start..index]);
+                start = index + separator.len();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+fn parse_path(path: &str, namespaces: &BTreeMap<String, String>) -> std::result::Result<LocationPath, String> {
+    if path.is_empty() {
+        return Err("empty path".to_string());
+    }
+    let absolute = path.starts_with('/');
+    if path == "/" {
+        return Ok(LocationPath {
+            absolute,
+            steps: Vec::new(),
+        });
+    }
+
+    let segments = split_top_level(path, "/");
+    let mut steps = Vec::new();
+    for (index, segment) in segments.iter().enumerate().skip(usize::from(absolute)) {
+        let segment = segment.trim();
+        if !segment.is_empty() {
+            steps.push(parse_step(segment, namespaces)?);
+        } else if index + 1 < segments.len() && !segments[index + 1].trim().is_empty() {
+            steps.push(Step {
+                axis: Axis::DescendantOrSelf,
+                test: NodeTest::AnyNode,
+                predicates: Vec::new(),
+            });
+        } else {
+            return Err("empty step".to_string());
+        }
+    }
+    Ok(LocationPath { absolute, steps })
+}
+
+fn parse_step(segment: &str, namespaces: &BTreeMap<String, String>) -> std::result::Result<Step, String> {
+    let (base, mut rest) = segment.split_at(segment.find('[').unwrap_or(segment.len()));
+    let mut predicates = Vec::new();
+    while !rest.is_empty() {
+        let end = closing_bracket(rest).ok_or_else(|| format!("unclosed predicate in '{}'", segment))?;
+        predicates.push(parse_predicate(rest[1..end].trim(), namespaces)?);
+        rest = rest[end + 1..].trim_start();
+        if !rest.is_empty() && !rest.starts_with('[') {
+            return Err(format!("unexpected '{}' after predicate", rest));
+        }
+    }
+
+    let base = base.trim();
+    let (axis, test) = match base {
+        "." => (Axis::SelfNode, NodeTest::AnyNode),
+        ".." => (Axis::Parent, NodeTest::AnyNode),
+        "text()" => (Axis::Child, NodeTest::Text),
+        "node()" => (Axis::Child, NodeTest::AnyNode),
+        _ => match base.strip_prefix('@') {
+            Some(name) => (Axis::Attribute, parse_name_test(name, namespaces)?),
+            None => (Axis::Child, parse_name_test(base, namespaces)?),
+        },
+    };
+    if !predicates.is_empty() && matches!(axis, Axis::SelfNode | Axis::Parent) {
+        return Err(format!("predicates are not supported on '{}'", base));
+    }
+    Ok(Step { axis, test, predicates })
+}
+
+/// Byte index of the `]` closing the predicate `text` starts with.
+fn closing_bracket(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    let mut quote = None;
+    for (index, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(open), c) if c == open => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '[') => depth += 1,
+            (None, ']') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_name_test(name: &str, namespaces: &BTreeMap<String, String>) -> std::result::Result<NodeTest, String> {
+    let (prefix, local) = match name.split_once(':') {
+        Some((prefix, local)) => (Some(prefix), local),
+        None => (None, name),
+    };
+    let is_name = |part: &str| {
+        part.chars().next().is_some_and(|c| c.is_alphabetic() || c == '_')
+            && part
+                .chars()
+                .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+    };
+    if !(local == "*" || is_name(local)) || prefix.is_some_and(|prefix| !is_name(prefix)) {
+        return Err(format!("unsupported step '{}'", name));
+    }
+
+    let namespace = match prefix {
+        Some("xml") => Some(XML_NAMESPACE.to_string()),
+        Some(prefix) => Some(
+            namespaces
+                .get(prefix)
+                .cloned()
+                .ok_or_else(|| format!("undeclared namespace prefix '{}'", prefix))?,
+        ),
+        None => None,
+    };
+    Ok(NodeTest::Name {
+        namespace,
+        local: (local != "*").then(|| local.to_string()),
+    })
+}
+
+fn parse_predicate(predicate: &str, namespaces: &BTreeMap<String, String>) -> std::result::Result<Predicate, String> {
+    if predicate == "last()" {
+        return Ok(Predicate::Last);
+    }
+    if let Ok(position) = predicate.parse::<usize>() {
+        return match position {
+            0 => Err("positions start at 1".to_string()),
+            _ => Ok(Predicate::Position(position)),
+        };
+    }
+
+    let (path, value, negate) = match split_top_level(predicate, "!=").as_slice() {
+        [path, value] => (*path, Some(*value), true),
+        _ => match split_top_level(predicate, "=").as_slice() {
+            [path, value] => (*path, Some(*value), false),
+            _ => (predicate, None, false),
+        },
+    };
+    let path = parse_path(path.trim(), namespaces)?;
+    let Some(value) = value else {
+        return Ok(Predicate::Exists(path));
+    };
+
+    let value = value.trim();
+    let literal = ['\'', '"']
+        .into_iter()
+        .find_map(|quote| value.strip_prefix(quote)?.strip_suffix(quote));
+    let value = match literal {
+        Some(literal) => literal.to_string(),
+        None if value.parse::<f64>().is_ok() => value.to_string(),
+        None => return Err(format!("expected a quoted string or number, got '{}'", value)),
+    };
+    Ok(Predicate::Compare { path, value, negate })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INVOICE: &str = r#"<?xml version="1.0"?>
+<Invoice xmlns="urn:oasis:names:specification:ubl:schema:xsd:Invoice-2" xmlns:cbc="urn:cbc" xmlns:cac="urn:cac">
+  <cbc:ID>INV-0042</cbc:ID>
+  <cbc:Note>First   note</cbc:Note>
+  <cac:InvoiceLine>
+    <cbc:ID>1</cbc:ID>
+    <cbc:LineExtensionAmount currencyID="EUR">100.00</cbc:LineExtensionAmount>
+  </cac:InvoiceLine>
+  <cac:InvoiceLine>
+    <cbc:ID>2</cbc:ID>
+    <cbc:LineExtensionAmount currencyID="USD">50.00</cbc:LineExtensionAmount>
+  </cac:InvoiceLine>
+</Invoice>"#;
+
+    fn namespaces() -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("cbc".to_string(), "urn:cbc".to_string()),
+            ("cac".to_string(), "urn:cac".to_string()),
+        ])
+    }
+
+    fn values(expression: &str) -> Vec<String> {
+        let doc = roxmltree::Document::parse(INVOICE).unwrap();
+        XPath::parse(expression, &namespaces()).unwrap().values(doc.root())
+    }
+
+    #[test]
+    fn test_paths_and_namespaces() {
+        assert_eq!(values("/Invoice/cbc:ID"), vec!["INV-0042"]);
+        assert_eq!(values("/Invoice/cbc:Note"), vec!["First note"]);
+        assert_eq!(values("//cac:InvoiceLine/cbc:ID"), vec!["1", "2"]);
+        assert_eq!(values("//cbc:LineExtensionAmount/@currencyID"), vec!["EUR", "USD"]);
+        assert_eq!(values("/Invoice/*/cbc:ID/text()"), vec!["1", "2"]);
+        assert_eq!(
+            values("//InvoiceLine/ID/../LineExtensionAmount"),
+            vec!["100.00", "50.00"]
+        );
+    }
+
+    #[test]
+    fn test_predicates_and_unions() {
+        assert_eq!(values("//cac:InvoiceLine[2]/cbc:ID"), vec!["2"]);
+        assert_eq!(values("//cac:InvoiceLine[last()]/cbc:ID"), vec!["2"]);
+        assert_eq!(values("//cbc:LineExtensionAmount[@currencyID='USD']"), vec!["50.00"]);
+        assert_eq!(
+            values("//cac:InvoiceLine[cbc:ID=\"1\"]/cbc:LineExtensionAmount"),
+            vec!["100.00"]
+        );
+        assert_eq!(values("//cac:InvoiceLine[cbc:ID!=1]/cbc:ID"), vec!["2"]);
+        assert_eq!(values("//*[@currencyID]"), vec!["100.00", "50.00"]);
+        assert_eq!(
+            values("/Invoice/cbc:Note | /Invoice/cbc:ID"),
+            vec!["INV-0042", "First note"]
+        );
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        for expression in [
+            "",
+            "/Invoice/",
+            "foo:ID",
+            "//Line[0]",
+            "//Line[@id=x]",
+            "//Line[1",
+            "count(//Line)",
+        ] {
+            let err = XPath::parse(expression, &namespaces()).unwrap_err();
+            assert!(matches!(err, KreuzbergError::Validation { .. }), "{}", expression);
+        }
+    }
+}
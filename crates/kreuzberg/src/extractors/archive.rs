@@ -1,26 +1,42 @@
-//! Archive extractors for ZIP, TAR, and 7z formats.
+//! Archive extractors for ZIP, TAR, 7z, RAR and ISO 9660 formats.
 
 use crate::Result;
-use crate::core::config::ExtractionConfig;
+use crate::core::config::{ArchiveConfig, ExtractionConfig};
+use crate::core::limits::exceed_limit;
 use crate::extraction::archive::{
-    ArchiveMetadata as ExtractedMetadata, extract_7z_metadata, extract_7z_text_content, extract_tar_metadata,
-    extract_tar_text_content, extract_zip_metadata, extract_zip_text_content,
+    ArchiveEntryStatus, ArchiveMetadata as ExtractedMetadata, ArchiveTextContent, extract_7z_metadata_with_passwords,
+    extract_7z_text_content_with_limits, extract_tar_metadata, extract_tar_text_content_with_limits,
+    extract_zip_metadata, extract_zip_text_content_with_limits,
 };
+#[cfg(feature = "iso")]
+use crate::extraction::archive::{extract_iso_metadata, extract_iso_text_content_with_limits};
+#[cfg(feature = "rar")]
+use crate::extraction::archive::{extract_rar_metadata_with_passwords, extract_rar_text_content_with_limits};
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ArchiveMetadata, ExtractionResult, Metadata};
+use crate::text::segmented::SegmentedBuffer;
+use crate::types::{ArchiveMetadata, ExtractionResult, ExtractionWarning, Metadata, WarningStage};
 use async_trait::async_trait;
 use std::collections::HashMap;
+use std::fmt::Write;
 
 /// Build an ExtractionResult from archive metadata and text contents.
 ///
 /// This helper function eliminates duplication across ZIP/TAR/7Z extractors by centralizing
 /// the logic for transforming extracted metadata into the final result structure.
+///
+/// Fails with `LimitExceeded` when decompression stopped at `limits.max_decompressed_bytes`
+/// and truncation is disabled.
 fn build_archive_result(
     extraction_metadata: ExtractedMetadata,
-    text_contents: HashMap<String, String>,
+    text_contents: ArchiveTextContent,
     format_name: &str,
     mime_type: &str,
-) -> ExtractionResult {
+    config: &ExtractionConfig,
+) -> Result<ExtractionResult> {
+    let decompressed_bytes = text_contents.decompressed_bytes;
+    let budget_exhausted = text_contents.truncated;
+    let warnings = archive_warnings(&text_contents);
+
     let file_names: Vec<String> = extraction_metadata
         .file_list
         .iter()
@@ -48,25 +64,41 @@ fn build_archive_result(
         })
         .collect();
     additional.insert("files".to_string(), serde_json::json!(file_details));
+    if !text_contents.skipped_entries.is_empty() {
+        additional.insert(
+            "skipped_entries".to_string(),
+            serde_json::json!(text_contents.skipped_entries),
+        );
+    }
+    if text_contents.truncated {
+        additional.insert("truncated".to_string(), serde_json::json!(true));
+    }
+    if !text_contents.entries.is_empty() {
+        additional.insert("entries".to_string(), serde_json::json!(text_contents.entries));
+    }
 
-    let mut output = format!(
+    let mut output = SegmentedBuffer::new();
+    let _ = write!(
+        output,
         "{} Archive ({} files, {} bytes)\n\n",
         format_name, extraction_metadata.file_count, extraction_metadata.total_size
     );
     output.push_str("Files:\n");
     for entry in &extraction_metadata.file_list {
-        output.push_str(&format!("- {} ({} bytes)\n", entry.path, entry.size));
+        let _ = writeln!(output, "- {} ({} bytes)", entry.path, entry.size);
     }
 
-    if !text_contents.is_empty() {
+    if !text_contents.contents.is_empty() {
         output.push_str("\n\nText File Contents:\n\n");
-        for (path, content) in text_contents {
-            output.push_str(&format!("=== {} ===\n{}\n\n", path, content));
+        for (path, content) in text_contents.contents {
+            let _ = writeln!(output, "=== {} ===", path);
+            output.push_string(content);
+            output.push_str("\n\n");
         }
     }
 
-    ExtractionResult {
-        content: output,
+    let mut result = ExtractionResult {
+        content: output.into_string(),
         mime_type: mime_type.to_string(),
         metadata: Metadata {
             format: Some(crate::types::FormatMetadata::Archive(archive_metadata)),
@@ -78,7 +110,67 @@ fn build_archive_result(
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings,
+    };
+
+    if let Some(limits) = &config.limits
+        && let Some(max) = limits.max_decompressed_bytes
+        && budget_exhausted
+        && decompressed_bytes >= max
+    {
+        let actual = extraction_metadata.total_size.max(decompressed_bytes);
+        exceed_limit(&mut result, limits, "max_decompressed_bytes", actual, max)?;
+    }
+
+    Ok(result)
+}
+
+/// Warnings for members that were not extracted and for a decompression budget that ran out.
+fn archive_warnings(text_contents: &ArchiveTextContent) -> Vec<ExtractionWarning> {
+    let mut warnings: Vec<ExtractionWarning> = text_contents
+        .entries
+        .iter()
+        .filter_map(|entry| {
+            let (code, reason) = match entry.status {
+                ArchiveEntryStatus::Skipped => ("archive_entry_skipped", "is larger than the per-entry size limit"),
+                ArchiveEntryStatus::Encrypted => ("archive_entry_encrypted", "is encrypted and no password matched"),
+                ArchiveEntryStatus::Failed => ("archive_entry_failed", "could not be read"),
+                ArchiveEntryStatus::Extracted | ArchiveEntryStatus::Expanded => return None,
+            };
+            let message = format!("Archive entry '{}' {}", entry.path, reason);
+            Some(ExtractionWarning::new(code, message, WarningStage::Extraction))
+        })
+        .collect();
+
+    if text_contents.truncated {
+        warnings.push(ExtractionWarning::new(
+            "archive_truncated",
+            "Decompressed-size budget exhausted, remaining entries were not extracted",
+            WarningStage::Limits,
+        ));
     }
+    warnings
+}
+
+/// Archive limits from the extraction config, falling back to the defaults.
+///
+/// `limits.max_decompressed_bytes` and `limits.max_recursion_depth` tighten the total size
+/// and nesting depth.
+pub(crate) fn archive_limits(config: &ExtractionConfig) -> ArchiveConfig {
+    let mut archive_limits = config.archives.clone().unwrap_or_default();
+    if let Some(limits) = &config.limits {
+        if let Some(max) = limits.max_decompressed_bytes {
+            archive_limits.max_total_size = archive_limits.max_total_size.min(max);
+        }
+        if let Some(max) = limits.max_recursion_depth {
+            archive_limits.max_depth = archive_limits.max_depth.min(max);
+        }
+    }
+    archive_limits
 }
 
 /// ZIP archive extractor.
@@ -128,7 +220,7 @@ impl Plugin for ZipExtractor {
 #[async_trait]
 impl DocumentExtractor for ZipExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -138,16 +230,11 @@ impl DocumentExtractor for ZipExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let extraction_metadata = extract_zip_metadata(content)?;
-        let text_contents = extract_zip_text_content(content)?;
-        Ok(build_archive_result(
-            extraction_metadata,
-            text_contents,
-            "ZIP",
-            mime_type,
-        ))
+        let text_contents = extract_zip_text_content_with_limits(content, &archive_limits(config))?;
+        build_archive_result(extraction_metadata, text_contents, "ZIP", mime_type, config)
     }
 
     fn supported_mime_types(&self) -> &[&str] {
@@ -206,7 +293,7 @@ impl Plugin for TarExtractor {
 #[async_trait]
 impl DocumentExtractor for TarExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -216,16 +303,11 @@ impl DocumentExtractor for TarExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
         let extraction_metadata = extract_tar_metadata(content)?;
-        let text_contents = extract_tar_text_content(content)?;
-        Ok(build_archive_result(
-            extraction_metadata,
-            text_contents,
-            "TAR",
-            mime_type,
-        ))
+        let text_contents = extract_tar_text_content_with_limits(content, &archive_limits(config))?;
+        build_archive_result(extraction_metadata, text_contents, "TAR", mime_type, config)
     }
 
     fn supported_mime_types(&self) -> &[&str] {
@@ -289,7 +371,7 @@ impl Plugin for SevenZExtractor {
 #[async_trait]
 impl DocumentExtractor for SevenZExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -299,16 +381,13 @@ impl DocumentExtractor for SevenZExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let extraction_metadata = extract_7z_metadata(content)?;
-        let text_contents = extract_7z_text_content(content)?;
-        Ok(build_archive_result(
-            extraction_metadata,
-            text_contents,
-            "7Z",
-            mime_type,
-        ))
+        let limits = archive_limits(config);
+        let passwords = limits.passwords.as_deref().unwrap_or_default();
+        let extraction_metadata = extract_7z_metadata_with_passwords(content, passwords)?;
+        let text_contents = extract_7z_text_content_with_limits(content, &limits)?;
+        build_archive_result(extraction_metadata, text_contents, "7Z", mime_type, config)
     }
 
     fn supported_mime_types(&self) -> &[&str] {
@@ -320,6 +399,168 @@ impl DocumentExtractor for SevenZExtractor {
     }
 }
 
+/// RAR archive extractor.
+///
+/// Extracts file lists and text content from RAR archives.
+#[cfg(feature = "rar")]
+pub struct RarExtractor;
+
+#[cfg(feature = "rar")]
+impl RarExtractor {
+    /// Create a new RAR extractor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "rar")]
+impl Default for RarExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "rar")]
+impl Plugin for RarExtractor {
+    fn name(&self) -> &str {
+        "rar-extractor"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn description(&self) -> &str {
+        "Extracts file lists and text content from RAR archives"
+    }
+
+    fn author(&self) -> &str {
+        "Kreuzberg Team"
+    }
+}
+
+#[cfg(feature = "rar")]
+#[async_trait]
+impl DocumentExtractor for RarExtractor {
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, content, config),
+        fields(
+            extractor.name = self.name(),
+            content.size_bytes = content.len(),
+        )
+    ))]
+    async fn extract_bytes(
+        &self,
+        content: &[u8],
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        let limits = archive_limits(config);
+        let passwords = limits.passwords.as_deref().unwrap_or_default();
+        let extraction_metadata = extract_rar_metadata_with_passwords(content, passwords)?;
+        let text_contents = extract_rar_text_content_with_limits(content, &limits)?;
+        build_archive_result(extraction_metadata, text_contents, "RAR", mime_type, config)
+    }
+
+    fn supported_mime_types(&self) -> &[&str] {
+        &[
+            "application/vnd.rar",
+            "application/x-rar-compressed",
+            "application/x-rar",
+        ]
+    }
+
+    fn priority(&self) -> i32 {
+        50
+    }
+}
+
+/// ISO 9660 disc image extractor.
+///
+/// Extracts file lists and text content from ISO 9660 images (including hybrid UDF images).
+#[cfg(feature = "iso")]
+pub struct IsoExtractor;
+
+#[cfg(feature = "iso")]
+impl IsoExtractor {
+    /// Create a new ISO extractor.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+#[cfg(feature = "iso")]
+impl Default for IsoExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "iso")]
+impl Plugin for IsoExtractor {
+    fn name(&self) -> &str {
+        "iso-extractor"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn description(&self) -> &str {
+        "Extracts file lists and text content from ISO 9660 disc images"
+    }
+
+    fn author(&self) -> &str {
+        "Kreuzberg Team"
+    }
+}
+
+#[cfg(feature = "iso")]
+#[async_trait]
+impl DocumentExtractor for IsoExtractor {
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, content, config),
+        fields(
+            extractor.name = self.name(),
+            content.size_bytes = content.len(),
+        )
+    ))]
+    async fn extract_bytes(
+        &self,
+        content: &[u8],
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        let extraction_metadata = extract_iso_metadata(content)?;
+        let text_contents = extract_iso_text_content_with_limits(content, &archive_limits(config))?;
+        build_archive_result(extraction_metadata, text_contents, "ISO", mime_type, config)
+    }
+
+    fn supported_mime_types(&self) -> &[&str] {
+        &["application/x-iso9660-image", "application/x-cd-image"]
+    }
+
+    fn priority(&self) -> i32 {
+        50
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -402,6 +643,128 @@ mod tests {
         assert_eq!(archive_meta.file_count, 1);
     }
 
+    #[tokio::test]
+    async fn test_zip_extractor_reports_skipped_entries() {
+        let extractor = ZipExtractor::new();
+
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            let options = FileOptions::<'_, ()>::default();
+
+            zip.start_file("bomb.txt", options).unwrap();
+            zip.write_all(&vec![b'0'; 4096]).unwrap();
+
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let config = ExtractionConfig {
+            archives: Some(ArchiveConfig {
+                max_entry_size: 1024,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let result = extractor
+            .extract_bytes(&bytes, "application/zip", &config)
+            .await
+            .unwrap();
+
+        assert!(!result.content.contains("Text File Contents"));
+        assert_eq!(
+            result.metadata.additional.get("skipped_entries"),
+            Some(&serde_json::json!(["bomb.txt"]))
+        );
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].code, "archive_entry_skipped");
+    }
+
+    #[tokio::test]
+    async fn test_zip_extractor_enforces_decompressed_bytes_limit() {
+        let extractor = ZipExtractor::new();
+
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            for name in ["a.txt", "b.txt"] {
+                zip.start_file(name, FileOptions::<'_, ()>::default()).unwrap();
+                zip.write_all(&vec![b'0'; 4096]).unwrap();
+            }
+            zip.finish().unwrap();
+        }
+        let bytes = cursor.into_inner();
+
+        let mut limits = crate::core::config::LimitsConfig {
+            max_decompressed_bytes: Some(5000),
+            ..Default::default()
+        };
+        let config = ExtractionConfig {
+            limits: Some(limits.clone()),
+            ..Default::default()
+        };
+        let err = extractor
+            .extract_bytes(&bytes, "application/zip", &config)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Limit exceeded: max_decompressed_bytes is 8192, maximum is 5000"
+        );
+
+        limits.truncate = true;
+        let config = ExtractionConfig {
+            limits: Some(limits),
+            ..Default::default()
+        };
+        let result = extractor
+            .extract_bytes(&bytes, "application/zip", &config)
+            .await
+            .unwrap();
+        assert_eq!(
+            result.metadata.additional.get("limits_exceeded"),
+            Some(&serde_json::json!(["max_decompressed_bytes"]))
+        );
+        assert!(result.content.contains("=== a.txt ==="));
+        assert!(!result.content.contains("=== b.txt ==="));
+    }
+
+    #[tokio::test]
+    async fn test_zip_extractor_expands_nested_archive() {
+        let extractor = ZipExtractor::new();
+
+        let mut inner = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut inner);
+            zip.start_file("notes.txt", FileOptions::<'_, ()>::default()).unwrap();
+            zip.write_all(b"Nested notes").unwrap();
+            zip.finish().unwrap();
+        }
+
+        let mut cursor = Cursor::new(Vec::new());
+        {
+            let mut zip = ZipWriter::new(&mut cursor);
+            zip.start_file("inner.zip", FileOptions::<'_, ()>::default()).unwrap();
+            zip.write_all(&inner.into_inner()).unwrap();
+            zip.finish().unwrap();
+        }
+
+        let bytes = cursor.into_inner();
+        let result = extractor
+            .extract_bytes(&bytes, "application/zip", &ExtractionConfig::default())
+            .await
+            .unwrap();
+
+        assert!(result.content.contains("=== inner.zip/notes.txt ==="));
+        assert!(result.content.contains("Nested notes"));
+        let entries = result.metadata.additional.get("entries").unwrap();
+        assert_eq!(entries[0]["path"], "inner.zip");
+        assert_eq!(entries[0]["status"], "expanded");
+        assert_eq!(entries[1]["path"], "inner.zip/notes.txt");
+        assert_eq!(entries[1]["depth"], 1);
+    }
+
     #[tokio::test]
     async fn test_zip_extractor_invalid() {
         let extractor = ZipExtractor::new();
@@ -405,6 +405,11 @@ impl DocumentExtractor for DocbookExtractor {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -419,7 +424,7 @@ impl DocumentExtractor for DocbookExtractor {
         )
     )]
     async fn extract_file(&self, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
-        let bytes = tokio::fs::read(path).await?;
+        let bytes = crate::core::io::load_file_async(path).await?;
         self.extract_bytes(&bytes, mime_type, config).await
     }
 
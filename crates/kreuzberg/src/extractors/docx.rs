@@ -6,9 +6,16 @@
 
 use crate::Result;
 use crate::core::config::ExtractionConfig;
+use crate::extraction::docx::BodyImage;
+use crate::extraction::embedded_images::{
+    IMAGE_MARKER, embedded_image, embedded_image_ocr_enabled, ocr_embedded_images,
+};
 use crate::extraction::{cells_to_markdown, office_metadata};
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata, PageBoundary, PageInfo, PageStructure, PageUnitType, Table};
+use crate::types::{
+    ExtractedImage, ExtractionResult, FormatMetadata, Metadata, OfficeMetadata, PageBoundary, PageInfo, PageStructure,
+    PageUnitType, Table,
+};
 use async_trait::async_trait;
 use std::io::Cursor;
 
@@ -103,10 +110,55 @@ fn convert_docx_table_to_table(docx_table: &docx_lite::Table, table_index: usize
 /// # Returns
 /// * `String` - Markdown formatted table
 
+/// Put [`IMAGE_MARKER`] at the end of the line holding each image's anchor text.
+///
+/// Anchors are searched from the previous image on, keeping the images in reading order; images
+/// whose anchor is not found in `text` are returned after the placed ones.
+fn mark_body_images(text: &str, body_images: Vec<BodyImage>) -> (String, Vec<ExtractedImage>) {
+    let mut positions = Vec::new();
+    let mut placed = Vec::new();
+    let mut unplaced = Vec::new();
+    let mut cursor = 0;
+
+    for (index, body_image) in body_images.into_iter().enumerate() {
+        let position = match body_image.anchor.as_deref() {
+            None => Some(cursor),
+            Some(anchor) => text[cursor..].find(anchor).map(|start| {
+                let anchor_end = cursor + start + anchor.len();
+                text[anchor_end..]
+                    .find('\n')
+                    .map_or(text.len(), |newline| anchor_end + newline)
+            }),
+        };
+
+        let image = embedded_image(body_image.data, body_image.format, index);
+        match position {
+            Some(position) => {
+                positions.push(position);
+                placed.push(image);
+                cursor = position;
+            }
+            None => unplaced.push(image),
+        }
+    }
+
+    let mut marked = String::with_capacity(text.len() + positions.len() * IMAGE_MARKER.len_utf8());
+    let mut last = 0;
+    for position in positions {
+        marked.push_str(&text[last..position]);
+        marked.push(IMAGE_MARKER);
+        last = position;
+    }
+    marked.push_str(&text[last..]);
+
+    placed.extend(unplaced);
+    (marked, placed)
+}
+
 #[async_trait]
 impl DocumentExtractor for DocxExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -116,12 +168,15 @@ impl DocumentExtractor for DocxExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let (text, tables, page_boundaries) = if crate::core::batch_mode::is_batch_mode() {
+        let decrypted = crate::extraction::office_encryption::decrypt_if_encrypted(content, config)?;
+        let content = decrypted.as_ref();
+
+        let (mut text, tables, mut page_boundaries) = if crate::core::batch_mode::is_batch_mode() {
             let content_owned = content.to_vec();
             let span = tracing::Span::current();
-            tokio::task::spawn_blocking(
+            crate::core::runtime::spawn_blocking(
                 move || -> crate::error::Result<(String, Vec<Table>, Option<Vec<PageBoundary>>)> {
                     let _guard = span.entered();
                     let cursor = Cursor::new(&content_owned);
@@ -163,10 +218,39 @@ impl DocumentExtractor for DocxExtractor {
             (text, tables, page_boundaries)
         };
 
+        let render_revisions = config.revisions.as_ref().is_some_and(|cfg| cfg.render_inline);
+        let revisions = crate::extraction::docx::extract_revisions(content, render_revisions).unwrap_or_else(|e| {
+            tracing::warn!("Failed to read DOCX revisions: {}", e);
+            Default::default()
+        });
+        if let Some(inline_text) = revisions.inline_text {
+            text = inline_text;
+            if page_boundaries.is_some() {
+                page_boundaries = crate::extraction::docx::page_boundaries_for_text(content, &text);
+            }
+        }
+
+        let mut images = None;
+        if embedded_image_ocr_enabled(config) {
+            let body_images = crate::extraction::docx::extract_body_images(content).unwrap_or_else(|e| {
+                tracing::warn!("Failed to read DOCX images for OCR: {}", e);
+                Vec::new()
+            });
+
+            if !body_images.is_empty() {
+                let (marked_text, mut body_images) = mark_body_images(&text, body_images);
+                text = ocr_embedded_images(&marked_text, &mut body_images, config).await;
+                if page_boundaries.is_some() {
+                    page_boundaries = crate::extraction::docx::page_boundaries_for_text(content, &text);
+                }
+                images = Some(body_images);
+            }
+        }
+
         let mut archive = if crate::core::batch_mode::is_batch_mode() {
             let content_owned = content.to_vec();
             let span = tracing::Span::current();
-            tokio::task::spawn_blocking(move || -> crate::error::Result<_> {
+            crate::core::runtime::spawn_blocking(move || -> crate::error::Result<_> {
                 let _guard = span.entered();
                 let cursor = Cursor::new(content_owned);
                 zip::ZipArchive::new(cursor)
@@ -181,90 +265,47 @@ impl DocumentExtractor for DocxExtractor {
                 .map_err(|e| crate::error::KreuzbergError::parsing(format!("Failed to open ZIP archive: {}", e)))?
         };
 
-        let mut metadata_map = std::collections::HashMap::new();
+        let mut metadata = Metadata::default();
+        let mut office = OfficeMetadata::default();
+
+        if !revisions.revisions.is_empty() {
+            metadata.revisions = Some(revisions.revisions);
+        }
 
         if let Ok(core) = office_metadata::extract_core_properties(&mut archive) {
-            if let Some(title) = core.title {
-                metadata_map.insert("title".to_string(), serde_json::Value::String(title));
-            }
+            metadata.title = core.title;
             if let Some(creator) = core.creator {
-                metadata_map.insert(
-                    "authors".to_string(),
-                    serde_json::Value::Array(vec![serde_json::Value::String(creator.clone())]),
-                );
-                metadata_map.insert("created_by".to_string(), serde_json::Value::String(creator));
-            }
-            if let Some(subject) = core.subject {
-                metadata_map.insert("subject".to_string(), serde_json::Value::String(subject));
-            }
-            if let Some(keywords) = core.keywords {
-                metadata_map.insert("keywords".to_string(), serde_json::Value::String(keywords));
-            }
-            if let Some(description) = core.description {
-                metadata_map.insert("description".to_string(), serde_json::Value::String(description));
-            }
-            if let Some(modified_by) = core.last_modified_by {
-                metadata_map.insert("modified_by".to_string(), serde_json::Value::String(modified_by));
-            }
-            if let Some(created) = core.created {
-                metadata_map.insert("created_at".to_string(), serde_json::Value::String(created));
-            }
-            if let Some(modified) = core.modified {
-                metadata_map.insert("modified_at".to_string(), serde_json::Value::String(modified));
-            }
-            if let Some(revision) = core.revision {
-                metadata_map.insert("revision".to_string(), serde_json::Value::String(revision));
-            }
-            if let Some(category) = core.category {
-                metadata_map.insert("category".to_string(), serde_json::Value::String(category));
-            }
-            if let Some(content_status) = core.content_status {
-                metadata_map.insert("content_status".to_string(), serde_json::Value::String(content_status));
-            }
-            if let Some(language) = core.language {
-                metadata_map.insert("language".to_string(), serde_json::Value::String(language));
+                metadata.authors = Some(vec![creator.clone()]);
+                metadata.created_by = Some(creator);
             }
+            metadata.subject = core.subject;
+            metadata.keywords = core.keywords.as_deref().map(office_metadata::split_keywords);
+            metadata.modified_by = core.last_modified_by;
+            metadata.created_at = core.created;
+            metadata.modified_at = core.modified;
+            metadata.language = core.language;
+            metadata.identifier = core.identifier;
+            office.description = core.description;
+            office.revision = core.revision;
+            office.category = core.category;
+            office.content_status = core.content_status;
         }
 
         if let Ok(app) = office_metadata::extract_docx_app_properties(&mut archive) {
-            if let Some(pages) = app.pages {
-                metadata_map.insert("page_count".to_string(), serde_json::Value::Number(pages.into()));
-            }
-            if let Some(words) = app.words {
-                metadata_map.insert("word_count".to_string(), serde_json::Value::Number(words.into()));
-            }
-            if let Some(chars) = app.characters {
-                metadata_map.insert("character_count".to_string(), serde_json::Value::Number(chars.into()));
-            }
-            if let Some(lines) = app.lines {
-                metadata_map.insert("line_count".to_string(), serde_json::Value::Number(lines.into()));
-            }
-            if let Some(paragraphs) = app.paragraphs {
-                metadata_map.insert(
-                    "paragraph_count".to_string(),
-                    serde_json::Value::Number(paragraphs.into()),
-                );
-            }
-            if let Some(template) = app.template {
-                metadata_map.insert("template".to_string(), serde_json::Value::String(template));
-            }
-            if let Some(company) = app.company {
-                metadata_map.insert("organization".to_string(), serde_json::Value::String(company));
-            }
-            if let Some(time) = app.total_time {
-                metadata_map.insert(
-                    "total_editing_time_minutes".to_string(),
-                    serde_json::Value::Number(time.into()),
-                );
-            }
-            if let Some(application) = app.application {
-                metadata_map.insert("application".to_string(), serde_json::Value::String(application));
-            }
+            office.page_count = app.pages;
+            office.word_count = app.words;
+            office.character_count = app.characters;
+            office.line_count = app.lines;
+            office.paragraph_count = app.paragraphs;
+            office.template = app.template;
+            office.organization = app.company;
+            office.total_editing_time_minutes = app.total_time;
+            office.application = app.application;
         }
 
         if let Ok(custom) = office_metadata::extract_custom_properties(&mut archive) {
             for (key, value) in custom {
-                metadata_map.insert(format!("custom_{}", key), value);
+                metadata.additional.insert(format!("custom_{}", key), value);
             }
         }
 
@@ -296,14 +337,19 @@ impl DocumentExtractor for DocxExtractor {
             mime_type: mime_type.to_string(),
             metadata: Metadata {
                 pages: page_structure,
-                additional: metadata_map,
-                ..Default::default()
+                format: Some(FormatMetadata::Office(office)),
+                ..metadata
             },
             pages: None,
             tables,
             detected_languages: None,
             chunks: None,
-            images: None,
+            images,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -352,6 +398,29 @@ mod tests {
         assert!(extractor.shutdown().is_ok());
     }
 
+    #[test]
+    fn test_mark_body_images_places_markers_after_anchor_lines() {
+        let body_image = |anchor: Option<&str>| BodyImage {
+            data: vec![0x89, 0x50, 0x4E, 0x47],
+            format: "png".to_string(),
+            anchor: anchor.map(str::to_string),
+        };
+        let body_images = vec![
+            body_image(None),
+            body_image(Some("Revenue by quarter")),
+            body_image(Some("Missing paragraph")),
+        ];
+
+        let (marked, images) = mark_body_images("Report\nRevenue by quarter\nSummary", body_images);
+
+        assert_eq!(
+            marked,
+            format!("{m}Report\nRevenue by quarter{m}\nSummary", m = IMAGE_MARKER)
+        );
+        let indexes: Vec<usize> = images.iter().map(|image| image.image_index).collect();
+        assert_eq!(indexes, vec![0, 1, 2]);
+    }
+
     #[test]
     fn test_convert_docx_table_to_table() {
         use docx_lite::{Paragraph, Run, Table as DocxTable, TableCell, TableRow};
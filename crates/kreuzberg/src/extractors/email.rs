@@ -1,17 +1,45 @@
 //! Email message extractor.
 
-use crate::Result;
-use crate::core::config::ExtractionConfig;
+use crate::core::config::{EmailConfig, ExtractionConfig};
+use crate::core::extractor::extract_bytes_with_registries;
+use crate::core::mime;
+use crate::extraction::email::clean_html_content;
+use crate::extraction::email_body::sanitize_body;
+use crate::extraction::embedded_images::{
+    IMAGE_MARKER, embedded_image, embedded_image_ocr_enabled, image_format_from_mime, ocr_embedded_images,
+};
 use crate::extractors::SyncExtractor;
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{EmailMetadata, ExtractionResult, Metadata};
+use crate::types::{
+    EmailAttachment, EmailExtractionResult, EmailMetadata, ErrorMetadata, ExtractedImage, ExtractionResult,
+    ExtractionWarning, Metadata, WarningStage,
+};
+use crate::{KreuzbergError, Result};
 use async_trait::async_trait;
+use regex::{Captures, Regex};
+use std::borrow::Cow;
 #[cfg(feature = "tokio-runtime")]
 use std::path::Path;
+use std::sync::OnceLock;
+
+static CID_IMAGE_TAG_RE: OnceLock<Regex> = OnceLock::new();
+static CID_TEXT_RE: OnceLock<Regex> = OnceLock::new();
+
+fn cid_image_tag_regex() -> &'static Regex {
+    CID_IMAGE_TAG_RE.get_or_init(|| Regex::new(r#"(?is)<img\b[^>]*?\ssrc\s*=\s*["']cid:([^"']+)["'][^>]*>"#).unwrap())
+}
+
+fn cid_text_regex() -> &'static Regex {
+    CID_TEXT_RE.get_or_init(|| Regex::new(r"\[cid:([^\]\s]+)\]").unwrap())
+}
 
 /// Email message extractor.
 ///
 /// Supports: .eml, .msg
+///
+/// Attachments are listed in the email metadata. With [`EmailConfig::extract_attachments`]
+/// they are also extracted as child documents, except by the synchronous extraction used
+/// without the `tokio-runtime` feature (WASM), which only lists them.
 pub struct EmailExtractor;
 
 impl Default for EmailExtractor {
@@ -45,49 +73,313 @@ impl Plugin for EmailExtractor {
 }
 
 impl SyncExtractor for EmailExtractor {
-    fn extract_sync(&self, content: &[u8], mime_type: &str, _config: &ExtractionConfig) -> Result<ExtractionResult> {
+    fn extract_sync(&self, content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
         let email_result = crate::extraction::email::extract_email_content(content, mime_type)?;
+        Ok(build_extraction_result(&sanitized(&email_result, config), mime_type))
+    }
+}
+
+/// The message with the parts of its body removed that `config.email` asks to strip.
+fn sanitized<'a>(email_result: &'a EmailExtractionResult, config: &ExtractionConfig) -> Cow<'a, EmailExtractionResult> {
+    match &config.email {
+        Some(email_config) => sanitize_body(email_result, email_config),
+        None => Cow::Borrowed(email_result),
+    }
+}
+
+fn build_extraction_result(email_result: &EmailExtractionResult, mime_type: &str) -> ExtractionResult {
+    let text = crate::extraction::email::build_email_text_output(email_result);
+
+    let attachment_names: Vec<String> = email_result
+        .attachments
+        .iter()
+        .filter_map(|att| att.filename.clone().or_else(|| att.name.clone()))
+        .collect();
+
+    let email_metadata = EmailMetadata {
+        from_email: email_result.from_email.clone(),
+        from_name: email_result.from_name.clone(),
+        to_emails: email_result.to_emails.clone(),
+        cc_emails: email_result.cc_emails.clone(),
+        bcc_emails: email_result.bcc_emails.clone(),
+        message_id: email_result.message_id.clone(),
+        reply_to_emails: email_result.reply_to_emails.clone(),
+        in_reply_to: email_result.in_reply_to.clone(),
+        references: email_result.references.clone(),
+        thread_id: email_result.thread_id.clone(),
+        has_dkim_signature: email_result.has_dkim_signature,
+        raw_headers: email_result.raw_headers.clone(),
+        calendar_events: email_result.calendar_events.clone(),
+        attachments: attachment_names,
+        quoted_text: email_result.quoted_text.clone(),
+        signature: email_result.signature.clone(),
+        tracking_pixels: email_result.tracking_pixels.clone(),
+    };
+
+    let mut additional = std::collections::HashMap::new();
+    for (key, value) in &email_result.metadata {
+        additional.insert(key.clone(), serde_json::json!(value));
+    }
 
-        let text = crate::extraction::email::build_email_text_output(&email_result);
-
-        let attachment_names: Vec<String> = email_result
-            .attachments
-            .iter()
-            .filter_map(|att| att.filename.clone().or_else(|| att.name.clone()))
-            .collect();
-
-        let email_metadata = EmailMetadata {
-            from_email: email_result.from_email.clone(),
-            from_name: None,
-            to_emails: email_result.to_emails.clone(),
-            cc_emails: email_result.cc_emails.clone(),
-            bcc_emails: email_result.bcc_emails.clone(),
-            message_id: email_result.message_id.clone(),
-            attachments: attachment_names,
+    ExtractionResult {
+        content: text,
+        mime_type: mime_type.to_string(),
+        metadata: Metadata {
+            format: Some(crate::types::FormatMetadata::Email(email_metadata)),
+            subject: email_result.subject.clone(),
+            date: email_result.date.clone(),
+            additional,
+            ..Default::default()
+        },
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
+    }
+}
+
+/// Build the result for a parsed message, extracting its attachments when configured.
+pub(crate) async fn email_extraction_result(
+    email_result: &EmailExtractionResult,
+    mime_type: &str,
+    config: &ExtractionConfig,
+) -> ExtractionResult {
+    let email_result = sanitized(email_result, config);
+    let mut result = if embedded_image_ocr_enabled(config) {
+        let (body, mut images) = mark_inline_images(&email_result);
+        let cleaned_text = ocr_embedded_images(&body, &mut images, config).await;
+        let email_result = EmailExtractionResult {
+            cleaned_text,
+            ..(*email_result).clone()
         };
 
-        let mut additional = std::collections::HashMap::new();
-        for (key, value) in &email_result.metadata {
-            additional.insert(key.clone(), serde_json::json!(value));
+        let mut result = build_extraction_result(&email_result, mime_type);
+        result.images = (!images.is_empty()).then_some(images);
+        result
+    } else {
+        build_extraction_result(&email_result, mime_type)
+    };
+
+    if let Some(email_config) = &config.email
+        && email_config.extract_attachments
+        && attachment_depth(email_config, config) > 0
+    {
+        let children = extract_attachments(&email_result, mime_type, email_config, config, &mut result.warnings).await;
+        result.children = Some(children);
+    }
+
+    result
+}
+
+/// Put [`IMAGE_MARKER`] into the body where inline (`cid:`) images are referenced.
+///
+/// HTML bodies with `<img src="cid:...">` tags are re-cleaned with those tags replaced; other
+/// bodies use the `[cid:...]` placeholders mail clients leave in plain text. Inline images that
+/// are never referenced follow the referenced ones, so their text ends up after the body.
+fn mark_inline_images(email_result: &EmailExtractionResult) -> (String, Vec<ExtractedImage>) {
+    let mut inline: Vec<(&str, &EmailAttachment)> = email_result
+        .attachments
+        .iter()
+        .filter(|attachment| attachment.is_image && attachment.data.is_some())
+        .filter_map(|attachment| Some((normalize_cid(attachment.content_id.as_deref()?), attachment)))
+        .collect();
+    let mut referenced = Vec::new();
+
+    let body = match &email_result.html_content {
+        Some(html) if cid_image_tag_regex().is_match(html) => {
+            let marked_html = mark_cid_references(html, cid_image_tag_regex(), &mut inline, &mut referenced);
+            clean_html_content(&marked_html)
         }
+        _ => mark_cid_references(
+            &email_result.cleaned_text,
+            cid_text_regex(),
+            &mut inline,
+            &mut referenced,
+        ),
+    };
+
+    let images = referenced
+        .into_iter()
+        .chain(inline.into_iter().map(|(_, attachment)| attachment))
+        .enumerate()
+        .filter_map(|(index, attachment)| {
+            let data = attachment.data.clone()?;
+            let format = image_format_from_mime(attachment.mime_type.as_deref().unwrap_or_default());
+            Some(embedded_image(data, format, index))
+        })
+        .collect();
+
+    (body, images)
+}
+
+fn normalize_cid(cid: &str) -> &str {
+    cid.trim().trim_matches(|c| c == '<' || c == '>')
+}
 
-        Ok(ExtractionResult {
-            content: text,
-            mime_type: mime_type.to_string(),
-            metadata: Metadata {
-                format: Some(crate::types::FormatMetadata::Email(email_metadata)),
-                subject: email_result.subject.clone(),
-                date: email_result.date.clone(),
-                additional,
-                ..Default::default()
-            },
-            tables: vec![],
-            detected_languages: None,
-            chunks: None,
-            images: None,
-            pages: None,
+/// Replace references to inline images with [`IMAGE_MARKER`], moving each referenced image
+/// from `inline` to `referenced` in the order the references appear.
+fn mark_cid_references<'a>(
+    text: &str,
+    regex: &Regex,
+    inline: &mut Vec<(&'a str, &'a EmailAttachment)>,
+    referenced: &mut Vec<&'a EmailAttachment>,
+) -> String {
+    regex
+        .replace_all(text, |caps: &Captures| {
+            let cid = normalize_cid(&caps[1]);
+            match inline
+                .iter()
+                .position(|(content_id, _)| content_id.eq_ignore_ascii_case(cid))
+            {
+                Some(position) => {
+                    referenced.push(inline.remove(position).1);
+                    IMAGE_MARKER.to_string()
+                }
+                None => caps[0].to_string(),
+            }
         })
+        .into_owned()
+}
+
+/// Resolve the MIME type an attachment is extracted as.
+///
+/// The declared content type wins when it is supported; generic types such as
+/// `application/octet-stream` fall back to the file name and then the content itself.
+fn attachment_mime_type(attachment: &EmailAttachment, data: &[u8]) -> Option<String> {
+    if let Some(declared) = attachment.mime_type.as_deref()
+        && let Ok(mime_type) = mime::validate_mime_type(declared)
+    {
+        return Some(mime_type);
+    }
+
+    if let Some(name) = attachment.filename.as_deref().or(attachment.name.as_deref())
+        && let Ok(mime_type) = mime::detect_mime_type(name, false).and_then(|m| mime::validate_mime_type(&m))
+    {
+        return Some(mime_type);
+    }
+
+    mime::detect_mime_type_from_bytes(data).ok()
+}
+
+/// Attachment depth left for this message, tightened by `limits.max_recursion_depth`.
+fn attachment_depth(email_config: &EmailConfig, config: &ExtractionConfig) -> usize {
+    match config.limits.as_ref().and_then(|limits| limits.max_recursion_depth) {
+        Some(max) => email_config.max_attachment_depth.min(max),
+        None => email_config.max_attachment_depth,
+    }
+}
+
+/// Run each attachment through the extractor registry and pipeline.
+///
+/// Attachments are extracted with one level less of `max_attachment_depth`, so attached
+/// messages expand their own attachments until the depth is used up. Attachments that are
+/// too large or of an unsupported type are skipped; extraction failures (e.g. encrypted
+/// documents) are returned as children carrying `metadata.error` instead of failing the
+/// message. Both are reported in `warnings`.
+async fn extract_attachments(
+    email_result: &EmailExtractionResult,
+    parent_mime_type: &str,
+    email_config: &EmailConfig,
+    config: &ExtractionConfig,
+    warnings: &mut Vec<ExtractionWarning>,
+) -> Vec<ExtractionResult> {
+    let mut child_config = config.clone();
+    child_config.email = Some(EmailConfig {
+        max_attachment_depth: attachment_depth(email_config, config) - 1,
+        ..email_config.clone()
+    });
+
+    let registries = config.context.registries();
+    let mut children = Vec::new();
+
+    for (index, attachment) in email_result.attachments.iter().enumerate() {
+        let Some(data) = attachment.data.as_deref() else {
+            continue;
+        };
+
+        let filename = attachment.filename.clone().or_else(|| attachment.name.clone());
+        let label = filename.as_deref().unwrap_or("unnamed");
+        let mut skip = |reason: String| {
+            tracing::debug!("Skipping email attachment {:?}: {}", filename, reason);
+            let message = format!("Skipped email attachment '{}': {}", label, reason);
+            warnings.push(ExtractionWarning::new(
+                "attachment_skipped",
+                message,
+                WarningStage::Extraction,
+            ));
+        };
+
+        if data.len() > email_config.max_attachment_size {
+            skip(format!(
+                "{} bytes exceeds the {} byte limit",
+                data.len(),
+                email_config.max_attachment_size
+            ));
+            continue;
+        }
+
+        let Some(mime_type) = attachment_mime_type(attachment, data) else {
+            skip("unsupported type".to_string());
+            continue;
+        };
+
+        let extraction = extract_bytes_with_registries(data, &mime_type, &child_config, &registries);
+        let mut child = match extraction.await {
+            Ok(child) => child,
+            Err(KreuzbergError::UnsupportedFormat(_)) => {
+                skip(format!("no extractor for {}", mime_type));
+                continue;
+            }
+            Err(e) => {
+                let message = format!("Failed to extract email attachment '{}': {}", label, e);
+                warnings.push(ExtractionWarning::new(
+                    "attachment_failed",
+                    message,
+                    WarningStage::Extraction,
+                ));
+                ExtractionResult {
+                    content: String::new(),
+                    mime_type: mime_type.clone(),
+                    metadata: Metadata {
+                        error: Some(ErrorMetadata {
+                            error_type: format!("{:?}", e),
+                            message: e.to_string(),
+                        }),
+                        ..Default::default()
+                    },
+                    tables: vec![],
+                    detected_languages: None,
+                    chunks: None,
+                    images: None,
+                    pages: None,
+                    entities: None,
+                    keywords: None,
+                    children: None,
+                    fields: None,
+                    warnings: Vec::new(),
+                }
+            }
+        };
+
+        child.metadata.additional.insert(
+            "parent".to_string(),
+            serde_json::json!({
+                "mime_type": parent_mime_type,
+                "message_id": email_result.message_id,
+                "attachment_index": index,
+                "filename": filename,
+            }),
+        );
+        children.push(child);
     }
+
+    children
 }
 
 #[async_trait]
@@ -105,7 +397,8 @@ impl DocumentExtractor for EmailExtractor {
         mime_type: &str,
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        self.extract_sync(content, mime_type, config)
+        let email_result = crate::extraction::email::extract_email_content(content, mime_type)?;
+        Ok(email_extraction_result(&email_result, mime_type, config).await)
     }
 
     #[cfg(feature = "tokio-runtime")]
@@ -117,7 +410,7 @@ impl DocumentExtractor for EmailExtractor {
     ))]
     #[cfg(feature = "tokio-runtime")]
     async fn extract_file(&self, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
-        let bytes = tokio::fs::read(path).await?;
+        let bytes = crate::core::io::load_file_async(path).await?;
         self.extract_bytes(&bytes, mime_type, config).await
     }
 
@@ -154,4 +447,24 @@ mod tests {
         assert!(mime_types.contains(&"message/rfc822"));
         assert!(mime_types.contains(&"application/vnd.ms-outlook"));
     }
+
+    #[test]
+    fn test_mark_inline_images_follows_cid_references() {
+        let html = r#"<p>Q3 results</p><img src="cid:chart@example"><p>Thanks</p>"#;
+        let eml = format!(
+            "From: a@example.com\r\nTo: b@example.com\r\nSubject: Report\r\nMIME-Version: 1.0\r\n\
+             Content-Type: multipart/related; boundary=\"b\"\r\n\r\n\
+             --b\r\nContent-Type: text/html\r\n\r\n{}\r\n\
+             --b\r\nContent-Type: image/png\r\nContent-ID: <chart@example>\r\n\
+             Content-Transfer-Encoding: base64\r\n\r\niVBORw0KGgo=\r\n--b--\r\n",
+            html
+        );
+        let email_result = crate::extraction::email::parse_eml_content(eml.as_bytes()).unwrap();
+
+        let (body, images) = mark_inline_images(&email_result);
+
+        assert_eq!(body, format!("Q3 results{}Thanks", IMAGE_MARKER));
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].format, "png");
+    }
 }
@@ -579,6 +579,11 @@ impl DocumentExtractor for EpubExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
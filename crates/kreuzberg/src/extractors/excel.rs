@@ -88,7 +88,7 @@ impl Plugin for ExcelExtractor {
 #[async_trait]
 impl DocumentExtractor for ExcelExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -98,8 +98,11 @@ impl DocumentExtractor for ExcelExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
+        let decrypted = crate::extraction::office_encryption::decrypt_if_encrypted(content, config)?;
+        let content = decrypted.as_ref();
+
         let extension = match mime_type {
             "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => ".xlsx",
             "application/vnd.ms-excel.sheet.macroEnabled.12" => ".xlsm",
@@ -116,7 +119,7 @@ impl DocumentExtractor for ExcelExtractor {
             let content_owned = content.to_vec();
             let extension_owned = extension.to_string();
             let span = tracing::Span::current();
-            tokio::task::spawn_blocking(move || {
+            crate::core::runtime::spawn_blocking(move || {
                 let _guard = span.entered();
                 crate::extraction::excel::read_excel_bytes(&content_owned, &extension_owned)
             })
@@ -155,16 +158,26 @@ impl DocumentExtractor for ExcelExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, path, _config),
+        skip(self, path, config),
         fields(
             extractor.name = self.name(),
         )
     ))]
-    async fn extract_file(&self, path: &Path, mime_type: &str, _config: &ExtractionConfig) -> Result<ExtractionResult> {
+    async fn extract_file(&self, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
+        if crate::extraction::office_encryption::is_encrypted_file(path)? {
+            let bytes = crate::core::io::load_file_async(path).await?;
+            return self.extract_bytes(&bytes, mime_type, config).await;
+        }
+
         let path_str = path
             .to_str()
             .ok_or_else(|| crate::KreuzbergError::validation("Invalid file path".to_string()))?;
@@ -199,6 +212,11 @@ impl DocumentExtractor for ExcelExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -441,6 +441,11 @@ impl DocumentExtractor for FictionBookExtractor {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
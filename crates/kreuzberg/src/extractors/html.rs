@@ -2,12 +2,19 @@
 
 use crate::Result;
 use crate::core::config::ExtractionConfig;
+use crate::extraction::embedded_images::{
+    IMAGE_MARKER, embedded_image, embedded_image_ocr_enabled, image_format_from_mime, ocr_embedded_images,
+};
 use crate::extractors::SyncExtractor;
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata, Table};
+use crate::text::segmented::SegmentedBuffer;
+use crate::types::{ExtractedImage, ExtractionResult, FormatMetadata, Metadata, Table};
 use async_trait::async_trait;
+use base64::prelude::*;
+use regex::Regex;
 #[cfg(feature = "tokio-runtime")]
 use std::path::Path;
+use std::sync::OnceLock;
 
 // NOTE: scraper dependency has been removed in favor of html-to-markdown-rs
 
@@ -24,6 +31,135 @@ impl HtmlExtractor {
     pub fn new() -> Self {
         Self
     }
+
+    /// Extract HTML with the OCR text of its data URI images placed where the images appear.
+    async fn extract_with_image_ocr(
+        &self,
+        content: &[u8],
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        let html = String::from_utf8_lossy(content);
+        let (marked_html, mut images) = mark_data_uri_images(&html);
+        if images.is_empty() {
+            return self.extract_sync(content, mime_type, config);
+        }
+
+        let mut result = convert_html(&marked_html, mime_type, config)?;
+        result.tables = extract_html_tables(&html)?;
+        add_link_map(&mut result, &html, true);
+        result.content = ocr_embedded_images(&result.content, &mut images, config).await;
+        result.images = Some(images);
+        Ok(result)
+    }
+}
+
+/// Convert HTML to a Markdown extraction result with the metadata of its frontmatter.
+fn convert_html(html: &str, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
+    let markdown = crate::extraction::html::convert_html_to_markdown(html, config.html_options.clone())?;
+
+    let (html_metadata, content_without_frontmatter) = crate::extraction::html::parse_html_metadata(&markdown)?;
+
+    Ok(ExtractionResult {
+        content: content_without_frontmatter,
+        mime_type: mime_type.to_string(),
+        metadata: Metadata {
+            format: html_metadata.map(|m| FormatMetadata::Html(Box::new(m))),
+            ..Default::default()
+        },
+        pages: None,
+        tables: Vec::new(),
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
+    })
+}
+
+/// Add the links and images of `html` to the HTML metadata of `result`.
+///
+/// With `resolve_images`, base64 data URI images are decoded into `result.images` (in document
+/// order, like [`mark_data_uri_images`]) and referenced by their `image_index`.
+fn add_link_map(result: &mut ExtractionResult, html: &str, resolve_images: bool) {
+    let (links, mut images) = crate::extraction::html::extract_link_map(html);
+    if links.is_empty() && images.is_empty() {
+        return;
+    }
+
+    let mut decoded = Vec::new();
+    for image in &mut images {
+        let Some((mime_type, payload)) = split_data_uri(&image.src) else {
+            continue;
+        };
+        let mime_type = mime_type.to_string();
+        if resolve_images && let Some(data) = decode_base64_payload(payload) {
+            image.image_index = Some(decoded.len());
+            decoded.push(embedded_image(data, image_format_from_mime(&mime_type), decoded.len()));
+        }
+        image.src = format!("data:{}", mime_type);
+    }
+    if !decoded.is_empty() {
+        result.images = Some(decoded);
+    }
+
+    if !matches!(result.metadata.format, Some(FormatMetadata::Html(_))) {
+        result.metadata.format = Some(FormatMetadata::Html(Box::default()));
+    }
+    if let Some(FormatMetadata::Html(metadata)) = &mut result.metadata.format {
+        metadata.links = links;
+        metadata.images = images;
+    }
+}
+
+/// Media type and base64 payload of an `image/*` data URI.
+fn split_data_uri(src: &str) -> Option<(&str, &str)> {
+    let (mime_type, payload) = src.strip_prefix("data:")?.split_once(";base64,")?;
+    mime_type
+        .to_ascii_lowercase()
+        .starts_with("image/")
+        .then_some((mime_type, payload))
+}
+
+fn decode_base64_payload(payload: &str) -> Option<Vec<u8>> {
+    let payload: String = payload.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+    BASE64_STANDARD.decode(payload).ok()
+}
+
+static DATA_URI_IMAGE_RE: OnceLock<Regex> = OnceLock::new();
+
+fn data_uri_image_regex() -> &'static Regex {
+    DATA_URI_IMAGE_RE.get_or_init(|| {
+        Regex::new(r#"(?is)<img\b[^>]*?\ssrc\s*=\s*["']data:(image/[a-z0-9.+-]+);base64,([^"']+)["'][^>]*>"#).unwrap()
+    })
+}
+
+/// Replace `<img>` tags carrying base64 data URIs with [`IMAGE_MARKER`] and decode their images.
+///
+/// Tags whose payload does not decode are left untouched.
+fn mark_data_uri_images(html: &str) -> (String, Vec<ExtractedImage>) {
+    let mut images = Vec::new();
+    let mut marked = SegmentedBuffer::new();
+    let mut copied_to = 0;
+
+    for caps in data_uri_image_regex().captures_iter(html) {
+        let Some(data) = decode_base64_payload(&caps[2]) else {
+            continue;
+        };
+        let tag = caps.get_match();
+        marked.push_str(&html[copied_to..tag.start()]);
+        marked.push(IMAGE_MARKER);
+        copied_to = tag.end();
+
+        let image_index = images.len();
+        images.push(embedded_image(data, image_format_from_mime(&caps[1]), image_index));
+    }
+    marked.push_str(&html[copied_to..]);
+
+    (marked.into_string(), images)
 }
 
 /// Extract all tables from HTML content using html-to-markdown-rs.
@@ -153,7 +289,7 @@ fn reconstruct_markdown_table(cells: &[Vec<String>]) -> String {
         return String::new();
     }
 
-    let mut markdown = String::new();
+    let mut markdown = SegmentedBuffer::new();
 
     for (row_idx, row) in cells.iter().enumerate() {
         markdown.push('|');
@@ -174,7 +310,7 @@ fn reconstruct_markdown_table(cells: &[Vec<String>]) -> String {
         }
     }
 
-    markdown
+    markdown.into_string()
 }
 
 impl Plugin for HtmlExtractor {
@@ -201,25 +337,11 @@ impl SyncExtractor for HtmlExtractor {
             .map(|s| s.to_string())
             .unwrap_or_else(|_| String::from_utf8_lossy(content).to_string());
 
-        let tables = extract_html_tables(&html)?;
-
-        let markdown = crate::extraction::html::convert_html_to_markdown(&html, config.html_options.clone())?;
-
-        let (html_metadata, content_without_frontmatter) = crate::extraction::html::parse_html_metadata(&markdown)?;
-
-        Ok(ExtractionResult {
-            content: content_without_frontmatter,
-            mime_type: mime_type.to_string(),
-            metadata: Metadata {
-                format: html_metadata.map(|m| crate::types::FormatMetadata::Html(Box::new(m))),
-                ..Default::default()
-            },
-            pages: None,
-            tables,
-            detected_languages: None,
-            chunks: None,
-            images: None,
-        })
+        let mut result = convert_html(&html, mime_type, config)?;
+        result.tables = extract_html_tables(&html)?;
+        let extract_images = config.images.as_ref().is_some_and(|images| images.extract_images);
+        add_link_map(&mut result, &html, extract_images);
+        Ok(result)
     }
 }
 
@@ -238,6 +360,10 @@ impl DocumentExtractor for HtmlExtractor {
         mime_type: &str,
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
+        if embedded_image_ocr_enabled(config) {
+            return self.extract_with_image_ocr(content, mime_type, config).await;
+        }
+
         self.extract_sync(content, mime_type, config)
     }
 
@@ -250,7 +376,7 @@ impl DocumentExtractor for HtmlExtractor {
     ))]
     #[cfg(feature = "tokio-runtime")]
     async fn extract_file(&self, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
-        let bytes = tokio::fs::read(path).await?;
+        let bytes = crate::core::io::load_file_async(path).await?;
         self.extract_bytes(&bytes, mime_type, config).await
     }
 
@@ -288,6 +414,18 @@ mod tests {
         assert!(mime_types.contains(&"application/xhtml+xml"));
     }
 
+    #[test]
+    fn test_mark_data_uri_images() {
+        let html = r#"<p>Sales</p><img alt="chart" src="data:image/png;base64,iVBORw0KGgo="><img src="logo.png">"#;
+
+        let (marked, images) = mark_data_uri_images(html);
+
+        assert_eq!(marked, format!("<p>Sales</p>{}<img src=\"logo.png\">", IMAGE_MARKER));
+        assert_eq!(images.len(), 1);
+        assert_eq!(images[0].format, "png");
+        assert_eq!(images[0].data, b"\x89PNG\r\n\x1a\n");
+    }
+
     #[test]
     fn test_extract_html_tables_basic() {
         let html = r#"
@@ -404,4 +542,41 @@ mod tests {
         assert_eq!(table.cells[1], vec!["Alice", "30"]);
         assert_eq!(table.cells[2], vec!["Bob", "25"]);
     }
+
+    #[tokio::test]
+    async fn test_html_extractor_link_map() {
+        let html = concat!(
+            r#"<p><a href="https://example.com/a">First</a> "#,
+            r#"<img src="data:image/png;base64,iVBORw0KGgo=" alt="Inline"> <img src="remote.jpg"></p>"#,
+        );
+        let extractor = HtmlExtractor::new();
+
+        let result = extractor
+            .extract_bytes(html.as_bytes(), "text/html", &ExtractionConfig::default())
+            .await
+            .unwrap();
+        let Some(FormatMetadata::Html(metadata)) = &result.metadata.format else {
+            panic!("expected HTML metadata");
+        };
+        assert_eq!(metadata.links.len(), 1);
+        assert_eq!(metadata.links[0].text, "First");
+        assert_eq!(metadata.images[0].src, "data:image/png");
+        assert_eq!(metadata.images[0].image_index, None);
+        assert_eq!(metadata.images[1].src, "remote.jpg");
+        assert!(result.images.is_none());
+
+        let config = ExtractionConfig {
+            images: Some(serde_json::from_str(r#"{"extract_images": true}"#).unwrap()),
+            ..Default::default()
+        };
+        let result = extractor
+            .extract_bytes(html.as_bytes(), "text/html", &config)
+            .await
+            .unwrap();
+        let Some(FormatMetadata::Html(metadata)) = &result.metadata.format else {
+            panic!("expected HTML metadata");
+        };
+        assert_eq!(metadata.images[0].image_index, Some(0));
+        assert_eq!(result.images.unwrap()[0].data, b"\x89PNG\r\n\x1a\n");
+    }
 }
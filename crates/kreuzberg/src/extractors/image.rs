@@ -3,21 +3,30 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::image::extract_image_metadata;
+use crate::plugins::registry::{OcrBackendRegistry, get_ocr_backend_registry};
 use crate::plugins::{DocumentExtractor, Plugin};
 use crate::types::{ExtractionResult, Metadata};
 use async_trait::async_trait;
+use std::sync::{Arc, RwLock};
 
 /// Image extractor for various image formats.
 ///
 /// Supports: PNG, JPEG, WebP, BMP, TIFF, GIF.
 /// Extracts dimensions, format, and EXIF metadata.
 /// Optionally runs OCR when configured.
-pub struct ImageExtractor;
+pub struct ImageExtractor {
+    ocr_backends: Arc<RwLock<OcrBackendRegistry>>,
+}
 
 impl ImageExtractor {
-    /// Create a new image extractor.
+    /// Create a new image extractor using the global OCR backend registry.
     pub fn new() -> Self {
-        Self
+        Self::with_ocr_registry(get_ocr_backend_registry())
+    }
+
+    /// Create a new image extractor that resolves OCR backends from the given registry.
+    pub fn with_ocr_registry(ocr_backends: Arc<RwLock<OcrBackendRegistry>>) -> Self {
+        Self { ocr_backends }
     }
 
     /// Extract text from image using OCR with optional page tracking for multi-frame TIFFs.
@@ -28,23 +37,9 @@ impl ImageExtractor {
         mime_type: &str,
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        use crate::plugins::registry::get_ocr_backend_registry;
-
-        let ocr_config = config.ocr.as_ref().ok_or_else(|| crate::KreuzbergError::Parsing {
-            message: "OCR config required for image OCR".to_string(),
-            source: None,
-        })?;
-
-        let backend = {
-            let registry = get_ocr_backend_registry();
-            let registry = registry.read().map_err(|e| crate::KreuzbergError::Plugin {
-                message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
-                plugin_name: "ocr-registry".to_string(),
-            })?;
-            registry.get(&ocr_config.backend)?
-        };
-
-        let ocr_result = backend.process_image(content, ocr_config).await?;
+        let timer = crate::core::stats::StageTimer::start();
+        let ocr_result = crate::plugins::run_ocr(content, &self.ocr_backends, config).await?;
+        let ocr_ms = timer.elapsed_ms();
 
         let ocr_text = ocr_result.content.clone();
         let ocr_extraction_result = crate::extraction::image::extract_text_from_image_with_ocr(
@@ -54,9 +49,22 @@ impl ImageExtractor {
             config.pages.as_ref(),
         )?;
 
+        let page_count = ocr_extraction_result.page_contents.as_ref().map(Vec::len);
+        let text_dense = !crate::core::provenance::is_sparse_text_layer(&ocr_extraction_result.content, page_count);
+
         let mut result = ocr_result;
         result.content = ocr_extraction_result.content;
         result.pages = ocr_extraction_result.page_contents;
+        if text_dense {
+            let provenance = result.metadata.provenance.get_or_insert_with(Default::default);
+            provenance
+                .scan_signals
+                .push(crate::core::provenance::SIGNAL_OCR_TEXT.to_string());
+        }
+        result.metadata.timings = ocr_ms.map(|ms| crate::types::ExtractionTimings {
+            ocr_ms: Some(ms),
+            ..Default::default()
+        });
 
         Ok(result)
     }
@@ -147,6 +155,11 @@ impl DocumentExtractor for ImageExtractor {
                     detected_languages: None,
                     chunks: None,
                     images: None,
+                    entities: None,
+                    keywords: None,
+                    children: None,
+                    fields: None,
+                    warnings: Vec::new(),
                 });
             }
         }
@@ -166,6 +179,11 @@ impl DocumentExtractor for ImageExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -213,7 +231,7 @@ mod tests {
 
     #[test]
     fn test_image_extractor_default() {
-        let extractor = ImageExtractor;
+        let extractor = ImageExtractor::default();
         assert_eq!(extractor.name(), "image-extractor");
     }
 }
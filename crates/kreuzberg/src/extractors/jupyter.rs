@@ -341,6 +341,11 @@ impl DocumentExtractor for JupyterExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
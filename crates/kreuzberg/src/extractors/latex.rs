@@ -94,6 +94,11 @@ impl DocumentExtractor for LatexExtractor {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
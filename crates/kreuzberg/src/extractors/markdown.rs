@@ -366,6 +366,11 @@ impl DocumentExtractor for MarkdownExtractor {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
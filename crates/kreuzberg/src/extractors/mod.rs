@@ -5,10 +5,12 @@
 
 use crate::Result;
 use crate::core::config::ExtractionConfig;
-use crate::plugins::registry::get_document_extractor_registry;
+use crate::plugins::registry::{
+    DocumentExtractorRegistry, OcrBackendRegistry, get_document_extractor_registry, get_ocr_backend_registry,
+};
 use crate::types::ExtractionResult;
 use once_cell::sync::Lazy;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 
 /// Trait for extractors that can work synchronously (WASM-compatible).
 ///
@@ -76,6 +78,9 @@ pub mod archive;
 #[cfg(feature = "email")]
 pub mod email;
 
+#[cfg(feature = "pst")]
+pub mod pst;
+
 #[cfg(feature = "excel")]
 pub mod excel;
 
@@ -145,9 +150,18 @@ pub use image::ImageExtractor;
 #[cfg(feature = "archives")]
 pub use archive::{SevenZExtractor, TarExtractor, ZipExtractor};
 
+#[cfg(feature = "iso")]
+pub use archive::IsoExtractor;
+
+#[cfg(feature = "rar")]
+pub use archive::RarExtractor;
+
 #[cfg(feature = "email")]
 pub use email::EmailExtractor;
 
+#[cfg(feature = "pst")]
+pub use pst::PstExtractor;
+
 #[cfg(feature = "excel")]
 pub use excel::ExcelExtractor;
 
@@ -265,17 +279,31 @@ pub fn register_default_extractors() -> Result<()> {
         .write()
         .map_err(|e| crate::KreuzbergError::Other(format!("Document extractor registry lock poisoned: {}", e)))?;
 
+    register_default_extractors_into(&mut registry, &get_ocr_backend_registry())
+}
+
+/// Register all built-in extractors with the given registry.
+///
+/// Extractors that run OCR (PDF, images) resolve their backends from `ocr_backends`,
+/// which lets isolated [`Extractor`](crate::Extractor) instances keep OCR backends separate too.
+pub fn register_default_extractors_into(
+    registry: &mut DocumentExtractorRegistry,
+    #[cfg_attr(not(feature = "ocr"), allow(unused_variables))] ocr_backends: &Arc<RwLock<OcrBackendRegistry>>,
+) -> Result<()> {
     registry.register(Arc::new(PlainTextExtractor::new()))?;
     registry.register(Arc::new(MarkdownExtractor::new()))?;
     registry.register(Arc::new(StructuredExtractor::new()))?;
 
     #[cfg(feature = "ocr")]
-    registry.register(Arc::new(ImageExtractor::new()))?;
+    registry.register(Arc::new(ImageExtractor::with_ocr_registry(Arc::clone(ocr_backends))))?;
 
     #[cfg(feature = "xml")]
     registry.register(Arc::new(XmlExtractor::new()))?;
 
-    #[cfg(feature = "pdf")]
+    #[cfg(all(feature = "pdf", feature = "ocr"))]
+    registry.register(Arc::new(PdfExtractor::with_ocr_registry(Arc::clone(ocr_backends))))?;
+
+    #[cfg(all(feature = "pdf", not(feature = "ocr")))]
     registry.register(Arc::new(PdfExtractor::new()))?;
 
     #[cfg(feature = "excel")]
@@ -306,6 +334,9 @@ pub fn register_default_extractors() -> Result<()> {
     #[cfg(feature = "email")]
     registry.register(Arc::new(EmailExtractor::new()))?;
 
+    #[cfg(feature = "pst")]
+    registry.register(Arc::new(PstExtractor::new()))?;
+
     #[cfg(feature = "html")]
     registry.register(Arc::new(HtmlExtractor::new()))?;
 
@@ -316,6 +347,12 @@ pub fn register_default_extractors() -> Result<()> {
         registry.register(Arc::new(SevenZExtractor::new()))?;
     }
 
+    #[cfg(feature = "rar")]
+    registry.register(Arc::new(RarExtractor::new()))?;
+
+    #[cfg(feature = "iso")]
+    registry.register(Arc::new(IsoExtractor::new()))?;
+
     Ok(())
 }
 
@@ -414,6 +451,24 @@ mod tests {
             assert!(extractor_names.contains(&"7z-extractor".to_string()));
         }
 
+        #[cfg(feature = "rar")]
+        {
+            expected_count += 1;
+            assert!(extractor_names.contains(&"rar-extractor".to_string()));
+        }
+
+        #[cfg(feature = "iso")]
+        {
+            expected_count += 1;
+            assert!(extractor_names.contains(&"iso-extractor".to_string()));
+        }
+
+        #[cfg(feature = "pst")]
+        {
+            expected_count += 1;
+            assert!(extractor_names.contains(&"pst-extractor".to_string()));
+        }
+
         assert_eq!(
             extractor_names.len(),
             expected_count,
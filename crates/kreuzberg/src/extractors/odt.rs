@@ -8,7 +8,7 @@ use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::{cells_to_markdown, office_metadata};
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata, Table};
+use crate::types::{ExtractionResult, FormatMetadata, Metadata, OfficeMetadata, Table};
 use async_trait::async_trait;
 use roxmltree::Document;
 use std::io::Cursor;
@@ -428,7 +428,7 @@ impl DocumentExtractor for OdtExtractor {
         let (text, tables) = if crate::core::batch_mode::is_batch_mode() {
             let content_for_task = content_owned.clone();
             let span = tracing::Span::current();
-            tokio::task::spawn_blocking(move || -> crate::error::Result<(String, Vec<Table>)> {
+            crate::core::runtime::spawn_blocking(move || -> crate::error::Result<(String, Vec<Table>)> {
                 let _guard = span.entered();
 
                 let cursor = Cursor::new(content_for_task);
@@ -475,7 +475,7 @@ impl DocumentExtractor for OdtExtractor {
             (combined_text, tables)
         };
 
-        let mut metadata_map = std::collections::HashMap::new();
+        let mut metadata = Metadata::default();
 
         let cursor = Cursor::new(content_owned.clone());
         let mut archive = zip::ZipArchive::new(cursor).map_err(|e| {
@@ -483,90 +483,46 @@ impl DocumentExtractor for OdtExtractor {
         })?;
 
         if let Ok(odt_props) = office_metadata::extract_odt_properties(&mut archive) {
-            if let Some(title) = odt_props.title {
-                metadata_map.insert("title".to_string(), serde_json::Value::String(title));
-            }
+            metadata.title = odt_props.title;
             if let Some(creator) = odt_props.creator {
-                metadata_map.insert(
-                    "authors".to_string(),
-                    serde_json::Value::Array(vec![serde_json::Value::String(creator.clone())]),
-                );
-                metadata_map.insert("created_by".to_string(), serde_json::Value::String(creator));
-            }
-            if let Some(initial_creator) = odt_props.initial_creator {
-                metadata_map.insert(
-                    "initial_creator".to_string(),
-                    serde_json::Value::String(initial_creator),
-                );
-            }
-            if let Some(subject) = odt_props.subject {
-                metadata_map.insert("subject".to_string(), serde_json::Value::String(subject));
-            }
-            if let Some(keywords) = odt_props.keywords {
-                metadata_map.insert("keywords".to_string(), serde_json::Value::String(keywords));
-            }
-            if let Some(description) = odt_props.description {
-                metadata_map.insert("description".to_string(), serde_json::Value::String(description));
-            }
-            if let Some(creation_date) = odt_props.creation_date {
-                metadata_map.insert("created_at".to_string(), serde_json::Value::String(creation_date));
-            }
-            if let Some(date) = odt_props.date {
-                metadata_map.insert("modified_at".to_string(), serde_json::Value::String(date));
-            }
-            if let Some(language) = odt_props.language {
-                metadata_map.insert("language".to_string(), serde_json::Value::String(language));
-            }
-            if let Some(generator) = odt_props.generator {
-                metadata_map.insert("generator".to_string(), serde_json::Value::String(generator));
-            }
-            if let Some(editing_duration) = odt_props.editing_duration {
-                metadata_map.insert(
-                    "editing_duration".to_string(),
-                    serde_json::Value::String(editing_duration),
-                );
-            }
-            if let Some(editing_cycles) = odt_props.editing_cycles {
-                metadata_map.insert("editing_cycles".to_string(), serde_json::Value::String(editing_cycles));
-            }
-            if let Some(page_count) = odt_props.page_count {
-                metadata_map.insert("page_count".to_string(), serde_json::Value::Number(page_count.into()));
-            }
-            if let Some(word_count) = odt_props.word_count {
-                metadata_map.insert("word_count".to_string(), serde_json::Value::Number(word_count.into()));
-            }
-            if let Some(character_count) = odt_props.character_count {
-                metadata_map.insert(
-                    "character_count".to_string(),
-                    serde_json::Value::Number(character_count.into()),
-                );
-            }
-            if let Some(paragraph_count) = odt_props.paragraph_count {
-                metadata_map.insert(
-                    "paragraph_count".to_string(),
-                    serde_json::Value::Number(paragraph_count.into()),
-                );
-            }
-            if let Some(table_count) = odt_props.table_count {
-                metadata_map.insert("table_count".to_string(), serde_json::Value::Number(table_count.into()));
-            }
-            if let Some(image_count) = odt_props.image_count {
-                metadata_map.insert("image_count".to_string(), serde_json::Value::Number(image_count.into()));
-            }
+                metadata.authors = Some(vec![creator.clone()]);
+                metadata.created_by = Some(creator);
+            }
+            metadata.subject = odt_props.subject;
+            metadata.keywords = odt_props.keywords.as_deref().map(office_metadata::split_keywords);
+            metadata.created_at = odt_props.creation_date;
+            metadata.modified_at = odt_props.date;
+            metadata.language = odt_props.language;
+            metadata.format = Some(FormatMetadata::Office(OfficeMetadata {
+                application: odt_props.generator,
+                description: odt_props.description,
+                initial_creator: odt_props.initial_creator,
+                editing_duration: odt_props.editing_duration,
+                editing_cycles: odt_props.editing_cycles,
+                page_count: odt_props.page_count,
+                word_count: odt_props.word_count,
+                character_count: odt_props.character_count,
+                paragraph_count: odt_props.paragraph_count,
+                table_count: odt_props.table_count,
+                image_count: odt_props.image_count,
+                ..Default::default()
+            }));
         }
 
         Ok(ExtractionResult {
             content: text,
             mime_type: mime_type.to_string(),
-            metadata: Metadata {
-                additional: metadata_map,
-                ..Default::default()
-            },
+            metadata,
             pages: None,
             tables,
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -7,12 +7,18 @@ use crate::types::{ExtractionResult, Metadata, PageContent};
 use async_trait::async_trait;
 use std::path::Path;
 
+#[cfg(feature = "ocr")]
+use crate::core::retry::with_retry;
 #[cfg(feature = "pdf")]
 use crate::pdf::error::PdfError;
 #[cfg(feature = "ocr")]
 use crate::pdf::rendering::{PageRenderOptions, PdfRenderer};
 #[cfg(all(feature = "pdf", feature = "ocr"))]
 use crate::types::Table;
+#[cfg(feature = "ocr")]
+use crate::types::{ExtractionWarning, WarningStage};
+#[cfg(feature = "ocr")]
+use crate::utils::resolution::with_png_dpi;
 #[cfg(feature = "pdf")]
 use pdfium_render::prelude::*;
 
@@ -213,7 +219,10 @@ fn assign_tables_and_images_to_pages(
 }
 
 /// PDF document extractor using pypdfium2 and playa-pdf.
-pub struct PdfExtractor;
+pub struct PdfExtractor {
+    #[cfg(feature = "ocr")]
+    ocr_backends: std::sync::Arc<std::sync::RwLock<crate::plugins::registry::OcrBackendRegistry>>,
+}
 
 impl Default for PdfExtractor {
     fn default() -> Self {
@@ -223,35 +232,61 @@ impl Default for PdfExtractor {
 
 impl PdfExtractor {
     pub fn new() -> Self {
-        Self
+        Self {
+            #[cfg(feature = "ocr")]
+            ocr_backends: crate::plugins::registry::get_ocr_backend_registry(),
+        }
+    }
+
+    /// Create a PDF extractor that resolves OCR backends from the given registry.
+    #[cfg(feature = "ocr")]
+    pub fn with_ocr_registry(
+        ocr_backends: std::sync::Arc<std::sync::RwLock<crate::plugins::registry::OcrBackendRegistry>>,
+    ) -> Self {
+        Self { ocr_backends }
     }
 
     /// Extract text from PDF using OCR.
     ///
-    /// Renders all pages to images and processes them with OCR.
+    /// Renders all pages to images, at the Tesseract preprocessing `target_dpi` when set, and
+    /// processes them with OCR. Each page PNG records the DPI it was rendered at, so backends
+    /// see the true resolution of the page.
+    ///
+    /// Returns the joined text, the mean OCR confidence across pages, the time spent rendering
+    /// and recognizing pages in milliseconds, and a `low_ocr_confidence` warning for each page
+    /// below the review threshold (`review.min_ocr_confidence`).
     #[cfg(feature = "ocr")]
-    async fn extract_with_ocr(&self, content: &[u8], config: &ExtractionConfig) -> Result<String> {
-        use crate::plugins::registry::get_ocr_backend_registry;
+    async fn extract_with_ocr(
+        &self,
+        content: &[u8],
+        config: &ExtractionConfig,
+    ) -> Result<(String, Option<f64>, Option<f64>, Vec<ExtractionWarning>)> {
         use image::ImageEncoder;
         use image::codecs::png::PngEncoder;
         use std::io::Cursor;
 
+        let timer = crate::core::stats::StageTimer::start();
+
         let ocr_config = config.ocr.as_ref().ok_or_else(|| crate::KreuzbergError::Parsing {
             message: "OCR config required for force_ocr".to_string(),
             source: None,
         })?;
 
         let backend = {
-            let registry = get_ocr_backend_registry();
-            let registry = registry.read().map_err(|e| crate::KreuzbergError::Plugin {
+            let registry = self.ocr_backends.read().map_err(|e| crate::KreuzbergError::Plugin {
                 message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
                 plugin_name: "ocr-registry".to_string(),
             })?;
             registry.get(&ocr_config.backend)?
         };
 
-        let images = {
-            let render_options = PageRenderOptions::default();
+        let pages = {
+            let mut render_options = PageRenderOptions::default();
+            if let Some(tesseract) = &ocr_config.tesseract_config
+                && let Some(preprocessing) = &tesseract.preprocessing
+            {
+                render_options.target_dpi = preprocessing.target_dpi;
+            }
             let renderer = PdfRenderer::new().map_err(|e| crate::KreuzbergError::Parsing {
                 message: format!("Failed to initialize PDF renderer: {}", e),
                 source: None,
@@ -265,10 +300,17 @@ impl PdfExtractor {
                 })?
         };
 
-        let mut page_texts = Vec::with_capacity(images.len());
+        let min_confidence = match &config.review {
+            Some(review) => review.min_ocr_confidence,
+            None => crate::core::config::ReviewConfig::default().min_ocr_confidence,
+        };
 
-        for image in images {
-            let rgb_image = image.to_rgb8();
+        let mut page_texts = Vec::with_capacity(pages.len());
+        let mut confidences = Vec::with_capacity(pages.len());
+        let mut warnings = Vec::new();
+
+        for (index, page) in pages.into_iter().enumerate() {
+            let rgb_image = page.image.to_rgb8();
             let (width, height) = rgb_image.dimensions();
 
             let mut image_bytes = Cursor::new(Vec::new());
@@ -280,14 +322,30 @@ impl PdfExtractor {
                     source: None,
                 })?;
 
-            let image_data = image_bytes.into_inner();
+            let image_data = with_png_dpi(image_bytes.into_inner(), page.dpi.max(0) as u32);
 
-            let ocr_result = backend.process_image(&image_data, ocr_config).await?;
+            let ocr_result = with_retry(config, "ocr", || backend.process_image(&image_data, ocr_config)).await?;
 
+            if let Some(conf) = ocr_result
+                .metadata
+                .additional
+                .get(crate::core::stats::OCR_CONFIDENCE_KEY)
+                .and_then(|v| v.as_f64())
+            {
+                if min_confidence.is_some_and(|min| conf < min) {
+                    let message = format!("OCR confidence {:.2} is below the review threshold", conf);
+                    let warning = ExtractionWarning::new("low_ocr_confidence", message, WarningStage::Ocr);
+                    warnings.push(warning.on_page(index + 1));
+                }
+                confidences.push(conf);
+            }
             page_texts.push(ocr_result.content);
         }
 
-        Ok(page_texts.join("\n\n"))
+        let mean_confidence =
+            (!confidences.is_empty()).then(|| confidences.iter().sum::<f64>() / confidences.len() as f64);
+
+        Ok((page_texts.join("\n\n"), mean_confidence, timer.elapsed_ms(), warnings))
     }
 }
 
@@ -329,7 +387,8 @@ impl DocumentExtractor for PdfExtractor {
             let content_owned = content.to_vec();
             let span = tracing::Span::current();
             let pages_config = config.pages.clone();
-            tokio::task::spawn_blocking(move || {
+            let layout_stats = config.pdf_options.as_ref().is_some_and(|options| options.layout_stats);
+            crate::core::runtime::spawn_blocking(move || {
                 let _guard = span.entered();
                 let bindings = Pdfium::bind_to_library(Pdfium::pdfium_platform_library_name_at_path("./"))
                     .or_else(|_| Pdfium::bind_to_system_library())
@@ -349,8 +408,12 @@ impl DocumentExtractor for PdfExtractor {
                 let (native_text, boundaries, page_contents) =
                     crate::pdf::text::extract_text_from_pdf_document(&document, pages_config.as_ref())?;
 
-                let pdf_metadata =
+                let mut pdf_metadata =
                     crate::pdf::metadata::extract_metadata_from_document(&document, boundaries.as_deref())?;
+                if layout_stats {
+                    let layout = crate::pdf::layout::compute_layout_stats(&document, &content_owned);
+                    pdf_metadata.pdf_specific.layout = Some(layout);
+                }
 
                 let tables = extract_tables_from_document(&document, &pdf_metadata)?;
 
@@ -387,48 +450,57 @@ impl DocumentExtractor for PdfExtractor {
             let (native_text, boundaries, page_contents) =
                 crate::pdf::text::extract_text_from_pdf_document(&document, config.pages.as_ref())?;
 
-            let pdf_metadata = crate::pdf::metadata::extract_metadata_from_document(&document, boundaries.as_deref())?;
+            let mut pdf_metadata =
+                crate::pdf::metadata::extract_metadata_from_document(&document, boundaries.as_deref())?;
+            if config.pdf_options.as_ref().is_some_and(|options| options.layout_stats) {
+                pdf_metadata.pdf_specific.layout = Some(crate::pdf::layout::compute_layout_stats(&document, content));
+            }
 
             let tables = extract_tables_from_document(&document, &pdf_metadata)?;
 
             (pdf_metadata, native_text, tables, page_contents)
         };
 
+        let page_count = pdf_metadata.page_structure.as_ref().map(|p| p.total_count);
+        let sparse_text_layer = crate::core::provenance::is_sparse_text_layer(&native_text, page_count);
+
+        if let Some(page_count) = page_count {
+            crate::core::limits::check_page_count(page_count, config)?;
+        }
+
         #[cfg(feature = "ocr")]
-        let text = if config.force_ocr {
+        let (text, ocr_confidence, ocr_ms, warnings) = if config.force_ocr {
             if config.ocr.is_some() {
                 self.extract_with_ocr(content, config).await?
             } else {
-                native_text
+                (native_text, None, None, Vec::new())
             }
         } else if config.ocr.is_some() {
             let decision = evaluate_native_text_for_ocr(&native_text, None);
 
-            if std::env::var("KREUZBERG_DEBUG_OCR").is_ok() {
-                eprintln!(
-                    "[kreuzberg::pdf::ocr] fallback={} non_whitespace={} alnum={} meaningful_words={} \
-                     avg_non_whitespace={:.2} avg_alnum={:.2} alnum_ratio={:.3}",
-                    decision.fallback,
-                    decision.stats.non_whitespace,
-                    decision.stats.alnum,
-                    decision.stats.meaningful_words,
-                    decision.avg_non_whitespace,
-                    decision.avg_alnum,
-                    decision.stats.alnum_ratio
-                );
-            }
+            tracing::debug!(
+                target: "kreuzberg::pdf::ocr",
+                fallback = decision.fallback,
+                non_whitespace = decision.stats.non_whitespace,
+                alnum = decision.stats.alnum,
+                meaningful_words = decision.stats.meaningful_words,
+                avg_non_whitespace = decision.avg_non_whitespace,
+                avg_alnum = decision.avg_alnum,
+                alnum_ratio = decision.stats.alnum_ratio,
+                "Evaluated native PDF text for OCR fallback"
+            );
 
             if decision.fallback {
                 self.extract_with_ocr(content, config).await?
             } else {
-                native_text
+                (native_text, None, None, Vec::new())
             }
         } else {
-            native_text
+            (native_text, None, None, Vec::new())
         };
 
         #[cfg(not(feature = "ocr"))]
-        let text = native_text;
+        let (text, ocr_confidence, ocr_ms, warnings) = (native_text, None, None, Vec::new());
 
         #[cfg(feature = "pdf")]
         if let Some(ref page_cfg) = config.pages
@@ -452,6 +524,7 @@ impl DocumentExtractor for PdfExtractor {
                         .enumerate()
                         .map(|(idx, img)| {
                             let format = img.filters.first().cloned().unwrap_or_else(|| "unknown".to_string());
+                            let dpi = crate::utils::resolution::image_dpi(&img.data);
                             crate::types::ExtractedImage {
                                 data: img.data,
                                 format,
@@ -459,6 +532,7 @@ impl DocumentExtractor for PdfExtractor {
                                 page_number: Some(img.page_number),
                                 width: Some(img.width as u32),
                                 height: Some(img.height as u32),
+                                dpi,
                                 colorspace: img.color_space,
                                 bits_per_component: img.bits_per_component.map(|b| b as u32),
                                 is_mask: false,
@@ -476,6 +550,13 @@ impl DocumentExtractor for PdfExtractor {
 
         let final_pages = assign_tables_and_images_to_pages(page_contents, &tables, images.as_deref().unwrap_or(&[]));
 
+        #[cfg(feature = "pdf")]
+        let mut pdf_specific = pdf_metadata.pdf_specific;
+        #[cfg(feature = "pdf")]
+        if config.pdf_options.as_ref().is_some_and(|options| options.validate_pdfa) {
+            pdf_specific.pdfa = Some(crate::pdf::pdfa::validate_pdfa(content));
+        }
+
         Ok(ExtractionResult {
             content: text,
             mime_type: mime_type.to_string(),
@@ -497,7 +578,19 @@ impl DocumentExtractor for PdfExtractor {
                 #[cfg(feature = "pdf")]
                 pages: pdf_metadata.page_structure.clone(),
                 #[cfg(feature = "pdf")]
-                format: Some(crate::types::FormatMetadata::Pdf(pdf_metadata.pdf_specific)),
+                format: Some(crate::types::FormatMetadata::Pdf(pdf_specific)),
+                stats: ocr_confidence.map(|conf| crate::types::ExtractionStats {
+                    ocr_confidence: Some(conf),
+                    ..Default::default()
+                }),
+                timings: ocr_ms.map(|ms| crate::types::ExtractionTimings {
+                    ocr_ms: Some(ms),
+                    ..Default::default()
+                }),
+                provenance: sparse_text_layer.then(|| crate::types::DocumentProvenance {
+                    scan_signals: vec![crate::core::provenance::SIGNAL_SPARSE_TEXT_LAYER.to_string()],
+                    ..Default::default()
+                }),
                 ..Default::default()
             },
             pages: final_pages,
@@ -505,12 +598,17 @@ impl DocumentExtractor for PdfExtractor {
             detected_languages: None,
             chunks: None,
             images,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings,
         })
     }
 
     #[cfg(feature = "tokio-runtime")]
     async fn extract_file(&self, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
-        let bytes = tokio::fs::read(path).await?;
+        let bytes = crate::core::io::load_file_async(path).await?;
         self.extract_bytes(&bytes, mime_type, config).await
     }
 
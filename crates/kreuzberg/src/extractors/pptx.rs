@@ -5,7 +5,7 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata};
+use crate::types::{ExtractionResult, ExtractionStats, Metadata};
 use async_trait::async_trait;
 use std::path::Path;
 
@@ -47,7 +47,7 @@ impl PptxExtractor {
             let tess_config_clone = tess_config.clone();
             let span = tracing::Span::current();
 
-            let ocr_result = tokio::task::spawn_blocking(move || {
+            let ocr_result = crate::core::runtime::spawn_blocking(move || {
                 let _guard = span.entered();
                 let cache_dir = std::env::var("KREUZBERG_CACHE_DIR").ok().map(std::path::PathBuf::from);
 
@@ -72,6 +72,11 @@ impl PptxExtractor {
                         chunks: None,
                         images: None,
                         pages: None,
+                        entities: None,
+                        keywords: None,
+                        children: None,
+                        fields: None,
+                        warnings: Vec::new(),
                     };
                     image.ocr_result = Some(Box::new(extraction_result));
                 }
@@ -118,26 +123,40 @@ impl DocumentExtractor for PptxExtractor {
         mime_type: &str,
         config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
+        let decrypted = crate::extraction::office_encryption::decrypt_if_encrypted(content, config)?;
+        let content = decrypted.as_ref();
         let extract_images = config.images.as_ref().is_some_and(|img| img.extract_images);
 
         let pages_config = config.pages.clone();
+        let pptx_config = config.pptx.clone().unwrap_or_default();
+        let revisions_config = config.revisions.clone();
         let pptx_result = if crate::core::batch_mode::is_batch_mode() {
             let content_owned = content.to_vec();
             let span = tracing::Span::current();
-            tokio::task::spawn_blocking(move || {
+            crate::core::runtime::spawn_blocking(move || {
                 let _guard = span.entered();
-                crate::extraction::pptx::extract_pptx_from_bytes(&content_owned, extract_images, pages_config.as_ref())
+                crate::extraction::pptx::extract_pptx_from_bytes_with_options(
+                    &content_owned,
+                    extract_images,
+                    pages_config.as_ref(),
+                    &pptx_config,
+                    revisions_config.as_ref(),
+                )
             })
             .await
             .map_err(|e| crate::error::KreuzbergError::parsing(format!("PPTX extraction task failed: {}", e)))??
         } else {
-            crate::extraction::pptx::extract_pptx_from_bytes(content, extract_images, config.pages.as_ref())?
+            crate::extraction::pptx::extract_pptx_from_bytes_with_options(
+                content,
+                extract_images,
+                config.pages.as_ref(),
+                &pptx_config,
+                revisions_config.as_ref(),
+            )?
         };
 
         let mut additional = std::collections::HashMap::new();
         additional.insert("slide_count".to_string(), serde_json::json!(pptx_result.slide_count));
-        additional.insert("image_count".to_string(), serde_json::json!(pptx_result.image_count));
-        additional.insert("table_count".to_string(), serde_json::json!(pptx_result.table_count));
 
         let images = if !pptx_result.images.is_empty() {
             #[cfg(feature = "ocr")]
@@ -153,9 +172,16 @@ impl DocumentExtractor for PptxExtractor {
             None
         };
 
+        let revisions = (!pptx_result.revisions.is_empty()).then_some(pptx_result.revisions);
         let mut metadata = Metadata {
             format: Some(crate::types::FormatMetadata::Pptx(pptx_result.metadata)),
+            revisions,
             additional,
+            stats: Some(ExtractionStats {
+                table_count: pptx_result.table_count,
+                image_count: pptx_result.image_count,
+                ..Default::default()
+            }),
             ..Default::default()
         };
 
@@ -172,6 +198,11 @@ impl DocumentExtractor for PptxExtractor {
             detected_languages: None,
             chunks: None,
             images,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -182,19 +213,28 @@ impl DocumentExtractor for PptxExtractor {
         )
     ))]
     async fn extract_file(&self, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
+        if crate::extraction::office_encryption::is_encrypted_file(path)? {
+            let bytes = crate::core::io::load_file_async(path).await?;
+            return self.extract_bytes(&bytes, mime_type, config).await;
+        }
+
         let path_str = path
             .to_str()
             .ok_or_else(|| crate::KreuzbergError::validation("Invalid file path".to_string()))?;
 
         let extract_images = config.images.as_ref().is_some_and(|img| img.extract_images);
 
-        let pptx_result =
-            crate::extraction::pptx::extract_pptx_from_path(path_str, extract_images, config.pages.as_ref())?;
+        let pptx_config = config.pptx.clone().unwrap_or_default();
+        let pptx_result = crate::extraction::pptx::extract_pptx_from_path_with_options(
+            path_str,
+            extract_images,
+            config.pages.as_ref(),
+            &pptx_config,
+            config.revisions.as_ref(),
+        )?;
 
         let mut additional = std::collections::HashMap::new();
         additional.insert("slide_count".to_string(), serde_json::json!(pptx_result.slide_count));
-        additional.insert("image_count".to_string(), serde_json::json!(pptx_result.image_count));
-        additional.insert("table_count".to_string(), serde_json::json!(pptx_result.table_count));
 
         let images = if !pptx_result.images.is_empty() {
             #[cfg(feature = "ocr")]
@@ -210,9 +250,16 @@ impl DocumentExtractor for PptxExtractor {
             None
         };
 
+        let revisions = (!pptx_result.revisions.is_empty()).then_some(pptx_result.revisions);
         let mut metadata = Metadata {
             format: Some(crate::types::FormatMetadata::Pptx(pptx_result.metadata)),
+            revisions,
             additional,
+            stats: Some(ExtractionStats {
+                table_count: pptx_result.table_count,
+                image_count: pptx_result.image_count,
+                ..Default::default()
+            }),
             ..Default::default()
         };
 
@@ -229,6 +276,11 @@ impl DocumentExtractor for PptxExtractor {
             detected_languages: None,
             chunks: None,
             images,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -0,0 +1,274 @@
+//! Outlook PST/OST mailbox extractor.
+
+use crate::Result;
+use crate::core::config::ExtractionConfig;
+use crate::core::mime::{MSG_MIME_TYPE, OST_MIME_TYPE, PST_MIME_TYPE};
+use crate::extraction::pst::{
+    MailboxExport, extract_pst_messages_from_bytes_with_limits, extract_pst_messages_with_limits,
+};
+use crate::extractors::email::email_extraction_result;
+use crate::plugins::{DocumentExtractor, Plugin};
+use crate::types::{ExtractionResult, ExtractionWarning, Metadata, WarningStage};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Outlook PST/OST mailbox extractor.
+///
+/// Supports: .pst, .ost
+///
+/// Every message becomes a child document built like an `.msg` extraction (including
+/// attachment extraction via [`EmailConfig`](crate::core::config::EmailConfig)) with its
+/// folder in `metadata.additional["folder_path"]`. The mailbox content is the text of all
+/// messages, each preceded by its folder. Messages beyond the mailbox limits of the
+/// `EmailConfig` are skipped with a `mailbox_truncated` warning. Requires `pffexport` from
+/// libpff at runtime.
+pub struct PstExtractor;
+
+impl Default for PstExtractor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PstExtractor {
+    pub fn new() -> Self {
+        Self
+    }
+
+    async fn build_result(
+        &self,
+        export: MailboxExport,
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> ExtractionResult {
+        let messages = export.messages;
+        let mut sections = Vec::with_capacity(messages.len());
+        let mut children = Vec::with_capacity(messages.len());
+        let mut folders: Vec<(String, usize)> = Vec::new();
+
+        for (index, message) in messages.into_iter().enumerate() {
+            let mut child = email_extraction_result(&message.email, MSG_MIME_TYPE, config).await;
+
+            sections.push(format!("Folder: {}\n{}", message.folder_path, child.content));
+
+            match folders.last_mut() {
+                Some((path, count)) if *path == message.folder_path => *count += 1,
+                _ => folders.push((message.folder_path.clone(), 1)),
+            }
+
+            child.metadata.additional.insert(
+                "parent".to_string(),
+                serde_json::json!({
+                    "mime_type": mime_type,
+                    "message_index": index,
+                    "folder_path": message.folder_path,
+                }),
+            );
+            child
+                .metadata
+                .additional
+                .insert("folder_path".to_string(), serde_json::json!(message.folder_path));
+            children.push(child);
+        }
+
+        let folder_details: Vec<serde_json::Value> = folders
+            .iter()
+            .map(|(path, count)| serde_json::json!({ "path": path, "message_count": count }))
+            .collect();
+
+        let mut warnings = Vec::new();
+        if export.truncated {
+            let message = format!(
+                "Mailbox limits reached after {} messages, remaining messages were not extracted",
+                children.len()
+            );
+            warnings.push(ExtractionWarning::new(
+                "mailbox_truncated",
+                message,
+                WarningStage::Limits,
+            ));
+        }
+
+        let mut additional = HashMap::new();
+        additional.insert("message_count".to_string(), serde_json::json!(children.len()));
+        additional.insert("folders".to_string(), serde_json::json!(folder_details));
+
+        ExtractionResult {
+            content: sections.join("\n\n"),
+            mime_type: mime_type.to_string(),
+            metadata: Metadata {
+                additional,
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: Some(children),
+            fields: None,
+            warnings,
+        }
+    }
+}
+
+impl Plugin for PstExtractor {
+    fn name(&self) -> &str {
+        "pst-extractor"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn description(&self) -> &str {
+        "Extracts messages and folder structure from Outlook PST/OST mailboxes"
+    }
+
+    fn author(&self) -> &str {
+        "Kreuzberg Team"
+    }
+}
+
+#[async_trait]
+impl DocumentExtractor for PstExtractor {
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, content, config),
+        fields(
+            extractor.name = self.name(),
+            content.size_bytes = content.len(),
+        )
+    ))]
+    async fn extract_bytes(
+        &self,
+        content: &[u8],
+        mime_type: &str,
+        config: &ExtractionConfig,
+    ) -> Result<ExtractionResult> {
+        let limits = config.email.clone().unwrap_or_default();
+        let export = extract_pst_messages_from_bytes_with_limits(content, &limits).await?;
+        Ok(self.build_result(export, mime_type, config).await)
+    }
+
+    #[cfg_attr(feature = "otel", tracing::instrument(
+        skip(self, path, config),
+        fields(
+            extractor.name = self.name(),
+        )
+    ))]
+    async fn extract_file(&self, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
+        let limits = config.email.clone().unwrap_or_default();
+        let export = extract_pst_messages_with_limits(path, &limits).await?;
+        Ok(self.build_result(export, mime_type, config).await)
+    }
+
+    fn supported_mime_types(&self) -> &[&str] {
+        &[PST_MIME_TYPE, OST_MIME_TYPE]
+    }
+
+    fn priority(&self) -> i32 {
+        50
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::extraction::pst::MailboxMessage;
+    use crate::types::EmailExtractionResult;
+
+    fn message(folder_path: &str, subject: &str) -> MailboxMessage {
+        MailboxMessage {
+            folder_path: folder_path.to_string(),
+            email: EmailExtractionResult {
+                subject: Some(subject.to_string()),
+                from_email: Some("alice@example.com".to_string()),
+                to_emails: vec!["bob@example.com".to_string()],
+                cc_emails: vec![],
+                bcc_emails: vec![],
+                date: None,
+                message_id: None,
+                from_name: None,
+                reply_to_emails: vec![],
+                in_reply_to: None,
+                references: vec![],
+                thread_id: None,
+                has_dkim_signature: false,
+                raw_headers: vec![],
+                plain_text: Some("Body".to_string()),
+                html_content: None,
+                cleaned_text: "Body".to_string(),
+                attachments: vec![],
+                calendar_events: vec![],
+                quoted_text: None,
+                signature: None,
+                tracking_pixels: vec![],
+                metadata: HashMap::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_pst_extractor_plugin_interface() {
+        let extractor = PstExtractor::new();
+        assert_eq!(extractor.name(), "pst-extractor");
+        assert_eq!(extractor.supported_mime_types(), &[PST_MIME_TYPE, OST_MIME_TYPE]);
+        assert!(extractor.initialize().is_ok());
+        assert!(extractor.shutdown().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_pst_result_groups_messages_by_folder() {
+        let export = MailboxExport {
+            messages: vec![
+                message("Inbox", "First"),
+                message("Inbox", "Second"),
+                message("Sent Items", "Third"),
+            ],
+            truncated: false,
+        };
+
+        let result = PstExtractor::new()
+            .build_result(export, PST_MIME_TYPE, &ExtractionConfig::default())
+            .await;
+
+        assert!(result.content.contains("Folder: Inbox\nSubject: First"));
+        assert_eq!(result.metadata.additional["message_count"], 3);
+        assert_eq!(result.metadata.additional["folders"][0]["message_count"], 2);
+        assert_eq!(result.metadata.additional["folders"][1]["path"], "Sent Items");
+
+        let children = result.children.unwrap();
+        assert_eq!(children.len(), 3);
+        assert_eq!(children[2].mime_type, MSG_MIME_TYPE);
+        assert_eq!(children[2].metadata.subject.as_deref(), Some("Third"));
+        assert_eq!(children[2].metadata.additional["folder_path"], "Sent Items");
+        assert_eq!(children[2].metadata.additional["parent"]["message_index"], 2);
+        assert!(result.warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pst_result_warns_when_truncated() {
+        let export = MailboxExport {
+            messages: vec![message("Inbox", "First")],
+            truncated: true,
+        };
+
+        let result = PstExtractor::new()
+            .build_result(export, PST_MIME_TYPE, &ExtractionConfig::default())
+            .await;
+
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(result.warnings[0].code, "mailbox_truncated");
+    }
+}
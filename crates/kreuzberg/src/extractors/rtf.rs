@@ -13,7 +13,7 @@ use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::extraction::cells_to_markdown;
 use crate::plugins::{DocumentExtractor, Plugin};
-use crate::types::{ExtractionResult, Metadata, Table};
+use crate::types::{ExtractionResult, FormatMetadata, Metadata, OfficeMetadata, Table};
 use async_trait::async_trait;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -136,7 +136,7 @@ fn parse_rtf_control_word(chars: &mut std::iter::Peekable<std::str::Chars>) -> (
 /// 3. Extracting text while skipping formatting groups
 /// 4. Detecting and extracting image metadata (\pict sections)
 /// 5. Normalizing whitespace
-fn extract_text_from_rtf(content: &str) -> (String, Vec<Table>) {
+pub(crate) fn extract_text_from_rtf(content: &str) -> (String, Vec<Table>) {
     struct TableState {
         rows: Vec<Vec<String>>,
         current_row: Vec<String>,
@@ -503,8 +503,12 @@ fn parse_rtf_datetime(segment: &str) -> Option<String> {
 }
 
 /// Extract metadata from the RTF `\\info` block and augment with computed statistics.
-fn extract_rtf_metadata(rtf_content: &str, extracted_text: &str) -> HashMap<String, Value> {
+///
+/// Common fields are returned as `additional` entries for promotion; the `\\version`
+/// revision number has no common counterpart and is returned in the office section.
+fn extract_rtf_metadata(rtf_content: &str, extracted_text: &str) -> (HashMap<String, Value>, Option<OfficeMetadata>) {
     let mut metadata: HashMap<String, Value> = HashMap::new();
+    let mut office: Option<OfficeMetadata> = None;
 
     if let Some(start) = rtf_content.find("{\\info") {
         let slice = &rtf_content[start..];
@@ -614,7 +618,7 @@ fn extract_rtf_metadata(rtf_content: &str, extracted_text: &str) -> HashMap<Stri
                 }
                 "version" => {
                     if let Some(val) = numeric.or_else(|| trimmed.parse::<i32>().ok()) {
-                        metadata.insert("revision".to_string(), Value::String(val.to_string()));
+                        office.get_or_insert_with(OfficeMetadata::default).revision = Some(val.to_string());
                     }
                 }
                 "nofpages" => {
@@ -670,7 +674,7 @@ fn extract_rtf_metadata(rtf_content: &str, extracted_text: &str) -> HashMap<Stri
             .or_insert(Value::Number(paragraph_count.into()));
     }
 
-    metadata
+    (metadata, office)
 }
 
 /// Extract image metadata from within a \pict group.
@@ -760,12 +764,13 @@ impl DocumentExtractor for RtfExtractor {
         let rtf_content = String::from_utf8_lossy(content);
 
         let (extracted_text, tables) = extract_text_from_rtf(&rtf_content);
-        let metadata_map = extract_rtf_metadata(&rtf_content, &extracted_text);
+        let (metadata_map, office) = extract_rtf_metadata(&rtf_content, &extracted_text);
 
         Ok(ExtractionResult {
             content: extracted_text,
             mime_type: mime_type.to_string(),
             metadata: Metadata {
+                format: office.map(FormatMetadata::Office),
                 additional: metadata_map,
                 ..Default::default()
             },
@@ -774,6 +779,11 @@ impl DocumentExtractor for RtfExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
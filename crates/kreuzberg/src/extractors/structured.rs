@@ -2,6 +2,7 @@
 
 use crate::Result;
 use crate::core::config::ExtractionConfig;
+use crate::extraction::structured::{parse_json_with_options, parse_yaml_with_options};
 use crate::plugins::{DocumentExtractor, Plugin};
 use crate::types::{ExtractionResult, Metadata};
 use async_trait::async_trait;
@@ -44,7 +45,7 @@ impl Plugin for StructuredExtractor {
 #[async_trait]
 impl DocumentExtractor for StructuredExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -54,11 +55,12 @@ impl DocumentExtractor for StructuredExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
+        let options = config.structured.clone().unwrap_or_default();
         let structured_result = match mime_type {
-            "application/json" | "text/json" => crate::extraction::structured::parse_json(content, None)?,
-            "application/x-yaml" | "text/yaml" | "text/x-yaml" => crate::extraction::structured::parse_yaml(content)?,
+            "application/json" | "text/json" => parse_json_with_options(content, None, &options)?,
+            "application/x-yaml" | "text/yaml" | "text/x-yaml" => parse_yaml_with_options(content, &options)?,
             "application/toml" | "text/toml" => crate::extraction::structured::parse_toml(content)?,
             _ => return Err(crate::KreuzbergError::UnsupportedFormat(mime_type.to_string())),
         };
@@ -69,6 +71,9 @@ impl DocumentExtractor for StructuredExtractor {
             serde_json::json!(structured_result.text_fields.len()),
         );
         additional.insert("data_format".to_string(), serde_json::json!(structured_result.format));
+        if structured_result.truncated {
+            additional.insert("truncated".to_string(), serde_json::json!(true));
+        }
 
         for (key, value) in structured_result.metadata {
             additional.insert(key, serde_json::json!(value));
@@ -82,10 +87,15 @@ impl DocumentExtractor for StructuredExtractor {
                 ..Default::default()
             },
             pages: None,
-            tables: vec![],
+            tables: structured_result.tables,
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -97,7 +107,7 @@ impl DocumentExtractor for StructuredExtractor {
         )
     ))]
     async fn extract_file(&self, path: &Path, mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
-        let bytes = tokio::fs::read(path).await?;
+        let bytes = crate::core::io::load_file_async(path).await?;
         self.extract_bytes(&bytes, mime_type, config).await
     }
 
@@ -139,4 +149,31 @@ mod tests {
         assert!(mime_types.contains(&"application/x-yaml"));
         assert!(mime_types.contains(&"application/toml"));
     }
+
+    #[tokio::test]
+    async fn test_structured_extractor_tabular_json() {
+        let extractor = StructuredExtractor::new();
+        let config = ExtractionConfig {
+            structured: Some(crate::core::config::StructuredConfig {
+                tabular: true,
+                max_array_items: Some(2),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let json = br#"{"orders": [{"id": 1, "total": 9.5}, {"id": 2, "total": 3}, {"id": 3, "total": 1}]}"#;
+
+        let result = extractor
+            .extract_bytes(json, "application/json", &config)
+            .await
+            .unwrap();
+
+        assert_eq!(result.tables.len(), 1);
+        assert_eq!(result.tables[0].cells.len(), 3);
+        assert!(result.content.starts_with("orders:\n| id | total |"));
+        assert_eq!(
+            result.metadata.additional.get("truncated"),
+            Some(&serde_json::json!(true))
+        );
+    }
 }
@@ -7,6 +7,29 @@ use crate::plugins::{DocumentExtractor, Plugin};
 use crate::types::ExtractionResult;
 use async_trait::async_trait;
 
+/// Decode a text file and name the encoding it was decoded from.
+///
+/// Valid UTF-8 is used as is. Anything else goes through encoding detection, which recognizes
+/// legacy encodings such as windows-1252, ISO-8859-x, Shift-JIS and EBCDIC, unless
+/// `text_encoding` is configured.
+#[cfg(feature = "quality")]
+fn decode_text(content: &[u8], config: &ExtractionConfig) -> (String, Option<String>) {
+    if config.text_encoding.is_none()
+        && let Ok(text) = std::str::from_utf8(content)
+    {
+        return (text.to_string(), Some("UTF-8".to_string()));
+    }
+    let (text, encoding) = crate::utils::decode_with_encoding(content, config.text_encoding.as_deref());
+    (text, Some(encoding.to_string()))
+}
+
+/// Without the `quality` feature there is no encoding detection; invalid UTF-8 is replaced.
+#[cfg(not(feature = "quality"))]
+fn decode_text(content: &[u8], _config: &ExtractionConfig) -> (String, Option<String>) {
+    let encoding = std::str::from_utf8(content).is_ok().then(|| "UTF-8".to_string());
+    (String::from_utf8_lossy(content).into_owned(), encoding)
+}
+
 /// Plain text extractor.
 ///
 /// Extracts content from plain text files (.txt).
@@ -54,7 +77,7 @@ impl Plugin for PlainTextExtractor {
 #[async_trait]
 impl DocumentExtractor for PlainTextExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -64,9 +87,9 @@ impl DocumentExtractor for PlainTextExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let text = String::from_utf8_lossy(content).into_owned();
+        let (text, encoding) = decode_text(content, config);
         let text = text.trim_end_matches('\n').trim_end_matches('\r').to_string();
         let line_count = text.lines().count();
         let word_count = text.split_whitespace().count();
@@ -83,6 +106,7 @@ impl DocumentExtractor for PlainTextExtractor {
                     headers: None,
                     links: None,
                     code_blocks: None,
+                    encoding,
                 })),
                 ..Default::default()
             },
@@ -91,6 +115,11 @@ impl DocumentExtractor for PlainTextExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -151,7 +180,7 @@ impl Plugin for MarkdownExtractor {
 #[async_trait]
 impl DocumentExtractor for MarkdownExtractor {
     #[cfg_attr(feature = "otel", tracing::instrument(
-        skip(self, content, _config),
+        skip(self, content, config),
         fields(
             extractor.name = self.name(),
             content.size_bytes = content.len(),
@@ -161,9 +190,10 @@ impl DocumentExtractor for MarkdownExtractor {
         &self,
         content: &[u8],
         mime_type: &str,
-        _config: &ExtractionConfig,
+        config: &ExtractionConfig,
     ) -> Result<ExtractionResult> {
-        let text_result = parse_text(content, true)?;
+        let (text, encoding) = decode_text(content, config);
+        let text_result = parse_text(text.as_bytes(), true)?;
 
         Ok(ExtractionResult {
             content: text_result.content,
@@ -176,6 +206,7 @@ impl DocumentExtractor for MarkdownExtractor {
                     headers: text_result.headers,
                     links: text_result.links,
                     code_blocks: text_result.code_blocks,
+                    encoding,
                 })),
                 ..Default::default()
             },
@@ -184,6 +215,11 @@ impl DocumentExtractor for MarkdownExtractor {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -219,6 +255,33 @@ mod tests {
         assert_eq!(text_meta.word_count, 6);
     }
 
+    #[cfg(feature = "quality")]
+    #[tokio::test]
+    async fn test_plain_text_extractor_legacy_encoding() {
+        const TEXT: &str = "Grüße aus Köln.\nLe garçon a mangé une crème brûlée.";
+        let extractor = PlainTextExtractor::new();
+        let (content, _, _) = encoding_rs::WINDOWS_1252.encode(TEXT);
+        let config = ExtractionConfig {
+            text_encoding: Some("windows-1252".to_string()),
+            ..Default::default()
+        };
+
+        let result = extractor.extract_bytes(&content, "text/plain", &config).await.unwrap();
+
+        assert_eq!(result.content, TEXT);
+        let crate::types::FormatMetadata::Text(text_meta) = result.metadata.format.unwrap() else {
+            panic!("Expected Text metadata");
+        };
+        assert_eq!(text_meta.encoding.as_deref(), Some("windows-1252"));
+
+        let ebcdic = b"\xC8\x85\x93\x93\x96\x6B\x40\xE6\x96\x99\x93\x84\x5A\x15".repeat(3);
+        let result = extractor
+            .extract_bytes(&ebcdic, "text/plain", &ExtractionConfig::default())
+            .await
+            .unwrap();
+        assert_eq!(result.content, "Hello, World!\nHello, World!\nHello, World!");
+    }
+
     #[tokio::test]
     async fn test_markdown_extractor() {
         let extractor = MarkdownExtractor::new();
@@ -1,11 +1,11 @@
 //! XML extractor.
 
-use crate::Result;
 use crate::core::config::ExtractionConfig;
-use crate::extraction::xml::parse_xml;
+use crate::extraction::xml::{map_xml, parse_xml};
 use crate::extractors::SyncExtractor;
 use crate::plugins::{DocumentExtractor, Plugin};
 use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
 use async_trait::async_trait;
 
 /// XML extractor.
@@ -53,16 +53,37 @@ impl Plugin for XmlExtractor {
 }
 
 impl SyncExtractor for XmlExtractor {
-    fn extract_sync(&self, content: &[u8], mime_type: &str, _config: &ExtractionConfig) -> Result<ExtractionResult> {
+    fn extract_sync(&self, content: &[u8], mime_type: &str, config: &ExtractionConfig) -> Result<ExtractionResult> {
         let xml_result = parse_xml(content, false)?;
+        let document_types = config.xml.as_ref().map_or(&[][..], |xml| xml.document_types.as_slice());
+        let mapped = match map_xml(content, document_types) {
+            Ok(mapped) => mapped,
+            Err(KreuzbergError::Parsing { message, .. }) => {
+                tracing::warn!("Skipping XPath mapping: {}", message);
+                None
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut text = xml_result.content;
+        let mut fields = None;
+        let mut document_type = None;
+        if let Some(mapped) = mapped {
+            if !mapped.sections.is_empty() {
+                text = mapped.to_markdown();
+            }
+            fields = Some(mapped.fields);
+            document_type = Some(mapped.document_type);
+        }
 
         Ok(ExtractionResult {
-            content: xml_result.content,
+            content: text,
             mime_type: mime_type.to_string(),
             metadata: crate::types::Metadata {
                 format: Some(crate::types::FormatMetadata::Xml(crate::types::XmlMetadata {
                     element_count: xml_result.element_count,
                     unique_elements: xml_result.unique_elements,
+                    document_type,
                 })),
                 ..Default::default()
             },
@@ -71,6 +92,11 @@ impl SyncExtractor for XmlExtractor {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields,
+            warnings: Vec::new(),
         })
     }
 }
@@ -133,6 +159,35 @@ mod tests {
         assert!(xml_meta.unique_elements.contains(&"item".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_xml_extractor_xpath_mapping() {
+        let extractor = XmlExtractor::new();
+        let content = br#"<order><id>A-17</id><note>Leave at door</note><item>Tea</item><item>Milk</item></order>"#;
+        let config: ExtractionConfig = serde_json::from_value(serde_json::json!({
+            "xml": {"document_types": [{
+                "name": "order",
+                "root": "order",
+                "fields": {"id": "/order/id", "items": "//item"},
+                "sections": [{"title": "Note", "path": "/order/note"}]
+            }]}
+        }))
+        .unwrap();
+
+        let result = extractor
+            .extract_bytes(content, "application/xml", &config)
+            .await
+            .unwrap();
+
+        assert_eq!(result.content, "## Note\n\nLeave at door");
+        let fields = result.fields.unwrap();
+        assert_eq!(fields["id"], "A-17");
+        assert_eq!(fields["items"], serde_json::json!(["Tea", "Milk"]));
+        let Some(crate::types::FormatMetadata::Xml(xml_meta)) = &result.metadata.format else {
+            panic!("Expected Xml metadata");
+        };
+        assert_eq!(xml_meta.document_type.as_deref(), Some("order"));
+    }
+
     #[test]
     fn test_xml_plugin_interface() {
         let extractor = XmlExtractor::new();
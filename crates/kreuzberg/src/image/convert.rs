@@ -0,0 +1,188 @@
+//! Image format conversion and compression.
+//!
+//! [`convert_image`] decodes an image, optionally downscales it and encodes it again in another
+//! format. Re-encoding as JPEG with a lower quality is the usual way to shrink extracted images
+//! before storing them or sending them to a vision model.
+
+use crate::error::{KreuzbergError, Result};
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageFormat};
+use std::io::Cursor;
+use std::str::FromStr;
+
+use super::resize::resize_image;
+
+/// Output format of [`convert_image`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImageOutputFormat {
+    /// Lossless PNG
+    #[default]
+    Png,
+    /// JPEG, encoded with [`ConvertOptions::quality`] (transparency is dropped)
+    Jpeg,
+    /// Lossless WebP
+    Webp,
+    /// TIFF
+    Tiff,
+    /// BMP
+    Bmp,
+    /// GIF
+    Gif,
+}
+
+impl ImageOutputFormat {
+    fn image_format(self) -> ImageFormat {
+        match self {
+            Self::Png => ImageFormat::Png,
+            Self::Jpeg => ImageFormat::Jpeg,
+            Self::Webp => ImageFormat::WebP,
+            Self::Tiff => ImageFormat::Tiff,
+            Self::Bmp => ImageFormat::Bmp,
+            Self::Gif => ImageFormat::Gif,
+        }
+    }
+}
+
+impl FromStr for ImageOutputFormat {
+    type Err = KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "png" => Ok(Self::Png),
+            "jpeg" | "jpg" => Ok(Self::Jpeg),
+            "webp" => Ok(Self::Webp),
+            "tiff" | "tif" => Ok(Self::Tiff),
+            "bmp" => Ok(Self::Bmp),
+            "gif" => Ok(Self::Gif),
+            _ => Err(KreuzbergError::validation(format!(
+                "Invalid image format '{}'. Use one of: png, jpeg, webp, tiff, bmp, gif",
+                s
+            ))),
+        }
+    }
+}
+
+/// Options of [`convert_image`].
+#[derive(Debug, Clone)]
+pub struct ConvertOptions {
+    /// Format to encode the image in
+    pub format: ImageOutputFormat,
+    /// JPEG quality from 1 (smallest) to 100 (best); ignored by the lossless formats
+    pub quality: u8,
+    /// Downscale the image so that neither side exceeds this many pixels (None = keep the size)
+    pub max_dimension: Option<u32>,
+}
+
+impl Default for ConvertOptions {
+    fn default() -> Self {
+        Self {
+            format: ImageOutputFormat::Png,
+            quality: 85,
+            max_dimension: None,
+        }
+    }
+}
+
+/// Re-encode an image in another format, optionally downscaling it first.
+///
+/// Accepts every format Kreuzberg can decode (PNG, JPEG, WebP, TIFF, BMP, GIF). Downscaled
+/// images are converted to RGB.
+///
+/// # Errors
+///
+/// `ImageProcessing` when the input cannot be decoded or the output cannot be encoded.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use kreuzberg::image::{ConvertOptions, ImageOutputFormat, convert_image};
+///
+/// # fn example() -> kreuzberg::Result<()> {
+/// let png = std::fs::read("scan.png")?;
+/// let options = ConvertOptions {
+///     format: ImageOutputFormat::Jpeg,
+///     quality: 70,
+///     max_dimension: Some(2000),
+/// };
+/// let jpeg = convert_image(&png, &options)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn convert_image(data: &[u8], options: &ConvertOptions) -> Result<Vec<u8>> {
+    let image = image::load_from_memory(data)
+        .map_err(|e| KreuzbergError::image_processing(format!("Failed to decode image: {}", e)))?;
+    let image = downscale(image, options.max_dimension)?;
+
+    let mut bytes = Vec::new();
+    let encoded = match options.format {
+        ImageOutputFormat::Jpeg => {
+            let encoder = JpegEncoder::new_with_quality(&mut bytes, options.quality.clamp(1, 100));
+            DynamicImage::ImageRgb8(image.to_rgb8()).write_with_encoder(encoder)
+        }
+        format => image.write_to(&mut Cursor::new(&mut bytes), format.image_format()),
+    };
+    encoded.map_err(|e| KreuzbergError::image_processing(format!("Failed to encode image: {}", e)))?;
+    Ok(bytes)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn downscale(image: DynamicImage, max_dimension: Option<u32>) -> Result<DynamicImage> {
+    let longest_side = image.width().max(image.height());
+    let Some(max_dimension) = max_dimension.filter(|&max| max > 0 && longest_side > max) else {
+        return Ok(image);
+    };
+
+    let scale = f64::from(max_dimension) / f64::from(longest_side);
+    let width = ((f64::from(image.width()) * scale).round() as u32).max(1);
+    let height = ((f64::from(image.height()) * scale).round() as u32).max(1);
+    resize_image(&image, width, height, scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgb};
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let image = ImageBuffer::from_fn(width, height, |x, y| Rgb([(x % 256) as u8, (y % 256) as u8, 128]));
+        let mut bytes = Vec::new();
+        DynamicImage::ImageRgb8(image)
+            .write_to(&mut Cursor::new(&mut bytes), ImageFormat::Png)
+            .unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_convert_to_jpeg_and_downscale() {
+        let options = ConvertOptions {
+            format: ImageOutputFormat::Jpeg,
+            quality: 60,
+            max_dimension: Some(100),
+        };
+        let jpeg = convert_image(&png(400, 200), &options).unwrap();
+
+        assert_eq!(image::guess_format(&jpeg).unwrap(), ImageFormat::Jpeg);
+        let decoded = image::load_from_memory(&jpeg).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (100, 50));
+    }
+
+    #[test]
+    fn test_convert_keeps_size_without_max_dimension() {
+        let options = ConvertOptions {
+            format: ImageOutputFormat::Webp,
+            ..Default::default()
+        };
+        let webp = convert_image(&png(40, 30), &options).unwrap();
+
+        let decoded = image::load_from_memory(&webp).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (40, 30));
+    }
+
+    #[test]
+    fn test_convert_invalid_input() {
+        let err = convert_image(b"not an image", &ConvertOptions::default()).unwrap_err();
+        assert!(matches!(err, KreuzbergError::ImageProcessing { .. }), "{}", err);
+        assert_eq!("JPG".parse::<ImageOutputFormat>().unwrap(), ImageOutputFormat::Jpeg);
+        assert!("svg".parse::<ImageOutputFormat>().is_err());
+    }
+}
@@ -0,0 +1,407 @@
+//! Image enhancement applied before OCR.
+//!
+//! Low-quality scans are cleaned up according to [`ImagePreprocessingConfig`], in this order:
+//! grayscale conversion, color inversion, denoising, contrast stretching, binarization and
+//! border removal. Every step after the grayscale conversion works on a single channel, so
+//! enabling any of them converts the image to grayscale as well.
+
+use crate::types::ImagePreprocessingConfig;
+use image::{DynamicImage, GrayImage, Luma};
+
+/// Side of the square window, in pixels, used by the adaptive and Sauvola thresholds.
+const THRESHOLD_WINDOW: u32 = 31;
+
+/// Amount subtracted from the local mean by the adaptive threshold.
+const ADAPTIVE_OFFSET: f64 = 10.0;
+
+/// Sauvola sensitivity to local contrast.
+const SAUVOLA_K: f64 = 0.34;
+
+/// Dynamic range of the standard deviation in the Sauvola threshold.
+const SAUVOLA_R: f64 = 128.0;
+
+/// Share of pixels clipped at each end of the histogram by the contrast stretch.
+const CONTRAST_CLIP: f64 = 0.01;
+
+/// Largest share of the width or height whitened on each side by border removal.
+const MAX_BORDER_RATIO: f64 = 0.1;
+
+/// Share of dark pixels above which an edge row or column is treated as border.
+const BORDER_DARK_RATIO: f64 = 0.5;
+
+/// Gray level below which a pixel counts as dark.
+const DARK_LEVEL: u8 = 128;
+
+/// Apply the enhancement steps enabled in `config` to an image about to be OCRed.
+///
+/// Returns the image unchanged when no step is enabled. Unknown binarization methods are
+/// ignored here, since configuration validation already rejects them.
+pub fn preprocess_for_ocr(image: DynamicImage, config: &ImagePreprocessingConfig) -> DynamicImage {
+    let method = config.binarization_method.as_str();
+    let binarize = matches!(method, "otsu" | "adaptive" | "sauvola");
+    let enhance = config.invert_colors || config.denoise || config.contrast_enhance || config.remove_borders;
+    if !config.grayscale && !enhance && !binarize {
+        return image;
+    }
+
+    let mut gray = image.to_luma8();
+    if config.invert_colors {
+        invert(&mut gray);
+    }
+    if config.denoise {
+        gray = median_filter(&gray);
+    }
+    if config.contrast_enhance {
+        stretch_contrast(&mut gray);
+    }
+    match method {
+        "otsu" => {
+            let threshold = otsu_threshold(&gray);
+            for pixel in gray.pixels_mut() {
+                pixel.0[0] = if pixel.0[0] <= threshold { 0 } else { 255 };
+            }
+        }
+        "adaptive" => threshold_locally(&mut gray, adaptive_threshold),
+        "sauvola" => threshold_locally(&mut gray, sauvola_threshold),
+        _ => {}
+    }
+    if config.remove_borders {
+        remove_borders(&mut gray);
+    }
+
+    DynamicImage::ImageLuma8(gray)
+}
+
+fn invert(image: &mut GrayImage) {
+    for pixel in image.pixels_mut() {
+        pixel.0[0] = 255 - pixel.0[0];
+    }
+}
+
+/// Replace every pixel with the median of its 3×3 neighbourhood, which removes speckles
+/// without blurring the edges of glyphs.
+fn median_filter(image: &GrayImage) -> GrayImage {
+    let (width, height) = image.dimensions();
+    GrayImage::from_fn(width, height, |x, y| {
+        let mut window = [0u8; 9];
+        for (i, level) in window.iter_mut().enumerate() {
+            let dx = i as i32 % 3 - 1;
+            let dy = i as i32 / 3 - 1;
+            let nx = x.saturating_add_signed(dx).min(width - 1);
+            let ny = y.saturating_add_signed(dy).min(height - 1);
+            *level = image.get_pixel(nx, ny).0[0];
+        }
+        window.sort_unstable();
+        Luma([window[4]])
+    })
+}
+
+/// Stretch the gray levels so the darkest and lightest percent of pixels become black and white.
+fn stretch_contrast(image: &mut GrayImage) {
+    let histogram = histogram(image);
+    let total = image.as_raw().len();
+    if total == 0 {
+        return;
+    }
+
+    let clip = (total as f64 * CONTRAST_CLIP) as usize;
+    let low = level_at_rank(&histogram, clip);
+    let high = level_at_rank(&histogram, total - 1 - clip);
+    if high <= low {
+        return;
+    }
+
+    let range = f64::from(high - low);
+    for pixel in image.pixels_mut() {
+        let level = pixel.0[0].clamp(low, high) - low;
+        pixel.0[0] = (f64::from(level) * 255.0 / range).round() as u8;
+    }
+}
+
+fn histogram(image: &GrayImage) -> [usize; 256] {
+    let mut histogram = [0; 256];
+    for pixel in image.pixels() {
+        histogram[pixel.0[0] as usize] += 1;
+    }
+    histogram
+}
+
+/// The gray level of the pixel at `rank` when all pixels are sorted from dark to light.
+fn level_at_rank(histogram: &[usize; 256], rank: usize) -> u8 {
+    let mut seen = 0;
+    for (level, &count) in histogram.iter().enumerate() {
+        seen += count;
+        if seen > rank {
+            return level as u8;
+        }
+    }
+    u8::MAX
+}
+
+/// The global threshold that maximizes the variance between dark and light pixels.
+fn otsu_threshold(image: &GrayImage) -> u8 {
+    let histogram = histogram(image);
+    let total = image.as_raw().len();
+    let level_sum: f64 = histogram
+        .iter()
+        .enumerate()
+        .map(|(level, &count)| level as f64 * count as f64)
+        .sum();
+
+    let mut threshold = 0;
+    let mut best_variance = 0.0;
+    let mut dark_count = 0;
+    let mut dark_sum = 0.0;
+    for (level, &count) in histogram.iter().enumerate() {
+        dark_count += count;
+        dark_sum += level as f64 * count as f64;
+        if dark_count == 0 {
+            continue;
+        }
+        let light_count = total - dark_count;
+        if light_count == 0 {
+            break;
+        }
+
+        let dark_mean = dark_sum / dark_count as f64;
+        let light_mean = (level_sum - dark_sum) / light_count as f64;
+        let variance = dark_count as f64 * light_count as f64 * (dark_mean - light_mean).powi(2);
+        if variance > best_variance {
+            best_variance = variance;
+            threshold = level as u8;
+        }
+    }
+    threshold
+}
+
+fn adaptive_threshold(mean: f64, _deviation: f64) -> f64 {
+    mean - ADAPTIVE_OFFSET
+}
+
+fn sauvola_threshold(mean: f64, deviation: f64) -> f64 {
+    mean * (1.0 + SAUVOLA_K * (deviation / SAUVOLA_R - 1.0))
+}
+
+/// Binarize against a threshold computed from the mean and standard deviation of the window
+/// around each pixel, which copes with uneven lighting across the page.
+fn threshold_locally(image: &mut GrayImage, threshold: fn(f64, f64) -> f64) {
+    let integral = IntegralImage::new(image);
+    for (x, y, pixel) in image.enumerate_pixels_mut() {
+        let (mean, deviation) = integral.window_stats(x, y);
+        pixel.0[0] = if f64::from(pixel.0[0]) <= threshold(mean, deviation) {
+            0
+        } else {
+            255
+        };
+    }
+}
+
+/// Summed-area tables of the gray levels and their squares.
+struct IntegralImage {
+    width: u32,
+    height: u32,
+    sums: Vec<u64>,
+    squares: Vec<u64>,
+}
+
+impl IntegralImage {
+    fn new(image: &GrayImage) -> Self {
+        let (width, height) = image.dimensions();
+        let stride = width as usize + 1;
+        let mut sums = vec![0; stride * (height as usize + 1)];
+        let mut squares = vec![0; sums.len()];
+
+        for y in 0..height as usize {
+            let mut row_sum = 0;
+            let mut row_squares = 0;
+            for x in 0..width as usize {
+                let level = u64::from(image.get_pixel(x as u32, y as u32).0[0]);
+                row_sum += level;
+                row_squares += level * level;
+                let index = (y + 1) * stride + x + 1;
+                sums[index] = sums[index - stride] + row_sum;
+                squares[index] = squares[index - stride] + row_squares;
+            }
+        }
+
+        Self {
+            width,
+            height,
+            sums,
+            squares,
+        }
+    }
+
+    /// Mean and standard deviation of the window centered on a pixel, clipped to the image.
+    fn window_stats(&self, x: u32, y: u32) -> (f64, f64) {
+        let half = THRESHOLD_WINDOW / 2;
+        let (left, top) = (x.saturating_sub(half) as usize, y.saturating_sub(half) as usize);
+        let right = (x + half + 1).min(self.width) as usize;
+        let bottom = (y + half + 1).min(self.height) as usize;
+
+        let area = ((right - left) * (bottom - top)) as f64;
+        let mean = self.window_sum(&self.sums, left, top, right, bottom) as f64 / area;
+        let mean_square = self.window_sum(&self.squares, left, top, right, bottom) as f64 / area;
+        (mean, (mean_square - mean * mean).max(0.0).sqrt())
+    }
+
+    fn window_sum(&self, table: &[u64], left: usize, top: usize, right: usize, bottom: usize) -> u64 {
+        let stride = self.width as usize + 1;
+        (table[bottom * stride + right] + table[top * stride + left])
+            - (table[top * stride + right] + table[bottom * stride + left])
+    }
+}
+
+/// Whiten the mostly dark rows and columns along each edge, up to a tenth of the image.
+fn remove_borders(image: &mut GrayImage) {
+    let (width, height) = image.dimensions();
+    let max_rows = (f64::from(height) * MAX_BORDER_RATIO) as u32;
+    let max_columns = (f64::from(width) * MAX_BORDER_RATIO) as u32;
+
+    let top = border_width(max_rows, |y| is_dark_row(image, y));
+    let bottom = border_width(max_rows, |y| is_dark_row(image, height - 1 - y));
+    let left = border_width(max_columns, |x| is_dark_column(image, x));
+    let right = border_width(max_columns, |x| is_dark_column(image, width - 1 - x));
+
+    for (x, y, pixel) in image.enumerate_pixels_mut() {
+        if y < top || y >= height - bottom || x < left || x >= width - right {
+            pixel.0[0] = 255;
+        }
+    }
+}
+
+/// Number of lines from an edge that are border, counting inwards until the first that isn't.
+fn border_width(max_lines: u32, is_border: impl Fn(u32) -> bool) -> u32 {
+    (0..max_lines).take_while(|&line| is_border(line)).count() as u32
+}
+
+fn is_dark_row(image: &GrayImage, y: u32) -> bool {
+    is_mostly_dark((0..image.width()).map(|x| image.get_pixel(x, y).0[0]))
+}
+
+fn is_dark_column(image: &GrayImage, x: u32) -> bool {
+    is_mostly_dark((0..image.height()).map(|y| image.get_pixel(x, y).0[0]))
+}
+
+fn is_mostly_dark(levels: impl Iterator<Item = u8>) -> bool {
+    let (mut dark, mut total) = (0, 0);
+    for level in levels {
+        total += 1;
+        if level < DARK_LEVEL {
+            dark += 1;
+        }
+    }
+    total > 0 && dark as f64 > total as f64 * BORDER_DARK_RATIO
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Rgb};
+
+    fn config(binarization_method: &str) -> ImagePreprocessingConfig {
+        ImagePreprocessingConfig {
+            binarization_method: binarization_method.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn gray(width: u32, height: u32, level: impl Fn(u32, u32) -> u8) -> DynamicImage {
+        DynamicImage::ImageLuma8(GrayImage::from_fn(width, height, |x, y| Luma([level(x, y)])))
+    }
+
+    #[test]
+    fn test_otsu_separates_text_from_background() {
+        let image = gray(40, 40, |x, y| {
+            let noise = (y % 5) as u8;
+            if x < 20 { 60 + noise } else { 190 - noise }
+        });
+
+        let result = preprocess_for_ocr(image, &config("otsu")).to_luma8();
+
+        assert_eq!(result.get_pixel(5, 7).0[0], 0);
+        assert_eq!(result.get_pixel(30, 7).0[0], 255);
+        assert!(result.pixels().all(|pixel| pixel.0[0] == 0 || pixel.0[0] == 255));
+    }
+
+    #[test]
+    fn test_local_thresholds_handle_uneven_lighting() {
+        let background = |x: u32| 120 + (x / 2) as u8;
+        let image = gray(200, 40, |x, y| {
+            if y == 20 && x % 10 == 0 {
+                background(x) - 100
+            } else {
+                background(x)
+            }
+        });
+
+        for method in ["adaptive", "sauvola"] {
+            let result = preprocess_for_ocr(image.clone(), &config(method)).to_luma8();
+
+            assert_eq!(result.get_pixel(10, 20).0[0], 0, "{method}");
+            assert_eq!(result.get_pixel(190, 20).0[0], 0, "{method}");
+            assert_eq!(result.get_pixel(15, 5).0[0], 255, "{method}");
+            assert_eq!(result.get_pixel(195, 5).0[0], 255, "{method}");
+        }
+    }
+
+    #[test]
+    fn test_denoise_removes_speckles() {
+        let image = gray(10, 10, |x, y| if (x, y) == (4, 4) { 0 } else { 200 });
+        let config = ImagePreprocessingConfig {
+            denoise: true,
+            ..config("none")
+        };
+
+        let result = preprocess_for_ocr(image, &config).to_luma8();
+
+        assert!(result.pixels().all(|pixel| pixel.0[0] == 200));
+    }
+
+    #[test]
+    fn test_contrast_stretch_and_inversion() {
+        let image = gray(100, 1, |x, _| 100 + (x / 2) as u8);
+        let config = ImagePreprocessingConfig {
+            contrast_enhance: true,
+            invert_colors: true,
+            ..config("none")
+        };
+
+        let result = preprocess_for_ocr(image, &config).to_luma8();
+
+        assert_eq!(result.get_pixel(0, 0).0[0], 255);
+        assert_eq!(result.get_pixel(99, 0).0[0], 0);
+    }
+
+    #[test]
+    fn test_remove_borders_keeps_content() {
+        let image = gray(100, 100, |x, y| match (x, y) {
+            (x, y) if x < 4 || y < 4 || x > 96 || y > 96 => 0,
+            (50, 50) => 0,
+            _ => 255,
+        });
+        let config = ImagePreprocessingConfig {
+            remove_borders: true,
+            ..config("none")
+        };
+
+        let result = preprocess_for_ocr(image, &config).to_luma8();
+
+        assert_eq!(result.get_pixel(0, 0).0[0], 255);
+        assert_eq!(result.get_pixel(98, 50).0[0], 255);
+        assert_eq!(result.get_pixel(50, 50).0[0], 0);
+    }
+
+    #[test]
+    fn test_no_steps_keeps_image() {
+        let image = DynamicImage::ImageRgb8(ImageBuffer::from_pixel(4, 4, Rgb([10u8, 200, 30])));
+        let config = ImagePreprocessingConfig {
+            grayscale: false,
+            ..config("none")
+        };
+
+        let result = preprocess_for_ocr(image.clone(), &config);
+
+        assert_eq!(result, image);
+    }
+}
@@ -1,6 +1,11 @@
+pub mod convert;
 pub mod dpi;
+pub mod enhance;
 pub mod preprocessing;
 pub mod resize;
 
+pub use convert::{ConvertOptions, ImageOutputFormat, convert_image};
 pub use dpi::calculate_optimal_dpi;
+pub use enhance::preprocess_for_ocr;
 pub use preprocessing::{NormalizeResult, normalize_image_dpi};
+pub use resize::scale_image;
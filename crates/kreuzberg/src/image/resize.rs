@@ -30,6 +30,17 @@ pub fn resize_image(image: &DynamicImage, new_width: u32, new_height: u32, scale
     Ok(DynamicImage::ImageRgb8(img_buffer))
 }
 
+/// Scale an image by `scale_factor`, keeping it as is when its size would change by less than a percent.
+pub fn scale_image(image: DynamicImage, scale_factor: f64) -> Result<DynamicImage> {
+    if (scale_factor - 1.0).abs() < 0.01 {
+        return Ok(image);
+    }
+
+    let new_width = ((f64::from(image.width()) * scale_factor).round() as u32).max(1);
+    let new_height = ((f64::from(image.height()) * scale_factor).round() as u32).max(1);
+    resize_image(&image, new_width, new_height, scale_factor)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,4 +97,14 @@ mod tests {
         let resized_aspect = resized.width() as f64 / resized.height() as f64;
         assert!((original_aspect - resized_aspect).abs() < 0.01);
     }
+
+    #[test]
+    fn test_scale_image() {
+        let scaled = scale_image(create_test_image(), 300.0 / 72.0).unwrap();
+        assert_eq!(scaled.width(), 417);
+        assert_eq!(scaled.height(), 417);
+
+        let unchanged = scale_image(create_test_image(), 1.005).unwrap();
+        assert_eq!(unchanged.width(), 100);
+    }
 }
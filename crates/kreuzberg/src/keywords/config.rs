@@ -1,11 +1,13 @@
 //! Configuration for keyword extraction.
 
 use super::types::KeywordAlgorithm;
+use crate::core::config::StopwordsConfig;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// YAKE-specific parameters.
 #[cfg(feature = "keywords-yake")]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct YakeParams {
     /// Window size for co-occurrence analysis (default: 2).
     ///
@@ -22,7 +24,7 @@ impl Default for YakeParams {
 
 /// RAKE-specific parameters.
 #[cfg(feature = "keywords-rake")]
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RakeParams {
     /// Minimum word length to consider (default: 1).
     pub min_word_length: usize,
@@ -42,7 +44,7 @@ impl Default for RakeParams {
 }
 
 /// Keyword extraction configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct KeywordConfig {
     /// Algorithm to use for extraction.
     pub algorithm: KeywordAlgorithm,
@@ -65,9 +67,15 @@ pub struct KeywordConfig {
 
     /// Language code for stopword filtering (e.g., "en", "de", "fr").
     ///
-    /// If None, no stopword filtering is applied.
+    /// If None (the default), the post-processor uses the languages found by language
+    /// detection, extracting per language when a document mixes several, and falls back
+    /// to English when detection is disabled.
     pub language: Option<String>,
 
+    /// Custom stopwords merged with or replacing the embedded list for `language`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopwords: Option<StopwordsConfig>,
+
     /// YAKE-specific tuning parameters.
     #[cfg(feature = "keywords-yake")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -86,7 +94,8 @@ impl Default for KeywordConfig {
             max_keywords: 10,
             min_score: 0.0,
             ngram_range: (1, 3),
-            language: Some("en".to_string()),
+            language: None,
+            stopwords: None,
             #[cfg(feature = "keywords-yake")]
             yake_params: None,
             #[cfg(feature = "keywords-rake")]
@@ -138,6 +147,12 @@ impl KeywordConfig {
         self
     }
 
+    /// Set custom stopwords.
+    pub fn with_stopwords(mut self, stopwords: StopwordsConfig) -> Self {
+        self.stopwords = Some(stopwords);
+        self
+    }
+
     /// Set YAKE-specific parameters.
     #[cfg(feature = "keywords-yake")]
     pub fn with_yake_params(mut self, params: YakeParams) -> Self {
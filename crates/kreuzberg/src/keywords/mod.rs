@@ -4,6 +4,9 @@
 //! - YAKE (Yet Another Keyword Extractor) - statistical approach
 //! - RAKE (Rapid Automatic Keyword Extraction) - co-occurrence based
 //!
+//! The [`tfidf`] module complements these per-document algorithms with corpus-aware
+//! TF-IDF scoring that finds the terms distinguishing each document of a collection.
+//!
 //! # Feature Flags
 //!
 //! - `keywords-yake`: Enable YAKE algorithm
@@ -46,6 +49,7 @@ use std::sync::Arc;
 
 pub mod config;
 pub mod processor;
+pub mod tfidf;
 pub mod types;
 
 #[cfg(feature = "keywords-yake")]
@@ -56,6 +60,7 @@ mod rake;
 
 pub use config::KeywordConfig;
 pub use processor::KeywordExtractor;
+pub use tfidf::{TfIdfConfig, TfIdfCorpus, TfIdfTerm, extract_tfidf_terms, extract_tfidf_terms_from_texts};
 
 #[cfg(feature = "keywords-rake")]
 pub use config::RakeParams;
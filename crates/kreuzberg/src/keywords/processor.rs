@@ -1,9 +1,11 @@
 //! Keyword extraction post-processor.
 //!
 //! This module provides a PostProcessor plugin that extracts keywords from
-//! extraction results and stores them in metadata.
+//! extraction results and stores them in `ExtractionResult.keywords`.
 
+use super::{Keyword, KeywordConfig};
 use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::stopwords::language_segments;
 use crate::{ExtractionConfig, ExtractionResult, KreuzbergError, Result};
 use async_trait::async_trait;
 
@@ -12,8 +14,10 @@ use async_trait::async_trait;
 /// This processor:
 /// - Runs in the Middle processing stage
 /// - Only processes when `config.keywords` is configured
-/// - Stores extracted keywords in `metadata.additional["keywords"]`
+/// - Stores extracted keywords in `result.keywords`
 /// - Uses the configured algorithm (YAKE or RAKE)
+/// - Without an explicit `language`, picks stopwords from the detected languages,
+///   extracting per language and merging the results for mixed-language documents
 ///
 /// # Example
 ///
@@ -59,13 +63,13 @@ impl PostProcessor for KeywordExtractor {
             return Ok(());
         }
 
-        let keywords = super::extract_keywords(&result.content, keyword_config)
-            .map_err(|e| KreuzbergError::Other(format!("Keyword extraction failed: {}", e)))?;
+        let keywords = if keyword_config.language.is_some() {
+            extract(&result.content, keyword_config)?
+        } else {
+            extract_per_language(result, config, keyword_config)?
+        };
 
-        result
-            .metadata
-            .additional
-            .insert("keywords".to_string(), serde_json::to_value(&keywords)?);
+        result.keywords = Some(keywords);
 
         Ok(())
     }
@@ -84,6 +88,50 @@ impl PostProcessor for KeywordExtractor {
     }
 }
 
+fn extract(text: &str, config: &KeywordConfig) -> Result<Vec<Keyword>> {
+    super::extract_keywords(text, config)
+        .map_err(|e| KreuzbergError::Other(format!("Keyword extraction failed: {}", e)))
+}
+
+/// Extract keywords from each detected language's text with that language's stopwords.
+///
+/// Results are merged case-insensitively, keeping the best score, then re-ranked.
+fn extract_per_language(
+    result: &ExtractionResult,
+    config: &ExtractionConfig,
+    keyword_config: &KeywordConfig,
+) -> Result<Vec<Keyword>> {
+    let mut groups: Vec<(&str, Vec<&str>)> = Vec::new();
+    for (text, language) in language_segments(result, config) {
+        match groups.iter_mut().find(|(existing, _)| *existing == language) {
+            Some((_, texts)) => texts.push(text),
+            None => groups.push((language, vec![text])),
+        }
+    }
+
+    let mut merged: Vec<Keyword> = Vec::new();
+    for (language, texts) in groups {
+        let config = KeywordConfig {
+            language: Some(language.to_string()),
+            ..keyword_config.clone()
+        };
+        for keyword in extract(&texts.join("\n\n"), &config)? {
+            match merged
+                .iter_mut()
+                .find(|k| k.text.to_lowercase() == keyword.text.to_lowercase())
+            {
+                Some(existing) if keyword.score > existing.score => *existing = keyword,
+                Some(_) => {}
+                None => merged.push(keyword),
+            }
+        }
+    }
+
+    merged.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    merged.truncate(keyword_config.max_keywords);
+    Ok(merged)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,15 +162,18 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         processor.process(&mut result, &config).await.unwrap();
 
-        assert!(result.metadata.additional.contains_key("keywords"));
-        let keywords = result.metadata.additional.get("keywords").unwrap();
-        assert!(keywords.is_array());
-        let kw_array = keywords.as_array().unwrap();
-        assert!(!kw_array.is_empty());
+        let keywords = result.keywords.as_ref().expect("keywords should be extracted");
+        assert!(!keywords.is_empty());
+        assert!(!result.metadata.additional.contains_key("keywords"));
     }
 
     #[tokio::test]
@@ -143,15 +194,69 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         processor.process(&mut result, &config).await.unwrap();
 
-        assert!(result.metadata.additional.contains_key("keywords"));
-        let keywords = result.metadata.additional.get("keywords").unwrap();
-        assert!(keywords.is_array());
-        let kw_array = keywords.as_array().unwrap();
-        assert!(!kw_array.is_empty());
+        let keywords = result.keywords.as_ref().expect("keywords should be extracted");
+        assert!(!keywords.is_empty());
+        assert!(!result.metadata.additional.contains_key("keywords"));
+    }
+
+    #[tokio::test]
+    #[cfg(all(feature = "keywords-yake", feature = "language-detection"))]
+    async fn test_keyword_processor_uses_detected_languages() {
+        use crate::core::config::LanguageDetectionConfig;
+        use crate::types::DetectedLanguage;
+
+        let detected = |language: &str| DetectedLanguage {
+            language: language.to_string(),
+            confidence: 0.9,
+            script: "Latin".to_string(),
+        };
+        let config = ExtractionConfig {
+            keywords: Some(KeywordConfig::yake()),
+            language_detection: Some(LanguageDetectionConfig {
+                enabled: true,
+                min_confidence: 0.5,
+                detect_multiple: true,
+                max_sample_kb: None,
+            }),
+            ..Default::default()
+        };
+
+        let mut result = ExtractionResult {
+            content: "Die Maschine lernt aus den Daten, und die Maschine wird mit jedem Datensatz besser.\n\n\
+                      Machine learning systems learn from data and improve with every dataset."
+                .to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: Some(vec![detected("deu"), detected("eng")]),
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+
+        KeywordExtractor.process(&mut result, &config).await.unwrap();
+
+        let keywords = result.keywords.as_ref().expect("keywords should be extracted");
+        assert!(!keywords.is_empty());
+        assert!(keywords.len() <= 10);
+        assert!(!result.metadata.additional.contains_key("keywords"));
+        for stopword in ["die", "und", "and", "with"] {
+            assert!(!keywords.iter().any(|k| k.text.to_lowercase() == stopword));
+        }
     }
 
     #[tokio::test]
@@ -168,11 +273,16 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         processor.process(&mut result, &config).await.unwrap();
 
-        assert!(!result.metadata.additional.contains_key("keywords"));
+        assert!(result.keywords.is_none());
     }
 
     #[tokio::test]
@@ -193,11 +303,16 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         processor.process(&mut result, &config).await.unwrap();
 
-        assert!(!result.metadata.additional.contains_key("keywords"));
+        assert!(result.keywords.is_none());
     }
 
     #[test]
@@ -229,6 +344,11 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config_with_keywords = ExtractionConfig {
@@ -254,6 +374,11 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let long_result = ExtractionResult {
@@ -265,6 +390,11 @@ machine learning that uses neural networks with multiple layers.
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
@@ -3,7 +3,8 @@
 use super::config::{KeywordConfig, RakeParams};
 use super::types::{Keyword, KeywordAlgorithm};
 use crate::Result;
-use crate::stopwords::{STOPWORDS, get_stopwords};
+use crate::core::config::StopwordsConfig;
+use crate::stopwords::{STOPWORDS, apply_stopwords_config, get_stopwords};
 use rake::*;
 use std::collections::HashSet;
 
@@ -31,26 +32,28 @@ pub fn extract_keywords_rake(text: &str, config: &KeywordConfig) -> Result<Vec<K
     let lang = config.language.as_deref().unwrap_or("en");
     let normalized_lang = normalize_language_code(lang);
 
-    let mut keywords = run_rake_with_stopwords(text, config, &params, stopwords_for_language(lang));
+    let custom = config.stopwords.as_ref();
+    let mut keywords = run_rake_with_stopwords(text, config, &params, stopwords_for_language(lang, custom));
 
     if keywords.is_empty() && normalized_lang != "en" {
-        keywords = run_rake_with_stopwords(text, config, &params, stopwords_for_language("en"));
+        keywords = run_rake_with_stopwords(text, config, &params, stopwords_for_language("en", custom));
     }
 
     Ok(keywords)
 }
 
-fn stopwords_for_language(lang: &str) -> StopWords {
-    if let Some(words) = get_stopwords(lang) {
-        let set: HashSet<String> = words.iter().cloned().collect();
-        return StopWords::from(set);
+fn stopwords_for_language(lang: &str, custom: Option<&StopwordsConfig>) -> StopWords {
+    let mut words = get_stopwords(lang)
+        .or_else(|| STOPWORDS.get("en"))
+        .cloned()
+        .unwrap_or_default();
+
+    if let Some(custom) = custom {
+        apply_stopwords_config(&mut words, custom);
     }
 
-    let english: HashSet<String> = STOPWORDS
-        .get("en")
-        .map(|set| set.iter().cloned().collect())
-        .unwrap_or_default();
-    StopWords::from(english)
+    let set: HashSet<String> = words.into_iter().collect();
+    StopWords::from(set)
 }
 
 fn run_rake_with_stopwords(
@@ -0,0 +1,431 @@
+//! Corpus-aware term extraction with TF-IDF.
+//!
+//! YAKE and RAKE score terms within a single document. TF-IDF instead weighs how often a
+//! term occurs in one document against how many documents of a corpus contain it, so terms
+//! shared by every document (boilerplate, the corpus topic itself) rank low and terms that
+//! distinguish a document rank high.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use kreuzberg::keywords::tfidf::{TfIdfConfig, extract_tfidf_terms_from_texts};
+//!
+//! let texts = [
+//!     "The invoice lists the shipping costs for the pallets.",
+//!     "The invoice lists the consulting hours for the audit.",
+//! ];
+//! let terms = extract_tfidf_terms_from_texts(&texts, &TfIdfConfig::default().with_ngram_range(1, 1));
+//!
+//! assert_eq!(terms.len(), 2);
+//! assert!(terms[0].iter().any(|t| t.term == "pallets"));
+//! assert!(!terms[0].iter().take(3).any(|t| t.term == "invoice"));
+//! ```
+
+use crate::core::config::StopwordsConfig;
+use crate::stopwords::{apply_stopwords_config, get_stopwords_with_fallback, resolve_language};
+use crate::types::ExtractionResult;
+use ahash::{AHashMap, AHashSet};
+use serde::{Deserialize, Serialize};
+
+/// TF-IDF term extraction configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TfIdfConfig {
+    /// Maximum number of terms returned per document (default: 10).
+    pub max_terms: usize,
+
+    /// N-gram range of candidate terms (min, max) (default: (1, 2)).
+    pub ngram_range: (usize, usize),
+
+    /// Terms found in fewer documents than this are dropped (default: 1).
+    pub min_document_frequency: usize,
+
+    /// Terms found in more than this fraction of the documents are dropped (default: 1.0).
+    ///
+    /// Only applied to corpora of more than one document.
+    pub max_document_frequency: f32,
+
+    /// Use `1 + ln(count)` instead of the raw count as term frequency (default: true).
+    ///
+    /// Keeps a term repeated many times in one document from drowning out all others.
+    pub sublinear_tf: bool,
+
+    /// Language code for stopword filtering (e.g. "en", "deu").
+    ///
+    /// If None, each document uses its first detected language, falling back to English.
+    pub language: Option<String>,
+
+    /// Custom stopwords merged with or replacing the embedded list.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stopwords: Option<StopwordsConfig>,
+}
+
+impl Default for TfIdfConfig {
+    fn default() -> Self {
+        Self {
+            max_terms: 10,
+            ngram_range: (1, 2),
+            min_document_frequency: 1,
+            max_document_frequency: 1.0,
+            sublinear_tf: true,
+            language: None,
+            stopwords: None,
+        }
+    }
+}
+
+impl TfIdfConfig {
+    /// Set maximum number of terms per document.
+    pub fn with_max_terms(mut self, max: usize) -> Self {
+        self.max_terms = max;
+        self
+    }
+
+    /// Set n-gram range.
+    pub fn with_ngram_range(mut self, min: usize, max: usize) -> Self {
+        self.ngram_range = (min, max);
+        self
+    }
+
+    /// Set document frequency bounds.
+    pub fn with_document_frequency(mut self, min_documents: usize, max_ratio: f32) -> Self {
+        self.min_document_frequency = min_documents;
+        self.max_document_frequency = max_ratio;
+        self
+    }
+
+    /// Set language for stopword filtering.
+    pub fn with_language(mut self, lang: impl Into<String>) -> Self {
+        self.language = Some(lang.into());
+        self
+    }
+}
+
+/// A term that distinguishes a document within a corpus.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TfIdfTerm {
+    /// The term, lowercased (n-grams joined with single spaces).
+    pub term: String,
+
+    /// TF-IDF weight, normalized so that the document's weights have unit length.
+    pub score: f32,
+
+    /// Number of occurrences in the document.
+    pub term_frequency: usize,
+
+    /// Number of documents of the corpus containing the term.
+    pub document_frequency: usize,
+}
+
+/// A corpus of documents to extract TF-IDF terms from.
+///
+/// Documents are added one by one; scores are computed over all documents added so far.
+///
+/// # Examples
+///
+/// ```rust
+/// use kreuzberg::keywords::tfidf::{TfIdfConfig, TfIdfCorpus};
+///
+/// let mut corpus = TfIdfCorpus::new(TfIdfConfig::default().with_ngram_range(1, 1));
+/// corpus.add_text("Quarterly revenue grew in the cloud segment.", None);
+/// corpus.add_text("Quarterly revenue fell in the hardware segment.", None);
+///
+/// let top: Vec<String> = corpus.terms_for(1).into_iter().take(2).map(|t| t.term).collect();
+/// assert_eq!(top, ["fell", "hardware"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TfIdfCorpus {
+    config: TfIdfConfig,
+    documents: Vec<AHashMap<String, usize>>,
+    document_frequencies: AHashMap<String, usize>,
+}
+
+impl TfIdfCorpus {
+    /// Create an empty corpus.
+    pub fn new(config: TfIdfConfig) -> Self {
+        Self {
+            config,
+            documents: Vec::new(),
+            document_frequencies: AHashMap::new(),
+        }
+    }
+
+    /// Number of documents in the corpus.
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// Whether the corpus has no documents.
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Add an extraction result, using its first detected language for stopwords unless the
+    /// config sets one.
+    pub fn add_result(&mut self, result: &ExtractionResult) {
+        let language = result
+            .detected_languages
+            .as_ref()
+            .and_then(|languages| languages.first())
+            .map(|detected| detected.language.as_str());
+        self.add_text(&result.content, language);
+    }
+
+    /// Add a plain-text document.
+    ///
+    /// `language` (ISO 639-1 or 639-3) selects the stopword list when the config sets none.
+    pub fn add_text(&mut self, text: &str, language: Option<&str>) {
+        let language = self
+            .config
+            .language
+            .as_deref()
+            .or(language)
+            .and_then(resolve_language)
+            .unwrap_or("en");
+        let stopwords = stopwords_for(language, self.config.stopwords.as_ref());
+
+        let counts = count_terms(text, self.config.ngram_range, &stopwords);
+        for term in counts.keys() {
+            *self.document_frequencies.entry(term.clone()).or_default() += 1;
+        }
+        self.documents.push(counts);
+    }
+
+    /// The highest-scoring terms of every document, in the order the documents were added.
+    pub fn terms(&self) -> Vec<Vec<TfIdfTerm>> {
+        (0..self.documents.len()).map(|index| self.terms_for(index)).collect()
+    }
+
+    /// The highest-scoring terms of the document at `index` (empty if out of range).
+    pub fn terms_for(&self, index: usize) -> Vec<TfIdfTerm> {
+        let Some(counts) = self.documents.get(index) else {
+            return Vec::new();
+        };
+
+        let documents = self.documents.len();
+        let max_documents = if documents > 1 {
+            (self.config.max_document_frequency.clamp(0.0, 1.0) as f64 * documents as f64).floor() as usize
+        } else {
+            documents
+        };
+
+        let mut terms: Vec<TfIdfTerm> = counts
+            .iter()
+            .filter_map(|(term, &count)| {
+                let document_frequency = self.document_frequencies.get(term).copied().unwrap_or(1);
+                if document_frequency < self.config.min_document_frequency || document_frequency > max_documents {
+                    return None;
+                }
+
+                let tf = if self.config.sublinear_tf {
+                    1.0 + (count as f64).ln()
+                } else {
+                    count as f64
+                };
+                // Smoothed IDF, as if one extra document contained every term.
+                let idf = ((1 + documents) as f64 / (1 + document_frequency) as f64).ln() + 1.0;
+
+                Some(TfIdfTerm {
+                    term: term.clone(),
+                    score: (tf * idf) as f32,
+                    term_frequency: count,
+                    document_frequency,
+                })
+            })
+            .collect();
+
+        let norm = terms.iter().map(|t| t.score * t.score).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for term in &mut terms {
+                term.score /= norm;
+            }
+        }
+
+        terms.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.term.cmp(&b.term))
+        });
+        terms.truncate(self.config.max_terms);
+        terms
+    }
+}
+
+/// Extract the terms that distinguish each result from the others.
+///
+/// Returns one list per result, in input order, sorted by descending score.
+pub fn extract_tfidf_terms(results: &[ExtractionResult], config: &TfIdfConfig) -> Vec<Vec<TfIdfTerm>> {
+    let mut corpus = TfIdfCorpus::new(config.clone());
+    for result in results {
+        corpus.add_result(result);
+    }
+    corpus.terms()
+}
+
+/// Extract the terms that distinguish each text from the others.
+///
+/// Like [`extract_tfidf_terms`] for plain strings; stopwords follow `config.language`,
+/// defaulting to English.
+pub fn extract_tfidf_terms_from_texts<S: AsRef<str>>(texts: &[S], config: &TfIdfConfig) -> Vec<Vec<TfIdfTerm>> {
+    let mut corpus = TfIdfCorpus::new(config.clone());
+    for text in texts {
+        corpus.add_text(text.as_ref(), None);
+    }
+    corpus.terms()
+}
+
+fn stopwords_for(language: &str, custom: Option<&StopwordsConfig>) -> AHashSet<String> {
+    let mut stopwords = get_stopwords_with_fallback(language, "en").cloned().unwrap_or_default();
+    if let Some(custom) = custom {
+        apply_stopwords_config(&mut stopwords, custom);
+    }
+    stopwords
+}
+
+/// Count the candidate n-grams of `text`.
+///
+/// N-grams don't cross punctuation, may not start or end with a stopword, and consist of
+/// words that contain a letter and are at least two characters long.
+fn count_terms(text: &str, ngram_range: (usize, usize), stopwords: &AHashSet<String>) -> AHashMap<String, usize> {
+    let min_n = ngram_range.0.max(1);
+    let max_n = ngram_range.1.max(min_n);
+    let mut counts = AHashMap::new();
+
+    for phrase in text.split(|c: char| !(c.is_alphanumeric() || c.is_whitespace() || c == '\'' || c == '-')) {
+        let words: Vec<String> = phrase
+            .split_whitespace()
+            .map(|word| word.trim_matches(|c| c == '\'' || c == '-').to_lowercase())
+            .collect();
+
+        for start in 0..words.len() {
+            for n in min_n..=max_n {
+                let Some(ngram) = words.get(start..start + n) else {
+                    break;
+                };
+                if !ngram.iter().all(|word| is_term_word(word))
+                    || stopwords.contains(&ngram[0])
+                    || stopwords.contains(&ngram[n - 1])
+                {
+                    continue;
+                }
+                *counts.entry(ngram.join(" ")).or_default() += 1;
+            }
+        }
+    }
+
+    counts
+}
+
+fn is_term_word(word: &str) -> bool {
+    word.chars().count() >= 2 && word.chars().any(char::is_alphabetic)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DetectedLanguage, Metadata};
+
+    fn result_with(content: &str, language: Option<&str>) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: language.map(|language| {
+                vec![DetectedLanguage {
+                    language: language.to_string(),
+                    confidence: 1.0,
+                    script: "Latin".to_string(),
+                }]
+            }),
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_shared_terms_rank_below_distinguishing_terms() {
+        let texts = [
+            "Kreuzberg extracts text. Kreuzberg parses PDF documents and PDF forms.",
+            "Kreuzberg extracts text. Kreuzberg reads spreadsheets and spreadsheets charts.",
+            "Kreuzberg extracts text. Kreuzberg transcribes email archives.",
+        ];
+        let config = TfIdfConfig::default().with_ngram_range(1, 1);
+
+        let terms = extract_tfidf_terms_from_texts(&texts, &config);
+
+        assert_eq!(terms.len(), 3);
+        assert_eq!(terms[0][0].term, "pdf");
+        assert_eq!(terms[0][0].term_frequency, 2);
+        assert_eq!(terms[1][0].term, "spreadsheets");
+        let shared = terms[2].iter().find(|t| t.term == "extracts").unwrap();
+        assert_eq!(shared.document_frequency, 3);
+        assert!(terms[2][0].score > shared.score);
+    }
+
+    #[test]
+    fn test_ngrams_skip_stopword_edges_and_punctuation() {
+        let counts = count_terms(
+            "The history of the city. City, history-of-the-city museums!",
+            (1, 3),
+            get_stopwords_with_fallback("en", "en").unwrap(),
+        );
+
+        assert!(counts.contains_key("history"));
+        assert_eq!(counts.get("city"), Some(&2));
+        assert!(counts.contains_key("history-of-the-city museums"));
+        assert!(!counts.contains_key("the history"));
+        assert!(!counts.contains_key("city city"));
+        assert!(!counts.keys().any(|term| term.ends_with(" of")));
+    }
+
+    #[test]
+    fn test_document_frequency_bounds() {
+        let texts = ["alpha beta gamma", "alpha beta delta", "alpha epsilon zeta"];
+        let config = TfIdfConfig::default()
+            .with_ngram_range(1, 1)
+            .with_document_frequency(2, 0.7);
+
+        let terms = extract_tfidf_terms_from_texts(&texts, &config);
+
+        let kept: Vec<&str> = terms[0].iter().map(|t| t.term.as_str()).collect();
+        assert_eq!(kept, vec!["beta"]);
+        assert!(terms[2].is_empty());
+    }
+
+    #[test]
+    fn test_results_use_detected_language() {
+        let results = [
+            result_with("Der Vertrag und die Rechnung wurden geprüft.", Some("deu")),
+            result_with("Der Bericht und die Rechnung wurden versendet.", Some("deu")),
+        ];
+
+        let terms = extract_tfidf_terms(&results, &TfIdfConfig::default().with_ngram_range(1, 1));
+
+        assert!(
+            terms
+                .iter()
+                .flatten()
+                .all(|t| !["der", "und", "die"].contains(&t.term.as_str()))
+        );
+        assert_eq!(terms[0][0].term, "geprüft");
+    }
+
+    #[test]
+    fn test_scores_have_unit_length() {
+        let mut corpus = TfIdfCorpus::new(TfIdfConfig::default().with_max_terms(usize::MAX));
+        corpus.add_text("Rust ownership rules prevent data races in concurrent code.", None);
+        corpus.add_text("Garbage collection pauses affect latency in managed runtimes.", None);
+
+        assert_eq!(corpus.len(), 2);
+        let norm: f32 = corpus.terms_for(0).iter().map(|t| t.score * t.score).sum();
+        assert!((norm - 1.0).abs() < 1e-4);
+        assert!(corpus.terms_for(5).is_empty());
+    }
+}
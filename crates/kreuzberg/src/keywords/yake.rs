@@ -3,6 +3,7 @@
 use super::config::KeywordConfig;
 use super::types::{Keyword, KeywordAlgorithm};
 use crate::Result;
+use crate::stopwords::{apply_stopwords_config, get_stopwords_with_fallback};
 use yake_rust::{Config as YakeConfig, StopWords, get_n_best};
 
 /// Extract keywords using YAKE algorithm.
@@ -34,7 +35,12 @@ pub fn extract_keywords_yake(text: &str, config: &KeywordConfig) -> Result<Vec<K
         ..YakeConfig::default()
     };
 
-    let stopwords = if let Some(ref lang) = config.language {
+    let stopwords = if let Some(ref custom) = config.stopwords {
+        let lang = config.language.as_deref().unwrap_or("en");
+        let mut words = get_stopwords_with_fallback(lang, "en").cloned().unwrap_or_default();
+        apply_stopwords_config(&mut words, custom);
+        StopWords::custom(words.into_iter().collect())
+    } else if let Some(ref lang) = config.language {
         StopWords::predefined(lang).unwrap_or_else(|| {
             tracing::debug!(
                 "WARNING: Stopwords not available for language '{}', using default English stopwords",
@@ -4,17 +4,20 @@
 
 use crate::Result;
 use crate::core::config::LanguageDetectionConfig;
+use crate::types::DetectedLanguage;
 use once_cell::sync::Lazy;
+use std::collections::HashMap;
 use std::sync::Arc;
-use whatlang::{Lang, detect};
+use whatlang::{Lang, Script, detect};
 
 pub mod processor;
 pub use processor::LanguageDetector;
 
 /// Detect languages in text using whatlang.
 ///
-/// Returns a list of detected language codes (ISO 639-3 format).
-/// Returns `None` if no languages could be detected with sufficient confidence.
+/// Returns the detected languages with their ISO 639-3 code, confidence and script,
+/// most prominent first. Returns `None` if no languages could be detected with
+/// sufficient confidence.
 ///
 /// # Arguments
 ///
@@ -32,15 +35,19 @@ pub use processor::LanguageDetector;
 ///     enabled: true,
 ///     min_confidence: 0.8,
 ///     detect_multiple: false,
+///     max_sample_kb: None,
 /// };
 /// let languages = detect_languages(text, &config).expect("language detection succeeded");
-/// println!("Detected languages: {:?}", languages);
+/// if let Some(languages) = languages {
+///     println!("{} ({:.2}, {})", languages[0].language, languages[0].confidence, languages[0].script);
+/// }
 /// ```
-pub fn detect_languages(text: &str, config: &LanguageDetectionConfig) -> Result<Option<Vec<String>>> {
+pub fn detect_languages(text: &str, config: &LanguageDetectionConfig) -> Result<Option<Vec<DetectedLanguage>>> {
     if !config.enabled {
         return Ok(None);
     }
 
+    let text = sample(text, config.max_sample_kb);
     if text.trim().is_empty() {
         return Ok(None);
     }
@@ -52,26 +59,86 @@ pub fn detect_languages(text: &str, config: &LanguageDetectionConfig) -> Result<
     detect_multiple_languages(text, config)
 }
 
+/// Detect the language of each paragraph of `text`.
+///
+/// Paragraphs are separated by blank lines and returned in document order with their
+/// ISO 639-3 code. A paragraph whose language can't be detected confidently (headings,
+/// tables of numbers) inherits the language of the previous paragraph, or `fallback`
+/// at the start of the text.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::language_detection::detect_paragraph_languages;
+/// use kreuzberg::core::config::LanguageDetectionConfig;
+///
+/// let text = "The quick brown fox jumps over the lazy dog near the river bank.\n\n\
+///             Der schnelle braune Fuchs springt über den faulen Hund am Flussufer.";
+/// let config = LanguageDetectionConfig {
+///     enabled: true,
+///     min_confidence: 0.8,
+///     detect_multiple: true,
+///     max_sample_kb: None,
+/// };
+/// let paragraphs = detect_paragraph_languages(text, &config, "eng");
+/// assert_eq!(paragraphs.len(), 2);
+/// assert_eq!(paragraphs[0].1, "eng");
+/// ```
+pub fn detect_paragraph_languages<'a>(
+    text: &'a str,
+    config: &LanguageDetectionConfig,
+    fallback: &str,
+) -> Vec<(&'a str, String)> {
+    let threshold = config.min_confidence.min(0.35);
+    let mut current = fallback.to_string();
+
+    text.split("\n\n")
+        .map(str::trim)
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| {
+            if let Some(info) = detect(paragraph)
+                && info.confidence() >= threshold
+            {
+                current = lang_to_iso639_3(info.lang());
+            }
+            (paragraph, current.clone())
+        })
+        .collect()
+}
+
+/// Limit `text` to its first `max_kb` KiB, cut back to a character boundary.
+fn sample(text: &str, max_kb: Option<usize>) -> &str {
+    let Some(max_bytes) = max_kb.map(|kb| kb.saturating_mul(1024)) else {
+        return text;
+    };
+    if text.len() <= max_bytes {
+        return text;
+    }
+
+    let mut end = max_bytes;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
 /// Detect a single primary language in the text.
-fn detect_single_language(text: &str, config: &LanguageDetectionConfig) -> Result<Option<Vec<String>>> {
+fn detect_single_language(text: &str, config: &LanguageDetectionConfig) -> Result<Option<Vec<DetectedLanguage>>> {
     match detect(text) {
-        Some(info) => {
-            if info.confidence() >= config.min_confidence {
-                let lang_code = lang_to_iso639_3(info.lang());
-                Ok(Some(vec![lang_code]))
-            } else {
-                Ok(None)
-            }
+        Some(info) if info.confidence() >= config.min_confidence => {
+            let language = detected_language(info.lang(), info.confidence(), info.script());
+            Ok(Some(vec![language]))
         }
-        None => Ok(None),
+        _ => Ok(None),
     }
 }
 
 /// Detect multiple languages in the text by analyzing chunks.
 ///
 /// This splits the text into chunks and detects the language of each chunk,
-/// then returns the most common languages found.
-fn detect_multiple_languages(text: &str, config: &LanguageDetectionConfig) -> Result<Option<Vec<String>>> {
+/// then returns the most common languages found. Each language's confidence is
+/// the mean over the chunks attributed to it.
+fn detect_multiple_languages(text: &str, config: &LanguageDetectionConfig) -> Result<Option<Vec<DetectedLanguage>>> {
     const CHUNK_SIZE: usize = 200;
     let char_vec: Vec<char> = text.chars().collect();
     let chunk_strings: Vec<String> = char_vec
@@ -83,27 +150,38 @@ fn detect_multiple_languages(text: &str, config: &LanguageDetectionConfig) -> Re
         return Ok(None);
     }
 
-    let mut lang_counts = std::collections::HashMap::new();
+    let mut lang_stats: HashMap<Lang, (usize, f64, Script)> = HashMap::new();
     let threshold = config.min_confidence.min(0.35);
 
     for chunk in &chunk_strings {
         if let Some(info) = detect(chunk)
             && info.confidence() >= threshold
         {
-            *lang_counts.entry(info.lang()).or_insert(0) += 1;
+            let entry = lang_stats.entry(info.lang()).or_insert((0, 0.0, info.script()));
+            entry.0 += 1;
+            entry.1 += info.confidence();
         }
     }
 
-    if lang_counts.is_empty() {
+    if lang_stats.is_empty() {
         return detect_single_language(text, config);
     }
 
-    let mut lang_vec: Vec<(Lang, usize)> = lang_counts.into_iter().collect();
-    lang_vec.sort_by(|a, b| b.1.cmp(&a.1));
+    let mut languages: Vec<(usize, DetectedLanguage)> = lang_stats
+        .into_iter()
+        .map(|(lang, (count, sum, script))| (count, detected_language(lang, sum / count as f64, script)))
+        .collect();
+    languages.sort_by(|a, b| b.0.cmp(&a.0).then(b.1.confidence.total_cmp(&a.1.confidence)));
 
-    let languages: Vec<String> = lang_vec.iter().map(|(lang, _)| lang_to_iso639_3(*lang)).collect();
+    Ok(Some(languages.into_iter().map(|(_, language)| language).collect()))
+}
 
-    Ok(Some(languages))
+fn detected_language(lang: Lang, confidence: f64, script: Script) -> DetectedLanguage {
+    DetectedLanguage {
+        language: lang_to_iso639_3(lang),
+        confidence,
+        script: script.name().to_string(),
+    }
 }
 
 /// Convert whatlang Lang enum to ISO 639-3 language code.
@@ -196,13 +274,14 @@ mod tests {
             enabled: true,
             min_confidence: 0.8,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
         assert_eq!(langs.len(), 1);
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -212,13 +291,14 @@ mod tests {
             enabled: true,
             min_confidence: 0.8,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
         assert_eq!(langs.len(), 1);
-        assert_eq!(langs[0], "spa");
+        assert_eq!(langs[0].language, "spa");
     }
 
     #[test]
@@ -230,6 +310,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.3,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
@@ -248,6 +329,7 @@ mod tests {
             enabled: false,
             min_confidence: 0.8,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
@@ -261,6 +343,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.8,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
@@ -276,6 +359,69 @@ mod tests {
         assert_eq!(lang_to_iso639_3(Lang::Cmn), "cmn");
     }
 
+    #[test]
+    fn test_detected_language_carries_confidence_and_script() {
+        let config = LanguageDetectionConfig {
+            enabled: true,
+            min_confidence: 0.5,
+            detect_multiple: false,
+            max_sample_kb: None,
+        };
+
+        let langs = detect_languages("Привет, как дела? Это русский текст для проверки.", &config)
+            .unwrap()
+            .unwrap();
+        assert_eq!(langs[0].language, "rus");
+        assert_eq!(langs[0].script, "Cyrillic");
+        assert!(langs[0].confidence >= 0.5 && langs[0].confidence <= 1.0);
+    }
+
+    #[test]
+    fn test_detect_paragraph_languages() {
+        let config = LanguageDetectionConfig {
+            enabled: true,
+            min_confidence: 0.8,
+            detect_multiple: true,
+            max_sample_kb: None,
+        };
+        let text = "The quick brown fox jumps over the lazy dog near the river bank.\n\n\
+                    12.5 | 17.0\n\n\
+                    El rápido zorro marrón salta sobre el perro perezoso cerca del río.\n\n";
+
+        let paragraphs = detect_paragraph_languages(text, &config, "deu");
+
+        assert_eq!(paragraphs.len(), 3);
+        assert_eq!(paragraphs[0].1, "eng");
+        assert_eq!(paragraphs[1], ("12.5 | 17.0", "eng".to_string()));
+        assert_eq!(paragraphs[2].1, "spa");
+    }
+
+    #[test]
+    fn test_sample_respects_char_boundaries() {
+        let text = "ä".repeat(1024);
+        let sampled = sample(&text, Some(1));
+        assert_eq!(sampled.len(), 1024);
+        assert_eq!(sample(&format!("x{text}"), Some(1)).len(), 1023);
+        assert_eq!(sample(&text, None).len(), text.len());
+        assert_eq!(sample("short", Some(1)), "short");
+    }
+
+    #[test]
+    fn test_max_sample_kb_limits_detection_to_prefix() {
+        let text = "The quick brown fox jumps over the lazy dog near the river bank. ".repeat(20)
+            + &"Der schnelle braune Fuchs springt über den faulen Hund am Flussufer. ".repeat(200);
+        let config = LanguageDetectionConfig {
+            enabled: true,
+            min_confidence: 0.3,
+            detect_multiple: true,
+            max_sample_kb: Some(1),
+        };
+
+        let langs = detect_languages(&text, &config).unwrap().unwrap();
+        assert_eq!(langs[0].language, "eng");
+        assert!(!langs.iter().any(|l| l.language == "deu"));
+    }
+
     #[test]
     fn test_confidence_threshold_filters_low_confidence() {
         let text = "ok yes no";
@@ -283,6 +429,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.99,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &high_confidence_config).unwrap();
@@ -296,13 +443,14 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &low_confidence_config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
         assert_eq!(langs.len(), 1);
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -313,13 +461,14 @@ mod tests {
             enabled: true,
             min_confidence: 0.01,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &very_low_threshold).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
         assert_eq!(langs.len(), 1);
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -329,6 +478,7 @@ mod tests {
             enabled: true,
             min_confidence: 1.0,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &max_threshold).unwrap();
@@ -348,6 +498,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &high_confidence_config).unwrap();
@@ -356,9 +507,9 @@ mod tests {
                 !langs.is_empty(),
                 "If detection succeeds, should find at least one language"
             );
-            let has_expected = langs.contains(&"eng".to_string())
-                || langs.contains(&"spa".to_string())
-                || langs.contains(&"fra".to_string());
+            let has_expected = langs.iter().any(|l| l.language == "eng")
+                || langs.iter().any(|l| l.language == "spa")
+                || langs.iter().any(|l| l.language == "fra");
             assert!(has_expected, "Should detect at least one of the languages in the text");
         }
     }
@@ -370,6 +521,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.95,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &high_confidence_config).unwrap();
@@ -385,12 +537,13 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         if let Some(langs) = result {
             assert_eq!(langs.len(), 1, "Single language mode should return one language");
-            assert_eq!(langs[0], "eng", "Should detect English");
+            assert_eq!(langs[0].language, "eng", "Should detect English");
         }
     }
 
@@ -405,13 +558,14 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
         assert!(!langs.is_empty());
-        assert!(langs.contains(&"eng".to_string()) || langs.contains(&"spa".to_string()));
+        assert!(langs.iter().any(|l| l.language == "eng") || langs.iter().any(|l| l.language == "spa"));
     }
 
     #[test]
@@ -425,13 +579,14 @@ mod tests {
             enabled: true,
             min_confidence: 0.4,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
         assert!(!langs.is_empty());
-        assert!(langs.contains(&"cmn".to_string()) || langs.contains(&"eng".to_string()));
+        assert!(langs.iter().any(|l| l.language == "cmn") || langs.iter().any(|l| l.language == "eng"));
     }
 
     #[test]
@@ -445,6 +600,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
@@ -464,6 +620,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
@@ -479,6 +636,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
@@ -494,6 +652,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
@@ -509,6 +668,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
@@ -524,6 +684,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.4,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
@@ -539,6 +700,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.4,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
@@ -554,6 +716,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
@@ -569,13 +732,14 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
         assert_eq!(langs.len(), 1);
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -588,13 +752,14 @@ mod tests {
             enabled: true,
             min_confidence: 0.7,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
         assert_eq!(langs.len(), 1);
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -604,6 +769,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
@@ -617,6 +783,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
@@ -630,6 +797,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
@@ -643,12 +811,13 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -658,12 +827,13 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -673,12 +843,13 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -699,6 +870,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.4,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
@@ -724,6 +896,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
@@ -748,12 +921,13 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -765,12 +939,13 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -782,12 +957,13 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -799,12 +975,13 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[test]
@@ -818,6 +995,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
@@ -837,6 +1015,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.4,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
@@ -856,6 +1035,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.4,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
@@ -872,6 +1052,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.3,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         for (word, _expected_lang) in words {
@@ -889,6 +1070,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&text, &config).unwrap();
@@ -904,6 +1086,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result1 = detect_languages(text, &config).unwrap();
@@ -919,6 +1102,7 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: true,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(&chunk_text, &config).unwrap();
@@ -937,12 +1121,13 @@ mod tests {
             enabled: true,
             min_confidence: 0.5,
             detect_multiple: false,
+            max_sample_kb: None,
         };
 
         let result = detect_languages(text, &config).unwrap();
         assert!(result.is_some());
         let langs = result.unwrap();
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 }
 
@@ -97,6 +97,7 @@ mod tests {
                 enabled: true,
                 min_confidence: 0.8,
                 detect_multiple: false,
+                max_sample_kb: None,
             }),
             ..Default::default()
         };
@@ -110,6 +111,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -117,7 +123,7 @@ mod tests {
         assert!(result.detected_languages.is_some());
         let langs = result.detected_languages.unwrap();
         assert!(!langs.is_empty());
-        assert_eq!(langs[0], "eng");
+        assert_eq!(langs[0].language, "eng");
     }
 
     #[tokio::test]
@@ -134,6 +140,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -169,6 +180,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config_with_lang = ExtractionConfig {
@@ -176,6 +192,7 @@ mod tests {
                 enabled: true,
                 min_confidence: 0.8,
                 detect_multiple: false,
+                max_sample_kb: None,
             }),
             ..Default::default()
         };
@@ -198,6 +215,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let long_result = ExtractionResult {
@@ -209,6 +231,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
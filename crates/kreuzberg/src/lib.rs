@@ -36,15 +36,18 @@
 
 pub mod cache;
 pub mod core;
+pub mod device;
+pub mod diff;
+pub mod doctor;
 pub mod error;
 pub mod extraction;
 pub mod extractors;
+pub mod merge;
 pub mod panic_context;
 pub mod plugins;
 pub mod text;
 pub mod types;
 
-#[cfg(feature = "quality")]
 pub mod utils;
 
 #[cfg(feature = "api")]
@@ -71,18 +74,49 @@ pub mod stopwords;
 #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
 pub mod keywords;
 
+#[cfg(feature = "entities")]
+pub mod entities;
+
+#[cfg(feature = "templates")]
+pub mod templates;
+
+#[cfg(feature = "sinks")]
+pub mod sinks;
+
 #[cfg(feature = "ocr")]
 pub mod ocr;
 
 #[cfg(feature = "pdf")]
 pub mod pdf;
 
-pub use error::{KreuzbergError, Result};
+pub use doctor::{DependencyStatus, DoctorReport, doctor};
+pub use error::{ErrorCode, ErrorInfo, KreuzbergError, Result};
+pub use merge::{MergeOptions, merge_results};
 pub use types::*;
 
+#[cfg(feature = "tokio-runtime")]
+pub use core::checkpoint::{batch_extract_file_resumable, batch_extract_file_resumable_sync};
+pub use core::dataset::{DatasetFormat, DatasetWriter};
+#[cfg(feature = "tokio-runtime")]
+pub use core::directory::{
+    DirectoryItem, DirectoryManifest, DirectoryOptions, DirectoryStream, FileChange, ManifestEntry, extract_directory,
+};
+pub use core::events::{DocumentSection, ExtractionEvent, ExtractionEvents};
 #[cfg(feature = "tokio-runtime")]
 pub use core::extractor::{batch_extract_bytes, batch_extract_file};
 pub use core::extractor::{extract_bytes, extract_file};
+#[cfg(feature = "tokio-runtime")]
+pub use core::governor::{GovernorLoad, JobPriority, QueuedJobs, configure_max_concurrent_jobs, with_priority};
+pub use core::io;
+#[cfg(feature = "logging")]
+pub use core::logging::init_logging;
+pub use core::logging::{LogConfig, LogFormat};
+pub use core::reload::{ConfigChange, ConfigHandle, ConfigUpdate, PluginLoader};
+pub use core::runtime::{ThreadPoolConfig, configure_thread_pools};
+pub use core::scoped::{Extractor, ExtractorBuilder};
+pub use core::scratch::{TempDirConfig, configure_temp_dir};
+pub use core::serialization::{RESULT_SCHEMA_VERSION, ResultFormat};
+pub use core::session::ExtractionSession;
 
 // Available in WASM (bytes-based)
 pub use core::extractor::{batch_extract_bytes_sync, extract_bytes_sync};
@@ -92,21 +126,41 @@ pub use core::extractor::{batch_extract_bytes_sync, extract_bytes_sync};
 pub use core::extractor::{batch_extract_file_sync, extract_file_sync};
 
 pub use core::config::{
-    ChunkingConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig, ImageExtractionConfig,
-    LanguageDetectionConfig, OcrConfig, PostProcessorConfig, TokenReductionConfig,
+    ChunkingConfig, DedupConfig, Device, DeviceConfig, EmbeddingConfig, EmbeddingModelType, ExtractionConfig,
+    HeaderFooterConfig, ImageExtractionConfig, LanguageDetectionConfig, LimitsConfig, LineRepairConfig,
+    MimeMappingsConfig, MimeSignature, NormalizationForm, OcrConfig, PostProcessorConfig, QualityGateAction,
+    QualityGateConfig, RetryConfig, ReviewConfig, StopwordsConfig, StopwordsMode, StructuredConfig, TaggerConfig,
+    TextMetricsConfig, TextNormalizationConfig, TokenReductionConfig, ValidationConfig, ValidationMode,
 };
 
 #[cfg(feature = "pdf")]
 pub use core::config::PdfConfig;
 
+#[cfg(feature = "archives")]
+pub use core::config::{ArchiveConfig, ArchiveMemberFilter};
+
+#[cfg(feature = "email")]
+pub use core::config::EmailConfig;
+
+#[cfg(any(feature = "office", feature = "excel"))]
+pub use core::config::OfficeConfig;
+
+#[cfg(feature = "office")]
+pub use core::config::{PptxConfig, RevisionsConfig};
+
+#[cfg(feature = "xml")]
+pub use core::config::{XmlConfig, XmlDocumentType, XmlSection};
+
 pub use core::mime::{
-    DOCX_MIME_TYPE, EXCEL_MIME_TYPE, HTML_MIME_TYPE, JSON_MIME_TYPE, MARKDOWN_MIME_TYPE, PDF_MIME_TYPE,
+    DOCX_MIME_TYPE, EXCEL_MIME_TYPE, HTML_MIME_TYPE, JSON_MIME_TYPE, MARKDOWN_MIME_TYPE, MimeInspection, PDF_MIME_TYPE,
     PLAIN_TEXT_MIME_TYPE, POWER_POINT_MIME_TYPE, XML_MIME_TYPE, detect_mime_type, detect_mime_type_from_bytes,
-    detect_or_validate, get_extensions_for_mime, validate_mime_type,
+    detect_or_validate, get_extensions_for_mime, inspect_content, inspect_file, register_mime_mappings,
+    validate_mime_type,
 };
 
 pub use plugins::registry::{
-    get_document_extractor_registry, get_ocr_backend_registry, get_post_processor_registry, get_validator_registry,
+    PluginRegistries, get_document_extractor_registry, get_ocr_backend_registry, get_post_processor_registry,
+    get_validator_registry,
 };
 
 #[cfg(feature = "embeddings")]
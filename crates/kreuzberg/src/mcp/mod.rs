@@ -9,6 +9,7 @@
 //! - **extract_bytes**: Extract content from base64-encoded bytes
 //! - **batch_extract_files**: Extract content from multiple files in parallel
 //! - **detect_mime_type**: Detect MIME type of a file
+//! - **kreuzberg://plugins** resource: Registered plugins with MIME types, priorities and stages
 //!
 //! # Example
 //!
@@ -24,9 +25,12 @@
 
 mod server;
 
-pub use server::{start_mcp_server, start_mcp_server_with_config};
+pub use server::{start_mcp_server, start_mcp_server_with_config, start_mcp_server_with_config_handle};
 
-pub use server::{BatchExtractFilesParams, DetectMimeTypeParams, ExtractBytesParams, ExtractFileParams, KreuzbergMcp};
+pub use server::{
+    BatchExtractFilesParams, DetectMimeTypeParams, ExtractBytesParams, ExtractFileParams, KreuzbergMcp,
+    PLUGINS_RESOURCE_URI,
+};
 
 #[doc(hidden)]
 pub use server::map_kreuzberg_error_to_mcp;
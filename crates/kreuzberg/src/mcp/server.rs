@@ -3,20 +3,32 @@
 //! This module provides the core MCP server that exposes document extraction
 //! as tools for AI assistants via the Model Context Protocol.
 
+use std::sync::Arc;
+
 use base64::prelude::*;
 use rmcp::{
-    ErrorData as McpError, ServerHandler, ServiceExt,
+    ErrorData as McpError, RoleServer, ServerHandler, ServiceExt,
     handler::server::{router::tool::ToolRouter, wrapper::Parameters},
     model::*,
-    schemars, tool, tool_handler, tool_router,
+    schemars,
+    service::{Peer, RequestContext},
+    tool, tool_handler, tool_router,
     transport::stdio,
 };
+use tokio::task::JoinSet;
 
+use crate::core::batch_mode::with_batch_mode;
+use crate::core::extractor::batch_error_result;
+use crate::core::shutdown;
 use crate::{
-    ExtractionConfig, ExtractionResult as KreuzbergResult, KreuzbergError, batch_extract_file, batch_extract_file_sync,
-    cache, detect_mime_type, extract_bytes, extract_bytes_sync, extract_file, extract_file_sync,
+    ConfigHandle, ConfigUpdate, DirectoryOptions, ExtractionConfig, ExtractionResult as KreuzbergResult,
+    KreuzbergError, batch_extract_file, batch_extract_file_sync, cache, detect_mime_type, extract_bytes,
+    extract_bytes_sync, extract_directory, extract_file, extract_file_sync,
 };
 
+/// URI of the MCP resource describing all registered plugins.
+pub const PLUGINS_RESOURCE_URI: &str = "kreuzberg://plugins";
+
 /// Request parameters for file extraction.
 #[derive(Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
 pub struct ExtractFileParams {
@@ -71,6 +83,28 @@ pub struct BatchExtractFilesParams {
     pub r#async: bool,
 }
 
+/// Request parameters for directory extraction.
+#[derive(Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct ExtractDirectoryParams {
+    /// Path to the directory
+    pub path: String,
+    /// Globs a file must match, e.g. "*.pdf" or "docs/**/*.md" (default: every file)
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Globs of files and directories to skip, e.g. "node_modules" or "*.log"
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Descend into subdirectories (default: true)
+    #[serde(default = "default_recursive")]
+    pub recursive: bool,
+    /// Enable OCR for scanned documents
+    #[serde(default)]
+    pub enable_ocr: bool,
+    /// Force OCR even if text extraction succeeds
+    #[serde(default)]
+    pub force_ocr: bool,
+}
+
 /// Request parameters for MIME type detection.
 #[derive(Debug, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
 pub struct DetectMimeTypeParams {
@@ -85,37 +119,79 @@ fn default_use_content() -> bool {
     true
 }
 
+fn default_recursive() -> bool {
+    true
+}
+
+/// Sends MCP progress notifications for a tool call whose client asked for them.
+struct ProgressReporter {
+    peer: Peer<RoleServer>,
+    token: ProgressToken,
+}
+
+impl ProgressReporter {
+    /// Reporter for the progress token of the request, if the client sent one.
+    fn from_context(context: &RequestContext<RoleServer>) -> Option<Self> {
+        let token = context.meta.get_progress_token()?;
+        Some(Self {
+            peer: context.peer.clone(),
+            token,
+        })
+    }
+
+    async fn report(&self, progress: usize, total: Option<usize>, message: String) {
+        let notification = ProgressNotificationParam {
+            progress_token: self.token.clone(),
+            progress: progress as f64,
+            total: total.map(|total| total as f64),
+            message: Some(message),
+        };
+        if let Err(e) = self.peer.notify_progress(notification).await {
+            tracing::debug!("Failed to send progress notification: {}", e);
+        }
+    }
+}
+
 /// Map Kreuzberg errors to MCP error responses with appropriate error codes.
 ///
 /// This function ensures different error types are properly differentiated in MCP responses:
 /// - `Validation` errors → `INVALID_PARAMS` (-32602)
 /// - `UnsupportedFormat` errors → `INVALID_PARAMS` (-32602)
+/// - `LimitExceeded` errors → `INVALID_PARAMS` (-32602)
+/// - `EncryptedDocument` errors → `INVALID_PARAMS` (-32602)
 /// - `Parsing` errors → `PARSE_ERROR` (-32700)
 /// - `Io` errors → `INTERNAL_ERROR` (-32603) with context preserved
 /// - All other errors → `INTERNAL_ERROR` (-32603)
 ///
-/// The error message and source chain are preserved to aid debugging.
+/// The error message and source chain are preserved to aid debugging. The error `data` carries
+/// the [`crate::ErrorInfo`] (code, stage, plugin, retryable flag) for programmatic handling.
 #[doc(hidden)]
 pub fn map_kreuzberg_error_to_mcp(error: KreuzbergError) -> McpError {
+    let data = serde_json::to_value(error.to_info()).ok();
+
     match error {
         KreuzbergError::Validation { message, source } => {
             let mut error_message = format!("Validation error: {}", message);
             if let Some(src) = source {
                 error_message.push_str(&format!(" (caused by: {})", src));
             }
-            McpError::invalid_params(error_message, None)
+            McpError::invalid_params(error_message, data)
         }
 
         KreuzbergError::UnsupportedFormat(mime_type) => {
-            McpError::invalid_params(format!("Unsupported format: {}", mime_type), None)
+            McpError::invalid_params(format!("Unsupported format: {}", mime_type), data)
         }
 
+        error @ KreuzbergError::LimitExceeded { .. } => McpError::invalid_params(error.to_string(), data),
+
+        error @ KreuzbergError::EncryptedDocument(_) => McpError::invalid_params(error.to_string(), data),
+
         KreuzbergError::MissingDependency(dep) => McpError::invalid_params(
             format!(
                 "Missing required dependency: {}. Please install it to use this feature.",
                 dep
             ),
-            None,
+            data,
         ),
 
         KreuzbergError::Parsing { message, source } => {
@@ -123,18 +199,18 @@ pub fn map_kreuzberg_error_to_mcp(error: KreuzbergError) -> McpError {
             if let Some(src) = source {
                 error_message.push_str(&format!(" (caused by: {})", src));
             }
-            McpError::parse_error(error_message, None)
+            McpError::parse_error(error_message, data)
         }
 
         // OSError/RuntimeError must bubble up - system errors need user reports ~keep
-        KreuzbergError::Io(io_err) => McpError::internal_error(format!("System I/O error: {}", io_err), None),
+        KreuzbergError::Io(io_err) => McpError::internal_error(format!("System I/O error: {}", io_err), data),
 
         KreuzbergError::Ocr { message, source } => {
             let mut error_message = format!("OCR processing error: {}", message);
             if let Some(src) = source {
                 error_message.push_str(&format!(" (caused by: {})", src));
             }
-            McpError::internal_error(error_message, None)
+            McpError::internal_error(error_message, data)
         }
 
         KreuzbergError::Cache { message, source } => {
@@ -142,7 +218,7 @@ pub fn map_kreuzberg_error_to_mcp(error: KreuzbergError) -> McpError {
             if let Some(src) = source {
                 error_message.push_str(&format!(" (caused by: {})", src));
             }
-            McpError::internal_error(error_message, None)
+            McpError::internal_error(error_message, data)
         }
 
         KreuzbergError::ImageProcessing { message, source } => {
@@ -150,7 +226,7 @@ pub fn map_kreuzberg_error_to_mcp(error: KreuzbergError) -> McpError {
             if let Some(src) = source {
                 error_message.push_str(&format!(" (caused by: {})", src));
             }
-            McpError::internal_error(error_message, None)
+            McpError::internal_error(error_message, data)
         }
 
         KreuzbergError::Serialization { message, source } => {
@@ -158,19 +234,26 @@ pub fn map_kreuzberg_error_to_mcp(error: KreuzbergError) -> McpError {
             if let Some(src) = source {
                 error_message.push_str(&format!(" (caused by: {})", src));
             }
-            McpError::internal_error(error_message, None)
+            McpError::internal_error(error_message, data)
         }
 
         KreuzbergError::Plugin { message, plugin_name } => {
-            McpError::internal_error(format!("Plugin '{}' error: {}", plugin_name, message), None)
+            McpError::internal_error(format!("Plugin '{}' error: {}", plugin_name, message), data)
         }
 
-        KreuzbergError::LockPoisoned(msg) => McpError::internal_error(format!("Internal lock poisoned: {}", msg), None),
+        KreuzbergError::LockPoisoned(msg) => McpError::internal_error(format!("Internal lock poisoned: {}", msg), data),
 
-        KreuzbergError::Other(msg) => McpError::internal_error(msg, None),
+        KreuzbergError::Other(msg) => McpError::internal_error(msg, data),
     }
 }
 
+/// Serialize the descriptions of the plugins in `registries` as pretty-printed JSON.
+fn plugins_resource_json(registries: &crate::PluginRegistries) -> Result<String, McpError> {
+    let plugins = crate::plugins::describe_registries(registries).map_err(map_kreuzberg_error_to_mcp)?;
+    serde_json::to_string_pretty(&plugins)
+        .map_err(|e| McpError::internal_error(format!("Failed to serialize plugin list: {}", e), None))
+}
+
 /// Kreuzberg MCP server.
 ///
 /// Provides document extraction capabilities via MCP tools.
@@ -181,7 +264,7 @@ pub fn map_kreuzberg_error_to_mcp(error: KreuzbergError) -> McpError {
 pub struct KreuzbergMcp {
     tool_router: ToolRouter<KreuzbergMcp>,
     /// Default extraction configuration loaded from config file via discovery
-    default_config: std::sync::Arc<ExtractionConfig>,
+    default_config: ConfigHandle,
 }
 
 #[tool_router]
@@ -215,9 +298,20 @@ impl KreuzbergMcp {
     ///
     /// * `config` - Default extraction configuration for all tool calls
     pub fn with_config(config: ExtractionConfig) -> Self {
+        Self::with_config_handle(ConfigHandle::new(config))
+    }
+
+    /// Create a new Kreuzberg MCP server instance with a reloadable config.
+    ///
+    /// Configuration changes made through the handle apply to all subsequent tool calls.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Handle to the default extraction configuration
+    pub fn with_config_handle(config: ConfigHandle) -> Self {
         Self {
             tool_router: Self::tool_router(),
-            default_config: std::sync::Arc::new(config),
+            default_config: config,
         }
     }
 
@@ -232,7 +326,8 @@ impl KreuzbergMcp {
         &self,
         Parameters(params): Parameters<ExtractFileParams>,
     ) -> Result<CallToolResult, McpError> {
-        let config = build_config(&self.default_config, params.enable_ocr, params.force_ocr);
+        reject_while_draining()?;
+        let config = build_config(&self.default_config.current(), params.enable_ocr, params.force_ocr);
 
         let result = if params.r#async {
             extract_file(&params.path, params.mime_type.as_deref(), &config)
@@ -256,11 +351,12 @@ impl KreuzbergMcp {
         &self,
         Parameters(params): Parameters<ExtractBytesParams>,
     ) -> Result<CallToolResult, McpError> {
+        reject_while_draining()?;
         let bytes = BASE64_STANDARD
             .decode(&params.data)
             .map_err(|e| McpError::invalid_params(format!("Invalid base64: {}", e), None))?;
 
-        let config = build_config(&self.default_config, params.enable_ocr, params.force_ocr);
+        let config = build_config(&self.default_config.current(), params.enable_ocr, params.force_ocr);
 
         let mime_type = params.mime_type.as_deref().unwrap_or("");
 
@@ -279,29 +375,34 @@ impl KreuzbergMcp {
     /// Extract content from multiple files in parallel.
     ///
     /// This tool efficiently processes multiple documents simultaneously, useful for batch operations.
-    #[tool(description = "Extract content from multiple files in parallel. Returns results for all files.")]
+    /// Clients that send a progress token receive a progress notification per finished file.
+    #[tool(
+        description = "Extract content from multiple files in parallel. Returns results for all files, reporting progress per file."
+    )]
     async fn batch_extract_files(
         &self,
         Parameters(params): Parameters<BatchExtractFilesParams>,
+        context: RequestContext<RoleServer>,
     ) -> Result<CallToolResult, McpError> {
-        let config = build_config(&self.default_config, params.enable_ocr, params.force_ocr);
-
-        let results = if params.r#async {
-            batch_extract_file(params.paths.clone(), &config)
-                .await
-                .map_err(map_kreuzberg_error_to_mcp)?
-        } else {
-            batch_extract_file_sync(params.paths.clone(), &config).map_err(map_kreuzberg_error_to_mcp)?
-        };
-
-        let mut response = String::new();
-        for (i, result) in results.iter().enumerate() {
-            response.push_str(&format!("=== Document {}: {} ===\n", i + 1, params.paths[i]));
-            response.push_str(&format_extraction_result(result));
-            response.push_str("\n\n");
-        }
+        reject_while_draining()?;
+        let progress = ProgressReporter::from_context(&context);
+        self.run_batch_extract_files(params, progress).await
+    }
 
-        Ok(CallToolResult::success(vec![Content::text(response)]))
+    /// Extract every matching file of a directory.
+    ///
+    /// Lets assistants ingest a project folder in one call instead of one call per file.
+    #[tool(
+        description = "Extract all files of a directory, filtered with optional include/exclude globs such as \"*.pdf\"."
+    )]
+    async fn extract_directory(
+        &self,
+        Parameters(params): Parameters<ExtractDirectoryParams>,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        reject_while_draining()?;
+        let progress = ProgressReporter::from_context(&context);
+        self.run_extract_directory(params, progress).await
     }
 
     /// Detect the MIME type of a file.
@@ -372,6 +473,94 @@ impl KreuzbergMcp {
 
         Ok(CallToolResult::success(vec![Content::text(response)]))
     }
+
+    /// Change selected fields of the server's configuration.
+    ///
+    /// This tool updates the OCR language, caching or batch concurrency used by all subsequent
+    /// tool calls without restarting the server. Returns the changed fields as JSON.
+    #[tool(
+        description = "Change the server's default configuration at runtime. Accepts ocr_language, use_cache and max_concurrent_extractions. Returns the changed fields with their previous and new values."
+    )]
+    fn update_config(&self, Parameters(update): Parameters<ConfigUpdate>) -> Result<CallToolResult, McpError> {
+        let changes = self
+            .default_config
+            .update(&update)
+            .map_err(map_kreuzberg_error_to_mcp)?;
+        let response = serde_json::to_string_pretty(&changes)
+            .map_err(|e| McpError::internal_error(format!("Failed to serialize config changes: {}", e), None))?;
+
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+}
+
+impl KreuzbergMcp {
+    async fn run_batch_extract_files(
+        &self,
+        params: BatchExtractFilesParams,
+        progress: Option<ProgressReporter>,
+    ) -> Result<CallToolResult, McpError> {
+        let config = build_config(&self.default_config.current(), params.enable_ocr, params.force_ocr);
+
+        let results = match &progress {
+            Some(progress) => batch_extract_with_progress(&params.paths, &config, progress).await,
+            None if params.r#async => batch_extract_file(params.paths.clone(), &config).await,
+            None => batch_extract_file_sync(params.paths.clone(), &config),
+        }
+        .map_err(map_kreuzberg_error_to_mcp)?;
+
+        let mut response = String::new();
+        for (i, result) in results.iter().enumerate() {
+            response.push_str(&format!("=== Document {}: {} ===\n", i + 1, params.paths[i]));
+            response.push_str(&format_extraction_result(result));
+            response.push_str("\n\n");
+        }
+
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
+
+    async fn run_extract_directory(
+        &self,
+        params: ExtractDirectoryParams,
+        progress: Option<ProgressReporter>,
+    ) -> Result<CallToolResult, McpError> {
+        let options = DirectoryOptions {
+            config: build_config(&self.default_config.current(), params.enable_ocr, params.force_ocr),
+            include: params.include,
+            exclude: params.exclude,
+            recursive: params.recursive,
+            ..Default::default()
+        };
+        let mut stream = extract_directory(&params.path, options).map_err(map_kreuzberg_error_to_mcp)?;
+
+        let mut items = Vec::new();
+        while let Some(item) = stream.recv().await {
+            let item = item.map_err(map_kreuzberg_error_to_mcp)?;
+            if let Some(progress) = &progress {
+                let message = format!("Extracted {}", item.relative_path);
+                progress.report(items.len() + 1, None, message).await;
+            }
+            items.push(item);
+        }
+        items.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
+
+        let failed = items.iter().filter(|item| item.result.is_err()).count();
+        let mut response = format!("Extracted {} files from {}", items.len(), params.path);
+        if failed > 0 {
+            response.push_str(&format!(" ({} failed)", failed));
+        }
+        response.push_str("\n\n");
+
+        for (i, item) in items.iter().enumerate() {
+            response.push_str(&format!("=== Document {}: {} ===\n", i + 1, item.relative_path));
+            match &item.result {
+                Ok(result) => response.push_str(&format_extraction_result(result)),
+                Err(e) => response.push_str(&format!("Error: {}\n", e)),
+            }
+            response.push_str("\n\n");
+        }
+
+        Ok(CallToolResult::success(vec![Content::text(response)]))
+    }
 }
 
 #[tool_handler]
@@ -381,6 +570,7 @@ impl ServerHandler for KreuzbergMcp {
             protocol_version: ProtocolVersion::default(),
             capabilities: ServerCapabilities {
                 tools: Some(ToolsCapability::default()),
+                resources: Some(ResourcesCapability::default()),
                 ..Default::default()
             },
             server_info: Implementation {
@@ -399,6 +589,37 @@ impl ServerHandler for KreuzbergMcp {
             ),
         }
     }
+
+    async fn list_resources(
+        &self,
+        _request: Option<PaginatedRequestParam>,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        let mut plugins = RawResource::new(PLUGINS_RESOURCE_URI, "plugins");
+        plugins.description = Some("Registered plugins with their MIME types, priorities and stages".to_string());
+        plugins.mime_type = Some("application/json".to_string());
+
+        Ok(ListResourcesResult::with_all_items(vec![plugins.no_annotation()]))
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        match request.uri.as_str() {
+            PLUGINS_RESOURCE_URI => Ok(ReadResourceResult {
+                contents: vec![ResourceContents::text(
+                    plugins_resource_json(&self.default_config.registries())?,
+                    request.uri,
+                )],
+            }),
+            _ => Err(McpError::resource_not_found(
+                format!("Unknown resource: {}", request.uri),
+                None,
+            )),
+        }
+    }
 }
 
 impl Default for KreuzbergMcp {
@@ -416,7 +637,7 @@ impl Default for KreuzbergMcp {
 /// Start the Kreuzberg MCP server.
 ///
 /// This function initializes and runs the MCP server using stdio transport.
-/// It will block until the server is shut down.
+/// It will block until the client disconnects or the server drains on SIGTERM or Ctrl+C.
 ///
 /// # Errors
 ///
@@ -434,10 +655,7 @@ impl Default for KreuzbergMcp {
 /// }
 /// ```
 pub async fn start_mcp_server() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let service = KreuzbergMcp::new()?.serve(stdio()).await?;
-
-    service.waiting().await?;
-    Ok(())
+    serve_stdio(KreuzbergMcp::new()?).await
 }
 
 /// Start MCP server with custom extraction config.
@@ -447,9 +665,49 @@ pub async fn start_mcp_server() -> Result<(), Box<dyn std::error::Error + Send +
 pub async fn start_mcp_server_with_config(
     config: ExtractionConfig,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let service = KreuzbergMcp::with_config(config).serve(stdio()).await?;
+    serve_stdio(KreuzbergMcp::with_config(config)).await
+}
+
+/// Start MCP server with a reloadable extraction config.
+///
+/// Reloading the handle (e.g. via [`ConfigHandle::watch`]) applies to all subsequent tool calls
+/// without restarting the server.
+pub async fn start_mcp_server_with_config_handle(
+    config: ConfigHandle,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    serve_stdio(KreuzbergMcp::with_config_handle(config)).await
+}
+
+/// Serve `server` over stdio until the client disconnects or the process shuts down.
+///
+/// On SIGTERM or Ctrl+C, new tool calls are rejected while in-flight extractions get up to the
+/// drain timeout to finish (see [`crate::core::shutdown`]).
+async fn serve_stdio(server: KreuzbergMcp) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let service = server.serve(stdio()).await?;
+    let drain_timeout = shutdown::drain_timeout();
+
+    let remaining = tokio::select! {
+        quit = service.waiting() => {
+            quit?;
+            return Ok(());
+        }
+        remaining = async {
+            shutdown::shutdown_signal().await;
+            shutdown::drain(drain_timeout).await
+        } => remaining,
+    };
+    shutdown::finish(&remaining).await;
+    Ok(())
+}
 
-    service.waiting().await?;
+/// Reject new extractions once the server is draining for shutdown.
+fn reject_while_draining() -> Result<(), McpError> {
+    if shutdown::is_draining() {
+        return Err(McpError::invalid_request(
+            "Server is shutting down and not accepting new requests",
+            None,
+        ));
+    }
     Ok(())
 }
 
@@ -473,6 +731,41 @@ fn build_config(default_config: &ExtractionConfig, enable_ocr: bool, force_ocr:
     config
 }
 
+/// Extract files concurrently like [`batch_extract_file`], reporting progress as each file finishes.
+async fn batch_extract_with_progress(
+    paths: &[String],
+    config: &ExtractionConfig,
+    progress: &ProgressReporter,
+) -> crate::Result<Vec<KreuzbergResult>> {
+    let config = Arc::new(config.clone());
+    let mut tasks = JoinSet::new();
+    for (index, path) in paths.iter().cloned().enumerate() {
+        let config = Arc::clone(&config);
+        tasks.spawn(async move {
+            let result = with_batch_mode(extract_file(&path, None, &config)).await;
+            (index, result)
+        });
+    }
+
+    let mut results = vec![None; paths.len()];
+    let mut completed = 0;
+    while let Some(joined) = tasks.join_next().await {
+        let (index, result) = joined.map_err(|e| KreuzbergError::Other(format!("Task panicked: {}", e)))?;
+        results[index] = Some(match result {
+            Ok(result) => result,
+            // System errors fail the whole batch, like in `batch_extract_file`
+            Err(e @ KreuzbergError::Io(_)) => return Err(e),
+            Err(e) => batch_error_result(&e),
+        });
+
+        completed += 1;
+        let message = format!("Extracted {}", paths[index]);
+        progress.report(completed, Some(paths.len()), message).await;
+    }
+
+    Ok(results.into_iter().flatten().collect())
+}
+
 /// Format extraction result as human-readable text.
 fn format_extraction_result(result: &KreuzbergResult) -> String {
     let mut response = String::new();
@@ -524,12 +817,14 @@ mod tests {
         assert!(router.has_route("extract_file"));
         assert!(router.has_route("extract_bytes"));
         assert!(router.has_route("batch_extract_files"));
+        assert!(router.has_route("extract_directory"));
         assert!(router.has_route("detect_mime_type"));
         assert!(router.has_route("cache_stats"));
         assert!(router.has_route("cache_clear"));
+        assert!(router.has_route("update_config"));
 
         let tools = router.list_all();
-        assert_eq!(tools.len(), 6);
+        assert_eq!(tools.len(), 8);
     }
 
     #[test]
@@ -540,6 +835,19 @@ mod tests {
         assert_eq!(info.server_info.name, "kreuzberg-mcp");
         assert_eq!(info.server_info.version, env!("CARGO_PKG_VERSION"));
         assert!(info.capabilities.tools.is_some());
+        assert!(info.capabilities.resources.is_some());
+    }
+
+    #[test]
+    fn test_plugins_resource_json() {
+        let json = plugins_resource_json(&crate::PluginRegistries::global()).unwrap();
+        let plugins: Vec<serde_json::Value> = serde_json::from_str(&json).unwrap();
+
+        assert!(
+            plugins
+                .iter()
+                .any(|p| p["kind"] == "document_extractor" && p["name"] == "plain-text-extractor")
+        );
     }
 
     #[test]
@@ -569,8 +877,26 @@ mod tests {
 
         let server = KreuzbergMcp::with_config(custom_config);
 
-        assert!(server.default_config.force_ocr);
-        assert!(!server.default_config.use_cache);
+        assert!(server.default_config.current().force_ocr);
+        assert!(!server.default_config.current().use_cache);
+    }
+
+    #[test]
+    fn test_update_config_tool() {
+        let server = KreuzbergMcp::with_config(ExtractionConfig::default());
+
+        let result = server.update_config(Parameters(ConfigUpdate {
+            use_cache: Some(false),
+            ..Default::default()
+        }));
+        assert!(result.is_ok());
+        assert!(!server.default_config.current().use_cache);
+
+        let result = server.update_config(Parameters(ConfigUpdate {
+            max_concurrent_extractions: Some(0),
+            ..Default::default()
+        }));
+        assert!(result.is_err());
     }
 
     #[test]
@@ -581,6 +907,7 @@ mod tests {
         assert_eq!(mcp_error.code.0, -32602);
         assert!(mcp_error.message.contains("Validation error"));
         assert!(mcp_error.message.contains("invalid file path"));
+        assert_eq!(mcp_error.data.unwrap()["code"], "validation");
     }
 
     #[test]
@@ -1079,7 +1406,7 @@ mod tests {
             r#async: true,
         };
 
-        let result = server.batch_extract_files(Parameters(params)).await;
+        let result = server.run_batch_extract_files(params, None).await;
 
         assert!(result.is_ok());
         let call_result = result.unwrap();
@@ -1109,7 +1436,7 @@ mod tests {
             r#async: true,
         };
 
-        let result = server.batch_extract_files(Parameters(params)).await;
+        let result = server.run_batch_extract_files(params, None).await;
 
         assert!(result.is_ok());
         let call_result = result.unwrap();
@@ -1136,7 +1463,7 @@ mod tests {
             r#async: true,
         };
 
-        let result = server.batch_extract_files(Parameters(params)).await;
+        let result = server.run_batch_extract_files(params, None).await;
 
         assert!(result.is_ok());
         let call_result = result.unwrap();
@@ -1162,11 +1489,61 @@ mod tests {
             r#async: true,
         };
 
-        let result = server.batch_extract_files(Parameters(params)).await;
+        let result = server.run_batch_extract_files(params, None).await;
 
         assert!(result.is_ok() || result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_extract_directory_with_globs() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("nested")).unwrap();
+        std::fs::write(dir.path().join("a.txt"), "first document").unwrap();
+        std::fs::write(dir.path().join("nested").join("b.txt"), "second document").unwrap();
+        std::fs::write(dir.path().join("skipped.log"), "not a document").unwrap();
+
+        let server = KreuzbergMcp::with_config(ExtractionConfig::default());
+        let params = ExtractDirectoryParams {
+            path: dir.path().to_string_lossy().to_string(),
+            include: vec!["*.txt".to_string()],
+            exclude: vec![],
+            recursive: true,
+            enable_ocr: false,
+            force_ocr: false,
+        };
+
+        let call_result = server.run_extract_directory(params, None).await.unwrap();
+
+        match &call_result.content[0].raw {
+            RawContent::Text(text) => {
+                assert!(text.text.starts_with("Extracted 2 files"));
+                assert!(text.text.contains("=== Document 1: a.txt ==="));
+                assert!(text.text.contains("=== Document 2: nested/b.txt ==="));
+                assert!(text.text.contains("second document"));
+                assert!(!text.text.contains("not a document"));
+            }
+            _ => panic!("Expected text content"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_extract_directory_with_missing_directory() {
+        let server = KreuzbergMcp::with_config(ExtractionConfig::default());
+        let params = ExtractDirectoryParams {
+            path: "/nonexistent/directory".to_string(),
+            include: vec![],
+            exclude: vec![],
+            recursive: true,
+            enable_ocr: false,
+            force_ocr: false,
+        };
+
+        let result = server.run_extract_directory(params, None).await;
+
+        let error = result.unwrap_err();
+        assert_eq!(error.code.0, -32602);
+    }
+
     #[tokio::test]
     async fn test_detect_mime_type_with_valid_file() {
         let server = KreuzbergMcp::with_config(ExtractionConfig::default());
@@ -1529,8 +1906,8 @@ mod tests {
 
         let server = KreuzbergMcp::with_config(custom_config.clone());
 
-        assert_eq!(server.default_config.force_ocr, custom_config.force_ocr);
-        assert_eq!(server.default_config.use_cache, custom_config.use_cache);
+        assert_eq!(server.default_config.current().force_ocr, custom_config.force_ocr);
+        assert_eq!(server.default_config.current().use_cache, custom_config.use_cache);
     }
 
     #[test]
@@ -1543,7 +1920,10 @@ mod tests {
         let server1 = KreuzbergMcp::with_config(custom_config);
         let server2 = server1.clone();
 
-        assert_eq!(server1.default_config.force_ocr, server2.default_config.force_ocr);
+        assert_eq!(
+            server1.default_config.current().force_ocr,
+            server2.default_config.current().force_ocr
+        );
     }
 
     #[tokio::test]
@@ -1624,7 +2004,7 @@ mod tests {
                 r#async: true,
             };
 
-            let result = server.batch_extract_files(Parameters(params)).await;
+            let result = server.run_batch_extract_files(params, None).await;
 
             if let Ok(call_result) = result
                 && let Some(content) = call_result.content.first()
@@ -1897,7 +2277,7 @@ mod tests {
                 r#async: true,
             };
 
-            let result = server.batch_extract_files(Parameters(params)).await;
+            let result = server.run_batch_extract_files(params, None).await;
 
             assert!(result.is_ok());
             let call_result = result.unwrap();
@@ -0,0 +1,494 @@
+//! Merging of several extraction results into one document.
+//!
+//! [`merge_results`] assembles one logical document from parts that were extracted on their
+//! own, such as a contract scanned as `part1.pdf` and `part2.pdf`. The contents are joined with
+//! a separator naming the next part, pages are numbered through the whole document, and chunks,
+//! tables, images, entities and other positioned data are moved to their place in the merged
+//! content. `metadata.additional["merged_parts"]` records the `source`, `mime_type`, byte range
+//! (`byte_start`, `byte_end`) and page range (`first_page`, `last_page`) of every part.
+//!
+//! The merge is deterministic: the output only depends on the parts and their order.
+//! Document-level metadata (title, dates, format metadata, ...) comes from the first part that
+//! has it, authors and keywords are combined, and per-document statistics, timings and text
+//! metrics are dropped as they don't describe the merged content.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::{ExtractionConfig, MergeOptions, extract_file_sync, merge_results};
+//!
+//! # fn example() -> kreuzberg::Result<()> {
+//! let config = ExtractionConfig::default();
+//! let sources = vec!["part1.pdf".to_string(), "part2.pdf".to_string()];
+//! let parts = sources
+//!     .iter()
+//!     .map(|path| extract_file_sync(path, None, &config))
+//!     .collect::<kreuzberg::Result<Vec<_>>>()?;
+//!
+//! let merged = merge_results(parts, MergeOptions { sources, ..Default::default() })?;
+//! println!("{} pages", merged.metadata.pages.map_or(0, |pages| pages.total_count));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::types::{ExtractionResult, Metadata, PageStructure};
+use crate::{KreuzbergError, Result};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+
+/// Separator used when [`MergeOptions::separator`] is not changed.
+pub const DEFAULT_MERGE_SEPARATOR: &str = "\n\n--- {source} ---\n\n";
+
+/// MIME type of a merged result whose parts have different MIME types.
+const MIXED_MIME_TYPE: &str = "multipart/mixed";
+
+/// Options for [`merge_results`].
+#[derive(Debug, Clone)]
+pub struct MergeOptions {
+    /// Text placed between two parts
+    ///
+    /// `{source}` is replaced with the name of the following part and `{index}` with its
+    /// position (1-indexed).
+    pub separator: String,
+
+    /// Names of the parts, e.g. their file names (empty = `part 1`, `part 2`, ...)
+    pub sources: Vec<String>,
+}
+
+impl Default for MergeOptions {
+    fn default() -> Self {
+        Self {
+            separator: DEFAULT_MERGE_SEPARATOR.to_string(),
+            sources: Vec::new(),
+        }
+    }
+}
+
+/// Where a part starts in the merged result.
+struct Offset {
+    bytes: usize,
+    chars: usize,
+    pages: usize,
+    images: usize,
+}
+
+impl Offset {
+    fn page(&self, page: usize) -> usize {
+        page + self.pages
+    }
+}
+
+/// Merge `results` into one document, in the given order.
+///
+/// # Errors
+///
+/// Returns a validation error when `results` is empty, or when `options.sources` is not empty
+/// and doesn't name every result.
+pub fn merge_results(results: Vec<ExtractionResult>, options: MergeOptions) -> Result<ExtractionResult> {
+    let Some(first) = results.first() else {
+        return Err(KreuzbergError::validation("Cannot merge an empty list of results"));
+    };
+    if !options.sources.is_empty() && options.sources.len() != results.len() {
+        return Err(KreuzbergError::validation(format!(
+            "Got {} sources for {} results to merge",
+            options.sources.len(),
+            results.len()
+        )));
+    }
+
+    let mime_type = if results.iter().all(|result| result.mime_type == first.mime_type) {
+        first.mime_type.clone()
+    } else {
+        MIXED_MIME_TYPE.to_string()
+    };
+    let mut merged = ExtractionResult {
+        content: String::new(),
+        mime_type,
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
+    };
+
+    let mut parts = Vec::with_capacity(results.len());
+    let mut offset = Offset {
+        bytes: 0,
+        chars: 0,
+        pages: 0,
+        images: 0,
+    };
+    for (index, result) in results.into_iter().enumerate() {
+        let source = match options.sources.get(index) {
+            Some(source) => source.clone(),
+            None => format!("part {}", index + 1),
+        };
+        if index > 0 {
+            let separator = options
+                .separator
+                .replace("{source}", &source)
+                .replace("{index}", &(index + 1).to_string());
+            merged.content.push_str(&separator);
+            offset.chars += separator.chars().count();
+        }
+        offset.bytes = merged.content.len();
+        merged.content.push_str(&result.content);
+
+        let page_count = page_count(&result);
+        parts.push(serde_json::json!({
+            "source": source,
+            "mime_type": result.mime_type,
+            "byte_start": offset.bytes,
+            "byte_end": merged.content.len(),
+            "first_page": offset.page(1),
+            "last_page": offset.page(page_count),
+        }));
+
+        let chars = result.content.chars().count();
+        let images = result.images.as_ref().map_or(0, Vec::len);
+        append(&mut merged, result, &offset);
+        offset.chars += chars;
+        offset.pages += page_count;
+        offset.images += images;
+    }
+
+    if let Some(chunks) = &mut merged.chunks {
+        let total_chunks = chunks.len();
+        for (chunk_index, chunk) in chunks.iter_mut().enumerate() {
+            chunk.metadata.chunk_index = chunk_index;
+            chunk.metadata.total_chunks = total_chunks;
+        }
+    }
+    if let Some(structure) = &mut merged.metadata.pages {
+        structure.total_count = offset.pages;
+    }
+    merged
+        .metadata
+        .additional
+        .insert("merged_parts".to_string(), Value::Array(parts));
+
+    Ok(merged)
+}
+
+/// Pages of a part: the page count of its metadata, or the highest page number of its pages,
+/// tables and images. A part without any page information counts as one page.
+fn page_count(result: &ExtractionResult) -> usize {
+    let total_count = result.metadata.pages.as_ref().map_or(0, |pages| pages.total_count);
+    let page_numbers = result
+        .pages
+        .iter()
+        .flatten()
+        .map(|page| page.page_number)
+        .chain(result.tables.iter().map(|table| table.page_number))
+        .chain(result.images.iter().flatten().filter_map(|image| image.page_number));
+    page_numbers.fold(total_count, usize::max).max(1)
+}
+
+/// Append everything but the content of `part` to `merged`, moved by `offset`.
+fn append(merged: &mut ExtractionResult, part: ExtractionResult, offset: &Offset) {
+    let ExtractionResult {
+        content: _,
+        mime_type: _,
+        metadata,
+        mut tables,
+        detected_languages,
+        chunks,
+        images,
+        pages,
+        entities,
+        keywords,
+        children,
+        fields,
+        mut warnings,
+    } = part;
+
+    merge_metadata(&mut merged.metadata, metadata, offset);
+
+    for table in &mut tables {
+        table.page_number = offset.page(table.page_number);
+    }
+    merged.tables.append(&mut tables);
+
+    extend(&mut merged.chunks, chunks, |chunk| {
+        chunk.metadata.byte_start += offset.bytes;
+        chunk.metadata.byte_end += offset.bytes;
+        chunk.metadata.first_page = chunk.metadata.first_page.map(|page| offset.page(page));
+        chunk.metadata.last_page = chunk.metadata.last_page.map(|page| offset.page(page));
+    });
+    extend(&mut merged.images, images, |image| {
+        image.image_index += offset.images;
+        image.page_number = image.page_number.map(|page| offset.page(page));
+    });
+    extend(&mut merged.pages, pages, |page| {
+        page.page_number = offset.page(page.page_number);
+        for table in &mut page.tables {
+            table.page_number = offset.page(table.page_number);
+        }
+        for image in &mut page.images {
+            image.image_index += offset.images;
+            image.page_number = image.page_number.map(|page| offset.page(page));
+        }
+    });
+    extend(&mut merged.entities, entities, |entity| {
+        entity.byte_start += offset.bytes;
+        entity.byte_end += offset.bytes;
+    });
+    extend(&mut merged.keywords, keywords, |keyword| {
+        for position in keyword.positions.iter_mut().flatten() {
+            *position += offset.chars;
+        }
+    });
+    extend(&mut merged.children, children, |_| {});
+
+    for language in detected_languages.into_iter().flatten() {
+        let languages = merged.detected_languages.get_or_insert_with(Vec::new);
+        if !languages.iter().any(|known| known.language == language.language) {
+            languages.push(language);
+        }
+    }
+
+    for (name, value) in fields.into_iter().flatten() {
+        let field = merged
+            .fields
+            .get_or_insert_with(BTreeMap::new)
+            .entry(name)
+            .or_insert(Value::Null);
+        if field.is_null() {
+            *field = value;
+        }
+    }
+
+    for warning in &mut warnings {
+        warning.page = warning.page.map(|page| offset.page(page));
+    }
+    merged.warnings.append(&mut warnings);
+}
+
+/// Combine the metadata of a part into the metadata of the merged result.
+fn merge_metadata(merged: &mut Metadata, metadata: Metadata, offset: &Offset) {
+    let Metadata {
+        title,
+        subject,
+        authors,
+        keywords,
+        language,
+        created_at,
+        modified_at,
+        created_by,
+        modified_by,
+        publisher,
+        identifier,
+        pages,
+        date,
+        format,
+        image_preprocessing,
+        json_schema,
+        error,
+        stats: _,
+        timings: _,
+        provenance,
+        text_metrics: _,
+        tags,
+        review,
+        duplicate_of: _,
+        findings,
+        revisions,
+        additional,
+    } = metadata;
+
+    keep_first(&mut merged.title, title);
+    keep_first(&mut merged.subject, subject);
+    keep_first(&mut merged.language, language);
+    keep_first(&mut merged.created_at, created_at);
+    keep_first(&mut merged.modified_at, modified_at);
+    keep_first(&mut merged.created_by, created_by);
+    keep_first(&mut merged.modified_by, modified_by);
+    keep_first(&mut merged.publisher, publisher);
+    keep_first(&mut merged.identifier, identifier);
+    keep_first(&mut merged.date, date);
+    keep_first(&mut merged.format, format);
+    keep_first(&mut merged.image_preprocessing, image_preprocessing);
+    keep_first(&mut merged.json_schema, json_schema);
+    keep_first(&mut merged.error, error);
+    keep_first(&mut merged.provenance, provenance);
+    keep_first(&mut merged.review, review);
+    append_unique(&mut merged.authors, authors);
+    append_unique(&mut merged.keywords, keywords);
+
+    if let Some(structure) = pages {
+        let merged_structure = merged.pages.get_or_insert(PageStructure {
+            total_count: 0,
+            unit_type: structure.unit_type,
+            boundaries: None,
+            pages: None,
+        });
+        extend(&mut merged_structure.boundaries, structure.boundaries, |boundary| {
+            boundary.byte_start += offset.bytes;
+            boundary.byte_end += offset.bytes;
+            boundary.page_number = offset.page(boundary.page_number);
+        });
+        extend(&mut merged_structure.pages, structure.pages, |page| {
+            page.number = offset.page(page.number);
+        });
+    }
+
+    for (name, mut matches) in tags.into_iter().flatten() {
+        for tag_match in &mut matches {
+            tag_match.byte_start += offset.bytes;
+            tag_match.byte_end += offset.bytes;
+        }
+        let merged_tags = merged.tags.get_or_insert_with(HashMap::new);
+        merged_tags.entry(name).or_default().append(&mut matches);
+    }
+    extend(&mut merged.findings, findings, |finding| {
+        if let Some(location) = &mut finding.location {
+            location.page = location.page.map(|page| offset.page(page));
+            location.byte_start = location.byte_start.map(|byte| byte + offset.bytes);
+            location.byte_end = location.byte_end.map(|byte| byte + offset.bytes);
+        }
+    });
+    extend(&mut merged.revisions, revisions, |revision| {
+        revision.page_number = revision.page_number.map(|page| offset.page(page));
+    });
+
+    for (key, value) in additional {
+        merged.additional.entry(key).or_insert(value);
+    }
+}
+
+fn keep_first<T>(merged: &mut Option<T>, value: Option<T>) {
+    if merged.is_none() {
+        *merged = value;
+    }
+}
+
+fn append_unique(merged: &mut Option<Vec<String>>, values: Option<Vec<String>>) {
+    for value in values.into_iter().flatten() {
+        let merged = merged.get_or_insert_with(Vec::new);
+        if !merged.contains(&value) {
+            merged.push(value);
+        }
+    }
+}
+
+/// Append `items` to `merged` after moving each with `shift`; `merged` stays `None` while no
+/// part has items.
+fn extend<T>(merged: &mut Option<Vec<T>>, items: Option<Vec<T>>, mut shift: impl FnMut(&mut T)) {
+    if let Some(mut items) = items {
+        items.iter_mut().for_each(&mut shift);
+        merged.get_or_insert_with(Vec::new).append(&mut items);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Chunk, ChunkMetadata, PageUnitType, Table};
+
+    fn result(content: &str, pages: usize) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: pages,
+                    unit_type: PageUnitType::Page,
+                    boundaries: None,
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![Table {
+                cells: vec![],
+                markdown: "| a |".to_string(),
+                page_number: pages,
+            }],
+            detected_languages: None,
+            chunks: Some(vec![chunk(content, pages)]),
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn chunk(content: &str, last_page: usize) -> Chunk {
+        Chunk {
+            content: content.to_string(),
+            embedding: None,
+            metadata: ChunkMetadata {
+                byte_start: 0,
+                byte_end: content.len(),
+                token_count: None,
+                chunk_index: 0,
+                total_chunks: 1,
+                first_page: Some(1),
+                last_page: Some(last_page),
+            },
+        }
+    }
+
+    #[test]
+    fn test_merge_results_moves_parts() {
+        let options = MergeOptions {
+            sources: vec!["part1.pdf".to_string(), "part2.pdf".to_string()],
+            ..Default::default()
+        };
+        let merged = merge_results(vec![result("First", 2), result("Second", 3)], options).unwrap();
+
+        assert_eq!(merged.content, "First\n\n--- part2.pdf ---\n\nSecond");
+        assert_eq!(merged.metadata.pages.as_ref().unwrap().total_count, 5);
+        let table_pages: Vec<_> = merged.tables.iter().map(|table| table.page_number).collect();
+        assert_eq!(table_pages, vec![2, 5]);
+
+        let chunks = merged.chunks.unwrap();
+        let second = &chunks[1].metadata;
+        assert_eq!(&merged.content[second.byte_start..second.byte_end], "Second");
+        assert_eq!((second.chunk_index, second.total_chunks), (1, 2));
+        assert_eq!((second.first_page, second.last_page), (Some(3), Some(5)));
+
+        let parts = &merged.metadata.additional["merged_parts"];
+        assert_eq!(parts[1]["source"], "part2.pdf");
+        assert_eq!(parts[1]["byte_start"], 26);
+        assert_eq!(parts[1]["first_page"], 3);
+        assert_eq!(parts[1]["last_page"], 5);
+    }
+
+    #[test]
+    fn test_merge_results_metadata() {
+        let mut first = result("First", 1);
+        first.metadata.authors = Some(vec!["Ann".to_string()]);
+        let mut second = result("Second", 1);
+        second.mime_type = "image/png".to_string();
+        second.metadata.title = Some("Contract".to_string());
+        second.metadata.authors = Some(vec!["Ann".to_string(), "Bob".to_string()]);
+
+        let options = MergeOptions {
+            separator: "\n[{index}]\n".to_string(),
+            ..Default::default()
+        };
+        let merged = merge_results(vec![first, second], options).unwrap();
+
+        assert_eq!(merged.content, "First\n[2]\nSecond");
+        assert_eq!(merged.mime_type, "multipart/mixed");
+        assert_eq!(merged.metadata.title.as_deref(), Some("Contract"));
+        assert_eq!(merged.metadata.additional["merged_parts"][0]["source"], "part 1");
+        assert_eq!(merged.metadata.authors.unwrap(), ["Ann", "Bob"]);
+
+        assert!(merge_results(vec![], MergeOptions::default()).is_err());
+        let unnamed = MergeOptions {
+            sources: vec!["only-one.pdf".to_string()],
+            ..Default::default()
+        };
+        assert!(merge_results(vec![result("a", 1), result("b", 1)], unnamed).is_err());
+    }
+}
@@ -16,6 +16,7 @@ impl OcrCache {
             path.push("ocr");
             path
         });
+        let cache_dir = crate::cache::long_path(&cache_dir);
 
         fs::create_dir_all(&cache_dir)
             .map_err(|e| OcrError::CacheError(format!("Failed to create cache directory: {}", e)))?;
@@ -0,0 +1,197 @@
+//! Page composition from the raw output of any OCR engine.
+//!
+//! Users running their own OCR (a cloud service, OCRopus, Kraken, a Tesseract they drive
+//! themselves) can still use Kreuzberg's page reconstruction: [`compose_from_hocr`] and
+//! [`compose_from_tsv`] turn one page of hOCR or Tesseract-style TSV into the content, the
+//! tables and the word geometry an OCR extraction produces.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::ocr::{ComposeOptions, compose_from_hocr};
+//!
+//! # fn example() -> Result<(), kreuzberg::ocr::OcrError> {
+//! let hocr = std::fs::read_to_string("page.hocr").expect("failed to read hOCR");
+//! let page = compose_from_hocr(&hocr, &ComposeOptions::default())?;
+//! println!("{}", page.content);
+//! for table in &page.tables {
+//!     println!("{}", table.markdown);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use super::error::OcrError;
+use super::hocr::{convert_hocr_to_markdown, extract_words_from_hocr};
+use super::table::{HocrWord, extract_words_from_tsv, reconstruct_table, table_to_markdown};
+use super::utils::{TSV_MIN_FIELDS, TSV_WORD_LEVEL};
+use crate::types::Table;
+
+/// Options of [`compose_from_hocr`] and [`compose_from_tsv`].
+///
+/// The table settings mean the same as the `table_*` fields of `TesseractConfig`.
+#[derive(Debug, Clone)]
+pub struct ComposeOptions {
+    /// Minimum word confidence (0-100) for table reconstruction and the returned words
+    pub min_confidence: f64,
+    /// Reconstruct a table from the word positions
+    pub detect_tables: bool,
+    /// Maximum horizontal distance in pixels between words of the same column
+    pub table_column_threshold: u32,
+    /// Maximum vertical distance between words of the same row, relative to the word height
+    pub table_row_threshold_ratio: f64,
+    /// Page number (1-indexed) assigned to the tables
+    pub page_number: usize,
+}
+
+impl Default for ComposeOptions {
+    fn default() -> Self {
+        Self {
+            min_confidence: 0.0,
+            detect_tables: true,
+            table_column_threshold: 50,
+            table_row_threshold_ratio: 0.5,
+            page_number: 1,
+        }
+    }
+}
+
+/// One page reconstructed from OCR output.
+#[derive(Debug, Clone)]
+pub struct ComposedPage {
+    /// Page content: Markdown for hOCR input, plain text (lines and paragraphs) for TSV input
+    pub content: String,
+    /// Tables reconstructed from the word positions
+    pub tables: Vec<Table>,
+    /// Recognized words with their bounding boxes in pixels
+    pub words: Vec<HocrWord>,
+}
+
+/// Compose a page from hOCR, the HTML output of Tesseract (`hocr`), OCRopus, Kraken and others.
+///
+/// # Errors
+///
+/// `ProcessingFailed` when the hOCR cannot be converted to Markdown.
+pub fn compose_from_hocr(hocr: &str, options: &ComposeOptions) -> Result<ComposedPage, OcrError> {
+    let content = convert_hocr_to_markdown(hocr, None)?;
+    let words = extract_words_from_hocr(hocr, options.min_confidence)?;
+    Ok(compose(content, words, options))
+}
+
+/// Compose a page from Tesseract-style TSV (`level page_num block_num par_num line_num word_num
+/// left top width height conf text`, with a header row).
+///
+/// The content keeps the line and paragraph structure of the TSV; low-confidence words are
+/// only left out of the tables and the returned words.
+pub fn compose_from_tsv(tsv: &str, options: &ComposeOptions) -> Result<ComposedPage, OcrError> {
+    let content = tsv_to_text(tsv);
+    let words = extract_words_from_tsv(tsv, options.min_confidence)?;
+    Ok(compose(content, words, options))
+}
+
+fn compose(content: String, words: Vec<HocrWord>, options: &ComposeOptions) -> ComposedPage {
+    let mut tables = Vec::new();
+    if options.detect_tables && !words.is_empty() {
+        let cells = reconstruct_table(
+            &words,
+            options.table_column_threshold,
+            options.table_row_threshold_ratio,
+            true,
+        );
+        if !cells.is_empty() {
+            let markdown = table_to_markdown(&cells);
+            tables.push(Table {
+                cells,
+                markdown,
+                page_number: options.page_number,
+            });
+        }
+    }
+
+    ComposedPage { content, tables, words }
+}
+
+/// Text of the word rows of a TSV: words of a line joined by spaces, lines of a paragraph by
+/// newlines and paragraphs by blank lines.
+fn tsv_to_text(tsv: &str) -> String {
+    let mut text = String::new();
+    let mut current_line: Option<[&str; 4]> = None;
+    for line in tsv.lines().skip(1) {
+        let fields: Vec<&str> = line.trim_end_matches('\r').split('\t').collect();
+        if fields.len() < TSV_MIN_FIELDS || fields[0].trim().parse::<u32>().ok() != Some(TSV_WORD_LEVEL) {
+            continue;
+        }
+        let word = fields[11].trim();
+        if word.is_empty() {
+            continue;
+        }
+
+        // page, block, paragraph and line number
+        let position = [fields[1], fields[2], fields[3], fields[4]];
+        match current_line {
+            Some(previous) if previous == position => text.push(' '),
+            Some(previous) if previous[..3] == position[..3] => text.push('\n'),
+            Some(_) => text.push_str("\n\n"),
+            None => {}
+        }
+        current_line = Some(position);
+        text.push_str(word);
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TSV: &str = "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\tleft\ttop\twidth\theight\tconf\ttext
+1\t1\t0\t0\t0\t0\t0\t0\t1000\t800\t-1\t
+5\t1\t1\t1\t1\t1\t100\t50\t80\t30\t96\tName
+5\t1\t1\t1\t1\t2\t400\t50\t80\t30\t95\tQty
+5\t1\t1\t1\t2\t1\t100\t100\t80\t30\t93\tApples
+5\t1\t1\t1\t2\t2\t400\t100\t30\t30\t91\t12
+5\t1\t2\t1\t1\t1\t100\t300\t120\t30\t20\tnoise";
+
+    #[test]
+    fn test_tsv_to_text_keeps_lines_and_paragraphs() {
+        assert_eq!(tsv_to_text(TSV), "Name Qty\nApples 12\n\nnoise");
+    }
+
+    #[test]
+    fn test_compose_from_tsv() {
+        let options = ComposeOptions {
+            min_confidence: 50.0,
+            page_number: 3,
+            ..Default::default()
+        };
+        let page = compose_from_tsv(TSV, &options).unwrap();
+        assert!(page.content.contains("noise"));
+        assert_eq!(page.words.len(), 4);
+        assert_eq!(page.tables.len(), 1);
+        assert_eq!(page.tables[0].page_number, 3);
+        assert!(page.tables[0].markdown.contains("Apples"));
+    }
+
+    #[test]
+    fn test_compose_from_hocr() {
+        let hocr = r#"<div class='ocr_page' title='bbox 0 0 1000 800'>
+            <p class='ocr_par'>
+                <span class='ocr_line' title='bbox 100 50 480 80'>
+                    <span class='ocrx_word' title='bbox 100 50 180 80; x_wconf 96'>Name</span>
+                    <span class='ocrx_word' title='bbox 400 50 480 80; x_wconf 95'>Qty</span>
+                </span>
+            </p>
+        </div>"#;
+
+        let page = compose_from_hocr(hocr, &ComposeOptions::default()).unwrap();
+        assert!(page.content.contains("Name"));
+        assert_eq!(page.words.len(), 2);
+
+        let without_tables = ComposeOptions {
+            detect_tables: false,
+            ..Default::default()
+        };
+        let page = compose_from_hocr(hocr, &without_tables).unwrap();
+        assert!(page.tables.is_empty());
+    }
+}
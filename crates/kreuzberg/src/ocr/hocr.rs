@@ -1,6 +1,23 @@
+//! hOCR utilities.
+//!
+//! hOCR is the HTML-based OCR output format of Tesseract, OCRopus, Kraken and most cloud
+//! engines. [`convert_hocr_to_markdown`] turns a page into Markdown and
+//! [`extract_words_from_hocr`] reads the word boxes, so output of any hOCR-producing engine can
+//! go through the same table reconstruction as Tesseract's (see [`super::compose`]).
+
 use super::error::OcrError;
+use crate::extraction::html::html_text;
+use html_to_markdown_rs::hocr::HocrWord;
 use html_to_markdown_rs::{ConversionOptions, convert};
+use regex::Regex;
+use std::sync::OnceLock;
+
+static WORD_RE: OnceLock<Regex> = OnceLock::new();
+static TITLE_RE: OnceLock<Regex> = OnceLock::new();
 
+/// Convert an hOCR page to Markdown.
+///
+/// Without `options`, spatial table detection and metadata extraction are turned off.
 pub fn convert_hocr_to_markdown(hocr_html: &str, options: Option<ConversionOptions>) -> Result<String, OcrError> {
     let use_default = options.is_none();
     let mut opts = options.unwrap_or_default();
@@ -13,10 +30,91 @@ pub fn convert_hocr_to_markdown(hocr_html: &str, options: Option<ConversionOptio
     convert(hocr_html, Some(opts)).map_err(|e| OcrError::ProcessingFailed(format!("hOCR conversion failed: {}", e)))
 }
 
+/// Extract the words (`ocrx_word` elements) of an hOCR document with their bounding boxes.
+///
+/// Boxes come from the `bbox` property of the `title` attribute and confidences from
+/// `x_wconf`; words without `x_wconf` count as fully confident (100). Words without a bounding
+/// box, empty words and words below `min_confidence` are skipped.
+pub fn extract_words_from_hocr(hocr: &str, min_confidence: f64) -> Result<Vec<HocrWord>, OcrError> {
+    let word_re = WORD_RE.get_or_init(|| Regex::new(r"(?s)<span\b([^>]*\bocrx_word\b[^>]*)>(.*?)</span>").unwrap());
+    let title_re = TITLE_RE.get_or_init(|| Regex::new(r#"\btitle\s*=\s*["']([^"']*)["']"#).unwrap());
+
+    let mut words = Vec::new();
+    for caps in word_re.captures_iter(hocr) {
+        let Some(title_caps) = title_re.captures(&caps[1]) else {
+            continue;
+        };
+        let title = &title_caps[1];
+        let Some((left, top, right, bottom)) = title_property(title, "bbox").and_then(parse_bbox) else {
+            continue;
+        };
+        let confidence = title_property(title, "x_wconf")
+            .and_then(|conf| conf.parse::<f64>().ok())
+            .unwrap_or(100.0);
+        if confidence < min_confidence {
+            continue;
+        }
+
+        let text = html_text(&caps[2]);
+        if text.is_empty() {
+            continue;
+        }
+        words.push(HocrWord {
+            text,
+            left,
+            top,
+            width: right.saturating_sub(left),
+            height: bottom.saturating_sub(top),
+            confidence,
+        });
+    }
+
+    Ok(words)
+}
+
+/// Value of a `name value; name value` property in an hOCR `title` attribute.
+fn title_property<'a>(title: &'a str, name: &str) -> Option<&'a str> {
+    title.split(';').find_map(|property| {
+        let (key, value) = property.trim().split_once(char::is_whitespace)?;
+        (key == name).then_some(value.trim())
+    })
+}
+
+fn parse_bbox(bbox: &str) -> Option<(u32, u32, u32, u32)> {
+    let mut coords = bbox.split_whitespace().map(|coord| coord.parse::<u32>().ok());
+    Some((coords.next()??, coords.next()??, coords.next()??, coords.next()??))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_extract_words_from_hocr() {
+        let hocr = r#"<div class='ocr_page' title='bbox 0 0 1000 800'>
+            <span class='ocr_line' title="bbox 100 50 260 80; baseline 0 -5">
+                <span class='ocrx_word' id='word_1_1' title='bbox 100 50 180 80; x_wconf 96'><strong>Hello</strong></span>
+                <span class='ocrx_word' id='word_1_2' title='bbox 190 50 260 80; x_wconf 41'>W&amp;rld</span>
+                <span class="ocrx_word" title="bbox 270 50 300 80">!</span>
+                <span class='ocrx_word' title='x_wconf 90'>nobox</span>
+            </span>
+        </div>"#;
+
+        let words = extract_words_from_hocr(hocr, 0.0).unwrap();
+        assert_eq!(words.len(), 3);
+        assert_eq!(words[0].text, "Hello");
+        assert_eq!(
+            (words[0].left, words[0].top, words[0].width, words[0].height),
+            (100, 50, 80, 30)
+        );
+        assert_eq!(words[0].confidence, 96.0);
+        assert_eq!(words[1].text, "W&rld");
+        assert_eq!(words[2].confidence, 100.0);
+
+        let confident = extract_words_from_hocr(hocr, 50.0).unwrap();
+        assert_eq!(confident.len(), 2);
+    }
+
     #[test]
     fn test_simple_hocr_conversion() {
         let hocr = r#"<div class="ocr_page">
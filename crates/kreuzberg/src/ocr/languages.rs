@@ -0,0 +1,243 @@
+//! Tesseract language pack management.
+//!
+//! Tesseract needs one `<code>.traineddata` file per OCR language in its tessdata directory.
+//! Without these helpers a missing pack only shows up as "Language 'deu' not found" deep
+//! inside an extraction; [`verify`] checks a language setting up front, [`list_installed`]
+//! shows what is available and [`download`] (`ocr-download` feature) fetches missing packs
+//! from the official tessdata repositories.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use kreuzberg::ocr::languages;
+//!
+//! # fn example() -> kreuzberg::Result<()> {
+//! println!("Installed: {}", languages::list_installed(None)?.join(", "));
+//! // Fails with a MissingDependency error naming every missing pack.
+//! languages::verify("eng+deu", None)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use super::validation::validate_language_code;
+use crate::{KreuzbergError, Result};
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Tessdata directories searched when `TESSDATA_PREFIX` is not set, in order.
+pub const TESSDATA_FALLBACK_DIRS: &[&str] = &[
+    "/opt/homebrew/share/tessdata",
+    "/opt/homebrew/opt/tesseract/share/tessdata",
+    "/usr/local/opt/tesseract/share/tessdata",
+    "/usr/share/tesseract-ocr/5/tessdata",
+    "/usr/share/tesseract-ocr/4/tessdata",
+    "/usr/share/tessdata",
+    "/usr/local/share/tessdata",
+    r#"C:\Program Files\Tesseract-OCR\tessdata"#,
+    r#"C:\ProgramData\Tesseract-OCR\tessdata"#,
+];
+
+/// File extension of Tesseract language packs.
+const TRAINEDDATA_EXTENSION: &str = "traineddata";
+
+/// The tessdata directory used for OCR: `TESSDATA_PREFIX` if set, otherwise the first
+/// existing directory of [`TESSDATA_FALLBACK_DIRS`].
+pub fn tessdata_dir() -> Option<PathBuf> {
+    env::var_os("TESSDATA_PREFIX").map(PathBuf::from).or_else(|| {
+        TESSDATA_FALLBACK_DIRS
+            .iter()
+            .map(PathBuf::from)
+            .find(|dir| dir.exists())
+    })
+}
+
+fn resolve_dir(tessdata_dir: Option<&Path>) -> Result<PathBuf> {
+    let dir = tessdata_dir.map(Path::to_path_buf).or_else(self::tessdata_dir);
+    dir.ok_or_else(|| {
+        KreuzbergError::MissingDependency(
+            "No tessdata directory found. Install Tesseract or set TESSDATA_PREFIX".to_string(),
+        )
+    })
+}
+
+/// Language codes with a language pack in `tessdata_dir` (default: [`tessdata_dir()`]), sorted.
+///
+/// Includes Tesseract's helper packs such as `osd` (orientation and script detection).
+pub fn list_installed(tessdata_dir: Option<&Path>) -> Result<Vec<String>> {
+    let dir = resolve_dir(tessdata_dir)?;
+    let mut languages = Vec::new();
+    for entry in std::fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|ext| ext == TRAINEDDATA_EXTENSION)
+            && let Some(code) = path.file_stem().and_then(|stem| stem.to_str())
+        {
+            languages.push(code.to_string());
+        }
+    }
+    languages.sort();
+    Ok(languages)
+}
+
+/// Check that every language of a Tesseract language setting (`"eng"`, `"eng+deu"`) is a
+/// supported code with an installed pack in `tessdata_dir` (default: [`tessdata_dir()`]).
+///
+/// # Errors
+///
+/// `Validation` for unsupported codes and `MissingDependency` naming all missing packs.
+pub fn verify(languages: &str, tessdata_dir: Option<&Path>) -> Result<()> {
+    let codes = language_codes(languages)?;
+    let dir = resolve_dir(tessdata_dir)?;
+    let missing: Vec<&str> = codes
+        .into_iter()
+        .filter(|code| !pack_path(&dir, code).exists())
+        .collect();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    Err(KreuzbergError::MissingDependency(format!(
+        "Missing Tesseract language pack(s) in '{}': {}. Run `kreuzberg ocr languages --download {}`",
+        dir.display(),
+        missing.join(", "),
+        missing.join("+")
+    )))
+}
+
+/// Split a language setting into its codes, rejecting empty and unsupported ones.
+fn language_codes(languages: &str) -> Result<Vec<&str>> {
+    let codes: Vec<&str> = languages
+        .split('+')
+        .map(str::trim)
+        .filter(|code| !code.is_empty())
+        .collect();
+    if codes.is_empty() {
+        return Err(KreuzbergError::validation("No OCR language given"));
+    }
+    for code in &codes {
+        validate_language_code(code).map_err(|e| KreuzbergError::validation(e.to_string()))?;
+    }
+    Ok(codes)
+}
+
+fn pack_path(dir: &Path, code: &str) -> PathBuf {
+    dir.join(format!("{}.{}", code, TRAINEDDATA_EXTENSION))
+}
+
+/// Tessdata model family to download.
+#[cfg(feature = "ocr-download")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TessdataModel {
+    /// `tessdata_fast`: integer LSTM models, the packs most distributions ship
+    #[default]
+    Fast,
+    /// `tessdata_best`: float LSTM models, slower but more accurate
+    Best,
+    /// `tessdata`: LSTM plus legacy engine models
+    Legacy,
+}
+
+#[cfg(feature = "ocr-download")]
+impl TessdataModel {
+    fn repository(self) -> &'static str {
+        match self {
+            Self::Fast => "tessdata_fast",
+            Self::Best => "tessdata_best",
+            Self::Legacy => "tessdata",
+        }
+    }
+}
+
+#[cfg(feature = "ocr-download")]
+impl std::str::FromStr for TessdataModel {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "fast" => Ok(Self::Fast),
+            "best" => Ok(Self::Best),
+            "legacy" => Ok(Self::Legacy),
+            _ => Err(format!("Invalid tessdata model: {}. Use 'fast', 'best' or 'legacy'", s)),
+        }
+    }
+}
+
+/// Download the language pack of every language in `languages` (`"deu"`, `"deu+fra"`) from
+/// the `tesseract-ocr` GitHub repositories into `tessdata_dir` (default: [`tessdata_dir()`]).
+///
+/// Packs are written to a temporary file and renamed into place, so an interrupted download
+/// never leaves a truncated pack behind. Existing packs are replaced. Returns the paths of the
+/// installed packs.
+#[cfg(feature = "ocr-download")]
+pub async fn download(languages: &str, model: TessdataModel, tessdata_dir: Option<&Path>) -> Result<Vec<PathBuf>> {
+    let codes = language_codes(languages)?;
+    let dir = resolve_dir(tessdata_dir)?;
+    tokio::fs::create_dir_all(&dir).await?;
+
+    let client = reqwest::Client::new();
+    let mut installed = Vec::with_capacity(codes.len());
+    for code in codes {
+        let url = format!(
+            "https://github.com/tesseract-ocr/{}/raw/main/{}.{}",
+            model.repository(),
+            code,
+            TRAINEDDATA_EXTENSION
+        );
+        tracing::info!("Downloading Tesseract language pack '{}' from {}", code, url);
+        let download_error =
+            |e: reqwest::Error| KreuzbergError::ocr_with_source(format!("Failed to download '{}'", url), e);
+        let response = client.get(&url).send().await.map_err(download_error)?;
+        let response = response.error_for_status().map_err(download_error)?;
+        let data = response.bytes().await.map_err(download_error)?;
+
+        let path = pack_path(&dir, code);
+        let partial = dir.join(format!("{}.{}.part", code, TRAINEDDATA_EXTENSION));
+        tokio::fs::write(&partial, &data).await?;
+        tokio::fs::rename(&partial, &path).await?;
+        installed.push(path);
+    }
+    Ok(installed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn tessdata_with(packs: &[&str]) -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for pack in packs {
+            std::fs::write(dir.path().join(pack), b"").unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_list_installed() {
+        let dir = tessdata_with(&["eng.traineddata", "deu.traineddata", "osd.traineddata", "notes.txt"]);
+        let installed = list_installed(Some(dir.path())).unwrap();
+        assert_eq!(installed, vec!["deu", "eng", "osd"]);
+    }
+
+    #[test]
+    fn test_verify_installed_languages() {
+        let dir = tessdata_with(&["eng.traineddata", "deu.traineddata"]);
+        verify("eng", Some(dir.path())).unwrap();
+        verify("eng+deu", Some(dir.path())).unwrap();
+    }
+
+    #[test]
+    fn test_verify_reports_all_missing_packs() {
+        let dir = tessdata_with(&["eng.traineddata"]);
+        let err = verify("eng+deu+fra", Some(dir.path())).unwrap_err();
+        assert!(matches!(err, KreuzbergError::MissingDependency(_)));
+        assert!(err.to_string().contains("deu, fra"), "{}", err);
+    }
+
+    #[test]
+    fn test_verify_rejects_unsupported_codes() {
+        let dir = tessdata_with(&[]);
+        for languages in ["", "klingon", "eng+../etc"] {
+            let err = verify(languages, Some(dir.path())).unwrap_err();
+            assert!(matches!(err, KreuzbergError::Validation { .. }), "{}", languages);
+        }
+    }
+}
@@ -9,8 +9,12 @@
 //! - **Result caching**: Persistent cache for OCR results using file hashing
 //! - **Table reconstruction**: Extract and reconstruct tables from hOCR/TSV output
 //! - **hOCR to Markdown**: Convert hOCR format to clean Markdown
+//! - **Bring your own OCR**: Compose content, tables and word boxes from the hOCR or TSV
+//!   output of any OCR engine ([`compose`])
 //! - **Batch processing**: Process multiple images efficiently
-//! - **Language support**: Validate and configure Tesseract languages
+//! - **Searchable PDFs**: Write scans as PDFs with an invisible OCR text layer (`pdf` feature)
+//! - **Language support**: Validate and configure Tesseract languages, and list, verify and
+//!   download language packs ([`languages`])
 //! - **PSM modes**: Support for all Tesseract Page Segmentation Modes
 //!
 //! # Example
@@ -38,9 +42,13 @@
 //! kreuzberg = { version = "4.0", features = ["ocr"] }
 //! ```
 pub mod cache;
+pub mod compose;
 pub mod error;
 pub mod hocr;
+pub mod languages;
 pub mod processor;
+#[cfg(feature = "pdf")]
+pub mod searchable_pdf;
 pub mod table;
 pub mod tesseract_backend;
 pub mod types;
@@ -48,9 +56,12 @@ pub mod utils;
 pub mod validation;
 
 pub use cache::{OcrCache, OcrCacheStats};
+pub use compose::{ComposeOptions, ComposedPage, compose_from_hocr, compose_from_tsv};
 pub use error::OcrError;
-pub use hocr::convert_hocr_to_markdown;
+pub use hocr::{convert_hocr_to_markdown, extract_words_from_hocr};
 pub use processor::OcrProcessor;
+#[cfg(feature = "pdf")]
+pub use searchable_pdf::{SearchablePage, build_searchable_pdf, create_searchable_pdf};
 pub use table::{HocrWord, extract_words_from_tsv, reconstruct_table, table_to_markdown};
 pub use tesseract_backend::TesseractBackend;
 pub use types::{BatchItemResult, ExtractionResult, PSMMode, Table, TesseractConfig};
@@ -8,9 +8,15 @@ use kreuzberg_tesseract::{TessPageSegMode, TesseractAPI};
 use super::cache::OcrCache;
 use super::error::OcrError;
 use super::hocr::convert_hocr_to_markdown;
+use super::languages::{TESSDATA_FALLBACK_DIRS, tessdata_dir};
 use super::table::{extract_words_from_tsv, reconstruct_table, table_to_markdown};
 use super::types::{BatchItemResult, TesseractConfig};
+use crate::image::{preprocess_for_ocr, scale_image};
 use crate::types::{OcrExtractionResult, OcrTable};
+use crate::utils::resolution::image_dpi;
+
+/// Longest side an image is upscaled to when resampling it to the preprocessing target DPI.
+const MAX_RESAMPLED_DIMENSION: f64 = 10_000.0;
 
 fn strip_control_characters(text: &str) -> String {
     if text
@@ -41,6 +47,35 @@ where
     tracing::debug!("[ci-debug][ocr::processor::{stage}] {timestamp:.3}s {}", details());
 }
 
+/// Resample an image to the preprocessing target DPI and enhance it for OCR.
+///
+/// Returns the prepared image and its resolution. Images that don't record their resolution
+/// keep their size, since scaling them would rest on a guess.
+fn prepare_image(
+    img: image::DynamicImage,
+    dpi: Option<u32>,
+    config: &TesseractConfig,
+) -> Result<(image::DynamicImage, Option<u32>), OcrError> {
+    let Some(preprocessing) = &config.preprocessing else {
+        return Ok((img, dpi));
+    };
+
+    let (img, dpi) = match dpi {
+        Some(source_dpi) if preprocessing.target_dpi > 0 => {
+            let mut scale = f64::from(preprocessing.target_dpi) / f64::from(source_dpi);
+            if scale > 1.0 {
+                let longest_side = f64::from(img.width().max(img.height()).max(1));
+                scale = scale.min(MAX_RESAMPLED_DIMENSION / longest_side).max(1.0);
+            }
+            let img = scale_image(img, scale)
+                .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to resample image: {}", e)))?;
+            (img, Some((f64::from(source_dpi) * scale).round() as u32))
+        }
+        _ => (img, dpi),
+    };
+    Ok((preprocess_for_ocr(img, preprocessing), dpi))
+}
+
 pub struct OcrProcessor {
     cache: OcrCache,
 }
@@ -112,23 +147,25 @@ impl OcrProcessor {
     pub fn process_files_batch(&self, file_paths: Vec<String>, config: &TesseractConfig) -> Vec<BatchItemResult> {
         use rayon::prelude::*;
 
-        file_paths
-            .par_iter()
-            .map(|path| match self.process_file(path, config) {
-                Ok(result) => BatchItemResult {
-                    file_path: path.clone(),
-                    success: true,
-                    result: Some(result),
-                    error: None,
-                },
-                Err(e) => BatchItemResult {
-                    file_path: path.clone(),
-                    success: false,
-                    result: None,
-                    error: Some(e.to_string()),
-                },
-            })
-            .collect()
+        crate::core::runtime::install(|| {
+            file_paths
+                .par_iter()
+                .map(|path| match self.process_file(path, config) {
+                    Ok(result) => BatchItemResult {
+                        file_path: path.clone(),
+                        success: true,
+                        result: Some(result),
+                        error: None,
+                    },
+                    Err(e) => BatchItemResult {
+                        file_path: path.clone(),
+                        success: false,
+                        result: None,
+                        error: Some(e.to_string()),
+                    },
+                })
+                .collect()
+        })
     }
 
     fn hash_config(&self, config: &TesseractConfig) -> String {
@@ -152,6 +189,14 @@ impl OcrProcessor {
         config.tessedit_use_primary_params_model.hash(&mut hasher);
         config.textord_space_size_is_variable.hash(&mut hasher);
         config.thresholding_method.hash(&mut hasher);
+        if let Some(preprocessing) = &config.preprocessing {
+            preprocessing.grayscale.hash(&mut hasher);
+            preprocessing.invert_colors.hash(&mut hasher);
+            preprocessing.denoise.hash(&mut hasher);
+            preprocessing.contrast_enhance.hash(&mut hasher);
+            preprocessing.binarization_method.hash(&mut hasher);
+            preprocessing.remove_borders.hash(&mut hasher);
+        }
 
         format!("{:016x}", hasher.finish())
     }
@@ -170,6 +215,7 @@ impl OcrProcessor {
 
         let img = image::load_from_memory(image_bytes)
             .map_err(|e| OcrError::ImageProcessingFailed(format!("Failed to decode image: {}", e)))?;
+        let (img, dpi) = prepare_image(img, image_dpi(image_bytes), config)?;
 
         let rgb_image = img.to_rgb8();
         let (width, height) = rgb_image.dimensions();
@@ -186,25 +232,8 @@ impl OcrProcessor {
         let api = TesseractAPI::new();
 
         let tessdata_env = env::var("TESSDATA_PREFIX").ok();
-        let fallback_paths = [
-            "/opt/homebrew/share/tessdata",
-            "/opt/homebrew/opt/tesseract/share/tessdata",
-            "/usr/local/opt/tesseract/share/tessdata",
-            "/usr/share/tesseract-ocr/5/tessdata",
-            "/usr/share/tesseract-ocr/4/tessdata",
-            "/usr/share/tessdata",
-            "/usr/local/share/tessdata",
-            r#"C:\Program Files\Tesseract-OCR\tessdata"#,
-            r#"C:\ProgramData\Tesseract-OCR\tessdata"#,
-        ];
-        let tessdata_path = tessdata_env
-            .clone()
-            .or_else(|| {
-                fallback_paths
-                    .iter()
-                    .find(|p| Path::new(p).exists())
-                    .map(|p| (*p).to_string())
-            })
+        let tessdata_path = tessdata_dir()
+            .map(|dir| dir.to_string_lossy().into_owned())
             .unwrap_or_default();
 
         log_ci_debug(ci_debug_enabled, "tessdata", || {
@@ -216,7 +245,7 @@ impl OcrProcessor {
                     .join(", ")
             });
             let resolved_exists = !tessdata_path.is_empty() && Path::new(&tessdata_path).exists();
-            let available_fallbacks = fallback_paths
+            let available_fallbacks = TESSDATA_FALLBACK_DIRS
                 .iter()
                 .filter(|p| Path::new(p).exists())
                 .map(|p| (*p).to_string())
@@ -258,9 +287,11 @@ impl OcrProcessor {
                 let traineddata_path = Path::new(&tessdata_path).join(format!("{}.traineddata", lang));
                 if !traineddata_path.exists() {
                     return Err(OcrError::TesseractInitializationFailed(format!(
-                        "Language '{}' not found. Traineddata file does not exist: {}",
+                        "Language '{}' not found. Traineddata file does not exist: {}. \
+                         Install it with `kreuzberg ocr languages --download {}`",
                         lang,
-                        traineddata_path.display()
+                        traineddata_path.display(),
+                        lang
                     )));
                 }
             }
@@ -366,6 +397,11 @@ impl OcrProcessor {
         )
         .map_err(|e| OcrError::ProcessingFailed(format!("Failed to set image: {}", e)))?;
 
+        if let Some(dpi) = dpi {
+            api.set_source_resolution(dpi as i32)
+                .map_err(|e| OcrError::ProcessingFailed(format!("Failed to set source resolution: {}", e)))?;
+        }
+
         log_ci_debug(ci_debug_enabled, "set_image", || {
             format!(
                 "width={} height={} bytes_per_pixel={} bytes_per_line={}",
@@ -378,6 +414,9 @@ impl OcrProcessor {
 
         log_ci_debug(ci_debug_enabled, "recognize", || "completed".to_string());
 
+        // Tesseract reports -1 when no text was recognized.
+        let mean_confidence = api.mean_text_conf().ok().filter(|conf| *conf >= 0);
+
         let tsv_data_for_tables = if config.enable_table_detection || config.output_format == "tsv" {
             Some(
                 api.get_tsv_text(0)
@@ -433,6 +472,12 @@ impl OcrProcessor {
             "output_format".to_string(),
             serde_json::Value::String(config.output_format.clone()),
         );
+        if let Some(conf) = mean_confidence {
+            metadata.insert(
+                crate::core::stats::OCR_CONFIDENCE_KEY.to_string(),
+                serde_json::json!(f64::from(conf) / 100.0),
+            );
+        }
         metadata.insert("table_count".to_string(), serde_json::Value::String("0".to_string()));
         metadata.insert(
             "tables_detected".to_string(),
@@ -0,0 +1,383 @@
+//! Searchable PDF output.
+//!
+//! Builds a PDF in which every page is the scanned page image with the OCR words laid
+//! over it as invisible text (text render mode 3), so the document looks like the scan
+//! but can be searched, selected and copied. Word positions come from Tesseract's TSV
+//! output.
+//!
+//! The text layer uses a non-embedded `Identity-H` font with a `ToUnicode` map, which
+//! keeps the output small and supports any character in the Basic Multilingual Plane.
+//! Each word is stretched horizontally to the width of its bounding box.
+
+use crate::core::config::{ExtractionConfig, OcrConfig};
+use crate::core::mime::{PDF_MIME_TYPE, detect_mime_type};
+use crate::ocr::processor::OcrProcessor;
+use crate::ocr::table::{HocrWord, extract_words_from_tsv};
+use crate::ocr::tesseract_backend::TesseractBackend;
+use crate::ocr::types::TesseractConfig;
+use crate::pdf::rendering::{PageRenderOptions, PdfRenderer};
+use crate::utils::resolution::{image_dpi, with_png_dpi};
+use crate::{KreuzbergError, Result};
+use image::DynamicImage;
+use image::codecs::jpeg::JpegEncoder;
+use image::codecs::png::PngEncoder;
+use image::{ExtendedColorType, ImageEncoder};
+use lopdf::content::{Content, Operation};
+use lopdf::{Document, Object, ObjectId, Stream, StringFormat, dictionary};
+use std::path::Path;
+
+/// Resolution used to render PDF pages, and assumed for images that don't record their own.
+pub const DEFAULT_DPI: u32 = 300;
+
+/// JPEG quality of the embedded page images.
+const JPEG_QUALITY: u8 = 85;
+
+/// Advance width of every glyph in the text-layer font, in thousandths of an em.
+const GLYPH_WIDTH: f32 = 500.0;
+
+/// Maps every 2-byte code to the same Unicode code point.
+const TO_UNICODE_CMAP: &str = "/CIDInit /ProcSet findresource begin
+12 dict begin
+begincmap
+/CIDSystemInfo << /Registry (Adobe) /Ordering (UCS) /Supplement 0 >> def
+/CMapName /Adobe-Identity-UCS def
+/CMapType 2 def
+1 begincodespacerange
+<0000> <FFFF>
+endcodespacerange
+1 beginbfrange
+<0000> <FFFF> <0000>
+endbfrange
+endcmap
+CMapName currentdict /CIDInit /ProcSet findresource pop defineresource pop
+end
+end
+";
+
+/// One page of a searchable PDF.
+#[derive(Debug, Clone)]
+pub struct SearchablePage {
+    /// Page image; it fills the whole page.
+    pub image: DynamicImage,
+    /// Resolution of `image`, which determines the physical page size.
+    pub dpi: u32,
+    /// OCR words in image pixel coordinates.
+    pub words: Vec<HocrWord>,
+}
+
+/// OCR a scanned document and return a searchable PDF of it.
+///
+/// PDFs are rendered page by page at [`DEFAULT_DPI`]; other inputs must be images (only
+/// the first frame of multi-frame images is used), sized by the resolution they record or
+/// [`DEFAULT_DPI`]. OCR uses `config.ocr`, falling back to English Tesseract defaults, and
+/// benefits from the OCR cache like regular extraction.
+///
+/// # Errors
+///
+/// Returns `UnsupportedFormat` for inputs that are neither PDFs nor images, and `Ocr`,
+/// `Pdf` or `ImageProcessing` errors when a step of the pipeline fails.
+pub fn create_searchable_pdf(path: impl AsRef<Path>, config: &ExtractionConfig) -> Result<Vec<u8>> {
+    let path = path.as_ref();
+    let mime_type = detect_mime_type(path, true)?;
+    let bytes = std::fs::read(path)?;
+
+    let images: Vec<(DynamicImage, u32)> = if mime_type == PDF_MIME_TYPE {
+        let options = PageRenderOptions {
+            target_dpi: DEFAULT_DPI as i32,
+            auto_adjust_dpi: false,
+            ..Default::default()
+        };
+        PdfRenderer::new()?
+            .render_all_pages(&bytes, &options)?
+            .into_iter()
+            .map(|page| (page.image, page.dpi as u32))
+            .collect()
+    } else if mime_type.starts_with("image/") {
+        let image = image::load_from_memory(&bytes).map_err(|e| image_error("Failed to decode image", e))?;
+        let dpi = image_dpi(&bytes).unwrap_or(DEFAULT_DPI);
+        vec![(image, dpi)]
+    } else {
+        return Err(KreuzbergError::UnsupportedFormat(format!(
+            "Searchable PDF output requires a PDF or image input, got {}",
+            mime_type
+        )));
+    };
+
+    let processor = OcrProcessor::new(None).map_err(ocr_error)?;
+    let tesseract_config = tsv_config(config.ocr.as_ref(), config.use_cache);
+
+    let mut pages = Vec::with_capacity(images.len());
+    for (image, dpi) in images {
+        let mut png = Vec::new();
+        let rgb = image.to_rgb8();
+        PngEncoder::new(&mut png)
+            .write_image(&rgb, rgb.width(), rgb.height(), ExtendedColorType::Rgb8)
+            .map_err(|e| image_error("Failed to encode page image", e))?;
+        let png = with_png_dpi(png, dpi);
+
+        let tsv = processor.process_image(&png, &tesseract_config).map_err(ocr_error)?;
+        let words = extract_words_from_tsv(&tsv.content, tesseract_config.min_confidence).map_err(ocr_error)?;
+        pages.push(SearchablePage { image, dpi, words });
+    }
+
+    build_searchable_pdf(&pages)
+}
+
+/// Assemble a searchable PDF from page images and their OCR words.
+pub fn build_searchable_pdf(pages: &[SearchablePage]) -> Result<Vec<u8>> {
+    if pages.is_empty() {
+        return Err(KreuzbergError::validation("A searchable PDF needs at least one page"));
+    }
+
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let font_id = add_text_layer_font(&mut doc);
+
+    let mut page_ids = Vec::with_capacity(pages.len());
+    for page in pages {
+        page_ids.push(add_page(&mut doc, pages_id, font_id, page)?);
+    }
+
+    let kids: Vec<Object> = page_ids.into_iter().map(Object::from).collect();
+    let count = kids.len() as i64;
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! {
+            "Type" => "Pages",
+            "Kids" => kids,
+            "Count" => count,
+        }),
+    );
+    let catalog_id = doc.add_object(dictionary! {
+        "Type" => "Catalog",
+        "Pages" => pages_id,
+    });
+    doc.trailer.set("Root", catalog_id);
+    doc.compress();
+
+    let mut output = Vec::new();
+    doc.save_to(&mut output)
+        .map_err(|e| KreuzbergError::Other(format!("Failed to write searchable PDF: {}", e)))?;
+    Ok(output)
+}
+
+fn add_page(doc: &mut Document, pages_id: ObjectId, font_id: ObjectId, page: &SearchablePage) -> Result<ObjectId> {
+    let rgb = page.image.to_rgb8();
+    let (width_px, height_px) = rgb.dimensions();
+    let scale = 72.0 / page.dpi.max(1) as f32;
+    let width = width_px as f32 * scale;
+    let height = height_px as f32 * scale;
+
+    let mut jpeg = Vec::new();
+    JpegEncoder::new_with_quality(&mut jpeg, JPEG_QUALITY)
+        .write_image(&rgb, width_px, height_px, ExtendedColorType::Rgb8)
+        .map_err(|e| image_error("Failed to encode page image", e))?;
+    let image_id = doc.add_object(
+        Stream::new(
+            dictionary! {
+                "Type" => "XObject",
+                "Subtype" => "Image",
+                "Width" => width_px as i64,
+                "Height" => height_px as i64,
+                "ColorSpace" => "DeviceRGB",
+                "BitsPerComponent" => 8,
+                "Filter" => "DCTDecode",
+            },
+            jpeg,
+        )
+        .with_compression(false),
+    );
+
+    let mut operations = vec![
+        Operation::new("q", vec![]),
+        Operation::new(
+            "cm",
+            vec![width.into(), 0.into(), 0.into(), height.into(), 0.into(), 0.into()],
+        ),
+        Operation::new("Do", vec!["Im0".into()]),
+        Operation::new("Q", vec![]),
+    ];
+    operations.extend(text_layer(&page.words, scale, height));
+    let content = Content { operations }
+        .encode()
+        .map_err(|e| KreuzbergError::Other(format!("Failed to encode PDF page content: {}", e)))?;
+    let content_id = doc.add_object(Stream::new(dictionary! {}, content));
+
+    Ok(doc.add_object(dictionary! {
+        "Type" => "Page",
+        "Parent" => pages_id,
+        "MediaBox" => vec![0.into(), 0.into(), width.into(), height.into()],
+        "Contents" => content_id,
+        "Resources" => dictionary! {
+            "XObject" => dictionary! { "Im0" => image_id },
+            "Font" => dictionary! { "F0" => font_id },
+        },
+    }))
+}
+
+/// Invisible text operations for `words`, converted from pixels to points.
+fn text_layer(words: &[HocrWord], scale: f32, page_height: f32) -> Vec<Operation> {
+    let mut operations = vec![Operation::new("BT", vec![]), Operation::new("Tr", vec![3.into()])];
+    for word in words {
+        let codes: Vec<u8> = word
+            .text
+            .chars()
+            .map(|c| u16::try_from(u32::from(c)).unwrap_or(0xFFFD))
+            .flat_map(u16::to_be_bytes)
+            .collect();
+        if codes.is_empty() || word.width == 0 || word.height == 0 {
+            continue;
+        }
+
+        let font_size = word.height as f32 * scale;
+        let natural_width = (codes.len() / 2) as f32 * GLYPH_WIDTH / 1000.0 * font_size;
+        let horizontal_scale = 100.0 * word.width as f32 * scale / natural_width;
+        let x = word.left as f32 * scale;
+        let y = page_height - (word.top + word.height) as f32 * scale;
+
+        operations.extend([
+            Operation::new("Tf", vec!["F0".into(), font_size.into()]),
+            Operation::new("Tz", vec![horizontal_scale.into()]),
+            Operation::new("Tm", vec![1.into(), 0.into(), 0.into(), 1.into(), x.into(), y.into()]),
+            Operation::new("Tj", vec![Object::String(codes, StringFormat::Hexadecimal)]),
+        ]);
+    }
+    operations.push(Operation::new("ET", vec![]));
+    operations
+}
+
+/// Type0 font whose codes are Unicode code points, used only for invisible text.
+fn add_text_layer_font(doc: &mut Document) -> ObjectId {
+    let to_unicode_id = doc.add_object(Stream::new(dictionary! {}, TO_UNICODE_CMAP.as_bytes().to_vec()));
+    let descriptor_id = doc.add_object(dictionary! {
+        "Type" => "FontDescriptor",
+        "FontName" => "GlyphLessFont",
+        "Flags" => 5,
+        "FontBBox" => vec![0.into(), 0.into(), (GLYPH_WIDTH as i64).into(), 1000.into()],
+        "ItalicAngle" => 0,
+        "Ascent" => 1000,
+        "Descent" => 0,
+        "CapHeight" => 1000,
+        "StemV" => 80,
+    });
+    let cid_font_id = doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "CIDFontType2",
+        "BaseFont" => "GlyphLessFont",
+        "CIDSystemInfo" => dictionary! {
+            "Registry" => Object::string_literal("Adobe"),
+            "Ordering" => Object::string_literal("Identity"),
+            "Supplement" => 0,
+        },
+        "FontDescriptor" => descriptor_id,
+        "DW" => GLYPH_WIDTH as i64,
+        "CIDToGIDMap" => "Identity",
+    });
+    doc.add_object(dictionary! {
+        "Type" => "Font",
+        "Subtype" => "Type0",
+        "BaseFont" => "GlyphLessFont",
+        "Encoding" => "Identity-H",
+        "DescendantFonts" => vec![cid_font_id.into()],
+        "ToUnicode" => to_unicode_id,
+    })
+}
+
+/// Tesseract settings for word-level TSV output.
+fn tsv_config(ocr: Option<&OcrConfig>, use_cache: bool) -> TesseractConfig {
+    let mut config = match ocr {
+        Some(OcrConfig {
+            tesseract_config: Some(tesseract_config),
+            ..
+        }) => TesseractBackend::convert_config(tesseract_config),
+        Some(ocr) => TesseractConfig {
+            language: ocr.language.clone(),
+            ..Default::default()
+        },
+        None => TesseractConfig::default(),
+    };
+    config.output_format = "tsv".to_string();
+    config.use_cache = config.use_cache && use_cache;
+    config
+}
+
+fn ocr_error(e: crate::ocr::error::OcrError) -> KreuzbergError {
+    KreuzbergError::Ocr {
+        message: format!("OCR failed while creating searchable PDF: {}", e),
+        source: Some(Box::new(e)),
+    }
+}
+
+fn image_error(message: &str, e: image::ImageError) -> KreuzbergError {
+    KreuzbergError::ImageProcessing {
+        message: format!("{}: {}", message, e),
+        source: Some(Box::new(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::RgbImage;
+
+    fn word(text: &str, left: u32, top: u32, width: u32, height: u32) -> HocrWord {
+        HocrWord {
+            text: text.to_string(),
+            left,
+            top,
+            width,
+            height,
+            confidence: 90.0,
+        }
+    }
+
+    fn page() -> SearchablePage {
+        SearchablePage {
+            image: DynamicImage::ImageRgb8(RgbImage::from_pixel(600, 300, image::Rgb([255, 255, 255]))),
+            dpi: 300,
+            words: vec![word("Hello", 60, 100, 150, 40), word("Wörld", 230, 100, 150, 40)],
+        }
+    }
+
+    #[test]
+    fn test_build_searchable_pdf_pages_and_text() {
+        let bytes = build_searchable_pdf(&[page(), page()]).unwrap();
+        assert!(bytes.starts_with(b"%PDF-1.5"));
+
+        let doc = Document::load_mem(&bytes).unwrap();
+        let pages = doc.get_pages();
+        assert_eq!(pages.len(), 2);
+
+        let page_id = pages[&1];
+        let media_box = doc.get_dictionary(page_id).unwrap().get(b"MediaBox").unwrap();
+        let media_box = media_box.as_array().unwrap();
+        assert!((media_box[2].as_float().unwrap() - 144.0).abs() < 0.01);
+        assert!((media_box[3].as_float().unwrap() - 72.0).abs() < 0.01);
+
+        let content = Content::decode(&doc.get_page_content(page_id).unwrap()).unwrap();
+        let shown: Vec<&Operation> = content.operations.iter().filter(|op| op.operator == "Tj").collect();
+        assert_eq!(shown.len(), 2);
+        let expected = [0, b'W', 0, 0xF6, 0, b'r', 0, b'l', 0, b'd'];
+        assert_eq!(shown[1].operands[0].as_str().unwrap(), expected);
+
+        let render_mode = content.operations.iter().find(|op| op.operator == "Tr").unwrap();
+        assert_eq!(render_mode.operands[0].as_i64().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_build_searchable_pdf_requires_pages() {
+        assert!(build_searchable_pdf(&[]).is_err());
+    }
+
+    #[test]
+    fn test_text_layer_fits_word_boxes() {
+        let operations = text_layer(&[word("ab", 300, 0, 600, 100)], 72.0 / 300.0, 792.0);
+        let tz = operations.iter().find(|op| op.operator == "Tz").unwrap();
+        let tm = operations.iter().find(|op| op.operator == "Tm").unwrap();
+
+        // Two glyphs of half an em at 24pt are 24pt wide; the box is 144pt wide.
+        assert!((tz.operands[0].as_float().unwrap() - 600.0).abs() < 0.01);
+        assert!((tm.operands[4].as_float().unwrap() - 72.0).abs() < 0.01);
+        assert!((tm.operands[5].as_float().unwrap() - 768.0).abs() < 0.01);
+    }
+}
@@ -5,6 +5,7 @@
 
 use crate::Result;
 use crate::core::config::OcrConfig;
+use crate::core::runtime::spawn_blocking;
 use crate::ocr::processor::OcrProcessor;
 use crate::plugins::{OcrBackend, OcrBackendType, Plugin};
 use crate::types::ExtractionResult;
@@ -53,7 +54,7 @@ impl TesseractBackend {
     ///
     /// The public API types (crate::types) use i32 for compatibility with PyO3,
     /// while internal types (crate::ocr::types) use u8/u32 for efficiency.
-    fn convert_config(public_config: &crate::types::TesseractConfig) -> InternalTesseractConfig {
+    pub(crate) fn convert_config(public_config: &crate::types::TesseractConfig) -> InternalTesseractConfig {
         InternalTesseractConfig {
             language: public_config.language.clone(),
             psm: public_config.psm as u8,
@@ -130,7 +131,7 @@ impl OcrBackend for TesseractBackend {
         let processor = Arc::clone(&self.processor);
         let image_bytes = image_bytes.to_vec();
 
-        let ocr_result = tokio::task::spawn_blocking(move || processor.process_image(&image_bytes, &tess_config_clone))
+        let ocr_result = spawn_blocking(move || processor.process_image(&image_bytes, &tess_config_clone))
             .await
             .map_err(|e| crate::KreuzbergError::Plugin {
                 message: format!("Tesseract task panicked: {}", e),
@@ -174,6 +175,11 @@ impl OcrBackend for TesseractBackend {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -184,7 +190,7 @@ impl OcrBackend for TesseractBackend {
         let processor = Arc::clone(&self.processor);
         let path_str = path.to_string_lossy().to_string();
 
-        let ocr_result = tokio::task::spawn_blocking(move || processor.process_file(&path_str, &tess_config_clone))
+        let ocr_result = spawn_blocking(move || processor.process_file(&path_str, &tess_config_clone))
             .await
             .map_err(|e| crate::KreuzbergError::Plugin {
                 message: format!("Tesseract task panicked: {}", e),
@@ -228,6 +234,11 @@ impl OcrBackend for TesseractBackend {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         })
     }
 
@@ -393,10 +404,12 @@ mod tests {
             target_dpi: 600,
             auto_rotate: false,
             deskew: true,
+            grayscale: true,
             denoise: true,
             contrast_enhance: true,
             binarization_method: "adaptive".to_string(),
             invert_colors: false,
+            remove_borders: false,
         };
 
         let custom_tess_config = crate::types::TesseractConfig {
@@ -0,0 +1,210 @@
+//! Font and layout statistics for scanned-vs-digital heuristics.
+//!
+//! Born-digital PDFs draw their text with fonts, most of them embedded, and place images next
+//! to it. Scans are page-sized images, at most with an invisible OCR text layer on top. The
+//! numbers in [`PdfLayoutStats`] separate the two well enough for callers to build their own
+//! force-OCR policies instead of relying on the built-in text layer check.
+
+use super::pdfa;
+use lopdf::{Document, Object};
+use pdfium_render::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Font and layout statistics of a PDF, stored in `PdfMetadata::layout`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PdfLayoutStats {
+    /// Fonts defined in the file, sorted by name
+    #[serde(default)]
+    pub fonts: Vec<PdfFontInfo>,
+
+    /// Share of the area covered by text and image objects that is text, from 0.0 (images
+    /// only) to 1.0 (text only). None when the pages have neither.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_coverage: Option<f64>,
+
+    /// Average share of the page area covered by images, from 0.0 to 1.0
+    pub image_coverage: f64,
+
+    /// Average number of non-whitespace characters per page in the text layer
+    pub avg_glyphs_per_page: f64,
+
+    /// Number of pages without any text in the text layer
+    pub pages_without_text: usize,
+}
+
+/// A font defined in a PDF.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PdfFontInfo {
+    /// Base font name without the subset prefix (`ABCDEF+`)
+    pub name: String,
+    /// Whether the font program is embedded in the file
+    pub embedded: bool,
+}
+
+/// Collect the [`PdfLayoutStats`] of a document.
+///
+/// Page statistics come from the loaded document, the font list from the raw file.
+pub fn compute_layout_stats(document: &PdfDocument<'_>, pdf_bytes: &[u8]) -> PdfLayoutStats {
+    let mut stats = PdfLayoutStats {
+        fonts: document_fonts(pdf_bytes),
+        ..Default::default()
+    };
+
+    let mut page_count = 0usize;
+    let mut glyphs = 0usize;
+    let mut text_area = 0.0f64;
+    let mut image_area = 0.0f64;
+    let mut image_coverage = 0.0f64;
+    for page in document.pages().iter() {
+        page_count += 1;
+        let page_glyphs = page
+            .text()
+            .map(|text| text.all().chars().filter(|c| !c.is_whitespace()).count())
+            .unwrap_or(0);
+        if page_glyphs == 0 {
+            stats.pages_without_text += 1;
+        }
+        glyphs += page_glyphs;
+
+        let mut page_image_area = 0.0f64;
+        for object in page.objects().iter() {
+            let area = object_area(&object);
+            match object.object_type() {
+                PdfPageObjectType::Text => text_area += area,
+                PdfPageObjectType::Image => page_image_area += area,
+                _ => {}
+            }
+        }
+        image_area += page_image_area;
+
+        let page_area = page.width().value as f64 * page.height().value as f64;
+        if page_area > 0.0 {
+            image_coverage += (page_image_area / page_area).min(1.0);
+        }
+    }
+
+    if page_count > 0 {
+        stats.avg_glyphs_per_page = glyphs as f64 / page_count as f64;
+        stats.image_coverage = image_coverage / page_count as f64;
+    }
+    if text_area + image_area > 0.0 {
+        stats.text_coverage = Some(text_area / (text_area + image_area));
+    }
+    stats
+}
+
+fn object_area(object: &PdfPageObject<'_>) -> f64 {
+    match (object.width(), object.height()) {
+        (Ok(width), Ok(height)) => (width.value as f64 * height.value as f64).abs(),
+        _ => 0.0,
+    }
+}
+
+/// Fonts of all font dictionaries in the file, one entry per base font name. Composite (Type 0)
+/// fonts are listed through their descendant font.
+fn document_fonts(pdf_bytes: &[u8]) -> Vec<PdfFontInfo> {
+    let Ok(doc) = Document::load_mem(pdf_bytes) else {
+        return Vec::new();
+    };
+
+    let mut fonts = BTreeMap::new();
+    for object in doc.objects.values() {
+        let Object::Dictionary(dict) = object else {
+            continue;
+        };
+        let is_font = pdfa::name(dict, b"Type") == Some(b"Font".as_slice());
+        if !is_font || pdfa::name(dict, b"Subtype") == Some(b"Type0".as_slice()) {
+            continue;
+        }
+        let Some(base_font) = pdfa::name(dict, b"BaseFont") else {
+            continue;
+        };
+        let embedded = pdfa::is_embedded_font(&doc, dict);
+        let embedded_entry = fonts.entry(strip_subset_prefix(base_font)).or_insert(false);
+        *embedded_entry |= embedded;
+    }
+
+    fonts
+        .into_iter()
+        .map(|(name, embedded)| PdfFontInfo { name, embedded })
+        .collect()
+}
+
+/// `ABCDEF+Helvetica` -> `Helvetica`. Subset prefixes are exactly six uppercase letters.
+fn strip_subset_prefix(base_font: &[u8]) -> String {
+    let name = String::from_utf8_lossy(base_font);
+    match name.split_once('+') {
+        Some((prefix, rest)) if prefix.len() == 6 && prefix.bytes().all(|b| b.is_ascii_uppercase()) => rest.to_string(),
+        _ => name.into_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::{Stream, dictionary};
+
+    #[test]
+    fn test_strip_subset_prefix() {
+        assert_eq!(strip_subset_prefix(b"ABCDEF+Helvetica"), "Helvetica");
+        assert_eq!(strip_subset_prefix(b"Helvetica"), "Helvetica");
+        assert_eq!(strip_subset_prefix(b"Abc+Sans"), "Abc+Sans");
+    }
+
+    #[test]
+    fn test_document_fonts() {
+        let mut doc = Document::with_version("1.7");
+        let font_file = doc.add_object(Stream::new(dictionary! {}, b"font program".to_vec()));
+        let descriptor = doc.add_object(dictionary! {
+            "Type" => "FontDescriptor",
+            "FontName" => "ABCDEF+Garamond",
+            "FontFile2" => font_file,
+        });
+        let garamond = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "TrueType",
+            "BaseFont" => "ABCDEF+Garamond",
+            "FontDescriptor" => descriptor,
+        });
+        let helvetica = doc.add_object(dictionary! {
+            "Type" => "Font",
+            "Subtype" => "Type1",
+            "BaseFont" => "Helvetica",
+        });
+        let pages_id = doc.new_object_id();
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+            "Resources" => dictionary! { "Font" => dictionary! { "F0" => garamond, "F1" => helvetica } },
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![page_id.into()],
+                "Count" => 1,
+            }),
+        );
+        let catalog_id = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+        doc.trailer.set("Root", catalog_id);
+        let mut pdf_bytes = Vec::new();
+        doc.save_to(&mut pdf_bytes).unwrap();
+
+        let fonts = document_fonts(&pdf_bytes);
+        assert_eq!(
+            fonts,
+            vec![
+                PdfFontInfo {
+                    name: "Garamond".to_string(),
+                    embedded: true,
+                },
+                PdfFontInfo {
+                    name: "Helvetica".to_string(),
+                    embedded: false,
+                },
+            ]
+        );
+    }
+}
@@ -1,4 +1,6 @@
 use super::error::{PdfError, Result};
+use super::layout::PdfLayoutStats;
+use super::pdfa::PdfAReport;
 use crate::types::{PageBoundary, PageInfo, PageStructure, PageUnitType};
 use pdfium_render::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -29,6 +31,14 @@ pub struct PdfMetadata {
     /// First page height in points (1/72 inch)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub height: Option<i64>,
+
+    /// PDF/A conformance report, present when `PdfConfig::validate_pdfa` is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pdfa: Option<PdfAReport>,
+
+    /// Font and layout statistics, present when `PdfConfig::layout_stats` is enabled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layout: Option<PdfLayoutStats>,
 }
 
 /// Complete PDF extraction metadata including common and PDF-specific fields.
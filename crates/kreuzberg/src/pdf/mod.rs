@@ -42,8 +42,12 @@ pub mod error;
 #[cfg(feature = "pdf")]
 pub mod images;
 #[cfg(feature = "pdf")]
+pub mod layout;
+#[cfg(feature = "pdf")]
 pub mod metadata;
 #[cfg(feature = "pdf")]
+pub mod pdfa;
+#[cfg(feature = "pdf")]
 pub mod rendering;
 #[cfg(feature = "pdf")]
 pub mod table;
@@ -57,9 +61,13 @@ pub use error::PdfError;
 #[cfg(feature = "pdf")]
 pub use images::{PdfImage, PdfImageExtractor, extract_images_from_pdf};
 #[cfg(feature = "pdf")]
+pub use layout::{PdfFontInfo, PdfLayoutStats, compute_layout_stats};
+#[cfg(feature = "pdf")]
 pub use metadata::extract_metadata;
 #[cfg(feature = "pdf")]
-pub use rendering::{PageRenderOptions, render_page_to_image};
+pub use pdfa::{PdfAReport, PdfAViolation, validate_pdfa};
+#[cfg(feature = "pdf")]
+pub use rendering::{PageRenderOptions, RenderedPage, render_page_to_image};
 #[cfg(feature = "pdf")]
 pub use table::extract_words_from_page;
 #[cfg(feature = "pdf")]
@@ -0,0 +1,347 @@
+//! PDF/A conformance checks for archival ingest.
+//!
+//! [`validate_pdfa`] reads the PDF/A identification from the XMP metadata and checks the
+//! requirements archival pipelines most often trip over: encryption, file identifiers, XMP
+//! metadata, output intents, font embedding, forbidden actions (JavaScript, Launch, ...), LZW
+//! compression, and for PDF/A-1 transparency and embedded files. It is a fast structural
+//! check, not a replacement for a full validator such as veraPDF; a file that passes may still
+//! violate rules that need content stream or color analysis.
+
+use lopdf::{Dictionary, Document, Object};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// Actions PDF/A forbids in every part.
+const FORBIDDEN_ACTIONS: &[&[u8]] = &[
+    b"JavaScript",
+    b"Launch",
+    b"Sound",
+    b"Movie",
+    b"ResetForm",
+    b"ImportData",
+];
+
+/// Result of [`validate_pdfa`], stored in `PdfMetadata::pdfa`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PdfAReport {
+    /// PDF/A part declared in the XMP metadata (`pdfaid:part`), None when the file claims none
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part: Option<u32>,
+
+    /// Conformance level declared with the part (`pdfaid:conformance`, e.g. "B"), if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conformance: Option<String>,
+
+    /// Whether the file claims PDF/A conformance and no violation was found
+    pub conformant: bool,
+
+    /// Requirements the file does not meet
+    #[serde(default)]
+    pub violations: Vec<PdfAViolation>,
+}
+
+/// A PDF/A requirement the file does not meet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PdfAViolation {
+    /// Stable identifier of the check (e.g. "fonts.embedded", "actions.forbidden")
+    pub rule: String,
+    /// What was found
+    pub message: String,
+}
+
+/// Check a PDF against the PDF/A requirements listed in the [module docs](self).
+///
+/// Part-specific rules use the part the file declares. A file that cannot be parsed is
+/// reported as non-conformant with a `document.structure` violation.
+pub fn validate_pdfa(pdf_bytes: &[u8]) -> PdfAReport {
+    let doc = match Document::load_mem(pdf_bytes) {
+        Ok(doc) => doc,
+        Err(e) => {
+            let message = format!("Failed to parse the PDF: {}", e);
+            return PdfAReport {
+                violations: vec![violation("document.structure", message)],
+                ..Default::default()
+            };
+        }
+    };
+
+    let mut report = PdfAReport::default();
+    let catalog = doc.catalog().ok();
+    let xmp = catalog.and_then(|catalog| xmp_metadata(&doc, catalog));
+    if let Some(xmp) = &xmp {
+        report.part = xmp_value(xmp, "pdfaid:part").and_then(|part| part.parse().ok());
+        report.conformance = xmp_value(xmp, "pdfaid:conformance");
+    }
+
+    let violations = &mut report.violations;
+    if report.part.is_none() {
+        violations.push(violation(
+            "identification",
+            "No PDF/A identification (pdfaid:part) in the XMP metadata",
+        ));
+    }
+    if xmp.is_none() {
+        violations.push(violation("metadata.xmp", "The catalog has no XMP metadata stream"));
+    }
+    if doc.is_encrypted() || doc.trailer.has(b"Encrypt") {
+        violations.push(violation("encryption", "The file is encrypted"));
+    }
+    if !doc.trailer.has(b"ID") {
+        violations.push(violation("trailer.id", "The trailer has no file identifier (ID)"));
+    }
+    let needs_output_intent = report.part.is_none_or(|part| part <= 3);
+    if needs_output_intent && !catalog.is_some_and(|catalog| has_pdfa_output_intent(&doc, catalog)) {
+        violations.push(violation("output_intent", "No GTS_PDFA1 output intent"));
+    }
+
+    let mut unembedded_fonts = BTreeSet::new();
+    let mut actions = BTreeSet::new();
+    let mut lzw = false;
+    let mut transparency = false;
+    for object in doc.objects.values() {
+        let dict = match object {
+            Object::Dictionary(dict) => dict,
+            Object::Stream(stream) => &stream.dict,
+            _ => continue,
+        };
+
+        if name(dict, b"Type") == Some(b"Font".as_slice()) && !is_embedded_font(&doc, dict) {
+            let font = name(dict, b"BaseFont").unwrap_or(b"(unnamed)");
+            unembedded_fonts.insert(String::from_utf8_lossy(font).into_owned());
+        }
+        if let Some(action) = name(dict, b"S").filter(|action| FORBIDDEN_ACTIONS.contains(action)) {
+            actions.insert(String::from_utf8_lossy(action).into_owned());
+        }
+        if dict.has(b"JS") {
+            actions.insert("JavaScript".to_string());
+        }
+        lzw |= uses_lzw(dict);
+        transparency |= uses_transparency(dict);
+    }
+
+    if !unembedded_fonts.is_empty() {
+        let fonts = unembedded_fonts.into_iter().collect::<Vec<_>>().join(", ");
+        violations.push(violation("fonts.embedded", format!("Fonts not embedded: {}", fonts)));
+    }
+    if !actions.is_empty() {
+        let names = actions.into_iter().collect::<Vec<_>>().join(", ");
+        violations.push(violation("actions.forbidden", format!("Forbidden actions: {}", names)));
+    }
+    if lzw {
+        violations.push(violation("compression.lzw", "Streams use LZW compression"));
+    }
+    if report.part == Some(1) {
+        if transparency {
+            violations.push(violation("transparency", "PDF/A-1 does not allow transparency"));
+        }
+        if catalog.is_some_and(|catalog| has_embedded_files(&doc, catalog)) {
+            violations.push(violation("embedded_files", "PDF/A-1 does not allow embedded files"));
+        }
+    }
+
+    report.conformant = report.part.is_some() && report.violations.is_empty();
+    report
+}
+
+fn violation(rule: &str, message: impl Into<String>) -> PdfAViolation {
+    PdfAViolation {
+        rule: rule.to_string(),
+        message: message.into(),
+    }
+}
+
+pub(super) fn name<'a>(dict: &'a Dictionary, key: &[u8]) -> Option<&'a [u8]> {
+    dict.get(key).ok()?.as_name().ok()
+}
+
+/// The value of `key` in `dict`, following a reference.
+fn resolve<'a>(doc: &'a Document, dict: &'a Dictionary, key: &[u8]) -> Option<&'a Object> {
+    let (_, object) = doc.dereference(dict.get(key).ok()?).ok()?;
+    Some(object)
+}
+
+fn resolve_dict<'a>(doc: &'a Document, dict: &'a Dictionary, key: &[u8]) -> Option<&'a Dictionary> {
+    resolve(doc, dict, key)?.as_dict().ok()
+}
+
+fn xmp_metadata(doc: &Document, catalog: &Dictionary) -> Option<String> {
+    let stream = resolve(doc, catalog, b"Metadata")?.as_stream().ok()?;
+    let data = stream.decompressed_content().unwrap_or_else(|_| stream.content.clone());
+    Some(String::from_utf8_lossy(&data).into_owned())
+}
+
+/// The value of an XMP property written as an attribute (`key="value"`) or element
+/// (`<key>value</key>`).
+fn xmp_value(xmp: &str, key: &str) -> Option<String> {
+    let rest = xmp[xmp.find(key)? + key.len()..].trim_start();
+    let value = if let Some(rest) = rest.strip_prefix('=') {
+        let rest = rest.trim_start();
+        let quote = rest.chars().next().filter(|c| matches!(c, '"' | '\''))?;
+        let rest = &rest[1..];
+        &rest[..rest.find(quote)?]
+    } else {
+        let rest = rest.strip_prefix('>')?;
+        &rest[..rest.find('<')?]
+    };
+    Some(value.trim().to_string()).filter(|value| !value.is_empty())
+}
+
+fn has_pdfa_output_intent(doc: &Document, catalog: &Dictionary) -> bool {
+    let Some(Ok(intents)) = resolve(doc, catalog, b"OutputIntents").map(Object::as_array) else {
+        return false;
+    };
+    intents.iter().any(|intent| {
+        doc.dereference(intent)
+            .ok()
+            .and_then(|(_, intent)| intent.as_dict().ok())
+            .is_some_and(|intent| name(intent, b"S") == Some(b"GTS_PDFA1".as_slice()))
+    })
+}
+
+/// Whether a font is embedded. Type 3 fonts are defined in the file and composite (Type 0)
+/// fonts are checked through their descendant font.
+pub(super) fn is_embedded_font(doc: &Document, font: &Dictionary) -> bool {
+    if matches!(name(font, b"Subtype"), Some(b"Type3" | b"Type0")) {
+        return true;
+    }
+    resolve_dict(doc, font, b"FontDescriptor").is_some_and(|descriptor| {
+        descriptor.has(b"FontFile") || descriptor.has(b"FontFile2") || descriptor.has(b"FontFile3")
+    })
+}
+
+fn uses_lzw(dict: &Dictionary) -> bool {
+    let is_lzw = |filter: &Object| matches!(filter.as_name(), Ok(b"LZWDecode"));
+    match dict.get(b"Filter") {
+        Ok(Object::Array(filters)) => filters.iter().any(is_lzw),
+        Ok(filter) => is_lzw(filter),
+        Err(_) => false,
+    }
+}
+
+/// Soft masks, constant alpha below 1 and transparency groups, all forbidden in PDF/A-1.
+fn uses_transparency(dict: &Dictionary) -> bool {
+    let soft_mask = dict
+        .get(b"SMask")
+        .is_ok_and(|mask| !matches!(mask.as_name(), Ok(b"None")));
+    let translucent = |key: &[u8]| dict.get(key).and_then(Object::as_float).is_ok_and(|ca| ca < 1.0);
+    let alpha = name(dict, b"Type") == Some(b"ExtGState".as_slice()) && (translucent(b"CA") || translucent(b"ca"));
+    let group = name(dict, b"S") == Some(b"Transparency".as_slice());
+    soft_mask || alpha || group
+}
+
+fn has_embedded_files(doc: &Document, catalog: &Dictionary) -> bool {
+    resolve_dict(doc, catalog, b"Names").is_some_and(|names| names.has(b"EmbeddedFiles"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lopdf::{Stream, dictionary};
+
+    const XMP: &str = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/">
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+<rdf:Description rdf:about="" xmlns:pdfaid="http://www.aiim.org/pdfa/ns/id/" pdfaid:part="2">
+<pdfaid:conformance>B</pdfaid:conformance></rdf:Description></rdf:RDF></x:xmpmeta>"#;
+
+    /// A one-page PDF; `font` adds a text font to the page resources.
+    fn build_pdf(xmp: Option<&str>, font: Option<Dictionary>, action: Option<Dictionary>) -> Vec<u8> {
+        let mut doc = Document::with_version("1.7");
+        let pages_id = doc.new_object_id();
+        let mut resources = Dictionary::new();
+        if let Some(font) = font {
+            let font_id = doc.add_object(font);
+            resources.set("Font", dictionary! { "F0" => font_id });
+        }
+        let page_id = doc.add_object(dictionary! {
+            "Type" => "Page",
+            "Parent" => pages_id,
+            "MediaBox" => vec![0.into(), 0.into(), 595.into(), 842.into()],
+            "Resources" => resources,
+        });
+        doc.objects.insert(
+            pages_id,
+            Object::Dictionary(dictionary! {
+                "Type" => "Pages",
+                "Kids" => vec![page_id.into()],
+                "Count" => 1,
+            }),
+        );
+
+        let output_intent_id = doc.add_object(dictionary! {
+            "Type" => "OutputIntent",
+            "S" => "GTS_PDFA1",
+            "OutputConditionIdentifier" => Object::string_literal("sRGB"),
+        });
+        let mut catalog = dictionary! {
+            "Type" => "Catalog",
+            "Pages" => pages_id,
+            "OutputIntents" => vec![output_intent_id.into()],
+        };
+        if let Some(xmp) = xmp {
+            let metadata = Stream::new(dictionary! { "Type" => "Metadata", "Subtype" => "XML" }, xmp.into());
+            catalog.set("Metadata", doc.add_object(metadata));
+        }
+        if let Some(action) = action {
+            catalog.set("OpenAction", doc.add_object(action));
+        }
+        let catalog_id = doc.add_object(catalog);
+        doc.trailer.set("Root", catalog_id);
+        doc.trailer.set(
+            "ID",
+            vec![Object::string_literal("kreuzberg"), Object::string_literal("kreuzberg")],
+        );
+
+        let mut output = Vec::new();
+        doc.save_to(&mut output).unwrap();
+        output
+    }
+
+    fn rules(report: &PdfAReport) -> Vec<&str> {
+        report.violations.iter().map(|v| v.rule.as_str()).collect()
+    }
+
+    #[test]
+    fn test_conformant_pdfa() {
+        let report = validate_pdfa(&build_pdf(Some(XMP), None, None));
+        assert_eq!(report.part, Some(2));
+        assert_eq!(report.conformance.as_deref(), Some("B"));
+        assert!(report.conformant, "unexpected violations: {:?}", report.violations);
+    }
+
+    #[test]
+    fn test_violations_are_reported() {
+        let font = dictionary! { "Type" => "Font", "Subtype" => "Type1", "BaseFont" => "Helvetica" };
+        let action = dictionary! { "S" => "JavaScript", "JS" => Object::string_literal("app.alert(1)") };
+        let report = validate_pdfa(&build_pdf(Some(XMP), Some(font), Some(action)));
+
+        assert!(!report.conformant);
+        assert_eq!(rules(&report), vec!["fonts.embedded", "actions.forbidden"]);
+        assert!(report.violations[0].message.contains("Helvetica"));
+        assert_eq!(report.violations[1].message, "Forbidden actions: JavaScript");
+    }
+
+    #[test]
+    fn test_missing_identification() {
+        let report = validate_pdfa(&build_pdf(None, None, None));
+        assert!(!report.conformant);
+        assert_eq!(rules(&report), vec!["identification", "metadata.xmp"]);
+    }
+
+    #[test]
+    fn test_unparseable_pdf() {
+        let report = validate_pdfa(b"not a pdf");
+        assert!(!report.conformant);
+        assert_eq!(rules(&report), vec!["document.structure"]);
+    }
+
+    #[test]
+    fn test_xmp_value_forms() {
+        assert_eq!(xmp_value(XMP, "pdfaid:part").as_deref(), Some("2"));
+        assert_eq!(xmp_value(XMP, "pdfaid:conformance").as_deref(), Some("B"));
+        assert_eq!(
+            xmp_value("<pdfaid:part>1</pdfaid:part>", "pdfaid:part").as_deref(),
+            Some("1")
+        );
+        assert_eq!(xmp_value("<x/>", "pdfaid:part"), None);
+    }
+}
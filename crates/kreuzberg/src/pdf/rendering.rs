@@ -26,6 +26,18 @@ impl Default for PageRenderOptions {
     }
 }
 
+/// A PDF page rendered to an image, with the resolution it was rendered at.
+#[derive(Debug, Clone)]
+pub struct RenderedPage {
+    pub image: DynamicImage,
+    /// Resolution of `image` in dots per inch
+    pub dpi: i32,
+    /// Page width in PDF points (1/72 inch)
+    pub width_points: f64,
+    /// Page height in PDF points (1/72 inch)
+    pub height_points: f64,
+}
+
 pub struct PdfRenderer {
     pdfium: Pdfium,
 }
@@ -55,6 +67,18 @@ impl PdfRenderer {
         options: &PageRenderOptions,
         password: Option<&str>,
     ) -> Result<DynamicImage> {
+        self.render_page_with_password(pdf_bytes, page_index, options, password)
+            .map(|page| page.image)
+    }
+
+    /// Render a page, keeping the DPI it was rendered at and its physical size.
+    pub fn render_page_with_password(
+        &self,
+        pdf_bytes: &[u8],
+        page_index: usize,
+        options: &PageRenderOptions,
+        password: Option<&str>,
+    ) -> Result<RenderedPage> {
         let document = self.pdfium.load_pdf_from_byte_slice(pdf_bytes, password).map_err(|e| {
             let err_msg = e.to_string();
             if (err_msg.contains("password") || err_msg.contains("Password")) && password.is_some() {
@@ -100,10 +124,15 @@ impl PdfRenderer {
 
         let image = bitmap.as_image().into_rgb8();
 
-        Ok(DynamicImage::ImageRgb8(image))
+        Ok(RenderedPage {
+            image: DynamicImage::ImageRgb8(image),
+            dpi,
+            width_points: f64::from(width_points),
+            height_points: f64::from(height_points),
+        })
     }
 
-    pub fn render_all_pages(&self, pdf_bytes: &[u8], options: &PageRenderOptions) -> Result<Vec<DynamicImage>> {
+    pub fn render_all_pages(&self, pdf_bytes: &[u8], options: &PageRenderOptions) -> Result<Vec<RenderedPage>> {
         self.render_all_pages_with_password(pdf_bytes, options, None)
     }
 
@@ -112,7 +141,7 @@ impl PdfRenderer {
         pdf_bytes: &[u8],
         options: &PageRenderOptions,
         password: Option<&str>,
-    ) -> Result<Vec<DynamicImage>> {
+    ) -> Result<Vec<RenderedPage>> {
         let document = self.pdfium.load_pdf_from_byte_slice(pdf_bytes, password).map_err(|e| {
             let err_msg = e.to_string();
             if (err_msg.contains("password") || err_msg.contains("Password")) && password.is_some() {
@@ -125,14 +154,14 @@ impl PdfRenderer {
         })?;
 
         let page_count = document.pages().len() as usize;
-        let mut images = Vec::with_capacity(page_count);
+        let mut pages = Vec::with_capacity(page_count);
 
         for page_index in 0..page_count {
-            let image = self.render_page_to_image_with_password(pdf_bytes, page_index, options, password)?;
-            images.push(image);
+            let page = self.render_page_with_password(pdf_bytes, page_index, options, password)?;
+            pages.push(page);
         }
 
-        Ok(images)
+        Ok(pages)
     }
 }
 
@@ -0,0 +1,249 @@
+//! Plugin capability introspection.
+//!
+//! This module provides a machine-readable description of every registered plugin,
+//! used by the CLI (`kreuzberg plugins list`), the API (`GET /plugins`) and the MCP
+//! server to debug which plugin handles a given document.
+
+use crate::plugins::registry::PluginRegistries;
+use crate::plugins::{Plugin, ProcessingStage};
+use crate::{KreuzbergError, Result};
+use serde::{Deserialize, Serialize};
+
+/// Kind of registered plugin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginKind {
+    /// Document extractor plugin
+    DocumentExtractor,
+    /// OCR backend plugin
+    OcrBackend,
+    /// Post-processor plugin
+    PostProcessor,
+    /// Validator plugin
+    Validator,
+    /// Image describer plugin
+    ImageDescriber,
+}
+
+impl PluginKind {
+    /// Serialized name of the plugin kind (e.g. `"document_extractor"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PluginKind::DocumentExtractor => "document_extractor",
+            PluginKind::OcrBackend => "ocr_backend",
+            PluginKind::PostProcessor => "post_processor",
+            PluginKind::Validator => "validator",
+            PluginKind::ImageDescriber => "image_describer",
+        }
+    }
+}
+
+/// Machine-readable description of a registered plugin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginInfo {
+    /// Plugin kind
+    pub kind: PluginKind,
+
+    /// Unique plugin name
+    pub name: String,
+
+    /// Plugin version
+    pub version: String,
+
+    /// Optional plugin description
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+
+    /// Optional plugin author
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub author: String,
+
+    /// MIME types handled (document extractors only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub mime_types: Vec<String>,
+
+    /// Registered priority (extractors, post-processors, validators)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
+
+    /// Processing stage (post-processors only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stage: Option<ProcessingStage>,
+
+    /// Post-processors this one must run after (post-processors only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub run_after: Vec<String>,
+
+    /// Post-processors this one must run before (post-processors only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub run_before: Vec<String>,
+
+    /// Supported languages (OCR backends only)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub languages: Vec<String>,
+}
+
+impl PluginInfo {
+    pub(crate) fn new<P: Plugin + ?Sized>(kind: PluginKind, plugin: &P) -> Self {
+        Self {
+            kind,
+            name: plugin.name().to_string(),
+            version: plugin.version(),
+            description: plugin.description().to_string(),
+            author: plugin.author().to_string(),
+            mime_types: Vec::new(),
+            priority: None,
+            stage: None,
+            run_after: Vec::new(),
+            run_before: Vec::new(),
+            languages: Vec::new(),
+        }
+    }
+}
+
+/// Describe every registered plugin.
+///
+/// Built-in extractors and post-processors are initialized first so the output reflects
+/// what an extraction would actually use. Results are sorted by kind, then name.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Other` if a registry lock is poisoned.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::{PluginKind, describe};
+///
+/// # fn main() -> kreuzberg::Result<()> {
+/// for plugin in describe()? {
+///     if plugin.kind == PluginKind::DocumentExtractor {
+///         println!("{} (priority {:?}): {:?}", plugin.name, plugin.priority, plugin.mime_types);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn describe() -> Result<Vec<PluginInfo>> {
+    describe_registries(&PluginRegistries::global())
+}
+
+/// Describe the plugins of `registries`, like [`describe`] does for the global registries.
+pub(crate) fn describe_registries(registries: &PluginRegistries) -> Result<Vec<PluginInfo>> {
+    crate::extractors::ensure_initialized()?;
+
+    #[cfg(any(feature = "keywords-yake", feature = "keywords-rake"))]
+    {
+        let _ = crate::keywords::ensure_initialized();
+    }
+
+    #[cfg(feature = "language-detection")]
+    {
+        let _ = crate::language_detection::ensure_initialized();
+    }
+
+    #[cfg(feature = "chunking")]
+    {
+        let _ = crate::chunking::ensure_initialized();
+    }
+
+    let mut plugins = Vec::new();
+
+    {
+        let registry = registries
+            .document_extractors
+            .read()
+            .map_err(|e| KreuzbergError::Other(format!("Document extractor registry lock poisoned: {}", e)))?;
+        plugins.extend(registry.describe());
+    }
+
+    {
+        let registry = registries
+            .ocr_backends
+            .read()
+            .map_err(|e| KreuzbergError::Other(format!("OCR backend registry lock poisoned: {}", e)))?;
+        plugins.extend(registry.describe());
+    }
+
+    {
+        let registry = registries
+            .post_processors
+            .read()
+            .map_err(|e| KreuzbergError::Other(format!("Post-processor registry lock poisoned: {}", e)))?;
+        plugins.extend(registry.describe());
+    }
+
+    {
+        let registry = registries
+            .validators
+            .read()
+            .map_err(|e| KreuzbergError::Other(format!("Validator registry lock poisoned: {}", e)))?;
+        plugins.extend(registry.describe());
+    }
+
+    {
+        let registry = registries
+            .image_describers
+            .read()
+            .map_err(|e| KreuzbergError::Other(format!("Image describer registry lock poisoned: {}", e)))?;
+        plugins.extend(registry.describe());
+    }
+
+    plugins.sort_by(|a, b| a.kind.cmp(&b.kind).then_with(|| a.name.cmp(&b.name)));
+
+    Ok(plugins)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_includes_builtin_extractors() {
+        let plugins = describe().unwrap();
+
+        let text_extractor = plugins
+            .iter()
+            .find(|p| p.kind == PluginKind::DocumentExtractor && p.name == "plain-text-extractor")
+            .expect("plain text extractor should be registered");
+
+        assert!(text_extractor.mime_types.iter().any(|m| m == "text/plain"));
+        assert!(text_extractor.priority.is_some());
+        assert!(text_extractor.stage.is_none());
+    }
+
+    #[test]
+    fn test_describe_is_sorted() {
+        let plugins = describe().unwrap();
+
+        for pair in plugins.windows(2) {
+            assert!((pair[0].kind, &pair[0].name) <= (pair[1].kind, &pair[1].name));
+        }
+    }
+
+    #[test]
+    fn test_plugin_info_serialization() {
+        let info = PluginInfo {
+            kind: PluginKind::PostProcessor,
+            name: "quality".to_string(),
+            version: "1.0.0".to_string(),
+            description: String::new(),
+            author: String::new(),
+            mime_types: Vec::new(),
+            priority: Some(30),
+            stage: Some(ProcessingStage::Early),
+            run_after: vec!["text-normalization".to_string()],
+            run_before: Vec::new(),
+            languages: Vec::new(),
+        };
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["kind"], "post_processor");
+        assert_eq!(json["stage"], "early");
+        assert_eq!(json["priority"], 30);
+        assert_eq!(json["run_after"], serde_json::json!(["text-normalization"]));
+        assert!(json.get("run_before").is_none());
+        assert!(json.get("mime_types").is_none());
+        assert!(json.get("description").is_none());
+    }
+}
@@ -65,6 +65,11 @@ use crate::KreuzbergError;
 ///             chunks: None,
 ///             images: None,
 ///             pages: None,
+///             entities: None,
+///             keywords: None,
+///             children: None,
+///             fields: None,
+///             warnings: Vec::new(),
 ///         })
 ///     }
 ///
@@ -144,6 +149,11 @@ pub trait DocumentExtractor: Plugin {
     ///         chunks: None,
     ///         images: None,
     ///         pages: None,
+    ///         entities: None,
+    ///         keywords: None,
+    ///         children: None,
+    ///         fields: None,
+    ///         warnings: Vec::new(),
     ///     })
     /// }
     /// # }
@@ -157,7 +167,8 @@ pub trait DocumentExtractor: Plugin {
 
     /// Extract content from a file.
     ///
-    /// Default implementation reads the file and calls `extract_bytes`.
+    /// Default implementation loads the file via [`load_file_async`](crate::core::io::load_file_async)
+    /// (memory-mapped for large files when the `mmap` feature is enabled) and calls `extract_bytes`.
     /// Override for custom file handling, streaming, or memory optimizations.
     ///
     /// # Arguments
@@ -215,6 +226,11 @@ pub trait DocumentExtractor: Plugin {
     ///         chunks: None,
     ///         images: None,
     ///         pages: None,
+    ///         entities: None,
+    ///         keywords: None,
+    ///         children: None,
+    ///         fields: None,
+    ///         warnings: Vec::new(),
     ///     })
     /// }
     /// # }
@@ -223,7 +239,7 @@ pub trait DocumentExtractor: Plugin {
         #[cfg(feature = "tokio-runtime")]
         {
             use crate::core::io;
-            let bytes = io::read_file_async(path).await?;
+            let bytes = io::load_file_async(path).await?;
             self.extract_bytes(&bytes, mime_type, config).await
         }
         #[cfg(not(feature = "tokio-runtime"))]
@@ -439,6 +455,11 @@ pub trait DocumentExtractor: Plugin {
 ///             chunks: None,
 ///             images: None,
 ///             pages: None,
+///             entities: None,
+///             keywords: None,
+///             children: None,
+///             fields: None,
+///             warnings: Vec::new(),
 ///         })
 ///     }
 ///
@@ -605,6 +626,11 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             })
         }
 
@@ -778,6 +804,11 @@ mod tests {
                     chunks: None,
                     images: None,
                     pages: None,
+                    entities: None,
+                    keywords: None,
+                    children: None,
+                    fields: None,
+                    warnings: Vec::new(),
                 })
             }
 
@@ -983,6 +1014,11 @@ mod tests {
                     chunks: None,
                     images: None,
                     pages: None,
+                    entities: None,
+                    keywords: None,
+                    children: None,
+                    fields: None,
+                    warnings: Vec::new(),
                 })
             }
 
@@ -1029,6 +1065,11 @@ mod tests {
                     chunks: None,
                     images: None,
                     pages: None,
+                    entities: None,
+                    keywords: None,
+                    children: None,
+                    fields: None,
+                    warnings: Vec::new(),
                 })
             }
 
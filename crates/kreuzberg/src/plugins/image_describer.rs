@@ -0,0 +1,436 @@
+//! Image describer plugin trait.
+//!
+//! This module defines the trait for plugins that caption extracted images, such as local
+//! vision-language models or cloud captioning APIs.
+
+use crate::core::config::ExtractionConfig;
+use crate::plugins::Plugin;
+use crate::plugins::registry::ImageDescriberRegistry;
+use crate::types::{ExtractedImage, ExtractionResult};
+use crate::{KreuzbergError, Result};
+use async_trait::async_trait;
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+
+static MARKDOWN_IMAGE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"!\[([^\]]*)\]\(([^)]*)\)").expect("Markdown image regex pattern is valid and should compile")
+});
+
+/// Context handed to an [`ImageDescriber`] together with the image bytes.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImageDescriptionContext {
+    /// MIME type of the document the image was extracted from
+    pub document_mime_type: String,
+
+    /// Image format (e.g., "jpeg", "png")
+    pub format: String,
+
+    /// Zero-indexed position of the image in the document
+    pub image_index: usize,
+
+    /// Page or slide the image is on (1-indexed), when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<usize>,
+
+    /// Image width in pixels, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+
+    /// Image height in pixels, when known
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+
+    /// Text recognized in the image, when it was OCRed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ocr_text: Option<String>,
+}
+
+/// Trait for image describer plugins.
+///
+/// An image describer produces a short description of an extracted image. When
+/// [`ImageExtractionConfig::describer`](crate::ImageExtractionConfig::describer) names a
+/// registered describer, every image in `ExtractionResult::images` without a description is
+/// passed to it and the output fills `ExtractedImage::description`.
+///
+/// # Thread Safety
+///
+/// Describers must be thread-safe (`Send + Sync`) to support concurrent extractions.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::{ImageDescriber, ImageDescriptionContext, Plugin};
+/// use kreuzberg::Result;
+/// use async_trait::async_trait;
+///
+/// struct FormatDescriber;
+///
+/// impl Plugin for FormatDescriber {
+///     fn name(&self) -> &str { "format-describer" }
+///     fn version(&self) -> String { "1.0.0".to_string() }
+///     fn initialize(&self) -> Result<()> { Ok(()) }
+///     fn shutdown(&self) -> Result<()> { Ok(()) }
+/// }
+///
+/// #[async_trait]
+/// impl ImageDescriber for FormatDescriber {
+///     async fn describe_image(&self, image: &[u8], context: &ImageDescriptionContext) -> Result<String> {
+///         Ok(format!("{} image ({} bytes)", context.format, image.len()))
+///     }
+/// }
+/// ```
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+pub trait ImageDescriber: Plugin {
+    /// Describe an image.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Raw image data (JPEG, PNG, etc.)
+    /// * `context` - Where the image came from and what is already known about it
+    ///
+    /// # Returns
+    ///
+    /// A description suitable as alt text or caption. Empty descriptions are ignored.
+    async fn describe_image(&self, image: &[u8], context: &ImageDescriptionContext) -> Result<String>;
+}
+
+/// Register an image describer with the global registry.
+///
+/// The describer is initialized and can then be selected by name through
+/// `ImageExtractionConfig::describer`.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::{ImageDescriber, ImageDescriptionContext, Plugin, register_image_describer};
+/// use kreuzberg::Result;
+/// use async_trait::async_trait;
+/// use std::sync::Arc;
+///
+/// struct Captioner;
+///
+/// impl Plugin for Captioner {
+///     fn name(&self) -> &str { "captioner" }
+///     fn version(&self) -> String { "1.0.0".to_string() }
+///     fn initialize(&self) -> Result<()> { Ok(()) }
+///     fn shutdown(&self) -> Result<()> { Ok(()) }
+/// }
+///
+/// #[async_trait]
+/// impl ImageDescriber for Captioner {
+///     async fn describe_image(&self, _: &[u8], _: &ImageDescriptionContext) -> Result<String> {
+///         Ok("A bar chart".to_string())
+///     }
+/// }
+///
+/// register_image_describer(Arc::new(Captioner))?;
+/// # Ok::<(), kreuzberg::KreuzbergError>(())
+/// ```
+pub fn register_image_describer(describer: Arc<dyn ImageDescriber>) -> crate::Result<()> {
+    use crate::plugins::registry::get_image_describer_registry;
+
+    let registry = get_image_describer_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let mut registry = registry
+        .write()
+        .expect("Image describer registry lock poisoned - critical runtime error");
+
+    registry.register(describer)
+}
+
+/// Unregister an image describer by name, calling its `shutdown()` method.
+///
+/// Succeeds if the describer did not exist.
+pub fn unregister_image_describer(name: &str) -> crate::Result<()> {
+    use crate::plugins::registry::get_image_describer_registry;
+
+    let registry = get_image_describer_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let mut registry = registry
+        .write()
+        .expect("Image describer registry lock poisoned - critical runtime error");
+
+    registry.remove(name)
+}
+
+/// List the names of all registered image describers.
+pub fn list_image_describers() -> crate::Result<Vec<String>> {
+    use crate::plugins::registry::get_image_describer_registry;
+
+    let registry = get_image_describer_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let registry = registry
+        .read()
+        .expect("Image describer registry lock poisoned - critical runtime error");
+
+    Ok(registry.list())
+}
+
+/// Remove all image describers from the global registry, calling their `shutdown()` methods.
+pub fn clear_image_describers() -> crate::Result<()> {
+    use crate::plugins::registry::get_image_describer_registry;
+
+    let registry = get_image_describer_registry();
+    // ~keep: Lock poisoning indicates a panic in another thread holding the lock.
+    let mut registry = registry
+        .write()
+        .expect("Image describer registry lock poisoned - critical runtime error");
+
+    registry.shutdown_all()
+}
+
+/// Describe the images of `result` with the describer named in `ImageExtractionConfig::describer`.
+///
+/// Images that already have a description are skipped. A describer failing on one image is
+/// logged and leaves that image undescribed; a describer that is not registered is an error.
+/// With `inline_descriptions`, the descriptions then become the alt text of the Markdown image
+/// references in `result.content`.
+pub(crate) async fn describe_images(
+    result: &mut ExtractionResult,
+    config: &ExtractionConfig,
+    registry: &RwLock<ImageDescriberRegistry>,
+) -> Result<()> {
+    let Some(image_config) = config.images.as_ref() else {
+        return Ok(());
+    };
+    let Some(describer_name) = image_config.describer.as_deref() else {
+        return Ok(());
+    };
+    let Some(images) = result.images.as_mut() else {
+        return Ok(());
+    };
+
+    let describer = {
+        let registry = registry
+            .read()
+            .map_err(|e| KreuzbergError::Other(format!("Image describer registry lock poisoned: {}", e)))?;
+        registry.get(describer_name)?
+    };
+
+    for image in images.iter_mut().filter(|image| image.description.is_none()) {
+        let context = ImageDescriptionContext {
+            document_mime_type: result.mime_type.clone(),
+            format: image.format.clone(),
+            image_index: image.image_index,
+            page_number: image.page_number,
+            width: image.width,
+            height: image.height,
+            ocr_text: image.ocr_result.as_ref().map(|ocr| ocr.content.clone()),
+        };
+
+        match describer.describe_image(&image.data, &context).await {
+            Ok(description) => {
+                let description = description.trim();
+                if !description.is_empty() {
+                    image.description = Some(description.to_string());
+                }
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Image describer '{}' failed on image {}: {}",
+                    describer_name,
+                    image.image_index,
+                    e
+                );
+            }
+        }
+    }
+
+    if image_config.inline_descriptions
+        && let Some(content) = inline_descriptions(&result.content, images)
+    {
+        result.content = content;
+    }
+
+    Ok(())
+}
+
+/// Replace the alt text of the Markdown image references in `content` with image descriptions.
+///
+/// References are paired with images in document order, so `None` is returned unless `content`
+/// holds exactly one reference per image.
+fn inline_descriptions(content: &str, images: &[ExtractedImage]) -> Option<String> {
+    let reference_count = MARKDOWN_IMAGE.find_iter(content).count();
+    if reference_count != images.len() {
+        tracing::debug!(
+            "Not inlining image descriptions: {} image references for {} images",
+            reference_count,
+            images.len()
+        );
+        return None;
+    }
+
+    let mut descriptions = images.iter().map(|image| image.description.as_deref());
+    let inlined = MARKDOWN_IMAGE.replace_all(content, |caps: &Captures| match descriptions.next().flatten() {
+        Some(description) => {
+            let alt_text = description.replace(['[', ']'], " ");
+            format!(
+                "![{}]({})",
+                alt_text.split_whitespace().collect::<Vec<_>>().join(" "),
+                &caps[2]
+            )
+        }
+        None => caps[0].to_string(),
+    });
+
+    Some(inlined.into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::config::ImageExtractionConfig;
+    use crate::types::Metadata;
+
+    struct MockDescriber;
+
+    impl Plugin for MockDescriber {
+        fn name(&self) -> &str {
+            "mock-describer"
+        }
+
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl ImageDescriber for MockDescriber {
+        async fn describe_image(&self, image: &[u8], context: &ImageDescriptionContext) -> Result<String> {
+            Ok(format!("{} image, {} bytes", context.format, image.len()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_image_describer_describe_image() {
+        let context = ImageDescriptionContext {
+            format: "png".to_string(),
+            ..Default::default()
+        };
+
+        let description = MockDescriber.describe_image(b"fake", &context).await.unwrap();
+        assert_eq!(description, "png image, 4 bytes");
+    }
+
+    #[test]
+    fn test_image_description_context_serialization_skips_unknowns() {
+        let context = ImageDescriptionContext {
+            document_mime_type: "application/pdf".to_string(),
+            format: "jpeg".to_string(),
+            image_index: 2,
+            page_number: Some(3),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&context).unwrap();
+        assert_eq!(json["page_number"], 3);
+        assert!(json.get("ocr_text").is_none());
+    }
+
+    fn describer_config(inline_descriptions: bool) -> ExtractionConfig {
+        let mut images: ImageExtractionConfig = serde_json::from_str("{}").unwrap();
+        images.describer = Some("mock-describer".to_string());
+        images.inline_descriptions = inline_descriptions;
+        ExtractionConfig {
+            images: Some(images),
+            ..Default::default()
+        }
+    }
+
+    fn result_with_images(content: &str, images: Vec<ExtractedImage>) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/markdown".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: Some(images),
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn image(data: &[u8], format: &str, image_index: usize) -> ExtractedImage {
+        ExtractedImage {
+            data: data.to_vec(),
+            format: format.to_string(),
+            image_index,
+            page_number: None,
+            width: None,
+            height: None,
+            dpi: None,
+            colorspace: None,
+            bits_per_component: None,
+            is_mask: false,
+            description: None,
+            ocr_result: None,
+        }
+    }
+
+    fn registry() -> RwLock<ImageDescriberRegistry> {
+        let mut registry = ImageDescriberRegistry::new();
+        registry.register(Arc::new(MockDescriber)).unwrap();
+        RwLock::new(registry)
+    }
+
+    #[tokio::test]
+    async fn test_describe_images_fills_descriptions_and_inlines_alt_text() {
+        let mut described = image(b"abc", "jpeg", 1);
+        described.description = Some("Company logo".to_string());
+        let images = vec![image(b"fake", "png", 0), described];
+        let mut result = result_with_images("Intro ![](a.png) and ![old](b.jpg)", images);
+
+        describe_images(&mut result, &describer_config(true), &registry())
+            .await
+            .unwrap();
+
+        let images = result.images.unwrap();
+        assert_eq!(images[0].description.as_deref(), Some("png image, 4 bytes"));
+        assert_eq!(images[1].description.as_deref(), Some("Company logo"));
+        assert_eq!(
+            result.content,
+            "Intro ![png image, 4 bytes](a.png) and ![Company logo](b.jpg)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_describe_images_leaves_content_when_references_do_not_match() {
+        let images = vec![image(b"fake", "png", 0)];
+        let mut result = result_with_images("No image references", images);
+
+        describe_images(&mut result, &describer_config(true), &registry())
+            .await
+            .unwrap();
+
+        assert_eq!(result.content, "No image references");
+        assert!(result.images.unwrap()[0].description.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_describe_images_unknown_describer_is_error() {
+        let mut result = result_with_images("", vec![]);
+        let registry = RwLock::new(ImageDescriberRegistry::new());
+
+        let err = describe_images(&mut result, &describer_config(false), &registry)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, KreuzbergError::Plugin { .. }));
+    }
+}
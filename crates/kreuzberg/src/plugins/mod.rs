@@ -10,6 +10,7 @@
 //! - [`DocumentExtractor`] - Document format extraction plugins
 //! - [`PostProcessor`] - Content post-processing plugins
 //! - [`Validator`] - Validation plugins
+//! - [`ImageDescriber`] - Image description (captioning) plugins
 //!
 //! # Language Support
 //!
@@ -48,6 +49,11 @@
 //! #             chunks: None,
 //! #             images: None,
 //! #             pages: None,
+//! #             entities: None,
+//! #             keywords: None,
+//! #             children: None,
+//! #             fields: None,
+//! #             warnings: Vec::new(),
 //! #         })
 //! #     }
 //! #     async fn extract_file(&self, _: &std::path::Path, _: &str, _: &kreuzberg::ExtractionConfig)
@@ -61,6 +67,11 @@
 //! #             chunks: None,
 //! #             images: None,
 //! #             pages: None,
+//! #             entities: None,
+//! #             keywords: None,
+//! #             children: None,
+//! #             fields: None,
+//! #             warnings: Vec::new(),
 //! #         })
 //! #     }
 //! #     fn supported_mime_types(&self) -> &[&str] { &[] }
@@ -123,6 +134,11 @@
 //!             chunks: None,
 //!             images: None,
 //!             pages: None,
+//!             entities: None,
+//!             keywords: None,
+//!             children: None,
+//!             fields: None,
+//!             warnings: Vec::new(),
 //!         })
 //!     }
 //!
@@ -196,17 +212,29 @@
 //! }
 //! ```
 
+mod describe;
 mod extractor;
+mod image_describer;
 mod ocr;
 mod processor;
 pub mod registry;
 mod traits;
 mod validator;
 
+#[cfg(any(feature = "api", feature = "mcp"))]
+pub(crate) use describe::describe_registries;
+pub use describe::{PluginInfo, PluginKind, describe};
 pub use extractor::{DocumentExtractor, clear_extractors, list_extractors, register_extractor, unregister_extractor};
+pub(crate) use image_describer::describe_images;
+pub use image_describer::{
+    ImageDescriber, ImageDescriptionContext, clear_image_describers, list_image_describers, register_image_describer,
+    unregister_image_describer,
+};
+#[cfg(any(feature = "ocr", feature = "office", feature = "html", feature = "email"))]
+pub(crate) use ocr::run_ocr;
 pub use ocr::{
     OcrBackend, OcrBackendType, clear_ocr_backends, list_ocr_backends, register_ocr_backend, unregister_ocr_backend,
 };
-pub use processor::{PostProcessor, ProcessingStage, list_post_processors};
+pub use processor::{PostProcessor, ProcessingStage, list_post_processors, post_processor_execution_plan};
 pub use traits::Plugin;
 pub use validator::{Validator, clear_validators, list_validators, register_validator, unregister_validator};
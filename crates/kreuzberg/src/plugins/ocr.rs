@@ -68,6 +68,11 @@ pub enum OcrBackendType {
 ///             chunks: None,
 ///             images: None,
 ///             pages: None,
+///             entities: None,
+///             keywords: None,
+///             children: None,
+///             fields: None,
+///             warnings: Vec::new(),
 ///         })
 ///     }
 ///
@@ -147,6 +152,11 @@ pub trait OcrBackend: Plugin {
     ///         chunks: None,
     ///         images: None,
     ///         pages: None,
+    ///         entities: None,
+    ///         keywords: None,
+    ///         children: None,
+    ///         fields: None,
+    ///         warnings: Vec::new(),
     ///     })
     /// }
     /// # }
@@ -170,7 +180,7 @@ pub trait OcrBackend: Plugin {
         #[cfg(feature = "tokio-runtime")]
         {
             use crate::core::io;
-            let bytes = io::read_file_async(path).await?;
+            let bytes = io::load_file_async(path).await?;
             self.process_image(&bytes, config).await
         }
         #[cfg(not(feature = "tokio-runtime"))]
@@ -320,6 +330,11 @@ pub trait OcrBackend: Plugin {
 ///             chunks: None,
 ///             images: None,
 ///             pages: None,
+///             entities: None,
+///             keywords: None,
+///             children: None,
+///             fields: None,
+///             warnings: Vec::new(),
 ///         })
 ///     }
 ///     fn supports_language(&self, _: &str) -> bool { true }
@@ -443,6 +458,42 @@ pub fn clear_ocr_backends() -> crate::Result<()> {
     registry.shutdown_all()
 }
 
+/// OCR an image with the backend selected by `config.ocr`.
+///
+/// This is the OCR step of image extraction, shared with the OCR of images embedded in
+/// other documents: the backend is resolved from `ocr_backends` and the call is retried
+/// according to `config.retry`. Backends cache their results themselves (Tesseract honours
+/// `tesseract_config.use_cache`).
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Parsing` if OCR is not configured, and the backend's error if it
+/// cannot be resolved or fails on every attempt.
+#[cfg(any(feature = "ocr", feature = "office", feature = "html", feature = "email"))]
+pub(crate) async fn run_ocr(
+    image_bytes: &[u8],
+    ocr_backends: &std::sync::RwLock<crate::plugins::registry::OcrBackendRegistry>,
+    config: &crate::core::config::ExtractionConfig,
+) -> Result<ExtractionResult> {
+    use crate::KreuzbergError;
+    use crate::core::retry::with_retry;
+
+    let ocr_config = config.ocr.as_ref().ok_or_else(|| KreuzbergError::Parsing {
+        message: "OCR config required for image OCR".to_string(),
+        source: None,
+    })?;
+
+    let backend = {
+        let registry = ocr_backends.read().map_err(|e| KreuzbergError::Plugin {
+            message: format!("Failed to acquire read lock on OCR backend registry: {}", e),
+            plugin_name: "ocr-registry".to_string(),
+        })?;
+        registry.get(&ocr_config.backend)?
+    };
+
+    with_retry(config, "ocr", || backend.process_image(image_bytes, ocr_config)).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -481,6 +532,11 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             })
         }
 
@@ -12,7 +12,8 @@ use async_trait::async_trait;
 ///
 /// Post-processors are executed in stage order (Early → Middle → Late).
 /// Use stages to control the order of post-processing operations.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ProcessingStage {
     /// Early stage - foundational processing.
     ///
@@ -42,6 +43,17 @@ pub enum ProcessingStage {
     Late,
 }
 
+impl ProcessingStage {
+    /// Lowercase stage name (e.g. `"early"`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProcessingStage::Early => "early",
+            ProcessingStage::Middle => "middle",
+            ProcessingStage::Late => "late",
+        }
+    }
+}
+
 /// Trait for post-processor plugins.
 ///
 /// Post-processors transform or enrich extraction results after the initial
@@ -59,7 +71,10 @@ pub enum ProcessingStage {
 /// 2. **Middle** - Keyword extraction, token reduction
 /// 3. **Late** - Custom hooks, final validation
 ///
-/// Within each stage, processors are executed in registration order.
+/// Within each stage, processors are executed by descending priority, then in registration
+/// order. Processors that must run after or before specific other processors declare that with
+/// [`run_after`](PostProcessor::run_after) and [`run_before`](PostProcessor::run_before), which
+/// take precedence over stages. [`post_processor_execution_plan`] shows the resulting order.
 ///
 /// # Error Handling
 ///
@@ -226,6 +241,50 @@ pub trait PostProcessor: Plugin {
     /// ```
     fn processing_stage(&self) -> ProcessingStage;
 
+    /// Optional: Names of post-processors this processor must run after.
+    ///
+    /// Ordering constraints take precedence over stage and priority, so a processor can hook in
+    /// between two others regardless of their stages. Names of processors that are not
+    /// registered are ignored. Defaults to no constraints.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use kreuzberg::plugins::{Plugin, PostProcessor, ProcessingStage};
+    /// # use kreuzberg::{Result, ExtractionResult, ExtractionConfig};
+    /// # use async_trait::async_trait;
+    /// # struct Redactor;
+    /// # impl Plugin for Redactor {
+    /// #     fn name(&self) -> &str { "redactor" }
+    /// #     fn version(&self) -> String { "1.0.0".to_string() }
+    /// #     fn initialize(&self) -> Result<()> { Ok(()) }
+    /// #     fn shutdown(&self) -> Result<()> { Ok(()) }
+    /// # }
+    /// # #[async_trait]
+    /// # impl PostProcessor for Redactor {
+    /// #     fn processing_stage(&self) -> ProcessingStage { ProcessingStage::Middle }
+    /// #     async fn process(&self, result: &mut ExtractionResult, _: &ExtractionConfig) -> Result<()> { Ok(()) }
+    /// /// Redact the cleaned-up text, before it is split into chunks
+    /// fn run_after(&self) -> Vec<String> {
+    ///     vec!["header-footer-removal".to_string()]
+    /// }
+    ///
+    /// fn run_before(&self) -> Vec<String> {
+    ///     vec!["text-chunking".to_string()]
+    /// }
+    /// # }
+    /// ```
+    fn run_after(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Optional: Names of post-processors this processor must run before.
+    ///
+    /// See [`run_after`](PostProcessor::run_after). Defaults to no constraints.
+    fn run_before(&self) -> Vec<String> {
+        Vec::new()
+    }
+
     /// Optional: Check if this processor should run for a given result.
     ///
     /// Allows conditional processing based on MIME type, metadata, or content.
@@ -317,6 +376,43 @@ pub fn list_post_processors() -> crate::Result<Vec<String>> {
     Ok(registry.list())
 }
 
+/// Names of the registered post-processors in the order the pipeline runs them.
+///
+/// The order follows stage and priority, adjusted to satisfy the ordering constraints the
+/// processors declare. Built-in post-processors are registered first, so the plan matches what
+/// an extraction runs. Useful to debug why a processor sees or misses the changes of another.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if the ordering constraints form a cycle, or
+/// `KreuzbergError::Other` if the registry lock is poisoned.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::post_processor_execution_plan;
+///
+/// # fn main() -> kreuzberg::Result<()> {
+/// for (position, name) in post_processor_execution_plan()?.iter().enumerate() {
+///     println!("{}. {}", position + 1, name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn post_processor_execution_plan() -> crate::Result<Vec<String>> {
+    use crate::plugins::registry::get_post_processor_registry;
+
+    crate::core::pipeline::ensure_builtin_post_processors();
+
+    let registry = get_post_processor_registry();
+    let registry = registry
+        .read()
+        .map_err(|e| crate::KreuzbergError::Other(format!("Post-processor registry lock poisoned: {}", e)))?;
+
+    let plan = registry.execution_plan()?;
+    Ok(plan.iter().map(|processor| processor.name().to_string()).collect())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -374,6 +470,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -424,6 +525,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -491,6 +597,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -521,6 +632,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -548,6 +664,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         assert_eq!(processor.estimated_duration_ms(&result), 0);
@@ -599,6 +720,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let txt_result = ExtractionResult {
@@ -610,6 +736,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         assert!(processor.should_process(&pdf_result, &config));
@@ -639,6 +770,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -4,11 +4,13 @@
 //! Each plugin type (OcrBackend, DocumentExtractor, etc.) has its own registry
 //! with type-safe registration and lookup.
 
-use crate::plugins::{DocumentExtractor, OcrBackend, PostProcessor, ProcessingStage, Validator};
+use crate::plugins::{
+    DocumentExtractor, ImageDescriber, OcrBackend, PluginInfo, PluginKind, PostProcessor, ProcessingStage, Validator,
+};
 use crate::{KreuzbergError, Result};
 use indexmap::IndexMap;
 use once_cell::sync::Lazy;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::{Arc, RwLock};
 
 /// Validate a plugin name before registration.
@@ -172,6 +174,18 @@ impl OcrBackendRegistry {
         self.backends.keys().cloned().collect()
     }
 
+    /// Describe all registered backends.
+    pub fn describe(&self) -> Vec<PluginInfo> {
+        self.backends
+            .values()
+            .map(|backend| {
+                let mut info = PluginInfo::new(PluginKind::OcrBackend, backend.as_ref());
+                info.languages = backend.supported_languages();
+                info
+            })
+            .collect()
+    }
+
     /// Remove a backend from the registry.
     ///
     /// Calls `shutdown()` on the backend before removing.
@@ -312,11 +326,89 @@ impl DocumentExtractorRegistry {
         Err(KreuzbergError::UnsupportedFormat(mime_type.to_string()))
     }
 
+    /// Get the highest priority extractor for a MIME type, applying priority overrides.
+    ///
+    /// `priority_override` is called with each candidate extractor's name and may return
+    /// a replacement priority (e.g. from `[extractors.<name>]` config sections). Exact MIME
+    /// matches are always preferred over wildcard matches, mirroring [`Self::get`].
+    ///
+    /// # Arguments
+    ///
+    /// * `mime_type` - MIME type to look up
+    /// * `priority_override` - Returns the overridden priority for an extractor name, if any
+    ///
+    /// # Returns
+    ///
+    /// The highest effective priority extractor, or an error if none found.
+    pub fn get_with_priority_override<F>(
+        &self,
+        mime_type: &str,
+        priority_override: F,
+    ) -> Result<Arc<dyn DocumentExtractor>>
+    where
+        F: Fn(&str) -> Option<i32>,
+    {
+        let effective_priority = |priority: i32, extractor: &Arc<dyn DocumentExtractor>| {
+            priority_override(extractor.name()).unwrap_or(priority)
+        };
+
+        if let Some(priority_map) = self.extractors.get(mime_type)
+            && let Some((_priority, extractor)) = priority_map
+                .iter()
+                .max_by_key(|(priority, extractor)| effective_priority(**priority, extractor))
+        {
+            return Ok(Arc::clone(extractor));
+        }
+
+        let mut best_match: Option<(i32, Arc<dyn DocumentExtractor>)> = None;
+
+        for (registered_mime, priority_map) in &self.extractors {
+            if let Some(prefix) = registered_mime.strip_suffix('*')
+                && registered_mime.ends_with("/*")
+                && mime_type.starts_with(prefix)
+            {
+                for (priority, extractor) in priority_map {
+                    let priority = effective_priority(*priority, extractor);
+                    if best_match.as_ref().is_none_or(|(current, _)| priority > *current) {
+                        best_match = Some((priority, Arc::clone(extractor)));
+                    }
+                }
+            }
+        }
+
+        best_match
+            .map(|(_priority, extractor)| extractor)
+            .ok_or_else(|| KreuzbergError::UnsupportedFormat(mime_type.to_string()))
+    }
+
     /// List all registered extractors.
     pub fn list(&self) -> Vec<String> {
         self.name_index.keys().cloned().collect()
     }
 
+    /// List all MIME types (including wildcards such as `text/*`) with a registered extractor.
+    pub fn mime_types(&self) -> Vec<String> {
+        self.extractors.keys().cloned().collect()
+    }
+
+    /// Describe all registered extractors, including the MIME types and priority they were registered with.
+    pub fn describe(&self) -> Vec<PluginInfo> {
+        self.name_index
+            .values()
+            .filter_map(|locations| {
+                let (mime_type, priority) = locations.first()?;
+                let extractor = self.extractors.get(mime_type)?.get(priority)?;
+
+                let mut info = PluginInfo::new(PluginKind::DocumentExtractor, extractor.as_ref());
+                let mut mime_types: Vec<String> = locations.iter().map(|(mime, _)| mime.clone()).collect();
+                mime_types.sort();
+                info.mime_types = mime_types;
+                info.priority = Some(*priority);
+                Some(info)
+            })
+            .collect()
+    }
+
     /// Remove an extractor from the registry.
     pub fn remove(&mut self, name: &str) -> Result<()> {
         let index_entries = match self.name_index.remove(name) {
@@ -386,11 +478,17 @@ impl PostProcessorRegistry {
     ///
     /// * `processor` - The post-processor to register
     /// * `priority` - Execution priority (higher = runs first within stage)
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` if the name is invalid or the ordering constraints
+    /// of the processor form a cycle with those of the registered processors.
     pub fn register(&mut self, processor: Arc<dyn PostProcessor>, priority: i32) -> Result<()> {
         let name = processor.name().to_string();
         let stage = processor.processing_stage();
 
         validate_plugin_name(&name)?;
+        self.check_ordering(&processor, stage, priority)?;
 
         processor.initialize()?;
 
@@ -433,11 +531,75 @@ impl PostProcessorRegistry {
         result
     }
 
+    /// All processors in the order the pipeline runs them.
+    ///
+    /// Processors run by stage, then priority (highest first), then registration order, except
+    /// where their [`run_after`](PostProcessor::run_after) and
+    /// [`run_before`](PostProcessor::run_before) constraints require otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns `KreuzbergError::Validation` if the ordering constraints form a cycle.
+    pub fn execution_plan(&self) -> Result<Vec<Arc<dyn PostProcessor>>> {
+        plan_order(self.stage_order())
+    }
+
+    /// All processors by stage, then priority (highest first), then registration order.
+    fn stage_order(&self) -> Vec<Arc<dyn PostProcessor>> {
+        [ProcessingStage::Early, ProcessingStage::Middle, ProcessingStage::Late]
+            .into_iter()
+            .flat_map(|stage| self.get_for_stage(stage))
+            .collect()
+    }
+
+    /// Fail if registering `processor` at `stage` and `priority` would make the ordering
+    /// constraints cyclic.
+    fn check_ordering(&self, processor: &Arc<dyn PostProcessor>, stage: ProcessingStage, priority: i32) -> Result<()> {
+        let name = processor.name();
+        let mut processors: Vec<_> = self
+            .stage_order()
+            .into_iter()
+            .filter(|registered| registered.name() != name)
+            .collect();
+
+        // Insert where registration would put it: after the processors of the same stage and priority
+        let key = (stage, std::cmp::Reverse(priority));
+        let position = processors
+            .iter()
+            .position(|registered| {
+                let (stage, priority) = self.name_index[registered.name()];
+                (stage, std::cmp::Reverse(priority)) > key
+            })
+            .unwrap_or(processors.len());
+        processors.insert(position, Arc::clone(processor));
+
+        plan_order(processors).map(|_| ())
+    }
+
     /// List all registered processor names.
     pub fn list(&self) -> Vec<String> {
         self.name_index.keys().cloned().collect()
     }
 
+    /// Describe all registered processors, including their stage and priority.
+    pub fn describe(&self) -> Vec<PluginInfo> {
+        self.processors
+            .iter()
+            .flat_map(|(stage, priority_map)| {
+                priority_map.iter().flat_map(move |(priority, processors)| {
+                    processors.iter().map(move |processor| {
+                        let mut info = PluginInfo::new(PluginKind::PostProcessor, processor.as_ref());
+                        info.priority = Some(*priority);
+                        info.stage = Some(*stage);
+                        info.run_after = processor.run_after();
+                        info.run_before = processor.run_before();
+                        info
+                    })
+                })
+            })
+            .collect()
+    }
+
     /// Remove a processor from the registry.
     pub fn remove(&mut self, name: &str) -> Result<()> {
         let (stage, priority) = match self.name_index.remove(name) {
@@ -490,6 +652,75 @@ impl Default for PostProcessorRegistry {
     }
 }
 
+/// Order `processors`, given in stage order, so that every ordering constraint holds.
+///
+/// Topological sort that always picks the earliest processor in stage order whose constraints
+/// are satisfied, so the plan is deterministic and deviates from stage order only where a
+/// constraint requires it. Constraints naming processors that are not in `processors` are
+/// ignored.
+fn plan_order(processors: Vec<Arc<dyn PostProcessor>>) -> Result<Vec<Arc<dyn PostProcessor>>> {
+    let (successors, mut predecessors) = constraint_graph(&processors);
+
+    let mut ready: BTreeSet<usize> = (0..processors.len()).filter(|&i| predecessors[i] == 0).collect();
+    let mut order = Vec::with_capacity(processors.len());
+    while let Some(position) = ready.pop_first() {
+        order.push(position);
+        for &next in &successors[position] {
+            predecessors[next] -= 1;
+            if predecessors[next] == 0 {
+                ready.insert(next);
+            }
+        }
+    }
+
+    if order.len() < processors.len() {
+        let unordered: Vec<&str> = processors
+            .iter()
+            .zip(&predecessors)
+            .filter(|(_, waiting)| **waiting > 0)
+            .map(|(processor, _)| processor.name())
+            .collect();
+        return Err(KreuzbergError::validation(format!(
+            "Post-processor ordering constraints form a cycle among: {}",
+            unordered.join(", ")
+        )));
+    }
+
+    let mut processors: Vec<Option<Arc<dyn PostProcessor>>> = processors.into_iter().map(Some).collect();
+    let planned = order.into_iter().filter_map(|position| processors[position].take());
+    Ok(planned.collect())
+}
+
+/// Edges between the positions of `processors`: `successors[i]` must run after processor `i`,
+/// which waits for `predecessors[i]` processors.
+fn constraint_graph(processors: &[Arc<dyn PostProcessor>]) -> (Vec<Vec<usize>>, Vec<usize>) {
+    let positions: HashMap<&str, usize> = processors
+        .iter()
+        .enumerate()
+        .map(|(position, processor)| (processor.name(), position))
+        .collect();
+
+    let mut successors = vec![Vec::new(); processors.len()];
+    let mut predecessors = vec![0; processors.len()];
+    for (position, processor) in processors.iter().enumerate() {
+        let after = processor.run_after().into_iter().map(|name| (name, true));
+        let before = processor.run_before().into_iter().map(|name| (name, false));
+        for (name, runs_after) in after.chain(before) {
+            let Some(&other) = positions.get(name.as_str()) else {
+                continue;
+            };
+            let (first, then) = if runs_after {
+                (other, position)
+            } else {
+                (position, other)
+            };
+            successors[first].push(then);
+            predecessors[then] += 1;
+        }
+    }
+    (successors, predecessors)
+}
+
 /// Registry for validator plugins.
 ///
 /// Manages validators with priority-based execution order.
@@ -549,6 +780,20 @@ impl ValidatorRegistry {
         names.into_iter().collect()
     }
 
+    /// Describe all registered validators, including their priority.
+    pub fn describe(&self) -> Vec<PluginInfo> {
+        self.validators
+            .iter()
+            .flat_map(|(priority, validators)| {
+                validators.values().map(move |validator| {
+                    let mut info = PluginInfo::new(PluginKind::Validator, validator.as_ref());
+                    info.priority = Some(*priority);
+                    info
+                })
+            })
+            .collect()
+    }
+
     /// Remove a validator from the registry.
     pub fn remove(&mut self, name: &str) -> Result<()> {
         let mut validator_to_shutdown: Option<Arc<dyn Validator>> = None;
@@ -586,6 +831,81 @@ impl Default for ValidatorRegistry {
     }
 }
 
+/// Registry for image describer plugins.
+///
+/// Describers are looked up by name from `ImageExtractionConfig::describer`.
+pub struct ImageDescriberRegistry {
+    describers: HashMap<String, Arc<dyn ImageDescriber>>,
+}
+
+impl ImageDescriberRegistry {
+    /// Create a new empty image describer registry.
+    pub fn new() -> Self {
+        Self {
+            describers: HashMap::new(),
+        }
+    }
+
+    /// Register an image describer, calling its `initialize()` method.
+    pub fn register(&mut self, describer: Arc<dyn ImageDescriber>) -> Result<()> {
+        let name = describer.name().to_string();
+
+        validate_plugin_name(&name)?;
+
+        describer.initialize()?;
+
+        self.describers.insert(name, describer);
+        Ok(())
+    }
+
+    /// Get an image describer by name.
+    pub fn get(&self, name: &str) -> Result<Arc<dyn ImageDescriber>> {
+        self.describers
+            .get(name)
+            .cloned()
+            .ok_or_else(|| KreuzbergError::Plugin {
+                message: format!("Image describer '{}' not registered", name),
+                plugin_name: name.to_string(),
+            })
+    }
+
+    /// List all registered describer names.
+    pub fn list(&self) -> Vec<String> {
+        self.describers.keys().cloned().collect()
+    }
+
+    /// Describe all registered describers.
+    pub fn describe(&self) -> Vec<PluginInfo> {
+        self.describers
+            .values()
+            .map(|describer| PluginInfo::new(PluginKind::ImageDescriber, describer.as_ref()))
+            .collect()
+    }
+
+    /// Remove a describer from the registry, calling its `shutdown()` method.
+    pub fn remove(&mut self, name: &str) -> Result<()> {
+        if let Some(describer) = self.describers.remove(name) {
+            describer.shutdown()?;
+        }
+        Ok(())
+    }
+
+    /// Shutdown all describers and clear the registry.
+    pub fn shutdown_all(&mut self) -> Result<()> {
+        let names: Vec<_> = self.describers.keys().cloned().collect();
+        for name in names {
+            self.remove(&name)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for ImageDescriberRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Global OCR backend registry singleton.
 pub static OCR_BACKEND_REGISTRY: Lazy<Arc<RwLock<OcrBackendRegistry>>> =
     Lazy::new(|| Arc::new(RwLock::new(OcrBackendRegistry::new())));
@@ -602,6 +922,10 @@ pub static POST_PROCESSOR_REGISTRY: Lazy<Arc<RwLock<PostProcessorRegistry>>> =
 pub static VALIDATOR_REGISTRY: Lazy<Arc<RwLock<ValidatorRegistry>>> =
     Lazy::new(|| Arc::new(RwLock::new(ValidatorRegistry::new())));
 
+/// Global image describer registry singleton.
+pub static IMAGE_DESCRIBER_REGISTRY: Lazy<Arc<RwLock<ImageDescriberRegistry>>> =
+    Lazy::new(|| Arc::new(RwLock::new(ImageDescriberRegistry::new())));
+
 /// Get the global OCR backend registry.
 pub fn get_ocr_backend_registry() -> Arc<RwLock<OcrBackendRegistry>> {
     OCR_BACKEND_REGISTRY.clone()
@@ -622,6 +946,110 @@ pub fn get_validator_registry() -> Arc<RwLock<ValidatorRegistry>> {
     VALIDATOR_REGISTRY.clone()
 }
 
+/// Get the global image describer registry.
+pub fn get_image_describer_registry() -> Arc<RwLock<ImageDescriberRegistry>> {
+    IMAGE_DESCRIBER_REGISTRY.clone()
+}
+
+/// A complete set of plugin registries.
+///
+/// The extraction pipeline resolves extractors, OCR backends, post-processors and validators
+/// from a `PluginRegistries` value. [`PluginRegistries::global()`] refers to the process-wide
+/// singletons used by the free extraction functions, while [`PluginRegistries::new()`] creates
+/// an isolated set owned by a single [`Extractor`](crate::Extractor).
+#[derive(Clone)]
+pub struct PluginRegistries {
+    /// OCR backend registry
+    pub ocr_backends: Arc<RwLock<OcrBackendRegistry>>,
+    /// Document extractor registry
+    pub document_extractors: Arc<RwLock<DocumentExtractorRegistry>>,
+    /// Post-processor registry
+    pub post_processors: Arc<RwLock<PostProcessorRegistry>>,
+    /// Validator registry
+    pub validators: Arc<RwLock<ValidatorRegistry>>,
+    /// Image describer registry
+    pub image_describers: Arc<RwLock<ImageDescriberRegistry>>,
+    is_global: bool,
+    resolved_extractors: Option<Arc<HashMap<String, Arc<dyn DocumentExtractor>>>>,
+}
+
+impl PluginRegistries {
+    /// Create a new isolated set of registries.
+    ///
+    /// The OCR backend registry contains the default backends (see [`OcrBackendRegistry::new`]);
+    /// all other registries start empty.
+    pub fn new() -> Self {
+        Self {
+            ocr_backends: Arc::new(RwLock::new(OcrBackendRegistry::new())),
+            document_extractors: Arc::new(RwLock::new(DocumentExtractorRegistry::new())),
+            post_processors: Arc::new(RwLock::new(PostProcessorRegistry::new())),
+            validators: Arc::new(RwLock::new(ValidatorRegistry::new())),
+            image_describers: Arc::new(RwLock::new(ImageDescriberRegistry::new())),
+            is_global: false,
+            resolved_extractors: None,
+        }
+    }
+
+    /// Handles to the process-wide global registries.
+    pub fn global() -> Self {
+        Self {
+            ocr_backends: get_ocr_backend_registry(),
+            document_extractors: get_document_extractor_registry(),
+            post_processors: get_post_processor_registry(),
+            validators: get_validator_registry(),
+            image_describers: get_image_describer_registry(),
+            is_global: true,
+            resolved_extractors: None,
+        }
+    }
+
+    /// Whether these are the process-wide global registries.
+    ///
+    /// Built-in plugins are lazily registered into the global registries only.
+    pub fn is_global(&self) -> bool {
+        self.is_global
+    }
+
+    /// Whether built-in plugins still have to be lazily registered before extracting.
+    ///
+    /// Registries that carry pre-resolved extractors were warmed up by an
+    /// [`ExtractionSession`](crate::ExtractionSession) and skip the per-call registration.
+    pub(crate) fn needs_lazy_init(&self) -> bool {
+        self.is_global && self.resolved_extractors.is_none()
+    }
+
+    /// Attach a snapshot of extractors resolved per MIME type.
+    pub(crate) fn with_resolved_extractors(mut self, extractors: HashMap<String, Arc<dyn DocumentExtractor>>) -> Self {
+        self.resolved_extractors = Some(Arc::new(extractors));
+        self
+    }
+
+    /// Whether both refer to the same registries (and pre-resolved extractors).
+    pub(crate) fn same_as(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.ocr_backends, &other.ocr_backends)
+            && Arc::ptr_eq(&self.document_extractors, &other.document_extractors)
+            && Arc::ptr_eq(&self.post_processors, &other.post_processors)
+            && Arc::ptr_eq(&self.validators, &other.validators)
+            && Arc::ptr_eq(&self.image_describers, &other.image_describers)
+            && match (&self.resolved_extractors, &other.resolved_extractors) {
+                (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+    }
+
+    /// Look up a pre-resolved extractor without taking the registry lock.
+    pub(crate) fn resolved_extractor(&self, mime_type: &str) -> Option<Arc<dyn DocumentExtractor>> {
+        self.resolved_extractors.as_ref()?.get(mime_type).cloned()
+    }
+}
+
+impl Default for PluginRegistries {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -662,6 +1090,11 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             })
         }
 
@@ -707,6 +1140,11 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             })
         }
 
@@ -750,6 +1188,63 @@ mod tests {
         }
     }
 
+    struct OrderedPostProcessor {
+        name: &'static str,
+        stage: ProcessingStage,
+        run_after: &'static [&'static str],
+        run_before: &'static [&'static str],
+    }
+
+    impl OrderedPostProcessor {
+        fn new(name: &'static str, stage: ProcessingStage) -> Self {
+            Self {
+                name,
+                stage,
+                run_after: &[],
+                run_before: &[],
+            }
+        }
+    }
+
+    impl Plugin for OrderedPostProcessor {
+        fn name(&self) -> &str {
+            self.name
+        }
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl PostProcessor for OrderedPostProcessor {
+        async fn process(&self, _result: &mut ExtractionResult, _: &ExtractionConfig) -> Result<()> {
+            Ok(())
+        }
+
+        fn processing_stage(&self) -> ProcessingStage {
+            self.stage
+        }
+
+        fn run_after(&self) -> Vec<String> {
+            self.run_after.iter().map(|name| name.to_string()).collect()
+        }
+
+        fn run_before(&self) -> Vec<String> {
+            self.run_before.iter().map(|name| name.to_string()).collect()
+        }
+    }
+
+    fn plan_names(registry: &PostProcessorRegistry) -> Vec<String> {
+        let plan = registry.execution_plan().unwrap();
+        plan.iter().map(|processor| processor.name().to_string()).collect()
+    }
+
     struct MockValidator {
         name: String,
         priority: i32,
@@ -916,6 +1411,39 @@ mod tests {
         assert_eq!(retrieved.name(), "high-priority-pdf");
     }
 
+    #[test]
+    fn test_document_extractor_registry_priority_override() {
+        let mut registry = DocumentExtractorRegistry::new();
+
+        registry
+            .register(Arc::new(MockExtractor {
+                name: "low-priority-pdf".to_string(),
+                mime_types: &["application/pdf"],
+                priority: 10,
+            }))
+            .unwrap();
+        registry
+            .register(Arc::new(MockExtractor {
+                name: "high-priority-pdf".to_string(),
+                mime_types: &["application/pdf"],
+                priority: 100,
+            }))
+            .unwrap();
+
+        let retrieved = registry
+            .get_with_priority_override("application/pdf", |name| (name == "low-priority-pdf").then_some(200))
+            .unwrap();
+        assert_eq!(retrieved.name(), "low-priority-pdf");
+
+        let retrieved = registry
+            .get_with_priority_override("application/pdf", |_| None)
+            .unwrap();
+        assert_eq!(retrieved.name(), "high-priority-pdf");
+
+        let result = registry.get_with_priority_override("application/unknown", |_| None);
+        assert!(matches!(result, Err(KreuzbergError::UnsupportedFormat(_))));
+    }
+
     #[test]
     fn test_document_extractor_registry_not_found() {
         let registry = DocumentExtractorRegistry::new();
@@ -1127,6 +1655,80 @@ mod tests {
         assert_eq!(registry.get_all().len(), 0);
     }
 
+    #[test]
+    fn test_document_extractor_registry_describe() {
+        let mut registry = DocumentExtractorRegistry::new();
+
+        registry
+            .register(Arc::new(MockExtractor {
+                name: "multi-extractor".to_string(),
+                mime_types: &["text/plain", "text/markdown"],
+                priority: 60,
+            }))
+            .unwrap();
+
+        let described = registry.describe();
+        assert_eq!(described.len(), 1);
+        assert_eq!(described[0].kind, PluginKind::DocumentExtractor);
+        assert_eq!(described[0].name, "multi-extractor");
+        assert_eq!(described[0].mime_types, vec!["text/markdown", "text/plain"]);
+        assert_eq!(described[0].priority, Some(60));
+    }
+
+    #[test]
+    fn test_post_processor_registry_describe() {
+        let mut registry = PostProcessorRegistry::new();
+
+        registry
+            .register(
+                Arc::new(MockPostProcessor {
+                    name: "late-processor".to_string(),
+                    stage: ProcessingStage::Late,
+                }),
+                25,
+            )
+            .unwrap();
+
+        let described = registry.describe();
+        assert_eq!(described.len(), 1);
+        assert_eq!(described[0].stage, Some(ProcessingStage::Late));
+        assert_eq!(described[0].priority, Some(25));
+    }
+
+    #[test]
+    fn test_plugin_registries_are_isolated() {
+        let isolated = PluginRegistries::new();
+        assert!(!isolated.is_global());
+        assert!(PluginRegistries::global().is_global());
+
+        isolated
+            .document_extractors
+            .write()
+            .unwrap()
+            .register(Arc::new(MockExtractor {
+                name: "isolated-extractor".to_string(),
+                mime_types: &["application/x-isolated"],
+                priority: 50,
+            }))
+            .unwrap();
+
+        assert!(
+            isolated
+                .document_extractors
+                .read()
+                .unwrap()
+                .get("application/x-isolated")
+                .is_ok()
+        );
+        assert!(
+            get_document_extractor_registry()
+                .read()
+                .unwrap()
+                .get("application/x-isolated")
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_document_extractor_registry_multiple_mime_types() {
         let mut registry = DocumentExtractorRegistry::new();
@@ -1175,6 +1777,70 @@ mod tests {
         assert_eq!(processors.len(), 0);
     }
 
+    #[test]
+    fn test_post_processor_registry_execution_plan() {
+        let mut registry = PostProcessorRegistry::new();
+        registry
+            .register(
+                Arc::new(OrderedPostProcessor::new("cleanup", ProcessingStage::Early)),
+                50,
+            )
+            .unwrap();
+        registry
+            .register(
+                Arc::new(OrderedPostProcessor::new("chunking", ProcessingStage::Middle)),
+                50,
+            )
+            .unwrap();
+        registry
+            .register(
+                Arc::new(OrderedPostProcessor::new("summary", ProcessingStage::Early)),
+                10,
+            )
+            .unwrap();
+        assert_eq!(plan_names(&registry), vec!["cleanup", "summary", "chunking"]);
+
+        // A late processor pulled in between the two by its constraints
+        let redaction = OrderedPostProcessor {
+            run_after: &["cleanup", "not-registered"],
+            run_before: &["chunking"],
+            ..OrderedPostProcessor::new("redaction", ProcessingStage::Late)
+        };
+        registry.register(Arc::new(redaction), 0).unwrap();
+        assert_eq!(
+            plan_names(&registry),
+            vec!["cleanup", "summary", "redaction", "chunking"]
+        );
+
+        let described = registry.describe();
+        let redaction = described.iter().find(|info| info.name == "redaction").unwrap();
+        assert_eq!(redaction.run_before, vec!["chunking"]);
+    }
+
+    #[test]
+    fn test_post_processor_registry_rejects_cycles() {
+        let mut registry = PostProcessorRegistry::new();
+        let first = OrderedPostProcessor {
+            run_before: &["second"],
+            ..OrderedPostProcessor::new("first", ProcessingStage::Early)
+        };
+        registry.register(Arc::new(first), 50).unwrap();
+
+        let second = OrderedPostProcessor {
+            run_before: &["first"],
+            ..OrderedPostProcessor::new("second", ProcessingStage::Late)
+        };
+        let result = registry.register(Arc::new(second), 50);
+        assert!(matches!(result, Err(KreuzbergError::Validation { .. })));
+        assert_eq!(registry.list(), vec!["first"]);
+
+        let itself = OrderedPostProcessor {
+            run_after: &["itself"],
+            ..OrderedPostProcessor::new("itself", ProcessingStage::Early)
+        };
+        assert!(registry.register(Arc::new(itself), 50).is_err());
+    }
+
     #[test]
     fn test_ocr_backend_registry_default() {
         let registry = OcrBackendRegistry::default();
@@ -5,7 +5,7 @@
 use crate::Result;
 use crate::core::config::ExtractionConfig;
 use crate::plugins::Plugin;
-use crate::types::ExtractionResult;
+use crate::types::{ExtractionResult, ValidationFinding};
 use async_trait::async_trait;
 use std::sync::Arc;
 
@@ -28,7 +28,10 @@ use std::sync::Arc;
 /// Validator errors are **fatal** - they cause the extraction to fail and bubble up
 /// to the caller. Use validators for hard requirements that must be met.
 ///
-/// For non-fatal checks, use post-processors instead.
+/// For soft policies, implement [`check`](Validator::check) and report
+/// [`ValidationFinding`]s instead: warnings are collected in `metadata.findings` without
+/// failing the extraction. With `validation.mode = "annotate"` in the config, rejections and
+/// error findings are recorded there too instead of failing the extraction.
 ///
 /// # Thread Safety
 ///
@@ -198,6 +201,49 @@ pub trait Validator: Plugin {
     /// ```
     async fn validate(&self, result: &ExtractionResult, config: &ExtractionConfig) -> Result<()>;
 
+    /// Optional: Check an extraction result and report findings.
+    ///
+    /// This is what the pipeline calls. The default runs [`validate`](Validator::validate) and
+    /// reports no findings; override it to report findings with a severity, a code, a message
+    /// and optionally a location. The pipeline fills in the validator name and stores them in
+    /// `metadata.findings`.
+    ///
+    /// Info and warning findings never fail the extraction. The first error finding rejects
+    /// the result like a validation error, unless `validation.mode` is `annotate`.
+    ///
+    /// # Example - Soft Length Policy
+    ///
+    /// ```rust
+    /// # use kreuzberg::plugins::{Plugin, Validator};
+    /// # use kreuzberg::{Result, ExtractionResult, ExtractionConfig, FindingSeverity, ValidationFinding};
+    /// # use async_trait::async_trait;
+    /// # struct ShortContentValidator;
+    /// # impl Plugin for ShortContentValidator {
+    /// #     fn name(&self) -> &str { "short-content" }
+    /// #     fn version(&self) -> String { "1.0.0".to_string() }
+    /// #     fn initialize(&self) -> Result<()> { Ok(()) }
+    /// #     fn shutdown(&self) -> Result<()> { Ok(()) }
+    /// # }
+    /// # #[async_trait]
+    /// # impl Validator for ShortContentValidator {
+    /// #     async fn validate(&self, _: &ExtractionResult, _: &ExtractionConfig) -> Result<()> { Ok(()) }
+    /// async fn check(&self, result: &ExtractionResult, config: &ExtractionConfig)
+    ///     -> Result<Vec<ValidationFinding>> {
+    ///     if result.content.trim().len() < 50 {
+    ///         return Ok(vec![ValidationFinding::new(
+    ///             FindingSeverity::Warning,
+    ///             "content_too_short",
+    ///             "Content is suspiciously short",
+    ///         )]);
+    ///     }
+    ///     Ok(Vec::new())
+    /// }
+    /// # }
+    /// ```
+    async fn check(&self, result: &ExtractionResult, config: &ExtractionConfig) -> Result<Vec<ValidationFinding>> {
+        self.validate(result, config).await.map(|()| Vec::new())
+    }
+
     /// Optional: Check if this validator should run for a given result.
     ///
     /// Allows conditional validation based on MIME type, metadata, or content.
@@ -490,6 +536,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -509,6 +560,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -517,6 +573,33 @@ mod tests {
         assert!(matches!(validation_result, Err(KreuzbergError::Validation { .. })));
     }
 
+    #[tokio::test]
+    async fn test_validator_check_default() {
+        let result = ExtractionResult {
+            content: "test content".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: crate::types::Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+        let config = ExtractionConfig::default();
+
+        let passing = MockValidator { should_fail: false };
+        assert!(passing.check(&result, &config).await.unwrap().is_empty());
+
+        let failing = MockValidator { should_fail: true };
+        let checked = failing.check(&result, &config).await;
+        assert!(matches!(checked, Err(KreuzbergError::Validation { .. })));
+    }
+
     #[test]
     fn test_validator_should_validate_default() {
         let validator = MockValidator { should_fail: false };
@@ -530,6 +613,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -566,6 +654,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -614,6 +707,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let txt_result = ExtractionResult {
@@ -625,6 +723,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         assert!(validator.should_validate(&pdf_result, &config));
@@ -709,6 +812,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -741,6 +849,11 @@ mod tests {
             detected_languages: None,
             chunks: None,
             images: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -768,6 +881,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -797,6 +915,11 @@ mod tests {
                 chunks: None,
                 images: None,
                 pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             };
 
             assert!(validator.validate(&result, &config).await.is_ok());
@@ -816,6 +939,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config = ExtractionConfig::default();
@@ -0,0 +1,126 @@
+//! LanceDB sink.
+
+use super::{VectorRecord, VectorSink, sink_error};
+use crate::{KreuzbergError, Result};
+use arrow_array::types::Float32Type;
+use arrow_array::{FixedSizeListArray, RecordBatch, RecordBatchIterator, StringArray, UInt64Array};
+use arrow_schema::{DataType, Field, Schema};
+use async_trait::async_trait;
+use lancedb::Connection;
+use std::sync::Arc;
+
+/// Upserts records into a LanceDB table, creating it on first use.
+///
+/// The table has the columns `id`, `document_id`, `chunk_index`, `content`, `vector`
+/// (fixed-size list of `f32`) and `payload` (the [`ChunkPayload`](super::ChunkPayload) as JSON).
+/// Existing rows are matched on `id`.
+pub struct LanceDbSink {
+    connection: Connection,
+    table: String,
+}
+
+impl LanceDbSink {
+    /// Open the database at `uri` (a local directory or an object-store URI such as `s3://bucket/db`).
+    pub async fn connect(uri: &str, table: impl Into<String>) -> Result<Self> {
+        let connection = lancedb::connect(uri)
+            .execute()
+            .await
+            .map_err(|e| sink_error("lancedb", e))?;
+        Ok(Self {
+            connection,
+            table: table.into(),
+        })
+    }
+}
+
+#[async_trait]
+impl VectorSink for LanceDbSink {
+    fn name(&self) -> &str {
+        "lancedb"
+    }
+
+    async fn upsert(&self, records: &[VectorRecord]) -> Result<usize> {
+        if records.is_empty() {
+            return Ok(0);
+        }
+        let batch = record_batch(records)?;
+        let schema = batch.schema();
+        let reader = RecordBatchIterator::new(vec![Ok(batch)], schema);
+
+        let tables = self
+            .connection
+            .table_names()
+            .execute()
+            .await
+            .map_err(|e| sink_error("lancedb", e))?;
+        if !tables.contains(&self.table) {
+            self.connection
+                .create_table(&self.table, Box::new(reader))
+                .execute()
+                .await
+                .map_err(|e| sink_error("lancedb", e))?;
+            return Ok(records.len());
+        }
+
+        let table = self
+            .connection
+            .open_table(&self.table)
+            .execute()
+            .await
+            .map_err(|e| sink_error("lancedb", e))?;
+        let mut merge = table.merge_insert(&["id"]);
+        merge.when_matched_update_all(None).when_not_matched_insert_all();
+        merge
+            .execute(Box::new(reader))
+            .await
+            .map_err(|e| sink_error("lancedb", e))?;
+
+        Ok(records.len())
+    }
+}
+
+fn record_batch(records: &[VectorRecord]) -> Result<RecordBatch> {
+    let dimensions = i32::try_from(records[0].vector.len())
+        .map_err(|_| KreuzbergError::validation("Embedding dimension is too large for LanceDB"))?;
+    let payloads = records
+        .iter()
+        .map(|record| serde_json::to_string(&record.payload))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Utf8, false),
+        Field::new("document_id", DataType::Utf8, false),
+        Field::new("chunk_index", DataType::UInt64, false),
+        Field::new("content", DataType::Utf8, false),
+        Field::new(
+            "vector",
+            DataType::FixedSizeList(Arc::new(Field::new("item", DataType::Float32, true)), dimensions),
+            false,
+        ),
+        Field::new("payload", DataType::Utf8, false),
+    ]));
+    let vectors = FixedSizeListArray::from_iter_primitive::<Float32Type, _, _>(
+        records
+            .iter()
+            .map(|record| Some(record.vector.iter().copied().map(Some).collect::<Vec<_>>())),
+        dimensions,
+    );
+
+    let ids = StringArray::from_iter_values(records.iter().map(|r| r.id.as_str()));
+    let document_ids = StringArray::from_iter_values(records.iter().map(|r| r.payload.document_id.as_str()));
+    let chunk_indexes = UInt64Array::from_iter_values(records.iter().map(|r| r.payload.chunk_index as u64));
+    let contents = StringArray::from_iter_values(records.iter().map(|r| r.payload.content.as_str()));
+
+    RecordBatch::try_new(
+        schema,
+        vec![
+            Arc::new(ids),
+            Arc::new(document_ids),
+            Arc::new(chunk_indexes),
+            Arc::new(contents),
+            Arc::new(vectors),
+            Arc::new(StringArray::from_iter_values(payloads)),
+        ],
+    )
+    .map_err(|e| sink_error("lancedb", e))
+}
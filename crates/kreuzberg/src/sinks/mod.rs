@@ -0,0 +1,304 @@
+//! Vector-store export of embedded chunks.
+//!
+//! A [`VectorSink`] takes the chunks of an extraction result, together with their
+//! embeddings, and upserts them into a vector store. Every store receives the same
+//! [`ChunkPayload`], so search code does not depend on which store a deployment uses.
+//! Record IDs are derived from the document ID and chunk index, so exporting the same
+//! document again replaces its chunks instead of duplicating them.
+//!
+//! # Feature Flags
+//!
+//! - `sinks`: The [`VectorSink`] trait, payload schema and export functions
+//! - `sinks-qdrant`: [`QdrantSink`] (Qdrant REST API)
+//! - `sinks-pgvector`: [`PgVectorSink`] (PostgreSQL with the `vector` extension)
+//! - `sinks-lancedb`: [`LanceDbSink`] (local or object-store LanceDB tables)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "sinks-qdrant")]
+//! # async fn example() -> kreuzberg::Result<()> {
+//! use kreuzberg::{ChunkingConfig, EmbeddingConfig, ExtractionConfig};
+//! use kreuzberg::sinks::{QdrantSink, extract_and_export};
+//!
+//! let config = ExtractionConfig {
+//!     chunking: Some(ChunkingConfig {
+//!         max_chars: 1000,
+//!         max_overlap: 200,
+//!         embedding: Some(EmbeddingConfig::default()),
+//!         preset: None,
+//!     }),
+//!     ..Default::default()
+//! };
+//! let sink = QdrantSink::new("http://localhost:6333", "documents").with_create_collection(true);
+//!
+//! let (result, written) = extract_and_export("report.pdf", &config, &sink).await?;
+//! println!("{} chunks of {} indexed", written, result.mime_type);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::config::ExtractionConfig;
+use crate::core::extractor::extract_file;
+use crate::types::ExtractionResult;
+use crate::{KreuzbergError, Result};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[cfg(feature = "sinks-lancedb")]
+mod lancedb;
+#[cfg(feature = "sinks-pgvector")]
+mod pgvector;
+#[cfg(feature = "sinks-qdrant")]
+mod qdrant;
+
+#[cfg(feature = "sinks-lancedb")]
+pub use lancedb::LanceDbSink;
+#[cfg(feature = "sinks-pgvector")]
+pub use pgvector::PgVectorSink;
+#[cfg(feature = "sinks-qdrant")]
+pub use qdrant::QdrantSink;
+
+/// Version of the [`ChunkPayload`] layout, stored in every payload.
+///
+/// Bumped only for incompatible changes; new optional fields keep the version.
+pub const PAYLOAD_SCHEMA_VERSION: u32 = 1;
+
+/// Payload stored with every vector.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkPayload {
+    /// Layout version ([`PAYLOAD_SCHEMA_VERSION`])
+    pub schema_version: u32,
+    /// Caller-chosen ID of the source document (a path by default)
+    pub document_id: String,
+    /// Zero-based index of the chunk in the document
+    pub chunk_index: usize,
+    /// Number of chunks in the document
+    pub total_chunks: usize,
+    /// Chunk text
+    pub content: String,
+    /// MIME type of the source document
+    pub mime_type: String,
+    /// Byte offset of the chunk in the extracted content
+    pub byte_start: usize,
+    /// Byte offset of the chunk end in the extracted content
+    pub byte_end: usize,
+    /// First page the chunk spans (1-indexed, with page tracking)
+    pub first_page: Option<usize>,
+    /// Last page the chunk spans (1-indexed, with page tracking)
+    pub last_page: Option<usize>,
+    /// Document title from the format metadata
+    pub title: Option<String>,
+}
+
+/// A vector with its ID and payload, ready to be upserted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VectorRecord {
+    /// Stable UUID derived from the document ID and chunk index
+    pub id: String,
+    /// Chunk embedding
+    pub vector: Vec<f32>,
+    /// Chunk text and position
+    pub payload: ChunkPayload,
+}
+
+/// A vector store that embedded chunks can be written to.
+#[async_trait]
+pub trait VectorSink: Send + Sync {
+    /// Short name of the store, used in errors and logs.
+    fn name(&self) -> &str;
+
+    /// Insert the records, replacing records with the same ID. Returns the number written.
+    async fn upsert(&self, records: &[VectorRecord]) -> Result<usize>;
+}
+
+/// Build the records for every chunk of `result`.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if the result has no chunks, a chunk has no
+/// embedding (chunking and `chunking.embedding` must be configured), or the embeddings
+/// differ in dimension.
+pub fn records_from_result(result: &ExtractionResult, document_id: &str) -> Result<Vec<VectorRecord>> {
+    let chunks = result.chunks.as_deref().unwrap_or_default();
+    if chunks.is_empty() {
+        return Err(KreuzbergError::validation(
+            "Extraction result has no chunks; configure `chunking` with an `embedding` model to export it",
+        ));
+    }
+
+    let mut dimensions = None;
+    chunks
+        .iter()
+        .map(|chunk| {
+            let vector = chunk.embedding.clone().ok_or_else(|| {
+                KreuzbergError::validation(format!(
+                    "Chunk {} of '{}' has no embedding; configure `chunking.embedding` to export it",
+                    chunk.metadata.chunk_index, document_id
+                ))
+            })?;
+            if *dimensions.get_or_insert(vector.len()) != vector.len() {
+                return Err(KreuzbergError::validation(format!(
+                    "Chunk {} of '{}' has a {}-dimensional embedding, expected {}",
+                    chunk.metadata.chunk_index,
+                    document_id,
+                    vector.len(),
+                    dimensions.unwrap_or_default()
+                )));
+            }
+
+            Ok(VectorRecord {
+                id: record_id(document_id, chunk.metadata.chunk_index),
+                vector,
+                payload: ChunkPayload {
+                    schema_version: PAYLOAD_SCHEMA_VERSION,
+                    document_id: document_id.to_string(),
+                    chunk_index: chunk.metadata.chunk_index,
+                    total_chunks: chunk.metadata.total_chunks,
+                    content: chunk.content.clone(),
+                    mime_type: result.mime_type.clone(),
+                    byte_start: chunk.metadata.byte_start,
+                    byte_end: chunk.metadata.byte_end,
+                    first_page: chunk.metadata.first_page,
+                    last_page: chunk.metadata.last_page,
+                    title: result.metadata.title.clone(),
+                },
+            })
+        })
+        .collect()
+}
+
+/// Upsert the embedded chunks of `result` into `sink`. Returns the number of records written.
+pub async fn export_result(result: &ExtractionResult, document_id: &str, sink: &dyn VectorSink) -> Result<usize> {
+    let records = records_from_result(result, document_id)?;
+    let written = sink.upsert(&records).await?;
+    tracing::debug!(sink = sink.name(), document_id, written, "Exported chunks");
+    Ok(written)
+}
+
+/// Extract, chunk and embed a file, then upsert its chunks into `sink`.
+///
+/// The path is used as the document ID. `config` must enable chunking with an embedding
+/// model. Returns the extraction result and the number of records written.
+pub async fn extract_and_export(
+    path: impl AsRef<Path>,
+    config: &ExtractionConfig,
+    sink: &dyn VectorSink,
+) -> Result<(ExtractionResult, usize)> {
+    let path = path.as_ref();
+    let result = extract_file(path, None, config).await?;
+    let written = export_result(&result, &path.to_string_lossy(), sink).await?;
+    Ok((result, written))
+}
+
+/// Name-based (v5) UUID, so the same document and chunk get the same ID on every machine.
+fn record_id(document_id: &str, chunk_index: usize) -> String {
+    let name = format!("kreuzberg:{}#{}", document_id, chunk_index);
+    uuid::Uuid::new_v5(&uuid::Uuid::NAMESPACE_URL, name.as_bytes()).to_string()
+}
+
+/// Wrap a store error so it reports the sink as its plugin.
+#[cfg(any(feature = "sinks-qdrant", feature = "sinks-pgvector", feature = "sinks-lancedb"))]
+fn sink_error(sink: &str, message: impl std::fmt::Display) -> KreuzbergError {
+    KreuzbergError::Plugin {
+        message: message.to_string(),
+        plugin_name: sink.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Chunk, ChunkMetadata};
+    use std::sync::Mutex;
+
+    fn chunk(index: usize, embedding: Option<Vec<f32>>) -> Chunk {
+        Chunk {
+            content: format!("chunk {}", index),
+            embedding,
+            metadata: ChunkMetadata {
+                byte_start: index * 10,
+                byte_end: index * 10 + 7,
+                token_count: None,
+                chunk_index: index,
+                total_chunks: 2,
+                first_page: Some(1),
+                last_page: Some(1),
+            },
+        }
+    }
+
+    fn result(chunks: Vec<Chunk>) -> ExtractionResult {
+        ExtractionResult {
+            content: "chunk 0   chunk 1".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Default::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: Some(chunks),
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[derive(Default)]
+    struct MemorySink {
+        records: Mutex<Vec<VectorRecord>>,
+    }
+
+    #[async_trait]
+    impl VectorSink for MemorySink {
+        fn name(&self) -> &str {
+            "memory"
+        }
+
+        async fn upsert(&self, records: &[VectorRecord]) -> Result<usize> {
+            self.records.lock().unwrap().extend_from_slice(records);
+            Ok(records.len())
+        }
+    }
+
+    #[test]
+    fn test_records_have_stable_ids_and_payload() {
+        let result = result(vec![chunk(0, Some(vec![0.1, 0.2])), chunk(1, Some(vec![0.3, 0.4]))]);
+
+        let records = records_from_result(&result, "docs/a.txt").unwrap();
+        let again = records_from_result(&result, "docs/a.txt").unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].id, again[0].id);
+        assert_ne!(records[0].id, records[1].id);
+        assert_ne!(records[0].id, records_from_result(&result, "docs/b.txt").unwrap()[0].id);
+        assert_eq!(uuid::Uuid::parse_str(&records[0].id).unwrap().get_version_num(), 5);
+        assert_eq!(records[1].payload.chunk_index, 1);
+        assert_eq!(records[1].payload.document_id, "docs/a.txt");
+        assert_eq!(records[1].payload.schema_version, PAYLOAD_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_records_require_embeddings() {
+        assert!(records_from_result(&result(vec![]), "a").is_err());
+        assert!(records_from_result(&result(vec![chunk(0, Some(vec![0.1])), chunk(1, None)]), "a").is_err());
+
+        let mixed = result(vec![chunk(0, Some(vec![0.1])), chunk(1, Some(vec![0.1, 0.2]))]);
+        let error = records_from_result(&mixed, "a").unwrap_err();
+        assert!(error.to_string().contains("2-dimensional embedding, expected 1"));
+    }
+
+    #[tokio::test]
+    async fn test_export_result_upserts_all_chunks() {
+        let sink = MemorySink::default();
+        let result = result(vec![chunk(0, Some(vec![1.0])), chunk(1, Some(vec![2.0]))]);
+
+        let written = export_result(&result, "doc", &sink).await.unwrap();
+
+        assert_eq!(written, 2);
+        assert_eq!(sink.records.lock().unwrap()[1].vector, vec![2.0]);
+    }
+}
@@ -0,0 +1,155 @@
+//! PostgreSQL sink using the `pgvector` extension.
+
+use super::{VectorRecord, VectorSink, sink_error};
+use crate::{KreuzbergError, Result};
+use async_trait::async_trait;
+use tokio_postgres::{Client, NoTls};
+
+/// Upserts records into a PostgreSQL table with a `vector` column.
+///
+/// The table has the columns `id uuid PRIMARY KEY`, `document_id text`, `chunk_index integer`,
+/// `content text`, `embedding vector(n)` and `payload jsonb`; [`PgVectorSink::create_table`]
+/// creates it. Vectors and UUIDs are sent as text and cast by the server, so no pgvector
+/// client types are needed.
+pub struct PgVectorSink {
+    client: Client,
+    table: String,
+}
+
+impl PgVectorSink {
+    /// Connect without TLS and write to `table`.
+    ///
+    /// The connection is driven by a background task on the current Tokio runtime.
+    /// Use [`PgVectorSink::from_client`] for TLS connections or connection pools.
+    pub async fn connect(connection_string: &str, table: impl Into<String>) -> Result<Self> {
+        let table = validate_table(table.into())?;
+        let (client, connection) = tokio_postgres::connect(connection_string, NoTls)
+            .await
+            .map_err(|e| sink_error("pgvector", e))?;
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::warn!("pgvector connection closed with error: {}", e);
+            }
+        });
+        Ok(Self { client, table })
+    }
+
+    /// Write to `table` through an existing client.
+    pub fn from_client(client: Client, table: impl Into<String>) -> Result<Self> {
+        Ok(Self {
+            client,
+            table: validate_table(table.into())?,
+        })
+    }
+
+    /// Create the `vector` extension and the table if they do not exist.
+    pub async fn create_table(&self, dimensions: usize) -> Result<()> {
+        let statement = format!(
+            "CREATE EXTENSION IF NOT EXISTS vector;
+             CREATE TABLE IF NOT EXISTS {} (
+                 id uuid PRIMARY KEY,
+                 document_id text NOT NULL,
+                 chunk_index integer NOT NULL,
+                 content text NOT NULL,
+                 embedding vector({}) NOT NULL,
+                 payload jsonb NOT NULL
+             )",
+            self.table, dimensions
+        );
+        self.client
+            .batch_execute(&statement)
+            .await
+            .map_err(|e| sink_error("pgvector", e))
+    }
+}
+
+#[async_trait]
+impl VectorSink for PgVectorSink {
+    fn name(&self) -> &str {
+        "pgvector"
+    }
+
+    async fn upsert(&self, records: &[VectorRecord]) -> Result<usize> {
+        let statement = format!(
+            "INSERT INTO {} (id, document_id, chunk_index, content, embedding, payload)
+             VALUES ($1::text::uuid, $2, $3, $4, $5::text::vector, $6::text::jsonb)
+             ON CONFLICT (id) DO UPDATE SET
+                 document_id = EXCLUDED.document_id,
+                 chunk_index = EXCLUDED.chunk_index,
+                 content = EXCLUDED.content,
+                 embedding = EXCLUDED.embedding,
+                 payload = EXCLUDED.payload",
+            self.table
+        );
+        let statement = self
+            .client
+            .prepare(&statement)
+            .await
+            .map_err(|e| sink_error("pgvector", e))?;
+
+        for record in records {
+            let chunk_index = i32::try_from(record.payload.chunk_index)
+                .map_err(|_| KreuzbergError::validation("Chunk index does not fit in an integer column"))?;
+            let vector = vector_literal(&record.vector);
+            let payload = serde_json::to_string(&record.payload)?;
+            self.client
+                .execute(
+                    &statement,
+                    &[
+                        &record.id,
+                        &record.payload.document_id,
+                        &chunk_index,
+                        &record.payload.content,
+                        &vector,
+                        &payload,
+                    ],
+                )
+                .await
+                .map_err(|e| sink_error("pgvector", e))?;
+        }
+
+        Ok(records.len())
+    }
+}
+
+/// Table names are interpolated into SQL, so only plain (optionally schema-qualified) identifiers are accepted.
+fn validate_table(table: String) -> Result<String> {
+    let valid = !table.is_empty()
+        && table.split('.').all(|part| {
+            part.chars().next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+    if valid {
+        Ok(table)
+    } else {
+        Err(KreuzbergError::validation(format!(
+            "Invalid pgvector table name '{}': use letters, digits and underscores",
+            table
+        )))
+    }
+}
+
+/// pgvector text representation, e.g. `[0.1,0.2]`.
+fn vector_literal(vector: &[f32]) -> String {
+    let values: Vec<String> = vector.iter().map(f32::to_string).collect();
+    format!("[{}]", values.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_table() {
+        assert!(validate_table("chunks".to_string()).is_ok());
+        assert!(validate_table("search.doc_chunks".to_string()).is_ok());
+        assert!(validate_table("chunks; DROP TABLE users".to_string()).is_err());
+        assert!(validate_table("1chunks".to_string()).is_err());
+        assert!(validate_table("search.".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_vector_literal() {
+        assert_eq!(vector_literal(&[0.5, -1.0, 2.25]), "[0.5,-1,2.25]");
+    }
+}
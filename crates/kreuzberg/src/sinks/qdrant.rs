@@ -0,0 +1,113 @@
+//! Qdrant sink over the REST API.
+
+use super::{VectorRecord, VectorSink, sink_error};
+use crate::Result;
+use async_trait::async_trait;
+use serde_json::json;
+
+/// Points sent per upsert request.
+const BATCH_SIZE: usize = 256;
+
+/// Upserts records as points of a Qdrant collection.
+///
+/// Point IDs are the record UUIDs and the payload is the [`ChunkPayload`](super::ChunkPayload).
+#[derive(Debug, Clone)]
+pub struct QdrantSink {
+    client: reqwest::Client,
+    url: String,
+    collection: String,
+    api_key: Option<String>,
+    create_collection: bool,
+}
+
+impl QdrantSink {
+    /// Sink for `collection` on the Qdrant server at `url` (e.g. `http://localhost:6333`).
+    pub fn new(url: impl Into<String>, collection: impl Into<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url: url.into().trim_end_matches('/').to_string(),
+            collection: collection.into(),
+            api_key: None,
+            create_collection: false,
+        }
+    }
+
+    /// Send `api-key` with every request (Qdrant Cloud or secured servers).
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Create the collection (cosine distance) on first upsert if it does not exist.
+    pub fn with_create_collection(mut self, create: bool) -> Self {
+        self.create_collection = create;
+        self
+    }
+
+    fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let request = self
+            .client
+            .request(method, format!("{}/collections/{}{}", self.url, self.collection, path));
+        match &self.api_key {
+            Some(key) => request.header("api-key", key),
+            None => request,
+        }
+    }
+
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<serde_json::Value> {
+        let response = request.send().await.map_err(|e| sink_error("qdrant", e))?;
+        let status = response.status();
+        let body = response.text().await.map_err(|e| sink_error("qdrant", e))?;
+        if !status.is_success() {
+            return Err(sink_error(
+                "qdrant",
+                format!("Collection '{}' request failed ({}): {}", self.collection, status, body),
+            ));
+        }
+        serde_json::from_str(&body).map_err(|e| sink_error("qdrant", e))
+    }
+
+    async fn ensure_collection(&self, dimensions: usize) -> Result<()> {
+        let exists = self.send(self.request(reqwest::Method::GET, "/exists")).await?;
+        if exists["result"]["exists"].as_bool() == Some(true) {
+            return Ok(());
+        }
+
+        let body = json!({ "vectors": { "size": dimensions, "distance": "Cosine" } });
+        self.send(self.request(reqwest::Method::PUT, "").json(&body)).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl VectorSink for QdrantSink {
+    fn name(&self) -> &str {
+        "qdrant"
+    }
+
+    async fn upsert(&self, records: &[VectorRecord]) -> Result<usize> {
+        let Some(first) = records.first() else {
+            return Ok(0);
+        };
+        if self.create_collection {
+            self.ensure_collection(first.vector.len()).await?;
+        }
+
+        for batch in records.chunks(BATCH_SIZE) {
+            let points = batch
+                .iter()
+                .map(|record| {
+                    Ok(json!({
+                        "id": record.id,
+                        "vector": record.vector,
+                        "payload": serde_json::to_value(&record.payload)?,
+                    }))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let request = self.request(reqwest::Method::PUT, "/points?wait=true");
+            self.send(request.json(&json!({ "points": points }))).await?;
+        }
+
+        Ok(records.len())
+    }
+}
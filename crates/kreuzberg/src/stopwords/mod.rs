@@ -22,6 +22,9 @@
 //! - Vietnamese (vi), Yoruba (yo), Chinese (zh), Zulu (zu)
 //!
 //! All stopword lists are embedded in the binary at compile time for zero-overhead access.
+//! They are parsed once, on first use. Keyword extraction and token reduction accept a
+//! [`StopwordsConfig`] to merge domain-specific words into a list or replace it
+//! (see [`apply_stopwords_config`]).
 //!
 //! # Usage
 //!
@@ -79,6 +82,8 @@
 //! }
 //! ```
 
+use crate::core::config::{ExtractionConfig, StopwordsConfig, StopwordsMode};
+use crate::types::ExtractionResult;
 use ahash::{AHashMap, AHashSet};
 use once_cell::sync::Lazy;
 
@@ -325,6 +330,183 @@ pub fn get_stopwords_with_fallback(language: &str, fallback: &str) -> Option<&'s
     get_stopwords(language).or_else(|| get_stopwords(fallback))
 }
 
+/// ISO 639-3 codes (as reported by language detection) of the languages with embedded stopwords.
+const ISO_639_3_TO_1: &[(&str, &str)] = &[
+    ("afr", "af"),
+    ("ara", "ar"),
+    ("arb", "ar"),
+    ("bul", "bg"),
+    ("ben", "bn"),
+    ("bre", "br"),
+    ("cat", "ca"),
+    ("ces", "cs"),
+    ("cmn", "zh"),
+    ("dan", "da"),
+    ("deu", "de"),
+    ("ell", "el"),
+    ("eng", "en"),
+    ("epo", "eo"),
+    ("est", "et"),
+    ("eus", "eu"),
+    ("fas", "fa"),
+    ("fin", "fi"),
+    ("fra", "fr"),
+    ("gle", "ga"),
+    ("glg", "gl"),
+    ("guj", "gu"),
+    ("hau", "ha"),
+    ("heb", "he"),
+    ("hin", "hi"),
+    ("hrv", "hr"),
+    ("hun", "hu"),
+    ("hye", "hy"),
+    ("ind", "id"),
+    ("ita", "it"),
+    ("jpn", "ja"),
+    ("kan", "kn"),
+    ("kor", "ko"),
+    ("kur", "ku"),
+    ("lat", "la"),
+    ("lav", "lv"),
+    ("lit", "lt"),
+    ("mal", "ml"),
+    ("mar", "mr"),
+    ("msa", "ms"),
+    ("nep", "ne"),
+    ("nld", "nl"),
+    ("nno", "no"),
+    ("nob", "no"),
+    ("nor", "no"),
+    ("pes", "fa"),
+    ("pol", "pl"),
+    ("por", "pt"),
+    ("ron", "ro"),
+    ("rus", "ru"),
+    ("sin", "si"),
+    ("slk", "sk"),
+    ("slv", "sl"),
+    ("som", "so"),
+    ("sot", "st"),
+    ("spa", "es"),
+    ("swa", "sw"),
+    ("swe", "sv"),
+    ("swh", "sw"),
+    ("tam", "ta"),
+    ("tel", "te"),
+    ("tgl", "tl"),
+    ("tha", "th"),
+    ("tur", "tr"),
+    ("ukr", "uk"),
+    ("urd", "ur"),
+    ("vie", "vi"),
+    ("yor", "yo"),
+    ("zho", "zh"),
+    ("zsm", "ms"),
+    ("zul", "zu"),
+];
+
+/// Resolve a language code to the key of its embedded stopword list.
+///
+/// Accepts ISO 639-1 codes and locales (`"en"`, `"pt-BR"`) as well as the ISO 639-3
+/// codes reported by language detection (`"eng"`, `"cmn"`, `"spa"`). Returns `None`
+/// if no stopword list exists for the language.
+///
+/// # Examples
+///
+/// ```rust
+/// use kreuzberg::stopwords::resolve_language;
+///
+/// assert_eq!(resolve_language("deu"), Some("de"));
+/// assert_eq!(resolve_language("cmn"), Some("zh"));
+/// assert_eq!(resolve_language("en_US"), Some("en"));
+/// assert_eq!(resolve_language("xyz"), None);
+/// ```
+pub fn resolve_language(code: &str) -> Option<&'static str> {
+    let normalized = code.to_lowercase();
+    let base = normalized.split(['-', '_']).next().unwrap_or_default();
+
+    let key = if base.len() == 3 {
+        ISO_639_3_TO_1
+            .iter()
+            .find(|(iso3, _)| *iso3 == base)
+            .map(|(_, iso1)| *iso1)?
+    } else {
+        base
+    };
+
+    STOPWORDS.get_key_value(key).map(|(key, _)| key.as_str())
+}
+
+/// Split the content of `result` into text segments with the stopword language of each.
+///
+/// Uses the languages found by language detection: when several were detected, every
+/// paragraph gets its own language, otherwise the whole content uses the dominant one.
+/// Falls back to English when detection is disabled or found no supported language.
+pub(crate) fn language_segments<'a>(
+    result: &'a ExtractionResult,
+    config: &ExtractionConfig,
+) -> Vec<(&'a str, &'static str)> {
+    let detected = result.detected_languages.as_deref().unwrap_or_default();
+    let dominant = detected
+        .first()
+        .and_then(|language| resolve_language(&language.language))
+        .unwrap_or("en");
+
+    #[cfg(feature = "language-detection")]
+    {
+        if detected.len() > 1
+            && let Some(detection) = config.language_detection.as_ref()
+        {
+            let fallback = detected[0].language.as_str();
+            let paragraphs =
+                crate::language_detection::detect_paragraph_languages(&result.content, detection, fallback);
+            return paragraphs
+                .into_iter()
+                .map(|(paragraph, language)| (paragraph, resolve_language(&language).unwrap_or(dominant)))
+                .collect();
+        }
+    }
+    #[cfg(not(feature = "language-detection"))]
+    let _ = config;
+
+    vec![(result.content.as_str(), dominant)]
+}
+
+/// Apply user-supplied stopwords to a stopword set.
+///
+/// With [`StopwordsMode::Merge`] the custom words are added to `stopwords`; with
+/// [`StopwordsMode::Replace`] they become the only stopwords. Custom words are
+/// lowercased and trimmed to match the embedded lists; blank entries are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use kreuzberg::stopwords::{apply_stopwords_config, get_stopwords};
+/// use kreuzberg::{StopwordsConfig, StopwordsMode};
+///
+/// let mut stopwords = get_stopwords("en").cloned().unwrap_or_default();
+/// let config = StopwordsConfig {
+///     custom: vec!["Lorem".to_string()],
+///     mode: StopwordsMode::Merge,
+/// };
+/// apply_stopwords_config(&mut stopwords, &config);
+/// assert!(stopwords.contains("lorem"));
+/// assert!(stopwords.contains("the"));
+/// ```
+pub fn apply_stopwords_config(stopwords: &mut AHashSet<String>, config: &StopwordsConfig) {
+    if config.mode == StopwordsMode::Replace {
+        stopwords.clear();
+    }
+
+    stopwords.extend(
+        config
+            .custom
+            .iter()
+            .map(|word| word.trim().to_lowercase())
+            .filter(|word| !word.is_empty()),
+    );
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -364,6 +546,22 @@ mod tests {
         assert!(es_stopwords.len() >= 200);
     }
 
+    #[test]
+    fn test_resolve_language() {
+        assert_eq!(resolve_language("en"), Some("en"));
+        assert_eq!(resolve_language("EN-gb"), Some("en"));
+        assert_eq!(resolve_language("eng"), Some("en"));
+        assert_eq!(resolve_language("spa"), Some("es"));
+        assert_eq!(resolve_language("jpn"), Some("ja"));
+        assert_eq!(resolve_language("nob"), Some("no"));
+        assert_eq!(resolve_language("xx"), None);
+        assert_eq!(resolve_language("zzz"), None);
+
+        for (_, iso1) in ISO_639_3_TO_1 {
+            assert!(STOPWORDS.contains_key(*iso1), "no stopwords for {iso1}");
+        }
+    }
+
     #[test]
     fn test_all_64_languages_loaded() {
         let expected_languages = [
@@ -1467,4 +1665,36 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_apply_stopwords_config_merge() {
+        let mut stopwords = get_stopwords("en").cloned().unwrap();
+        let original_len = stopwords.len();
+        let config = StopwordsConfig {
+            custom: vec!["  Invoice ".to_string(), "".to_string(), "the".to_string()],
+            mode: StopwordsMode::Merge,
+        };
+
+        apply_stopwords_config(&mut stopwords, &config);
+
+        assert!(stopwords.contains("invoice"));
+        assert!(stopwords.contains("the"));
+        assert!(!stopwords.contains(""));
+        assert_eq!(stopwords.len(), original_len + 1);
+    }
+
+    #[test]
+    fn test_apply_stopwords_config_replace() {
+        let mut stopwords = get_stopwords("de").cloned().unwrap();
+        let config = StopwordsConfig {
+            custom: vec!["Rechnung".to_string(), "Betrag".to_string()],
+            mode: StopwordsMode::Replace,
+        };
+
+        apply_stopwords_config(&mut stopwords, &config);
+
+        assert_eq!(stopwords.len(), 2);
+        assert!(stopwords.contains("rechnung"));
+        assert!(!stopwords.contains("der"));
+    }
 }
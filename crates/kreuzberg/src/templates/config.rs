@@ -0,0 +1,142 @@
+//! Template definitions for field extraction.
+
+use crate::Result;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Template-based field extraction configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct TemplateConfig {
+    /// YAML files with a top-level `templates` list, checked after the inline templates in order.
+    #[serde(default)]
+    pub files: Vec<PathBuf>,
+
+    /// Inline template definitions, checked first.
+    #[serde(default)]
+    pub templates: Vec<DocumentTemplate>,
+}
+
+impl TemplateConfig {
+    /// The inline templates followed by those of `files`, in the order they are checked.
+    pub fn resolve(&self) -> Result<Vec<DocumentTemplate>> {
+        let mut templates = self.templates.clone();
+        for file in &self.files {
+            templates.extend(super::load_templates(file)?);
+        }
+        Ok(templates)
+    }
+}
+
+/// Field specification for one document type, such as the invoices of one supplier.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DocumentTemplate {
+    /// Template name, recorded in `metadata.additional["template"]` of matching documents.
+    pub name: String,
+
+    /// Conditions a document must meet for the template to apply (default: always applies).
+    #[serde(default, rename = "match")]
+    pub matcher: TemplateMatch,
+
+    /// Fields to extract, keyed by the name they get in `ExtractionResult.fields`.
+    #[serde(default)]
+    pub fields: BTreeMap<String, FieldSpec>,
+}
+
+/// Conditions for applying a template. All given conditions must hold.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct TemplateMatch {
+    /// Accepted MIME types (default: any).
+    #[serde(default)]
+    pub mime_types: Vec<String>,
+
+    /// Phrases that must all occur in the content, compared case-insensitively.
+    #[serde(default)]
+    pub contains: Vec<String>,
+
+    /// Regular expression that must match somewhere in the content.
+    #[serde(default)]
+    pub pattern: Option<String>,
+}
+
+/// How to locate one field.
+///
+/// `table` takes precedence over `anchor` and `pattern`. With an `anchor`, the value is the
+/// rest of the line after the anchor text (or the next non-empty line if that is blank), and
+/// `pattern` narrows it down further. Without an anchor, `pattern` is searched in the whole
+/// region. Patterns yield their first capture group, or the whole match if they have none.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct FieldSpec {
+    /// Label preceding the value, e.g. `"Invoice No:"`, compared case-insensitively.
+    #[serde(default)]
+    pub anchor: Option<String>,
+
+    /// Regular expression selecting the value.
+    #[serde(default)]
+    pub pattern: Option<String>,
+
+    /// Extract rows of a table instead of a single value.
+    #[serde(default)]
+    pub table: Option<TableFieldSpec>,
+
+    /// Part of the document to search (default: the whole content).
+    #[serde(default)]
+    pub region: Option<PageRegion>,
+
+    /// Type the value is converted to (default: string).
+    #[serde(default, rename = "type")]
+    pub value_type: FieldType,
+}
+
+/// Maps table columns to keys of the extracted rows.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct TableFieldSpec {
+    /// Row key → header text of the source column, matched case-insensitively as a substring.
+    ///
+    /// The first table whose header row contains all columns is used; every following
+    /// row becomes an object with these keys.
+    pub columns: BTreeMap<String, String>,
+}
+
+/// Part of a document, given as a page and a vertical band of its lines.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PageRegion {
+    /// Page number (1-indexed). Requires page extraction (`pages.extract_pages`) for text
+    /// fields; tables are filtered by their page number.
+    #[serde(default)]
+    pub page: Option<usize>,
+
+    /// Start of the band as a fraction of the lines (0.0-1.0, default: 0.0).
+    #[serde(default)]
+    pub top: f64,
+
+    /// End of the band as a fraction of the lines (0.0-1.0, default: 1.0).
+    #[serde(default = "default_bottom")]
+    pub bottom: f64,
+}
+
+impl Default for PageRegion {
+    fn default() -> Self {
+        Self {
+            page: None,
+            top: 0.0,
+            bottom: default_bottom(),
+        }
+    }
+}
+
+/// Type of an extracted value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldType {
+    /// Trimmed text.
+    #[default]
+    String,
+    /// Number, ignoring currency symbols and thousands separators (`"$1,234.50"` → `1234.5`).
+    Number,
+}
+
+fn default_bottom() -> f64 {
+    1.0
+}
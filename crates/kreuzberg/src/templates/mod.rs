@@ -0,0 +1,558 @@
+//! Template-based field extraction.
+//!
+//! Templates describe where the fields of one document type live: after an anchor label,
+//! at a regular expression, in a table column or within a page region. A document is
+//! checked against the templates in order and the first one whose `match` conditions hold
+//! fills `ExtractionResult::fields`. Extraction is deterministic and needs no model, which
+//! suits invoices, purchase orders and other forms with a stable layout.
+//!
+//! # Feature Flags
+//!
+//! - `templates`: Enable template-based field extraction (included in `full` and `cli`)
+//!
+//! # Template files
+//!
+//! ```yaml
+//! templates:
+//!   - name: acme_invoice
+//!     match:
+//!       contains: ["ACME Corp", "Invoice"]
+//!     fields:
+//!       invoice_number:
+//!         anchor: "Invoice No:"
+//!       total:
+//!         pattern: 'Total:?\s*\$?([0-9.,]+)'
+//!         type: number
+//!       issued:
+//!         anchor: "Date:"
+//!         region: { page: 1, top: 0.0, bottom: 0.3 }
+//!       line_items:
+//!         table:
+//!           columns: { description: Description, amount: Amount }
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! # use kreuzberg::templates::{extract_fields, parse_templates};
+//! # use kreuzberg::{ExtractionResult, Metadata};
+//! let templates = parse_templates(
+//!     r#"
+//! templates:
+//!   - name: invoice
+//!     match: { contains: ["invoice"] }
+//!     fields:
+//!       number: { anchor: "Invoice No:" }
+//!       total: { pattern: 'Total: \$([0-9.,]+)', type: number }
+//! "#,
+//! )?;
+//!
+//! let result = ExtractionResult {
+//!     content: "INVOICE\nInvoice No: INV-0042\nTotal: $1,250.00".to_string(),
+//!     mime_type: "text/plain".to_string(),
+//!     metadata: Metadata::default(),
+//!     tables: vec![],
+//!     detected_languages: None,
+//!     chunks: None,
+//!     images: None,
+//!     pages: None,
+//!     entities: None,
+//!     keywords: None,
+//!     children: None,
+//!     fields: None,
+//!     warnings: Vec::new(),
+//! };
+//!
+//! let extracted = extract_fields(&result, &templates)?.expect("template should match");
+//! assert_eq!(extracted.template, "invoice");
+//! assert_eq!(extracted.fields["number"], "INV-0042");
+//! assert_eq!(extracted.fields["total"], 1250);
+//! # Ok::<(), kreuzberg::KreuzbergError>(())
+//! ```
+
+use crate::plugins::registry::get_post_processor_registry;
+use crate::types::Table;
+use crate::{ExtractionResult, KreuzbergError, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+pub mod config;
+pub mod processor;
+
+pub use config::{DocumentTemplate, FieldSpec, FieldType, PageRegion, TableFieldSpec, TemplateConfig, TemplateMatch};
+pub use processor::TemplateProcessor;
+
+/// Fields extracted from a document by a matching template.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemplateFields {
+    /// Name of the template that matched.
+    pub template: String,
+    /// Extracted values keyed by field name; `null` where a field was not found.
+    pub fields: BTreeMap<String, Value>,
+}
+
+/// Layout of a template file.
+#[derive(Deserialize)]
+struct TemplateFile {
+    templates: Vec<DocumentTemplate>,
+}
+
+/// Parse templates from YAML with a top-level `templates` list.
+pub fn parse_templates(yaml: &str) -> Result<Vec<DocumentTemplate>> {
+    let file: TemplateFile = serde_yaml_ng::from_str(yaml)
+        .map_err(|e| KreuzbergError::validation(format!("Invalid template definition: {}", e)))?;
+    for template in &file.templates {
+        check_template(template)?;
+    }
+    Ok(file.templates)
+}
+
+/// Load templates from a YAML file with a top-level `templates` list.
+pub fn load_templates(path: impl AsRef<Path>) -> Result<Vec<DocumentTemplate>> {
+    let path = path.as_ref();
+    let yaml = std::fs::read_to_string(path)?;
+    parse_templates(&yaml).map_err(|e| match e {
+        KreuzbergError::Validation { message, source } => KreuzbergError::Validation {
+            message: format!("{} ({})", message, path.display()),
+            source,
+        },
+        other => other,
+    })
+}
+
+/// Apply the first template that matches `result`.
+///
+/// Returns `None` when no template matches.
+///
+/// # Errors
+///
+/// Returns a `Validation` error if a template contains an invalid regular expression.
+pub fn extract_fields(result: &ExtractionResult, templates: &[DocumentTemplate]) -> Result<Option<TemplateFields>> {
+    for template in templates {
+        if !matches(template, result)? {
+            continue;
+        }
+
+        let mut fields = BTreeMap::new();
+        for (name, spec) in &template.fields {
+            let pattern = spec
+                .pattern
+                .as_deref()
+                .map(|pattern| compile(pattern, &template.name, name))
+                .transpose()?;
+            fields.insert(name.clone(), extract_field(result, spec, pattern.as_ref()));
+        }
+        return Ok(Some(TemplateFields {
+            template: template.name.clone(),
+            fields,
+        }));
+    }
+    Ok(None)
+}
+
+/// Check that the patterns of `template` compile and its regions are valid.
+pub(crate) fn check_template(template: &DocumentTemplate) -> Result<()> {
+    if let Some(pattern) = &template.matcher.pattern {
+        compile(pattern, &template.name, "match")?;
+    }
+    for (name, spec) in &template.fields {
+        if let Some(pattern) = &spec.pattern {
+            compile(pattern, &template.name, name)?;
+        }
+        if let Some(region) = &spec.region {
+            let valid = (0.0..=1.0).contains(&region.top)
+                && (0.0..=1.0).contains(&region.bottom)
+                && region.top < region.bottom
+                && region.page != Some(0);
+            if !valid {
+                return Err(KreuzbergError::validation(format!(
+                    "Invalid region for field '{}' of template '{}': expected page >= 1 and 0 <= top < bottom <= 1",
+                    name, template.name
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn compile(pattern: &str, template: &str, field: &str) -> Result<Regex> {
+    Regex::new(pattern).map_err(|e| {
+        KreuzbergError::validation(format!(
+            "Invalid pattern for '{}' in template '{}': {}",
+            field, template, e
+        ))
+    })
+}
+
+fn matches(template: &DocumentTemplate, result: &ExtractionResult) -> Result<bool> {
+    let matcher = &template.matcher;
+    let mime_type = result.mime_type.as_str();
+    if !matcher.mime_types.is_empty() && !matcher.mime_types.iter().any(|m| m.eq_ignore_ascii_case(mime_type)) {
+        return Ok(false);
+    }
+
+    if !matcher.contains.is_empty() {
+        let content = result.content.to_lowercase();
+        if !matcher.contains.iter().all(|p| content.contains(&p.to_lowercase())) {
+            return Ok(false);
+        }
+    }
+
+    match &matcher.pattern {
+        Some(pattern) => Ok(compile(pattern, &template.name, "match")?.is_match(&result.content)),
+        None => Ok(true),
+    }
+}
+
+fn extract_field(result: &ExtractionResult, spec: &FieldSpec, pattern: Option<&Regex>) -> Value {
+    if let Some(table) = &spec.table {
+        let page = spec.region.as_ref().and_then(|region| region.page);
+        return table_rows(&result.tables, table, page);
+    }
+
+    let Some(text) = region_text(result, spec.region.as_ref()) else {
+        return Value::Null;
+    };
+    let value = match (&spec.anchor, pattern) {
+        (Some(anchor), Some(pattern)) => after_anchor(&text, anchor).and_then(|value| capture(pattern, value)),
+        (Some(anchor), None) => after_anchor(&text, anchor),
+        (None, Some(pattern)) => capture(pattern, &text),
+        (None, None) => None,
+    };
+    value.map_or(Value::Null, |value| convert(value, spec.value_type))
+}
+
+/// Text of `region`, or `None` if its page is not available.
+fn region_text<'a>(result: &'a ExtractionResult, region: Option<&PageRegion>) -> Option<Cow<'a, str>> {
+    let Some(region) = region else {
+        return Some(Cow::Borrowed(&result.content));
+    };
+    let text = match region.page {
+        Some(page) => {
+            let pages = result.pages.as_ref()?;
+            pages.iter().find(|p| p.page_number == page)?.content.as_str()
+        }
+        None => result.content.as_str(),
+    };
+    if region.top <= 0.0 && region.bottom >= 1.0 {
+        return Some(Cow::Borrowed(text));
+    }
+
+    let lines: Vec<&str> = text.lines().collect();
+    let start = (region.top.clamp(0.0, 1.0) * lines.len() as f64).floor() as usize;
+    let end = (region.bottom.clamp(0.0, 1.0) * lines.len() as f64).ceil() as usize;
+    Some(Cow::Owned(lines[start.min(end)..end].join("\n")))
+}
+
+/// The rest of the line after `anchor`, or the next non-empty line if that is blank.
+fn after_anchor<'a>(text: &'a str, anchor: &str) -> Option<&'a str> {
+    if anchor.is_empty() {
+        return None;
+    }
+    // ASCII lowercasing keeps byte offsets valid for the original text.
+    let start = text.to_ascii_lowercase().find(&anchor.to_ascii_lowercase())?;
+    let rest = &text[start + anchor.len()..];
+
+    let mut lines = rest.lines();
+    let same_line = lines.next().unwrap_or_default().trim().trim_start_matches(':').trim();
+    if !same_line.is_empty() {
+        return Some(same_line);
+    }
+    lines.map(str::trim).find(|line| !line.is_empty())
+}
+
+/// First capture group of `pattern` in `text`, or the whole match.
+fn capture<'a>(pattern: &Regex, text: &'a str) -> Option<&'a str> {
+    let captures = pattern.captures(text)?;
+    captures.get(1).or_else(|| captures.get(0)).map(|m| m.as_str())
+}
+
+fn convert(value: &str, value_type: FieldType) -> Value {
+    let value = value.trim();
+    match value_type {
+        FieldType::String => Value::String(value.to_string()),
+        FieldType::Number => match parse_number(value) {
+            Some(number) if number.fract() == 0.0 && number.abs() < 9_007_199_254_740_992.0 => {
+                Value::from(number as i64)
+            }
+            Some(number) => serde_json::Number::from_f64(number).map_or(Value::Null, Value::Number),
+            None => Value::Null,
+        },
+    }
+}
+
+/// Parse a formatted amount such as `$1,234.50`, `1.234,50 €` or `(42.00)`.
+///
+/// The last `.` or `,` is the decimal separator when both occur; a lone `,` followed by
+/// exactly three digits, or a repeated separator, is a thousands separator.
+fn parse_number(text: &str) -> Option<f64> {
+    let negative = text.starts_with('-') || (text.starts_with('(') && text.ends_with(')'));
+    let digits: String = text
+        .chars()
+        .filter(|&c| c.is_ascii_digit() || matches!(c, '.' | ','))
+        .collect();
+
+    let decimal = match (digits.rfind('.'), digits.rfind(',')) {
+        (Some(dot), Some(comma)) => Some(dot.max(comma)),
+        (Some(dot), None) => (digits.matches('.').count() == 1).then_some(dot),
+        (None, Some(comma)) => (digits.matches(',').count() == 1 && digits.len() - comma != 4).then_some(comma),
+        (None, None) => None,
+    };
+    let normalized: String = digits
+        .char_indices()
+        .filter_map(|(index, c)| match c {
+            '.' | ',' => (Some(index) == decimal).then_some('.'),
+            _ => Some(c),
+        })
+        .collect();
+
+    let number: f64 = normalized.parse().ok()?;
+    Some(if negative { -number } else { number })
+}
+
+/// Rows of the first table on `page` whose header contains every mapped column.
+fn table_rows(tables: &[Table], spec: &TableFieldSpec, page: Option<usize>) -> Value {
+    let candidates = tables.iter().filter(|t| page.is_none_or(|p| t.page_number == p));
+    for table in candidates {
+        let Some((header, rows)) = table.cells.split_first() else {
+            continue;
+        };
+        let columns: Option<Vec<(&String, usize)>> = spec
+            .columns
+            .iter()
+            .map(|(key, heading)| {
+                let heading = heading.to_lowercase();
+                let index = header.iter().position(|cell| cell.to_lowercase().contains(&heading))?;
+                Some((key, index))
+            })
+            .collect();
+        let Some(columns) = columns else {
+            continue;
+        };
+
+        let rows = rows
+            .iter()
+            .filter_map(|row| {
+                let object: Map<String, Value> = columns
+                    .iter()
+                    .map(|(key, index)| {
+                        let cell = row.get(*index).map_or("", |cell| cell.trim());
+                        ((*key).clone(), Value::String(cell.to_string()))
+                    })
+                    .collect();
+                let empty = object.values().all(|value| value.as_str() == Some(""));
+                (!empty).then_some(Value::Object(object))
+            })
+            .collect();
+        return Value::Array(rows);
+    }
+    Value::Null
+}
+
+/// Lazy-initialized flag that ensures the template processor is registered exactly once.
+static PROCESSOR_INITIALIZED: Lazy<Result<()>> = Lazy::new(register_template_processor);
+
+/// Ensure the template processor is registered.
+///
+/// This function is called automatically when needed.
+/// It's safe to call multiple times - registration only happens once.
+pub fn ensure_initialized() -> Result<()> {
+    PROCESSOR_INITIALIZED
+        .as_ref()
+        .map(|_| ())
+        .map_err(|e| crate::KreuzbergError::Plugin {
+            message: format!("Failed to register template processor: {}", e),
+            plugin_name: "template-fields".to_string(),
+        })
+}
+
+/// Register the template field extraction processor with the global registry.
+///
+/// **Note:** This is called automatically on first use.
+/// Explicit calling is optional.
+pub fn register_template_processor() -> Result<()> {
+    let registry = get_post_processor_registry();
+    let mut registry = registry
+        .write()
+        .map_err(|e| crate::KreuzbergError::Other(format!("Post-processor registry lock poisoned: {}", e)))?;
+
+    registry.register(Arc::new(TemplateProcessor), 42)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, PageContent};
+
+    const INVOICE: &str = "ACME Corp
+Invoice No: INV-2024-001
+Date:
+  2024-03-15
+
+Subtotal 1.000,00
+Total: $1,190.00";
+
+    fn result_with(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn templates(yaml: &str) -> Vec<DocumentTemplate> {
+        parse_templates(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_anchor_and_pattern_fields() {
+        let templates = templates(
+            r#"
+templates:
+  - name: acme
+    match: { mime_types: [application/pdf], contains: ["acme corp"] }
+    fields:
+      number: { anchor: "invoice no" }
+      date: { anchor: "Date:" }
+      subtotal: { anchor: "Subtotal", type: number }
+      total: { pattern: 'Total: \$([0-9.,]+)', type: number }
+      po_number: { anchor: "PO:" }
+"#,
+        );
+
+        let extracted = extract_fields(&result_with(INVOICE), &templates).unwrap().unwrap();
+        assert_eq!(extracted.template, "acme");
+        assert_eq!(extracted.fields["number"], "INV-2024-001");
+        assert_eq!(extracted.fields["date"], "2024-03-15");
+        assert_eq!(extracted.fields["subtotal"], 1000);
+        assert_eq!(extracted.fields["total"], 1190);
+        assert_eq!(extracted.fields["po_number"], Value::Null);
+    }
+
+    #[test]
+    fn test_first_matching_template_wins() {
+        let templates = templates(
+            r#"
+templates:
+  - name: globex
+    match: { contains: ["Globex"] }
+  - name: acme
+    match: { pattern: 'Invoice No: INV-\d+' }
+  - name: fallback
+"#,
+        );
+
+        let extracted = extract_fields(&result_with(INVOICE), &templates).unwrap().unwrap();
+        assert_eq!(extracted.template, "acme");
+        assert!(
+            extract_fields(&result_with(INVOICE), &templates[..1])
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_table_columns() {
+        let mut result = result_with(INVOICE);
+        result.tables = vec![Table {
+            cells: vec![
+                vec!["Item Description".to_string(), "Qty".to_string(), "Amount".to_string()],
+                vec!["Widget".to_string(), "2".to_string(), "$20.00".to_string()],
+                vec![String::new(), String::new(), String::new()],
+                vec!["Gadget".to_string(), "1".to_string(), "$5.00".to_string()],
+            ],
+            markdown: String::new(),
+            page_number: 1,
+        }];
+        let templates = templates(
+            r#"
+templates:
+  - name: items
+    fields:
+      line_items: { table: { columns: { description: description, amount: AMOUNT } } }
+      on_page_two: { table: { columns: { amount: Amount } }, region: { page: 2 } }
+"#,
+        );
+
+        let extracted = extract_fields(&result, &templates).unwrap().unwrap();
+        let items = extracted.fields["line_items"].as_array().unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0]["description"], "Widget");
+        assert_eq!(items[1]["amount"], "$5.00");
+        assert_eq!(extracted.fields["on_page_two"], Value::Null);
+    }
+
+    #[test]
+    fn test_page_region() {
+        let mut result = result_with("Reference: A1\nbody\nbody\nReference: B2");
+        result.pages = Some(vec![PageContent {
+            page_number: 1,
+            content: "Reference: A1\nbody\nbody\nReference: B2".to_string(),
+            tables: vec![],
+            images: vec![],
+        }]);
+        let templates = templates(
+            r#"
+templates:
+  - name: regions
+    fields:
+      head: { anchor: "Reference:", region: { page: 1, bottom: 0.25 } }
+      foot: { anchor: "Reference:", region: { top: 0.75 } }
+      missing_page: { anchor: "Reference:", region: { page: 3 } }
+"#,
+        );
+
+        let extracted = extract_fields(&result, &templates).unwrap().unwrap();
+        assert_eq!(extracted.fields["head"], "A1");
+        assert_eq!(extracted.fields["foot"], "B2");
+        assert_eq!(extracted.fields["missing_page"], Value::Null);
+    }
+
+    #[test]
+    fn test_invalid_templates() {
+        let bad_pattern = r#"
+templates:
+  - name: bad
+    fields:
+      total: { pattern: '([0-9' }
+"#;
+        assert!(parse_templates(bad_pattern).is_err());
+
+        let bad_region = r#"
+templates:
+  - name: bad
+    fields:
+      total: { anchor: "Total", region: { top: 0.8, bottom: 0.2 } }
+"#;
+        assert!(parse_templates(bad_region).is_err());
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number("$1,234.50"), Some(1234.5));
+        assert_eq!(parse_number("1.234,50 €"), Some(1234.5));
+        assert_eq!(parse_number("12,5"), Some(12.5));
+        assert_eq!(parse_number("1,234"), Some(1234.0));
+        assert_eq!(parse_number("1.234.567"), Some(1234567.0));
+        assert_eq!(parse_number("(42.00)"), Some(-42.0));
+        assert_eq!(parse_number("n/a"), None);
+    }
+}
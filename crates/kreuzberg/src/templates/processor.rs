@@ -0,0 +1,142 @@
+//! Template field extraction post-processor.
+//!
+//! This module provides a PostProcessor plugin that applies the configured document
+//! templates and stores the extracted values in `ExtractionResult::fields`.
+
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::{ExtractionConfig, ExtractionResult, Result};
+use async_trait::async_trait;
+
+/// Post-processor that extracts template fields from document content and tables.
+///
+/// This processor:
+/// - Runs in the Middle processing stage
+/// - Only processes when `config.templates` is configured
+/// - Stores the values of the first matching template in `result.fields` and its name in
+///   `result.metadata.additional["template"]`
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::plugins::Plugin;
+/// use kreuzberg::templates::TemplateProcessor;
+///
+/// let processor = TemplateProcessor;
+/// assert_eq!(processor.name(), "template-fields");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateProcessor;
+
+impl Plugin for TemplateProcessor {
+    fn name(&self) -> &str {
+        "template-fields"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for TemplateProcessor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let template_config = match &config.templates {
+            Some(cfg) => cfg,
+            None => return Ok(()),
+        };
+
+        let templates = template_config.resolve()?;
+        if let Some(extracted) = super::extract_fields(result, &templates)? {
+            result
+                .metadata
+                .additional
+                .insert("template".to_string(), serde_json::Value::String(extracted.template));
+            result.fields = Some(extracted.fields);
+        }
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Middle
+    }
+
+    fn should_process(&self, _result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        config.templates.is_some()
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        (result.content.len() as u64) / 50_000 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::templates::TemplateConfig;
+    use crate::types::Metadata;
+
+    fn result_with(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_template_processor_populates_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("templates.yaml");
+        std::fs::write(
+            &file,
+            "templates:\n  - name: receipt\n    fields:\n      store: { anchor: 'Store:' }\n",
+        )
+        .unwrap();
+        let config = ExtractionConfig {
+            templates: Some(TemplateConfig {
+                files: vec![file],
+                templates: vec![],
+            }),
+            ..Default::default()
+        };
+
+        let mut result = result_with("Store: Corner Shop\nTotal 4.20");
+        TemplateProcessor.process(&mut result, &config).await.unwrap();
+
+        let fields = result.fields.expect("fields should be set");
+        assert_eq!(fields["store"], "Corner Shop");
+        assert_eq!(result.metadata.additional["template"], "receipt");
+    }
+
+    #[tokio::test]
+    async fn test_template_processor_no_config() {
+        let processor = TemplateProcessor;
+        let config = ExtractionConfig::default();
+
+        let mut result = result_with("Store: Corner Shop");
+        processor.process(&mut result, &config).await.unwrap();
+
+        assert!(result.fields.is_none());
+        assert!(!processor.should_process(&result, &config));
+    }
+}
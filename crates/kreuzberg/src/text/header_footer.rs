@@ -0,0 +1,556 @@
+//! Running header, footer and page-number removal.
+//!
+//! Paginated documents (PDF in particular) repeat the same lines at the top and bottom of
+//! every page: document titles, chapter names, copyright notices, "Page X of Y". Left in
+//! `content`, this boilerplate ends up in every chunk. [`HeaderFooterProcessor`] detects lines
+//! that recur at the same edge of many pages and strips them.
+//!
+//! Lines are compared after trimming, lowercasing, collapsing whitespace and replacing every
+//! run of digits with `#`, so running lines that contain page numbers still match.
+
+use crate::core::config::HeaderFooterConfig;
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::types::PageBoundary;
+use crate::{ExtractionConfig, ExtractionResult, Result};
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+
+/// Result of removing headers and footers from a list of pages.
+#[derive(Debug, Clone, Default)]
+pub struct HeaderFooterRemoval {
+    /// Cleaned page texts, in input order
+    pub pages: Vec<String>,
+    /// Distinct removed lines (trimmed)
+    pub removed: HashSet<String>,
+    /// Total number of removed lines across all pages
+    pub removed_count: usize,
+}
+
+/// Remove running headers, footers and page numbers from `pages`.
+///
+/// Returns `None` if there are fewer than `config.min_pages` pages or nothing was removed.
+pub fn remove_headers_footers(pages: &[&str], config: &HeaderFooterConfig) -> Option<HeaderFooterRemoval> {
+    if pages.len() < config.min_pages.max(2) {
+        return None;
+    }
+
+    let edges: Vec<Vec<EdgeLine>> = pages
+        .iter()
+        .map(|page| edge_lines(page, config.lines_to_check))
+        .collect();
+
+    let mut counts: HashMap<(Edge, &str), usize> = HashMap::new();
+    for page_edges in &edges {
+        let distinct: HashSet<(Edge, &str)> = page_edges.iter().map(|line| (line.edge, line.key.as_str())).collect();
+        for key in distinct {
+            *counts.entry(key).or_default() += 1;
+        }
+    }
+
+    let threshold = ((pages.len() as f64 * config.min_repeat_ratio.clamp(0.0, 1.0)).ceil() as usize).max(2);
+
+    let mut removal = HeaderFooterRemoval::default();
+    for (page, page_edges) in pages.iter().zip(&edges) {
+        let drop: HashSet<usize> = page_edges
+            .iter()
+            .filter(|line| {
+                counts.get(&(line.edge, line.key.as_str())).copied().unwrap_or(0) >= threshold
+                    || (config.remove_page_numbers && is_page_number(&line.key))
+            })
+            .map(|line| line.index)
+            .collect();
+
+        let mut kept = Vec::new();
+        for (index, line) in page.split('\n').enumerate() {
+            if drop.contains(&index) {
+                removal.removed.insert(line.trim().to_string());
+                removal.removed_count += 1;
+            } else {
+                kept.push(line);
+            }
+        }
+        let cleaned = kept.join("\n");
+        removal.pages.push(cleaned);
+    }
+
+    (removal.removed_count > 0).then_some(removal)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Edge {
+    Top,
+    Bottom,
+}
+
+#[derive(Debug)]
+struct EdgeLine {
+    index: usize,
+    edge: Edge,
+    key: String,
+}
+
+/// The first and last `count` non-empty lines of a page with their normalized keys.
+fn edge_lines(page: &str, count: usize) -> Vec<EdgeLine> {
+    let non_empty: Vec<(usize, &str)> = page
+        .split('\n')
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .collect();
+
+    let top = non_empty
+        .iter()
+        .take(count)
+        .map(|&(index, line)| (index, line, Edge::Top));
+    let bottom_start = non_empty.len().saturating_sub(count).max(count.min(non_empty.len()));
+    let bottom = non_empty[bottom_start..]
+        .iter()
+        .map(|&(index, line)| (index, line, Edge::Bottom));
+
+    top.chain(bottom)
+        .map(|(index, line, edge)| EdgeLine {
+            index,
+            edge,
+            key: normalize_line(line),
+        })
+        .collect()
+}
+
+/// Lowercase, collapse whitespace and replace digit runs with `#`.
+fn normalize_line(line: &str) -> String {
+    let mut key = String::with_capacity(line.len());
+    let mut in_digits = false;
+    for word in line.split_whitespace() {
+        if !key.is_empty() {
+            key.push(' ');
+            in_digits = false;
+        }
+        for c in word.chars() {
+            if c.is_ascii_digit() {
+                if !in_digits {
+                    key.push('#');
+                }
+                in_digits = true;
+            } else {
+                key.extend(c.to_lowercase());
+                in_digits = false;
+            }
+        }
+    }
+    key
+}
+
+/// Whether a normalized line is a bare page number such as `#`, `- # -`, `page #` or `# of #`.
+fn is_page_number(key: &str) -> bool {
+    let stripped: String = key
+        .trim_start_matches("page")
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '–' | '—' | '|'))
+        .collect();
+
+    matches!(stripped.as_str(), "#" | "#of#" | "#/#")
+}
+
+/// Post-processor removing running headers, footers and page numbers.
+///
+/// Enabled by setting [`PostProcessorConfig::header_footer`](crate::PostProcessorConfig::header_footer).
+/// Runs early (before language detection, keyword extraction and chunking) and records the
+/// number of removed lines in `metadata.additional["header_footer_lines_removed"]`.
+#[derive(Debug, Clone, Copy)]
+pub struct HeaderFooterProcessor;
+
+impl Plugin for HeaderFooterProcessor {
+    fn name(&self) -> &str {
+        "header-footer-removal"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for HeaderFooterProcessor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let Some(hf_config) = header_footer_config(config) else {
+            return Ok(());
+        };
+
+        let removed_count = match page_boundaries(result) {
+            Some(boundaries) => strip_with_boundaries(result, &boundaries, hf_config),
+            None => strip_with_pages(result, hf_config),
+        };
+
+        if removed_count > 0 {
+            result.metadata.additional.insert(
+                "header_footer_lines_removed".to_string(),
+                serde_json::Value::from(removed_count),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Early
+    }
+
+    fn should_process(&self, result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        header_footer_config(config).is_some() && (page_boundaries(result).is_some() || result.pages.is_some())
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        (result.content.len() / 102400).max(1) as u64
+    }
+}
+
+fn header_footer_config(config: &ExtractionConfig) -> Option<&HeaderFooterConfig> {
+    config.postprocessor.as_ref()?.header_footer.as_ref()
+}
+
+/// Page boundaries from metadata, if they are ordered and valid for the current content.
+pub(crate) fn page_boundaries(result: &ExtractionResult) -> Option<Vec<PageBoundary>> {
+    let boundaries = result.metadata.pages.as_ref()?.boundaries.as_ref()?;
+    let content = &result.content;
+
+    let mut previous_end = 0;
+    for boundary in boundaries {
+        if boundary.byte_start < previous_end
+            || boundary.byte_start > boundary.byte_end
+            || boundary.byte_end > content.len()
+            || !content.is_char_boundary(boundary.byte_start)
+            || !content.is_char_boundary(boundary.byte_end)
+        {
+            return None;
+        }
+        previous_end = boundary.byte_end;
+    }
+
+    Some(boundaries.clone())
+}
+
+/// Clean each page slice of `content` and rebuild the content and boundaries.
+fn strip_with_boundaries(
+    result: &mut ExtractionResult,
+    boundaries: &[PageBoundary],
+    config: &HeaderFooterConfig,
+) -> usize {
+    let slices: Vec<&str> = boundaries
+        .iter()
+        .map(|b| &result.content[b.byte_start..b.byte_end])
+        .collect();
+
+    let Some(removal) = remove_headers_footers(&slices, config) else {
+        return 0;
+    };
+
+    replace_page_texts(result, boundaries, &removal.pages);
+    removal.removed_count
+}
+
+/// Replace the page slices of `content` with `pages` and rebuild the page boundaries.
+///
+/// `pages` must hold one entry per boundary. Per-page content in `result.pages` is updated too.
+pub(crate) fn replace_page_texts(result: &mut ExtractionResult, boundaries: &[PageBoundary], pages: &[String]) {
+    let mut content = String::with_capacity(result.content.len());
+    let mut new_boundaries = Vec::with_capacity(boundaries.len());
+    let mut cursor = 0;
+
+    for (boundary, cleaned) in boundaries.iter().zip(pages) {
+        content.push_str(&result.content[cursor..boundary.byte_start]);
+        let byte_start = content.len();
+        content.push_str(cleaned);
+        new_boundaries.push(PageBoundary {
+            byte_start,
+            byte_end: content.len(),
+            page_number: boundary.page_number,
+        });
+        cursor = boundary.byte_end;
+    }
+    content.push_str(&result.content[cursor..]);
+
+    if let Some(page_contents) = result.pages.as_mut() {
+        for page in page_contents.iter_mut() {
+            if let Some(position) = boundaries.iter().position(|b| b.page_number == page.page_number) {
+                page.content = pages[position].clone();
+            }
+        }
+    }
+
+    result.content = content;
+    if let Some(structure) = result.metadata.pages.as_mut() {
+        structure.boundaries = Some(new_boundaries);
+    }
+}
+
+/// Clean per-page content and splice the cleaned pages into `content`.
+///
+/// Each page's original text is located in `content` in page order and replaced by its cleaned
+/// version, so only lines within the checked edges of a page are removed. Pages whose text does
+/// not appear verbatim in `content` are left untouched there.
+fn strip_with_pages(result: &mut ExtractionResult, config: &HeaderFooterConfig) -> usize {
+    let Some(pages) = result.pages.as_mut() else {
+        return 0;
+    };
+
+    let slices: Vec<&str> = pages.iter().map(|page| page.content.as_str()).collect();
+    let Some(removal) = remove_headers_footers(&slices, config) else {
+        return 0;
+    };
+
+    let mut content = String::with_capacity(result.content.len());
+    let mut cursor = 0;
+    for (page, cleaned) in pages.iter().zip(&removal.pages) {
+        if let Some(offset) = result.content[cursor..].find(page.content.as_str()) {
+            let start = cursor + offset;
+            content.push_str(&result.content[cursor..start]);
+            content.push_str(cleaned);
+            cursor = start + page.content.len();
+        }
+    }
+    content.push_str(&result.content[cursor..]);
+    result.content = content;
+
+    for (page, cleaned) in pages.iter_mut().zip(removal.pages) {
+        page.content = cleaned;
+    }
+
+    removal.removed_count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, PageContent, PageStructure, PageUnitType};
+
+    fn page(number: usize, body: &str) -> String {
+        format!("ACME Corp Annual Report\n{}\nPage {} of 3", body, number)
+    }
+
+    #[test]
+    fn test_normalize_line() {
+        assert_eq!(normalize_line("  Page 12   of 40 "), "page # of #");
+        assert_eq!(normalize_line("Chapter 3: Results"), "chapter #: results");
+    }
+
+    #[test]
+    fn test_is_page_number() {
+        assert!(is_page_number(&normalize_line("12")));
+        assert!(is_page_number(&normalize_line("- 12 -")));
+        assert!(is_page_number(&normalize_line("Page 3 of 9")));
+        assert!(is_page_number(&normalize_line("3 / 9")));
+        assert!(!is_page_number(&normalize_line("Figure 3")));
+    }
+
+    #[test]
+    fn test_remove_headers_footers() {
+        let pages = [page(1, "First body"), page(2, "Second body"), page(3, "Third body")];
+        let slices: Vec<&str> = pages.iter().map(String::as_str).collect();
+
+        let removal = remove_headers_footers(&slices, &HeaderFooterConfig::default()).unwrap();
+        assert_eq!(removal.pages, vec!["First body", "Second body", "Third body"]);
+        assert_eq!(removal.removed_count, 6);
+    }
+
+    #[test]
+    fn test_remove_headers_footers_requires_min_pages() {
+        let pages = [page(1, "First body"), page(2, "Second body")];
+        let slices: Vec<&str> = pages.iter().map(String::as_str).collect();
+        assert!(remove_headers_footers(&slices, &HeaderFooterConfig::default()).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_processor_updates_content_and_boundaries() {
+        let pages = [page(1, "First body"), page(2, "Second body"), page(3, "Third body")];
+        let mut content = String::new();
+        let mut boundaries = Vec::new();
+        for (i, text) in pages.iter().enumerate() {
+            if i > 0 {
+                content.push_str("\n\n");
+            }
+            let byte_start = content.len();
+            content.push_str(text);
+            boundaries.push(PageBoundary {
+                byte_start,
+                byte_end: content.len(),
+                page_number: i + 1,
+            });
+        }
+
+        let mut result = ExtractionResult {
+            content,
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: 3,
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(boundaries),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+
+        let config = ExtractionConfig {
+            postprocessor: Some(crate::PostProcessorConfig {
+                header_footer: Some(HeaderFooterConfig::default()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let processor = HeaderFooterProcessor;
+        assert!(processor.should_process(&result, &config));
+        processor.process(&mut result, &config).await.unwrap();
+
+        assert_eq!(result.content, "First body\n\nSecond body\n\nThird body");
+        let boundaries = result.metadata.pages.unwrap().boundaries.unwrap();
+        assert_eq!(
+            &result.content[boundaries[1].byte_start..boundaries[1].byte_end],
+            "Second body"
+        );
+        assert_eq!(result.metadata.additional["header_footer_lines_removed"], 6);
+    }
+
+    #[tokio::test]
+    async fn test_processor_with_page_content() {
+        // Bodies differ beyond their numbers; "Body 1".."Body 3" would repeat like a header.
+        let pages: Vec<PageContent> = ["Introduction", "Methods", "Results"]
+            .iter()
+            .enumerate()
+            .map(|(i, body)| PageContent {
+                page_number: i + 1,
+                content: page(i + 1, body),
+                tables: vec![],
+                images: vec![],
+            })
+            .collect();
+        let content = pages.iter().map(|p| p.content.as_str()).collect::<Vec<_>>().join("\n");
+
+        let mut result = ExtractionResult {
+            content,
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: Some(pages),
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+
+        let config = ExtractionConfig {
+            postprocessor: Some(crate::PostProcessorConfig {
+                header_footer: Some(HeaderFooterConfig::default()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        HeaderFooterProcessor.process(&mut result, &config).await.unwrap();
+
+        assert_eq!(result.content, "Introduction\nMethods\nResults");
+        assert_eq!(result.pages.unwrap()[0].content, "Introduction");
+    }
+
+    #[tokio::test]
+    async fn test_processor_with_page_content_keeps_body_lines_matching_headers() {
+        let bodies = [
+            "Body 1",
+            "Intro\nDetails\nACME Corp Annual Report\nMore details\nSummary",
+            "Body 3",
+        ];
+        let pages: Vec<PageContent> = bodies
+            .iter()
+            .enumerate()
+            .map(|(i, body)| PageContent {
+                page_number: i + 1,
+                content: page(i + 1, body),
+                tables: vec![],
+                images: vec![],
+            })
+            .collect();
+        let content = pages
+            .iter()
+            .map(|p| p.content.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        let mut result = ExtractionResult {
+            content,
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: Some(pages),
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+
+        let config = ExtractionConfig {
+            postprocessor: Some(crate::PostProcessorConfig {
+                header_footer: Some(HeaderFooterConfig {
+                    lines_to_check: 1,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        HeaderFooterProcessor.process(&mut result, &config).await.unwrap();
+
+        assert_eq!(
+            result.content,
+            "Body 1\n\nIntro\nDetails\nACME Corp Annual Report\nMore details\nSummary\n\nBody 3"
+        );
+        assert_eq!(result.metadata.additional["header_footer_lines_removed"], 6);
+    }
+
+    #[test]
+    fn test_processor_disabled_by_default() {
+        let result = ExtractionResult {
+            content: String::new(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: Some(vec![]),
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+        assert!(!HeaderFooterProcessor.should_process(&result, &ExtractionConfig::default()));
+    }
+}
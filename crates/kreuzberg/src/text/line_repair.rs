@@ -0,0 +1,517 @@
+//! Hyphenation and hard line-break repair.
+//!
+//! Text extracted from PDFs and OCR output keeps the line breaks of the page layout: words are
+//! split with a hyphen at the end of a line and every paragraph is broken into lines of roughly
+//! equal width. These breaks fragment words and sentences, which hurts tokenization, embeddings
+//! and search. [`LineRepairProcessor`] joins hyphenated words and merges hard-wrapped lines back
+//! into paragraphs.
+//!
+//! Repair is language-aware: scripts written without spaces between words (Chinese, Japanese,
+//! Thai, ...) are joined without inserting a space, and suspended hyphens before a conjunction
+//! ("pre- and post-processing", "Ein- und Ausgang") are kept.
+
+use crate::core::config::LineRepairConfig;
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::text::header_footer::{page_boundaries, replace_page_texts};
+use crate::{ExtractionConfig, ExtractionResult, Result};
+use async_trait::async_trait;
+
+/// Minimum width (in characters) of a paragraph for its lines to count as hard-wrapped.
+const MIN_WRAP_WIDTH: usize = 30;
+
+/// A line is only merged with the next one if it fills at least this fraction of the paragraph width.
+const WRAPPED_LINE_RATIO: f64 = 0.6;
+
+const SOFT_HYPHEN: char = '\u{00AD}';
+
+/// Result of repairing line breaks.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LineRepair {
+    /// Repaired text
+    pub text: String,
+    /// Number of line breaks that were removed
+    pub repairs: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Join {
+    /// Remove the trailing hyphen and join the word parts
+    DropHyphen,
+    /// Join without a separator
+    Direct,
+    /// Join with a single space
+    Space,
+}
+
+/// Join hyphenated words and merge hard-wrapped lines in `text`.
+///
+/// `language` is an ISO 639-1 or 639-3 code (a region suffix such as `zh-CN` is ignored). When
+/// it is `None`, spacing is decided from the script of the characters around each break.
+pub fn repair_line_breaks(text: &str, config: &LineRepairConfig, language: Option<&str>) -> LineRepair {
+    let language = language.map(primary_subtag);
+    let lines: Vec<&str> = text.split('\n').collect();
+    let widths = paragraph_widths(&lines);
+
+    let mut repair = LineRepair {
+        text: String::with_capacity(text.len()),
+        repairs: 0,
+    };
+
+    for (index, line) in lines.iter().enumerate() {
+        if index == 0 {
+            repair.text.push_str(line);
+            continue;
+        }
+
+        match join_kind(lines[index - 1], line, widths[index], config, language.as_deref()) {
+            Some(join) => {
+                let end = repair.text.trim_end().len();
+                repair.text.truncate(end);
+                match join {
+                    Join::DropHyphen => {
+                        repair.text.pop();
+                    }
+                    Join::Space => repair.text.push(' '),
+                    Join::Direct => {}
+                }
+                repair.text.push_str(line.trim_start());
+                repair.repairs += 1;
+            }
+            None => {
+                repair.text.push('\n');
+                repair.text.push_str(line);
+            }
+        }
+    }
+
+    repair
+}
+
+/// Lowercased language code without region or script suffix.
+fn primary_subtag(language: &str) -> String {
+    language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or(language)
+        .to_ascii_lowercase()
+}
+
+/// For every line, the width in characters of the longest line of its paragraph.
+fn paragraph_widths(lines: &[&str]) -> Vec<usize> {
+    let mut widths = vec![0; lines.len()];
+    let mut start = 0;
+
+    while start < lines.len() {
+        let end = lines[start..]
+            .iter()
+            .position(|line| line.trim().is_empty())
+            .map_or(lines.len(), |offset| start + offset);
+        let width = lines[start..end]
+            .iter()
+            .map(|line| line.trim().chars().count())
+            .max()
+            .unwrap_or(0);
+        widths[start..end].fill(width);
+        start = end + 1;
+    }
+
+    widths
+}
+
+/// How to join `previous` and `line`, or `None` to keep the line break.
+fn join_kind(
+    previous: &str,
+    line: &str,
+    width: usize,
+    config: &LineRepairConfig,
+    language: Option<&str>,
+) -> Option<Join> {
+    let previous = previous.trim_end();
+    let line = line.trim_start();
+    if previous.is_empty() || line.is_empty() || is_structural(previous) || starts_block(line) {
+        return None;
+    }
+
+    if config.dehyphenate
+        && let Some(join) = hyphen_join(previous, line, language)
+    {
+        return Some(join);
+    }
+
+    if !config.merge_lines
+        || width < MIN_WRAP_WIDTH
+        || (previous.chars().count() as f64) < width as f64 * WRAPPED_LINE_RATIO
+        || previous.ends_with(':')
+    {
+        return None;
+    }
+
+    let last = previous.chars().next_back()?;
+    let first = line.chars().next()?;
+    let unspaced = (is_unspaced_char(last) && is_unspaced_char(first))
+        || (language.is_some_and(is_unspaced_language) && (is_unspaced_char(last) || is_unspaced_char(first)));
+
+    Some(if unspaced { Join::Direct } else { Join::Space })
+}
+
+/// Join for a line ending in a hyphen attached to a word, or `None` if it doesn't.
+fn hyphen_join(previous: &str, line: &str, language: Option<&str>) -> Option<Join> {
+    let mut chars = previous.chars().rev();
+    let hyphen = chars.next().filter(|&c| is_hyphen(c))?;
+    let before = chars.next().filter(|c| !c.is_whitespace())?;
+
+    if hyphen == SOFT_HYPHEN {
+        return Some(Join::DropHyphen);
+    }
+
+    let first = line.chars().next()?;
+    if !before.is_alphabetic() || !first.is_lowercase() {
+        // "Anglo-\nSaxon", "1990-\n1995": the hyphen belongs to the text
+        return Some(Join::Direct);
+    }
+
+    let next_word = line.split(|c: char| !c.is_alphabetic()).next().unwrap_or_default();
+    if is_conjunction(next_word, language) {
+        return Some(Join::Space);
+    }
+
+    let fragment = previous[..previous.len() - hyphen.len_utf8()]
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default();
+    if fragment.chars().any(is_hyphen) {
+        // "state-of-the-\nart" is a hyphenated compound, not a split word
+        return Some(Join::Direct);
+    }
+
+    Some(Join::DropHyphen)
+}
+
+fn is_hyphen(c: char) -> bool {
+    matches!(c, '-' | '\u{2010}' | SOFT_HYPHEN)
+}
+
+/// Conjunctions that follow a suspended hyphen ("pre- and post-").
+fn is_conjunction(word: &str, language: Option<&str>) -> bool {
+    let conjunctions: &[&str] = match language {
+        Some("de" | "deu" | "ger") => &["und", "oder", "bzw", "sowie", "bis"],
+        Some("nl" | "nld" | "dut") => &["en", "of", "tot"],
+        Some("fr" | "fra" | "fre") => &["et", "ou"],
+        _ => &["and", "or", "nor"],
+    };
+    conjunctions.iter().any(|c| word.eq_ignore_ascii_case(c))
+}
+
+/// Languages written without spaces between words.
+fn is_unspaced_language(language: &str) -> bool {
+    matches!(
+        language,
+        "zh" | "zho" | "chi" | "cmn" | "yue" | "ja" | "jpn" | "th" | "tha" | "lo" | "lao" | "km" | "khm" | "my" | "mya"
+    )
+}
+
+/// Characters of scripts written without spaces between words.
+fn is_unspaced_char(c: char) -> bool {
+    matches!(c,
+        '\u{3000}'..='\u{303F}' // CJK symbols and punctuation
+        | '\u{3040}'..='\u{30FF}' // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}' // CJK extension A
+        | '\u{4E00}'..='\u{9FFF}' // CJK unified ideographs
+        | '\u{FF00}'..='\u{FFEF}' // Halfwidth and fullwidth forms
+        | '\u{0E00}'..='\u{0EFF}' // Thai, Lao
+        | '\u{1000}'..='\u{109F}' // Myanmar
+        | '\u{1780}'..='\u{17FF}' // Khmer
+    )
+}
+
+/// Headings and table rows are never merged with the following line.
+fn is_structural(line: &str) -> bool {
+    line.starts_with('#') || line.contains('|') || line.contains('\t')
+}
+
+/// Lines that start a new block: headings, table rows and list items.
+fn starts_block(line: &str) -> bool {
+    is_structural(line) || is_list_item(line)
+}
+
+fn is_list_item(line: &str) -> bool {
+    if let Some(rest) = line.strip_prefix(['-', '*', '•', '·', '–', '—', '▪', '◦']) {
+        return rest.starts_with(char::is_whitespace);
+    }
+
+    // "1. ", "12) ", "a) "
+    let marker_len = line.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(0);
+    let marker = &line[..marker_len];
+    (marker.len() == 1 || ((1..=3).contains(&marker.len()) && marker.bytes().all(|b| b.is_ascii_digit())))
+        && line[marker_len..].starts_with(['.', ')'])
+        && line[marker_len + 1..].starts_with(char::is_whitespace)
+}
+
+/// Post-processor joining hyphenated words and hard-wrapped lines.
+///
+/// Enabled by setting [`PostProcessorConfig::line_repair`](crate::PostProcessorConfig::line_repair).
+/// Runs in the Early stage after header/footer removal and language detection, so detected
+/// languages are used when no language is configured. Pages are repaired individually when page
+/// boundaries are available. The number of removed line breaks is recorded in
+/// `metadata.additional["line_breaks_repaired"]`.
+#[derive(Debug, Clone, Copy)]
+pub struct LineRepairProcessor;
+
+impl Plugin for LineRepairProcessor {
+    fn name(&self) -> &str {
+        "line-break-repair"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for LineRepairProcessor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let Some(repair_config) = line_repair_config(config) else {
+            return Ok(());
+        };
+
+        let language = repair_config
+            .language
+            .clone()
+            .or_else(|| Some(result.detected_languages.as_ref()?.first()?.language.clone()));
+        let language = language.as_deref();
+
+        let repairs = match page_boundaries(result) {
+            Some(boundaries) => {
+                let repaired: Vec<LineRepair> = boundaries
+                    .iter()
+                    .map(|b| repair_line_breaks(&result.content[b.byte_start..b.byte_end], repair_config, language))
+                    .collect();
+                let repairs = repaired.iter().map(|r| r.repairs).sum();
+                if repairs > 0 {
+                    let pages: Vec<String> = repaired.into_iter().map(|r| r.text).collect();
+                    replace_page_texts(result, &boundaries, &pages);
+                }
+                repairs
+            }
+            None => {
+                let repaired = repair_line_breaks(&result.content, repair_config, language);
+                result.content = repaired.text;
+                if let Some(pages) = result.pages.as_mut() {
+                    for page in pages.iter_mut() {
+                        page.content = repair_line_breaks(&page.content, repair_config, language).text;
+                    }
+                }
+                repaired.repairs
+            }
+        };
+
+        if repairs > 0 {
+            result
+                .metadata
+                .additional
+                .insert("line_breaks_repaired".to_string(), serde_json::Value::from(repairs));
+        }
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Early
+    }
+
+    fn should_process(&self, result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        line_repair_config(config).is_some() && !result.content.is_empty()
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        (result.content.len() / 102400).max(1) as u64
+    }
+}
+
+fn line_repair_config(config: &ExtractionConfig) -> Option<&LineRepairConfig> {
+    config.postprocessor.as_ref()?.line_repair.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, PageBoundary, PageStructure, PageUnitType};
+
+    fn repair(text: &str, language: Option<&str>) -> String {
+        repair_line_breaks(text, &LineRepairConfig::default(), language).text
+    }
+
+    #[test]
+    fn test_dehyphenation() {
+        assert_eq!(repair("the extrac-\ntion works", None), "the extraction works");
+        assert_eq!(repair("an Anglo-\nSaxon king", None), "an Anglo-Saxon king");
+        assert_eq!(repair("state-of-the-\nart models", None), "state-of-the-art models");
+        assert_eq!(repair("pages 10-\n12", None), "pages 10-12");
+        assert_eq!(repair("soft\u{00AD}\nhyphen", None), "softhyphen");
+    }
+
+    #[test]
+    fn test_suspended_hyphens() {
+        assert_eq!(repair("pre-\nand post-processing", None), "pre- and post-processing");
+        assert_eq!(repair("Ein-\nund Ausgang", Some("de")), "Ein- und Ausgang");
+        assert_eq!(repair("Ein-\nund Ausgang", Some("en")), "Einund Ausgang");
+    }
+
+    #[test]
+    fn test_merges_wrapped_lines() {
+        let text = "This paragraph was wrapped by the page layout of a PDF\n\
+                    document and continues on the following line until it\n\
+                    ends here.\n\n\
+                    Second paragraph.";
+        assert_eq!(
+            repair(text, None),
+            "This paragraph was wrapped by the page layout of a PDF document and continues on the following line until it ends here.\n\nSecond paragraph."
+        );
+    }
+
+    #[test]
+    fn test_keeps_short_lines_and_structure() {
+        let address = "ACME Corp\n42 Main Street\nSpringfield";
+        assert_eq!(repair(address, None), address);
+
+        let list =
+            "The following items are required for the installation:\n- a working compiler toolchain\n- network access";
+        assert_eq!(repair(list, None), list);
+
+        let table = "| Name | Value of the configuration option |\n| foo | bar baz qux quux corge grault |";
+        assert_eq!(repair(table, None), table);
+    }
+
+    #[test]
+    fn test_unspaced_scripts() {
+        let text = "これは日本語の文章で、ページのレイアウトによって改行されています。\n次の行に続きます。";
+        assert_eq!(
+            repair(text, None),
+            "これは日本語の文章で、ページのレイアウトによって改行されています。次の行に続きます。"
+        );
+
+        let mixed = format!("{}Rust\nで書かれています", "あ".repeat(30));
+        assert_eq!(
+            repair(&mixed, Some("ja-JP")),
+            format!("{}Rustで書かれています", "あ".repeat(30))
+        );
+        assert_eq!(
+            repair(&mixed, None),
+            format!("{}Rust で書かれています", "あ".repeat(30))
+        );
+    }
+
+    #[test]
+    fn test_disabled_options() {
+        let config = LineRepairConfig {
+            dehyphenate: false,
+            merge_lines: false,
+            language: None,
+        };
+        let text = "the extrac-\ntion works";
+        assert_eq!(repair_line_breaks(text, &config, None).text, text);
+        assert_eq!(repair_line_breaks(text, &config, None).repairs, 0);
+    }
+
+    #[test]
+    fn test_is_list_item() {
+        assert!(is_list_item("- item"));
+        assert!(is_list_item("• item"));
+        assert!(is_list_item("12. item"));
+        assert!(is_list_item("a) item"));
+        assert!(!is_list_item("2.0 release"));
+        assert!(!is_list_item("-5 degrees"));
+    }
+
+    #[tokio::test]
+    async fn test_processor_repairs_pages() {
+        let pages = ["first page has a hyphen-\nated word", "second page"];
+        let mut content = String::new();
+        let mut boundaries = Vec::new();
+        for (i, text) in pages.iter().enumerate() {
+            if i > 0 {
+                content.push_str("\n\n");
+            }
+            let byte_start = content.len();
+            content.push_str(text);
+            boundaries.push(PageBoundary {
+                byte_start,
+                byte_end: content.len(),
+                page_number: i + 1,
+            });
+        }
+
+        let mut result = ExtractionResult {
+            content,
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: 2,
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(boundaries),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+
+        let config = ExtractionConfig {
+            postprocessor: Some(crate::PostProcessorConfig {
+                line_repair: Some(LineRepairConfig::default()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let processor = LineRepairProcessor;
+        assert!(processor.should_process(&result, &config));
+        processor.process(&mut result, &config).await.unwrap();
+
+        assert_eq!(result.content, "first page has a hyphenated word\n\nsecond page");
+        let boundaries = result.metadata.pages.unwrap().boundaries.unwrap();
+        assert_eq!(
+            &result.content[boundaries[1].byte_start..boundaries[1].byte_end],
+            "second page"
+        );
+        assert_eq!(result.metadata.additional["line_breaks_repaired"], 1);
+    }
+
+    #[test]
+    fn test_processor_disabled_by_default() {
+        let result = ExtractionResult {
+            content: "some text".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+        assert!(!LineRepairProcessor.should_process(&result, &ExtractionConfig::default()));
+    }
+}
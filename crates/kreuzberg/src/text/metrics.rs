@@ -0,0 +1,316 @@
+//! Readability and complexity metrics.
+//!
+//! [`compute_text_metrics`] derives sentence and word statistics, the Flesch reading ease and
+//! Flesch-Kincaid grade, the LIX index and vocabulary richness from plain text. Syllables are
+//! estimated from vowel groups, which is accurate enough for readability scoring of English and
+//! most European languages without a pronunciation dictionary. [`TextMetricsProcessor`] attaches
+//! the metrics to `metadata.text_metrics` so content-quality dashboards can read them from every
+//! extraction result.
+
+use crate::core::config::TextMetricsConfig;
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::types::TextMetrics;
+use crate::{ExtractionConfig, ExtractionResult, Result};
+use ahash::AHashMap;
+use async_trait::async_trait;
+
+/// Characters that end a sentence when followed by whitespace or the end of the text.
+const SENTENCE_TERMINATORS: &[char] = &['.', '!', '?', '。', '！', '？', '…'];
+
+/// Terminators of scripts written without spaces, which end a sentence on their own.
+const FULLWIDTH_TERMINATORS: &[char] = &['。', '！', '？'];
+
+/// Vowels of English and the Latin-script European languages (lowercase).
+const VOWELS: &str = "aeiouyàáâãäåæèéêëìíîïòóôõöøùúûüýÿœ";
+
+/// Compute readability, sentence/word and vocabulary metrics for `text`.
+///
+/// Words longer than `config.long_word_length` characters count as long words for LIX.
+/// A text without words yields all-zero metrics.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::TextMetricsConfig;
+/// use kreuzberg::text::compute_text_metrics;
+///
+/// let metrics = compute_text_metrics("The cat sat on the mat. It was happy.", &TextMetricsConfig::default());
+/// assert_eq!(metrics.sentence_count, 2);
+/// assert_eq!(metrics.word_count, 9);
+/// assert!(metrics.flesch_reading_ease > 90.0);
+/// ```
+pub fn compute_text_metrics(text: &str, config: &TextMetricsConfig) -> TextMetrics {
+    let mut metrics = TextMetrics::default();
+    let mut frequencies: AHashMap<String, usize> = AHashMap::new();
+    let mut character_count = 0;
+
+    for sentence in sentences(text) {
+        let mut has_words = false;
+        for word in words(sentence) {
+            has_words = true;
+            let length = word.chars().count();
+            character_count += length;
+            metrics.word_count += 1;
+            metrics.syllable_count += count_syllables(word);
+            if length > config.long_word_length {
+                metrics.long_word_count += 1;
+            }
+            *frequencies.entry(word.to_lowercase()).or_default() += 1;
+        }
+        if has_words {
+            metrics.sentence_count += 1;
+        }
+    }
+
+    if metrics.word_count == 0 {
+        return metrics;
+    }
+
+    let words = metrics.word_count as f64;
+    let words_per_sentence = words / metrics.sentence_count as f64;
+    let syllables_per_word = metrics.syllable_count as f64 / words;
+
+    metrics.unique_word_count = frequencies.len();
+    metrics.avg_sentence_length = words_per_sentence;
+    metrics.avg_word_length = character_count as f64 / words;
+    metrics.avg_syllables_per_word = syllables_per_word;
+    metrics.flesch_reading_ease = 206.835 - 1.015 * words_per_sentence - 84.6 * syllables_per_word;
+    metrics.flesch_kincaid_grade = 0.39 * words_per_sentence + 11.8 * syllables_per_word - 15.59;
+    metrics.lix = words_per_sentence + 100.0 * metrics.long_word_count as f64 / words;
+    metrics.type_token_ratio = frequencies.len() as f64 / words;
+    metrics.hapax_ratio = frequencies.values().filter(|&&count| count == 1).count() as f64 / frequencies.len() as f64;
+
+    metrics
+}
+
+/// Split `text` into sentences at terminators followed by whitespace and at blank lines.
+///
+/// Blank lines end a sentence so that headings and list items without final punctuation
+/// don't merge with the following paragraph.
+fn sentences(text: &str) -> impl Iterator<Item = &str> {
+    text.split("\n\n").flat_map(split_paragraph)
+}
+
+fn split_paragraph(paragraph: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = paragraph.char_indices().peekable();
+
+    while let Some((_, c)) = chars.next() {
+        if !SENTENCE_TERMINATORS.contains(&c) {
+            continue;
+        }
+        // Keep runs such as "?!" or "..." together.
+        while let Some(&(_, next)) = chars.peek()
+            && SENTENCE_TERMINATORS.contains(&next)
+        {
+            chars.next();
+        }
+        match chars.peek() {
+            Some(&(end, next)) if next.is_whitespace() || FULLWIDTH_TERMINATORS.contains(&c) => {
+                sentences.push(&paragraph[start..end]);
+                start = end;
+            }
+            _ => {}
+        }
+    }
+
+    if start < paragraph.len() {
+        sentences.push(&paragraph[start..]);
+    }
+    sentences
+}
+
+/// Words of `sentence`: runs of letters, digits and inner apostrophes containing a letter.
+fn words(sentence: &str) -> impl Iterator<Item = &str> {
+    sentence
+        .split(|c: char| !(c.is_alphanumeric() || c == '\'' || c == '’'))
+        .map(|word| word.trim_matches(|c| c == '\'' || c == '’'))
+        .filter(|word| word.chars().any(char::is_alphabetic))
+}
+
+/// Estimate the syllables of `word` from its vowel groups.
+///
+/// A silent final "e" (as in "make") is not counted, while "-le" after a consonant (as in
+/// "table") is. Every word has at least one syllable.
+fn count_syllables(word: &str) -> usize {
+    let lower: Vec<char> = word.to_lowercase().chars().filter(|c| c.is_alphabetic()).collect();
+    let mut syllables = 0;
+    let mut previous_vowel = false;
+
+    for &c in &lower {
+        let vowel = is_vowel(c);
+        if vowel && !previous_vowel {
+            syllables += 1;
+        }
+        previous_vowel = vowel;
+    }
+
+    if let [.., before, 'e'] = lower.as_slice()
+        && syllables > 1
+        && !is_vowel(*before)
+        && !(*before == 'l' && lower.len() > 2 && !is_vowel(lower[lower.len() - 3]))
+    {
+        syllables -= 1;
+    }
+
+    syllables.max(1)
+}
+
+fn is_vowel(c: char) -> bool {
+    VOWELS.contains(c)
+}
+
+/// Post-processor computing [`TextMetrics`] for the extracted content.
+///
+/// Enabled by setting [`PostProcessorConfig::metrics`](crate::PostProcessorConfig::metrics).
+/// Runs at the end of the Middle stage, after cleanup and before token reduction, so the scores
+/// describe the document as a reader sees it. Results are stored in `metadata.text_metrics`.
+#[derive(Debug, Clone, Copy)]
+pub struct TextMetricsProcessor;
+
+impl Plugin for TextMetricsProcessor {
+    fn name(&self) -> &str {
+        "text-metrics"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for TextMetricsProcessor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let Some(metrics_config) = metrics_config(config) else {
+            return Ok(());
+        };
+
+        let metrics = compute_text_metrics(&result.content, metrics_config);
+        if metrics.word_count > 0 {
+            result.metadata.text_metrics = Some(metrics);
+        }
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Middle
+    }
+
+    fn should_process(&self, result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        metrics_config(config).is_some() && !result.content.is_empty()
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        (result.content.len() / 102400).max(1) as u64
+    }
+}
+
+fn metrics_config(config: &ExtractionConfig) -> Option<&TextMetricsConfig> {
+    config.postprocessor.as_ref()?.metrics.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PostProcessorConfig;
+    use crate::types::Metadata;
+
+    fn metrics(text: &str) -> TextMetrics {
+        compute_text_metrics(text, &TextMetricsConfig::default())
+    }
+
+    #[test]
+    fn test_count_syllables() {
+        for (word, expected) in [
+            ("cat", 1),
+            ("make", 1),
+            ("table", 2),
+            ("reading", 2),
+            ("beautiful", 3),
+            ("readability", 5),
+            ("the", 1),
+            ("rhythm", 1),
+            ("Übersetzung", 4),
+        ] {
+            assert_eq!(count_syllables(word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_sentence_and_word_statistics() {
+        let m = metrics("Mr Smith isn't here. Is he at home?! Yes...\n\nChapter Two\n\n42 %");
+
+        assert_eq!(m.sentence_count, 4);
+        assert_eq!(m.word_count, 11);
+        assert_eq!(m.unique_word_count, 11);
+        assert!((m.type_token_ratio - 1.0).abs() < f64::EPSILON);
+        assert!((m.hapax_ratio - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_simple_text_scores_easier_than_complex_text() {
+        let simple = metrics("The cat sat on the mat. The dog ran to the park. We had fun.");
+        let complex = metrics(
+            "Notwithstanding considerable methodological heterogeneity, comprehensive investigations \
+             substantiate the hypothesis that organizational interdependencies fundamentally \
+             characterize institutional performance.",
+        );
+
+        assert!(simple.flesch_reading_ease > 90.0);
+        assert!(complex.flesch_reading_ease < 0.0);
+        assert!(simple.flesch_kincaid_grade < complex.flesch_kincaid_grade);
+        assert!(simple.lix < 30.0 && complex.lix > 60.0);
+        assert!(simple.type_token_ratio < 1.0);
+        assert_eq!(simple.long_word_count, 0);
+    }
+
+    #[test]
+    fn test_empty_text() {
+        assert_eq!(metrics("  \n 12 34 "), TextMetrics::default());
+    }
+
+    #[tokio::test]
+    async fn test_processor_attaches_metrics() {
+        let config = ExtractionConfig {
+            postprocessor: Some(PostProcessorConfig {
+                metrics: Some(TextMetricsConfig::default()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut result = ExtractionResult {
+            content: "Short words are easy. Long sentences with many words are harder to read.".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+
+        assert!(TextMetricsProcessor.should_process(&result, &config));
+        assert!(!TextMetricsProcessor.should_process(&result, &ExtractionConfig::default()));
+        TextMetricsProcessor.process(&mut result, &config).await.unwrap();
+
+        let metrics = result.metadata.text_metrics.expect("metrics should be attached");
+        assert_eq!(metrics.sentence_count, 2);
+        assert_eq!(metrics.word_count, 13);
+    }
+}
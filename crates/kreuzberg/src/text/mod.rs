@@ -1,3 +1,9 @@
+pub mod header_footer;
+pub mod line_repair;
+pub mod metrics;
+pub mod segmented;
+pub mod taggers;
+
 #[cfg(feature = "quality")]
 pub mod quality;
 
@@ -10,16 +16,35 @@ pub mod token_reduction;
 #[cfg(feature = "quality")]
 pub mod quality_processor;
 
+#[cfg(feature = "quality")]
+pub mod normalization;
+
+#[cfg(feature = "quality")]
+pub mod quality_gate;
+
 #[cfg(feature = "quality")]
 pub use quality::{calculate_quality_score, clean_extracted_text, normalize_spaces};
 
 #[cfg(feature = "quality")]
 pub use quality_processor::QualityProcessor;
 
+#[cfg(feature = "quality")]
+pub use normalization::{TextNormalizationProcessor, normalize_text};
+
+#[cfg(feature = "quality")]
+pub use quality_gate::{GarbleScore, QualityGateValidator, calculate_garble_score};
+
 #[cfg(feature = "quality")]
 pub use string_utils::{calculate_text_confidence, fix_mojibake, get_encoding_cache_key, safe_decode};
 
 #[cfg(feature = "quality")]
 pub use token_reduction::{
-    ReductionLevel, TokenReductionConfig, batch_reduce_tokens, get_reduction_statistics, reduce_tokens,
+    ReductionLevel, TokenReductionConfig, TokenReductionProcessor, batch_reduce_tokens, count_tokens,
+    get_reduction_statistics, reduce_tokens,
 };
+
+pub use header_footer::HeaderFooterProcessor;
+pub use line_repair::{LineRepairProcessor, repair_line_breaks};
+pub use metrics::{TextMetricsProcessor, compute_text_metrics};
+pub use segmented::SegmentedBuffer;
+pub use taggers::{TaggerProcessor, find_tags};
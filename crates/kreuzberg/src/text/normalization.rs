@@ -0,0 +1,364 @@
+//! Unicode normalization and invisible-character cleanup.
+//!
+//! Extractors return text in whatever form the source used: PDFs often carry decomposed
+//! accents and ligatures, web pages sprinkle zero-width spaces and bidi marks, and office
+//! documents use typographic quotes and dashes. Two copies of the same document extracted from
+//! different formats therefore rarely compare equal byte for byte. [`normalize_text`] applies a
+//! [`TextNormalizationConfig`] policy so that they do.
+
+use crate::core::config::{NormalizationForm, TextNormalizationConfig};
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::text::header_footer::{page_boundaries, replace_page_texts};
+use crate::{ExtractionConfig, ExtractionResult, Result};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use unicode_normalization::UnicodeNormalization;
+
+/// Zero-width characters removed by `remove_zero_width`.
+///
+/// ZWNJ (U+200C) and ZWJ (U+200D) are deliberately absent: they select glyph forms in Indic
+/// and Arabic scripts and join emoji sequences.
+const ZERO_WIDTH: &[char] = &[
+    '\u{00AD}', // soft hyphen
+    '\u{180E}', // Mongolian vowel separator
+    '\u{200B}', // zero-width space
+    '\u{2060}', // word joiner
+    '\u{FEFF}', // zero-width no-break space / BOM
+];
+
+fn is_bidi_control(c: char) -> bool {
+    matches!(c, '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+}
+
+/// C0/C1 controls, keeping the whitespace controls that carry layout.
+fn is_removable_control(c: char) -> bool {
+    c.is_control() && !matches!(c, '\t' | '\n' | '\r' | '\u{000C}')
+}
+
+fn canonical_quote(c: char) -> Option<char> {
+    match c {
+        '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{201B}' | '\u{2039}' | '\u{203A}' => Some('\''),
+        '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{201F}' | '\u{00AB}' | '\u{00BB}' => Some('"'),
+        _ => None,
+    }
+}
+
+fn canonical_dash(c: char) -> Option<char> {
+    match c {
+        '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{FE58}' | '\u{FE63}' | '\u{FF0D}' => Some('-'),
+        _ => None,
+    }
+}
+
+/// Normalize `text` according to `config`.
+///
+/// Characters are removed first, then the Unicode normalization form is applied, then quotes
+/// and dashes are canonicalized (so that compatibility forms such as "﹘" are caught as well).
+/// Returns the input unchanged without allocating when there is nothing to do.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::TextNormalizationConfig;
+/// use kreuzberg::text::normalize_text;
+///
+/// let text = "\u{201C}Cafe\u{0301}\u{201D} \u{2014} zero\u{200B}width";
+/// let normalized = normalize_text(text, &TextNormalizationConfig::default());
+/// assert_eq!(normalized, "\"Café\" - zerowidth");
+/// ```
+pub fn normalize_text<'a>(text: &'a str, config: &TextNormalizationConfig) -> Cow<'a, str> {
+    // ASCII is invariant under every normalization form; only control characters can change.
+    if text.is_ascii() && !(config.remove_control_chars && text.chars().any(is_removable_control)) {
+        return Cow::Borrowed(text);
+    }
+
+    let kept = text.chars().filter(|&c| {
+        !((config.remove_zero_width && ZERO_WIDTH.contains(&c))
+            || (config.remove_bidi_controls && is_bidi_control(c))
+            || (config.remove_control_chars && is_removable_control(c)))
+    });
+
+    let canonicalize = |c: char| {
+        if config.canonicalize_quotes
+            && let Some(quote) = canonical_quote(c)
+        {
+            quote
+        } else if config.canonicalize_dashes
+            && let Some(dash) = canonical_dash(c)
+        {
+            dash
+        } else {
+            c
+        }
+    };
+
+    let normalized: String = match config.form {
+        NormalizationForm::None => kept.map(canonicalize).collect(),
+        NormalizationForm::Nfc => kept.nfc().map(canonicalize).collect(),
+        NormalizationForm::Nfkc => kept.nfkc().map(canonicalize).collect(),
+    };
+
+    if normalized == text {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned(normalized)
+    }
+}
+
+/// Post-processor applying Unicode normalization and the control-character policy.
+///
+/// Enabled by setting [`PostProcessorConfig::normalization`](crate::PostProcessorConfig::normalization).
+/// Runs first in the Early stage so that header/footer removal, line repair and keyword
+/// extraction all see normalized text. Pages are normalized individually when page boundaries
+/// are available, and the boundaries are rebuilt to match the new byte offsets.
+#[derive(Debug, Clone, Copy)]
+pub struct TextNormalizationProcessor;
+
+impl Plugin for TextNormalizationProcessor {
+    fn name(&self) -> &str {
+        "text-normalization"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for TextNormalizationProcessor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let Some(normalization) = normalization_config(config) else {
+            return Ok(());
+        };
+
+        match page_boundaries(result) {
+            Some(boundaries) => {
+                let pages: Vec<Cow<'_, str>> = boundaries
+                    .iter()
+                    .map(|b| normalize_text(&result.content[b.byte_start..b.byte_end], normalization))
+                    .collect();
+                if pages.iter().any(|page| matches!(page, Cow::Owned(_))) {
+                    let pages: Vec<String> = pages.into_iter().map(Cow::into_owned).collect();
+                    replace_page_texts(result, &boundaries, &pages);
+                }
+            }
+            None => {
+                if let Cow::Owned(normalized) = normalize_text(&result.content, normalization) {
+                    result.content = normalized;
+                }
+                if let Some(pages) = result.pages.as_mut() {
+                    for page in pages.iter_mut() {
+                        if let Cow::Owned(normalized) = normalize_text(&page.content, normalization) {
+                            page.content = normalized;
+                        }
+                    }
+                }
+            }
+        }
+
+        for table in result.tables.iter_mut() {
+            for cell in table.cells.iter_mut().flatten() {
+                if let Cow::Owned(normalized) = normalize_text(cell, normalization) {
+                    *cell = normalized;
+                }
+            }
+            if let Cow::Owned(normalized) = normalize_text(&table.markdown, normalization) {
+                table.markdown = normalized;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Early
+    }
+
+    fn should_process(&self, result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        normalization_config(config).is_some() && !result.content.is_empty()
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        (result.content.len() / 102400).max(1) as u64
+    }
+}
+
+fn normalization_config(config: &ExtractionConfig) -> Option<&TextNormalizationConfig> {
+    config.postprocessor.as_ref()?.normalization.as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Metadata, PageBoundary, PageStructure, PageUnitType};
+
+    fn normalize(text: &str) -> String {
+        normalize_text(text, &TextNormalizationConfig::default()).into_owned()
+    }
+
+    #[test]
+    fn test_normalization_forms() {
+        let decomposed = "Cafe\u{0301} \u{FB01}le";
+        assert_eq!(normalize(decomposed), "Café \u{FB01}le");
+
+        let nfkc = TextNormalizationConfig {
+            form: NormalizationForm::Nfkc,
+            ..Default::default()
+        };
+        assert_eq!(normalize_text(decomposed, &nfkc), "Café file");
+        assert_eq!(normalize_text("\u{FF21}\u{FF11}", &nfkc), "A1");
+
+        let none = TextNormalizationConfig {
+            form: NormalizationForm::None,
+            ..Default::default()
+        };
+        assert_eq!(normalize_text(decomposed, &none), decomposed);
+    }
+
+    #[test]
+    fn test_removes_invisible_characters() {
+        assert_eq!(
+            normalize("zero\u{200B}width\u{FEFF} soft\u{00AD}hyphen"),
+            "zerowidth softhyphen"
+        );
+        assert_eq!(
+            normalize("\u{202B}\u{05E9}\u{05DC}\u{05D5}\u{05DD}\u{202C}\u{200E}"),
+            "שלום"
+        );
+        assert_eq!(
+            normalize("bell\u{0007} tab\tline\nfeed\u{000C}"),
+            "bell tab\tline\nfeed\u{000C}"
+        );
+
+        // ZWJ emoji sequences and ZWNJ in Persian are kept
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        assert_eq!(normalize(family), family);
+        let persian = "\u{0645}\u{06CC}\u{200C}\u{062E}\u{0648}\u{0627}\u{0647}\u{0645}";
+        assert_eq!(normalize(persian), persian);
+    }
+
+    #[test]
+    fn test_canonicalizes_quotes_and_dashes() {
+        assert_eq!(
+            normalize("\u{201E}Zitat\u{201C} \u{00AB}citation\u{00BB} it\u{2019}s"),
+            "\"Zitat\" \"citation\" it's"
+        );
+        assert_eq!(normalize("1990\u{2013}2000 \u{2014} \u{2212}5"), "1990-2000 - -5");
+
+        let keep_typography = TextNormalizationConfig {
+            canonicalize_quotes: false,
+            canonicalize_dashes: false,
+            ..Default::default()
+        };
+        let text = "\u{201C}a\u{201D} \u{2013} b";
+        assert_eq!(normalize_text(text, &keep_typography), text);
+    }
+
+    #[test]
+    fn test_sources_compare_equal_after_normalization() {
+        let from_pdf = "Re\u{0301}sume\u{0301} \u{2013} \u{201C}final\u{201D}\u{200B}";
+        let from_html = "\u{200E}Résumé - \"final\"";
+        assert_eq!(normalize(from_pdf), normalize(from_html));
+    }
+
+    #[test]
+    fn test_ascii_is_borrowed() {
+        assert!(matches!(
+            normalize_text("plain ascii text", &TextNormalizationConfig::default()),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_processor_rebuilds_page_boundaries() {
+        let first = "Cafe\u{0301}\u{200B}";
+        let content = format!("{first}\n\nsecond \u{2014} page");
+        let mut result = ExtractionResult {
+            content: content.clone(),
+            mime_type: "application/pdf".to_string(),
+            metadata: Metadata {
+                pages: Some(PageStructure {
+                    total_count: 2,
+                    unit_type: PageUnitType::Page,
+                    boundaries: Some(vec![
+                        PageBoundary {
+                            byte_start: 0,
+                            byte_end: first.len(),
+                            page_number: 1,
+                        },
+                        PageBoundary {
+                            byte_start: first.len() + 2,
+                            byte_end: content.len(),
+                            page_number: 2,
+                        },
+                    ]),
+                    pages: None,
+                }),
+                ..Default::default()
+            },
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+
+        let config = ExtractionConfig {
+            postprocessor: Some(crate::PostProcessorConfig {
+                normalization: Some(TextNormalizationConfig::default()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let processor = TextNormalizationProcessor;
+        assert!(processor.should_process(&result, &config));
+        processor.process(&mut result, &config).await.unwrap();
+
+        assert_eq!(result.content, "Café\n\nsecond - page");
+        let boundaries = result.metadata.pages.unwrap().boundaries.unwrap();
+        assert_eq!(
+            &result.content[boundaries[0].byte_start..boundaries[0].byte_end],
+            "Café"
+        );
+        assert_eq!(
+            &result.content[boundaries[1].byte_start..boundaries[1].byte_end],
+            "second - page"
+        );
+    }
+
+    #[test]
+    fn test_processor_disabled_by_default() {
+        let result = ExtractionResult {
+            content: "some text".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+        assert!(!TextNormalizationProcessor.should_process(&result, &ExtractionConfig::default()));
+        assert_eq!(TextNormalizationProcessor.name(), "text-normalization");
+    }
+}
@@ -3,7 +3,9 @@ use regex::Regex;
 use std::borrow::Cow;
 use std::collections::HashMap;
 
+use crate::types::{FormatMetadata, Metadata};
 use crate::utils::quality::{collapse_scattered_ascii, normalize_whitespace_ascii};
+use memchr::{memchr_iter, memchr2, memmem};
 
 // ============================================================================
 // ============================================================================
@@ -252,6 +254,38 @@ fn calculate_structure_bonus(text: &str) -> f64 {
     structure_score.min(1.0)
 }
 
+/// Flatten extraction metadata into the string map consumed by [`calculate_quality_score`].
+///
+/// Besides the `additional` fields this includes the typed common fields that count
+/// towards the metadata bonus (title, authors, subject, keywords, description).
+pub(crate) fn quality_metadata_fields(metadata: &Metadata) -> HashMap<String, String> {
+    let mut fields: HashMap<String, String> = metadata
+        .additional
+        .iter()
+        .map(|(k, v)| (k.clone(), v.to_string()))
+        .collect();
+
+    if let Some(title) = &metadata.title {
+        fields.insert("title".to_string(), title.clone());
+    }
+    if let Some(authors) = &metadata.authors {
+        fields.insert("author".to_string(), authors.join(", "));
+    }
+    if let Some(subject) = &metadata.subject {
+        fields.insert("subject".to_string(), subject.clone());
+    }
+    if let Some(keywords) = &metadata.keywords {
+        fields.insert("keywords".to_string(), keywords.join(", "));
+    }
+    if let Some(FormatMetadata::Office(office)) = &metadata.format
+        && let Some(description) = &office.description
+    {
+        fields.insert("description".to_string(), description.clone());
+    }
+
+    fields
+}
+
 #[inline]
 fn calculate_metadata_bonus(metadata: &HashMap<String, String>) -> f64 {
     const IMPORTANT_FIELDS: &[&str] = &["title", "author", "subject", "description", "keywords"];
@@ -284,6 +318,11 @@ pub fn clean_extracted_text(text: &str) -> String {
 
 #[inline]
 fn clean_scripts<'a>(text: Cow<'a, str>) -> Cow<'a, str> {
+    // Script/style tags need a `<`, JS functions and CSS rules need a `{`.
+    if memchr2(b'<', b'{', text.as_bytes()).is_none() {
+        return text;
+    }
+
     let script_replacements = [
         (&*SCRIPT_TAG_PATTERN, " "),
         (&*STYLE_TAG_PATTERN, " "),
@@ -387,6 +426,14 @@ pub fn normalize_spaces(text: &str) -> String {
         return String::new();
     }
 
+    if text.is_ascii() {
+        return normalize_spaces_ascii(text);
+    }
+
+    normalize_spaces_unicode(text)
+}
+
+fn normalize_spaces_unicode(text: &str) -> String {
     let mut result = String::with_capacity(text.len());
 
     let mut first = true;
@@ -420,6 +467,76 @@ pub fn normalize_spaces(text: &str) -> String {
     result
 }
 
+/// Byte-level equivalent of [`normalize_spaces_unicode`] for ASCII input.
+///
+/// Paragraph and line boundaries are located with `memchr`, and whitespace runs are
+/// collapsed in a single pass without building intermediate strings.
+fn normalize_spaces_ascii(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+
+    let mut paragraph_start = 0;
+    let mut paragraph_ends = memmem::find_iter(bytes, b"\n\n");
+
+    while paragraph_start <= bytes.len() {
+        // `find_iter` reports non-overlapping matches, which is exactly how `str::split` splits.
+        let paragraph_end = paragraph_ends.next().unwrap_or(bytes.len());
+        let paragraph = &bytes[paragraph_start..paragraph_end];
+        let paragraph_len_before = result.len();
+
+        if paragraph_len_before > 0 {
+            result.extend_from_slice(b"\n\n");
+        }
+        let content_start = result.len();
+
+        let mut line_start = 0;
+        for line_end in memchr_iter(b'\n', paragraph).chain(std::iter::once(paragraph.len())) {
+            let line = &paragraph[line_start..line_end];
+            line_start = line_end + 1;
+
+            let line_len_before = result.len();
+            if line_len_before > content_start {
+                result.push(b'\n');
+            }
+            if !push_collapsed_ascii_line(&mut result, line) {
+                result.truncate(line_len_before);
+            }
+        }
+
+        if result.len() == content_start {
+            result.truncate(paragraph_len_before);
+        }
+
+        paragraph_start = paragraph_end + 2;
+    }
+
+    String::from_utf8(result).unwrap_or_else(|_| normalize_spaces_unicode(text))
+}
+
+/// Append `line` with whitespace runs collapsed to single spaces and both ends trimmed.
+///
+/// Returns `false` if the line contained only whitespace.
+#[inline]
+fn push_collapsed_ascii_line(result: &mut Vec<u8>, line: &[u8]) -> bool {
+    let mut wrote = false;
+    let mut pending_space = false;
+
+    for &byte in line {
+        if matches!(byte, b' ' | b'\t' | b'\r' | 0x0B | 0x0C) {
+            pending_space = wrote;
+        } else {
+            if pending_space {
+                result.push(b' ');
+                pending_space = false;
+            }
+            result.push(byte);
+            wrote = true;
+        }
+    }
+
+    wrote
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -601,6 +718,35 @@ mod tests {
         assert_eq!(normalized, "Para 1\n\nPara 2");
     }
 
+    #[test]
+    fn test_normalize_spaces_ascii_matches_unicode_path() {
+        let inputs = [
+            "single line",
+            "  leading and trailing  ",
+            "tabs\tand\x0bvertical\x0cfeeds\r\nhere",
+            "Para 1\n\n\n\nPara 2",
+            "a\n\n\nb\n\n",
+            "line 1\n   \nline 2\n\n\t\n\nline 3",
+            "\n\n\n  x  \n\n",
+            "trailing newline\n",
+        ];
+
+        for input in inputs {
+            assert_eq!(
+                normalize_spaces_ascii(input),
+                normalize_spaces_unicode(input),
+                "mismatch for {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_clean_scripts_skips_text_without_markers() {
+        let text = "Plain text without any markup";
+        assert!(matches!(clean_scripts(Cow::Borrowed(text)), Cow::Borrowed(_)));
+    }
+
     #[test]
     fn test_count_non_table_dash_artifacts() {
         let text = "Some text --- with dashes";
@@ -0,0 +1,346 @@
+//! Garble detection for OCR output.
+//!
+//! A scan that OCR could not read still produces text, just not words. [`calculate_garble_score`]
+//! measures two things that separate the two cases without a full dictionary:
+//!
+//! - **Dictionary-hit rate**: the share of words found in the stopword list of the document
+//!   language. Function and other very common words make up a large part of running text in
+//!   every language, while garbled output almost never reproduces them.
+//! - **Confusion score**: the share of tokens free of typical OCR confusion patterns, such as
+//!   digits inside words ("he11o", "w0rd"), stray symbols inside words ("th|s") and tokens made
+//!   only of symbols ("~^").
+//!
+//! The gate itself is configured through [`QualityGateConfig`] and evaluated by the pipeline;
+//! [`QualityGateValidator`] rejects flagged results when the action is `reject`.
+
+use crate::core::config::{QualityGateAction, QualityGateConfig};
+use crate::plugins::{Plugin, Validator};
+use crate::types::{ExtractionResult, FormatMetadata};
+use crate::{ExtractionConfig, KreuzbergError, Result};
+use async_trait::async_trait;
+
+/// Metadata key set to `true` or `false` when the gate evaluated a result.
+pub const LOW_QUALITY_KEY: &str = "low_quality";
+
+const FALLBACK_LANGUAGE: &str = "en";
+
+/// Symbols that appear as standalone tokens in clean text (markdown, bullets, separators).
+const STANDALONE_SYMBOLS: &[char] = &[
+    '-', '–', '—', '|', '*', '#', '>', '`', '•', '·', '&', '+', '=', '/', '§', '%', '€', '$', '£', '…', '"', '\'', '.',
+    ',', ':', ';', '!', '?', '(', ')', '[', ']',
+];
+
+/// Symbols that legitimately occur inside words.
+const INNER_WORD_SYMBOLS: &[char] = &['-', '\'', '’', '.', '/', '@', '&', '_', ':', ',', '+', '%'];
+
+/// Garble scores for a piece of text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GarbleScore {
+    /// Number of whitespace-separated tokens containing a letter
+    pub word_count: usize,
+    /// Share of words (0.0-1.0) found in the stopword list (None if the language has no list)
+    pub dictionary_hit_rate: Option<f64>,
+    /// Share of tokens (0.0-1.0) free of OCR confusion patterns
+    pub confusion_score: f64,
+}
+
+/// Score `text` for OCR garbage.
+///
+/// `language` is an ISO 639-1 or 639-3 code; stopwords for unsupported languages fall back to
+/// none, in which case only the confusion score is computed.
+pub fn calculate_garble_score(text: &str, language: &str) -> GarbleScore {
+    let stopwords = crate::stopwords::get_stopwords(language);
+
+    let mut token_count = 0usize;
+    let mut confused = 0usize;
+    let mut word_count = 0usize;
+    let mut hits = 0usize;
+
+    for token in text.split_whitespace() {
+        token_count += 1;
+
+        let word = token.trim_matches(|c: char| !c.is_alphanumeric());
+        if word.is_empty() {
+            if !token.chars().all(|c| STANDALONE_SYMBOLS.contains(&c)) {
+                confused += 1;
+            }
+            continue;
+        }
+
+        if is_confused(word) {
+            confused += 1;
+        }
+
+        if word.chars().any(char::is_alphabetic) {
+            word_count += 1;
+            if let Some(stopwords) = stopwords
+                && stopwords.contains(&word.to_lowercase())
+            {
+                hits += 1;
+            }
+        }
+    }
+
+    GarbleScore {
+        word_count,
+        dictionary_hit_rate: stopwords.map(|_| ratio(hits, word_count)),
+        confusion_score: 1.0 - ratio(confused, token_count),
+    }
+}
+
+fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 { 0.0 } else { part as f64 / total as f64 }
+}
+
+/// Whether a token (with surrounding punctuation trimmed) shows an OCR confusion pattern.
+fn is_confused(word: &str) -> bool {
+    let stray_symbol = |c: char| !c.is_alphanumeric() && !INNER_WORD_SYMBOLS.contains(&c);
+    if word.chars().any(stray_symbol) {
+        return true;
+    }
+
+    // Uppercase identifiers ("MP3", "B2B", "H2O") legitimately mix letters and digits.
+    if word.chars().all(|c| c.is_uppercase() || c.is_ascii_digit()) {
+        return false;
+    }
+
+    // A run of digits with a letter on both sides ("he11o", "w0rd"), as opposed to a
+    // letter suffix ("3rd", "1990s") or prefix ("A4", "v2").
+    let chars: Vec<char> = word.chars().collect();
+    let mut index = 0;
+    while index < chars.len() {
+        if chars[index].is_ascii_digit() {
+            let start = index;
+            while index < chars.len() && chars[index].is_ascii_digit() {
+                index += 1;
+            }
+            if start > 0 && chars[start - 1].is_alphabetic() && chars.get(index).is_some_and(|c| c.is_alphabetic()) {
+                return true;
+            }
+        } else {
+            index += 1;
+        }
+    }
+
+    false
+}
+
+/// Whether `result` was produced by OCR.
+fn is_ocr_result(result: &ExtractionResult) -> bool {
+    matches!(result.metadata.format, Some(FormatMetadata::Ocr(_)))
+        || result
+            .metadata
+            .additional
+            .contains_key(crate::core::stats::OCR_CONFIDENCE_KEY)
+        || result
+            .metadata
+            .stats
+            .as_ref()
+            .is_some_and(|stats| stats.ocr_confidence.is_some())
+}
+
+/// Score `result` against `config` and record the outcome in `metadata.additional`.
+///
+/// Returns `true` if the result falls below a threshold. Results that are not scored (too few
+/// words, or not OCR output when `ocr_only` is set) are left untouched.
+pub(crate) fn apply_quality_gate(result: &mut ExtractionResult, config: &QualityGateConfig) -> bool {
+    if config.ocr_only && !is_ocr_result(result) {
+        return false;
+    }
+
+    let language = config
+        .language
+        .clone()
+        .or_else(|| Some(result.detected_languages.as_ref()?.first()?.language.clone()))
+        .unwrap_or_else(|| FALLBACK_LANGUAGE.to_string());
+
+    let score = calculate_garble_score(&result.content, &language);
+    if score.word_count < config.min_words {
+        return false;
+    }
+
+    let low_dictionary = score
+        .dictionary_hit_rate
+        .is_some_and(|rate| rate < config.min_dictionary_hit_rate);
+    let low_quality = low_dictionary || score.confusion_score < config.min_confusion_score;
+
+    let additional = &mut result.metadata.additional;
+    if let Some(rate) = score.dictionary_hit_rate {
+        additional.insert("dictionary_hit_rate".to_string(), serde_json::Value::from(rate));
+    }
+    additional.insert(
+        "confusion_score".to_string(),
+        serde_json::Value::from(score.confusion_score),
+    );
+    additional.insert(LOW_QUALITY_KEY.to_string(), serde_json::Value::Bool(low_quality));
+
+    low_quality
+}
+
+/// The error returned for results rejected by the gate.
+pub(crate) fn rejection_error(result: &ExtractionResult) -> KreuzbergError {
+    let score = |key: &str| {
+        result
+            .metadata
+            .additional
+            .get(key)
+            .and_then(|v| v.as_f64())
+            .map_or_else(|| "n/a".to_string(), |v| format!("{v:.2}"))
+    };
+    KreuzbergError::validation(format!(
+        "Extracted text failed the quality gate (dictionary hit rate {}, confusion score {})",
+        score("dictionary_hit_rate"),
+        score("confusion_score")
+    ))
+}
+
+/// Built-in validator that fails extractions flagged by the quality gate.
+///
+/// Only active when [`ExtractionConfig::quality_gate`] is set with
+/// [`QualityGateAction::Reject`]; with the default `flag` action the result is returned with
+/// `metadata.additional["low_quality"] = true` instead.
+#[derive(Debug, Clone, Copy)]
+pub struct QualityGateValidator;
+
+impl Plugin for QualityGateValidator {
+    fn name(&self) -> &str {
+        "quality-gate"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl Validator for QualityGateValidator {
+    async fn validate(&self, result: &ExtractionResult, _config: &ExtractionConfig) -> Result<()> {
+        match result.metadata.additional.get(LOW_QUALITY_KEY) {
+            Some(serde_json::Value::Bool(true)) => Err(rejection_error(result)),
+            _ => Ok(()),
+        }
+    }
+
+    fn should_validate(&self, _result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        config
+            .quality_gate
+            .as_ref()
+            .is_some_and(|gate| gate.action == QualityGateAction::Reject)
+    }
+
+    fn priority(&self) -> i32 {
+        100
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Metadata;
+
+    const CLEAN: &str = "The committee met on Tuesday to review the budget for the coming year. \
+        Most of the members agreed that the proposal was reasonable, but some of them asked for \
+        more details about the costs of the new building and the timeline for its construction.";
+
+    const GARBLED: &str = "Tlie c0mmittee rnet 0n Tuesdav t0 rev|ew tbe budqet f0r tlie c0ming ycar. \
+        M0st 0f tlie rnernbers aqreed tbat tlie pr0p0sal ~^ wos reas0nable, bnt s0me 0f tbern \
+        askcd f0r rn0re dctails ab0ut tlie c0sts 0f tlie ncw bui1ding.";
+
+    fn ocr_result(content: &str) -> ExtractionResult {
+        let mut metadata = Metadata::default();
+        metadata.additional.insert(
+            crate::core::stats::OCR_CONFIDENCE_KEY.to_string(),
+            serde_json::json!(0.4),
+        );
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "image/png".to_string(),
+            metadata,
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_garble_score_separates_clean_and_garbled_text() {
+        let clean = calculate_garble_score(CLEAN, "en");
+        let garbled = calculate_garble_score(GARBLED, "en");
+
+        assert!(clean.dictionary_hit_rate.unwrap() > 0.3);
+        assert!(clean.confusion_score > 0.95);
+        assert!(garbled.dictionary_hit_rate.unwrap() < 0.15);
+        assert!(garbled.confusion_score < 0.8);
+    }
+
+    #[test]
+    fn test_legitimate_alphanumerics_are_not_confusions() {
+        for word in ["3rd", "1990s", "A4", "COVID-19", "it's", "e-mail", "v2.0", "MP3"] {
+            assert!(!is_confused(word), "{word} should not count as a confusion");
+        }
+        for word in ["he11o", "w0rd", "th|s", "bui1ding"] {
+            assert!(is_confused(word), "{word} should count as a confusion");
+        }
+    }
+
+    #[test]
+    fn test_apply_quality_gate_flags_garbled_ocr() {
+        let config = QualityGateConfig::default();
+
+        let mut garbled = ocr_result(GARBLED);
+        assert!(apply_quality_gate(&mut garbled, &config));
+        assert_eq!(garbled.metadata.additional[LOW_QUALITY_KEY], serde_json::json!(true));
+
+        let mut clean = ocr_result(CLEAN);
+        assert!(!apply_quality_gate(&mut clean, &config));
+        assert_eq!(clean.metadata.additional[LOW_QUALITY_KEY], serde_json::json!(false));
+    }
+
+    #[test]
+    fn test_apply_quality_gate_skips_non_ocr_and_short_text() {
+        let config = QualityGateConfig::default();
+
+        let mut native = ocr_result(GARBLED);
+        native.metadata.additional.clear();
+        assert!(!apply_quality_gate(&mut native, &config));
+        assert!(!native.metadata.additional.contains_key(LOW_QUALITY_KEY));
+
+        let mut short = ocr_result("~^ w0rd");
+        assert!(!apply_quality_gate(&mut short, &config));
+        assert!(!short.metadata.additional.contains_key(LOW_QUALITY_KEY));
+    }
+
+    #[tokio::test]
+    async fn test_validator_rejects_only_with_reject_action() {
+        let validator = QualityGateValidator;
+        let mut result = ocr_result(GARBLED);
+        let mut config = ExtractionConfig {
+            quality_gate: Some(QualityGateConfig::default()),
+            ..Default::default()
+        };
+        apply_quality_gate(&mut result, config.quality_gate.as_ref().unwrap());
+
+        assert!(!validator.should_validate(&result, &config));
+
+        config.quality_gate.as_mut().unwrap().action = QualityGateAction::Reject;
+        assert!(validator.should_validate(&result, &config));
+        let err = validator.validate(&result, &config).await.unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+    }
+}
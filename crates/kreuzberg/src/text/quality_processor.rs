@@ -52,14 +52,7 @@ impl PostProcessor for QualityProcessor {
         // Calculate quality score
         let quality_score = crate::text::quality::calculate_quality_score(
             &result.content,
-            Some(
-                &result
-                    .metadata
-                    .additional
-                    .iter()
-                    .map(|(k, v)| (k.clone(), v.to_string()))
-                    .collect(),
-            ),
+            Some(&crate::text::quality::quality_metadata_fields(&result.metadata)),
         );
 
         result.metadata.additional.insert(
@@ -109,6 +102,11 @@ mod tests {
 	            chunks: None,
 	            images: None,
 	            pages: None,
+	            entities: None,
+	            keywords: None,
+	            children: None,
+	            fields: None,
+	            warnings: Vec::new(),
 	        };
 
         processor.process(&mut result, &config).await.unwrap();
@@ -135,6 +133,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         // When disabled, the processor should not run, so no quality_score should be added
@@ -170,6 +173,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let config_with_quality = ExtractionConfig {
@@ -198,6 +206,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let long_result = ExtractionResult {
@@ -209,6 +222,11 @@ mod tests {
             chunks: None,
             images: None,
             pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
         };
 
         let short_duration = processor.estimated_duration_ms(&short_result);
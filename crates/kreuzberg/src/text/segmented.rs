@@ -0,0 +1,203 @@
+//! Segmented text buffer for assembling large extraction outputs.
+//!
+//! Appending to a single growing `String` reallocates and copies the whole buffer every time
+//! it runs out of capacity, which dominates allocator time for 100MB+ outputs. A
+//! [`SegmentedBuffer`] instead appends into a list of fixed-capacity segments that are never
+//! reallocated, and copies everything exactly once when the final string is materialized.
+
+use std::fmt;
+
+/// Capacity of the first segment when none is given.
+const DEFAULT_INITIAL_CAPACITY: usize = 8 * 1024;
+
+/// Upper bound for the capacity of a single segment.
+const MAX_SEGMENT_CAPACITY: usize = 1024 * 1024;
+
+/// Append-only text buffer made of non-reallocating segments.
+///
+/// Segment capacities double (up to 1 MiB) as the buffer grows, so the number of segments
+/// stays logarithmic for small outputs and linear in 1 MiB steps for huge ones.
+#[derive(Debug, Clone)]
+pub struct SegmentedBuffer {
+    segments: Vec<String>,
+    len: usize,
+    next_capacity: usize,
+}
+
+impl SegmentedBuffer {
+    /// Create an empty buffer.
+    pub fn new() -> Self {
+        Self::with_capacity(DEFAULT_INITIAL_CAPACITY)
+    }
+
+    /// Create an empty buffer whose first segment holds `capacity` bytes.
+    ///
+    /// No memory is allocated until the first append.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            segments: Vec::new(),
+            len: 0,
+            next_capacity: capacity.max(1),
+        }
+    }
+
+    /// Total length in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Append a string slice.
+    pub fn push_str(&mut self, s: &str) {
+        if s.is_empty() {
+            return;
+        }
+
+        self.len += s.len();
+
+        if let Some(last) = self.segments.last_mut()
+            && last.capacity() - last.len() >= s.len()
+        {
+            last.push_str(s);
+            return;
+        }
+
+        let mut segment = String::with_capacity(self.next_capacity.max(s.len()));
+        segment.push_str(s);
+        self.push_segment(segment);
+    }
+
+    /// Append a character.
+    pub fn push(&mut self, c: char) {
+        let mut buf = [0u8; 4];
+        self.push_str(c.encode_utf8(&mut buf));
+    }
+
+    /// Append an owned string, reusing its allocation when it is large.
+    pub fn push_string(&mut self, s: String) {
+        if s.len() < self.next_capacity / 2 {
+            self.push_str(&s);
+            return;
+        }
+
+        self.len += s.len();
+        self.segments.push(s);
+    }
+
+    fn push_segment(&mut self, segment: String) {
+        self.segments.push(segment);
+        self.next_capacity = (self.next_capacity * 2).min(MAX_SEGMENT_CAPACITY);
+    }
+
+    /// Materialize the buffer into a single `String` with one allocation.
+    pub fn into_string(self) -> String {
+        if self.segments.len() == 1 {
+            return self.segments.into_iter().next().unwrap_or_default();
+        }
+
+        let mut result = String::with_capacity(self.len);
+        for segment in &self.segments {
+            result.push_str(segment);
+        }
+        result
+    }
+
+    /// Materialize the buffer with leading and trailing whitespace removed.
+    ///
+    /// Equivalent to `buffer.into_string().trim().to_string()` without the second copy.
+    pub fn into_trimmed_string(self) -> String {
+        let mut result = self.into_string();
+
+        let end = result.trim_end().len();
+        result.truncate(end);
+
+        let start = result.len() - result.trim_start().len();
+        result.drain(..start);
+
+        result
+    }
+}
+
+impl Default for SegmentedBuffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Write for SegmentedBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s);
+        Ok(())
+    }
+}
+
+impl fmt::Display for SegmentedBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.segments {
+            f.write_str(segment)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<SegmentedBuffer> for String {
+    fn from(buffer: SegmentedBuffer) -> Self {
+        buffer.into_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fmt::Write;
+
+    #[test]
+    fn test_segments_do_not_reallocate() {
+        let mut buffer = SegmentedBuffer::with_capacity(4);
+        let mut expected = String::new();
+
+        for i in 0..1000 {
+            let piece = format!("line {}\n", i);
+            buffer.push_str(&piece);
+            expected.push_str(&piece);
+        }
+
+        assert!(buffer.segments.iter().all(|s| s.len() <= s.capacity()));
+        assert!(buffer.segments.len() > 1);
+        assert_eq!(buffer.len(), expected.len());
+        assert_eq!(buffer.to_string(), expected);
+        assert_eq!(buffer.into_string(), expected);
+    }
+
+    #[test]
+    fn test_push_string_reuses_large_allocation() {
+        let mut buffer = SegmentedBuffer::with_capacity(16);
+        buffer.push_str("head ");
+        buffer.push_string("x".repeat(100));
+        buffer.push('!');
+
+        assert_eq!(buffer.len(), 106);
+        assert_eq!(buffer.into_string(), format!("head {}!", "x".repeat(100)));
+    }
+
+    #[test]
+    fn test_into_trimmed_string() {
+        let mut buffer = SegmentedBuffer::new();
+        buffer.push_str("\n\n  ");
+        write!(buffer, "Slide {}", 1).unwrap();
+        buffer.push_str("  \n");
+
+        assert_eq!(buffer.into_trimmed_string(), "Slide 1");
+    }
+
+    #[test]
+    fn test_empty_buffer() {
+        let buffer = SegmentedBuffer::new();
+        assert!(buffer.is_empty());
+        assert_eq!(buffer.into_trimmed_string(), "");
+    }
+}
@@ -1,5 +1,6 @@
 use chardetng::EncodingDetector;
 use encoding_rs::Encoding;
+use memchr::{memchr, memchr3};
 use once_cell::sync::Lazy;
 use regex::Regex;
 use std::borrow::Cow;
@@ -115,6 +116,10 @@ fn calculate_text_confidence_internal(text: &str) -> f64 {
         return 0.0;
     }
 
+    if text.is_ascii() {
+        return calculate_ascii_text_confidence(text.as_bytes());
+    }
+
     let total_chars = text.len() as f64;
 
     let replacement_count = REPLACEMENT_CHARS.find_iter(text).count() as f64;
@@ -140,12 +145,59 @@ fn calculate_text_confidence_internal(text: &str) -> f64 {
     (readability_score - final_penalty).clamp(0.0, 1.0)
 }
 
+/// Byte-level equivalent of [`calculate_text_confidence_internal`] for ASCII input.
+///
+/// ASCII text cannot contain replacement characters or Cyrillic runs, so only control
+/// characters and readable bytes need to be counted.
+fn calculate_ascii_text_confidence(bytes: &[u8]) -> f64 {
+    let total_chars = bytes.len() as f64;
+
+    let mut control_count = 0usize;
+    let mut readable_chars = 0usize;
+    for &byte in bytes {
+        if is_ascii_control_artifact(byte) {
+            control_count += 1;
+        }
+        if byte.is_ascii_graphic() || matches!(byte, b' ' | b'\t' | b'\n' | b'\r' | 0x0B | 0x0C) {
+            readable_chars += 1;
+        }
+    }
+
+    let penalty = (control_count as f64 * 2.0) / total_chars;
+    let readability_score = readable_chars as f64 / total_chars;
+
+    (readability_score - penalty).clamp(0.0, 1.0)
+}
+
+/// ASCII bytes matched by [`CONTROL_CHARS`].
+#[inline]
+fn is_ascii_control_artifact(byte: u8) -> bool {
+    matches!(byte, 0x00..=0x08 | 0x0B | 0x0C | 0x0E..=0x1F | 0x7F)
+}
+
+/// Whether `text` may contain anything [`fix_mojibake_internal`] would remove.
+///
+/// C1 controls (U+0080..U+009F) start with `0xC2`, combining diacritics (U+0300..U+036F)
+/// with `0xCC`/`0xCD` and U+FFFD with `0xEF` in UTF-8, so text without those lead bytes
+/// and without ASCII control characters can skip the regex passes entirely.
+#[inline]
+fn has_mojibake_candidates(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    if bytes.iter().any(|&byte| is_ascii_control_artifact(byte)) {
+        return true;
+    }
+    if text.is_ascii() {
+        return false;
+    }
+    memchr3(0xC2, 0xCC, 0xCD, bytes).is_some() || memchr(0xEF, bytes).is_some()
+}
+
 pub fn fix_mojibake(text: &str) -> String {
     fix_mojibake_internal(text)
 }
 
 fn fix_mojibake_internal(text: &str) -> String {
-    if text.is_empty() {
+    if text.is_empty() || !has_mojibake_candidates(text) {
         return text.to_string();
     }
 
@@ -209,6 +261,21 @@ mod tests {
         assert_eq!(fixed, "Textwithcontrolchars");
     }
 
+    #[test]
+    fn test_fix_mojibake_non_ascii_candidates() {
+        assert_eq!(fix_mojibake("caf\u{0301}e\u{FFFD}\u{0085}!"), "cafe!");
+        assert_eq!(fix_mojibake("Grüße aus Köln"), "Grüße aus Köln");
+        assert!(!has_mojibake_candidates("Grüße aus Köln"));
+        assert!(has_mojibake_candidates("broken \u{FFFD}"));
+    }
+
+    #[test]
+    fn test_calculate_text_confidence_ascii_control_penalty() {
+        let text = "abcd\x01";
+        let expected = (4.0 - 2.0) / 5.0;
+        assert!((calculate_text_confidence(text) - expected).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn test_get_encoding_cache_key() {
         let key = get_encoding_cache_key("hash123", 1024);
@@ -0,0 +1,303 @@
+//! Custom regex and dictionary taggers.
+//!
+//! Users name their own patterns in `postprocessor.taggers` (contract numbers, ticket IDs,
+//! product names) and [`TaggerProcessor`] reports every match under that name in
+//! `metadata.tags`, with byte offsets into the content. Patterns use Rust `regex` syntax;
+//! dictionaries match their terms as whole words, ignoring case.
+
+use crate::core::config::TaggerConfig;
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::types::TagMatch;
+use crate::{ExtractionConfig, ExtractionResult, KreuzbergError, Result};
+use async_trait::async_trait;
+use regex::{Regex, RegexBuilder};
+use std::collections::HashMap;
+
+/// Find the matches of every tagger in `text`.
+///
+/// Taggers without matches are left out of the returned map.
+///
+/// # Errors
+///
+/// Returns `KreuzbergError::Validation` if a tagger's pattern is not a valid regular expression.
+///
+/// # Example
+///
+/// ```rust
+/// use kreuzberg::TaggerConfig;
+/// use kreuzberg::text::find_tags;
+/// use std::collections::HashMap;
+///
+/// let taggers = HashMap::from([
+///     ("contract_number".to_string(), TaggerConfig::Pattern(r"\bCN-\d{6}\b".to_string())),
+///     ("product".to_string(), TaggerConfig::Dictionary(vec!["Widget Pro".to_string()])),
+/// ]);
+///
+/// let tags = find_tags("Contract CN-004211 covers 40 widget pro units.", &taggers).unwrap();
+/// assert_eq!(tags["contract_number"][0].text, "CN-004211");
+/// assert_eq!(tags["product"][0].byte_start, 29);
+/// ```
+pub fn find_tags(text: &str, taggers: &HashMap<String, TaggerConfig>) -> Result<HashMap<String, Vec<TagMatch>>> {
+    let mut tags = HashMap::new();
+
+    for (name, tagger) in taggers {
+        let matches = match tagger {
+            TaggerConfig::Pattern(pattern) => {
+                let regex = Regex::new(pattern).map_err(|e| {
+                    KreuzbergError::validation_with_source(format!("Invalid pattern for tagger '{name}'"), e)
+                })?;
+                regex
+                    .find_iter(text)
+                    .filter(|m| !m.is_empty())
+                    .map(|m| tag_match(text, m.start(), m.end()))
+                    .collect()
+            }
+            TaggerConfig::Dictionary(terms) => match dictionary_regex(terms)? {
+                Some(regex) => regex
+                    .find_iter(text)
+                    .filter(|m| is_whole_word(text, m.start(), m.end()))
+                    .map(|m| tag_match(text, m.start(), m.end()))
+                    .collect(),
+                None => Vec::new(),
+            },
+        };
+
+        if !matches.is_empty() {
+            tags.insert(name.clone(), matches);
+        }
+    }
+
+    Ok(tags)
+}
+
+/// Build a case-insensitive alternation of `terms`, longest first so that "Widget Pro" wins
+/// over "Widget". Returns `None` when there are no non-blank terms.
+fn dictionary_regex(terms: &[String]) -> Result<Option<Regex>> {
+    let mut terms: Vec<&str> = terms.iter().map(|t| t.trim()).filter(|t| !t.is_empty()).collect();
+    if terms.is_empty() {
+        return Ok(None);
+    }
+    terms.sort_by_key(|t| std::cmp::Reverse(t.len()));
+
+    let alternation = terms.iter().map(|t| regex::escape(t)).collect::<Vec<_>>().join("|");
+    RegexBuilder::new(&alternation)
+        .case_insensitive(true)
+        .build()
+        .map(Some)
+        .map_err(|e| KreuzbergError::validation_with_source("Invalid tagger dictionary", e))
+}
+
+/// Whether the match at `start..end` is not part of a longer word.
+///
+/// Only word characters at the edges of the match need a boundary, so terms such as "C++"
+/// or ".NET" still match.
+fn is_whole_word(text: &str, start: usize, end: usize) -> bool {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let matched = &text[start..end];
+
+    let before_ok =
+        !matched.chars().next().is_some_and(is_word) || !text[..start].chars().next_back().is_some_and(is_word);
+    let after_ok =
+        !matched.chars().next_back().is_some_and(is_word) || !text[end..].chars().next().is_some_and(is_word);
+    before_ok && after_ok
+}
+
+fn tag_match(text: &str, start: usize, end: usize) -> TagMatch {
+    TagMatch {
+        text: text[start..end].to_string(),
+        byte_start: start,
+        byte_end: end,
+    }
+}
+
+/// Post-processor running the custom taggers of
+/// [`PostProcessorConfig::taggers`](crate::PostProcessorConfig::taggers).
+///
+/// Runs in the Middle stage, after header/footer removal and line repair, so offsets refer
+/// to the cleaned content. Token reduction runs later and may change the content; the offsets
+/// then refer to the text before reduction. An invalid pattern is reported as a processing
+/// error for this processor and leaves `metadata.tags` unset.
+#[derive(Debug, Clone, Copy)]
+pub struct TaggerProcessor;
+
+impl Plugin for TaggerProcessor {
+    fn name(&self) -> &str {
+        "custom-taggers"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for TaggerProcessor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let Some(taggers) = taggers(config) else {
+            return Ok(());
+        };
+
+        let tags = find_tags(&result.content, taggers)?;
+        if !tags.is_empty() {
+            result.metadata.tags = Some(tags);
+        }
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Middle
+    }
+
+    fn should_process(&self, result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        taggers(config).is_some() && !result.content.is_empty()
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        (result.content.len() / 102400).max(1) as u64
+    }
+}
+
+fn taggers(config: &ExtractionConfig) -> Option<&HashMap<String, TaggerConfig>> {
+    config
+        .postprocessor
+        .as_ref()?
+        .taggers
+        .as_ref()
+        .filter(|taggers| !taggers.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PostProcessorConfig;
+    use crate::types::Metadata;
+
+    fn tagger_map(entries: &[(&str, TaggerConfig)]) -> HashMap<String, TaggerConfig> {
+        entries
+            .iter()
+            .map(|(name, tagger)| (name.to_string(), tagger.clone()))
+            .collect()
+    }
+
+    fn dictionary(terms: &[&str]) -> TaggerConfig {
+        TaggerConfig::Dictionary(terms.iter().map(|t| t.to_string()).collect())
+    }
+
+    #[test]
+    fn test_pattern_matches_with_offsets() {
+        let text = "Renewal of CN-123456 and CN-654321; not CN-12345.";
+        let contract = TaggerConfig::Pattern(r"\bCN-\d{6}\b".to_string());
+        let tags = find_tags(text, &tagger_map(&[("contract", contract)])).unwrap();
+
+        let found: Vec<&str> = tags["contract"].iter().map(|m| m.text.as_str()).collect();
+        assert_eq!(found, vec!["CN-123456", "CN-654321"]);
+        for m in &tags["contract"] {
+            assert_eq!(&text[m.byte_start..m.byte_end], m.text);
+        }
+    }
+
+    #[test]
+    fn test_dictionary_matches_whole_words_ignoring_case() {
+        let text = "Über Widget Pro, widgets and WIDGET. Skills: C++ and Rust.";
+        let tags = find_tags(
+            text,
+            &tagger_map(&[
+                ("product", dictionary(&["Widget", "Widget Pro", "  "])),
+                ("language", dictionary(&["C++", "rust"])),
+            ]),
+        )
+        .unwrap();
+
+        let products: Vec<&str> = tags["product"].iter().map(|m| m.text.as_str()).collect();
+        assert_eq!(products, vec!["Widget Pro", "WIDGET"]);
+        let languages: Vec<&str> = tags["language"].iter().map(|m| m.text.as_str()).collect();
+        assert_eq!(languages, vec!["C++", "Rust"]);
+    }
+
+    #[test]
+    fn test_taggers_without_matches_are_omitted() {
+        let tags = find_tags(
+            "nothing to see",
+            &tagger_map(&[
+                ("id", TaggerConfig::Pattern(r"ID-\d+".to_string())),
+                ("empty", dictionary(&[])),
+            ]),
+        )
+        .unwrap();
+        assert!(tags.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_pattern_is_validation_error() {
+        let broken = TaggerConfig::Pattern("(unclosed".to_string());
+        let err = find_tags("text", &tagger_map(&[("broken", broken)])).unwrap_err();
+        assert!(matches!(err, KreuzbergError::Validation { .. }));
+        assert!(err.to_string().contains("broken"));
+    }
+
+    #[test]
+    fn test_config_from_toml() {
+        let config: PostProcessorConfig = toml::from_str(
+            r#"
+            [taggers]
+            contract_number = '\bCN-\d{6}\b'
+            product = ["Widget Pro", "Gadget"]
+            "#,
+        )
+        .unwrap();
+
+        let taggers = config.taggers.unwrap();
+        assert_eq!(
+            taggers["contract_number"],
+            TaggerConfig::Pattern(r"\bCN-\d{6}\b".to_string())
+        );
+        assert_eq!(taggers["product"], dictionary(&["Widget Pro", "Gadget"]));
+    }
+
+    #[tokio::test]
+    async fn test_processor_attaches_tags() {
+        let config = ExtractionConfig {
+            postprocessor: Some(PostProcessorConfig {
+                taggers: Some(tagger_map(&[(
+                    "ticket",
+                    TaggerConfig::Pattern(r"[A-Z]+-\d+".to_string()),
+                )])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut result = ExtractionResult {
+            content: "Fixed in KB-42, see also OPS-7.".to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        };
+
+        assert!(TaggerProcessor.should_process(&result, &config));
+        assert!(!TaggerProcessor.should_process(&result, &ExtractionConfig::default()));
+        TaggerProcessor.process(&mut result, &config).await.unwrap();
+
+        let tags = result.metadata.tags.expect("tags should be attached");
+        assert_eq!(tags["ticket"].len(), 2);
+        assert_eq!(tags["ticket"][1].text, "OPS-7");
+    }
+}
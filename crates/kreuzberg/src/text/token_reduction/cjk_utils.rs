@@ -1,44 +1,43 @@
 use std::ops::RangeInclusive;
 
+/// Unicode ranges segmented as CJK: kana and the common ideograph blocks.
+const CJK_RANGES: [RangeInclusive<u32>; 5] = [
+    0x3040..=0x309F, // Hiragana
+    0x30A0..=0x30FF, // Katakana
+    0x3400..=0x4DBF, // CJK Unified Ideographs Extension A
+    0x4E00..=0x9FFF, // CJK Unified Ideographs
+    0xF900..=0xFAFF, // CJK Compatibility Ideographs
+];
+
 /// CJK text tokenizer for token reduction.
 ///
 /// This tokenizer uses bigram (2-character) tokenization for CJK text,
 /// which is appropriate for token reduction where we want to preserve
-/// meaning while reducing token count.
+/// meaning while reducing token count. Runs of CJK characters are split
+/// out of mixed tokens such as `"GPU加速"`, so the Latin part stays one token.
 ///
 /// # Unicode Range Coverage
 ///
-/// **Currently covers:** CJK Unified Ideographs (U+4E00-U+9FFF)
-/// - Covers ~20,992 common Chinese/Japanese Kanji characters
-/// - Sufficient for token reduction purposes with Chinese and Japanese text
-///
-/// **Intentionally excluded:**
-/// - Hiragana (U+3040-U+309F): Japanese phonetic script
-/// - Katakana (U+30A0-U+30FF): Japanese phonetic script
-/// - Hangul (U+AC00-U+D7AF): Korean alphabet
+/// **Covers:** Hiragana, Katakana, CJK Unified Ideographs (including
+/// Extension A) and CJK Compatibility Ideographs.
 ///
-/// These exclusions are intentional for token reduction. Hiragana and Katakana
-/// are typically tokenized with whitespace, and Hangul has different tokenization
-/// requirements. If broader CJK support is needed, consider expanding the range
-/// or using language-specific tokenizers.
-pub struct CjkTokenizer {
-    cjk_range: RangeInclusive<u32>,
-}
+/// **Intentionally excluded:** Hangul. Korean separates words with spaces,
+/// so whitespace tokenization already yields sensible tokens.
+pub struct CjkTokenizer;
 
 impl CjkTokenizer {
     pub fn new() -> Self {
-        Self {
-            cjk_range: 0x4E00..=0x9FFF,
-        }
+        Self
     }
 
-    /// Checks if a character is a CJK Unified Ideograph (U+4E00-U+9FFF).
+    /// Checks if a character is a kana character or a CJK ideograph.
     ///
-    /// Returns true for Chinese characters and Japanese Kanji, false for
-    /// Hiragana, Katakana, Hangul, and non-CJK characters.
+    /// Returns true for Chinese characters, Japanese Kanji, Hiragana and
+    /// Katakana, false for Hangul and non-CJK characters.
     #[inline]
     pub fn is_cjk_char(&self, c: char) -> bool {
-        self.cjk_range.contains(&(c as u32))
+        let code = c as u32;
+        CJK_RANGES.iter().any(|range| range.contains(&code))
     }
 
     #[inline]
@@ -47,8 +46,33 @@ impl CjkTokenizer {
     }
 
     pub fn tokenize_cjk_string(&self, text: &str) -> Vec<String> {
-        let chars: Vec<char> = text.chars().collect();
-        self.tokenize_cjk_chars(&chars)
+        let mut tokens = Vec::new();
+        let mut cjk_run: Vec<char> = Vec::new();
+        let mut other_run = String::new();
+
+        for c in text.chars() {
+            if self.is_cjk_char(c) {
+                if !other_run.is_empty() {
+                    tokens.push(std::mem::take(&mut other_run));
+                }
+                cjk_run.push(c);
+            } else {
+                if !cjk_run.is_empty() {
+                    tokens.extend(self.tokenize_cjk_chars(&cjk_run));
+                    cjk_run.clear();
+                }
+                other_run.push(c);
+            }
+        }
+
+        if !cjk_run.is_empty() {
+            tokens.extend(self.tokenize_cjk_chars(&cjk_run));
+        }
+        if !other_run.is_empty() {
+            tokens.push(other_run);
+        }
+
+        tokens
     }
 
     pub fn tokenize_cjk_chars(&self, chars: &[char]) -> Vec<String> {
@@ -94,6 +118,19 @@ impl CjkTokenizer {
         }
         all_tokens
     }
+
+    /// Number of tokens in `text`: whitespace-delimited words, with CJK runs counted as bigrams.
+    pub fn count_tokens(&self, text: &str) -> usize {
+        text.split_whitespace()
+            .map(|token| {
+                if self.has_cjk(token) {
+                    self.tokenize_cjk_string(token).len()
+                } else {
+                    1
+                }
+            })
+            .sum()
+    }
 }
 
 impl Default for CjkTokenizer {
@@ -119,6 +156,10 @@ mod tests {
         assert!(!tokenizer.is_cjk_char('Z'));
         assert!(!tokenizer.is_cjk_char('1'));
         assert!(!tokenizer.is_cjk_char(' '));
+
+        assert!(tokenizer.is_cjk_char('ひ'));
+        assert!(tokenizer.is_cjk_char('カ'));
+        assert!(!tokenizer.is_cjk_char('한'));
     }
 
     #[test]
@@ -161,4 +202,25 @@ mod tests {
         let tokens = tokenizer.tokenize_mixed_text("学习 machine learning 技术");
         assert_eq!(tokens, vec!["学习", "machine", "learning", "技术"]);
     }
+
+    #[test]
+    fn test_tokenize_cjk_string_splits_mixed_runs() {
+        let tokenizer = CjkTokenizer::new();
+
+        let tokens = tokenizer.tokenize_cjk_string("GPU加速计算");
+        assert_eq!(tokens, vec!["GPU", "加速", "计算"]);
+
+        let tokens = tokenizer.tokenize_cjk_string("東京タワーです。");
+        assert_eq!(tokens, vec!["東京", "タワ", "ーで", "す", "。"]);
+    }
+
+    #[test]
+    fn test_count_tokens() {
+        let tokenizer = CjkTokenizer::new();
+
+        assert_eq!(tokenizer.count_tokens(""), 0);
+        assert_eq!(tokenizer.count_tokens("hello brave new world"), 4);
+        assert_eq!(tokenizer.count_tokens("学习 machine learning 技术"), 4);
+        assert_eq!(tokenizer.count_tokens("机器学习很有趣"), 4);
+    }
 }
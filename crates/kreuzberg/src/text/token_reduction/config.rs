@@ -1,3 +1,4 @@
+use crate::core::config::StopwordsConfig;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -45,9 +46,15 @@ pub struct TokenReductionConfig {
     pub enable_parallel: bool,
     pub use_simd: bool,
     pub custom_stopwords: Option<HashMap<String, Vec<String>>>,
+    /// Custom stopwords merged with or replacing the embedded list for every language
+    pub stopwords: Option<StopwordsConfig>,
     pub preserve_patterns: Vec<String>,
     pub target_reduction: Option<f32>,
     pub enable_semantic_clustering: bool,
+    /// Keep at most this fraction (0.0-1.0) of the input tokens, dropping the least important sentences
+    pub target_ratio: Option<f32>,
+    /// Keep at most this many tokens, dropping the least important sentences
+    pub max_tokens: Option<usize>,
 }
 
 impl Default for TokenReductionConfig {
@@ -61,9 +68,12 @@ impl Default for TokenReductionConfig {
             enable_parallel: true,
             use_simd: true,
             custom_stopwords: None,
+            stopwords: None,
             preserve_patterns: vec![],
             target_reduction: None,
             enable_semantic_clustering: false,
+            target_ratio: None,
+            max_tokens: None,
         }
     }
 }
@@ -92,9 +102,26 @@ impl TokenReductionConfig {
             enable_parallel,
             use_simd,
             custom_stopwords,
+            stopwords: None,
             preserve_patterns: preserve_patterns.unwrap_or_default(),
             target_reduction: target_reduction.map(|t| t.clamp(0.0, 1.0)),
             enable_semantic_clustering,
+            target_ratio: None,
+            max_tokens: None,
+        }
+    }
+
+    /// Token budget for a text of `input_tokens` tokens, if `target_ratio` or `max_tokens` is set.
+    ///
+    /// When both are set the smaller budget wins.
+    pub fn token_budget(&self, input_tokens: usize) -> Option<usize> {
+        let ratio_budget = self
+            .target_ratio
+            .map(|ratio| (input_tokens as f64 * f64::from(ratio.clamp(0.0, 1.0))).ceil() as usize);
+
+        match (ratio_budget, self.max_tokens) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (budget, None) | (None, budget) => budget,
         }
     }
 }
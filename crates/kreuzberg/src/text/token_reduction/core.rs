@@ -94,7 +94,8 @@ impl TokenReducer {
     }
 
     pub fn reduce(&self, text: &str) -> String {
-        if text.is_empty() || matches!(self.config.level, ReductionLevel::Off) {
+        let budget = self.token_budget(text);
+        if text.is_empty() || (matches!(self.config.level, ReductionLevel::Off) && budget.is_none()) {
             return text.to_string();
         }
 
@@ -104,21 +105,31 @@ impl TokenReducer {
             &text.nfc().collect::<String>()
         };
 
-        match self.config.level {
+        let reduced = match self.config.level {
             ReductionLevel::Off => working_text.to_string(),
             ReductionLevel::Light => self.apply_light_reduction_optimized(working_text),
             ReductionLevel::Moderate => self.apply_moderate_reduction_optimized(working_text),
             ReductionLevel::Aggressive => self.apply_aggressive_reduction_optimized(working_text),
             ReductionLevel::Maximum => self.apply_maximum_reduction_optimized(working_text),
+        };
+
+        match budget {
+            Some(budget) => self.apply_token_budget(&reduced, budget),
+            None => reduced,
         }
     }
 
+    /// Count tokens the way budgets are measured: words, with CJK runs counted as bigrams.
+    pub fn count_tokens(&self, text: &str) -> usize {
+        self.cjk_tokenizer.count_tokens(text)
+    }
+
     pub fn batch_reduce(&self, texts: &[&str]) -> Vec<String> {
         if !self.config.enable_parallel || texts.len() < 2 {
             return texts.iter().map(|text| self.reduce(text)).collect();
         }
 
-        texts.par_iter().map(|text| self.reduce(text)).collect()
+        crate::core::runtime::install(|| texts.par_iter().map(|text| self.reduce(text)).collect())
     }
 
     fn apply_light_reduction_optimized(&self, text: &str) -> String {
@@ -169,14 +180,68 @@ impl TokenReducer {
         result
     }
 
+    fn token_budget(&self, text: &str) -> Option<usize> {
+        if self.config.target_ratio.is_none() && self.config.max_tokens.is_none() {
+            return None;
+        }
+        self.config.token_budget(self.count_tokens(text))
+    }
+
+    /// Drop the least important sentences until `text` fits in `budget` tokens.
+    ///
+    /// Kept sentences stay in document order. If no sentence fits on its own, the most
+    /// important one is cut off after `budget` tokens.
+    fn apply_token_budget(&self, text: &str, budget: usize) -> String {
+        if self.count_tokens(text) <= budget {
+            return text.to_string();
+        }
+
+        let has_cjk_content = self.cjk_tokenizer.has_cjk(text);
+        let sentences = split_sentences(text);
+        let mut scored: Vec<(usize, f32)> = sentences
+            .iter()
+            .enumerate()
+            .map(|(i, sentence)| (i, self.score_sentence_importance(sentence, i, sentences.len())))
+            .collect();
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let mut remaining = budget;
+        let mut kept: Vec<usize> = Vec::new();
+        for &(i, _) in &scored {
+            let tokens = self.count_tokens(sentences[i]);
+            if tokens <= remaining {
+                remaining -= tokens;
+                kept.push(i);
+            }
+        }
+
+        if kept.is_empty() {
+            let Some(&(top, _)) = scored.first() else {
+                return String::new();
+            };
+            let tokens: Vec<String> = self
+                .universal_tokenize(sentences[top])
+                .into_iter()
+                .take(budget)
+                .collect();
+            return self.smart_join(&tokens, has_cjk_content);
+        }
+
+        kept.sort_unstable();
+        let kept: Vec<String> = kept.into_iter().map(|i| sentences[i].to_string()).collect();
+        self.smart_join(&kept, has_cjk_content)
+    }
+
     fn apply_parallel_moderate_reduction(&self, text: &str) -> String {
-        let num_threads = rayon::current_num_threads();
+        let num_threads = crate::core::runtime::current_num_threads();
         let chunks = chunk_text_for_parallel(text, num_threads);
 
-        let processed_chunks: Vec<String> = chunks
-            .par_iter()
-            .map(|chunk| self.filter_pipeline.apply_moderate_filters(chunk))
-            .collect();
+        let processed_chunks: Vec<String> = crate::core::runtime::install(|| {
+            chunks
+                .par_iter()
+                .map(|chunk| self.filter_pipeline.apply_moderate_filters(chunk))
+                .collect()
+        });
 
         processed_chunks.join(" ")
     }
@@ -251,7 +316,7 @@ impl TokenReducer {
             .cloned()
             .collect();
 
-        let has_cjk_content = text.chars().any(|c| c as u32 >= 0x4E00 && (c as u32) <= 0x9FFF);
+        let has_cjk_content = self.cjk_tokenizer.has_cjk(text);
         let fallback_threshold = if has_cjk_content {
             original_count / 5
         } else {
@@ -481,6 +546,30 @@ impl TokenReducer {
     }
 }
 
+/// Split `text` after sentence-ending punctuation (Latin and CJK) and line breaks.
+fn split_sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+
+    for (i, c) in text.char_indices() {
+        if matches!(c, '.' | '!' | '?' | '。' | '！' | '？' | '\n') {
+            let end = i + c.len_utf8();
+            let sentence = text[start..end].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+            }
+            start = end;
+        }
+    }
+
+    let rest = text[start..].trim();
+    if !rest.is_empty() {
+        sentences.push(rest);
+    }
+
+    sentences
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -793,4 +882,78 @@ mod tests {
 
         assert!(!result.is_empty());
     }
+
+    #[test]
+    fn test_max_tokens_budget() {
+        let config = TokenReductionConfig {
+            level: ReductionLevel::Off,
+            max_tokens: Some(12),
+            ..Default::default()
+        };
+
+        let reducer = TokenReducer::new(&config, Some("en")).unwrap();
+        let input = "Kreuzberg extracts text from 56 formats. The weather was nice. \
+                     It supports OCR with Tesseract and PaddleOCR backends. We had lunch outside.";
+        let result = reducer.reduce(input);
+
+        assert!(!result.is_empty());
+        assert!(reducer.count_tokens(&result) <= 12);
+        assert!(input.contains(result.split(". ").next().unwrap()));
+    }
+
+    #[test]
+    fn test_target_ratio_budget() {
+        let config = TokenReductionConfig {
+            level: ReductionLevel::Light,
+            target_ratio: Some(0.5),
+            ..Default::default()
+        };
+
+        let reducer = TokenReducer::new(&config, Some("en")).unwrap();
+        let input = "First sentence has five words. Second sentence also has six words. \
+                     Third sentence is here. Fourth sentence closes the text.";
+        let result = reducer.reduce(input);
+
+        assert!(!result.is_empty());
+        assert!(reducer.count_tokens(&result) <= reducer.count_tokens(input).div_ceil(2));
+    }
+
+    #[test]
+    fn test_budget_truncates_single_long_sentence() {
+        let config = TokenReductionConfig {
+            level: ReductionLevel::Off,
+            max_tokens: Some(3),
+            ..Default::default()
+        };
+
+        let reducer = TokenReducer::new(&config, None).unwrap();
+        let result = reducer.reduce("one two three four five six seven");
+
+        assert_eq!(result, "one two three");
+    }
+
+    #[test]
+    fn test_cjk_token_budget() {
+        let config = TokenReductionConfig {
+            level: ReductionLevel::Off,
+            max_tokens: Some(6),
+            ..Default::default()
+        };
+
+        let reducer = TokenReducer::new(&config, Some("zh")).unwrap();
+        let input = "机器学习是人工智能的分支。今天天气很好。深度学习使用神经网络。";
+        let result = reducer.reduce(input);
+
+        assert!(!result.is_empty());
+        assert!(!result.contains(' '));
+        assert!(reducer.count_tokens(&result) <= 6);
+    }
+
+    #[test]
+    fn test_split_sentences() {
+        assert_eq!(
+            split_sentences("One. Two!\nThree? 四。五"),
+            vec!["One.", "Two!", "Three?", "四。", "五"]
+        );
+    }
 }
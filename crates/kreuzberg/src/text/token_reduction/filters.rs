@@ -1,5 +1,5 @@
 use crate::error::{KreuzbergError, Result};
-use crate::stopwords::STOPWORDS;
+use crate::stopwords::{STOPWORDS, apply_stopwords_config};
 use crate::text::token_reduction::config::TokenReductionConfig;
 use ahash::{AHashMap, AHashSet};
 use once_cell::sync::Lazy;
@@ -37,6 +37,10 @@ impl FilterPipeline {
                 .expect("English stopwords must be available - indicates build failure if missing")
         });
 
+        if let Some(ref stopwords_config) = config.stopwords {
+            apply_stopwords_config(&mut stopwords, stopwords_config);
+        }
+
         if let Some(ref custom) = config.custom_stopwords
             && let Some(custom_for_lang) = custom.get(language)
         {
@@ -451,6 +455,28 @@ mod tests {
         assert!(result.contains("test"));
     }
 
+    #[cfg_attr(coverage, ignore = "coverage instrumentation disables SIMD stopword paths")]
+    #[test]
+    fn test_stopwords_config_replace() {
+        let config = TokenReductionConfig {
+            stopwords: Some(crate::core::config::StopwordsConfig {
+                custom: vec!["Custom".to_string()],
+                mode: crate::core::config::StopwordsMode::Replace,
+            }),
+            ..Default::default()
+        };
+
+        let config = Arc::new(config);
+        let pipeline = FilterPipeline::new(&config, "en").unwrap();
+
+        let input = "This is a custom word test";
+        let result = pipeline.remove_stopwords(input);
+
+        assert!(!result.contains("custom"));
+        assert!(result.contains(" is "));
+        assert!(result.contains("word"));
+    }
+
     #[test]
     fn test_spanish_stopwords() {
         let config = Arc::new(TokenReductionConfig::default());
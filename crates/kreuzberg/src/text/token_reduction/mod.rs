@@ -2,11 +2,13 @@ mod cjk_utils;
 mod config;
 mod core;
 mod filters;
+mod processor;
 mod semantic;
 mod simd_text;
 
 pub use config::{ReductionLevel, TokenReductionConfig};
 pub use core::TokenReducer;
+pub use processor::TokenReductionProcessor;
 
 // TODO: reorganize token_reduction - move out of text, and reorganize text properly into utils etc.
 
@@ -50,6 +52,23 @@ pub fn reduce_tokens(
     Ok(reducer.reduce(text))
 }
 
+/// Counts tokens the way `target_ratio` and `max_tokens` budgets are measured.
+///
+/// Whitespace-delimited words count as one token each; runs of CJK characters
+/// (ideographs and kana) count one token per character bigram.
+///
+/// # Examples
+///
+/// ```rust
+/// use kreuzberg::text::token_reduction::count_tokens;
+///
+/// assert_eq!(count_tokens("reduce to four tokens"), 4);
+/// assert_eq!(count_tokens("机器学习"), 2);
+/// ```
+pub fn count_tokens(text: &str) -> usize {
+    cjk_utils::CjkTokenizer::new().count_tokens(text)
+}
+
 /// Reduces token count for multiple texts efficiently using parallel processing.
 ///
 /// This function processes multiple texts in parallel using Rayon, providing
@@ -0,0 +1,225 @@
+//! Token reduction post-processor.
+//!
+//! Applies [`ExtractionConfig::token_reduction`](crate::ExtractionConfig::token_reduction) to the
+//! extracted content: stopword removal and sentence selection according to the configured mode,
+//! followed by the `target_ratio`/`max_tokens` budget.
+
+use super::{ReductionLevel, TokenReducer, TokenReductionConfig, count_tokens};
+use crate::plugins::{Plugin, PostProcessor, ProcessingStage};
+use crate::stopwords::language_segments;
+use crate::{ExtractionConfig, ExtractionResult, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+/// Post-processor that reduces the token count of `result.content`.
+///
+/// This processor:
+/// - Runs in the Late processing stage, so keyword extraction, entity extraction and chunking
+///   still see the full text
+/// - Only processes when `config.token_reduction` sets a mode other than `"off"` or a budget
+/// - Picks stopwords from the detected languages, reducing each paragraph with its own
+///   language's list when a document mixes several, and falls back to English when
+///   detection is disabled
+#[derive(Debug, Clone, Copy)]
+pub struct TokenReductionProcessor;
+
+impl Plugin for TokenReductionProcessor {
+    fn name(&self) -> &str {
+        "token-reduction"
+    }
+
+    fn version(&self) -> String {
+        env!("CARGO_PKG_VERSION").to_string()
+    }
+
+    fn initialize(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn shutdown(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+impl PostProcessor for TokenReductionProcessor {
+    async fn process(&self, result: &mut ExtractionResult, config: &ExtractionConfig) -> Result<()> {
+        let Some(options) = config.token_reduction.as_ref() else {
+            return Ok(());
+        };
+
+        let reduction = reduction_config(options);
+        let segments = language_segments(result, config);
+
+        let reduced = if let [(text, language)] = *segments.as_slice() {
+            TokenReducer::new(&reduction, Some(language))?.reduce(text)
+        } else {
+            // Reduce each paragraph with its own stopwords, then apply the budget once to
+            // the joined text so it still refers to the whole document.
+            let budget = reduction.token_budget(count_tokens(&result.content));
+            let per_paragraph = TokenReductionConfig {
+                target_ratio: None,
+                max_tokens: None,
+                ..reduction.clone()
+            };
+
+            let mut reducers: HashMap<&str, TokenReducer> = HashMap::new();
+            let mut paragraphs = Vec::with_capacity(segments.len());
+            for &(text, language) in &segments {
+                let reducer = match reducers.entry(language) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => entry.insert(TokenReducer::new(&per_paragraph, Some(language))?),
+                };
+                let paragraph = reducer.reduce(text);
+                if !paragraph.is_empty() {
+                    paragraphs.push(paragraph);
+                }
+            }
+
+            let joined = paragraphs.join("\n\n");
+            match budget {
+                Some(max_tokens) => {
+                    let final_pass = TokenReductionConfig {
+                        level: ReductionLevel::Off,
+                        target_ratio: None,
+                        max_tokens: Some(max_tokens),
+                        ..reduction
+                    };
+                    TokenReducer::new(&final_pass, None)?.reduce(&joined)
+                }
+                None => joined,
+            }
+        };
+        result.content = reduced;
+
+        Ok(())
+    }
+
+    fn processing_stage(&self) -> ProcessingStage {
+        ProcessingStage::Late
+    }
+
+    fn should_process(&self, result: &ExtractionResult, config: &ExtractionConfig) -> bool {
+        config.token_reduction.as_ref().is_some_and(|options| {
+            ReductionLevel::from(options.mode.as_str()) != ReductionLevel::Off
+                || options.target_ratio.is_some()
+                || options.max_tokens.is_some()
+        }) && !result.content.is_empty()
+    }
+
+    fn estimated_duration_ms(&self, result: &ExtractionResult) -> u64 {
+        (result.content.len() / 51200).max(1) as u64
+    }
+}
+
+fn reduction_config(options: &crate::core::config::TokenReductionConfig) -> TokenReductionConfig {
+    TokenReductionConfig {
+        level: ReductionLevel::from(options.mode.as_str()),
+        stopwords: options.stopwords.clone(),
+        target_ratio: options.target_ratio,
+        max_tokens: options.max_tokens,
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Metadata;
+
+    fn result_with(content: &str) -> ExtractionResult {
+        ExtractionResult {
+            content: content.to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: Metadata::default(),
+            tables: vec![],
+            detected_languages: None,
+            chunks: None,
+            images: None,
+            pages: None,
+            entities: None,
+            keywords: None,
+            children: None,
+            fields: None,
+            warnings: Vec::new(),
+        }
+    }
+
+    fn config_with(mode: &str, max_tokens: Option<usize>) -> ExtractionConfig {
+        ExtractionConfig {
+            token_reduction: Some(crate::core::config::TokenReductionConfig {
+                mode: mode.to_string(),
+                preserve_important_words: true,
+                stopwords: None,
+                target_ratio: None,
+                max_tokens,
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_applies_max_tokens_budget() {
+        let config = config_with("off", Some(8));
+        let mut result = result_with(
+            "Kreuzberg extracts text from documents. The weather was nice today. \
+             It runs OCR on scanned pages. We had lunch outside.",
+        );
+
+        assert!(TokenReductionProcessor.should_process(&result, &config));
+        TokenReductionProcessor.process(&mut result, &config).await.unwrap();
+
+        assert!(!result.content.is_empty());
+        assert!(result.content.split_whitespace().count() <= 8);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "language-detection")]
+    async fn test_uses_stopwords_of_each_detected_language() {
+        use crate::core::config::LanguageDetectionConfig;
+        use crate::types::DetectedLanguage;
+
+        let detected = |language: &str| DetectedLanguage {
+            language: language.to_string(),
+            confidence: 0.9,
+            script: "Latin".to_string(),
+        };
+        let config = ExtractionConfig {
+            language_detection: Some(LanguageDetectionConfig {
+                enabled: true,
+                min_confidence: 0.5,
+                detect_multiple: true,
+                max_sample_kb: None,
+            }),
+            ..config_with("moderate", None)
+        };
+        let mut result = result_with(
+            "Der Hund und die Katze schlafen unter dem Tisch im Garten.\n\n\
+             The dog and the cat sleep under the table in the garden.",
+        );
+        result.detected_languages = Some(vec![detected("deu"), detected("eng")]);
+
+        TokenReductionProcessor.process(&mut result, &config).await.unwrap();
+
+        let words: Vec<&str> = result.content.split_whitespace().collect();
+        for stopword in ["und", "dem", "and", "the"] {
+            assert!(
+                !words.contains(&stopword),
+                "{stopword} survived in {:?}",
+                result.content
+            );
+        }
+        assert!(words.contains(&"Hund") && words.contains(&"dog"));
+    }
+
+    #[test]
+    fn test_skips_off_mode_without_budget() {
+        let result = result_with("Some text.");
+        assert!(!TokenReductionProcessor.should_process(&result, &config_with("off", None)));
+        assert!(!TokenReductionProcessor.should_process(&result, &ExtractionConfig::default()));
+        assert!(TokenReductionProcessor.should_process(&result, &config_with("light", None)));
+        assert_eq!(TokenReductionProcessor.processing_stage(), ProcessingStage::Late);
+    }
+}
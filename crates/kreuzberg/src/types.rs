@@ -1,5 +1,6 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[cfg(feature = "pdf")]
 use crate::pdf::metadata::PdfMetadata;
@@ -16,8 +17,13 @@ pub struct ExtractionResult {
     pub mime_type: String,
     pub metadata: Metadata,
     pub tables: Vec<Table>,
+
+    /// Languages detected in the content, most prominent first.
+    ///
+    /// Populated when `ExtractionConfig.language_detection` is set (requires the
+    /// `language-detection` feature).
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub detected_languages: Option<Vec<String>>,
+    pub detected_languages: Option<Vec<DetectedLanguage>>,
 
     /// Text chunks when chunking is enabled.
     ///
@@ -41,6 +47,107 @@ pub struct ExtractionResult {
     /// with tables and images mapped to their respective pages.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pages: Option<Vec<PageContent>>,
+
+    /// Named entities found in the content.
+    ///
+    /// Populated by the entity extraction post-processor when `ExtractionConfig.entities`
+    /// is set (requires the `entities` feature).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entities: Option<Vec<Entity>>,
+
+    /// Keywords extracted from the content.
+    ///
+    /// Populated by the keyword extraction post-processor when `ExtractionConfig.keywords`
+    /// is set (requires the `keywords-yake` or `keywords-rake` feature).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<Vec<Keyword>>,
+
+    /// Results for documents embedded in this one, such as email attachments.
+    ///
+    /// Populated when `ExtractionConfig.email.extract_attachments` is set (requires the
+    /// `email` feature). Each child records its origin in `metadata.additional["parent"]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<ExtractionResult>>,
+
+    /// Field values extracted by the first matching document template, keyed by field name.
+    ///
+    /// Populated when `ExtractionConfig.templates` is set and a template matches (requires the
+    /// `templates` feature). Fields the template could not locate are `null`; the template name
+    /// is recorded in `metadata.additional["template"]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fields: Option<BTreeMap<String, serde_json::Value>>,
+
+    /// Non-fatal issues encountered while processing the document, in the order they occurred.
+    ///
+    /// Skipped attachments and archive entries, pages OCR could barely read, repaired
+    /// structures and truncations by `limits` are reported here instead of failing the
+    /// extraction.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<ExtractionWarning>,
+}
+
+/// A non-fatal issue encountered while processing a document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExtractionWarning {
+    /// Stable identifier of the kind of issue, e.g. `attachment_failed`
+    pub code: String,
+
+    /// Human-readable description
+    pub message: String,
+
+    /// Processing stage that reported the issue
+    pub stage: WarningStage,
+
+    /// Page the issue applies to (1-indexed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+}
+
+impl ExtractionWarning {
+    /// Create a warning that applies to the whole document.
+    pub fn new(code: impl Into<String>, message: impl Into<String>, stage: WarningStage) -> Self {
+        Self {
+            code: code.into(),
+            message: message.into(),
+            stage,
+            page: None,
+        }
+    }
+
+    /// Attach the page (1-indexed) the warning applies to.
+    pub fn on_page(mut self, page: usize) -> Self {
+        self.page = Some(page);
+        self
+    }
+}
+
+/// Processing stage that reported an [`ExtractionWarning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarningStage {
+    /// Repair of a malformed document before extraction
+    Recovery,
+    /// Format-specific extraction
+    Extraction,
+    /// OCR of pages and images
+    Ocr,
+    /// Post-processors, chunking and language detection
+    PostProcessing,
+    /// Resource limits of `ExtractionConfig::limits`
+    Limits,
+}
+
+impl WarningStage {
+    /// Snake-case name used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            WarningStage::Recovery => "recovery",
+            WarningStage::Extraction => "extraction",
+            WarningStage::Ocr => "ocr",
+            WarningStage::PostProcessing => "post_processing",
+            WarningStage::Limits => "limits",
+        }
+    }
 }
 
 /// Format-specific metadata (discriminated union).
@@ -55,6 +162,7 @@ pub enum FormatMetadata {
     Excel(ExcelMetadata),
     Email(EmailMetadata),
     Pptx(PptxMetadata),
+    Office(OfficeMetadata),
     Archive(ArchiveMetadata),
     Image(ImageMetadata),
     Xml(XmlMetadata),
@@ -105,6 +213,14 @@ pub struct Metadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_by: Option<String>,
 
+    /// Publishing entity (Dublin Core `publisher`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<String>,
+
+    /// Unique document identifier such as an ISBN, DOI or URN (Dublin Core `identifier`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+
     /// Page/slide/sheet structure with boundaries
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pages: Option<PageStructure>,
@@ -132,15 +248,130 @@ pub struct Metadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ErrorMetadata>,
 
-    /// Additional custom fields from postprocessors.
+    /// Summary statistics, computed for every result by the extraction pipeline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<ExtractionStats>,
+
+    /// Per-stage durations, recorded when `ExtractionConfig::collect_timings` is set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timings: Option<ExtractionTimings>,
+
+    /// Producing application, capture device and whether the document looks scanned
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provenance: Option<DocumentProvenance>,
+
+    /// Readability scores and vocabulary statistics, set by the `text-metrics` post-processor
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text_metrics: Option<TextMetrics>,
+
+    /// Matches of the configured custom taggers, keyed by tagger name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<HashMap<String, Vec<TagMatch>>>,
+
+    /// Whether the result should go to human review and why, set when `ExtractionConfig::review` is configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review: Option<ReviewStatus>,
+
+    /// Path of the earlier result this one nearly duplicates (its index for byte batches), set by
+    /// batch and directory extraction when `ExtractionConfig::dedup` is configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duplicate_of: Option<String>,
+
+    /// Findings reported by validators, in validator order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub findings: Option<Vec<ValidationFinding>>,
+
+    /// Tracked changes and comments of DOCX and PPTX documents, in document order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revisions: Option<Vec<Revision>>,
+
+    /// Additional custom fields from extractors and postprocessors.
     ///
-    /// This flattened HashMap allows Python/TypeScript postprocessors to add
-    /// arbitrary fields (entity extraction, keyword extraction, etc.).
-    /// Fields are merged at the root level during serialization.
+    /// This flattened HashMap is the "extra" map for anything without a typed home:
+    /// format-specific keys that have no dedicated section, custom document properties,
+    /// and fields added by Python/TypeScript postprocessors (entity extraction, keyword
+    /// extraction, etc.). Fields are merged at the root level during serialization.
     #[serde(flatten)]
     pub additional: HashMap<String, serde_json::Value>,
 }
 
+impl Metadata {
+    /// Move well-known common fields out of `additional` into their typed counterparts.
+    ///
+    /// Several extractors (LaTeX, Typst, EPUB, OPML, Markdown front matter, ...) report
+    /// document properties as loose keys. This normalizes them so consumers find the
+    /// title, authors, keywords, etc. in the same place regardless of format. Typed
+    /// fields that are already set take precedence and the loose duplicate is dropped,
+    /// so every key is serialized only once. Non-string values (e.g. structured keyword
+    /// extraction output) are never touched.
+    ///
+    /// Recognized keys: `title`, `subject`, `author`/`authors`/`creator`, `keywords`,
+    /// `language`, `created_at`, `modified_at`, `created_by`, `modified_by`,
+    /// `publisher` and `identifier`.
+    pub fn promote_common_fields(&mut self) {
+        promote_string(&mut self.additional, "title", &mut self.title);
+        promote_string(&mut self.additional, "subject", &mut self.subject);
+        promote_string(&mut self.additional, "language", &mut self.language);
+        promote_string(&mut self.additional, "created_at", &mut self.created_at);
+        promote_string(&mut self.additional, "modified_at", &mut self.modified_at);
+        promote_string(&mut self.additional, "created_by", &mut self.created_by);
+        promote_string(&mut self.additional, "modified_by", &mut self.modified_by);
+        promote_string(&mut self.additional, "publisher", &mut self.publisher);
+        promote_string(&mut self.additional, "identifier", &mut self.identifier);
+
+        for key in ["authors", "author", "creator"] {
+            promote_list(&mut self.additional, key, &mut self.authors, &[" and ", ";"]);
+        }
+        promote_list(&mut self.additional, "keywords", &mut self.keywords, &[",", ";"]);
+    }
+}
+
+fn promote_string(additional: &mut HashMap<String, serde_json::Value>, key: &str, target: &mut Option<String>) {
+    if !matches!(additional.get(key), Some(serde_json::Value::String(_))) {
+        return;
+    }
+    if let Some(serde_json::Value::String(value)) = additional.remove(key) {
+        let value = value.trim();
+        if target.is_none() && !value.is_empty() {
+            *target = Some(value.to_string());
+        }
+    }
+}
+
+fn promote_list(
+    additional: &mut HashMap<String, serde_json::Value>,
+    key: &str,
+    target: &mut Option<Vec<String>>,
+    separators: &[&str],
+) {
+    let items: Vec<String> = match additional.get(key) {
+        Some(serde_json::Value::String(value)) => {
+            let mut parts = vec![value.as_str()];
+            for separator in separators {
+                parts = parts.into_iter().flat_map(|part| part.split(separator)).collect();
+            }
+            parts
+                .into_iter()
+                .map(str::trim)
+                .filter(|p| !p.is_empty())
+                .map(String::from)
+                .collect()
+        }
+        Some(serde_json::Value::Array(values)) if values.iter().all(|v| v.is_string()) => values
+            .iter()
+            .filter_map(|v| v.as_str())
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(String::from)
+            .collect(),
+        _ => return,
+    };
+    additional.remove(key);
+    if target.is_none() && !items.is_empty() {
+        *target = Some(items);
+    }
+}
+
 /// Unified page structure for documents.
 ///
 /// Supports different page types (PDF pages, PPTX slides, Excel sheets)
@@ -281,8 +512,106 @@ pub struct EmailMetadata {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub message_id: Option<String>,
 
+    /// Reply-To addresses
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub reply_to_emails: Vec<String>,
+
+    /// Message-ID this message replies to (In-Reply-To header)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub in_reply_to: Option<String>,
+
+    /// Message-IDs of the conversation ancestors, oldest first (References header)
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub references: Vec<String>,
+
+    /// Conversation identifier: the Message-ID of the thread's root message
+    ///
+    /// Messages that belong to the same conversation share the same `thread_id`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_id: Option<String>,
+
+    /// Whether the message carries a DKIM-Signature header
+    #[serde(default)]
+    pub has_dkim_signature: bool,
+
+    /// All RFC 5322 header fields as (name, value) pairs in message order (unfolded, not decoded)
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub raw_headers: Vec<(String, String)>,
+
     /// List of attachment filenames
     pub attachments: Vec<String>,
+
+    /// Meetings from calendar invites (`text/calendar` parts, `.ics` attachments) and Outlook
+    /// meeting requests
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub calendar_events: Vec<CalendarEvent>,
+
+    /// Quoted replies removed from the body with `email.strip_quoted_replies`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub quoted_text: Option<String>,
+
+    /// Signature removed from the body with `email.strip_signatures`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub signature: Option<String>,
+
+    /// URLs of the tracking pixels removed from the HTML body with `email.strip_tracking_pixels`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub tracking_pixels: Vec<String>,
+}
+
+/// A meeting from a calendar invite or an Outlook meeting request.
+///
+/// Times are ISO 8601: UTC times end in `Z`, floating and zoned times don't (see `timezone`),
+/// and all-day events only have a date.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarEvent {
+    /// What the invite asks for (iCalendar METHOD), e.g. `REQUEST`, `CANCEL` or `REPLY`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+
+    /// Unique identifier of the event
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<String>,
+
+    /// Meeting title
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
+    /// Start time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<String>,
+
+    /// End time
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<String>,
+
+    /// Time zone of `start` and `end` (TZID), when they are not UTC
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timezone: Option<String>,
+
+    /// Where the meeting takes place
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+
+    /// Organizer as `Name <address>` or just the address
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organizer: Option<String>,
+
+    /// Invited attendees, formatted like `organizer`
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub attendees: Vec<String>,
+
+    /// Meeting description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Event status, e.g. `CONFIRMED` or `CANCELLED`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+
+    /// Recurrence rule (iCalendar RRULE), e.g. `FREQ=WEEKLY;BYDAY=MO`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<String>,
 }
 
 /// Archive (ZIP/TAR/7Z) metadata.
@@ -328,6 +657,9 @@ pub struct XmlMetadata {
     pub element_count: usize,
     /// List of unique element tag names (sorted)
     pub unique_elements: Vec<String>,
+    /// Name of the configured document type whose XPath mapping was applied
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub document_type: Option<String>,
 }
 
 /// Text/Markdown metadata.
@@ -354,6 +686,10 @@ pub struct TextMetadata {
     /// Code blocks as (language, code) tuples (for Markdown files)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code_blocks: Option<Vec<(String, String)>>,
+
+    /// Encoding the file was decoded from, e.g. `UTF-8`, `windows-1252` or `IBM037`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
 }
 
 /// HTML metadata extracted from HTML documents.
@@ -423,6 +759,47 @@ pub struct HtmlMetadata {
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub link_alternate: Option<String>,
+
+    /// Hyperlinks (`<a href>`) in document order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub links: Vec<HtmlLink>,
+
+    /// Images (`<img>`) in document order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub images: Vec<HtmlImage>,
+}
+
+/// A hyperlink of an HTML document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HtmlLink {
+    /// Anchor text with markup removed, entities decoded and whitespace collapsed
+    pub text: String,
+    /// `href` attribute as written in the document
+    pub href: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// `rel` attribute, e.g. `nofollow`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rel: Option<String>,
+    /// Byte offset of the `<a>` tag in the HTML source
+    pub position: usize,
+}
+
+/// An image of an HTML document.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HtmlImage {
+    /// `src` attribute; base64 data URIs are shortened to `data:<media type>`
+    pub src: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Byte offset of the `<img>` tag in the HTML source
+    pub position: usize,
+    /// Index of the decoded image in `ExtractionResult::images` (data URI images, when image
+    /// extraction is enabled)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_index: Option<usize>,
 }
 
 /// OCR processing metadata.
@@ -453,6 +830,276 @@ pub struct ErrorMetadata {
     pub message: String,
 }
 
+/// Where a document came from: the software that produced it and the device that captured it.
+///
+/// Filled by the extraction pipeline from format metadata (PDF `Producer`/`Creator`,
+/// Office `Application`, EXIF `Software`/`Make`/`Model`). `likely_scanned` is a heuristic
+/// for choosing an OCR strategy; `scan_signals` lists the evidence behind it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DocumentProvenance {
+    /// Application that wrote the file (PDF `Producer`, Office `Application`, EXIF `Software`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub producer: Option<String>,
+    /// Authoring application the content originated from (PDF `Creator`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator_tool: Option<String>,
+    /// Capture device (EXIF `Make`/`Model`, or a scanner named in the producer string)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scanner_model: Option<String>,
+    /// Whether the document is most likely a scan or photo rather than born-digital
+    pub likely_scanned: bool,
+    /// Evidence for `likely_scanned` (e.g. "raster_image", "scanner_producer", "sparse_text_layer")
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub scan_signals: Vec<String>,
+}
+
+/// Summary statistics for an extraction result.
+///
+/// Counts are computed from the final content after post-processing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExtractionStats {
+    /// Number of whitespace-separated words
+    pub word_count: usize,
+    /// Number of characters (Unicode scalar values)
+    pub character_count: usize,
+    /// Number of pages/slides/sheets, when the format has them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_count: Option<usize>,
+    /// Number of tables found in the document
+    pub table_count: usize,
+    /// Number of images found in the document
+    pub image_count: usize,
+    /// Mean OCR confidence (0.0-1.0), when OCR was used
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ocr_confidence: Option<f64>,
+    /// Wall-clock duration of each stage, when measured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub durations: Option<StageDurations>,
+}
+
+/// Wall-clock duration of the extraction stages in milliseconds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct StageDurations {
+    /// Document extractor, including OCR
+    pub extraction_ms: f64,
+    /// Post-processors, chunking, language detection and validators
+    pub post_processing_ms: f64,
+}
+
+/// Wall-clock duration of each extraction stage in milliseconds.
+///
+/// Recorded in `metadata.timings` when `ExtractionConfig::collect_timings` is set. Stages that
+/// did not run are `None`, as are all stages where no monotonic clock is available (WebAssembly).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExtractionTimings {
+    /// MIME type detection or validation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_detection_ms: Option<f64>,
+    /// Document extractor, including OCR
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub extraction_ms: Option<f64>,
+    /// OCR of pages and images, as part of `extraction_ms`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ocr_ms: Option<f64>,
+    /// Image descriptions and post-processors
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_processing_ms: Option<f64>,
+    /// Text chunking, including embeddings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunking_ms: Option<f64>,
+    /// Language detection
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language_detection_ms: Option<f64>,
+    /// Whole extraction, from MIME detection to the final result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_ms: Option<f64>,
+}
+
+/// Readability scores, sentence/word statistics and vocabulary richness of a text.
+///
+/// The Flesch scores are calibrated for English; LIX works across most European languages.
+/// Scripts written without spaces between words count each run of characters as one word.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TextMetrics {
+    /// Number of sentences
+    pub sentence_count: usize,
+    /// Number of words (runs of letters and digits containing at least one letter)
+    pub word_count: usize,
+    /// Estimated number of syllables over all words
+    pub syllable_count: usize,
+    /// Number of words longer than the configured long-word length
+    pub long_word_count: usize,
+    /// Number of distinct words, compared case-insensitively
+    pub unique_word_count: usize,
+    /// Mean number of words per sentence
+    pub avg_sentence_length: f64,
+    /// Mean number of characters per word
+    pub avg_word_length: f64,
+    /// Mean number of syllables per word
+    pub avg_syllables_per_word: f64,
+    /// Flesch reading ease (higher is easier; 60-70 is plain English)
+    pub flesch_reading_ease: f64,
+    /// Flesch-Kincaid grade level (US school grade)
+    pub flesch_kincaid_grade: f64,
+    /// LIX readability index (below 30 very easy, above 60 very hard)
+    pub lix: f64,
+    /// Distinct words divided by total words
+    pub type_token_ratio: f64,
+    /// Fraction of distinct words that occur exactly once
+    pub hapax_ratio: f64,
+}
+
+/// A match of a custom tagger (see [`TaggerConfig`](crate::TaggerConfig)).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagMatch {
+    /// The matched text as it appears in the content.
+    pub text: String,
+
+    /// Byte offset where the match starts in `ExtractionResult.content` (UTF-8 valid boundary).
+    pub byte_start: usize,
+
+    /// Byte offset where the match ends in `ExtractionResult.content` (UTF-8 valid boundary).
+    pub byte_end: usize,
+}
+
+/// Outcome of human-review flagging (see [`ReviewConfig`](crate::ReviewConfig)).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReviewStatus {
+    /// Whether at least one review signal fired
+    pub required: bool,
+    /// The signals that fired, in evaluation order
+    #[serde(default)]
+    pub reasons: Vec<ReviewReason>,
+}
+
+/// A signal that routes a result to human review.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ReviewReason {
+    /// Mean OCR confidence (0.0-1.0) below `ReviewConfig::min_ocr_confidence`
+    LowOcrConfidence { confidence: f64, threshold: f64 },
+    /// Quality score (0.0-1.0) below `ReviewConfig::min_quality_score`
+    LowQualityScore { score: f64, threshold: f64 },
+    /// The quality gate marked the text as garbled
+    LowQuality,
+    /// The result was truncated to the listed `LimitsConfig` limits
+    LimitsExceeded { limits: Vec<String> },
+    /// Archive entries were skipped because of the archive limits
+    ArchiveTruncated,
+    /// A validator rejected the result
+    ValidatorFailed { validator: String, message: String },
+}
+
+/// A finding reported by a [`Validator`](crate::plugins::Validator).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationFinding {
+    /// Name of the validator, filled in by the pipeline
+    #[serde(default)]
+    pub validator: String,
+    /// How serious the finding is
+    pub severity: FindingSeverity,
+    /// Stable machine-readable identifier, e.g. `content_too_short`
+    pub code: String,
+    /// Human-readable description
+    pub message: String,
+    /// Where in the document the finding applies
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<FindingLocation>,
+}
+
+impl ValidationFinding {
+    /// Finding without a location.
+    pub fn new(severity: FindingSeverity, code: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            validator: String::new(),
+            severity,
+            code: code.into(),
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// Attach a location to the finding.
+    pub fn at(mut self, location: FindingLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+}
+
+/// Severity of a [`ValidationFinding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FindingSeverity {
+    /// Noteworthy, no action needed
+    Info,
+    /// Suspicious, the result is still usable
+    Warning,
+    /// The result violates the policy; rejects it unless `ValidationConfig::mode` is `annotate`
+    Error,
+}
+
+/// Location of a [`ValidationFinding`] in the document.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FindingLocation {
+    /// Page number (1-indexed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<usize>,
+    /// Byte offset where the finding starts in `ExtractionResult.content`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byte_start: Option<usize>,
+    /// Byte offset where the finding ends in `ExtractionResult.content`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byte_end: Option<usize>,
+}
+
+/// A tracked change or comment in a DOCX or PPTX document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Revision {
+    /// Insertion, deletion or comment
+    pub kind: RevisionKind,
+    /// Author name as recorded by the editing application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    /// Timestamp as stored in the document (ISO 8601)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<String>,
+    /// Inserted or deleted text, or the comment text
+    pub text: String,
+    /// Text a comment refers to (DOCX)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<String>,
+    /// Slide a comment is attached to (PPTX)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_number: Option<usize>,
+    /// Whether the change awaits review or the comment is still open
+    pub status: RevisionStatus,
+}
+
+/// Kind of a [`Revision`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionKind {
+    /// Tracked insertion (including the destination of a move)
+    Insertion,
+    /// Tracked deletion (including the source of a move)
+    Deletion,
+    /// Reviewer comment
+    Comment,
+}
+
+/// Review state of a [`Revision`].
+///
+/// Accepted and rejected changes are applied to the document and no longer recorded, so tracked
+/// changes found in a file are always pending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RevisionStatus {
+    /// Tracked change not yet accepted or rejected, or open comment
+    Pending,
+    /// Comment marked as resolved (done)
+    Resolved,
+}
+
 /// Extracted table structure.
 ///
 /// Represents a table detected and extracted from a document (PDF, image, etc.).
@@ -522,6 +1169,173 @@ pub struct ChunkMetadata {
     pub last_page: Option<usize>,
 }
 
+/// A named entity found in the extracted content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entity {
+    /// Kind of entity.
+    pub entity_type: EntityType,
+
+    /// The entity text as it appears in the content.
+    pub text: String,
+
+    /// Byte offset where the entity starts in `ExtractionResult.content` (UTF-8 valid boundary).
+    pub byte_start: usize,
+
+    /// Byte offset where the entity ends in `ExtractionResult.content` (UTF-8 valid boundary).
+    pub byte_end: usize,
+
+    /// Recognizer confidence (0.0-1.0).
+    pub confidence: f64,
+}
+
+/// Kind of a named entity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum EntityType {
+    /// Person name
+    Person,
+    /// Company, institution or other organization
+    Organization,
+    /// Calendar date
+    Date,
+    /// Monetary amount
+    Money,
+}
+
+impl EntityType {
+    /// All entity types.
+    pub const ALL: [EntityType; 4] = [
+        EntityType::Person,
+        EntityType::Organization,
+        EntityType::Date,
+        EntityType::Money,
+    ];
+
+    /// Lowercase name used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EntityType::Person => "person",
+            EntityType::Organization => "organization",
+            EntityType::Date => "date",
+            EntityType::Money => "money",
+        }
+    }
+}
+
+impl std::str::FromStr for EntityType {
+    type Err = crate::KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "person" | "per" => Ok(EntityType::Person),
+            "organization" | "organisation" | "org" => Ok(EntityType::Organization),
+            "date" => Ok(EntityType::Date),
+            "money" => Ok(EntityType::Money),
+            other => Err(crate::KreuzbergError::validation(format!(
+                "Unknown entity type '{}', expected one of: person, organization, date, money",
+                other
+            ))),
+        }
+    }
+}
+
+/// A language detected in the content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DetectedLanguage {
+    /// ISO 639-3 language code (e.g. `eng`, `deu`, `cmn`).
+    pub language: String,
+
+    /// Detection confidence (0.0-1.0).
+    ///
+    /// With `detect_multiple`, this is the mean confidence over the text windows
+    /// attributed to the language.
+    pub confidence: f64,
+
+    /// Writing system the language was detected in (e.g. `Latin`, `Cyrillic`, `Mandarin`).
+    pub script: String,
+}
+
+/// A keyword extracted from the content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Keyword {
+    /// The keyword text.
+    pub text: String,
+
+    /// Relevance score (higher is better, algorithm-specific range).
+    pub score: f32,
+
+    /// Algorithm that extracted this keyword.
+    pub algorithm: KeywordAlgorithm,
+
+    /// Optional positions where keyword appears in text (character offsets).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub positions: Option<Vec<usize>>,
+}
+
+impl Keyword {
+    /// Create a new keyword.
+    pub fn new(text: String, score: f32, algorithm: KeywordAlgorithm) -> Self {
+        Self {
+            text,
+            score,
+            algorithm,
+            positions: None,
+        }
+    }
+
+    /// Create a new keyword with positions.
+    pub fn with_positions(text: String, score: f32, algorithm: KeywordAlgorithm, positions: Vec<usize>) -> Self {
+        Self {
+            text,
+            score,
+            algorithm,
+            positions: Some(positions),
+        }
+    }
+}
+
+/// Keyword extraction algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum KeywordAlgorithm {
+    /// YAKE (Yet Another Keyword Extractor) - statistical approach
+    #[cfg(feature = "keywords-yake")]
+    Yake,
+
+    /// RAKE (Rapid Automatic Keyword Extraction) - co-occurrence based
+    #[cfg(feature = "keywords-rake")]
+    Rake,
+}
+
+impl KeywordAlgorithm {
+    /// Lowercase name used in serialized output.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "keywords-yake")]
+            KeywordAlgorithm::Yake => "yake",
+            #[cfg(feature = "keywords-rake")]
+            KeywordAlgorithm::Rake => "rake",
+        }
+    }
+}
+
+impl std::str::FromStr for KeywordAlgorithm {
+    type Err = crate::KreuzbergError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            #[cfg(feature = "keywords-yake")]
+            "yake" => Ok(KeywordAlgorithm::Yake),
+            #[cfg(feature = "keywords-rake")]
+            "rake" => Ok(KeywordAlgorithm::Rake),
+            other => Err(crate::KreuzbergError::validation(format!(
+                "Unknown keyword algorithm '{}', expected 'yake' or 'rake'",
+                other
+            ))),
+        }
+    }
+}
+
 /// Extracted image from a document.
 ///
 /// Contains raw image data, metadata, and optional nested OCR results.
@@ -550,6 +1364,10 @@ pub struct ExtractedImage {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub height: Option<u32>,
 
+    /// Resolution recorded in the image data, in dots per inch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dpi: Option<u32>,
+
     /// Colorspace information (e.g., "RGB", "CMYK", "Gray")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub colorspace: Option<String>,
@@ -666,6 +1484,9 @@ pub struct PptxExtractionResult {
     /// Per-slide content (when page tracking is enabled)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page_contents: Option<Vec<PageContent>>,
+    /// Slide comments, with their slide in `page_number`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub revisions: Vec<Revision>,
 }
 
 /// PowerPoint presentation metadata.
@@ -679,6 +1500,86 @@ pub struct PptxMetadata {
     pub fonts: Vec<String>,
 }
 
+/// Office document metadata (DOCX, ODT, RTF).
+///
+/// Document properties from `docProps/app.xml`/`docProps/core.xml` (OOXML) or
+/// `meta.xml` (OpenDocument) that have no counterpart in the base `Metadata` struct.
+/// Title, authors, keywords, dates and language live on `Metadata` itself.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OfficeMetadata {
+    /// Application that produced the document (e.g. "Microsoft Office Word", "LibreOffice/7.6")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application: Option<String>,
+
+    /// Template the document was based on
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+
+    /// Company/organization the document belongs to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+
+    /// Free-form document description/comments
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Author who originally created the document, when different from the last creator
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_creator: Option<String>,
+
+    /// Revision number
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+
+    /// Document category
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+
+    /// Content status (e.g. "Draft", "Final")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_status: Option<String>,
+
+    /// Total editing time in minutes (OOXML)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_editing_time_minutes: Option<i32>,
+
+    /// Total editing time as an ISO 8601 duration (OpenDocument)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editing_duration: Option<String>,
+
+    /// Number of editing sessions (OpenDocument)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub editing_cycles: Option<String>,
+
+    /// Page count reported by the producing application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_count: Option<i32>,
+
+    /// Word count reported by the producing application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word_count: Option<i32>,
+
+    /// Character count reported by the producing application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub character_count: Option<i32>,
+
+    /// Line count reported by the producing application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_count: Option<i32>,
+
+    /// Paragraph count reported by the producing application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paragraph_count: Option<i32>,
+
+    /// Table count reported by the producing application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_count: Option<i32>,
+
+    /// Image count reported by the producing application
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_count: Option<i32>,
+}
+
 /// Email extraction result.
 ///
 /// Complete representation of an extracted email message (.eml or .msg)
@@ -699,6 +1600,20 @@ pub struct EmailExtractionResult {
     pub date: Option<String>,
     /// Message-ID header value
     pub message_id: Option<String>,
+    /// Sender display name
+    pub from_name: Option<String>,
+    /// Reply-To addresses
+    pub reply_to_emails: Vec<String>,
+    /// Message-ID this message replies to (In-Reply-To header)
+    pub in_reply_to: Option<String>,
+    /// Message-IDs of the conversation ancestors, oldest first (References header)
+    pub references: Vec<String>,
+    /// Message-ID of the conversation's root message
+    pub thread_id: Option<String>,
+    /// Whether the message carries a DKIM-Signature header
+    pub has_dkim_signature: bool,
+    /// All header fields as (name, value) pairs in message order
+    pub raw_headers: Vec<(String, String)>,
     /// Plain text version of the email body
     pub plain_text: Option<String>,
     /// HTML version of the email body
@@ -707,6 +1622,14 @@ pub struct EmailExtractionResult {
     pub cleaned_text: String,
     /// List of email attachments
     pub attachments: Vec<EmailAttachment>,
+    /// Meetings from calendar invites and Outlook meeting requests
+    pub calendar_events: Vec<CalendarEvent>,
+    /// Quoted replies removed from the body (`EmailConfig::strip_quoted_replies`)
+    pub quoted_text: Option<String>,
+    /// Signature removed from the body (`EmailConfig::strip_signatures`)
+    pub signature: Option<String>,
+    /// URLs of the tracking pixels removed from the HTML body (`EmailConfig::strip_tracking_pixels`)
+    pub tracking_pixels: Vec<String>,
     /// Additional email headers and metadata
     pub metadata: HashMap<String, String>,
 }
@@ -728,6 +1651,9 @@ pub struct EmailAttachment {
     pub is_image: bool,
     /// Attachment data (if extracted)
     pub data: Option<Vec<u8>>,
+    /// Content-ID of an inline part, referenced from the body as `cid:<id>`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_id: Option<String>,
 }
 
 /// OCR extraction result.
@@ -764,7 +1690,7 @@ pub struct OcrTable {
 /// These settings control how images are preprocessed before OCR to improve
 /// text recognition quality. Different preprocessing strategies work better
 /// for different document types.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct ImagePreprocessingConfig {
     /// Target DPI for the image (300 is standard, 600 for small text).
@@ -776,17 +1702,23 @@ pub struct ImagePreprocessingConfig {
     /// Correct skew (tilted images).
     pub deskew: bool,
 
-    /// Remove noise from the image.
+    /// Convert the image to grayscale. Implied by every other enhancement step.
+    pub grayscale: bool,
+
+    /// Remove noise from the image (3×3 median filter).
     pub denoise: bool,
 
     /// Enhance contrast for better text visibility.
     pub contrast_enhance: bool,
 
-    /// Binarization method: "otsu", "sauvola", "adaptive".
+    /// Binarization method: "otsu", "sauvola", "adaptive", or "none" to keep gray levels.
     pub binarization_method: String,
 
     /// Invert colors (white text on black → black on white).
     pub invert_colors: bool,
+
+    /// Whiten the dark borders scanners leave around the page.
+    pub remove_borders: bool,
 }
 
 impl Default for ImagePreprocessingConfig {
@@ -795,10 +1727,12 @@ impl Default for ImagePreprocessingConfig {
             target_dpi: 300,
             auto_rotate: true,
             deskew: true,
+            grayscale: true,
             denoise: false,
             contrast_enhance: false,
             binarization_method: "otsu".to_string(),
             invert_colors: false,
+            remove_borders: false,
         }
     }
 }
@@ -808,7 +1742,7 @@ impl Default for ImagePreprocessingConfig {
 /// Provides fine-grained control over Tesseract OCR engine parameters.
 /// Most users can use the defaults, but these settings allow optimization
 /// for specific document types (invoices, handwriting, etc.).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(default)]
 pub struct TesseractConfig {
     /// Language code (e.g., "eng", "deu", "fra")
@@ -1029,6 +1963,7 @@ mod tests {
                 headers: None,
                 links: None,
                 code_blocks: None,
+                encoding: None,
             })),
             ..Default::default()
         };
@@ -1052,4 +1987,95 @@ mod tests {
 
         assert_eq!(json.get("quality_score").unwrap(), 1.0);
     }
+
+    #[test]
+    fn test_promote_common_fields_moves_loose_keys() {
+        let mut metadata = Metadata::default();
+        metadata
+            .additional
+            .insert("title".to_string(), serde_json::json!("Paper"));
+        metadata
+            .additional
+            .insert("author".to_string(), serde_json::json!("Ada Lovelace and Alan Turing"));
+        metadata
+            .additional
+            .insert("keywords".to_string(), serde_json::json!("math; computing, history"));
+        metadata
+            .additional
+            .insert("publisher".to_string(), serde_json::json!("ACM"));
+        metadata
+            .additional
+            .insert("rights".to_string(), serde_json::json!("CC-BY"));
+
+        metadata.promote_common_fields();
+
+        assert_eq!(metadata.title.as_deref(), Some("Paper"));
+        assert_eq!(
+            metadata.authors,
+            Some(vec!["Ada Lovelace".to_string(), "Alan Turing".to_string()])
+        );
+        assert_eq!(
+            metadata.keywords,
+            Some(vec!["math".to_string(), "computing".to_string(), "history".to_string()])
+        );
+        assert_eq!(metadata.publisher.as_deref(), Some("ACM"));
+        assert_eq!(metadata.additional.len(), 1);
+        assert!(metadata.additional.contains_key("rights"));
+    }
+
+    #[test]
+    fn test_promote_common_fields_keeps_typed_values() {
+        let mut metadata = Metadata {
+            title: Some("Typed".to_string()),
+            authors: Some(vec!["Jane Doe".to_string()]),
+            ..Default::default()
+        };
+        metadata
+            .additional
+            .insert("title".to_string(), serde_json::json!("Loose"));
+        metadata
+            .additional
+            .insert("creator".to_string(), serde_json::json!("Jane Doe"));
+        metadata.additional.insert(
+            "keywords".to_string(),
+            serde_json::json!([{"text": "rust", "score": 0.9}]),
+        );
+
+        metadata.promote_common_fields();
+
+        assert_eq!(metadata.title.as_deref(), Some("Typed"));
+        assert_eq!(metadata.authors, Some(vec!["Jane Doe".to_string()]));
+        assert!(!metadata.additional.contains_key("title"));
+        assert!(!metadata.additional.contains_key("creator"));
+        assert!(metadata.keywords.is_none());
+        assert!(metadata.additional.contains_key("keywords"));
+    }
+
+    #[test]
+    fn test_office_metadata_round_trip() {
+        let metadata = Metadata {
+            title: Some("Report".to_string()),
+            format: Some(FormatMetadata::Office(OfficeMetadata {
+                application: Some("Microsoft Office Word".to_string()),
+                page_count: Some(3),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_value(&metadata).unwrap();
+        assert_eq!(json["format_type"], "office");
+        assert_eq!(json["title"], "Report");
+        assert_eq!(json["application"], "Microsoft Office Word");
+        assert_eq!(json["page_count"], 3);
+
+        let restored: Metadata = serde_json::from_value(json).unwrap();
+        let Some(FormatMetadata::Office(office)) = restored.format else {
+            panic!("expected office metadata");
+        };
+        assert_eq!(office.page_count, Some(3));
+        assert_eq!(restored.title.as_deref(), Some("Report"));
+        // serde hands the flattened format fields to `additional` as well; common fields stay out.
+        assert!(!restored.additional.contains_key("title"));
+    }
 }
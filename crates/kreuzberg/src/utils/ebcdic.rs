@@ -0,0 +1,189 @@
+//! EBCDIC code pages.
+//!
+//! `encoding_rs` only covers the WHATWG Encoding Standard, which has no EBCDIC encodings. Text
+//! exported from IBM mainframes and midrange systems is decoded here instead. All supported code
+//! pages share the Latin-1 repertoire and differ from code page 037 in a handful of positions.
+//!
+//! NL (the EBCDIC line separator) is decoded as `\n` rather than U+0085, so records stay on
+//! separate lines after control characters are cleaned up.
+
+/// Code page 037 (US/Canada) as Latin-1 code points.
+#[rustfmt::skip]
+const CP037: [u8; 256] = [
+    0x00, 0x01, 0x02, 0x03, 0x9C, 0x09, 0x86, 0x7F, 0x97, 0x8D, 0x8E, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+    0x10, 0x11, 0x12, 0x13, 0x9D, 0x85, 0x08, 0x87, 0x18, 0x19, 0x92, 0x8F, 0x1C, 0x1D, 0x1E, 0x1F,
+    0x80, 0x81, 0x82, 0x83, 0x84, 0x0A, 0x17, 0x1B, 0x88, 0x89, 0x8A, 0x8B, 0x8C, 0x05, 0x06, 0x07,
+    0x90, 0x91, 0x16, 0x93, 0x94, 0x95, 0x96, 0x04, 0x98, 0x99, 0x9A, 0x9B, 0x14, 0x15, 0x9E, 0x1A,
+    0x20, 0xA0, 0xE2, 0xE4, 0xE0, 0xE1, 0xE3, 0xE5, 0xE7, 0xF1, 0xA2, 0x2E, 0x3C, 0x28, 0x2B, 0x7C,
+    0x26, 0xE9, 0xEA, 0xEB, 0xE8, 0xED, 0xEE, 0xEF, 0xEC, 0xDF, 0x21, 0x24, 0x2A, 0x29, 0x3B, 0xAC,
+    0x2D, 0x2F, 0xC2, 0xC4, 0xC0, 0xC1, 0xC3, 0xC5, 0xC7, 0xD1, 0xA6, 0x2C, 0x25, 0x5F, 0x3E, 0x3F,
+    0xF8, 0xC9, 0xCA, 0xCB, 0xC8, 0xCD, 0xCE, 0xCF, 0xCC, 0x60, 0x3A, 0x23, 0x40, 0x27, 0x3D, 0x22,
+    0xD8, 0x61, 0x62, 0x63, 0x64, 0x65, 0x66, 0x67, 0x68, 0x69, 0xAB, 0xBB, 0xF0, 0xFD, 0xFE, 0xB1,
+    0xB0, 0x6A, 0x6B, 0x6C, 0x6D, 0x6E, 0x6F, 0x70, 0x71, 0x72, 0xAA, 0xBA, 0xE6, 0xB8, 0xC6, 0xA4,
+    0xB5, 0x7E, 0x73, 0x74, 0x75, 0x76, 0x77, 0x78, 0x79, 0x7A, 0xA1, 0xBF, 0xD0, 0xDD, 0xDE, 0xAE,
+    0x5E, 0xA3, 0xA5, 0xB7, 0xA9, 0xA7, 0xB6, 0xBC, 0xBD, 0xBE, 0x5B, 0x5D, 0xAF, 0xA8, 0xB4, 0xD7,
+    0x7B, 0x41, 0x42, 0x43, 0x44, 0x45, 0x46, 0x47, 0x48, 0x49, 0xAD, 0xF4, 0xF6, 0xF2, 0xF3, 0xF5,
+    0x7D, 0x4A, 0x4B, 0x4C, 0x4D, 0x4E, 0x4F, 0x50, 0x51, 0x52, 0xB9, 0xFB, 0xFC, 0xF9, 0xFA, 0xFF,
+    0x5C, 0xF7, 0x53, 0x54, 0x55, 0x56, 0x57, 0x58, 0x59, 0x5A, 0xB2, 0xD4, 0xD6, 0xD2, 0xD3, 0xD5,
+    0x30, 0x31, 0x32, 0x33, 0x34, 0x35, 0x36, 0x37, 0x38, 0x39, 0xB3, 0xDB, 0xDC, 0xD9, 0xDA, 0x9F,
+];
+
+/// An EBCDIC code page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EbcdicCodePage {
+    /// IBM037: US, Canada, Netherlands, Portugal, Brazil, Australia, New Zealand
+    Cp037,
+    /// IBM273: Germany, Austria
+    Cp273,
+    /// IBM500: International Latin-1
+    Cp500,
+    /// IBM1047: Latin-1 for open systems (z/OS UNIX)
+    Cp1047,
+    /// IBM1140: code page 037 with the euro sign
+    Cp1140,
+}
+
+impl EbcdicCodePage {
+    /// Code page for an encoding label such as `cp500`, `ibm-1047` or `ebcdic-cp-us`
+    /// (case-insensitive).
+    pub fn for_label(label: &str) -> Option<Self> {
+        let label = label.trim().to_ascii_lowercase().replace(['-', '_'], "");
+        match label.as_str() {
+            "cp037" | "ibm037" | "ebcdiccpus" | "ebcdic" => Some(Self::Cp037),
+            "cp273" | "ibm273" => Some(Self::Cp273),
+            "cp500" | "ibm500" | "ebcdiccpbe" => Some(Self::Cp500),
+            "cp1047" | "ibm1047" => Some(Self::Cp1047),
+            "cp1140" | "ibm1140" => Some(Self::Cp1140),
+            _ => None,
+        }
+    }
+
+    /// IANA-style name of the code page, e.g. `IBM037`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Cp037 => "IBM037",
+            Self::Cp273 => "IBM273",
+            Self::Cp500 => "IBM500",
+            Self::Cp1047 => "IBM1047",
+            Self::Cp1140 => "IBM01140",
+        }
+    }
+
+    /// Positions that differ from code page 037.
+    fn differences(self) -> &'static [(u8, char)] {
+        match self {
+            Self::Cp037 => &[],
+            Self::Cp273 => &[
+                (0x43, '{'),
+                (0x4A, '\u{C4}'),
+                (0x4F, '!'),
+                (0x59, '~'),
+                (0x5A, '\u{DC}'),
+                (0x5F, '^'),
+                (0x63, '['),
+                (0x6A, '\u{F6}'),
+                (0x7C, '\u{A7}'),
+                (0xA1, '\u{DF}'),
+                (0xB0, '\u{A2}'),
+                (0xB5, '@'),
+                (0xBA, '\u{AC}'),
+                (0xBB, '|'),
+                (0xBC, '\u{203E}'),
+                (0xC0, '\u{E4}'),
+                (0xCC, '\u{A6}'),
+                (0xD0, '\u{FC}'),
+                (0xDC, '}'),
+                (0xE0, '\u{D6}'),
+                (0xEC, '\\'),
+                (0xFC, ']'),
+            ],
+            Self::Cp500 => &[
+                (0x4A, '['),
+                (0x4F, '!'),
+                (0x5A, ']'),
+                (0x5F, '^'),
+                (0xB0, '\u{A2}'),
+                (0xBA, '\u{AC}'),
+                (0xBB, '|'),
+            ],
+            Self::Cp1047 => &[
+                (0x15, '\n'),
+                (0x25, '\u{85}'),
+                (0x5F, '^'),
+                (0xAD, '['),
+                (0xB0, '\u{AC}'),
+                (0xBA, '\u{DD}'),
+                (0xBB, '\u{A8}'),
+                (0xBD, ']'),
+            ],
+            Self::Cp1140 => &[(0x9F, '\u{20AC}')],
+        }
+    }
+
+    /// Decode `bytes`. Every byte maps to a character, so decoding never fails.
+    pub fn decode(self, bytes: &[u8]) -> String {
+        let mut table: [char; 256] = std::array::from_fn(|byte| char::from(CP037[byte]));
+        for &(byte, c) in self.differences() {
+            table[usize::from(byte)] = c;
+        }
+        bytes
+            .iter()
+            .map(|&byte| match table[usize::from(byte)] {
+                '\u{85}' => '\n',
+                c => c,
+            })
+            .collect()
+    }
+}
+
+/// Whether `bytes` look like EBCDIC text: frequent EBCDIC spaces (0x40), almost no ASCII spaces
+/// and mostly letters, digits, spaces, periods, commas and line separators when read as
+/// code page 037.
+pub fn looks_like_ebcdic(bytes: &[u8]) -> bool {
+    let sample = &bytes[..bytes.len().min(4096)];
+    if sample.len() < 16 {
+        return false;
+    }
+
+    let count = |predicate: fn(u8) -> bool| sample.iter().filter(|&&byte| predicate(byte)).count();
+    let spaces = count(|byte| byte == 0x40);
+    let ascii_spaces = count(|byte| byte == 0x20);
+    let text = count(|byte| {
+        let c = CP037[usize::from(byte)];
+        c.is_ascii_alphanumeric() || matches!(c, b' ' | b'.' | b',' | b'\n' | 0x85)
+    });
+
+    spaces * 20 >= sample.len() && ascii_spaces * 100 < sample.len() && text * 10 >= sample.len() * 8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// "Hello, World!" followed by NL in code page 037.
+    const HELLO_CP037: &[u8] = &[
+        0xC8, 0x85, 0x93, 0x93, 0x96, 0x6B, 0x40, 0xE6, 0x96, 0x99, 0x93, 0x84, 0x5A, 0x15,
+    ];
+
+    #[test]
+    fn test_decode_cp037() {
+        assert_eq!(EbcdicCodePage::Cp037.decode(HELLO_CP037), "Hello, World!\n");
+        assert_eq!(EbcdicCodePage::Cp500.decode(HELLO_CP037), "Hello, World]\n");
+        assert_eq!(EbcdicCodePage::Cp1140.decode(&[0x9F]), "\u{20AC}");
+    }
+
+    #[test]
+    fn test_for_label() {
+        assert_eq!(EbcdicCodePage::for_label("IBM-1047"), Some(EbcdicCodePage::Cp1047));
+        assert_eq!(EbcdicCodePage::for_label("cp500"), Some(EbcdicCodePage::Cp500));
+        assert_eq!(EbcdicCodePage::for_label("windows-1252"), None);
+    }
+
+    #[test]
+    fn test_looks_like_ebcdic() {
+        let ebcdic = HELLO_CP037.repeat(4);
+        assert!(looks_like_ebcdic(&ebcdic));
+        assert!(!looks_like_ebcdic("Hello, World! ".repeat(4).as_bytes()));
+        assert!(!looks_like_ebcdic("Grüße aus Köln, schöne Grüße ".repeat(4).as_bytes()));
+    }
+}
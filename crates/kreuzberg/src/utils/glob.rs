@@ -0,0 +1,64 @@
+//! Glob matching of `/`-separated relative paths.
+//!
+//! Patterns use the syntax of the `glob` crate: `*`, `?` and `[...]` within a path segment
+//! and `**` for any number of segments. Used for the include and exclude globs of directory
+//! extraction and for selecting archive members.
+
+use crate::{KreuzbergError, Result};
+use glob::{MatchOptions, Pattern};
+
+/// `*` and `?` stop at `/`, so only `**` crosses path segments.
+const MATCH_OPTIONS: MatchOptions = MatchOptions {
+    case_sensitive: true,
+    require_literal_separator: true,
+    require_literal_leading_dot: false,
+};
+
+/// Match a `/`-separated relative path against a glob; patterns without `/` match the last
+/// path segment. Invalid patterns match nothing (see [`validate_globs`]).
+pub(crate) fn glob_match(pattern: &str, relative_path: &str) -> bool {
+    let Ok(compiled) = Pattern::new(pattern.trim_start_matches('/')) else {
+        return false;
+    };
+    if pattern.contains('/') {
+        compiled.matches_with(relative_path, MATCH_OPTIONS)
+    } else {
+        let name = relative_path.rsplit('/').next().unwrap_or(relative_path);
+        compiled.matches_with(name, MATCH_OPTIONS)
+    }
+}
+
+/// Reject patterns that are not valid globs.
+pub(crate) fn validate_globs(patterns: &[String]) -> Result<()> {
+    for pattern in patterns {
+        if let Err(e) = Pattern::new(pattern) {
+            return Err(KreuzbergError::validation(format!("Invalid glob '{}': {}", pattern, e)));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*.txt", "notes.txt"));
+        assert!(glob_match("*.txt", "a/b/notes.txt"));
+        assert!(glob_match("report-??.pdf", "2024/report-01.pdf"));
+        assert!(glob_match("docs/*.md", "docs/readme.md"));
+        assert!(!glob_match("docs/*.md", "docs/api/readme.md"));
+        assert!(glob_match("docs/**/*.md", "docs/api/v1/readme.md"));
+        assert!(glob_match("docs/**/*.md", "docs/readme.md"));
+        assert!(glob_match("**/build", "crates/core/build"));
+        assert!(!glob_match("*.txt", "notes.txt.bak"));
+        assert!(glob_match("scan-[0-9].pdf", "scans/scan-7.pdf"));
+    }
+
+    #[test]
+    fn test_validate_globs() {
+        assert!(validate_globs(&["*.pdf".to_string(), "docs/**".to_string()]).is_ok());
+        assert!(validate_globs(&["docs/a**".to_string()]).is_err());
+    }
+}
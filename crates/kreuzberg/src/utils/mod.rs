@@ -3,10 +3,21 @@
 //! This module provides:
 //! - Quality processing: clean OCR artifacts, calculate quality scores
 //! - String utilities: safe decoding, mojibake fixing, encoding detection
+//! - EBCDIC code pages for mainframe text
+//! - Image resolution: the DPI recorded in PNG and JPEG files
+//! - Glob matching of relative paths
+
+#[cfg(feature = "quality")]
+pub mod ebcdic;
 
 #[cfg(feature = "quality")]
 pub mod quality;
 
+#[cfg(any(feature = "tokio-runtime", feature = "archives"))]
+pub(crate) mod glob;
+
+pub mod resolution;
+
 #[cfg(feature = "quality")]
 pub mod string_utils;
 
@@ -14,4 +25,4 @@ pub mod string_utils;
 pub use quality::{calculate_quality_score, clean_extracted_text, normalize_spaces};
 
 #[cfg(feature = "quality")]
-pub use string_utils::{calculate_text_confidence, fix_mojibake, safe_decode};
+pub use string_utils::{calculate_text_confidence, decode_with_encoding, fix_mojibake, safe_decode};
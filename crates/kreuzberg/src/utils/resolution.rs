@@ -0,0 +1,198 @@
+//! Physical resolution recorded in encoded images.
+//!
+//! Pages rendered from PDFs carry the DPI they were rendered at in a PNG `pHYs` chunk, so OCR
+//! backends and image consumers see the true resolution instead of assuming 72 DPI. Scans and
+//! photos usually record theirs in a `pHYs` chunk or a JPEG JFIF header as well.
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+const METRES_PER_INCH: f64 = 0.0254;
+
+const CENTIMETRES_PER_INCH: f64 = 2.54;
+
+/// A chunk of a PNG file.
+struct PngChunk<'a> {
+    kind: &'a [u8],
+    data: &'a [u8],
+    /// The whole chunk, including its length, type and CRC
+    raw: &'a [u8],
+}
+
+/// Horizontal resolution recorded in a PNG or JPEG image, rounded to whole dots per inch.
+///
+/// Reads the `pHYs` chunk of PNGs and the JFIF header of JPEGs. Returns `None` for other
+/// formats and for images that only record an aspect ratio.
+pub fn image_dpi(bytes: &[u8]) -> Option<u32> {
+    if bytes.starts_with(PNG_SIGNATURE) {
+        png_dpi(bytes)
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        jpeg_dpi(bytes)
+    } else {
+        None
+    }
+}
+
+/// Record `dpi` in the `pHYs` chunk of a PNG, replacing any resolution it already has.
+///
+/// Data that is not a PNG is returned unchanged.
+pub fn with_png_dpi(png: Vec<u8>, dpi: u32) -> Vec<u8> {
+    if !png.starts_with(PNG_SIGNATURE) || dpi == 0 {
+        return png;
+    }
+
+    let pixels_per_metre = (f64::from(dpi) / METRES_PER_INCH).round() as u32;
+    let mut data = Vec::with_capacity(9);
+    data.extend_from_slice(&pixels_per_metre.to_be_bytes());
+    data.extend_from_slice(&pixels_per_metre.to_be_bytes());
+    data.push(1);
+    let phys = png_chunk(b"pHYs", &data);
+
+    let chunks = png_chunks(&png);
+    let consumed = PNG_SIGNATURE.len() + chunks.iter().map(|chunk| chunk.raw.len()).sum::<usize>();
+    let mut output = Vec::with_capacity(png.len() + phys.len());
+    output.extend_from_slice(PNG_SIGNATURE);
+    for chunk in &chunks {
+        if chunk.kind == b"pHYs" {
+            continue;
+        }
+        output.extend_from_slice(chunk.raw);
+        if chunk.kind == b"IHDR" {
+            output.extend_from_slice(&phys);
+        }
+    }
+    output.extend_from_slice(&png[consumed..]);
+    output
+}
+
+fn png_dpi(png: &[u8]) -> Option<u32> {
+    let chunks = png_chunks(png);
+    let data = chunks.iter().find(|chunk| chunk.kind == b"pHYs")?.data;
+    // Unit 1 is the metre; unit 0 only gives the pixel aspect ratio.
+    if data.len() != 9 || data[8] != 1 {
+        return None;
+    }
+    let pixels_per_metre = u32::from_be_bytes([data[0], data[1], data[2], data[3]]);
+    let dpi = (f64::from(pixels_per_metre) * METRES_PER_INCH).round() as u32;
+    (dpi > 0).then_some(dpi)
+}
+
+fn jpeg_dpi(jpeg: &[u8]) -> Option<u32> {
+    let mut position = 2;
+    while let Some(&[0xFF, marker, high, low]) = jpeg.get(position..position + 4) {
+        let length = usize::from(u16::from_be_bytes([high, low]));
+        let segment = jpeg.get(position + 4..position + 2 + length)?;
+        if marker == 0xE0 && segment.starts_with(b"JFIF\0") && segment.len() >= 12 {
+            let density = u16::from_be_bytes([segment[8], segment[9]]);
+            return match segment[7] {
+                1 if density > 0 => Some(u32::from(density)),
+                2 if density > 0 => Some((f64::from(density) * CENTIMETRES_PER_INCH).round() as u32),
+                _ => None,
+            };
+        }
+        // Image data follows the start-of-scan segment.
+        if marker == 0xDA {
+            break;
+        }
+        position += 2 + length;
+    }
+    None
+}
+
+/// Split a PNG into its chunks, stopping at the first truncated one.
+fn png_chunks(png: &[u8]) -> Vec<PngChunk<'_>> {
+    let mut chunks = Vec::new();
+    let mut position = PNG_SIGNATURE.len();
+    while let Some(&[a, b, c, d]) = png.get(position..position + 4) {
+        let length = u32::from_be_bytes([a, b, c, d]) as usize;
+        let Some(raw) = png.get(position..position + 12 + length) else {
+            break;
+        };
+        chunks.push(PngChunk {
+            kind: &raw[4..8],
+            data: &raw[8..8 + length],
+            raw,
+        });
+        position += raw.len();
+    }
+    chunks
+}
+
+fn png_chunk(kind: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut chunk = Vec::with_capacity(data.len() + 12);
+    chunk.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    chunk.extend_from_slice(kind);
+    chunk.extend_from_slice(data);
+    let crc = crc32(&chunk[4..]);
+    chunk.extend_from_slice(&crc.to_be_bytes());
+    chunk
+}
+
+/// CRC-32 (ISO 3309) as used by PNG chunks.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minimal_png() -> Vec<u8> {
+        let mut png = PNG_SIGNATURE.to_vec();
+        png.extend(png_chunk(b"IHDR", &[0, 0, 0, 1, 0, 0, 0, 1, 8, 0, 0, 0, 0]));
+        png.extend(png_chunk(
+            b"IDAT",
+            &[0x78, 0x9C, 0x63, 0x60, 0x00, 0x00, 0x00, 0x02, 0x00, 0x01],
+        ));
+        png.extend(png_chunk(b"IEND", &[]));
+        png
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+    }
+
+    #[test]
+    fn test_png_dpi_round_trip() {
+        let png = minimal_png();
+        assert_eq!(image_dpi(&png), None);
+
+        let png = with_png_dpi(png, 300);
+        assert_eq!(image_dpi(&png), Some(300));
+
+        let kinds: Vec<String> = png_chunks(&png)
+            .iter()
+            .map(|chunk| String::from_utf8_lossy(chunk.kind).into_owned())
+            .collect();
+        assert_eq!(kinds, vec!["IHDR", "pHYs", "IDAT", "IEND"]);
+
+        let png = with_png_dpi(png, 150);
+        assert_eq!(image_dpi(&png), Some(150));
+        assert_eq!(png_chunks(&png).len(), 4);
+    }
+
+    #[test]
+    fn test_jpeg_dpi() {
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10];
+        jpeg.extend_from_slice(b"JFIF\0");
+        jpeg.extend_from_slice(&[1, 1, 1, 0, 200, 0, 200, 0, 0, 0xFF, 0xD9]);
+        assert_eq!(image_dpi(&jpeg), Some(200));
+
+        jpeg[13] = 0;
+        assert_eq!(image_dpi(&jpeg), None);
+    }
+
+    #[test]
+    fn test_other_formats() {
+        assert_eq!(image_dpi(b"GIF89a"), None);
+        assert_eq!(with_png_dpi(b"GIF89a".to_vec(), 300), b"GIF89a");
+    }
+}
@@ -1,3 +1,4 @@
+use super::ebcdic::{EbcdicCodePage, looks_like_ebcdic};
 use ahash::AHashMap;
 use chardetng::EncodingDetector;
 use encoding_rs::Encoding;
@@ -155,15 +156,35 @@ fn calculate_cache_key(data: &[u8]) -> String {
 /// an encoding detector, and finally tries a small curated list before returning a
 /// mojibake-cleaned string.
 pub fn safe_decode(byte_data: &[u8], encoding: Option<&str>) -> String {
+    decode_with_encoding(byte_data, encoding).0
+}
+
+/// [`safe_decode`] that also returns the name of the encoding the text was decoded from, such as
+/// `UTF-8`, `windows-1252`, `Shift_JIS` or `IBM037`.
+///
+/// Besides the encodings of the WHATWG Encoding Standard, EBCDIC is recognized. Detected EBCDIC
+/// is decoded as code page 037; other code pages can be given as `encoding` (see [`EbcdicCodePage`]).
+pub fn decode_with_encoding(byte_data: &[u8], encoding: Option<&str>) -> (String, &'static str) {
     if byte_data.is_empty() {
-        return String::new();
+        return (String::new(), encoding_rs::UTF_8.name());
+    }
+
+    if let Some(enc_name) = encoding
+        && let Some(code_page) = EbcdicCodePage::for_label(enc_name)
+    {
+        return (fix_mojibake_internal(&code_page.decode(byte_data)), code_page.name());
     }
 
     if let Some(enc_name) = encoding
         && let Some(enc) = Encoding::for_label(enc_name.as_bytes())
     {
-        let (decoded, _, _) = enc.decode(byte_data);
-        return fix_mojibake_internal(&decoded);
+        let (decoded, used, _) = enc.decode(byte_data);
+        return (fix_mojibake_internal(&decoded), used.name());
+    }
+
+    if looks_like_ebcdic(byte_data) {
+        let code_page = EbcdicCodePage::Cp037;
+        return (fix_mojibake_internal(&code_page.decode(byte_data)), code_page.name());
     }
 
     let cache_key = calculate_cache_key(byte_data);
@@ -172,8 +193,8 @@ pub fn safe_decode(byte_data: &[u8], encoding: Option<&str>) -> String {
     match ENCODING_CACHE.write() {
         Ok(mut cache) => {
             if let Some(cached_encoding) = cache.get(&cache_key) {
-                let (decoded, _, _) = cached_encoding.decode(byte_data);
-                return fix_mojibake_internal(&decoded);
+                let (decoded, used, _) = cached_encoding.decode(byte_data);
+                return (fix_mojibake_internal(&decoded), used.name());
             }
         }
         Err(e) => {
@@ -197,7 +218,7 @@ pub fn safe_decode(byte_data: &[u8], encoding: Option<&str>) -> String {
         }
     }
 
-    let (decoded, _, had_errors) = encoding.decode(byte_data);
+    let (decoded, used, had_errors) = encoding.decode(byte_data);
 
     if had_errors {
         for enc_name in &[
@@ -211,7 +232,7 @@ pub fn safe_decode(byte_data: &[u8], encoding: Option<&str>) -> String {
             if let Some(enc) = Encoding::for_label(enc_name.as_bytes()) {
                 let (test_decoded, _, test_errors) = enc.decode(byte_data);
                 if !test_errors && calculate_text_confidence_internal(&test_decoded) > 0.5 {
-                    return fix_mojibake_internal(&test_decoded);
+                    return (fix_mojibake_internal(&test_decoded), enc.name());
                 }
             }
         }
@@ -230,12 +251,12 @@ pub fn safe_decode(byte_data: &[u8], encoding: Option<&str>) -> String {
                 encoding.name(),
                 confidence,
                 final_text.len(),
-                preview
+                crate::core::logging::redact(&preview)
             );
         }
     }
 
-    final_text
+    (final_text, used.name())
 }
 
 /// Estimate how trustworthy a decoded string is on a 0.0–1.0 scale.
@@ -317,6 +338,27 @@ mod tests {
         assert_eq!(safe_decode(text, None), "Hello, 世界! مرحبا");
     }
 
+    #[test]
+    fn test_decode_with_encoding_detects_legacy_encodings() {
+        let japanese = "日本語のテキストです。これはエンコーディング検出のテストです。";
+        let (bytes, _, _) = encoding_rs::SHIFT_JIS.encode(japanese);
+        assert_eq!(decode_with_encoding(&bytes, None), (japanese.to_string(), "Shift_JIS"));
+
+        let text = "Le garçon a mangé une crème brûlée à côté de la fenêtre, très élégant.";
+        let (bytes, _, _) = encoding_rs::WINDOWS_1252.encode(text);
+        assert_eq!(decode_with_encoding(&bytes, None), (text.to_string(), "windows-1252"));
+    }
+
+    #[test]
+    fn test_decode_with_encoding_ebcdic() {
+        let ebcdic = b"\xC8\x85\x93\x93\x96\x6B\x40\xE6\x96\x99\x93\x84\x5A\x15".repeat(2);
+        assert_eq!(
+            decode_with_encoding(&ebcdic, None),
+            ("Hello, World!\nHello, World!\n".to_string(), "IBM037")
+        );
+        assert_eq!(decode_with_encoding(&ebcdic, Some("cp500")).1, "IBM500");
+    }
+
     #[test]
     fn test_encoding_cache_eviction() {
         let mut cache = ENCODING_CACHE.write().unwrap();
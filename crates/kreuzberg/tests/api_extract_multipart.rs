@@ -50,3 +50,111 @@ Hello world\r\n\
 
     assert_eq!(content.trim_end_matches('\n'), "Hello world");
 }
+
+#[cfg(feature = "archives")]
+#[tokio::test]
+async fn test_extract_expands_zip_archives() {
+    use std::io::Write;
+    use zip::write::{FileOptions, ZipWriter};
+
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    {
+        let mut zip = ZipWriter::new(&mut cursor);
+        let options = FileOptions::<'_, ()>::default();
+        zip.start_file("first.txt", options).unwrap();
+        zip.write_all(b"First member").unwrap();
+        zip.start_file("nested/second.md", options).unwrap();
+        zip.write_all(b"# Second member").unwrap();
+        zip.finish().unwrap();
+    }
+    let archive = cursor.into_inner();
+
+    let router = create_router_with_limits(ExtractionConfig::default(), ApiSizeLimits::from_mb(5, 5));
+
+    let boundary = "X-BOUNDARY";
+    let mut body = format!(
+        "--{boundary}\r\n\
+Content-Disposition: form-data; name=\"files\"; filename=\"batch.zip\"\r\n\
+Content-Type: application/zip\r\n\
+\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(&archive);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/extract?expand_archives=true")
+        .header("content-type", format!("multipart/form-data; boundary={boundary}"))
+        .header("content-length", body.len())
+        .body(Body::from(body))
+        .expect("Failed to build request");
+
+    let response = router.oneshot(request).await.expect("Request failed");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let bytes = to_bytes(response.into_body(), 1_000_000)
+        .await
+        .expect("Failed to read body");
+    let value: Value = serde_json::from_slice(&bytes).expect("Response JSON parse failed");
+    let results = value.as_array().expect("Response should be a list");
+    assert_eq!(results.len(), 2);
+
+    let paths: Vec<&str> = results
+        .iter()
+        .filter_map(|result| result["metadata"]["archive"]["path"].as_str())
+        .collect();
+    assert_eq!(paths, vec!["first.txt", "nested/second.md"]);
+    assert_eq!(results[0]["metadata"]["archive"]["filename"], "batch.zip");
+    assert!(results[0]["content"].as_str().unwrap().contains("First member"));
+    assert!(results[1]["content"].as_str().unwrap().contains("Second member"));
+}
+
+#[cfg(feature = "archives")]
+#[tokio::test]
+async fn test_extract_reports_failing_single_archive_member_per_file() {
+    use std::io::Write;
+    use zip::write::{FileOptions, ZipWriter};
+
+    let mut cursor = std::io::Cursor::new(Vec::new());
+    {
+        let mut zip = ZipWriter::new(&mut cursor);
+        zip.start_file("broken.pdf", FileOptions::<'_, ()>::default()).unwrap();
+        zip.write_all(b"not a pdf").unwrap();
+        zip.finish().unwrap();
+    }
+    let archive = cursor.into_inner();
+
+    let router = create_router_with_limits(ExtractionConfig::default(), ApiSizeLimits::from_mb(5, 5));
+
+    let boundary = "X-BOUNDARY";
+    let mut body = format!(
+        "--{boundary}\r\n\
+Content-Disposition: form-data; name=\"files\"; filename=\"single.zip\"\r\n\
+Content-Type: application/zip\r\n\
+\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(&archive);
+    body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/extract?expand_archives=true")
+        .header("content-type", format!("multipart/form-data; boundary={boundary}"))
+        .header("content-length", body.len())
+        .body(Body::from(body))
+        .expect("Failed to build request");
+
+    let response = router.oneshot(request).await.expect("Request failed");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let bytes = to_bytes(response.into_body(), 1_000_000)
+        .await
+        .expect("Failed to read body");
+    let value: Value = serde_json::from_slice(&bytes).expect("Response JSON parse failed");
+    let results = value.as_array().expect("Response should be a list");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["metadata"]["archive"]["path"], "broken.pdf");
+    assert!(results[0]["metadata"]["error"].is_object());
+}
@@ -0,0 +1,61 @@
+//! Integration tests for draining the API server on shutdown.
+//!
+//! Draining is process-wide and can't be undone, so these tests run in their own binary.
+
+#![cfg(feature = "api")]
+
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+};
+use tower::ServiceExt;
+
+use kreuzberg::{
+    ExtractionConfig,
+    api::{HealthResponse, create_router},
+    core::shutdown,
+};
+
+/// Test that a draining server fails its health check and rejects new extractions.
+#[tokio::test]
+async fn test_draining_server_rejects_new_work() {
+    shutdown::begin_draining();
+    assert!(shutdown::is_draining());
+
+    let app = create_router(ExtractionConfig::default());
+    let response = app
+        .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let health: HealthResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(health.status, "draining");
+
+    let boundary = "----boundary";
+    let body_content = format!(
+        "--{}\r\n\
+         Content-Disposition: form-data; name=\"files\"; filename=\"test.txt\"\r\n\
+         Content-Type: text/plain\r\n\
+         \r\n\
+         Hello, world!\r\n\
+         --{}--\r\n",
+        boundary, boundary
+    );
+    let request = Request::builder()
+        .method("POST")
+        .uri("/extract")
+        .header("content-type", format!("multipart/form-data; boundary={}", boundary))
+        .body(Body::from(body_content))
+        .unwrap();
+
+    let app = create_router(ExtractionConfig::default());
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    // A server started now shuts down right away
+    tokio::time::timeout(std::time::Duration::from_secs(5), shutdown::shutdown_signal())
+        .await
+        .unwrap();
+}
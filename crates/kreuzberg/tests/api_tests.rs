@@ -7,11 +7,15 @@ use axum::{
     http::{Request, StatusCode},
 };
 use serde_json::json;
+use std::sync::Arc;
 use tower::ServiceExt;
 
 use kreuzberg::{
-    ExtractionConfig,
-    api::{HealthResponse, InfoResponse, create_router},
+    ConfigHandle, ExtractionConfig, PluginRegistries,
+    api::{
+        ApiSizeLimits, HealthResponse, InfoResponse, create_router, create_router_with_admin_token,
+        create_router_with_config_handle,
+    },
 };
 
 /// Test the health check endpoint.
@@ -31,6 +35,7 @@ async fn test_health_endpoint() {
 
     assert_eq!(health.status, "healthy");
     assert!(!health.version.is_empty());
+    assert!(health.load.max_concurrent_jobs >= 1);
 }
 
 /// Test the info endpoint.
@@ -50,6 +55,8 @@ async fn test_info_endpoint() {
 
     assert!(!info.version.is_empty());
     assert!(info.rust_backend);
+    assert_eq!(info.device.available.last(), Some(&kreuzberg::Device::Cpu));
+    assert_eq!(info.doctor.version, info.version);
 }
 
 /// Test extract endpoint with no files returns 400.
@@ -655,6 +662,8 @@ async fn test_error_response_format_validation() {
     assert!(error["message"].is_string());
     assert_eq!(error["status_code"], 400);
     assert_eq!(error["error_type"], "ValidationError");
+    assert_eq!(error["code"], "validation");
+    assert_eq!(error["retryable"], false);
 }
 
 /// Test error response format for parsing errors.
@@ -964,3 +973,200 @@ async fn test_extract_default_mime_type() {
             || response.status() == StatusCode::INTERNAL_SERVER_ERROR
     );
 }
+
+/// Test extract endpoint selecting a profile of the config file via `?profile=`.
+#[tokio::test]
+async fn test_extract_with_profile() {
+    let dir = tempfile::tempdir().unwrap();
+    let config_path = dir.path().join("kreuzberg.toml");
+    std::fs::write(
+        &config_path,
+        "use_cache = false\n\n[profile.chunked.chunking]\nmax_chars = 50\nmax_overlap = 10\n",
+    )
+    .unwrap();
+    let handle = ConfigHandle::from_file(&config_path).unwrap();
+
+    let boundary = "----boundary";
+    let body_content = format!(
+        "--{}\r\n\
+         Content-Disposition: form-data; name=\"files\"; filename=\"test.txt\"\r\n\
+         Content-Type: text/plain\r\n\
+         \r\n\
+         Hello, world!\r\n\
+         --{}--\r\n",
+        boundary, boundary
+    );
+    let request = |uri: &str| {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", format!("multipart/form-data; boundary={}", boundary))
+            .body(Body::from(body_content.clone()))
+            .unwrap()
+    };
+
+    let app = create_router_with_config_handle(handle.clone(), ApiSizeLimits::default());
+    let response = app.oneshot(request("/extract?profile=chunked")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let results: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+    assert!(results[0]["chunks"].is_array(), "Profile should enable chunking");
+
+    let app = create_router_with_config_handle(handle, ApiSizeLimits::default());
+    let response = app.oneshot(request("/extract?profile=missing")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+/// Test extract endpoint with an explicit queue priority via `?priority=`.
+#[tokio::test]
+async fn test_extract_with_priority() {
+    let boundary = "----boundary";
+    let body_content = format!(
+        "--{}\r\n\
+         Content-Disposition: form-data; name=\"files\"; filename=\"test.txt\"\r\n\
+         Content-Type: text/plain\r\n\
+         \r\n\
+         Hello, world!\r\n\
+         --{}--\r\n",
+        boundary, boundary
+    );
+    let request = |uri: &str| {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", format!("multipart/form-data; boundary={}", boundary))
+            .body(Body::from(body_content.clone()))
+            .unwrap()
+    };
+
+    let app = create_router(ExtractionConfig::default());
+    let response = app.oneshot(request("/extract?priority=low")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let app = create_router(ExtractionConfig::default());
+    let response = app.oneshot(request("/extract?priority=urgent")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+/// Test extract endpoint returning selected fields and a window of the content.
+#[tokio::test]
+async fn test_extract_with_field_selection_and_pagination() {
+    let boundary = "----boundary";
+    let body_content = format!(
+        "--{}\r\n\
+         Content-Disposition: form-data; name=\"files\"; filename=\"test.txt\"\r\n\
+         Content-Type: text/plain\r\n\
+         \r\n\
+         Hello, world!\r\n\
+         --{}--\r\n",
+        boundary, boundary
+    );
+    let request = |uri: &str| {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", format!("multipart/form-data; boundary={}", boundary))
+            .body(Body::from(body_content.clone()))
+            .unwrap()
+    };
+
+    let app = create_router(ExtractionConfig::default());
+    let response = app
+        .oneshot(request("/extract?fields=content&content_offset=7&content_limit=5"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let results: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(results[0]["content"], "world");
+    assert_eq!(results[0]["content_page"]["offset"], 7);
+    assert!(results[0]["content_page"]["total_length"].as_u64().unwrap() >= 13);
+    assert!(results[0].get("metadata").is_none());
+
+    let app = create_router(ExtractionConfig::default());
+    let response = app.oneshot(request("/extract?fields=content,text")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+/// Test that admin endpoints require the admin token and reload the plugins.
+#[tokio::test]
+async fn test_config_reload_requires_admin_token() {
+    let handle = ConfigHandle::new(ExtractionConfig::default())
+        .with_plugin_loader(["plugins"], |_dirs| Ok(PluginRegistries::new()))
+        .unwrap();
+    let plugins_before = handle.registries();
+    let request = |token: Option<&str>| {
+        let mut builder = Request::builder().method("POST").uri("/config/reload");
+        if let Some(token) = token {
+            builder = builder.header("authorization", format!("Bearer {}", token));
+        }
+        builder.body(Body::empty()).unwrap()
+    };
+    let router = || {
+        let admin_token = Some("secret".to_string());
+        create_router_with_admin_token(handle.clone(), ApiSizeLimits::default(), admin_token)
+    };
+
+    for token in [None, Some("wrong")] {
+        let response = router().oneshot(request(token)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+    assert!(Arc::ptr_eq(
+        &handle.registries().ocr_backends,
+        &plugins_before.ocr_backends
+    ));
+
+    let response = router().oneshot(request(Some("secret"))).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let response: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(response["reloaded"], true);
+    assert!(!Arc::ptr_eq(
+        &handle.registries().ocr_backends,
+        &plugins_before.ocr_backends
+    ));
+
+    let response = router()
+        .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+/// Test changing config fields at runtime via `PATCH /config`.
+#[tokio::test]
+async fn test_config_update_endpoint() {
+    let handle = ConfigHandle::new(ExtractionConfig::default());
+    let request = |body: serde_json::Value| {
+        Request::builder()
+            .method("PATCH")
+            .uri("/config")
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+
+    let app = create_router_with_config_handle(handle.clone(), ApiSizeLimits::default());
+    let response = app
+        .oneshot(request(json!({"ocr_language": "deu", "use_cache": false})))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let response: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(response["updated"][0]["field"], "ocr_language");
+    assert_eq!(response["updated"][1]["previous"], true);
+    assert_eq!(handle.current().ocr.as_ref().unwrap().language, "deu");
+    assert!(!handle.current().use_cache);
+
+    let app = create_router_with_config_handle(handle.clone(), ApiSizeLimits::default());
+    let response = app
+        .oneshot(request(json!({"max_concurrent_extractions": 0})))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(handle.current().max_concurrent_extractions, None);
+}
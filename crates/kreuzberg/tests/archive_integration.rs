@@ -1,6 +1,6 @@
 //! Archive extraction integration tests.
 //!
-//! Tests for ZIP, TAR, TAR.GZ, and 7z archive extraction.
+//! Tests for ZIP, TAR, TAR.GZ, 7z and ISO 9660 archive extraction.
 //! Validates metadata extraction, content extraction, nested archives, and error handling.
 
 #![cfg(feature = "archives")]
@@ -362,6 +362,32 @@ async fn test_corrupted_archive() {
     );
 }
 
+/// Test ZIP with a truncated central directory is recovered unless strict.
+#[tokio::test]
+async fn test_truncated_zip_recovery() {
+    let zip_bytes = create_simple_zip();
+    let directory = zip_bytes
+        .windows(4)
+        .position(|window| window == b"PK\x01\x02")
+        .expect("ZIP should have a central directory");
+    let truncated = &zip_bytes[..directory];
+
+    let result = extract_bytes(truncated, "application/zip", &ExtractionConfig::default())
+        .await
+        .expect("Should recover truncated ZIP");
+
+    assert!(result.content.contains("Hello from ZIP!"));
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].code, "document_repaired");
+
+    let strict = ExtractionConfig {
+        strict: true,
+        ..Default::default()
+    };
+    let result = extract_bytes(truncated, "application/zip", &strict).await;
+    assert!(result.is_err(), "Strict mode should fail on truncated ZIP");
+}
+
 /// Test large archive (100+ files).
 #[tokio::test]
 async fn test_large_archive() {
@@ -513,6 +539,30 @@ fn test_archive_extraction_sync() {
     assert_eq!(archive_meta.file_list[0], "test.txt");
 }
 
+/// Test ISO 9660 image extraction.
+#[cfg(feature = "iso")]
+#[tokio::test]
+async fn test_iso_extraction() {
+    if !helpers::test_documents_available() {
+        return;
+    }
+
+    let iso_bytes = std::fs::read(helpers::get_test_file_path("archives/sample.iso")).unwrap();
+    let result = extract_bytes(&iso_bytes, "application/x-iso9660-image", &ExtractionConfig::default())
+        .await
+        .expect("Should extract ISO image");
+
+    assert!(result.content.contains("ISO Archive"));
+    assert!(result.content.contains("Hello from ISO"));
+    assert!(result.content.to_lowercase().contains("docs/notes.md"));
+
+    let archive_meta = match result.metadata.format.as_ref().unwrap() {
+        kreuzberg::FormatMetadata::Archive(meta) => meta,
+        _ => panic!("Expected Archive metadata"),
+    };
+    assert_eq!(archive_meta.format, "ISO");
+}
+
 fn create_simple_zip() -> Vec<u8> {
     let mut cursor = Cursor::new(Vec::new());
     {
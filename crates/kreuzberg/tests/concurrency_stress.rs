@@ -135,6 +135,11 @@ async fn test_concurrent_extractions_with_cache() {
             enabled: false,
             enabled_processors: None,
             disabled_processors: None,
+            header_footer: None,
+            line_repair: None,
+            normalization: None,
+            metrics: None,
+            taggers: None,
         }),
         ..Default::default()
     };
@@ -352,6 +357,11 @@ async fn test_concurrent_pipeline_processing() {
             enabled: true,
             enabled_processors: Some(vec!["concurrent-test".to_string()]),
             disabled_processors: None,
+            header_footer: None,
+            line_repair: None,
+            normalization: None,
+            metrics: None,
+            taggers: None,
         }),
         ..Default::default()
     };
@@ -370,6 +380,11 @@ async fn test_concurrent_pipeline_processing() {
                 chunks: None,
                 images: None,
                 pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             };
 
             run_pipeline(result, &config).await
@@ -166,6 +166,7 @@ async fn test_language_detection_single() {
             enabled: true,
             min_confidence: 0.8,
             detect_multiple: false,
+            max_sample_kb: None,
         }),
         ..Default::default()
     };
@@ -180,7 +181,9 @@ async fn test_language_detection_single() {
     assert!(result.detected_languages.is_some(), "Should detect language");
     let languages = result.detected_languages.unwrap();
     assert!(!languages.is_empty(), "Should detect at least one language");
-    assert_eq!(languages[0], "eng", "Should detect English");
+    assert_eq!(languages[0].language, "eng", "Should detect English");
+    assert!(languages[0].confidence > 0.0, "Should report a confidence");
+    assert_eq!(languages[0].script, "Latin");
 }
 
 /// Test language detection for multi-language document.
@@ -193,6 +196,7 @@ async fn test_language_detection_multiple() {
             enabled: true,
             min_confidence: 0.7,
             detect_multiple: true,
+            max_sample_kb: None,
         }),
         ..Default::default()
     };
@@ -218,6 +222,7 @@ async fn test_language_detection_confidence() {
             enabled: true,
             min_confidence: 0.9,
             detect_multiple: false,
+            max_sample_kb: None,
         }),
         ..Default::default()
     };
@@ -243,6 +248,7 @@ async fn test_language_detection_disabled() {
             enabled: false,
             min_confidence: 0.8,
             detect_multiple: false,
+            max_sample_kb: None,
         }),
         ..Default::default()
     };
@@ -351,6 +357,9 @@ async fn test_token_reduction_aggressive() {
         token_reduction: Some(TokenReductionConfig {
             mode: "aggressive".to_string(),
             preserve_important_words: true,
+            stopwords: None,
+            target_ratio: None,
+            max_tokens: None,
         }),
         ..Default::default()
     };
@@ -372,6 +381,9 @@ async fn test_token_reduction_conservative() {
         token_reduction: Some(TokenReductionConfig {
             mode: "light".to_string(),
             preserve_important_words: true,
+            stopwords: None,
+            target_ratio: None,
+            max_tokens: None,
         }),
         ..Default::default()
     };
@@ -393,6 +405,9 @@ async fn test_token_reduction_disabled() {
         token_reduction: Some(TokenReductionConfig {
             mode: "off".to_string(),
             preserve_important_words: false,
+            stopwords: None,
+            target_ratio: None,
+            max_tokens: None,
         }),
         ..Default::default()
     };
@@ -407,6 +422,32 @@ async fn test_token_reduction_disabled() {
     assert!(result.content.contains("without token reduction"));
 }
 
+/// Test token reduction with a token budget.
+#[cfg(feature = "quality")]
+#[tokio::test]
+async fn test_token_reduction_max_tokens() {
+    let config = ExtractionConfig {
+        token_reduction: Some(TokenReductionConfig {
+            mode: "off".to_string(),
+            preserve_important_words: true,
+            stopwords: None,
+            target_ratio: None,
+            max_tokens: Some(20),
+        }),
+        ..Default::default()
+    };
+
+    let text = "Token budgets keep the most important sentences. Filler sentences are dropped first. ".repeat(10);
+    let text_bytes = text.as_bytes();
+
+    let result = extract_bytes(text_bytes, "text/plain", &config)
+        .await
+        .expect("Should extract successfully");
+
+    assert!(!result.content.is_empty());
+    assert!(result.content.split_whitespace().count() <= 20);
+}
+
 /// Test quality processing enabled - quality scoring applied.
 #[tokio::test]
 #[cfg(feature = "quality")]
@@ -18,8 +18,8 @@ fn test_from_file_toml_succeeds() {
 
     let toml_content = r#"
 [ocr]
-enabled = true
 backend = "tesseract"
+language = "eng"
 
 [chunking]
 max_chars = 1000
@@ -48,8 +48,8 @@ fn test_from_file_yaml_succeeds() {
 
     let yaml_content = r#"
 ocr:
-  enabled: true
   backend: tesseract
+  language: eng
 chunking:
   max_chars: 1000
   max_overlap: 100
@@ -78,8 +78,8 @@ fn test_from_file_json_succeeds() {
     let json_content = r#"
 {
   "ocr": {
-    "enabled": true,
-    "backend": "tesseract"
+    "backend": "tesseract",
+    "language": "eng"
   },
   "chunking": {
     "max_chars": 1000,
@@ -110,7 +110,7 @@ fn test_from_file_yml_extension_succeeds() {
 
     let yml_content = r#"
 ocr:
-  enabled: true
+  language: eng
 "#;
 
     fs::write(&config_path, yml_content).unwrap();
@@ -227,7 +227,7 @@ fn test_discover_finds_config_in_current_dir() {
 
     let toml_content = r#"
 [ocr]
-enabled = true
+language = "eng"
 "#;
 
     fs::write(&config_path, toml_content).unwrap();
@@ -254,7 +254,7 @@ fn test_discover_finds_config_in_parent_dir() {
 
     let toml_content = r#"
 [ocr]
-enabled = true
+language = "eng"
 "#;
 
     fs::write(&config_path, toml_content).unwrap();
@@ -300,8 +300,8 @@ fn test_discover_returns_none_when_not_found() {
 fn test_discover_file_name_preference() {
     let temp_dir = TempDir::new().unwrap();
 
-    fs::write(temp_dir.path().join("kreuzberg.toml"), "[ocr]\nenabled = true").unwrap();
-    fs::write(temp_dir.path().join(".kreuzberg.toml"), "[ocr]\nenabled = false").unwrap();
+    fs::write(temp_dir.path().join("kreuzberg.toml"), "[ocr]\nlanguage = \"eng\"").unwrap();
+    fs::write(temp_dir.path().join(".kreuzberg.toml"), "[ocr]\nlanguage = \"deu\"").unwrap();
 
     let original_dir = std::env::current_dir().unwrap();
     if std::env::set_current_dir(temp_dir.path()).is_err() {
@@ -326,7 +326,7 @@ fn test_discover_with_nested_directories() {
 
     let toml_content = r#"
 [ocr]
-enabled = true
+language = "eng"
 "#;
 
     fs::write(&config_path, toml_content).unwrap();
@@ -357,9 +357,8 @@ fn test_from_file_comprehensive_config() {
     let temp_dir = TempDir::new().unwrap();
     let config_path = temp_dir.path().join("config.toml");
 
-    let toml_content = r#"
+    let mut toml_content = r#"
 [ocr]
-enabled = true
 backend = "tesseract"
 
 [chunking]
@@ -370,11 +369,12 @@ max_overlap = 200
 enabled = true
 
 [images]
-enabled = true
-
-[pdf_options]
 extract_images = true
-"#;
+"#
+    .to_string();
+    if cfg!(feature = "pdf") {
+        toml_content.push_str("\n[pdf_options]\nextract_images = true\n");
+    }
 
     fs::write(&config_path, toml_content).unwrap();
 
@@ -414,3 +414,60 @@ max_overlap = -100
         assert!(chunking.max_chars > 0, "max_chars should be positive");
     }
 }
+
+/// Test unknown keys are rejected with their location.
+#[test]
+fn test_from_file_unknown_key_fails_with_location() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.toml");
+
+    let toml_content = r#"use_cache = true
+
+[ocr]
+backend = "tesseract"
+languge = "deu"
+"#;
+
+    fs::write(&config_path, toml_content).unwrap();
+
+    let err = ExtractionConfig::from_file(&config_path).unwrap_err();
+    assert!(matches!(err, KreuzbergError::Validation { .. }));
+    assert!(
+        err.to_string()
+            .contains("ocr.languge (line 5, column 1): unknown key, did you mean `language`?"),
+        "Error should locate the unknown key: {}",
+        err
+    );
+}
+
+/// Test out-of-range values are rejected with their location.
+#[test]
+fn test_from_file_out_of_range_value_fails_with_location() {
+    let temp_dir = TempDir::new().unwrap();
+    let config_path = temp_dir.path().join("config.yaml");
+
+    let yaml_content = r#"language_detection:
+  enabled: true
+  min_confidence: 1.5
+"#;
+
+    fs::write(&config_path, yaml_content).unwrap();
+
+    let err = ExtractionConfig::from_file(&config_path).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("language_detection.min_confidence (line 3, column 3): must be between 0.0 and 1.0, got 1.5"),
+        "Error should locate the out-of-range value: {}",
+        err
+    );
+}
+
+/// Test the JSON schema describes the configuration fields.
+#[test]
+fn test_json_schema_describes_config() {
+    let schema = ExtractionConfig::json_schema();
+
+    assert!(schema["properties"]["chunking"].is_object());
+    assert!(schema["$defs"]["ChunkingConfig"]["properties"]["max_chars"].is_object());
+    assert!(schema["properties"].get("html_options").is_none());
+}
@@ -2,7 +2,14 @@
 
 #![cfg(feature = "office")]
 
-use kreuzberg::{ExtractionConfig, extract_file};
+use kreuzberg::{ExtractionConfig, FormatMetadata, OfficeMetadata, extract_file};
+
+fn office_metadata(metadata: &kreuzberg::Metadata) -> &OfficeMetadata {
+    match metadata.format.as_ref() {
+        Some(FormatMetadata::Office(office)) => office,
+        other => panic!("Expected office metadata, got {:?}", other),
+    }
+}
 
 #[tokio::test]
 async fn test_docx_full_metadata_extraction() {
@@ -29,62 +36,40 @@ async fn test_docx_full_metadata_extraction() {
     );
 
     assert_eq!(
-        result.metadata.additional.get("created_by").and_then(|v| v.as_str()),
+        result.metadata.created_by.as_deref(),
         Some("Christoph Auer"),
         "Should have correct creator"
     );
     assert_eq!(
-        result.metadata.additional.get("modified_by").and_then(|v| v.as_str()),
+        result.metadata.authors.as_deref(),
+        Some(&["Christoph Auer".to_string()][..]),
+        "Creator should also be listed as author"
+    );
+    assert_eq!(
+        result.metadata.modified_by.as_deref(),
         Some("Maxim Lysak"),
         "Should have correct last modified by"
     );
     assert_eq!(
-        result.metadata.additional.get("created_at").and_then(|v| v.as_str()),
+        result.metadata.created_at.as_deref(),
         Some("2024-10-09T12:43:00Z"),
         "Should have correct creation date"
     );
-    assert_eq!(
-        result.metadata.additional.get("revision").and_then(|v| v.as_str()),
-        Some("7"),
-        "Should have revision number"
-    );
 
-    assert_eq!(
-        result.metadata.additional.get("page_count").and_then(|v| v.as_i64()),
-        Some(2),
-        "Should have 2 pages"
-    );
-    assert_eq!(
-        result.metadata.additional.get("word_count").and_then(|v| v.as_i64()),
-        Some(108),
-        "Should have 108 words"
-    );
-    assert_eq!(
-        result
-            .metadata
-            .additional
-            .get("character_count")
-            .and_then(|v| v.as_i64()),
-        Some(620),
-        "Should have 620 characters"
-    );
-    assert_eq!(
-        result.metadata.additional.get("line_count").and_then(|v| v.as_i64()),
-        Some(5),
-        "Should have 5 lines"
-    );
-    assert_eq!(
-        result
-            .metadata
-            .additional
-            .get("paragraph_count")
-            .and_then(|v| v.as_i64()),
-        Some(1),
-        "Should have 1 paragraph"
-    );
+    let office = office_metadata(&result.metadata);
+    assert_eq!(office.revision.as_deref(), Some("7"), "Should have revision number");
+    assert_eq!(office.page_count, Some(2), "Should have 2 pages");
+    assert_eq!(office.word_count, Some(108), "Should have 108 words");
+    assert_eq!(office.character_count, Some(620), "Should have 620 characters");
+    assert_eq!(office.line_count, Some(5), "Should have 5 lines");
+    assert_eq!(office.paragraph_count, Some(1), "Should have 1 paragraph");
+
+    let json = serde_json::to_value(&result.metadata).expect("Metadata should serialize");
+    assert_eq!(json["format_type"], "office");
+    assert_eq!(json["created_by"], "Christoph Auer");
+    assert_eq!(json["word_count"], 108);
 
     println!("✅ DOCX metadata extraction test passed!");
-    println!("   Found {} metadata fields", result.metadata.additional.len());
 }
 
 #[tokio::test]
@@ -107,16 +92,9 @@ async fn test_docx_minimal_metadata_extraction() {
 
     assert!(!result.content.is_empty(), "Content should not be empty");
 
-    assert_eq!(
-        result.metadata.additional.get("page_count").and_then(|v| v.as_i64()),
-        Some(1),
-        "Should have 1 page"
-    );
-    assert_eq!(
-        result.metadata.additional.get("word_count").and_then(|v| v.as_i64()),
-        Some(520),
-        "Should have 520 words"
-    );
+    let office = office_metadata(&result.metadata);
+    assert_eq!(office.page_count, Some(1), "Should have 1 page");
+    assert_eq!(office.word_count, Some(520), "Should have 520 words");
 
     println!("✅ DOCX minimal metadata extraction test passed!");
 }
@@ -5,7 +5,7 @@
 
 #![cfg(feature = "email")]
 
-use kreuzberg::core::config::ExtractionConfig;
+use kreuzberg::core::config::{EmailConfig, ExtractionConfig};
 use kreuzberg::core::extractor::extract_bytes;
 
 mod helpers;
@@ -98,6 +98,91 @@ Attachment content here.\r\n\
     assert!(result.content.contains("Email body text") || result.content.contains("Attachment content"));
 }
 
+/// Test recursive extraction of attachments as child documents.
+#[tokio::test]
+async fn test_eml_attachments_extracted_as_children() {
+    let eml_content = b"From: sender@example.com\r\n\
+To: recipient@example.com\r\n\
+Subject: Email with Attachment\r\n\
+Message-ID: <parent@example.com>\r\n\
+Content-Type: multipart/mixed; boundary=\"----boundary\"\r\n\
+\r\n\
+------boundary\r\n\
+Content-Type: text/plain\r\n\
+\r\n\
+Email body text.\r\n\
+------boundary\r\n\
+Content-Type: application/octet-stream; name=\"notes.txt\"\r\n\
+Content-Disposition: attachment; filename=\"notes.txt\"\r\n\
+\r\n\
+Attachment content here.\r\n\
+------boundary--\r\n";
+
+    let result = extract_bytes(eml_content, "message/rfc822", &ExtractionConfig::default())
+        .await
+        .expect("Should extract EML with attachment");
+    assert!(result.children.is_none(), "Attachments are only listed by default");
+
+    let config = ExtractionConfig {
+        email: Some(EmailConfig {
+            extract_attachments: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let result = extract_bytes(eml_content, "message/rfc822", &config)
+        .await
+        .expect("Should extract EML with attachment");
+
+    let children = result.children.expect("Attachments should be extracted");
+    assert_eq!(children.len(), 1);
+    assert_eq!(children[0].mime_type, "text/plain");
+    assert!(children[0].content.contains("Attachment content here"));
+
+    let parent = &children[0].metadata.additional["parent"];
+    assert_eq!(parent["filename"], "notes.txt");
+    assert_eq!(parent["attachment_index"], 0);
+    assert_eq!(parent["mime_type"], "message/rfc822");
+    assert!(result.warnings.is_empty());
+}
+
+/// Test oversized attachments are skipped and reported as warnings.
+#[tokio::test]
+async fn test_eml_oversized_attachment_reported_as_warning() {
+    let eml_content = b"From: sender@example.com\r\n\
+To: recipient@example.com\r\n\
+Subject: Email with Attachment\r\n\
+Content-Type: multipart/mixed; boundary=\"----boundary\"\r\n\
+\r\n\
+------boundary\r\n\
+Content-Type: text/plain\r\n\
+\r\n\
+Email body text.\r\n\
+------boundary\r\n\
+Content-Type: text/plain; name=\"notes.txt\"\r\n\
+Content-Disposition: attachment; filename=\"notes.txt\"\r\n\
+\r\n\
+Attachment content here.\r\n\
+------boundary--\r\n";
+
+    let config = ExtractionConfig {
+        email: Some(EmailConfig {
+            extract_attachments: true,
+            max_attachment_size: 8,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let result = extract_bytes(eml_content, "message/rfc822", &config)
+        .await
+        .expect("Should extract EML despite the oversized attachment");
+
+    assert_eq!(result.children.map(|children| children.len()), Some(0));
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].code, "attachment_skipped");
+    assert!(result.warnings[0].message.contains("notes.txt"));
+}
+
 /// Test EML with HTML body.
 #[tokio::test]
 async fn test_eml_html_body() {
@@ -136,6 +221,53 @@ Content-Type: text/html; charset=utf-8\r\n\
     assert_eq!(result.metadata.subject, Some("HTML Email".to_string()));
 }
 
+/// Test quoted replies, signatures and tracking pixels are stripped and kept in the metadata.
+#[tokio::test]
+async fn test_eml_strip_quoted_reply_and_tracking_pixel() {
+    let eml_content = b"From: jane@example.com\r\n\
+To: john@example.com\r\n\
+Subject: Re: Budget\r\n\
+Content-Type: text/html; charset=utf-8\r\n\
+\r\n\
+<html><body>\r\n\
+<p>Approved, thanks.</p>\r\n\
+<img src=\"https://track.example.com/open.gif\" width=\"1\" height=\"1\">\r\n\
+<div class=\"gmail_signature\">Jane Doe</div>\r\n\
+<div class=\"gmail_quote\">On Friday John wrote:<blockquote>Please approve the budget.</blockquote></div>\r\n\
+</body></html>";
+
+    let config = ExtractionConfig {
+        email: Some(EmailConfig {
+            strip_quoted_replies: true,
+            strip_signatures: true,
+            strip_tracking_pixels: true,
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let result = extract_bytes(eml_content, "message/rfc822", &config)
+        .await
+        .expect("Should extract HTML email");
+
+    assert!(result.content.contains("Approved, thanks."));
+    assert!(!result.content.contains("Please approve"));
+    assert!(!result.content.contains("Jane Doe"));
+
+    let email_meta = match result.metadata.format.as_ref().unwrap() {
+        kreuzberg::FormatMetadata::Email(meta) => meta,
+        _ => panic!("Expected Email metadata"),
+    };
+    let quoted_text = email_meta.quoted_text.as_deref().unwrap();
+    assert!(quoted_text.contains("Please approve the budget."));
+    assert_eq!(email_meta.signature.as_deref(), Some("Jane Doe"));
+    assert_eq!(email_meta.tracking_pixels, vec!["https://track.example.com/open.gif"]);
+
+    let result = extract_bytes(eml_content, "message/rfc822", &ExtractionConfig::default())
+        .await
+        .expect("Should extract HTML email");
+    assert!(result.content.contains("Please approve the budget."));
+}
+
 /// Test EML with plain text body.
 #[tokio::test]
 async fn test_eml_plain_text_body() {
@@ -625,10 +625,12 @@ fn test_ocr_with_preprocessing_extreme_dpi() {
                     target_dpi: 10000,
                     auto_rotate: true,
                     deskew: true,
+                    grayscale: true,
                     denoise: false,
                     contrast_enhance: false,
                     binarization_method: "otsu".to_string(),
                     invert_colors: false,
+                    remove_borders: false,
                 }),
                 ..Default::default()
             }),
@@ -670,10 +672,12 @@ fn test_ocr_with_invalid_binarization_method() {
                     target_dpi: 300,
                     auto_rotate: true,
                     deskew: true,
+                    grayscale: true,
                     denoise: false,
                     contrast_enhance: false,
                     binarization_method: "invalid_method_xyz".to_string(),
                     invert_colors: false,
+                    remove_borders: false,
                 }),
                 ..Default::default()
             }),
@@ -18,6 +18,7 @@
 
 use kreuzberg::core::config::ExtractionConfig;
 use kreuzberg::core::extractor::extract_file;
+use kreuzberg::types::FormatMetadata;
 use std::path::{Path, PathBuf};
 
 mod helpers;
@@ -69,45 +70,38 @@ async fn test_odt_metadata_extraction() {
         "Should contain document title in content"
     );
 
-    let metadata = &result.metadata.additional;
+    let metadata = &result.metadata;
     println!("Extracted metadata: {:?}", metadata);
 
-    if let Some(title) = metadata.get("title") {
-        assert_eq!(title.as_str(), Some("Test Metadata Document"), "Title should match");
+    if let Some(title) = metadata.title.as_deref() {
+        assert_eq!(title, "Test Metadata Document", "Title should match");
     }
 
-    if let Some(subject) = metadata.get("subject") {
-        assert_eq!(
-            subject.as_str(),
-            Some("Testing ODT Metadata Extraction"),
-            "Subject should match"
-        );
+    if let Some(subject) = metadata.subject.as_deref() {
+        assert_eq!(subject, "Testing ODT Metadata Extraction", "Subject should match");
     }
 
-    if let Some(created_by) = metadata.get("created_by") {
-        assert_eq!(created_by.as_str(), Some("John Doe"), "Creator should match");
+    if let Some(created_by) = metadata.created_by.as_deref() {
+        assert_eq!(created_by, "John Doe", "Creator should match");
     }
 
-    if let Some(authors) = metadata.get("authors") {
-        let authors_array = authors.as_array().expect("Authors should be an array");
-        assert_eq!(authors_array.len(), 1, "Should have one author");
-        assert_eq!(authors_array[0].as_str(), Some("John Doe"), "Author name should match");
+    if let Some(authors) = metadata.authors.as_ref() {
+        assert_eq!(authors.len(), 1, "Should have one author");
+        assert_eq!(authors[0], "John Doe", "Author name should match");
     }
 
-    assert!(metadata.get("created_at").is_some(), "Creation date should be present");
+    assert!(metadata.created_at.is_some(), "Creation date should be present");
 
-    assert!(
-        metadata.get("modified_at").is_some(),
-        "Modification date should be present"
-    );
+    assert!(metadata.modified_at.is_some(), "Modification date should be present");
 
-    if let Some(generator) = metadata.get("generator") {
-        let gen_str = generator.as_str().expect("Generator should be a string");
-        assert!(gen_str.contains("Pandoc"), "Generator should be Pandoc");
+    let Some(FormatMetadata::Office(office)) = metadata.format.as_ref() else {
+        panic!("ODT metadata should include an office section");
+    };
+    if let Some(generator) = office.application.as_deref() {
+        assert!(generator.contains("Pandoc"), "Generator should be Pandoc");
     }
 
     println!("✅ ODT metadata extraction test passed!");
-    println!("   Metadata fields extracted: {}", metadata.len());
 }
 
 /// Tests extraction of tables with captions from ODT
@@ -0,0 +1,65 @@
+//! Integration tests for password-protected Office Open XML documents.
+//!
+//! `office/encrypted.docx` is `office/document.docx` encrypted with ECMA-376 agile encryption
+//! (AES-256, SHA-512, spinCount 100000, with data integrity) and the password `kreuzberg`.
+
+#![cfg(feature = "office")]
+
+use kreuzberg::core::config::{ExtractionConfig, OfficeConfig};
+use kreuzberg::{KreuzbergError, extract_file};
+
+mod helpers;
+
+use helpers::{get_test_file_path, skip_if_missing};
+
+fn config_with_passwords(passwords: &[&str]) -> ExtractionConfig {
+    ExtractionConfig {
+        office: Some(OfficeConfig {
+            passwords: Some(passwords.iter().map(|password| password.to_string()).collect()),
+        }),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_encrypted_docx_extracts_with_password() {
+    if skip_if_missing("office/encrypted.docx") || skip_if_missing("office/document.docx") {
+        return;
+    }
+
+    let plain = extract_file(
+        get_test_file_path("office/document.docx"),
+        None,
+        &ExtractionConfig::default(),
+    )
+    .await
+    .expect("Should extract the unencrypted DOCX");
+    let encrypted = extract_file(
+        get_test_file_path("office/encrypted.docx"),
+        None,
+        &config_with_passwords(&["wrong", "kreuzberg"]),
+    )
+    .await
+    .expect("Should decrypt and extract the encrypted DOCX");
+
+    assert!(!encrypted.content.trim().is_empty());
+    assert_eq!(encrypted.content, plain.content);
+}
+
+#[tokio::test]
+async fn test_encrypted_docx_without_matching_password_fails() {
+    if skip_if_missing("office/encrypted.docx") {
+        return;
+    }
+
+    let path = get_test_file_path("office/encrypted.docx");
+    let err = extract_file(&path, None, &config_with_passwords(&["wrong"]))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, KreuzbergError::EncryptedDocument(_)), "got {:?}", err);
+
+    let err = extract_file(&path, None, &ExtractionConfig::default())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("office.passwords"), "got {}", err);
+}
@@ -10,7 +10,7 @@ use kreuzberg::core::config::{ExtractionConfig, PostProcessorConfig};
 use kreuzberg::core::pipeline::run_pipeline;
 use kreuzberg::plugins::registry::get_post_processor_registry;
 use kreuzberg::plugins::{Plugin, PostProcessor, ProcessingStage};
-use kreuzberg::types::{ExtractionResult, Metadata};
+use kreuzberg::types::{ExtractionResult, Metadata, WarningStage};
 use kreuzberg::{KreuzbergError, Result};
 use serial_test::serial;
 use std::sync::Arc;
@@ -139,6 +139,11 @@ async fn test_pipeline_empty_no_processors() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -184,6 +189,11 @@ async fn test_pipeline_single_processor_per_stage() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -229,6 +239,11 @@ async fn test_pipeline_multiple_processors_per_stage() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -265,6 +280,11 @@ async fn test_pipeline_all_stages_enabled() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -299,12 +319,22 @@ async fn test_pipeline_postprocessing_disabled() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
             enabled: false,
             enabled_processors: None,
             disabled_processors: None,
+            header_footer: None,
+            line_repair: None,
+            normalization: None,
+            metrics: None,
+            taggers: None,
         }),
         ..Default::default()
     };
@@ -346,6 +376,11 @@ async fn test_pipeline_early_stage_runs_first() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -386,6 +421,11 @@ async fn test_pipeline_middle_stage_runs_second() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -422,6 +462,11 @@ async fn test_pipeline_late_stage_runs_last() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -458,6 +503,11 @@ async fn test_pipeline_within_stage_priority_order() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -523,6 +573,11 @@ async fn test_pipeline_cross_stage_data_flow() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -580,6 +635,11 @@ async fn test_pipeline_early_stage_error_recorded() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -622,6 +682,11 @@ async fn test_pipeline_middle_stage_error_propagation() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -694,6 +759,11 @@ async fn test_pipeline_late_stage_error_doesnt_affect_earlier_stages() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -782,6 +852,11 @@ async fn test_pipeline_processor_error_doesnt_stop_other_processors() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -860,6 +935,11 @@ async fn test_pipeline_multiple_processor_errors() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -902,6 +982,11 @@ async fn test_pipeline_error_context_preservation() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -916,6 +1001,74 @@ async fn test_pipeline_error_context_preservation() {
     }
 }
 
+#[tokio::test]
+#[serial]
+async fn test_pipeline_non_fatal_error_reported_as_warning() {
+    clear_processor_registry();
+
+    struct ValidationFailingProcessor;
+    impl Plugin for ValidationFailingProcessor {
+        fn name(&self) -> &str {
+            "validation-failing"
+        }
+        fn version(&self) -> String {
+            "1.0.0".to_string()
+        }
+        fn initialize(&self) -> Result<()> {
+            Ok(())
+        }
+        fn shutdown(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+    #[async_trait]
+    impl PostProcessor for ValidationFailingProcessor {
+        async fn process(&self, _: &mut ExtractionResult, _: &ExtractionConfig) -> Result<()> {
+            Err(KreuzbergError::validation("content rejected"))
+        }
+        fn processing_stage(&self) -> ProcessingStage {
+            ProcessingStage::Middle
+        }
+    }
+
+    let registry = get_post_processor_registry();
+    {
+        let mut reg = registry
+            .write()
+            .expect("Failed to acquire write lock on registry in test");
+        reg.register(Arc::new(ValidationFailingProcessor), 50).unwrap();
+    }
+
+    let result = ExtractionResult {
+        content: "content".to_string(),
+        mime_type: "text/plain".to_string(),
+        metadata: Metadata::default(),
+        tables: vec![],
+        detected_languages: None,
+        chunks: None,
+        images: None,
+        pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
+    };
+    let config = ExtractionConfig::default();
+
+    let result = run_pipeline(result, &config).await.unwrap();
+    assert!(
+        result
+            .metadata
+            .additional
+            .contains_key("processing_error_validation-failing")
+    );
+    assert_eq!(result.warnings.len(), 1);
+    assert_eq!(result.warnings[0].code, "post_processor_failed");
+    assert_eq!(result.warnings[0].stage, WarningStage::PostProcessing);
+    assert!(result.warnings[0].message.contains("validation-failing"));
+}
+
 #[tokio::test]
 #[serial]
 async fn test_pipeline_metadata_added_in_early_visible_in_middle() {
@@ -974,6 +1127,11 @@ async fn test_pipeline_metadata_added_in_early_visible_in_middle() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -1045,6 +1203,11 @@ async fn test_pipeline_content_modified_in_middle_visible_in_late() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -1114,6 +1277,11 @@ async fn test_pipeline_multiple_processors_modifying_same_metadata() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -1202,6 +1370,11 @@ async fn test_pipeline_processors_reading_previous_output() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -1257,6 +1430,11 @@ async fn test_pipeline_large_content_modification() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -1293,12 +1471,22 @@ async fn test_pipeline_enabled_processors_whitelist() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
             enabled: true,
             enabled_processors: Some(vec!["proc1".to_string(), "proc3".to_string()]),
             disabled_processors: None,
+            header_footer: None,
+            line_repair: None,
+            normalization: None,
+            metrics: None,
+            taggers: None,
         }),
         ..Default::default()
     };
@@ -1338,12 +1526,22 @@ async fn test_pipeline_disabled_processors_blacklist() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
             enabled: true,
             enabled_processors: None,
             disabled_processors: Some(vec!["proc2".to_string()]),
+            header_footer: None,
+            line_repair: None,
+            normalization: None,
+            metrics: None,
+            taggers: None,
         }),
         ..Default::default()
     };
@@ -1383,6 +1581,11 @@ async fn test_pipeline_no_filtering_runs_all() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig::default();
 
@@ -1421,12 +1624,22 @@ async fn test_pipeline_empty_whitelist_runs_none() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
     let config = ExtractionConfig {
         postprocessor: Some(PostProcessorConfig {
             enabled: true,
             enabled_processors: Some(vec![]),
             disabled_processors: None,
+            header_footer: None,
+            line_repair: None,
+            normalization: None,
+            metrics: None,
+            taggers: None,
         }),
         ..Default::default()
     };
@@ -59,6 +59,11 @@ impl DocumentExtractor for FailingExtractor {
                 chunks: None,
                 images: None,
                 pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             })
         }
     }
@@ -304,6 +309,11 @@ fn test_extractor_priority_ordering_complex() {
                 chunks: None,
                 images: None,
                 pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             })
         }
         fn supported_mime_types(&self) -> &[&str] {
@@ -464,6 +474,11 @@ async fn test_processor_execution_order_within_stage() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
 
     let config = ExtractionConfig::default();
@@ -496,6 +511,11 @@ async fn test_processor_error_propagation() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
 
     let config = ExtractionConfig::default();
@@ -668,6 +688,11 @@ async fn test_validator_content_validation() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
 
     let validation = validators[0].validate(&short_result, &config).await;
@@ -682,6 +707,11 @@ async fn test_validator_content_validation() {
         chunks: None,
         images: None,
         pages: None,
+        entities: None,
+        keywords: None,
+        children: None,
+        fields: None,
+        warnings: Vec::new(),
     };
 
     let validation = validators[0].validate(&long_result, &config).await;
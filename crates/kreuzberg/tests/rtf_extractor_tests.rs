@@ -33,6 +33,7 @@
 
 use kreuzberg::core::config::ExtractionConfig;
 use kreuzberg::core::extractor::extract_file;
+use kreuzberg::types::FormatMetadata;
 use std::path::PathBuf;
 
 mod helpers;
@@ -618,14 +619,28 @@ async fn test_rtf_word_sample_matches_docx_metadata_and_content() {
         "RTF content should include the same body text as DOCX"
     );
 
-    for key in ["created_by", "modified_by", "created_at", "revision"] {
-        assert_eq!(
-            rtf_result.metadata.additional.get(key).and_then(|v| v.as_str()),
-            docx_result.metadata.additional.get(key).and_then(|v| v.as_str()),
-            "Metadata field {} should align with DOCX",
-            key
-        );
-    }
+    assert_eq!(
+        rtf_result.metadata.created_by, docx_result.metadata.created_by,
+        "Metadata field created_by should align with DOCX"
+    );
+    assert_eq!(
+        rtf_result.metadata.modified_by, docx_result.metadata.modified_by,
+        "Metadata field modified_by should align with DOCX"
+    );
+    assert_eq!(
+        rtf_result.metadata.created_at, docx_result.metadata.created_at,
+        "Metadata field created_at should align with DOCX"
+    );
+    let Some(FormatMetadata::Office(docx_office)) = docx_result.metadata.format.as_ref() else {
+        panic!("DOCX metadata should include an office section");
+    };
+    let Some(FormatMetadata::Office(rtf_office)) = rtf_result.metadata.format.as_ref() else {
+        panic!("RTF metadata should include an office section");
+    };
+    assert_eq!(
+        rtf_office.revision, docx_office.revision,
+        "Metadata field revision should align with DOCX"
+    );
 
     for (key, expected) in [
         ("page_count", 2),
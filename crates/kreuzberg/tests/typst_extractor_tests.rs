@@ -63,12 +63,12 @@ async fn test_simple_typst_document_extraction() {
     assert!(!extraction.content.is_empty(), "Extracted content should not be empty");
 
     assert!(
-        extraction.metadata.additional.contains_key("title"),
+        extraction.metadata.title.is_some(),
         "Document title should be extracted from #set document()"
     );
 
     assert!(
-        extraction.metadata.additional.contains_key("author"),
+        extraction.metadata.authors.is_some(),
         "Document author should be extracted"
     );
 
@@ -271,22 +271,22 @@ async fn test_metadata_extraction() {
 
     let extraction = result.unwrap();
 
-    if let Some(title) = extraction.metadata.additional.get("title") {
+    if let Some(title) = &extraction.metadata.title {
         assert!(
-            title.to_string().contains("Metadata") || title.to_string().contains("Example"),
+            title.contains("Metadata") || title.contains("Example"),
             "Title should contain expected text"
         );
     }
 
-    if let Some(author) = extraction.metadata.additional.get("author") {
+    if let Some(authors) = &extraction.metadata.authors {
         assert!(
-            author.to_string().contains("John") || author.to_string().contains("Doe"),
+            authors.iter().any(|a| a.contains("John") || a.contains("Doe")),
             "Author should contain expected text"
         );
     }
 
-    if let Some(keywords) = extraction.metadata.additional.get("keywords") {
-        assert!(!keywords.to_string().is_empty(), "Keywords should be present");
+    if let Some(keywords) = &extraction.metadata.keywords {
+        assert!(!keywords.is_empty(), "Keywords should be present");
     }
 
     assert!(!extraction.content.is_empty(), "Document should extract content");
@@ -332,10 +332,7 @@ async fn test_advanced_typst_document_extraction() {
 
     let extraction = result.unwrap();
 
-    assert!(
-        extraction.metadata.additional.contains_key("title"),
-        "Title should be extracted"
-    );
+    assert!(extraction.metadata.title.is_some(), "Title should be extracted");
 
     assert!(
         !extraction.content.is_empty(),
@@ -461,8 +458,8 @@ async fn test_undergradmath_extraction() {
         "Should extract content from complex math document"
     );
 
-    if let Some(title) = extraction.metadata.additional.get("title") {
-        assert!(!title.to_string().is_empty(), "Title should be extracted");
+    if let Some(title) = &extraction.metadata.title {
+        assert!(!title.is_empty(), "Title should be extracted");
     }
 
     assert!(
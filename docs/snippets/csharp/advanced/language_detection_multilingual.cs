@@ -18,7 +18,7 @@ class Program
         {
             var result = await KreuzbergClient.ExtractFileAsync("multilingual_document.pdf", config);
 
-            var languages = result.DetectedLanguages ?? new List<string>();
+            var languages = result.DetectedLanguages ?? new List<DetectedLanguage>();
 
             if (languages.Count > 0)
             {
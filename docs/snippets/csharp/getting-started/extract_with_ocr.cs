@@ -13,4 +13,4 @@ var config = new ExtractionConfig
 var result = KreuzbergClient.ExtractFileSync("scanned.pdf", config);
 
 Console.WriteLine(result.Content);
-Console.WriteLine($"Detected Languages: {string.Join(", ", result.DetectedLanguages ?? new List<string>())}");
+Console.WriteLine($"Detected Languages: {string.Join(", ", result.DetectedLanguages ?? new List<DetectedLanguage>())}");
@@ -38,7 +38,7 @@ var multiResult = KreuzbergClient.ExtractFileSync("multilingual_document.pdf", m
 Console.WriteLine("Detected Languages:");
 foreach (var lang in multiResult.DetectedLanguages)
 {
-    Console.WriteLine($"  - {lang}");
+    Console.WriteLine($"  - {lang.Language} ({lang.Confidence:F2}, {lang.Script})");
 }
 
 // Language detection with metadata
@@ -13,7 +13,7 @@ async function extractMetadata() {
   console.log("Metadata:", result.metadata);
 
   if (result.detectedLanguages) {
-    console.log("Detected Languages:", result.detectedLanguages.join(", "));
+    console.log("Detected Languages:", result.detectedLanguages.map((lang) => lang.language).join(", "));
   }
 
   if (result.metadata.pageCount) {
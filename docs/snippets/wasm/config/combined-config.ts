@@ -28,7 +28,7 @@ async function extractWithFullConfig() {
   console.log(`Chunks: ${result.chunks?.length ?? 0}`);
   console.log(`Images: ${result.images?.length ?? 0}`);
   console.log(`Tables: ${result.tables.length}`);
-  console.log(`Languages: ${result.detectedLanguages?.join(", ")}`);
+  console.log(`Languages: ${result.detectedLanguages?.map((lang) => lang.language).join(", ")}`);
 }
 
 extractWithFullConfig().catch(console.error);
@@ -23,7 +23,7 @@ async function filterAndSummarizeMetadata(files: string[]): Promise<DocumentSumm
       title: result.metadata.title,
       author: result.metadata.author,
       pageCount: result.metadata.pageCount,
-      language: result.detectedLanguages?.[0] ?? "unknown",
+      language: result.detectedLanguages?.[0]?.language ?? "unknown",
     });
   }
 
@@ -24,6 +24,7 @@ func TestDiscover(t *testing.T) {
 
 	if err := os.WriteFile(configPath, []byte(`[chunking]
 max_chars = 50
+max_overlap = 10
 `), 0644); err != nil {
 		t.Fatalf("Failed to write config file: %v", err)
 	}
@@ -113,14 +113,15 @@ def assert_detected_languages(result: Any, expected: list[str], min_confidence:
     if languages is None:
         pytest.fail("Expected detected languages but field is None")
 
-    missing = [lang for lang in expected if lang not in languages]
+    detected = {lang["language"]: lang["confidence"] for lang in languages}
+    missing = [lang for lang in expected if lang not in detected]
     if missing:
         pytest.fail(f"Expected languages {expected!r}, missing {missing!r}")
 
     if min_confidence is not None:
-        confidence = result.metadata.get("confidence") if isinstance(result.metadata, Mapping) else None
-        if confidence is not None and confidence < min_confidence:
-            pytest.fail(f"Expected confidence >= {min_confidence}, got {confidence}")
+        low = {lang: detected[lang] for lang in expected if detected[lang] < min_confidence}
+        if low:
+            pytest.fail(f"Expected confidence >= {min_confidence}, got {low!r}")
 
 
 def assert_metadata_expectation(result: Any, path: str, expectation: dict[str, Any]) -> None:
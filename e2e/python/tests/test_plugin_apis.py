@@ -21,6 +21,7 @@ def test_config_discover(tmp_path: Path, monkeypatch) -> None:
     config_path = tmp_path / "kreuzberg.toml"
     config_path.write_text("""[chunking]
 max_chars = 50
+max_overlap = 10
 """)
 
     subdir = tmp_path / "subdir"
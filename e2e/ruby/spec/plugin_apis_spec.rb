@@ -20,6 +20,7 @@ RSpec.describe 'Configuration' do
       File.write(config_path, <<~TOML)
         [chunking]
         max_chars = 50
+        max_overlap = 10
       TOML
 
       subdir = File.join(tmpdir, 'subdir')
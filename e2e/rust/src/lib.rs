@@ -122,21 +122,17 @@ pub mod assertions {
         };
 
         for lang in expected {
-            assert!(
-                languages.iter().any(|detected| detected == lang),
-                "Expected detected languages to contain {lang}, got {:?}",
-                languages
-            );
-        }
+            let Some(detected) = languages.iter().find(|detected| detected.language == *lang) else {
+                panic!("Expected detected languages to contain {lang}, got {:?}", languages);
+            };
 
-        if let Some(threshold) = min_confidence
-            && let Ok(Value::Object(map)) = serde_json::to_value(&result.metadata)
-            && let Some(confidence) = map.get("confidence").and_then(Value::as_f64)
-        {
-            assert!(
-                (confidence as f32) >= threshold,
-                "Expected confidence >= {threshold}, got {confidence}"
-            );
+            if let Some(threshold) = min_confidence {
+                assert!(
+                    detected.confidence >= f64::from(threshold),
+                    "Expected confidence >= {threshold} for {lang}, got {}",
+                    detected.confidence
+                );
+            }
         }
     }
 
@@ -15,6 +15,7 @@ fn test_config_discover() {
         &config_path,
         r#"[chunking]
 max_chars = 50
+max_overlap = 10
 "#,
     )
     .expect("Failed to write config file");
@@ -15,7 +15,7 @@ describe("Configuration", () => {
 	it("Discover configuration from current or parent directories", () => {
 		const tmpDir = fs.mkdtempSync(path.join(os.tmpdir(), "kreuzberg-test-"));
 		const configPath = path.join(tmpDir, "kreuzberg.toml");
-		fs.writeFileSync(configPath, "[chunking]\nmax_chars = 50\n");
+		fs.writeFileSync(configPath, "[chunking]\nmax_chars = 50\nmax_overlap = 10\n");
 
 		const subDir = path.join(tmpDir, "subdir");
 		fs.mkdirSync(subDir);
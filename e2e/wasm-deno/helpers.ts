@@ -120,6 +120,8 @@ function mapTokenReductionConfig(raw: PlainRecord): TokenReductionConfig {
 		config.mode = raw.mode as string;
 	}
 	assignBooleanField(config as PlainRecord, raw, "preserve_important_words", "preserveImportantWords");
+	assignNumberField(config as PlainRecord, raw, "target_ratio", "targetRatio");
+	assignNumberField(config as PlainRecord, raw, "max_tokens", "maxTokens");
 	return config;
 }
 
@@ -128,6 +130,7 @@ function mapLanguageDetectionConfig(raw: PlainRecord): LanguageDetectionConfig {
 	assignBooleanField(config as PlainRecord, raw, "enabled", "enabled");
 	assignNumberField(config as PlainRecord, raw, "min_confidence", "minConfidence");
 	assignBooleanField(config as PlainRecord, raw, "detect_multiple", "detectMultiple");
+	assignNumberField(config as PlainRecord, raw, "max_sample_kb", "maxSampleKb");
 	return config;
 }
 
@@ -284,14 +287,13 @@ export const assertions = {
 		assertExists(result.detectedLanguages);
 		const languages = result.detectedLanguages ?? [];
 		assertEquals(
-			expected.every((lang) => languages.includes(lang)),
+			expected.every((lang) => languages.some((detected) => detected.language === lang)),
 			true,
 		);
 
-		if (typeof minConfidence === "number" && isPlainRecord(result.metadata)) {
-			const confidence = (result.metadata as PlainRecord).confidence;
-			if (typeof confidence === "number") {
-				assertEquals(confidence >= minConfidence, true);
+		if (typeof minConfidence === "number") {
+			for (const detected of languages.filter((lang) => expected.includes(lang.language))) {
+				assertEquals(detected.confidence >= minConfidence, true);
 			}
 		}
 	},
@@ -102,7 +102,8 @@ class LanguageTranslator:
     def process(self, result: ExtractionResult) -> ExtractionResult:
         """Translate content to target language."""
         result.metadata["translated_to"] = self.target_language
-        result.metadata["original_language"] = result.detected_languages[0] if result.detected_languages else "unknown"
+        languages = result.detected_languages
+        result.metadata["original_language"] = languages[0]["language"] if languages else "unknown"
 
         return result
 
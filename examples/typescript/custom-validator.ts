@@ -120,7 +120,7 @@ class LanguageValidator implements ValidatorProtocol {
 			throw new Error("ValidationError: No languages detected in content");
 		}
 
-		const primaryLanguage = result.detectedLanguages[0];
+		const primaryLanguage = result.detectedLanguages[0]?.language;
 		if (primaryLanguage && !this.allowedLanguages.includes(primaryLanguage)) {
 			throw new Error(
 				`ValidationError: Detected language '${primaryLanguage}' not in allowed list: ${this.allowedLanguages.join(", ")}`,
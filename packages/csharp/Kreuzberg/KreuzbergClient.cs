@@ -1064,7 +1064,7 @@ public static class KreuzbergClient
             };
 
             result.Tables = DeserializeField<List<Table>>(cRes.TablesJson) ?? new List<Table>();
-            result.DetectedLanguages = DeserializeField<List<string>>(cRes.DetectedLanguagesJson);
+            result.DetectedLanguages = DeserializeField<List<DetectedLanguage>>(cRes.DetectedLanguagesJson);
             result.Metadata = Serialization.ParseMetadata(InteropUtilities.ReadUtf8(cRes.MetadataJson));
             result.Chunks = DeserializeField<List<Chunk>>(cRes.ChunksJson);
             result.Images = DeserializeField<List<ExtractedImage>>(cRes.ImagesJson);
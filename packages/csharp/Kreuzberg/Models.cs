@@ -34,10 +34,10 @@ public sealed class ExtractionResult
     public List<Table> Tables { get; set; } = new();
 
     /// <summary>
-    /// Detected languages in the document, if language detection was enabled.
+    /// Detected languages in the document, most prominent first, if language detection was enabled.
     /// </summary>
     [JsonPropertyName("detected_languages")]
-    public List<string>? DetectedLanguages { get; set; }
+    public List<DetectedLanguage>? DetectedLanguages { get; set; }
 
     /// <summary>
     /// Text chunks if chunking was enabled, each with metadata and optional embedding vector.
@@ -58,6 +58,35 @@ public sealed class ExtractionResult
     public bool Success { get; set; }
 }
 
+/// <summary>
+/// A language detected in the extracted content.
+/// </summary>
+public sealed class DetectedLanguage
+{
+    /// <summary>
+    /// ISO 639-3 language code (e.g. "eng", "deu").
+    /// </summary>
+    [JsonPropertyName("language")]
+    public string Language { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Detection confidence (0.0-1.0).
+    /// </summary>
+    [JsonPropertyName("confidence")]
+    public double Confidence { get; set; }
+
+    /// <summary>
+    /// Writing system the language was detected in (e.g. "Latin", "Cyrillic").
+    /// </summary>
+    [JsonPropertyName("script")]
+    public string Script { get; set; } = string.Empty;
+
+    /// <summary>
+    /// Returns the language code.
+    /// </summary>
+    public override string ToString() => Language;
+}
+
 /// <summary>
 /// Represents a table extracted from a document.
 /// </summary>
@@ -150,6 +179,9 @@ public sealed class ExtractedImage
     [JsonPropertyName("height")]
     public uint? Height { get; set; }
 
+    [JsonPropertyName("dpi")]
+    public uint? Dpi { get; set; }
+
     [JsonPropertyName("colorspace")]
     public string? Colorspace { get; set; }
 
@@ -781,6 +813,9 @@ public sealed class ImagePreprocessingConfig
     [JsonPropertyName("deskew")]
     public bool? Deskew { get; set; }
 
+    [JsonPropertyName("grayscale")]
+    public bool? Grayscale { get; set; }
+
     [JsonPropertyName("denoise")]
     public bool? Denoise { get; set; }
 
@@ -792,6 +827,9 @@ public sealed class ImagePreprocessingConfig
 
     [JsonPropertyName("invert_colors")]
     public bool? InvertColors { get; set; }
+
+    [JsonPropertyName("remove_borders")]
+    public bool? RemoveBorders { get; set; }
 }
 
 public sealed class ChunkingConfig
@@ -858,6 +896,12 @@ public sealed class TokenReductionConfig
 
     [JsonPropertyName("preserve_important_words")]
     public bool? PreserveImportantWords { get; set; }
+
+    [JsonPropertyName("target_ratio")]
+    public double? TargetRatio { get; set; }
+
+    [JsonPropertyName("max_tokens")]
+    public int? MaxTokens { get; set; }
 }
 
 public sealed class LanguageDetectionConfig
@@ -870,6 +914,9 @@ public sealed class LanguageDetectionConfig
 
     [JsonPropertyName("detect_multiple")]
     public bool? DetectMultiple { get; set; }
+
+    [JsonPropertyName("max_sample_kb")]
+    public int? MaxSampleKb { get; set; }
 }
 
 public sealed class PostProcessorConfig
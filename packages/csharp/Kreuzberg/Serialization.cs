@@ -137,7 +137,7 @@ internal static class Serialization
 
         if (root.TryGetProperty("detected_languages", out var langs))
         {
-            result.DetectedLanguages = DeserializeElement<List<string>>(langs);
+            result.DetectedLanguages = DeserializeElement<List<DetectedLanguage>>(langs);
         }
 
         if (root.TryGetProperty("chunks", out var chunks))
@@ -77,10 +77,12 @@ type ImagePreprocessingConfig struct {
 	TargetDPI        *int   `json:"target_dpi,omitempty"`
 	AutoRotate       *bool  `json:"auto_rotate,omitempty"`
 	Deskew           *bool  `json:"deskew,omitempty"`
+	Grayscale        *bool  `json:"grayscale,omitempty"`
 	Denoise          *bool  `json:"denoise,omitempty"`
 	ContrastEnhance  *bool  `json:"contrast_enhance,omitempty"`
 	BinarizationMode string `json:"binarization_method,omitempty"`
 	InvertColors     *bool  `json:"invert_colors,omitempty"`
+	RemoveBorders    *bool  `json:"remove_borders,omitempty"`
 }
 
 // ChunkingConfig configures text chunking for downstream RAG/Retrieval workloads.
@@ -113,8 +115,10 @@ type PdfConfig struct {
 
 // TokenReductionConfig governs token pruning before embeddings.
 type TokenReductionConfig struct {
-	Mode                   string `json:"mode,omitempty"`
-	PreserveImportantWords *bool  `json:"preserve_important_words,omitempty"`
+	Mode                   string   `json:"mode,omitempty"`
+	PreserveImportantWords *bool    `json:"preserve_important_words,omitempty"`
+	TargetRatio            *float64 `json:"target_ratio,omitempty"`
+	MaxTokens              *int     `json:"max_tokens,omitempty"`
 }
 
 // LanguageDetectionConfig enables automatic language detection.
@@ -122,6 +126,7 @@ type LanguageDetectionConfig struct {
 	Enabled        *bool    `json:"enabled,omitempty"`
 	MinConfidence  *float64 `json:"min_confidence,omitempty"`
 	DetectMultiple *bool    `json:"detect_multiple,omitempty"`
+	MaxSampleKB    *int     `json:"max_sample_kb,omitempty"`
 }
 
 // PostProcessorConfig determines which post processors run.
@@ -406,13 +406,16 @@ func TestMimeDetectionFromPath(t *testing.T) {
 func TestEncodingDetectionInMetadata(t *testing.T) {
 	result := &ExtractionResult{
 		Content:           "test",
-		DetectedLanguages: []string{"en", "fr"},
+		DetectedLanguages: []DetectedLanguage{
+			{Language: "eng", Confidence: 0.92, Script: "Latin"},
+			{Language: "fra", Confidence: 0.41, Script: "Latin"},
+		},
 	}
 	if len(result.DetectedLanguages) != 2 {
 		t.Fatalf("expected 2 detected languages, got %d", len(result.DetectedLanguages))
 	}
-	if result.DetectedLanguages[0] != "en" {
-		t.Fatalf("expected first language 'en', got '%s'", result.DetectedLanguages[0])
+	if result.DetectedLanguages[0].Language != "eng" {
+		t.Fatalf("expected first language 'eng', got '%s'", result.DetectedLanguages[0].Language)
 	}
 }
 
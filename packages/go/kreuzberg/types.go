@@ -4,15 +4,22 @@ import "encoding/json"
 
 // ExtractionResult mirrors the Rust ExtractionResult struct returned by the core API.
 type ExtractionResult struct {
-	Content           string           `json:"content"`
-	MimeType          string           `json:"mime_type"`
-	Metadata          Metadata         `json:"metadata"`
-	Tables            []Table          `json:"tables"`
-	DetectedLanguages []string         `json:"detected_languages,omitempty"`
-	Chunks            []Chunk          `json:"chunks,omitempty"`
-	Images            []ExtractedImage `json:"images,omitempty"`
-	Pages             []PageContent    `json:"pages,omitempty"`
-	Success           bool             `json:"success"`
+	Content           string             `json:"content"`
+	MimeType          string             `json:"mime_type"`
+	Metadata          Metadata           `json:"metadata"`
+	Tables            []Table            `json:"tables"`
+	DetectedLanguages []DetectedLanguage `json:"detected_languages,omitempty"`
+	Chunks            []Chunk            `json:"chunks,omitempty"`
+	Images            []ExtractedImage   `json:"images,omitempty"`
+	Pages             []PageContent      `json:"pages,omitempty"`
+	Success           bool               `json:"success"`
+}
+
+// DetectedLanguage is a language found in the content with its confidence and script.
+type DetectedLanguage struct {
+	Language   string  `json:"language"`
+	Confidence float64 `json:"confidence"`
+	Script     string  `json:"script"`
 }
 
 // Table represents a detected table in the source document.
@@ -48,6 +55,7 @@ type ExtractedImage struct {
 	PageNumber       *int              `json:"page_number,omitempty"`
 	Width            *uint32           `json:"width,omitempty"`
 	Height           *uint32           `json:"height,omitempty"`
+	DPI              *uint32           `json:"dpi,omitempty"`
 	Colorspace       *string           `json:"colorspace,omitempty"`
 	BitsPerComponent *uint32           `json:"bits_per_component,omitempty"`
 	IsMask           bool              `json:"is_mask"`
@@ -0,0 +1,37 @@
+package dev.kreuzberg;
+
+import com.fasterxml.jackson.annotation.JsonCreator;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import java.util.Objects;
+
+/**
+ * A language detected in the extracted content.
+ *
+ * @param language the ISO 639-3 language code (e.g. "eng", "deu")
+ * @param confidence the detection confidence (0.0-1.0)
+ * @param script the writing system the language was detected in (e.g. "Latin", "Cyrillic")
+ */
+public record DetectedLanguage(
+    @JsonProperty("language") String language,
+    @JsonProperty("confidence") double confidence,
+    @JsonProperty("script") String script
+) {
+    /**
+     * Creates a new DetectedLanguage.
+     *
+     * @param language the ISO 639-3 language code (must not be null)
+     * @param confidence the detection confidence
+     * @param script the writing system (must not be null)
+     * @throws NullPointerException if language or script is null
+     */
+    @JsonCreator
+    public DetectedLanguage(
+        @JsonProperty("language") String language,
+        @JsonProperty("confidence") double confidence,
+        @JsonProperty("script") String script
+    ) {
+        this.language = Objects.requireNonNull(language, "language must not be null");
+        this.confidence = confidence;
+        this.script = Objects.requireNonNull(script, "script must not be null");
+    }
+}
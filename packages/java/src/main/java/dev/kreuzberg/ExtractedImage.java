@@ -16,6 +16,7 @@ public final class ExtractedImage {
     private final Integer pageNumber;
     private final Integer width;
     private final Integer height;
+    private final Integer dpi;
     private final String colorspace;
     private final Integer bitsPerComponent;
     private final boolean mask;
@@ -30,6 +31,7 @@ public final class ExtractedImage {
         @JsonProperty("page_number") Integer pageNumber,
         @JsonProperty("width") Integer width,
         @JsonProperty("height") Integer height,
+        @JsonProperty("dpi") Integer dpi,
         @JsonProperty("colorspace") String colorspace,
         @JsonProperty("bits_per_component") Integer bitsPerComponent,
         @JsonProperty("is_mask") boolean mask,
@@ -42,6 +44,7 @@ public final class ExtractedImage {
         this.pageNumber = pageNumber;
         this.width = width;
         this.height = height;
+        this.dpi = dpi;
         this.colorspace = colorspace;
         this.bitsPerComponent = bitsPerComponent;
         this.mask = mask;
@@ -73,6 +76,10 @@ public final class ExtractedImage {
         return Optional.ofNullable(height);
     }
 
+    public Optional<Integer> getDpi() {
+        return Optional.ofNullable(dpi);
+    }
+
     public Optional<String> getColorspace() {
         return Optional.ofNullable(colorspace);
     }
@@ -109,6 +116,7 @@ public final class ExtractedImage {
             && Objects.equals(pageNumber, other.pageNumber)
             && Objects.equals(width, other.width)
             && Objects.equals(height, other.height)
+            && Objects.equals(dpi, other.dpi)
             && Objects.equals(colorspace, other.colorspace)
             && Objects.equals(bitsPerComponent, other.bitsPerComponent)
             && Objects.equals(description, other.description)
@@ -123,6 +131,7 @@ public final class ExtractedImage {
             pageNumber,
             width,
             height,
+            dpi,
             colorspace,
             bitsPerComponent,
             mask,
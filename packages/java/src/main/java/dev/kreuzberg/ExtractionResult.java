@@ -17,7 +17,7 @@ public final class ExtractionResult {
     private final String mimeType;
     private final Map<String, Object> metadata;
     private final List<Table> tables;
-    private final List<String> detectedLanguages;
+    private final List<DetectedLanguage> detectedLanguages;
     private final List<Chunk> chunks;
     private final List<ExtractedImage> images;
     private final PageStructure pageStructure;
@@ -31,7 +31,7 @@ public final class ExtractionResult {
         String mimeType,
         Map<String, Object> metadata,
         List<Table> tables,
-        List<String> detectedLanguages,
+        List<DetectedLanguage> detectedLanguages,
         List<Chunk> chunks,
         List<ExtractedImage> images,
         PageStructure pageStructure,
@@ -71,7 +71,7 @@ public final class ExtractionResult {
         return tables;
     }
 
-    public List<String> getDetectedLanguages() {
+    public List<DetectedLanguage> getDetectedLanguages() {
         return detectedLanguages;
     }
 
@@ -16,6 +16,7 @@ final class ResultParser {
 
     private static final TypeReference<List<Table>> TABLE_LIST = new TypeReference<>() { };
     private static final TypeReference<List<String>> STRING_LIST = new TypeReference<>() { };
+    private static final TypeReference<List<DetectedLanguage>> DETECTED_LANGUAGE_LIST = new TypeReference<>() { };
     private static final TypeReference<List<Chunk>> CHUNK_LIST = new TypeReference<>() { };
     private static final TypeReference<List<ExtractedImage>> IMAGE_LIST = new TypeReference<>() { };
     private static final TypeReference<Map<String, Object>> METADATA_MAP = new TypeReference<>() { };
@@ -39,7 +40,8 @@ final class ResultParser {
         try {
             Map<String, Object> metadata = decode(metadataJson, METADATA_MAP, Collections.emptyMap());
             List<Table> tables = decode(tablesJson, TABLE_LIST, List.of());
-            List<String> detectedLanguages = decode(detectedLanguagesJson, STRING_LIST, List.of());
+            List<DetectedLanguage> detectedLanguages =
+                decode(detectedLanguagesJson, DETECTED_LANGUAGE_LIST, List.of());
             List<Chunk> chunks = decode(chunksJson, CHUNK_LIST, List.of());
             List<ExtractedImage> images = decode(imagesJson, IMAGE_LIST, List.of());
             PageStructure pageStructure = decode(pageStructureJson, PAGE_STRUCTURE, null);
@@ -128,7 +130,7 @@ final class ResultParser {
         private final String mimeType;
         private final Map<String, Object> metadata;
         private final List<Table> tables;
-        private final List<String> detectedLanguages;
+        private final List<DetectedLanguage> detectedLanguages;
         private final List<Chunk> chunks;
         private final List<ExtractedImage> images;
         private final PageStructure pageStructure;
@@ -139,7 +141,7 @@ final class ResultParser {
             @JsonProperty("mime_type") String mimeType,
             @JsonProperty("metadata") Map<String, Object> metadata,
             @JsonProperty("tables") List<Table> tables,
-            @JsonProperty("detected_languages") List<String> detectedLanguages,
+            @JsonProperty("detected_languages") List<DetectedLanguage> detectedLanguages,
             @JsonProperty("chunks") List<Chunk> chunks,
             @JsonProperty("images") List<ExtractedImage> images,
             @JsonProperty("page_structure") PageStructure pageStructure,
@@ -12,19 +12,23 @@ public final class ImagePreprocessingConfig {
   private final int targetDpi;
   private final boolean autoRotate;
   private final boolean deskew;
+  private final boolean grayscale;
   private final boolean denoise;
   private final boolean contrastEnhance;
   private final String binarizationMethod;
   private final boolean invertColors;
+  private final boolean removeBorders;
 
   private ImagePreprocessingConfig(Builder builder) {
     this.targetDpi = builder.targetDpi;
     this.autoRotate = builder.autoRotate;
     this.deskew = builder.deskew;
+    this.grayscale = builder.grayscale;
     this.denoise = builder.denoise;
     this.contrastEnhance = builder.contrastEnhance;
     this.binarizationMethod = builder.binarizationMethod;
     this.invertColors = builder.invertColors;
+    this.removeBorders = builder.removeBorders;
   }
 
   public static Builder builder() {
@@ -43,6 +47,10 @@ public final class ImagePreprocessingConfig {
     return deskew;
   }
 
+  public boolean isGrayscale() {
+    return grayscale;
+  }
+
   public boolean isDenoise() {
     return denoise;
   }
@@ -59,15 +67,21 @@ public final class ImagePreprocessingConfig {
     return invertColors;
   }
 
+  public boolean isRemoveBorders() {
+    return removeBorders;
+  }
+
   public Map<String, Object> toMap() {
     Map<String, Object> map = new HashMap<>();
     map.put("target_dpi", targetDpi);
     map.put("auto_rotate", autoRotate);
     map.put("deskew", deskew);
+    map.put("grayscale", grayscale);
     map.put("denoise", denoise);
     map.put("contrast_enhance", contrastEnhance);
     map.put("binarization_method", binarizationMethod);
     map.put("invert_colors", invertColors);
+    map.put("remove_borders", removeBorders);
     return map;
   }
 
@@ -75,10 +89,12 @@ public final class ImagePreprocessingConfig {
     private int targetDpi = 300;
     private boolean autoRotate = true;
     private boolean deskew = true;
+    private boolean grayscale = true;
     private boolean denoise = false;
     private boolean contrastEnhance = true;
     private String binarizationMethod = "otsu";
     private boolean invertColors = false;
+    private boolean removeBorders = false;
 
     private Builder() {
     }
@@ -98,6 +114,11 @@ public final class ImagePreprocessingConfig {
       return this;
     }
 
+    public Builder grayscale(boolean grayscale) {
+      this.grayscale = grayscale;
+      return this;
+    }
+
     public Builder denoise(boolean denoise) {
       this.denoise = denoise;
       return this;
@@ -118,6 +139,11 @@ public final class ImagePreprocessingConfig {
       return this;
     }
 
+    public Builder removeBorders(boolean removeBorders) {
+      this.removeBorders = removeBorders;
+      return this;
+    }
+
     public ImagePreprocessingConfig build() {
       return new ImagePreprocessingConfig(this);
     }
@@ -137,6 +163,9 @@ public final class ImagePreprocessingConfig {
     if (map.get("deskew") instanceof Boolean) {
       builder.deskew((Boolean) map.get("deskew"));
     }
+    if (map.get("grayscale") instanceof Boolean) {
+      builder.grayscale((Boolean) map.get("grayscale"));
+    }
     if (map.get("denoise") instanceof Boolean) {
       builder.denoise((Boolean) map.get("denoise"));
     }
@@ -149,6 +178,9 @@ public final class ImagePreprocessingConfig {
     if (map.get("invert_colors") instanceof Boolean) {
       builder.invertColors((Boolean) map.get("invert_colors"));
     }
+    if (map.get("remove_borders") instanceof Boolean) {
+      builder.removeBorders((Boolean) map.get("remove_borders"));
+    }
     return builder.build();
   }
 }
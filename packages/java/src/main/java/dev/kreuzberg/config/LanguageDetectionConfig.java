@@ -12,11 +12,13 @@ public final class LanguageDetectionConfig {
   private final boolean enabled;
   private final double minConfidence;
   private final boolean detectMultiple;
+  private final Integer maxSampleKb;
 
   private LanguageDetectionConfig(Builder builder) {
     this.enabled = builder.enabled;
     this.minConfidence = builder.minConfidence;
     this.detectMultiple = builder.detectMultiple;
+    this.maxSampleKb = builder.maxSampleKb;
   }
 
   public static Builder builder() {
@@ -35,11 +37,21 @@ public final class LanguageDetectionConfig {
     return detectMultiple;
   }
 
+  /**
+   * Only the first {@code maxSampleKb} KiB of text are analyzed; {@code null} analyzes everything.
+   */
+  public Integer getMaxSampleKb() {
+    return maxSampleKb;
+  }
+
   public Map<String, Object> toMap() {
     Map<String, Object> map = new HashMap<>();
     map.put("enabled", enabled);
     map.put("min_confidence", minConfidence);
     map.put("detect_multiple", detectMultiple);
+    if (maxSampleKb != null) {
+      map.put("max_sample_kb", maxSampleKb);
+    }
     return map;
   }
 
@@ -47,6 +59,7 @@ public final class LanguageDetectionConfig {
     private boolean enabled = false;
     private double minConfidence = 0.5;
     private boolean detectMultiple = false;
+    private Integer maxSampleKb;
 
     private Builder() {
     }
@@ -66,6 +79,11 @@ public final class LanguageDetectionConfig {
       return this;
     }
 
+    public Builder maxSampleKb(Integer maxSampleKb) {
+      this.maxSampleKb = maxSampleKb;
+      return this;
+    }
+
     public LanguageDetectionConfig build() {
       return new LanguageDetectionConfig(this);
     }
@@ -88,6 +106,10 @@ public final class LanguageDetectionConfig {
     if (detectMultipleValue instanceof Boolean) {
       builder.detectMultiple((Boolean) detectMultipleValue);
     }
+    Object maxSampleKbValue = map.get("max_sample_kb");
+    if (maxSampleKbValue instanceof Number) {
+      builder.maxSampleKb(((Number) maxSampleKbValue).intValue());
+    }
     return builder.build();
   }
 }
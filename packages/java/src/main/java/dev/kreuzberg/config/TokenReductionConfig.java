@@ -16,10 +16,14 @@ public final class TokenReductionConfig {
 
   private final String mode;
   private final boolean preserveImportantWords;
+  private final Double targetRatio;
+  private final Integer maxTokens;
 
   private TokenReductionConfig(Builder builder) {
     this.mode = builder.mode;
     this.preserveImportantWords = builder.preserveImportantWords;
+    this.targetRatio = builder.targetRatio;
+    this.maxTokens = builder.maxTokens;
   }
 
   public static Builder builder() {
@@ -34,16 +38,38 @@ public final class TokenReductionConfig {
     return preserveImportantWords;
   }
 
+  /**
+   * Fraction (0.0-1.0) of the original tokens to keep; {@code null} sets no ratio budget.
+   */
+  public Double getTargetRatio() {
+    return targetRatio;
+  }
+
+  /**
+   * Maximum number of tokens to keep; {@code null} sets no token limit.
+   */
+  public Integer getMaxTokens() {
+    return maxTokens;
+  }
+
   public Map<String, Object> toMap() {
     Map<String, Object> map = new HashMap<>();
     map.put("mode", mode);
     map.put("preserve_important_words", preserveImportantWords);
+    if (targetRatio != null) {
+      map.put("target_ratio", targetRatio);
+    }
+    if (maxTokens != null) {
+      map.put("max_tokens", maxTokens);
+    }
     return map;
   }
 
   public static final class Builder {
     private String mode = "off";
     private boolean preserveImportantWords = true;
+    private Double targetRatio;
+    private Integer maxTokens;
 
     private Builder() {
     }
@@ -62,6 +88,19 @@ public final class TokenReductionConfig {
       return this;
     }
 
+    public Builder targetRatio(Double targetRatio) {
+      if (targetRatio != null && (targetRatio < 0.0 || targetRatio > 1.0)) {
+        throw new IllegalArgumentException("targetRatio must be between 0.0 and 1.0");
+      }
+      this.targetRatio = targetRatio;
+      return this;
+    }
+
+    public Builder maxTokens(Integer maxTokens) {
+      this.maxTokens = maxTokens;
+      return this;
+    }
+
     public TokenReductionConfig build() {
       return new TokenReductionConfig(this);
     }
@@ -78,6 +117,12 @@ public final class TokenReductionConfig {
     if (map.get("preserve_important_words") instanceof Boolean) {
       builder.preserveImportantWords((Boolean) map.get("preserve_important_words"));
     }
+    if (map.get("target_ratio") instanceof Number) {
+      builder.targetRatio(((Number) map.get("target_ratio")).doubleValue());
+    }
+    if (map.get("max_tokens") instanceof Number) {
+      builder.maxTokens(((Number) map.get("max_tokens")).intValue());
+    }
     return builder.build();
   }
 }
@@ -46,21 +46,39 @@ from kreuzberg._internal_bindings import (
     EmbeddingConfig,
     EmbeddingModelType,
     EmbeddingPreset,
+    EntityConfig,
     ExtractedTable,
     ExtractionConfig,
     ExtractionResult,
+    ExtractionSession,
+    HeaderFooterConfig,
     ImageExtractionConfig,
     ImagePreprocessingConfig,
+    KeywordAlgorithm,
+    KeywordConfig,
     LanguageDetectionConfig,
+    LineRepairConfig,
+    OCRProcessor,
     OcrConfig,
     PdfConfig,
     PostProcessorConfig,
+    QualityGateConfig,
+    RakeParams,
+    StopwordsConfig,
     TesseractConfig,
+    TextMetricsConfig,
+    TextNormalizationConfig,
     TokenReductionConfig,
+    YakeParams,
+    calculate_optimal_dpi,
     clear_document_extractors,
+    clear_image_describers,
     clear_ocr_backends,
     clear_post_processors,
     clear_validators,
+    compress_image,
+    configure_thread_pools,
+    convert_image,
     detect_mime_type_from_bytes,
     detect_mime_type_from_path,
     get_embedding_preset,
@@ -69,13 +87,17 @@ from kreuzberg._internal_bindings import (
     get_last_panic_context,
     list_document_extractors,
     list_embedding_presets,
+    list_image_describers,
     list_ocr_backends,
     list_post_processors,
     list_validators,
+    normalize_image_dpi,
+    register_image_describer,
     register_ocr_backend,
     register_post_processor,
     register_validator,
     unregister_document_extractor,
+    unregister_image_describer,
     unregister_ocr_backend,
     unregister_post_processor,
     unregister_validator,
@@ -107,9 +129,11 @@ from kreuzberg._internal_bindings import (
 )
 from kreuzberg.exceptions import (
     CacheError,
+    EncryptedDocumentError,
     ErrorCode,
     ImageProcessingError,
     KreuzbergError,
+    LimitExceededError,
     MissingDependencyError,
     OCRError,
     PanicContext,
@@ -118,7 +142,7 @@ from kreuzberg.exceptions import (
     ValidationError,
 )
 from kreuzberg.postprocessors.protocol import PostProcessorProtocol
-from kreuzberg.types import Chunk, ChunkMetadata, ExtractedImage, Metadata
+from kreuzberg.types import Chunk, ChunkMetadata, DetectedLanguage, ExtractedImage, ExtractionWarning, Keyword, Metadata
 
 if TYPE_CHECKING:
     from pathlib import Path
@@ -134,22 +158,34 @@ __all__ = [
     "Chunk",
     "ChunkMetadata",
     "ChunkingConfig",
+    "DetectedLanguage",
     "EmbeddingConfig",
     "EmbeddingModelType",
     "EmbeddingPreset",
+    "EncryptedDocumentError",
+    "EntityConfig",
     "ErrorCode",
     "ExtractedImage",
     "ExtractedTable",
     "ExtractionConfig",
     "ExtractionResult",
+    "ExtractionSession",
+    "ExtractionWarning",
+    "HeaderFooterConfig",
     "ImageExtractionConfig",
     "ImagePreprocessingConfig",
     "ImageProcessingError",
+    "Keyword",
+    "KeywordAlgorithm",
+    "KeywordConfig",
     "KreuzbergError",
     "LanguageDetectionConfig",
+    "LimitExceededError",
+    "LineRepairConfig",
     "Metadata",
     "MissingDependencyError",
     "OCRError",
+    "OCRProcessor",
     "OcrConfig",
     "PanicContext",
     "ParsingError",
@@ -157,18 +193,29 @@ __all__ = [
     "PluginError",
     "PostProcessorConfig",
     "PostProcessorProtocol",
+    "QualityGateConfig",
+    "RakeParams",
+    "StopwordsConfig",
     "TesseractConfig",
+    "TextMetricsConfig",
+    "TextNormalizationConfig",
     "TokenReductionConfig",
     "ValidationError",
+    "YakeParams",
     "__version__",
     "batch_extract_bytes",
     "batch_extract_bytes_sync",
     "batch_extract_files",
     "batch_extract_files_sync",
+    "calculate_optimal_dpi",
     "clear_document_extractors",
+    "clear_image_describers",
     "clear_ocr_backends",
     "clear_post_processors",
     "clear_validators",
+    "compress_image",
+    "configure_thread_pools",
+    "convert_image",
     "detect_mime_type",
     "detect_mime_type_from_path",
     "extract_bytes",
@@ -181,13 +228,17 @@ __all__ = [
     "get_last_panic_context",
     "list_document_extractors",
     "list_embedding_presets",
+    "list_image_describers",
     "list_ocr_backends",
     "list_post_processors",
     "list_validators",
+    "normalize_image_dpi",
+    "register_image_describer",
     "register_ocr_backend",
     "register_post_processor",
     "register_validator",
     "unregister_document_extractor",
+    "unregister_image_describer",
     "unregister_ocr_backend",
     "unregister_post_processor",
     "unregister_validator",
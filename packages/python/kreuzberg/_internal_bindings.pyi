@@ -7,28 +7,45 @@ __all__ = [
     "EmbeddingConfig",
     "EmbeddingModelType",
     "EmbeddingPreset",
+    "EntityConfig",
     "ExtractedTable",
     "ExtractionConfig",
     "ExtractionResult",
+    "ExtractionSession",
+    "HeaderFooterConfig",
     "ImageExtractionConfig",
     "ImagePreprocessingConfig",
+    "KeywordAlgorithm",
+    "KeywordConfig",
     "LanguageDetectionConfig",
+    "LineRepairConfig",
+    "OCRProcessor",
     "OcrBackendProtocol",
     "OcrConfig",
     "PdfConfig",
     "PostProcessorConfig",
     "PostProcessorProtocol",
+    "QualityGateConfig",
+    "RakeParams",
+    "StopwordsConfig",
     "TesseractConfig",
+    "TextMetricsConfig",
+    "TextNormalizationConfig",
     "TokenReductionConfig",
     "ValidatorProtocol",
+    "YakeParams",
     "batch_extract_bytes",
     "batch_extract_bytes_sync",
     "batch_extract_files",
     "batch_extract_files_sync",
+    "calculate_optimal_dpi",
     "clear_document_extractors",
     "clear_ocr_backends",
     "clear_post_processors",
     "clear_validators",
+    "compress_image",
+    "configure_thread_pools",
+    "convert_image",
     "detect_mime_type_from_bytes",
     "detect_mime_type_from_path",
     "extract_bytes",
@@ -44,6 +61,7 @@ __all__ = [
     "list_ocr_backends",
     "list_post_processors",
     "list_validators",
+    "normalize_image_dpi",
     "register_ocr_backend",
     "register_post_processor",
     "register_validator",
@@ -76,6 +94,10 @@ class ValidatorProtocol(Protocol):
     def priority(self) -> int: ...
     def should_validate(self, result: dict[str, Any]) -> bool: ...
 
+class ImageDescriberProtocol(Protocol):
+    def name(self) -> str: ...
+    def describe_image(self, image: bytes, context: dict[str, Any]) -> str: ...
+
 class ExtractionConfig:
     use_cache: bool
     enable_quality_processing: bool
@@ -86,9 +108,15 @@ class ExtractionConfig:
     pdf_options: PdfConfig | None
     token_reduction: TokenReductionConfig | None
     language_detection: LanguageDetectionConfig | None
+    keywords: KeywordConfig | None
     postprocessor: PostProcessorConfig | None
     max_concurrent_extractions: int | None
     html_options: dict[str, Any] | None
+    entities: EntityConfig | None
+    quality_gate: QualityGateConfig | None
+    collect_timings: bool
+    strict_mime: bool
+    strict: bool
 
     def __init__(
         self,
@@ -102,9 +130,15 @@ class ExtractionConfig:
         pdf_options: PdfConfig | None = None,
         token_reduction: TokenReductionConfig | None = None,
         language_detection: LanguageDetectionConfig | None = None,
+        keywords: KeywordConfig | None = None,
         postprocessor: PostProcessorConfig | None = None,
         max_concurrent_extractions: int | None = None,
         html_options: dict[str, Any] | None = None,
+        entities: EntityConfig | None = None,
+        quality_gate: QualityGateConfig | None = None,
+        collect_timings: bool | None = None,
+        strict_mime: bool | None = None,
+        strict: bool | None = None,
     ) -> None: ...
     @staticmethod
     def from_file(path: str | Path) -> ExtractionConfig: ...
@@ -177,6 +211,8 @@ class ImageExtractionConfig:
     auto_adjust_dpi: bool
     min_dpi: int
     max_dpi: int
+    describer: str | None
+    inline_descriptions: bool
 
     def __init__(
         self,
@@ -187,12 +223,16 @@ class ImageExtractionConfig:
         auto_adjust_dpi: bool | None = None,
         min_dpi: int | None = None,
         max_dpi: int | None = None,
+        describer: str | None = None,
+        inline_descriptions: bool | None = None,
     ) -> None: ...
 
 class PdfConfig:
     extract_images: bool
     passwords: list[str] | None
     extract_metadata: bool
+    validate_pdfa: bool
+    layout_stats: bool
 
     def __init__(
         self,
@@ -200,23 +240,86 @@ class PdfConfig:
         extract_images: bool | None = None,
         passwords: list[str] | None = None,
         extract_metadata: bool | None = None,
+        validate_pdfa: bool | None = None,
+        layout_stats: bool | None = None,
     ) -> None: ...
 
 class TokenReductionConfig:
     mode: str
     preserve_important_words: bool
+    stopwords: StopwordsConfig | None
+    target_ratio: float | None
+    max_tokens: int | None
 
     def __init__(
         self,
         *,
-        mode: Literal["off", "moderate", "aggressive"] | None = None,
+        mode: Literal["off", "light", "moderate", "aggressive", "maximum"] | None = None,
         preserve_important_words: bool | None = None,
+        stopwords: StopwordsConfig | None = None,
+        target_ratio: float | None = None,
+        max_tokens: int | None = None,
+    ) -> None: ...
+
+class StopwordsConfig:
+    custom: list[str]
+    mode: Literal["merge", "replace"]
+
+    def __init__(
+        self,
+        *,
+        custom: list[str] | None = None,
+        mode: Literal["merge", "replace"] | None = None,
+    ) -> None: ...
+
+class KeywordAlgorithm:
+    YAKE: KeywordAlgorithm
+    RAKE: KeywordAlgorithm
+
+class YakeParams:
+    window_size: int
+
+    def __init__(self, *, window_size: int | None = None) -> None: ...
+
+class RakeParams:
+    min_word_length: int
+    max_words_per_phrase: int
+
+    def __init__(
+        self,
+        *,
+        min_word_length: int | None = None,
+        max_words_per_phrase: int | None = None,
+    ) -> None: ...
+
+class KeywordConfig:
+    algorithm: KeywordAlgorithm
+    max_keywords: int
+    min_score: float
+    ngram_range: tuple[int, int]
+    language: str | None
+    stopwords: StopwordsConfig | None
+    yake_params: YakeParams | None
+    rake_params: RakeParams | None
+
+    def __init__(
+        self,
+        *,
+        algorithm: KeywordAlgorithm | None = None,
+        max_keywords: int | None = None,
+        min_score: float | None = None,
+        ngram_range: tuple[int, int] | None = None,
+        language: str | None = None,
+        stopwords: StopwordsConfig | None = None,
+        yake_params: YakeParams | None = None,
+        rake_params: RakeParams | None = None,
     ) -> None: ...
 
 class LanguageDetectionConfig:
     enabled: bool
     min_confidence: float
     detect_multiple: bool
+    max_sample_kb: int | None
 
     def __init__(
         self,
@@ -224,12 +327,106 @@ class LanguageDetectionConfig:
         enabled: bool | None = None,
         min_confidence: float | None = None,
         detect_multiple: bool | None = None,
+        max_sample_kb: int | None = None,
+    ) -> None: ...
+
+class HeaderFooterConfig:
+    lines_to_check: int
+    min_pages: int
+    min_repeat_ratio: float
+    remove_page_numbers: bool
+
+    def __init__(
+        self,
+        *,
+        lines_to_check: int | None = None,
+        min_pages: int | None = None,
+        min_repeat_ratio: float | None = None,
+        remove_page_numbers: bool | None = None,
+    ) -> None: ...
+
+class LineRepairConfig:
+    dehyphenate: bool
+    merge_lines: bool
+    language: str | None
+
+    def __init__(
+        self,
+        *,
+        dehyphenate: bool | None = None,
+        merge_lines: bool | None = None,
+        language: str | None = None,
+    ) -> None: ...
+
+EntityTypeName = Literal["person", "organization", "date", "money"]
+
+class EntityConfig:
+    entity_types: list[str]
+    min_confidence: float
+
+    def __init__(
+        self,
+        *,
+        entity_types: list[str] | None = None,
+        min_confidence: float | None = None,
+    ) -> None: ...
+
+NormalizationFormName = Literal["none", "nfc", "nfkc"]
+
+class TextNormalizationConfig:
+    form: NormalizationFormName
+    remove_zero_width: bool
+    remove_bidi_controls: bool
+    remove_control_chars: bool
+    canonicalize_quotes: bool
+    canonicalize_dashes: bool
+
+    def __init__(
+        self,
+        *,
+        form: NormalizationFormName | None = None,
+        remove_zero_width: bool | None = None,
+        remove_bidi_controls: bool | None = None,
+        remove_control_chars: bool | None = None,
+        canonicalize_quotes: bool | None = None,
+        canonicalize_dashes: bool | None = None,
+    ) -> None: ...
+
+QualityGateActionName = Literal["flag", "reject"]
+
+class QualityGateConfig:
+    min_dictionary_hit_rate: float
+    min_confusion_score: float
+    min_words: int
+    ocr_only: bool
+    action: QualityGateActionName
+    language: str | None
+
+    def __init__(
+        self,
+        *,
+        min_dictionary_hit_rate: float | None = None,
+        min_confusion_score: float | None = None,
+        min_words: int | None = None,
+        ocr_only: bool | None = None,
+        action: QualityGateActionName | None = None,
+        language: str | None = None,
     ) -> None: ...
 
+class TextMetricsConfig:
+    long_word_length: int
+
+    def __init__(self, *, long_word_length: int | None = None) -> None: ...
+
 class PostProcessorConfig:
     enabled: bool
     enabled_processors: list[str] | None
     disabled_processors: list[str] | None
+    header_footer: HeaderFooterConfig | None
+    line_repair: LineRepairConfig | None
+    normalization: TextNormalizationConfig | None
+    metrics: TextMetricsConfig | None
+    taggers: dict[str, str | list[str]] | None
 
     def __init__(
         self,
@@ -237,16 +434,23 @@ class PostProcessorConfig:
         enabled: bool | None = None,
         enabled_processors: list[str] | None = None,
         disabled_processors: list[str] | None = None,
+        header_footer: HeaderFooterConfig | None = None,
+        line_repair: LineRepairConfig | None = None,
+        normalization: TextNormalizationConfig | None = None,
+        metrics: TextMetricsConfig | None = None,
+        taggers: dict[str, str | list[str]] | None = None,
     ) -> None: ...
 
 class ImagePreprocessingConfig:
     target_dpi: int
     auto_rotate: bool
     deskew: bool
+    grayscale: bool
     denoise: bool
     contrast_enhance: bool
     binarization_method: str
     invert_colors: bool
+    remove_borders: bool
 
     def __init__(
         self,
@@ -254,10 +458,12 @@ class ImagePreprocessingConfig:
         target_dpi: int | None = None,
         auto_rotate: bool | None = None,
         deskew: bool | None = None,
+        grayscale: bool | None = None,
         denoise: bool | None = None,
         contrast_enhance: bool | None = None,
         binarization_method: str | None = None,
         invert_colors: bool | None = None,
+        remove_borders: bool | None = None,
     ) -> None: ...
 
 class TesseractConfig:
@@ -309,6 +515,27 @@ class TesseractConfig:
         thresholding_method: bool | None = None,
     ) -> None: ...
 
+class PdfAViolation(TypedDict):
+    rule: str
+    message: str
+
+class PdfAReport(TypedDict, total=False):
+    part: int
+    conformance: str
+    conformant: bool
+    violations: list[PdfAViolation]
+
+class PdfFontInfo(TypedDict):
+    name: str
+    embedded: bool
+
+class PdfLayoutStats(TypedDict, total=False):
+    fonts: list[PdfFontInfo]
+    text_coverage: float
+    image_coverage: float
+    avg_glyphs_per_page: float
+    pages_without_text: int
+
 class PdfMetadata(TypedDict, total=False):
     title: str
     subject: str
@@ -324,11 +551,27 @@ class PdfMetadata(TypedDict, total=False):
     width: int
     height: int
     summary: str
+    pdfa: PdfAReport
+    layout: PdfLayoutStats
 
 class ExcelMetadata(TypedDict, total=False):
     sheet_count: int
     sheet_names: list[str]
 
+class CalendarEvent(TypedDict, total=False):
+    method: str
+    uid: str
+    summary: str
+    start: str
+    end: str
+    timezone: str
+    location: str
+    organizer: str
+    attendees: list[str]
+    description: str
+    status: str
+    recurrence: str
+
 class EmailMetadata(TypedDict, total=False):
     from_email: str
     from_name: str
@@ -336,7 +579,17 @@ class EmailMetadata(TypedDict, total=False):
     cc_emails: list[str]
     bcc_emails: list[str]
     message_id: str
+    reply_to_emails: list[str]
+    in_reply_to: str
+    references: list[str]
+    thread_id: str
+    has_dkim_signature: bool
+    raw_headers: list[tuple[str, str]]
+    calendar_events: list[CalendarEvent]
     attachments: list[str]
+    quoted_text: str
+    signature: str
+    tracking_pixels: list[str]
 
 class PptxMetadata(TypedDict, total=False):
     title: str
@@ -345,6 +598,26 @@ class PptxMetadata(TypedDict, total=False):
     summary: str
     fonts: list[str]
 
+class OfficeMetadata(TypedDict, total=False):
+    application: str
+    template: str
+    organization: str
+    description: str
+    initial_creator: str
+    revision: str
+    category: str
+    content_status: str
+    total_editing_time_minutes: int
+    editing_duration: str
+    editing_cycles: str
+    page_count: int
+    word_count: int
+    character_count: int
+    line_count: int
+    paragraph_count: int
+    table_count: int
+    image_count: int
+
 class ArchiveMetadata(TypedDict, total=False):
     format: str
     file_count: int
@@ -361,6 +634,7 @@ class ImageMetadata(TypedDict, total=False):
 class XmlMetadata(TypedDict, total=False):
     element_count: int
     unique_elements: list[str]
+    document_type: str
 
 class TextMetadata(TypedDict, total=False):
     line_count: int
@@ -369,6 +643,21 @@ class TextMetadata(TypedDict, total=False):
     headers: list[str]
     links: list[tuple[str, str]]
     code_blocks: list[tuple[str, str]]
+    encoding: str
+
+class HtmlLink(TypedDict, total=False):
+    text: str
+    href: str
+    title: str
+    rel: str
+    position: int
+
+class HtmlImage(TypedDict, total=False):
+    src: str
+    alt: str
+    title: str
+    position: int
+    image_index: int
 
 class HtmlMetadata(TypedDict, total=False):
     title: str
@@ -392,6 +681,8 @@ class HtmlMetadata(TypedDict, total=False):
     link_author: str
     link_license: str
     link_alternate: str
+    links: list[HtmlLink]
+    images: list[HtmlImage]
 
 class OcrMetadata(TypedDict, total=False):
     language: str
@@ -419,12 +710,83 @@ class ErrorMetadata(TypedDict, total=False):
     error_type: str
     message: str
 
+class StageDurations(TypedDict):
+    extraction_ms: float
+    post_processing_ms: float
+
+class ExtractionStats(TypedDict, total=False):
+    word_count: int
+    character_count: int
+    page_count: int
+    table_count: int
+    image_count: int
+    ocr_confidence: float
+    durations: StageDurations
+
+class ExtractionTimings(TypedDict, total=False):
+    mime_detection_ms: float
+    extraction_ms: float
+    ocr_ms: float
+    post_processing_ms: float
+    chunking_ms: float
+    language_detection_ms: float
+    total_ms: float
+
+class DocumentProvenance(TypedDict, total=False):
+    producer: str
+    creator_tool: str
+    scanner_model: str
+    likely_scanned: bool
+    scan_signals: list[str]
+
+class TextMetrics(TypedDict):
+    sentence_count: int
+    word_count: int
+    syllable_count: int
+    long_word_count: int
+    unique_word_count: int
+    avg_sentence_length: float
+    avg_word_length: float
+    avg_syllables_per_word: float
+    flesch_reading_ease: float
+    flesch_kincaid_grade: float
+    lix: float
+    type_token_ratio: float
+    hapax_ratio: float
+
+class TagMatch(TypedDict):
+    text: str
+    byte_start: int
+    byte_end: int
+
+class ReviewReason(TypedDict, total=False):
+    kind: str
+    confidence: float
+    score: float
+    threshold: float
+    limits: list[str]
+    validator: str
+    message: str
+
+class ReviewStatus(TypedDict):
+    required: bool
+    reasons: list[ReviewReason]
+
+class Revision(TypedDict, total=False):
+    kind: str
+    author: str
+    date: str
+    text: str
+    anchor: str
+    page_number: int
+    status: str
+
 class Metadata(TypedDict, total=False):
     language: str
     date: str
     subject: str
 
-    format_type: Literal["pdf", "excel", "email", "pptx", "archive", "image", "xml", "text", "html", "ocr"]
+    format_type: Literal["pdf", "excel", "email", "pptx", "office", "archive", "image", "xml", "text", "html", "ocr"]
 
     title: str
     authors: list[str]
@@ -432,6 +794,9 @@ class Metadata(TypedDict, total=False):
     created_at: str
     modified_at: str
     created_by: str
+    modified_by: str
+    publisher: str
+    identifier: str
     producer: str
     page_count: int
     pdf_version: str
@@ -439,6 +804,8 @@ class Metadata(TypedDict, total=False):
     width: int
     height: int
     summary: str
+    pdfa: PdfAReport
+    layout: PdfLayoutStats
 
     sheet_count: int
     sheet_names: list[str]
@@ -449,12 +816,35 @@ class Metadata(TypedDict, total=False):
     cc_emails: list[str]
     bcc_emails: list[str]
     message_id: str
+    reply_to_emails: list[str]
+    in_reply_to: str
+    references: list[str]
+    thread_id: str
+    has_dkim_signature: bool
+    raw_headers: list[tuple[str, str]]
+    calendar_events: list[CalendarEvent]
     attachments: list[str]
+    quoted_text: str
+    signature: str
+    tracking_pixels: list[str]
 
     author: str
     description: str
     fonts: list[str]
 
+    application: str
+    template: str
+    organization: str
+    initial_creator: str
+    revision: str
+    category: str
+    content_status: str
+    total_editing_time_minutes: int
+    editing_duration: str
+    editing_cycles: str
+    paragraph_count: int
+    image_count: int
+
     format: str
     file_count: int
     file_list: list[str]
@@ -472,6 +862,7 @@ class Metadata(TypedDict, total=False):
     headers: list[str]
     links: list[tuple[str, str]]
     code_blocks: list[tuple[str, str]]
+    encoding: str
 
     canonical: str
     base_href: str
@@ -500,6 +891,14 @@ class Metadata(TypedDict, total=False):
     image_preprocessing: ImagePreprocessingMetadata
     json_schema: dict[str, Any]
     error: ErrorMetadata
+    stats: ExtractionStats
+    timings: ExtractionTimings
+    provenance: DocumentProvenance
+    text_metrics: TextMetrics
+    tags: dict[str, list[TagMatch]]
+    review: ReviewStatus
+    duplicate_of: str
+    revisions: list[Revision]
 
 class ExtractedImage(TypedDict, total=False):
     data: bytes
@@ -508,6 +907,7 @@ class ExtractedImage(TypedDict, total=False):
     page_number: int
     width: int
     height: int
+    dpi: int
     colorspace: str
     bits_per_component: int
     is_mask: bool
@@ -519,20 +919,74 @@ class Chunk(TypedDict, total=False):
     embedding: list[float] | None
     metadata: dict[str, Any]
 
+class DetectedLanguage(TypedDict):
+    language: str
+    confidence: float
+    script: str
+
+class Entity(TypedDict):
+    entity_type: EntityTypeName
+    text: str
+    byte_start: int
+    byte_end: int
+    confidence: float
+
+class Keyword(TypedDict):
+    text: str
+    score: float
+    algorithm: Literal["yake", "rake"]
+    positions: list[int] | None
+
+class ExtractionWarning(TypedDict):
+    code: str
+    message: str
+    stage: Literal["recovery", "extraction", "ocr", "post_processing", "limits"]
+    page: int | None
+
 class ExtractionResult:
     content: str
     mime_type: str
     metadata: Metadata
     tables: list[ExtractedTable]
-    detected_languages: list[str] | None
+    detected_languages: list[DetectedLanguage] | None
     chunks: list[Chunk] | None
     images: list[ExtractedImage] | None
+    entities: list[Entity] | None
+    keywords: list[Keyword] | None
+    warnings: list[ExtractionWarning]
+
+class ExtractionSession:
+    def __init__(self, config: ExtractionConfig = ...) -> None: ...
+    def extract_file_sync(self, path: str | Path | bytes, mime_type: str | None = None) -> ExtractionResult: ...
+    def extract_bytes_sync(self, data: bytes | bytearray, mime_type: str) -> ExtractionResult: ...
+    def extract_file(self, path: str | Path | bytes, mime_type: str | None = None) -> Awaitable[ExtractionResult]: ...
+    def extract_bytes(self, data: bytes | bytearray, mime_type: str) -> Awaitable[ExtractionResult]: ...
 
 class ExtractedTable:
     cells: list[list[str]]
     markdown: str
     page_number: int
 
+class OcrCacheStats(TypedDict):
+    total_files: int
+    total_size_mb: float
+
+class OcrBatchItem(TypedDict):
+    file_path: str
+    success: bool
+    result: dict[str, Any] | None
+    error: str | None
+
+class OCRProcessor:
+    def __init__(self, cache_dir: str | Path | None = None) -> None: ...
+    def process_image(self, image_bytes: bytes, config: TesseractConfig | None = None) -> dict[str, Any]: ...
+    def process_file(self, path: str | Path, config: TesseractConfig | None = None) -> dict[str, Any]: ...
+    def process_files_batch(
+        self, paths: list[str | Path], config: TesseractConfig | None = None
+    ) -> list[OcrBatchItem]: ...
+    def clear_cache(self) -> None: ...
+    def get_cache_stats(self) -> OcrCacheStats: ...
+
 @overload
 def extract_file_sync(
     path: str | Path | bytes,
@@ -592,6 +1046,10 @@ def unregister_post_processor(name: str) -> None: ...
 def register_validator(validator: ValidatorProtocol) -> None: ...
 def clear_validators() -> None: ...
 def unregister_validator(name: str) -> None: ...
+def register_image_describer(describer: ImageDescriberProtocol) -> None: ...
+def unregister_image_describer(name: str) -> None: ...
+def list_image_describers() -> list[str]: ...
+def clear_image_describers() -> None: ...
 def list_embedding_presets() -> list[str]: ...
 def get_embedding_preset(name: str) -> EmbeddingPreset | None: ...
 def clear_document_extractors() -> None: ...
@@ -608,3 +1066,30 @@ def unregister_document_extractor(name: str) -> None: ...
 def unregister_ocr_backend(name: str) -> None: ...
 def get_last_error_code() -> int | None: ...
 def get_last_panic_context() -> str | None: ...
+def configure_thread_pools(
+    rayon_threads: int | None = None,
+    worker_threads: int | None = None,
+    max_blocking_threads: int | None = None,
+) -> None: ...
+def normalize_image_dpi(
+    rgb_data: bytes,
+    width: int,
+    height: int,
+    config: ImageExtractionConfig | None = None,
+    current_dpi: float | None = None,
+) -> tuple[bytes, tuple[int, int], ImagePreprocessingMetadata]: ...
+def calculate_optimal_dpi(
+    page_width: float,
+    page_height: float,
+    target_dpi: int = 300,
+    max_dimension: int = 4096,
+    min_dpi: int = 72,
+    max_dpi: int = 600,
+) -> int: ...
+def convert_image(
+    image_bytes: bytes,
+    format: Literal["png", "jpeg", "jpg", "webp", "tiff", "tif", "bmp", "gif"] = "png",
+    quality: int = 85,
+    max_dimension: int | None = None,
+) -> bytes: ...
+def compress_image(image_bytes: bytes, quality: int = 75, max_dimension: int | None = None) -> bytes: ...
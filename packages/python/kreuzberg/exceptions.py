@@ -91,6 +91,17 @@ class KreuzbergError(Exception):
     information. The context is serialized to JSON when the exception is converted
     to a string.
 
+    Errors raised by the native library also carry machine-readable attributes, which
+    are set on every exception it raises (including ``OSError`` and ``RuntimeError``):
+
+    Attributes:
+        code: Stable error code such as ``"parsing"``, ``"missing_dependency"`` or
+            ``"limit_exceeded"``; ``None`` for errors raised from Python code
+        stage: Pipeline stage the error belongs to (``"mime_detection"``, ``"extraction"``,
+            ``"ocr"`` or ``"cache"``), if known
+        plugin: Name of the failing plugin, for plugin errors
+        retryable: Whether retrying the same operation may succeed
+
     Args:
         message: Human-readable error message
         context: Optional dictionary with debugging context (file paths, config, etc.)
@@ -100,6 +111,11 @@ class KreuzbergError(Exception):
 
     """
 
+    code: str | None = None
+    stage: str | None = None
+    plugin: str | None = None
+    retryable: bool = False
+
     def __init__(self, message: str, *, context: dict[str, Any] | None = None) -> None:
         super().__init__(message)
         self.message = message
@@ -145,6 +161,19 @@ class ValidationError(KreuzbergError):
     """
 
 
+class LimitExceededError(ValidationError):
+    """Raised when a document exceeds a configured resource limit.
+
+    Limits are set with the ``limits`` section of the extraction config. With
+    ``truncate = true`` the result is truncated instead and the exceeded limits are
+    listed in ``metadata["limits_exceeded"]``.
+
+    Example:
+        >>> raise LimitExceededError("Limit exceeded: max_pages is 120, maximum is 100")
+
+    """
+
+
 class ParsingError(KreuzbergError):
     """Raised when document parsing fails.
 
@@ -157,6 +186,18 @@ class ParsingError(KreuzbergError):
     """
 
 
+class EncryptedDocumentError(ParsingError):
+    """Raised when none of the configured passwords opens an encrypted document.
+
+    Passwords for encrypted Word, Excel and PowerPoint files are set with
+    ``office.passwords``.
+
+    Example:
+        >>> raise EncryptedDocumentError("Encrypted document: none of the configured passwords opens the document")
+
+    """
+
+
 class OCRError(KreuzbergError):
     """Raised when OCR processing fails.
 
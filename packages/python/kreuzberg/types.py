@@ -18,6 +18,23 @@ class ExcelMetadata(TypedDict, total=False):
     sheet_names: list[str]
 
 
+class CalendarEvent(TypedDict, total=False):
+    """A meeting from a calendar invite (``text/calendar`` part, ``.ics`` file or TNEF meeting request)."""
+
+    method: str
+    uid: str
+    summary: str
+    start: str
+    end: str
+    timezone: str
+    location: str
+    organizer: str
+    attendees: list[str]
+    description: str
+    status: str
+    recurrence: str
+
+
 class EmailMetadata(TypedDict, total=False):
     """Email metadata."""
 
@@ -27,7 +44,17 @@ class EmailMetadata(TypedDict, total=False):
     cc_emails: list[str]
     bcc_emails: list[str]
     message_id: str | None
+    reply_to_emails: list[str]
+    in_reply_to: str | None
+    references: list[str]
+    thread_id: str | None
+    has_dkim_signature: bool
+    raw_headers: list[tuple[str, str]]
+    calendar_events: list[CalendarEvent]
     attachments: list[str]
+    quoted_text: str
+    signature: str
+    tracking_pixels: list[str]
 
 
 class ArchiveMetadata(TypedDict, total=False):
@@ -54,6 +81,7 @@ class XmlMetadata(TypedDict, total=False):
 
     element_count: int
     unique_elements: list[str]
+    document_type: str
 
 
 class TextMetadata(TypedDict, total=False):
@@ -65,6 +93,40 @@ class TextMetadata(TypedDict, total=False):
     headers: list[str] | None
     links: list[tuple[str, str]] | None
     code_blocks: list[tuple[str, str]] | None
+    encoding: str | None
+
+
+class PdfAViolation(TypedDict):
+    """A PDF/A requirement the file does not meet."""
+
+    rule: str
+    message: str
+
+
+class PdfAReport(TypedDict, total=False):
+    """PDF/A conformance report, set when ``PdfConfig.validate_pdfa`` is enabled."""
+
+    part: int
+    conformance: str
+    conformant: bool
+    violations: list[PdfAViolation]
+
+
+class PdfFontInfo(TypedDict):
+    """A font defined in a PDF; ``name`` is the base font name without the subset prefix."""
+
+    name: str
+    embedded: bool
+
+
+class PdfLayoutStats(TypedDict, total=False):
+    """Font and layout statistics, set when ``PdfConfig.layout_stats`` is enabled."""
+
+    fonts: list[PdfFontInfo]
+    text_coverage: float
+    image_coverage: float
+    avg_glyphs_per_page: float
+    pages_without_text: int
 
 
 class PdfMetadata(TypedDict, total=False):
@@ -79,6 +141,28 @@ class PdfMetadata(TypedDict, total=False):
     creation_date: str | None
     modification_date: str | None
     page_count: int
+    pdfa: PdfAReport
+    layout: PdfLayoutStats
+
+
+class HtmlLink(TypedDict, total=False):
+    """A hyperlink of an HTML document."""
+
+    text: str
+    href: str
+    title: str
+    rel: str
+    position: int
+
+
+class HtmlImage(TypedDict, total=False):
+    """An image of an HTML document; ``image_index`` points into ``ExtractionResult.images``."""
+
+    src: str
+    alt: str
+    title: str
+    position: int
+    image_index: int
 
 
 class HtmlMetadata(TypedDict, total=False):
@@ -105,6 +189,8 @@ class HtmlMetadata(TypedDict, total=False):
     link_author: str | None
     link_license: str | None
     link_alternate: str | None
+    links: list[HtmlLink]
+    images: list[HtmlImage]
 
 
 class PptxMetadata(TypedDict, total=False):
@@ -117,6 +203,29 @@ class PptxMetadata(TypedDict, total=False):
     fonts: list[str]
 
 
+class OfficeMetadata(TypedDict, total=False):
+    """Word-processing document metadata (DOCX, ODT, RTF)."""
+
+    application: str | None
+    template: str | None
+    organization: str | None
+    description: str | None
+    initial_creator: str | None
+    revision: str | None
+    category: str | None
+    content_status: str | None
+    total_editing_time_minutes: int | None
+    editing_duration: str | None
+    editing_cycles: str | None
+    page_count: int | None
+    word_count: int | None
+    character_count: int | None
+    line_count: int | None
+    paragraph_count: int | None
+    table_count: int | None
+    image_count: int | None
+
+
 class OcrMetadata(TypedDict, total=False):
     """OCR processing metadata."""
 
@@ -152,6 +261,119 @@ class ErrorMetadata(TypedDict, total=False):
     message: str
 
 
+class StageDurations(TypedDict):
+    """Wall-clock duration of each extraction stage, in milliseconds."""
+
+    extraction_ms: float
+    post_processing_ms: float
+
+
+class ExtractionStats(TypedDict, total=False):
+    """Summary statistics computed for every extraction result."""
+
+    word_count: int
+    character_count: int
+    page_count: int
+    table_count: int
+    image_count: int
+    ocr_confidence: float
+    durations: StageDurations
+
+
+class ExtractionTimings(TypedDict, total=False):
+    """Per-stage durations in milliseconds, recorded when ``collect_timings`` is set.
+
+    Stages that did not run are absent. ``ocr_ms`` is part of ``extraction_ms``.
+    """
+
+    mime_detection_ms: float
+    extraction_ms: float
+    ocr_ms: float
+    post_processing_ms: float
+    chunking_ms: float
+    language_detection_ms: float
+    total_ms: float
+
+
+class DocumentProvenance(TypedDict, total=False):
+    """Producing application, capture device and scan heuristic for a document."""
+
+    producer: str
+    creator_tool: str
+    scanner_model: str
+    likely_scanned: bool
+    scan_signals: list[str]
+
+
+class TextMetrics(TypedDict):
+    """Readability scores, sentence/word statistics and vocabulary richness of the content."""
+
+    sentence_count: int
+    word_count: int
+    syllable_count: int
+    long_word_count: int
+    unique_word_count: int
+    avg_sentence_length: float
+    avg_word_length: float
+    avg_syllables_per_word: float
+    flesch_reading_ease: float
+    flesch_kincaid_grade: float
+    lix: float
+    type_token_ratio: float
+    hapax_ratio: float
+
+
+class TagMatch(TypedDict):
+    """A match of a custom tagger with byte offsets into the content."""
+
+    text: str
+    byte_start: int
+    byte_end: int
+
+
+class ReviewReason(TypedDict, total=False):
+    """A signal that routes a result to human review.
+
+    ``kind`` is one of ``low_ocr_confidence`` (``confidence``, ``threshold``),
+    ``low_quality_score`` (``score``, ``threshold``), ``low_quality``,
+    ``limits_exceeded`` (``limits``), ``archive_truncated`` or
+    ``validator_failed`` (``validator``, ``message``).
+    """
+
+    kind: str
+    confidence: float
+    score: float
+    threshold: float
+    limits: list[str]
+    validator: str
+    message: str
+
+
+class ReviewStatus(TypedDict):
+    """Whether a result needs human review, and the signals behind it."""
+
+    required: bool
+    reasons: list[ReviewReason]
+
+
+class Revision(TypedDict, total=False):
+    """A tracked change or comment in a DOCX or PPTX document.
+
+    ``kind`` is ``insertion``, ``deletion`` or ``comment``; ``status`` is
+    ``pending`` for tracked changes and open comments and ``resolved`` for
+    comments marked done. ``anchor`` is the commented text (DOCX) and
+    ``page_number`` the commented slide (PPTX).
+    """
+
+    kind: str
+    author: str
+    date: str
+    text: str
+    anchor: str
+    page_number: int
+    status: str
+
+
 class ChunkMetadata(TypedDict):
     """Chunk metadata describing offsets within the original document."""
 
@@ -239,6 +461,32 @@ class Chunk(TypedDict, total=False):
     metadata: ChunkMetadata
 
 
+class DetectedLanguage(TypedDict):
+    """Language detected in the content, with confidence (0.0-1.0) and writing script."""
+
+    language: str
+    confidence: float
+    script: str
+
+
+class Keyword(TypedDict):
+    """Keyword extracted by YAKE or RAKE."""
+
+    text: str
+    score: float
+    algorithm: Literal["yake", "rake"]
+    positions: list[int] | None
+
+
+class ExtractionWarning(TypedDict):
+    """Non-fatal issue encountered while processing a document, e.g. a skipped attachment."""
+
+    code: str
+    message: str
+    stage: Literal["recovery", "extraction", "ocr", "post_processing", "limits"]
+    page: int | None
+
+
 class ExtractedImage(TypedDict, total=False):
     """Image artifact extracted from a document page."""
 
@@ -248,6 +496,7 @@ class ExtractedImage(TypedDict, total=False):
     page_number: int | None
     width: int | None
     height: int | None
+    dpi: int | None
     colorspace: str | None
     bits_per_component: int | None
     is_mask: bool
@@ -271,29 +520,36 @@ class Metadata(TypedDict, total=False):
     Format-specific fields are flattened at the root level. Use the format_type
     discriminator to determine which fields are present.
 
-    Common fields:
-        language: Document language (ISO 639-1 code)
-        date: Document date (ISO 8601 format)
-        subject: Document subject
+    Common fields (normalized across formats):
+        title, authors, keywords, language, subject, created_at, modified_at,
+        created_by, modified_by, publisher, identifier
+        date: Document date (deprecated, use created_at/modified_at)
 
     Discriminator:
         format_type: Format discriminator ("pdf", "excel", "email", etc.)
 
     Format-specific fields (flattened at root level):
         PDF fields (when format_type == "pdf"):
-            title, authors, keywords, created_at, modified_at, created_by,
-            producer, page_count, pdf_version, is_encrypted, width, height, summary
+            producer, page_count, pdf_version, is_encrypted, width, height, summary, pdfa, layout
 
         Excel fields (when format_type == "excel"):
             sheet_count, sheet_names
 
         Email fields (when format_type == "email"):
             from_email, from_name, to_emails, cc_emails, bcc_emails,
-            message_id, attachments
+            message_id, reply_to_emails, in_reply_to, references, thread_id,
+            has_dkim_signature, raw_headers, calendar_events, attachments,
+            quoted_text, signature, tracking_pixels
 
         PowerPoint fields (when format_type == "pptx"):
             author, description, fonts
 
+        Office fields (when format_type == "office", DOCX/ODT):
+            application, template, organization, description, initial_creator,
+            revision, category, content_status, total_editing_time_minutes,
+            editing_duration, editing_cycles, page_count, word_count,
+            character_count, line_count, paragraph_count, table_count, image_count
+
         Archive fields (when format_type == "archive"):
             format, file_count, file_list, total_size, compressed_size
 
@@ -324,6 +580,33 @@ class Metadata(TypedDict, total=False):
     Error handling:
         error: Error metadata dict for batch operations
 
+    Summary statistics:
+        stats: Word/character/page/table/image counts, mean OCR confidence and
+            stage durations, present on every result
+        timings: Per-stage durations (MIME detection, extraction, OCR, post-processing,
+            chunking, language detection), present when collect_timings is set
+
+    Provenance:
+        provenance: Producing application (PDF Producer/Creator, Office Application,
+            EXIF Software), scanner model and a likely_scanned flag with its evidence
+
+    Readability:
+        text_metrics: Flesch reading ease, Flesch-Kincaid grade, LIX, sentence/word
+            statistics and vocabulary richness, when PostProcessorConfig.metrics is set
+
+    Custom taggers:
+        tags: Matches of each tagger in PostProcessorConfig.taggers, keyed by tagger name
+
+    Human review:
+        review: Review verdict and reasons, present when ExtractionConfig.review is set
+
+    Deduplication:
+        duplicate_of: Path (or batch index) of the earlier result this one nearly duplicates,
+            set by batch and directory extraction when ExtractionConfig.dedup is set
+
+    Office revisions:
+        revisions: Tracked changes and comments of DOCX and PPTX documents
+
     Custom fields:
         Any additional fields added by Python postprocessors (entity extraction,
         keyword extraction, etc.) will appear as top-level keys in the dict.
@@ -342,7 +625,7 @@ class Metadata(TypedDict, total=False):
     date: str
     subject: str
 
-    format_type: Literal["pdf", "excel", "email", "pptx", "archive", "image", "xml", "text", "html", "ocr"]
+    format_type: Literal["pdf", "excel", "email", "pptx", "office", "archive", "image", "xml", "text", "html", "ocr"]
 
     title: str
     authors: list[str]
@@ -350,6 +633,9 @@ class Metadata(TypedDict, total=False):
     created_at: str
     modified_at: str
     created_by: str
+    modified_by: str
+    publisher: str
+    identifier: str
     producer: str
     page_count: int
     pdf_version: str
@@ -357,6 +643,8 @@ class Metadata(TypedDict, total=False):
     width: int
     height: int
     summary: str
+    pdfa: PdfAReport
+    layout: PdfLayoutStats
 
     sheet_count: int
     sheet_names: list[str]
@@ -367,12 +655,35 @@ class Metadata(TypedDict, total=False):
     cc_emails: list[str]
     bcc_emails: list[str]
     message_id: str
+    reply_to_emails: list[str]
+    in_reply_to: str
+    references: list[str]
+    thread_id: str
+    has_dkim_signature: bool
+    raw_headers: list[tuple[str, str]]
+    calendar_events: list[CalendarEvent]
     attachments: list[str]
+    quoted_text: str
+    signature: str
+    tracking_pixels: list[str]
 
     author: str
     description: str
     fonts: list[str]
 
+    application: str
+    template: str
+    organization: str
+    initial_creator: str
+    revision: str
+    category: str
+    content_status: str
+    total_editing_time_minutes: int
+    editing_duration: str
+    editing_cycles: str
+    paragraph_count: int
+    image_count: int
+
     format: str
     file_count: int
     file_list: list[str]
@@ -383,6 +694,7 @@ class Metadata(TypedDict, total=False):
 
     element_count: int
     unique_elements: list[str]
+    document_type: str
 
     line_count: int
     word_count: int
@@ -390,6 +702,7 @@ class Metadata(TypedDict, total=False):
     headers: list[str]
     links: list[tuple[str, str]]
     code_blocks: list[tuple[str, str]]
+    encoding: str
 
     canonical: str
     base_href: str
@@ -418,6 +731,14 @@ class Metadata(TypedDict, total=False):
     image_preprocessing: ImagePreprocessingMetadata
     json_schema: dict[str, Any]
     error: ErrorMetadata
+    stats: ExtractionStats
+    timings: ExtractionTimings
+    provenance: DocumentProvenance
+    text_metrics: TextMetrics
+    tags: dict[str, list[TagMatch]]
+    review: ReviewStatus
+    duplicate_of: str
+    revisions: list[Revision]
 
 
 class Table(TypedDict):
@@ -436,45 +757,68 @@ class ExtractionResult(TypedDict):
         mime_type: MIME type of the processed document
         metadata: Strongly-typed metadata (see Metadata TypedDict)
         tables: List of extracted tables
-        detected_languages: List of detected language codes (ISO 639-1)
+        detected_languages: Detected languages (ISO 639-3 code, confidence and script), most prominent first
         chunks: Optional list of text chunks with embeddings and metadata
         images: Optional list of extracted images (with nested OCR results)
         pages: Optional list of per-page content when page extraction is enabled
+        keywords: Optional list of extracted keywords when keyword extraction is enabled
+        warnings: Non-fatal issues encountered during processing, in the order they occurred
     """
 
     content: str
     mime_type: str
     metadata: Metadata
     tables: list[Table]
-    detected_languages: list[str] | None
+    detected_languages: list[DetectedLanguage] | None
     chunks: list[Chunk] | None
     images: list[ExtractedImage] | None
     pages: list[PageContent] | None
+    keywords: list[Keyword] | None
+    warnings: list[ExtractionWarning]
 
 
 __all__ = [
     "ArchiveMetadata",
+    "CalendarEvent",
     "Chunk",
     "ChunkMetadata",
+    "DetectedLanguage",
+    "DocumentProvenance",
     "EmailMetadata",
     "ErrorMetadata",
     "ExcelMetadata",
     "ExtractedImage",
     "ExtractionResult",
+    "ExtractionStats",
+    "ExtractionTimings",
+    "ExtractionWarning",
+    "HtmlImage",
+    "HtmlLink",
     "HtmlMetadata",
     "ImageMetadata",
     "ImagePreprocessingMetadata",
+    "Keyword",
     "Metadata",
     "OcrMetadata",
+    "OfficeMetadata",
     "PageBoundary",
     "PageConfig",
     "PageContent",
     "PageInfo",
     "PageStructure",
     "PageUnitType",
+    "PdfAReport",
+    "PdfAViolation",
+    "PdfFontInfo",
+    "PdfLayoutStats",
     "PdfMetadata",
     "PptxMetadata",
+    "ReviewReason",
+    "ReviewStatus",
+    "Revision",
+    "StageDurations",
     "Table",
     "TextMetadata",
+    "TextMetrics",
     "XmlMetadata",
 ]
@@ -16,6 +16,8 @@ from kreuzberg import (
     ExtractionResult,
     LanguageDetectionConfig,
     PostProcessorConfig,
+    TextMetricsConfig,
+    TokenReductionConfig,
     extract_bytes,
     extract_bytes_sync,
     extract_file,
@@ -176,6 +178,60 @@ def test_extract_bytes_sync_with_language_detection() -> None:
     result = extract_bytes_sync(content, mime_type, config)
 
     assert result.detected_languages is not None
+    assert result.detected_languages[0]["language"] == "eng"
+    assert 0.0 <= result.detected_languages[0]["confidence"] <= 1.0
+    assert result.detected_languages[0]["script"] == "Latin"
+
+
+def test_language_detection_config_max_sample_kb() -> None:
+    """Test max_sample_kb round-trips through LanguageDetectionConfig."""
+    config = LanguageDetectionConfig(max_sample_kb=16)
+    assert config.max_sample_kb == 16
+
+    config.max_sample_kb = None
+    assert config.max_sample_kb is None
+
+
+@pytest.mark.asyncio
+async def test_extract_bytes_with_token_budget() -> None:
+    """Test token reduction keeps the content within max_tokens."""
+    content = b"Budgets keep the important sentences. Filler text is dropped first. " * 10
+
+    config = ExtractionConfig(token_reduction=TokenReductionConfig(max_tokens=20))
+    result = await extract_bytes(content, "text/plain", config)
+
+    assert result.content
+    assert len(result.content.split()) <= 20
+
+
+@pytest.mark.asyncio
+async def test_extract_bytes_with_text_metrics() -> None:
+    """Test readability metrics are attached to the metadata."""
+    content = b"The cat sat on the mat. The dog ran to the park. We had fun."
+
+    config = ExtractionConfig(postprocessor=PostProcessorConfig(metrics=TextMetricsConfig()))
+    result = await extract_bytes(content, "text/plain", config)
+
+    metrics = result.metadata["text_metrics"]
+    assert metrics["sentence_count"] == 3
+    assert metrics["word_count"] == 14
+    assert metrics["flesch_reading_ease"] > 90
+
+
+@pytest.mark.asyncio
+async def test_extract_bytes_with_custom_taggers() -> None:
+    """Test regex and dictionary tagger matches are attached to the metadata."""
+    content = b"Contract CN-123456 covers 40 Widget Pro units."
+
+    taggers = {"contract_number": r"\bCN-\d{6}\b", "product": ["widget pro"]}
+    config = ExtractionConfig(postprocessor=PostProcessorConfig(taggers=taggers))
+    result = await extract_bytes(content, "text/plain", config)
+
+    tags = result.metadata["tags"]
+    assert tags["contract_number"] == [{"text": "CN-123456", "byte_start": 9, "byte_end": 18}]
+    assert tags["product"][0]["text"] == "Widget Pro"
+    assert config.postprocessor is not None
+    assert config.postprocessor.taggers == taggers
 
 
 @pytest.mark.asyncio
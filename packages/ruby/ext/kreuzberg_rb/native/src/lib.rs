@@ -10,22 +10,31 @@ use html_to_markdown_rs::options::{
     WhitespaceMode,
 };
 use kreuzberg::core::config::PageConfig;
+use kreuzberg::entities::EntityConfig;
 use kreuzberg::keywords::{
     KeywordAlgorithm as RustKeywordAlgorithm, KeywordConfig as RustKeywordConfig, RakeParams as RustRakeParams,
     YakeParams as RustYakeParams,
 };
 use kreuzberg::types::TesseractConfig as RustTesseractConfig;
 use kreuzberg::{
-    ChunkingConfig, EmbeddingConfig, ExtractionConfig, ExtractionResult as RustExtractionResult, ImageExtractionConfig,
-    ImagePreprocessingConfig, KreuzbergError, LanguageDetectionConfig, OcrConfig, PdfConfig, PostProcessorConfig,
-    TokenReductionConfig,
+    ChunkingConfig, EmbeddingConfig, EntityType, ExtractionConfig, ExtractionResult as RustExtractionResult,
+    HeaderFooterConfig, ImageExtractionConfig, ImagePreprocessingConfig, KreuzbergError, LanguageDetectionConfig,
+    LineRepairConfig, NormalizationForm, OcrConfig, PdfConfig, PostProcessorConfig, QualityGateAction,
+    QualityGateConfig, StopwordsConfig, StopwordsMode, TextMetricsConfig, TextNormalizationConfig, TokenReductionConfig,
 };
 use magnus::exception::ExceptionClass;
 use magnus::r_hash::ForEach;
 use magnus::value::ReprValue;
-use magnus::{Error, IntoValue, RArray, RHash, Ruby, Symbol, TryConvert, Value, function, scan_args::scan_args};
+use magnus::{
+    Class, Error, IntoValue, Module, Object, RArray, RHash, Ruby, Symbol, TryConvert, Value, function, method,
+    scan_args::scan_args,
+};
+use std::ffi::c_void;
 use std::fs;
+use std::panic::{self, AssertUnwindSafe};
 use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// Keeps Ruby values alive across plugin registrations by informing the GC.
 struct GcGuardedValue {
@@ -52,6 +61,59 @@ impl Drop for GcGuardedValue {
     }
 }
 
+/// Set once a plugin implemented in Ruby is registered.
+///
+/// Ruby plugins are called on the extracting thread and need the GVL, so extraction only
+/// releases the GVL while none have been registered.
+static RUBY_PLUGINS_REGISTERED: AtomicBool = AtomicBool::new(false);
+
+/// Run `func` without holding the GVL, so other Ruby threads can run meanwhile.
+///
+/// `func` must not touch Ruby objects. Panics are carried across the C boundary and resumed
+/// once the GVL is held again.
+fn without_gvl<F, R>(func: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    if RUBY_PLUGINS_REGISTERED.load(Ordering::Acquire) {
+        return func();
+    }
+
+    struct Call<F, R> {
+        func: Option<F>,
+        result: Option<std::thread::Result<R>>,
+    }
+
+    unsafe extern "C" fn call<F, R>(data: *mut c_void) -> *mut c_void
+    where
+        F: FnOnce() -> R,
+    {
+        let call = unsafe { &mut *data.cast::<Call<F, R>>() };
+        if let Some(func) = call.func.take() {
+            call.result = Some(panic::catch_unwind(AssertUnwindSafe(func)));
+        }
+        ptr::null_mut()
+    }
+
+    let mut call = Call {
+        func: Some(func),
+        result: None,
+    };
+    unsafe {
+        rb_sys::rb_thread_call_without_gvl(
+            Some(call::<F, R>),
+            (&mut call as *mut Call<F, R>).cast(),
+            None,
+            ptr::null_mut(),
+        );
+    }
+
+    match call.result.expect("without_gvl callback did not run") {
+        Ok(result) => result,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
 unsafe extern "C" {
     fn kreuzberg_last_error_code() -> i32;
     fn kreuzberg_last_panic_context() -> *const std::ffi::c_char;
@@ -82,39 +144,40 @@ fn get_error_code() -> i32 {
 /// Convert Kreuzberg errors to Ruby exceptions
 fn kreuzberg_error(err: KreuzbergError) -> Error {
     let ruby = Ruby::get().expect("Ruby not initialized");
+    let info = err.to_info();
 
     let fetch_error_class = |name: &str| -> Option<ExceptionClass> {
         ruby.eval::<ExceptionClass>(&format!("Kreuzberg::Errors::{}", name))
             .ok()
     };
 
-    match err {
+    let (class, message) = match err {
         KreuzbergError::Validation { message, .. } => {
             if let Some(class) = fetch_error_class("ValidationError") {
-                Error::new(class, message)
+                (class, message)
             } else {
-                Error::new(ruby.exception_arg_error(), message)
+                (ruby.exception_arg_error(), message)
             }
         }
         KreuzbergError::Parsing { message, .. } => {
             if let Some(class) = fetch_error_class("ParsingError") {
-                Error::new(class, message)
+                (class, message)
             } else {
-                Error::new(ruby.exception_runtime_error(), format!("ParsingError: {}", message))
+                (ruby.exception_runtime_error(), format!("ParsingError: {}", message))
             }
         }
         KreuzbergError::Ocr { message, .. } => {
             if let Some(class) = fetch_error_class("OCRError") {
-                Error::new(class, message)
+                (class, message)
             } else {
-                Error::new(ruby.exception_runtime_error(), format!("OCRError: {}", message))
+                (ruby.exception_runtime_error(), format!("OCRError: {}", message))
             }
         }
         KreuzbergError::MissingDependency(message) => {
             if let Some(class) = fetch_error_class("MissingDependencyError") {
-                Error::new(class, message)
+                (class, message)
             } else {
-                Error::new(
+                (
                     ruby.exception_runtime_error(),
                     format!("MissingDependencyError: {}", message),
                 )
@@ -122,9 +185,9 @@ fn kreuzberg_error(err: KreuzbergError) -> Error {
         }
         KreuzbergError::Plugin { message, plugin_name } => {
             if let Some(class) = fetch_error_class("PluginError") {
-                Error::new(class, format!("{}: {}", plugin_name, message))
+                (class, format!("{}: {}", plugin_name, message))
             } else {
-                Error::new(
+                (
                     ruby.exception_runtime_error(),
                     format!("Plugin error in '{}': {}", plugin_name, message),
                 )
@@ -132,23 +195,49 @@ fn kreuzberg_error(err: KreuzbergError) -> Error {
         }
         KreuzbergError::Io(err) => {
             if let Some(class) = fetch_error_class("IOError") {
-                Error::new(class, err.to_string())
+                (class, err.to_string())
             } else {
-                Error::new(ruby.exception_runtime_error(), format!("IO error: {}", err))
+                (ruby.exception_runtime_error(), format!("IO error: {}", err))
             }
         }
         KreuzbergError::UnsupportedFormat(message) => {
             if let Some(class) = fetch_error_class("UnsupportedFormatError") {
-                Error::new(class, message)
+                (class, message)
             } else {
-                Error::new(
+                (
                     ruby.exception_runtime_error(),
                     format!("UnsupportedFormatError: {}", message),
                 )
             }
         }
-        other => Error::new(ruby.exception_runtime_error(), other.to_string()),
+        other => (ruby.exception_runtime_error(), other.to_string()),
+    };
+
+    structured_exception(&ruby, class, message, &info)
+}
+
+/// Instantiate `class` with the error's `ErrorInfo` as `@code`, `@stage`, `@plugin` and
+/// `@retryable`, read by `Kreuzberg::Errors::Error`.
+fn structured_exception(ruby: &Ruby, class: ExceptionClass, message: String, info: &kreuzberg::ErrorInfo) -> Error {
+    let Ok(exception) = class.new_instance((message.as_str(),)) else {
+        return Error::new(class, message);
+    };
+
+    let optional = |value: &Option<String>| match value {
+        Some(value) => ruby.str_new(value).as_value(),
+        None => ruby.qnil().as_value(),
+    };
+    let ivars = [
+        ("@code", ruby.str_new(info.code.as_str()).as_value()),
+        ("@stage", optional(&info.stage)),
+        ("@plugin", optional(&info.plugin)),
+        ("@retryable", info.retryable.into_value_with(ruby)),
+    ];
+    for (name, value) in ivars {
+        let _: Result<Value, Error> = exception.funcall("instance_variable_set", (name, value));
     }
+
+    Error::from(exception)
 }
 
 fn runtime_error(message: impl Into<String>) -> Error {
@@ -417,10 +506,16 @@ fn parse_language_detection_config(ruby: &Ruby, hash: RHash) -> Result<LanguageD
         false
     };
 
+    let max_sample_kb = match get_kw(ruby, hash, "max_sample_kb") {
+        Some(val) if !val.is_nil() => Some(usize::try_convert(val)?),
+        _ => None,
+    };
+
     let config = LanguageDetectionConfig {
         enabled,
         min_confidence,
         detect_multiple,
+        max_sample_kb,
     };
 
     Ok(config)
@@ -451,10 +546,24 @@ fn parse_pdf_config(ruby: &Ruby, hash: RHash) -> Result<PdfConfig, Error> {
         true
     };
 
+    let validate_pdfa = if let Some(val) = get_kw(ruby, hash, "validate_pdfa") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
+    let layout_stats = if let Some(val) = get_kw(ruby, hash, "layout_stats") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
     let config = PdfConfig {
         extract_images,
         passwords,
         extract_metadata,
+        validate_pdfa,
+        layout_stats,
     };
 
     Ok(config)
@@ -498,6 +607,18 @@ fn parse_image_extraction_config(ruby: &Ruby, hash: RHash) -> Result<ImageExtrac
         600
     };
 
+    let describer = if let Some(val) = get_kw(ruby, hash, "describer") {
+        Some(String::try_convert(val)?)
+    } else {
+        None
+    };
+
+    let inline_descriptions = if let Some(val) = get_kw(ruby, hash, "inline_descriptions") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
     let config = ImageExtractionConfig {
         extract_images,
         target_dpi,
@@ -505,6 +626,8 @@ fn parse_image_extraction_config(ruby: &Ruby, hash: RHash) -> Result<ImageExtrac
         auto_adjust_dpi,
         min_dpi,
         max_dpi,
+        describer,
+        inline_descriptions,
     };
 
     Ok(config)
@@ -534,6 +657,12 @@ fn parse_image_preprocessing_config(ruby: &Ruby, hash: RHash) -> Result<ImagePre
         true
     };
 
+    let grayscale = if let Some(val) = get_kw(ruby, hash, "grayscale") {
+        bool::try_convert(val)?
+    } else {
+        true
+    };
+
     let denoise = if let Some(val) = get_kw(ruby, hash, "denoise") {
         bool::try_convert(val)?
     } else {
@@ -558,14 +687,22 @@ fn parse_image_preprocessing_config(ruby: &Ruby, hash: RHash) -> Result<ImagePre
         false
     };
 
+    let remove_borders = if let Some(val) = get_kw(ruby, hash, "remove_borders") {
+        bool::try_convert(val)?
+    } else {
+        false
+    };
+
     let config = ImagePreprocessingConfig {
         target_dpi,
         auto_rotate,
         deskew,
+        grayscale,
         denoise,
         contrast_enhance,
         binarization_method,
         invert_colors,
+        remove_borders,
     };
 
     Ok(config)
@@ -597,15 +734,210 @@ fn parse_postprocessor_config(ruby: &Ruby, hash: RHash) -> Result<PostProcessorC
         None
     };
 
+    let header_footer = if let Some(val) = get_kw(ruby, hash, "header_footer")
+        && !val.is_nil()
+    {
+        if let Ok(enabled) = bool::try_convert(val) {
+            enabled.then(HeaderFooterConfig::default)
+        } else {
+            let hf_hash = RHash::try_convert(val)?;
+            Some(parse_header_footer_config(ruby, hf_hash)?)
+        }
+    } else {
+        None
+    };
+
+    let line_repair = if let Some(val) = get_kw(ruby, hash, "line_repair")
+        && !val.is_nil()
+    {
+        if let Ok(enabled) = bool::try_convert(val) {
+            enabled.then(LineRepairConfig::default)
+        } else {
+            let lr_hash = RHash::try_convert(val)?;
+            Some(parse_line_repair_config(ruby, lr_hash)?)
+        }
+    } else {
+        None
+    };
+
+    let normalization = if let Some(val) = get_kw(ruby, hash, "normalization")
+        && !val.is_nil()
+    {
+        if let Ok(enabled) = bool::try_convert(val) {
+            enabled.then(TextNormalizationConfig::default)
+        } else {
+            let norm_hash = RHash::try_convert(val)?;
+            Some(parse_text_normalization_config(ruby, norm_hash)?)
+        }
+    } else {
+        None
+    };
+
+    let metrics = if let Some(val) = get_kw(ruby, hash, "metrics")
+        && !val.is_nil()
+    {
+        if let Ok(enabled) = bool::try_convert(val) {
+            enabled.then(TextMetricsConfig::default)
+        } else {
+            let metrics_hash = RHash::try_convert(val)?;
+            let mut metrics_config = TextMetricsConfig::default();
+            if let Some(val) = get_kw(ruby, metrics_hash, "long_word_length") {
+                metrics_config.long_word_length = usize::try_convert(val)?;
+            }
+            Some(metrics_config)
+        }
+    } else {
+        None
+    };
+
+    let taggers = if let Some(val) = get_kw(ruby, hash, "taggers")
+        && !val.is_nil()
+    {
+        let taggers_json = ruby_value_to_json(val)?;
+        Some(serde_json::from_value(taggers_json).map_err(|e| runtime_error(format!("Invalid taggers: {}", e)))?)
+    } else {
+        None
+    };
+
     let config = PostProcessorConfig {
         enabled,
         enabled_processors,
         disabled_processors,
+        header_footer,
+        line_repair,
+        normalization,
+        metrics,
+        taggers,
     };
 
     Ok(config)
 }
 
+/// Parse HeaderFooterConfig from Ruby Hash
+fn parse_header_footer_config(ruby: &Ruby, hash: RHash) -> Result<HeaderFooterConfig, Error> {
+    let mut config = HeaderFooterConfig::default();
+
+    if let Some(val) = get_kw(ruby, hash, "lines_to_check") {
+        config.lines_to_check = usize::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "min_pages") {
+        config.min_pages = usize::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "min_repeat_ratio") {
+        config.min_repeat_ratio = f64::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "remove_page_numbers") {
+        config.remove_page_numbers = bool::try_convert(val)?;
+    }
+
+    Ok(config)
+}
+
+/// Parse LineRepairConfig from Ruby Hash
+fn parse_line_repair_config(ruby: &Ruby, hash: RHash) -> Result<LineRepairConfig, Error> {
+    let mut config = LineRepairConfig::default();
+
+    if let Some(val) = get_kw(ruby, hash, "dehyphenate") {
+        config.dehyphenate = bool::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "merge_lines") {
+        config.merge_lines = bool::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "language")
+        && !val.is_nil()
+    {
+        config.language = Some(symbol_to_string(val)?);
+    }
+
+    Ok(config)
+}
+
+/// Parse EntityConfig from Ruby Hash
+fn parse_entity_config(ruby: &Ruby, hash: RHash) -> Result<EntityConfig, Error> {
+    let mut config = EntityConfig::default();
+
+    if let Some(val) = get_kw(ruby, hash, "entity_types")
+        && !val.is_nil()
+    {
+        let arr = RArray::try_convert(val)?;
+        config.entity_types = arr
+            .into_iter()
+            .map(|item| {
+                symbol_to_string(item)?
+                    .parse::<EntityType>()
+                    .map_err(|e| runtime_error(e.to_string()))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "min_confidence") {
+        config.min_confidence = f64::try_convert(val)?;
+    }
+
+    Ok(config)
+}
+
+/// Parse TextNormalizationConfig from Ruby Hash
+fn parse_text_normalization_config(ruby: &Ruby, hash: RHash) -> Result<TextNormalizationConfig, Error> {
+    let mut config = TextNormalizationConfig::default();
+
+    if let Some(val) = get_kw(ruby, hash, "form")
+        && !val.is_nil()
+    {
+        config.form = symbol_to_string(val)?
+            .parse::<NormalizationForm>()
+            .map_err(|e| runtime_error(e.to_string()))?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "remove_zero_width") {
+        config.remove_zero_width = bool::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "remove_bidi_controls") {
+        config.remove_bidi_controls = bool::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "remove_control_chars") {
+        config.remove_control_chars = bool::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "canonicalize_quotes") {
+        config.canonicalize_quotes = bool::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "canonicalize_dashes") {
+        config.canonicalize_dashes = bool::try_convert(val)?;
+    }
+
+    Ok(config)
+}
+
+/// Parse QualityGateConfig from Ruby Hash
+fn parse_quality_gate_config(ruby: &Ruby, hash: RHash) -> Result<QualityGateConfig, Error> {
+    let mut config = QualityGateConfig::default();
+
+    if let Some(val) = get_kw(ruby, hash, "min_dictionary_hit_rate") {
+        config.min_dictionary_hit_rate = f64::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "min_confusion_score") {
+        config.min_confusion_score = f64::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "min_words") {
+        config.min_words = usize::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "ocr_only") {
+        config.ocr_only = bool::try_convert(val)?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "action")
+        && !val.is_nil()
+    {
+        config.action = symbol_to_string(val)?
+            .parse::<QualityGateAction>()
+            .map_err(|e| runtime_error(e.to_string()))?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "language")
+        && !val.is_nil()
+    {
+        config.language = Some(symbol_to_string(val)?);
+    }
+
+    Ok(config)
+}
+
 /// Parse TokenReductionConfig from Ruby Hash
 fn parse_token_reduction_config(ruby: &Ruby, hash: RHash) -> Result<TokenReductionConfig, Error> {
     let mode = if let Some(val) = get_kw(ruby, hash, "mode") {
@@ -620,14 +952,59 @@ fn parse_token_reduction_config(ruby: &Ruby, hash: RHash) -> Result<TokenReducti
         true
     };
 
+    let stopwords = match get_kw(ruby, hash, "stopwords") {
+        Some(val) if !val.is_nil() => Some(parse_stopwords_config(ruby, RHash::try_convert(val)?)?),
+        _ => None,
+    };
+
+    let target_ratio = match get_kw(ruby, hash, "target_ratio") {
+        Some(val) if !val.is_nil() => Some(f64::try_convert(val)? as f32),
+        _ => None,
+    };
+
+    let max_tokens = match get_kw(ruby, hash, "max_tokens") {
+        Some(val) if !val.is_nil() => Some(usize::try_convert(val)?),
+        _ => None,
+    };
+
     let config = TokenReductionConfig {
         mode,
         preserve_important_words,
+        stopwords,
+        target_ratio,
+        max_tokens,
     };
 
     Ok(config)
 }
 
+/// Parse StopwordsConfig from Ruby Hash
+fn parse_stopwords_config(ruby: &Ruby, hash: RHash) -> Result<StopwordsConfig, Error> {
+    let mut config = StopwordsConfig::default();
+
+    if let Some(val) = get_kw(ruby, hash, "custom")
+        && !val.is_nil()
+    {
+        config.custom = RArray::try_convert(val)?.to_vec::<String>()?;
+    }
+    if let Some(val) = get_kw(ruby, hash, "mode")
+        && !val.is_nil()
+    {
+        config.mode = symbol_to_string(val)?
+            .parse::<StopwordsMode>()
+            .map_err(|e| runtime_error(e.to_string()))?;
+    }
+
+    Ok(config)
+}
+
+fn stopwords_config_to_ruby_hash(ruby: &Ruby, config: &StopwordsConfig) -> Result<RHash, Error> {
+    let hash = ruby.hash_new();
+    hash.aset("custom", config.custom.clone())?;
+    hash.aset("mode", config.mode.as_str())?;
+    Ok(hash)
+}
+
 fn parse_keyword_config(ruby: &Ruby, hash: RHash) -> Result<RustKeywordConfig, Error> {
     let mut config = RustKeywordConfig::default();
 
@@ -669,6 +1046,12 @@ fn parse_keyword_config(ruby: &Ruby, hash: RHash) -> Result<RustKeywordConfig, E
         config.language = Some(symbol_to_string(val)?);
     }
 
+    if let Some(val) = get_kw(ruby, hash, "stopwords")
+        && !val.is_nil()
+    {
+        config.stopwords = Some(parse_stopwords_config(ruby, RHash::try_convert(val)?)?);
+    }
+
     if let Some(val) = get_kw(ruby, hash, "yake_params")
         && !val.is_nil()
     {
@@ -932,6 +1315,10 @@ fn keyword_config_to_ruby_hash(ruby: &Ruby, config: &RustKeywordConfig) -> Resul
     range_array.push(config.ngram_range.1 as i64)?;
     hash.aset("ngram_range", range_array)?;
 
+    if let Some(stopwords) = &config.stopwords {
+        hash.aset("stopwords", stopwords_config_to_ruby_hash(ruby, stopwords)?)?;
+    }
+
     if let Some(yake) = &config.yake_params {
         let yake_hash = ruby.hash_new();
         yake_hash.aset("window_size", yake.window_size as i64)?;
@@ -1100,6 +1487,18 @@ fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extractio
             config.force_ocr = bool::try_convert(val)?;
         }
 
+        if let Some(val) = get_kw(ruby, hash, "collect_timings") {
+            config.collect_timings = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "strict_mime") {
+            config.strict_mime = bool::try_convert(val)?;
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "strict") {
+            config.strict = bool::try_convert(val)?;
+        }
+
         if let Some(val) = get_kw(ruby, hash, "ocr")
             && !val.is_nil()
         {
@@ -1156,6 +1555,20 @@ fn parse_extraction_config(ruby: &Ruby, opts: Option<RHash>) -> Result<Extractio
             config.keywords = Some(parse_keyword_config(ruby, keywords_hash)?);
         }
 
+        if let Some(val) = get_kw(ruby, hash, "entities")
+            && !val.is_nil()
+        {
+            let entities_hash = RHash::try_convert(val)?;
+            config.entities = Some(parse_entity_config(ruby, entities_hash)?);
+        }
+
+        if let Some(val) = get_kw(ruby, hash, "quality_gate")
+            && !val.is_nil()
+        {
+            let quality_gate_hash = RHash::try_convert(val)?;
+            config.quality_gate = Some(parse_quality_gate_config(ruby, quality_gate_hash)?);
+        }
+
         if let Some(val) = get_kw(ruby, hash, "html_options")
             && !val.is_nil()
         {
@@ -1216,6 +1629,36 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
             ruby.qfalse().as_value()
         },
     )?;
+    set_hash_entry(
+        ruby,
+        &hash,
+        "collect_timings",
+        if config.collect_timings {
+            ruby.qtrue().as_value()
+        } else {
+            ruby.qfalse().as_value()
+        },
+    )?;
+    set_hash_entry(
+        ruby,
+        &hash,
+        "strict_mime",
+        if config.strict_mime {
+            ruby.qtrue().as_value()
+        } else {
+            ruby.qfalse().as_value()
+        },
+    )?;
+    set_hash_entry(
+        ruby,
+        &hash,
+        "strict",
+        if config.strict {
+            ruby.qtrue().as_value()
+        } else {
+            ruby.qfalse().as_value()
+        },
+    )?;
 
     if let Some(ocr) = config.ocr {
         let ocr_hash = ruby.hash_new();
@@ -1299,6 +1742,14 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
                 ruby.qfalse().as_value()
             },
         )?;
+        if let Some(max_sample_kb) = lang_detection.max_sample_kb {
+            set_hash_entry(
+                ruby,
+                &lang_hash,
+                "max_sample_kb",
+                ruby.integer_from_i64(max_sample_kb as i64).into_value_with(ruby),
+            )?;
+        }
         set_hash_entry(ruby, &hash, "language_detection", lang_hash.into_value_with(ruby))?;
     }
 
@@ -1378,6 +1829,24 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
             "max_dpi",
             ruby.integer_from_i64(images.max_dpi as i64).into_value_with(ruby),
         )?;
+        if let Some(describer) = images.describer {
+            set_hash_entry(
+                ruby,
+                &images_hash,
+                "describer",
+                ruby.str_new(&describer).into_value_with(ruby),
+            )?;
+        }
+        set_hash_entry(
+            ruby,
+            &images_hash,
+            "inline_descriptions",
+            if images.inline_descriptions {
+                ruby.qtrue().as_value()
+            } else {
+                ruby.qfalse().as_value()
+            },
+        )?;
         set_hash_entry(ruby, &hash, "image_extraction", images_hash.into_value_with(ruby))?;
     }
 
@@ -1411,6 +1880,104 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
                 disabled_array.into_value_with(ruby),
             )?;
         }
+        if let Some(header_footer) = postprocessor.header_footer {
+            let hf_hash = ruby.hash_new();
+            set_hash_entry(
+                ruby,
+                &hf_hash,
+                "lines_to_check",
+                ruby.integer_from_u64(header_footer.lines_to_check as u64).into_value_with(ruby),
+            )?;
+            set_hash_entry(
+                ruby,
+                &hf_hash,
+                "min_pages",
+                ruby.integer_from_u64(header_footer.min_pages as u64).into_value_with(ruby),
+            )?;
+            set_hash_entry(
+                ruby,
+                &hf_hash,
+                "min_repeat_ratio",
+                ruby.float_from_f64(header_footer.min_repeat_ratio).into_value_with(ruby),
+            )?;
+            set_hash_entry(
+                ruby,
+                &hf_hash,
+                "remove_page_numbers",
+                if header_footer.remove_page_numbers {
+                    ruby.qtrue().as_value()
+                } else {
+                    ruby.qfalse().as_value()
+                },
+            )?;
+            set_hash_entry(ruby, &pp_hash, "header_footer", hf_hash.into_value_with(ruby))?;
+        }
+        if let Some(line_repair) = postprocessor.line_repair {
+            let lr_hash = ruby.hash_new();
+            set_hash_entry(
+                ruby,
+                &lr_hash,
+                "dehyphenate",
+                if line_repair.dehyphenate {
+                    ruby.qtrue().as_value()
+                } else {
+                    ruby.qfalse().as_value()
+                },
+            )?;
+            set_hash_entry(
+                ruby,
+                &lr_hash,
+                "merge_lines",
+                if line_repair.merge_lines {
+                    ruby.qtrue().as_value()
+                } else {
+                    ruby.qfalse().as_value()
+                },
+            )?;
+            if let Some(language) = line_repair.language {
+                set_hash_entry(ruby, &lr_hash, "language", ruby.str_new(&language).into_value_with(ruby))?;
+            }
+            set_hash_entry(ruby, &pp_hash, "line_repair", lr_hash.into_value_with(ruby))?;
+        }
+        if let Some(normalization) = postprocessor.normalization {
+            let norm_hash = ruby.hash_new();
+            set_hash_entry(
+                ruby,
+                &norm_hash,
+                "form",
+                ruby.str_new(normalization.form.as_str()).into_value_with(ruby),
+            )?;
+            for (key, enabled) in [
+                ("remove_zero_width", normalization.remove_zero_width),
+                ("remove_bidi_controls", normalization.remove_bidi_controls),
+                ("remove_control_chars", normalization.remove_control_chars),
+                ("canonicalize_quotes", normalization.canonicalize_quotes),
+                ("canonicalize_dashes", normalization.canonicalize_dashes),
+            ] {
+                let value = if enabled {
+                    ruby.qtrue().as_value()
+                } else {
+                    ruby.qfalse().as_value()
+                };
+                set_hash_entry(ruby, &norm_hash, key, value)?;
+            }
+            set_hash_entry(ruby, &pp_hash, "normalization", norm_hash.into_value_with(ruby))?;
+        }
+        if let Some(metrics) = postprocessor.metrics {
+            let metrics_hash = ruby.hash_new();
+            set_hash_entry(
+                ruby,
+                &metrics_hash,
+                "long_word_length",
+                ruby.integer_from_u64(metrics.long_word_length as u64).into_value_with(ruby),
+            )?;
+            set_hash_entry(ruby, &pp_hash, "metrics", metrics_hash.into_value_with(ruby))?;
+        }
+        if let Some(taggers) = postprocessor.taggers {
+            let taggers_json = serde_json::to_value(&taggers)
+                .map_err(|e| runtime_error(format!("Failed to serialize taggers: {}", e)))?;
+            set_hash_entry(ruby, &pp_hash, "taggers", json_value_to_ruby(ruby, &taggers_json)?)?;
+        }
         set_hash_entry(ruby, &hash, "postprocessor", pp_hash.into_value_with(ruby))?;
     }
 
@@ -1432,6 +1999,26 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
                 ruby.qfalse().as_value()
             },
         )?;
+        if let Some(stopwords) = &token_reduction.stopwords {
+            let stopwords_hash = stopwords_config_to_ruby_hash(ruby, stopwords)?;
+            set_hash_entry(ruby, &tr_hash, "stopwords", stopwords_hash.into_value_with(ruby))?;
+        }
+        if let Some(target_ratio) = token_reduction.target_ratio {
+            set_hash_entry(
+                ruby,
+                &tr_hash,
+                "target_ratio",
+                ruby.float_from_f64(f64::from(target_ratio)).into_value_with(ruby),
+            )?;
+        }
+        if let Some(max_tokens) = token_reduction.max_tokens {
+            set_hash_entry(
+                ruby,
+                &tr_hash,
+                "max_tokens",
+                ruby.integer_from_i64(max_tokens as i64).into_value_with(ruby),
+            )?;
+        }
         set_hash_entry(ruby, &hash, "token_reduction", tr_hash.into_value_with(ruby))?;
     }
 
@@ -1440,6 +2027,30 @@ fn extraction_config_to_ruby_hash(ruby: &Ruby, config: ExtractionConfig) -> Resu
         set_hash_entry(ruby, &hash, "keywords", keywords_hash.into_value_with(ruby))?;
     }
 
+    if let Some(entities) = config.entities {
+        let entities_hash = ruby.hash_new();
+        let types_array = ruby.ary_new();
+        for entity_type in &entities.entity_types {
+            types_array.push(entity_type.as_str())?;
+        }
+        entities_hash.aset("entity_types", types_array)?;
+        entities_hash.aset("min_confidence", entities.min_confidence)?;
+        set_hash_entry(ruby, &hash, "entities", entities_hash.into_value_with(ruby))?;
+    }
+
+    if let Some(quality_gate) = config.quality_gate {
+        let gate_hash = ruby.hash_new();
+        gate_hash.aset("min_dictionary_hit_rate", quality_gate.min_dictionary_hit_rate)?;
+        gate_hash.aset("min_confusion_score", quality_gate.min_confusion_score)?;
+        gate_hash.aset("min_words", quality_gate.min_words)?;
+        gate_hash.aset("ocr_only", quality_gate.ocr_only)?;
+        gate_hash.aset("action", quality_gate.action.as_str())?;
+        if let Some(language) = quality_gate.language {
+            gate_hash.aset("language", language)?;
+        }
+        set_hash_entry(ruby, &hash, "quality_gate", gate_hash.into_value_with(ruby))?;
+    }
+
     if let Some(html_options) = config.html_options {
         let html_hash = html_options_to_ruby_hash(ruby, &html_options)?;
         set_hash_entry(ruby, &hash, "html_options", html_hash.into_value_with(ruby))?;
@@ -1560,9 +2171,15 @@ fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> Resul
     set_hash_entry(ruby, &hash, "tables", tables_value)?;
 
     if let Some(langs) = result.detected_languages {
-        let langs_array = ruby.ary_from_vec(langs);
-        let langs_value = langs_array.into_value_with(ruby);
-        set_hash_entry(ruby, &hash, "detected_languages", langs_value)?;
+        let langs_array = ruby.ary_new();
+        for lang in langs {
+            let lang_hash = ruby.hash_new();
+            lang_hash.aset("language", lang.language)?;
+            lang_hash.aset("confidence", lang.confidence)?;
+            lang_hash.aset("script", lang.script)?;
+            langs_array.push(lang_hash)?;
+        }
+        set_hash_entry(ruby, &hash, "detected_languages", langs_array.into_value_with(ruby))?;
     } else {
         set_hash_entry(ruby, &hash, "detected_languages", ruby.qnil().as_value())?;
     }
@@ -1631,6 +2248,11 @@ fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> Resul
             } else {
                 image_hash.aset("height", ruby.qnil().as_value())?;
             }
+            if let Some(dpi) = image.dpi {
+                image_hash.aset("dpi", dpi as i64)?;
+            } else {
+                image_hash.aset("dpi", ruby.qnil().as_value())?;
+            }
             if let Some(colorspace) = image.colorspace {
                 image_hash.aset("colorspace", colorspace)?;
             } else {
@@ -1670,92 +2292,154 @@ fn extraction_result_to_ruby(ruby: &Ruby, result: RustExtractionResult) -> Resul
     if let Some(page_content_list) = result.pages {
         let pages_array = ruby.ary_new();
         for page_content in page_content_list {
-            let page_hash = ruby.hash_new();
-            page_hash.aset("page_number", page_content.page_number as i64)?;
-            page_hash.aset("content", page_content.content)?;
-
-            let tables_array = ruby.ary_new();
-            for table in page_content.tables {
-                let table_hash = ruby.hash_new();
-
-                let cells_array = ruby.ary_new();
-                for row in table.cells {
-                    let row_array = ruby.ary_from_vec(row);
-                    cells_array.push(row_array)?;
-                }
-                table_hash.aset("cells", cells_array)?;
-                table_hash.aset("markdown", table.markdown)?;
-                table_hash.aset("page_number", table.page_number as i64)?;
-
-                tables_array.push(table_hash)?;
-            }
-            page_hash.aset("tables", tables_array)?;
-
-            let images_array = ruby.ary_new();
-            for image in page_content.images {
-                let image_hash = ruby.hash_new();
-                let data_value = ruby.str_from_slice(&image.data).into_value_with(ruby);
-                image_hash.aset("data", data_value)?;
-                image_hash.aset("format", image.format)?;
-                image_hash.aset("image_index", image.image_index as i64)?;
-                if let Some(page) = image.page_number {
-                    image_hash.aset("page_number", page as i64)?;
-                } else {
-                    image_hash.aset("page_number", ruby.qnil().as_value())?;
-                }
-                if let Some(width) = image.width {
-                    image_hash.aset("width", width as i64)?;
-                } else {
-                    image_hash.aset("width", ruby.qnil().as_value())?;
-                }
-                if let Some(height) = image.height {
-                    image_hash.aset("height", height as i64)?;
-                } else {
-                    image_hash.aset("height", ruby.qnil().as_value())?;
-                }
-                if let Some(colorspace) = image.colorspace {
-                    image_hash.aset("colorspace", colorspace)?;
-                } else {
-                    image_hash.aset("colorspace", ruby.qnil().as_value())?;
-                }
-                if let Some(bits) = image.bits_per_component {
-                    image_hash.aset("bits_per_component", bits as i64)?;
-                } else {
-                    image_hash.aset("bits_per_component", ruby.qnil().as_value())?;
-                }
-                image_hash.aset(
-                    "is_mask",
-                    if image.is_mask {
-                        ruby.qtrue().as_value()
-                    } else {
-                        ruby.qfalse().as_value()
-                    },
-                )?;
-                if let Some(description) = image.description {
-                    image_hash.aset("description", description)?;
-                } else {
-                    image_hash.aset("description", ruby.qnil().as_value())?;
-                }
-                if let Some(ocr_result) = image.ocr_result {
-                    let nested = extraction_result_to_ruby(ruby, *ocr_result)?;
-                    image_hash.aset("ocr_result", nested.into_value_with(ruby))?;
-                } else {
-                    image_hash.aset("ocr_result", ruby.qnil().as_value())?;
-                }
-                images_array.push(image_hash)?;
-            }
-            page_hash.aset("images", images_array)?;
-
-            pages_array.push(page_hash)?;
+            pages_array.push(page_content_to_ruby(ruby, page_content)?)?;
         }
         set_hash_entry(ruby, &hash, "pages", pages_array.into_value_with(ruby))?;
     } else {
         set_hash_entry(ruby, &hash, "pages", ruby.qnil().as_value())?;
     }
 
+    if let Some(entities) = result.entities {
+        let entities_array = ruby.ary_new();
+        for entity in entities {
+            let entity_hash = ruby.hash_new();
+            entity_hash.aset("entity_type", entity.entity_type.as_str())?;
+            entity_hash.aset("text", entity.text)?;
+            entity_hash.aset("byte_start", entity.byte_start as i64)?;
+            entity_hash.aset("byte_end", entity.byte_end as i64)?;
+            entity_hash.aset("confidence", entity.confidence)?;
+            entities_array.push(entity_hash)?;
+        }
+        set_hash_entry(ruby, &hash, "entities", entities_array.into_value_with(ruby))?;
+    } else {
+        set_hash_entry(ruby, &hash, "entities", ruby.qnil().as_value())?;
+    }
+
+    if let Some(keywords) = result.keywords {
+        let keywords_array = ruby.ary_new();
+        for keyword in keywords {
+            let keyword_hash = ruby.hash_new();
+            keyword_hash.aset("text", keyword.text)?;
+            keyword_hash.aset("score", keyword.score as f64)?;
+            keyword_hash.aset("algorithm", keyword.algorithm.as_str())?;
+            keyword_hash.aset(
+                "positions",
+                keyword
+                    .positions
+                    .map(|positions| positions.into_iter().map(|pos| pos as i64).collect::<Vec<_>>()),
+            )?;
+            keywords_array.push(keyword_hash)?;
+        }
+        set_hash_entry(ruby, &hash, "keywords", keywords_array.into_value_with(ruby))?;
+    } else {
+        set_hash_entry(ruby, &hash, "keywords", ruby.qnil().as_value())?;
+    }
+
+    let warnings_array = ruby.ary_new();
+    for warning in result.warnings {
+        let warning_hash = ruby.hash_new();
+        warning_hash.aset("code", warning.code)?;
+        warning_hash.aset("message", warning.message)?;
+        warning_hash.aset("stage", warning.stage.as_str())?;
+        warning_hash.aset("page", warning.page.map(|page| page as i64))?;
+        warnings_array.push(warning_hash)?;
+    }
+    set_hash_entry(ruby, &hash, "warnings", warnings_array.into_value_with(ruby))?;
+
     Ok(hash)
 }
 
+/// Convert a page of a result into a Ruby hash.
+fn page_content_to_ruby(ruby: &Ruby, page_content: kreuzberg::PageContent) -> Result<RHash, Error> {
+    let page_hash = ruby.hash_new();
+    page_hash.aset("page_number", page_content.page_number as i64)?;
+    page_hash.aset("content", page_content.content)?;
+
+    let tables_array = ruby.ary_new();
+    for table in page_content.tables {
+        tables_array.push(table_to_ruby(ruby, table)?)?;
+    }
+    page_hash.aset("tables", tables_array)?;
+
+    let images_array = ruby.ary_new();
+    for image in page_content.images {
+        let image_hash = ruby.hash_new();
+        let data_value = ruby.str_from_slice(&image.data).into_value_with(ruby);
+        image_hash.aset("data", data_value)?;
+        image_hash.aset("format", image.format)?;
+        image_hash.aset("image_index", image.image_index as i64)?;
+        if let Some(page) = image.page_number {
+            image_hash.aset("page_number", page as i64)?;
+        } else {
+            image_hash.aset("page_number", ruby.qnil().as_value())?;
+        }
+        if let Some(width) = image.width {
+            image_hash.aset("width", width as i64)?;
+        } else {
+            image_hash.aset("width", ruby.qnil().as_value())?;
+        }
+        if let Some(height) = image.height {
+            image_hash.aset("height", height as i64)?;
+        } else {
+            image_hash.aset("height", ruby.qnil().as_value())?;
+        }
+        if let Some(dpi) = image.dpi {
+            image_hash.aset("dpi", dpi as i64)?;
+        } else {
+            image_hash.aset("dpi", ruby.qnil().as_value())?;
+        }
+        if let Some(colorspace) = image.colorspace {
+            image_hash.aset("colorspace", colorspace)?;
+        } else {
+            image_hash.aset("colorspace", ruby.qnil().as_value())?;
+        }
+        if let Some(bits) = image.bits_per_component {
+            image_hash.aset("bits_per_component", bits as i64)?;
+        } else {
+            image_hash.aset("bits_per_component", ruby.qnil().as_value())?;
+        }
+        image_hash.aset(
+            "is_mask",
+            if image.is_mask {
+                ruby.qtrue().as_value()
+            } else {
+                ruby.qfalse().as_value()
+            },
+        )?;
+        if let Some(description) = image.description {
+            image_hash.aset("description", description)?;
+        } else {
+            image_hash.aset("description", ruby.qnil().as_value())?;
+        }
+        if let Some(ocr_result) = image.ocr_result {
+            let nested = extraction_result_to_ruby(ruby, *ocr_result)?;
+            image_hash.aset("ocr_result", nested.into_value_with(ruby))?;
+        } else {
+            image_hash.aset("ocr_result", ruby.qnil().as_value())?;
+        }
+        images_array.push(image_hash)?;
+    }
+    page_hash.aset("images", images_array)?;
+
+    Ok(page_hash)
+}
+
+/// Convert a table into a Ruby hash.
+fn table_to_ruby(ruby: &Ruby, table: kreuzberg::Table) -> Result<RHash, Error> {
+    let table_hash = ruby.hash_new();
+
+    let cells_array = ruby.ary_new();
+    for row in table.cells {
+        let row_array = ruby.ary_from_vec(row);
+        cells_array.push(row_array)?;
+    }
+    table_hash.aset("cells", cells_array)?;
+    table_hash.aset("markdown", table.markdown)?;
+    table_hash.aset("page_number", table.page_number as i64)?;
+
+    Ok(table_hash)
+}
+
 /// Extract content from a file (synchronous).
 ///
 /// @param path [String] Path to the file
@@ -1784,6 +2468,81 @@ fn extract_file_sync(args: &[Value]) -> Result<RHash, Error> {
     extraction_result_to_ruby(&ruby, result)
 }
 
+/// Extract a file and yield its pages, or its sections and tables, one at a time.
+///
+/// The file is extracted with page extraction enabled and without holding the GVL. Each event
+/// is converted to a Ruby hash only when it is yielded, so a large document is never held as
+/// Ruby objects all at once.
+///
+/// @param path [String] Path to the file
+/// @param mime_type [String, nil] Optional MIME type hint
+/// @param options [Hash] Extraction configuration
+/// @yieldparam event [Hash] Event with "type" ("document", "page", "section" or "table")
+/// @return [nil]
+///
+/// @example
+///   Kreuzberg.extract_file_each("big.pdf") do |event|
+///     puts event["page_number"] if event["type"] == "page"
+///   end
+///
+fn extract_file_each(args: &[Value]) -> Result<Value, Error> {
+    let ruby = Ruby::get().expect("Ruby not initialized");
+    if !ruby.block_given() {
+        return Err(Error::new(ruby.exception_arg_error(), "extract_file_each requires a block"));
+    }
+    let args = scan_args::<(String,), (Option<String>,), (), (), RHash, ()>(args)?;
+    let (path,) = args.required;
+    let (mime_type,) = args.optional;
+
+    let mut config = parse_extraction_config(&ruby, Some(args.keywords))?;
+    config.pages.get_or_insert_with(PageConfig::default).extract_pages = true;
+
+    let result = without_gvl(|| kreuzberg::extract_file_sync(&path, mime_type.as_deref(), &config))
+        .map_err(kreuzberg_error)?;
+
+    for event in result.into_events() {
+        let event_hash = extraction_event_to_ruby(&ruby, event)?;
+        let _: Value = ruby.yield_value(event_hash)?;
+    }
+
+    Ok(ruby.qnil().as_value())
+}
+
+/// Convert an extraction event into a Ruby hash with a "type" entry.
+fn extraction_event_to_ruby(ruby: &Ruby, event: kreuzberg::ExtractionEvent) -> Result<RHash, Error> {
+    match event {
+        kreuzberg::ExtractionEvent::Document { mime_type, metadata } => {
+            let hash = ruby.hash_new();
+            hash.aset("type", "document")?;
+            hash.aset("mime_type", mime_type)?;
+            let metadata_value = serde_json::to_value(&metadata)
+                .map_err(|e| runtime_error(format!("Failed to serialize metadata: {}", e)))?;
+            hash.aset("metadata", json_value_to_ruby(ruby, &metadata_value)?)?;
+            Ok(hash)
+        }
+        kreuzberg::ExtractionEvent::Page(page_content) => {
+            let hash = page_content_to_ruby(ruby, page_content)?;
+            hash.aset("type", "page")?;
+            Ok(hash)
+        }
+        kreuzberg::ExtractionEvent::Section(section) => {
+            let hash = ruby.hash_new();
+            hash.aset("type", "section")?;
+            hash.aset("index", section.index)?;
+            hash.aset("heading", section.heading)?;
+            hash.aset("level", section.level)?;
+            hash.aset("content", section.content)?;
+            Ok(hash)
+        }
+        kreuzberg::ExtractionEvent::Table(table) => {
+            let hash = ruby.hash_new();
+            hash.aset("type", "table")?;
+            hash.aset("table", table_to_ruby(ruby, table)?)?;
+            Ok(hash)
+        }
+    }
+}
+
 /// Extract content from bytes (synchronous).
 ///
 /// @param data [String] Binary data to extract
@@ -2246,7 +3005,7 @@ fn register_post_processor(args: &[Value]) -> Result<(), Error> {
                                 message: format!("detected_languages must be JSON-serializable: {}", e),
                                 plugin_name: processor_name.clone(),
                             })?;
-                        let languages: Vec<String> =
+                        let languages: Vec<kreuzberg::DetectedLanguage> =
                             serde_json::from_value(langs_json).map_err(|e| kreuzberg::KreuzbergError::Plugin {
                                 message: format!("Failed to deserialize detected_languages: {}", e),
                                 plugin_name: processor_name.clone(),
@@ -2296,6 +3055,7 @@ fn register_post_processor(args: &[Value]) -> Result<(), Error> {
         .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
         .register(processor_impl, priority)
         .map_err(kreuzberg_error)?;
+    RUBY_PLUGINS_REGISTERED.store(true, Ordering::Release);
 
     Ok(())
 }
@@ -2402,6 +3162,7 @@ fn register_validator(args: &[Value]) -> Result<(), Error> {
         .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
         .register(validator_impl)
         .map_err(kreuzberg_error)?;
+    RUBY_PLUGINS_REGISTERED.store(true, Ordering::Release);
 
     Ok(())
 }
@@ -2503,6 +3264,11 @@ fn register_ocr_backend(name: String, backend: Value) -> Result<(), Error> {
                 chunks: None,
                 images: None,
                 pages: None,
+                entities: None,
+                keywords: None,
+                children: None,
+                fields: None,
+                warnings: Vec::new(),
             })
         }
 
@@ -2533,6 +3299,7 @@ fn register_ocr_backend(name: String, backend: Value) -> Result<(), Error> {
         .map_err(|e| runtime_error(format!("Failed to acquire registry lock: {}", e)))?
         .register(backend_impl)
         .map_err(kreuzberg_error)?;
+    RUBY_PLUGINS_REGISTERED.store(true, Ordering::Release);
 
     Ok(())
 }
@@ -2887,6 +3654,64 @@ fn last_panic_context_json(ruby: &Ruby) -> Value {
     }
 }
 
+/// Pre-warmed extraction context reused across calls (`Kreuzberg::ExtractionSession`).
+///
+/// Wraps [`kreuzberg::ExtractionSession`]; the Ruby class in `extraction_session.rb` turns the
+/// returned hashes into `Kreuzberg::Result` objects.
+#[magnus::wrap(class = "Kreuzberg::ExtractionSession", free_immediately, size)]
+struct ExtractionSession {
+    inner: kreuzberg::ExtractionSession,
+}
+
+impl ExtractionSession {
+    /// Create a session from extraction options.
+    ///
+    /// @param options [Hash] Extraction configuration used for every call
+    /// @return [Kreuzberg::ExtractionSession]
+    ///
+    fn new(args: &[Value]) -> Result<Self, Error> {
+        let ruby = Ruby::get().expect("Ruby not initialized");
+        let args = scan_args::<(), (), (), (), RHash, ()>(args)?;
+        let config = parse_extraction_config(&ruby, Some(args.keywords))?;
+
+        let inner = without_gvl(|| kreuzberg::ExtractionSession::new(config)).map_err(kreuzberg_error)?;
+        Ok(Self { inner })
+    }
+
+    /// Extract content from a file with the session's configuration.
+    ///
+    /// @param path [String] Path to the file
+    /// @param mime_type [String, nil] Optional MIME type hint
+    /// @return [Hash] Extraction result
+    ///
+    fn extract_file_sync(&self, args: &[Value]) -> Result<RHash, Error> {
+        let ruby = Ruby::get().expect("Ruby not initialized");
+        let args = scan_args::<(String,), (Option<String>,), (), (), (), ()>(args)?;
+        let (path,) = args.required;
+        let (mime_type,) = args.optional;
+
+        let result = without_gvl(|| self.inner.extract_file_sync(&path, mime_type.as_deref()))
+            .map_err(kreuzberg_error)?;
+
+        extraction_result_to_ruby(&ruby, result)
+    }
+
+    /// Extract content from bytes with the session's configuration.
+    ///
+    /// @param data [String] Binary data to extract
+    /// @param mime_type [String] MIME type of the data
+    /// @return [Hash] Extraction result
+    ///
+    fn extract_bytes_sync(&self, data: String, mime_type: String) -> Result<RHash, Error> {
+        let ruby = Ruby::get().expect("Ruby not initialized");
+
+        let result = without_gvl(|| self.inner.extract_bytes_sync(data.as_bytes(), &mime_type))
+            .map_err(kreuzberg_error)?;
+
+        extraction_result_to_ruby(&ruby, result)
+    }
+}
+
 /// Initialize the Kreuzberg Ruby module
 #[magnus::init]
 fn init(ruby: &Ruby) -> Result<(), Error> {
@@ -2896,6 +3721,7 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
     module.define_module_function("extract_bytes_sync", function!(extract_bytes_sync, -1))?;
     module.define_module_function("batch_extract_files_sync", function!(batch_extract_files_sync, -1))?;
     module.define_module_function("batch_extract_bytes_sync", function!(batch_extract_bytes_sync, -1))?;
+    module.define_module_function("extract_file_each", function!(extract_file_each, -1))?;
 
     module.define_module_function("extract_file", function!(extract_file, -1))?;
     module.define_module_function("extract_bytes", function!(extract_bytes, -1))?;
@@ -2941,6 +3767,17 @@ fn init(ruby: &Ruby) -> Result<(), Error> {
     module.define_module_function("_last_error_code_native", function!(last_error_code, 0))?;
     module.define_module_function("_last_panic_context_json_native", function!(last_panic_context_json, 0))?;
 
+    let session_class = module.define_class("ExtractionSession", ruby.class_object())?;
+    session_class.define_singleton_method("native_new", function!(ExtractionSession::new, -1))?;
+    session_class.define_method(
+        "native_extract_file_sync",
+        method!(ExtractionSession::extract_file_sync, -1),
+    )?;
+    session_class.define_method(
+        "native_extract_bytes_sync",
+        method!(ExtractionSession::extract_bytes_sync, 2),
+    )?;
+
     Ok(())
 }
 
@@ -3049,6 +3886,8 @@ mod tests {
             auto_adjust_dpi: true,
             min_dpi: 72,
             max_dpi: 600,
+            describer: None,
+            inline_descriptions: false,
         };
 
         assert!(config.extract_images);
@@ -3065,10 +3904,12 @@ mod tests {
             target_dpi: 300,
             auto_rotate: true,
             deskew: true,
+            grayscale: true,
             denoise: false,
             contrast_enhance: false,
             binarization_method: "otsu".to_string(),
             invert_colors: false,
+            remove_borders: false,
         };
 
         assert_eq!(config.target_dpi, 300);
@@ -3086,6 +3927,11 @@ mod tests {
             enabled: true,
             enabled_processors: Some(vec!["processor1".to_string(), "processor2".to_string()]),
             disabled_processors: None,
+            header_footer: None,
+            line_repair: None,
+            normalization: None,
+            metrics: None,
+            taggers: None,
         };
 
         assert!(config.enabled);
@@ -3099,6 +3945,9 @@ mod tests {
         let config = TokenReductionConfig {
             mode: "moderate".to_string(),
             preserve_important_words: true,
+            stopwords: None,
+            target_ratio: None,
+            max_tokens: None,
         };
 
         assert_eq!(config.mode, "moderate");
@@ -3115,15 +3964,25 @@ mod tests {
                 auto_adjust_dpi: true,
                 min_dpi: 72,
                 max_dpi: 600,
+                describer: None,
+                inline_descriptions: false,
             }),
             postprocessor: Some(PostProcessorConfig {
                 enabled: true,
                 enabled_processors: None,
                 disabled_processors: None,
+                header_footer: None,
+                line_repair: None,
+                normalization: None,
+                metrics: None,
+                taggers: None,
             }),
             token_reduction: Some(TokenReductionConfig {
                 mode: "light".to_string(),
                 preserve_important_words: true,
+                stopwords: None,
+                target_ratio: None,
+                max_tokens: None,
             }),
             ..Default::default()
         };
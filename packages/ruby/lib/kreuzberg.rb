@@ -11,6 +11,7 @@ require 'kreuzberg_rb'
 module Kreuzberg
   autoload :Config, 'kreuzberg/config'
   autoload :Result, 'kreuzberg/result'
+  autoload :ExtractionEvent, 'kreuzberg/extraction_event'
   autoload :CLI, 'kreuzberg/cli'
   autoload :CLIProxy, 'kreuzberg/cli_proxy'
   autoload :APIProxy, 'kreuzberg/api_proxy'
@@ -36,12 +37,13 @@ module Kreuzberg
     alias native_batch_extract_files batch_extract_files
     alias native_batch_extract_bytes_sync batch_extract_bytes_sync
     alias native_batch_extract_bytes batch_extract_bytes
+    alias native_extract_file_each extract_file_each
     alias native_clear_cache clear_cache
     alias native_cache_stats cache_stats
 
     private :native_extract_file_sync, :native_extract_bytes_sync, :native_batch_extract_files_sync
     private :native_extract_file, :native_extract_bytes, :native_batch_extract_files
-    private :native_batch_extract_bytes_sync, :native_batch_extract_bytes
+    private :native_batch_extract_bytes_sync, :native_batch_extract_bytes, :native_extract_file_each
   end
 
   # Register a Ruby post-processor that conforms to PostProcessorProtocol.
@@ -98,6 +100,7 @@ end
 
 require_relative 'kreuzberg/cache_api'
 require_relative 'kreuzberg/extraction_api'
+require_relative 'kreuzberg/extraction_session'
 
 Kreuzberg.singleton_class.prepend(Kreuzberg::CacheAPI)
 Kreuzberg.singleton_class.prepend(Kreuzberg::ExtractionAPI)
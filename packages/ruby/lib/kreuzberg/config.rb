@@ -174,21 +174,26 @@ module Kreuzberg
     # @example
     #   lang = LanguageDetection.new(enabled: true, min_confidence: 0.8)
     #
+    # @example Only analyze the first 64 KiB of text
+    #   lang = LanguageDetection.new(enabled: true, max_sample_kb: 64)
+    #
     class LanguageDetection
-      attr_reader :enabled, :min_confidence, :detect_multiple
+      attr_reader :enabled, :min_confidence, :detect_multiple, :max_sample_kb
 
-      def initialize(enabled: false, min_confidence: 0.5, detect_multiple: false)
+      def initialize(enabled: false, min_confidence: 0.5, detect_multiple: false, max_sample_kb: nil)
         @enabled = enabled ? true : false
         @min_confidence = min_confidence.to_f
         @detect_multiple = detect_multiple ? true : false
+        @max_sample_kb = max_sample_kb&.to_i
       end
 
       def to_h
         {
           enabled: @enabled,
           min_confidence: @min_confidence,
-          detect_multiple: @detect_multiple
-        }
+          detect_multiple: @detect_multiple,
+          max_sample_kb: @max_sample_kb
+        }.compact
       end
     end
 
@@ -198,12 +203,14 @@ module Kreuzberg
     #   pdf = PDF.new(extract_images: true, passwords: ["secret", "backup"])
     #
     class PDF
-      attr_reader :extract_images, :passwords, :extract_metadata
+      attr_reader :extract_images, :passwords, :extract_metadata, :validate_pdfa, :layout_stats
 
       def initialize(
         extract_images: false,
         passwords: nil,
-        extract_metadata: true
+        extract_metadata: true,
+        validate_pdfa: false,
+        layout_stats: false
       )
         @extract_images = extract_images ? true : false
         @passwords = if passwords.is_a?(Array)
@@ -212,13 +219,17 @@ module Kreuzberg
                        (passwords ? [passwords.to_s] : nil)
                      end
         @extract_metadata = extract_metadata ? true : false
+        @validate_pdfa = validate_pdfa ? true : false
+        @layout_stats = layout_stats ? true : false
       end
 
       def to_h
         {
           extract_images: @extract_images,
           passwords: @passwords,
-          extract_metadata: @extract_metadata
+          extract_metadata: @extract_metadata,
+          validate_pdfa: @validate_pdfa,
+          layout_stats: @layout_stats
         }.compact
       end
     end
@@ -238,7 +249,7 @@ module Kreuzberg
     #
     class ImageExtraction
       attr_reader :extract_images, :target_dpi, :max_image_dimension,
-                  :auto_adjust_dpi, :min_dpi, :max_dpi
+                  :auto_adjust_dpi, :min_dpi, :max_dpi, :describer, :inline_descriptions
 
       def initialize(
         extract_images: true,
@@ -246,7 +257,9 @@ module Kreuzberg
         max_image_dimension: 2000,
         auto_adjust_dpi: true,
         min_dpi: 150,
-        max_dpi: 600
+        max_dpi: 600,
+        describer: nil,
+        inline_descriptions: false
       )
         @extract_images = extract_images ? true : false
         @target_dpi = target_dpi.to_i
@@ -254,6 +267,8 @@ module Kreuzberg
         @auto_adjust_dpi = auto_adjust_dpi ? true : false
         @min_dpi = min_dpi.to_i
         @max_dpi = max_dpi.to_i
+        @describer = describer&.to_s
+        @inline_descriptions = inline_descriptions ? true : false
       end
 
       def to_h
@@ -263,8 +278,10 @@ module Kreuzberg
           max_image_dimension: @max_image_dimension,
           auto_adjust_dpi: @auto_adjust_dpi,
           min_dpi: @min_dpi,
-          max_dpi: @max_dpi
-        }
+          max_dpi: @max_dpi,
+          describer: @describer,
+          inline_descriptions: @inline_descriptions
+        }.compact
       end
     end
 
@@ -284,31 +301,36 @@ module Kreuzberg
     #     denoise: true,
     #     contrast_enhance: true,
     #     binarization_method: "sauvola",
-    #     invert_colors: false
+    #     invert_colors: false,
+    #     remove_borders: true
     #   )
     #
     class ImagePreprocessing
-      attr_reader :target_dpi, :auto_rotate, :deskew, :denoise,
-                  :contrast_enhance, :binarization_method, :invert_colors
+      attr_reader :target_dpi, :auto_rotate, :deskew, :grayscale, :denoise,
+                  :contrast_enhance, :binarization_method, :invert_colors, :remove_borders
 
       def initialize(
         target_dpi: 300,
         auto_rotate: true,
         deskew: true,
+        grayscale: true,
         denoise: false,
         contrast_enhance: true,
         binarization_method: 'otsu',
-        invert_colors: false
+        invert_colors: false,
+        remove_borders: false
       )
         @target_dpi = target_dpi.to_i
         @auto_rotate = auto_rotate ? true : false
         @deskew = deskew ? true : false
+        @grayscale = grayscale ? true : false
         @denoise = denoise ? true : false
         @contrast_enhance = contrast_enhance ? true : false
         @binarization_method = binarization_method.to_s
         @invert_colors = invert_colors ? true : false
+        @remove_borders = remove_borders ? true : false
 
-        valid_methods = %w[otsu sauvola adaptive]
+        valid_methods = %w[otsu sauvola adaptive none]
         return if valid_methods.include?(@binarization_method)
 
         raise ArgumentError, "binarization_method must be one of: #{valid_methods.join(', ')}"
@@ -319,10 +341,12 @@ module Kreuzberg
           target_dpi: @target_dpi,
           auto_rotate: @auto_rotate,
           deskew: @deskew,
+          grayscale: @grayscale,
           denoise: @denoise,
           contrast_enhance: @contrast_enhance,
           binarization_method: @binarization_method,
-          invert_colors: @invert_colors
+          invert_colors: @invert_colors,
+          remove_borders: @remove_borders
         }
       end
     end
@@ -338,12 +362,18 @@ module Kreuzberg
     # @example Aggressive reduction
     #   token = TokenReduction.new(mode: "aggressive", preserve_important_words: false)
     #
+    # @example Keep at most 4000 tokens
+    #   token = TokenReduction.new(max_tokens: 4000)
+    #
     class TokenReduction
-      attr_reader :mode, :preserve_important_words
+      attr_reader :mode, :preserve_important_words, :stopwords, :target_ratio, :max_tokens
 
-      def initialize(mode: 'off', preserve_important_words: true)
+      def initialize(mode: 'off', preserve_important_words: true, stopwords: nil, target_ratio: nil, max_tokens: nil)
         @mode = mode.to_s
         @preserve_important_words = preserve_important_words ? true : false
+        @stopwords = Stopwords.coerce(stopwords)
+        @target_ratio = target_ratio&.to_f
+        @max_tokens = max_tokens&.to_i
 
         valid_modes = %w[off light moderate aggressive maximum]
         return if valid_modes.include?(@mode)
@@ -354,7 +384,47 @@ module Kreuzberg
       def to_h
         {
           mode: @mode,
-          preserve_important_words: @preserve_important_words
+          preserve_important_words: @preserve_important_words,
+          stopwords: @stopwords&.to_h,
+          target_ratio: @target_ratio,
+          max_tokens: @max_tokens
+        }.compact
+      end
+    end
+
+    # Custom stopwords for keyword extraction and token reduction
+    #
+    # @example Add domain words to the embedded list
+    #   stopwords = Stopwords.new(custom: %w[invoice total])
+    #
+    # @example Use only the given words
+    #   stopwords = Stopwords.new(custom: %w[foo bar], mode: :replace)
+    #
+    class Stopwords
+      attr_reader :custom, :mode
+
+      def self.coerce(value)
+        return nil if value.nil?
+        return value if value.is_a?(Stopwords)
+        return new(**value.transform_keys(&:to_sym)) if value.is_a?(Hash)
+
+        raise ArgumentError, "Expected #{self}, Hash, or nil, got #{value.class}"
+      end
+
+      def initialize(custom: [], mode: 'merge')
+        @custom = Array(custom).map(&:to_s)
+        @mode = mode.to_s
+
+        valid_modes = %w[merge replace]
+        return if valid_modes.include?(@mode)
+
+        raise ArgumentError, "mode must be one of: #{valid_modes.join(', ')}"
+      end
+
+      def to_h
+        {
+          custom: @custom,
+          mode: @mode
         }
       end
     end
@@ -449,7 +519,7 @@ module Kreuzberg
     # Keyword extraction configuration for document analysis
     class Keywords
       attr_reader :algorithm, :max_keywords, :min_score, :ngram_range,
-                  :language, :yake_params, :rake_params
+                  :language, :stopwords, :yake_params, :rake_params
 
       def initialize(
         algorithm: nil,
@@ -457,6 +527,7 @@ module Kreuzberg
         min_score: nil,
         ngram_range: nil,
         language: nil,
+        stopwords: nil,
         yake_params: nil,
         rake_params: nil
       )
@@ -465,6 +536,7 @@ module Kreuzberg
         @min_score = min_score&.to_f
         @ngram_range = ngram_range&.map(&:to_i)
         @language = language&.to_s
+        @stopwords = Stopwords.coerce(stopwords)
         @yake_params = normalize_nested(yake_params, KeywordYakeParams)
         @rake_params = normalize_nested(rake_params, KeywordRakeParams)
       end
@@ -476,6 +548,7 @@ module Kreuzberg
           min_score: @min_score,
           ngram_range: @ngram_range,
           language: @language,
+          stopwords: @stopwords&.to_h,
           yake_params: @yake_params&.to_h,
           rake_params: @rake_params&.to_h
         }.compact
@@ -492,6 +565,66 @@ module Kreuzberg
       end
     end
 
+    # Named-entity extraction configuration
+    #
+    # @example Extract only persons and organizations
+    #   entities = Entities.new(entity_types: %i[person organization])
+    #
+    class Entities
+      attr_reader :entity_types, :min_confidence
+
+      def initialize(entity_types: nil, min_confidence: nil)
+        @entity_types = entity_types&.map(&:to_s)
+        @min_confidence = min_confidence&.to_f
+      end
+
+      def to_h
+        {
+          entity_types: @entity_types,
+          min_confidence: @min_confidence
+        }.compact
+      end
+    end
+
+    # Garble detection gate for OCR output
+    #
+    # Results whose dictionary-hit rate or OCR confusion score falls below the
+    # thresholds get metadata["low_quality"] = true, or raise when action is :reject.
+    #
+    # @example Reject unreadable scans
+    #   gate = QualityGate.new(action: :reject)
+    #
+    class QualityGate
+      attr_reader :min_dictionary_hit_rate, :min_confusion_score, :min_words, :ocr_only, :action, :language
+
+      def initialize(
+        min_dictionary_hit_rate: nil,
+        min_confusion_score: nil,
+        min_words: nil,
+        ocr_only: true,
+        action: nil,
+        language: nil
+      )
+        @min_dictionary_hit_rate = min_dictionary_hit_rate&.to_f
+        @min_confusion_score = min_confusion_score&.to_f
+        @min_words = min_words&.to_i
+        @ocr_only = ocr_only ? true : false
+        @action = action&.to_s
+        @language = language&.to_s
+      end
+
+      def to_h
+        {
+          min_dictionary_hit_rate: @min_dictionary_hit_rate,
+          min_confusion_score: @min_confusion_score,
+          min_words: @min_words,
+          ocr_only: @ocr_only,
+          action: @action,
+          language: @language
+        }.compact
+      end
+    end
+
     # Page tracking configuration for multi-page documents
     #
     # @example Enable page extraction
@@ -540,23 +673,55 @@ module Kreuzberg
     #   )
     #
     class PostProcessor
-      attr_reader :enabled, :enabled_processors, :disabled_processors
-
+      attr_reader :enabled, :enabled_processors, :disabled_processors, :header_footer, :line_repair,
+                  :normalization, :metrics, :taggers
+
+      # @param header_footer [Boolean, Hash, nil] Remove running headers, footers and page
+      #   numbers. +true+ uses the defaults; a Hash may set +lines_to_check+, +min_pages+,
+      #   +min_repeat_ratio+ and +remove_page_numbers+.
+      # @param line_repair [Boolean, Hash, nil] Join hyphenated words and hard-wrapped lines.
+      #   +true+ uses the defaults; a Hash may set +dehyphenate+, +merge_lines+ and +language+.
+      # @param normalization [Boolean, Hash, nil] Unicode normalization and invisible-character
+      #   cleanup. +true+ uses the defaults; a Hash may set +form+ (+:none+, +:nfc+, +:nfkc+),
+      #   +remove_zero_width+, +remove_bidi_controls+, +remove_control_chars+,
+      #   +canonicalize_quotes+ and +canonicalize_dashes+.
+      # @param metrics [Boolean, Hash, nil] Readability and vocabulary metrics stored in
+      #   +metadata["text_metrics"]+. +true+ uses the defaults; a Hash may set +long_word_length+.
+      # @param taggers [Hash, nil] Named custom taggers whose matches are stored in
+      #   +metadata["tags"]+. Each value is a regular expression (String or Regexp) or an
+      #   Array of dictionary terms matched as whole words, ignoring case.
       def initialize(
         enabled: true,
         enabled_processors: nil,
-        disabled_processors: nil
+        disabled_processors: nil,
+        header_footer: nil,
+        line_repair: nil,
+        normalization: nil,
+        metrics: nil,
+        taggers: nil
       )
         @enabled = enabled ? true : false
         @enabled_processors = enabled_processors&.map(&:to_s)
         @disabled_processors = disabled_processors&.map(&:to_s)
+        @header_footer = header_footer
+        @line_repair = line_repair
+        @normalization = normalization
+        @metrics = metrics
+        @taggers = taggers&.to_h do |name, tagger|
+          [name.to_s, tagger.is_a?(Regexp) ? tagger.source : tagger]
+        end
       end
 
       def to_h
         {
           enabled: @enabled,
           enabled_processors: @enabled_processors,
-          disabled_processors: @disabled_processors
+          disabled_processors: @disabled_processors,
+          header_footer: @header_footer,
+          line_repair: @line_repair,
+          normalization: @normalization,
+          metrics: @metrics,
+          taggers: @taggers
         }.compact
       end
     end
@@ -607,7 +772,8 @@ module Kreuzberg
                   :ocr, :chunking, :language_detection, :pdf_options,
                   :image_extraction, :image_preprocessing, :postprocessor,
                   :token_reduction, :keywords, :html_options, :pages,
-                  :max_concurrent_extractions
+                  :entities, :quality_gate, :collect_timings, :strict_mime,
+                  :strict, :max_concurrent_extractions
 
       # Load configuration from a file.
       #
@@ -665,6 +831,11 @@ module Kreuzberg
         keywords: nil,
         html_options: nil,
         pages: nil,
+        entities: nil,
+        quality_gate: nil,
+        collect_timings: false,
+        strict_mime: false,
+        strict: false,
         max_concurrent_extractions: nil
       )
         @use_cache = use_cache ? true : false
@@ -681,6 +852,11 @@ module Kreuzberg
         @keywords = normalize_config(keywords, Keywords)
         @html_options = normalize_config(html_options, HtmlOptions)
         @pages = normalize_config(pages, PageConfig)
+        @entities = normalize_config(entities, Entities)
+        @quality_gate = normalize_config(quality_gate, QualityGate)
+        @collect_timings = collect_timings ? true : false
+        @strict_mime = strict_mime ? true : false
+        @strict = strict ? true : false
         @max_concurrent_extractions = max_concurrent_extractions&.to_i
       end
 
@@ -701,6 +877,11 @@ module Kreuzberg
           keywords: @keywords&.to_h,
           html_options: @html_options&.to_h,
           pages: @pages&.to_h,
+          entities: @entities&.to_h,
+          quality_gate: @quality_gate&.to_h,
+          collect_timings: @collect_timings,
+          strict_mime: @strict_mime,
+          strict: @strict,
           max_concurrent_extractions: @max_concurrent_extractions
         }.compact
       end
@@ -63,13 +63,27 @@ module Kreuzberg
     end
 
     # Base error class for all Kreuzberg errors
+    #
+    # Errors raised by the native extension also carry machine-readable details:
+    # +code+ is a stable error code such as "parsing" or "missing_dependency",
+    # +stage+ the pipeline stage ("mime_detection", "extraction", "ocr" or "cache"),
+    # +plugin+ the name of the failing plugin, and +retryable?+ whether retrying
+    # the same operation may succeed.
     class Error < StandardError
-      attr_reader :panic_context, :error_code
+      attr_reader :panic_context, :error_code, :code, :stage, :plugin
 
       def initialize(message, panic_context: nil, error_code: nil)
         super(message)
         @panic_context = panic_context
         @error_code = error_code
+        @code = nil
+        @stage = nil
+        @plugin = nil
+        @retryable = false
+      end
+
+      def retryable?
+        @retryable == true
       end
     end
 
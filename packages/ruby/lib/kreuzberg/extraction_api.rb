@@ -75,6 +75,19 @@ module Kreuzberg
       results
     end
 
+    def extract_file_each(path, mime_type: nil, config: nil, &block)
+      return enum_for(:extract_file_each, path, mime_type: mime_type, config: config) unless block
+
+      opts = normalize_config(config)
+      each_event = ->(hash) { block.call(ExtractionEvent.new(hash)) }
+      if mime_type
+        native_extract_file_each(path.to_s, mime_type.to_s, **opts, &each_event)
+      else
+        native_extract_file_each(path.to_s, **opts, &each_event)
+      end
+      nil
+    end
+
     def normalize_config(config)
       return {} if config.nil?
       return config if config.is_a?(Hash)
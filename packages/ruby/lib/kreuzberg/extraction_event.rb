@@ -0,0 +1,70 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # Event yielded by {Kreuzberg.extract_file_each}
+  #
+  # The first event has type +:document+ and carries the MIME type and metadata. It is
+  # followed by one +:page+ event per page or, for formats without pages, by +:section+
+  # events (content from one Markdown heading to the next) and then +:table+ events.
+  #
+  # @example
+  #   Kreuzberg.extract_file_each("report.docx") do |event|
+  #     puts "#{event.section.heading}: #{event.section.content.length}" if event.type == :section
+  #   end
+  #
+  class ExtractionEvent
+    # Content from one Markdown heading to the next
+    #
+    # @!attribute [r] index
+    #   @return [Integer] Position of the section in the document (0-indexed)
+    # @!attribute [r] heading
+    #   @return [String, nil] Heading text (nil for the text before the first heading)
+    # @!attribute [r] level
+    #   @return [Integer] Heading level from 1 to 6 (0 for the text before the first heading)
+    # @!attribute [r] content
+    #   @return [String] Section text without the heading line
+    #
+    Section = Struct.new(:index, :heading, :level, :content, keyword_init: true) do
+      def to_h
+        { index: index, heading: heading, level: level, content: content }
+      end
+    end
+
+    # @return [Symbol] :document, :page, :section or :table
+    attr_reader :type
+    # @return [String, nil] MIME type of the document (:document events)
+    attr_reader :mime_type
+    # @return [Hash, nil] Document metadata (:document events)
+    attr_reader :metadata
+    # @return [Result::PageContent, nil] Page (:page events)
+    attr_reader :page
+    # @return [Section, nil] Section (:section events)
+    attr_reader :section
+    # @return [Result::Table, nil] Table (:table events)
+    attr_reader :table
+
+    # Initialize from a native event hash
+    #
+    # @param hash [Hash] Hash yielded by the native extension
+    #
+    def initialize(hash)
+      @type = hash['type'].to_sym
+      case @type
+      when :document
+        @mime_type = hash['mime_type']
+        @metadata = hash['metadata']
+      when :page
+        @page = Result.new('pages' => [hash]).pages.first
+      when :section
+        @section = Section.new(
+          index: hash['index'],
+          heading: hash['heading'],
+          level: hash['level'],
+          content: hash['content']
+        )
+      when :table
+        @table = Result.new('tables' => [hash['table']]).tables.first
+      end
+    end
+  end
+end
@@ -0,0 +1,52 @@
+# frozen_string_literal: true
+
+module Kreuzberg
+  # Pre-warmed extraction context reused across calls
+  #
+  # Registers the built-in extractors and post-processors, checks the configured OCR
+  # backend and resolves extractors once, so repeated extractions skip that work. Every
+  # call uses the configuration the session was created with.
+  #
+  # @example
+  #   session = Kreuzberg::ExtractionSession.new(config: { force_ocr: true })
+  #   %w[doc1.pdf doc2.docx].each do |path|
+  #     puts session.extract_file_sync(path).content
+  #   end
+  #
+  class ExtractionSession
+    class << self
+      private :native_new
+
+      # @param config [Config::Extraction, Hash, nil] Extraction configuration used for every call
+      # @return [ExtractionSession]
+      def new(config: nil)
+        native_new(**config.to_h)
+      end
+    end
+
+    private :native_extract_file_sync, :native_extract_bytes_sync
+
+    # Extract content from a file.
+    #
+    # @param path [String, Pathname] Path to the file
+    # @param mime_type [String, nil] Optional MIME type hint
+    # @return [Result]
+    def extract_file_sync(path, mime_type: nil)
+      hash = if mime_type
+               native_extract_file_sync(path.to_s, mime_type.to_s)
+             else
+               native_extract_file_sync(path.to_s)
+             end
+      Result.new(hash)
+    end
+
+    # Extract content from bytes.
+    #
+    # @param data [String] Binary data to extract
+    # @param mime_type [String] MIME type of the data
+    # @return [Result]
+    def extract_bytes_sync(data, mime_type)
+      Result.new(native_extract_bytes_sync(data.to_s, mime_type.to_s))
+    end
+  end
+end
@@ -21,7 +21,7 @@ module Kreuzberg
   # rubocop:disable Metrics/ClassLength
   class Result
     attr_reader :content, :mime_type, :metadata, :metadata_json, :tables,
-                :detected_languages, :chunks, :images, :pages
+                :detected_languages, :chunks, :images, :pages, :entities, :keywords, :warnings
 
     # Table structure
     #
@@ -87,6 +87,7 @@ module Kreuzberg
       :page_number,
       :width,
       :height,
+      :dpi,
       :colorspace,
       :bits_per_component,
       :is_mask,
@@ -102,6 +103,7 @@ module Kreuzberg
           page_number: page_number,
           width: width,
           height: height,
+          dpi: dpi,
           colorspace: colorspace,
           bits_per_component: bits_per_component,
           is_mask: is_mask,
@@ -133,6 +135,95 @@ module Kreuzberg
       end
     end
 
+    # Named entity
+    #
+    # @!attribute [r] entity_type
+    #   @return [String] Entity type ("person", "organization", "date" or "money")
+    # @!attribute [r] text
+    #   @return [String] Entity text as it appears in the content
+    # @!attribute [r] byte_start
+    #   @return [Integer] Starting byte offset (UTF-8)
+    # @!attribute [r] byte_end
+    #   @return [Integer] Ending byte offset (UTF-8)
+    # @!attribute [r] confidence
+    #   @return [Float] Recognizer confidence (0.0-1.0)
+    #
+    Entity = Struct.new(:entity_type, :text, :byte_start, :byte_end, :confidence, keyword_init: true) do
+      def to_h
+        {
+          entity_type: entity_type,
+          text: text,
+          byte_start: byte_start,
+          byte_end: byte_end,
+          confidence: confidence
+        }
+      end
+    end
+
+    # Detected language
+    #
+    # @!attribute [r] language
+    #   @return [String] ISO 639-3 language code
+    # @!attribute [r] confidence
+    #   @return [Float] Detection confidence (0.0-1.0)
+    # @!attribute [r] script
+    #   @return [String] Writing system (e.g. "Latin", "Cyrillic")
+    #
+    DetectedLanguage = Struct.new(:language, :confidence, :script, keyword_init: true) do
+      def to_h
+        {
+          language: language,
+          confidence: confidence,
+          script: script
+        }
+      end
+    end
+
+    # Extracted keyword
+    #
+    # @!attribute [r] text
+    #   @return [String] Keyword text
+    # @!attribute [r] score
+    #   @return [Float] Relevance score (algorithm-specific range)
+    # @!attribute [r] algorithm
+    #   @return [String] Algorithm that produced the keyword ("yake" or "rake")
+    # @!attribute [r] positions
+    #   @return [Array<Integer>, nil] Character offsets where the keyword appears
+    #
+    Keyword = Struct.new(:text, :score, :algorithm, :positions, keyword_init: true) do
+      def to_h
+        {
+          text: text,
+          score: score,
+          algorithm: algorithm,
+          positions: positions
+        }
+      end
+    end
+
+    # Non-fatal issue encountered while processing the document
+    #
+    # @!attribute [r] code
+    #   @return [String] Stable identifier of the kind of issue (e.g. "attachment_failed")
+    # @!attribute [r] message
+    #   @return [String] Human-readable description
+    # @!attribute [r] stage
+    #   @return [String] Stage that reported the issue ("recovery", "extraction", "ocr",
+    #     "post_processing" or "limits")
+    # @!attribute [r] page
+    #   @return [Integer, nil] Page the issue applies to (1-indexed)
+    #
+    ExtractionWarning = Struct.new(:code, :message, :stage, :page, keyword_init: true) do
+      def to_h
+        {
+          code: code,
+          message: message,
+          stage: stage,
+          page: page
+        }
+      end
+    end
+
     # Initialize from native hash result
     #
     # @param hash [Hash] Hash returned from native extension
@@ -148,6 +239,9 @@ module Kreuzberg
       @chunks = parse_chunks(get_value(hash, 'chunks'))
       @images = parse_images(get_value(hash, 'images'))
       @pages = parse_pages(get_value(hash, 'pages'))
+      @entities = parse_entities(get_value(hash, 'entities'))
+      @keywords = parse_keywords(get_value(hash, 'keywords'))
+      @warnings = parse_warnings(get_value(hash, 'warnings'))
     end
 
     # Convert to hash
@@ -160,10 +254,13 @@ module Kreuzberg
         mime_type: @mime_type,
         metadata: @metadata,
         tables: serialize_tables,
-        detected_languages: @detected_languages,
+        detected_languages: @detected_languages&.map(&:to_h),
         chunks: serialize_chunks,
         images: serialize_images,
-        pages: serialize_pages
+        pages: serialize_pages,
+        entities: @entities&.map(&:to_h),
+        keywords: @keywords&.map(&:to_h),
+        warnings: @warnings.map(&:to_h)
       }
     end
 
@@ -218,8 +315,13 @@ module Kreuzberg
     def parse_detected_languages(langs_data)
       return nil if langs_data.nil?
 
-      # Detected languages is now just an array of strings
-      langs_data.is_a?(Array) ? langs_data : []
+      langs_data.map do |lang_hash|
+        DetectedLanguage.new(
+          language: lang_hash['language'],
+          confidence: lang_hash['confidence'],
+          script: lang_hash['script']
+        )
+      end
     end
 
     def parse_chunks(chunks_data)
@@ -253,6 +355,7 @@ module Kreuzberg
           page_number: image_hash['page_number'],
           width: image_hash['width'],
           height: image_hash['height'],
+          dpi: image_hash['dpi'],
           colorspace: image_hash['colorspace'],
           bits_per_component: image_hash['bits_per_component'],
           is_mask: image_hash['is_mask'],
@@ -274,6 +377,46 @@ module Kreuzberg
         )
       end
     end
+
+    def parse_entities(entities_data)
+      return nil if entities_data.nil?
+
+      entities_data.map do |entity_hash|
+        Entity.new(
+          entity_type: entity_hash['entity_type'],
+          text: entity_hash['text'],
+          byte_start: entity_hash['byte_start'],
+          byte_end: entity_hash['byte_end'],
+          confidence: entity_hash['confidence']
+        )
+      end
+    end
+
+    def parse_keywords(keywords_data)
+      return nil if keywords_data.nil?
+
+      keywords_data.map do |keyword_hash|
+        Keyword.new(
+          text: keyword_hash['text'],
+          score: keyword_hash['score'],
+          algorithm: keyword_hash['algorithm'],
+          positions: keyword_hash['positions']
+        )
+      end
+    end
+
+    def parse_warnings(warnings_data)
+      return [] if warnings_data.nil?
+
+      warnings_data.map do |warning_hash|
+        ExtractionWarning.new(
+          code: warning_hash['code'],
+          message: warning_hash['message'],
+          stage: warning_hash['stage'],
+          page: warning_hash['page']
+        )
+      end
+    end
   end
   # rubocop:enable Metrics/ClassLength
 end
@@ -70,7 +70,7 @@ module Kreuzberg
     #   - "mime_type" [String] - MIME type of the source document
     #   - "metadata" [Hash] - Document metadata (title, author, etc.)
     #   - "tables" [Array<Hash>] - Extracted tables
-    #   - "detected_languages" [Array<String>, nil] - Detected language codes
+    #   - "detected_languages" [Array<Hash>, nil] - Detected languages ("language", "confidence", "script")
     #   - "chunks" [Array<String>, nil] - Content chunks (if chunking enabled)
     #
     # @return [void]
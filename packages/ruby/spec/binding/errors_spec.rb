@@ -11,6 +11,21 @@ RSpec.describe Kreuzberg::Errors do
         raise described_class, 'Test error'
       end.to raise_error(described_class, 'Test error')
     end
+
+    it 'defaults the structured error fields' do
+      error = described_class.new('Test error')
+      expect(error.code).to be_nil
+      expect(error.retryable?).to be(false)
+    end
+
+    it 'carries the error code of native errors' do
+      expect do
+        Kreuzberg.extract_bytes_sync('content', 'invalid/type')
+      end.to raise_error(Kreuzberg::Errors::UnsupportedFormatError) do |error|
+        expect(error.code).to eq('unsupported_format')
+        expect(error.retryable?).to be(false)
+      end
+    end
   end
 
   describe Kreuzberg::Errors::ValidationError do
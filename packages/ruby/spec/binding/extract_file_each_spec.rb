@@ -0,0 +1,61 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+require 'tmpdir'
+
+RSpec.describe 'Kreuzberg.extract_file_each' do
+  let(:test_pdf) do
+    test_document_path('pdfs/5_level_paging_and_5_level_ept_intel_revision_1_1_may_2017.pdf')
+  end
+
+  around do |example|
+    Dir.mktmpdir('kreuzberg-events') do |dir|
+      @dir = dir
+      example.run
+    end
+  end
+
+  def write_markdown(content)
+    path = File.join(@dir, 'notes.md')
+    File.write(path, content)
+    path
+  end
+
+  it 'yields the document first and then one event per page' do
+    events = []
+    Kreuzberg.extract_file_each(test_pdf) { |event| events << event }
+
+    expect(events.first.type).to eq(:document)
+    expect(events.first.mime_type).to eq('application/pdf')
+
+    pages = events.drop(1)
+    expect(pages).not_to be_empty
+    expect(pages.map(&:type).uniq).to eq([:page])
+    expect(pages.map { |event| event.page.page_number }).to eq((1..pages.size).to_a)
+  end
+
+  it 'yields sections for documents without pages' do
+    path = write_markdown("# Intro\n\nFirst part.\n\n## Details\n\nSecond part.\n")
+
+    sections = Kreuzberg.extract_file_each(path).select { |event| event.type == :section }.map(&:section)
+
+    expect(sections.map(&:heading)).to eq(%w[Intro Details])
+    expect(sections.map(&:level)).to eq([1, 2])
+    expect(sections.last.content).to include('Second part.')
+  end
+
+  it 'returns an enumerator without a block' do
+    path = write_markdown("# Title\n\nBody\n")
+
+    enumerator = Kreuzberg.extract_file_each(path, mime_type: 'text/markdown')
+
+    expect(enumerator).to be_a(Enumerator)
+    expect(enumerator.first.type).to eq(:document)
+  end
+
+  it 'raises for missing files' do
+    expect do
+      Kreuzberg.extract_file_each(File.join(@dir, 'missing.pdf')) { |_event| nil }
+    end.to raise_error(Kreuzberg::Errors::Error)
+  end
+end
@@ -0,0 +1,34 @@
+# frozen_string_literal: true
+
+require 'spec_helper'
+require 'tmpdir'
+
+RSpec.describe Kreuzberg::ExtractionSession do
+  around do |example|
+    Dir.mktmpdir('kreuzberg-session') do |dir|
+      @dir = dir
+      example.run
+    end
+  end
+
+  it 'extracts files and bytes with the same session' do
+    path = File.join(@dir, 'notes.txt')
+    File.write(path, 'Session text')
+    session = described_class.new
+
+    file_result = session.extract_file_sync(path)
+    bytes_result = session.extract_bytes_sync('Session bytes', 'text/plain')
+
+    expect(file_result).to be_a(Kreuzberg::Result)
+    expect(file_result.content).to include('Session text')
+    expect(bytes_result.content).to include('Session bytes')
+  end
+
+  it 'accepts a configuration object or hash' do
+    [Kreuzberg::Config::Extraction.new(use_cache: false), { use_cache: false }].each do |config|
+      result = described_class.new(config: config).extract_bytes_sync('# Title', 'text/markdown')
+
+      expect(result.mime_type).to eq('text/markdown')
+    end
+  end
+end
@@ -15,6 +15,11 @@ from typing import Any
 from docling.document_converter import DocumentConverter
 
 
+def table_cells(document: Any) -> list[list[list[str]]]:
+    """Return the tables of a converted document as rows of cell texts."""
+    return [[[cell.text for cell in row] for row in table.data.grid] for table in document.tables]
+
+
 def extract_sync(file_path: str) -> dict[str, Any]:
     """Extract using synchronous single-file API."""
     start = time.perf_counter()
@@ -26,6 +31,7 @@ def extract_sync(file_path: str) -> dict[str, Any]:
     return {
         "content": markdown,
         "metadata": {"framework": "docling"},
+        "tables": table_cells(result.document),
         "_extraction_time_ms": duration_ms,
     }
 
@@ -47,6 +53,7 @@ def extract_batch(file_paths: list[str]) -> list[dict[str, Any]]:
                 {
                     "content": markdown,
                     "metadata": {"framework": "docling"},
+                    "tables": table_cells(result.document),
                     "_extraction_time_ms": per_file_duration_ms,
                     "_batch_total_ms": total_duration_ms,
                 }
@@ -26,6 +26,7 @@ def extract_sync(file_path: str) -> dict[str, Any]:
     return {
         "content": result.content,
         "metadata": result.metadata or {},
+        "tables": [table.cells for table in result.tables],
         "_extraction_time_ms": duration_ms,
     }
 
@@ -39,6 +40,7 @@ async def extract_async(file_path: str) -> dict[str, Any]:
     return {
         "content": result.content,
         "metadata": result.metadata or {},
+        "tables": [table.cells for table in result.tables],
         "_extraction_time_ms": duration_ms,
     }
 
@@ -55,6 +57,8 @@ def extract_batch_sync(file_paths: list[str]) -> list[dict[str, Any]]:
         {
             "content": result.content,
             "metadata": result.metadata or {},
+            "tables": [table.cells for table in result.tables],
+        "tables": [table.cells for table in result.tables],
             "_extraction_time_ms": per_file_duration_ms,
             "_batch_total_ms": total_duration_ms,
         }
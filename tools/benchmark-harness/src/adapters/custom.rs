@@ -0,0 +1,377 @@
+//! Custom adapters declared in a TOML file
+//!
+//! Lets users benchmark in-house extractors without writing Rust: each `[[adapter]]` entry
+//! describes a command to run per document and how to read its output, and becomes a
+//! [`SubprocessAdapter`].
+//!
+//! ```toml
+//! [[adapter]]
+//! name = "acme-extract"
+//! command = ["python3", "acme_extract.py", "--input", "{file}"]
+//! warmup_command = ["python3", "-c", "import acme"]
+//! working_dir = "../acme"              # relative to this file
+//! formats = ["pdf", "docx"]            # default: all formats the harness knows
+//! version = "2.3.1"
+//! env = { ACME_THREADS = "1" }
+//!
+//! [adapter.output]
+//! format = "json"                      # or "text": stdout is the extracted content
+//! content = "/result/text"             # JSON pointers into the output
+//! extraction_time_ms = "/timing/ms"
+//!
+//! [[adapter]]
+//! name = "acme-batch"
+//! command = ["acme", "--batch", "{files}"]
+//! batch = true
+//! ```
+//!
+//! `{file}` is replaced by the document path and a standalone `{files}` argument expands to all
+//! document paths of a batch. Without either placeholder, paths are appended to the command.
+
+use crate::adapters::subprocess::SubprocessAdapter;
+use crate::{Error, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Placeholder replaced by the document path
+pub const FILE_PLACEHOLDER: &str = "{file}";
+
+/// Placeholder argument expanded to all document paths
+pub const FILES_PLACEHOLDER: &str = "{files}";
+
+/// A file of custom adapter declarations
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomAdaptersFile {
+    /// Declared adapters (`[[adapter]]` tables)
+    #[serde(default, rename = "adapter")]
+    pub adapters: Vec<CustomAdapterSpec>,
+}
+
+/// Declaration of a single custom adapter
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomAdapterSpec {
+    /// Framework name used in results and for `--frameworks`
+    pub name: String,
+
+    /// Program and arguments, with `{file}` / `{files}` placeholders
+    pub command: Vec<String>,
+
+    /// Whether the command accepts all documents of a batch at once
+    #[serde(default)]
+    pub batch: bool,
+
+    /// Command run once before the warmup extraction (e.g. to load models)
+    #[serde(default)]
+    pub warmup_command: Option<Vec<String>>,
+
+    /// Working directory, relative to the TOML file
+    #[serde(default)]
+    pub working_dir: Option<PathBuf>,
+
+    /// Additional environment variables
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+
+    /// Supported file extensions; all formats the harness knows when omitted
+    #[serde(default)]
+    pub formats: Option<Vec<String>>,
+
+    /// Framework version reported in results
+    #[serde(default)]
+    pub version: Option<String>,
+
+    /// How to read the command's output
+    #[serde(default)]
+    pub output: OutputSpec,
+}
+
+/// Format of a custom adapter's standard output
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// A JSON document; fields are located with JSON pointers
+    #[default]
+    Json,
+    /// Plain text; the whole output is the extracted content
+    Text,
+}
+
+/// How to read the extraction result from a custom adapter's output
+///
+/// The defaults match the output of the bundled wrapper scripts.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct OutputSpec {
+    /// Output format
+    pub format: OutputFormat,
+
+    /// JSON pointer to the extracted text
+    pub content: String,
+
+    /// JSON pointer to the extracted tables
+    pub tables: String,
+
+    /// JSON pointer to the extracted metadata
+    pub metadata: String,
+
+    /// JSON pointer to the extraction time in milliseconds measured by the extractor itself
+    pub extraction_time_ms: String,
+}
+
+impl Default for OutputSpec {
+    fn default() -> Self {
+        Self {
+            format: OutputFormat::Json,
+            content: "/content".to_string(),
+            tables: "/tables".to_string(),
+            metadata: "/metadata".to_string(),
+            extraction_time_ms: "/_extraction_time_ms".to_string(),
+        }
+    }
+}
+
+impl OutputSpec {
+    /// Convert raw output into the JSON shape of the bundled wrapper scripts
+    ///
+    /// (`content`, `tables`, `metadata`, `_extraction_time_ms`)
+    pub fn normalize(&self, stdout: &str) -> Result<serde_json::Value> {
+        match self.format {
+            OutputFormat::Text => Ok(serde_json::json!({ "content": stdout })),
+            OutputFormat::Json => {
+                let value: serde_json::Value = serde_json::from_str(stdout)
+                    .map_err(|e| Error::Benchmark(format!("Failed to parse subprocess output: {}", e)))?;
+
+                let content = value
+                    .pointer(&self.content)
+                    .ok_or_else(|| Error::Benchmark(format!("Output has no content at '{}'", self.content)))?;
+
+                let mut normalized = serde_json::Map::new();
+                normalized.insert("content".to_string(), content.clone());
+                for (key, pointer) in [
+                    ("tables", &self.tables),
+                    ("metadata", &self.metadata),
+                    ("_extraction_time_ms", &self.extraction_time_ms),
+                ] {
+                    if let Some(field) = value.pointer(pointer) {
+                        normalized.insert(key.to_string(), field.clone());
+                    }
+                }
+
+                Ok(serde_json::Value::Object(normalized))
+            }
+        }
+    }
+}
+
+impl CustomAdapterSpec {
+    fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(Error::Config("Custom adapter name cannot be empty".to_string()));
+        }
+
+        if self.command.first().is_none_or(|program| program.trim().is_empty()) {
+            return Err(Error::Config(format!(
+                "Custom adapter '{}' needs a command with a program",
+                self.name
+            )));
+        }
+
+        if self.warmup_command.as_ref().is_some_and(|command| command.is_empty()) {
+            return Err(Error::Config(format!(
+                "Custom adapter '{}' has an empty warmup_command",
+                self.name
+            )));
+        }
+
+        for pointer in [
+            &self.output.content,
+            &self.output.tables,
+            &self.output.metadata,
+            &self.output.extraction_time_ms,
+        ] {
+            if !pointer.is_empty() && !pointer.starts_with('/') {
+                return Err(Error::Config(format!(
+                    "Custom adapter '{}': '{}' is not a JSON pointer (must start with '/')",
+                    self.name, pointer
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Build the adapter described by this spec
+    ///
+    /// # Arguments
+    /// * `base_dir` - Directory that a relative `working_dir` is resolved against
+    pub fn into_adapter(self, base_dir: &Path) -> Result<SubprocessAdapter> {
+        self.validate()?;
+
+        let mut command = self.command.into_iter();
+        let program = command.next().unwrap_or_default();
+        let args: Vec<String> = command.collect();
+        let env: Vec<(String, String)> = self.env.into_iter().collect();
+
+        let mut adapter = if self.batch {
+            SubprocessAdapter::with_batch_support(self.name, program, args, env)
+        } else {
+            SubprocessAdapter::new(self.name, program, args, env)
+        };
+
+        if let Some(dir) = self.working_dir {
+            adapter.set_working_dir(base_dir.join(dir));
+        }
+        if let Some(formats) = self.formats {
+            adapter.set_supported_formats(formats);
+        }
+        if let Some(warmup_command) = self.warmup_command {
+            adapter.set_warmup_command(warmup_command);
+        }
+        if let Some(version) = self.version {
+            adapter.set_version(version);
+        }
+        adapter.set_output_spec(self.output);
+
+        Ok(adapter)
+    }
+}
+
+/// Load the custom adapters declared in a TOML file
+///
+/// # Arguments
+/// * `path` - Path to the TOML file
+///
+/// # Returns
+/// * `Ok(Vec<SubprocessAdapter>)` - One adapter per `[[adapter]]` entry
+/// * `Err(Error::Config)` - The file is malformed or an adapter spec is invalid
+pub fn load_custom_adapters(path: &Path) -> Result<Vec<SubprocessAdapter>> {
+    let contents = std::fs::read_to_string(path).map_err(Error::Io)?;
+    let file: CustomAdaptersFile = toml::from_str(&contents)
+        .map_err(|e| Error::Config(format!("Invalid adapter file {}: {}", path.display(), e)))?;
+
+    let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+    file.adapters
+        .into_iter()
+        .map(|spec| spec.into_adapter(base_dir))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapter::FrameworkAdapter;
+    use std::time::Duration;
+
+    fn parse(toml_str: &str) -> CustomAdaptersFile {
+        toml::from_str(toml_str).unwrap()
+    }
+
+    #[test]
+    fn test_parse_spec_with_defaults() {
+        let file = parse(
+            r#"
+            [[adapter]]
+            name = "acme"
+            command = ["acme-extract", "{file}"]
+            formats = ["PDF"]
+            version = "1.0"
+            "#,
+        );
+
+        let adapter = file.adapters[0].clone().into_adapter(Path::new(".")).unwrap();
+        assert_eq!(adapter.name(), "acme");
+        assert_eq!(adapter.version(), "1.0");
+        assert!(adapter.supports_format("pdf"));
+        assert!(!adapter.supports_format("docx"));
+        assert!(!adapter.supports_batch());
+        assert_eq!(file.adapters[0].output, OutputSpec::default());
+    }
+
+    #[test]
+    fn test_invalid_specs_rejected() {
+        let unknown_field = "[[adapter]]\nname = \"x\"\ncommand = [\"x\"]\nshell = true";
+        assert!(toml::from_str::<CustomAdaptersFile>(unknown_field).is_err());
+
+        let empty_command = parse("[[adapter]]\nname = \"x\"\ncommand = []");
+        assert!(empty_command.adapters[0].clone().into_adapter(Path::new(".")).is_err());
+
+        let bad_pointer = parse("[[adapter]]\nname = \"x\"\ncommand = [\"x\"]\n[adapter.output]\ncontent = \"text\"");
+        assert!(bad_pointer.adapters[0].clone().into_adapter(Path::new(".")).is_err());
+    }
+
+    #[test]
+    fn test_output_spec_normalizes_json_pointers() {
+        let spec = OutputSpec {
+            content: "/result/text".to_string(),
+            extraction_time_ms: "/timing/ms".to_string(),
+            ..Default::default()
+        };
+
+        let output = r#"{"result": {"text": "hello"}, "timing": {"ms": 12.5}}"#;
+        let normalized = spec.normalize(output).unwrap();
+        assert_eq!(normalized["content"], "hello");
+        assert_eq!(normalized["_extraction_time_ms"], 12.5);
+        assert!(normalized.get("tables").is_none());
+
+        assert!(spec.normalize(r#"{"text": "hello"}"#).is_err());
+    }
+
+    #[test]
+    fn test_output_spec_text_format() {
+        let spec = OutputSpec {
+            format: OutputFormat::Text,
+            ..Default::default()
+        };
+        assert_eq!(spec.normalize("plain output").unwrap()["content"], "plain output");
+    }
+
+    #[test]
+    fn test_load_custom_adapters_resolves_working_dir() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("adapters.toml");
+        std::fs::write(
+            &path,
+            r#"
+            [[adapter]]
+            name = "acme-batch"
+            command = ["acme", "--batch", "{files}"]
+            batch = true
+            working_dir = "acme"
+            "#,
+        )
+        .unwrap();
+
+        let adapters = load_custom_adapters(&path).unwrap();
+        assert_eq!(adapters.len(), 1);
+        assert!(adapters[0].supports_batch());
+        assert_eq!(adapters[0].working_dir(), Some(temp_dir.path().join("acme").as_path()));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_custom_adapter_extracts_with_placeholder() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let document = temp_dir.path().join("doc.txt");
+        std::fs::write(&document, "document text").unwrap();
+
+        let spec = parse(
+            r#"
+            [[adapter]]
+            name = "cat"
+            command = ["cat", "{file}"]
+            formats = ["txt"]
+
+            [adapter.output]
+            format = "text"
+            "#,
+        );
+        let adapter = spec.adapters[0].clone().into_adapter(temp_dir.path()).unwrap();
+
+        let result = adapter.extract(&document, Duration::from_secs(10)).await.unwrap();
+        assert!(result.success, "{:?}", result.error_message);
+        assert_eq!(result.output.unwrap().content, "document text");
+    }
+}
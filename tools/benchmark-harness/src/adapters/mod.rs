@@ -1,5 +1,6 @@
 //! Framework adapter implementations
 
+pub mod custom;
 pub mod external;
 pub mod kreuzberg;
 pub mod native;
@@ -8,6 +9,7 @@ pub mod python;
 pub mod ruby;
 pub mod subprocess;
 
+pub use custom::{CustomAdapterSpec, OutputFormat, OutputSpec, load_custom_adapters};
 pub use external::{
     create_docling_adapter, create_docling_batch_adapter, create_markitdown_adapter, create_pandoc_adapter,
     create_tika_batch_adapter, create_tika_sync_adapter, create_unstructured_adapter,
@@ -5,10 +5,11 @@
 
 use crate::adapter::FrameworkAdapter;
 use crate::monitoring::ResourceMonitor;
+use crate::quality::ExtractedOutput;
 use crate::types::{BenchmarkResult, FrameworkCapabilities, PerformanceMetrics};
 use crate::{Error, Result};
 use async_trait::async_trait;
-use kreuzberg::{ExtractionConfig, batch_extract_file, extract_file};
+use kreuzberg::{ExtractionConfig, ExtractionResult, batch_extract_file, extract_file};
 use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
@@ -35,6 +36,20 @@ impl NativeAdapter {
     }
 }
 
+/// Collect the parts of an extraction result used for quality scoring
+fn extracted_output(result: &ExtractionResult) -> ExtractedOutput {
+    let metadata = serde_json::to_value(&result.metadata)
+        .ok()
+        .and_then(|value| value.as_object().cloned())
+        .unwrap_or_default();
+
+    ExtractedOutput {
+        content: result.content.clone(),
+        tables: result.tables.iter().map(|table| table.cells.clone()).collect(),
+        metadata,
+    }
+}
+
 impl Default for NativeAdapter {
     fn default() -> Self {
         Self::new()
@@ -116,6 +131,7 @@ impl FrameworkAdapter for NativeAdapter {
                 metrics: PerformanceMetrics {
                     peak_memory_bytes: resource_stats.peak_memory_bytes,
                     avg_cpu_percent: resource_stats.avg_cpu_percent,
+                    cpu_time_ms: resource_stats.cpu_time_ms,
                     throughput_bytes_per_sec: 0.0,
                     p50_memory_bytes: resource_stats.p50_memory_bytes,
                     p95_memory_bytes: resource_stats.p95_memory_bytes,
@@ -132,12 +148,16 @@ impl FrameworkAdapter for NativeAdapter {
                     .to_lowercase(),
                 framework_capabilities: FrameworkCapabilities::default(),
                 pdf_metadata: None,
+                output: None,
             });
         }
 
+        let output = extraction_result.ok().map(|result| extracted_output(&result));
+
         let metrics = PerformanceMetrics {
             peak_memory_bytes: resource_stats.peak_memory_bytes,
             avg_cpu_percent: resource_stats.avg_cpu_percent,
+            cpu_time_ms: resource_stats.cpu_time_ms,
             throughput_bytes_per_sec: throughput,
             p50_memory_bytes: resource_stats.p50_memory_bytes,
             p95_memory_bytes: resource_stats.p95_memory_bytes,
@@ -165,6 +185,7 @@ impl FrameworkAdapter for NativeAdapter {
                 .to_lowercase(),
             framework_capabilities: FrameworkCapabilities::default(),
             pdf_metadata: None,
+            output,
         })
     }
 
@@ -210,6 +231,7 @@ impl FrameworkAdapter for NativeAdapter {
                 file_extension: "batch".to_string(),
                 framework_capabilities: FrameworkCapabilities::default(),
                 pdf_metadata: None,
+                output: None,
             }]);
         }
 
@@ -222,6 +244,7 @@ impl FrameworkAdapter for NativeAdapter {
         let metrics = PerformanceMetrics {
             peak_memory_bytes: resource_stats.peak_memory_bytes,
             avg_cpu_percent: resource_stats.avg_cpu_percent,
+            cpu_time_ms: resource_stats.cpu_time_ms,
             throughput_bytes_per_sec: throughput,
             p50_memory_bytes: resource_stats.p50_memory_bytes,
             p95_memory_bytes: resource_stats.p95_memory_bytes,
@@ -245,6 +268,7 @@ impl FrameworkAdapter for NativeAdapter {
             file_extension: "batch".to_string(),
             framework_capabilities: FrameworkCapabilities::default(),
             pdf_metadata: None,
+            output: None,
         }])
     }
 
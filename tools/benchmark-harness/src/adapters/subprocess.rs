@@ -5,7 +5,9 @@
 //! in separate processes while monitoring resource usage.
 
 use crate::adapter::FrameworkAdapter;
-use crate::monitoring::ResourceMonitor;
+use crate::adapters::custom::{FILE_PLACEHOLDER, FILES_PLACEHOLDER, OutputSpec};
+use crate::monitoring::{ResourceMonitor, ResourceStats};
+use crate::quality::ExtractedOutput;
 use crate::types::{BenchmarkResult, FrameworkCapabilities, PerformanceMetrics};
 use crate::{Error, Result};
 use async_trait::async_trait;
@@ -26,6 +28,10 @@ pub struct SubprocessAdapter {
     env: Vec<(String, String)>,
     supports_batch: bool,
     working_dir: Option<PathBuf>,
+    formats: Option<Vec<String>>,
+    warmup_command: Option<Vec<String>>,
+    version: Option<String>,
+    output_spec: Option<OutputSpec>,
 }
 
 impl SubprocessAdapter {
@@ -49,6 +55,10 @@ impl SubprocessAdapter {
             env,
             supports_batch: false,
             working_dir: None,
+            formats: None,
+            warmup_command: None,
+            version: None,
+            output_spec: None,
         }
     }
 
@@ -75,6 +85,10 @@ impl SubprocessAdapter {
             env,
             supports_batch: true,
             working_dir: None,
+            formats: None,
+            warmup_command: None,
+            version: None,
+            output_spec: None,
         }
     }
 
@@ -86,16 +100,119 @@ impl SubprocessAdapter {
         self.working_dir = Some(dir);
     }
 
+    /// Working directory for subprocess execution, if set
+    pub fn working_dir(&self) -> Option<&Path> {
+        self.working_dir.as_deref()
+    }
+
+    /// Restrict the adapter to the given file extensions
+    ///
+    /// # Arguments
+    /// * `formats` - Supported extensions (matched case-insensitively)
+    pub fn set_supported_formats(&mut self, formats: Vec<String>) {
+        self.formats = Some(formats);
+    }
+
+    /// Set a command to run before the warmup extraction
+    ///
+    /// # Arguments
+    /// * `command` - Program and arguments, run with the adapter's working directory and environment
+    pub fn set_warmup_command(&mut self, command: Vec<String>) {
+        self.warmup_command = Some(command);
+    }
+
+    /// Set the framework version reported by [`FrameworkAdapter::version`]
+    pub fn set_version(&mut self, version: String) {
+        self.version = Some(version);
+    }
+
+    /// Set how the subprocess output is read instead of the default JSON format
+    pub fn set_output_spec(&mut self, spec: OutputSpec) {
+        self.output_spec = Some(spec);
+    }
+
+    /// Build the command arguments for the given documents
+    ///
+    /// `{file}` is replaced by the first document path and a standalone `{files}` argument expands
+    /// to all paths. Without placeholders, the paths are appended to the base arguments.
+    fn build_args(&self, file_paths: &[&Path]) -> Vec<String> {
+        let paths: Vec<String> = file_paths.iter().map(|p| p.to_string_lossy().into_owned()).collect();
+        let first = paths.first().map(String::as_str).unwrap_or_default();
+
+        let mut args = Vec::with_capacity(self.args.len() + paths.len());
+        let mut substituted = false;
+        for arg in &self.args {
+            if arg == FILES_PLACEHOLDER {
+                args.extend(paths.iter().cloned());
+                substituted = true;
+            } else if arg.contains(FILE_PLACEHOLDER) {
+                args.push(arg.replace(FILE_PLACEHOLDER, first));
+                substituted = true;
+            } else {
+                args.push(arg.clone());
+            }
+        }
+
+        if !substituted {
+            args.extend(paths);
+        }
+        args
+    }
+
+    /// Run the configured warmup command, if any
+    async fn run_warmup_command(&self, timeout: Duration) -> Result<()> {
+        let Some((program, args)) = self.warmup_command.as_deref().and_then(|c| c.split_first()) else {
+            return Ok(());
+        };
+
+        let mut cmd = Command::new(program);
+        if let Some(dir) = &self.working_dir {
+            cmd.current_dir(dir);
+        }
+        cmd.args(args);
+        for (key, value) in &self.env {
+            cmd.env(key, value);
+        }
+        cmd.stdin(Stdio::null());
+        cmd.stdout(Stdio::null());
+        cmd.stderr(Stdio::piped());
+
+        let output = match tokio::time::timeout(timeout, cmd.output()).await {
+            Ok(Ok(output)) => output,
+            Ok(Err(e)) => {
+                return Err(Error::Benchmark(format!("Failed to run warmup command: {}", e)));
+            }
+            Err(_) => {
+                return Err(Error::Timeout(format!("Warmup command exceeded {:?}", timeout)));
+            }
+        };
+
+        if !output.status.success() {
+            return Err(Error::Benchmark(format!(
+                "Warmup command failed with exit code {:?}\nstderr: {}",
+                output.status.code(),
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Execute the extraction subprocess
-    async fn execute_subprocess(&self, file_path: &Path, timeout: Duration) -> Result<(String, String, Duration)> {
+    ///
+    /// Returns stdout, stderr, wall-clock duration and the resource usage of the subprocess tree.
+    async fn execute_subprocess(
+        &self,
+        file_path: &Path,
+        timeout: Duration,
+    ) -> Result<(String, String, Duration, ResourceStats)> {
         let start = Instant::now();
 
         let mut cmd = Command::new(&self.command);
         if let Some(dir) = &self.working_dir {
             cmd.current_dir(dir);
         }
-        cmd.args(&self.args);
-        cmd.arg(file_path.to_string_lossy().as_ref());
+        cmd.args(self.build_args(&[file_path]));
 
         for (key, value) in &self.env {
             cmd.env(key, value);
@@ -109,7 +226,19 @@ impl SubprocessAdapter {
             .spawn()
             .map_err(|e| Error::Benchmark(format!("Failed to spawn subprocess: {}", e)))?;
 
-        let output = match tokio::time::timeout(timeout, child.wait_with_output()).await {
+        let monitor = child.id().map(ResourceMonitor::for_process_tree);
+        if let Some(monitor) = &monitor {
+            monitor.start(Duration::from_millis(10)).await;
+        }
+
+        let waited = tokio::time::timeout(timeout, child.wait_with_output()).await;
+
+        let resource_stats = match &monitor {
+            Some(monitor) => ResourceMonitor::calculate_stats(&monitor.stop().await),
+            None => ResourceStats::default(),
+        };
+
+        let output = match waited {
             Ok(Ok(output)) => output,
             Ok(Err(e)) => {
                 return Err(Error::Benchmark(format!("Failed to wait for subprocess: {}", e)));
@@ -132,26 +261,24 @@ impl SubprocessAdapter {
             )));
         }
 
-        Ok((stdout, stderr, duration))
+        Ok((stdout, stderr, duration, resource_stats))
     }
 
     /// Execute batch extraction subprocess with multiple files
+    ///
+    /// Returns stdout, stderr, wall-clock duration and the resource usage of the subprocess tree.
     async fn execute_subprocess_batch(
         &self,
         file_paths: &[&Path],
         timeout: Duration,
-    ) -> Result<(String, String, Duration)> {
+    ) -> Result<(String, String, Duration, ResourceStats)> {
         let start = Instant::now();
 
         let mut cmd = Command::new(&self.command);
         if let Some(dir) = &self.working_dir {
             cmd.current_dir(dir);
         }
-        cmd.args(&self.args);
-
-        for path in file_paths {
-            cmd.arg(path.to_string_lossy().as_ref());
-        }
+        cmd.args(self.build_args(file_paths));
 
         for (key, value) in &self.env {
             cmd.env(key, value);
@@ -165,7 +292,19 @@ impl SubprocessAdapter {
             .spawn()
             .map_err(|e| Error::Benchmark(format!("Failed to spawn batch subprocess: {}", e)))?;
 
-        let output = match tokio::time::timeout(timeout, child.wait_with_output()).await {
+        let monitor = child.id().map(ResourceMonitor::for_process_tree);
+        if let Some(monitor) = &monitor {
+            monitor.start(Duration::from_millis(10)).await;
+        }
+
+        let waited = tokio::time::timeout(timeout, child.wait_with_output()).await;
+
+        let resource_stats = match &monitor {
+            Some(monitor) => ResourceMonitor::calculate_stats(&monitor.stop().await),
+            None => ResourceStats::default(),
+        };
+
+        let output = match waited {
             Ok(Ok(output)) => output,
             Ok(Err(e)) => {
                 return Err(Error::Benchmark(format!("Failed to wait for batch subprocess: {}", e)));
@@ -188,13 +327,18 @@ impl SubprocessAdapter {
             )));
         }
 
-        Ok((stdout, stderr, duration))
+        Ok((stdout, stderr, duration, resource_stats))
     }
 
     /// Parse extraction result from subprocess output
     ///
-    /// Expected output format: JSON with `content` and optional `metadata` fields
+    /// Expected output format: JSON with `content` and optional `metadata` fields, unless an
+    /// [`OutputSpec`] describes a different layout
     fn parse_output(&self, stdout: &str) -> Result<serde_json::Value> {
+        if let Some(spec) = &self.output_spec {
+            return spec.normalize(stdout);
+        }
+
         serde_json::from_str(stdout).map_err(|e| Error::Benchmark(format!("Failed to parse subprocess output: {}", e)))
     }
 }
@@ -206,6 +350,10 @@ impl FrameworkAdapter for SubprocessAdapter {
     }
 
     fn supports_format(&self, file_type: &str) -> bool {
+        if let Some(formats) = &self.formats {
+            return formats.iter().any(|format| format.eq_ignore_ascii_case(file_type));
+        }
+
         matches!(
             file_type.to_lowercase().as_str(),
             "pdf"
@@ -240,15 +388,9 @@ impl FrameworkAdapter for SubprocessAdapter {
     async fn extract(&self, file_path: &Path, timeout: Duration) -> Result<BenchmarkResult> {
         let file_size = std::fs::metadata(file_path).map_err(Error::Io)?.len();
 
-        let monitor = ResourceMonitor::new();
-        monitor.start(Duration::from_millis(10)).await;
-
-        let (stdout, _stderr, duration) = match self.execute_subprocess(file_path, timeout).await {
+        let (stdout, _stderr, duration, resource_stats) = match self.execute_subprocess(file_path, timeout).await {
             Ok(result) => result,
             Err(e) => {
-                let samples = monitor.stop().await;
-                let resource_stats = ResourceMonitor::calculate_stats(&samples);
-
                 return Ok(BenchmarkResult {
                     framework: self.name.clone(),
                     file_path: file_path.to_path_buf(),
@@ -258,14 +400,7 @@ impl FrameworkAdapter for SubprocessAdapter {
                     duration: Duration::from_secs(0),
                     extraction_duration: None,
                     subprocess_overhead: None,
-                    metrics: PerformanceMetrics {
-                        peak_memory_bytes: resource_stats.peak_memory_bytes,
-                        avg_cpu_percent: resource_stats.avg_cpu_percent,
-                        throughput_bytes_per_sec: 0.0,
-                        p50_memory_bytes: resource_stats.p50_memory_bytes,
-                        p95_memory_bytes: resource_stats.p95_memory_bytes,
-                        p99_memory_bytes: resource_stats.p99_memory_bytes,
-                    },
+                    metrics: PerformanceMetrics::default(),
                     quality: None,
                     iterations: vec![],
                     statistics: None,
@@ -277,13 +412,11 @@ impl FrameworkAdapter for SubprocessAdapter {
                         .to_lowercase(),
                     framework_capabilities: FrameworkCapabilities::default(),
                     pdf_metadata: None,
+                    output: None,
                 });
             }
         };
 
-        let samples = monitor.stop().await;
-        let resource_stats = ResourceMonitor::calculate_stats(&samples);
-
         let parsed = match self.parse_output(&stdout) {
             Ok(value) => value,
             Err(e) => {
@@ -299,6 +432,7 @@ impl FrameworkAdapter for SubprocessAdapter {
                     metrics: PerformanceMetrics {
                         peak_memory_bytes: resource_stats.peak_memory_bytes,
                         avg_cpu_percent: resource_stats.avg_cpu_percent,
+                        cpu_time_ms: resource_stats.cpu_time_ms,
                         throughput_bytes_per_sec: 0.0,
                         p50_memory_bytes: resource_stats.p50_memory_bytes,
                         p95_memory_bytes: resource_stats.p95_memory_bytes,
@@ -315,6 +449,7 @@ impl FrameworkAdapter for SubprocessAdapter {
                         .to_lowercase(),
                     framework_capabilities: FrameworkCapabilities::default(),
                     pdf_metadata: None,
+                    output: None,
                 });
             }
         };
@@ -335,6 +470,7 @@ impl FrameworkAdapter for SubprocessAdapter {
         let metrics = PerformanceMetrics {
             peak_memory_bytes: resource_stats.peak_memory_bytes,
             avg_cpu_percent: resource_stats.avg_cpu_percent,
+            cpu_time_ms: resource_stats.cpu_time_ms,
             throughput_bytes_per_sec: throughput,
             p50_memory_bytes: resource_stats.p50_memory_bytes,
             p95_memory_bytes: resource_stats.p95_memory_bytes,
@@ -362,11 +498,12 @@ impl FrameworkAdapter for SubprocessAdapter {
                 .to_lowercase(),
             framework_capabilities: FrameworkCapabilities::default(),
             pdf_metadata: None,
+            output: Some(ExtractedOutput::from_json(&parsed)),
         })
     }
 
     fn version(&self) -> String {
-        "unknown".to_string()
+        self.version.clone().unwrap_or_else(|| "unknown".to_string())
     }
 
     fn supports_batch(&self) -> bool {
@@ -387,15 +524,9 @@ impl FrameworkAdapter for SubprocessAdapter {
             .filter_map(|p| std::fs::metadata(p).ok().map(|m| m.len()))
             .sum();
 
-        let monitor = ResourceMonitor::new();
-        monitor.start(Duration::from_millis(10)).await;
-
-        let (_stdout, _stderr, duration) = match self.execute_subprocess_batch(file_paths, timeout).await {
+        let (_, _, duration, resource_stats) = match self.execute_subprocess_batch(file_paths, timeout).await {
             Ok(result) => result,
             Err(e) => {
-                let samples = monitor.stop().await;
-                let resource_stats = ResourceMonitor::calculate_stats(&samples);
-
                 return Ok(vec![BenchmarkResult {
                     framework: self.name.clone(),
                     file_path: PathBuf::from(format!("batch-{}-files", file_paths.len())),
@@ -405,14 +536,7 @@ impl FrameworkAdapter for SubprocessAdapter {
                     duration: Duration::from_secs(0),
                     extraction_duration: None,
                     subprocess_overhead: None,
-                    metrics: PerformanceMetrics {
-                        peak_memory_bytes: resource_stats.peak_memory_bytes,
-                        avg_cpu_percent: resource_stats.avg_cpu_percent,
-                        throughput_bytes_per_sec: 0.0,
-                        p50_memory_bytes: resource_stats.p50_memory_bytes,
-                        p95_memory_bytes: resource_stats.p95_memory_bytes,
-                        p99_memory_bytes: resource_stats.p99_memory_bytes,
-                    },
+                    metrics: PerformanceMetrics::default(),
                     quality: None,
                     iterations: vec![],
                     statistics: None,
@@ -420,13 +544,11 @@ impl FrameworkAdapter for SubprocessAdapter {
                     file_extension: "batch".to_string(),
                     framework_capabilities: FrameworkCapabilities::default(),
                     pdf_metadata: None,
+                    output: None,
                 }]);
             }
         };
 
-        let samples = monitor.stop().await;
-        let resource_stats = ResourceMonitor::calculate_stats(&samples);
-
         let batch_throughput = if duration.as_secs_f64() > 0.0 {
             total_file_size as f64 / duration.as_secs_f64()
         } else {
@@ -445,6 +567,7 @@ impl FrameworkAdapter for SubprocessAdapter {
             metrics: PerformanceMetrics {
                 peak_memory_bytes: resource_stats.peak_memory_bytes,
                 avg_cpu_percent: resource_stats.avg_cpu_percent,
+                cpu_time_ms: resource_stats.cpu_time_ms,
                 throughput_bytes_per_sec: batch_throughput,
                 p50_memory_bytes: resource_stats.p50_memory_bytes,
                 p95_memory_bytes: resource_stats.p95_memory_bytes,
@@ -457,9 +580,17 @@ impl FrameworkAdapter for SubprocessAdapter {
             file_extension: "batch".to_string(),
             framework_capabilities: FrameworkCapabilities::default(),
             pdf_metadata: None,
+            output: None,
         }])
     }
 
+    async fn warmup(&self, warmup_file: &Path, timeout: Duration) -> Result<Duration> {
+        let start = Instant::now();
+        self.run_warmup_command(timeout).await?;
+        let _ = self.extract(warmup_file, timeout).await?;
+        Ok(start.elapsed())
+    }
+
     async fn setup(&self) -> Result<()> {
         which::which(&self.command)
             .map_err(|e| Error::Benchmark(format!("Command '{}' not found: {}", self.command.display(), e)))?;
@@ -477,6 +608,7 @@ impl Default for PerformanceMetrics {
         Self {
             peak_memory_bytes: 0,
             avg_cpu_percent: 0.0,
+            cpu_time_ms: 0,
             throughput_bytes_per_sec: 0.0,
             p50_memory_bytes: 0,
             p95_memory_bytes: 0,
@@ -502,4 +634,24 @@ mod tests {
         assert!(adapter.supports_format("docx"));
         assert!(!adapter.supports_format("unknown"));
     }
+
+    #[test]
+    fn test_build_args_placeholders() {
+        let file = Path::new("/docs/a.pdf");
+        let other = Path::new("/docs/b.pdf");
+
+        let appended = SubprocessAdapter::new("test", "echo", vec!["-v".to_string()], vec![]);
+        assert_eq!(appended.build_args(&[file]), vec!["-v", "/docs/a.pdf"]);
+
+        let args = vec!["--input={file}".to_string(), "-q".to_string()];
+        let single = SubprocessAdapter::new("test", "echo", args, vec![]);
+        assert_eq!(single.build_args(&[file]), vec!["--input=/docs/a.pdf", "-q"]);
+
+        let args = vec!["{files}".to_string(), "-q".to_string()];
+        let batch = SubprocessAdapter::with_batch_support("test", "echo", args, vec![]);
+        assert_eq!(
+            batch.build_args(&[file, other]),
+            vec!["/docs/a.pdf", "/docs/b.pdf", "-q"]
+        );
+    }
 }
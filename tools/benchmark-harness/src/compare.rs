@@ -0,0 +1,431 @@
+//! Baseline comparison and regression gate
+//!
+//! Compares two `results.json` files per framework, looking only at files present in both runs:
+//!
+//! - **Latency**: median of the per-file median durations; a regression is a relative increase
+//!   above the latency threshold
+//! - **Accuracy**: mean quality score over files with ground truth; a regression is a relative
+//!   decrease above the accuracy threshold
+//! - **Failures**: files that succeeded in the baseline but fail in the current run
+//!
+//! Frameworks missing from the current run are reported but not treated as regressions, so a
+//! CI job can benchmark a subset of frameworks against a full baseline.
+
+use crate::types::BenchmarkResult;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Allowed relative regressions before a comparison fails
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RegressionThresholds {
+    /// Maximum allowed relative increase of median latency (0.10 = 10%)
+    pub max_latency_regression: f64,
+
+    /// Maximum allowed relative decrease of the mean quality score (0.10 = 10%)
+    pub max_accuracy_regression: f64,
+}
+
+impl Default for RegressionThresholds {
+    fn default() -> Self {
+        Self {
+            max_latency_regression: 0.10,
+            max_accuracy_regression: 0.10,
+        }
+    }
+}
+
+/// Parse a regression threshold such as `"10%"` or `"10"` into a fraction (`0.10`)
+pub fn parse_percentage(value: &str) -> Result<f64> {
+    let number = value.trim().trim_end_matches('%').trim();
+    let percent: f64 = number
+        .parse()
+        .map_err(|_| Error::Config(format!("Invalid percentage '{}': expected e.g. \"10%\"", value)))?;
+
+    if !percent.is_finite() || percent < 0.0 {
+        return Err(Error::Config(format!(
+            "Invalid percentage '{}': must be a non-negative number",
+            value
+        )));
+    }
+
+    Ok(percent / 100.0)
+}
+
+/// Comparison of one framework between baseline and current run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrameworkComparison {
+    /// Framework name
+    pub framework: String,
+
+    /// Number of files present in both runs
+    pub files_compared: usize,
+
+    /// Median latency in the baseline run (successful files only)
+    pub baseline_median: Option<Duration>,
+
+    /// Median latency in the current run (successful files only)
+    pub current_median: Option<Duration>,
+
+    /// Relative latency change (0.05 = 5% slower)
+    pub latency_change: Option<f64>,
+
+    /// Mean quality score in the baseline run
+    pub baseline_quality: Option<f64>,
+
+    /// Mean quality score in the current run
+    pub current_quality: Option<f64>,
+
+    /// Relative quality change (-0.05 = 5% less accurate)
+    pub quality_change: Option<f64>,
+
+    /// Files that succeeded in the baseline but fail in the current run
+    pub new_failures: Vec<PathBuf>,
+
+    /// Human-readable descriptions of threshold violations
+    pub regressions: Vec<String>,
+}
+
+/// Result of comparing two benchmark runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonReport {
+    /// Thresholds the comparison was checked against
+    pub thresholds: RegressionThresholds,
+
+    /// Per-framework comparisons for frameworks present in both runs
+    pub frameworks: Vec<FrameworkComparison>,
+
+    /// Frameworks present in the baseline but missing from the current run
+    pub missing_frameworks: Vec<String>,
+}
+
+impl ComparisonReport {
+    /// Whether any framework regressed beyond the thresholds
+    pub fn has_regressions(&self) -> bool {
+        self.frameworks.iter().any(|f| !f.regressions.is_empty())
+    }
+}
+
+impl fmt::Display for ComparisonReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Thresholds: latency +{:.1}%, accuracy -{:.1}%",
+            self.thresholds.max_latency_regression * 100.0,
+            self.thresholds.max_accuracy_regression * 100.0
+        )?;
+
+        for comparison in &self.frameworks {
+            let status = if comparison.regressions.is_empty() {
+                "ok"
+            } else {
+                "REGRESSED"
+            };
+            writeln!(
+                f,
+                "\n{} ({} file(s)): {}",
+                comparison.framework, comparison.files_compared, status
+            )?;
+            writeln!(
+                f,
+                "  median latency: {} -> {} ({})",
+                format_duration(comparison.baseline_median),
+                format_duration(comparison.current_median),
+                format_change(comparison.latency_change)
+            )?;
+            if comparison.baseline_quality.is_some() || comparison.current_quality.is_some() {
+                writeln!(
+                    f,
+                    "  quality score:  {} -> {} ({})",
+                    format_score(comparison.baseline_quality),
+                    format_score(comparison.current_quality),
+                    format_change(comparison.quality_change)
+                )?;
+            }
+            for regression in &comparison.regressions {
+                writeln!(f, "  ✗ {}", regression)?;
+            }
+        }
+
+        for framework in &self.missing_frameworks {
+            writeln!(f, "\n{}: missing from current run", framework)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn format_duration(duration: Option<Duration>) -> String {
+    duration.map_or_else(|| "n/a".to_string(), |d| format!("{:.1}ms", d.as_secs_f64() * 1000.0))
+}
+
+fn format_score(score: Option<f64>) -> String {
+    score.map_or_else(|| "n/a".to_string(), |s| format!("{:.3}", s))
+}
+
+fn format_change(change: Option<f64>) -> String {
+    change.map_or_else(|| "n/a".to_string(), |c| format!("{:+.1}%", c * 100.0))
+}
+
+/// Load benchmark results written by `write_json`
+pub fn load_results(path: &Path) -> Result<Vec<BenchmarkResult>> {
+    let contents = std::fs::read_to_string(path).map_err(Error::Io)?;
+    serde_json::from_str(&contents)
+        .map_err(|e| Error::Benchmark(format!("Failed to parse results {}: {}", path.display(), e)))
+}
+
+/// Compare a current run against a baseline
+///
+/// # Arguments
+/// * `baseline` - Results of the reference run
+/// * `current` - Results of the run under test
+/// * `thresholds` - Allowed relative regressions
+pub fn compare_results(
+    baseline: &[BenchmarkResult],
+    current: &[BenchmarkResult],
+    thresholds: RegressionThresholds,
+) -> ComparisonReport {
+    let baseline = group_by_framework(baseline);
+    let current = group_by_framework(current);
+
+    let mut frameworks = Vec::new();
+    let mut missing_frameworks = Vec::new();
+
+    for (framework, baseline_files) in &baseline {
+        let Some(current_files) = current.get(framework) else {
+            missing_frameworks.push(framework.to_string());
+            continue;
+        };
+
+        let common: BTreeSet<&Path> = baseline_files
+            .keys()
+            .filter(|path| current_files.contains_key(*path))
+            .copied()
+            .collect();
+
+        let pairs: Vec<(&BenchmarkResult, &BenchmarkResult)> = common
+            .iter()
+            .map(|path| (baseline_files[path], current_files[path]))
+            .collect();
+
+        frameworks.push(compare_framework(framework, &pairs, thresholds));
+    }
+
+    ComparisonReport {
+        thresholds,
+        frameworks,
+        missing_frameworks,
+    }
+}
+
+fn group_by_framework(results: &[BenchmarkResult]) -> BTreeMap<&str, BTreeMap<&Path, &BenchmarkResult>> {
+    let mut grouped: BTreeMap<&str, BTreeMap<&Path, &BenchmarkResult>> = BTreeMap::new();
+    for result in results {
+        grouped
+            .entry(result.framework.as_str())
+            .or_default()
+            .insert(result.file_path.as_path(), result);
+    }
+    grouped
+}
+
+fn compare_framework(
+    framework: &str,
+    pairs: &[(&BenchmarkResult, &BenchmarkResult)],
+    thresholds: RegressionThresholds,
+) -> FrameworkComparison {
+    let new_failures: Vec<PathBuf> = pairs
+        .iter()
+        .filter(|(baseline, current)| baseline.success && !current.success)
+        .map(|(_, current)| current.file_path.clone())
+        .collect();
+
+    let both_succeeded: Vec<_> = pairs.iter().filter(|(b, c)| b.success && c.success).collect();
+
+    let baseline_median = median(both_succeeded.iter().map(|(b, _)| median_duration(b)).collect());
+    let current_median = median(both_succeeded.iter().map(|(_, c)| median_duration(c)).collect());
+    let latency_change = match (baseline_median, current_median) {
+        (Some(baseline), Some(current)) if !baseline.is_zero() => {
+            Some(current.as_secs_f64() / baseline.as_secs_f64() - 1.0)
+        }
+        _ => None,
+    };
+
+    let scored: Vec<(f64, f64)> = both_succeeded
+        .iter()
+        .filter_map(|(b, c)| Some((b.quality.as_ref()?.quality_score, c.quality.as_ref()?.quality_score)))
+        .collect();
+    let baseline_quality = mean(scored.iter().map(|(b, _)| *b));
+    let current_quality = mean(scored.iter().map(|(_, c)| *c));
+    let quality_change = match (baseline_quality, current_quality) {
+        (Some(baseline), Some(current)) if baseline > 0.0 => Some(current / baseline - 1.0),
+        _ => None,
+    };
+
+    let mut regressions = Vec::new();
+    if let Some(change) = latency_change
+        && change > thresholds.max_latency_regression
+    {
+        regressions.push(format!(
+            "median latency increased by {:.1}% (allowed {:.1}%)",
+            change * 100.0,
+            thresholds.max_latency_regression * 100.0
+        ));
+    }
+    if let Some(change) = quality_change
+        && -change > thresholds.max_accuracy_regression
+    {
+        regressions.push(format!(
+            "quality score decreased by {:.1}% (allowed {:.1}%)",
+            -change * 100.0,
+            thresholds.max_accuracy_regression * 100.0
+        ));
+    }
+    if !new_failures.is_empty() {
+        regressions.push(format!(
+            "{} file(s) that succeeded in the baseline now fail",
+            new_failures.len()
+        ));
+    }
+
+    FrameworkComparison {
+        framework: framework.to_string(),
+        files_compared: pairs.len(),
+        baseline_median,
+        current_median,
+        latency_change,
+        baseline_quality,
+        current_quality,
+        quality_change,
+        new_failures,
+        regressions,
+    }
+}
+
+/// Median duration of a result: the iteration median when available, else its duration
+fn median_duration(result: &BenchmarkResult) -> Duration {
+    result
+        .statistics
+        .as_ref()
+        .map_or(result.duration, |statistics| statistics.median)
+}
+
+fn median(mut values: Vec<Duration>) -> Option<Duration> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort();
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        Some((values[mid - 1] + values[mid]) / 2)
+    } else {
+        Some(values[mid])
+    }
+}
+
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, count), value| (sum + value, count + 1));
+    if count == 0 { None } else { Some(sum / count as f64) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{PerformanceMetrics, QualityMetrics};
+
+    fn result(framework: &str, file: &str, duration_ms: u64, success: bool, quality: Option<f64>) -> BenchmarkResult {
+        BenchmarkResult {
+            framework: framework.to_string(),
+            file_path: PathBuf::from(file),
+            file_size: 1024,
+            success,
+            error_message: None,
+            duration: Duration::from_millis(duration_ms),
+            extraction_duration: None,
+            subprocess_overhead: None,
+            metrics: PerformanceMetrics::default(),
+            quality: quality.map(|quality_score| QualityMetrics {
+                f1_score_text: quality_score,
+                f1_score_numeric: quality_score,
+                cer: 1.0 - quality_score,
+                wer: 1.0 - quality_score,
+                table_cell_f1: None,
+                metadata_accuracy: None,
+                quality_score,
+            }),
+            iterations: vec![],
+            statistics: None,
+            cold_start_duration: None,
+            file_extension: "pdf".to_string(),
+            framework_capabilities: Default::default(),
+            pdf_metadata: None,
+            output: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_percentage() {
+        assert!((parse_percentage("10%").unwrap() - 0.10).abs() < 1e-9);
+        assert!((parse_percentage(" 2.5 ").unwrap() - 0.025).abs() < 1e-9);
+        assert!(parse_percentage("ten").is_err());
+        assert!(parse_percentage("-5%").is_err());
+    }
+
+    #[test]
+    fn test_no_regression_within_threshold() {
+        let baseline = vec![
+            result("kreuzberg", "a.pdf", 100, true, Some(0.9)),
+            result("kreuzberg", "b.pdf", 200, true, None),
+        ];
+        let current = vec![
+            result("kreuzberg", "a.pdf", 105, true, Some(0.88)),
+            result("kreuzberg", "b.pdf", 210, true, None),
+            result("kreuzberg", "new.pdf", 5000, true, None),
+        ];
+
+        let report = compare_results(&baseline, &current, RegressionThresholds::default());
+        assert!(!report.has_regressions(), "{}", report);
+
+        let comparison = &report.frameworks[0];
+        assert_eq!(comparison.files_compared, 2);
+        assert_eq!(comparison.baseline_median, Some(Duration::from_millis(150)));
+        assert!((comparison.latency_change.unwrap() - 0.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_latency_accuracy_and_failure_regressions() {
+        let baseline = vec![
+            result("kreuzberg", "a.pdf", 100, true, Some(0.9)),
+            result("kreuzberg", "b.pdf", 100, true, None),
+            result("docling", "a.pdf", 1000, true, None),
+        ];
+        let current = vec![
+            result("kreuzberg", "a.pdf", 150, true, Some(0.6)),
+            result("kreuzberg", "b.pdf", 100, false, None),
+        ];
+
+        let report = compare_results(&baseline, &current, RegressionThresholds::default());
+        assert!(report.has_regressions());
+        assert_eq!(report.missing_frameworks, vec!["docling".to_string()]);
+
+        let comparison = &report.frameworks[0];
+        assert_eq!(comparison.regressions.len(), 3, "{:?}", comparison.regressions);
+        assert_eq!(comparison.new_failures, vec![PathBuf::from("b.pdf")]);
+        assert!(report.to_string().contains("REGRESSED"));
+    }
+
+    #[test]
+    fn test_load_results_roundtrip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("results.json");
+        let results = vec![result("kreuzberg", "a.pdf", 100, true, Some(1.0))];
+        crate::output::write_json(&results, &path).unwrap();
+
+        let loaded = load_results(&path).unwrap();
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].quality.as_ref().unwrap().quality_score, 1.0);
+    }
+}
@@ -31,7 +31,7 @@ pub struct BenchmarkConfig {
     /// Output directory for results
     pub output_dir: PathBuf,
 
-    /// Whether to include quality assessment
+    /// Whether to score results against fixture ground truth (CER/WER, table cell F1, metadata accuracy)
     pub measure_quality: bool,
 
     /// Sample interval for resource monitoring (milliseconds)
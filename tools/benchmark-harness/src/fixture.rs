@@ -17,7 +17,12 @@
 //!   },
 //!   "ground_truth": {
 //!     "text_file": "path/to/ground_truth.txt",
-//!     "source": "pdf_text_layer"
+//!     "source": "pdf_text_layer",
+//!     "tables_file": "path/to/expected_tables.json",
+//!     "metadata": {
+//!       "title": "Test Document",
+//!       "page_count": 10
+//!     }
 //!   }
 //! }
 //! ```
@@ -61,6 +66,19 @@ pub struct GroundTruth {
 
     /// Source of the ground truth ("pdf_text_layer", "markdown_file", "manual")
     pub source: String,
+
+    /// Path to a JSON file with the expected tables (optional)
+    ///
+    /// Either an array of tables given as rows of cell strings, or an array of
+    /// objects with a `cells` field in the format Kreuzberg returns tables.
+    #[serde(default)]
+    pub tables_file: Option<PathBuf>,
+
+    /// Expected metadata field values (optional)
+    ///
+    /// Keys are metadata field names or dotted paths into nested metadata (e.g. "pdf.page_count").
+    #[serde(default)]
+    pub metadata: HashMap<String, serde_json::Value>,
 }
 
 impl Fixture {
@@ -97,6 +115,13 @@ impl Fixture {
                 });
             }
 
+            if gt.tables_file.as_ref().is_some_and(|f| f.is_absolute()) {
+                return Err(Error::InvalidFixture {
+                    path: fixture_path.to_path_buf(),
+                    reason: "ground_truth.tables_file must be relative".to_string(),
+                });
+            }
+
             if !matches!(gt.source.as_str(), "pdf_text_layer" | "markdown_file" | "manual") {
                 return Err(Error::InvalidFixture {
                     path: fixture_path.to_path_buf(),
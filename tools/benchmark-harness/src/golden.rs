@@ -0,0 +1,683 @@
+//! Golden-output regression checks
+//!
+//! A golden corpus is a directory with a `golden.toml` manifest listing documents, and the
+//! checked-in golden output of each document: `<name>.md` with the extracted content and
+//! `<name>.json` with the MIME type, metadata and tables. [`check_corpus`] extracts every
+//! document with Kreuzberg and compares the output against its golden output:
+//!
+//! - **Text similarity**: 1 - CER of the content, ignoring whitespace differences
+//! - **Table cell F1**: see [`table_cell_f1`], checked when either side has tables
+//! - **Metadata accuracy**: fraction of golden metadata fields with a matching value, skipping
+//!   fields listed in `ignore_metadata` (e.g. timestamps)
+//! - **MIME type**: must not change
+//!
+//! A document regresses when a score falls below its tolerance. Scores are also summarized per
+//! format (file extension), so a drop in one extractor stands out. After an intended change,
+//! [`update_corpus`] rewrites the golden outputs for review in the diff.
+//!
+//! ## Manifest Format
+//!
+//! ```toml
+//! [tolerance]
+//! min_text_similarity = 0.98
+//! min_table_f1 = 0.95
+//! min_metadata_accuracy = 1.0
+//! ignore_metadata = ["created_at", "modified_at"]
+//!
+//! [[document]]
+//! name = "scanned_invoice"
+//! path = "../../../test_documents/images/invoice.png"
+//! # Per-document overrides of the tolerances above
+//! min_text_similarity = 0.9
+//! ```
+//!
+//! Edit distances are quadratic in the length of the differing region, so golden corpora
+//! should be kept to small documents.
+
+use crate::quality::{character_error_rate, metadata_accuracy, table_cell_f1};
+use crate::{Error, Result};
+use kreuzberg::{ExtractionConfig, ExtractionResult, extract_file};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// File name of the manifest in a golden corpus directory
+pub const MANIFEST_FILE: &str = "golden.toml";
+
+/// Tolerances for comparing an extraction against its golden output
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Tolerance {
+    /// Minimum text similarity (1 - CER, ignoring whitespace differences)
+    pub min_text_similarity: f64,
+
+    /// Minimum table cell F1
+    pub min_table_f1: f64,
+
+    /// Minimum fraction of golden metadata fields with a matching value
+    pub min_metadata_accuracy: f64,
+
+    /// Top-level metadata fields that are not compared
+    pub ignore_metadata: Vec<String>,
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self {
+            min_text_similarity: 0.98,
+            min_table_f1: 0.95,
+            min_metadata_accuracy: 1.0,
+            ignore_metadata: Vec::new(),
+        }
+    }
+}
+
+/// A document of a golden corpus (`[[document]]` entry of the manifest)
+#[derive(Debug, Clone, Deserialize)]
+pub struct GoldenDocument {
+    /// Name of the golden output files (`<name>.md` and `<name>.json`)
+    pub name: String,
+
+    /// Path to the document, relative to the corpus directory
+    pub path: PathBuf,
+
+    /// Override of the corpus `min_text_similarity`
+    #[serde(default)]
+    pub min_text_similarity: Option<f64>,
+
+    /// Override of the corpus `min_table_f1`
+    #[serde(default)]
+    pub min_table_f1: Option<f64>,
+
+    /// Override of the corpus `min_metadata_accuracy`
+    #[serde(default)]
+    pub min_metadata_accuracy: Option<f64>,
+
+    /// Metadata fields ignored in addition to the corpus `ignore_metadata`
+    #[serde(default)]
+    pub ignore_metadata: Vec<String>,
+}
+
+impl GoldenDocument {
+    /// Tolerances of this document: the corpus tolerances with the document's overrides applied
+    pub fn tolerance(&self, corpus: &Tolerance) -> Tolerance {
+        let mut ignore_metadata = corpus.ignore_metadata.clone();
+        ignore_metadata.extend(self.ignore_metadata.iter().cloned());
+
+        Tolerance {
+            min_text_similarity: self.min_text_similarity.unwrap_or(corpus.min_text_similarity),
+            min_table_f1: self.min_table_f1.unwrap_or(corpus.min_table_f1),
+            min_metadata_accuracy: self.min_metadata_accuracy.unwrap_or(corpus.min_metadata_accuracy),
+            ignore_metadata,
+        }
+    }
+
+    /// Format the document is summarized under: its lowercase file extension
+    pub fn format(&self) -> String {
+        self.path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map_or_else(|| "unknown".to_string(), str::to_lowercase)
+    }
+}
+
+/// Manifest of a golden corpus
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct GoldenManifest {
+    /// Tolerances of all documents
+    #[serde(default)]
+    pub tolerance: Tolerance,
+
+    /// Documents of the corpus
+    #[serde(default, rename = "document")]
+    pub documents: Vec<GoldenDocument>,
+}
+
+impl GoldenManifest {
+    /// Load the manifest of a golden corpus directory
+    ///
+    /// # Returns
+    /// * `Ok(GoldenManifest)` - Parsed manifest
+    /// * `Err(Error::FixtureNotFound)` - The directory has no `golden.toml`
+    /// * `Err(Error::Config)` - The manifest is malformed or names a document twice
+    pub fn load(corpus_dir: &Path) -> Result<Self> {
+        let path = corpus_dir.join(MANIFEST_FILE);
+        let contents = std::fs::read_to_string(&path).map_err(|_| Error::FixtureNotFound(path.clone()))?;
+        let manifest: Self = toml::from_str(&contents)
+            .map_err(|e| Error::Config(format!("Invalid golden manifest {}: {}", path.display(), e)))?;
+
+        let mut names = HashSet::new();
+        for document in &manifest.documents {
+            if !names.insert(document.name.as_str()) {
+                return Err(Error::Config(format!(
+                    "Golden document '{}' is listed twice in {}",
+                    document.name,
+                    path.display()
+                )));
+            }
+        }
+
+        Ok(manifest)
+    }
+}
+
+/// Golden output of a document
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GoldenOutput {
+    /// Extracted content, stored as `<name>.md`
+    #[serde(skip)]
+    pub content: String,
+
+    /// Detected MIME type
+    pub mime_type: String,
+
+    /// Extracted metadata fields
+    #[serde(default)]
+    pub metadata: serde_json::Map<String, serde_json::Value>,
+
+    /// Extracted tables as rows of cell texts
+    #[serde(default)]
+    pub tables: Vec<Vec<Vec<String>>>,
+}
+
+impl GoldenOutput {
+    /// Collect the compared parts of an extraction result
+    pub fn from_result(result: &ExtractionResult) -> Self {
+        let metadata = serde_json::to_value(&result.metadata)
+            .ok()
+            .and_then(|value| value.as_object().cloned())
+            .unwrap_or_default();
+
+        Self {
+            content: result.content.clone(),
+            mime_type: result.mime_type.clone(),
+            metadata,
+            tables: result.tables.iter().map(|table| table.cells.clone()).collect(),
+        }
+    }
+
+    /// Read the golden output `name` from a corpus directory
+    ///
+    /// # Returns
+    /// * `Ok(None)` - The corpus has no golden output for `name` yet
+    /// * `Ok(Some(GoldenOutput))` - Loaded golden output
+    /// * `Err(Error)` - A golden file is unreadable or malformed
+    pub fn load(corpus_dir: &Path, name: &str) -> Result<Option<Self>> {
+        let content_path = corpus_dir.join(format!("{}.md", name));
+        let json_path = corpus_dir.join(format!("{}.json", name));
+        if !content_path.exists() || !json_path.exists() {
+            return Ok(None);
+        }
+
+        let content = std::fs::read_to_string(&content_path).map_err(Error::Io)?;
+        let json = std::fs::read_to_string(&json_path).map_err(Error::Io)?;
+        let mut output: Self = serde_json::from_str(&json)
+            .map_err(|e| Error::Config(format!("Invalid golden output {}: {}", json_path.display(), e)))?;
+        output.content = content;
+
+        Ok(Some(output))
+    }
+
+    /// Write the golden output `name` to a corpus directory
+    pub fn save(&self, corpus_dir: &Path, name: &str) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(corpus_dir.join(format!("{}.md", name)), &self.content).map_err(Error::Io)?;
+        std::fs::write(corpus_dir.join(format!("{}.json", name)), json + "\n").map_err(Error::Io)?;
+        Ok(())
+    }
+}
+
+/// Comparison of one document against its golden output
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DocumentReport {
+    /// Name of the golden document
+    pub name: String,
+
+    /// Format the document is summarized under
+    pub format: String,
+
+    /// 1 - CER of the content (1.0 = identical apart from whitespace, 0.0 when not extracted)
+    pub text_similarity: f64,
+
+    /// Table cell F1 (None when neither side has tables)
+    pub table_cell_f1: Option<f64>,
+
+    /// Metadata accuracy (None when no golden metadata field is compared)
+    pub metadata_accuracy: Option<f64>,
+
+    /// Human-readable descriptions of tolerance violations
+    pub regressions: Vec<String>,
+}
+
+impl DocumentReport {
+    fn failed(document: &GoldenDocument, reason: String) -> Self {
+        Self {
+            name: document.name.clone(),
+            format: document.format(),
+            text_similarity: 0.0,
+            table_cell_f1: None,
+            metadata_accuracy: None,
+            regressions: vec![reason],
+        }
+    }
+}
+
+/// Scores of all documents of one format
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FormatSummary {
+    /// Format (file extension)
+    pub format: String,
+
+    /// Number of documents
+    pub documents: usize,
+
+    /// Number of regressed documents
+    pub regressed: usize,
+
+    /// Mean text similarity
+    pub mean_text_similarity: f64,
+
+    /// Lowest text similarity
+    pub min_text_similarity: f64,
+
+    /// Mean table cell F1 over documents with tables
+    pub mean_table_cell_f1: Option<f64>,
+}
+
+/// Result of checking a golden corpus
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CorpusReport {
+    /// Per-format summaries, ordered by format
+    pub formats: Vec<FormatSummary>,
+
+    /// Per-document comparisons, in manifest order
+    pub documents: Vec<DocumentReport>,
+}
+
+impl CorpusReport {
+    /// Create the report of the given document comparisons, summarizing them per format
+    pub fn new(documents: Vec<DocumentReport>) -> Self {
+        let mut by_format: BTreeMap<&str, Vec<&DocumentReport>> = BTreeMap::new();
+        for document in &documents {
+            by_format.entry(document.format.as_str()).or_default().push(document);
+        }
+
+        let formats = by_format
+            .into_iter()
+            .map(|(format, reports)| {
+                let similarities: Vec<f64> = reports.iter().map(|r| r.text_similarity).collect();
+                let table_scores: Vec<f64> = reports.iter().filter_map(|r| r.table_cell_f1).collect();
+
+                FormatSummary {
+                    format: format.to_string(),
+                    documents: reports.len(),
+                    regressed: reports.iter().filter(|r| !r.regressions.is_empty()).count(),
+                    mean_text_similarity: mean(&similarities).unwrap_or_default(),
+                    min_text_similarity: similarities.iter().copied().fold(1.0, f64::min),
+                    mean_table_cell_f1: mean(&table_scores),
+                }
+            })
+            .collect();
+
+        Self { formats, documents }
+    }
+
+    /// Whether any document regressed beyond its tolerances
+    pub fn has_regressions(&self) -> bool {
+        self.documents.iter().any(|d| !d.regressions.is_empty())
+    }
+
+    /// Write the report to a JSON file
+    pub fn write_json(&self, output_path: &Path) -> Result<()> {
+        if let Some(parent) = output_path.parent() {
+            std::fs::create_dir_all(parent).map_err(Error::Io)?;
+        }
+
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| Error::Benchmark(format!("Failed to serialize golden report: {}", e)))?;
+
+        std::fs::write(output_path, json).map_err(Error::Io)?;
+
+        Ok(())
+    }
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+}
+
+impl fmt::Display for CorpusReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for summary in &self.formats {
+            let tables = summary
+                .mean_table_cell_f1
+                .map_or_else(String::new, |score| format!(", table cell F1 {:.3}", score));
+            writeln!(
+                f,
+                "{} ({} document(s), {} regressed): text similarity {:.3} mean, {:.3} min{}",
+                summary.format,
+                summary.documents,
+                summary.regressed,
+                summary.mean_text_similarity,
+                summary.min_text_similarity,
+                tables
+            )?;
+
+            for document in self.documents.iter().filter(|d| d.format == summary.format) {
+                let status = if document.regressions.is_empty() {
+                    "ok"
+                } else {
+                    "REGRESSED"
+                };
+                writeln!(
+                    f,
+                    "  {}: {} (text similarity {:.3})",
+                    document.name, status, document.text_similarity
+                )?;
+                for regression in &document.regressions {
+                    writeln!(f, "    ✗ {}", regression)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Compare an extraction output against the golden output of a document
+///
+/// # Arguments
+/// * `document` - Golden document the outputs belong to
+/// * `golden` - Checked-in golden output
+/// * `actual` - Output of the current extraction
+/// * `tolerance` - Tolerances of the document (see [`GoldenDocument::tolerance`])
+pub fn compare_output(
+    document: &GoldenDocument,
+    golden: &GoldenOutput,
+    actual: &GoldenOutput,
+    tolerance: &Tolerance,
+) -> DocumentReport {
+    let mut regressions = Vec::new();
+
+    if actual.mime_type != golden.mime_type {
+        regressions.push(format!(
+            "MIME type changed from {} to {}",
+            golden.mime_type, actual.mime_type
+        ));
+    }
+
+    let text_similarity = 1.0 - character_error_rate(&golden.content, &actual.content).min(1.0);
+    if text_similarity < tolerance.min_text_similarity {
+        regressions.push(format!(
+            "text similarity {:.3} below {:.3}",
+            text_similarity, tolerance.min_text_similarity
+        ));
+    }
+
+    let table_f1 =
+        (!golden.tables.is_empty() || !actual.tables.is_empty()).then(|| table_cell_f1(&golden.tables, &actual.tables));
+    if let Some(score) = table_f1
+        && score < tolerance.min_table_f1
+    {
+        regressions.push(format!(
+            "table cell F1 {:.3} below {:.3}",
+            score, tolerance.min_table_f1
+        ));
+    }
+
+    let expected_metadata: HashMap<String, serde_json::Value> = golden
+        .metadata
+        .iter()
+        .filter(|(field, _)| !tolerance.ignore_metadata.contains(*field))
+        .map(|(field, value)| (field.clone(), value.clone()))
+        .collect();
+    let metadata = (!expected_metadata.is_empty()).then(|| metadata_accuracy(&expected_metadata, &actual.metadata));
+    if let Some(accuracy) = metadata
+        && accuracy < tolerance.min_metadata_accuracy
+    {
+        regressions.push(format!(
+            "metadata accuracy {:.3} below {:.3}",
+            accuracy, tolerance.min_metadata_accuracy
+        ));
+    }
+
+    DocumentReport {
+        name: document.name.clone(),
+        format: document.format(),
+        text_similarity,
+        table_cell_f1: table_f1,
+        metadata_accuracy: metadata,
+        regressions,
+    }
+}
+
+async fn extract(corpus_dir: &Path, document: &GoldenDocument, config: &ExtractionConfig) -> Result<GoldenOutput> {
+    let path = corpus_dir.join(&document.path);
+    if !path.exists() {
+        return Err(Error::DocumentNotFound(path));
+    }
+
+    let result = extract_file(&path, None, config)
+        .await
+        .map_err(|e| Error::ExtractionFailed {
+            framework: "kreuzberg".to_string(),
+            file: path.clone(),
+            message: e.to_string(),
+        })?;
+
+    Ok(GoldenOutput::from_result(&result))
+}
+
+/// Extract every document of a golden corpus and compare it against its golden output
+///
+/// Documents without golden output and documents that fail to extract are reported as
+/// regressions, so the check fails until their golden output is created with
+/// [`update_corpus`].
+///
+/// # Arguments
+/// * `corpus_dir` - Directory containing `golden.toml` and the golden outputs
+/// * `config` - Extraction configuration (the one the golden outputs were created with)
+pub async fn check_corpus(corpus_dir: &Path, config: &ExtractionConfig) -> Result<CorpusReport> {
+    let manifest = GoldenManifest::load(corpus_dir)?;
+
+    let mut documents = Vec::with_capacity(manifest.documents.len());
+    for document in &manifest.documents {
+        let tolerance = document.tolerance(&manifest.tolerance);
+        let Some(golden) = GoldenOutput::load(corpus_dir, &document.name)? else {
+            let reason = "no golden output (create it with --update)".to_string();
+            documents.push(DocumentReport::failed(document, reason));
+            continue;
+        };
+
+        let report = match extract(corpus_dir, document, config).await {
+            Ok(actual) => compare_output(document, &golden, &actual, &tolerance),
+            Err(e) => DocumentReport::failed(document, e.to_string()),
+        };
+        documents.push(report);
+    }
+
+    Ok(CorpusReport::new(documents))
+}
+
+/// Extract every document of a golden corpus and write the outputs as its golden outputs
+///
+/// # Returns
+/// The number of golden outputs written
+pub async fn update_corpus(corpus_dir: &Path, config: &ExtractionConfig) -> Result<usize> {
+    let manifest = GoldenManifest::load(corpus_dir)?;
+
+    for document in &manifest.documents {
+        extract(corpus_dir, document, config)
+            .await?
+            .save(corpus_dir, &document.name)?;
+    }
+
+    Ok(manifest.documents.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn document(name: &str) -> GoldenDocument {
+        GoldenDocument {
+            name: name.to_string(),
+            path: PathBuf::from(format!("{}.txt", name)),
+            min_text_similarity: None,
+            min_table_f1: None,
+            min_metadata_accuracy: None,
+            ignore_metadata: Vec::new(),
+        }
+    }
+
+    fn output(content: &str, title: &str, modified_at: &str) -> GoldenOutput {
+        let metadata = json!({"title": title, "modified_at": modified_at});
+        GoldenOutput {
+            content: content.to_string(),
+            mime_type: "text/plain".to_string(),
+            metadata: metadata.as_object().unwrap().clone(),
+            tables: Vec::new(),
+        }
+    }
+
+    fn write_corpus(dir: &Path) {
+        std::fs::write(dir.join("notes.txt"), "Golden outputs catch extraction regressions.\n").unwrap();
+        std::fs::write(
+            dir.join(MANIFEST_FILE),
+            r#"
+            [tolerance]
+            ignore_metadata = ["encoding"]
+
+            [[document]]
+            name = "notes"
+            path = "notes.txt"
+            "#,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_manifest_tolerances() {
+        let manifest: GoldenManifest = toml::from_str(
+            r#"
+            [tolerance]
+            min_text_similarity = 0.9
+            ignore_metadata = ["created_at"]
+
+            [[document]]
+            name = "scan"
+            path = "docs/Scan.PNG"
+            min_text_similarity = 0.8
+            ignore_metadata = ["modified_at"]
+            "#,
+        )
+        .unwrap();
+
+        let tolerance = manifest.documents[0].tolerance(&manifest.tolerance);
+        assert_eq!(tolerance.min_text_similarity, 0.8);
+        assert_eq!(tolerance.min_table_f1, 0.95);
+        assert_eq!(tolerance.min_metadata_accuracy, 1.0);
+        assert_eq!(tolerance.ignore_metadata, vec!["created_at", "modified_at"]);
+        assert_eq!(manifest.documents[0].format(), "png");
+    }
+
+    #[test]
+    fn test_manifest_rejects_duplicate_names() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join(MANIFEST_FILE),
+            "[[document]]\nname = \"a\"\npath = \"a.txt\"\n\n[[document]]\nname = \"a\"\npath = \"b.txt\"\n",
+        )
+        .unwrap();
+
+        assert!(matches!(GoldenManifest::load(temp_dir.path()), Err(Error::Config(_))));
+    }
+
+    #[test]
+    fn test_compare_output() {
+        let document = document("doc");
+        let mut tolerance = Tolerance {
+            ignore_metadata: vec!["modified_at".to_string()],
+            ..Default::default()
+        };
+        let golden = output("Quarterly report\n\nRevenue grew", "Q3", "2024");
+
+        let reformatted = output("Quarterly report Revenue grew", "Q3", "2025");
+        let report = compare_output(&document, &golden, &reformatted, &tolerance);
+        assert!(report.regressions.is_empty(), "{:?}", report.regressions);
+        assert_eq!(report.text_similarity, 1.0);
+        assert_eq!(report.metadata_accuracy, Some(1.0));
+        assert_eq!(report.table_cell_f1, None);
+
+        let mut degraded = output("Quarterly rep0rt", "Q2", "2024");
+        degraded.mime_type = "text/markdown".to_string();
+        degraded.tables = vec![vec![vec!["Revenue".to_string()]]];
+        let report = compare_output(&document, &golden, &degraded, &tolerance);
+        assert_eq!(report.regressions.len(), 4, "{:?}", report.regressions);
+        assert_eq!(report.table_cell_f1, Some(0.0));
+        assert_eq!(report.metadata_accuracy, Some(0.0));
+
+        tolerance.min_text_similarity = 0.0;
+        tolerance.min_table_f1 = 0.0;
+        tolerance.min_metadata_accuracy = 0.0;
+        let report = compare_output(&document, &golden, &degraded, &tolerance);
+        assert_eq!(report.regressions.len(), 1);
+        assert!(report.regressions[0].starts_with("MIME type changed"));
+    }
+
+    #[test]
+    fn test_corpus_report_per_format() {
+        let report = |name: &str, format: &str, text_similarity: f64| DocumentReport {
+            name: name.to_string(),
+            format: format.to_string(),
+            text_similarity,
+            table_cell_f1: None,
+            metadata_accuracy: None,
+            regressions: (text_similarity < 0.98)
+                .then(|| "text similarity below 0.980".to_string())
+                .into_iter()
+                .collect(),
+        };
+
+        let corpus = CorpusReport::new(vec![
+            report("b", "pdf", 0.9),
+            report("a", "docx", 1.0),
+            report("c", "pdf", 1.0),
+        ]);
+
+        assert!(corpus.has_regressions());
+        let formats: Vec<_> = corpus.formats.iter().map(|summary| summary.format.as_str()).collect();
+        assert_eq!(formats, vec!["docx", "pdf"]);
+        let pdf = &corpus.formats[1];
+        assert_eq!((pdf.documents, pdf.regressed), (2, 1));
+        assert!((pdf.mean_text_similarity - 0.95).abs() < 1e-9);
+        assert_eq!(pdf.min_text_similarity, 0.9);
+        assert!(corpus.to_string().contains("    ✗ text similarity below 0.980"));
+    }
+
+    #[tokio::test]
+    async fn test_update_and_check_corpus() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        write_corpus(temp_dir.path());
+        let config = ExtractionConfig {
+            use_cache: false,
+            ..Default::default()
+        };
+
+        let report = check_corpus(temp_dir.path(), &config).await.unwrap();
+        assert!(report.has_regressions());
+        assert_eq!(report.documents[0].text_similarity, 0.0);
+
+        assert_eq!(update_corpus(temp_dir.path(), &config).await.unwrap(), 1);
+        let report = check_corpus(temp_dir.path(), &config).await.unwrap();
+        assert!(!report.has_regressions(), "{}", report);
+        assert_eq!(report.formats[0].format, "txt");
+
+        std::fs::write(temp_dir.path().join("notes.md"), "Something else entirely").unwrap();
+        let report = check_corpus(temp_dir.path(), &config).await.unwrap();
+        assert!(report.has_regressions());
+        assert!(report.documents[0].regressions[0].starts_with("text similarity"));
+    }
+}
@@ -2,26 +2,33 @@
 //!
 //! This crate provides infrastructure for benchmarking Kreuzberg against other
 //! document extraction frameworks, measuring performance (throughput, memory, latency)
-//! and quality (F1 scores, text accuracy).
+//! and quality (F1 scores, text accuracy), and for catching extraction-quality regressions
+//! against checked-in golden outputs.
 
 pub mod adapter;
 pub mod adapters;
+pub mod compare;
 pub mod config;
 pub mod error;
 pub mod fixture;
+pub mod golden;
 pub mod monitoring;
 pub mod output;
+pub mod quality;
 pub mod registry;
 pub mod runner;
 pub mod types;
 
 pub use adapter::FrameworkAdapter;
 pub use adapters::{NativeAdapter, NodeAdapter, PythonAdapter, RubyAdapter};
+pub use compare::{ComparisonReport, RegressionThresholds, compare_results};
 pub use config::{BenchmarkConfig, BenchmarkMode};
 pub use error::{Error, Result};
 pub use fixture::{Fixture, FixtureManager};
+pub use golden::{CorpusReport, GoldenManifest, check_corpus, update_corpus};
 pub use monitoring::{ResourceMonitor, ResourceSample, ResourceStats};
 pub use output::{write_by_extension_analysis, write_json};
+pub use quality::{ExpectedOutput, ExtractedOutput};
 pub use registry::AdapterRegistry;
 pub use runner::BenchmarkRunner;
-pub use types::{BenchmarkResult, FrameworkCapabilities, PdfMetadata};
+pub use types::{BenchmarkResult, FrameworkCapabilities, PdfMetadata, QualityMetrics};
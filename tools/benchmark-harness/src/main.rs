@@ -87,6 +87,44 @@ enum Commands {
         /// Enable quality assessment
         #[arg(long, default_value = "true")]
         measure_quality: bool,
+
+        /// TOML file declaring custom adapters (`[[adapter]]` entries)
+        #[arg(long)]
+        adapters: Option<PathBuf>,
+    },
+
+    /// Compare results against a baseline and fail on regressions
+    Compare {
+        /// Baseline results.json
+        #[arg(short, long)]
+        baseline: PathBuf,
+
+        /// Current results.json
+        #[arg(short, long)]
+        current: PathBuf,
+
+        /// Maximum allowed median latency regression (e.g. "10%")
+        #[arg(long, default_value = "10%")]
+        max_regression: String,
+
+        /// Maximum allowed quality score regression (defaults to --max-regression)
+        #[arg(long)]
+        max_accuracy_regression: Option<String>,
+    },
+
+    /// Check extraction output against the golden outputs of a corpus and fail on regressions
+    Golden {
+        /// Golden corpus directory containing golden.toml
+        #[arg(short, long)]
+        corpus: PathBuf,
+
+        /// Rewrite the golden outputs from the current extraction instead of checking them
+        #[arg(long)]
+        update: bool,
+
+        /// Write the report as JSON to this file
+        #[arg(short, long)]
+        output: Option<PathBuf>,
     },
 }
 
@@ -141,6 +179,7 @@ async fn main() -> Result<()> {
             iterations,
             ocr,
             measure_quality,
+            adapters,
         } => {
             use benchmark_harness::{AdapterRegistry, BenchmarkRunner, NativeAdapter};
             use kreuzberg::{ExtractionConfig, OcrConfig};
@@ -429,9 +468,19 @@ async fn main() -> Result<()> {
                 "[adapter] Open source extraction frameworks: {}/7 available",
                 external_count
             );
+
+            let mut custom_count = 0;
+
+            if let Some(adapters_path) = &adapters {
+                for name in registry.load_custom_adapters(adapters_path)? {
+                    eprintln!("[adapter] ✓ {} (custom)", name);
+                    custom_count += 1;
+                }
+            }
+
             eprintln!(
                 "[adapter] Total adapters: {} available",
-                kreuzberg_count + external_count
+                kreuzberg_count + external_count + custom_count
             );
 
             let mut runner = BenchmarkRunner::new(config, registry);
@@ -478,5 +527,67 @@ async fn main() -> Result<()> {
 
             Ok(())
         }
+
+        Commands::Compare {
+            baseline,
+            current,
+            max_regression,
+            max_accuracy_regression,
+        } => {
+            use benchmark_harness::compare::{load_results, parse_percentage};
+            use benchmark_harness::{RegressionThresholds, compare_results};
+
+            let max_latency_regression = parse_percentage(&max_regression)?;
+            let thresholds = RegressionThresholds {
+                max_latency_regression,
+                max_accuracy_regression: match max_accuracy_regression {
+                    Some(value) => parse_percentage(&value)?,
+                    None => max_latency_regression,
+                },
+            };
+
+            let report = compare_results(&load_results(&baseline)?, &load_results(&current)?, thresholds);
+            print!("{}", report);
+
+            if report.has_regressions() {
+                eprintln!("\nRegression detected against {}", baseline.display());
+                std::process::exit(1);
+            }
+
+            println!("\nNo regressions against {}", baseline.display());
+            Ok(())
+        }
+
+        Commands::Golden { corpus, update, output } => {
+            use benchmark_harness::{check_corpus, update_corpus};
+            use kreuzberg::ExtractionConfig;
+
+            let config = ExtractionConfig {
+                use_cache: false,
+                ..Default::default()
+            };
+
+            if update {
+                let count = update_corpus(&corpus, &config).await?;
+                println!("Updated {} golden output(s) in {}", count, corpus.display());
+                return Ok(());
+            }
+
+            let report = check_corpus(&corpus, &config).await?;
+            print!("{}", report);
+
+            if let Some(output) = output {
+                report.write_json(&output)?;
+                println!("\nReport written to: {}", output.display());
+            }
+
+            if report.has_regressions() {
+                eprintln!("\nGolden output regression detected in {}", corpus.display());
+                std::process::exit(1);
+            }
+
+            println!("\nAll {} document(s) match their golden output", report.documents.len());
+            Ok(())
+        }
     }
 }
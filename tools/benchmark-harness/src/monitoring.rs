@@ -2,11 +2,15 @@
 //!
 //! This module provides real-time monitoring of CPU and memory usage during
 //! document extraction, with percentile calculations for performance analysis.
+//!
+//! In-process adapters monitor the harness process itself. Subprocess adapters monitor the
+//! spawned process together with all of its descendants, so interpreters that fork workers
+//! (or wrappers such as `uv run`) are measured in full.
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
-use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use sysinfo::{Pid, Process, ProcessRefreshKind, ProcessesToUpdate, System};
 use tokio::sync::Mutex;
 
 /// Sample of resource usage at a point in time
@@ -16,15 +20,27 @@ pub struct ResourceSample {
     pub memory_bytes: u64,
     /// CPU usage percentage (0.0 - 100.0 * num_cpus)
     pub cpu_percent: f64,
+    /// CPU time (user + system) consumed so far, in milliseconds
+    pub cpu_time_ms: u64,
     /// Timestamp when sample was taken (relative to monitoring start)
     pub timestamp_ms: u64,
 }
 
+/// Which processes a [`ResourceMonitor`] accounts for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MonitorScope {
+    /// A single, already running process; CPU time is counted from when monitoring starts
+    Process,
+    /// A process and all of its descendants; CPU time is counted from process start
+    ProcessTree,
+}
+
 /// Resource monitor that samples CPU and memory usage periodically
 pub struct ResourceMonitor {
     samples: Arc<Mutex<Vec<ResourceSample>>>,
     running: Arc<AtomicBool>,
     pid: Pid,
+    scope: MonitorScope,
 }
 
 impl ResourceMonitor {
@@ -35,6 +51,22 @@ impl ResourceMonitor {
             samples: Arc::new(Mutex::new(Vec::new())),
             running: Arc::new(AtomicBool::new(false)),
             pid,
+            scope: MonitorScope::Process,
+        }
+    }
+
+    /// Create a resource monitor for a spawned process and all of its descendants
+    ///
+    /// Memory and CPU usage of the whole process tree are summed in every sample.
+    ///
+    /// # Arguments
+    /// * `pid` - Process ID of the spawned process (e.g., from `Child::id()`)
+    pub fn for_process_tree(pid: u32) -> Self {
+        Self {
+            samples: Arc::new(Mutex::new(Vec::new())),
+            running: Arc::new(AtomicBool::new(false)),
+            pid: Pid::from_u32(pid),
+            scope: MonitorScope::ProcessTree,
         }
     }
 
@@ -52,20 +84,36 @@ impl ResourceMonitor {
         let samples = Arc::clone(&self.samples);
         let running = Arc::clone(&self.running);
         let pid = self.pid;
+        let scope = self.scope;
 
         tokio::spawn(async move {
             let mut system = System::new();
             let start = std::time::Instant::now();
+            let mut cpu_time_baseline_ms = None;
 
             let refresh_kind = ProcessRefreshKind::nothing().with_memory().with_cpu();
 
             while running.load(Ordering::SeqCst) {
-                system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
-
-                if let Some(process) = system.process(pid) {
+                let usage = match scope {
+                    MonitorScope::Process => {
+                        system.refresh_processes_specifics(ProcessesToUpdate::Some(&[pid]), false, refresh_kind);
+                        system.process(pid).map(ProcessUsage::of)
+                    }
+                    MonitorScope::ProcessTree => {
+                        system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh_kind);
+                        ProcessUsage::of_tree(&system, pid)
+                    }
+                };
+
+                if let Some(usage) = usage {
+                    let baseline = match scope {
+                        MonitorScope::Process => *cpu_time_baseline_ms.get_or_insert(usage.cpu_time_ms),
+                        MonitorScope::ProcessTree => 0,
+                    };
                     let sample = ResourceSample {
-                        memory_bytes: process.memory(),
-                        cpu_percent: process.cpu_usage() as f64,
+                        memory_bytes: usage.memory_bytes,
+                        cpu_percent: usage.cpu_percent,
+                        cpu_time_ms: usage.cpu_time_ms.saturating_sub(baseline),
                         timestamp_ms: start.elapsed().as_millis() as u64,
                     };
 
@@ -114,9 +162,12 @@ impl ResourceMonitor {
         let peak_memory = *memory_values.iter().max().unwrap_or(&0);
         let avg_cpu = cpu_values.iter().sum::<f64>() / cpu_values.len() as f64;
 
+        let cpu_time_ms = samples.iter().map(|s| s.cpu_time_ms).max().unwrap_or(0);
+
         ResourceStats {
             peak_memory_bytes: peak_memory,
             avg_cpu_percent: avg_cpu,
+            cpu_time_ms,
             p50_memory_bytes: Self::calculate_percentile(memory_values.clone(), 0.50),
             p95_memory_bytes: Self::calculate_percentile(memory_values.clone(), 0.95),
             p99_memory_bytes: Self::calculate_percentile(memory_values, 0.99),
@@ -131,6 +182,54 @@ impl Default for ResourceMonitor {
     }
 }
 
+/// Resource usage of one process or process tree at a single refresh
+#[derive(Debug, Clone, Copy, Default)]
+struct ProcessUsage {
+    memory_bytes: u64,
+    cpu_percent: f64,
+    cpu_time_ms: u64,
+}
+
+impl ProcessUsage {
+    fn of(process: &Process) -> Self {
+        Self {
+            memory_bytes: process.memory(),
+            cpu_percent: process.cpu_usage() as f64,
+            cpu_time_ms: process.accumulated_cpu_time(),
+        }
+    }
+
+    /// Sum the usage of `root` and all of its descendants
+    ///
+    /// Threads are skipped because their memory is already part of the owning process.
+    /// Returns `None` once `root` has exited.
+    fn of_tree(system: &System, root: Pid) -> Option<Self> {
+        system.process(root)?;
+
+        let mut total = Self::default();
+        let mut pending = vec![root];
+
+        while let Some(pid) = pending.pop() {
+            if let Some(process) = system.process(pid) {
+                let usage = Self::of(process);
+                total.memory_bytes += usage.memory_bytes;
+                total.cpu_percent += usage.cpu_percent;
+                total.cpu_time_ms += usage.cpu_time_ms;
+            }
+
+            pending.extend(
+                system
+                    .processes()
+                    .values()
+                    .filter(|p| p.parent() == Some(pid) && p.thread_kind().is_none())
+                    .map(|p| p.pid()),
+            );
+        }
+
+        Some(total)
+    }
+}
+
 /// Resource usage statistics
 #[derive(Debug, Clone, Copy, Default)]
 pub struct ResourceStats {
@@ -138,6 +237,8 @@ pub struct ResourceStats {
     pub peak_memory_bytes: u64,
     /// Average CPU usage percentage
     pub avg_cpu_percent: f64,
+    /// CPU time (user + system) consumed while monitoring, in milliseconds
+    pub cpu_time_ms: u64,
     /// 50th percentile (median) memory usage
     pub p50_memory_bytes: u64,
     /// 95th percentile memory usage
@@ -186,22 +287,44 @@ mod tests {
         assert!(samples.len() >= 3, "Should have at least 3 samples");
     }
 
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_process_tree_monitor_includes_children() {
+        let mut child = tokio::process::Command::new("sh")
+            .args(["-c", "sleep 0.3 & wait"])
+            .spawn()
+            .expect("Failed to spawn sh");
+
+        let monitor = ResourceMonitor::for_process_tree(child.id().expect("child should be running"));
+        monitor.start(Duration::from_millis(10)).await;
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let samples = monitor.stop().await;
+        child.wait().await.unwrap();
+
+        let stats = ResourceMonitor::calculate_stats(&samples);
+        assert!(stats.sample_count > 0, "Should have sampled the child process");
+        assert!(stats.peak_memory_bytes > 0);
+    }
+
     #[tokio::test]
     async fn test_resource_stats_calculation() {
         let samples = vec![
             ResourceSample {
                 memory_bytes: 100,
                 cpu_percent: 10.0,
+                cpu_time_ms: 5,
                 timestamp_ms: 0,
             },
             ResourceSample {
                 memory_bytes: 200,
                 cpu_percent: 20.0,
+                cpu_time_ms: 12,
                 timestamp_ms: 10,
             },
             ResourceSample {
                 memory_bytes: 150,
                 cpu_percent: 15.0,
+                cpu_time_ms: 18,
                 timestamp_ms: 20,
             },
         ];
@@ -211,6 +334,7 @@ mod tests {
         assert_eq!(stats.peak_memory_bytes, 200);
         assert_eq!(stats.p50_memory_bytes, 150);
         assert!((stats.avg_cpu_percent - 15.0).abs() < 0.1);
+        assert_eq!(stats.cpu_time_ms, 18);
         assert_eq!(stats.sample_count, 3);
     }
 
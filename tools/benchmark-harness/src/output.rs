@@ -3,7 +3,7 @@
 //! This module provides functionality for persisting benchmark results to disk
 //! in JSON format.
 
-use crate::types::BenchmarkResult;
+use crate::types::{BenchmarkResult, QualityMetrics};
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -47,6 +47,18 @@ pub struct FrameworkExtensionStats {
     pub avg_throughput_mbps: f64,
     /// Average peak memory in MB
     pub avg_peak_memory_mb: f64,
+    /// P95 of peak memory across files in MB
+    pub p95_peak_memory_mb: f64,
+    /// Average CPU time per extraction in milliseconds
+    pub avg_cpu_time_ms: f64,
+    /// Average character error rate over files with ground truth
+    pub avg_cer: Option<f64>,
+    /// Average word error rate over files with ground truth
+    pub avg_wer: Option<f64>,
+    /// Average table cell F1 over files with expected tables
+    pub avg_table_cell_f1: Option<f64>,
+    /// Average metadata accuracy over files with expected metadata
+    pub avg_metadata_accuracy: Option<f64>,
 }
 
 /// Analysis of results grouped by file extension
@@ -178,6 +190,31 @@ fn calculate_framework_stats(results: &[&BenchmarkResult]) -> FrameworkExtension
         0.0
     };
 
+    let mut peak_memories: Vec<f64> = successful_results
+        .iter()
+        .map(|r| r.metrics.peak_memory_bytes as f64 / 1_000_000.0)
+        .collect();
+    peak_memories.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let p95_peak_memory_mb = if !peak_memories.is_empty() {
+        let idx = ((peak_memories.len() as f64 * 0.95) as usize).min(peak_memories.len() - 1);
+        peak_memories[idx]
+    } else {
+        0.0
+    };
+
+    let avg_cpu_time_ms = if !successful_results.is_empty() {
+        successful_results
+            .iter()
+            .map(|r| r.metrics.cpu_time_ms as f64)
+            .sum::<f64>()
+            / successful_results.len() as f64
+    } else {
+        0.0
+    };
+
+    let qualities: Vec<&QualityMetrics> = successful_results.iter().filter_map(|r| r.quality.as_ref()).collect();
+
     FrameworkExtensionStats {
         count,
         successful,
@@ -187,9 +224,21 @@ fn calculate_framework_stats(results: &[&BenchmarkResult]) -> FrameworkExtension
         p95_duration_ms,
         avg_throughput_mbps,
         avg_peak_memory_mb,
+        p95_peak_memory_mb,
+        avg_cpu_time_ms,
+        avg_cer: mean(qualities.iter().map(|q| q.cer)),
+        avg_wer: mean(qualities.iter().map(|q| q.wer)),
+        avg_table_cell_f1: mean(qualities.iter().filter_map(|q| q.table_cell_f1)),
+        avg_metadata_accuracy: mean(qualities.iter().filter_map(|q| q.metadata_accuracy)),
     }
 }
 
+/// Mean of the values, or `None` if there are none
+fn mean(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, count), value| (sum + value, count + 1));
+    if count == 0 { None } else { Some(sum / count as f64) }
+}
+
 /// Write by-extension analysis to JSON file
 ///
 /// # Arguments
@@ -235,6 +284,7 @@ mod tests {
             metrics: PerformanceMetrics {
                 peak_memory_bytes: 10_000_000,
                 avg_cpu_percent: 50.0,
+                cpu_time_ms: 2_000,
                 throughput_bytes_per_sec: 1024.0,
                 p50_memory_bytes: 8_000_000,
                 p95_memory_bytes: 9_500_000,
@@ -247,6 +297,7 @@ mod tests {
             file_extension: "txt".to_string(),
             framework_capabilities: Default::default(),
             pdf_metadata: None,
+            output: None,
         }];
 
         write_json(&results, &output_path).unwrap();
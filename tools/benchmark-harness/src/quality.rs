@@ -0,0 +1,474 @@
+//! Accuracy scoring against ground-truth fixtures
+//!
+//! Fixtures may reference expected outputs (see [`GroundTruth`](crate::fixture::GroundTruth)):
+//! the expected text, the expected table cells and expected metadata fields. This module
+//! compares a framework's output against them:
+//!
+//! - **CER/WER**: Levenshtein distance over characters/words divided by the reference length,
+//!   after collapsing whitespace (0.0 = exact match; can exceed 1.0 for very noisy output)
+//! - **Token F1**: bag-of-words F1 over all tokens and over numeric tokens only
+//! - **Table cell F1**: F1 over the multiset of non-empty cell texts of all tables, independent
+//!   of how a framework splits or orders tables
+//! - **Metadata accuracy**: fraction of expected metadata fields with a matching value
+//!
+//! Edit distances are quadratic in the length of the differing region, so ground-truth texts
+//! should be kept to benchmark-sized documents.
+
+use crate::fixture::Fixture;
+use crate::types::QualityMetrics;
+use crate::{Error, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Output of a single extraction, as needed for accuracy scoring
+#[derive(Debug, Clone, Default)]
+pub struct ExtractedOutput {
+    /// Extracted text content
+    pub content: String,
+
+    /// Extracted tables as rows of cell texts
+    pub tables: Vec<Vec<Vec<String>>>,
+
+    /// Extracted metadata fields
+    pub metadata: serde_json::Map<String, serde_json::Value>,
+}
+
+impl ExtractedOutput {
+    /// Read the extraction output from a subprocess JSON payload
+    ///
+    /// Expects `content`, and optionally `tables` (each either an array of rows or an object
+    /// with a `cells` array) and `metadata`.
+    pub fn from_json(value: &serde_json::Value) -> Self {
+        let content = value
+            .get("content")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        let tables = value
+            .get("tables")
+            .and_then(|v| v.as_array())
+            .map(|tables| {
+                tables
+                    .iter()
+                    .filter_map(|table| table.get("cells").unwrap_or(table).as_array())
+                    .map(|rows| rows.iter().map(cells_from_json).collect())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let metadata = value
+            .get("metadata")
+            .and_then(|v| v.as_object())
+            .cloned()
+            .unwrap_or_default();
+
+        Self {
+            content,
+            tables,
+            metadata,
+        }
+    }
+}
+
+fn cells_from_json(row: &serde_json::Value) -> Vec<String> {
+    row.as_array()
+        .map(|cells| {
+            cells
+                .iter()
+                .map(|cell| match cell {
+                    serde_json::Value::String(s) => s.clone(),
+                    serde_json::Value::Null => String::new(),
+                    other => other.to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Expected output of a fixture, loaded from its ground-truth files
+#[derive(Debug, Clone, Default)]
+pub struct ExpectedOutput {
+    /// Expected text content
+    pub text: String,
+
+    /// Expected tables as rows of cell texts (empty when the fixture has no `tables_file`)
+    pub tables: Vec<Vec<Vec<String>>>,
+
+    /// Expected metadata field values
+    pub metadata: HashMap<String, serde_json::Value>,
+}
+
+impl ExpectedOutput {
+    /// Load the expected output of a fixture
+    ///
+    /// # Arguments
+    /// * `fixture` - Fixture to load the ground truth for
+    /// * `fixture_dir` - Directory containing the fixture file
+    ///
+    /// # Returns
+    /// * `Ok(None)` - The fixture has no ground truth
+    /// * `Ok(Some(ExpectedOutput))` - Loaded ground truth
+    /// * `Err(Error)` - A referenced ground-truth file is missing or malformed
+    pub fn from_fixture(fixture: &Fixture, fixture_dir: &Path) -> Result<Option<Self>> {
+        let Some(ground_truth) = &fixture.ground_truth else {
+            return Ok(None);
+        };
+
+        let text_path = fixture_dir.join(&ground_truth.text_file);
+        let text = std::fs::read_to_string(&text_path).map_err(|_| Error::DocumentNotFound(text_path))?;
+
+        let tables = match &ground_truth.tables_file {
+            Some(tables_file) => {
+                let tables_path = fixture_dir.join(tables_file);
+                let contents =
+                    std::fs::read_to_string(&tables_path).map_err(|_| Error::DocumentNotFound(tables_path.clone()))?;
+                parse_expected_tables(&contents).map_err(|e| Error::InvalidFixture {
+                    path: tables_path,
+                    reason: format!("invalid expected tables: {}", e),
+                })?
+            }
+            None => Vec::new(),
+        };
+
+        Ok(Some(Self {
+            text,
+            tables,
+            metadata: ground_truth.metadata.clone(),
+        }))
+    }
+}
+
+/// Expected tables are either `[[["a", "b"], ...], ...]` or `[{"cells": [["a", "b"], ...]}, ...]`
+fn parse_expected_tables(contents: &str) -> serde_json::Result<Vec<Vec<Vec<String>>>> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ExpectedTable {
+        Rows(Vec<Vec<String>>),
+        Object { cells: Vec<Vec<String>> },
+    }
+
+    let tables: Vec<ExpectedTable> = serde_json::from_str(contents)?;
+    Ok(tables
+        .into_iter()
+        .map(|table| match table {
+            ExpectedTable::Rows(cells) | ExpectedTable::Object { cells } => cells,
+        })
+        .collect())
+}
+
+/// Score an extraction output against the expected output
+pub fn score(expected: &ExpectedOutput, actual: &ExtractedOutput) -> QualityMetrics {
+    let expected_words = tokenize(&expected.text);
+    let actual_words = tokenize(&actual.content);
+
+    let cer = character_error_rate(&expected.text, &actual.content);
+    let wer = error_rate(&expected_words, &actual_words);
+
+    let f1_score_text = token_f1(&expected_words, &actual_words);
+    let f1_score_numeric = token_f1(&numeric_tokens(&expected_words), &numeric_tokens(&actual_words));
+
+    let table_cell_f1 = (!expected.tables.is_empty()).then(|| table_cell_f1(&expected.tables, &actual.tables));
+    let metadata_accuracy =
+        (!expected.metadata.is_empty()).then(|| metadata_accuracy(&expected.metadata, &actual.metadata));
+
+    let mut scores = vec![f1_score_text, 1.0 - cer.min(1.0)];
+    scores.extend(table_cell_f1);
+    scores.extend(metadata_accuracy);
+    let quality_score = scores.iter().sum::<f64>() / scores.len() as f64;
+
+    QualityMetrics {
+        f1_score_text,
+        f1_score_numeric,
+        cer,
+        wer,
+        table_cell_f1,
+        metadata_accuracy,
+        quality_score,
+    }
+}
+
+/// Character error rate of `hypothesis` against `reference`, ignoring whitespace differences
+pub fn character_error_rate(reference: &str, hypothesis: &str) -> f64 {
+    let reference: Vec<char> = normalize_whitespace(reference).chars().collect();
+    let hypothesis: Vec<char> = normalize_whitespace(hypothesis).chars().collect();
+    error_rate(&reference, &hypothesis)
+}
+
+/// Word error rate of `hypothesis` against `reference`
+pub fn word_error_rate(reference: &str, hypothesis: &str) -> f64 {
+    error_rate(&tokenize(reference), &tokenize(hypothesis))
+}
+
+fn error_rate<T: PartialEq>(reference: &[T], hypothesis: &[T]) -> f64 {
+    if reference.is_empty() {
+        return if hypothesis.is_empty() { 0.0 } else { 1.0 };
+    }
+    edit_distance(reference, hypothesis) as f64 / reference.len() as f64
+}
+
+/// Levenshtein distance, skipping the common prefix and suffix first
+fn edit_distance<T: PartialEq>(a: &[T], b: &[T]) -> usize {
+    let prefix = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[prefix..], &b[prefix..]);
+    let suffix = a.iter().rev().zip(b.iter().rev()).take_while(|(x, y)| x == y).count();
+    let (a, b) = (&a[..a.len() - suffix], &b[..b.len() - suffix]);
+
+    if a.is_empty() || b.is_empty() {
+        return a.len().max(b.len());
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, x) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, y) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(x != y);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split_whitespace().map(str::to_string).collect()
+}
+
+fn numeric_tokens(tokens: &[String]) -> Vec<String> {
+    tokens
+        .iter()
+        .filter(|token| token.chars().any(|c| c.is_ascii_digit()))
+        .cloned()
+        .collect()
+}
+
+/// Bag-of-tokens F1; 1.0 when both sides are empty
+fn token_f1(expected: &[String], actual: &[String]) -> f64 {
+    if expected.is_empty() && actual.is_empty() {
+        return 1.0;
+    }
+
+    let mut remaining: HashMap<&str, usize> = HashMap::new();
+    for token in expected {
+        *remaining.entry(token.as_str()).or_default() += 1;
+    }
+
+    let mut matched = 0;
+    for token in actual {
+        if let Some(count) = remaining.get_mut(token.as_str())
+            && *count > 0
+        {
+            *count -= 1;
+            matched += 1;
+        }
+    }
+
+    f1(matched, expected.len(), actual.len())
+}
+
+/// F1 over the multiset of non-empty, whitespace-normalized cell texts
+pub fn table_cell_f1(expected: &[Vec<Vec<String>>], actual: &[Vec<Vec<String>>]) -> f64 {
+    let cells = |tables: &[Vec<Vec<String>>]| -> Vec<String> {
+        tables
+            .iter()
+            .flatten()
+            .flatten()
+            .map(|cell| normalize_whitespace(cell))
+            .filter(|cell| !cell.is_empty())
+            .collect()
+    };
+
+    token_f1(&cells(expected), &cells(actual))
+}
+
+/// Fraction of expected metadata fields whose extracted value matches
+///
+/// Field names may be dotted paths into nested objects (e.g. `"pdf.page_count"`). Strings are
+/// compared trimmed and case-insensitively, numbers numerically.
+pub fn metadata_accuracy(
+    expected: &HashMap<String, serde_json::Value>,
+    actual: &serde_json::Map<String, serde_json::Value>,
+) -> f64 {
+    if expected.is_empty() {
+        return 1.0;
+    }
+
+    let matched = expected
+        .iter()
+        .filter(|(field, value)| lookup(actual, field).is_some_and(|found| values_match(value, found)))
+        .count();
+
+    matched as f64 / expected.len() as f64
+}
+
+fn lookup<'a>(metadata: &'a serde_json::Map<String, serde_json::Value>, field: &str) -> Option<&'a serde_json::Value> {
+    if let Some(value) = metadata.get(field) {
+        return Some(value);
+    }
+
+    let mut parts = field.split('.');
+    let mut value = metadata.get(parts.next()?)?;
+    for part in parts {
+        value = value.get(part)?;
+    }
+    Some(value)
+}
+
+fn values_match(expected: &serde_json::Value, actual: &serde_json::Value) -> bool {
+    use serde_json::Value;
+
+    match (expected, actual) {
+        (Value::String(e), Value::String(a)) => e.trim().eq_ignore_ascii_case(a.trim()),
+        (Value::Number(e), Value::Number(a)) => e.as_f64() == a.as_f64(),
+        (Value::Number(e), Value::String(a)) | (Value::String(a), Value::Number(e)) => {
+            a.trim().parse::<f64>().ok() == e.as_f64()
+        }
+        _ => expected == actual,
+    }
+}
+
+fn f1(matched: usize, expected: usize, actual: usize) -> f64 {
+    if matched == 0 {
+        return 0.0;
+    }
+    let precision = matched as f64 / actual as f64;
+    let recall = matched as f64 / expected as f64;
+    2.0 * precision * recall / (precision + recall)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn row(cells: &[&str]) -> Vec<String> {
+        cells.iter().map(|cell| cell.to_string()).collect()
+    }
+
+    fn table(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter().copied().map(row).collect()
+    }
+
+    #[test]
+    fn test_error_rates() {
+        assert_eq!(character_error_rate("hello world", "hello  world\n"), 0.0);
+        assert!((character_error_rate("kitten", "sitting") - 3.0 / 6.0).abs() < 1e-9);
+        assert!((word_error_rate("the quick brown fox", "the quick fox") - 0.25).abs() < 1e-9);
+        assert_eq!(word_error_rate("", ""), 0.0);
+        assert_eq!(word_error_rate("", "noise"), 1.0);
+    }
+
+    #[test]
+    fn test_edit_distance_with_common_affixes() {
+        let a: Vec<char> = "prefix-abc-suffix".chars().collect();
+        let b: Vec<char> = "prefix-axc-suffix".chars().collect();
+        assert_eq!(edit_distance(&a, &b), 1);
+        assert_eq!(edit_distance(&a, &a), 0);
+        assert_eq!(edit_distance(&a[..6], &a), 11);
+    }
+
+    #[test]
+    fn test_table_cell_f1_ignores_table_boundaries() {
+        let expected = vec![table(&[&["Name", "Qty"], &["Widget", "4"]])];
+        let split = vec![table(&[&["Name", "Qty"]]), table(&[&["Widget", " 4 "]])];
+        assert_eq!(table_cell_f1(&expected, &split), 1.0);
+
+        let partial = vec![table(&[&["Name", "Qty"], &["Gadget", ""]])];
+        let score = table_cell_f1(&expected, &partial);
+        assert!((score - 2.0 / 3.5).abs() < 1e-9, "got {score}");
+        assert_eq!(table_cell_f1(&expected, &[]), 0.0);
+    }
+
+    #[test]
+    fn test_metadata_accuracy() {
+        let expected = HashMap::from([
+            ("title".to_string(), json!("Quarterly Report")),
+            ("pdf.page_count".to_string(), json!(3)),
+            ("author".to_string(), json!("Jane Doe")),
+        ]);
+        let actual = json!({"title": " quarterly report", "pdf": {"page_count": "3"}, "author": "J. Doe"});
+
+        let accuracy = metadata_accuracy(&expected, actual.as_object().unwrap());
+        assert!((accuracy - 2.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_extracted_output_from_json() {
+        let output = ExtractedOutput::from_json(&json!({
+            "content": "text",
+            "tables": [{"cells": [["a", "b"]], "markdown": "| a | b |"}, [["c", 1, null]]],
+            "metadata": {"title": "Doc"},
+        }));
+
+        assert_eq!(output.content, "text");
+        assert_eq!(output.tables, vec![table(&[&["a", "b"]]), table(&[&["c", "1", ""]])]);
+        assert_eq!(output.metadata["title"], "Doc");
+    }
+
+    #[test]
+    fn test_expected_output_from_fixture() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp_dir.path().join("expected.txt"), "Expected text").unwrap();
+        std::fs::write(
+            temp_dir.path().join("tables.json"),
+            r#"[[["a", "b"]], {"cells": [["c"]], "page_number": 1}]"#,
+        )
+        .unwrap();
+
+        let fixture: Fixture = serde_json::from_value(json!({
+            "document": "doc.pdf",
+            "file_type": "pdf",
+            "file_size": 10,
+            "ground_truth": {
+                "text_file": "expected.txt",
+                "source": "manual",
+                "tables_file": "tables.json",
+                "metadata": {"page_count": 1}
+            }
+        }))
+        .unwrap();
+
+        let expected = ExpectedOutput::from_fixture(&fixture, temp_dir.path())
+            .unwrap()
+            .unwrap();
+        assert_eq!(expected.text, "Expected text");
+        assert_eq!(expected.tables, vec![table(&[&["a", "b"]]), table(&[&["c"]])]);
+        assert_eq!(expected.metadata["page_count"], 1);
+    }
+
+    #[test]
+    fn test_score() {
+        let expected = ExpectedOutput {
+            text: "Invoice 42 total 100.00".to_string(),
+            tables: vec![table(&[&["Item", "Price"]])],
+            metadata: HashMap::from([("title".to_string(), json!("Invoice"))]),
+        };
+        let actual = ExtractedOutput {
+            content: "Invoice 42 total 100.00".to_string(),
+            tables: vec![table(&[&["Item", "Price"]])],
+            metadata: json!({"title": "Invoice"}).as_object().unwrap().clone(),
+        };
+
+        let metrics = score(&expected, &actual);
+        assert_eq!(metrics.cer, 0.0);
+        assert_eq!(metrics.wer, 0.0);
+        assert_eq!(metrics.f1_score_text, 1.0);
+        assert_eq!(metrics.f1_score_numeric, 1.0);
+        assert_eq!(metrics.table_cell_f1, Some(1.0));
+        assert_eq!(metrics.metadata_accuracy, Some(1.0));
+        assert_eq!(metrics.quality_score, 1.0);
+
+        let empty = score(&expected, &ExtractedOutput::default());
+        assert_eq!(empty.cer, 1.0);
+        assert_eq!(empty.table_cell_f1, Some(0.0));
+        assert_eq!(empty.quality_score, 0.0);
+    }
+}
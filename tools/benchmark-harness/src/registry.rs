@@ -6,6 +6,7 @@
 use crate::Error;
 use crate::adapter::FrameworkAdapter;
 use ahash::AHashMap;
+use std::path::Path;
 use std::sync::Arc;
 
 /// Registry for framework adapters
@@ -42,6 +43,27 @@ impl AdapterRegistry {
         Ok(())
     }
 
+    /// Register the custom adapters declared in a TOML file
+    ///
+    /// See [`crate::adapters::custom`] for the file format.
+    ///
+    /// # Arguments
+    /// * `path` - Path to the adapter declarations
+    ///
+    /// # Returns
+    /// * `Ok(Vec<String>)` - Names of the registered adapters
+    /// * `Err(Error::Config)` - Invalid declarations or an adapter name that is already registered
+    pub fn load_custom_adapters(&mut self, path: &Path) -> crate::Result<Vec<String>> {
+        let adapters = crate::adapters::load_custom_adapters(path)?;
+
+        let mut names = Vec::with_capacity(adapters.len());
+        for adapter in adapters {
+            names.push(adapter.name().to_string());
+            self.register(Arc::new(adapter))?;
+        }
+        Ok(names)
+    }
+
     /// Get an adapter by name
     ///
     /// # Arguments
@@ -169,4 +191,20 @@ mod tests {
         assert!(removed.is_some());
         assert_eq!(registry.len(), 0);
     }
+
+    #[test]
+    fn test_load_custom_adapters() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("adapters.toml");
+        let adapter = "[[adapter]]\nname = \"acme\"\ncommand = [\"acme\", \"{file}\"]\n";
+
+        std::fs::write(&path, format!("{adapter}\n{adapter}")).unwrap();
+        let mut registry = AdapterRegistry::new();
+        assert!(registry.load_custom_adapters(&path).is_err());
+
+        std::fs::write(&path, adapter).unwrap();
+        let mut registry = AdapterRegistry::new();
+        assert_eq!(registry.load_custom_adapters(&path).unwrap(), vec!["acme".to_string()]);
+        assert!(registry.contains("acme"));
+    }
 }
@@ -6,9 +6,11 @@
 use crate::adapter::FrameworkAdapter;
 use crate::config::{BenchmarkConfig, BenchmarkMode};
 use crate::fixture::FixtureManager;
+use crate::quality::{self, ExpectedOutput};
 use crate::registry::AdapterRegistry;
 use crate::types::{BenchmarkResult, DurationStatistics, IterationResult, PerformanceMetrics};
 use crate::{Error, Result};
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
@@ -102,6 +104,8 @@ fn aggregate_metrics(iterations: &[IterationResult]) -> PerformanceMetrics {
 
     let avg_cpu_percent = iterations.iter().map(|i| i.metrics.avg_cpu_percent).sum::<f64>() / count;
 
+    let cpu_time_ms = (iterations.iter().map(|i| i.metrics.cpu_time_ms).sum::<u64>() as f64 / count) as u64;
+
     let throughput_bytes_per_sec = iterations
         .iter()
         .map(|i| i.metrics.throughput_bytes_per_sec)
@@ -117,6 +121,7 @@ fn aggregate_metrics(iterations: &[IterationResult]) -> PerformanceMetrics {
     PerformanceMetrics {
         peak_memory_bytes,
         avg_cpu_percent,
+        cpu_time_ms,
         throughput_bytes_per_sec,
         p50_memory_bytes,
         p95_memory_bytes,
@@ -172,6 +177,7 @@ impl BenchmarkRunner {
     /// * `adapter` - Framework adapter to use
     /// * `config` - Benchmark configuration
     /// * `cold_start_duration` - Optional cold start duration for this framework
+    /// * `expected` - Expected output for quality scoring, if the fixture has ground truth
     ///
     /// # Returns
     /// Aggregated benchmark result with iterations and statistics
@@ -180,6 +186,7 @@ impl BenchmarkRunner {
         adapter: Arc<dyn FrameworkAdapter>,
         config: &BenchmarkConfig,
         cold_start_duration: Option<Duration>,
+        expected: Option<&ExpectedOutput>,
     ) -> Result<BenchmarkResult> {
         let total_iterations = config.warmup_iterations + config.benchmark_iterations;
         let mut all_results = Vec::new();
@@ -192,9 +199,20 @@ impl BenchmarkRunner {
             }
         }
 
+        let quality = expected.and_then(|expected| {
+            all_results
+                .iter()
+                .find_map(|result| result.output.as_ref())
+                .map(|output| quality::score(expected, output))
+        });
+        for result in &mut all_results {
+            result.output = None;
+        }
+
         if config.benchmark_iterations == 1 && !all_results.is_empty() {
             let mut result = all_results.into_iter().next().unwrap();
             result.cold_start_duration = cold_start_duration;
+            result.quality = quality;
             return Ok(result);
         }
 
@@ -242,13 +260,14 @@ impl BenchmarkRunner {
             extraction_duration: avg_extraction_duration,
             subprocess_overhead,
             metrics: aggregated_metrics,
-            quality: first_result.quality.clone(),
+            quality,
             iterations,
             statistics: Some(statistics),
             cold_start_duration,
             file_extension: first_result.file_extension.clone(),
             framework_capabilities: first_result.framework_capabilities.clone(),
             pdf_metadata: first_result.pdf_metadata.clone(),
+            output: None,
         })
     }
 
@@ -336,6 +355,7 @@ impl BenchmarkRunner {
             file_extension: first_result.file_extension.clone(),
             framework_capabilities: first_result.framework_capabilities.clone(),
             pdf_metadata: first_result.pdf_metadata.clone(),
+            output: None,
         }];
 
         Ok(aggregated_results)
@@ -392,13 +412,17 @@ impl BenchmarkRunner {
             }
         }
 
+        let expected_outputs = if self.config.measure_quality {
+            self.load_expected_outputs()
+        } else {
+            HashMap::new()
+        };
+
         let mut results = Vec::new();
 
         let use_batch = matches!(self.config.benchmark_mode, BenchmarkMode::Batch);
 
         if use_batch {
-            use std::collections::HashMap;
-
             let mut adapter_files: HashMap<String, Vec<PathBuf>> = HashMap::new();
 
             for (fixture_path, fixture) in self.fixtures.fixtures() {
@@ -447,8 +471,10 @@ impl BenchmarkRunner {
                             let file_path = file_path.clone();
                             let config = config.clone();
                             let cold_start = self.cold_start_durations.get(adapter_name).copied();
+                            let expected = expected_outputs.get(&file_path);
 
-                            match Self::run_iterations_static(&file_path, adapter, &config, cold_start).await {
+                            match Self::run_iterations_static(&file_path, adapter, &config, cold_start, expected).await
+                            {
                                 Ok(result) => {
                                     results.push(result);
                                 }
@@ -480,7 +506,8 @@ impl BenchmarkRunner {
 
             for (file_path, framework_name, adapter) in task_queue {
                 let cold_start = self.cold_start_durations.get(&framework_name).copied();
-                match Self::run_iterations_static(&file_path, adapter, &config, cold_start).await {
+                let expected = expected_outputs.get(&file_path);
+                match Self::run_iterations_static(&file_path, adapter, &config, cold_start, expected).await {
                     Ok(result) => {
                         results.push(result);
                     }
@@ -498,6 +525,33 @@ impl BenchmarkRunner {
         Ok(results)
     }
 
+    /// Load the expected outputs of all fixtures with ground truth, keyed by document path
+    ///
+    /// Fixtures whose ground-truth files cannot be loaded are reported and left unscored.
+    fn load_expected_outputs(&self) -> HashMap<PathBuf, ExpectedOutput> {
+        let mut expected_outputs = HashMap::new();
+
+        for (fixture_path, fixture) in self.fixtures.fixtures() {
+            let fixture_dir = fixture_path.parent().unwrap_or_else(|| std::path::Path::new("."));
+
+            match ExpectedOutput::from_fixture(fixture, fixture_dir) {
+                Ok(Some(expected)) => {
+                    expected_outputs.insert(fixture.resolve_document_path(fixture_dir), expected);
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    eprintln!(
+                        "Warning: Skipping quality scoring for {}: {}",
+                        fixture_path.display(),
+                        e
+                    );
+                }
+            }
+        }
+
+        expected_outputs
+    }
+
     /// Get reference to benchmark configuration
     pub fn config(&self) -> &BenchmarkConfig {
         &self.config
@@ -1,5 +1,6 @@
 //! Core types for benchmark results and metrics
 
+use crate::quality::ExtractedOutput;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::time::Duration;
@@ -64,6 +65,11 @@ pub struct BenchmarkResult {
     /// PDF-specific metadata (only present for PDF files)
     /// Includes text layer detection results and OCR strategy
     pub pdf_metadata: Option<PdfMetadata>,
+
+    /// Extracted content, tables and metadata used for quality scoring
+    /// Dropped once scored and never written to results files
+    #[serde(skip)]
+    pub output: Option<ExtractedOutput>,
 }
 
 /// Performance metrics collected during extraction
@@ -75,6 +81,11 @@ pub struct PerformanceMetrics {
     /// Average CPU usage percentage (0-100)
     pub avg_cpu_percent: f64,
 
+    /// CPU time (user + system) spent by the extraction process in milliseconds
+    /// For subprocess adapters this includes all child processes
+    #[serde(default)]
+    pub cpu_time_ms: u64,
+
     /// Throughput in bytes per second
     pub throughput_bytes_per_sec: f64,
 
@@ -97,10 +108,22 @@ pub struct QualityMetrics {
     /// Numeric token F1 score (0.0-1.0)
     pub f1_score_numeric: f64,
 
-    /// Layout/structure F1 score (0.0-1.0)
-    pub f1_score_layout: f64,
+    /// Character error rate against the expected text (0.0 = exact match)
+    pub cer: f64,
+
+    /// Word error rate against the expected text (0.0 = exact match)
+    pub wer: f64,
+
+    /// Table cell F1 score (0.0-1.0)
+    /// Only present when the fixture defines expected tables
+    pub table_cell_f1: Option<f64>,
+
+    /// Fraction of expected metadata fields extracted correctly (0.0-1.0)
+    /// Only present when the fixture defines expected metadata
+    pub metadata_accuracy: Option<f64>,
 
-    /// Overall text quality score (0.0-1.0)
+    /// Overall quality score (0.0-1.0)
+    /// Mean of text F1, 1 - CER (clamped) and the table/metadata scores when present
     pub quality_score: f64,
 }
 
@@ -198,13 +198,13 @@ public static class TestHelpers
         {
             return;
         }
-        var langs = result.DetectedLanguages ?? new List<string>();
+        var langs = result.DetectedLanguages ?? new List<DetectedLanguage>();
         if (langs.Count == 0)
         {
             throw new XunitException($"Expected detected languages {string.Join(", ", expectedList)} but field is empty");
         }
 
-        var missing = expectedList.Where(lang => !langs.Any(candidate => string.Equals(candidate, lang, StringComparison.OrdinalIgnoreCase))).ToArray();
+        var missing = expectedList.Where(lang => !langs.Any(candidate => string.Equals(candidate.Language, lang, StringComparison.OrdinalIgnoreCase))).ToArray();
         if (missing.Length > 0)
         {
             throw new XunitException($"Expected languages {string.Join(", ", expectedList)}, missing {string.Join(", ", missing)}");
@@ -212,11 +212,11 @@ public static class TestHelpers
 
         if (minConfidence.HasValue)
         {
-            var metadataNode = MetadataToJson(result.Metadata);
-            var confidence = LookupMetadata(metadataNode, "confidence");
-            if (confidence is JsonValue value && value.TryGetValue<double>(out var dbl) && dbl < minConfidence.Value)
+            var low = langs.FirstOrDefault(candidate =>
+                expectedList.Contains(candidate.Language, StringComparer.OrdinalIgnoreCase) && candidate.Confidence < minConfidence.Value);
+            if (low != null)
             {
-                throw new XunitException($"Expected confidence >= {minConfidence.Value}, got {dbl}");
+                throw new XunitException($"Expected confidence >= {minConfidence.Value} for {low.Language}, got {low.Confidence}");
             }
         }
     }
@@ -177,8 +177,11 @@ func assertDetectedLanguages(t *testing.T, result *kreuzberg.ExtractionResult, e
 	for _, lang := range expected {
 		found := false
 		for _, candidate := range langs {
-			if strings.EqualFold(candidate, lang) {
+			if strings.EqualFold(candidate.Language, lang) {
 				found = true
+				if minConfidence != nil && candidate.Confidence < *minConfidence {
+					t.Fatalf("expected confidence >= %f for %s, got %f", *minConfidence, lang, candidate.Confidence)
+				}
 				break
 			}
 		}
@@ -189,15 +192,6 @@ func assertDetectedLanguages(t *testing.T, result *kreuzberg.ExtractionResult, e
 	if len(missing) > 0 {
 		t.Fatalf("expected languages %v, missing %v", expected, missing)
 	}
-
-	if minConfidence != nil {
-		metadata := metadataAsMap(t, result.Metadata)
-		if value, ok := lookupMetadataValue(metadata, "confidence").(float64); ok {
-			if value < *minConfidence {
-				t.Fatalf("expected confidence >= %f, got %f", *minConfidence, value)
-			}
-		}
-	}
 }
 
 func assertMetadataExpectation(t *testing.T, result *kreuzberg.ExtractionResult, path string, expectation []byte) {
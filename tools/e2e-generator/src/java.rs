@@ -11,6 +11,7 @@ const JAVA_HELPERS_TEMPLATE: &str = r#"package com.kreuzberg.e2e;
 
 import com.fasterxml.jackson.databind.JsonNode;
 import com.fasterxml.jackson.databind.ObjectMapper;
+import dev.kreuzberg.DetectedLanguage;
 import dev.kreuzberg.ExtractionResult;
 import dev.kreuzberg.Kreuzberg;
 import dev.kreuzberg.MissingDependencyException;
@@ -223,22 +224,20 @@ public final class E2EHelpers {
             if (expected.isEmpty()) {
                 return;
             }
-            List<String> languages = result.getDetectedLanguages();
+            List<DetectedLanguage> languages = result.getDetectedLanguages();
             assertNotNull(languages, "Expected detected languages to be present");
             boolean allFound = expected.stream()
-                    .allMatch(lang -> languages.contains(lang));
+                    .allMatch(lang -> languages.stream().anyMatch(detected -> detected.language().equals(lang)));
             assertTrue(allFound,
                     String.format("Expected languages %s to be in %s", expected, languages));
 
             if (minConfidence != null) {
-                Map<String, Object> metadata = result.getMetadata();
-                if (metadata != null && metadata.containsKey("confidence")) {
-                    Object confObj = metadata.get("confidence");
-                    double confidence = confObj instanceof Number
-                            ? ((Number) confObj).doubleValue()
-                            : 0.0;
-                    assertTrue(confidence >= minConfidence,
-                            String.format("Expected confidence >= %f, got %f", minConfidence, confidence));
+                for (DetectedLanguage detected : languages) {
+                    if (expected.contains(detected.language())) {
+                        assertTrue(detected.confidence() >= minConfidence,
+                                String.format("Expected confidence >= %f for %s, got %f",
+                                        minConfidence, detected.language(), detected.confidence()));
+                    }
                 }
             }
         }
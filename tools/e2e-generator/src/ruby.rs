@@ -141,13 +141,12 @@ module E2ERuby
 
       languages = result.detected_languages
       expect(languages).not_to be_nil
-      expect(expected.all? { |lang| languages.include?(lang) }).to be(true)
+      detected = languages.to_h { |lang| [lang.language, lang.confidence] }
+      expect(expected.all? { |lang| detected.key?(lang) }).to be(true)
 
       return unless min_confidence
 
-      metadata = result.metadata || {}
-      confidence = metadata['confidence'] || metadata[:confidence]
-      expect(confidence).to be >= min_confidence if confidence
+      expected.each { |lang| expect(detected[lang]).to be >= min_confidence }
     end
 
     def self.assert_metadata_expectation(result, path, expectation)
@@ -140,6 +140,8 @@ function mapTokenReductionConfig(raw: PlainRecord): TokenReductionConfig {
         config.mode = raw.mode as string;
     }
     assignBooleanField(config as PlainRecord, raw, "preserve_important_words", "preserveImportantWords");
+    assignNumberField(config as PlainRecord, raw, "target_ratio", "targetRatio");
+    assignNumberField(config as PlainRecord, raw, "max_tokens", "maxTokens");
     return config;
 }
 
@@ -148,6 +150,7 @@ function mapLanguageDetectionConfig(raw: PlainRecord): LanguageDetectionConfig {
     assignBooleanField(config as PlainRecord, raw, "enabled", "enabled");
     assignNumberField(config as PlainRecord, raw, "min_confidence", "minConfidence");
     assignBooleanField(config as PlainRecord, raw, "detect_multiple", "detectMultiple");
+    assignNumberField(config as PlainRecord, raw, "max_sample_kb", "maxSampleKb");
     return config;
 }
 
@@ -300,12 +303,11 @@ export const assertions = {
         }
         expect(result.detectedLanguages).toBeDefined();
         const languages = result.detectedLanguages ?? [];
-        expect(expected.every((lang) => languages.includes(lang))).toBe(true);
+        expect(expected.every((lang) => languages.some((detected) => detected.language === lang))).toBe(true);
 
-        if (typeof minConfidence === "number" && isPlainRecord(result.metadata)) {
-            const confidence = (result.metadata as PlainRecord).confidence;
-            if (typeof confidence === "number") {
-                expect(confidence >= minConfidence).toBe(true);
+        if (typeof minConfidence === "number") {
+            for (const detected of languages.filter((lang) => expected.includes(lang.language))) {
+                expect(detected.confidence >= minConfidence).toBe(true);
             }
         }
     },